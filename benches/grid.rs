@@ -0,0 +1,97 @@
+//! 表格数据路径的性能基准
+//!
+//! 运行：`cargo bench --bench grid`
+//!
+//! 在 10 000 行 × 12 列的结果集上测量：
+//! - 切换 Tab 时共享结果（`Arc` 克隆）与复制整个结果集
+//! - 首次筛选与命中缓存的筛选
+//!
+//! 共享结果应远快于复制，命中缓存应远快于重新筛选；
+//! 不满足时以非零状态退出，防止结果共享或筛选缓存失效导致的性能回退。
+
+use std::hint::black_box;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use gridix::database::QueryResult;
+use gridix::ui::{filter_rows_cached, ColumnFilter, FilterCache, FilterOperator, SearchOptions};
+
+const ROWS: usize = 10_000;
+const COLUMNS: usize = 12;
+
+/// 命中缓存的筛选至少比重新筛选快的倍数
+const MIN_FILTER_CACHE_SPEEDUP: f64 = 5.0;
+/// 共享结果至少比复制结果集快的倍数
+const MIN_SHARE_SPEEDUP: f64 = 100.0;
+
+fn sample_result() -> QueryResult {
+    QueryResult {
+        columns: (0..COLUMNS).map(|c| format!("col_{}", c)).collect(),
+        rows: (0..ROWS)
+            .map(|r| (0..COLUMNS).map(|c| format!("value {} / {} {}", r, c, r * 31 % 97)).collect())
+            .collect(),
+        ..Default::default()
+    }
+}
+
+/// 重复运行并返回每次耗时的中位数
+fn measure(name: &str, iterations: usize, mut f: impl FnMut()) -> Duration {
+    let mut samples: Vec<Duration> = (0..iterations)
+        .map(|_| {
+            let start = Instant::now();
+            f();
+            start.elapsed()
+        })
+        .collect();
+    samples.sort();
+    let median = samples[samples.len() / 2];
+    println!("{:<28} {:>12.3?}  (中位数，{} 次)", name, median, iterations);
+    median
+}
+
+fn speedup(slow: Duration, fast: Duration) -> f64 {
+    slow.as_secs_f64() / fast.as_secs_f64().max(1e-9)
+}
+
+fn main() {
+    let result = Arc::new(sample_result());
+    let mut failures = Vec::new();
+
+    // 切换 Tab：共享 vs 复制
+    let deep = measure("复制结果集", 20, || {
+        black_box(QueryResult::clone(&result));
+    });
+    let shared = measure("共享结果（Arc）", 1000, || {
+        black_box(Arc::clone(&result));
+    });
+    if speedup(deep, shared) < MIN_SHARE_SPEEDUP {
+        failures.push(format!("共享结果只比复制快 {:.1} 倍", speedup(deep, shared)));
+    }
+
+    // 筛选：首次计算 vs 命中缓存
+    let filters = vec![ColumnFilter {
+        operator: FilterOperator::Contains,
+        value: "42".to_string(),
+        ..ColumnFilter::new("col_3".to_string())
+    }];
+    let options = SearchOptions::default();
+    let cold = measure("筛选（重新计算）", 20, || {
+        let mut cache = FilterCache::default();
+        black_box(filter_rows_cached(&result, "7", &None, &options, &filters, &mut cache).len());
+    });
+    let mut cache = FilterCache::default();
+    filter_rows_cached(&result, "7", &None, &options, &filters, &mut cache);
+    let warm = measure("筛选（命中缓存）", 200, || {
+        black_box(filter_rows_cached(&result, "7", &None, &options, &filters, &mut cache).len());
+    });
+    if speedup(cold, warm) < MIN_FILTER_CACHE_SPEEDUP {
+        failures.push(format!("命中缓存的筛选只比重新计算快 {:.1} 倍", speedup(cold, warm)));
+    }
+
+    if !failures.is_empty() {
+        for failure in &failures {
+            eprintln!("性能回退: {}", failure);
+        }
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,269 @@
+//! 本地 API 服务
+//!
+//! 在 127.0.0.1 上监听只读 HTTP 请求，执行共享的查询并返回 JSON 或 CSV。
+//! 服务需要启用 `api-server` 功能编译；未启用时面板仍可编辑设置，但无法启动。
+
+use std::sync::Arc;
+
+use parking_lot::RwLock;
+
+use crate::core::{t, tf, ApiServerConfig};
+use crate::database::ConnectionConfig;
+use crate::ui;
+use super::DbManagerApp;
+
+/// 服务读取的共享状态，保存配置时更新
+#[cfg_attr(not(feature = "api-server"), allow(dead_code))]
+#[derive(Default)]
+pub(super) struct ApiShared {
+    pub config: ApiServerConfig,
+    /// 已补全超时设置的连接
+    pub connections: Vec<ConnectionConfig>,
+}
+
+/// 共享状态的句柄
+pub(super) type ApiSharedHandle = Arc<RwLock<ApiShared>>;
+
+/// 运行中的服务
+#[cfg_attr(not(feature = "api-server"), allow(dead_code))]
+pub(super) struct ApiServer {
+    task: tokio::task::JoinHandle<()>,
+    port: u16,
+}
+
+impl DbManagerApp {
+    /// 打开 API 服务面板
+    pub(super) fn open_api_server(&mut self) {
+        let mut connections: Vec<String> = self
+            .manager
+            .connections
+            .values()
+            .filter(|c| !c.config.is_scratchpad())
+            .map(|c| c.config.name.clone())
+            .collect();
+        connections.sort();
+        let active = self
+            .manager
+            .active
+            .clone()
+            .filter(|name| connections.contains(name));
+        self.api_server_state
+            .open(self.app_config.api_server.clone(), connections, self.sql.clone(), active);
+        self.api_server_state.running_port = self.api_server.as_ref().map(|s| s.port);
+    }
+
+    /// 处理 API 服务面板的操作
+    pub(super) fn handle_api_server_result(&mut self, result: ui::ApiServerResult) {
+        match result {
+            ui::ApiServerResult::None => {}
+            ui::ApiServerResult::Save(config) => {
+                self.app_config.api_server = config;
+                self.save_config();
+                self.notifications.success(t("notify.api_settings_saved"));
+            }
+            ui::ApiServerResult::Start(config) => {
+                self.app_config.api_server = config;
+                self.save_config();
+                self.start_api_server();
+            }
+            ui::ApiServerResult::Stop => self.stop_api_server(),
+        }
+        self.api_server_state.running_port = self.api_server.as_ref().map(|s| s.port);
+    }
+
+    /// 用当前配置和连接更新服务读取的共享状态（草稿本不对外提供）
+    pub(super) fn sync_api_server(&self) {
+        let connections = self
+            .manager
+            .connections
+            .values()
+            .filter(|c| !c.config.is_scratchpad())
+            .map(|c| self.resolve_timeouts(c.config.clone()))
+            .collect();
+        *self.api_shared.write() = ApiShared {
+            config: self.app_config.api_server.clone(),
+            connections,
+        };
+    }
+
+    /// 停止服务
+    pub(super) fn stop_api_server(&mut self) {
+        if let Some(server) = self.api_server.take() {
+            server.task.abort();
+            self.notifications.info(t("notify.api_stopped"));
+        }
+    }
+
+    /// 启动服务
+    #[cfg(feature = "api-server")]
+    pub(super) fn start_api_server(&mut self) {
+        self.stop_api_server();
+        // 没有令牌时不提供服务，首次启动时生成随机令牌
+        match self.app_config.api_server.ensure_token() {
+            Ok(false) => {}
+            Ok(true) => {
+                self.api_server_state.config.token = self.app_config.api_server.token.clone();
+                self.save_config();
+                self.notifications.info(t("notify.api_token_generated"));
+            }
+            Err(e) => {
+                self.notifications.error(tf("notify.api_start_failed", &[&e]));
+                return;
+            }
+        }
+        self.sync_api_server();
+        let port = self.app_config.api_server.port;
+        let listener = match self.runtime.block_on(tokio::net::TcpListener::bind(("127.0.0.1", port))) {
+            Ok(listener) => listener,
+            Err(e) => {
+                self.notifications.error(tf("notify.api_port_failed", &[&port.to_string(), &e.to_string()]));
+                return;
+            }
+        };
+        let shared = Arc::clone(&self.api_shared);
+        let task = self.runtime.spawn(server::serve(listener, shared));
+        self.api_server = Some(ApiServer { task, port });
+        self.notifications.success(tf("notify.api_started", &[&port.to_string()]));
+    }
+
+    /// 启动服务（未启用 `api-server` 功能）
+    #[cfg(not(feature = "api-server"))]
+    pub(super) fn start_api_server(&mut self) {
+        self.notifications.error(tf("notify.api_disabled", &["api-server"]));
+    }
+}
+
+#[cfg(feature = "api-server")]
+mod server {
+    use std::time::Duration;
+
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::{TcpListener, TcpStream};
+
+    use crate::core::{http_error, http_response, write_csv, ApiFormat, ApiRequest, ApiRoute, MAX_REQUEST_HEAD};
+    use crate::database::execute_read_only;
+
+    use super::ApiSharedHandle;
+
+    /// 读取请求头的超时
+    const READ_TIMEOUT_SECS: u64 = 10;
+
+    /// 接受连接，每个连接在单独的任务中处理
+    pub(super) async fn serve(listener: TcpListener, shared: ApiSharedHandle) {
+        loop {
+            match listener.accept().await {
+                Ok((stream, _)) => {
+                    let shared = shared.clone();
+                    tokio::spawn(async move {
+                        if let Err(e) = handle_connection(stream, shared).await {
+                            tracing::debug!(error = %e, "API 请求处理失败");
+                        }
+                    });
+                }
+                Err(e) => tracing::warn!(error = %e, "API 服务接受连接失败"),
+            }
+        }
+    }
+
+    async fn handle_connection(mut stream: TcpStream, shared: ApiSharedHandle) -> std::io::Result<()> {
+        let head = tokio::time::timeout(Duration::from_secs(READ_TIMEOUT_SECS), read_head(&mut stream)).await;
+        let response = match head {
+            Ok(Ok(head)) => respond(&head, &shared).await,
+            Ok(Err(e)) => http_error(400, &e),
+            Err(_) => http_error(400, "读取请求超时"),
+        };
+        stream.write_all(&response).await?;
+        stream.shutdown().await
+    }
+
+    /// 读取到请求头结束（空行）
+    async fn read_head(stream: &mut TcpStream) -> Result<String, String> {
+        let mut buf = Vec::new();
+        let mut chunk = [0u8; 1024];
+        loop {
+            let n = stream.read(&mut chunk).await.map_err(|e| e.to_string())?;
+            if n == 0 {
+                break;
+            }
+            buf.extend_from_slice(&chunk[..n]);
+            if buf.windows(4).any(|w| w == b"\r\n\r\n") {
+                break;
+            }
+            if buf.len() > MAX_REQUEST_HEAD {
+                return Err("请求头过长".to_string());
+            }
+        }
+        String::from_utf8(buf).map_err(|_| "请求头不是 UTF-8".to_string())
+    }
+
+    async fn respond(head: &str, shared: &ApiSharedHandle) -> Vec<u8> {
+        let request = match ApiRequest::parse(head) {
+            Ok(request) => request,
+            Err(e) => return http_error(400, &e),
+        };
+        // 读取快照后立即释放锁，执行查询期间不阻塞界面更新设置
+        let (query, format, config) = {
+            let shared = shared.read();
+            if !request.allowed_host() {
+                return http_error(403, "Host 请求头无效");
+            }
+            if !request.authorized(&shared.config.token) {
+                return http_error(401, "令牌无效");
+            }
+            match request.route() {
+                ApiRoute::List => {
+                    let list: Vec<_> = shared
+                        .config
+                        .queries
+                        .iter()
+                        .map(|q| serde_json::json!({ "name": q.name, "connection": q.connection }))
+                        .collect();
+                    let body = serde_json::to_vec(&list).unwrap_or_default();
+                    return http_response(200, "application/json; charset=utf-8", &body);
+                }
+                ApiRoute::NotFound => return http_error(404, "路径不存在"),
+                ApiRoute::MethodNotAllowed => return http_error(405, "只支持 GET"),
+                ApiRoute::Query { name, format } => {
+                    let Some(query) = shared.config.find(&name).cloned() else {
+                        return http_error(404, &format!("查询不存在: {}", name));
+                    };
+                    if let Err(e) = query.validate() {
+                        return http_error(403, &e);
+                    }
+                    let Some(connection) = shared.connections.iter().find(|c| c.name == query.connection).cloned()
+                    else {
+                        return http_error(404, &format!("连接不存在: {}", query.connection));
+                    };
+                    (query, format, connection)
+                }
+            }
+        };
+
+        let timeout_secs = config.query_timeout_secs();
+        let result = match tokio::time::timeout(Duration::from_secs(timeout_secs), execute_read_only(&config, &query.sql)).await
+        {
+            Ok(Ok(result)) => result,
+            Ok(Err(e)) => return http_error(500, &e.to_string()),
+            Err(_) => return http_error(504, &format!("查询超时 ({}秒)", timeout_secs)),
+        };
+
+        match format {
+            ApiFormat::Json => {
+                let body = serde_json::json!({
+                    "name": query.name,
+                    "columns": result.columns,
+                    "rows": result.rows,
+                    "truncated": result.truncated,
+                });
+                http_response(200, "application/json; charset=utf-8", body.to_string().as_bytes())
+            }
+            ApiFormat::Csv => {
+                let mut body = Vec::new();
+                match write_csv(&result, &mut body) {
+                    Ok(()) => http_response(200, "text/csv; charset=utf-8", &body),
+                    Err(e) => http_error(500, &e),
+                }
+            }
+        }
+    }
+}
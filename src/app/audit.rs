@@ -0,0 +1,60 @@
+//! 审计日志模块
+//!
+//! 为各个功能创建审计记录器，并负责打开审计日志窗口和导出日志。
+
+use crate::core::{t, tf, AuditRecorder, AuditSource};
+use crate::database::QueryResult;
+use super::DbManagerApp;
+
+impl DbManagerApp {
+    /// 为当前连接创建审计记录器
+    pub(super) fn audit_recorder(&self, source: AuditSource) -> AuditRecorder {
+        self.audit_recorder_for(self.manager.active.as_deref().unwrap_or_default(), source)
+    }
+
+    /// 创建记录指定连接上写操作的审计记录器（排队的查询开始时当前连接可能已切换）
+    pub(super) fn audit_recorder_for(&self, connection: &str, source: AuditSource) -> AuditRecorder {
+        let (connection, database_type) = self
+            .manager
+            .connections
+            .get(connection)
+            .map(|c| (c.config.name.clone(), c.config.db_type.display_name().to_string()))
+            .unwrap_or_default();
+        AuditRecorder::new(self.audit_log.clone(), connection, database_type, source)
+    }
+
+    /// 读取审计日志并打开窗口
+    pub(super) fn open_audit_log(&mut self) {
+        let Some(log) = &self.audit_log else {
+            self.notifications.error(t("notify.audit_path_unknown"));
+            return;
+        };
+        match log.read() {
+            Ok(entries) => self.audit_log_state.open(entries, log.path().display().to_string()),
+            Err(e) => {
+                self.notifications.error(e);
+            }
+        }
+    }
+
+    /// 将筛选后的审计记录导出为 CSV
+    pub(super) fn export_audit_log(&mut self, report: &QueryResult) {
+        let file_name = format!("audit_{}.csv", chrono::Local::now().format("%Y%m%d_%H%M%S"));
+        let Some(path) = rfd::FileDialog::new()
+            .set_file_name(file_name)
+            .add_filter("CSV 文件", &["csv"])
+            .save_file()
+        else {
+            return;
+        };
+
+        match crate::core::export_to_csv(report, &path) {
+            Ok(()) => {
+                self.notifications.success(tf("notify.audit_exported", &[&report.rows.len().to_string(), &path.display().to_string()]));
+            }
+            Err(e) => {
+                self.notifications.error(tf("common.export_failed", &[&e.to_string()]));
+            }
+        }
+    }
+}
@@ -0,0 +1,87 @@
+//! 查询基准测试
+//!
+//! 在后台按顺序重复执行编辑器中的查询：先执行预热次数（不计时），再逐次计时执行，
+//! 每次完成后立即回报。只允许只读查询，停止或关闭窗口时中止任务。
+
+use std::time::Instant;
+
+use crate::core::{is_read_only_statement, t, BenchmarkSample};
+use crate::database::{execute_query, split_statements};
+
+use super::message::Message;
+use super::DbManagerApp;
+
+impl DbManagerApp {
+    /// 打开基准测试窗口
+    pub(super) fn open_benchmark(&mut self) {
+        if !self.manager.get_active().is_some_and(|c| c.connected) {
+            self.notifications.warning(t("common.need_connection"));
+            return;
+        }
+        let statements = split_statements(&self.sql);
+        if statements.is_empty() {
+            self.notifications.warning(t("notify.benchmark_no_query"));
+            return;
+        }
+        if !statements.iter().all(|s| is_read_only_statement(s)) {
+            self.notifications.warning(t("notify.benchmark_read_only"));
+            return;
+        }
+        self.benchmark_state.open(self.sql.trim().to_string());
+    }
+
+    /// 开始执行基准测试
+    pub(super) fn start_benchmark(&mut self) {
+        self.stop_benchmark();
+        let Some(conn) = self.manager.get_active() else {
+            return;
+        };
+        let config = self.resolve_timeouts(conn.config.clone());
+        let statements = split_statements(&self.benchmark_state.sql);
+        let warmup = self.benchmark_state.warmup;
+        let runs = self.benchmark_state.runs;
+        let run_id = self.benchmark_state.start();
+        let tx = self.tx.clone();
+
+        let handle = self.runtime.spawn(async move {
+            for i in 0..warmup + runs {
+                let is_warmup = i < warmup;
+                let start = Instant::now();
+                let mut rows = 0;
+                let mut error = None;
+                for statement in &statements {
+                    match execute_query(&config, statement).await {
+                        Ok(result) => rows = result.rows.len(),
+                        Err(e) => {
+                            error = Some(e.to_string());
+                            break;
+                        }
+                    }
+                }
+                let sample = match error {
+                    Some(e) => Err(e),
+                    None => Ok(BenchmarkSample {
+                        elapsed_ms: start.elapsed().as_secs_f64() * 1000.0,
+                        rows,
+                    }),
+                };
+                let failed = sample.is_err();
+                if tx.send(Message::BenchmarkRun(run_id, is_warmup, sample)).is_err() || failed {
+                    break;
+                }
+            }
+            if tx.send(Message::BenchmarkDone(run_id)).is_err() {
+                tracing::warn!("无法发送基准测试结果：接收端已关闭");
+            }
+        });
+        self.benchmark_task = Some(handle);
+    }
+
+    /// 停止正在进行的基准测试
+    pub(super) fn stop_benchmark(&mut self) {
+        if let Some(handle) = self.benchmark_task.take() {
+            handle.abort();
+        }
+        self.benchmark_state.running = false;
+    }
+}
@@ -0,0 +1,147 @@
+//! 无界面模式：使用已保存的连接执行查询并导出结果，或运行已保存的工作流
+
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::time::Duration;
+
+use crate::core::{
+    write_csv, write_json, write_sql, AppConfig, AuditLog, AuditRecorder, AuditSource, CliArgs, ExportFormat,
+    PluginRegistry, StepStatus, WorkflowRun,
+};
+use crate::database::{execute_query, ConnectionConfig, QueryResult};
+
+use super::workflow::execute_workflow;
+
+/// 执行命令行指定的查询，将结果写到输出文件或标准输出
+///
+/// 成功时返回结果行数；行数提示写到标准错误，不影响输出内容。
+pub fn run_headless(args: &CliArgs) -> Result<usize, String> {
+    let mut app_config = AppConfig::load();
+    app_config.unlock_from_env()?;
+    let mut config = app_config
+        .connections
+        .iter()
+        .find(|c| c.name == args.connection)
+        .cloned()
+        .ok_or_else(|| format!("找不到已保存的连接: {}", args.connection))?
+        .with_default_timeouts(app_config.connect_timeout_secs, app_config.query_timeout_secs);
+    if let Some(database) = &args.database {
+        config.database = database.clone();
+    }
+    run_query_headless(&config, args, AuditLog::open_default())
+}
+
+/// 在指定连接上执行命令行查询并输出结果
+///
+/// 写操作记录到审计日志（`audit_log` 为空时不记录）。
+pub fn run_query_headless(config: &ConnectionConfig, args: &CliArgs, audit_log: Option<AuditLog>) -> Result<usize, String> {
+    let audit = AuditRecorder::new(
+        audit_log,
+        config.name.clone(),
+        config.db_type.display_name().to_string(),
+        AuditSource::Cli,
+    );
+    let runtime = build_runtime()?;
+    let timeout_secs = config.query_timeout_secs();
+    let result = match runtime
+        .block_on(async { tokio::time::timeout(Duration::from_secs(timeout_secs), execute_query(config, &args.query)).await })
+    {
+        Ok(Ok(result)) => Ok(result),
+        Ok(Err(e)) => Err(e.to_string()),
+        Err(_) => Err(format!("查询超时 ({}秒)", timeout_secs)),
+    };
+    audit.record(&args.query, result.as_ref().map(|r| r.affected_rows).map_err(String::as_str));
+    let result = result?;
+
+    // 非查询语句没有结果集，只报告影响行数
+    if result.columns.is_empty() {
+        eprintln!("影响 {} 行", result.affected_rows);
+        return Ok(0);
+    }
+    match &args.output {
+        Some(path) => {
+            let file = File::create(path).map_err(|e| format!("无法创建输出文件 {}: {}", path.display(), e))?;
+            write_result(&result, args, &mut BufWriter::new(file))?;
+        }
+        None => write_result(&result, args, &mut io::stdout().lock())?,
+    }
+    eprintln!("{} 行", result.rows.len());
+    Ok(result.rows.len())
+}
+
+/// 运行已保存的工作流，逐步将进度写到标准错误
+///
+/// 任一步骤失败时返回错误。
+pub fn run_workflow_headless(name: &str) -> Result<(), String> {
+    let mut app_config = AppConfig::load();
+    app_config.unlock_from_env()?;
+    let workflow = app_config
+        .workflows
+        .iter()
+        .find(|w| w.name == name)
+        .cloned()
+        .ok_or_else(|| format!("找不到已保存的工作流: {}", name))?;
+    workflow.validate()?;
+    let connections: Vec<ConnectionConfig> = app_config
+        .connections
+        .iter()
+        .map(|c| c.clone().with_default_timeouts(app_config.connect_timeout_secs, app_config.query_timeout_secs))
+        .collect();
+
+    let total = workflow.steps.len();
+    let runtime = build_runtime()?;
+    let run = runtime.block_on(execute_workflow(
+        WorkflowRun::new(workflow),
+        &connections,
+        &PluginRegistry::new(),
+        AuditLog::open_default(),
+        |run| {
+            let Some((i, status)) = run
+                .statuses
+                .iter()
+                .enumerate()
+                .rfind(|(_, s)| !matches!(s, StepStatus::Pending | StepStatus::Skipped))
+            else {
+                return;
+            };
+            let step = run.workflow.steps[i].describe();
+            match status {
+                StepStatus::Running => eprintln!("[{}/{}] {}", i + 1, total, step),
+                StepStatus::Done(msg) => eprintln!("[{}/{}] 完成: {}", i + 1, total, msg),
+                StepStatus::Failed(e) => eprintln!("[{}/{}] 失败: {}", i + 1, total, e),
+                _ => {}
+            }
+        },
+    ));
+
+    let skipped = run.statuses.iter().filter(|s| **s == StepStatus::Skipped).count();
+    if skipped > 0 {
+        eprintln!("已跳过 {} 个步骤", skipped);
+    }
+    match run.failed_count() {
+        0 => Ok(()),
+        failed => Err(format!("工作流「{}」有 {} 个步骤失败", run.workflow.name, failed)),
+    }
+}
+
+fn build_runtime() -> Result<tokio::runtime::Runtime, String> {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|e| format!("无法创建 tokio 运行时: {}", e))
+}
+
+fn write_result(result: &QueryResult, args: &CliArgs, out: &mut dyn Write) -> Result<(), String> {
+    if let Some(id) = &args.plugin_format {
+        let plugins = PluginRegistry::new();
+        let exporter = plugins.exporter(id).ok_or_else(|| format!("导出插件不存在: {}", id))?;
+        exporter.export(result, out)?;
+        return out.flush().map_err(|e| e.to_string());
+    }
+    match args.format {
+        ExportFormat::Csv => write_csv(result, out)?,
+        ExportFormat::Json => write_json(result, out)?,
+        ExportFormat::Sql => write_sql(result, &args.table, out)?,
+    }
+    out.flush().map_err(|e| e.to_string())
+}
@@ -0,0 +1,47 @@
+//! 列设置
+//!
+//! 列头右键设置的数字格式、拖动调整的列宽和列顺序按连接、数据库和表保存（随配置在退出时保存），
+//! 切换表时载入该表的设置。没有对应表的查询结果（如自定义 SQL）只在当前会话中生效。
+//! 列头菜单隐藏和冻结的列、置顶的行不保存，切换表时重置。
+
+use std::collections::HashMap;
+
+use crate::core::RecentTarget;
+use super::DbManagerApp;
+
+impl DbManagerApp {
+    /// 当前表变化后载入该表的列设置
+    pub(super) fn sync_column_settings(&mut self) {
+        let target = self.filter_preset_target();
+        if target == self.column_settings_target {
+            return;
+        }
+        self.grid_state.hidden_columns.clear();
+        self.grid_state.frozen_columns = 0;
+        self.grid_state.pinned_rows.clear();
+        match &target {
+            Some(RecentTarget { connection, database, table: Some(table) }) => {
+                let database = database.as_deref();
+                self.grid_state.column_formats = self.app_config.column_formats.for_table(connection, database, table);
+                self.grid_state.column_widths = self.app_config.column_widths.for_table(connection, database, table);
+                self.grid_state.column_order = self.app_config.column_orders.for_table(connection, database, table);
+            }
+            _ => {
+                self.grid_state.column_formats = HashMap::new();
+                self.grid_state.column_widths = HashMap::new();
+                self.grid_state.column_order.clear();
+            }
+        }
+        self.column_settings_target = target;
+    }
+
+    /// 保存当前表的列设置
+    pub(super) fn save_column_settings(&mut self) {
+        if let Some(RecentTarget { connection, database, table: Some(table) }) = &self.column_settings_target {
+            let database = database.as_deref();
+            self.app_config.column_formats.set_table(connection, database, table, &self.grid_state.column_formats);
+            self.app_config.column_widths.set_table(connection, database, table, &self.grid_state.column_widths);
+            self.app_config.column_orders.set_table(connection, database, table, &self.grid_state.column_order);
+        }
+    }
+}
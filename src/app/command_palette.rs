@@ -0,0 +1,132 @@
+//! 命令面板模块
+//!
+//! 收集可执行的命令并分发到与菜单、快捷键相同的处理逻辑。
+
+use crate::core::{t, Action, ThemePreset};
+use crate::ui::{self, PaletteCommand, PaletteItem, ToolbarActions};
+use super::DbManagerApp;
+
+/// 设置工具栏操作标志的函数
+type ToolbarCommand = fn(&mut ToolbarActions);
+
+/// 没有对应快捷键操作的工具栏功能：（名称键，搜索关键字，设置的工具栏操作）
+const TOOLBAR_COMMANDS: &[(&str, &str, ToolbarCommand)] = &[
+    ("toolbar.size_overview", "size overview", |a| a.show_size_overview = true),
+    ("toolbar.session_monitor", "session monitor", |a| a.show_session_monitor = true),
+    ("toolbar.copy_table", "duplicate copy table", |a| a.duplicate_table = true),
+    ("toolbar.truncate_table", "truncate table", |a| a.truncate_table = true),
+    ("toolbar.generate_data", "generate test data", |a| a.generate_test_data = true),
+    ("toolbar.scheduled_queries", "scheduled queries scheduler", |a| a.show_scheduler = true),
+    ("toolbar.pin_snapshot", "pin snapshot", |a| a.pin_snapshot = true),
+    ("toolbar.compare_snapshot", "compare snapshot diff", |a| a.compare_snapshot = true),
+    ("toolbar.audit_log", "audit log", |a| a.show_audit_log = true),
+    ("toolbar.pool_stats", "connection pool stats", |a| a.show_pool_stats = true),
+    ("toolbar.trash", "trash recycle bin restore deleted", |a| a.show_trash = true),
+    ("toolbar.master_password", "master password encrypt lock unlock", |a| a.show_master_password = true),
+    ("toolbar.benchmark", "benchmark run repeat timing latency", |a| a.show_benchmark = true),
+    ("toolbar.profile", "profile statistics buffers explain analyze", |a| a.show_profile = true),
+    ("toolbar.row_bookmarks", "row bookmarks jump list favorite", |a| a.show_row_bookmarks = true),
+    ("toolbar.edit_theme", "theme editor", |a| a.show_theme_editor = true),
+    ("toolbar.font_settings", "font settings", |a| a.show_font_settings = true),
+    ("toolbar.about", "about", |a| a.show_about = true),
+    ("toolbar.start_tour", "tour tutorial onboarding guide", |a| a.start_tour = true),
+];
+
+impl DbManagerApp {
+    /// 打开命令面板
+    pub(super) fn open_command_palette(&mut self) {
+        let items = self.palette_items();
+        self.command_palette_state.open(items);
+    }
+
+    /// 命令面板中的所有命令
+    fn palette_items(&self) -> Vec<PaletteItem> {
+        let mut items = Vec::new();
+
+        // 快捷键系统中的操作
+        for &action in Action::all() {
+            if action == Action::ShowCommandPalette {
+                continue;
+            }
+            items.push(
+                PaletteItem::new(t("palette.command"), action.description(), PaletteCommand::Action(action))
+                    .with_hint(self.keybindings.display(action))
+                    .with_keywords(action.id().replace('_', " ")),
+            );
+        }
+
+        // 工具栏功能
+        for &(key, keywords, apply) in TOOLBAR_COMMANDS {
+            items.push(
+                PaletteItem::new(t("palette.command"), t(key), PaletteCommand::Toolbar(apply)).with_keywords(keywords),
+            );
+        }
+
+        // 连接
+        let mut connections: Vec<&String> = self.manager.connections.keys().collect();
+        connections.sort();
+        for name in connections {
+            let hint = self
+                .manager
+                .connections
+                .get(name)
+                .map(|c| c.config.db_type.display_name())
+                .unwrap_or_default();
+            items.push(
+                PaletteItem::new(t("palette.connect"), name.as_str(), PaletteCommand::Connect(name.clone()))
+                    .with_hint(hint),
+            );
+        }
+
+        // 当前连接的数据库和表
+        if let Some(conn) = self.manager.get_active() {
+            for db in &conn.databases {
+                items.push(PaletteItem::new(
+                    t("palette.database"),
+                    db.as_str(),
+                    PaletteCommand::SwitchDatabase(db.clone()),
+                ));
+            }
+            for table in &conn.tables {
+                items.push(PaletteItem::new(
+                    t("palette.table"),
+                    table.as_str(),
+                    PaletteCommand::OpenTable(table.clone()),
+                ));
+            }
+        }
+
+        // 主题
+        for preset in ThemePreset::ALL {
+            items.push(
+                PaletteItem::new(t("palette.theme"), preset.display_name(), PaletteCommand::SetTheme(preset))
+                    .with_keywords("theme"),
+            );
+        }
+
+        // 收藏的查询
+        for item in self.query_history.items().iter().filter(|i| i.favorite) {
+            let label = item.sql.split_whitespace().collect::<Vec<_>>().join(" ");
+            items.push(
+                PaletteItem::new(t("palette.favorite"), label, PaletteCommand::RunSql(item.sql.clone()))
+                    .with_hint(item.connection.clone().unwrap_or_default()),
+            );
+        }
+
+        items
+    }
+
+    /// 执行命令面板中选择的命令
+    pub(super) fn run_palette_command(&mut self, command: PaletteCommand, toolbar_actions: &mut ToolbarActions) {
+        match command {
+            PaletteCommand::Action(action) => self.run_action(action, toolbar_actions),
+            PaletteCommand::Toolbar(apply) => apply(toolbar_actions),
+            PaletteCommand::Connect(name) => toolbar_actions.switch_connection = Some(name),
+            PaletteCommand::SwitchDatabase(db) => toolbar_actions.switch_database = Some(db),
+            PaletteCommand::OpenTable(table) => toolbar_actions.switch_table = Some(table),
+            PaletteCommand::SetTheme(preset) => toolbar_actions.theme_changed = Some(preset),
+            PaletteCommand::RunSql(sql) => self.handle_history_action(ui::HistoryPanelAction::Run(sql)),
+            PaletteCommand::OpenRecent(target) => self.open_recent(target),
+        }
+    }
+}
@@ -0,0 +1,49 @@
+//! 配置文件热加载
+//!
+//! 定期检查配置文件的修改时间，在外部修改了主题或快捷键时立即生效，无需重启。
+//! 程序自己保存配置时读回的设置与内存中一致，不会重复应用。
+
+use std::time::{Duration, Instant};
+
+use crate::core::{t, tf, AppConfig, CONFIG_CHECK_INTERVAL_SECS};
+use eframe::egui;
+
+use super::DbManagerApp;
+
+impl DbManagerApp {
+    /// 配置文件被外部修改时重新加载主题和快捷键
+    pub(super) fn check_config_file(&mut self, ctx: &egui::Context) {
+        if self.last_config_check.elapsed() < Duration::from_secs(CONFIG_CHECK_INTERVAL_SECS) {
+            return;
+        }
+        self.last_config_check = Instant::now();
+
+        let modified = AppConfig::modified_time();
+        if modified.is_none() || modified == self.config_modified {
+            return;
+        }
+        self.config_modified = modified;
+        let Some(path) = AppConfig::config_path() else {
+            return;
+        };
+        let on_disk = match AppConfig::read(&path) {
+            Ok(config) => config,
+            Err(e) => {
+                self.notifications.warning(tf("notify.config_reload_failed", &[&e]));
+                return;
+            }
+        };
+
+        let reload = self.app_config.apply_reloaded(&on_disk);
+        if reload.theme {
+            self.restore_configured_theme(ctx);
+        }
+        if reload.keybindings {
+            self.keybindings = self.app_config.keybindings.clone();
+        }
+        if !reload.is_empty() {
+            tracing::info!(path = ?path, "已重新加载配置文件");
+            self.notifications.info(t("notify.config_reloaded"));
+        }
+    }
+}
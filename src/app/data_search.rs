@@ -0,0 +1,139 @@
+//! 全库数据搜索
+//!
+//! 在当前数据库选中的表中搜索关键字：每张表先读取列信息，只在文本列上
+//! 生成 `LIKE` 查询，用信号量限制同时执行的查询数。每张表完成后立即回报，
+//! 停止搜索或开始新的搜索时中止整个任务。
+
+use std::sync::Arc;
+
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+use crate::core::constants::database::{DATA_SEARCH_CONCURRENCY, DATA_SEARCH_ROW_LIMIT};
+use crate::core::{data_search_sql, searchable_columns, t, tf};
+use crate::database::{execute_query, get_table_columns};
+use crate::ui::{self, DataSearchOutcome};
+
+use super::message::Message;
+use super::DbManagerApp;
+
+impl DbManagerApp {
+    /// 打开全库数据搜索窗口
+    pub(super) fn open_data_search(&mut self) {
+        let Some(conn) = self.manager.get_active().filter(|c| c.connected) else {
+            self.notifications.warning(t("common.need_connection"));
+            return;
+        };
+        let db_type = conn.config.db_type;
+        if !db_type.uses_sql() {
+            self.notifications.warning(tf("notify.data_search_unsupported", &[db_type.display_name()]));
+            return;
+        }
+        if conn.tables.is_empty() {
+            self.notifications.warning(t("notify.data_search_no_tables"));
+            return;
+        }
+        let tables = conn.tables.clone();
+        self.data_search_state.open(tables);
+    }
+
+    /// 在选中的表中开始搜索
+    pub(super) fn start_data_search(&mut self) {
+        self.cancel_data_search();
+        let Some(conn) = self.manager.get_active() else {
+            return;
+        };
+        let config = self.resolve_timeouts(conn.config.clone());
+        let db_type = config.db_type;
+        let is_mysql = self.is_mysql();
+
+        let tables: Vec<(String, Result<String, String>)> = self
+            .data_search_state
+            .selected_tables()
+            .into_iter()
+            .map(|table| {
+                let quoted = self.quote_table_name(&table);
+                (table, quoted)
+            })
+            .collect();
+        let term = self.data_search_state.term.clone();
+        let column_filter = self.data_search_state.column_filter.clone();
+        let case_sensitive = self.data_search_state.case_sensitive;
+        let search_id = self.data_search_state.start(tables.len());
+        let tx = self.tx.clone();
+
+        let handle = self.runtime.spawn(async move {
+            let semaphore = Arc::new(Semaphore::new(DATA_SEARCH_CONCURRENCY));
+            let mut tasks = JoinSet::new();
+            for (table, quoted_table) in tables {
+                let semaphore = Arc::clone(&semaphore);
+                let config = config.clone();
+                let term = term.clone();
+                let column_filter = column_filter.clone();
+                let tx = tx.clone();
+                tasks.spawn(async move {
+                    let Ok(_permit) = semaphore.acquire_owned().await else {
+                        return;
+                    };
+                    let outcome = match quoted_table {
+                        Err(e) => DataSearchOutcome::Failed(e),
+                        Ok(quoted_table) => match get_table_columns(&config, &table).await {
+                            Err(e) => DataSearchOutcome::Failed(e.to_string()),
+                            Ok(columns) => {
+                                let quoted_columns: Result<Vec<String>, String> = searchable_columns(&columns, &column_filter)
+                                    .iter()
+                                    .map(|c| ui::quote_identifier(c, is_mysql))
+                                    .collect();
+                                match quoted_columns {
+                                    Err(e) => DataSearchOutcome::Failed(e),
+                                    Ok(quoted_columns) => match data_search_sql(
+                                        db_type,
+                                        &quoted_table,
+                                        &quoted_columns,
+                                        &term,
+                                        case_sensitive,
+                                        DATA_SEARCH_ROW_LIMIT,
+                                    ) {
+                                        None => DataSearchOutcome::NoTextColumns,
+                                        Some(sql) => {
+                                            let result = execute_query(&config, &sql).await.map_err(|e| e.to_string());
+                                            DataSearchOutcome::Searched(sql, result)
+                                        }
+                                    },
+                                }
+                            }
+                        },
+                    };
+                    let _ = tx.send(Message::DataSearchTableDone(search_id, table, outcome));
+                });
+            }
+            while tasks.join_next().await.is_some() {}
+            if tx.send(Message::DataSearchDone(search_id)).is_err() {
+                tracing::warn!("无法发送数据搜索结果：接收端已关闭");
+            }
+        });
+        self.data_search_task = Some(handle);
+    }
+
+    /// 停止正在进行的搜索（中止任务时会一并中止各表的查询）
+    pub(super) fn cancel_data_search(&mut self) {
+        if let Some(handle) = self.data_search_task.take() {
+            handle.abort();
+        }
+        self.data_search_state.searching = false;
+    }
+
+    /// 在表格中打开某张表的搜索结果，搜索 SQL 显示在编辑器中
+    pub(super) fn open_data_search_hit(&mut self, table: String, sql: String) {
+        self.data_search_state.close();
+        self.cancel_data_search();
+        self.record_recent(Some(&table));
+        self.selected_table = Some(table.clone());
+        self.grid_state.primary_key_column = None;
+        self.fetch_primary_key(&table);
+        self.fetch_column_types(&table);
+        self.sql = sql.clone();
+        self.show_sql_editor = true;
+        self.execute(sql);
+    }
+}
@@ -4,17 +4,23 @@
 
 use std::time::Instant;
 
-use crate::core::constants;
+use crate::core::{constants, generate_rows, t, tf, AuditRecorder, AuditSource, NotificationLevel, Rng};
 use crate::database::{
-    connect_database, execute_query, get_primary_key_column, get_tables_for_database,
-    ConnectResult,
+    change_sqlite_key, connect_database, execute_batch, execute_query, get_grants, get_sessions, get_table_ddl, get_table_sizes, get_users, maintenance_errors, MaintenanceOp, execute_query_page, get_all_sqlite_tables, save_sqlite_to_file, get_primary_key_column, get_redis_key_value, get_table_columns, get_tables_page,
+    supports_batch_transaction, ConnectResult, ConnectionConfig, DatabaseType, QueryResult, SqliteAttachment,
     ssh_tunnel::SSH_TUNNEL_MANAGER,
 };
 
 use super::message::Message;
 use super::DbManagerApp;
+use crate::ui::{self, MaintenanceTarget, ResultPage};
 
 impl DbManagerApp {
+    /// 用首选项中的默认超时补全连接未单独配置的超时
+    pub(super) fn resolve_timeouts(&self, config: ConnectionConfig) -> ConnectionConfig {
+        config.with_default_timeouts(self.app_config.connect_timeout_secs, self.app_config.query_timeout_secs)
+    }
+
     /// 连接到数据库
     pub(super) fn connect(&mut self, name: String) {
         if let Some(conn) = self.manager.connections.get(&name) {
@@ -24,10 +30,11 @@ impl DbManagerApp {
             self.connecting = true;
             self.manager.active = Some(name.clone());
 
+            let config = self.resolve_timeouts(config);
+            let timeout_secs = config.connect_timeout_secs();
             self.runtime.spawn(async move {
                 use tokio::time::{timeout, Duration};
                 // 连接超时
-                let timeout_secs = constants::database::CONNECTION_TIMEOUT_SECS;
                 let result = timeout(
                     Duration::from_secs(timeout_secs),
                     connect_database(&config),
@@ -44,9 +51,10 @@ impl DbManagerApp {
                     Err(_) => {
                         // 提供更详细的超时错误信息
                         let host_info = match &config.db_type {
-                            crate::database::DatabaseType::SQLite => {
+                            db_type if db_type.is_file_based() => {
                                 format!("文件: {}", if config.database.is_empty() { "未指定" } else { &config.database })
                             }
+                            DatabaseType::Odbc => format!("ODBC 数据源: {}", config.database),
                             _ => format!("{}:{}", config.host, config.port),
                         };
                         let err_msg = format!(
@@ -76,13 +84,14 @@ impl DbManagerApp {
 
         self.connecting = true;
 
+        let config = self.resolve_timeouts(config);
+        let timeout_secs = config.connect_timeout_secs();
         self.runtime.spawn(async move {
             use tokio::time::{timeout, Duration};
-            let timeout_secs = constants::database::CONNECTION_TIMEOUT_SECS;
             let db_name = database.clone();
             let result = timeout(
                 Duration::from_secs(timeout_secs),
-                get_tables_for_database(&config, &database),
+                get_tables_page(&config, &database, 0, constants::database::TABLE_PAGE_SIZE),
             )
             .await;
             let tables_result = match result {
@@ -106,6 +115,50 @@ impl DbManagerApp {
         });
     }
 
+    /// 读取当前数据库的下一批表（侧边栏"加载更多"）
+    pub(super) fn load_more_tables(&mut self) {
+        if self.sidebar_panel_state.loading_tables {
+            return;
+        }
+        let Some(active_name) = self.manager.active.clone() else {
+            return;
+        };
+        let Some(conn) = self.manager.connections.get(&active_name) else {
+            return;
+        };
+        let Some(database) = conn.selected_database.clone() else {
+            return;
+        };
+        if !conn.has_more_tables {
+            return;
+        }
+        let offset = conn.tables.len();
+        let config = self.resolve_timeouts(conn.config.clone());
+        let timeout_secs = config.query_timeout_secs();
+        let tx = self.tx.clone();
+
+        self.sidebar_panel_state.loading_tables = true;
+        self.runtime.spawn(async move {
+            use tokio::time::{timeout, Duration};
+            let result = timeout(
+                Duration::from_secs(timeout_secs),
+                get_tables_page(&config, &database, offset, constants::database::TABLE_PAGE_SIZE),
+            )
+            .await;
+            let tables_result = match result {
+                Ok(Ok(page)) => Ok(page),
+                Ok(Err(e)) => Err(e.to_string()),
+                Err(_) => Err(format!("获取表列表超时 ({}秒)", timeout_secs)),
+            };
+            if tx
+                .send(Message::MoreTablesLoaded(active_name, database, tables_result))
+                .is_err()
+            {
+                tracing::warn!("无法发送表列表结果：接收端已关闭");
+            }
+        });
+    }
+
     /// 断开数据库连接
     pub(super) fn disconnect(&mut self, name: String) {
         // 清理 SSH 隧道和连接池
@@ -136,20 +189,36 @@ impl DbManagerApp {
         if self.manager.active.as_deref() == Some(&name) {
             self.manager.active = None;
             self.selected_table = None;
-            self.result = None;
+            self.set_result(None);
         }
     }
 
     /// 删除连接配置
     pub(super) fn delete_connection(&mut self, name: &str) {
-        self.manager.connections.remove(name);
-        // 删除该连接的历史记录
-        self.app_config.command_history.remove(name);
+        // 草稿本需要释放内存数据库
+        if self
+            .manager
+            .connections
+            .get(name)
+            .is_some_and(|c| c.config.is_scratchpad())
+        {
+            self.disconnect(name.to_string());
+        }
+        self.save_current_history();
+        if let Some(conn) = self.manager.connections.remove(name) {
+            if conn.config.is_scratchpad() {
+                // 草稿本不进入回收站，直接删除历史记录
+                self.app_config.command_history.remove(name);
+                self.forget_connection_data(name);
+            } else {
+                self.trash_connection(conn.config);
+            }
+        }
         // 如果删除的是当前连接，清空当前状态
         if self.manager.active.as_deref() == Some(name) {
             self.manager.active = None;
             self.selected_table = None;
-            self.result = None;
+            self.set_result(None);
             self.command_history.clear();
             self.current_history_connection = None;
         }
@@ -158,51 +227,61 @@ impl DbManagerApp {
 
     /// 执行 SQL 查询
     pub(super) fn execute(&mut self, sql: String) {
+        self.execute_from(sql, AuditSource::Editor);
+    }
+
+    /// 执行 SQL 查询，写操作以 `source` 作为来源记入审计日志
+    pub(super) fn execute_from(&mut self, sql: String, source: AuditSource) {
         if sql.trim().is_empty() {
             return;
         }
 
         // 提前检查连接状态
         let Some(active_name) = &self.manager.active else {
-            self.notifications.warning("请先连接数据库");
+            self.notifications.warning(t("common.need_connection"));
             return;
         };
         let Some(conn) = self.manager.connections.get(active_name) else {
-            self.notifications.warning("请先连接数据库");
+            self.notifications.warning(t("common.need_connection"));
             return;
         };
 
-        let config = conn.config.clone();
-        let tx = self.tx.clone();
+        let connection = conn.config.name.clone();
 
         // 添加到命令历史
         if self.command_history.first() != Some(&sql) {
             self.command_history.insert(0, sql.clone());
             // 限制每个连接最多保存历史记录
-            if self.command_history.len() > constants::history::MAX_COMMAND_HISTORY_PER_CONNECTION {
-                self.command_history.pop();
-            }
+            self.command_history.truncate(self.app_config.command_history_limit);
             // 保存历史记录到配置文件
             self.save_current_history();
             let _ = self.app_config.save();
         }
         self.history_index = None;
 
-        self.executing = true;
-        self.result = None;
-        self.last_query_time_ms = None;
-
-        // 同步 SQL 到当前 Tab 并设置执行状态
+        // 同步 SQL 到当前 Tab
         if let Some(tab) = self.tab_manager.get_active_mut() {
             tab.sql = sql.clone();
-            tab.executing = true;
             tab.update_title();
         }
 
+        self.submit_query(connection, sql, source);
+    }
+
+    /// 在后台执行查询，结果通过 `Message::QueryDone` 发送
+    pub(super) fn spawn_query(
+        &self,
+        config: ConnectionConfig,
+        sql: String,
+        audit: AuditRecorder,
+        tab_id: Option<String>,
+    ) {
+        let tx = self.tx.clone();
+        let config = self.resolve_timeouts(config);
+        let timeout_secs = config.query_timeout_secs();
         self.runtime.spawn(async move {
             use tokio::time::{timeout, Duration};
             let start = Instant::now();
-            let timeout_secs = constants::database::QUERY_TIMEOUT_SECS;
             // 查询超时
             let result = timeout(
                 Duration::from_secs(timeout_secs),
@@ -218,8 +297,9 @@ impl DbManagerApp {
                     timeout_secs
                 )),
             };
+            audit.record(&sql, query_result.as_ref().map(|r| r.affected_rows).map_err(String::as_str));
             if tx
-                .send(Message::QueryDone(sql, query_result, elapsed_ms))
+                .send(Message::QueryDone(tab_id, sql, query_result, elapsed_ms))
                 .is_err()
             {
                 tracing::warn!("无法发送查询结果：接收端已关闭");
@@ -227,6 +307,117 @@ impl DbManagerApp {
         });
     }
 
+    /// 读取当前分页结果的指定页
+    pub(super) fn execute_page(&mut self, index: usize) {
+        let Some(page) = self.grid_state.page.as_ref().map(|p| p.with_index(index)) else {
+            return;
+        };
+        let Some(conn) = self.manager.get_active() else {
+            self.notifications.warning(t("common.need_connection"));
+            return;
+        };
+        let config = conn.config.clone();
+
+        self.executing = true;
+        if let Some(tab) = self.tab_manager.get_active_mut() {
+            tab.executing = true;
+        }
+        self.spawn_page_query(config, page, None);
+    }
+
+    /// 在后台读取一页结果
+    pub(super) fn spawn_page_query(&self, config: ConnectionConfig, page: ResultPage, tab_id: Option<String>) {
+        let tx = self.tx.clone();
+        let config = self.resolve_timeouts(config);
+        let timeout_secs = config.query_timeout_secs();
+        self.runtime.spawn(async move {
+            use tokio::time::{timeout, Duration};
+            let start = Instant::now();
+            let result = timeout(
+                Duration::from_secs(timeout_secs),
+                execute_query_page(&config, &page.sql, page.offset(), page.size),
+            )
+            .await;
+            let elapsed_ms = start.elapsed().as_millis() as u64;
+            let page_result = match result {
+                Ok(Ok(res)) => Ok(res),
+                Ok(Err(e)) => Err(e.to_string()),
+                Err(_) => Err(format!("查询超时 ({}秒)", timeout_secs)),
+            };
+            if tx
+                .send(Message::QueryPageDone(tab_id, page, page_result, elapsed_ms))
+                .is_err()
+            {
+                tracing::warn!("无法发送查询结果：接收端已关闭");
+            }
+        });
+    }
+
+    /// 通过驱动获取表结构并作为查询结果显示（用于没有统一元数据 SQL 的 ODBC、按抽样推断字段的 MongoDB）
+    pub(super) fn show_table_columns_via_catalog(&mut self, table: String) {
+        let Some(conn) = self.manager.get_active() else {
+            return;
+        };
+
+        let config = conn.config.clone();
+        let tx = self.tx.clone();
+        self.executing = true;
+        self.set_result(None);
+
+        self.runtime.spawn(async move {
+            let start = Instant::now();
+            let result = get_table_columns(&config, &table)
+                .await
+                .map(|columns| QueryResult {
+                    columns: ["column_name", "data_type", "nullable", "default", "primary_key"]
+                        .map(String::from)
+                        .to_vec(),
+                    rows: columns
+                        .into_iter()
+                        .map(|c| {
+                            vec![
+                                c.name,
+                                c.data_type,
+                                if c.is_nullable { "YES" } else { "NO" }.to_string(),
+                                c.default_value.unwrap_or_else(|| "NULL".to_string()),
+                                if c.is_primary_key { "YES" } else { "" }.to_string(),
+                            ]
+                        })
+                        .collect(),
+                    ..Default::default()
+                })
+                .map_err(|e| e.to_string());
+            let elapsed_ms = start.elapsed().as_millis() as u64;
+            let label = format!("-- 表结构: {}", table);
+            if tx.send(Message::QueryDone(None, label, result, elapsed_ms)).is_err() {
+                tracing::warn!("无法发送查询结果：接收端已关闭");
+            }
+        });
+    }
+
+    /// 按类型读取 Redis 键的值并显示在表格中
+    pub(super) fn show_redis_key(&mut self, key: String) {
+        let Some(conn) = self.manager.get_active() else {
+            return;
+        };
+
+        let config = conn.config.clone();
+        let tx = self.tx.clone();
+        self.executing = true;
+        self.set_result(None);
+
+        self.runtime.spawn(async move {
+            let start = Instant::now();
+            let result = get_redis_key_value(&config, &key)
+                .await
+                .map_err(|e| e.to_string());
+            let elapsed_ms = start.elapsed().as_millis() as u64;
+            if tx.send(Message::RedisKeyFetched(key, result, elapsed_ms)).is_err() {
+                tracing::warn!("无法发送键值结果：接收端已关闭");
+            }
+        });
+    }
+
     /// 异步获取表的主键列
     pub(super) fn fetch_primary_key(&self, table_name: &str) {
         let Some(conn) = self.manager.get_active() else {
@@ -249,10 +440,647 @@ impl DbManagerApp {
         });
     }
 
+    /// 异步读取表结构中的列类型（已读取过的表跳过），用于搜索栏和筛选面板的列名补全
+    pub(super) fn fetch_column_types(&self, table_name: &str) {
+        if self.autocomplete.column_types(table_name).is_some() {
+            return;
+        }
+        let Some(conn) = self.manager.get_active() else {
+            return;
+        };
+
+        let config = conn.config.clone();
+        let table = table_name.to_string();
+        let tx = self.tx.clone();
+
+        self.runtime.spawn(async move {
+            let result = get_table_columns(&config, &table).await.map_err(|e| e.to_string());
+            if tx.send(Message::ColumnTypesFetched(table, result)).is_err() {
+                tracing::warn!("无法发送列信息：接收端已关闭");
+            }
+        });
+    }
+
+    /// 按顺序执行触发器/存储过程/序列 DDL（编辑时先 DROP 再 CREATE），完成后刷新侧边栏列表
+    ///
+    /// DDL 支持事务的数据库（SQLite、PostgreSQL）在一个事务中执行，重建失败时原对象保持不变；
+    /// 其他数据库在重建失败后执行 `restore` 恢复原定义
+    pub(super) fn execute_object_ddl(&mut self, statements: Vec<String>, restore: Option<String>) {
+        let Some(conn) = self.manager.get_active() else {
+            self.notifications.warning(t("common.need_connection"));
+            return;
+        };
+
+        let config = conn.config.clone();
+        let tx = self.tx.clone();
+        let audit = self.audit_recorder(AuditSource::Ddl);
+        self.executing = true;
+
+        self.runtime.spawn(async move {
+            let transactional = statements.len() > 1
+                && matches!(config.db_type, DatabaseType::SQLite | DatabaseType::PostgreSQL);
+            let result = if transactional {
+                let script = statements.join("\n");
+                let result = execute_batch(&config, &statements, true).await.map_err(|e| e.to_string());
+                audit.record(&script, result.as_ref().copied().map_err(String::as_str));
+                result.map(|_| ())
+            } else {
+                let mut result = Ok(());
+                for (i, sql) in statements.iter().enumerate() {
+                    match execute_query(&config, sql).await {
+                        Ok(res) => audit.record(sql, Ok(res.affected_rows)),
+                        Err(e) => {
+                            let e = e.to_string();
+                            audit.record(sql, Err(&e));
+                            // 原对象已删除但新定义创建失败：重新创建原对象
+                            result = match restore.as_deref().filter(|_| i > 0) {
+                                Some(restore) => match execute_query(&config, restore).await {
+                                    Ok(res) => {
+                                        audit.record(restore, Ok(res.affected_rows));
+                                        Err(format!("{}（已恢复原定义）", e))
+                                    }
+                                    Err(restore_err) => {
+                                        let restore_err = restore_err.to_string();
+                                        audit.record(restore, Err(&restore_err));
+                                        Err(format!("{}；恢复原定义也失败: {}", e, restore_err))
+                                    }
+                                },
+                                None => Err(e),
+                            };
+                            break;
+                        }
+                    }
+                }
+                result
+            };
+            if tx.send(Message::ObjectDdlDone(result)).is_err() {
+                tracing::warn!("无法发送 DDL 执行结果：接收端已关闭");
+            }
+        });
+    }
+
+    /// 保存表格修改：按首选项先在检查窗口中确认生成的 SQL，否则直接执行
+    pub(super) fn review_grid_sql(&mut self, statements: Vec<String>) {
+        let Some(conn) = self.manager.get_active() else {
+            self.notifications.warning(t("common.need_connection"));
+            return;
+        };
+        let transaction = supports_batch_transaction(conn.config.db_type);
+        if self.app_config.confirm_grid_delete {
+            let table = self.selected_table.clone().unwrap_or_default();
+            self.sql_review_state.open(table, &statements, transaction);
+        } else {
+            self.grid_state.clear_edits();
+            self.execute_grid_statements(statements, transaction);
+        }
+    }
+
+    /// 在同一连接上执行表格保存的语句，完成后刷新表格
+    pub(super) fn execute_grid_statements(&mut self, statements: Vec<String>, transaction: bool) {
+        let Some(conn) = self.manager.get_active() else {
+            self.notifications.warning(t("common.need_connection"));
+            return;
+        };
+
+        let config = self.resolve_timeouts(conn.config.clone());
+        let timeout_secs = config.query_timeout_secs();
+        let tx = self.tx.clone();
+        let audit = self.audit_recorder(AuditSource::GridSave);
+        self.executing = true;
+
+        self.runtime.spawn(async move {
+            use tokio::time::{timeout, Duration};
+            let script = statements.join(";\n");
+            let start = Instant::now();
+            let result = match timeout(
+                Duration::from_secs(timeout_secs),
+                execute_batch(&config, &statements, transaction),
+            )
+            .await
+            {
+                Ok(result) => result.map_err(|e| e.to_string()),
+                Err(_) => Err(format!("保存超时 ({}秒)", timeout_secs)),
+            };
+            audit.record(&script, result.as_ref().copied().map_err(String::as_str));
+            let elapsed_ms = start.elapsed().as_millis() as u64;
+            if tx.send(Message::GridSaveDone(script, transaction, result, elapsed_ms)).is_err() {
+                tracing::warn!("无法发送表格保存结果：接收端已关闭");
+            }
+        });
+    }
+
+    /// 打开表大小概览窗口并加载数据
+    pub(super) fn open_size_overview(&mut self) {
+        let Some(conn) = self.manager.get_active() else {
+            self.notifications.warning(t("common.need_connection"));
+            return;
+        };
+        let db_type = conn.config.db_type;
+        if !matches!(db_type, DatabaseType::SQLite | DatabaseType::PostgreSQL | DatabaseType::MySQL) {
+            self.notifications.warning(tf("notify.size_overview_unsupported", &[db_type.display_name()]));
+            return;
+        }
+        let title = match (&conn.selected_database, db_type) {
+            (Some(database), DatabaseType::PostgreSQL | DatabaseType::MySQL) => database.clone(),
+            (None, DatabaseType::PostgreSQL | DatabaseType::MySQL) => {
+                self.notifications.warning(t("common.need_database"));
+                return;
+            }
+            _ => conn.config.name.clone(),
+        };
+        self.size_overview_state.open(title);
+        self.load_table_sizes();
+    }
+
+    /// 加载当前数据库各表的大小和行数
+    pub(super) fn load_table_sizes(&mut self) {
+        let Some(conn) = self.manager.get_active() else {
+            return;
+        };
+
+        let config = conn.config.clone();
+        let tx = self.tx.clone();
+        self.size_overview_state.loading = true;
+
+        self.runtime.spawn(async move {
+            let result = get_table_sizes(&config).await.map_err(|e| e.to_string());
+            let _ = tx.send(Message::TableSizesFetched(result));
+        });
+    }
+
+    /// 打开建表语句窗口并异步加载
+    pub(super) fn open_table_ddl(&mut self, table: String) {
+        let Some(conn) = self.manager.get_active() else {
+            self.notifications.warning(t("common.need_connection"));
+            return;
+        };
+
+        let config = conn.config.clone();
+        let tx = self.tx.clone();
+        self.table_ddl_state.open(table.clone());
+
+        self.runtime.spawn(async move {
+            let result = get_table_ddl(&config, &table).await.map_err(|e| e.to_string());
+            let _ = tx.send(Message::TableDdlFetched(table, result));
+        });
+    }
+
+    /// 将建表语句保存为 .sql 文件
+    pub(super) fn export_table_ddl(&mut self, table: &str, ddl: &str) {
+        let Some(path) = rfd::FileDialog::new()
+            .set_file_name(format!("{}.sql", table))
+            .add_filter("SQL 文件", &["sql"])
+            .save_file()
+        else {
+            return;
+        };
+
+        match std::fs::write(&path, format!("{}\n", ddl)) {
+            Ok(()) => {
+                self.notifications.success(tf("notify.ddl_exported", &[&path.display().to_string()]));
+            }
+            Err(e) => {
+                self.notifications.error(tf("common.export_failed", &[&e.to_string()]));
+            }
+        }
+    }
+
+    /// 打开表操作（重命名/删除/清空/复制）对话框
+    pub(super) fn open_table_action(&mut self, action: ui::TableAction, table: String) {
+        let Some(conn) = self.manager.get_active() else {
+            self.notifications.warning(t("common.need_connection"));
+            return;
+        };
+        let db_type = conn.config.db_type;
+        if matches!(db_type, DatabaseType::Redis | DatabaseType::MongoDB) {
+            self.notifications.warning(tf("notify.action_unsupported", &[db_type.display_name(), action.display_name()]));
+            return;
+        }
+        let schema = self.split_table_name(&table).0.map(str::to_string);
+        self.table_action_state.open(action, db_type, schema, table);
+    }
+
+    /// 异步执行表操作 SQL，完成后刷新表列表
+    pub(super) fn execute_table_action(&mut self, action: ui::TableAction, table: String, statements: Vec<String>) {
+        let Some(conn) = self.manager.get_active() else {
+            self.notifications.warning(t("common.need_connection"));
+            return;
+        };
+
+        let config = conn.config.clone();
+        let tx = self.tx.clone();
+        let audit = self.audit_recorder(AuditSource::TableAction);
+        self.executing = true;
+
+        self.runtime.spawn(async move {
+            let mut result = Ok(());
+            for sql in &statements {
+                match execute_query(&config, sql).await {
+                    Ok(res) => audit.record(sql, Ok(res.affected_rows)),
+                    Err(e) => {
+                        let e = e.to_string();
+                        audit.record(sql, Err(&e));
+                        result = Err(e);
+                        break;
+                    }
+                }
+            }
+            if tx.send(Message::TableActionDone(action, table, result)).is_err() {
+                tracing::warn!("无法发送表操作结果：接收端已关闭");
+            }
+        });
+    }
+
+    /// 打开测试数据生成对话框；指定表时同时加载列信息
+    pub(super) fn open_data_generator(&mut self, table: Option<String>) {
+        let Some(conn) = self.manager.get_active() else {
+            self.notifications.warning(t("common.need_connection"));
+            return;
+        };
+        let db_type = conn.config.db_type;
+        if matches!(db_type, DatabaseType::Redis | DatabaseType::MongoDB) {
+            self.notifications.warning(tf("notify.datagen_unsupported", &[db_type.display_name()]));
+            return;
+        }
+        let tables = conn.tables.clone();
+        self.datagen_state.open(db_type, tables, table.clone());
+        if let Some(table) = table {
+            self.load_datagen_columns(table);
+        }
+    }
+
+    /// 异步加载测试数据生成的列信息
+    pub(super) fn load_datagen_columns(&mut self, table: String) {
+        let Some(conn) = self.manager.get_active() else {
+            return;
+        };
+
+        let config = conn.config.clone();
+        let tx = self.tx.clone();
+
+        self.runtime.spawn(async move {
+            let result = get_table_columns(&config, &table).await.map_err(|e| e.to_string());
+            let _ = tx.send(Message::DataGenColumnsFetched(table, result));
+        });
+    }
+
+    /// 按计划分批生成并插入测试数据，进度显示在工具栏中且可取消
+    pub(super) fn generate_test_data(&mut self, plan: ui::DataGenPlan) {
+        let Some(conn) = self.manager.get_active() else {
+            self.notifications.warning(t("common.need_connection"));
+            return;
+        };
+        let mut generators = match plan.generators.iter().map(|g| g.compile()).collect::<Result<Vec<_>, _>>() {
+            Ok(generators) => generators,
+            Err(e) => {
+                self.notifications.error(e);
+                return;
+            }
+        };
+
+        let config = conn.config.clone();
+        let (schema, table) = self.split_table_name(&plan.table);
+        let (schema, table) = (schema.map(str::to_string), table.to_string());
+        let tx = self.tx.clone();
+        let audit = self.audit_recorder(AuditSource::DataGen);
+        let progress_id = self.progress.start(format!("生成测试数据: {}", plan.table), true);
+        let Some(cancel) = self.progress.get(progress_id).map(|task| task.cancel_token()) else {
+            return;
+        };
+        let seed = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |d| d.as_nanos() as u64);
+
+        self.runtime.spawn(async move {
+            use std::sync::atomic::Ordering;
+
+            let mut rng = Rng::new(seed);
+            let total = plan.row_count;
+            let mut inserted = 0;
+            let mut error = None;
+            while inserted < total && !cancel.load(Ordering::Relaxed) {
+                let count = ui::DATAGEN_BATCH_SIZE.min(total - inserted);
+                let rows = generate_rows(&mut generators, &mut rng, count);
+                let sql = ui::insert_rows_sql(config.db_type, schema.as_deref(), &table, &plan.columns, &rows);
+                match execute_query(&config, &sql).await {
+                    Ok(res) => audit.record(&sql, Ok(res.affected_rows)),
+                    Err(e) => {
+                        let e = e.to_string();
+                        audit.record(&sql, Err(&e));
+                        error = Some(e);
+                        break;
+                    }
+                }
+                inserted += count;
+                let _ = tx.send(Message::DataGenProgress(progress_id, inserted, total));
+            }
+            if tx.send(Message::DataGenDone(progress_id, plan.table, inserted, error)).is_err() {
+                tracing::warn!("无法发送数据生成结果：接收端已关闭");
+            }
+        });
+    }
+
+    /// 打开会话监控窗口并加载数据
+    pub(super) fn open_session_monitor(&mut self) {
+        let Some(conn) = self.manager.get_active() else {
+            self.notifications.warning(t("common.need_connection"));
+            return;
+        };
+        let db_type = conn.config.db_type;
+        if !matches!(db_type, DatabaseType::PostgreSQL | DatabaseType::MySQL) {
+            self.notifications.warning(tf("notify.sessions_unsupported", &[db_type.display_name()]));
+            return;
+        }
+        let title = conn.config.name.clone();
+        self.session_monitor_state.open(db_type, title);
+        self.load_sessions();
+    }
+
+    /// 加载服务器会话列表
+    pub(super) fn load_sessions(&mut self) {
+        let Some(conn) = self.manager.get_active() else {
+            return;
+        };
+
+        let config = conn.config.clone();
+        let tx = self.tx.clone();
+        self.session_monitor_state.loading = true;
+
+        self.runtime.spawn(async move {
+            let result = get_sessions(&config).await.map_err(|e| e.to_string());
+            let _ = tx.send(Message::SessionsFetched(result));
+        });
+    }
+
+    /// 异步执行终止会话/取消查询的 SQL
+    pub(super) fn kill_session(&mut self, sql: String) {
+        let Some(conn) = self.manager.get_active() else {
+            return;
+        };
+
+        let config = conn.config.clone();
+        let tx = self.tx.clone();
+        let audit = self.audit_recorder(AuditSource::Session);
+
+        self.runtime.spawn(async move {
+            let result = execute_query(&config, &sql).await.map(|r| r.affected_rows).map_err(|e| e.to_string());
+            audit.record_admin(&sql, result.as_ref().copied().map_err(String::as_str));
+            let result = result.map(|_| ());
+            if tx.send(Message::SessionKilled(result)).is_err() {
+                tracing::warn!("无法发送终止会话结果：接收端已关闭");
+            }
+        });
+    }
+
+    /// 加载权限管理的用户/角色列表
+    pub(super) fn load_privilege_users(&self) {
+        let Some(conn) = self.manager.get_active() else {
+            return;
+        };
+
+        let config = conn.config.clone();
+        let tx = self.tx.clone();
+
+        self.runtime.spawn(async move {
+            let result = get_users(&config).await.map_err(|e| e.to_string());
+            let _ = tx.send(Message::PrivilegeUsersFetched(result));
+        });
+    }
+
+    /// 加载用户在当前数据库中的权限
+    pub(super) fn load_grants(&self, user: String) {
+        let Some(conn) = self.manager.get_active() else {
+            return;
+        };
+
+        let config = conn.config.clone();
+        let tx = self.tx.clone();
+
+        self.runtime.spawn(async move {
+            let result = get_grants(&config, &user).await.map_err(|e| e.to_string());
+            let _ = tx.send(Message::GrantsFetched(user, result));
+        });
+    }
+
+    /// 按顺序执行 GRANT/REVOKE，完成后重新加载权限
+    pub(super) fn apply_privileges(&mut self, statements: Vec<String>) {
+        let Some(conn) = self.manager.get_active() else {
+            self.notifications.warning(t("common.need_connection"));
+            return;
+        };
+
+        let config = conn.config.clone();
+        let tx = self.tx.clone();
+        let audit = self.audit_recorder(AuditSource::Privileges);
+        self.executing = true;
+
+        self.runtime.spawn(async move {
+            let mut result = Ok(());
+            for sql in &statements {
+                match execute_query(&config, sql).await {
+                    Ok(res) => audit.record(sql, Ok(res.affected_rows)),
+                    Err(e) => {
+                        let e = e.to_string();
+                        audit.record(sql, Err(&e));
+                        result = Err(e);
+                        break;
+                    }
+                }
+            }
+            if tx.send(Message::PrivilegesApplied(result)).is_err() {
+                tracing::warn!("无法发送权限修改结果：接收端已关闭");
+            }
+        });
+    }
+
+    /// 异步执行表/数据库维护操作，执行期间保留进度通知
+    pub(super) fn run_maintenance(&mut self, op: MaintenanceOp, target: MaintenanceTarget) {
+        let Some(conn) = self.manager.get_active() else {
+            self.notifications.warning(t("common.need_connection"));
+            return;
+        };
+
+        let db_type = conn.config.db_type;
+        let (label, sql) = match &target {
+            MaintenanceTarget::Table(table) => {
+                let (schema, name) = self.split_table_name(table);
+                (format!("{} {}", op.display_name(), table), op.table_sql(db_type, schema, name))
+            }
+            MaintenanceTarget::Database(database) => (
+                format!("{} {}", op.display_name(), database),
+                op.database_sql(db_type, database, &conn.tables),
+            ),
+        };
+        let sql = match sql {
+            Ok(sql) => sql,
+            Err(e) => {
+                self.notifications.warning(e);
+                return;
+            }
+        };
+
+        let config = conn.config.clone();
+        let tx = self.tx.clone();
+        let audit = self.audit_recorder(AuditSource::Maintenance);
+        // 大表的维护可能耗时较长，进度通知在完成时手动关闭
+        let progress_id = self.notifications.push_with_duration(
+            NotificationLevel::Info,
+            tf("notify.maintenance_running", &[&label]),
+            std::time::Duration::from_secs(3600),
+        );
+
+        self.runtime.spawn(async move {
+            let start = Instant::now();
+            let result = execute_query(&config, &sql).await.map_err(|e| e.to_string());
+            audit.record_admin(&sql, result.as_ref().map(|r| r.affected_rows).map_err(String::as_str));
+            let result = result.map(|r| maintenance_errors(&r));
+            let elapsed = start.elapsed().as_millis() as u64;
+            if tx.send(Message::MaintenanceDone(label, progress_id, result, elapsed)).is_err() {
+                tracing::warn!("无法发送维护操作结果：接收端已关闭");
+            }
+        });
+    }
+
+    /// 修改 SQLite 加密密钥（SQLCipher PRAGMA rekey）
+    pub(super) fn change_sqlite_key(&mut self, name: String, new_key: String) {
+        let Some(conn) = self.manager.connections.get(&name) else {
+            return;
+        };
+
+        let config = conn.config.clone();
+        let tx = self.tx.clone();
+
+        self.runtime.spawn(async move {
+            let result = change_sqlite_key(&config, &new_key)
+                .await
+                .map_err(|e| e.to_string());
+            if tx
+                .send(Message::SqliteKeyChanged(name, new_key, result))
+                .is_err()
+            {
+                tracing::warn!("无法发送密钥修改结果：接收端已关闭");
+            }
+        });
+    }
+
+    /// 新建草稿本连接（内存 SQLite）并立即连接
+    pub(super) fn new_scratchpad(&mut self) {
+        let name = (1..)
+            .map(|n| if n == 1 { "草稿本".to_string() } else { format!("草稿本 {}", n) })
+            .find(|name| !self.manager.connections.contains_key(name))
+            .unwrap_or_else(|| "草稿本".to_string());
+
+        self.manager.add(ConnectionConfig::scratchpad(name.clone()));
+        self.connect(name);
+    }
+
+    /// 将草稿本保存为 SQLite 文件
+    pub(super) fn save_scratchpad(&mut self, name: String) {
+        let Some(conn) = self.manager.connections.get(&name) else {
+            return;
+        };
+        let config = conn.config.clone();
+
+        let Some(path) = rfd::FileDialog::new()
+            .set_file_name("scratchpad.db")
+            .add_filter("SQLite 数据库", &["db", "sqlite", "sqlite3"])
+            .save_file()
+        else {
+            return;
+        };
+
+        // VACUUM INTO 要求目标文件不存在，覆盖前先删除（文件对话框已确认覆盖）
+        if path.exists()
+            && let Err(e) = std::fs::remove_file(&path)
+        {
+            self.notifications.error(tf("notify.overwrite_failed", &[&e.to_string()]));
+            return;
+        }
+
+        let path = path.display().to_string();
+        let tx = self.tx.clone();
+        self.runtime.spawn(async move {
+            let result = save_sqlite_to_file(&config, &path)
+                .await
+                .map_err(|e| e.to_string());
+            if tx.send(Message::ScratchpadSaved(path, result)).is_err() {
+                tracing::warn!("无法发送保存结果：接收端已关闭");
+            }
+        });
+    }
+
+    /// 为 SQLite 连接附加数据库文件（ATTACH DATABASE）
+    pub(super) fn attach_sqlite_database(&mut self, name: String) {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("SQLite 数据库", &["db", "sqlite", "sqlite3"])
+            .add_filter("所有文件", &["*"])
+            .pick_file()
+        else {
+            return;
+        };
+        let Some(conn) = self.manager.connections.get_mut(&name) else {
+            return;
+        };
+
+        let path = path.display().to_string();
+        let alias = SqliteAttachment::suggest_alias(&path, &conn.config.sqlite_attachments);
+        conn.config
+            .sqlite_attachments
+            .push(SqliteAttachment::new(alias.clone(), path));
+        let was_connected = conn.connected;
+
+        self.save_config();
+        self.notifications.success(tf("notify.database_attached", &[&alias]));
+
+        // 重新连接以刷新 schema 列表
+        if was_connected {
+            self.connect(name);
+        }
+    }
+
+    /// 分离 SQLite 附加数据库
+    pub(super) fn detach_sqlite_database(&mut self, name: String, alias: String) {
+        let Some(conn) = self.manager.connections.get_mut(&name) else {
+            return;
+        };
+
+        conn.config.sqlite_attachments.retain(|a| a.alias != alias);
+        let was_connected = conn.connected;
+
+        self.save_config();
+        self.notifications.info(tf("notify.database_detached", &[&alias]));
+
+        if was_connected {
+            self.selected_table = None;
+            self.set_result(None);
+            self.connect(name);
+        }
+    }
+
+    /// 加载 SQLite 所有 schema 的表用于自动补全（含附加数据库）
+    pub(super) fn load_sqlite_autocomplete_tables(&self) {
+        let Some(conn) = self.manager.get_active() else {
+            return;
+        };
+
+        let config = conn.config.clone();
+        let tx = self.tx.clone();
+
+        self.runtime.spawn(async move {
+            let result = get_all_sqlite_tables(&config)
+                .await
+                .map_err(|e| e.to_string());
+            if tx.send(Message::AutocompleteTablesFetched(result)).is_err() {
+                tracing::warn!("无法发送表列表：接收端已关闭");
+            }
+        });
+    }
+
     /// 处理连接错误的通用逻辑
     pub(super) fn handle_connection_error(&mut self, name: &str, error: String) {
-        self.notifications.error(format!("连接失败: {}", error));
+        self.notifications.error(tf("notify.connect_failed", &[&error]));
         self.autocomplete.clear();
+        self.pending_recent = None;
         if let Some(conn) = self.manager.connections.get_mut(name) {
             conn.set_error(error);
         }
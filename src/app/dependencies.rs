@@ -0,0 +1,45 @@
+//! 对象依赖关系图
+//!
+//! 读取当前数据库的外键、视图定义和触发器，在依赖关系窗口中显示选中对象的上下游。
+
+use crate::core::t;
+use crate::database::{get_foreign_keys, get_triggers, get_views};
+use crate::ui;
+
+use super::{DbManagerApp, Message};
+
+impl DbManagerApp {
+    /// 打开对象的依赖关系窗口并异步读取元数据
+    pub(super) fn open_dependencies(&mut self, object: String) {
+        if self.manager.get_active().is_none() {
+            self.notifications.warning(t("common.need_connection"));
+            return;
+        }
+        self.dependency_state.open(object);
+        self.load_dependencies();
+    }
+
+    /// 重新读取外键、视图和触发器
+    pub(super) fn load_dependencies(&mut self) {
+        let Some(conn) = self.manager.get_active() else {
+            return;
+        };
+
+        let config = conn.config.clone();
+        let tables = conn.tables.clone();
+        let tx = self.tx.clone();
+        self.dependency_state.loading = true;
+        self.dependency_state.error = None;
+
+        self.runtime.spawn(async move {
+            let (foreign_keys, views, triggers) =
+                tokio::join!(get_foreign_keys(&config), get_views(&config), get_triggers(&config));
+            let result = foreign_keys
+                .and_then(|foreign_keys| {
+                    Ok(ui::DependencyData { tables, foreign_keys, views: views?, triggers: triggers? })
+                })
+                .map_err(|e| e.to_string());
+            let _ = tx.send(Message::DependenciesFetched(result));
+        });
+    }
+}
@@ -0,0 +1,47 @@
+//! 长时间查询的桌面通知
+//!
+//! 查询耗时超过首选项中的阈值、且窗口不在前台时，完成或失败后发送系统桌面通知，
+//! 并请求任务栏提醒，方便在等待分析查询时切换到其他程序。
+
+use eframe::egui;
+
+use crate::core::{query_notification_text, send_desktop_notification};
+use crate::database::QueryResult;
+
+use super::DbManagerApp;
+
+impl DbManagerApp {
+    /// 查询完成后按需发送桌面通知
+    pub(super) fn notify_query_finished(
+        &self,
+        ctx: &egui::Context,
+        tab_id: Option<&str>,
+        sql: &str,
+        result: &Result<QueryResult, String>,
+        elapsed_ms: u64,
+    ) {
+        let threshold_secs = self.app_config.desktop_notify_secs;
+        if threshold_secs == 0 || elapsed_ms < threshold_secs.saturating_mul(1000) {
+            return;
+        }
+        if ctx.input(|i| i.viewport().focused).unwrap_or(true) {
+            return;
+        }
+
+        let tab = tab_id
+            .and_then(|id| self.tab_manager.position(id))
+            .map(|idx| self.tab_manager.tabs[idx].title.as_str());
+        let outcome = match result {
+            Ok(res) if res.columns.is_empty() => Ok(res.affected_rows as usize),
+            Ok(res) => Ok(res.original_row_count.unwrap_or(res.rows.len())),
+            Err(e) => Err(e.as_str()),
+        };
+        let (title, body) = query_notification_text(tab, sql, outcome, elapsed_ms);
+        if let Err(e) = send_desktop_notification(&title, &body) {
+            tracing::warn!(error = %e, "发送桌面通知失败");
+        }
+        ctx.send_viewport_cmd(egui::ViewportCommand::RequestUserAttention(
+            egui::UserAttentionType::Informational,
+        ));
+    }
+}
@@ -0,0 +1,52 @@
+//! 独立窗口中的查询标签页
+//!
+//! 标签页可以弹出到单独的系统窗口（egui 多视口），方便在多显示器上把结果窗口放在其他程序旁边。
+//! 窗口只读显示该标签页的 SQL 和结果，查询仍在主窗口中执行；关闭窗口即合并回主窗口。
+
+use eframe::egui;
+
+use crate::core::tf;
+use crate::ui::{DetachedTabAction, DetachedTabWindow};
+
+use super::DbManagerApp;
+
+impl DbManagerApp {
+    /// 在独立窗口中打开标签页，或合并回主窗口
+    pub(super) fn toggle_tab_detach(&mut self, idx: usize) {
+        let Some(detached) = self.tab_manager.toggle_detach(idx) else {
+            return;
+        };
+        let title = &self.tab_manager.tabs[idx].title;
+        if detached {
+            self.notifications.info(tf("notify.tab_detached", &[title]));
+        } else {
+            self.notifications.info(tf("notify.tab_reattached", &[title]));
+        }
+    }
+
+    /// 显示所有分离出去的标签页窗口（每帧调用，关闭的标签页不再显示，其窗口随之关闭）
+    pub(super) fn show_detached_tabs(&mut self, ctx: &egui::Context) {
+        let mut actions = Vec::new();
+        for (idx, tab) in self.tab_manager.tabs.iter().enumerate().filter(|(_, t)| t.detached) {
+            let action = DetachedTabWindow::show(ctx, tab, &self.highlight_colors);
+            if action != DetachedTabAction::None {
+                actions.push((idx, action));
+            }
+        }
+
+        for (idx, action) in actions {
+            match action {
+                DetachedTabAction::Reattach => {
+                    if let Some(tab) = self.tab_manager.tabs.get_mut(idx) {
+                        tab.detached = false;
+                    }
+                }
+                DetachedTabAction::Focus => {
+                    self.activate_tab(idx);
+                    ctx.send_viewport_cmd_to(egui::ViewportId::ROOT, egui::ViewportCommand::Focus);
+                }
+                DetachedTabAction::None => {}
+            }
+        }
+    }
+}
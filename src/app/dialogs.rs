@@ -2,7 +2,8 @@
 //!
 //! 将对话框的渲染和事件处理从主 update 循环中分离出来。
 
-use crate::core::KeyBindings;
+use crate::core::{t, tf, KeyBindings};
+use crate::database::POOL_MANAGER;
 use crate::ui::{self, ExportConfig, KeyBindingsDialog};
 use super::DbManagerApp;
 
@@ -13,20 +14,58 @@ pub struct DialogResults {
     pub save_connection: bool,
     /// 导出配置（如果触发导出）
     pub export_action: Option<ExportConfig>,
+    /// 已确认导出敏感列原始值的导出配置
+    pub unmasked_export: Option<ExportConfig>,
     /// 导入操作
     pub import_action: ui::ImportAction,
     /// DDL 创建 SQL
     pub ddl_sql: Option<String>,
+    /// 在 ER 图中设计的表（原表名，表定义）
+    pub er_design_table: Option<(Option<String>, ui::TableDefinition)>,
     /// 创建数据库 SQL
     pub create_db_sql: Option<String>,
     /// 创建用户 SQL
     pub create_user_sql: Option<Vec<String>>,
-    /// 历史记录选中的 SQL
-    pub history_selected_sql: Option<String>,
-    /// 是否清空历史
-    pub clear_history: bool,
+    /// 新建/修改/删除触发器、存储过程及重置序列的 SQL（按顺序执行）
+    pub object_ddl_sql: Option<(Vec<String>, Option<String>)>,
+    /// 重新加载表大小概览
+    pub refresh_size_overview: bool,
+    /// 重新读取依赖关系
+    pub refresh_dependencies: bool,
+    /// 全库数据搜索窗口的操作
+    pub data_search: Option<ui::DataSearchResult>,
+    /// 导出建表语句（表名，语句）
+    pub export_table_ddl: Option<(String, String)>,
+    /// 在新标签页中打开建表语句
+    pub open_table_ddl_in_tab: Option<String>,
+    /// 重命名/删除/清空/复制表（操作，表名，SQL）
+    pub table_action: Option<(ui::TableAction, String, Vec<String>)>,
+    /// 测试数据生成需要加载列信息的表
+    pub datagen_load_columns: Option<String>,
+    /// 测试数据生成计划
+    pub datagen_plan: Option<ui::DataGenPlan>,
+    /// 定时查询操作
+    pub scheduler_action: Option<ui::SchedulerResult>,
+    /// 导出结果对比报告
+    pub export_diff_report: Option<crate::database::QueryResult>,
+    /// 重新读取审计日志
+    pub refresh_audit_log: bool,
+    /// 导出审计日志
+    pub export_audit_log: Option<crate::database::QueryResult>,
+    /// 重新加载会话监控
+    pub refresh_sessions: bool,
+    /// 终止会话/取消查询的 SQL
+    pub kill_session_sql: Option<String>,
+    /// 需要加载权限的用户
+    pub load_grants_user: Option<String>,
+    /// 权限修改的 GRANT/REVOKE 语句
+    pub privilege_sql: Option<Vec<String>>,
+    /// 历史面板操作
+    pub history_action: Option<ui::HistoryPanelAction>,
     /// 更新后的快捷键绑定
     pub updated_keybindings: Option<KeyBindings>,
+    /// 命令面板中选择执行的命令
+    pub palette_command: Option<ui::PaletteCommand>,
 }
 
 impl DbManagerApp {
@@ -47,14 +86,14 @@ impl DbManagerApp {
         let delete_msg = self
             .pending_delete_name
             .as_ref()
-            .map(|n| format!("确定要删除连接 '{}' 吗？", n))
+            .map(|n| tf("confirm.delete_connection", &[n]))
             .unwrap_or_default();
         ui::ConfirmDialog::show(
             ctx,
             &mut self.show_delete_confirm,
-            "删除连接",
+            t("confirm.delete_connection_title"),
             &delete_msg,
-            "删除",
+            t("confirm.delete"),
             &mut confirm_delete,
         );
 
@@ -64,6 +103,42 @@ impl DbManagerApp {
             self.delete_connection(&name);
         }
 
+        // 崩溃恢复提示
+        if self.pending_recovery.is_some() {
+            let mut confirm_recovery = false;
+            let recovery_msg = self.recovery_message();
+            ui::ConfirmDialog::show(
+                ctx,
+                &mut self.show_recovery_prompt,
+                t("recovery.title"),
+                &recovery_msg,
+                t("recovery.restore"),
+                &mut confirm_recovery,
+            );
+            if !self.show_recovery_prompt {
+                self.finish_recovery_prompt(confirm_recovery);
+            }
+        }
+
+        // 导出敏感列原始值确认
+        if self.pending_unmask_export.is_some() {
+            let mut confirm_unmask = false;
+            let unmask_msg = self.unmask_confirm_message();
+            ui::ConfirmDialog::show(
+                ctx,
+                &mut self.show_unmask_confirm,
+                t("masking.unmask_title"),
+                &unmask_msg,
+                t("masking.unmask_confirm"),
+                &mut confirm_unmask,
+            );
+            if confirm_unmask {
+                results.unmasked_export = self.pending_unmask_export.take();
+            } else if !self.show_unmask_confirm {
+                self.pending_unmask_export = None;
+            }
+        }
+
         // 导出对话框
         let table_name = self
             .selected_table
@@ -74,7 +149,8 @@ impl DbManagerApp {
             &mut self.show_export_dialog,
             &mut self.export_config,
             &table_name,
-            self.result.as_ref(),
+            self.result.as_deref(),
+            &self.plugins,
             &mut results.export_action,
             &self.export_status,
         );
@@ -89,10 +165,13 @@ impl DbManagerApp {
         );
 
         // DDL 对话框（创建表）
-        results.ddl_sql = ui::DdlDialog::show_create_table(
-            ctx,
-            &mut self.ddl_dialog_state,
-        );
+        match ui::DdlDialog::show_create_table(ctx, &mut self.ddl_dialog_state) {
+            Some(ui::DdlDialogResult::Create(sql)) => results.ddl_sql = Some(sql),
+            Some(ui::DdlDialogResult::Design { original_name, table }) => {
+                results.er_design_table = Some((original_name, table));
+            }
+            None => {}
+        }
 
         // 新建数据库对话框
         let create_db_result = ui::CreateDbDialog::show(
@@ -118,18 +197,149 @@ impl DbManagerApp {
             ui::CreateUserDialogResult::Cancelled | ui::CreateUserDialogResult::None => {}
         }
 
+        // 触发器/存储过程/函数编辑对话框
+        if let ui::ObjectDdlDialogResult::Execute(statements, restore) = ui::ObjectDdlDialog::show(
+            ctx,
+            &mut self.object_ddl_dialog_state,
+            &self.highlight_colors,
+        ) {
+            results.object_ddl_sql = Some((statements, restore));
+        }
+
+        // 删除触发器/存储过程确认对话框
+        let mut confirm_drop = false;
+        let drop_msg = self
+            .pending_drop_object
+            .as_ref()
+            .map(|(label, _)| format!("确定要删除{}吗？此操作不可撤销。", label))
+            .unwrap_or_default();
+        ui::ConfirmDialog::show(
+            ctx,
+            &mut self.show_drop_object_confirm,
+            "删除对象",
+            &drop_msg,
+            "删除",
+            &mut confirm_drop,
+        );
+        if confirm_drop
+            && let Some((_, sql)) = self.pending_drop_object.take()
+        {
+            results.object_ddl_sql = Some((vec![sql], None));
+        }
+
+        // 设置序列下一个值对话框
+        if let ui::SequenceDialogResult::Restart(sql) =
+            ui::SequenceDialog::show(ctx, &mut self.sequence_dialog_state)
+        {
+            results.object_ddl_sql = Some((vec![sql], None));
+        }
+
+        // 权限管理对话框
+        match ui::PrivilegeDialog::show(ctx, &mut self.privilege_dialog_state) {
+            ui::PrivilegeDialogResult::LoadGrants(user) => results.load_grants_user = Some(user),
+            ui::PrivilegeDialogResult::Apply(statements) => results.privilege_sql = Some(statements),
+            ui::PrivilegeDialogResult::Cancelled | ui::PrivilegeDialogResult::None => {}
+        }
+
+        // 表大小概览窗口
+        if let ui::SizeOverviewResult::Refresh = ui::SizeOverviewDialog::show(ctx, &mut self.size_overview_state) {
+            results.refresh_size_overview = true;
+        }
+
+        // 依赖关系窗口
+        if let ui::DependencyResult::Refresh = ui::DependencyDialog::show(ctx, &mut self.dependency_state) {
+            results.refresh_dependencies = true;
+        }
+
+        // 全库数据搜索窗口
+        match ui::DataSearchDialog::show(ctx, &mut self.data_search_state) {
+            ui::DataSearchResult::None => {}
+            action => results.data_search = Some(action),
+        }
+
+        // 建表语句窗口
+        match ui::TableDdlDialog::show(ctx, &mut self.table_ddl_state, &self.highlight_colors) {
+            ui::TableDdlResult::Export(table, ddl) => results.export_table_ddl = Some((table, ddl)),
+            ui::TableDdlResult::OpenInTab(ddl) => results.open_table_ddl_in_tab = Some(ddl),
+            ui::TableDdlResult::None => {}
+        }
+
+        // 表操作对话框
+        if let ui::TableActionResult::Execute(action, table, statements) =
+            ui::TableActionDialog::show(ctx, &mut self.table_action_state)
+        {
+            results.table_action = Some((action, table, statements));
+        }
+
+        // 测试数据生成对话框
+        match ui::DataGenDialog::show(ctx, &mut self.datagen_state) {
+            ui::DataGenResult::LoadColumns(table) => results.datagen_load_columns = Some(table),
+            ui::DataGenResult::Generate(plan) => results.datagen_plan = Some(plan),
+            ui::DataGenResult::Cancelled | ui::DataGenResult::None => {}
+        }
+
+        // 定时查询对话框
+        match ui::SchedulerDialog::show(ctx, &mut self.scheduler_state, &self.scheduler) {
+            ui::SchedulerResult::None => {}
+            action => results.scheduler_action = Some(action),
+        }
+
+        // 结果对比窗口
+        if let ui::ResultDiffResult::Export(report) = ui::ResultDiffDialog::show(ctx, &mut self.result_diff_state) {
+            results.export_diff_report = Some(report);
+        }
+
+        // 审计日志窗口
+        match ui::AuditLogDialog::show(ctx, &mut self.audit_log_state) {
+            ui::AuditLogResult::Refresh => results.refresh_audit_log = true,
+            ui::AuditLogResult::Export(report) => results.export_audit_log = Some(report),
+            ui::AuditLogResult::None => {}
+        }
+
+        // 主题编辑器（预览需要立即应用到界面）
+        let theme_result = ui::ThemeEditorDialog::show(ctx, &mut self.theme_editor_state);
+        self.handle_theme_editor_result(ctx, theme_result);
+
+        // 首选项（预览需要立即应用到界面）
+        let prefs_result = ui::PreferencesDialog::show(ctx, &mut self.preferences_state, &self.keybindings);
+        self.handle_preferences_result(ctx, prefs_result);
+
+        // 字体设置（需要立即重建字体）
+        if let ui::FontSettingsResult::Apply(settings) = ui::FontSettingsDialog::show(ctx, &mut self.font_settings_state) {
+            self.apply_font_settings(ctx, settings);
+        }
+
+        // 会话监控窗口
+        match ui::SessionMonitorDialog::show(ctx, &mut self.session_monitor_state) {
+            ui::SessionMonitorResult::Refresh => results.refresh_sessions = true,
+            ui::SessionMonitorResult::Kill(sql) => results.kill_session_sql = Some(sql),
+            ui::SessionMonitorResult::None => {}
+        }
+
+        // 修改加密密钥对话框
+        if let ui::ChangeKeyDialogResult::Change(conn_name, new_key) =
+            ui::ChangeKeyDialog::show(ctx, &mut self.change_key_dialog_state)
+        {
+            self.change_sqlite_key(conn_name, new_key);
+        }
+
         // 历史记录面板
-        ui::HistoryPanel::show(
+        results.history_action = ui::HistoryPanel::show(
             ctx,
             &mut self.show_history_panel,
             &self.query_history,
-            &mut results.history_selected_sql,
-            &mut results.clear_history,
             &mut self.history_panel_state,
         );
 
+        // SQL 对比窗口
+        if let ui::SqlDiffResult::UseOld(sql) = ui::SqlDiffDialog::show(ctx, &mut self.sql_diff_state) {
+            results.history_action = Some(ui::HistoryPanelAction::Use(sql));
+        }
+
         // 帮助面板
-        ui::HelpDialog::show_with_scroll(ctx, &mut self.show_help, &mut self.help_scroll_offset);
+        if ui::HelpDialog::show_with_scroll(ctx, &mut self.show_help, &mut self.help_scroll_offset) {
+            self.start_tour();
+        }
         
         // 关于对话框
         ui::AboutDialog::show(ctx, &mut self.show_about);
@@ -137,6 +347,115 @@ impl DbManagerApp {
         // 快捷键设置对话框
         results.updated_keybindings = KeyBindingsDialog::show(ctx, &mut self.keybindings_dialog_state);
 
+        // 命令面板
+        results.palette_command = ui::CommandPalette::show(ctx, &mut self.command_palette_state);
+
+        // 工作流面板
+        let workflow_result = ui::WorkflowDialog::show(ctx, &mut self.workflow_state, &self.plugins);
+        self.handle_workflow_result(workflow_result);
+
+        // API 服务面板
+        let api_result = ui::ApiServerDialog::show(ctx, &mut self.api_server_state);
+        self.handle_api_server_result(api_result);
+
+        // 日志查看器
+        ui::LogViewerDialog::show(ctx, &mut self.log_viewer_state);
+
+        // 连接池诊断
+        if self.pool_stats_state.needs_refresh() {
+            self.pool_stats_state.set_stats(POOL_MANAGER.stats());
+        }
+        if let ui::PoolStatsResult::CloseIdle = ui::PoolStatsDialog::show(ctx, &mut self.pool_stats_state) {
+            self.close_idle_pools();
+        }
+
+        // 表格保存 SQL 检查
+        match ui::SqlReviewDialog::show(ctx, &mut self.sql_review_state) {
+            ui::SqlReviewResult::Execute { statements, transaction } => {
+                self.grid_state.clear_edits();
+                self.execute_grid_statements(statements, transaction);
+            }
+            ui::SqlReviewResult::Copied => {
+                self.notifications.info(t("notify.review_copied"));
+            }
+            ui::SqlReviewResult::Cancelled | ui::SqlReviewResult::None => {}
+        }
+
+        // 批量修改（生成的语句先进入影响行预览）
+        if let ui::BulkEditResult::Preview(sql) = ui::BulkEditDialog::show(ctx, &mut self.bulk_edit_state) {
+            self.open_write_preview(sql);
+        }
+
+        // 影响行预览
+        if let ui::WritePreviewResult::Execute(sql) = ui::WritePreviewDialog::show(ctx, &mut self.write_preview_state) {
+            self.execute_previewed_write(sql);
+        }
+
+        // 通知中心（打开期间新出现的错误视为已查看）
+        if self.notification_center_state.show {
+            self.notifications.mark_seen();
+        }
+        let center_result =
+            ui::NotificationCenterDialog::show(ctx, &mut self.notification_center_state, &self.notifications);
+        if let ui::NotificationCenterResult::Clear = center_result {
+            self.notifications.clear_history();
+        }
+
+        // 回收站
+        let trash_result = ui::TrashDialog::show(
+            ctx,
+            &mut self.trash_dialog_state,
+            &self.app_config.trash,
+            self.app_config.trash_retention_days,
+        );
+        match trash_result {
+            ui::TrashDialogResult::None => {}
+            ui::TrashDialogResult::Restore(index) => self.restore_trash_entry(index),
+            ui::TrashDialogResult::Delete(index) => self.delete_trash_entry(index),
+            ui::TrashDialogResult::Empty => self.empty_trash(),
+        }
+
+        // 主密码
+        let master_password = ui::MasterPasswordDialog::show(ctx, &mut self.master_password_state);
+        self.handle_master_password_result(master_password);
+
+        // 基准测试
+        match ui::BenchmarkDialog::show(ctx, &mut self.benchmark_state) {
+            ui::BenchmarkResult::None => {}
+            ui::BenchmarkResult::Start => self.start_benchmark(),
+            ui::BenchmarkResult::Stop => self.stop_benchmark(),
+        }
+
+        // 行书签
+        let scope = self.row_bookmark_scope();
+        let bookmark_result = ui::RowBookmarkDialog::show(
+            ctx,
+            &mut self.row_bookmark_state,
+            &self.app_config.row_bookmarks,
+            scope.as_ref().map(|(connection, database)| (connection.as_str(), database.as_deref())),
+            self.selected_table.as_deref(),
+        );
+        match bookmark_result {
+            ui::RowBookmarkResult::None => {}
+            ui::RowBookmarkResult::Jump(table, bookmark) => self.jump_to_row_bookmark(table, bookmark),
+            ui::RowBookmarkResult::Remove(table, bookmark) => self.remove_row_bookmark(&table, &bookmark),
+        }
+
+        // 透视表
+        if let ui::PivotResult::Export = ui::PivotDialog::show(ctx, &mut self.pivot_state) {
+            self.export_pivot();
+        }
+
+        // 查询分析（不是模态窗口，打开时可以继续编辑和执行查询）
+        if let ui::ProfileResult::Refresh = ui::ProfileDialog::show(ctx, &mut self.profile_state) {
+            let sql = self.profile_state.sql.clone();
+            self.start_profile(sql, self.profile_state.query_elapsed_ms);
+        }
+
+        // 文件外部修改提示
+        let file_change = ui::FileChangeDialog::show(ctx, &mut self.file_change_state);
+        self.handle_file_change_result(file_change);
+
         results
     }
 
@@ -146,6 +465,9 @@ impl DbManagerApp {
         if let Some(config) = results.export_action {
             self.handle_export_with_config(config);
         }
+        if let Some(config) = results.unmasked_export {
+            self.export_with_config(config);
+        }
 
         // 处理导入
         match results.import_action {
@@ -167,7 +489,7 @@ impl DbManagerApp {
                 self.focus_sql_editor = true;
                 self.show_import_dialog = false;
                 self.import_state.clear();
-                self.notifications.success("SQL 已复制到编辑器");
+                self.notifications.success(t("notify.sql_copied_to_editor"));
             }
             ui::ImportAction::Close => {
                 self.import_state.clear();
@@ -182,16 +504,21 @@ impl DbManagerApp {
             self.focus_sql_editor = true;
         }
 
+        // 处理 ER 图中设计的表
+        if let Some((original_name, table)) = results.er_design_table {
+            self.er_diagram_state.apply_design(original_name.as_deref(), table);
+        }
+
         // 处理创建数据库
         if let Some(sql) = results.create_db_sql {
             if sql.starts_with("SQLITE_CREATE:") {
                 let path = sql.trim_start_matches("SQLITE_CREATE:");
-                self.notifications.info(format!("SQLite 数据库将创建于: {}", path));
+                self.notifications.info(tf("notify.sqlite_create_at", &[path]));
             } else {
                 self.sql = sql;
                 self.show_sql_editor = true;
                 self.focus_sql_editor = true;
-                self.notifications.info("SQL 已生成，按 Ctrl+Enter 执行");
+                self.notifications.info(t("notify.sql_generated"));
             }
         }
 
@@ -200,22 +527,155 @@ impl DbManagerApp {
             self.sql = statements.join("\n");
             self.show_sql_editor = true;
             self.focus_sql_editor = true;
-            self.notifications.info("SQL 已生成，按 Ctrl+Enter 执行");
+            self.notifications.info(t("notify.sql_generated"));
         }
 
-        // 处理历史记录
-        if let Some(sql) = results.history_selected_sql {
-            self.sql = sql;
+        // 处理触发器/存储过程/序列 DDL
+        if let Some((statements, restore)) = results.object_ddl_sql {
+            self.execute_object_ddl(statements, restore);
+        }
+
+        // 处理表大小概览刷新
+        if results.refresh_size_overview {
+            self.load_table_sizes();
+        }
+        if results.refresh_dependencies {
+            self.load_dependencies();
+        }
+
+        // 处理全库数据搜索
+        match results.data_search {
+            Some(ui::DataSearchResult::Search) => self.start_data_search(),
+            Some(ui::DataSearchResult::Cancel) => self.cancel_data_search(),
+            Some(ui::DataSearchResult::Open { table, sql }) => self.open_data_search_hit(table, sql),
+            Some(ui::DataSearchResult::None) | None => {}
+        }
+
+        // 处理建表语句
+        if let Some((table, ddl)) = results.export_table_ddl {
+            self.export_table_ddl(&table, &ddl);
+        }
+        if let Some(ddl) = results.open_table_ddl_in_tab {
+            self.table_ddl_state.close();
+            self.open_sql_in_new_tab(&ddl);
         }
 
-        if results.clear_history {
-            self.query_history.clear();
+        // 处理表操作
+        if let Some((action, table, statements)) = results.table_action {
+            self.execute_table_action(action, table, statements);
+        }
+
+        // 处理测试数据生成
+        if let Some(table) = results.datagen_load_columns {
+            self.load_datagen_columns(table);
+        }
+        if let Some(plan) = results.datagen_plan {
+            self.generate_test_data(plan);
+        }
+
+        // 处理定时查询
+        if let Some(action) = results.scheduler_action {
+            self.handle_scheduler_action(action);
+        }
+
+        // 处理结果对比报告导出
+        if let Some(report) = results.export_diff_report {
+            self.export_diff_report(&report);
+        }
+
+        // 处理审计日志
+        if results.refresh_audit_log {
+            self.open_audit_log();
+        }
+        if let Some(report) = results.export_audit_log {
+            self.export_audit_log(&report);
+        }
+
+        // 处理会话监控
+        if let Some(sql) = results.kill_session_sql {
+            self.kill_session(sql);
+        } else if results.refresh_sessions {
+            self.load_sessions();
+        }
+
+        // 处理权限管理
+        if let Some(user) = results.load_grants_user {
+            self.load_grants(user);
+        }
+        if let Some(statements) = results.privilege_sql {
+            self.apply_privileges(statements);
+        }
+
+        // 处理历史记录
+        if let Some(action) = results.history_action {
+            self.handle_history_action(action);
         }
 
         // 处理快捷键更新
         if let Some(keybindings) = results.updated_keybindings {
+            self.app_config.keybindings = keybindings.clone();
             self.keybindings = keybindings;
-            self.notifications.success("快捷键设置已保存");
+            match self.app_config.save() {
+                Ok(()) => self.notifications.success(t("notify.keybindings_saved")),
+                Err(e) => self.notifications.error(tf("notify.keybindings_save_failed", &[&e])),
+            };
+        }
+    }
+
+    /// 处理历史面板的操作
+    pub(super) fn handle_history_action(&mut self, action: ui::HistoryPanelAction) {
+        match action {
+            ui::HistoryPanelAction::Use(sql) => {
+                self.sql = sql;
+                return;
+            }
+            ui::HistoryPanelAction::Run(sql) => {
+                self.sql = sql.clone();
+                self.execute(sql);
+                return;
+            }
+            ui::HistoryPanelAction::OpenInNewTab(sql) => {
+                self.open_sql_in_new_tab(&sql);
+                return;
+            }
+            ui::HistoryPanelAction::DiffWithEditor(idx) => {
+                if let Some(item) = self.query_history.items().get(idx) {
+                    let title = format!("历史 {}", item.timestamp.format("%m-%d %H:%M:%S"));
+                    self.sql_diff_state.open(title, &item.sql, &self.sql);
+                }
+                return;
+            }
+            ui::HistoryPanelAction::ToggleFavorite(idx) => self.query_history.toggle_favorite(idx),
+            ui::HistoryPanelAction::Remove(idx) => {
+                self.query_history.remove(idx);
+            }
+            ui::HistoryPanelAction::Clear => self.query_history.clear(),
+            ui::HistoryPanelAction::SetLimit(limit) => {
+                self.query_history.set_max_size(limit);
+                self.app_config.history_limit = limit;
+                let _ = self.app_config.save();
+            }
+        }
+        self.save_query_history();
+    }
+
+    /// 在新标签页中打开 SQL 并切换到编辑器
+    pub(super) fn open_sql_in_new_tab(&mut self, sql: &str) {
+        self.tab_manager.new_tab_with_sql(sql);
+        if let Some(tab) = self.tab_manager.get_active() {
+            self.sql = tab.sql.clone();
+            self.grid_state.page = tab.page.clone();
+            let result = tab.result.clone();
+            self.set_result(result);
+        }
+        self.show_sql_editor = true;
+        self.focus_sql_editor = true;
+    }
+
+    /// 将查询历史写入历史文件
+    pub(super) fn save_query_history(&self) {
+        if let Err(e) = self.query_history.save() {
+            tracing::warn!(error = %e, "保存查询历史失败");
         }
     }
 }
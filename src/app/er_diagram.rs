@@ -1,7 +1,8 @@
 //! ER 关系图模块
 //!
-//! 处理 ER 图数据加载和关系推断。
+//! 处理 ER 图数据加载、关系推断、表设计和导出。
 
+use crate::core::{t, tf};
 use crate::ui;
 use super::{DbManagerApp, Message};
 
@@ -20,11 +21,14 @@ impl DbManagerApp {
             let config = conn.config.clone();
 
             if tables.is_empty() {
-                self.notifications.warning(format!("数据库 {} 没有表，请先选择数据库", db_name));
+                self.notifications.warning(tf("notify.er_no_tables", &[&db_name]));
                 self.er_diagram_state.loading = false;
                 return;
             }
 
+            // 已在数据库中创建的设计表由真实表替代
+            self.er_diagram_state.tables.retain(|t| !tables.contains(&t.name));
+
             // 创建 ER 表结构
             for table_name in &tables {
                 let er_table = ui::ERTable::new(table_name.clone());
@@ -38,11 +42,7 @@ impl DbManagerApp {
                 eframe::egui::Vec2::new(60.0, 50.0),
             );
 
-            self.notifications.info(format!(
-                "ER图: 加载 {} 张表，正在获取结构... ({})",
-                tables.len(),
-                db_name
-            ));
+            self.notifications.info(tf("notify.er_loading", &[&tables.len().to_string(), &db_name]));
 
             // 异步加载每个表的列信息
             for table_name in &tables {
@@ -65,7 +65,7 @@ impl DbManagerApp {
                 let _ = tx.send(Message::ForeignKeysFetched(result.map_err(|e| e.to_string())));
             });
         } else {
-            self.notifications.warning("请先连接数据库");
+            self.notifications.warning(t("common.need_connection"));
             self.er_diagram_state.loading = false;
         }
 
@@ -137,4 +137,111 @@ impl DbManagerApp {
 
         relationships
     }
+
+    /// 导出 ER 图
+    ///
+    /// SVG、Mermaid、PlantUML 直接写入文件；PNG 需要先在下一帧截取画布。
+    pub(super) fn export_er_diagram(&mut self, format: ui::ERExportFormat) {
+        let file_name = format!("er_diagram_{}.{}", chrono::Local::now().format("%Y%m%d_%H%M%S"), format.extension());
+        let Some(path) = rfd::FileDialog::new()
+            .set_file_name(file_name)
+            .add_filter(format.label(), &[format.extension()])
+            .save_file()
+        else {
+            return;
+        };
+
+        let state = &self.er_diagram_state;
+        let relationships = state.all_relationships();
+        let content = match format {
+            ui::ERExportFormat::Png => {
+                self.er_png_export_path = Some(path);
+                self.er_diagram_state.request_capture();
+                return;
+            }
+            ui::ERExportFormat::Svg => ui::to_svg(&state.tables, &relationships),
+            ui::ERExportFormat::Mermaid => ui::to_mermaid(&state.tables, &relationships),
+            ui::ERExportFormat::PlantUml => ui::to_plantuml(&state.tables, &relationships),
+        };
+
+        match std::fs::write(&path, content) {
+            Ok(()) => {
+                self.notifications.success(tf("notify.er_exported", &[&path.display().to_string()]));
+            }
+            Err(e) => {
+                self.notifications.error(tf("common.export_failed", &[&e.to_string()]));
+            }
+        }
+    }
+
+    /// 画布已为截图完整绘制，请求截图
+    pub(super) fn capture_er_diagram(&mut self, ctx: &egui::Context, rect: egui::Rect) {
+        if let Some(path) = self.er_png_export_path.take() {
+            self.er_png_capture = Some((path, rect));
+            ctx.send_viewport_cmd(egui::ViewportCommand::Screenshot(Default::default()));
+        }
+    }
+
+    /// 收到截图后裁剪出画布区域并保存为 PNG
+    pub(super) fn save_er_screenshot(&mut self, ctx: &egui::Context) {
+        if self.er_png_capture.is_none() {
+            return;
+        }
+        let screenshot = ctx.input(|i| {
+            i.raw.events.iter().find_map(|e| match e {
+                egui::Event::Screenshot { image, .. } => Some(image.clone()),
+                _ => None,
+            })
+        });
+        let (Some(image), Some((path, rect))) = (screenshot, self.er_png_capture.take()) else {
+            return;
+        };
+
+        let region = image.region(&rect, Some(ctx.pixels_per_point()));
+        let pixels: Vec<u8> = region.pixels.iter().flat_map(|c| c.to_srgba_unmultiplied()).collect();
+        let result = image::RgbaImage::from_raw(region.size[0] as u32, region.size[1] as u32, pixels)
+            .ok_or_else(|| "截图数据无效".to_string())
+            .and_then(|img| img.save(&path).map_err(|e| e.to_string()));
+        match result {
+            Ok(()) => {
+                self.notifications.success(tf("notify.er_exported", &[&path.display().to_string()]));
+            }
+            Err(e) => {
+                self.notifications.error(tf("notify.er_png_failed", &[&e]));
+            }
+        }
+    }
+
+    /// 在 ER 图上设计新表，或编辑已有的设计表
+    pub(super) fn design_er_table(&mut self, name: Option<&str>) {
+        if self.ddl_dialog_state.show {
+            return;
+        }
+        if let Some(name) = name {
+            if let Some(table) = self.er_diagram_state.design_definition(name) {
+                self.ddl_dialog_state.open_edit_design(table.clone());
+            }
+            return;
+        }
+
+        let db_type = self.manager.get_active().map(|c| c.config.db_type).unwrap_or_default();
+        if db_type.uses_sql() {
+            self.ddl_dialog_state.open_design_table(db_type);
+        } else {
+            self.notifications.warning(tf("notify.create_table_unsupported", &[db_type.display_name()]));
+        }
+    }
+
+    /// 为设计中的表生成建表语句并在新标签页中打开
+    pub(super) fn generate_er_design_ddl(&mut self) {
+        match self.er_diagram_state.design_ddl() {
+            Ok(sql) => {
+                self.open_sql_in_new_tab(&sql);
+                self.notifications.info(t("notify.create_sql_generated"));
+            }
+            Err(e) => {
+                self.notifications.error(e);
+            }
+        }
+    }
 }
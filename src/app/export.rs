@@ -1,8 +1,8 @@
 //! 数据导出功能
 //!
-//! 提供 CSV、SQL、JSON 格式的数据导出功能。
+//! 提供 CSV、SQL、JSON 格式以及插件格式的数据导出功能。
 
-use crate::core::ExportFormat;
+use crate::core::{ExportFormat, PluginRegistry};
 use crate::database::QueryResult;
 use crate::ui::ExportConfig;
 use std::path::Path;
@@ -30,7 +30,7 @@ pub fn filter_result_for_export(result: &QueryResult, config: &ExportConfig) ->
         .map(|row| {
             selected_indices
                 .iter()
-                .filter_map(|&i| row.get(i).cloned())
+                .filter_map(|&i| row.get(i).map(|v| config.export_value(i, v).into_owned()))
                 .collect()
         })
         .collect();
@@ -212,23 +212,26 @@ pub fn export_json(
         .rows
         .iter()
         .map(|row| {
-            result
-                .columns
-                .iter()
-                .zip(row.iter())
-                .map(|(col, cell)| {
-                    let value = if cell == "NULL" {
-                        serde_json::Value::Null
-                    } else if let Ok(num) = cell.parse::<i64>() {
-                        serde_json::Value::Number(num.into())
-                    } else if let Ok(num) = cell.parse::<f64>() {
-                        serde_json::json!(num)
-                    } else {
-                        serde_json::Value::String(cell.clone())
-                    };
-                    (col.clone(), value)
-                })
-                .collect()
+            let mut obj = serde_json::Map::new();
+            for (col, cell) in result.columns.iter().zip(row.iter()) {
+                let value = if cell == "NULL" {
+                    serde_json::Value::Null
+                } else if let Ok(num) = cell.parse::<i64>() {
+                    serde_json::Value::Number(num.into())
+                } else if let Ok(num) = cell.parse::<f64>() {
+                    serde_json::json!(num)
+                } else if config.json_nested && cell.starts_with(['{', '[']) {
+                    serde_json::from_str(cell).unwrap_or_else(|_| serde_json::Value::String(cell.clone()))
+                } else {
+                    serde_json::Value::String(cell.clone())
+                };
+                if config.json_nested {
+                    insert_nested(&mut obj, col, value);
+                } else {
+                    obj.insert(col.clone(), value);
+                }
+            }
+            obj
         })
         .collect();
 
@@ -244,6 +247,41 @@ pub fn export_json(
     Ok(())
 }
 
+/// 按 `a.b` 形式的列名写入嵌套对象（路径上已有非对象值时按原列名写入）
+fn insert_nested(
+    obj: &mut serde_json::Map<String, serde_json::Value>,
+    column: &str,
+    value: serde_json::Value,
+) {
+    let mut parts = column.split('.').filter(|p| !p.is_empty()).peekable();
+    let mut target = &mut *obj;
+    while let Some(part) = parts.next() {
+        if parts.peek().is_none() {
+            target.insert(part.to_string(), value);
+            return;
+        }
+        let entry = target
+            .entry(part.to_string())
+            .or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()));
+        match entry {
+            serde_json::Value::Object(map) => target = map,
+            _ => break,
+        }
+    }
+    obj.insert(column.to_string(), value);
+}
+
+/// 使用导出插件导出
+fn export_with_plugin(result: &QueryResult, path: &Path, id: &str, plugins: &PluginRegistry) -> Result<(), String> {
+    use std::io::Write;
+
+    let exporter = plugins.exporter(id).ok_or_else(|| format!("导出插件不存在: {}", id))?;
+    let file = std::fs::File::create(path).map_err(|e| e.to_string())?;
+    let mut out = std::io::BufWriter::new(file);
+    exporter.export(result, &mut out)?;
+    out.flush().map_err(|e| e.to_string())
+}
+
 /// 执行导出操作
 ///
 /// 根据配置选择相应的导出格式并执行
@@ -252,14 +290,18 @@ pub fn execute_export(
     table_name: &str,
     path: &Path,
     config: &ExportConfig,
+    plugins: &PluginRegistry,
 ) -> Result<String, String> {
     // 根据配置过滤数据
     let filtered_result = filter_result_for_export(result, config);
 
-    let export_result = match config.format {
-        ExportFormat::Csv => export_csv(&filtered_result, path, config),
-        ExportFormat::Sql => export_sql(&filtered_result, table_name, path, config),
-        ExportFormat::Json => export_json(&filtered_result, path, config),
+    let export_result = match config.plugin_exporter.as_deref() {
+        Some(id) => export_with_plugin(&filtered_result, path, id, plugins),
+        None => match config.format {
+            ExportFormat::Csv => export_csv(&filtered_result, path, config),
+            ExportFormat::Sql => export_sql(&filtered_result, table_name, path, config),
+            ExportFormat::Json => export_json(&filtered_result, path, config),
+        },
     };
 
     export_result.map(|()| {
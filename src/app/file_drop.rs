@@ -0,0 +1,94 @@
+//! 拖放文件处理
+
+use std::path::{Path, PathBuf};
+
+use crate::core::{t, tf, DroppedFileKind};
+use crate::database::{ConnectionConfig, DatabaseType};
+
+use super::DbManagerApp;
+
+impl DbManagerApp {
+    /// 处理拖放到窗口的文件，并在拖动经过窗口时显示提示
+    pub(super) fn handle_dropped_files(&mut self, ctx: &egui::Context) {
+        let (hovering, dropped): (bool, Vec<PathBuf>) = ctx.input(|i| {
+            (
+                !i.raw.hovered_files.is_empty(),
+                i.raw.dropped_files.iter().filter_map(|f| f.path.clone()).collect(),
+            )
+        });
+
+        if hovering {
+            Self::paint_drop_overlay(ctx);
+        }
+
+        for path in dropped {
+            match DroppedFileKind::from_path(&path) {
+                DroppedFileKind::SqliteDatabase => self.open_sqlite_file(&path),
+                DroppedFileKind::SqlScript => self.open_sql_file_path(&path),
+                DroppedFileKind::ImportData => self.import_dropped_file(path),
+                DroppedFileKind::Unsupported => {
+                    self.notifications.warning(tf("drop.unsupported", &[&path.display().to_string()]));
+                }
+            }
+        }
+    }
+
+    fn paint_drop_overlay(ctx: &egui::Context) {
+        let painter = ctx.layer_painter(egui::LayerId::new(
+            egui::Order::Foreground,
+            egui::Id::new("file_drop_overlay"),
+        ));
+        let rect = ctx.content_rect();
+        painter.rect_filled(rect, 0.0, egui::Color32::from_black_alpha(160));
+        painter.text(
+            rect.center(),
+            egui::Align2::CENTER_CENTER,
+            t("drop.hint"),
+            egui::FontId::proportional(18.0),
+            egui::Color32::WHITE,
+        );
+    }
+
+    /// 为 SQLite 文件建立连接（已有指向同一文件的连接时直接连接）
+    fn open_sqlite_file(&mut self, path: &Path) {
+        let database = path.display().to_string();
+        let existing = self
+            .manager
+            .connections
+            .values()
+            .find(|c| c.config.db_type == DatabaseType::SQLite && c.config.database == database)
+            .map(|c| c.config.name.clone());
+        if let Some(name) = existing {
+            self.connect(name);
+            return;
+        }
+
+        let stem = path
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "SQLite".to_string());
+        let name = (1..)
+            .map(|n| if n == 1 { stem.clone() } else { format!("{} {}", stem, n) })
+            .find(|name| !self.manager.connections.contains_key(name))
+            .unwrap_or(stem);
+
+        self.manager.add(ConnectionConfig {
+            database,
+            ..ConnectionConfig::new(name.clone(), DatabaseType::SQLite)
+        });
+        self.save_config();
+        self.notifications.info(tf("drop.sqlite_added", &[&name]));
+        self.connect(name);
+    }
+
+    /// 打开导入向导并预览拖入的 CSV / JSON 文件
+    fn import_dropped_file(&mut self, path: PathBuf) {
+        if !self.manager.get_active().is_some_and(|c| c.connected) {
+            self.notifications.warning(t("drop.import_needs_connection"));
+            return;
+        }
+        self.handle_import();
+        self.import_state.set_file(path);
+        self.refresh_import_preview();
+    }
+}
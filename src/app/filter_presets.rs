@@ -0,0 +1,35 @@
+//! 筛选预设
+//!
+//! 按连接、数据库和表保存当前的筛选条件（随配置在退出时保存），
+//! 在筛选面板中一键应用。草稿本是临时连接，不保存预设。
+
+use crate::core::{tf, FilterPreset, RecentTarget};
+use crate::ui::ColumnFilter;
+use super::DbManagerApp;
+
+impl DbManagerApp {
+    /// 当前选中的表（预设按连接、数据库和表保存）
+    pub(super) fn filter_preset_target(&self) -> Option<RecentTarget> {
+        self.recent_target(Some(self.selected_table.as_deref()?))
+    }
+
+    /// 将当前筛选条件保存为当前表的预设，同名预设会被覆盖
+    pub(super) fn save_filter_preset(&mut self, name: String) {
+        let Some(RecentTarget { connection, database, table: Some(table) }) = self.filter_preset_target() else {
+            return;
+        };
+        let preset = FilterPreset {
+            name: name.clone(),
+            filters: self.grid_state.filters.iter().map(ColumnFilter::to_saved).collect(),
+        };
+        self.app_config.filter_presets.save(&connection, database.as_deref(), &table, preset);
+        self.notifications.success(tf("notify.filter_preset_saved", &[&name]));
+    }
+
+    /// 删除当前表的筛选预设
+    pub(super) fn delete_filter_preset(&mut self, name: &str) {
+        if let Some(RecentTarget { connection, database, table: Some(table) }) = self.filter_preset_target() {
+            self.app_config.filter_presets.remove(&connection, database.as_deref(), &table, name);
+        }
+    }
+}
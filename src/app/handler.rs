@@ -2,8 +2,12 @@
 //!
 //! 处理从异步任务返回的各种消息，更新应用状态。
 
+use std::sync::Arc;
+use std::time::Instant;
+
 use eframe::egui;
 
+use crate::core::{ddl_tables, t, tf};
 use crate::ui;
 use super::{DbManagerApp, Message};
 
@@ -23,24 +27,169 @@ impl DbManagerApp {
                 Message::DatabaseSelected(conn_name, db_name, result) => {
                     self.handle_database_selected(ctx, conn_name, db_name, result);
                 }
-                Message::QueryDone(sql, result, elapsed_ms) => {
-                    self.handle_query_done(ctx, sql, result, elapsed_ms);
+                Message::MoreTablesLoaded(conn_name, db_name, result) => {
+                    self.handle_more_tables_loaded(ctx, conn_name, db_name, result);
+                }
+                Message::QueryDone(tab_id, sql, result, elapsed_ms) => {
+                    self.finish_query(ctx, tab_id, sql, result, elapsed_ms, None);
+                }
+                Message::RowsChunk(progress_id, columns, rows) => {
+                    self.handle_rows_chunk(ctx, progress_id, columns, rows);
+                }
+                Message::StreamDone(progress_id, sql, result, elapsed_ms) => {
+                    self.handle_stream_done(ctx, progress_id, sql, result, elapsed_ms);
+                }
+                Message::QueryPageDone(tab_id, page, result, elapsed_ms) => {
+                    self.handle_query_page_done(ctx, tab_id, page, result, elapsed_ms);
+                }
+                Message::RedisKeyFetched(key, result, elapsed_ms) => {
+                    self.handle_redis_key_fetched(ctx, key, result, elapsed_ms);
                 }
                 Message::PrimaryKeyFetched(table_name, pk_column) => {
                     self.handle_primary_key_fetched(ctx, table_name, pk_column);
                 }
+                Message::ColumnTypesFetched(table_name, result) => {
+                    self.handle_column_types_fetched(ctx, table_name, result);
+                }
                 Message::TriggersFetched(result) => {
                     self.handle_triggers_fetched(ctx, result);
                 }
                 Message::RoutinesFetched(result) => {
                     self.handle_routines_fetched(ctx, result);
                 }
+                Message::SequencesFetched(result) => {
+                    self.handle_sequences_fetched(ctx, result);
+                }
+                Message::HealthChecked(conn_name, result) => {
+                    self.handle_health_checked(ctx, conn_name, result);
+                }
+                Message::IdlePoolsClosed(closed) => {
+                    self.pool_stats_state.invalidate();
+                    if closed == 0 {
+                        self.notifications.info(t("notify.no_idle_pools"));
+                    } else {
+                        self.notifications.success(tf("notify.idle_pools_closed", &[&closed.to_string()]));
+                    }
+                }
+                Message::ServerTimezoneFetched(conn_name, offset) => {
+                    if let Some(conn) = self.manager.connections.get_mut(&conn_name) {
+                        conn.server_utc_offset = offset;
+                    }
+                    ctx.request_repaint();
+                }
+                Message::TableSizesFetched(result) => {
+                    self.size_overview_state.set_result(result);
+                    ctx.request_repaint();
+                }
+                Message::DataSearchTableDone(search_id, table, outcome) => {
+                    self.data_search_state.add_result(search_id, table, outcome);
+                    ctx.request_repaint();
+                }
+                Message::DataSearchDone(search_id) => {
+                    self.data_search_state.finish(search_id);
+                    ctx.request_repaint();
+                }
+                Message::DependenciesFetched(result) => {
+                    self.dependency_state.set_data(result);
+                    ctx.request_repaint();
+                }
+                Message::TableDdlFetched(table, result) => {
+                    self.table_ddl_state.set_result(&table, result);
+                    ctx.request_repaint();
+                }
+                Message::WritePreviewLoaded(select_sql, result) => {
+                    self.write_preview_state.set_result(&select_sql, result);
+                    ctx.request_repaint();
+                }
+                Message::TableActionDone(action, table, result) => {
+                    self.handle_table_action_done(ctx, action, table, result);
+                }
+                Message::DataGenColumnsFetched(table, result) => {
+                    self.datagen_state.set_columns(&table, result);
+                    ctx.request_repaint();
+                }
+                Message::DataGenProgress(progress_id, inserted, total) => {
+                    self.progress.update(progress_id, inserted as f32 / total.max(1) as f32);
+                    ctx.request_repaint();
+                }
+                Message::DataGenDone(progress_id, table, inserted, error) => {
+                    self.handle_datagen_done(ctx, progress_id, table, inserted, error);
+                }
+                Message::ScheduledQueryDone(id, result, elapsed_ms) => {
+                    self.handle_scheduled_query_done(ctx, id, result, elapsed_ms);
+                }
+                Message::WorkflowProgress(progress_id, run) => {
+                    self.handle_workflow_progress(ctx, progress_id, run);
+                }
+                Message::SessionsFetched(result) => {
+                    self.session_monitor_state.set_result(result);
+                    ctx.request_repaint();
+                }
+                Message::SessionKilled(result) => {
+                    self.handle_session_killed(ctx, result);
+                }
+                Message::PrivilegeUsersFetched(result) => {
+                    self.handle_privilege_users_fetched(ctx, result);
+                }
+                Message::GrantsFetched(user, result) => {
+                    self.handle_grants_fetched(ctx, user, result);
+                }
+                Message::PrivilegesApplied(result) => {
+                    self.handle_privileges_applied(ctx, result);
+                }
+                Message::MaintenanceDone(label, progress_id, result, elapsed_ms) => {
+                    self.handle_maintenance_done(ctx, label, progress_id, result, elapsed_ms);
+                }
                 Message::ForeignKeysFetched(result) => {
                     self.handle_foreign_keys_fetched(ctx, result);
                 }
                 Message::ERTableColumnsFetched(table_name, result) => {
                     self.handle_er_table_columns_fetched(ctx, table_name, result);
                 }
+                Message::AutocompleteTablesFetched(result) => {
+                    // 失败时保留当前 schema 的表，不打扰用户
+                    if let Ok(tables) = result {
+                        self.autocomplete.set_tables(tables);
+                    }
+                }
+                Message::ScratchpadSaved(path, result) => {
+                    match result {
+                        Ok(()) => {
+                            self.notifications.success(tf("notify.scratchpad_saved", &[&path]));
+                        }
+                        Err(e) => {
+                            self.notifications.error(tf("notify.scratchpad_save_failed", &[&e]));
+                        }
+                    }
+                    ctx.request_repaint();
+                }
+                Message::SqliteKeyChanged(conn_name, new_key, result) => {
+                    self.handle_sqlite_key_changed(ctx, conn_name, new_key, result);
+                }
+                Message::ObjectDdlDone(result) => {
+                    self.handle_object_ddl_done(ctx, result);
+                }
+                Message::GridSaveDone(sql, transaction, result, elapsed_ms) => {
+                    self.handle_grid_save_done(ctx, sql, transaction, result, elapsed_ms);
+                }
+                Message::MetadataRefreshed(snapshot) => {
+                    self.handle_metadata_refreshed(ctx, snapshot);
+                }
+                Message::SqlValidated(result) => {
+                    self.handle_sql_validated(ctx, result);
+                }
+                Message::BenchmarkRun(run_id, warmup, sample) => {
+                    self.benchmark_state.add_sample(run_id, warmup, sample);
+                    ctx.request_repaint();
+                }
+                Message::BenchmarkDone(run_id) => {
+                    self.benchmark_state.finish(run_id);
+                    ctx.request_repaint();
+                }
+                Message::QueryProfiled(request_id, result) => {
+                    self.profile_state.set_result(request_id, result);
+                    ctx.request_repaint();
+                }
             }
         }
     }
@@ -55,17 +204,20 @@ impl DbManagerApp {
         self.connecting = false;
         match result {
             Ok(tables) => {
-                self.notifications.success(
-                    format!("已连接到 {} ({} 张表)", name, tables.len())
-                );
+                self.notifications.success(tf("notify.connected_tables", &[&name, &tables.len().to_string()]));
                 self.load_history_for_connection(&name);
                 self.autocomplete.set_tables(tables.clone());
                 if let Some(conn) = self.manager.connections.get_mut(&name) {
+                    self.autocomplete.set_dialect(conn.config.db_type);
                     conn.set_connected(tables);
                 }
                 self.sidebar_panel_state.selection.reset_for_connection_change();
+                self.metadata_refresh.reset(Instant::now());
                 self.load_triggers();
                 self.load_routines();
+                self.load_sequences();
+                self.load_server_timezone(&name);
+                self.resume_pending_recent(&name);
             }
             Err(e) => self.handle_connection_error(&name, e),
         }
@@ -82,15 +234,17 @@ impl DbManagerApp {
         self.connecting = false;
         match result {
             Ok(databases) => {
-                self.notifications.success(
-                    format!("已连接到 {} ({} 个数据库)", name, databases.len())
-                );
+                self.notifications.success(tf("notify.connected_databases", &[&name, &databases.len().to_string()]));
                 self.load_history_for_connection(&name);
                 self.autocomplete.clear();
                 if let Some(conn) = self.manager.connections.get_mut(&name) {
+                    self.autocomplete.set_dialect(conn.config.db_type);
                     conn.set_connected_with_databases(databases);
                 }
                 self.sidebar_panel_state.selection.reset_for_connection_change();
+                self.metadata_refresh.reset(Instant::now());
+                self.load_server_timezone(&name);
+                self.resume_pending_recent(&name);
             }
             Err(e) => self.handle_connection_error(&name, e),
         }
@@ -103,47 +257,91 @@ impl DbManagerApp {
         ctx: &egui::Context,
         conn_name: String,
         db_name: String,
-        result: Result<Vec<String>, String>,
+        result: Result<(Vec<String>, bool), String>,
     ) {
         self.connecting = false;
         match result {
-            Ok(tables) => {
-                self.notifications.success(
-                    format!("已选择数据库 {} ({} 张表)", db_name, tables.len())
-                );
+            Ok((tables, has_more)) => {
+                let more = if has_more { "+" } else { "" };
+                let count = format!("{}{}", tables.len(), more);
+                self.notifications.success(tf("notify.database_selected", &[&db_name, &count]));
                 self.autocomplete.set_tables(tables.clone());
-                if let Some(conn) = self.manager.connections.get_mut(&conn_name) {
+                let is_sqlite = if let Some(conn) = self.manager.connections.get_mut(&conn_name) {
                     conn.set_database(db_name, tables);
+                    conn.has_more_tables = has_more;
+                    conn.config.db_type == crate::database::DatabaseType::SQLite
+                } else {
+                    false
+                };
+                // SQLite 附加数据库：自动补全包含所有 schema 的表
+                if is_sqlite {
+                    self.load_sqlite_autocomplete_tables();
                 }
+                self.record_recent(None);
                 self.sidebar_panel_state.selection.reset_for_database_change();
+                self.sidebar_panel_state.quick_filters.tables.clear();
+                self.sidebar_panel_state.loading_tables = false;
+                self.metadata_refresh.reset(Instant::now());
                 self.load_triggers();
                 self.load_routines();
+                self.load_sequences();
             }
             Err(e) => {
-                self.notifications.error(format!("选择数据库失败: {}", e));
+                self.notifications.error(tf("notify.select_database_failed", &[&e]));
+                self.pending_recent = None;
             }
         }
         self.selected_table = None;
-        self.result = None;
+        self.set_result(None);
+        self.resume_pending_recent(&conn_name);
+        ctx.request_repaint();
+    }
+
+    /// 处理下一批表读取完成消息（连接或数据库已切换时丢弃）
+    fn handle_more_tables_loaded(
+        &mut self,
+        ctx: &egui::Context,
+        conn_name: String,
+        db_name: String,
+        result: Result<(Vec<String>, bool), String>,
+    ) {
+        self.sidebar_panel_state.loading_tables = false;
+        let Some(conn) = self.manager.connections.get_mut(&conn_name) else {
+            return;
+        };
+        if conn.selected_database.as_deref() != Some(db_name.as_str()) {
+            return;
+        }
+        match result {
+            Ok((tables, has_more)) => {
+                conn.append_tables(tables, has_more);
+                self.autocomplete.set_tables(conn.tables.clone());
+            }
+            Err(e) => {
+                self.notifications.error(tf("notify.load_more_tables_failed", &[&e]));
+            }
+        }
         ctx.request_repaint();
     }
 
     /// 处理查询完成消息
-    fn handle_query_done(
+    pub(super) fn handle_query_done(
         &mut self,
         ctx: &egui::Context,
         sql: String,
         result: Result<crate::database::QueryResult, String>,
         elapsed_ms: u64,
     ) {
-        use crate::core::constants;
+        use crate::core::QueryHistoryItem;
 
         self.executing = false;
         self.last_query_time_ms = Some(elapsed_ms);
+        self.grid_state.page = None;
 
         let sql_lower = sql.trim().to_lowercase();
         let is_update_or_delete = sql_lower.starts_with("update") || sql_lower.starts_with("delete");
         let is_insert = sql_lower.starts_with("insert");
+        let ddl_targets = ddl_tables(&sql);
 
         let db_type = self
             .manager
@@ -153,32 +351,24 @@ impl DbManagerApp {
 
         match result {
             Ok(mut res) => {
-                // 限制结果集大小
-                let original_rows = res.rows.len();
-                let was_truncated = original_rows > constants::database::MAX_RESULT_SET_ROWS;
-                if was_truncated {
-                    res.rows.truncate(constants::database::MAX_RESULT_SET_ROWS);
-                    res.truncated = true;
-                    res.original_row_count = Some(original_rows);
-                }
-
-                self.query_history.add(
-                    sql,
-                    db_type,
-                    true,
-                    if res.affected_rows > 0 { Some(res.affected_rows) } else { None },
-                );
-
-                let msg = if res.columns.is_empty() {
-                    format!("执行成功，影响 {} 行 ({}ms)", res.affected_rows, elapsed_ms)
-                } else if was_truncated {
-                    format!(
-                        "查询完成，返回 {} 行（已截断，原始 {} 行，建议使用 LIMIT）({}ms)",
-                        res.rows.len(), original_rows, elapsed_ms
-                    )
-                } else {
-                    format!("查询完成，返回 {} 行 ({}ms)", res.rows.len(), elapsed_ms)
-                };
+                let (original_rows, was_truncated) = self.limit_result_rows(&mut res);
+                self.sql_error_marker = None;
+                self.profile_after_query(&sql, elapsed_ms);
+
+                self.query_history.add(QueryHistoryItem {
+                    rows_affected: if res.affected_rows > 0 { Some(res.affected_rows) } else { None },
+                    row_count: if res.columns.is_empty() { None } else { Some(original_rows) },
+                    elapsed_ms: Some(elapsed_ms),
+                    ..QueryHistoryItem::new(sql, db_type, self.manager.active.clone(), true)
+                });
+                self.save_query_history();
+
+                // 表结构变化后在后台刷新表和列信息
+                if let Some(tables) = ddl_targets {
+                    self.request_metadata_refresh(tables);
+                }
+
+                let msg = query_done_message(&res, original_rows, was_truncated, elapsed_ms);
                 self.notifications.success(&msg);
 
                 self.selected_row = None;
@@ -198,9 +388,11 @@ impl DbManagerApp {
                     self.grid_state.focused = true;
                 }
 
-                // 同步到当前 Tab
+                // 同步到当前 Tab（与表格共享同一份结果）
+                let res = Arc::new(res);
                 if let Some(tab) = self.tab_manager.get_active_mut() {
-                    tab.result = Some(res.clone());
+                    tab.result = Some(Arc::clone(&res));
+                    tab.page = None;
                     tab.executing = false;
                     tab.query_time_ms = Some(elapsed_ms);
                     tab.last_message = Some(msg);
@@ -212,13 +404,18 @@ impl DbManagerApp {
                         self.autocomplete.set_columns(table.clone(), res.columns.clone());
                     }
 
-                self.result = Some(res);
+                self.set_result(Some(res));
             }
             Err(e) => {
-                self.query_history.add(sql, db_type, false, None);
-                let err_msg = format!("错误: {}", e);
+                let (err_msg, info) = self.describe_query_error(&sql, &e);
+                self.mark_sql_error(&sql, &info, &err_msg);
+                self.query_history.add(QueryHistoryItem {
+                    elapsed_ms: Some(elapsed_ms),
+                    ..QueryHistoryItem::new(sql, db_type, self.manager.active.clone(), false)
+                });
+                self.save_query_history();
                 self.notifications.error(&err_msg);
-                self.result = Some(crate::database::QueryResult::default());
+                self.set_result(Some(Arc::default()));
 
                 if let Some(tab) = self.tab_manager.get_active_mut() {
                     tab.executing = false;
@@ -229,6 +426,113 @@ impl DbManagerApp {
         ctx.request_repaint();
     }
 
+    /// 按首选项中的行数上限截断结果集，返回原始行数和是否已截断
+    ///
+    /// 流式读取只发送上限内的行，总行数在 `original_row_count` 中
+    pub(super) fn limit_result_rows(&self, res: &mut crate::database::QueryResult) -> (usize, bool) {
+        let original_rows = res.original_row_count.unwrap_or_default().max(res.rows.len());
+        let max_rows = self.app_config.max_result_rows;
+        let was_truncated = original_rows > max_rows;
+        if was_truncated {
+            res.rows.truncate(max_rows);
+            res.truncated = true;
+            res.original_row_count = Some(original_rows);
+        }
+        (original_rows, was_truncated)
+    }
+
+    /// 处理分页查询的一页读取完成消息
+    ///
+    /// 第一页按普通查询处理（记录历史、更新补全），翻页只替换表格数据
+    fn handle_query_page_done(
+        &mut self,
+        ctx: &egui::Context,
+        tab_id: Option<String>,
+        page: ui::ResultPage,
+        result: Result<crate::database::QueryResult, String>,
+        elapsed_ms: u64,
+    ) {
+        let res = match result {
+            Ok(res) if page.index == 0 => {
+                self.finish_query(ctx, tab_id, page.sql.clone(), Ok(res), elapsed_ms, Some(page));
+                return;
+            }
+            Ok(res) => res,
+            Err(e) if page.index == 0 => {
+                self.finish_query(ctx, tab_id, page.sql, Err(e), elapsed_ms, None);
+                return;
+            }
+            Err(e) => {
+                // 翻页失败时保留当前页
+                self.executing = false;
+                self.notifications.error(tf("notify.page_load_failed", &[&(page.index + 1).to_string(), &e]));
+                if let Some(tab) = self.tab_manager.get_active_mut() {
+                    tab.executing = false;
+                }
+                ctx.request_repaint();
+                return;
+            }
+        };
+
+        self.executing = false;
+        self.last_query_time_ms = Some(elapsed_ms);
+        let msg = tf(
+            "notify.page_loaded",
+            &[
+                &(page.index + 1).to_string(),
+                &(page.offset() + 1).to_string(),
+                &(page.offset() + res.rows.len()).to_string(),
+                &elapsed_ms.to_string(),
+            ],
+        );
+        self.notifications.info(&msg);
+
+        self.grid_state.clear_edits();
+        self.grid_state.cursor = (0, self.grid_state.cursor.1);
+        self.grid_state.scroll_to_row = Some(0);
+        self.selected_row = None;
+        self.selected_cell = None;
+
+        let res = Arc::new(res);
+        if let Some(tab) = self.tab_manager.get_active_mut() {
+            tab.result = Some(Arc::clone(&res));
+            tab.page = Some(page.clone());
+            tab.executing = false;
+            tab.query_time_ms = Some(elapsed_ms);
+            tab.last_message = Some(msg);
+        }
+        self.grid_state.page = Some(page);
+        self.set_result(Some(res));
+        ctx.request_repaint();
+    }
+
+    /// 处理 Redis 键值读取完成消息（结果按查询结果显示，类型和 TTL 显示在状态栏）
+    fn handle_redis_key_fetched(
+        &mut self,
+        ctx: &egui::Context,
+        key: String,
+        result: Result<crate::database::RedisKeyValue, String>,
+        elapsed_ms: u64,
+    ) {
+        let label = format!("-- 键: {}", key);
+        match result {
+            Ok(value) => {
+                let ttl = match value.ttl {
+                    Some(secs) => tf("notify.redis_ttl", &[&secs.to_string()]),
+                    None => t("notify.redis_no_expiry").to_string(),
+                };
+                let items = value.result.rows.len().to_string();
+                let info = tf("notify.redis_key_info", &[&key, &value.key_type, &ttl, &items]);
+                self.handle_query_done(ctx, label, Ok(value.result), elapsed_ms);
+                self.notifications.info(&info);
+                if let Some(tab) = self.tab_manager.get_active_mut() {
+                    tab.last_message = Some(info);
+                }
+            }
+            Err(e) => self.handle_query_done(ctx, label, Err(e), elapsed_ms),
+        }
+    }
+
     /// 处理主键获取完成消息
     fn handle_primary_key_fetched(
         &mut self,
@@ -249,6 +553,23 @@ impl DbManagerApp {
         ctx.request_repaint();
     }
 
+    /// 处理列信息获取完成消息（已切换到其他表时丢弃）
+    fn handle_column_types_fetched(
+        &mut self,
+        ctx: &egui::Context,
+        table_name: String,
+        result: Result<Vec<crate::database::ColumnInfo>, String>,
+    ) {
+        match result {
+            Ok(columns) if self.selected_table.as_deref() == Some(&table_name) => {
+                self.autocomplete.set_column_infos(table_name, columns);
+                ctx.request_repaint();
+            }
+            Ok(_) => {}
+            Err(e) => tracing::debug!(table = %table_name, error = %e, "读取列类型失败"),
+        }
+    }
+
     /// 处理触发器获取完成消息
     fn handle_triggers_fetched(
         &mut self,
@@ -261,7 +582,25 @@ impl DbManagerApp {
                 self.sidebar_panel_state.set_triggers(triggers);
             }
             Err(e) => {
-                self.notifications.error(format!("加载触发器失败: {}", e));
+                self.notifications.error(tf("notify.load_triggers_failed", &[&e]));
+            }
+        }
+        ctx.request_repaint();
+    }
+
+    /// 处理序列获取完成消息
+    fn handle_sequences_fetched(
+        &mut self,
+        ctx: &egui::Context,
+        result: Result<Vec<crate::database::SequenceInfo>, String>,
+    ) {
+        match result {
+            Ok(sequences) => {
+                self.sidebar_panel_state.set_sequences(sequences);
+            }
+            Err(e) => {
+                self.sidebar_panel_state.set_sequences(Vec::new());
+                self.notifications.error(tf("notify.load_sequences_failed", &[&e]));
             }
         }
         ctx.request_repaint();
@@ -281,7 +620,7 @@ impl DbManagerApp {
             Err(e) => {
                 // 对于 SQLite 不显示错误，因为它不支持存储过程
                 if !e.contains("不支持") {
-                    self.notifications.error(format!("加载存储过程失败: {}", e));
+                    self.notifications.error(tf("notify.load_routines_failed", &[&e]));
                 }
             }
         }
@@ -326,21 +665,16 @@ impl DbManagerApp {
                 self.er_diagram_state.loading = false;
 
                 if rel_count > 0 {
-                    self.notifications.info(format!(
-                        "ER图: {} 张表, {} 个关系",
-                        self.er_diagram_state.tables.len(),
-                        rel_count
-                    ));
+                    let tables = self.er_diagram_state.tables.len().to_string();
+                    self.notifications.info(tf("notify.er_summary", &[&tables, &rel_count.to_string()]));
                 } else {
-                    self.notifications.info(format!(
-                        "ER图: {} 张表（未发现外键关系）",
-                        self.er_diagram_state.tables.len()
-                    ));
+                    let tables = self.er_diagram_state.tables.len().to_string();
+                    self.notifications.info(tf("notify.er_summary_no_fk", &[&tables]));
                 }
             }
             Err(e) => {
                 self.er_diagram_state.loading = false;
-                self.notifications.error(format!("加载外键关系失败: {}", e));
+                self.notifications.error(tf("notify.load_foreign_keys_failed", &[&e]));
             }
         }
         ctx.request_repaint();
@@ -384,18 +718,306 @@ impl DbManagerApp {
                         let inferred = self.infer_relationships_from_columns();
                         if !inferred.is_empty() {
                             self.er_diagram_state.relationships = inferred;
-                            self.notifications.info(format!(
-                                "ER图: 推断出 {} 个关系",
-                                self.er_diagram_state.relationships.len()
-                            ));
+                            let count = self.er_diagram_state.relationships.len().to_string();
+                            self.notifications.info(tf("notify.er_inferred", &[&count]));
+                        }
+                    }
+                }
+            }
+            Err(e) => {
+                self.notifications.warning(tf("notify.table_schema_failed", &[&table_name, &e]));
+            }
+        }
+        ctx.request_repaint();
+    }
+
+    /// 处理 SQLite 加密密钥修改完成消息
+    fn handle_sqlite_key_changed(
+        &mut self,
+        ctx: &egui::Context,
+        conn_name: String,
+        new_key: String,
+        result: Result<(), String>,
+    ) {
+        match result {
+            Ok(()) => {
+                // 同步更新保存的密钥，否则下次将无法打开数据库
+                if let Some(conn) = self.manager.connections.get_mut(&conn_name) {
+                    conn.config.sqlite_key = new_key;
+                }
+                self.save_config();
+                self.notifications.success(tf("notify.key_changed", &[&conn_name]));
+            }
+            Err(e) => {
+                self.notifications.error(tf("notify.key_change_failed", &[&e]));
+            }
+        }
+        ctx.request_repaint();
+    }
+
+    /// 处理触发器/存储过程 DDL 执行完成消息
+    fn handle_object_ddl_done(&mut self, ctx: &egui::Context, result: Result<(), String>) {
+        self.executing = false;
+        match result {
+            Ok(()) => {
+                self.notifications.success(t("notify.ddl_done"));
+                // 重新加载列表以反映新建、修改或删除的对象
+                self.load_triggers();
+                self.load_routines();
+                self.load_sequences();
+            }
+            Err(e) => {
+                self.notifications.error(tf("notify.ddl_failed", &[&e]));
+            }
+        }
+        ctx.request_repaint();
+    }
+
+    /// 处理表格保存完成消息：记录历史，成功后重新加载表格
+    fn handle_grid_save_done(
+        &mut self,
+        ctx: &egui::Context,
+        sql: String,
+        transaction: bool,
+        result: Result<u64, String>,
+        elapsed_ms: u64,
+    ) {
+        use crate::core::QueryHistoryItem;
+
+        self.executing = false;
+        let db_type = self
+            .manager
+            .get_active()
+            .map(|c| c.config.db_type.display_name().to_string())
+            .unwrap_or_default();
+        let connection = self.manager.active.clone();
+
+        match result {
+            Ok(affected) => {
+                self.query_history.add(QueryHistoryItem {
+                    rows_affected: Some(affected),
+                    elapsed_ms: Some(elapsed_ms),
+                    ..QueryHistoryItem::new(sql, db_type, connection, true)
+                });
+                self.notifications.success(tf("notify.grid_saved", &[&affected.to_string(), &elapsed_ms.to_string()]));
+                if let Some(table) = self.selected_table.clone() {
+                    self.preview_table(&table);
+                }
+            }
+            Err(e) => {
+                self.query_history.add(QueryHistoryItem {
+                    elapsed_ms: Some(elapsed_ms),
+                    ..QueryHistoryItem::new(sql, db_type, connection, false)
+                });
+                if transaction {
+                    self.notifications.error(tf("notify.grid_save_rolled_back", &[&e]));
+                } else {
+                    self.notifications.error(tf("notify.grid_save_failed", &[&e]));
+                }
+            }
+        }
+        self.save_query_history();
+        ctx.request_repaint();
+    }
+
+    /// 处理权限管理用户列表获取完成消息
+    fn handle_privilege_users_fetched(&mut self, ctx: &egui::Context, result: Result<Vec<String>, String>) {
+        match result {
+            Ok(users) => self.privilege_dialog_state.set_users(users),
+            Err(e) => {
+                self.privilege_dialog_state.set_users(Vec::new());
+                self.privilege_dialog_state.error = Some(format!("加载用户失败: {}", e));
+            }
+        }
+        ctx.request_repaint();
+    }
+
+    /// 处理用户权限获取完成消息
+    fn handle_grants_fetched(
+        &mut self,
+        ctx: &egui::Context,
+        user: String,
+        result: Result<Vec<crate::database::GrantInfo>, String>,
+    ) {
+        match result {
+            Ok(grants) => self.privilege_dialog_state.set_grants(&user, grants),
+            Err(e) => {
+                self.privilege_dialog_state.set_grants(&user, Vec::new());
+                self.privilege_dialog_state.error = Some(format!("加载权限失败: {}", e));
+            }
+        }
+        ctx.request_repaint();
+    }
+
+    /// 处理 GRANT/REVOKE 执行完成消息
+    fn handle_privileges_applied(&mut self, ctx: &egui::Context, result: Result<(), String>) {
+        self.executing = false;
+        match result {
+            Ok(()) => {
+                self.notifications.success(t("notify.privileges_updated"));
+            }
+            Err(e) => {
+                self.notifications.error(tf("notify.privileges_failed", &[&e]));
+            }
+        }
+        // 部分语句可能已执行，无论成功与否都重新加载实际权限
+        if self.privilege_dialog_state.show
+            && let Some(user) = self.privilege_dialog_state.selected_user.clone()
+        {
+            self.privilege_dialog_state.select_user(user.clone());
+            self.load_grants(user);
+        }
+        ctx.request_repaint();
+    }
+
+    /// 处理表操作完成消息：提示结果并刷新表列表
+    fn handle_table_action_done(
+        &mut self,
+        ctx: &egui::Context,
+        action: ui::TableAction,
+        table: String,
+        result: Result<(), String>,
+    ) {
+        self.executing = false;
+        match result {
+            Ok(()) => {
+                self.notifications.success(tf("notify.table_action_done", &[action.display_name(), &table]));
+                match action {
+                    // 原表已不存在，清除选中状态和结果
+                    ui::TableAction::Rename | ui::TableAction::Drop => {
+                        if let Some(target) = self.recent_target(Some(&table)) {
+                            self.app_config.recent_objects.remove(&target);
+                            self.app_config.filter_presets.remove_table(
+                                &target.connection,
+                                target.database.as_deref(),
+                                &table,
+                            );
+                            self.app_config.column_formats.remove_table(
+                                &target.connection,
+                                target.database.as_deref(),
+                                &table,
+                            );
+                            self.app_config.column_widths.remove_table(
+                                &target.connection,
+                                target.database.as_deref(),
+                                &table,
+                            );
+                            self.app_config.column_orders.remove_table(
+                                &target.connection,
+                                target.database.as_deref(),
+                                &table,
+                            );
+                            self.app_config.row_bookmarks.remove_table(
+                                &target.connection,
+                                target.database.as_deref(),
+                                &table,
+                            );
+                        }
+                        if self.selected_table.as_deref() == Some(table.as_str()) {
+                            self.selected_table = None;
+                            self.set_result(None);
+                        }
+                        self.request_metadata_refresh(vec![table]);
+                    }
+                    ui::TableAction::Duplicate => self.request_metadata_refresh(Vec::new()),
+                    // 正在查看该表时重新加载（已为空）
+                    ui::TableAction::Truncate => {
+                        if self.selected_table.as_deref() == Some(table.as_str()) {
+                            self.handle_query_table(table);
                         }
                     }
                 }
             }
             Err(e) => {
-                self.notifications.warning(format!("获取表 {} 结构失败: {}", table_name, e));
+                self.notifications.error(tf("notify.table_action_failed", &[action.display_name(), &table, &e]));
             }
         }
         ctx.request_repaint();
     }
+
+    /// 处理测试数据生成完成消息
+    fn handle_datagen_done(
+        &mut self,
+        ctx: &egui::Context,
+        progress_id: u64,
+        table: String,
+        inserted: usize,
+        error: Option<String>,
+    ) {
+        // 用户取消时进度任务已被移除
+        let cancelled = self.progress.get(progress_id).is_none();
+        self.progress.finish(progress_id);
+        match error {
+            Some(e) => {
+                self.notifications.error(tf("notify.datagen_failed", &[&inserted.to_string(), &e]));
+            }
+            None if cancelled => {
+                self.notifications.warning(tf("notify.datagen_cancelled", &[&table, &inserted.to_string()]));
+            }
+            None => {
+                self.notifications.success(tf("notify.datagen_done", &[&table, &inserted.to_string()]));
+            }
+        }
+        if inserted > 0 && self.selected_table.as_deref() == Some(table.as_str()) {
+            self.handle_query_table(table);
+        }
+        ctx.request_repaint();
+    }
+
+    /// 处理终止会话完成消息
+    fn handle_session_killed(&mut self, ctx: &egui::Context, result: Result<(), String>) {
+        match result {
+            Ok(()) => {
+                self.notifications.success(t("notify.session_kill_sent"));
+            }
+            Err(e) => {
+                self.notifications.error(tf("notify.session_kill_failed", &[&e]));
+            }
+        }
+        if self.session_monitor_state.show {
+            self.load_sessions();
+        }
+        ctx.request_repaint();
+    }
+
+    /// 处理维护操作完成消息
+    fn handle_maintenance_done(
+        &mut self,
+        ctx: &egui::Context,
+        label: String,
+        progress_id: u64,
+        result: Result<Vec<String>, String>,
+        elapsed_ms: u64,
+    ) {
+        self.notifications.dismiss(progress_id);
+        match result {
+            Ok(errors) if errors.is_empty() => {
+                self.notifications.success(tf("notify.maintenance_done", &[&label, &elapsed_ms.to_string()]));
+            }
+            Ok(errors) => {
+                self.notifications.warning(tf("notify.maintenance_partial", &[&label, &errors.join("\n")]));
+            }
+            Err(e) => {
+                self.notifications.error(tf("notify.maintenance_failed", &[&label, &e]));
+            }
+        }
+        ctx.request_repaint();
+    }
+}
+
+/// 查询完成时显示的消息
+pub(super) fn query_done_message(
+    res: &crate::database::QueryResult,
+    original_rows: usize,
+    was_truncated: bool,
+    elapsed_ms: u64,
+) -> String {
+    if res.columns.is_empty() {
+        tf("notify.statement_done", &[&res.affected_rows.to_string(), &elapsed_ms.to_string()])
+    } else if was_truncated {
+        let args = [res.rows.len().to_string(), original_rows.to_string(), elapsed_ms.to_string()];
+        tf("notify.query_done_truncated", &[&args[0], &args[1], &args[2]])
+    } else {
+        tf("notify.query_done", &[&res.rows.len().to_string(), &elapsed_ms.to_string()])
+    }
 }
@@ -0,0 +1,122 @@
+//! 连接保活检查和空闲连接池清理
+//!
+//! 按首选项中的间隔在后台向每个已连接的连接发送轻量查询，结果显示在侧边栏的连接状态圆点上；
+//! 连接失效时先透明重连，仍然失败则提示用户重新连接。
+//! 切换数据库等留下的连接池空闲超过首选项中的时间后自动断开。
+
+use std::time::{Duration, Instant};
+
+use eframe::egui;
+
+use crate::core::{constants, tf, HealthStatus};
+use crate::database::{ping, ping_statement, POOL_MANAGER};
+use super::{DbManagerApp, Message};
+
+impl DbManagerApp {
+    /// 对到期的连接开始保活检查，未到期时安排下次检查的重绘
+    pub(super) fn run_health_checks(&mut self, ctx: &egui::Context) {
+        let interval = self.app_config.keep_alive_secs;
+        if interval == 0 || self.connecting {
+            return;
+        }
+
+        let now = Instant::now();
+        let mut next_check: Option<Duration> = None;
+        for (name, conn) in self.manager.connections.iter_mut() {
+            if !conn.connected || ping_statement(conn.config.db_type).is_none() {
+                continue;
+            }
+            if !conn.keep_alive.is_due(interval, now) {
+                if let Some(wait) = conn.keep_alive.time_until_next(interval, now) {
+                    next_check = Some(next_check.map_or(wait, |w| w.min(wait)));
+                }
+                continue;
+            }
+
+            conn.keep_alive.start(now);
+            let config = conn
+                .config
+                .clone()
+                .with_default_timeouts(self.app_config.connect_timeout_secs, self.app_config.query_timeout_secs);
+            let timeout_secs = config.connect_timeout_secs();
+            let name = name.clone();
+            let tx = self.tx.clone();
+            self.runtime.spawn(async move {
+                use tokio::time::timeout;
+                let result = match timeout(Duration::from_secs(timeout_secs), ping(&config)).await {
+                    Ok(Ok(outcome)) => Ok(outcome),
+                    Ok(Err(e)) => Err(e.to_string()),
+                    Err(_) => Err(format!("保活检查超时 ({}秒)", timeout_secs)),
+                };
+                if tx.send(Message::HealthChecked(name, result)).is_err() {
+                    tracing::warn!("无法发送保活检查结果：接收端已关闭");
+                }
+            });
+        }
+
+        if let Some(wait) = next_check {
+            ctx.request_repaint_after(wait);
+        }
+    }
+
+    /// 定期断开空闲超过首选项中时间的连接池
+    pub(super) fn run_pool_cleanup(&mut self, ctx: &egui::Context) {
+        let ttl = self.app_config.pool_idle_ttl_secs;
+        if ttl == 0 {
+            return;
+        }
+        let interval = Duration::from_secs(constants::database::POOL_CLEANUP_INTERVAL_SECS);
+        let elapsed = self.last_pool_cleanup.elapsed();
+        if elapsed < interval {
+            ctx.request_repaint_after(interval - elapsed);
+            return;
+        }
+
+        self.last_pool_cleanup = Instant::now();
+        self.runtime.spawn(async move {
+            POOL_MANAGER.close_idle(Duration::from_secs(ttl)).await;
+        });
+        ctx.request_repaint_after(interval);
+    }
+
+    /// 立即断开所有没有查询在使用的连接池
+    pub(super) fn close_idle_pools(&mut self) {
+        let tx = self.tx.clone();
+        self.runtime.spawn(async move {
+            let closed = POOL_MANAGER.close_idle(Duration::ZERO).await;
+            if tx.send(Message::IdlePoolsClosed(closed)).is_err() {
+                tracing::warn!("无法发送连接池清理结果：接收端已关闭");
+            }
+        });
+    }
+
+    /// 处理保活检查结果：更新状态，连接断开或恢复时通知
+    pub(super) fn handle_health_checked(
+        &mut self,
+        ctx: &egui::Context,
+        name: String,
+        result: Result<(u64, bool), String>,
+    ) {
+        let Some(conn) = self.manager.connections.get_mut(&name) else {
+            return;
+        };
+        // 检查期间已断开的连接不再更新
+        if !conn.connected {
+            return;
+        }
+        let before = conn.keep_alive.status();
+        let reconnected = matches!(result, Ok((_, true)));
+        let changed = conn.keep_alive.record(result.map(|(latency_ms, _)| latency_ms));
+        let status = conn.keep_alive.status();
+        let error = conn.keep_alive.last_error().unwrap_or_default().to_string();
+
+        if changed && status == HealthStatus::Down {
+            self.notifications.error(tf("notify.connection_lost", &[&name, &error]));
+        } else if before == HealthStatus::Down && status != HealthStatus::Down {
+            self.notifications.success(tf("notify.connection_recovered", &[&name]));
+        } else if reconnected {
+            self.notifications.info(tf("notify.connection_reconnected", &[&name]));
+        }
+        ctx.request_repaint();
+    }
+}
@@ -4,17 +4,28 @@
 
 use crate::core::{
     import_csv_to_sql, import_json_to_sql, preview_csv, preview_json,
-    CsvImportConfig, JsonImportConfig,
+    t, tf, AuditSource, CsvImportConfig, JsonImportConfig, UpsertSyntax,
 };
 use crate::ui;
 
 use super::DbManagerApp;
 
 impl DbManagerApp {
-    /// 打开导入对话框
+    /// 打开导入对话框（upsert 的冲突键默认为当前表的主键）
     pub(super) fn handle_import(&mut self) {
         self.show_import_dialog = true;
         self.import_state.clear();
+        self.import_state.upsert_syntax = self
+            .manager
+            .get_active()
+            .and_then(|c| UpsertSyntax::for_db(c.config.db_type));
+        if let Some(pk) = self
+            .grid_state
+            .primary_key_column
+            .and_then(|idx| self.result.as_ref()?.columns.get(idx).cloned())
+        {
+            self.import_state.upsert_keys = pk;
+        }
     }
     
     /// 选择导入文件
@@ -117,6 +128,13 @@ impl DbManagerApp {
         };
         
         let is_mysql = self.is_mysql();
+        let upsert = match self.import_state.upsert_config() {
+            Ok(upsert) => upsert,
+            Err(e) => {
+                self.notifications.warning(e);
+                return;
+            }
+        };
         
         let statements: Vec<String> = match self.import_state.format {
             ui::ImportFormat::Sql => {
@@ -133,13 +151,14 @@ impl DbManagerApp {
                     has_header: self.import_state.csv_config.has_header,
                     quote_char: self.import_state.csv_config.quote_char,
                     table_name: self.import_state.csv_config.table_name.clone(),
+                    upsert,
                     ..Default::default()
                 };
                 
                 match import_csv_to_sql(path, &config, is_mysql) {
                     Ok(result) => result.sql_statements,
                     Err(e) => {
-                        self.notifications.error(format!("CSV 转换失败: {}", e));
+                        self.notifications.error(tf("notify.csv_convert_failed", &[&e]));
                         return;
                     }
                 }
@@ -152,13 +171,14 @@ impl DbManagerApp {
                         Some(self.import_state.json_config.json_path.clone())
                     },
                     table_name: self.import_state.json_config.table_name.clone(),
+                    upsert,
                     ..Default::default()
                 };
                 
                 match import_json_to_sql(path, &config, is_mysql) {
                     Ok(result) => result.sql_statements,
                     Err(e) => {
-                        self.notifications.error(format!("JSON 转换失败: {}", e));
+                        self.notifications.error(tf("notify.json_convert_failed", &[&e]));
                         return;
                     }
                 }
@@ -166,7 +186,7 @@ impl DbManagerApp {
         };
         
         if statements.is_empty() {
-            self.notifications.warning("没有可执行的 SQL 语句");
+            self.notifications.warning(t("notify.import_no_statements"));
             return;
         }
         
@@ -184,30 +204,27 @@ impl DbManagerApp {
         let valid_count = valid_statements.len();
         
         if valid_count == 0 {
-            self.notifications.warning("没有有效的 SQL 语句");
+            self.notifications.warning(t("notify.import_no_valid_statements"));
             return;
         }
         
         // 开始事务（如果启用）
         if use_transaction {
-            self.execute("BEGIN".to_string());
+            self.execute_from("BEGIN".to_string(), AuditSource::Import);
         }
         
         // 批量执行所有语句
         for stmt in valid_statements {
-            self.execute(stmt);
+            self.execute_from(stmt, AuditSource::Import);
         }
         
         // 提交事务（如果启用）
         if use_transaction {
-            self.execute("COMMIT".to_string());
+            self.execute_from("COMMIT".to_string(), AuditSource::Import);
         }
         
-        self.notifications.info(format!(
-            "导入中: {} 条语句已提交执行（使用事务: {}）",
-            valid_count,
-            if use_transaction { "是" } else { "否" }
-        ));
+        let key = if use_transaction { "notify.import_started_transaction" } else { "notify.import_started" };
+        self.notifications.info(tf(key, &[&valid_count.to_string()]));
         
         self.import_state.clear();
     }
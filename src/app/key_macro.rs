@@ -0,0 +1,97 @@
+//! 键盘宏
+//!
+//! 表格或 SQL 编辑器处于 Normal 模式时，`Q` 开始/停止录制，`@` 回放（表格中可加计数前缀，如 `3@`）。
+//! 录制的是原始键盘事件，回放时逐帧注入，由表格和编辑器按正常的按键处理执行。
+
+use eframe::egui;
+use crate::core::{t, tf};
+use crate::ui;
+
+use super::DbManagerApp;
+
+impl DbManagerApp {
+    /// 注入回放的事件，或录制当前帧的键盘事件（在每帧处理输入之前调用）
+    pub(super) fn key_macro_input(&mut self, ctx: &egui::Context, raw_input: &mut egui::RawInput) {
+        if let Some(events) = self.key_macro.next_frame() {
+            // 使用录制时的修饰键，避免回放时仍按住的 Shift 改变按键的含义
+            let modifiers = events.iter().find_map(|e| match e {
+                egui::Event::Key { modifiers, .. } => Some(*modifiers),
+                _ => None,
+            });
+            if let Some(modifiers) = modifiers {
+                raw_input.modifiers = modifiers;
+            }
+            raw_input.events.extend(events);
+            ctx.request_repaint();
+        } else {
+            self.key_macro.record_frame(&raw_input.events);
+        }
+    }
+
+    /// 处理宏的录制和回放按键
+    pub(super) fn handle_macro_keys(&mut self, ctx: &egui::Context) {
+        let in_normal_mode = match self.focus_area {
+            ui::FocusArea::DataGrid => {
+                self.grid_state.mode == ui::GridMode::Normal && self.grid_state.command_buffer.is_empty()
+            }
+            ui::FocusArea::SqlEditor => self.editor_mode == ui::EditorMode::Normal,
+            _ => false,
+        };
+        // 回放的事件中不处理宏按键，避免宏调用自身
+        if !in_normal_mode || self.key_macro.is_replaying() {
+            return;
+        }
+
+        let (toggle, replay) = ctx.input(|i| {
+            (
+                i.key_pressed(egui::Key::Q) && i.modifiers.shift && !i.modifiers.ctrl,
+                i.events.iter().any(|e| matches!(e, egui::Event::Text(t) if t == "@")),
+            )
+        });
+
+        if toggle {
+            if self.key_macro.is_recording() {
+                let keys = self.key_macro.stop();
+                if keys > 0 {
+                    self.notifications.success(tf("notify.macro_recorded", &[&keys.to_string()]));
+                } else {
+                    self.notifications.info(t("notify.macro_empty"));
+                }
+            } else if self.key_macro.start() {
+                self.notifications.info(t("notify.macro_recording"));
+            }
+        } else if replay && !self.key_macro.is_recording() {
+            // 表格中的计数前缀作为回放次数
+            let count = match self.focus_area {
+                ui::FocusArea::DataGrid => self.grid_state.count.take(),
+                _ => None,
+            }
+            .unwrap_or(1);
+            if self.key_macro.replay(count) {
+                self.notifications.info(tf("notify.macro_replay", &[&count.to_string()]));
+            } else {
+                self.notifications.warning(t("notify.macro_none"));
+            }
+        }
+    }
+
+    /// 录制宏时在窗口右下角显示录制标记
+    pub(super) fn show_macro_indicator(&self, ctx: &egui::Context) {
+        if !self.key_macro.is_recording() {
+            return;
+        }
+        egui::Area::new(egui::Id::new("key_macro_indicator"))
+            .anchor(egui::Align2::RIGHT_BOTTOM, [-12.0, -12.0])
+            .order(egui::Order::Foreground)
+            .interactable(false)
+            .show(ctx, |ui| {
+                egui::Frame::popup(ui.style()).show(ui, |ui| {
+                    ui.label(
+                        egui::RichText::new("● 正在录制宏 (Q 停止)")
+                            .small()
+                            .color(egui::Color32::from_rgb(235, 80, 80)),
+                    );
+                });
+            });
+    }
+}
@@ -3,108 +3,137 @@
 //! 集中管理所有键盘快捷键的处理逻辑。
 
 use eframe::egui;
-use crate::ui;
+use crate::core::{t, tf, Action};
+use crate::database::DriverCapabilities;
+use crate::ui::{self, ToolbarActions};
 
 use super::DbManagerApp;
 
 impl DbManagerApp {
     /// 处理键盘快捷键
-    pub(super) fn handle_keyboard_shortcuts(&mut self, ctx: &egui::Context) {
+    ///
+    /// 可配置的快捷键通过 `KeyBindings` 查找触发的操作后统一分发，
+    /// Tab 焦点循环和 Esc 关闭面板固定不变。
+    pub(super) fn handle_keyboard_shortcuts(&mut self, ctx: &egui::Context, toolbar_actions: &mut ToolbarActions) {
+        // 正在录制快捷键时不响应任何快捷键
+        if self.keybindings_dialog_state.is_recording() {
+            return;
+        }
+
         // 检查是否有模态对话框打开
         let has_dialog = self.has_modal_dialog_open();
 
-        ctx.input(|i| {
-            // ===== 始终可用的快捷键（即使对话框打开） =====
-            
-            // F1: 帮助（切换）
-            if i.key_pressed(egui::Key::F1) {
-                self.show_help = !self.show_help;
-            }
+        let (triggered, cycle_focus, escape) = ctx.input(|i| {
+            (
+                self.keybindings.triggered(i),
+                // Tab: 焦点循环导航（侧边栏 -> 数据表格 -> SQL编辑器 -> 侧边栏）
+                (!i.modifiers.ctrl && !i.modifiers.alt && i.key_pressed(egui::Key::Tab)).then_some(i.modifiers.shift),
+                i.key_pressed(egui::Key::Escape),
+            )
+        });
 
-            // ===== 对话框打开时跳过以下快捷键 =====
-            if has_dialog {
-                return;
+        for action in triggered {
+            // 缩放快捷键由 handle_zoom_shortcuts 处理（对话框打开时也可用）
+            if matches!(action, Action::ZoomIn | Action::ZoomOut | Action::ZoomReset) {
+                continue;
             }
+            // 对话框打开时只响应帮助和主题选择器
+            if has_dialog && !matches!(action, Action::ShowHelp | Action::ShowThemeSelector) {
+                continue;
+            }
+            self.run_action(action, toolbar_actions);
+        }
 
-            // Ctrl+N: 新建连接
-            if i.modifiers.ctrl && !i.modifiers.shift && i.key_pressed(egui::Key::N) {
-                self.show_connection_dialog = true;
-            }
-            
-            // Ctrl+Shift+N: 新建表
-            if i.modifiers.ctrl && i.modifiers.shift && i.key_pressed(egui::Key::N)
-                && let Some(conn) = self.manager.get_active()
-                && conn.selected_database.is_some()
-            {
-                let db_type = conn.config.db_type;
-                self.ddl_dialog_state.open_create_table(db_type);
+        // ===== 对话框打开时跳过以下快捷键 =====
+        // SQL 编辑器命令行自行处理 Tab 补全和 Esc 取消
+        if has_dialog || self.editor_mode == ui::EditorMode::Command {
+            return;
+        }
+
+        if let Some(reverse) = cycle_focus {
+            self.cycle_focus(reverse);
+        }
+
+        // Q 录制宏，@ 回放
+        self.handle_macro_keys(ctx);
+
+        // Escape: 取消当前操作/关闭面板
+        if escape {
+            // 优先关闭帮助面板
+            if self.show_help {
+                self.show_help = false;
+            } else if self.show_history_panel {
+                self.show_history_panel = false;
+            } else if self.show_er_diagram {
+                self.show_er_diagram = false;
             }
+        }
+    }
 
-            // Ctrl+Shift+D: 新建数据库
-            if i.modifiers.ctrl && i.modifiers.shift && i.key_pressed(egui::Key::D) {
+    /// 执行快捷键对应的操作
+    pub(super) fn run_action(&mut self, action: Action, toolbar_actions: &mut ToolbarActions) {
+        match action {
+            Action::ShowHelp => self.show_help = !self.show_help,
+            Action::NewConnection => self.show_connection_dialog = true,
+            Action::NewTable => {
+                if let Some(conn) = self.manager.get_active()
+                    && conn.selected_database.is_some()
+                {
+                    let db_type = conn.config.db_type;
+                    if db_type.uses_sql() {
+                        self.ddl_dialog_state.open_create_table(db_type);
+                    } else {
+                        self.notifications.warning(tf("notify.create_table_unsupported", &[db_type.display_name()]));
+                    }
+                }
+            }
+            Action::NewDatabase => {
                 let db_type = self.manager.get_active()
                     .map(|c| c.config.db_type)
                     .unwrap_or_default();
                 self.create_db_dialog_state.open(db_type);
             }
-
-            // Ctrl+Shift+U: 新建用户
-            if i.modifiers.ctrl && i.modifiers.shift && i.key_pressed(egui::Key::U) {
+            Action::NewUser => {
                 if let Some(conn) = self.manager.get_active() {
                     let db_type = conn.config.db_type;
-                    if matches!(db_type, crate::database::DatabaseType::SQLite) {
-                        self.notifications.warning("SQLite 不支持用户管理");
+                    if !DriverCapabilities::for_db_type(db_type).user_management {
+                        self.notifications.warning(tf("notify.users_unsupported", &[db_type.display_name()]));
                     } else {
                         let databases = conn.databases.clone();
                         self.create_user_dialog_state.open(db_type, databases);
                     }
                 } else {
-                    self.notifications.warning("请先连接数据库");
+                    self.notifications.warning(t("common.need_connection"));
                 }
             }
-
-            // Ctrl+E: 导出
-            if i.modifiers.ctrl && i.key_pressed(egui::Key::E) && self.result.is_some() {
-                self.show_export_dialog = true;
-                self.export_status = None;
-            }
-
-            // Ctrl+I: 导入
-            if i.modifiers.ctrl && i.key_pressed(egui::Key::I) {
-                self.handle_import();
-            }
-
-            // Ctrl+H: 历史记录
-            if i.modifiers.ctrl && i.key_pressed(egui::Key::H) {
-                self.show_history_panel = !self.show_history_panel;
+            Action::ManagePrivileges => self.open_privilege_manager(),
+            Action::Export => {
+                if self.result.is_some() {
+                    self.open_export_dialog();
+                }
             }
-
-            // Ctrl+R: 切换 ER 关系图
-            if i.modifiers.ctrl && !i.modifiers.shift && i.key_pressed(egui::Key::R) {
+            Action::Import => self.handle_import(),
+            Action::ShowHistory => self.show_history_panel = !self.show_history_panel,
+            Action::ToggleErDiagram => {
                 self.show_er_diagram = !self.show_er_diagram;
                 if self.show_er_diagram {
                     self.load_er_diagram_data();
-                    self.notifications.info("ER 关系图已打开");
+                    self.notifications.info(t("notify.er_opened"));
                 } else {
-                    self.notifications.info("ER 关系图已关闭");
+                    self.notifications.info(t("notify.er_closed"));
                 }
             }
-
-            // F5: 刷新表列表
-            if i.key_pressed(egui::Key::F5)
-                && let Some(name) = self.manager.active.clone()
-            {
-                self.connect(name);
+            // 刷新表列表
+            Action::Refresh => {
+                if let Some(name) = self.manager.active.clone() {
+                    self.connect(name);
+                }
             }
-
-            // Ctrl+L: 清空命令行
-            if i.modifiers.ctrl && i.key_pressed(egui::Key::L) {
+            Action::ClearCommandLine => {
                 self.sql.clear();
                 self.notifications.dismiss_all();
             }
-
-            // Ctrl+J: 切换 SQL 编辑器显示
-            if i.modifiers.ctrl && i.key_pressed(egui::Key::J) {
+            Action::ToggleEditor => {
                 self.show_sql_editor = !self.show_sql_editor;
                 if self.show_sql_editor {
                     // 打开时自动聚焦到编辑器
@@ -117,9 +146,7 @@ impl DbManagerApp {
                     self.grid_state.focused = true;
                 }
             }
-
-            // Ctrl+B: 切换侧边栏显示
-            if i.modifiers.ctrl && i.key_pressed(egui::Key::B) {
+            Action::ToggleSidebar => {
                 self.show_sidebar = !self.show_sidebar;
                 if self.show_sidebar {
                     // 打开侧边栏时聚焦到侧边栏
@@ -131,154 +158,154 @@ impl DbManagerApp {
                     self.grid_state.focused = true;
                 }
             }
-
-            // Ctrl+K: 清空搜索
-            if i.modifiers.ctrl && i.key_pressed(egui::Key::K) {
-                self.search_text.clear();
+            Action::ClearSearch => self.search_text.clear(),
+            Action::AddFilter => {
+                if let Some(result) = &self.result
+                    && let Some(col) = result.columns.first()
+                {
+                    self.grid_state.filters.push(ui::components::ColumnFilter::new(col.clone()));
+                }
             }
-
-            // Ctrl+F: 添加筛选条件
-            if i.modifiers.ctrl && i.key_pressed(egui::Key::F) && !i.modifiers.shift
-                && let Some(result) = &self.result
-                    && let Some(col) = result.columns.first() {
-                        self.grid_state.filters.push(ui::components::ColumnFilter::new(col.clone()));
-                    }
-
-            // Ctrl+Shift+F: 清空筛选条件
-            if i.modifiers.ctrl && i.modifiers.shift && i.key_pressed(egui::Key::F) {
-                self.grid_state.filters.clear();
+            Action::ClearFilters => self.grid_state.filters.clear(),
+            Action::AutoFitColumns => {
+                self.grid_state.column_widths.clear();
+                self.save_column_settings();
             }
-
-            // Ctrl+S: 触发保存表格修改
-            if i.modifiers.ctrl && i.key_pressed(egui::Key::S) {
-                self.grid_state.pending_save = true;
+            // 编辑 SQL 文件时保存文件，否则保存表格修改
+            Action::Save => {
+                if self.saves_sql_file() {
+                    self.save_sql_file(false);
+                } else {
+                    self.grid_state.pending_save = true;
+                }
             }
-
-            // Ctrl+G: 跳转到行
-            if i.modifiers.ctrl && i.key_pressed(egui::Key::G) {
-                self.grid_state.show_goto_dialog = true;
+            Action::GotoLine => self.grid_state.show_goto_dialog = true,
+            Action::NewTab => {
+                self.tab_manager.new_tab();
+                if let Some(tab) = self.tab_manager.get_active() {
+                    self.sql = tab.sql.clone();
+                    self.grid_state.page = tab.page.clone();
+                    let result = tab.result.clone();
+                    self.set_result(result);
+                }
             }
-
-            // Ctrl+Tab: 下一个查询标签页
-            if i.modifiers.ctrl && !i.modifiers.shift && i.key_pressed(egui::Key::Tab) {
+            Action::NextTab => {
                 self.tab_manager.next_tab();
+                self.activate_tab(self.tab_manager.active_index);
             }
-
-            // Ctrl+Shift+Tab: 上一个查询标签页
-            if i.modifiers.ctrl && i.modifiers.shift && i.key_pressed(egui::Key::Tab) {
+            Action::PrevTab => {
                 self.tab_manager.prev_tab();
+                self.activate_tab(self.tab_manager.active_index);
             }
-
-            // Ctrl+W: 关闭当前查询标签页
-            if i.modifiers.ctrl && i.key_pressed(egui::Key::W) {
+            Action::CloseTab => {
                 self.tab_manager.close_active_tab();
-            }
+                self.forget_closed_tab_queries();
+                self.trash_closed_tabs();
+                self.activate_tab(self.tab_manager.active_index);
+            }
+            Action::ReopenTab => self.reopen_closed_tab(),
+            Action::RenameTab => self.start_tab_rename(self.tab_manager.active_index),
+            Action::PinTab => self.toggle_tab_pin(self.tab_manager.active_index),
+            Action::OpenFile => self.open_sql_file(),
+            Action::SaveFileAs => self.save_sql_file(true),
+            Action::ToggleDarkMode => toolbar_actions.toggle_dark_mode = true,
+            Action::ShowThemeSelector => toolbar_actions.open_theme_selector = true,
+            Action::ShowPreferences => toolbar_actions.show_preferences = true,
+            Action::ShowKeyBindings => self.keybindings_dialog_state.open(&self.keybindings),
+            Action::ShowCommandPalette => self.open_command_palette(),
+            Action::ShowQuickSwitcher => self.open_quick_switcher(),
+            Action::ShowDataSearch => toolbar_actions.show_data_search = true,
+            Action::SidebarConnections => self.switch_sidebar_section(ui::SidebarSection::Connections),
+            Action::SidebarDatabases => self.switch_sidebar_section(ui::SidebarSection::Databases),
+            Action::SidebarTables => self.switch_sidebar_section(ui::SidebarSection::Tables),
+            Action::SidebarFilters => self.switch_sidebar_section(ui::SidebarSection::Filters),
+            Action::SidebarTriggers => self.switch_sidebar_section(ui::SidebarSection::Triggers),
+            Action::SidebarRoutines => self.switch_sidebar_section(ui::SidebarSection::Routines),
+            Action::SidebarSequences => self.switch_sidebar_section(ui::SidebarSection::Sequences),
+            Action::ZoomIn => toolbar_actions.zoom_in = true,
+            Action::ZoomOut => toolbar_actions.zoom_out = true,
+            Action::ZoomReset => toolbar_actions.zoom_reset = true,
+        }
+    }
 
-            // Ctrl+D: 切换日/夜模式
-            if i.modifiers.ctrl && !i.modifiers.shift && i.key_pressed(egui::Key::D) {
-                self.pending_toggle_dark_mode = true;
-            }
+    /// 快速切换到侧边栏不同区域（再按一次关闭）
+    fn switch_sidebar_section(&mut self, s: ui::SidebarSection) {
+        // 数据库/表只做导航，不切换面板显示
+        // 连接/筛选/触发器/存储过程/序列切换对应面板的显示状态
+        let is_toggle_panel = matches!(s,
+            ui::SidebarSection::Connections |
+            ui::SidebarSection::Filters |
+            ui::SidebarSection::Triggers |
+            ui::SidebarSection::Routines |
+            ui::SidebarSection::Sequences
+        );
+
+        let panel_visible = match s {
+            ui::SidebarSection::Connections => self.sidebar_panel_state.show_connections,
+            ui::SidebarSection::Databases | ui::SidebarSection::Tables => self.sidebar_panel_state.show_connections,
+            ui::SidebarSection::Filters => self.sidebar_panel_state.show_filters,
+            ui::SidebarSection::Triggers => self.sidebar_panel_state.show_triggers,
+            ui::SidebarSection::Routines => self.sidebar_panel_state.show_routines,
+            ui::SidebarSection::Sequences => self.sidebar_panel_state.show_sequences,
+        };
 
-            // Tab: 焦点循环导航（侧边栏 -> 数据表格 -> SQL编辑器 -> 侧边栏）
-            if !i.modifiers.ctrl && !i.modifiers.alt && i.key_pressed(egui::Key::Tab) {
-                self.cycle_focus(i.modifiers.shift);
+        if is_toggle_panel && self.show_sidebar && self.sidebar_section == s && panel_visible {
+            // 当前已在该面板，切换关闭
+            match s {
+                ui::SidebarSection::Connections => {
+                    self.sidebar_panel_state.show_connections = false;
+                }
+                ui::SidebarSection::Filters => {
+                    self.sidebar_panel_state.show_filters = false;
+                }
+                ui::SidebarSection::Triggers => {
+                    self.sidebar_panel_state.show_triggers = false;
+                }
+                ui::SidebarSection::Routines => {
+                    self.sidebar_panel_state.show_routines = false;
+                }
+                ui::SidebarSection::Sequences => {
+                    self.sidebar_panel_state.show_sequences = false;
+                }
+                _ => {}
             }
+            return;
+        }
 
-            // Ctrl+1-6: 快速切换到侧边栏不同区域（再按一次关闭）
-            if i.modifiers.ctrl && !i.modifiers.shift {
-                let section = if i.key_pressed(egui::Key::Num1) {
-                    Some(ui::SidebarSection::Connections) // 1: 连接
-                } else if i.key_pressed(egui::Key::Num2) {
-                    Some(ui::SidebarSection::Databases)   // 2: 数据库
-                } else if i.key_pressed(egui::Key::Num3) {
-                    Some(ui::SidebarSection::Tables)      // 3: 表
-                } else if i.key_pressed(egui::Key::Num4) {
-                    Some(ui::SidebarSection::Filters)     // 4: 筛选
-                } else if i.key_pressed(egui::Key::Num5) {
-                    Some(ui::SidebarSection::Triggers)    // 5: 触发器
-                } else if i.key_pressed(egui::Key::Num6) {
-                    Some(ui::SidebarSection::Routines)    // 6: 存储过程
-                } else {
-                    None
-                };
-                
-                if let Some(s) = section {
-                    // Ctrl+2/3 (数据库/表) 只做导航，不切换面板显示
-                    // Ctrl+1/4/5/6 切换对应面板的显示状态
-                    let is_toggle_panel = matches!(s, 
-                        ui::SidebarSection::Connections | 
-                        ui::SidebarSection::Filters | 
-                        ui::SidebarSection::Triggers | 
-                        ui::SidebarSection::Routines
-                    );
-                    
-                    let panel_visible = match s {
-                        ui::SidebarSection::Connections => self.sidebar_panel_state.show_connections,
-                        ui::SidebarSection::Databases | ui::SidebarSection::Tables => self.sidebar_panel_state.show_connections,
-                        ui::SidebarSection::Filters => self.sidebar_panel_state.show_filters,
-                        ui::SidebarSection::Triggers => self.sidebar_panel_state.show_triggers,
-                        ui::SidebarSection::Routines => self.sidebar_panel_state.show_routines,
-                    };
-                    
-                    if is_toggle_panel && self.show_sidebar && self.sidebar_section == s && panel_visible {
-                        // 当前已在该面板，切换关闭（仅对 Ctrl+1/4/5/6）
-                        match s {
-                            ui::SidebarSection::Connections => {
-                                self.sidebar_panel_state.show_connections = false;
-                            }
-                            ui::SidebarSection::Filters => {
-                                self.sidebar_panel_state.show_filters = false;
-                            }
-                            ui::SidebarSection::Triggers => {
-                                self.sidebar_panel_state.show_triggers = false;
-                            }
-                            ui::SidebarSection::Routines => {
-                                self.sidebar_panel_state.show_routines = false;
-                            }
-                            _ => {}
-                        }
-                    } else {
-                        // 打开侧边栏并显示对应面板
-                        self.show_sidebar = true;
-                        self.focus_area = ui::FocusArea::Sidebar;
-                        self.sidebar_section = s;
-                        self.grid_state.focused = false;
-                        // 确保对应面板可见
-                        match s {
-                            ui::SidebarSection::Connections | ui::SidebarSection::Databases | ui::SidebarSection::Tables => {
-                                self.sidebar_panel_state.show_connections = true;
-                            }
-                            ui::SidebarSection::Filters => {
-                                self.sidebar_panel_state.show_filters = true;
-                            }
-                            ui::SidebarSection::Triggers => {
-                                self.sidebar_panel_state.show_triggers = true;
-                            }
-                            ui::SidebarSection::Routines => {
-                                self.sidebar_panel_state.show_routines = true;
-                            }
-                        }
-                    }
+        // 打开侧边栏并显示对应面板
+        self.show_sidebar = true;
+        self.focus_area = ui::FocusArea::Sidebar;
+        self.sidebar_section = s;
+        self.grid_state.focused = false;
+        self.focus_sql_editor = false;
+        // 确保对应面板可见
+        let name = match s {
+            ui::SidebarSection::Connections | ui::SidebarSection::Databases | ui::SidebarSection::Tables => {
+                self.sidebar_panel_state.show_connections = true;
+                match s {
+                    ui::SidebarSection::Connections => "连接列表",
+                    ui::SidebarSection::Databases => "数据库列表",
+                    _ => "表列表",
                 }
             }
-
-            // Alt+K: 打开快捷键设置对话框
-            if i.modifiers.alt && !i.modifiers.ctrl && i.key_pressed(egui::Key::K) {
-                self.keybindings_dialog_state.open(&self.keybindings);
+            ui::SidebarSection::Filters => {
+                self.sidebar_panel_state.show_filters = true;
+                "筛选面板"
             }
-
-            // Escape: 取消当前操作/关闭面板
-            if i.key_pressed(egui::Key::Escape) {
-                // 优先关闭帮助面板
-                if self.show_help {
-                    self.show_help = false;
-                } else if self.show_history_panel {
-                    self.show_history_panel = false;
-                } else if self.show_er_diagram {
-                    self.show_er_diagram = false;
-                }
+            ui::SidebarSection::Triggers => {
+                self.sidebar_panel_state.show_triggers = true;
+                "触发器列表"
             }
-        });
+            ui::SidebarSection::Routines => {
+                self.sidebar_panel_state.show_routines = true;
+                "存储过程列表"
+            }
+            ui::SidebarSection::Sequences => {
+                self.sidebar_panel_state.show_sequences = true;
+                "序列列表"
+            }
+        };
+        self.notifications.info(tf("notify.switched_to", &[name]));
     }
 
     /// 焦点循环导航
@@ -338,21 +365,24 @@ impl DbManagerApp {
 
     /// 处理缩放快捷键
     pub(super) fn handle_zoom_shortcuts(&mut self, ctx: &egui::Context) {
+        let zoom_in = self.keybindings.get(Action::ZoomIn).cloned();
+        let zoom_out = self.keybindings.get(Action::ZoomOut).cloned();
+        let zoom_reset = self.keybindings.get(Action::ZoomReset).cloned();
         let zoom_delta = ctx.input(|i| {
             let mut delta = 0.0f32;
 
-            // Ctrl++ 或 Ctrl+= 放大
-            if i.modifiers.ctrl && (i.key_pressed(egui::Key::Plus) || i.key_pressed(egui::Key::Equals)) {
+            // 放大（默认 Ctrl++ 或 Ctrl+=）
+            if zoom_in.as_ref().is_some_and(|b| b.matches(i)) {
                 delta = 0.1;
             }
 
-            // Ctrl+- 缩小
-            if i.modifiers.ctrl && i.key_pressed(egui::Key::Minus) {
+            // 缩小（默认 Ctrl+-）
+            if zoom_out.as_ref().is_some_and(|b| b.matches(i)) {
                 delta = -0.1;
             }
 
-            // Ctrl+0 重置缩放
-            if i.modifiers.ctrl && i.key_pressed(egui::Key::Num0) {
+            // 重置缩放（默认 Ctrl+0）
+            if zoom_reset.as_ref().is_some_and(|b| b.matches(i)) {
                 return Some(-999.0); // 特殊值表示重置
             }
 
@@ -0,0 +1,73 @@
+//! 敏感列脱敏
+//!
+//! 按连接保存标记为敏感的列，切换连接时载入表格；导出包含敏感列的原始值前需要确认。
+
+use crate::core::{t, tf};
+use crate::ui::ExportConfig;
+
+use super::DbManagerApp;
+
+impl DbManagerApp {
+    /// 当前连接变化后载入该连接的敏感列
+    pub(super) fn sync_masked_columns(&mut self) {
+        if self.masking_connection == self.manager.active {
+            return;
+        }
+        self.grid_state.masked_columns = self
+            .manager
+            .active
+            .as_deref()
+            .map(|connection| self.app_config.masking.columns(connection))
+            .unwrap_or_default();
+        self.masking_connection = self.manager.active.clone();
+    }
+
+    /// 表格列头中标记或取消标记敏感列后按连接保存
+    pub(super) fn set_column_masked(&mut self, column: &str, masked: bool) {
+        let Some(connection) = self.manager.active.clone() else {
+            self.notifications.info(t("notify.masking_not_saved"));
+            return;
+        };
+        self.app_config.masking.set_masked(&connection, column, masked);
+        self.save_config();
+        if masked {
+            self.notifications.info(tf("notify.masking_marked", &[column, &connection]));
+        }
+    }
+
+    /// 打开导出对话框时记录结果中的敏感列，默认导出脱敏值
+    pub(super) fn prepare_export_masking(&mut self) {
+        self.export_config.unmask = false;
+        self.export_config.masked_columns = self
+            .result
+            .as_ref()
+            .map(|r| {
+                (0..r.columns.len())
+                    .filter(|&i| self.grid_state.is_masked(&r.columns[i]))
+                    .collect()
+            })
+            .unwrap_or_default();
+    }
+
+    /// 导出敏感列原始值前请求确认，返回是否需要等待确认
+    pub(super) fn confirm_unmasked_export(&mut self, config: &ExportConfig) -> bool {
+        if !config.exports_unmasked() {
+            return false;
+        }
+        self.pending_unmask_export = Some(config.clone());
+        self.show_unmask_confirm = true;
+        true
+    }
+
+    /// 确认导出原始值的提示文字
+    pub(super) fn unmask_confirm_message(&self) -> String {
+        let columns: Vec<&str> = match (&self.pending_unmask_export, &self.result) {
+            (Some(config), Some(result)) => config
+                .unmasked_columns()
+                .filter_map(|i| result.columns.get(i).map(String::as_str))
+                .collect(),
+            _ => Vec::new(),
+        };
+        tf("masking.unmask_message", &[&columns.join(", ")])
+    }
+}
@@ -0,0 +1,65 @@
+//! 主密码
+//!
+//! 设置主密码后连接配置加密保存，启动时弹出解锁对话框；跳过解锁时已保存的连接不可用，
+//! 也不会被覆盖，之后可以从工具菜单再次解锁。
+
+use crate::core::{t, tf};
+use crate::ui;
+
+use super::DbManagerApp;
+
+impl DbManagerApp {
+    /// 打开主密码对话框（未解锁时先解锁）
+    pub(super) fn open_master_password(&mut self) {
+        if self.app_config.is_locked() {
+            self.master_password_state.open_unlock();
+        } else {
+            self.master_password_state
+                .open_settings(self.app_config.has_master_password());
+        }
+    }
+
+    /// 处理主密码对话框的结果
+    pub(super) fn handle_master_password_result(&mut self, result: ui::MasterPasswordResult) {
+        match result {
+            ui::MasterPasswordResult::None => {}
+            ui::MasterPasswordResult::Unlock(password) => match self.app_config.unlock(&password) {
+                Ok(connections) => {
+                    self.master_password_state.close();
+                    self.notifications.success(tf("notify.vault_unlocked", &[&connections.len().to_string()]));
+                    for config in connections {
+                        self.manager.add(config);
+                    }
+                    self.check_recovery();
+                }
+                Err(e) => self.master_password_state.fail(e),
+            },
+            ui::MasterPasswordResult::Set(password) => match self.app_config.set_master_password(&password) {
+                Ok(()) => {
+                    self.save_config();
+                    // 下次自动保存时用新的密钥重新写入恢复文件
+                    self.last_recovery = None;
+                    self.notifications.success(t("notify.master_password_set"));
+                }
+                Err(e) => {
+                    self.notifications.error(e);
+                }
+            },
+            ui::MasterPasswordResult::Remove => match self.app_config.remove_master_password() {
+                Ok(()) => {
+                    self.save_config();
+                    self.last_recovery = None;
+                    self.notifications.info(t("notify.master_password_removed"));
+                }
+                Err(e) => {
+                    self.notifications.error(e);
+                }
+            },
+            ui::MasterPasswordResult::Cancelled => {
+                if self.app_config.is_locked() {
+                    self.notifications.warning(t("notify.vault_still_locked"));
+                }
+            }
+        }
+    }
+}
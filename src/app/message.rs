@@ -2,7 +2,10 @@
 //!
 //! 定义应用程序中异步任务完成后发送的消息类型。
 
-use crate::database::{QueryResult, TriggerInfo, ForeignKeyInfo, ColumnInfo, RoutineInfo};
+use crate::database::{QueryProfile, QueryResult, TriggerInfo, ForeignKeyInfo, ColumnInfo, RoutineInfo, RedisKeyValue, SequenceInfo, GrantInfo, SessionInfo, TableSizeInfo};
+use crate::core::{BenchmarkSample, WorkflowRun};
+use crate::ui::{DataSearchOutcome, DependencyData, ResultPage, TableAction};
+use super::metadata::MetadataSnapshot;
 
 /// 异步任务完成后发送的消息
 pub enum Message {
@@ -10,18 +13,94 @@ pub enum Message {
     ConnectedWithTables(String, Result<Vec<String>, String>),
     /// 数据库连接完成 - MySQL/PostgreSQL 模式 (连接名, 数据库列表结果)
     ConnectedWithDatabases(String, Result<Vec<String>, String>),
-    /// 数据库选择完成 (连接名, 数据库名, 表列表结果)
-    DatabaseSelected(String, String, Result<Vec<String>, String>),
-    /// 查询执行完成 (SQL语句, 查询结果, 耗时毫秒)
-    QueryDone(String, Result<QueryResult, String>, u64),
+    /// 数据库选择完成 (连接名, 数据库名, 第一批表及是否还有更多)
+    DatabaseSelected(String, String, Result<(Vec<String>, bool), String>),
+    /// 下一批表读取完成 (连接名, 数据库名, 本批表及是否还有更多)
+    MoreTablesLoaded(String, String, Result<(Vec<String>, bool), String>),
+    /// 查询执行完成 (排队执行时发起查询的 Tab ID, SQL语句, 查询结果, 耗时毫秒)
+    QueryDone(Option<String>, String, Result<QueryResult, String>, u64),
+    /// 流式读取的一批行 (进度任务 ID, 列名, 本批行)
+    RowsChunk(u64, Vec<String>, Vec<Vec<String>>),
+    /// 流式读取完成 (进度任务 ID, SQL语句, 不含已发送行的结果, 耗时毫秒)
+    StreamDone(u64, String, Result<QueryResult, String>, u64),
+    /// 分页查询的一页读取完成 (排队执行时发起查询的 Tab ID, 页信息, 当前页结果, 耗时毫秒)
+    QueryPageDone(Option<String>, ResultPage, Result<QueryResult, String>, u64),
+    /// Redis 键值读取完成 (键名, 键值结果, 耗时毫秒)
+    RedisKeyFetched(String, Result<RedisKeyValue, String>, u64),
     /// 主键列获取完成 (表名, 主键列名)
     PrimaryKeyFetched(String, Option<String>),
+    /// 表结构中的列信息获取完成，用于列名补全的类型提示 (表名, 列信息结果)
+    ColumnTypesFetched(String, Result<Vec<ColumnInfo>, String>),
     /// 触发器列表获取完成 (触发器列表结果)
     TriggersFetched(Result<Vec<TriggerInfo>, String>),
     /// 存储过程/函数列表获取完成 (存储过程列表结果)
     RoutinesFetched(Result<Vec<RoutineInfo>, String>),
+    /// 序列/自增计数器列表获取完成 (序列列表结果)
+    SequencesFetched(Result<Vec<SequenceInfo>, String>),
+    /// 服务器时区获取完成 (连接名, 相对 UTC 的偏移秒数)
+    ServerTimezoneFetched(String, Option<i32>),
+    /// 表大小概览获取完成 (各表大小结果)
+    TableSizesFetched(Result<Vec<TableSizeInfo>, String>),
+    /// 全库数据搜索中一张表完成 (搜索编号, 表名, 搜索结果)
+    DataSearchTableDone(u64, String, DataSearchOutcome),
+    /// 全库数据搜索全部完成 (搜索编号)
+    DataSearchDone(u64),
+    /// 依赖关系图的元数据读取完成
+    DependenciesFetched(Result<DependencyData, String>),
+    /// 建表语句获取完成 (表名, 建表语句结果)
+    TableDdlFetched(String, Result<String, String>),
+    /// 影响行预览查询完成 (预览 SELECT 语句, 查询结果)
+    WritePreviewLoaded(String, Result<QueryResult, String>),
+    /// 表格保存的语句执行完成 (执行的语句, 是否在事务中执行, 影响的总行数, 耗时毫秒)
+    GridSaveDone(String, bool, Result<u64, String>, u64),
+    /// 表操作执行完成 (操作, 表名, 执行结果)
+    TableActionDone(TableAction, String, Result<(), String>),
+    /// 测试数据生成的列信息获取完成 (表名, 列信息列表)
+    DataGenColumnsFetched(String, Result<Vec<ColumnInfo>, String>),
+    /// 测试数据生成进度 (进度任务 ID, 已插入行数, 总行数)
+    DataGenProgress(u64, usize, usize),
+    /// 测试数据生成完成 (进度任务 ID, 表名, 已插入行数, 错误信息)
+    DataGenDone(u64, String, usize, Option<String>),
+    /// 定时查询执行完成 (任务 ID, 查询结果, 耗时毫秒)
+    ScheduledQueryDone(u64, Result<QueryResult, String>, u64),
+    /// 会话列表获取完成 (会话列表结果)
+    SessionsFetched(Result<Vec<SessionInfo>, String>),
+    /// 终止会话/取消查询完成 (执行结果)
+    SessionKilled(Result<(), String>),
+    /// 权限管理的用户/角色列表获取完成 (用户列表结果)
+    PrivilegeUsersFetched(Result<Vec<String>, String>),
+    /// 用户权限获取完成 (用户名, 权限列表结果)
+    GrantsFetched(String, Result<Vec<GrantInfo>, String>),
+    /// GRANT/REVOKE 执行完成 (执行结果)
+    PrivilegesApplied(Result<(), String>),
     /// 外键关系获取完成 (外键列表结果)
     ForeignKeysFetched(Result<Vec<ForeignKeyInfo>, String>),
     /// ER图表结构获取完成 (表名, 列信息列表)
     ERTableColumnsFetched(String, Result<Vec<ColumnInfo>, String>),
+    /// 自动补全用的完整表列表获取完成（SQLite 附加数据库）
+    AutocompleteTablesFetched(Result<Vec<String>, String>),
+    /// 草稿本保存到文件完成 (文件路径, 结果)
+    ScratchpadSaved(String, Result<(), String>),
+    /// SQLite 加密密钥修改完成 (连接名, 新密钥, 结果)
+    SqliteKeyChanged(String, String, Result<(), String>),
+    /// 触发器/存储过程/序列 DDL 执行完成 (执行结果)
+    ObjectDdlDone(Result<(), String>),
+    /// 维护操作完成 (操作描述, 进度通知 ID, 结果中报告的错误, 耗时毫秒)
+    MaintenanceDone(String, u64, Result<Vec<String>, String>, u64),
+    /// 工作流步骤开始或结束 (进度任务 ID, 当前运行进度)
+    WorkflowProgress(u64, WorkflowRun),
+    /// 保活检查完成 (连接名, 耗时毫秒及是否重新建立了连接)
+    HealthChecked(String, Result<(u64, bool), String>),
+    /// 手动断开空闲连接池完成 (断开的数量)
+    IdlePoolsClosed(usize),
+    /// 后台元数据刷新完成
+    MetadataRefreshed(MetadataSnapshot),
+    /// SQL 检查完成 (检查的语句数, 或第一条出错的语句及错误信息)
+    SqlValidated(Result<usize, (String, String)>),
+    /// 基准测试完成一次执行 (执行编号, 是否为预热, 耗时和行数)
+    BenchmarkRun(u64, bool, Result<BenchmarkSample, String>),
+    /// 基准测试全部完成或出错停止 (执行编号)
+    BenchmarkDone(u64),
+    /// 查询分析完成 (分析编号, 运行时统计)
+    QueryProfiled(u64, Result<QueryProfile, String>),
 }
@@ -0,0 +1,186 @@
+//! 后台元数据刷新
+//!
+//! 按首选项中的间隔以及执行 DDL 之后，在后台重新读取当前连接（数据库）的表列表，
+//! 并分批读取列信息；比较后合并到侧边栏和自动补全，不重置选中状态。
+
+use std::collections::HashSet;
+use std::time::Instant;
+
+use eframe::egui;
+
+use crate::core::{constants, pick_column_tables, remap_selection, CatalogDiff, METADATA_COLUMN_BATCH};
+use crate::database::{
+    connect_database, get_table_columns, get_tables_page, ColumnInfo, ConnectResult, DatabaseType, DbError,
+};
+use crate::ui::SidebarSection;
+use super::{DbManagerApp, Message};
+
+/// 一次后台刷新读取到的元数据
+pub struct MetadataSnapshot {
+    /// 刷新编号（见 `MetadataRefresh::start`）
+    pub generation: u64,
+    pub connection: String,
+    pub database: Option<String>,
+    /// 表列表；连接返回的是数据库列表时为 `None`
+    pub tables: Result<Option<Vec<String>>, String>,
+    /// 表列表是分批读取的，且还有未读取的表
+    pub has_more: bool,
+    /// 读取到的列信息 (表名, 列信息列表)
+    pub columns: Vec<(String, Vec<ColumnInfo>)>,
+}
+
+impl DbManagerApp {
+    /// 到期时开始后台刷新，未到期时安排下次检查的重绘
+    pub(super) fn run_metadata_refresh(&mut self, ctx: &egui::Context) {
+        let interval = self.app_config.metadata_refresh_secs;
+        let now = Instant::now();
+        let Some(name) = self.manager.active.clone() else {
+            return;
+        };
+        let Some(conn) = self.manager.connections.get(&name) else {
+            return;
+        };
+        // Redis 的"表"是键扫描结果，不做后台刷新；只有数据库列表时没有表可刷新
+        if !conn.connected
+            || self.connecting
+            || conn.config.db_type == DatabaseType::Redis
+            || (conn.selected_database.is_none() && !conn.databases.is_empty())
+        {
+            return;
+        }
+
+        if !self.metadata_refresh.is_due(interval, now) {
+            if let Some(wait) = self.metadata_refresh.time_until_next(interval, now) {
+                ctx.request_repaint_after(wait);
+            }
+            return;
+        }
+
+        let (generation, stale) = self.metadata_refresh.start();
+        let cached: HashSet<String> =
+            conn.tables.iter().filter(|t| self.autocomplete.has_columns(t)).cloned().collect();
+        let database = conn.selected_database.clone();
+        // 分批加载的表列表只刷新已加载的部分；已全部加载时多读一批以发现新表
+        let table_limit = if conn.has_more_tables {
+            conn.tables.len()
+        } else {
+            conn.tables.len() + constants::database::TABLE_PAGE_SIZE
+        };
+        let config = self.resolve_timeouts(conn.config.clone());
+        let timeout_secs = config.query_timeout_secs();
+        let tx = self.tx.clone();
+
+        self.runtime.spawn(async move {
+            use tokio::time::{timeout, Duration};
+            let fetch = async {
+                let (tables, has_more) = match &database {
+                    Some(database) => {
+                        let (tables, has_more) = get_tables_page(&config, database, 0, table_limit).await?;
+                        (Some(tables), has_more)
+                    }
+                    None => match connect_database(&config).await? {
+                        ConnectResult::Tables(tables) => (Some(tables), false),
+                        ConnectResult::Databases(_) => (None, false),
+                    },
+                };
+                let mut columns = Vec::new();
+                if let Some(tables) = &tables {
+                    let picked = pick_column_tables(tables, &stale, |t| cached.contains(t), METADATA_COLUMN_BATCH);
+                    for table in picked {
+                        // 单个表读取失败不影响其他表
+                        match get_table_columns(&config, &table).await {
+                            Ok(cols) => columns.push((table, cols)),
+                            Err(e) => tracing::debug!(table = %table, error = %e, "读取列信息失败"),
+                        }
+                    }
+                }
+                Ok::<_, DbError>((tables, has_more, columns))
+            };
+            let (tables, has_more, columns) = match timeout(Duration::from_secs(timeout_secs), fetch).await {
+                Ok(Ok((tables, has_more, columns))) => (Ok(tables), has_more, columns),
+                Ok(Err(e)) => (Err(e.to_string()), false, Vec::new()),
+                Err(_) => (Err(format!("刷新元数据超时 ({}秒)", timeout_secs)), false, Vec::new()),
+            };
+            let snapshot = MetadataSnapshot {
+                generation,
+                connection: name,
+                database,
+                tables,
+                has_more,
+                columns,
+            };
+            if tx.send(Message::MetadataRefreshed(snapshot)).is_err() {
+                tracing::warn!("无法发送元数据刷新结果：接收端已关闭");
+            }
+        });
+    }
+
+    /// 请求尽快刷新元数据（执行 DDL 或表操作之后）
+    pub(super) fn request_metadata_refresh(&mut self, tables: Vec<String>) {
+        self.metadata_refresh.request(tables);
+    }
+
+    /// 合并后台刷新的结果：只更新变化的部分，保持侧边栏的选中项
+    pub(super) fn handle_metadata_refreshed(&mut self, ctx: &egui::Context, snapshot: MetadataSnapshot) {
+        if !self.metadata_refresh.finish(snapshot.generation, Instant::now()) {
+            return;
+        }
+        let is_active = self.manager.active.as_deref() == Some(snapshot.connection.as_str());
+        let Some(conn) = self.manager.connections.get_mut(&snapshot.connection) else {
+            return;
+        };
+        if !is_active || !conn.connected || conn.selected_database != snapshot.database {
+            return;
+        }
+
+        let tables = match snapshot.tables {
+            Ok(Some(tables)) => tables,
+            Ok(None) => return,
+            Err(e) => {
+                // 后台任务失败不打扰用户，下次到期时重试
+                tracing::warn!(connection = %snapshot.connection, error = %e, "后台刷新元数据失败");
+                return;
+            }
+        };
+
+        // 刷新期间又加载了更多表时，这次的结果不完整
+        if snapshot.has_more && tables.len() < conn.tables.len() {
+            return;
+        }
+        conn.has_more_tables = snapshot.has_more;
+        let diff = CatalogDiff::between(&conn.tables, &tables);
+        if !diff.is_empty() {
+            tracing::info!(
+                connection = %snapshot.connection,
+                added = diff.added.len(),
+                removed = diff.removed.len(),
+                "表列表已更新"
+            );
+            // 键盘导航索引基于按表名筛选后的列表
+            let panel_state = &mut self.sidebar_panel_state;
+            let visible = |list: &[String]| -> Vec<String> {
+                panel_state
+                    .quick_filters
+                    .indices(SidebarSection::Tables, list)
+                    .into_iter()
+                    .map(|i| list[i].clone())
+                    .collect()
+            };
+            let (old_visible, new_visible) = (visible(&conn.tables), visible(&tables));
+            panel_state.selection.tables = remap_selection(&old_visible, &new_visible, panel_state.selection.tables);
+            let sqlite_schema = conn.config.db_type == DatabaseType::SQLite && snapshot.database.is_some();
+            self.autocomplete.retain_columns(&tables);
+            conn.tables = tables;
+            // SQLite 附加数据库：自动补全包含所有 schema 的表
+            if sqlite_schema {
+                self.load_sqlite_autocomplete_tables();
+            } else {
+                self.autocomplete.set_tables(conn.tables.clone());
+            }
+            ctx.request_repaint();
+        }
+        for (table, columns) in snapshot.columns {
+            self.autocomplete.set_column_infos(table, columns);
+        }
+    }
+}
@@ -5,41 +5,120 @@
 //!
 //! ## 子模块
 //!
+//! - `api_server`: 本地只读 API 服务
+//! - `audit`: 审计日志记录与查看
+//! - `benchmark`: 重复执行查询的基准测试
+//! - `column_settings`: 按表保存的列数字格式和列宽
+//! - `cli`: 无界面模式的查询执行与导出
+//! - `config_reload`: 配置文件热加载
+//! - `data_search`: 全库数据搜索
 //! - `database`: 数据库连接和查询操作
+//! - `dependencies`: 对象依赖关系图
+//! - `desktop_notify`: 长时间查询的桌面通知
+//! - `detached_tabs`: 在独立窗口中显示查询标签页
 //! - `dialogs`: 对话框渲染和处理
 //! - `er_diagram`: ER 关系图数据加载
 //! - `export`: 数据导出功能
+//! - `file_drop`: 拖放文件处理
+//! - `filter_presets`: 按表保存的筛选预设
 //! - `handler`: 异步消息处理
+//! - `health`: 连接保活检查和空闲连接池清理
 //! - `import`: 数据导入功能
+//! - `key_macro`: 键盘宏的录制和回放
 //! - `keyboard`: 键盘快捷键处理
+//! - `masking`: 敏感列脱敏
+//! - `master_password`: 主密码加密连接配置
 //! - `message`: 异步消息定义
+//! - `metadata`: 后台刷新表和列信息
+//! - `pinned_tabs`: 查询标签页的重命名、固定和会话恢复
+//! - `pivot`: 查询结果的透视表
+//! - `recent`: 最近使用的表和数据库及快速切换
+//! - `recovery`: 崩溃恢复（定期保存未保存的内容，启动时询问是否恢复）
+//! - `print_view`: 生成表格的打印视图（分页 HTML 报表）
+//! - `profiling`: 读取查询的运行时统计
+//! - `queue`: 按连接排队执行查询
 //! - `render`: UI 渲染和操作处理
+//! - `result_memory`: 查询结果的内存占用检查和磁盘缓存
+//! - `row_bookmarks`: 按主键收藏的行和书签跳转
+//! - `scheduler`: 定时查询执行
+//! - `sql_errors`: 查询错误的出错位置标记和名称建议
+//! - `sql_file`: .sql 文件的打开、保存和外部修改检测
 //! - `state`: 应用状态定义
+//! - `streaming`: 流式读取查询结果
+//! - `theme`: 自定义主题编辑与导入导出
+//! - `tour`: 新手引导
+//! - `trash`: 回收站（删除的连接和关闭的标签页）
+//! - `validation`: 不执行而检查 SQL
+//! - `workflow`: 工作流执行
+//! - `write_preview`: UPDATE/DELETE 执行前的影响行预览和按筛选条件批量修改
 
+mod api_server;
+mod audit;
+mod benchmark;
+mod cli;
+mod column_settings;
+mod command_palette;
+mod config_reload;
+mod data_search;
 mod database;
+mod dependencies;
+mod desktop_notify;
+mod detached_tabs;
 mod dialogs;
 mod er_diagram;
 mod export;
+mod file_drop;
+mod filter_presets;
 mod handler;
+mod health;
 mod import;
+mod key_macro;
 mod keyboard;
+mod masking;
+mod master_password;
 mod message;
+mod metadata;
+mod pinned_tabs;
+mod pivot;
+mod print_view;
+mod profiling;
+mod queue;
+mod recent;
+mod recovery;
 mod render;
+mod result_memory;
+mod row_bookmarks;
+mod scheduler;
+mod sql_errors;
+mod sql_file;
 pub mod state;
+mod preferences;
+mod streaming;
+mod theme;
+mod tour;
+mod trash;
+mod validation;
+mod workflow;
+mod write_preview;
 
 use eframe::egui;
 use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::Arc;
 
 use crate::core::{
-    clear_highlight_cache, constants, AppConfig, AutoComplete, HighlightColors,
-    KeyBindings, NotificationManager, ProgressManager, QueryHistory, ThemeManager, ThemePreset,
+    apply_fonts, clear_highlight_cache, constants, set_language, tf, AppConfig, AuditLog, AutoComplete, HighlightColors,
+    KeyBindings, KeyMacro, MetadataRefresh, NotificationManager, PluginRegistry, ProgressManager, QueryHistory, QueryQueue, QueryScheduler, RecentTarget, ThemeManager,
+    ThemePreset, Tour, TourTarget, ValueFormatter,
 };
-use crate::database::{ConnectionConfig, ConnectionManager, QueryResult};
+use crate::database::{ConnectionConfig, ConnectionManager, DatabaseType, QueryResult};
 use crate::ui::{
     self, DdlDialogState, ExportConfig, KeyBindingsDialogState, QueryTabManager,
     SqlEditorActions, ToolbarActions,
 };
 
+pub use cli::{run_headless, run_workflow_headless};
+#[allow(unused_imports)] // 公开 API
+pub use cli::run_query_headless;
 use message::Message;
 
 /// 数据库管理器主应用结构体
@@ -77,7 +156,7 @@ pub struct DbManagerApp {
     /// 当前 SQL 编辑器内容
     sql: String,
     /// 当前查询结果
-    result: Option<QueryResult>,
+    result: Option<Arc<QueryResult>>,
     /// 多 Tab 查询管理器，支持多个独立查询
     tab_manager: QueryTabManager,
 
@@ -92,6 +171,10 @@ pub struct DbManagerApp {
     connecting: bool,
     /// 是否正在执行查询
     executing: bool,
+    /// 正在流式读取的查询
+    streaming_queries: Vec<streaming::StreamingQuery>,
+    /// 按连接排队执行的查询
+    query_queue: QueryQueue<queue::PendingQuery>,
 
     // ==================== 配置和历史 ====================
     /// 应用程序配置（主题、UI 缩放等）
@@ -114,6 +197,8 @@ pub struct DbManagerApp {
     search_text: String,
     /// 搜索限定的列名
     search_column: Option<String>,
+    /// 搜索选项（正则、全词、大小写）
+    search_options: ui::SearchOptions,
     /// 当前选中的行索引
     selected_row: Option<usize>,
     /// 当前选中的单元格 (行, 列)
@@ -140,6 +225,10 @@ pub struct DbManagerApp {
     show_delete_confirm: bool,
     /// 待删除的连接名
     pending_delete_name: Option<String>,
+    /// 是否显示删除触发器/存储过程确认对话框
+    show_drop_object_confirm: bool,
+    /// 待删除的对象（提示信息, DROP 语句）
+    pending_drop_object: Option<(String, String)>,
 
     // ==================== 主题和外观 ====================
     /// 主题管理器
@@ -156,8 +245,10 @@ pub struct DbManagerApp {
     show_autocomplete: bool,
     /// 当前选中的补全项索引
     selected_completion: usize,
-    /// SQL 编辑器模式 (Normal/Insert)
+    /// SQL 编辑器模式 (Normal/Insert/Command)
     editor_mode: ui::EditorMode,
+    /// SQL 编辑器命令行状态
+    command_line_state: ui::CommandLineState,
 
     // ==================== UI 显示状态 ====================
     /// SQL 编辑器是否展开显示
@@ -192,20 +283,150 @@ pub struct DbManagerApp {
     create_db_dialog_state: ui::CreateDbDialogState,
     /// 新建用户对话框状态
     create_user_dialog_state: ui::CreateUserDialogState,
+    /// 修改加密密钥对话框状态
+    change_key_dialog_state: ui::ChangeKeyDialogState,
+    /// 触发器/存储过程/函数编辑对话框状态
+    object_ddl_dialog_state: ui::ObjectDdlDialogState,
+    /// 设置序列下一个值对话框状态
+    sequence_dialog_state: ui::SequenceDialogState,
+    /// 权限管理对话框状态
+    privilege_dialog_state: ui::PrivilegeDialogState,
+    /// 表大小概览窗口状态
+    size_overview_state: ui::SizeOverviewState,
+    /// 依赖关系窗口状态
+    dependency_state: ui::DependencyState,
+    /// 全库数据搜索窗口状态
+    data_search_state: ui::DataSearchState,
+    /// 正在进行的全库数据搜索任务（停止或重新搜索时中止）
+    data_search_task: Option<tokio::task::JoinHandle<()>>,
+    /// 会话监控窗口状态
+    session_monitor_state: ui::SessionMonitorState,
+    /// 建表语句窗口状态
+    table_ddl_state: ui::TableDdlState,
+    /// 表操作（重命名/删除/清空/复制）对话框状态
+    table_action_state: ui::TableActionState,
+    /// 测试数据生成对话框状态
+    datagen_state: ui::DataGenState,
+    /// 定时查询对话框状态
+    scheduler_state: ui::SchedulerState,
+    /// 结果对比窗口状态
+    result_diff_state: ui::ResultDiffState,
+    /// SQL 对比窗口状态
+    sql_diff_state: ui::SqlDiffState,
+    /// 定时查询调度器
+    scheduler: QueryScheduler,
+    /// 审计日志（无法获取配置目录时为空）
+    audit_log: Option<AuditLog>,
+    /// 审计日志窗口状态
+    audit_log_state: ui::AuditLogState,
+    /// 主题编辑器状态
+    theme_editor_state: ui::ThemeEditorState,
+    /// 字体设置窗口状态
+    font_settings_state: ui::FontSettingsState,
+    /// 首选项窗口状态
+    preferences_state: ui::PreferencesState,
+    /// 命令面板状态
+    command_palette_state: ui::CommandPaletteState,
+    /// 快速切换器选中、等待连接或数据库切换完成后打开的目标
+    pending_recent: Option<RecentTarget>,
+    /// 表格中列设置对应的表（切换表后重新载入）
+    column_settings_target: Option<RecentTarget>,
+    /// 表格中已载入敏感列的连接
+    masking_connection: Option<String>,
+    /// 表格中已载入行书签的表
+    row_bookmark_target: Option<RecentTarget>,
+    /// 是否显示导出敏感列原始值的确认
+    show_unmask_confirm: bool,
+    /// 等待确认的导出配置
+    pending_unmask_export: Option<ExportConfig>,
+    /// 工作流面板状态
+    workflow_state: ui::WorkflowState,
+    /// API 服务面板状态
+    api_server_state: ui::ApiServerState,
+    /// 运行中的本地 API 服务
+    api_server: Option<api_server::ApiServer>,
+    /// API 服务读取的配置和连接
+    api_shared: api_server::ApiSharedHandle,
+    /// 通知中心状态
+    notification_center_state: ui::NotificationCenterState,
+    /// 回收站窗口状态
+    trash_dialog_state: ui::TrashDialogState,
+    /// 主密码对话框状态
+    master_password_state: ui::MasterPasswordState,
+    /// 基准测试窗口状态
+    benchmark_state: ui::BenchmarkState,
+    /// 正在执行的基准测试任务
+    benchmark_task: Option<tokio::task::JoinHandle<()>>,
+    /// 查询分析面板状态
+    profile_state: ui::ProfileState,
+    /// 行书签列表窗口状态
+    row_bookmark_state: ui::RowBookmarkState,
+    /// 透视表窗口状态
+    pivot_state: ui::PivotState,
+    /// 日志查看器状态
+    log_viewer_state: ui::LogViewerState,
+    /// 连接池诊断窗口状态
+    pool_stats_state: ui::PoolStatsState,
+    /// 影响行预览窗口状态
+    write_preview_state: ui::WritePreviewState,
+    /// 表格保存 SQL 检查窗口状态
+    sql_review_state: ui::SqlReviewState,
+    /// 批量修改窗口状态
+    bulk_edit_state: ui::BulkEditState,
+    /// 后台元数据刷新的调度状态
+    metadata_refresh: MetadataRefresh,
+    /// 上次检查空闲连接池的时间
+    last_pool_cleanup: std::time::Instant,
     /// 快捷键绑定
     keybindings: KeyBindings,
     /// 快捷键设置对话框状态
     keybindings_dialog_state: KeyBindingsDialogState,
+    /// 键盘宏（Q 录制，@ 回放）
+    key_macro: KeyMacro,
+    /// 新手引导
+    tour: Tour,
+    /// 引导中各区域在本帧的位置
+    tour_targets: std::collections::HashMap<TourTarget, egui::Rect>,
+    /// 编辑器中标出的查询出错位置
+    sql_error_marker: Option<ui::SqlErrorMarker>,
     /// 中央面板左右分割比例 (0.0-1.0, 左侧占比)
     central_panel_ratio: f32,
     /// 是否显示 ER 图面板
     show_er_diagram: bool,
     /// ER 图状态
     er_diagram_state: ui::ERDiagramState,
+    /// 等待截取画布的 PNG 导出路径
+    er_png_export_path: Option<std::path::PathBuf>,
+    /// 已发出截图请求的 PNG 导出（路径与画布区域）
+    er_png_capture: Option<(std::path::PathBuf, egui::Rect)>,
     /// SQL 编辑器高度（用于可调整大小）
     sql_editor_height: f32,
-    /// 待执行的切换日/夜模式操作（由键盘快捷键设置）
-    pending_toggle_dark_mode: bool,
+    /// 文件外部修改提示状态
+    file_change_state: ui::FileChangeState,
+    /// 上次检查文件外部修改的时间
+    last_file_check: std::time::Instant,
+    /// 上次检查配置文件修改的时间
+    last_config_check: std::time::Instant,
+    /// 已加载的配置文件的修改时间
+    config_modified: Option<std::time::SystemTime>,
+    /// 是否显示崩溃恢复提示
+    show_recovery_prompt: bool,
+    /// 启动时发现、等待用户决定的恢复内容
+    pending_recovery: Option<crate::core::RecoveryState>,
+    /// 最近一次写入恢复文件的内容
+    last_recovery: Option<crate::core::RecoveryState>,
+    /// 上次检查是否需要写入恢复文件的时间
+    last_recovery_save: std::time::Instant,
+    /// 写入磁盘的后台标签页查询结果
+    spill_store: crate::core::SpillStore,
+    /// 上次估算结果内存占用的时间
+    last_memory_check: std::time::Instant,
+    /// 上次估算时的结果（地址和行数），未变化时不重新估算
+    result_memory_key: Vec<(usize, usize, usize)>,
+    /// 是否已提醒过内存占用超过阈值
+    memory_warned: bool,
+    /// 插件注册表（导出格式、单元格渲染器、侧边栏面板）
+    plugins: Arc<PluginRegistry>,
 }
 
 impl DbManagerApp {
@@ -216,16 +437,59 @@ impl DbManagerApp {
             || self.show_export_dialog
             || self.show_import_dialog
             || self.show_delete_confirm
+            || self.show_recovery_prompt
             || self.show_help
             || self.show_about
+            || self.tour.is_active()
             || self.show_history_panel
             || self.ddl_dialog_state.show
             || self.create_db_dialog_state.show
             || self.create_user_dialog_state.show
+            || self.change_key_dialog_state.show
+            || self.object_ddl_dialog_state.show
+            || self.sequence_dialog_state.show
+            || self.privilege_dialog_state.show
+            || self.size_overview_state.show
+            || self.dependency_state.show
+            || self.data_search_state.show
+            || self.session_monitor_state.show
+            || self.table_ddl_state.show
+            || self.table_action_state.show
+            || self.datagen_state.show
+            || self.scheduler_state.show
+            || self.result_diff_state.show
+            || self.sql_diff_state.show
+            || self.audit_log_state.show
+            || self.theme_editor_state.show
+            || self.font_settings_state.show
+            || self.preferences_state.show
+            || self.command_palette_state.show
+            || self.workflow_state.show
+            || self.api_server_state.show
+            || self.notification_center_state.show
+            || self.trash_dialog_state.show
+            || self.master_password_state.show
+            || self.benchmark_state.show
+            || self.row_bookmark_state.show
+            || self.pivot_state.show
+            || self.log_viewer_state.show
+            || self.pool_stats_state.show
+            || self.write_preview_state.show
+            || self.sql_review_state.show
+            || self.bulk_edit_state.show
+            || self.show_drop_object_confirm
+            || self.show_unmask_confirm
             || self.keybindings_dialog_state.show
+            || self.file_change_state.show
     }
 
+    /// 使用内置插件创建应用
     pub fn new(cc: &eframe::CreationContext<'_>) -> Self {
+        Self::with_plugins(cc, PluginRegistry::new())
+    }
+
+    /// 使用自定义插件注册表创建应用，供注册了第三方插件的二进制使用
+    pub fn with_plugins(cc: &eframe::CreationContext<'_>, plugins: PluginRegistry) -> Self {
         let (tx, rx) = channel();
         
         // 创建 tokio runtime，优先多线程，失败则降级到单线程
@@ -242,11 +506,20 @@ impl DbManagerApp {
 
         // 加载配置
         let app_config = AppConfig::load();
-        let theme_manager = ThemeManager::new(app_config.theme_preset);
-        let highlight_colors = HighlightColors::from_theme(&theme_manager.colors);
-        let query_history = QueryHistory::new(100);
+        let mut theme_manager = ThemeManager::new(app_config.theme_preset);
+        if let Some(theme) = app_config
+            .custom_theme
+            .as_ref()
+            .and_then(|name| app_config.custom_themes.iter().find(|t| &t.name == name))
+        {
+            theme_manager.set_custom(theme.clone());
+        }
+        let highlight_colors = HighlightColors::from_manager(&theme_manager);
+        let query_history = QueryHistory::load(app_config.history_limit);
 
-        // 应用主题
+        // 应用界面语言、字体和主题
+        set_language(app_config.language);
+        apply_fonts(&cc.egui_ctx, &app_config.fonts);
         theme_manager.apply(&cc.egui_ctx);
 
         // 获取基础 DPI 缩放并应用用户缩放设置
@@ -254,13 +527,30 @@ impl DbManagerApp {
         let ui_scale = app_config.ui_scale.clamp(constants::ui::UI_SCALE_MIN, constants::ui::UI_SCALE_MAX);
         cc.egui_ctx.set_pixels_per_point(base_pixels_per_point * ui_scale);
 
+        let mut plugins = plugins;
+        if let Some(dir) = &app_config.image_preview_dir {
+            plugins.set_image_preview_dir(Some(dir));
+        }
+        let plugins = Arc::new(plugins);
+        let grid_state = ui::DataGridState {
+            show_footer: app_config.show_grid_footer,
+            value_format: ValueFormatter {
+                timestamp_display: app_config.timestamp_display,
+                server_offset: None,
+                number: app_config.number_format,
+            },
+            plugins: Arc::clone(&plugins),
+            ..ui::DataGridState::new()
+        };
+        let keybindings = app_config.keybindings.clone();
+
         // 从配置恢复连接
         let mut manager = ConnectionManager::default();
         for config in &app_config.connections {
             manager.add(config.clone());
         }
 
-        Self {
+        let mut app = Self {
             manager,
             show_connection_dialog: false,
             new_config: ConnectionConfig::default(),
@@ -273,6 +563,8 @@ impl DbManagerApp {
             runtime,
             connecting: false,
             executing: false,
+            streaming_queries: Vec::new(),
+            query_queue: QueryQueue::new(app_config.max_parallel_queries),
             app_config,
             query_history,
             command_history: Vec::new(),
@@ -282,9 +574,10 @@ impl DbManagerApp {
             current_history_connection: None,
             search_text: String::new(),
             search_column: None,
+            search_options: ui::SearchOptions::default(),
             selected_row: None,
             selected_cell: None,
-            grid_state: ui::DataGridState::new(),
+            grid_state,
             show_export_dialog: false,
             export_config: ExportConfig::default(),
             export_status: None,
@@ -294,6 +587,8 @@ impl DbManagerApp {
             history_panel_state: ui::HistoryPanelState::default(),
             show_delete_confirm: false,
             pending_delete_name: None,
+            show_drop_object_confirm: false,
+            pending_drop_object: None,
             theme_manager,
             highlight_colors,
             last_query_time_ms: None,
@@ -301,6 +596,7 @@ impl DbManagerApp {
             show_autocomplete: false,
             selected_completion: 0,
             editor_mode: ui::EditorMode::Normal,
+            command_line_state: ui::CommandLineState::new(),
             show_sql_editor: false,
             focus_sql_editor: false,
             show_sidebar: false,
@@ -317,14 +613,94 @@ impl DbManagerApp {
             ddl_dialog_state: DdlDialogState::default(),
             create_db_dialog_state: ui::CreateDbDialogState::new(),
             create_user_dialog_state: ui::CreateUserDialogState::new(),
-            keybindings: KeyBindings::default(),
+            change_key_dialog_state: ui::ChangeKeyDialogState::new(),
+            object_ddl_dialog_state: ui::ObjectDdlDialogState::new(),
+            sequence_dialog_state: ui::SequenceDialogState::new(),
+            privilege_dialog_state: ui::PrivilegeDialogState::new(),
+            size_overview_state: ui::SizeOverviewState::new(),
+            dependency_state: ui::DependencyState::new(),
+            data_search_state: ui::DataSearchState::new(),
+            data_search_task: None,
+            session_monitor_state: ui::SessionMonitorState::new(),
+            table_ddl_state: ui::TableDdlState::new(),
+            table_action_state: ui::TableActionState::new(),
+            datagen_state: ui::DataGenState::new(),
+            scheduler_state: ui::SchedulerState::new(),
+            result_diff_state: ui::ResultDiffState::new(),
+            sql_diff_state: ui::SqlDiffState::new(),
+            scheduler: QueryScheduler::new(),
+            audit_log: AuditLog::open_default(),
+            audit_log_state: ui::AuditLogState::new(),
+            theme_editor_state: ui::ThemeEditorState::new(),
+            font_settings_state: ui::FontSettingsState::new(),
+            preferences_state: ui::PreferencesState::new(),
+            command_palette_state: ui::CommandPaletteState::new(),
+            pending_recent: None,
+            column_settings_target: None,
+            masking_connection: None,
+            row_bookmark_target: None,
+            show_unmask_confirm: false,
+            pending_unmask_export: None,
+            workflow_state: ui::WorkflowState::new(),
+            api_server_state: ui::ApiServerState::new(),
+            api_server: None,
+            api_shared: Default::default(),
+            notification_center_state: ui::NotificationCenterState::new(),
+            trash_dialog_state: ui::TrashDialogState::new(),
+            master_password_state: ui::MasterPasswordState::new(),
+            benchmark_state: ui::BenchmarkState::new(),
+            benchmark_task: None,
+            profile_state: ui::ProfileState::new(),
+            row_bookmark_state: ui::RowBookmarkState::new(),
+            pivot_state: ui::PivotState::new(),
+            log_viewer_state: ui::LogViewerState::new(),
+            pool_stats_state: ui::PoolStatsState::new(),
+            write_preview_state: ui::WritePreviewState::new(),
+            sql_review_state: ui::SqlReviewState::new(),
+            bulk_edit_state: ui::BulkEditState::new(),
+            metadata_refresh: MetadataRefresh::new(),
+            last_pool_cleanup: std::time::Instant::now(),
+            keybindings,
             keybindings_dialog_state: KeyBindingsDialogState::default(),
+            key_macro: KeyMacro::new(),
+            tour: Tour::new(),
+            tour_targets: std::collections::HashMap::new(),
+            sql_error_marker: None,
             central_panel_ratio: 0.65,
             show_er_diagram: false,
             er_diagram_state: ui::ERDiagramState::new(),
+            er_png_export_path: None,
+            er_png_capture: None,
             sql_editor_height: 200.0,  // 默认 SQL 编辑器高度
-            pending_toggle_dark_mode: false,
+            file_change_state: ui::FileChangeState::new(),
+            last_file_check: std::time::Instant::now(),
+            last_config_check: std::time::Instant::now(),
+            config_modified: AppConfig::modified_time(),
+            show_recovery_prompt: false,
+            pending_recovery: None,
+            last_recovery: None,
+            last_recovery_save: std::time::Instant::now(),
+            spill_store: crate::core::SpillStore::default(),
+            last_memory_check: std::time::Instant::now(),
+            result_memory_key: Vec::new(),
+            memory_warned: false,
+            plugins,
+        };
+        app.restore_pinned_tabs();
+        app.check_recovery();
+        app.purge_expired_trash();
+        // 连接配置已用主密码加密时先解锁
+        if app.app_config.is_locked() {
+            app.master_password_state.open_unlock();
+        }
+        // 首次启动（还没有连接）时自动开始新手引导
+        if !app.app_config.tour_completed && !app.app_config.has_master_password() && app.manager.connections.is_empty() {
+            app.tour.start();
         }
+        if app.app_config.api_server.auto_start {
+            app.start_api_server();
+        }
+        app
     }
 
     /// 设置 UI 缩放比例
@@ -336,6 +712,21 @@ impl DbManagerApp {
         let _ = self.app_config.save();
     }
 
+    /// 替换当前显示的结果集
+    ///
+    /// 换成另一个结果时递增表格的结果代数，基于旧结果的筛选和列宽缓存随之失效
+    fn set_result(&mut self, result: Option<Arc<QueryResult>>) {
+        let same = match (&self.result, &result) {
+            (Some(current), Some(new)) => Arc::ptr_eq(current, new),
+            (None, None) => true,
+            _ => false,
+        };
+        self.result = result;
+        if !same {
+            self.grid_state.result_replaced();
+        }
+    }
+
     /// 检查当前连接是否是 MySQL（用于选择 SQL 引号类型）
     fn is_mysql(&self) -> bool {
         self.manager.get_active()
@@ -343,11 +734,130 @@ impl DbManagerApp {
             .unwrap_or(false)
     }
 
+    /// 为当前连接引用表名
+    ///
+    /// 前缀是已附加的 SQLite 数据库或 ODBC schema 时按 `schema.table` 分段引用，
+    /// 名称本身带点的表整体引用；MongoDB 集合名引用为 JSON 字符串
+    fn quote_table_name(&self, table: &str) -> Result<String, String> {
+        let Some(conn) = self.manager.get_active() else {
+            return ui::quote_identifier(table, false);
+        };
+        if conn.config.db_type == DatabaseType::MongoDB {
+            return serde_json::to_string(table).map_err(|e| e.to_string());
+        }
+        if Self::has_schema_prefix(conn, table) {
+            ui::quote_qualified_identifier(table, false)
+        } else {
+            ui::quote_identifier(table, conn.config.db_type == DatabaseType::MySQL)
+        }
+    }
+
+    /// 按当前连接拆分 `schema.table`，前缀不是已知 schema 时整个名称作为表名
+    fn split_table_name<'a>(&self, table: &'a str) -> (Option<&'a str>, &'a str) {
+        match (self.manager.get_active(), table.split_once('.')) {
+            (Some(conn), Some((schema, name))) if Self::has_schema_prefix(conn, table) => (Some(schema), name),
+            _ => (None, table),
+        }
+    }
+
+    /// 表名的点号前缀是否为已知 schema
+    ///
+    /// SQLite 的已知 schema 是附加数据库的别名；ODBC 数据源有 schema 时所有表名都带前缀，
+    /// 因此只有表列表中的名称全部带前缀时才拆分（没有 schema 的数据源中带点的表名保持完整）
+    fn has_schema_prefix(conn: &crate::database::Connection, table: &str) -> bool {
+        let Some((schema, _)) = table.split_once('.') else {
+            return false;
+        };
+        match conn.config.db_type {
+            DatabaseType::SQLite => conn.config.sqlite_attachments.iter().any(|a| a.alias == schema),
+            DatabaseType::Odbc => conn.tables.iter().all(|t| t.contains('.')),
+            _ => false,
+        }
+    }
+
+    /// 生成整表预览查询（按当前数据库方言限制行数）
+    fn table_preview_sql(&self, table: &str) -> Result<String, String> {
+        let quoted_table = self.quote_table_name(table)?;
+        let db_type = self.manager.get_active()
+            .map(|c| c.config.db_type)
+            .unwrap_or_default();
+        Ok(db_type.select_all_sql(&quoted_table, self.app_config.default_query_limit))
+    }
+
+    /// 将表格筛选条件转换为 WHERE 子句，在服务端重新查询当前表，生成的 SQL 显示在编辑器中
+    fn apply_filters_server_side(&mut self) {
+        let Some(table) = self.selected_table.clone() else {
+            return;
+        };
+        let Some(db_type) = self.manager.get_active().map(|c| c.config.db_type) else {
+            return;
+        };
+        if !db_type.uses_sql() {
+            self.notifications.warning(tf("notify.server_filter_unsupported", &[db_type.display_name()]));
+            return;
+        }
+        let sql = self.quote_table_name(&table).and_then(|quoted_table| {
+            Ok(match ui::filters_to_where(&self.grid_state.filters, db_type)? {
+                Some(clause) => db_type.select_where_sql(&quoted_table, &clause, self.app_config.default_query_limit),
+                None => db_type.select_all_sql(&quoted_table, self.app_config.default_query_limit),
+            })
+        });
+        match sql {
+            Ok(sql) => {
+                self.sql = sql.clone();
+                self.show_sql_editor = true;
+                self.focus_sql_editor = true;
+                self.execute(sql);
+            }
+            Err(e) => {
+                self.notifications.error(tf("notify.filter_sql_failed", &[&e]));
+            }
+        }
+    }
+
+    /// 当前连接是否使用 SQL（Redis 使用命令控制台，MongoDB 使用 JSON 命令）
+    fn uses_sql(&self) -> bool {
+        self.manager.get_active()
+            .map(|c| c.config.db_type.uses_sql())
+            .unwrap_or(true)
+    }
+
+    /// 预览表数据（Redis 按键类型读取值）
+    fn preview_table(&mut self, table: &str) {
+        let is_redis = self.manager.get_active()
+            .is_some_and(|c| c.config.db_type == DatabaseType::Redis);
+        if is_redis {
+            self.show_redis_key(table.to_string());
+        } else if let Ok(sql) = self.table_preview_sql(table) {
+            self.execute(sql);
+        }
+    }
+
+    /// 打开导出对话框（MongoDB 结果默认导出为保留嵌套结构的 JSON）
+    fn open_export_dialog(&mut self) {
+        let is_mongodb = self.manager.get_active()
+            .is_some_and(|c| c.config.db_type == DatabaseType::MongoDB);
+        if is_mongodb {
+            self.export_config.format = crate::core::ExportFormat::Json;
+            self.export_config.json_nested = true;
+        }
+        // 按表格中调整后的列顺序导出
+        self.export_config.column_order = self
+            .result
+            .as_ref()
+            .map(|r| self.grid_state.ordered_columns(&r.columns))
+            .unwrap_or_default();
+        self.prepare_export_masking();
+        self.show_export_dialog = true;
+        self.export_status = None;
+    }
+
     fn set_theme(&mut self, ctx: &egui::Context, preset: ThemePreset) {
         self.theme_manager.set_theme(preset);
         self.theme_manager.apply(ctx);
         self.highlight_colors = HighlightColors::from_theme(&self.theme_manager.colors);
         self.app_config.theme_preset = preset;
+        self.app_config.custom_theme = None;
         // 清除语法高亮缓存，确保使用新主题颜色
         clear_highlight_cache();
         let _ = self.app_config.save();
@@ -357,13 +867,16 @@ impl DbManagerApp {
         // 保存当前连接的历史记录
         self.save_current_history();
 
+        // 草稿本是临时连接，不写入配置
         self.app_config.connections = self
             .manager
             .connections
             .values()
+            .filter(|c| !c.config.is_scratchpad())
             .map(|c| c.config.clone())
             .collect();
         let _ = self.app_config.save();
+        self.sync_api_server();
     }
 
     /// 保存当前连接的历史记录到配置
@@ -429,24 +942,61 @@ impl DbManagerApp {
         }
     }
 
+    /// 加载当前数据库的序列/自增计数器（保留旧列表直到新结果返回，刷新后选中位置不变）
+    fn load_sequences(&mut self) {
+        if let Some(conn) = self.manager.get_active() {
+            let config = conn.config.clone();
+            let tx = self.tx.clone();
+
+            self.sidebar_panel_state.loading_sequences = true;
+
+            self.runtime.spawn(async move {
+                let result = crate::database::get_sequences(&config).await;
+                let _ = tx.send(Message::SequencesFetched(result.map_err(|e| e.to_string())));
+            });
+        }
+    }
+
+    /// 获取服务器时区，用于将不带时区的时间戳转换为 UTC 或本地时间
+    fn load_server_timezone(&mut self, conn_name: &str) {
+        if let Some(conn) = self.manager.connections.get(conn_name) {
+            let config = conn.config.clone();
+            let tx = self.tx.clone();
+            let conn_name = conn_name.to_string();
+
+            self.runtime.spawn(async move {
+                let offset = crate::database::get_server_utc_offset(&config).await.ok().flatten();
+                let _ = tx.send(Message::ServerTimezoneFetched(conn_name, offset));
+            });
+        }
+    }
+
     fn handle_export_with_config(&mut self, config: ExportConfig) {
+        if self.confirm_unmasked_export(&config) {
+            return;
+        }
+        self.export_with_config(config);
+    }
+
+    fn export_with_config(&mut self, config: ExportConfig) {
+        self.load_spilled_pages();
         let table_name = self
             .selected_table
             .clone()
             .unwrap_or_else(|| "query_result".to_string());
 
         if let Some(result) = &self.result {
-            let filter_name = format!("{} 文件", config.format.display_name());
-            let filter_ext = config.format.extension();
+            let (format_name, filter_ext) = config.format_label(&self.plugins);
+            let filter_name = format!("{} 文件", format_name);
 
             let file_dialog = rfd::FileDialog::new()
                 .set_file_name(format!("{}.{}", table_name, filter_ext))
-                .add_filter(&filter_name, &[filter_ext]);
+                .add_filter(&filter_name, &[filter_ext.as_str()]);
 
             if let Some(path) = file_dialog.save_file() {
                 // 使用导出模块执行导出
                 self.export_status =
-                    Some(export::execute_export(result, &table_name, &path, &config));
+                    Some(export::execute_export(result, &table_name, &path, &config, &self.plugins));
             }
         }
     }
@@ -460,27 +1010,41 @@ impl DbManagerApp {
 impl eframe::App for DbManagerApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         self.handle_messages(ctx);
-        self.handle_keyboard_shortcuts(ctx);
+        self.run_scheduled_queries(ctx);
+        self.run_metadata_refresh(ctx);
+        self.run_health_checks(ctx);
+        self.run_pool_cleanup(ctx);
+        let mut toolbar_actions = ToolbarActions::default();
+        self.handle_keyboard_shortcuts(ctx, &mut toolbar_actions);
         self.handle_zoom_shortcuts(ctx);
+        self.save_er_screenshot(ctx);
+        self.show_macro_indicator(ctx);
+        self.show_detached_tabs(ctx);
         
         // 清理过期通知，如果有通知被清理则请求重绘
         if self.notifications.tick() {
             ctx.request_repaint();
         }
 
-        let mut toolbar_actions = ToolbarActions::default();
-
-        // 检测焦点切换快捷键
-        self.handle_focus_shortcuts(ctx, &mut toolbar_actions);
-
         // ===== 对话框 =====
-        let dialog_results = self.render_dialogs(ctx);
+        let mut dialog_results = self.render_dialogs(ctx);
+        if let Some(command) = dialog_results.palette_command.take() {
+            self.run_palette_command(command, &mut toolbar_actions);
+        }
         let save_connection = dialog_results.save_connection;
         self.handle_dialog_results(dialog_results);
+        self.sync_column_settings();
+        self.sync_masked_columns();
+        self.sync_row_bookmarks();
 
         // SQL 编辑器操作（将在主内容区内部渲染）
         let mut sql_editor_actions = SqlEditorActions::default();
 
+        // ===== 底部按键提示栏 =====
+        if self.app_config.show_key_hints {
+            self.render_hint_bar(ctx);
+        }
+
         // ===== 中心面板 =====
         let central_frame = egui::Frame::NONE
             .fill(ctx.style().visuals.panel_fill)
@@ -529,6 +1093,7 @@ impl eframe::App for DbManagerApp {
                         egui::Layout::top_down(egui::Align::LEFT),
                         |ui| {
                             ui.set_min_size(egui::vec2(sidebar_width, available_height));
+                            self.mark_tour_target(TourTarget::Sidebar, ui.max_rect());
                             
                             // 只有在没有对话框打开时，侧边栏才响应键盘
                             let is_sidebar_focused = self.focus_area == ui::FocusArea::Sidebar 
@@ -540,6 +1105,22 @@ impl eframe::App for DbManagerApp {
                                 .as_ref()
                                 .map(|r| r.columns.clone())
                                 .unwrap_or_default();
+                            let column_types =
+                                self.selected_table.as_deref().and_then(|t| self.autocomplete.column_types(t));
+                            let preset_target = self.filter_preset_target();
+                            let filter_presets = preset_target.as_ref().and_then(|t| {
+                                let table = t.table.as_deref()?;
+                                Some(self.app_config.filter_presets.for_table(&t.connection, t.database.as_deref(), table))
+                            });
+
+                            // 各连接排队中的查询数，显示在连接名旁
+                            self.sidebar_panel_state.queued_queries = self
+                                .manager
+                                .connections
+                                .keys()
+                                .map(|name| (name.clone(), self.query_queue.queued(name)))
+                                .filter(|&(_, queued)| queued > 0)
+                                .collect();
 
                             let (actions, filter_changed) = ui::Sidebar::show_in_ui(
                                 ui,
@@ -552,6 +1133,9 @@ impl eframe::App for DbManagerApp {
                                 sidebar_width,
                                 &mut self.grid_state.filters,
                                 &columns,
+                                column_types,
+                                filter_presets,
+                                &self.plugins,
                             );
                             sidebar_actions = actions;
 
@@ -641,9 +1225,11 @@ impl eframe::App for DbManagerApp {
                                     selected_table_for_toolbar.as_deref(),
                                     self.ui_scale,
                                     &self.progress,
+                                    self.notifications.unseen_errors(),
                                     is_toolbar_focused,
                                     self.toolbar_index,
                                 );
+                                self.mark_tour_target(TourTarget::Toolbar, ui.min_rect());
                                 
                                 // 处理进度任务取消
                                 if let Some(id) = cancel_task_id {
@@ -681,11 +1267,12 @@ impl eframe::App for DbManagerApp {
                                     ui,
                                     &self.tab_manager.tabs,
                                     self.tab_manager.active_index,
+                                    &mut self.tab_manager.renaming,
                                     &self.highlight_colors,
                                 );
                                 
-                                // 如果焦点在Tab栏，处理键盘输入
-                                if self.focus_area == ui::FocusArea::QueryTabs {
+                                // 如果焦点在Tab栏（且没有在重命名），处理键盘输入
+                                if self.focus_area == ui::FocusArea::QueryTabs && self.tab_manager.renaming.is_none() {
                                     ui::QueryTabBar::handle_keyboard(
                                         ui,
                                         self.tab_manager.tabs.len(),
@@ -696,7 +1283,7 @@ impl eframe::App for DbManagerApp {
                                     // 显示焦点提示
                                     ui.horizontal(|ui| {
                                         ui.label(egui::RichText::new("TAB焦点").small().color(self.highlight_colors.keyword));
-                                        ui.label(egui::RichText::new(" h/l:切换 j:表格 k:工具栏 d:删除").small().color(egui::Color32::GRAY));
+                                        ui.label(egui::RichText::new(" h/l:切换 j:表格 k:工具栏 d:删除 r:重命名 p:固定").small().color(egui::Color32::GRAY));
                                     });
                                 }
                                 
@@ -728,7 +1315,7 @@ impl eframe::App for DbManagerApp {
                                 let data_grid_height = total_content_height - sql_editor_height - divider_height;
 
                                 // 数据表格区域（支持左右分割显示 ER 图）
-                                ui.allocate_ui_with_layout(
+                                let grid_rect = ui.allocate_ui_with_layout(
                                     egui::vec2(ui.available_width(), data_grid_height),
                                     egui::Layout::top_down(egui::Align::LEFT),
                                     |ui| {
@@ -754,18 +1341,65 @@ impl eframe::App for DbManagerApp {
                                                         self.grid_state.focused = self.focus_area == ui::FocusArea::DataGrid 
                                                             && !self.has_modal_dialog_open();
                                                         
-                                                        let table_name = self.selected_table.as_deref();
+                                                        // 非 SQL 数据源不支持表格编辑生成 SQL
+                                                        let uses_sql = self.uses_sql();
+                                                        self.grid_state.db_type = self.manager.get_active().map(|c| c.config.db_type);
+                                                        self.grid_state.value_format.server_offset = self.manager.get_active().and_then(|c| c.server_utc_offset);
+                                                        let table_name = self.selected_table.as_deref().filter(|_| uses_sql);
+                                                        // 搜索栏：输入时表格不响应键盘
+                                                        let column_types = self.selected_table.as_deref().and_then(|t| self.autocomplete.column_types(t));
+                                                        if ui::SearchBar::show(ui, &mut self.search_text, &mut self.search_column, &mut self.search_options, &result.columns, column_types) {
+                                                            self.grid_state.focused = false;
+                                                        }
                                                         let (grid_actions, _) = ui::DataGrid::show_editable(
                                                             ui,
                                                             result,
                                                             &self.search_text,
                                                             &self.search_column,
+                                                            &self.search_options,
                                                             &mut self.selected_row,
                                                             &mut self.selected_cell,
                                                             &mut self.grid_state,
                                                             table_name,
                                                         );
                                                         
+                                                        // 处理翻页请求
+                                                        if let Some(index) = grid_actions.page_request {
+                                                            self.execute_page(index);
+                                                        }
+
+                                                        // 处理服务端筛选请求
+                                                        if grid_actions.apply_filters_server_side {
+                                                            self.apply_filters_server_side();
+                                                        }
+
+                                                        // 处理批量修改请求
+                                                        if grid_actions.bulk_edit {
+                                                            self.open_bulk_edit();
+                                                        }
+
+                                                        // 处理打印视图请求
+                                                        if grid_actions.print_view {
+                                                            self.save_print_view(grid_actions.display_rows);
+                                                        } else if grid_actions.pivot_view {
+                                                            self.open_pivot(&grid_actions.display_rows);
+                                                        }
+
+                                                        // 保存修改的列数字格式、列宽和列顺序
+
+                                                        if grid_actions.column_formats_changed
+                                                            || grid_actions.column_widths_changed
+                                                            || grid_actions.column_order_changed
+                                                        {
+                                                            self.save_column_settings();
+                                                        }
+                                                        if let Some((column, masked)) = &grid_actions.masked_column {
+                                                            self.set_column_masked(column, *masked);
+                                                        }
+                                                        if let Some(row) = grid_actions.bookmark_row {
+                                                            self.toggle_row_bookmark(row);
+                                                        }
+
                                                         // 处理打开筛选面板请求
                                                         if grid_actions.open_filter_panel {
                                                             self.show_sidebar = true;
@@ -849,6 +1483,21 @@ impl eframe::App for DbManagerApp {
                                                 if er_response.fit_view_requested {
                                                     self.er_diagram_state.fit_to_view(ui.available_size());
                                                 }
+                                                if let Some(format) = er_response.export_requested {
+                                                    self.export_er_diagram(format);
+                                                }
+                                                if let Some(rect) = er_response.capture_rect {
+                                                    self.capture_er_diagram(ui.ctx(), rect);
+                                                }
+                                                if er_response.new_table_requested {
+                                                    self.design_er_table(None);
+                                                }
+                                                if let Some(name) = er_response.edit_table_requested {
+                                                    self.design_er_table(Some(&name));
+                                                }
+                                                if er_response.generate_ddl_requested {
+                                                    self.generate_er_design_ddl();
+                                                }
                                             }
                                         );
                                     });
@@ -858,12 +1507,22 @@ impl eframe::App for DbManagerApp {
                                         self.grid_state.focused = self.focus_area == ui::FocusArea::DataGrid 
                                             && !self.has_modal_dialog_open();
                                         
-                                        let table_name = self.selected_table.as_deref();
+                                        // 非 SQL 数据源不支持表格编辑生成 SQL
+                                        let uses_sql = self.uses_sql();
+                                        self.grid_state.db_type = self.manager.get_active().map(|c| c.config.db_type);
+                                        self.grid_state.value_format.server_offset = self.manager.get_active().and_then(|c| c.server_utc_offset);
+                                        let table_name = self.selected_table.as_deref().filter(|_| uses_sql);
+                                        // 搜索栏：输入时表格不响应键盘
+                                        let column_types = self.selected_table.as_deref().and_then(|t| self.autocomplete.column_types(t));
+                                        if ui::SearchBar::show(ui, &mut self.search_text, &mut self.search_column, &mut self.search_options, &result.columns, column_types) {
+                                            self.grid_state.focused = false;
+                                        }
                                         let (grid_actions, _) = ui::DataGrid::show_editable(
                                             ui,
                                             result,
                                             &self.search_text,
                                             &self.search_column,
+                                            &self.search_options,
                                             &mut self.selected_row,
                                             &mut self.selected_cell,
                                             &mut self.grid_state,
@@ -875,17 +1534,20 @@ impl eframe::App for DbManagerApp {
                                             self.notifications.info(msg);
                                         }
 
-                                        // 执行生成的 SQL
-                                        for sql in grid_actions.sql_to_execute {
-                                            self.execute(sql);
+                                        // 保存修改生成的 SQL
+                                        if !grid_actions.sql_to_review.is_empty() {
+                                            self.review_grid_sql(grid_actions.sql_to_review);
+                                        }
+
+                                        // 处理翻页请求
+                                        if let Some(index) = grid_actions.page_request {
+                                            self.execute_page(index);
                                         }
 
                                         // 处理刷新请求
                                         if grid_actions.refresh_requested
-                                            && let Some(table) = &self.selected_table
-                                            && let Ok(quoted_table) = ui::quote_identifier(table, self.is_mysql()) {
-                                                let sql = format!("SELECT * FROM {} LIMIT {};", quoted_table, constants::database::DEFAULT_QUERY_LIMIT);
-                                                self.execute(sql);
+                                            && let Some(table) = self.selected_table.clone() {
+                                                self.preview_table(&table);
                                             }
                                         
                                         // 处理焦点转移请求
@@ -915,6 +1577,38 @@ impl eframe::App for DbManagerApp {
                                             self.grid_state.focused = true;
                                         }
                                         
+                                        // 处理服务端筛选请求
+                                        if grid_actions.apply_filters_server_side {
+                                            self.apply_filters_server_side();
+                                        }
+
+                                        // 处理批量修改请求
+                                        if grid_actions.bulk_edit {
+                                            self.open_bulk_edit();
+                                        }
+
+                                        // 处理打印视图请求
+                                        if grid_actions.print_view {
+                                            self.save_print_view(grid_actions.display_rows);
+                                        } else if grid_actions.pivot_view {
+                                            self.open_pivot(&grid_actions.display_rows);
+                                        }
+
+                                        // 保存修改的列数字格式、列宽和列顺序
+
+                                        if grid_actions.column_formats_changed
+                                            || grid_actions.column_widths_changed
+                                            || grid_actions.column_order_changed
+                                        {
+                                            self.save_column_settings();
+                                        }
+                                        if let Some((column, masked)) = &grid_actions.masked_column {
+                                            self.set_column_masked(column, *masked);
+                                        }
+                                        if let Some(row) = grid_actions.bookmark_row {
+                                            self.toggle_row_bookmark(row);
+                                        }
+
                                         // 处理打开筛选面板请求
                                         if grid_actions.open_filter_panel {
                                             self.show_sidebar = true;
@@ -924,10 +1618,10 @@ impl eframe::App for DbManagerApp {
                                         }
                                         
                                         // 处理切换Tab请求 (数字+Enter)
-                                        if let Some(tab_idx) = grid_actions.switch_to_tab {
-                                            if tab_idx < self.tab_manager.tabs.len() {
-                                                self.tab_manager.set_active(tab_idx);
-                                            }
+                                        if let Some(tab_idx) = grid_actions.switch_to_tab
+                                            && tab_idx < self.tab_manager.tabs.len()
+                                        {
+                                            self.activate_tab(tab_idx);
                                         }
                                     } else if result.affected_rows > 0 {
                                         ui.vertical_centered(|ui| {
@@ -951,18 +1645,39 @@ impl eframe::App for DbManagerApp {
                                     ui::Welcome::show(ui);
                                 } else if self.manager.active.is_some() {
                                     // 有连接但没有结果
+                                    let db_type = self.manager.get_active()
+                                        .map(|c| c.config.db_type)
+                                        .unwrap_or_default();
+                                    let mut preview_key = None;
                                     ui.vertical_centered(|ui| {
                                         ui.add_space(50.0);
-                                        ui.label("在底部命令行输入 SQL 查询");
+                                        ui.label(match db_type {
+                                            DatabaseType::Redis => "在底部命令控制台输入 Redis 命令",
+                                            DatabaseType::MongoDB => "在底部输入 JSON 格式的 MongoDB 命令（find、aggregate 等）",
+                                            _ => "在底部命令行输入 SQL 查询",
+                                        });
                                         ui.add_space(8.0);
 
-                                        if let Some(table) = &self.selected_table
-                                            && ui.button(format!("查询表 {} 的数据", table)).clicked()
-                                                && let Ok(quoted_table) = ui::quote_identifier(table, self.is_mysql()) {
-                                                    self.sql = format!("SELECT * FROM {} LIMIT {};", quoted_table, constants::database::DEFAULT_QUERY_LIMIT);
+                                        if let Some(table) = &self.selected_table {
+                                            let button_text = match db_type {
+                                                DatabaseType::Redis => format!("查看键 {} 的值", table),
+                                                DatabaseType::MongoDB => format!("查询集合 {} 的文档", table),
+                                                _ => format!("查询表 {} 的数据", table),
+                                            };
+                                            if db_type == DatabaseType::Redis {
+                                                if ui.button(button_text).clicked() {
+                                                    preview_key = Some(table.clone());
+                                                }
+                                            } else if ui.button(button_text).clicked()
+                                                && let Ok(sql) = self.table_preview_sql(table) {
+                                                    self.sql = sql;
                                                     sql_editor_actions.execute = true;
                                                 }
+                                        }
                                     });
+                                    if let Some(key) = preview_key {
+                                        self.show_redis_key(key);
+                                    }
                                 } else {
                                     ui.vertical_centered(|ui| {
                                         ui.add_space(50.0);
@@ -970,20 +1685,39 @@ impl eframe::App for DbManagerApp {
                                     });
                                 }
                                     }
-                                ); // allocate_ui_with_layout 数据表格区域结束
+                                ).response.rect; // allocate_ui_with_layout 数据表格区域结束
+                                self.mark_tour_target(TourTarget::Grid, grid_rect);
 
                                 // ===== SQL 编辑器 =====
+                                let editor_top = ui.cursor().min;
                                 sql_editor_actions = self.render_sql_editor_in_ui(ui, total_content_height);
+                                if self.show_sql_editor {
+                                    let editor_rect = egui::Rect::from_min_max(
+                                        editor_top,
+                                        egui::pos2(ui.max_rect().max.x, ui.min_rect().max.y),
+                                    );
+                                    self.mark_tour_target(TourTarget::Editor, editor_rect);
+                                }
                             }); // Frame 闭包结束
                     }
                 ); // allocate_ui_with_layout 主内容区结束
             }); // horizontal 布局结束
         }); // CentralPanel 闭包结束
+        self.show_tour(ctx);
         
         // ===== 处理各种操作 =====
+        if let Some(command) = sql_editor_actions.command.take() {
+            self.run_editor_command(command, &mut toolbar_actions);
+        }
         self.handle_toolbar_actions(ctx, toolbar_actions);
         self.handle_sidebar_actions(sidebar_actions);
         self.handle_sql_editor_actions(sql_editor_actions);
+        self.sync_file_tab();
+        self.check_sql_files();
+        self.check_config_file(ctx);
+        self.autosave_recovery();
+        self.check_result_memory(ctx);
+        self.handle_dropped_files(ctx);
 
         // 保存新连接
         if save_connection {
@@ -998,13 +1732,27 @@ impl eframe::App for DbManagerApp {
         ui::NotificationToast::show(ctx, &self.notifications);
         
         // 持续刷新（有活动任务或有通知时需要刷新）
-        if self.connecting || self.executing || !self.notifications.is_empty() {
+        if self.connecting || self.executing || !self.query_queue.is_idle() || !self.notifications.is_empty() {
             ctx.request_repaint();
         }
+        // 有关联文件的标签页时定期刷新以检测外部修改
+        if self.tab_manager.tabs.iter().any(|tab| tab.file.is_some()) {
+            ctx.request_repaint_after(std::time::Duration::from_secs(crate::core::FILE_CHECK_INTERVAL_SECS));
+        }
+        // 定期刷新以检测配置文件的外部修改
+        ctx.request_repaint_after(std::time::Duration::from_secs(crate::core::CONFIG_CHECK_INTERVAL_SECS));
+        ctx.request_repaint_after(std::time::Duration::from_secs(crate::core::RECOVERY_AUTOSAVE_SECS));
+    }
+
+    fn raw_input_hook(&mut self, ctx: &egui::Context, raw_input: &mut egui::RawInput) {
+        self.key_macro_input(ctx, raw_input);
     }
 
     fn on_exit(&mut self, _gl: Option<&eframe::glow::Context>) {
         self.save_config();
+        self.save_pinned_tabs();
+        self.clear_recovery();
+        self.spill_store.clear();
         
         // 清理连接池，确保所有数据库连接正确关闭
         self.runtime.block_on(async {
@@ -0,0 +1,69 @@
+//! 查询标签页的重命名和固定
+//!
+//! 固定的标签页在"关闭其他/关闭右侧"时保留，退出时保存到会话文件，下次启动时恢复在最左侧。
+//! 定时查询的结果标签页由任务管理，不保存到会话。
+
+use crate::core::{tf, SessionState};
+use crate::ui::{self, QueryTab};
+
+use super::DbManagerApp;
+
+impl DbManagerApp {
+    /// 开始重命名标签页（焦点移到 Tab 栏，避免输入被表格当作快捷键）
+    pub(super) fn start_tab_rename(&mut self, idx: usize) {
+        self.tab_manager.start_rename(idx);
+        self.focus_area = ui::FocusArea::QueryTabs;
+        self.grid_state.focused = false;
+    }
+
+    /// 切换标签页的固定状态
+    pub(super) fn toggle_tab_pin(&mut self, idx: usize) {
+        let Some(pinned) = self.tab_manager.toggle_pin(idx) else {
+            return;
+        };
+        let title = &self.tab_manager.tabs[idx].title;
+        if pinned {
+            self.notifications.info(tf("notify.tab_pinned", &[title]));
+        } else {
+            self.notifications.info(tf("notify.tab_unpinned", &[title]));
+        }
+    }
+
+    /// 恢复上次退出时固定的标签页
+    pub(super) fn restore_pinned_tabs(&mut self) {
+        let Some(session) = SessionState::load() else {
+            return;
+        };
+        let room = self.tab_manager.max_tabs.saturating_sub(self.tab_manager.tabs.len());
+        let restored: Vec<QueryTab> = session
+            .tabs
+            .into_iter()
+            .filter(|t| t.pinned)
+            .take(room)
+            .map(QueryTab::from_state)
+            .collect();
+        if restored.is_empty() {
+            return;
+        }
+        // 固定的标签页排在最左侧，仍然激活启动时新建的空白标签页
+        let count = restored.len();
+        self.tab_manager.tabs.splice(0..0, restored);
+        self.tab_manager.active_index += count;
+    }
+
+    /// 把固定的标签页保存到会话文件
+    pub(super) fn save_pinned_tabs(&self) {
+        let mut session = SessionState::load().unwrap_or_default();
+        session.tabs = self
+            .tab_manager
+            .tabs
+            .iter()
+            .filter(|tab| tab.pinned && !tab.scheduled)
+            .map(QueryTab::to_state)
+            .collect();
+        session.active_tab_index = 0;
+        if let Err(e) = session.save() {
+            tracing::warn!(error = %e, "保存固定的标签页失败");
+        }
+    }
+}
@@ -0,0 +1,63 @@
+//! 透视表
+//!
+//! 表格中点击"透视"后对当前显示的行做交叉汇总，结果可以导出为 CSV。
+//! 敏感列以脱敏后的值参与分组，避免在透视表中显示原始值。
+
+use std::borrow::Cow;
+
+use crate::core::{mask_value, tf};
+use crate::database::QueryResult;
+use super::DbManagerApp;
+
+impl DbManagerApp {
+    /// 打开透视表窗口，`rows` 为表格显示的原始行索引
+    pub(super) fn open_pivot(&mut self, rows: &[usize]) {
+        self.load_spilled_pages();
+        let Some(result) = self.result.as_deref().filter(|r| !r.columns.is_empty()) else {
+            return;
+        };
+        let masked: Vec<bool> = result.columns.iter().map(|c| self.grid_state.is_masked(c)).collect();
+        let source = if masked.contains(&true) {
+            Cow::Owned(QueryResult {
+                columns: result.columns.clone(),
+                rows: result
+                    .rows
+                    .iter()
+                    .map(|row| {
+                        row.iter()
+                            .zip(&masked)
+                            .map(|(value, &masked)| if masked { mask_value(value) } else { value.clone() })
+                            .collect()
+                    })
+                    .collect(),
+                ..Default::default()
+            })
+        } else {
+            Cow::Borrowed(result)
+        };
+        self.pivot_state.open(&source, rows);
+    }
+
+    /// 将透视结果保存为 CSV 文件
+    pub(super) fn export_pivot(&mut self) {
+        let Some(report) = self.pivot_state.table().map(|t| t.to_query_result()) else {
+            return;
+        };
+        let Some(path) = rfd::FileDialog::new()
+            .set_file_name("pivot.csv")
+            .add_filter("CSV 文件", &["csv"])
+            .save_file()
+        else {
+            return;
+        };
+
+        match crate::core::export_to_csv(&report, &path) {
+            Ok(()) => {
+                self.notifications.success(tf("notify.pivot_exported", &[&path.display().to_string()]));
+            }
+            Err(e) => {
+                self.notifications.error(tf("common.export_failed", &[&e]));
+            }
+        }
+    }
+}
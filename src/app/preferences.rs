@@ -0,0 +1,77 @@
+//! 首选项模块
+//!
+//! 预览、保存或撤销首选项窗口中的修改。
+
+use std::sync::Arc;
+
+use crate::core::{set_language, system_fonts, t, tf, Preferences};
+use crate::ui;
+use eframe::egui;
+use super::DbManagerApp;
+
+impl DbManagerApp {
+    /// 将首选项应用到界面（不写入配置文件）
+    ///
+    /// 历史记录条数只在保存时生效，避免预览时截断已有记录。
+    fn preview_preferences(&mut self, ctx: &egui::Context, prefs: &Preferences) {
+        let history_limit = self.app_config.history_limit;
+        let command_history_limit = self.app_config.command_history_limit;
+        let image_preview_dir = self.app_config.image_preview_dir.clone();
+        self.app_config.apply_preferences(prefs);
+        self.app_config.history_limit = history_limit;
+        self.app_config.command_history_limit = command_history_limit;
+
+        // 预览目录变化时重新注册图片预览渲染器
+        if self.app_config.image_preview_dir != image_preview_dir {
+            let mut plugins = (*self.plugins).clone();
+            plugins.set_image_preview_dir(self.app_config.image_preview_dir.as_deref());
+            self.plugins = Arc::new(plugins);
+            self.grid_state.plugins = Arc::clone(&self.plugins);
+        }
+
+        set_language(prefs.language);
+        if (self.ui_scale - prefs.ui_scale).abs() > f32::EPSILON {
+            self.ui_scale = prefs.ui_scale;
+            ctx.set_pixels_per_point(self.base_pixels_per_point * prefs.ui_scale);
+        }
+        self.grid_state.show_footer = prefs.show_grid_footer;
+        self.grid_state.value_format.timestamp_display = prefs.timestamp_display;
+        self.grid_state.value_format.number = prefs.number_format;
+    }
+
+    /// 处理首选项窗口的结果
+    pub(super) fn handle_preferences_result(&mut self, ctx: &egui::Context, result: ui::PreferencesResult) {
+        match result {
+            ui::PreferencesResult::None => {}
+            ui::PreferencesResult::Preview(prefs) | ui::PreferencesResult::Cancel(prefs) => {
+                self.preview_preferences(ctx, &prefs);
+            }
+            ui::PreferencesResult::Save(prefs) => {
+                self.preview_preferences(ctx, &prefs);
+                self.app_config.apply_preferences(&prefs);
+                self.set_query_limit(prefs.max_parallel_queries);
+
+                self.query_history.set_max_size(prefs.history_limit);
+                self.save_query_history();
+                self.command_history.truncate(prefs.command_history_limit);
+                self.save_current_history();
+                for history in self.app_config.command_history.values_mut() {
+                    history.truncate(prefs.command_history_limit);
+                }
+
+                if let Err(e) = self.app_config.save() {
+                    self.notifications.error(tf("common.save_config_failed", &[&e]));
+                } else {
+                    self.notifications.success(t("prefs.saved"));
+                };
+            }
+            ui::PreferencesResult::OpenFontSettings => {
+                self.font_settings_state
+                    .open(self.app_config.fonts.clone(), system_fonts().to_vec());
+            }
+            ui::PreferencesResult::OpenKeyBindings => {
+                self.keybindings_dialog_state.open(&self.keybindings);
+            }
+        }
+    }
+}
@@ -0,0 +1,75 @@
+//! 打印视图
+//!
+//! 把表格当前显示的行和列（筛选、排序后，不含隐藏的列）保存为分页的 HTML 报表，
+//! 用浏览器打开后打印或打印为 PDF。敏感列和表格中一样显示脱敏值。
+
+use crate::core::{mask_value, tf, PrintReport};
+
+use super::DbManagerApp;
+
+impl DbManagerApp {
+    /// 保存打印视图，`rows` 为表格显示的原始行索引（按显示顺序）
+    pub(super) fn save_print_view(&mut self, rows: Vec<usize>) {
+        self.load_spilled_pages();
+        let Some(result) = self.result.clone() else {
+            return;
+        };
+        let title = self
+            .selected_table
+            .clone()
+            .or_else(|| self.tab_manager.get_active().map(|t| t.title.clone()))
+            .unwrap_or_else(|| "query_result".to_string());
+        let now = chrono::Local::now();
+        let Some(path) = rfd::FileDialog::new()
+            .set_file_name(format!("{}_{}.html", title, now.format("%Y%m%d_%H%M%S")))
+            .add_filter("HTML 文件", &["html"])
+            .save_file()
+        else {
+            return;
+        };
+
+        let mut report = PrintReport::new(title, now.format("%Y-%m-%d %H:%M:%S").to_string());
+        report.sql = self.tab_manager.get_active().map(|t| t.sql.clone());
+        report.filters = self
+            .grid_state
+            .filters
+            .iter()
+            .filter(|f| f.enabled && f.is_valid())
+            .map(|f| f.describe())
+            .collect();
+        if !self.search_text.is_empty() {
+            report.filters.push(format!("搜索 \"{}\"", self.search_text));
+        }
+
+        let columns = self.grid_state.visible_columns(&result.columns);
+        let column_names: Vec<String> = columns.iter().map(|&c| result.columns[c].clone()).collect();
+        let masked: Vec<bool> = column_names.iter().map(|name| self.grid_state.is_masked(name)).collect();
+        let cells: Vec<Vec<String>> = rows
+            .iter()
+            .filter_map(|&r| result.rows.get(r))
+            .map(|row| {
+                columns
+                    .iter()
+                    .zip(&masked)
+                    .map(|(&c, &masked)| {
+                        let value = row.get(c).map_or("", String::as_str);
+                        if masked { mask_value(value) } else { value.to_string() }
+                    })
+                    .collect()
+            })
+            .collect();
+        let cell_refs: Vec<&[String]> = cells.iter().map(Vec::as_slice).collect();
+        let html = report.to_html(&column_names, &cell_refs);
+
+        match std::fs::write(&path, html) {
+            Ok(()) => {
+                let rows = cells.len().to_string();
+                let pages = report.page_count(cells.len()).to_string();
+                self.notifications.success(tf("notify.print_view_saved", &[&rows, &pages, &path.display().to_string()]));
+            }
+            Err(e) => {
+                self.notifications.error(tf("notify.print_view_failed", &[&e.to_string()]));
+            }
+        }
+    }
+}
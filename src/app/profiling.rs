@@ -0,0 +1,67 @@
+//! 查询分析
+//!
+//! 再次执行最近的查询，读取数据库提供的运行时统计并显示在查询分析面板中。
+//! 分析会真正执行查询，因此只分析只读查询。
+
+use crate::core::{is_read_only_statement, t};
+use crate::database::{profile_query, supports_profiling};
+
+use super::message::Message;
+use super::DbManagerApp;
+
+impl DbManagerApp {
+    /// 打开查询分析面板并分析当前连接最近成功执行的查询
+    pub(super) fn open_profile(&mut self) {
+        let active = self.manager.active.clone();
+        let latest = self
+            .query_history
+            .items()
+            .iter()
+            .find(|item| item.success && item.connection == active)
+            .map(|item| (item.sql.clone(), item.elapsed_ms));
+        match latest {
+            Some((sql, elapsed_ms)) => self.start_profile(sql, elapsed_ms),
+            None => self.profile_state.show = true,
+        }
+    }
+
+    /// 查询成功后自动分析（面板打开且勾选了自动分析时）
+    pub(super) fn profile_after_query(&mut self, sql: &str, elapsed_ms: u64) {
+        if !self.profile_state.show || !self.profile_state.auto || self.profile_state.loading {
+            return;
+        }
+        // 写操作和 EXPLAIN 本身不分析，保留面板中上次的结果
+        let is_explain = sql.trim_start().get(..7).is_some_and(|p| p.eq_ignore_ascii_case("explain"));
+        if is_read_only_statement(sql) && !is_explain {
+            self.start_profile(sql.to_string(), Some(elapsed_ms));
+        }
+    }
+
+    /// 在后台分析查询，结果通过 `Message::QueryProfiled` 发送
+    pub(super) fn start_profile(&mut self, sql: String, query_elapsed_ms: Option<u64>) {
+        let Some(config) = self.manager.get_active().map(|c| c.config.clone()) else {
+            self.notifications.warning(t("common.need_connection"));
+            return;
+        };
+        if !supports_profiling(config.db_type) {
+            let error = format!("{} 不支持查询分析", config.db_type.display_name());
+            self.profile_state.fail(sql, query_elapsed_ms, error);
+            return;
+        }
+        if !is_read_only_statement(&sql) {
+            let error = "分析会再次执行查询，只能分析只读查询".to_string();
+            self.profile_state.fail(sql, query_elapsed_ms, error);
+            return;
+        }
+
+        let request_id = self.profile_state.start(sql.clone(), query_elapsed_ms);
+        let config = self.resolve_timeouts(config);
+        let tx = self.tx.clone();
+        self.runtime.spawn(async move {
+            let result = profile_query(&config, &sql).await.map_err(|e| e.to_string());
+            if tx.send(Message::QueryProfiled(request_id, result)).is_err() {
+                tracing::warn!("无法发送查询分析结果：接收端已关闭");
+            }
+        });
+    }
+}
@@ -0,0 +1,221 @@
+//! 按连接排队执行查询
+//!
+//! 编辑器提交的查询按 Tab 执行：每个连接同时执行的查询数不超过首选项中的上限，
+//! 超出的查询排队并在 Tab 栏显示排队数。后台 Tab 的查询完成时只更新该 Tab，
+//! 不影响当前 Tab 的编辑器和表格。
+
+use std::sync::Arc;
+
+use eframe::egui;
+
+use crate::core::{constants, ddl_tables, tf, AuditSource, QueryHistoryItem};
+use crate::database::{supports_paging, supports_streaming, QueryResult};
+use crate::ui::ResultPage;
+
+use super::handler::query_done_message;
+use super::DbManagerApp;
+
+/// 等待执行的查询
+pub(super) struct PendingQuery {
+    sql: String,
+    source: AuditSource,
+}
+
+impl DbManagerApp {
+    /// 提交当前 Tab 的查询：连接上执行中的查询未达上限时立即执行，否则排队
+    pub(super) fn submit_query(&mut self, connection: String, sql: String, source: AuditSource) {
+        let Some(tab_id) = self.tab_manager.get_active().map(|tab| tab.id.clone()) else {
+            return;
+        };
+        let Some(job) = self.query_queue.submit(&connection, &tab_id, PendingQuery { sql, source }) else {
+            if let Some(tab) = self.tab_manager.get_active_mut() {
+                tab.queued = true;
+            }
+            let running = self.query_queue.running(&connection).to_string();
+            let position = self.query_queue.position(&tab_id).unwrap_or(1).to_string();
+            self.notifications.info(tf("notify.query_queued", &[&connection, &running, &position]));
+            return;
+        };
+        self.start_query(connection, tab_id, job);
+    }
+
+    /// 开始执行已从队列取出的查询
+    fn start_query(&mut self, connection: String, tab_id: String, job: PendingQuery) {
+        let is_active = self.tab_manager.get_active().is_some_and(|tab| tab.id == tab_id);
+        let Some(config) = self.manager.connections.get(&connection).map(|c| c.config.clone()) else {
+            // 排队期间连接已被删除
+            let still_queued = self.query_queue.position(&tab_id).is_some();
+            if let Some(tab) = self.tab_manager.tabs.iter_mut().find(|tab| tab.id == tab_id) {
+                tab.queued = still_queued;
+                tab.last_message = Some(format!("错误: 连接 {} 已不存在", connection));
+            }
+            self.release_query_slot(&tab_id);
+            return;
+        };
+
+        // 同一 Tab 还有后续排队的查询时保持排队标记
+        let still_queued = self.query_queue.position(&tab_id).is_some();
+        if let Some(tab) = self.tab_manager.tabs.iter_mut().find(|tab| tab.id == tab_id) {
+            tab.queued = still_queued;
+            tab.executing = true;
+        }
+        if is_active {
+            self.set_result(None);
+            self.last_query_time_ms = None;
+        }
+
+        let PendingQuery { sql, source } = job;
+        let tab_id = Some(tab_id);
+        // 支持流式分页的查询只读取第一页
+        if supports_paging(config.db_type, &sql) {
+            self.spawn_page_query(config, ResultPage::first(sql, constants::database::RESULT_PAGE_SIZE), tab_id);
+            return;
+        }
+        let audit = self.audit_recorder_for(&connection, source);
+        // 支持逐批读取的查询边读取边显示
+        if supports_streaming(config.db_type, &sql) {
+            self.spawn_streaming_query(config, sql, audit, tab_id);
+        } else {
+            self.spawn_query(config, sql, audit, tab_id);
+        }
+    }
+
+    /// 处理查询完成：不属于任何 Tab 或属于当前 Tab 时更新表格，属于后台 Tab 时只更新该 Tab，
+    /// 之后开始排队中的查询
+    pub(super) fn finish_query(
+        &mut self,
+        ctx: &egui::Context,
+        tab_id: Option<String>,
+        sql: String,
+        result: Result<QueryResult, String>,
+        elapsed_ms: u64,
+        page: Option<ResultPage>,
+    ) {
+        self.notify_query_finished(ctx, tab_id.as_deref(), &sql, &result, elapsed_ms);
+
+        let Some(tab_id) = tab_id else {
+            self.handle_query_done(ctx, sql, result, elapsed_ms);
+            self.apply_first_page(page);
+            return;
+        };
+
+        if self.tab_manager.get_active().is_some_and(|tab| tab.id == tab_id) {
+            self.handle_query_done(ctx, sql, result, elapsed_ms);
+            self.apply_first_page(page);
+        } else {
+            self.handle_background_query_done(&tab_id, sql, result, elapsed_ms, page);
+        }
+        self.release_query_slot(&tab_id);
+        ctx.request_repaint();
+    }
+
+    /// 分页查询的第一页读取完成后显示分页信息
+    fn apply_first_page(&mut self, page: Option<ResultPage>) {
+        let Some(page) = page else {
+            return;
+        };
+        self.grid_state.page = Some(page.clone());
+        if let Some(tab) = self.tab_manager.get_active_mut() {
+            tab.page = Some(page);
+        }
+    }
+
+    /// 后台 Tab 的查询完成：记录历史并更新该 Tab，通知中带上 Tab 标题
+    fn handle_background_query_done(
+        &mut self,
+        tab_id: &str,
+        sql: String,
+        result: Result<QueryResult, String>,
+        elapsed_ms: u64,
+        page: Option<ResultPage>,
+    ) {
+        let connection = self.query_queue.connection_of(tab_id).map(str::to_string);
+        let db_type = connection
+            .as_ref()
+            .and_then(|name| self.manager.connections.get(name))
+            .map(|c| c.config.db_type.display_name().to_string())
+            .unwrap_or_default();
+        let ddl_targets = ddl_tables(&sql);
+
+        let (message, result) = match result {
+            Ok(mut res) => {
+                let (original_rows, was_truncated) = self.limit_result_rows(&mut res);
+                self.query_history.add(QueryHistoryItem {
+                    rows_affected: if res.affected_rows > 0 { Some(res.affected_rows) } else { None },
+                    row_count: if res.columns.is_empty() { None } else { Some(original_rows) },
+                    elapsed_ms: Some(elapsed_ms),
+                    ..QueryHistoryItem::new(sql, db_type, connection.clone(), true)
+                });
+                // 表结构变化后在后台刷新当前连接的表和列信息
+                if let Some(tables) = ddl_targets
+                    && connection.is_some()
+                    && connection == self.manager.active
+                {
+                    self.request_metadata_refresh(tables);
+                }
+                (Ok(query_done_message(&res, original_rows, was_truncated, elapsed_ms)), Some(Arc::new(res)))
+            }
+            Err(e) => {
+                let (message, _) = self.describe_query_error(&sql, &e);
+                self.query_history.add(QueryHistoryItem {
+                    elapsed_ms: Some(elapsed_ms),
+                    ..QueryHistoryItem::new(sql, db_type, connection, false)
+                });
+                (Err(message), None)
+            }
+        };
+        self.save_query_history();
+
+        // Tab 在执行期间已关闭
+        let Some(tab) = self.tab_manager.tabs.iter_mut().find(|tab| tab.id == tab_id) else {
+            return;
+        };
+        tab.executing = false;
+        tab.query_time_ms = Some(elapsed_ms);
+        if let Some(res) = result {
+            tab.result = Some(res);
+            tab.page = page;
+        }
+        match message {
+            Ok(msg) => {
+                self.notifications.success(format!("{}: {}", tab.title, msg));
+                tab.last_message = Some(msg);
+            }
+            Err(msg) => {
+                self.notifications.error(format!("{}: {}", tab.title, msg));
+                tab.last_message = Some(msg);
+            }
+        }
+    }
+
+    /// 释放 Tab 占用的执行名额，开始因此可以执行的排队查询
+    fn release_query_slot(&mut self, tab_id: &str) {
+        for (connection, tab_id, job) in self.query_queue.finish(tab_id) {
+            self.start_query(connection, tab_id, job);
+        }
+    }
+
+    /// 修改每个连接同时执行的查询数上限
+    pub(super) fn set_query_limit(&mut self, limit: usize) {
+        for (connection, tab_id, job) in self.query_queue.set_limit(limit) {
+            self.start_query(connection, tab_id, job);
+        }
+    }
+
+    /// 取消 Tab 排队中的查询
+    pub(super) fn cancel_queued_query(&mut self, index: usize) {
+        let Some(tab) = self.tab_manager.tabs.get_mut(index) else {
+            return;
+        };
+        if self.query_queue.cancel(&tab.id) {
+            tab.queued = false;
+            self.notifications.info(tf("notify.queued_query_cancelled", &[&tab.title]));
+        }
+    }
+
+    /// 丢弃已关闭的 Tab 排队中的查询
+    pub(super) fn forget_closed_tab_queries(&mut self) {
+        let tabs = &self.tab_manager.tabs;
+        self.query_queue.retain_tabs(|id| tabs.iter().any(|tab| tab.id == id));
+    }
+}
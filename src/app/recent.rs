@@ -0,0 +1,102 @@
+//! 最近使用的表和数据库
+//!
+//! 记录打开的表和切换的数据库（随配置在退出时保存）。快速切换器选中
+//! 其他连接或数据库中的表时，先连接、切换数据库，完成后再打开表。
+
+use chrono::Local;
+
+use crate::core::{t, tf, RecentTarget};
+use crate::ui::{PaletteCommand, PaletteItem};
+use super::DbManagerApp;
+
+impl DbManagerApp {
+    /// 当前连接和数据库中的目标（草稿本是临时连接，不记录）
+    pub(super) fn recent_target(&self, table: Option<&str>) -> Option<RecentTarget> {
+        let name = self.manager.active.clone()?;
+        let conn = self.manager.connections.get(&name).filter(|c| !c.config.is_scratchpad())?;
+        if table.is_none() && conn.selected_database.is_none() {
+            return None;
+        }
+        Some(RecentTarget {
+            connection: name,
+            database: conn.selected_database.clone(),
+            table: table.map(str::to_string),
+        })
+    }
+
+    /// 记录一次使用，`table` 为空时记录当前数据库
+    pub(super) fn record_recent(&mut self, table: Option<&str>) {
+        if let Some(target) = self.recent_target(table) {
+            self.app_config.recent_objects.record(
+                &target.connection,
+                target.database.as_deref(),
+                target.table.as_deref(),
+                Local::now(),
+            );
+        }
+    }
+
+    /// 打开表并记录为最近使用
+    pub(super) fn open_table(&mut self, table: String) {
+        self.record_recent(Some(&table));
+        self.handle_query_table(table);
+    }
+
+    /// 打开快速切换器，按使用频率和最近使用时间列出表和数据库
+    pub(super) fn open_quick_switcher(&mut self) {
+        let items: Vec<PaletteItem> = self
+            .app_config
+            .recent_objects
+            .ranked_entries(Local::now())
+            .into_iter()
+            .filter(|e| self.manager.connections.contains_key(&e.connection))
+            .map(|e| {
+                let group = if e.table.is_some() { t("palette.table") } else { t("palette.database") };
+                PaletteItem::new(group, e.label(), PaletteCommand::OpenRecent(e.target()))
+                    .with_hint(e.connection.clone())
+                    .with_keywords(e.connection.clone())
+            })
+            .collect();
+        if items.is_empty() {
+            self.notifications.info(t("notify.no_recent_objects"));
+            return;
+        }
+        self.command_palette_state.open_with_placeholder(items, t("palette.recent_placeholder"));
+    }
+
+    /// 打开快速切换器选中的目标，未连接或数据库不同时先切换
+    pub(super) fn open_recent(&mut self, target: RecentTarget) {
+        let Some(conn) = self.manager.connections.get(&target.connection) else {
+            self.notifications.warning(tf("notify.recent_connection_missing", &[&target.connection]));
+            return;
+        };
+        let is_active = conn.connected && self.manager.active.as_deref() == Some(target.connection.as_str());
+        let needs_database = target
+            .database
+            .as_ref()
+            .filter(|db| conn.selected_database.as_ref() != Some(*db))
+            .cloned();
+
+        if !is_active {
+            let name = target.connection.clone();
+            self.pending_recent = Some(target);
+            self.connect(name);
+            self.selected_table = None;
+            self.set_result(None);
+        } else if let Some(database) = needs_database {
+            self.pending_recent = Some(target);
+            self.select_database(database);
+        } else if let Some(table) = target.table {
+            self.open_table(table);
+        } else {
+            self.record_recent(None);
+        }
+    }
+
+    /// 连接或数据库切换完成后继续打开等待中的目标
+    pub(super) fn resume_pending_recent(&mut self, connection: &str) {
+        if let Some(target) = self.pending_recent.take_if(|t| t.connection == connection) {
+            self.open_recent(target);
+        }
+    }
+}
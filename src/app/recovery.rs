@@ -0,0 +1,179 @@
+//! 崩溃恢复
+//!
+//! 定期把标签页的 SQL 和表格中未保存的修改写入恢复文件，正常退出时删除；
+//! 启动时发现恢复文件则询问是否恢复。固定的标签页已由会话恢复处理，不写入恢复文件。
+//! 设置了主密码时恢复文件加密保存：未解锁时不自动保存，加密的恢复文件在解锁后再检查。
+
+use std::time::{Duration, Instant};
+
+use chrono::Local;
+
+use crate::core::{t, tf, RecoveredEdits, RecoveryState, RECOVERY_AUTOSAVE_SECS};
+use crate::ui::{self, QueryTab};
+
+use super::DbManagerApp;
+
+impl DbManagerApp {
+    /// 启动时（以及解锁主密码后）检查上次是否正常退出
+    pub(super) fn check_recovery(&mut self) {
+        let Some(path) = RecoveryState::path() else {
+            return;
+        };
+        // 加密的恢复文件等解锁后再读取
+        if self.pending_recovery.is_some() || (self.app_config.is_locked() && RecoveryState::is_encrypted(&path)) {
+            return;
+        }
+        match RecoveryState::load(&path, self.app_config.master_key.as_ref()) {
+            Some(state) if !state.is_empty() => {
+                tracing::info!(saved_at = %state.saved_at, "发现上次未正常退出时的恢复文件");
+                self.pending_recovery = Some(state);
+                self.show_recovery_prompt = true;
+            }
+            _ => RecoveryState::remove(&path),
+        }
+    }
+
+    /// 恢复提示中的说明
+    pub(super) fn recovery_message(&self) -> String {
+        let Some(state) = &self.pending_recovery else {
+            return String::new();
+        };
+        let tabs = state.tabs.iter().filter(|tab| !tab.sql.trim().is_empty()).count();
+        let saved_at = state.saved_at.format("%Y-%m-%d %H:%M").to_string();
+        let mut message = tf("recovery.found_tabs", &[&saved_at, &tabs.to_string()]);
+        if let Some(edits) = &state.grid_edits {
+            message.push_str(&tf("recovery.found_edits", &[&edits.table, &edits.statements.len().to_string()]));
+        }
+        message.push_str(t("recovery.question"));
+        message
+    }
+
+    /// 处理恢复提示的选择
+    pub(super) fn finish_recovery_prompt(&mut self, restore: bool) {
+        let Some(state) = self.pending_recovery.take() else {
+            return;
+        };
+        if restore {
+            self.restore_recovery(state);
+        }
+        if let Some(path) = RecoveryState::path() {
+            RecoveryState::remove(&path);
+        }
+    }
+
+    /// 在新标签页中恢复内容（表格修改以 SQL 形式恢复，不自动执行）
+    fn restore_recovery(&mut self, state: RecoveryState) {
+        let room = self.tab_manager.max_tabs.saturating_sub(self.tab_manager.tabs.len());
+        let mut tabs: Vec<QueryTab> = state
+            .tabs
+            .into_iter()
+            .filter(|tab| !tab.sql.trim().is_empty())
+            .map(QueryTab::from_state)
+            .collect();
+        if let Some(edits) = &state.grid_edits {
+            let mut tab = QueryTab::new();
+            tab.title = format!("恢复: {}", edits.table);
+            tab.sql = edits.script();
+            tab.renamed = true;
+            tabs.push(tab);
+        }
+        let skipped = tabs.len().saturating_sub(room);
+        tabs.truncate(room);
+        let count = tabs.len();
+        if count == 0 {
+            self.notifications.warning(t("notify.recovery_tab_limit"));
+            return;
+        }
+
+        let first = self.tab_manager.tabs.len();
+        self.tab_manager.tabs.extend(tabs);
+        self.activate_tab(first + count - 1);
+        if skipped > 0 {
+            self.notifications.warning(tf("notify.recovery_partial", &[&count.to_string(), &skipped.to_string()]));
+        } else {
+            self.notifications.success(tf("notify.recovery_restored", &[&count.to_string()]));
+        }
+    }
+
+    /// 定期写入恢复文件（内容未变化时不写）
+    pub(super) fn autosave_recovery(&mut self) {
+        // 等待用户决定是否恢复，避免覆盖上次的恢复文件；未解锁主密码时无法加密，不保存
+        if self.pending_recovery.is_some()
+            || self.app_config.is_locked()
+            || self.last_recovery_save.elapsed() < Duration::from_secs(RECOVERY_AUTOSAVE_SECS)
+        {
+            return;
+        }
+        self.last_recovery_save = Instant::now();
+        let Some(path) = RecoveryState::path() else {
+            return;
+        };
+
+        let state = self.recovery_snapshot();
+        if self.last_recovery.as_ref().is_some_and(|last| last.same_content(&state)) {
+            return;
+        }
+        let result = if state.is_empty() {
+            RecoveryState::remove(&path);
+            Ok(())
+        } else {
+            state.save(&path, self.app_config.master_key.as_ref())
+        };
+        match result {
+            Ok(()) => self.last_recovery = Some(state),
+            Err(e) => tracing::warn!(error = %e, "写入恢复文件失败"),
+        }
+    }
+
+    /// 当前需要恢复的内容
+    fn recovery_snapshot(&mut self) -> RecoveryState {
+        let active = self.tab_manager.active_index;
+        let tabs = self
+            .tab_manager
+            .tabs
+            .iter()
+            .enumerate()
+            // 固定的标签页启动时会自动恢复，定时查询的标签页由任务重新创建
+            .filter(|(_, tab)| !tab.pinned && !tab.scheduled)
+            .map(|(idx, tab)| {
+                let mut state = tab.to_state();
+                // 活动标签页的内容在编辑器中
+                if idx == active {
+                    state.sql = self.sql.clone();
+                }
+                state
+            })
+            .collect();
+        RecoveryState {
+            saved_at: Local::now(),
+            tabs,
+            grid_edits: self.pending_grid_edits(),
+        }
+    }
+
+    /// 表格中未保存的修改（生成保存需要的 SQL）
+    fn pending_grid_edits(&mut self) -> Option<RecoveredEdits> {
+        if !self.grid_state.has_changes() {
+            return None;
+        }
+        let result = self.result.clone()?;
+        let table = self.selected_table.clone()?;
+        let connection = self.manager.active.clone()?;
+        let mut actions = ui::DataGridActions::default();
+        ui::generate_save_sql(&result, &mut self.grid_state, &table, &mut actions);
+        if actions.sql_to_review.is_empty() {
+            return None;
+        }
+        Some(RecoveredEdits { connection, table, statements: actions.sql_to_review })
+    }
+
+    /// 正常退出时删除恢复文件（未解锁主密码时保留上次未读取的恢复文件）
+    pub(super) fn clear_recovery(&self) {
+        if self.pending_recovery.is_none()
+            && !self.app_config.is_locked()
+            && let Some(path) = RecoveryState::path()
+        {
+            RecoveryState::remove(&path);
+        }
+    }
+}
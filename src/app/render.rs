@@ -4,12 +4,27 @@
 
 use eframe::egui;
 
-use crate::core::{constants, format_sql};
+use crate::core::{default_log_path, format_sql_for, set_language, system_fonts, t, tf, Action, LogTail};
+use crate::database::{DatabaseType, DriverCapabilities, POOL_MANAGER};
 use crate::ui::{self, SqlEditorActions, TabBarActions, ToolbarActions};
 
 use super::DbManagerApp;
 
 impl DbManagerApp {
+    /// 渲染底部按键提示栏（需要在中心面板之前调用）
+    pub(super) fn render_hint_bar(&self, ctx: &egui::Context) {
+        let area = if self.has_modal_dialog_open() { ui::FocusArea::Dialog } else { self.focus_area };
+        let context = ui::HintContext {
+            area,
+            grid_mode: self.grid_state.mode,
+            grid_pending: &self.grid_state.command_buffer,
+            editor_mode: self.editor_mode,
+        };
+        egui::TopBottomPanel::bottom("key_hint_bar").show(ctx, |ui| {
+            ui::HintBar::show(ui, &context, &self.keybindings);
+        });
+    }
+
     /// 渲染 SQL 编辑器面板（在主内容区内部渲染，不遮挡侧边栏）
     pub(super) fn render_sql_editor_in_ui(
         &mut self,
@@ -77,12 +92,26 @@ impl DbManagerApp {
             |ui| {
                 // 获取最新通知消息用于状态栏显示
                 let latest_msg = self.notifications.latest_message().map(|s| s.to_string());
+                let console_hint = match self.manager.get_active().map(|c| c.config.db_type) {
+                    Some(DatabaseType::Redis) => Some("命令控制台 · 每行一条命令"),
+                    Some(DatabaseType::MongoDB) => Some("JSON 命令 · find / aggregate"),
+                    _ => None,
+                };
+                // 命令行补全候选项（仅在命令行打开时收集）
+                let connections: Vec<String> = if self.editor_mode == ui::EditorMode::Command {
+                    let mut names: Vec<String> = self.manager.connections.keys().cloned().collect();
+                    names.sort();
+                    names
+                } else {
+                    Vec::new()
+                };
+                let tables = self.manager.get_active().map(|c| c.tables.as_slice()).unwrap_or_default();
                 sql_editor_actions = ui::SqlEditor::show(
                     ui,
                     &mut self.sql,
                     &self.command_history,
                     &mut self.history_index,
-                    self.executing,
+                    self.executing || self.tab_manager.get_active().is_some_and(|tab| tab.executing || tab.queued),
                     &latest_msg,
                     &self.highlight_colors,
                     self.last_query_time_ms,
@@ -92,6 +121,11 @@ impl DbManagerApp {
                     &mut self.focus_sql_editor,
                     is_editor_focused,
                     &mut self.editor_mode,
+                    console_hint,
+                    &mut self.command_line_state,
+                    &connections,
+                    tables,
+                    self.sql_error_marker.as_ref(),
                 );
             }
         );
@@ -111,23 +145,58 @@ impl DbManagerApp {
         // EXPLAIN 分析
         if actions.explain && !self.sql.is_empty() {
             let sql = self.sql.trim();
-            let explain_sql = if self.is_mysql() {
-                format!("EXPLAIN FORMAT=TRADITIONAL {}", sql)
-            } else if self.manager.get_active()
-                .map(|c| c.config.db_type == crate::database::DatabaseType::PostgreSQL)
-                .unwrap_or(false)
-            {
-                format!("EXPLAIN (ANALYZE, BUFFERS, FORMAT TEXT) {}", sql)
-            } else {
-                format!("EXPLAIN QUERY PLAN {}", sql)
+            let db_type = self.manager.get_active()
+                .map(|c| c.config.db_type)
+                .unwrap_or_default();
+            let explain_sql = match db_type {
+                DatabaseType::MySQL => format!("EXPLAIN FORMAT=TRADITIONAL {}", sql),
+                DatabaseType::PostgreSQL => format!("EXPLAIN (ANALYZE, BUFFERS, FORMAT TEXT) {}", sql),
+                // ODBC 数据源方言未知，使用最常见的 EXPLAIN 语法
+                DatabaseType::DuckDB | DatabaseType::ClickHouse | DatabaseType::Odbc => format!("EXPLAIN {}", sql),
+                // 执行计划通过 DBMS_XPLAN 读取
+                DatabaseType::Oracle => format!("EXPLAIN PLAN FOR {}", sql.trim_end_matches(';')),
+                DatabaseType::SQLite => format!("EXPLAIN QUERY PLAN {}", sql),
+                // MongoDB 使用 explain 命令包裹原命令
+                DatabaseType::MongoDB => format!("{{\"explain\": {}}}", sql),
+                DatabaseType::Redis => String::new(),
             };
-            self.execute(explain_sql);
-            self.notifications.info("正在分析执行计划...");
+            if explain_sql.is_empty() {
+                self.notifications.warning(t("notify.explain_console"));
+            } else {
+                self.execute(explain_sql);
+                self.notifications.info(t("notify.explain_running"));
+            }
         }
 
-        // 格式化
+        // 不执行而检查 SQL
+        if actions.validate && !self.sql.trim().is_empty() {
+            self.validate_editor_sql();
+        }
+
+        // 预览 UPDATE/DELETE 影响的行
+        if actions.preview_write && !self.sql.trim().is_empty() {
+            self.open_write_preview(self.sql.clone());
+        }
+
+        // 格式化（MongoDB 美化 JSON，Redis 命令保持原样）
         if actions.format {
-            self.sql = format_sql(&self.sql);
+            let db_type = self.manager.get_active()
+                .map(|c| c.config.db_type)
+                .unwrap_or_default();
+            match db_type {
+                DatabaseType::MongoDB => match serde_json::from_str::<serde_json::Value>(&self.sql) {
+                    Ok(value) => {
+                        if let Ok(pretty) = serde_json::to_string_pretty(&value) {
+                            self.sql = pretty;
+                        }
+                    }
+                    Err(e) => {
+                        self.notifications.warning(tf("notify.json_invalid", &[&e.to_string()]));
+                    }
+                },
+                DatabaseType::Redis => {}
+                _ => self.sql = format_sql_for(&self.sql, &db_type),
+            }
         }
 
         // 清空
@@ -137,6 +206,11 @@ impl DbManagerApp {
             self.last_query_time_ms = None;
         }
 
+        // 命令行输入错误
+        if let Some(e) = actions.command_error {
+            self.notifications.error(e);
+        }
+
         // 焦点转移到表格
         if actions.focus_to_grid {
             self.focus_area = ui::FocusArea::DataGrid;
@@ -151,6 +225,49 @@ impl DbManagerApp {
         }
     }
 
+    /// 执行 SQL 编辑器命令行中的命令
+    pub(super) fn run_editor_command(&mut self, command: ui::EditorCommand, toolbar_actions: &mut ToolbarActions) {
+        match command {
+            ui::EditorCommand::Write => self.run_action(Action::Save, toolbar_actions),
+            ui::EditorCommand::Quit => self.run_action(Action::CloseTab, toolbar_actions),
+            ui::EditorCommand::TabNew => self.run_action(Action::NewTab, toolbar_actions),
+            ui::EditorCommand::TabNext => self.run_action(Action::NextTab, toolbar_actions),
+            ui::EditorCommand::TabPrev => self.run_action(Action::PrevTab, toolbar_actions),
+            ui::EditorCommand::Help => self.show_help = true,
+            ui::EditorCommand::Connect(name) => {
+                if self.manager.connections.contains_key(&name) {
+                    toolbar_actions.switch_connection = Some(name);
+                } else {
+                    self.notifications.error(tf("cmdline.no_connection", &[&name]));
+                }
+            }
+            ui::EditorCommand::Table(name) => {
+                if self.manager.get_active().is_some() {
+                    toolbar_actions.switch_table = Some(name);
+                } else {
+                    self.notifications.warning(t("common.need_connection"));
+                }
+            }
+            ui::EditorCommand::Export(format) => {
+                if self.result.is_none() {
+                    self.notifications.warning(t("cmdline.no_result"));
+                    return;
+                }
+                self.open_export_dialog();
+                if let Some(format) = format {
+                    self.export_config.format = format;
+                }
+            }
+            // 执行、格式化等由编辑器转换为 SqlEditorActions 中的标志
+            ui::EditorCommand::Run
+            | ui::EditorCommand::Explain
+            | ui::EditorCommand::Preview
+            | ui::EditorCommand::Validate
+            | ui::EditorCommand::Format
+            | ui::EditorCommand::Clear => {}
+        }
+    }
+
     /// 处理工具栏操作
     pub(super) fn handle_toolbar_actions(&mut self, ctx: &egui::Context, actions: ToolbarActions) {
         if actions.toggle_sidebar {
@@ -161,19 +278,19 @@ impl DbManagerApp {
             self.show_sql_editor = !self.show_sql_editor;
         }
 
-        if actions.refresh_tables {
-            if let Some(name) = self.manager.active.clone() {
-                self.connect(name);
-            }
+        if actions.refresh_tables
+            && let Some(name) = self.manager.active.clone()
+        {
+            self.connect(name);
         }
 
         // 连接切换
-        if let Some(conn_name) = actions.switch_connection {
-            if self.manager.active.as_deref() != Some(&conn_name) {
-                self.connect(conn_name);
-                self.selected_table = None;
-                self.result = None;
-            }
+        if let Some(conn_name) = actions.switch_connection
+            && self.manager.active.as_deref() != Some(&conn_name)
+        {
+            self.connect(conn_name);
+            self.selected_table = None;
+            self.set_result(None);
         }
 
         // 数据库切换
@@ -183,23 +300,11 @@ impl DbManagerApp {
 
         // 表切换
         if let Some(table_name) = actions.switch_table {
-            self.selected_table = Some(table_name.clone());
-            self.grid_state.primary_key_column = None;
-            if let Ok(quoted_table) = ui::quote_identifier(&table_name, self.is_mysql()) {
-                let query_sql = format!(
-                    "SELECT * FROM {} LIMIT {};",
-                    quoted_table,
-                    constants::database::DEFAULT_QUERY_LIMIT
-                );
-                self.execute(query_sql);
-            }
-            self.fetch_primary_key(&table_name);
-            self.sql.clear();
+            self.open_table(table_name);
         }
 
         if actions.export {
-            self.show_export_dialog = true;
-            self.export_status = None;
+            self.open_export_dialog();
         }
 
         if actions.import {
@@ -210,7 +315,11 @@ impl DbManagerApp {
             let db_type = self.manager.get_active()
                 .map(|c| c.config.db_type)
                 .unwrap_or_default();
-            self.ddl_dialog_state.open_create_table(db_type);
+            if db_type.uses_sql() {
+                self.ddl_dialog_state.open_create_table(db_type);
+            } else {
+                self.notifications.warning(tf("notify.create_table_unsupported", &[db_type.display_name()]));
+            }
         }
 
         if actions.create_database {
@@ -224,16 +333,145 @@ impl DbManagerApp {
             self.handle_create_user_action();
         }
 
+        if actions.manage_privileges {
+            self.open_privilege_manager();
+        }
+
+        if actions.show_size_overview {
+            self.open_size_overview();
+        }
+
+        if actions.show_session_monitor {
+            self.open_session_monitor();
+        }
+
+        if actions.duplicate_table || actions.truncate_table {
+            let action = if actions.duplicate_table {
+                ui::TableAction::Duplicate
+            } else {
+                ui::TableAction::Truncate
+            };
+            match self.selected_table.clone() {
+                Some(table) => self.open_table_action(action, table),
+                None => {
+                    self.notifications.warning(t("common.need_table"));
+                }
+            }
+        }
+
+        if actions.generate_test_data {
+            self.open_data_generator(self.selected_table.clone());
+        }
+
+        if actions.show_scheduler {
+            self.open_scheduler();
+        }
+
+        if actions.pin_snapshot {
+            self.pin_result_snapshot();
+        }
+
+        if actions.compare_snapshot {
+            self.compare_with_snapshot();
+        }
+
+        if actions.show_audit_log {
+            self.open_audit_log();
+        }
+
+        if actions.open_file {
+            self.open_sql_file();
+        }
+
+        if actions.save_file {
+            self.save_sql_file(false);
+        }
+
+        if actions.show_workflows {
+            self.open_workflows();
+        }
+
+        if actions.show_api_server {
+            self.open_api_server();
+        }
+
+        if actions.show_log_viewer {
+            match default_log_path() {
+                Some(path) => self.log_viewer_state.open(LogTail::new(path)),
+                None => {
+                    self.notifications.error(t("notify.log_path_unknown"));
+                }
+            }
+        }
+
+        if actions.show_data_search {
+            self.open_data_search();
+        }
+
+        if actions.show_pool_stats {
+            self.pool_stats_state.open(POOL_MANAGER.stats());
+        }
+
+        if actions.show_trash {
+            self.trash_dialog_state.open();
+        }
+
+        if actions.show_master_password {
+            self.open_master_password();
+        }
+
+        if actions.show_benchmark {
+            self.open_benchmark();
+        }
+
+        if actions.show_profile {
+            self.open_profile();
+        }
+
+        if actions.show_row_bookmarks {
+            self.row_bookmark_state.open();
+        }
+
+        if actions.show_notifications {
+            self.notification_center_state.open();
+            self.notifications.mark_seen();
+        }
+
         if actions.toggle_er_diagram {
             self.show_er_diagram = !self.show_er_diagram;
             if self.show_er_diagram {
                 self.load_er_diagram_data();
-                self.notifications.info("ER 关系图已打开");
+                self.notifications.info(t("notify.er_opened"));
             } else {
-                self.notifications.info("ER 关系图已关闭");
+                self.notifications.info(t("notify.er_closed"));
             }
         }
 
+        if actions.show_theme_editor {
+            self.open_theme_editor();
+        }
+
+        if let Some(lang) = actions.language_changed
+            && lang != self.app_config.language
+        {
+            set_language(lang);
+            self.app_config.language = lang;
+            if let Err(e) = self.app_config.save() {
+                self.notifications.error(tf("common.save_config_failed", &[&e]));
+            } else {
+                self.notifications.info(tf("language.changed", &[lang.native_name()]));
+            };
+        }
+
+        if actions.show_preferences {
+            self.preferences_state.open(self.app_config.preferences());
+        }
+
+        if actions.show_font_settings {
+            self.font_settings_state
+                .open(self.app_config.fonts.clone(), system_fonts().to_vec());
+        }
+
         if let Some(preset) = actions.theme_changed {
             if self.app_config.is_dark_mode {
                 self.app_config.dark_theme = preset;
@@ -243,9 +481,8 @@ impl DbManagerApp {
             self.set_theme(ctx, preset);
         }
 
-        // 处理日/夜模式切换（来自工具栏按钮或 Ctrl+D 快捷键）
-        if actions.toggle_dark_mode || self.pending_toggle_dark_mode {
-            self.pending_toggle_dark_mode = false;
+        // 处理日/夜模式切换（来自工具栏按钮或快捷键）
+        if actions.toggle_dark_mode {
             self.app_config.is_dark_mode = !self.app_config.is_dark_mode;
             let new_theme = if self.app_config.is_dark_mode {
                 self.app_config.dark_theme
@@ -278,6 +515,10 @@ impl DbManagerApp {
             self.show_about = true;
         }
 
+        if actions.start_tour {
+            self.start_tour();
+        }
+
         if actions.show_keybindings {
             self.keybindings_dialog_state.open(&self.keybindings);
         }
@@ -287,17 +528,37 @@ impl DbManagerApp {
     fn handle_create_user_action(&mut self) {
         if let Some(conn) = self.manager.get_active() {
             let db_type = conn.config.db_type;
-            if matches!(db_type, crate::database::DatabaseType::SQLite) {
-                self.notifications.warning("SQLite 不支持用户管理");
+            if !DriverCapabilities::for_db_type(db_type).user_management {
+                self.notifications.warning(tf("notify.users_unsupported", &[db_type.display_name()]));
             } else {
                 let databases = conn.databases.clone();
                 self.create_user_dialog_state.open(db_type, databases);
             }
         } else {
-            self.notifications.warning("请先连接数据库");
+            self.notifications.warning(t("common.need_connection"));
         }
     }
 
+    /// 打开权限管理对话框并加载用户列表
+    pub(super) fn open_privilege_manager(&mut self) {
+        let Some(conn) = self.manager.get_active() else {
+            self.notifications.warning(t("common.need_connection"));
+            return;
+        };
+        let db_type = conn.config.db_type;
+        if !matches!(db_type, DatabaseType::MySQL | DatabaseType::PostgreSQL) {
+            self.notifications.warning(tf("notify.privileges_unsupported", &[db_type.display_name()]));
+            return;
+        }
+        let Some(database) = conn.selected_database.clone() else {
+            self.notifications.warning(t("common.need_database"));
+            return;
+        };
+        let tables = conn.tables.clone();
+        self.privilege_dialog_state.open(db_type, database, tables);
+        self.load_privilege_users();
+    }
+
     /// 处理侧边栏操作
     pub(super) fn handle_sidebar_actions(&mut self, actions: ui::SidebarActions) {
         // 焦点转移
@@ -331,10 +592,39 @@ impl DbManagerApp {
             self.select_database(db_name);
         }
 
+        if actions.load_more_tables {
+            self.load_more_tables();
+        }
+
         // 删除请求
         if let Some(name) = actions.delete {
-            self.pending_delete_name = Some(name);
-            self.show_delete_confirm = true;
+            if self.app_config.confirm_delete_connection {
+                self.pending_delete_name = Some(name);
+                self.show_delete_confirm = true;
+            } else {
+                self.delete_connection(&name);
+            }
+        }
+
+        // 修改 SQLite 加密密钥
+        if let Some(name) = actions.change_sqlite_key {
+            self.change_key_dialog_state.open(name);
+        }
+
+        // 草稿本
+        if actions.new_scratchpad {
+            self.new_scratchpad();
+        }
+        if let Some(name) = actions.save_scratchpad {
+            self.save_scratchpad(name);
+        }
+
+        // SQLite 附加/分离数据库
+        if let Some(name) = actions.attach_sqlite_database {
+            self.attach_sqlite_database(name);
+        }
+        if let Some((name, alias)) = actions.detach_sqlite_database {
+            self.detach_sqlite_database(name, alias);
         }
 
         // 查看表结构
@@ -342,9 +632,44 @@ impl DbManagerApp {
             self.handle_show_table_schema(table);
         }
 
+        // 建表语句
+        if let Some(table) = actions.show_table_ddl {
+            self.open_table_ddl(table);
+        }
+
+        // 依赖关系
+        if let Some(table) = actions.show_dependencies {
+            self.open_dependencies(table);
+        }
+
+        // 重命名/删除/清空/复制表
+        if let Some((ui::SidebarSection::Tables, table)) = actions.rename_item {
+            self.open_table_action(ui::TableAction::Rename, table);
+        }
+        if let Some(table) = actions.drop_table {
+            self.open_table_action(ui::TableAction::Drop, table);
+        }
+        if let Some(table) = actions.truncate_table {
+            self.open_table_action(ui::TableAction::Truncate, table);
+        }
+        if let Some(table) = actions.duplicate_table {
+            self.open_table_action(ui::TableAction::Duplicate, table);
+        }
+        if let Some(table) = actions.generate_test_data {
+            self.open_data_generator(Some(table));
+        }
+
         // 查询表数据
         if let Some(table) = actions.query_table {
-            self.handle_query_table(table);
+            self.open_table(table);
+        }
+
+        // 筛选预设
+        if let Some(name) = actions.save_filter_preset {
+            self.save_filter_preset(name);
+        }
+        if let Some(name) = actions.delete_filter_preset {
+            self.delete_filter_preset(&name);
         }
 
         // 触发器定义
@@ -352,7 +677,7 @@ impl DbManagerApp {
             self.sql = definition;
             self.show_sql_editor = true;
             self.focus_sql_editor = true;
-            self.notifications.info("触发器定义已加载到编辑器");
+            self.notifications.info(t("notify.trigger_loaded"));
         }
 
         // 存储过程/函数定义
@@ -360,7 +685,73 @@ impl DbManagerApp {
             self.sql = definition;
             self.show_sql_editor = true;
             self.focus_sql_editor = true;
-            self.notifications.info("存储过程/函数定义已加载到编辑器");
+            self.notifications.info(t("notify.routine_loaded"));
+        }
+
+        // 表大小概览
+        if actions.show_size_overview {
+            self.open_size_overview();
+        }
+
+        // 会话监控
+        if actions.show_session_monitor {
+            self.open_session_monitor();
+        }
+
+        // 表/数据库维护操作
+        if let Some((op, target)) = actions.maintenance {
+            self.run_maintenance(op, target);
+        }
+
+        // 新建/编辑/删除触发器、存储过程
+        if let Some(db_type) = self.manager.get_active().map(|c| c.config.db_type) {
+            let opened = if let Some(kind) = actions.create_object {
+                Some(self.object_ddl_dialog_state.open_create(db_type, kind))
+            } else if let Some(trigger) = &actions.edit_trigger {
+                Some(self.object_ddl_dialog_state.open_edit_trigger(db_type, trigger))
+            } else {
+                actions
+                    .edit_routine
+                    .as_ref()
+                    .map(|routine| self.object_ddl_dialog_state.open_edit_routine(db_type, routine))
+            };
+            if let Some(Err(e)) = opened {
+                self.notifications.warning(e);
+            }
+
+            let drop_request = if let Some(trigger) = actions.drop_trigger {
+                Some((
+                    format!("触发器 '{}'", trigger.name),
+                    ui::drop_object_sql(db_type, ui::DbObjectKind::Trigger, &trigger.name, &trigger.table_name, ""),
+                ))
+            } else {
+                actions.drop_routine.map(|routine| {
+                    let kind = ui::DbObjectKind::from_routine(&routine.routine_type);
+                    (
+                        format!("{} '{}'", kind.display_name(), routine.name),
+                        ui::drop_object_sql(db_type, kind, &routine.name, "", &routine.parameters),
+                    )
+                })
+            };
+            match drop_request {
+                Some((label, Ok(sql))) => {
+                    if self.app_config.confirm_drop_object {
+                        self.pending_drop_object = Some((label, sql));
+                        self.show_drop_object_confirm = true;
+                    } else {
+                        self.execute_object_ddl(vec![sql], None);
+                    }
+                }
+                Some((_, Err(e))) => {
+                    self.notifications.warning(e);
+                }
+                None => {}
+            }
+
+            // 设置序列/自增计数器的下一个值
+            if let Some(sequence) = actions.restart_sequence {
+                self.sequence_dialog_state.open(db_type, sequence);
+            }
         }
     }
 
@@ -369,11 +760,20 @@ impl DbManagerApp {
         self.selected_table = Some(table.clone());
         if let Some(conn) = self.manager.get_active() {
             let schema_sql = match conn.config.db_type {
-                crate::database::DatabaseType::SQLite => {
-                    let escaped = table.replace('\'', "''");
-                    format!("PRAGMA table_info('{}');", escaped)
+                DatabaseType::SQLite => {
+                    // 附加数据库的表带 schema 前缀
+                    match table.split_once('.').filter(|(schema, _)| {
+                        conn.config.sqlite_attachments.iter().any(|a| a.alias == *schema)
+                    }) {
+                        Some((schema, name)) => format!(
+                            "PRAGMA \"{}\".table_info('{}');",
+                            schema,
+                            name.replace('\'', "''")
+                        ),
+                        None => format!("PRAGMA table_info('{}');", table.replace('\'', "''")),
+                    }
                 }
-                crate::database::DatabaseType::PostgreSQL => {
+                DatabaseType::PostgreSQL => {
                     let escaped = table.replace('\'', "''");
                     format!(
                         "SELECT column_name, data_type, is_nullable, column_default \
@@ -383,32 +783,135 @@ impl DbManagerApp {
                         escaped
                     )
                 }
-                crate::database::DatabaseType::MySQL => {
+                DatabaseType::MySQL => {
                     let escaped = table.replace('`', "``").replace('.', "_");
                     format!("DESCRIBE `{}`;", escaped)
                 }
+                DatabaseType::ClickHouse => {
+                    let escaped = table.replace('\\', "\\\\").replace('`', "\\`");
+                    format!("DESCRIBE TABLE `{}`;", escaped)
+                }
+                // Redis 键没有结构，直接显示值
+                DatabaseType::Redis => {
+                    self.show_redis_key(table);
+                    return;
+                }
+                // MongoDB 集合没有固定结构，按抽样文档推断字段
+                DatabaseType::MongoDB => {
+                    self.show_table_columns_via_catalog(table);
+                    return;
+                }
+                // ODBC 没有统一的元数据 SQL，返回目录函数获取的列信息
+                DatabaseType::Odbc => {
+                    self.show_table_columns_via_catalog(table);
+                    return;
+                }
+                DatabaseType::Oracle => {
+                    let escaped = table.replace('\'', "''");
+                    format!(
+                        "SELECT column_name, data_type, data_length, nullable, data_default \
+                         FROM all_tab_columns \
+                         WHERE owner = SYS_CONTEXT('USERENV', 'CURRENT_SCHEMA') AND table_name = '{}' \
+                         ORDER BY column_id",
+                        escaped
+                    )
+                }
+                DatabaseType::DuckDB => {
+                    match self.quote_table_name(&table) {
+                        Ok(quoted) => format!("DESCRIBE {};", quoted),
+                        Err(e) => {
+                            self.notifications.error(e);
+                            return;
+                        }
+                    }
+                }
             };
             self.execute(schema_sql);
             self.sql.clear();
         }
+
+        // 表结构中附带自增列的下一个值
+        let next_values: Vec<String> = self
+            .sidebar_panel_state
+            .sequences
+            .iter()
+            .filter(|s| s.table_name.as_deref() == Some(table.as_str()))
+            .filter_map(|s| Some(tf("notify.sequence_next_value", &[s.column_name.as_ref()?, &s.next_value?.to_string()])))
+            .collect();
+        for message in next_values {
+            self.notifications.info(message);
+        }
     }
 
     /// 处理查询表数据
-    fn handle_query_table(&mut self, table: String) {
+    pub(super) fn handle_query_table(&mut self, table: String) {
         self.selected_table = Some(table.clone());
         self.grid_state.primary_key_column = None;
-        if let Ok(quoted_table) = ui::quote_identifier(&table, self.is_mysql()) {
-            let query_sql = format!(
-                "SELECT * FROM {} LIMIT {};",
-                quoted_table,
-                constants::database::DEFAULT_QUERY_LIMIT
-            );
-            self.execute(query_sql);
-        }
+        self.preview_table(&table);
         self.fetch_primary_key(&table);
+        self.fetch_column_types(&table);
         self.sql.clear();
     }
 
+    /// 将当前结果固定为当前 Tab 的快照
+    fn pin_result_snapshot(&mut self) {
+        self.load_spilled_pages();
+        let Some(result) = self.result.clone().filter(|r| !r.columns.is_empty()) else {
+            self.notifications.warning(t("notify.no_result_to_pin"));
+            return;
+        };
+        let rows = result.rows.len();
+        let sql = self.tab_manager.get_active().map(|t| t.sql.clone()).unwrap_or_else(|| self.sql.clone());
+        if let Some(tab) = self.tab_manager.get_active_mut() {
+            tab.snapshot = Some(ui::ResultSnapshot {
+                sql,
+                result,
+                taken_at: chrono::Local::now(),
+            });
+            self.notifications.success(tf("notify.snapshot_pinned", &[&rows.to_string()]));
+        }
+    }
+
+    /// 打开结果对比窗口，对比当前 Tab 的快照和当前结果
+    fn compare_with_snapshot(&mut self) {
+        self.load_spilled_pages();
+        let Some(snapshot) = self.tab_manager.get_active().and_then(|t| t.snapshot.clone()) else {
+            self.notifications.warning(t("notify.no_snapshot"));
+            return;
+        };
+        let Some(current) = self.result.clone() else {
+            self.notifications.warning(t("notify.no_result_to_compare"));
+            return;
+        };
+        // 已知主键时默认按主键匹配
+        let key_column = self
+            .grid_state
+            .primary_key_column
+            .and_then(|i| current.columns.get(i).cloned());
+        let sql = self.tab_manager.get_active().map(|t| t.sql.clone()).unwrap_or_default();
+        self.result_diff_state.open(snapshot, current, &sql, key_column.as_deref());
+    }
+
+    /// 将结果对比报告保存为 CSV 文件
+    pub(super) fn export_diff_report(&mut self, report: &crate::database::QueryResult) {
+        let Some(path) = rfd::FileDialog::new()
+            .set_file_name("result_diff.csv")
+            .add_filter("CSV 文件", &["csv"])
+            .save_file()
+        else {
+            return;
+        };
+
+        match crate::core::export_to_csv(report, &path) {
+            Ok(()) => {
+                self.notifications.success(tf("notify.diff_exported", &[&path.display().to_string()]));
+            }
+            Err(e) => {
+                self.notifications.error(tf("common.export_failed", &[&e.to_string()]));
+            }
+        }
+    }
+
     /// 处理 Tab 栏操作
     pub(super) fn handle_tab_actions(&mut self, tab_actions: TabBarActions) {
         if tab_actions.new_tab {
@@ -416,18 +919,16 @@ impl DbManagerApp {
         }
 
         if let Some(idx) = tab_actions.switch_to {
-            self.tab_manager.set_active(idx);
-            if let Some(tab) = self.tab_manager.get_active() {
-                self.sql = tab.sql.clone();
-                self.result = tab.result.clone();
-            }
+            self.activate_tab(idx);
         }
 
         if let Some(idx) = tab_actions.close_tab {
             self.tab_manager.close_tab(idx);
             if let Some(tab) = self.tab_manager.get_active() {
                 self.sql = tab.sql.clone();
-                self.result = tab.result.clone();
+                self.grid_state.page = tab.page.clone();
+                let result = tab.result.clone();
+                self.set_result(result);
             }
         }
 
@@ -438,67 +939,27 @@ impl DbManagerApp {
         if tab_actions.close_right {
             self.tab_manager.close_tabs_to_right();
         }
-    }
-
-    /// 检测并处理焦点切换快捷键
-    pub(super) fn handle_focus_shortcuts(
-        &mut self,
-        ctx: &egui::Context,
-        toolbar_actions: &mut ToolbarActions,
-    ) {
-        let has_dialog = self.has_modal_dialog_open();
-
-        ctx.input(|i| {
-            // Ctrl+Shift+T: 打开主题选择器
-            if i.modifiers.ctrl && i.modifiers.shift && i.key_pressed(egui::Key::T) {
-                toolbar_actions.open_theme_selector = true;
-            }
 
-            if has_dialog {
-                return;
-            }
+        if let Some(idx) = tab_actions.cancel_queued {
+            self.cancel_queued_query(idx);
+        }
 
-            // Ctrl+T: 新建查询标签页
-            if i.modifiers.ctrl && !i.modifiers.shift && i.key_pressed(egui::Key::T) {
-                self.tab_manager.new_tab();
-                if let Some(tab) = self.tab_manager.get_active() {
-                    self.sql = tab.sql.clone();
-                    self.result = tab.result.clone();
-                }
-            }
+        if let Some(idx) = tab_actions.start_rename {
+            self.start_tab_rename(idx);
+        }
 
-            // Ctrl+1~4: 聚焦侧边栏不同区域
-            self.handle_sidebar_focus_shortcuts(i);
+        if let Some((idx, title)) = tab_actions.rename {
+            self.tab_manager.rename_tab(idx, &title);
+        }
 
-            // Ctrl+D: 切换日/夜模式
-            if i.modifiers.ctrl && !i.modifiers.shift && i.key_pressed(egui::Key::D) {
-                toolbar_actions.toggle_dark_mode = true;
-            }
-        });
-    }
+        if let Some(idx) = tab_actions.toggle_pin {
+            self.toggle_tab_pin(idx);
+        }
 
-    /// 处理侧边栏焦点快捷键
-    fn handle_sidebar_focus_shortcuts(&mut self, input: &egui::InputState) {
-        // 顺序：1连接 2数据库 3表 4筛选 5触发器 6存储过程
-        let shortcuts = [
-            (egui::Key::Num1, ui::SidebarSection::Connections, "连接列表"),
-            (egui::Key::Num2, ui::SidebarSection::Databases, "数据库列表"),
-            (egui::Key::Num3, ui::SidebarSection::Tables, "表列表"),
-            (egui::Key::Num4, ui::SidebarSection::Filters, "筛选面板"),
-            (egui::Key::Num5, ui::SidebarSection::Triggers, "触发器列表"),
-            (egui::Key::Num6, ui::SidebarSection::Routines, "存储过程列表"),
-        ];
-
-        for (key, section, name) in shortcuts {
-            if input.modifiers.ctrl && input.key_pressed(key) {
-                self.show_sidebar = true;
-                self.focus_area = ui::FocusArea::Sidebar;
-                self.sidebar_section = section;
-                self.grid_state.focused = false;
-                self.focus_sql_editor = false;
-                self.notifications.info(format!("切换到: {}", name));
-                break;
-            }
+        if let Some(idx) = tab_actions.toggle_detach {
+            self.toggle_tab_detach(idx);
         }
+        self.forget_closed_tab_queries();
+        self.trash_closed_tabs();
     }
 }
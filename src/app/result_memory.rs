@@ -0,0 +1,275 @@
+//! 查询结果的内存占用检查
+//!
+//! 估算所有标签页的查询结果占用的内存，超过首选项中的阈值时：启用了磁盘缓存则先把
+//! 后台标签页的整个结果写入临时文件（切换回该标签页时读回），仍然超过时把当前结果中
+//! 远离表格显示位置的页写入磁盘（滚动到附近时读回）；仍然超过时提示用户。
+//!
+//! 只有按原始顺序浏览当前结果时才分页写入磁盘：筛选、排序、搜索、统计面板、未保存的修改等
+//! 需要读取所有行的操作会先读回全部页，导出、打印等操作执行前也会读回。
+
+use std::io;
+use std::ops::Range;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use eframe::egui;
+
+use crate::core::{tf, SpillStore, SPILL_PAGE_ROWS};
+use crate::database::QueryResult;
+use crate::ui;
+
+use super::DbManagerApp;
+
+/// 重新估算内存占用的最短间隔（流式读取时结果每帧都在变化）
+const MEMORY_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 分页写入磁盘时，表格正在使用的行前后保留在内存中的行数
+const KEEP_ROWS_AROUND: usize = 2 * SPILL_PAGE_ROWS;
+
+impl DbManagerApp {
+    /// 检查查询结果的内存占用，并读回活动标签页已写入磁盘的结果和表格需要的页
+    pub(super) fn check_result_memory(&mut self, ctx: &egui::Context) {
+        self.restore_spilled_result();
+        if self.load_needed_pages() {
+            // 本帧显示的占位行在下一帧显示读回的数据
+            ctx.request_repaint();
+        }
+
+        // 标签页重新执行查询或已关闭时，磁盘上的旧结果不再需要
+        let tabs = &self.tab_manager.tabs;
+        self.spill_store
+            .retain(|id| tabs.iter().any(|tab| tab.id == id && tab.result.is_none()));
+        self.spill_store.prune_pages();
+
+        if self.last_memory_check.elapsed() < MEMORY_CHECK_INTERVAL {
+            return;
+        }
+        self.last_memory_check = Instant::now();
+
+        // 结果没有变化时不重新估算
+        let results = self.loaded_results();
+        let key: Vec<(usize, usize, usize)> = results
+            .iter()
+            .map(|result| (Arc::as_ptr(result) as usize, result.rows.len(), self.spill_store.spilled_pages(result)))
+            .collect();
+        if key == self.result_memory_key {
+            return;
+        }
+        self.result_memory_key = key;
+
+        let limit = self.app_config.memory_warning_mb.saturating_mul(1024 * 1024);
+        let mut total: usize = results.iter().map(|result| result.estimated_size()).sum();
+        if total > limit && self.app_config.spill_large_results {
+            total = self.spill_background_results(total, limit);
+        }
+        if total > limit && self.app_config.spill_large_results {
+            total = self.spill_result_pages(total, limit);
+        }
+
+        if total <= limit {
+            self.memory_warned = false;
+        } else if !self.memory_warned {
+            self.memory_warned = true;
+            tracing::warn!(bytes = total, limit, "查询结果占用的内存超过阈值");
+            let size = ui::format_bytes(total as i64);
+            let limit_mb = self.app_config.memory_warning_mb.to_string();
+            self.notifications.warning(tf("notify.result_memory_high", &[&size, &limit_mb]));
+        }
+    }
+
+    /// 当前在内存中的查询结果（同一份结果只计一次）
+    fn loaded_results(&self) -> Vec<Arc<QueryResult>> {
+        let mut results: Vec<Arc<QueryResult>> = Vec::new();
+        let tab_results = self.tab_manager.tabs.iter().filter_map(|tab| tab.result.as_ref());
+        for result in tab_results.chain(self.result.as_ref()) {
+            if !results.iter().any(|r| Arc::ptr_eq(r, result)) {
+                results.push(Arc::clone(result));
+            }
+        }
+        results
+    }
+
+    /// 按占用从大到小把后台标签页的结果写入磁盘，返回剩余的内存占用
+    fn spill_background_results(&mut self, mut total: usize, limit: usize) -> usize {
+        let active = self.tab_manager.active_index;
+        let mut candidates: Vec<(usize, usize)> = self
+            .tab_manager
+            .tabs
+            .iter()
+            .enumerate()
+            // 独立窗口每帧直接显示标签页的结果，写入磁盘后窗口中的结果会消失
+            .filter(|(idx, tab)| *idx != active && !tab.executing && !tab.detached)
+            .filter_map(|(idx, tab)| {
+                let result = tab.result.as_ref()?;
+                // 结果还被表格或流式读取引用时写入磁盘也无法释放内存；
+                // 已分页写入磁盘的结果先读回（见 `load_needed_pages`），不整个写入
+                (Arc::strong_count(result) == 1 && self.spill_store.spilled_pages(result) == 0)
+                    .then(|| (idx, result.estimated_size()))
+            })
+            .collect();
+        candidates.sort_by_key(|c| std::cmp::Reverse(c.1));
+
+        for (idx, size) in candidates {
+            if total <= limit {
+                break;
+            }
+            let tab = &mut self.tab_manager.tabs[idx];
+            let Some(result) = tab.result.take() else {
+                continue;
+            };
+            match self.spill_store.spill(&tab.id, &result) {
+                Ok(spilled) => {
+                    tracing::info!(tab = %tab.title, rows = spilled.rows, "查询结果已写入磁盘缓存");
+                    total = total.saturating_sub(size);
+                }
+                Err(e) => {
+                    tracing::warn!(error = %e, "写入结果缓存失败");
+                    tab.result = Some(result);
+                    break;
+                }
+            }
+        }
+        total
+    }
+
+    /// 读回活动标签页已写入磁盘的结果
+    fn restore_spilled_result(&mut self) {
+        let Some(tab) = self.tab_manager.get_active() else {
+            return;
+        };
+        if tab.result.is_some() || !self.spill_store.contains(&tab.id) {
+            return;
+        }
+        let id = tab.id.clone();
+        let Some(restored) = self.spill_store.restore(&id) else {
+            return;
+        };
+        match restored {
+            Ok(result) => {
+                let result = Some(Arc::new(result));
+                if let Some(tab) = self.tab_manager.get_active_mut() {
+                    tab.result = result.clone();
+                }
+                self.set_result(result);
+            }
+            Err(e) => {
+                self.notifications.error(tf("notify.spill_read_failed", &[&e.to_string()]));
+            }
+        }
+    }
+
+    /// 当前结果是否可以分页写入磁盘（按原始顺序浏览、没有搜索，且标签页没有在执行或显示在独立窗口中）
+    fn result_pages_allowed(&self) -> bool {
+        self.grid_state.is_plain_browsing()
+            && self.search_text.is_empty()
+            && self.tab_manager.get_active().is_some_and(|tab| !tab.executing && !tab.detached)
+    }
+
+    /// 表格正在使用的行及前后保留的行
+    fn rows_to_keep(&self) -> Range<usize> {
+        let rows = self.grid_state.rows_in_use();
+        rows.start.saturating_sub(KEEP_ROWS_AROUND)..rows.end.saturating_add(KEEP_ROWS_AROUND)
+    }
+
+    /// 把当前结果中远离表格显示位置的页写入磁盘，返回剩余的内存占用
+    fn spill_result_pages(&mut self, total: usize, limit: usize) -> usize {
+        if !self.result_pages_allowed() {
+            return total;
+        }
+        let keep = self.rows_to_keep();
+        match self.with_exclusive_result(None, |store, result| store.spill_pages(result, keep, total - limit)) {
+            Some(Ok(freed)) => {
+                if freed > 0 {
+                    tracing::info!(bytes = freed, "当前结果的部分页已写入磁盘缓存");
+                }
+                total.saturating_sub(freed)
+            }
+            Some(Err(e)) => {
+                tracing::warn!(error = %e, "写入结果缓存失败");
+                total
+            }
+            None => total,
+        }
+    }
+
+    /// 读回表格需要的页：当前结果中正在使用的行附近的页（需要所有行时读回全部），
+    /// 以及不再是当前结果的标签页结果的全部页。返回是否读回了页
+    fn load_needed_pages(&mut self) -> bool {
+        let mut loaded = false;
+        let background: Vec<usize> = self
+            .tab_manager
+            .tabs
+            .iter()
+            .enumerate()
+            .filter(|(_, tab)| {
+                tab.result.as_ref().is_some_and(|result| {
+                    self.spill_store.spilled_pages(result) > 0
+                        && !self.result.as_ref().is_some_and(|current| Arc::ptr_eq(current, result))
+                })
+            })
+            .map(|(idx, _)| idx)
+            .collect();
+        for idx in background {
+            loaded |= self.load_pages(Some(idx), 0..usize::MAX);
+        }
+
+        if self.result.as_ref().is_some_and(|result| self.spill_store.spilled_pages(result) > 0) {
+            let rows = if self.result_pages_allowed() { self.rows_to_keep() } else { 0..usize::MAX };
+            loaded |= self.load_pages(None, rows);
+        }
+        loaded
+    }
+
+    /// 读回当前结果中写入磁盘的所有页（导出、打印等需要完整结果的操作执行前调用）
+    pub(super) fn load_spilled_pages(&mut self) {
+        self.load_pages(None, 0..usize::MAX);
+    }
+
+    /// 读回结果中与 `rows` 重叠的页，返回是否读回了页
+    fn load_pages(&mut self, tab: Option<usize>, rows: Range<usize>) -> bool {
+        match self.with_exclusive_result(tab, |store, result| store.load_pages(result, rows)) {
+            Some(Ok(pages)) => pages > 0,
+            Some(Err(e)) => {
+                tracing::warn!(error = %e, "读取结果缓存失败");
+                self.notifications.error(tf("notify.spill_read_failed", &[&e.to_string()]));
+                false
+            }
+            None => false,
+        }
+    }
+
+    /// 对结果执行需要独占的操作（`tab` 为 `None` 时为当前结果）
+    ///
+    /// 同一份结果同时被当前结果和标签页引用，操作期间暂时取出这些引用；
+    /// 结果还被其他地方引用（流式读取、结果对比等）时操作不会修改结果
+    fn with_exclusive_result<R>(
+        &mut self,
+        tab: Option<usize>,
+        f: impl FnOnce(&mut SpillStore, &mut Arc<QueryResult>) -> io::Result<R>,
+    ) -> Option<io::Result<R>> {
+        let mut result = match tab {
+            Some(idx) => self.tab_manager.tabs.get_mut(idx)?.result.take()?,
+            None => self.result.take()?,
+        };
+        let current = tab.is_none() || self.result.take_if(|r| Arc::ptr_eq(r, &result)).is_some();
+        let holders: Vec<usize> = self
+            .tab_manager
+            .tabs
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(idx, t)| t.result.take_if(|r| Arc::ptr_eq(r, &result)).map(|_| idx))
+            .chain(tab)
+            .collect();
+
+        let output = f(&mut self.spill_store, &mut result);
+
+        for idx in holders {
+            self.tab_manager.tabs[idx].result = Some(Arc::clone(&result));
+        }
+        // 放回的是同一个结果，行号不变，筛选缓存仍然有效
+        if current {
+            self.result = Some(result);
+        }
+        Some(output)
+    }
+}
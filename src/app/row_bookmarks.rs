@@ -0,0 +1,123 @@
+//! 行书签
+//!
+//! 表格行号右键菜单按主键收藏行，按连接、数据库和表保存；切换表时载入该表的书签
+//! 用于行号标记。书签列表中选择书签后把主键转换为筛选条件，在服务端重新查询该表。
+//! 草稿本是临时连接，不保存书签。
+
+use crate::core::{mask_value, t, tf, RecentTarget, RowBookmark};
+use crate::ui::{ColumnFilter, FilterOperator};
+use super::DbManagerApp;
+
+/// 说明中包含的其他列数
+const LABEL_COLUMNS: usize = 2;
+
+impl DbManagerApp {
+    /// 书签列表显示的 (连接, 数据库)
+    pub(super) fn row_bookmark_scope(&self) -> Option<(String, Option<String>)> {
+        let name = self.manager.active.clone()?;
+        let conn = self.manager.connections.get(&name).filter(|c| !c.config.is_scratchpad())?;
+        Some((name, conn.selected_database.clone()))
+    }
+
+    /// 当前表变化后载入该表收藏的行
+    pub(super) fn sync_row_bookmarks(&mut self) {
+        let target = self.filter_preset_target();
+        if target == self.row_bookmark_target {
+            return;
+        }
+        self.grid_state.bookmarked_rows = match &target {
+            Some(RecentTarget { connection, database, table: Some(table) }) => self
+                .app_config
+                .row_bookmarks
+                .for_table(connection, database.as_deref(), table)
+                .iter()
+                .map(|b| b.key_value.clone())
+                .collect(),
+            _ => Default::default(),
+        };
+        self.row_bookmark_target = target;
+    }
+
+    /// 收藏或取消收藏结果中的一行（按主键）
+    pub(super) fn toggle_row_bookmark(&mut self, row: usize) {
+        let Some(RecentTarget { connection, database, table: Some(table) }) = self.filter_preset_target() else {
+            self.notifications.warning(t("notify.bookmark_needs_table"));
+            return;
+        };
+        let (Some(result), Some(pk)) = (&self.result, self.grid_state.primary_key_column) else {
+            return;
+        };
+        let Some(values) = result.rows.get(row) else {
+            return;
+        };
+        let (Some(key_column), Some(key_value)) = (result.columns.get(pk), values.get(pk).cloned()) else {
+            return;
+        };
+        if key_value == "NULL" {
+            self.notifications.warning(t("notify.bookmark_null_key"));
+            return;
+        }
+        // 说明取该行其他列的前几个非空值（敏感列显示脱敏值）
+        let label = result
+            .columns
+            .iter()
+            .zip(values)
+            .enumerate()
+            .filter(|&(i, (_, value))| i != pk && !value.is_empty() && value != "NULL")
+            .take(LABEL_COLUMNS)
+            .map(|(_, (column, value))| {
+                if self.grid_state.is_masked(column) { mask_value(value) } else { value.clone() }
+            })
+            .collect::<Vec<_>>()
+            .join(" · ");
+        let bookmark = RowBookmark { key_column: key_column.clone(), key_value: key_value.clone(), label };
+
+        let added = self.app_config.row_bookmarks.toggle(&connection, database.as_deref(), &table, bookmark);
+        if added {
+            self.grid_state.bookmarked_rows.insert(key_value.clone());
+            self.notifications.success(tf("notify.bookmark_added", &[&table, key_column, &key_value]));
+        } else {
+            self.grid_state.bookmarked_rows.remove(&key_value);
+            self.notifications.info(tf("notify.bookmark_removed", &[&table, key_column, &key_value]));
+        }
+        self.save_config();
+    }
+
+    /// 删除书签列表中的书签
+    pub(super) fn remove_row_bookmark(&mut self, table: &str, bookmark: &RowBookmark) {
+        let Some((connection, database)) = self.row_bookmark_scope() else {
+            return;
+        };
+        self.app_config.row_bookmarks.remove(
+            &connection,
+            database.as_deref(),
+            table,
+            &bookmark.key_column,
+            &bookmark.key_value,
+        );
+        if self.selected_table.as_deref() == Some(table) {
+            self.grid_state.bookmarked_rows.remove(&bookmark.key_value);
+        }
+        self.save_config();
+    }
+
+    /// 按主键重新查询书签所在的表，筛选条件替换为主键等于书签的值
+    pub(super) fn jump_to_row_bookmark(&mut self, table: String, bookmark: RowBookmark) {
+        if self.selected_table.as_deref() != Some(table.as_str()) {
+            self.record_recent(Some(&table));
+            self.selected_table = Some(table.clone());
+            self.grid_state.primary_key_column = None;
+            self.fetch_primary_key(&table);
+            self.fetch_column_types(&table);
+        }
+        self.grid_state.filters = vec![
+            ColumnFilter::new(bookmark.key_column)
+                .with_operator(FilterOperator::Equals)
+                .with_value(bookmark.key_value)
+                .with_case_sensitive(true),
+        ];
+        self.grid_state.filter_cache.invalidate();
+        self.row_bookmark_state.close();
+        self.apply_filters_server_side();
+    }
+}
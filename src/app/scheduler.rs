@@ -0,0 +1,195 @@
+//! 定时查询模块
+//!
+//! 按调度器的计划在后台执行查询，将结果更新到固定的结果标签页，并在越过阈值时提醒。
+
+use std::sync::Arc;
+use std::time::Instant;
+
+use chrono::Local;
+use eframe::egui;
+
+use crate::core::{tf, AuditRecorder, AuditSource, ScheduledQuery};
+use crate::database::{execute_query, QueryResult};
+use crate::ui;
+use super::{DbManagerApp, Message};
+
+impl DbManagerApp {
+    /// 打开定时查询对话框，以当前编辑器中的查询作为新任务
+    pub(super) fn open_scheduler(&mut self) {
+        let connection = self.manager.active.clone();
+        self.scheduler_state.open(connection, &self.sql);
+    }
+
+    /// 处理定时查询对话框的操作
+    pub(super) fn handle_scheduler_action(&mut self, action: ui::SchedulerResult) {
+        let now = Local::now().naive_local();
+        match action {
+            ui::SchedulerResult::Create(job) => self.add_scheduled_query(*job),
+            ui::SchedulerResult::SetEnabled(id, enabled) => self.scheduler.set_enabled(id, enabled, now),
+            ui::SchedulerResult::RunNow(id) => self.scheduler.run_now(id, now),
+            ui::SchedulerResult::Remove(id) => {
+                if let Some(job) = self.scheduler.remove(id) {
+                    self.unpin_tab(job.tab_id.as_deref());
+                    self.notifications.info(tf("notify.schedule_deleted", &[&job.name]));
+                }
+            }
+            ui::SchedulerResult::ShowTab(tab_id) => {
+                if let Some(idx) = self.tab_manager.position(&tab_id) {
+                    self.scheduler_state.close();
+                    self.activate_tab(idx);
+                }
+            }
+            ui::SchedulerResult::None => {}
+        }
+    }
+
+    /// 创建固定的结果标签页并开始调度
+    fn add_scheduled_query(&mut self, mut job: ScheduledQuery) {
+        let Some(tab_id) = self.tab_manager.new_pinned_tab(&format!("⏱ {}", job.name), &job.sql) else {
+            self.scheduler_state.error = Some("标签页数量已达上限，请先关闭部分标签页".to_string());
+            return;
+        };
+        job.tab_id = Some(tab_id);
+        let description = job.schedule.describe();
+        let name = job.name.clone();
+        self.scheduler.add(job, Local::now().naive_local());
+        self.scheduler_state.close();
+        // 新标签页已激活，同步编辑器和结果
+        self.activate_tab(self.tab_manager.active_index);
+        self.notifications.success(tf("notify.schedule_started", &[&name, &description]));
+    }
+
+    /// 切换到指定标签页并同步编辑器和结果
+    pub(super) fn activate_tab(&mut self, idx: usize) {
+        self.tab_manager.set_active(idx);
+        if let Some(tab) = self.tab_manager.get_active() {
+            self.sql = tab.sql.clone();
+            self.grid_state.page = tab.page.clone();
+            self.last_query_time_ms = tab.query_time_ms;
+            let result = tab.result.clone();
+            self.set_result(result);
+        }
+    }
+
+    /// 取消标签页的固定状态（任务删除后标签页保留为普通查询）
+    fn unpin_tab(&mut self, tab_id: Option<&str>) {
+        if let Some(idx) = tab_id.and_then(|id| self.tab_manager.position(id)) {
+            let tab = &mut self.tab_manager.tabs[idx];
+            tab.pinned = false;
+            tab.executing = false;
+        }
+    }
+
+    /// 执行到期的定时查询，并安排在下一个任务到期时重绘
+    pub(super) fn run_scheduled_queries(&mut self, ctx: &egui::Context) {
+        if self.scheduler.is_empty() {
+            return;
+        }
+        let now = Local::now().naive_local();
+
+        for job in self.scheduler.take_due(now) {
+            // 结果标签页已关闭时停止任务
+            let Some(idx) = job.tab_id.as_deref().and_then(|id| self.tab_manager.position(id)) else {
+                self.scheduler.remove(job.id);
+                self.notifications.info(tf("notify.schedule_tab_closed", &[&job.name]));
+                continue;
+            };
+            let Some(config) = self.manager.connections.get(&job.connection).map(|c| c.config.clone()) else {
+                self.scheduler.finish(job.id, now, Err("连接不存在"));
+                continue;
+            };
+            self.tab_manager.tabs[idx].executing = true;
+
+            let tx = self.tx.clone();
+            let audit = AuditRecorder::new(
+                self.audit_log.clone(),
+                job.connection.clone(),
+                config.db_type.display_name().to_string(),
+                AuditSource::Scheduler,
+            );
+            let config = self.resolve_timeouts(config);
+            let timeout_secs = config.query_timeout_secs();
+            self.runtime.spawn(async move {
+                use tokio::time::{timeout, Duration};
+                let start = Instant::now();
+                let result = match timeout(Duration::from_secs(timeout_secs), execute_query(&config, &job.sql)).await {
+                    Ok(Ok(res)) => Ok(res),
+                    Ok(Err(e)) => Err(e.to_string()),
+                    Err(_) => Err(format!("查询超时 ({}秒)", timeout_secs)),
+                };
+                audit.record(&job.sql, result.as_ref().map(|r| r.affected_rows).map_err(String::as_str));
+                let elapsed_ms = start.elapsed().as_millis() as u64;
+                if tx.send(Message::ScheduledQueryDone(job.id, result, elapsed_ms)).is_err() {
+                    tracing::warn!("无法发送定时查询结果：接收端已关闭");
+                }
+            });
+        }
+
+        if let Some(wait) = self.scheduler.time_until_next(now) {
+            ctx.request_repaint_after(wait);
+        }
+    }
+
+    /// 处理定时查询完成消息：更新结果标签页并检查阈值
+    pub(super) fn handle_scheduled_query_done(
+        &mut self,
+        ctx: &egui::Context,
+        id: u64,
+        result: Result<QueryResult, String>,
+        elapsed_ms: u64,
+    ) {
+        let Some(job) = self.scheduler.get(id) else {
+            return;
+        };
+        let name = job.name.clone();
+        let tab_id = job.tab_id.clone();
+        let had_error = job.last_error.is_some();
+
+        let now = Local::now().naive_local();
+        let alert = self.scheduler.finish(id, now, result.as_ref().map_err(String::as_str));
+        let run_count = self.scheduler.get(id).map_or(0, |j| j.run_count);
+
+        let message = match &result {
+            Ok(res) => format!(
+                "定时查询第 {} 次 · {} · 返回 {} 行 ({}ms)",
+                run_count,
+                now.format("%H:%M:%S"),
+                res.rows.len(),
+                elapsed_ms
+            ),
+            Err(e) => format!("定时查询第 {} 次 · {} · 错误: {}", run_count, now.format("%H:%M:%S"), e),
+        };
+
+        if let Some(idx) = tab_id.as_deref().and_then(|id| self.tab_manager.position(id)) {
+            let is_active = idx == self.tab_manager.active_index;
+            let tab = &mut self.tab_manager.tabs[idx];
+            tab.executing = false;
+            tab.query_time_ms = Some(elapsed_ms);
+            tab.last_message = Some(message);
+            if let Ok(mut res) = result {
+                let max_rows = self.app_config.max_result_rows;
+                if res.rows.len() > max_rows {
+                    res.original_row_count = Some(res.rows.len());
+                    res.rows.truncate(max_rows);
+                    res.truncated = true;
+                }
+                tab.result = Some(Arc::new(res));
+                tab.page = None;
+                if is_active {
+                    let result = tab.result.clone();
+                    self.grid_state.page = None;
+                    self.last_query_time_ms = Some(elapsed_ms);
+                    self.set_result(result);
+                }
+            } else if !had_error {
+                // 连续失败时只提醒一次
+                self.notifications.error(tf("notify.schedule_failed", &[&name]));
+            }
+        }
+
+        if let Some(alert) = alert {
+            self.notifications.warning(alert);
+        }
+        ctx.request_repaint();
+    }
+}
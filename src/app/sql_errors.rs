@@ -0,0 +1,53 @@
+//! 查询错误提示
+//!
+//! 分析数据库返回的错误信息：在错误信息后附加出错行号和拼写相近的表名或列名，
+//! 当前标签页的查询出错时在编辑器中标出出错位置，修改 SQL 后标记消失。
+
+use crate::core::{analyze_sql_error, suggest_for_unknown, SqlErrorInfo, UnknownObject};
+use crate::ui::SqlErrorMarker;
+
+use super::DbManagerApp;
+
+impl DbManagerApp {
+    /// 生成显示给用户的错误信息（附带出错行号和名称建议）
+    pub(super) fn describe_query_error(&self, sql: &str, error: &str) -> (String, SqlErrorInfo) {
+        let info = analyze_sql_error(error, sql);
+        let mut message = format!("错误: {}", error);
+        if let Some(line) = info.line
+            && sql.trim().contains('\n')
+        {
+            message.push_str(&format!("\n出错位置: 第 {} 行", line));
+        }
+        if let Some(object) = &info.unknown
+            && let Some(suggestion) = suggest_for_unknown(object, sql, &self.autocomplete)
+        {
+            let kind = match object {
+                UnknownObject::Column(_) => "列",
+                UnknownObject::Table(_) => "表",
+            };
+            message.push_str(&format!("\n是否想输入{} {}？", kind, suggestion));
+        }
+        (message, info)
+    }
+
+    /// 在编辑器中标出出错位置（执行的语句不在编辑器中时不标记）
+    pub(super) fn mark_sql_error(&mut self, sql: &str, info: &SqlErrorInfo, message: &str) {
+        self.sql_error_marker = None;
+        let Some(span) = &info.span else {
+            return;
+        };
+        // 执行的可能是编辑器中的一条语句或选中的文本
+        let statement = sql.trim();
+        let lead = sql.len() - sql.trim_start().len();
+        let Some(base) = self.sql.find(statement) else {
+            return;
+        };
+        let start = span.start.saturating_sub(lead).min(statement.len());
+        let end = span.end.saturating_sub(lead).clamp(start, statement.len());
+        self.sql_error_marker = Some(SqlErrorMarker {
+            text: self.sql.clone(),
+            span: base + start..base + end,
+            message: message.to_string(),
+        });
+    }
+}
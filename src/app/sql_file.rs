@@ -0,0 +1,175 @@
+//! .sql 文件的打开、保存和外部修改检测
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::core::{t, tf, SqlFile, FILE_CHECK_INTERVAL_SECS};
+use crate::ui;
+
+use super::DbManagerApp;
+
+impl DbManagerApp {
+    /// 选择并打开 .sql 文件
+    pub(super) fn open_sql_file(&mut self) {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("SQL 文件", &["sql"])
+            .add_filter("所有文件", &["*"])
+            .pick_file()
+        else {
+            return;
+        };
+        self.open_sql_file_path(&path);
+    }
+
+    /// 在新标签页中打开指定的 .sql 文件（已打开时切换到对应标签页）
+    pub(super) fn open_sql_file_path(&mut self, path: &Path) {
+        let (file, content) = match SqlFile::open(path) {
+            Ok(opened) => opened,
+            Err(e) => {
+                self.notifications.error(tf("file.open_failed", &[&e.to_string()]));
+                return;
+            }
+        };
+        let name = file.file_name();
+        match self.tab_manager.open_file_tab(file, content) {
+            Some(idx) => {
+                self.activate_tab(idx);
+                self.show_sql_editor = true;
+                self.focus_area = ui::FocusArea::SqlEditor;
+                self.focus_sql_editor = true;
+                self.notifications.info(tf("file.opened", &[&name]));
+            }
+            None => {
+                self.notifications.warning(t("file.too_many_tabs"));
+            }
+        }
+    }
+
+    /// 保存当前标签页的 SQL 到文件
+    ///
+    /// 标签页未关联文件或 `save_as` 为 true 时先选择保存位置。
+    pub(super) fn save_sql_file(&mut self, save_as: bool) {
+        self.sync_file_tab();
+        let sql = self.sql.clone();
+        let current = self
+            .tab_manager
+            .get_active()
+            .and_then(|tab| tab.file.as_ref())
+            .map(|f| f.path().to_path_buf());
+
+        let path = match current {
+            Some(path) if !save_as => path,
+            _ => match Self::pick_save_path(current.as_deref()) {
+                Some(path) => path,
+                None => return,
+            },
+        };
+
+        let Some(tab) = self.tab_manager.get_active_mut() else {
+            return;
+        };
+        let file = match tab.file.as_mut() {
+            Some(file) if file.path() == path => file,
+            _ => tab.file.insert(SqlFile::new(&path)),
+        };
+        match file.save(&sql) {
+            Ok(()) => {
+                tab.title = file.file_name();
+                tab.sql = sql;
+                tab.modified = false;
+                self.notifications.success(tf("file.saved", &[&path.display().to_string()]));
+            }
+            Err(e) => {
+                self.notifications.error(tf("file.save_failed", &[&e.to_string()]));
+            }
+        }
+    }
+
+    fn pick_save_path(current: Option<&Path>) -> Option<PathBuf> {
+        let mut dialog = rfd::FileDialog::new().add_filter("SQL 文件", &["sql"]);
+        dialog = match current {
+            Some(path) => {
+                if let Some(dir) = path.parent() {
+                    dialog = dialog.set_directory(dir);
+                }
+                dialog.set_file_name(path.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default())
+            }
+            None => dialog.set_file_name("query.sql"),
+        };
+        dialog.save_file()
+    }
+
+    /// Ctrl+S 是否保存 SQL 文件（而不是表格修改）
+    ///
+    /// 表格有未保存的修改时优先保存表格；否则焦点在编辑器或当前标签页关联了文件时保存文件。
+    pub(super) fn saves_sql_file(&self) -> bool {
+        if self.grid_state.has_changes() {
+            return false;
+        }
+        self.focus_area == ui::FocusArea::SqlEditor
+            || self.tab_manager.get_active().is_some_and(|tab| tab.file.is_some())
+    }
+
+    /// 将编辑器内容同步到关联文件的标签页，用于显示未保存标记
+    pub(super) fn sync_file_tab(&mut self) {
+        if let Some(tab) = self.tab_manager.get_active_mut()
+            && tab.file.is_some()
+            && tab.sql != self.sql
+        {
+            tab.sql = self.sql.clone();
+        }
+    }
+
+    /// 定期检查已打开的文件是否在外部被修改
+    pub(super) fn check_sql_files(&mut self) {
+        if self.last_file_check.elapsed() < Duration::from_secs(FILE_CHECK_INTERVAL_SECS) {
+            return;
+        }
+        self.last_file_check = Instant::now();
+        // 上一个提示未处理前不再检查
+        if self.file_change_state.show {
+            return;
+        }
+
+        for tab in &mut self.tab_manager.tabs {
+            let Some(file) = tab.file.as_mut() else {
+                continue;
+            };
+            if let Some(change) = file.check_external_change() {
+                let path = file.path().display().to_string();
+                self.file_change_state.open(tab.id.clone(), path, change);
+                break;
+            }
+        }
+    }
+
+    /// 处理外部修改提示的选择
+    pub(super) fn handle_file_change_result(&mut self, result: ui::FileChangeResult) {
+        if result == ui::FileChangeResult::None {
+            return;
+        }
+        let Some((tab_id, change)) = self.file_change_state.take() else {
+            return;
+        };
+        let Some(idx) = self.tab_manager.position(&tab_id) else {
+            return;
+        };
+        let is_active = idx == self.tab_manager.active_index;
+        let tab = &mut self.tab_manager.tabs[idx];
+        let Some(file) = tab.file.as_mut() else {
+            return;
+        };
+
+        match (result, change) {
+            (ui::FileChangeResult::Reload, crate::core::ExternalChange::Modified(content)) => {
+                file.reloaded(&content);
+                tab.sql = content;
+                if is_active {
+                    self.sql = tab.sql.clone();
+                }
+                self.notifications.info(tf("file.reloaded", &[&tab.title]));
+            }
+            (_, change) => file.keep_local(&change),
+        }
+    }
+}
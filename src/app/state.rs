@@ -7,7 +7,7 @@
 use crate::database::{ConnectionConfig, ConnectionManager, QueryResult};
 use crate::ui::{
     DataGridState, ExportConfig, FocusArea, HistoryPanelState, ImportState,
-    KeyBindingsDialogState, QueryTabManager, SidebarPanelState, SidebarSection,
+    KeyBindingsDialogState, QueryTabManager, SearchOptions, SidebarPanelState, SidebarSection,
 };
 use crate::ui::{
     CreateDbDialogState, CreateUserDialogState, DdlDialogState, ERDiagramState,
@@ -17,6 +17,7 @@ use crate::core::{
     QueryHistory, ThemeManager,
 };
 use std::sync::mpsc::{Sender, Receiver};
+use std::sync::Arc;
 use super::message::Message;
 
 /// 连接相关状态
@@ -40,7 +41,7 @@ pub struct QueryState {
     /// 当前 SQL 编辑器内容
     pub sql: String,
     /// 当前查询结果
-    pub result: Option<QueryResult>,
+    pub result: Option<Arc<QueryResult>>,
     /// 多 Tab 查询管理器
     pub tab_manager: QueryTabManager,
     /// 是否正在执行查询
@@ -64,6 +65,8 @@ pub struct SearchState {
     pub search_text: String,
     /// 搜索限定的列名
     pub search_column: Option<String>,
+    /// 搜索选项（正则、全词、大小写）
+    pub search_options: SearchOptions,
     /// 当前选中的行索引
     pub selected_row: Option<usize>,
     /// 当前选中的单元格 (行, 列)
@@ -77,6 +80,7 @@ impl SearchState {
         Self {
             search_text: String::new(),
             search_column: None,
+            search_options: SearchOptions::default(),
             selected_row: None,
             selected_cell: None,
             grid_state: DataGridState::new(),
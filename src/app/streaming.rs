@@ -0,0 +1,196 @@
+//! 流式读取查询结果
+//!
+//! SQLite、PostgreSQL、MySQL 的查询按批读取：第一批行到达后表格即开始显示，
+//! 其余行在后台继续追加，读取进度显示在工具栏的进度指示中，可随时取消。
+
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Instant;
+
+use crate::core::{constants, tf, AuditRecorder};
+use crate::database::{execute_query_streaming, ConnectionConfig, QueryResult};
+
+use super::message::Message;
+use super::DbManagerApp;
+
+/// 正在流式读取的查询
+pub(super) struct StreamingQuery {
+    /// 进度任务 ID（同时用于识别属于本次查询的消息）
+    progress_id: u64,
+    /// 发起查询的 Tab
+    tab_id: Option<String>,
+    /// 已读取的结果（与表格、Tab 共享）
+    result: Arc<QueryResult>,
+}
+
+impl DbManagerApp {
+    /// 在后台流式执行查询，行按批通过 `Message::RowsChunk` 发送
+    pub(super) fn spawn_streaming_query(
+        &mut self,
+        config: ConnectionConfig,
+        sql: String,
+        audit: AuditRecorder,
+        tab_id: Option<String>,
+    ) {
+        // 同一 Tab 的新查询取代尚未读取完的查询，其他 Tab 的读取继续进行
+        if let Some(index) = self.streaming_queries.iter().position(|s| s.tab_id == tab_id) {
+            let previous = self.streaming_queries.swap_remove(index);
+            self.progress.cancel(previous.progress_id);
+        }
+        let progress_id = self.progress.start("读取查询结果", true);
+        let Some(cancel) = self.progress.get(progress_id).map(|task| task.cancel_token()) else {
+            return;
+        };
+        self.streaming_queries.push(StreamingQuery {
+            progress_id,
+            tab_id,
+            result: Arc::default(),
+        });
+
+        let tx = self.tx.clone();
+        let max_rows = self.app_config.max_result_rows;
+        let config = self.resolve_timeouts(config);
+        let timeout_secs = config.query_timeout_secs();
+        self.runtime.spawn(async move {
+            use tokio::time::{timeout, Duration};
+            let start = Instant::now();
+            let chunk_tx = tx.clone();
+            let mut sent = 0;
+            // 超过结果集行数上限的行只计数，不发送到界面
+            let on_chunk = move |columns: &[String], mut rows: Vec<Vec<String>>| {
+                if cancel.load(Ordering::Relaxed) {
+                    return false;
+                }
+                rows.truncate(max_rows.saturating_sub(sent));
+                if rows.is_empty() {
+                    return true;
+                }
+                sent += rows.len();
+                chunk_tx.send(Message::RowsChunk(progress_id, columns.to_vec(), rows)).is_ok()
+            };
+            let result = timeout(
+                Duration::from_secs(timeout_secs),
+                execute_query_streaming(&config, &sql, constants::database::STREAM_CHUNK_SIZE, on_chunk),
+            )
+            .await;
+            let elapsed_ms = start.elapsed().as_millis() as u64;
+            let query_result = match result {
+                Ok(Ok(res)) => Ok(res),
+                Ok(Err(e)) => Err(e.to_string()),
+                Err(_) => Err(format!(
+                    "查询超时 ({}秒)。建议: 1) 添加 LIMIT 限制结果集 2) 优化查询条件 3) 检查索引",
+                    timeout_secs
+                )),
+            };
+            audit.record(&sql, query_result.as_ref().map(|r| r.affected_rows).map_err(String::as_str));
+            if tx
+                .send(Message::StreamDone(progress_id, sql, query_result, elapsed_ms))
+                .is_err()
+            {
+                tracing::warn!("无法发送查询结果：接收端已关闭");
+            }
+        });
+    }
+
+    /// 处理流式读取的一批行：追加到发起查询的 Tab，当前 Tab 的表格随之更新
+    pub(super) fn handle_rows_chunk(
+        &mut self,
+        ctx: &egui::Context,
+        progress_id: u64,
+        columns: Vec<String>,
+        rows: Vec<Vec<String>>,
+    ) {
+        let Some(mut stream) = self.take_stream(progress_id) else {
+            return;
+        };
+        let tab_index = self.release_streamed_result(&stream);
+        let shown = match &stream.tab_id {
+            Some(_) => tab_index == Some(self.tab_manager.active_index),
+            None => true,
+        };
+
+        let result = Arc::make_mut(&mut stream.result);
+        if result.rows.is_empty() {
+            result.columns = columns;
+            if shown {
+                self.grid_state.page = None;
+                self.selected_row = None;
+                self.selected_cell = None;
+            }
+        }
+        result.rows.extend(rows);
+        self.progress
+            .set_description(progress_id, format!("读取查询结果: 已读取 {} 行", result.rows.len()));
+
+        if let Some(tab) = tab_index.and_then(|i| self.tab_manager.tabs.get_mut(i)) {
+            tab.result = Some(Arc::clone(&stream.result));
+        }
+        if shown {
+            self.set_result(Some(Arc::clone(&stream.result)));
+        }
+        self.streaming_queries.push(stream);
+        ctx.request_repaint();
+    }
+
+    /// 处理流式读取完成：把已显示的行并入最终结果，之后按普通查询完成处理
+    pub(super) fn handle_stream_done(
+        &mut self,
+        ctx: &egui::Context,
+        progress_id: u64,
+        sql: String,
+        result: Result<QueryResult, String>,
+        elapsed_ms: u64,
+    ) {
+        // 已被新查询取代的读取直接丢弃
+        let Some(stream) = self.take_stream(progress_id) else {
+            return;
+        };
+        let cancelled = self.progress.get(progress_id).is_none();
+        self.progress.finish(progress_id);
+        self.release_streamed_result(&stream);
+
+        let result = result.map(|mut res| {
+            let mut rows = Arc::unwrap_or_clone(stream.result).rows;
+            if !rows.is_empty() {
+                rows.append(&mut res.rows);
+                res.rows = rows;
+            }
+            // 未超过行数上限时总行数与显示的行数一致
+            if res.original_row_count == Some(res.rows.len()) {
+                res.original_row_count = None;
+            }
+            res
+        });
+        let shown_rows = result.as_ref().map_or(0, |res| res.rows.len());
+        self.finish_query(ctx, stream.tab_id, sql, result, elapsed_ms, None);
+        if cancelled {
+            self.notifications.warning(tf("notify.stream_cancelled", &[&shown_rows.to_string()]));
+        }
+    }
+
+    /// 取出指定进度任务对应的读取
+    fn take_stream(&mut self, progress_id: u64) -> Option<StreamingQuery> {
+        let index = self.streaming_queries.iter().position(|s| s.progress_id == progress_id)?;
+        Some(self.streaming_queries.swap_remove(index))
+    }
+
+    /// 释放表格和发起查询的 Tab 对已读取结果的引用，追加行时无需复制，返回该 Tab 的索引
+    fn release_streamed_result(&mut self, stream: &StreamingQuery) -> Option<usize> {
+        let shares = |result: &Option<Arc<QueryResult>>| {
+            result.as_ref().is_some_and(|r| Arc::ptr_eq(r, &stream.result))
+        };
+        if shares(&self.result) {
+            self.set_result(None);
+        }
+        let tab_index = stream
+            .tab_id
+            .as_ref()
+            .and_then(|id| self.tab_manager.tabs.iter().position(|tab| &tab.id == id));
+        if let Some(tab) = tab_index.and_then(|i| self.tab_manager.tabs.get_mut(i))
+            && shares(&tab.result)
+        {
+            tab.result = None;
+        }
+        tab_index
+    }
+}
@@ -0,0 +1,161 @@
+//! 自定义主题模块
+//!
+//! 打开主题编辑器、预览和保存自定义主题，以及导入/导出主题文件；
+//! 应用字体设置。
+
+use crate::core::{apply_fonts, clear_highlight_cache, t, tf, CustomTheme, FontSettings, HighlightColors};
+use crate::ui;
+use eframe::egui;
+use super::DbManagerApp;
+
+impl DbManagerApp {
+    /// 打开主题编辑器：正在使用自定义主题时编辑它，否则以当前预设为起点
+    pub(super) fn open_theme_editor(&mut self) {
+        let (theme, original_name) = match &self.theme_manager.custom {
+            Some(theme) => (theme.clone(), Some(theme.name.clone())),
+            None => {
+                let name = format!("{} 自定义", self.theme_manager.current.display_name());
+                (CustomTheme::from_preset(name, self.theme_manager.current), None)
+            }
+        };
+        self.theme_editor_state.open(theme, original_name, self.custom_theme_names());
+    }
+
+    /// 已保存的自定义主题名称
+    fn custom_theme_names(&self) -> Vec<String> {
+        self.app_config.custom_themes.iter().map(|t| t.name.clone()).collect()
+    }
+
+    /// 应用自定义主题（不写入配置）
+    fn apply_custom_theme(&mut self, ctx: &egui::Context, theme: CustomTheme) {
+        self.theme_manager.set_custom(theme);
+        self.theme_manager.apply(ctx);
+        self.highlight_colors = HighlightColors::from_manager(&self.theme_manager);
+        clear_highlight_cache();
+    }
+
+    /// 恢复配置中的主题（丢弃预览）
+    pub(super) fn restore_configured_theme(&mut self, ctx: &egui::Context) {
+        let custom = self
+            .app_config
+            .custom_theme
+            .as_ref()
+            .and_then(|name| self.app_config.custom_themes.iter().find(|t| &t.name == name))
+            .cloned();
+        match custom {
+            Some(theme) => self.apply_custom_theme(ctx, theme),
+            None => {
+                self.theme_manager.set_theme(self.app_config.theme_preset);
+                self.theme_manager.apply(ctx);
+                self.highlight_colors = HighlightColors::from_manager(&self.theme_manager);
+                clear_highlight_cache();
+            }
+        }
+    }
+
+    /// 处理主题编辑器的结果
+    pub(super) fn handle_theme_editor_result(&mut self, ctx: &egui::Context, result: ui::ThemeEditorResult) {
+        match result {
+            ui::ThemeEditorResult::None => {}
+            ui::ThemeEditorResult::Preview(theme) => self.apply_custom_theme(ctx, theme),
+            ui::ThemeEditorResult::Save { original_name, theme } => {
+                let themes = &mut self.app_config.custom_themes;
+                let existing = original_name.as_ref().and_then(|name| themes.iter().position(|t| &t.name == name));
+                match existing {
+                    Some(idx) => themes[idx] = theme.clone(),
+                    None => themes.push(theme.clone()),
+                }
+                self.app_config.custom_theme = Some(theme.name.clone());
+                self.apply_custom_theme(ctx, theme.clone());
+                self.theme_editor_state.original_name = Some(theme.name.clone());
+                self.theme_editor_state.saved_names = self.custom_theme_names();
+                self.theme_editor_state.error = None;
+                match self.app_config.save() {
+                    Ok(()) => {
+                        self.notifications.success(tf("theme.saved", &[&theme.name]));
+                    }
+                    Err(e) => {
+                        self.notifications.error(tf("common.save_config_failed", &[&e]));
+                    }
+                }
+            }
+            ui::ThemeEditorResult::Load(name) => {
+                if let Some(theme) = self.app_config.custom_themes.iter().find(|t| t.name == name).cloned() {
+                    self.theme_editor_state.open(theme.clone(), Some(name), self.custom_theme_names());
+                    self.apply_custom_theme(ctx, theme);
+                }
+            }
+            ui::ThemeEditorResult::Delete(name) => {
+                self.app_config.custom_themes.retain(|t| t.name != name);
+                if self.app_config.custom_theme.as_deref() == Some(name.as_str()) {
+                    self.app_config.custom_theme = None;
+                }
+                // 继续以当前颜色编辑一个未保存的副本
+                self.theme_editor_state.original_name = None;
+                self.theme_editor_state.saved_names = self.custom_theme_names();
+                if let Err(e) = self.app_config.save() {
+                    self.notifications.error(tf("common.save_config_failed", &[&e]));
+                } else {
+                    self.notifications.info(tf("theme.deleted", &[&name]));
+                }
+            }
+            ui::ThemeEditorResult::Import => self.import_custom_theme(ctx),
+            ui::ThemeEditorResult::Export(theme) => self.export_custom_theme(&theme),
+            ui::ThemeEditorResult::Close => self.restore_configured_theme(ctx),
+        }
+    }
+
+    /// 从 TOML/JSON 文件导入主题到编辑器（保存后才会写入配置）
+    fn import_custom_theme(&mut self, ctx: &egui::Context) {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("主题文件", &["toml", "json"])
+            .pick_file()
+        else {
+            return;
+        };
+
+        match CustomTheme::import_from(&path) {
+            Ok(theme) => {
+                let original_name = self.custom_theme_names().into_iter().find(|n| *n == theme.name);
+                self.theme_editor_state.open(theme.clone(), original_name, self.custom_theme_names());
+                self.notifications.info(tf("theme.imported", &[&theme.name]));
+                self.apply_custom_theme(ctx, theme);
+            }
+            Err(e) => {
+                self.notifications.error(e);
+            }
+        }
+    }
+
+    /// 导出主题到文件
+    fn export_custom_theme(&mut self, theme: &CustomTheme) {
+        let Some(path) = rfd::FileDialog::new()
+            .set_file_name(format!("{}.toml", theme.name))
+            .add_filter("TOML", &["toml"])
+            .add_filter("JSON", &["json"])
+            .save_file()
+        else {
+            return;
+        };
+
+        match theme.export_to(&path) {
+            Ok(()) => {
+                self.notifications.success(tf("theme.exported", &[&path.display().to_string()]));
+            }
+            Err(e) => {
+                self.notifications.error(e);
+            }
+        }
+    }
+
+    /// 应用并保存字体设置
+    pub(super) fn apply_font_settings(&mut self, ctx: &egui::Context, settings: FontSettings) {
+        apply_fonts(ctx, &settings);
+        self.app_config.fonts = settings;
+        if let Err(e) = self.app_config.save() {
+            self.notifications.error(tf("fonts.save_failed", &[&e]));
+        } else {
+            self.notifications.success(t("fonts.applied"));
+        };
+    }
+}
@@ -0,0 +1,63 @@
+//! 新手引导
+//!
+//! 首次启动（还没有连接）时自动开始，之后可以在帮助或命令面板中重新打开。
+//! 各区域在渲染时记录自己的位置，引导覆盖层据此高亮当前步骤的区域。
+
+use eframe::egui;
+
+use crate::core::{t, TourTarget};
+use crate::ui::{TourAction, TourOverlay};
+
+use super::DbManagerApp;
+
+impl DbManagerApp {
+    /// 从第一步开始引导
+    pub(super) fn start_tour(&mut self) {
+        self.show_help = false;
+        self.tour.start();
+    }
+
+    /// 记录区域在本帧的位置
+    pub(super) fn mark_tour_target(&mut self, target: TourTarget, rect: egui::Rect) {
+        if self.tour.is_active() {
+            self.tour_targets.insert(target, rect);
+        }
+    }
+
+    /// 显示引导覆盖层（在主界面渲染之后调用）
+    pub(super) fn show_tour(&mut self, ctx: &egui::Context) {
+        if !self.tour.is_active() {
+            return;
+        }
+        let target = self
+            .tour
+            .current()
+            .and_then(|step| step.target)
+            .and_then(|t| self.tour_targets.get(&t).copied());
+        // 隐藏的区域不会再记录位置，每帧重新收集
+        self.tour_targets.clear();
+
+        match TourOverlay::show(ctx, &self.tour, target) {
+            TourAction::Next => {
+                if self.tour.advance() {
+                    self.finish_tour();
+                }
+            }
+            TourAction::Prev => self.tour.prev(),
+            TourAction::Skip => {
+                self.tour.finish();
+                self.finish_tour();
+            }
+            TourAction::None => {}
+        }
+    }
+
+    /// 记录已完成引导，之后启动时不再自动显示
+    fn finish_tour(&mut self) {
+        if !self.app_config.tour_completed {
+            self.app_config.tour_completed = true;
+            let _ = self.app_config.save();
+        }
+        self.notifications.info(t("notify.tour_reopen_hint"));
+    }
+}
@@ -0,0 +1,145 @@
+//! 回收站
+//!
+//! 删除的连接和关闭的查询标签页先放入回收站，可以在回收站窗口中恢复，Ctrl+Shift+T 重新打开最近关闭的标签页。
+//! 连接的最近使用记录、筛选预设和列设置在回收站中的记录被永久删除时才清除。
+
+use chrono::Local;
+
+use crate::core::{t, tf, TabState, TrashEntry, TrashItem};
+use crate::database::ConnectionConfig;
+use crate::ui::QueryTab;
+
+use super::DbManagerApp;
+
+impl DbManagerApp {
+    /// 把删除的连接连同命令历史放入回收站
+    pub(super) fn trash_connection(&mut self, config: ConnectionConfig) {
+        let history = self.app_config.command_history.remove(&config.name).unwrap_or_default();
+        self.notifications.info(tf("notify.connection_trashed", &[&config.name]));
+        self.app_config.trash.push_connection(config, history, Local::now());
+    }
+
+    /// 把刚关闭的标签页放入回收站（空白标签页和定时查询的结果标签页除外）
+    pub(super) fn trash_closed_tabs(&mut self) {
+        let closed: Vec<TabState> = self
+            .tab_manager
+            .take_closed()
+            .into_iter()
+            .filter(|tab| !tab.scheduled && !tab.sql.trim().is_empty())
+            .map(|tab| tab.to_state())
+            .collect();
+        if closed.is_empty() {
+            return;
+        }
+        for state in closed {
+            self.app_config.trash.push_tab(state, Local::now());
+        }
+        self.save_config();
+    }
+
+    /// 重新打开最近关闭的标签页
+    pub(super) fn reopen_closed_tab(&mut self) {
+        if !self.app_config.trash.entries().iter().any(|e| e.item.is_tab()) {
+            self.notifications.info(t("notify.no_closed_tabs"));
+            return;
+        }
+        if !self.has_room_for_tab() {
+            return;
+        }
+        if let Some(state) = self.app_config.trash.pop_tab() {
+            self.open_restored_tab(state);
+            self.save_config();
+        }
+    }
+
+    /// 恢复回收站中的记录
+    pub(super) fn restore_trash_entry(&mut self, index: usize) {
+        let is_tab = self.app_config.trash.entries().get(index).is_some_and(|e| e.item.is_tab());
+        if is_tab && !self.has_room_for_tab() {
+            return;
+        }
+        let Some(entry) = self.app_config.trash.take(index) else {
+            return;
+        };
+        match entry.item {
+            TrashItem::Tab(state) => self.open_restored_tab(state),
+            TrashItem::Connection { mut config, history } => {
+                // 名称已被新建的连接使用时加上序号
+                let base = config.name.clone();
+                let mut n = 2;
+                while self.manager.connections.contains_key(&config.name) {
+                    config.name = format!("{} ({})", base, n);
+                    n += 1;
+                }
+                if !history.is_empty() {
+                    self.app_config.command_history.insert(config.name.clone(), history);
+                }
+                self.notifications.success(tf("notify.connection_restored", &[&config.name]));
+                self.manager.add(*config);
+            }
+        }
+        self.save_config();
+    }
+
+    /// 永久删除回收站中的一条记录
+    pub(super) fn delete_trash_entry(&mut self, index: usize) {
+        if let Some(entry) = self.app_config.trash.take(index) {
+            self.forget_trashed_connections(vec![entry]);
+            self.save_config();
+        }
+    }
+
+    /// 清空回收站
+    pub(super) fn empty_trash(&mut self) {
+        let entries = self.app_config.trash.clear();
+        self.forget_trashed_connections(entries);
+        self.save_config();
+    }
+
+    /// 清除超过保留天数的记录（启动时调用）
+    pub(super) fn purge_expired_trash(&mut self) {
+        let expired = self.app_config.trash.purge(Local::now(), self.app_config.trash_retention_days);
+        if !expired.is_empty() {
+            tracing::info!(count = expired.len(), "已清除回收站中过期的记录");
+            self.forget_trashed_connections(expired);
+            self.save_config();
+        }
+    }
+
+    /// 清除按连接保存的数据
+    pub(super) fn forget_connection_data(&mut self, name: &str) {
+        self.app_config.recent_objects.remove_connection(name);
+        self.app_config.filter_presets.remove_connection(name);
+        self.app_config.column_formats.remove_connection(name);
+        self.app_config.column_widths.remove_connection(name);
+        self.app_config.column_orders.remove_connection(name);
+        self.app_config.masking.remove_connection(name);
+        self.app_config.row_bookmarks.remove_connection(name);
+    }
+
+    /// 永久删除的连接不再保留其数据（同名的连接仍存在时保留）
+    fn forget_trashed_connections(&mut self, entries: Vec<TrashEntry>) {
+        for entry in entries {
+            if let TrashItem::Connection { config, .. } = entry.item
+                && !self.manager.connections.contains_key(&config.name)
+            {
+                self.forget_connection_data(&config.name);
+            }
+        }
+    }
+
+    /// 标签页数量是否还未达到上限
+    fn has_room_for_tab(&mut self) -> bool {
+        let room = self.tab_manager.tabs.len() < self.tab_manager.max_tabs;
+        if !room {
+            self.notifications.warning(t("notify.tab_limit"));
+        }
+        room
+    }
+
+    /// 在最右侧打开恢复的标签页
+    fn open_restored_tab(&mut self, state: TabState) {
+        self.tab_manager.tabs.push(QueryTab::from_state(state));
+        self.activate_tab(self.tab_manager.tabs.len() - 1);
+    }
+}
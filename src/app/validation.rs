@@ -0,0 +1,61 @@
+//! 检查 SQL
+//!
+//! 不执行而检查编辑器中的 SQL：逐条预处理语句（或 EXPLAIN），由数据库检查语法以及表和列是否存在。
+//! 发现问题时和查询出错一样在编辑器中标出位置，并在通知中给出名称建议。
+
+use eframe::egui;
+
+use crate::core::{t, tf};
+use crate::database::{split_statements, supports_validation, validate_sql};
+
+use super::message::Message;
+use super::DbManagerApp;
+
+impl DbManagerApp {
+    /// 在后台检查编辑器中的 SQL，结果通过 `Message::SqlValidated` 发送
+    pub(super) fn validate_editor_sql(&mut self) {
+        let statements = split_statements(&self.sql);
+        if statements.is_empty() {
+            return;
+        }
+        let Some(config) = self.manager.get_active().map(|c| c.config.clone()) else {
+            self.notifications.warning(t("common.need_connection"));
+            return;
+        };
+        if !supports_validation(config.db_type) {
+            self.notifications.warning(tf("notify.validation_unsupported", &[config.db_type.display_name()]));
+            return;
+        }
+
+        let config = self.resolve_timeouts(config);
+        let tx = self.tx.clone();
+        self.runtime.spawn(async move {
+            let mut result = Ok(statements.len());
+            for statement in statements {
+                if let Err(e) = validate_sql(&config, &statement).await {
+                    result = Err((statement, e.to_string()));
+                    break;
+                }
+            }
+            if tx.send(Message::SqlValidated(result)).is_err() {
+                tracing::warn!("无法发送检查结果：接收端已关闭");
+            }
+        });
+    }
+
+    /// 处理检查结果：通过时清除编辑器中的出错标记，否则标出第一条出错语句中的位置
+    pub(super) fn handle_sql_validated(&mut self, ctx: &egui::Context, result: Result<usize, (String, String)>) {
+        match result {
+            Ok(count) => {
+                self.sql_error_marker = None;
+                self.notifications.success(tf("notify.validation_passed", &[&count.to_string()]));
+            }
+            Err((statement, error)) => {
+                let (message, info) = self.describe_query_error(&statement, &error);
+                self.mark_sql_error(&statement, &info, &message);
+                self.notifications.warning(message);
+            }
+        }
+        ctx.request_repaint();
+    }
+}
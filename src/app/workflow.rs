@@ -0,0 +1,190 @@
+//! 工作流执行
+//!
+//! 在后台依次执行工作流的查询和导出步骤，逐步报告进度；命令行模式复用同一套执行逻辑。
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::time::Duration;
+
+use chrono::Local;
+use eframe::egui;
+
+use crate::core::{
+    expand_export_path, t, tf, write_csv, write_json, write_sql, AuditLog, AuditRecorder, AuditSource, ExportFormat,
+    PluginRegistry, Workflow, WorkflowRun, WorkflowStep,
+};
+use crate::database::{execute_query, ConnectionConfig, QueryResult};
+use crate::ui;
+use super::{DbManagerApp, Message};
+
+/// 依次执行工作流的步骤，每个步骤开始和结束时调用 `on_progress`
+///
+/// `connections` 中的配置应已补全超时设置。导出步骤导出最近一次查询步骤的结果，
+/// 指定了导出插件时在 `plugins` 中查找。
+pub(super) async fn execute_workflow(
+    mut run: WorkflowRun,
+    connections: &[ConnectionConfig],
+    plugins: &PluginRegistry,
+    audit_log: Option<AuditLog>,
+    mut on_progress: impl FnMut(&WorkflowRun),
+) -> WorkflowRun {
+    let mut last_result: Option<QueryResult> = None;
+    while let Some(idx) = run.start_next() {
+        on_progress(&run);
+        let outcome = match run.workflow.steps[idx].clone() {
+            WorkflowStep::Query { connection, sql } => {
+                match run_query_step(connections, &connection, &sql, audit_log.clone()).await {
+                    Ok(result) => {
+                        let message = if result.columns.is_empty() {
+                            format!("影响 {} 行", result.affected_rows)
+                        } else {
+                            format!("{} 行", result.rows.len())
+                        };
+                        last_result = Some(result);
+                        Ok(message)
+                    }
+                    Err(e) => Err(e),
+                }
+            }
+            WorkflowStep::Export { format, path, table, plugin } => match &last_result {
+                Some(result) => {
+                    let path = expand_export_path(&path, Local::now());
+                    export_step(result, format, plugin.as_deref(), &table, Path::new(&path), plugins)
+                        .map(|()| format!("已导出 {} 行到 {}", result.rows.len(), path))
+                }
+                None => Err("没有可导出的查询结果".to_string()),
+            },
+        };
+        run.finish_step(idx, outcome);
+        on_progress(&run);
+    }
+    run
+}
+
+async fn run_query_step(
+    connections: &[ConnectionConfig],
+    connection: &str,
+    sql: &str,
+    audit_log: Option<AuditLog>,
+) -> Result<QueryResult, String> {
+    let config = connections
+        .iter()
+        .find(|c| c.name == connection)
+        .ok_or_else(|| format!("连接不存在: {}", connection))?;
+    let audit = AuditRecorder::new(
+        audit_log,
+        config.name.clone(),
+        config.db_type.display_name().to_string(),
+        AuditSource::Workflow,
+    );
+    let timeout_secs = config.query_timeout_secs();
+    let result = match tokio::time::timeout(Duration::from_secs(timeout_secs), execute_query(config, sql)).await {
+        Ok(Ok(result)) => Ok(result),
+        Ok(Err(e)) => Err(e.to_string()),
+        Err(_) => Err(format!("查询超时 ({}秒)", timeout_secs)),
+    };
+    audit.record(sql, result.as_ref().map(|r| r.affected_rows).map_err(String::as_str));
+    result
+}
+
+fn export_step(
+    result: &QueryResult,
+    format: ExportFormat,
+    plugin: Option<&str>,
+    table: &str,
+    path: &Path,
+    plugins: &PluginRegistry,
+) -> Result<(), String> {
+    // 先查找插件，插件不存在时不创建文件
+    let exporter = match plugin {
+        Some(id) => Some(plugins.exporter(id).ok_or_else(|| format!("导出插件不存在: {}", id))?),
+        None => None,
+    };
+    let file = File::create(path).map_err(|e| format!("无法创建文件 {}: {}", path.display(), e))?;
+    let mut out = BufWriter::new(file);
+    if let Some(exporter) = exporter {
+        exporter.export(result, &mut out)?;
+        return out.flush().map_err(|e| e.to_string());
+    }
+    let table = if table.is_empty() { "exported" } else { table };
+    match format {
+        ExportFormat::Csv => write_csv(result, &mut out),
+        ExportFormat::Json => write_json(result, &mut out),
+        ExportFormat::Sql => write_sql(result, table, &mut out),
+    }
+}
+
+impl DbManagerApp {
+    /// 打开工作流面板
+    pub(super) fn open_workflows(&mut self) {
+        let mut connections: Vec<String> = self
+            .manager
+            .connections
+            .values()
+            .filter(|c| !c.config.is_scratchpad())
+            .map(|c| c.config.name.clone())
+            .collect();
+        connections.sort();
+        self.workflow_state.open(self.app_config.workflows.clone(), connections);
+    }
+
+    /// 处理工作流面板的操作
+    pub(super) fn handle_workflow_result(&mut self, result: ui::WorkflowResult) {
+        match result {
+            ui::WorkflowResult::None => {}
+            ui::WorkflowResult::Save(workflows) => {
+                self.app_config.workflows = workflows;
+                self.save_config();
+                self.notifications.success(t("notify.workflow_saved"));
+            }
+            ui::WorkflowResult::Run(workflow) => self.run_workflow(workflow),
+        }
+    }
+
+    /// 在后台运行工作流
+    fn run_workflow(&mut self, workflow: Workflow) {
+        if let Err(e) = workflow.validate() {
+            self.notifications.error(e);
+            return;
+        }
+        let connections: Vec<ConnectionConfig> = self
+            .manager
+            .connections
+            .values()
+            .map(|c| self.resolve_timeouts(c.config.clone()))
+            .collect();
+        let progress_id = self.progress.start(format!("工作流: {}", workflow.name), false);
+        let run = WorkflowRun::new(workflow);
+        self.workflow_state.run = Some(run.clone());
+
+        let tx = self.tx.clone();
+        let plugins = self.plugins.clone();
+        let audit_log = self.audit_log.clone();
+        self.runtime.spawn(async move {
+            execute_workflow(run, &connections, &plugins, audit_log, |run| {
+                if tx.send(Message::WorkflowProgress(progress_id, run.clone())).is_err() {
+                    tracing::warn!("无法发送工作流进度：接收端已关闭");
+                }
+            })
+            .await;
+        });
+    }
+
+    /// 处理工作流进度消息
+    pub(super) fn handle_workflow_progress(&mut self, ctx: &egui::Context, progress_id: u64, run: WorkflowRun) {
+        let total = run.statuses.len().max(1);
+        self.progress.update(progress_id, run.finished_count() as f32 / total as f32);
+        if run.is_finished() {
+            self.progress.finish(progress_id);
+            let failed = run.failed_count();
+            if failed == 0 {
+                self.notifications.success(tf("notify.workflow_done", &[&run.workflow.name]));
+            } else {
+                self.notifications.error(tf("notify.workflow_failed", &[&run.workflow.name, &failed.to_string()]));
+            }
+        }
+        self.workflow_state.run = Some(run);
+        ctx.request_repaint();
+    }
+}
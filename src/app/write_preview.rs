@@ -0,0 +1,87 @@
+//! UPDATE/DELETE 执行前的影响行预览
+//!
+//! 把写语句改写为条件相同的 SELECT 并在后台执行，在预览窗口中确认后才执行原语句。
+//! 批量修改按当前筛选条件生成 UPDATE 语句，同样经过预览确认。
+
+use crate::core::{t, tf, write_preview};
+use crate::database::{execute_query, DatabaseType};
+use crate::ui;
+
+use super::message::Message;
+use super::DbManagerApp;
+
+impl DbManagerApp {
+    /// 打开影响行预览窗口并执行预览查询
+    pub(super) fn open_write_preview(&mut self, sql: String) {
+        let Some(conn) = self.manager.get_active() else {
+            self.notifications.warning(t("common.need_connection"));
+            return;
+        };
+        let db_type = conn.config.db_type;
+        if matches!(db_type, DatabaseType::MongoDB | DatabaseType::Redis) {
+            self.notifications.warning(tf("notify.write_preview_unsupported", &[db_type.display_name()]));
+            return;
+        }
+        let Some(preview) = write_preview(&sql) else {
+            self.notifications.warning(t("notify.write_preview_single"));
+            return;
+        };
+
+        let config = self.resolve_timeouts(conn.config.clone());
+        let timeout_secs = config.query_timeout_secs();
+        let select_sql = preview.select_sql.clone();
+        let tx = self.tx.clone();
+        self.write_preview_state.open(sql, preview);
+
+        self.runtime.spawn(async move {
+            use tokio::time::{timeout, Duration};
+            let result = match timeout(Duration::from_secs(timeout_secs), execute_query(&config, &select_sql)).await {
+                Ok(result) => result.map_err(|e| e.to_string()),
+                Err(_) => Err(format!("预览查询超时 ({}秒)", timeout_secs)),
+            };
+            if tx.send(Message::WritePreviewLoaded(select_sql, result)).is_err() {
+                tracing::warn!("无法发送影响行预览结果：接收端已关闭");
+            }
+        });
+    }
+
+    /// 确认预览后执行原语句（语句来自编辑器时同时清空编辑器）
+    pub(super) fn execute_previewed_write(&mut self, sql: String) {
+        if self.sql.trim() == sql.trim() {
+            self.sql.clear();
+        }
+        self.execute(sql);
+    }
+
+    /// 打开批量修改窗口（修改当前筛选条件匹配的所有行）
+    pub(super) fn open_bulk_edit(&mut self) {
+        let Some(table) = self.selected_table.clone() else {
+            self.notifications.warning(t("common.need_table"));
+            return;
+        };
+        let Some(db_type) = self.manager.get_active().map(|c| c.config.db_type) else {
+            return;
+        };
+        if !db_type.uses_sql() {
+            self.notifications.warning(tf("notify.bulk_edit_unsupported", &[db_type.display_name()]));
+            return;
+        }
+        let Some(columns) = self.result.as_ref().map(|r| r.columns.clone()).filter(|c| !c.is_empty()) else {
+            return;
+        };
+        let where_clause = self.quote_table_name(&table).and_then(|quoted_table| {
+            Ok((quoted_table, ui::filters_to_where(&self.grid_state.filters, db_type)?))
+        });
+        match where_clause {
+            Ok((quoted_table, Some(clause))) => {
+                self.bulk_edit_state.open(quoted_table, columns, clause, db_type);
+            }
+            Ok((_, None)) => {
+                self.notifications.warning(t("notify.bulk_edit_needs_filter"));
+            }
+            Err(e) => {
+                self.notifications.error(tf("notify.filter_sql_failed", &[&e]));
+            }
+        }
+    }
+}
@@ -0,0 +1,313 @@
+//! 本地 API 服务
+//!
+//! 以只读 HTTP 接口共享应用中保存的查询，供本机的仪表盘或脚本读取结果：
+//! - `GET /queries`：列出保存的查询
+//! - `GET /queries/<名称>`：执行查询并返回 JSON，`.csv` 后缀或 `?format=csv` 返回 CSV
+//!
+//! 请求需要通过 `Authorization: Bearer <令牌>` 携带令牌（不接受查询参数，避免令牌留在日志和浏览历史中），
+//! 首次启动时自动生成随机令牌；
+//! `Host` 请求头只接受 127.0.0.1 和 localhost，防止网页通过 DNS 重绑定访问。
+//! 本模块只负责配置、请求解析和响应构造，监听和执行由应用层完成
+//! （需要启用 `api-server` 功能编译）。
+
+// 未启用 `api-server` 功能时请求处理部分不会被应用使用
+#![cfg_attr(not(feature = "api-server"), allow(dead_code))]
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use ring::rand::{SecureRandom, SystemRandom};
+use serde::{Deserialize, Serialize};
+
+use super::is_read_only_statement;
+use crate::database::split_statements;
+
+/// 默认监听端口
+pub const DEFAULT_API_PORT: u16 = 7878;
+
+/// 请求头的最大长度
+pub const MAX_REQUEST_HEAD: usize = 8 * 1024;
+
+/// 自动生成的令牌的随机字节数
+const TOKEN_BYTES: usize = 24;
+
+/// 允许的 `Host` 请求头（不含端口）
+const ALLOWED_HOSTS: &[&str] = &["127.0.0.1", "localhost"];
+
+/// 通过 API 共享的查询
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedQuery {
+    /// 名称，用作 URL 路径
+    pub name: String,
+    /// 已保存的连接名称
+    pub connection: String,
+    pub sql: String,
+}
+
+impl SavedQuery {
+    /// 校验查询是否可以通过 API 共享（名称可作为路径、只读）
+    pub fn validate(&self) -> Result<(), String> {
+        let name = self.name.trim();
+        if name.is_empty() {
+            return Err("查询名称不能为空".to_string());
+        }
+        if name.contains(['/', '?', '#']) {
+            return Err(format!("查询名称不能包含 / ? #: {}", name));
+        }
+        if self.connection.is_empty() {
+            return Err(format!("查询「{}」未选择连接", name));
+        }
+        let statements = split_statements(&self.sql);
+        if statements.is_empty() {
+            return Err(format!("查询「{}」SQL 为空", name));
+        }
+        if statements.len() > 1 {
+            return Err(format!("查询「{}」只能包含一条语句", name));
+        }
+        if !is_read_only_statement(&self.sql) {
+            return Err(format!("查询「{}」不是只读语句", name));
+        }
+        Ok(())
+    }
+}
+
+/// API 服务配置
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ApiServerConfig {
+    /// 监听端口（只监听 127.0.0.1）
+    pub port: u16,
+    /// 访问令牌，为空时启动服务会自动生成
+    pub token: String,
+    /// 启动应用时自动启动服务
+    pub auto_start: bool,
+    /// 共享的查询
+    pub queries: Vec<SavedQuery>,
+}
+
+impl Default for ApiServerConfig {
+    fn default() -> Self {
+        Self {
+            port: DEFAULT_API_PORT,
+            token: String::new(),
+            auto_start: false,
+            queries: Vec::new(),
+        }
+    }
+}
+
+impl ApiServerConfig {
+    /// 按名称查找查询
+    pub fn find(&self, name: &str) -> Option<&SavedQuery> {
+        self.queries.iter().find(|q| q.name == name)
+    }
+
+    /// 令牌为空时生成随机令牌，返回是否生成了新令牌
+    pub fn ensure_token(&mut self) -> Result<bool, String> {
+        if !self.token.trim().is_empty() {
+            return Ok(false);
+        }
+        self.token = generate_api_token()?;
+        Ok(true)
+    }
+
+    /// 校验所有查询，名称不能重复
+    pub fn validate(&self) -> Result<(), String> {
+        for (i, query) in self.queries.iter().enumerate() {
+            query.validate()?;
+            if self.queries[..i].iter().any(|q| q.name.trim() == query.name.trim()) {
+                return Err(format!("查询名称重复: {}", query.name.trim()));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 生成随机访问令牌
+pub fn generate_api_token() -> Result<String, String> {
+    let mut bytes = [0u8; TOKEN_BYTES];
+    SystemRandom::new().fill(&mut bytes).map_err(|_| "无法生成随机令牌")?;
+    Ok(URL_SAFE_NO_PAD.encode(bytes))
+}
+
+/// 结果格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiFormat {
+    Json,
+    Csv,
+}
+
+/// 请求对应的接口
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ApiRoute {
+    /// 列出保存的查询
+    List,
+    /// 执行查询
+    Query { name: String, format: ApiFormat },
+    /// 路径不存在
+    NotFound,
+    /// 只支持 GET
+    MethodNotAllowed,
+}
+
+/// 解析后的请求
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiRequest {
+    pub method: String,
+    /// 解码后的路径（不含查询字符串）
+    pub path: String,
+    /// 查询字符串参数
+    pub params: Vec<(String, String)>,
+    /// `Authorization: Bearer` 请求头中的令牌
+    pub bearer: Option<String>,
+    /// `Host` 请求头
+    pub host: Option<String>,
+}
+
+impl ApiRequest {
+    /// 解析请求行和请求头
+    pub fn parse(head: &str) -> Result<Self, String> {
+        let mut lines = head.lines();
+        let request_line = lines.next().ok_or("空请求")?;
+        let mut parts = request_line.split_whitespace();
+        let (Some(method), Some(target), Some(_version)) = (parts.next(), parts.next(), parts.next()) else {
+            return Err(format!("无效的请求行: {}", request_line));
+        };
+        let (path, query) = target.split_once('?').unwrap_or((target, ""));
+        let params = query
+            .split('&')
+            .filter(|p| !p.is_empty())
+            .map(|p| {
+                let (k, v) = p.split_once('=').unwrap_or((p, ""));
+                (percent_decode(k), percent_decode(v))
+            })
+            .collect();
+        let headers: Vec<(&str, &str)> = lines
+            .take_while(|l| !l.is_empty())
+            .filter_map(|l| l.split_once(':'))
+            .map(|(name, value)| (name.trim(), value.trim()))
+            .collect();
+        let header = |name: &str| headers.iter().find(|(n, _)| n.eq_ignore_ascii_case(name)).map(|(_, v)| *v);
+        let bearer = header("authorization").and_then(|v| v.strip_prefix("Bearer ").map(|t| t.trim().to_string()));
+        let host = header("host").map(str::to_string);
+
+        Ok(Self {
+            method: method.to_string(),
+            path: percent_decode(path),
+            params,
+            bearer,
+            host,
+        })
+    }
+
+    /// 查询字符串参数
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.params.iter().find(|(k, _)| k == name).map(|(_, v)| v.as_str())
+    }
+
+    /// `Authorization` 请求头中的令牌是否匹配（未设置令牌时拒绝所有请求）
+    pub fn authorized(&self, token: &str) -> bool {
+        !token.is_empty()
+            && self.bearer.as_deref().is_some_and(|bearer| constant_time_eq(bearer.as_bytes(), token.as_bytes()))
+    }
+
+    /// `Host` 请求头是否为本机地址（缺少时拒绝）
+    pub fn allowed_host(&self) -> bool {
+        let Some(host) = &self.host else {
+            return false;
+        };
+        let name = match host.rsplit_once(':') {
+            Some((name, port)) if port.chars().all(|c| c.is_ascii_digit()) => name,
+            _ => host.as_str(),
+        };
+        ALLOWED_HOSTS.iter().any(|allowed| name.eq_ignore_ascii_case(allowed))
+    }
+
+    /// 请求对应的接口
+    pub fn route(&self) -> ApiRoute {
+        if self.method != "GET" {
+            return ApiRoute::MethodNotAllowed;
+        }
+        let path = self.path.trim_end_matches('/');
+        if path.is_empty() || path == "/queries" {
+            return ApiRoute::List;
+        }
+        let Some(name) = path.strip_prefix("/queries/").filter(|n| !n.is_empty()) else {
+            return ApiRoute::NotFound;
+        };
+        let csv_param = self.param("format").is_some_and(|f| f.eq_ignore_ascii_case("csv"));
+        match (name.strip_suffix(".csv"), name.strip_suffix(".json")) {
+            (Some(name), _) => ApiRoute::Query {
+                name: name.to_string(),
+                format: ApiFormat::Csv,
+            },
+            (_, Some(name)) => ApiRoute::Query {
+                name: name.to_string(),
+                format: ApiFormat::Json,
+            },
+            _ => ApiRoute::Query {
+                name: name.to_string(),
+                format: if csv_param { ApiFormat::Csv } else { ApiFormat::Json },
+            },
+        }
+    }
+}
+
+/// 按常量时间比较，耗时不随第一个不同字节的位置变化
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    let diff = a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y));
+    a.len() == b.len() && std::hint::black_box(diff) == 0
+}
+
+/// 解码 URL 中的 `%XX` 和 `+`
+pub fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' if i + 2 < bytes.len() => {
+                match std::str::from_utf8(&bytes[i + 1..i + 3]).ok().and_then(|h| u8::from_str_radix(h, 16).ok()) {
+                    Some(b) => {
+                        out.push(b);
+                        i += 3;
+                        continue;
+                    }
+                    None => out.push(b'%'),
+                }
+            }
+            b'+' => out.push(b' '),
+            b => out.push(b),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+/// 构造 HTTP 响应
+pub fn http_response(status: u16, content_type: &str, body: &[u8]) -> Vec<u8> {
+    let reason = match status {
+        200 => "OK",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        403 => "Forbidden",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        504 => "Gateway Timeout",
+        _ => "Internal Server Error",
+    };
+    let mut response = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        status,
+        reason,
+        content_type,
+        body.len()
+    )
+    .into_bytes();
+    response.extend_from_slice(body);
+    response
+}
+
+/// 构造 JSON 错误响应
+pub fn http_error(status: u16, message: &str) -> Vec<u8> {
+    let body = serde_json::json!({ "error": message }).to_string();
+    http_response(status, "application/json; charset=utf-8", body.as_bytes())
+}
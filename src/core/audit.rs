@@ -0,0 +1,298 @@
+//! 审计日志
+//!
+//! 将执行过的写操作（INSERT/UPDATE/DELETE/DDL/权限变更）逐条追加到本地日志文件，
+//! 记录时间、连接、影响行数和发起的功能。日志每行一条 JSON 记录，只追加不修改。
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Local};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+
+use crate::database::QueryResult;
+
+/// 写操作的起始关键字（调用存储过程、导入导出、加锁和修改会话设置也可能修改数据）
+const WRITE_KEYWORDS: &[&str] = &[
+    "insert", "update", "delete", "merge", "replace", "upsert", "create", "alter", "drop", "truncate", "rename",
+    "grant", "revoke", "comment", "call", "exec", "execute", "do", "copy", "load", "lock", "set",
+];
+
+/// 同一进程内的写入互斥，避免并发追加的记录交错
+static APPEND_LOCK: Mutex<()> = Mutex::new(());
+
+/// 发起写操作的功能
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditSource {
+    /// SQL 编辑器
+    Editor,
+    /// 表格编辑保存
+    GridSave,
+    /// 数据导入
+    Import,
+    /// 表操作（重命名/删除/清空/复制）
+    TableAction,
+    /// 触发器/存储过程/序列 DDL
+    Ddl,
+    /// 测试数据生成
+    DataGen,
+    /// 权限管理
+    Privileges,
+    /// 定时查询
+    Scheduler,
+    /// 工作流
+    Workflow,
+    /// 无界面命令行
+    Cli,
+    /// 表维护（VACUUM/ANALYZE/OPTIMIZE/REINDEX）
+    Maintenance,
+    /// 会话监控中终止会话
+    Session,
+}
+
+impl AuditSource {
+    /// 所有来源
+    pub const ALL: [AuditSource; 12] = [
+        AuditSource::Editor,
+        AuditSource::GridSave,
+        AuditSource::Import,
+        AuditSource::TableAction,
+        AuditSource::Ddl,
+        AuditSource::DataGen,
+        AuditSource::Privileges,
+        AuditSource::Scheduler,
+        AuditSource::Workflow,
+        AuditSource::Cli,
+        AuditSource::Maintenance,
+        AuditSource::Session,
+    ];
+
+    /// 显示名称
+    pub fn label(&self) -> &'static str {
+        match self {
+            AuditSource::Editor => "SQL 编辑器",
+            AuditSource::GridSave => "表格保存",
+            AuditSource::Import => "数据导入",
+            AuditSource::TableAction => "表操作",
+            AuditSource::Ddl => "DDL",
+            AuditSource::DataGen => "测试数据",
+            AuditSource::Privileges => "权限管理",
+            AuditSource::Scheduler => "定时查询",
+            AuditSource::Workflow => "工作流",
+            AuditSource::Cli => "命令行",
+            AuditSource::Maintenance => "表维护",
+            AuditSource::Session => "会话管理",
+        }
+    }
+}
+
+/// 一条审计记录
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    /// 执行时间
+    pub timestamp: DateTime<Local>,
+    /// 连接名
+    pub connection: String,
+    /// 数据库类型
+    pub database_type: String,
+    /// 发起的功能
+    pub source: AuditSource,
+    /// 执行的语句
+    pub sql: String,
+    /// 是否执行成功
+    pub success: bool,
+    /// 影响行数（仅成功时）
+    #[serde(default)]
+    pub affected_rows: Option<u64>,
+    /// 错误信息（仅失败时）
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+/// 判断语句是否为需要审计的写操作（跳过开头的注释；WITH 语句中包含写操作时也算）
+pub fn is_write_statement(sql: &str) -> bool {
+    let text = strip_leading_comments(sql).trim_start_matches(|c: char| c == '(' || c.is_whitespace());
+    let lower = text.to_lowercase();
+    let mut words = lower.split(|c: char| !c.is_alphanumeric() && c != '_').filter(|w| !w.is_empty());
+    match words.next() {
+        Some("with") => words.any(|w| matches!(w, "insert" | "update" | "delete" | "merge")),
+        Some(first) => WRITE_KEYWORDS.contains(&first),
+        None => false,
+    }
+}
+
+/// 判断语句是否为只读查询（按白名单匹配起始关键字，不在白名单中的语句一律视为可能修改数据）
+///
+/// `SELECT ... INTO` 会建表或写入变量，`EXPLAIN ANALYZE` 会真正执行后面的写语句，都不算只读；
+/// 语句中调用的函数仍可能修改数据，需要配合只读事务执行
+pub fn is_read_only_statement(sql: &str) -> bool {
+    let text = strip_leading_comments(sql).trim_start_matches(|c: char| c == '(' || c.is_whitespace());
+    let lower = text.to_lowercase();
+    let mut words = lower.split(|c: char| !c.is_alphanumeric() && c != '_').filter(|w| !w.is_empty());
+    match words.next() {
+        Some("select") => !words.any(|w| w == "into"),
+        Some("with") => !words.any(|w| matches!(w, "insert" | "update" | "delete" | "merge" | "into")),
+        Some("explain") => {
+            let rest: Vec<&str> = words.collect();
+            !rest.iter().any(|w| matches!(*w, "analyze" | "analyse"))
+                || !rest.iter().any(|w| WRITE_KEYWORDS.contains(w) || *w == "into")
+        }
+        Some("show" | "describe" | "desc" | "values") => true,
+        _ => false,
+    }
+}
+
+/// 去掉开头的空白和注释
+pub fn strip_leading_comments(sql: &str) -> &str {
+    let mut rest = sql.trim_start();
+    loop {
+        if let Some(line) = rest.strip_prefix("--") {
+            rest = line.split_once('\n').map_or("", |(_, r)| r).trim_start();
+        } else if let Some(block) = rest.strip_prefix("/*") {
+            rest = block.split_once("*/").map_or("", |(_, r)| r).trim_start();
+        } else {
+            return rest;
+        }
+    }
+}
+
+/// 审计日志文件
+#[derive(Debug, Clone)]
+pub struct AuditLog {
+    path: PathBuf,
+}
+
+impl AuditLog {
+    /// 使用指定的日志文件
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// 默认日志文件（配置目录下的 `audit.log`）
+    pub fn open_default() -> Option<Self> {
+        dirs::config_dir().map(|p| Self::new(p.join("gridix").join("audit.log")))
+    }
+
+    /// 日志文件路径
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// 追加一条记录
+    pub fn append(&self, entry: &AuditEntry) -> Result<(), String> {
+        let mut line = serde_json::to_string(entry).map_err(|e| format!("序列化失败: {}", e))?;
+        line.push('\n');
+
+        let _guard = APPEND_LOCK.lock();
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).map_err(|e| format!("创建目录失败: {}", e))?;
+        }
+
+        let mut options = OpenOptions::new();
+        options.create(true).append(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        let mut file = options.open(&self.path).map_err(|e| format!("打开审计日志失败: {}", e))?;
+        file.write_all(line.as_bytes()).map_err(|e| format!("写入审计日志失败: {}", e))
+    }
+
+    /// 读取所有记录（按写入顺序）；无法解析的行会被跳过
+    pub fn read(&self) -> Result<Vec<AuditEntry>, String> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+        let content = fs::read_to_string(&self.path).map_err(|e| format!("读取审计日志失败: {}", e))?;
+        let mut skipped = 0;
+        let entries = content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .filter_map(|line| serde_json::from_str(line).map_err(|_| skipped += 1).ok())
+            .collect();
+        if skipped > 0 {
+            tracing::warn!(skipped, "审计日志中有无法解析的记录");
+        }
+        Ok(entries)
+    }
+}
+
+/// 为某个连接和功能记录写操作
+#[derive(Debug, Clone)]
+pub struct AuditRecorder {
+    log: Option<AuditLog>,
+    connection: String,
+    database_type: String,
+    source: AuditSource,
+}
+
+impl AuditRecorder {
+    /// 创建记录器；`log` 为空时不记录
+    pub fn new(log: Option<AuditLog>, connection: String, database_type: String, source: AuditSource) -> Self {
+        Self {
+            log,
+            connection,
+            database_type,
+            source,
+        }
+    }
+
+    /// 记录一条语句的执行结果（非写操作会被忽略）
+    pub fn record(&self, sql: &str, outcome: Result<u64, &str>) {
+        if is_write_statement(sql) {
+            self.record_admin(sql, outcome);
+        }
+    }
+
+    /// 记录一条管理操作的执行结果，不按语句类型过滤
+    ///
+    /// 用于终止会话（PostgreSQL 通过 `SELECT pg_terminate_backend` 实现）和表维护等本身就会改变服务器状态的操作
+    pub fn record_admin(&self, sql: &str, outcome: Result<u64, &str>) {
+        let Some(log) = &self.log else {
+            return;
+        };
+        let entry = AuditEntry {
+            timestamp: Local::now(),
+            connection: self.connection.clone(),
+            database_type: self.database_type.clone(),
+            source: self.source,
+            sql: sql.trim().to_string(),
+            success: outcome.is_ok(),
+            affected_rows: outcome.ok(),
+            error: outcome.err().map(str::to_string),
+        };
+        if let Err(e) = log.append(&entry) {
+            tracing::warn!(error = %e, "写入审计日志失败");
+        }
+    }
+}
+
+/// 将审计记录整理为表格，用于导出
+pub fn audit_report(entries: &[&AuditEntry]) -> QueryResult {
+    let columns = ["时间", "连接", "数据库类型", "来源", "状态", "影响行数", "错误", "SQL"]
+        .map(String::from)
+        .to_vec();
+    let rows = entries
+        .iter()
+        .map(|e| {
+            vec![
+                e.timestamp.format("%Y-%m-%d %H:%M:%S%.3f %:z").to_string(),
+                e.connection.clone(),
+                e.database_type.clone(),
+                e.source.label().to_string(),
+                if e.success { "成功" } else { "失败" }.to_string(),
+                e.affected_rows.map(|n| n.to_string()).unwrap_or_default(),
+                e.error.clone().unwrap_or_default(),
+                e.sql.clone(),
+            ]
+        })
+        .collect();
+    QueryResult {
+        columns,
+        rows,
+        ..Default::default()
+    }
+}
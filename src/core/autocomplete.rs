@@ -3,6 +3,8 @@
 #![allow(dead_code)] // 预留 API
 
 use super::constants::autocomplete as consts;
+use crate::database::{ColumnInfo, DatabaseType};
+use std::collections::HashMap;
 
 /// SQL 关键字列表
 const SQL_KEYWORDS: &[&str] = &[
@@ -182,6 +184,110 @@ const SQL_FUNCTIONS: &[&str] = &[
     "JSON_ARRAY",
 ];
 
+/// ClickHouse 方言关键字
+const CLICKHOUSE_KEYWORDS: &[&str] = &[
+    "PREWHERE",
+    "FINAL",
+    "SAMPLE",
+    "ARRAY JOIN",
+    "GLOBAL",
+    "ANY",
+    "ASOF",
+    "LIMIT BY",
+    "SETTINGS",
+    "FORMAT",
+    "ENGINE",
+    "PARTITION BY",
+    "PRIMARY KEY",
+    "TTL",
+    "CODEC",
+    "MATERIALIZED",
+    "ALIAS",
+    "ON CLUSTER",
+    "OPTIMIZE",
+    "SYSTEM",
+    "DETACH",
+    "ATTACH",
+    "EXCHANGE",
+    "MergeTree",
+    "ReplacingMergeTree",
+    "SummingMergeTree",
+    "AggregatingMergeTree",
+    "CollapsingMergeTree",
+    "ReplicatedMergeTree",
+    "Distributed",
+    "Nullable",
+    "LowCardinality",
+];
+
+/// ClickHouse 常用函数
+const CLICKHOUSE_FUNCTIONS: &[&str] = &[
+    "toDate",
+    "toDateTime",
+    "toDateTime64",
+    "toStartOfDay",
+    "toStartOfHour",
+    "toStartOfMonth",
+    "toStartOfWeek",
+    "toYYYYMM",
+    "toYYYYMMDD",
+    "toString",
+    "toInt32",
+    "toInt64",
+    "toUInt32",
+    "toUInt64",
+    "toFloat64",
+    "toDecimal64",
+    "formatDateTime",
+    "parseDateTimeBestEffort",
+    "now64",
+    "today",
+    "yesterday",
+    "countIf",
+    "sumIf",
+    "avgIf",
+    "uniq",
+    "uniqExact",
+    "uniqCombined",
+    "quantile",
+    "quantiles",
+    "median",
+    "groupArray",
+    "groupUniqArray",
+    "argMax",
+    "argMin",
+    "any",
+    "anyLast",
+    "topK",
+    "arrayJoin",
+    "arrayMap",
+    "arrayFilter",
+    "arrayExists",
+    "arraySort",
+    "arrayDistinct",
+    "has",
+    "hasAny",
+    "hasAll",
+    "indexOf",
+    "splitByChar",
+    "splitByString",
+    "JSONExtract",
+    "JSONExtractString",
+    "JSONExtractInt",
+    "JSONExtractFloat",
+    "visitParamExtractString",
+    "match",
+    "extract",
+    "replaceRegexpAll",
+    "multiIf",
+    "assumeNotNull",
+    "cityHash64",
+    "sipHash64",
+    "generateUUIDv4",
+    "runningDifference",
+    "neighbor",
+];
+
 /// 自动补全建议
 #[derive(Debug, Clone)]
 pub struct CompletionItem {
@@ -220,7 +326,15 @@ pub struct AutoComplete {
     /// 当前数据库的表列表
     tables: Vec<String>,
     /// 表的列信息 (table_name -> columns)
-    columns: std::collections::HashMap<String, Vec<String>>,
+    columns: HashMap<String, Vec<String>>,
+    /// 从表结构读取的列类型 (table_name -> column_name -> data_type)
+    column_types: HashMap<String, HashMap<String, String>>,
+    /// 当前数据库方言的额外关键字
+    dialect_keywords: &'static [&'static str],
+    /// 当前数据库方言的额外函数
+    dialect_functions: &'static [&'static str],
+    /// 方言说明（显示在补全项详情中）
+    dialect_name: &'static str,
 }
 
 impl Default for AutoComplete {
@@ -233,10 +347,22 @@ impl AutoComplete {
     pub fn new() -> Self {
         Self {
             tables: Vec::new(),
-            columns: std::collections::HashMap::new(),
+            columns: HashMap::new(),
+            column_types: HashMap::new(),
+            dialect_keywords: &[],
+            dialect_functions: &[],
+            dialect_name: "",
         }
     }
 
+    /// 设置数据库方言（切换连接时调用，决定额外的关键字和函数补全）
+    pub fn set_dialect(&mut self, db_type: DatabaseType) {
+        (self.dialect_keywords, self.dialect_functions, self.dialect_name) = match db_type {
+            DatabaseType::ClickHouse => (CLICKHOUSE_KEYWORDS, CLICKHOUSE_FUNCTIONS, "ClickHouse"),
+            _ => (&[][..], &[][..], ""),
+        };
+    }
+
     /// 更新表列表（限制最大数量）
     pub fn set_tables(&mut self, tables: Vec<String>) {
         self.tables = if tables.len() > consts::MAX_CACHED_TABLES {
@@ -256,10 +382,49 @@ impl AutoComplete {
         self.columns.insert(table, limited_columns);
     }
 
+    /// 添加从表结构读取的列信息（同时记录列类型）
+    pub fn set_column_infos(&mut self, table: String, columns: Vec<ColumnInfo>) {
+        let types = columns.iter().map(|c| (c.name.clone(), c.data_type.clone())).collect();
+        self.column_types.insert(table.clone(), types);
+        self.set_columns(table, columns.into_iter().map(|c| c.name).collect());
+    }
+
+    /// 表的列类型（只有从表结构读取过的表才有）
+    pub fn column_types(&self, table: &str) -> Option<&HashMap<String, String>> {
+        self.column_types.get(table)
+    }
+
+    /// 是否已缓存该表的列信息
+    pub fn has_columns(&self, table: &str) -> bool {
+        self.columns.contains_key(table)
+    }
+
+    /// 已缓存的表名
+    pub fn tables(&self) -> &[String] {
+        &self.tables
+    }
+
+    /// 已缓存的某个表的列名
+    pub fn columns(&self, table: &str) -> Option<&[String]> {
+        self.columns.get(table).map(Vec::as_slice)
+    }
+
+    /// 所有已缓存的列名
+    pub fn all_columns(&self) -> impl Iterator<Item = &str> {
+        self.columns.values().flatten().map(String::as_str)
+    }
+
+    /// 丢弃已不存在的表的列信息
+    pub fn retain_columns(&mut self, tables: &[String]) {
+        self.columns.retain(|table, _| tables.contains(table));
+        self.column_types.retain(|table, _| tables.contains(table));
+    }
+
     /// 清空所有信息
     pub fn clear(&mut self) {
         self.tables.clear();
         self.columns.clear();
+        self.column_types.clear();
     }
 
     /// 获取补全建议
@@ -305,6 +470,28 @@ impl AutoComplete {
             }
         }
 
+        // 方言关键字和函数补全（函数名区分大小写，按原样插入）
+        for keyword in self.dialect_keywords {
+            if keyword.to_uppercase().starts_with(&prefix) {
+                completions.push(CompletionItem {
+                    label: keyword.to_string(),
+                    insert_text: keyword.to_string(),
+                    kind: CompletionKind::Keyword,
+                    detail: Some(format!("{} 关键字", self.dialect_name)),
+                });
+            }
+        }
+        for func in self.dialect_functions {
+            if func.to_uppercase().starts_with(&prefix) {
+                completions.push(CompletionItem {
+                    label: format!("{}()", func),
+                    insert_text: format!("{}(", func),
+                    kind: CompletionKind::Function,
+                    detail: Some(format!("{} 函数", self.dialect_name)),
+                });
+            }
+        }
+
         // 表名补全
         for table in &self.tables {
             if table.to_uppercase().starts_with(&prefix) {
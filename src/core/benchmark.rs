@@ -0,0 +1,56 @@
+//! 查询基准测试统计
+//!
+//! 同一查询重复执行多次后，根据每次的耗时计算最小、平均、P95、最大耗时和每秒行数。
+
+/// 一次执行的耗时和返回行数
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BenchmarkSample {
+    /// 耗时（毫秒）
+    pub elapsed_ms: f64,
+    /// 返回的行数
+    pub rows: usize,
+}
+
+/// 基准测试统计
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BenchmarkStats {
+    /// 执行次数
+    pub runs: usize,
+    /// 最短耗时（毫秒）
+    pub min_ms: f64,
+    /// 平均耗时（毫秒）
+    pub avg_ms: f64,
+    /// P95 耗时（毫秒）
+    pub p95_ms: f64,
+    /// 最长耗时（毫秒）
+    pub max_ms: f64,
+    /// 每秒返回的行数（总耗时为 0 时为 `None`）
+    pub rows_per_sec: Option<f64>,
+}
+
+impl BenchmarkStats {
+    /// 计算统计，没有样本时返回 `None`
+    pub fn from_samples(samples: &[BenchmarkSample]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut times: Vec<f64> = samples.iter().map(|s| s.elapsed_ms).collect();
+        times.sort_by(f64::total_cmp);
+        let total_ms: f64 = times.iter().sum();
+        let total_rows: usize = samples.iter().map(|s| s.rows).sum();
+        Some(Self {
+            runs: times.len(),
+            min_ms: times[0],
+            avg_ms: total_ms / times.len() as f64,
+            p95_ms: percentile(&times, 0.95),
+            max_ms: times[times.len() - 1],
+            rows_per_sec: (total_ms > 0.0).then(|| total_rows as f64 / (total_ms / 1000.0)),
+        })
+    }
+}
+
+/// 已排序数据的百分位数（最近秩法）
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = (p * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
@@ -0,0 +1,87 @@
+//! 二进制（BLOB）单元格
+//!
+//! 查询结果的单元格都是文本，二进制值按 PostgreSQL bytea 的十六进制输出格式
+//! 编码为 `\x` 加小写十六进制，表格中显示为 `<BLOB n bytes>`。
+//! 编码后的内容可以无损还原，用于十六进制预览、保存到文件和生成字面量。
+
+use crate::database::DatabaseType;
+use std::fmt::Write;
+
+/// 编码后二进制值的前缀
+const BLOB_PREFIX: &str = "\\x";
+
+/// 每行显示的字节数
+const HEX_DUMP_WIDTH: usize = 16;
+
+/// 将二进制值编码为单元格文本
+pub fn encode_blob(bytes: &[u8]) -> String {
+    format!("{}{}", BLOB_PREFIX, hex(bytes))
+}
+
+/// 单元格是否为编码后的二进制值
+pub fn is_blob(cell: &str) -> bool {
+    cell.strip_prefix(BLOB_PREFIX)
+        .is_some_and(|hex| hex.len().is_multiple_of(2) && hex.bytes().all(|b| b.is_ascii_hexdigit()))
+}
+
+/// 二进制值的字节数（不是二进制值时为 `None`）
+pub fn blob_len(cell: &str) -> Option<usize> {
+    is_blob(cell).then(|| (cell.len() - BLOB_PREFIX.len()) / 2)
+}
+
+/// 还原编码后的二进制值
+pub fn decode_blob(cell: &str) -> Option<Vec<u8>> {
+    let hex = cell.strip_prefix(BLOB_PREFIX).filter(|_| is_blob(cell))?;
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+        .collect()
+}
+
+/// 表格中显示的占位文本
+pub fn blob_label(len: usize) -> String {
+    format!("<BLOB {} bytes>", len)
+}
+
+/// 十六进制/ASCII 对照输出（偏移量、16 个字节、可打印字符）
+pub fn hex_dump(bytes: &[u8]) -> String {
+    bytes
+        .chunks(HEX_DUMP_WIDTH)
+        .enumerate()
+        .map(|(line, chunk)| {
+            let hex: Vec<String> = chunk.iter().map(|b| format!("{:02x}", b)).collect();
+            let ascii: String = chunk
+                .iter()
+                .map(|&b| if b.is_ascii_graphic() || b == b' ' { b as char } else { '.' })
+                .collect();
+            format!(
+                "{:08x}  {:<width$}  |{}|",
+                line * HEX_DUMP_WIDTH,
+                hex.join(" "),
+                ascii,
+                width = HEX_DUMP_WIDTH * 3 - 1
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// 各数据库的二进制字面量
+pub fn blob_literal(bytes: &[u8], db_type: DatabaseType) -> String {
+    let hex = hex(bytes);
+    match db_type {
+        DatabaseType::PostgreSQL => format!("'\\x{}'::bytea", hex),
+        DatabaseType::DuckDB => format!("from_hex('{}')", hex),
+        DatabaseType::Oracle => format!("HEXTORAW('{}')", hex),
+        DatabaseType::ClickHouse => format!("unhex('{}')", hex),
+        _ => format!("X'{}'", hex),
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    let mut text = String::with_capacity(bytes.len() * 2);
+    for b in bytes {
+        let _ = write!(text, "{:02x}", b);
+    }
+    text
+}
@@ -0,0 +1,204 @@
+//! 命令行参数解析
+//!
+//! 不带参数时启动图形界面；指定 `--connection` 和 `--query` 时以无界面模式
+//! 使用已保存的连接执行查询并导出结果，指定 `--workflow` 时运行已保存的工作流，
+//! 便于脚本和定时任务调用。`--config` / `--profile` 选择使用的配置文件，对所有模式有效。
+
+use std::path::PathBuf;
+
+use super::{ConfigLocation, ExportFormat, PluginRegistry};
+
+/// 命令行用法说明
+pub const CLI_USAGE: &str = "\
+用法:
+  gridix                                   启动图形界面
+  gridix -c <连接> -q <SQL> [选项]          执行查询并输出结果后退出
+  gridix --workflow <名称>                  运行已保存的工作流后退出
+
+选项:
+  -c, --connection <名称>    已保存的连接名称
+  -q, --query <SQL>          要执行的 SQL
+      --query-file <路径>    从文件读取要执行的 SQL
+  -d, --database <名称>      覆盖连接的默认数据库
+  -f, --format <格式>        输出格式: csv | json | sql | 插件格式如 markdown（默认按输出文件扩展名，否则 csv）
+  -o, --output <路径>        输出文件（默认写到标准输出）
+  -t, --table <名称>         SQL 格式 INSERT 语句使用的表名（默认 exported）
+  -w, --workflow <名称>      运行已保存的工作流
+      --config <路径>        使用指定的配置文件
+      --profile <名称>       使用命名配置（配置目录下的 profiles/<名称>.toml），如 work、personal
+  -h, --help                 显示帮助
+  -V, --version              显示版本";
+
+/// 无界面执行的参数
+#[derive(Debug, Clone, PartialEq)]
+pub struct CliArgs {
+    /// 已保存的连接名称
+    pub connection: String,
+    /// 要执行的 SQL
+    pub query: String,
+    /// 覆盖连接的默认数据库
+    pub database: Option<String>,
+    /// 输出格式
+    pub format: ExportFormat,
+    /// 插件导出格式 id（设置时代替 `format`）
+    pub plugin_format: Option<String>,
+    /// 输出文件，`None` 表示标准输出
+    pub output: Option<PathBuf>,
+    /// SQL 格式使用的表名
+    pub table: String,
+}
+
+/// 解析后的命令
+#[derive(Debug, Clone, PartialEq)]
+pub enum CliCommand {
+    /// 启动图形界面
+    Gui,
+    /// 无界面执行查询
+    Run(CliArgs),
+    /// 运行已保存的工作流
+    Workflow(String),
+    /// 显示帮助
+    Help,
+    /// 显示版本
+    Version,
+}
+
+/// 取出选择配置文件的参数（`--config`、`--profile`），返回配置文件位置和其余参数
+pub fn split_config_args<I>(args: I) -> Result<(ConfigLocation, Vec<String>), String>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut args = args.into_iter();
+    let mut location = ConfigLocation::Default;
+    let mut rest = Vec::new();
+
+    while let Some(arg) = args.next() {
+        let (flag, inline) = match arg.split_once('=') {
+            Some((flag, value)) if matches!(flag, "--config" | "--profile") => {
+                (flag.to_string(), Some(value.to_string()))
+            }
+            _ => (arg, None),
+        };
+        if !matches!(flag.as_str(), "--config" | "--profile") {
+            rest.push(flag);
+            continue;
+        }
+        if location != ConfigLocation::Default {
+            return Err("--config 和 --profile 只能指定一个".to_string());
+        }
+        let value = inline
+            .or_else(|| args.next())
+            .ok_or_else(|| format!("参数 {} 缺少值", flag))?;
+        location = if flag == "--config" {
+            ConfigLocation::File(PathBuf::from(value))
+        } else if ConfigLocation::is_valid_profile_name(&value) {
+            ConfigLocation::Profile(value)
+        } else {
+            return Err(format!("无效的配置名称: {}（只能包含字母、数字、- 和 _）", value));
+        };
+    }
+    Ok((location, rest))
+}
+
+/// 解析命令行参数（不含程序名）
+///
+/// `--query-file` 在解析时读取文件内容。
+pub fn parse_cli_args<I>(args: I) -> Result<CliCommand, String>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut args = args.into_iter();
+    let mut connection = None;
+    let mut query = None;
+    let mut database = None;
+    let mut format = None;
+    let mut plugin_format = None;
+    let plugins = PluginRegistry::new();
+    let mut output: Option<PathBuf> = None;
+    let mut table = None;
+    let mut workflow = None;
+    let mut any = false;
+
+    while let Some(arg) = args.next() {
+        any = true;
+        // 支持 --name=value 形式
+        let (flag, inline) = match arg.split_once('=') {
+            Some((flag, value)) if flag.starts_with("--") => (flag.to_string(), Some(value.to_string())),
+            _ => (arg, None),
+        };
+        let mut value = || {
+            inline
+                .clone()
+                .or_else(|| args.next())
+                .ok_or_else(|| format!("参数 {} 缺少值", flag))
+        };
+
+        match flag.as_str() {
+            "-h" | "--help" => return Ok(CliCommand::Help),
+            "-V" | "--version" => return Ok(CliCommand::Version),
+            "-c" | "--connection" => connection = Some(value()?),
+            "-q" | "--query" => query = Some(value()?),
+            "--query-file" => {
+                let path = value()?;
+                let sql = std::fs::read_to_string(&path)
+                    .map_err(|e| format!("无法读取 SQL 文件 {}: {}", path, e))?;
+                query = Some(sql);
+            }
+            "-d" | "--database" => database = Some(value()?),
+            "-f" | "--format" => {
+                let name = value()?;
+                match ExportFormat::from_name(&name) {
+                    Some(f) => format = Some(f),
+                    None => {
+                        let exporter = plugins.exporter(&name).ok_or_else(|| format!("不支持的输出格式: {}", name))?;
+                        plugin_format = Some(exporter.id().to_string());
+                    }
+                }
+            }
+            "-o" | "--output" => output = Some(PathBuf::from(value()?)),
+            "-t" | "--table" => table = Some(value()?),
+            "-w" | "--workflow" => workflow = Some(value()?),
+            other => return Err(format!("未知参数: {}", other)),
+        }
+    }
+
+    if !any {
+        return Ok(CliCommand::Gui);
+    }
+    if let Some(name) = workflow {
+        if connection.is_some() || query.is_some() {
+            return Err("--workflow 不能与 --connection / --query 同时使用".to_string());
+        }
+        return Ok(CliCommand::Workflow(name));
+    }
+
+    let connection = connection.ok_or("缺少 --connection")?;
+    let query = query
+        .filter(|q| !q.trim().is_empty())
+        .ok_or("缺少 --query 或 --query-file")?;
+    let extension = output.as_ref().and_then(|p| p.extension()).and_then(|e| e.to_str());
+    if format.is_none()
+        && plugin_format.is_none()
+        && let Some(ext) = extension
+        && ExportFormat::from_name(ext).is_none()
+    {
+        plugin_format = plugins
+            .exporters()
+            .iter()
+            .find(|e| e.extension().eq_ignore_ascii_case(ext))
+            .map(|e| e.id().to_string());
+    }
+    let format = format
+        .or_else(|| extension.and_then(ExportFormat::from_name))
+        .unwrap_or(ExportFormat::Csv);
+
+    Ok(CliCommand::Run(CliArgs {
+        connection,
+        query,
+        database,
+        format,
+        plugin_format,
+        output,
+        table: table.unwrap_or_else(|| "exported".to_string()),
+    }))
+}
@@ -0,0 +1,124 @@
+//! 列布局
+//!
+//! 按连接、数据库和表保存手动调整过的列宽和列的显示顺序（随配置持久化），
+//! 再次打开同一张表时恢复；没有保存的列按内容自动计算宽度。
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+/// 一张表的列宽
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TableColumnWidths {
+    connection: String,
+    #[serde(default)]
+    database: Option<String>,
+    table: String,
+    #[serde(default)]
+    widths: BTreeMap<String, f32>,
+}
+
+impl TableColumnWidths {
+    fn is(&self, connection: &str, database: Option<&str>, table: &str) -> bool {
+        self.connection == connection && self.database.as_deref() == database && self.table == table
+    }
+}
+
+/// 按表保存的列宽
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ColumnWidths {
+    #[serde(default)]
+    tables: Vec<TableColumnWidths>,
+}
+
+impl ColumnWidths {
+    /// 某张表各列的宽度
+    pub fn for_table(&self, connection: &str, database: Option<&str>, table: &str) -> HashMap<String, f32> {
+        self.tables
+            .iter()
+            .find(|t| t.is(connection, database, table))
+            .map(|t| t.widths.iter().map(|(k, v)| (k.clone(), *v)).collect())
+            .unwrap_or_default()
+    }
+
+    /// 替换某张表各列的宽度（为空时删除该表的记录）
+    pub fn set_table(&mut self, connection: &str, database: Option<&str>, table: &str, widths: &HashMap<String, f32>) {
+        self.remove_table(connection, database, table);
+        if !widths.is_empty() {
+            self.tables.push(TableColumnWidths {
+                connection: connection.to_string(),
+                database: database.map(str::to_string),
+                table: table.to_string(),
+                // 只保存整数像素，避免配置文件中出现很长的小数
+                widths: widths.iter().map(|(k, v)| (k.clone(), v.round())).collect(),
+            });
+        }
+    }
+
+    /// 删除某张表的列宽（表已不存在时）
+    pub fn remove_table(&mut self, connection: &str, database: Option<&str>, table: &str) {
+        self.tables.retain(|t| !t.is(connection, database, table));
+    }
+
+    /// 删除某个连接的所有列宽
+    pub fn remove_connection(&mut self, connection: &str) {
+        self.tables.retain(|t| t.connection != connection);
+    }
+}
+
+/// 一张表的列顺序
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TableColumnOrder {
+    connection: String,
+    #[serde(default)]
+    database: Option<String>,
+    table: String,
+    #[serde(default)]
+    columns: Vec<String>,
+}
+
+impl TableColumnOrder {
+    fn is(&self, connection: &str, database: Option<&str>, table: &str) -> bool {
+        self.connection == connection && self.database.as_deref() == database && self.table == table
+    }
+}
+
+/// 按表保存的列显示顺序（只影响显示，不修改表结构）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ColumnOrders {
+    #[serde(default)]
+    tables: Vec<TableColumnOrder>,
+}
+
+impl ColumnOrders {
+    /// 某张表的列顺序（列名，没有调整过时为空）
+    pub fn for_table(&self, connection: &str, database: Option<&str>, table: &str) -> Vec<String> {
+        self.tables
+            .iter()
+            .find(|t| t.is(connection, database, table))
+            .map(|t| t.columns.clone())
+            .unwrap_or_default()
+    }
+
+    /// 替换某张表的列顺序（为空时删除该表的记录）
+    pub fn set_table(&mut self, connection: &str, database: Option<&str>, table: &str, columns: &[String]) {
+        self.remove_table(connection, database, table);
+        if !columns.is_empty() {
+            self.tables.push(TableColumnOrder {
+                connection: connection.to_string(),
+                database: database.map(str::to_string),
+                table: table.to_string(),
+                columns: columns.to_vec(),
+            });
+        }
+    }
+
+    /// 删除某张表的列顺序（表已不存在时）
+    pub fn remove_table(&mut self, connection: &str, database: Option<&str>, table: &str) {
+        self.tables.retain(|t| !t.is(connection, database, table));
+    }
+
+    /// 删除某个连接的所有列顺序
+    pub fn remove_connection(&mut self, connection: &str) {
+        self.tables.retain(|t| t.connection != connection);
+    }
+}
@@ -1,10 +1,75 @@
-use super::history::QueryHistory;
-use super::theme::ThemePreset;
+use super::api::ApiServerConfig;
+use super::column_layout::{ColumnOrders, ColumnWidths};
+use super::constants;
+use super::fonts::FontSettings;
+use super::i18n::Language;
+use super::keybindings::KeyBindings;
+use super::masking::MaskingRules;
+use super::filter_preset::FilterPresets;
+use super::recent::RecentObjects;
+use super::row_bookmark::RowBookmarks;
+use super::theme::{CustomTheme, ThemePreset};
+use super::trash::Trash;
+use super::vault::{MasterKey, Vault, MASTER_PASSWORD_ENV};
+use super::value_format::{ColumnFormats, NumberFormat, TimestampDisplay};
+use super::workflow::Workflow;
 use crate::database::ConnectionConfig;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::time::SystemTime;
+
+/// 检查配置文件外部修改的间隔（秒）
+pub const CONFIG_CHECK_INTERVAL_SECS: u64 = 2;
+
+/// 本次运行使用的配置文件位置
+static CONFIG_LOCATION: OnceLock<ConfigLocation> = OnceLock::new();
+
+/// 配置文件位置
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum ConfigLocation {
+    /// 配置目录下的 config.toml
+    #[default]
+    Default,
+    /// `--config` 指定的文件
+    File(PathBuf),
+    /// `--profile` 指定的命名配置（配置目录下的 profiles/<名称>.toml）
+    Profile(String),
+}
+
+impl ConfigLocation {
+    /// 配置文件路径
+    pub fn path(&self) -> Option<PathBuf> {
+        match self {
+            Self::Default => AppConfig::config_dir().map(|p| p.join("config.toml")),
+            Self::File(path) => Some(path.clone()),
+            Self::Profile(name) => AppConfig::config_dir().map(|p| p.join("profiles").join(format!("{}.toml", name))),
+        }
+    }
+
+    /// 命名配置的名称是否可用（只能包含字母、数字、`-` 和 `_`）
+    pub fn is_valid_profile_name(name: &str) -> bool {
+        !name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+    }
+}
+
+/// 重新加载配置文件后发生变化的设置
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ConfigReload {
+    /// 主题设置
+    pub theme: bool,
+    /// 快捷键
+    pub keybindings: bool,
+}
+
+impl ConfigReload {
+    /// 是否没有变化
+    pub fn is_empty(&self) -> bool {
+        !self.theme && !self.keybindings
+    }
+}
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct AppConfig {
@@ -20,20 +85,194 @@ pub struct AppConfig {
     /// 当前是否为夜间模式
     #[serde(default = "default_dark_mode")]
     pub is_dark_mode: bool,
+    /// 用户自定义主题
+    #[serde(default)]
+    pub custom_themes: Vec<CustomTheme>,
+    /// 当前使用的自定义主题名称（为空时使用预设主题）
     #[serde(default)]
-    pub query_history: QueryHistory,
+    pub custom_theme: Option<String>,
+    /// 查询历史保留的条数（不含收藏）
+    #[serde(default = "default_history_limit")]
+    pub history_limit: usize,
     /// 每个连接的 SQL 命令历史记录 (连接名 -> SQL 列表)
     #[serde(default)]
     pub command_history: HashMap<String, Vec<String>>,
     /// UI 缩放比例 (0.5 - 2.0)
     #[serde(default = "default_ui_scale")]
     pub ui_scale: f32,
+    /// 界面、编辑器、表格的字体设置
+    #[serde(default)]
+    pub fonts: FontSettings,
+    /// 界面语言
+    #[serde(default)]
+    pub language: Language,
+    /// 每个连接保留的命令历史条数
+    #[serde(default = "default_command_history_limit")]
+    pub command_history_limit: usize,
+    /// 表预览查询的默认行数（LIMIT）
+    #[serde(default = "default_query_limit")]
+    pub default_query_limit: usize,
+    /// 查询结果最多保留的行数
+    #[serde(default = "default_max_result_rows")]
+    pub max_result_rows: usize,
+    /// 查询结果占用内存超过该值（MB）时提醒
+    #[serde(default = "default_memory_warning_mb")]
+    pub memory_warning_mb: usize,
+    /// 内存占用超过提醒阈值时把后台标签页的结果写入磁盘缓存
+    #[serde(default)]
+    pub spill_large_results: bool,
+    /// 单元格中的图片路径从该目录读取并悬停预览，未设置时不读取本地图片
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image_preview_dir: Option<PathBuf>,
+    /// 连接超时（秒）
+    #[serde(default = "default_connect_timeout_secs")]
+    pub connect_timeout_secs: u64,
+    /// 查询超时（秒）
+    #[serde(default = "default_query_timeout_secs")]
+    pub query_timeout_secs: u64,
+    /// 后台刷新表和列信息的间隔（秒），0 表示只在执行 DDL 后刷新
+    #[serde(default = "default_metadata_refresh_secs")]
+    pub metadata_refresh_secs: u64,
+    /// 已连接的连接发送保活检查的间隔（秒），0 表示不检查
+    #[serde(default = "default_keep_alive_secs")]
+    pub keep_alive_secs: u64,
+    /// 连接池空闲多久后自动断开（秒），0 表示不自动断开
+    #[serde(default = "default_pool_idle_ttl_secs")]
+    pub pool_idle_ttl_secs: u64,
+    /// 每个连接同时执行的查询数上限，超出的查询排队
+    #[serde(default = "default_max_parallel_queries")]
+    pub max_parallel_queries: usize,
+    /// 查询超过该时长（秒）且窗口不在前台时，完成后发送桌面通知，0 表示不发送
+    #[serde(default = "default_desktop_notify_secs")]
+    pub desktop_notify_secs: u64,
+    /// 删除连接前确认
+    #[serde(default = "default_true")]
+    pub confirm_delete_connection: bool,
+    /// 删除触发器、存储过程等对象前确认
+    #[serde(default = "default_true")]
+    pub confirm_drop_object: bool,
+    /// 保存表格修改前在检查窗口中确认生成的 SQL
+    #[serde(default = "default_true")]
+    pub confirm_grid_delete: bool,
+    /// 在表格底部显示行数和聚合统计
+    #[serde(default = "default_true")]
+    pub show_grid_footer: bool,
+    /// 在窗口底部显示当前区域可用的按键
+    #[serde(default = "default_true")]
+    pub show_key_hints: bool,
+    /// 时间戳列的显示时区
+    #[serde(default)]
+    pub timestamp_display: TimestampDisplay,
+    /// 数字的显示格式（列没有单独设置时使用）
+    #[serde(default)]
+    pub number_format: NumberFormat,
+    /// 自定义快捷键（未配置的操作使用默认快捷键）
+    #[serde(default)]
+    pub keybindings: KeyBindings,
+    /// 已保存的工作流
+    #[serde(default)]
+    pub workflows: Vec<Workflow>,
+    /// 本地 API 服务设置和共享的查询
+    #[serde(default)]
+    pub api_server: ApiServerConfig,
+    /// 最近使用的表和数据库（快速切换器）
+    #[serde(default)]
+    pub recent_objects: RecentObjects,
+    /// 按表保存的筛选预设
+    #[serde(default)]
+    pub filter_presets: FilterPresets,
+    /// 按表保存的列数字格式
+    #[serde(default)]
+    pub column_formats: ColumnFormats,
+    /// 按表保存的列宽
+    #[serde(default)]
+    pub column_widths: ColumnWidths,
+    /// 按表保存的列显示顺序
+    #[serde(default)]
+    pub column_orders: ColumnOrders,
+    /// 按连接保存的敏感列
+    #[serde(default)]
+    pub masking: MaskingRules,
+    /// 按表保存的行书签
+    #[serde(default)]
+    pub row_bookmarks: RowBookmarks,
+    /// 是否已完成新手引导（完成后启动时不再自动显示）
+    #[serde(default)]
+    pub tour_completed: bool,
+    /// 回收站（删除的连接和关闭的查询 Tab）
+    #[serde(default)]
+    pub trash: Trash,
+    /// 回收站保留天数，超过后启动时清除
+    #[serde(default = "default_trash_retention_days")]
+    pub trash_retention_days: u32,
+    /// 主密码加密的连接列表（设置主密码后 `connections` 不再明文保存）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vault: Option<Vault>,
+    /// 解锁后的主密码密钥，保存时用它重新加密连接列表
+    #[serde(skip)]
+    pub master_key: Option<MasterKey>,
 }
 
 fn default_ui_scale() -> f32 {
     1.0
 }
 
+fn default_true() -> bool {
+    true
+}
+
+fn default_command_history_limit() -> usize {
+    constants::history::MAX_COMMAND_HISTORY_PER_CONNECTION
+}
+
+fn default_query_limit() -> usize {
+    constants::database::DEFAULT_QUERY_LIMIT
+}
+
+fn default_max_result_rows() -> usize {
+    constants::database::MAX_RESULT_SET_ROWS
+}
+
+fn default_memory_warning_mb() -> usize {
+    constants::database::MEMORY_WARNING_MB
+}
+
+fn default_connect_timeout_secs() -> u64 {
+    constants::database::CONNECTION_TIMEOUT_SECS
+}
+
+fn default_query_timeout_secs() -> u64 {
+    constants::database::QUERY_TIMEOUT_SECS
+}
+
+fn default_metadata_refresh_secs() -> u64 {
+    constants::database::METADATA_REFRESH_SECS
+}
+
+fn default_keep_alive_secs() -> u64 {
+    constants::database::KEEP_ALIVE_SECS
+}
+
+fn default_pool_idle_ttl_secs() -> u64 {
+    constants::database::POOL_IDLE_TTL_SECS
+}
+
+fn default_max_parallel_queries() -> usize {
+    constants::database::MAX_PARALLEL_QUERIES
+}
+
+fn default_desktop_notify_secs() -> u64 {
+    constants::database::DESKTOP_NOTIFY_SECS
+}
+
+fn default_history_limit() -> usize {
+    constants::history::MAX_QUERY_HISTORY
+}
+
+fn default_trash_retention_days() -> u32 {
+    constants::history::TRASH_RETENTION_DAYS
+}
+
 fn default_light_theme() -> ThemePreset {
     ThemePreset::TokyoNightLight
 }
@@ -54,20 +293,388 @@ impl Default for AppConfig {
             light_theme: default_light_theme(),
             dark_theme: default_dark_theme(),
             is_dark_mode: default_dark_mode(),
-            query_history: QueryHistory::new(100),
+            custom_themes: Vec::new(),
+            custom_theme: None,
+            history_limit: default_history_limit(),
             command_history: HashMap::new(),
             ui_scale: default_ui_scale(),
+            fonts: FontSettings::default(),
+            language: Language::default(),
+            command_history_limit: default_command_history_limit(),
+            default_query_limit: default_query_limit(),
+            max_result_rows: default_max_result_rows(),
+            memory_warning_mb: default_memory_warning_mb(),
+            spill_large_results: false,
+            image_preview_dir: None,
+            connect_timeout_secs: default_connect_timeout_secs(),
+            query_timeout_secs: default_query_timeout_secs(),
+            metadata_refresh_secs: default_metadata_refresh_secs(),
+            keep_alive_secs: default_keep_alive_secs(),
+            pool_idle_ttl_secs: default_pool_idle_ttl_secs(),
+            max_parallel_queries: default_max_parallel_queries(),
+            desktop_notify_secs: default_desktop_notify_secs(),
+            confirm_delete_connection: true,
+            confirm_drop_object: true,
+            confirm_grid_delete: true,
+            show_grid_footer: true,
+            show_key_hints: true,
+            timestamp_display: TimestampDisplay::default(),
+            number_format: NumberFormat::default(),
+            keybindings: KeyBindings::default(),
+            workflows: Vec::new(),
+            api_server: ApiServerConfig::default(),
+            recent_objects: RecentObjects::default(),
+            filter_presets: FilterPresets::default(),
+            column_formats: ColumnFormats::default(),
+            column_widths: ColumnWidths::default(),
+            column_orders: ColumnOrders::default(),
+            masking: MaskingRules::default(),
+            row_bookmarks: RowBookmarks::default(),
+            tour_completed: false,
+            trash: Trash::default(),
+            trash_retention_days: default_trash_retention_days(),
+            vault: None,
+            master_key: None,
         }
     }
 }
 
+/// 首选项窗口编辑的配置项
+///
+/// 对应 `AppConfig` 中可以在界面上修改的字段，编辑完成后通过
+/// [`AppConfig::apply_preferences`] 写回。
+#[derive(Debug, Clone, PartialEq)]
+pub struct Preferences {
+    // 常规
+    pub language: Language,
+    pub ui_scale: f32,
+    pub confirm_delete_connection: bool,
+    pub confirm_drop_object: bool,
+    pub confirm_grid_delete: bool,
+    pub show_key_hints: bool,
+    // 编辑器
+    pub history_limit: usize,
+    pub command_history_limit: usize,
+    pub trash_retention_days: u32,
+    // 表格
+    pub default_query_limit: usize,
+    pub max_result_rows: usize,
+    pub memory_warning_mb: usize,
+    pub spill_large_results: bool,
+    /// 图片预览目录，为空时不预览
+    pub image_preview_dir: String,
+    pub show_grid_footer: bool,
+    pub timestamp_display: TimestampDisplay,
+    pub number_format: NumberFormat,
+    // 数据库
+    pub connect_timeout_secs: u64,
+    pub query_timeout_secs: u64,
+    pub metadata_refresh_secs: u64,
+    pub keep_alive_secs: u64,
+    pub pool_idle_ttl_secs: u64,
+    pub max_parallel_queries: usize,
+    pub desktop_notify_secs: u64,
+}
+
+impl Default for Preferences {
+    fn default() -> Self {
+        AppConfig::default().preferences()
+    }
+}
+
+impl Preferences {
+    /// 校验取值范围，返回所有错误
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        fn check<T: PartialOrd + std::fmt::Display>(
+            errors: &mut Vec<String>,
+            name: &str,
+            value: T,
+            range: std::ops::RangeInclusive<T>,
+        ) {
+            if !range.contains(&value) {
+                errors.push(format!("{} 必须在 {} 到 {} 之间", name, range.start(), range.end()));
+            }
+        }
+
+        let mut errors = Vec::new();
+        check(&mut errors, "界面缩放", self.ui_scale, constants::ui::UI_SCALE_MIN..=constants::ui::UI_SCALE_MAX);
+        check(&mut errors, "查询历史条数", self.history_limit, constants::history::QUERY_HISTORY_LIMIT_RANGE);
+        check(
+            &mut errors,
+            "命令历史条数",
+            self.command_history_limit,
+            constants::history::COMMAND_HISTORY_LIMIT_RANGE,
+        );
+        check(
+            &mut errors,
+            "回收站保留天数",
+            self.trash_retention_days,
+            constants::history::TRASH_RETENTION_RANGE_DAYS,
+        );
+        check(&mut errors, "表预览行数", self.default_query_limit, constants::database::QUERY_LIMIT_RANGE);
+        check(&mut errors, "结果集行数上限", self.max_result_rows, constants::database::RESULT_ROWS_RANGE);
+        check(
+            &mut errors,
+            "内存提醒阈值",
+            self.memory_warning_mb,
+            constants::database::MEMORY_WARNING_RANGE_MB,
+        );
+        check(
+            &mut errors,
+            "连接超时",
+            self.connect_timeout_secs,
+            constants::database::CONNECTION_TIMEOUT_RANGE_SECS,
+        );
+        check(&mut errors, "查询超时", self.query_timeout_secs, constants::database::QUERY_TIMEOUT_RANGE_SECS);
+        check(
+            &mut errors,
+            "元数据刷新间隔",
+            self.metadata_refresh_secs,
+            constants::database::METADATA_REFRESH_RANGE_SECS,
+        );
+        check(&mut errors, "保活检查间隔", self.keep_alive_secs, constants::database::KEEP_ALIVE_RANGE_SECS);
+        check(
+            &mut errors,
+            "空闲连接池断开时间",
+            self.pool_idle_ttl_secs,
+            constants::database::POOL_IDLE_TTL_RANGE_SECS,
+        );
+        check(
+            &mut errors,
+            "同时执行查询数",
+            self.max_parallel_queries,
+            constants::database::PARALLEL_QUERIES_RANGE,
+        );
+        check(
+            &mut errors,
+            "桌面通知阈值",
+            self.desktop_notify_secs,
+            constants::database::DESKTOP_NOTIFY_RANGE_SECS,
+        );
+        if errors.is_empty() { Ok(()) } else { Err(errors) }
+    }
+}
+
 impl AppConfig {
+    /// 当前的首选项
+    pub fn preferences(&self) -> Preferences {
+        Preferences {
+            language: self.language,
+            ui_scale: self.ui_scale,
+            confirm_delete_connection: self.confirm_delete_connection,
+            confirm_drop_object: self.confirm_drop_object,
+            confirm_grid_delete: self.confirm_grid_delete,
+            show_key_hints: self.show_key_hints,
+            history_limit: self.history_limit,
+            command_history_limit: self.command_history_limit,
+            trash_retention_days: self.trash_retention_days,
+            default_query_limit: self.default_query_limit,
+            max_result_rows: self.max_result_rows,
+            memory_warning_mb: self.memory_warning_mb,
+            spill_large_results: self.spill_large_results,
+            image_preview_dir: self
+                .image_preview_dir
+                .as_ref()
+                .map(|dir| dir.display().to_string())
+                .unwrap_or_default(),
+            show_grid_footer: self.show_grid_footer,
+            timestamp_display: self.timestamp_display,
+            number_format: self.number_format,
+            connect_timeout_secs: self.connect_timeout_secs,
+            query_timeout_secs: self.query_timeout_secs,
+            metadata_refresh_secs: self.metadata_refresh_secs,
+            keep_alive_secs: self.keep_alive_secs,
+            pool_idle_ttl_secs: self.pool_idle_ttl_secs,
+            max_parallel_queries: self.max_parallel_queries,
+            desktop_notify_secs: self.desktop_notify_secs,
+        }
+    }
+
+    /// 写回首选项
+    pub fn apply_preferences(&mut self, prefs: &Preferences) {
+        self.language = prefs.language;
+        self.ui_scale = prefs.ui_scale;
+        self.confirm_delete_connection = prefs.confirm_delete_connection;
+        self.confirm_drop_object = prefs.confirm_drop_object;
+        self.confirm_grid_delete = prefs.confirm_grid_delete;
+        self.show_key_hints = prefs.show_key_hints;
+        self.history_limit = prefs.history_limit;
+        self.command_history_limit = prefs.command_history_limit;
+        self.trash_retention_days = prefs.trash_retention_days;
+        self.default_query_limit = prefs.default_query_limit;
+        self.max_result_rows = prefs.max_result_rows;
+        self.memory_warning_mb = prefs.memory_warning_mb;
+        self.spill_large_results = prefs.spill_large_results;
+        let image_preview_dir = prefs.image_preview_dir.trim();
+        self.image_preview_dir = (!image_preview_dir.is_empty()).then(|| PathBuf::from(image_preview_dir));
+        self.show_grid_footer = prefs.show_grid_footer;
+        self.timestamp_display = prefs.timestamp_display;
+        self.number_format = prefs.number_format;
+        self.connect_timeout_secs = prefs.connect_timeout_secs;
+        self.query_timeout_secs = prefs.query_timeout_secs;
+        self.metadata_refresh_secs = prefs.metadata_refresh_secs;
+        self.keep_alive_secs = prefs.keep_alive_secs;
+        self.pool_idle_ttl_secs = prefs.pool_idle_ttl_secs;
+        self.max_parallel_queries = prefs.max_parallel_queries;
+        self.desktop_notify_secs = prefs.desktop_notify_secs;
+    }
+
+    /// 将手动编辑配置文件导致的越界取值限制到允许范围内
+    pub fn sanitize(&mut self) {
+        use constants::{database, history};
+        fn clamp<T: PartialOrd + Copy>(value: T, range: std::ops::RangeInclusive<T>) -> T {
+            if value < *range.start() {
+                *range.start()
+            } else if value > *range.end() {
+                *range.end()
+            } else {
+                value
+            }
+        }
+        self.ui_scale = clamp(self.ui_scale, constants::ui::UI_SCALE_MIN..=constants::ui::UI_SCALE_MAX);
+        self.history_limit = clamp(self.history_limit, history::QUERY_HISTORY_LIMIT_RANGE);
+        self.command_history_limit = clamp(self.command_history_limit, history::COMMAND_HISTORY_LIMIT_RANGE);
+        self.trash_retention_days = clamp(self.trash_retention_days, history::TRASH_RETENTION_RANGE_DAYS);
+        self.default_query_limit = clamp(self.default_query_limit, database::QUERY_LIMIT_RANGE);
+        self.max_result_rows = clamp(self.max_result_rows, database::RESULT_ROWS_RANGE);
+        self.memory_warning_mb = clamp(self.memory_warning_mb, database::MEMORY_WARNING_RANGE_MB);
+        self.connect_timeout_secs = clamp(self.connect_timeout_secs, database::CONNECTION_TIMEOUT_RANGE_SECS);
+        self.query_timeout_secs = clamp(self.query_timeout_secs, database::QUERY_TIMEOUT_RANGE_SECS);
+        self.metadata_refresh_secs = clamp(self.metadata_refresh_secs, database::METADATA_REFRESH_RANGE_SECS);
+        self.keep_alive_secs = clamp(self.keep_alive_secs, database::KEEP_ALIVE_RANGE_SECS);
+        self.pool_idle_ttl_secs = clamp(self.pool_idle_ttl_secs, database::POOL_IDLE_TTL_RANGE_SECS);
+        self.max_parallel_queries = clamp(self.max_parallel_queries, database::PARALLEL_QUERIES_RANGE);
+        self.desktop_notify_secs = clamp(self.desktop_notify_secs, database::DESKTOP_NOTIFY_RANGE_SECS);
+    }
+
+    /// 采用配置文件中无需重启即可生效的设置（主题、快捷键），返回发生变化的部分
+    pub fn apply_reloaded(&mut self, on_disk: &AppConfig) -> ConfigReload {
+        let mode_changed = (self.is_dark_mode, self.light_theme, self.dark_theme)
+            != (on_disk.is_dark_mode, on_disk.light_theme, on_disk.dark_theme);
+        let theme = mode_changed
+            || self.theme_preset != on_disk.theme_preset
+            || self.custom_theme != on_disk.custom_theme
+            || self.custom_themes != on_disk.custom_themes;
+        if theme {
+            self.is_dark_mode = on_disk.is_dark_mode;
+            self.light_theme = on_disk.light_theme;
+            self.dark_theme = on_disk.dark_theme;
+            self.custom_themes = on_disk.custom_themes.clone();
+            self.custom_theme = on_disk.custom_theme.clone();
+            // 修改了日/夜主题或模式时切换到当前模式对应的主题
+            self.theme_preset = match (mode_changed, self.is_dark_mode) {
+                (true, true) => self.dark_theme,
+                (true, false) => self.light_theme,
+                (false, _) => on_disk.theme_preset,
+            };
+        }
+
+        let keybindings = self.keybindings != on_disk.keybindings;
+        if keybindings {
+            self.keybindings = on_disk.keybindings.clone();
+        }
+        ConfigReload { theme, keybindings }
+    }
+
+    /// 是否设置了主密码
+    pub fn has_master_password(&self) -> bool {
+        self.vault.is_some() || self.master_key.is_some()
+    }
+
+    /// 连接列表是否已用主密码加密且尚未解锁
+    pub fn is_locked(&self) -> bool {
+        self.vault.is_some() && self.master_key.is_none()
+    }
+
+    /// 用主密码解锁连接列表和回收站中删除的连接，返回解锁出的连接（与未解锁期间新建的连接同名时保留新建的）
+    pub fn unlock(&mut self, password: &str) -> Result<Vec<ConnectionConfig>, String> {
+        let vault = self.vault.as_ref().ok_or("未设置主密码")?;
+        let (key, content) = vault.open(password)?;
+        let unlocked: Vec<ConnectionConfig> = content
+            .connections
+            .into_iter()
+            .filter(|c| !self.connections.iter().any(|existing| existing.name == c.name))
+            .collect();
+        self.connections.extend(unlocked.iter().cloned());
+        self.trash.restore(content.trash);
+        self.master_key = Some(key);
+        Ok(unlocked)
+    }
+
+    /// 无界面模式下用环境变量中的主密码解锁
+    pub fn unlock_from_env(&mut self) -> Result<(), String> {
+        if !self.is_locked() {
+            return Ok(());
+        }
+        let password = std::env::var(MASTER_PASSWORD_ENV)
+            .map_err(|_| format!("连接已使用主密码加密，请通过环境变量 {} 提供主密码", MASTER_PASSWORD_ENV))?;
+        self.unlock(&password).map(|_| ())
+    }
+
+    /// 设置或修改主密码，下次保存时加密连接列表
+    pub fn set_master_password(&mut self, password: &str) -> Result<(), String> {
+        if self.is_locked() {
+            return Err("请先解锁连接配置".to_string());
+        }
+        self.master_key = Some(MasterKey::create(password)?);
+        Ok(())
+    }
+
+    /// 取消主密码，下次保存时连接列表恢复为普通保存
+    pub fn remove_master_password(&mut self) -> Result<(), String> {
+        if self.is_locked() {
+            return Err("请先解锁连接配置".to_string());
+        }
+        self.master_key = None;
+        self.vault = None;
+        Ok(())
+    }
+
+    /// 序列化为配置文件内容（设置了主密码时加密连接列表和回收站中删除的连接）
+    pub fn to_toml(&self) -> Result<String, String> {
+        let Some(key) = &self.master_key else {
+            return toml::to_string_pretty(self).map_err(|e| e.to_string());
+        };
+        let mut table = toml::Table::try_from(self).map_err(|e| e.to_string())?;
+        let vault = key.seal(&self.connections, &self.trash.connection_entries())?;
+        table.insert("connections".to_string(), toml::Value::Array(Vec::new()));
+        let trash = self.trash.without_connections();
+        table.insert("trash".to_string(), toml::Value::try_from(trash).map_err(|e| e.to_string())?);
+        table.insert("vault".to_string(), toml::Value::try_from(vault).map_err(|e| e.to_string())?);
+        toml::to_string_pretty(&table).map_err(|e| e.to_string())
+    }
+
     pub fn config_dir() -> Option<PathBuf> {
         dirs::config_dir().map(|p| p.join("gridix"))
     }
 
+    /// 设置本次运行使用的配置文件（需在首次读取配置前调用）
+    pub fn set_location(location: ConfigLocation) {
+        if CONFIG_LOCATION.set(location).is_err() {
+            tracing::warn!("配置文件位置已确定，忽略新的设置");
+        }
+    }
+
+    /// 本次运行使用的配置文件位置
+    pub fn location() -> &'static ConfigLocation {
+        CONFIG_LOCATION.get_or_init(ConfigLocation::default)
+    }
+
     pub fn config_path() -> Option<PathBuf> {
-        Self::config_dir().map(|p| p.join("config.toml"))
+        Self::location().path()
+    }
+
+    /// 配置文件的修改时间（文件不存在时为 `None`）
+    pub fn modified_time() -> Option<SystemTime> {
+        Self::config_path().and_then(|path| fs::metadata(path).ok()).and_then(|m| m.modified().ok())
+    }
+
+    /// 读取并校验指定的配置文件
+    pub fn read(path: &Path) -> Result<Self, String> {
+        let content = fs::read_to_string(path).map_err(|e| format!("读取配置文件失败: {}", e))?;
+        let mut config = toml::from_str::<Self>(&content).map_err(|e| format!("解析配置文件失败: {}", e))?;
+        config.sanitize();
+        Ok(config)
     }
 
     pub fn load() -> Self {
@@ -81,30 +688,22 @@ impl AppConfig {
             return Self::default();
         }
         
-        let content = match fs::read_to_string(&path) {
-            Ok(c) => c,
-            Err(e) => {
-                tracing::warn!(error = %e, "读取配置文件失败");
-                return Self::default();
-            }
-        };
-        
-        match toml::from_str(&content) {
+        match Self::read(&path) {
             Ok(config) => config,
             Err(e) => {
-                tracing::warn!(error = %e, path = ?path, "解析配置文件失败");
+                tracing::warn!(error = %e, path = ?path, "加载配置文件失败");
                 Self::default()
             }
         }
     }
 
     pub fn save(&self) -> Result<(), String> {
-        let dir = Self::config_dir().ok_or("无法找到配置目录")?;
         let path = Self::config_path().ok_or("无法找到配置路径")?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+        }
 
-        fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
-
-        let toml_str = toml::to_string_pretty(self).map_err(|e| e.to_string())?;
+        let toml_str = self.to_toml()?;
         
         // 原子写入：先写入临时文件，再重命名
         // 这样即使程序在写入过程中崩溃，原配置文件也不会损坏
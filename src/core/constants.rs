@@ -8,8 +8,20 @@
 pub mod history {
     /// 每个连接的最大命令历史记录数
     pub const MAX_COMMAND_HISTORY_PER_CONNECTION: usize = 100;
-    /// 全局查询历史最大条数
+    /// 全局查询历史默认最大条数（不含收藏）
     pub const MAX_QUERY_HISTORY: usize = 100;
+    /// 查询历史条数上限的可选范围
+    pub const QUERY_HISTORY_LIMIT_RANGE: std::ops::RangeInclusive<usize> = 10..=10000;
+    /// 每个连接命令历史条数的可选范围
+    pub const COMMAND_HISTORY_LIMIT_RANGE: std::ops::RangeInclusive<usize> = 10..=1000;
+    /// 快速切换器保留的最近使用表和数据库条数
+    pub const MAX_RECENT_OBJECTS: usize = 200;
+    /// 回收站默认保留天数
+    pub const TRASH_RETENTION_DAYS: u32 = 30;
+    /// 回收站保留天数的可选范围
+    pub const TRASH_RETENTION_RANGE_DAYS: std::ops::RangeInclusive<u32> = 1..=365;
+    /// 回收站中最多保留的关闭 Tab 数
+    pub const MAX_TRASHED_TABS: usize = 20;
 }
 
 /// 数据库相关常量
@@ -22,12 +34,68 @@ pub mod database {
     pub const QUERY_TIMEOUT_SECS: u64 = 300;
     /// 默认查询限制行数
     pub const DEFAULT_QUERY_LIMIT: usize = 100;
+    /// 连接超时的可选范围（秒）
+    pub const CONNECTION_TIMEOUT_RANGE_SECS: std::ops::RangeInclusive<u64> = 1..=600;
+    /// 查询超时的可选范围（秒）
+    pub const QUERY_TIMEOUT_RANGE_SECS: std::ops::RangeInclusive<u64> = 1..=86400;
+    /// 后台刷新表和列信息的默认间隔（秒）
+    pub const METADATA_REFRESH_SECS: u64 = 300;
+    /// 元数据刷新间隔的可选范围（秒，0 表示只在执行 DDL 后刷新）
+    pub const METADATA_REFRESH_RANGE_SECS: std::ops::RangeInclusive<u64> = 0..=86400;
+    /// 连接保活检查的默认间隔（秒）
+    pub const KEEP_ALIVE_SECS: u64 = 60;
+    /// 保活检查间隔的可选范围（秒，0 表示不检查）
+    pub const KEEP_ALIVE_RANGE_SECS: std::ops::RangeInclusive<u64> = 0..=3600;
+    /// 保活检查耗时超过该值时显示为响应缓慢（毫秒）
+    pub const KEEP_ALIVE_SLOW_MS: u64 = 1000;
+    /// 连接池空闲多久后自动断开的默认值（秒）
+    pub const POOL_IDLE_TTL_SECS: u64 = 600;
+    /// 连接池空闲断开时间的可选范围（秒，0 表示不自动断开）
+    pub const POOL_IDLE_TTL_RANGE_SECS: std::ops::RangeInclusive<u64> = 0..=86400;
+    /// 查询超过该时长且窗口不在前台时发送桌面通知的默认值（秒）
+    pub const DESKTOP_NOTIFY_SECS: u64 = 10;
+    /// 桌面通知阈值的可选范围（秒，0 表示不发送）
+    pub const DESKTOP_NOTIFY_RANGE_SECS: std::ops::RangeInclusive<u64> = 0..=3600;
+    /// 每个连接同时执行的查询数默认上限
+    pub const MAX_PARALLEL_QUERIES: usize = 2;
+    /// 同时执行查询数上限的可选范围
+    pub const PARALLEL_QUERIES_RANGE: std::ops::RangeInclusive<usize> = 1..=16;
+    /// 检查空闲连接池的间隔（秒）
+    pub const POOL_CLEANUP_INTERVAL_SECS: u64 = 60;
+    /// 表预览行数的可选范围
+    pub const QUERY_LIMIT_RANGE: std::ops::RangeInclusive<usize> = 1..=100000;
     /// 大结果集警告阈值
     pub const LARGE_RESULT_SET_WARNING_THRESHOLD: usize = 10000;
     /// 最大结果集行数限制（防止内存溢出）
     pub const MAX_RESULT_SET_ROWS: usize = 500000;
+    /// 结果集行数上限的可选范围（不超过 `MAX_RESULT_SET_ROWS`）
+    pub const RESULT_ROWS_RANGE: std::ops::RangeInclusive<usize> = 100..=MAX_RESULT_SET_ROWS;
+    /// 查询结果内存占用的默认提醒阈值（MB）
+    pub const MEMORY_WARNING_MB: usize = 1024;
+    /// 内存提醒阈值的可选范围（MB）
+    pub const MEMORY_WARNING_RANGE_MB: std::ops::RangeInclusive<usize> = 64..=65536;
     /// 启用并行筛选的行数阈值
     pub const PARALLEL_FILTER_THRESHOLD: usize = 10000;
+    /// 在后台线程筛选和排序的行数阈值
+    pub const BACKGROUND_FILTER_THRESHOLD: usize = 50000;
+    /// Redis 键浏览最多列出的键数量
+    pub const REDIS_MAX_SCAN_KEYS: usize = 5000;
+    /// Redis 集合类型值预览的最大元素数
+    pub const REDIS_MAX_VALUE_ITEMS: usize = 10000;
+    /// 分页流式读取的结果集每页行数（ClickHouse 大结果集）
+    pub const RESULT_PAGE_SIZE: usize = 1000;
+    /// 流式读取查询结果时每批发送到表格的行数
+    pub const STREAM_CHUNK_SIZE: usize = 1000;
+    /// 侧边栏每次读取的表数量（表很多时分批加载）
+    pub const TABLE_PAGE_SIZE: usize = 1000;
+    /// 全库数据搜索同时查询的表数
+    pub const DATA_SEARCH_CONCURRENCY: usize = 4;
+    /// 全库数据搜索每张表最多返回的行数
+    pub const DATA_SEARCH_ROW_LIMIT: usize = 50;
+    /// 基准测试执行次数的可选范围
+    pub const BENCHMARK_RUNS_RANGE: std::ops::RangeInclusive<usize> = 1..=1000;
+    /// 基准测试预热次数的可选范围
+    pub const BENCHMARK_WARMUP_RANGE: std::ops::RangeInclusive<usize> = 0..=100;
     
     /// 连接池相关常量
     pub mod pool {
@@ -35,6 +103,10 @@ pub mod database {
         pub const MAX_MYSQL_POOLS: usize = 20;
         /// 最大缓存的 PostgreSQL 客户端数量
         pub const MAX_POSTGRES_CLIENTS: usize = 20;
+        /// 最多缓存的 PostgreSQL 预处理语句数量（所有连接共享）
+        pub const MAX_CACHED_STATEMENTS: usize = 256;
+        /// MySQL 每个连接缓存的预处理语句数量
+        pub const MYSQL_STMT_CACHE_SIZE: usize = 64;
         /// MySQL 连接池最小连接数
         pub const MYSQL_POOL_MIN_CONNECTIONS: usize = 2;
         /// MySQL 连接池最大连接数
@@ -43,6 +115,10 @@ pub mod database {
         pub const MYSQL_IDLE_TIMEOUT_SECS: u64 = 300;
         /// MySQL 连接最大生存时间（秒）
         pub const MYSQL_MAX_LIFETIME_SECS: u64 = 1800;
+        /// 单独配置连接池连接数的允许范围
+        pub const POOL_CONNECTIONS_RANGE: std::ops::RangeInclusive<usize> = 1..=100;
+        /// 单独配置空闲连接超时的允许范围（秒）
+        pub const POOL_IDLE_TIMEOUT_RANGE_SECS: std::ops::RangeInclusive<u64> = 10..=86400;
     }
 }
 
@@ -52,6 +128,10 @@ pub mod ui {
     pub const UI_SCALE_MIN: f32 = 0.5;
     /// UI 缩放最大值
     pub const UI_SCALE_MAX: f32 = 2.0;
+    /// 字号最小值
+    pub const FONT_SIZE_MIN: f32 = 8.0;
+    /// 字号最大值
+    pub const FONT_SIZE_MAX: f32 = 32.0;
     /// 连接名称最大长度
     pub const CONNECTION_NAME_MAX_LENGTH: usize = 64;
     /// 用户名最大长度
@@ -109,6 +189,12 @@ pub mod grid {
     pub const CJK_CHAR_WIDTH: f32 = 14.0;
     /// 单元格内容截断长度
     pub const CELL_TRUNCATE_LEN: usize = 50;
+    /// 二进制查看窗口最多显示的字节数
+    pub const BLOB_VIEW_MAX_BYTES: usize = 64 * 1024;
+    /// 悬停预览显示的字节数
+    pub const BLOB_HOVER_BYTES: usize = 128;
+    /// 从文件导入二进制内容的大小上限（16 MB）
+    pub const BLOB_IMPORT_MAX_SIZE: u64 = 16 * 1024 * 1024;
 }
 
 /// 错误消息常量（统一中文错误提示）
@@ -0,0 +1,77 @@
+//! 全库数据搜索
+//!
+//! 在选中的表中搜索包含关键字的行：只搜索文本类型的列，每张表生成一条
+//! `LIKE` 查询（各列之间为 OR），由调用方限制并发执行。
+
+use crate::database::{ColumnInfo, DatabaseType};
+
+/// LIKE 的转义字符（ClickHouse 不支持 ESCAPE 子句，使用反斜杠）
+const LIKE_ESCAPE: char = '!';
+
+/// 列类型是否为文本（字符串、文本、枚举等）
+pub fn is_text_type(data_type: &str) -> bool {
+    let lower = data_type.to_lowercase();
+    ["char", "text", "clob", "string", "enum", "citext", "nvarchar", "varchar2"]
+        .iter()
+        .any(|t| lower.contains(t))
+}
+
+/// 可搜索的文本列，`column_filter` 不为空时只保留列名包含它的列（忽略大小写）
+pub fn searchable_columns(columns: &[ColumnInfo], column_filter: &str) -> Vec<String> {
+    let filter = column_filter.trim().to_lowercase();
+    columns
+        .iter()
+        .filter(|c| is_text_type(&c.data_type))
+        .filter(|c| filter.is_empty() || c.name.to_lowercase().contains(&filter))
+        .map(|c| c.name.clone())
+        .collect()
+}
+
+/// 搜索一张表的 SQL（表名和列名需已加引号），没有可搜索的列时返回 `None`
+pub fn data_search_sql(
+    db_type: DatabaseType,
+    quoted_table: &str,
+    quoted_columns: &[String],
+    term: &str,
+    case_sensitive: bool,
+    limit: usize,
+) -> Option<String> {
+    if quoted_columns.is_empty() || term.is_empty() {
+        return None;
+    }
+    let term = if case_sensitive { term.to_string() } else { term.to_lowercase() };
+    let escape = if db_type == DatabaseType::ClickHouse { '\\' } else { LIKE_ESCAPE };
+    let mut pattern = String::with_capacity(term.len() + 2);
+    pattern.push('%');
+    for c in term.chars() {
+        if matches!(c, '%' | '_') || c == escape {
+            pattern.push(escape);
+        }
+        pattern.push(c);
+    }
+    pattern.push('%');
+    // MySQL 和 ClickHouse 的反斜杠也是转义符
+    if matches!(db_type, DatabaseType::MySQL | DatabaseType::ClickHouse) {
+        pattern = pattern.replace('\\', "\\\\");
+    }
+    let literal = format!("'{}'", pattern.replace('\'', "''"));
+    let escape_clause = if db_type == DatabaseType::ClickHouse {
+        String::new()
+    } else {
+        format!(" ESCAPE '{}'", LIKE_ESCAPE)
+    };
+
+    let conditions: Vec<String> = quoted_columns
+        .iter()
+        .map(|col| {
+            let expr = match (case_sensitive, db_type) {
+                (false, _) => format!("LOWER({})", col),
+                // MySQL 的默认排序规则不区分大小写
+                (true, DatabaseType::MySQL) => format!("BINARY {}", col),
+                (true, _) => col.clone(),
+            };
+            format!("{} LIKE {}{}", expr, literal, escape_clause)
+        })
+        .collect();
+    Some(db_type.select_where_sql(quoted_table, &conditions.join(" OR "), limit))
+}
@@ -0,0 +1,499 @@
+//! 测试数据生成
+//!
+//! 为表的每一列配置生成器（序列、随机数、姓名、邮箱、日期范围、正则模式、候选列表），
+//! 按行生成值，供数据生成对话框批量插入。
+//!
+//! 随机数使用内置的 SplitMix64，固定种子时结果可复现，便于测试。
+
+use chrono::{Duration, NaiveDate};
+
+const FIRST_NAMES: &[&str] = &[
+    "James", "Mary", "John", "Linda", "Robert", "Emma", "Michael", "Olivia", "David", "Sophia",
+    "William", "Ava", "Daniel", "Mia", "Lucas", "Chloe", "Henry", "Grace", "Leo", "Alice",
+];
+const LAST_NAMES: &[&str] = &[
+    "Smith", "Johnson", "Brown", "Taylor", "Miller", "Wilson", "Moore", "Clark", "Lewis", "Walker",
+    "Hall", "Young", "King", "Wright", "Scott", "Green", "Baker", "Adams", "Nelson", "Carter",
+];
+const EMAIL_DOMAINS: &[&str] = &["example.com", "example.org", "mail.test", "demo.local"];
+
+/// 模式中 `*`、`+` 的最大重复次数
+const MAX_REPEAT: u32 = 8;
+
+// ============================================================================
+// 随机数
+// ============================================================================
+
+/// 轻量伪随机数生成器（SplitMix64）
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    /// 使用指定种子创建
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    /// 下一个 64 位随机数
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// `[min, max]` 范围内的整数
+    pub fn range_i64(&mut self, min: i64, max: i64) -> i64 {
+        let (lo, hi) = if min <= max { (min, max) } else { (max, min) };
+        let span = (hi as i128 - lo as i128 + 1) as u128;
+        (lo as i128 + (self.next_u64() as u128 % span) as i128) as i64
+    }
+
+    /// `[0, 1)` 范围内的浮点数
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// 从切片中随机选取
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range_i64(0, items.len() as i64 - 1) as usize]
+    }
+}
+
+// ============================================================================
+// 生成器配置
+// ============================================================================
+
+/// 列值生成器
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValueGenerator {
+    /// 不插入该列（使用默认值或自增）
+    Skip,
+    /// 始终为 NULL
+    Null,
+    /// 递增序列
+    Sequence { start: i64, step: i64 },
+    /// 随机整数
+    Int { min: i64, max: i64 },
+    /// 随机小数
+    Float { min: f64, max: f64, decimals: u32 },
+    /// 英文姓名
+    Name,
+    /// 邮箱地址
+    Email,
+    /// 日期范围（`YYYY-MM-DD`），`with_time` 为 true 时附带时间
+    Date { from: String, to: String, with_time: bool },
+    /// 正则模式（支持字符类、`\d` `\w`、分组与 `|`、量词）
+    Pattern(String),
+    /// 从逗号分隔的候选值中随机选取
+    List(String),
+}
+
+impl ValueGenerator {
+    /// 所有生成器类型的默认配置（用于下拉选择）
+    pub fn kinds() -> Vec<ValueGenerator> {
+        vec![
+            ValueGenerator::Skip,
+            ValueGenerator::Null,
+            ValueGenerator::Sequence { start: 1, step: 1 },
+            ValueGenerator::Int { min: 1, max: 1000 },
+            ValueGenerator::Float { min: 0.0, max: 1000.0, decimals: 2 },
+            ValueGenerator::Name,
+            ValueGenerator::Email,
+            ValueGenerator::Date {
+                from: "2020-01-01".to_string(),
+                to: "2025-12-31".to_string(),
+                with_time: false,
+            },
+            ValueGenerator::Pattern("[a-z]{8}".to_string()),
+            ValueGenerator::List("a,b,c".to_string()),
+        ]
+    }
+
+    /// 生成器名称
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ValueGenerator::Skip => "跳过（默认值）",
+            ValueGenerator::Null => "NULL",
+            ValueGenerator::Sequence { .. } => "序列",
+            ValueGenerator::Int { .. } => "随机整数",
+            ValueGenerator::Float { .. } => "随机小数",
+            ValueGenerator::Name => "姓名",
+            ValueGenerator::Email => "邮箱",
+            ValueGenerator::Date { .. } => "日期",
+            ValueGenerator::Pattern(_) => "正则模式",
+            ValueGenerator::List(_) => "候选列表",
+        }
+    }
+
+    /// 根据列名和类型推荐生成器
+    pub fn suggest(name: &str, data_type: &str, is_primary_key: bool, default_value: Option<&str>) -> Self {
+        let name = name.to_lowercase();
+        let data_type = data_type.to_lowercase();
+        let is_int = data_type.contains("int") || data_type == "number" || data_type == "serial";
+
+        // 自增主键交给数据库生成
+        let auto_increment = data_type.contains("serial")
+            || default_value.is_some_and(|d| d.contains("nextval"))
+            || (is_primary_key && data_type == "integer");
+        if auto_increment {
+            return ValueGenerator::Skip;
+        }
+        if is_primary_key && is_int {
+            return ValueGenerator::Sequence { start: 1, step: 1 };
+        }
+        if name.contains("email") || name.contains("mail") {
+            return ValueGenerator::Email;
+        }
+        if name.contains("name") {
+            return ValueGenerator::Name;
+        }
+        if data_type.contains("bool") {
+            return ValueGenerator::List("true,false".to_string());
+        }
+        if data_type.contains("date") || data_type.contains("time") {
+            let with_time = data_type.contains("time");
+            return ValueGenerator::Date {
+                from: "2020-01-01".to_string(),
+                to: "2025-12-31".to_string(),
+                with_time,
+            };
+        }
+        if is_int {
+            return ValueGenerator::Int { min: 1, max: 1000 };
+        }
+        if ["float", "double", "real", "decimal", "numeric"].iter().any(|t| data_type.contains(t)) {
+            return ValueGenerator::Float { min: 0.0, max: 1000.0, decimals: 2 };
+        }
+        ValueGenerator::Pattern("[A-Z][a-z]{4,9}".to_string())
+    }
+
+    /// 校验配置并编译为可执行的生成器
+    pub fn compile(&self) -> Result<CompiledGenerator, String> {
+        let compiled = match self {
+            ValueGenerator::Skip => return Err("跳过的列不生成值".to_string()),
+            ValueGenerator::Null => CompiledGenerator::Null,
+            ValueGenerator::Sequence { start, step } => CompiledGenerator::Sequence { next: *start, step: *step },
+            ValueGenerator::Int { min, max } => CompiledGenerator::Int { min: *min, max: *max },
+            ValueGenerator::Float { min, max, decimals } => {
+                if !min.is_finite() || !max.is_finite() {
+                    return Err("小数范围无效".to_string());
+                }
+                CompiledGenerator::Float { min: *min, max: *max, decimals: (*decimals).min(10) as usize }
+            }
+            ValueGenerator::Name => CompiledGenerator::Name,
+            ValueGenerator::Email => CompiledGenerator::Email,
+            ValueGenerator::Date { from, to, with_time } => {
+                let parse = |s: &str| {
+                    NaiveDate::parse_from_str(s.trim(), "%Y-%m-%d")
+                        .map_err(|_| format!("日期格式应为 YYYY-MM-DD: {}", s))
+                };
+                let (from, to) = (parse(from)?, parse(to)?);
+                if from > to {
+                    return Err("开始日期不能晚于结束日期".to_string());
+                }
+                CompiledGenerator::Date { from, days: (to - from).num_days(), with_time: *with_time }
+            }
+            ValueGenerator::Pattern(pattern) => CompiledGenerator::Pattern(parse_pattern(pattern)?),
+            ValueGenerator::List(list) => {
+                let items: Vec<String> = list
+                    .split(',')
+                    .map(|s| s.trim().to_string())
+                    .filter(|s| !s.is_empty())
+                    .collect();
+                if items.is_empty() {
+                    return Err("候选列表不能为空".to_string());
+                }
+                CompiledGenerator::List(items)
+            }
+        };
+        Ok(compiled)
+    }
+}
+
+// ============================================================================
+// 值生成
+// ============================================================================
+
+/// 生成的值
+#[derive(Debug, Clone, PartialEq)]
+pub enum GenValue {
+    /// NULL
+    Null,
+    /// 数值（SQL 中不加引号）
+    Number(String),
+    /// 文本（SQL 中作为字符串字面量）
+    Text(String),
+}
+
+impl GenValue {
+    /// 显示用文本
+    pub fn display(&self) -> &str {
+        match self {
+            GenValue::Null => "NULL",
+            GenValue::Number(s) | GenValue::Text(s) => s,
+        }
+    }
+
+    /// 转换为 SQL 字面量；MySQL 默认将反斜杠视为转义符
+    pub fn to_sql(&self, backslash_escapes: bool) -> String {
+        match self {
+            GenValue::Null => "NULL".to_string(),
+            GenValue::Number(s) => s.clone(),
+            GenValue::Text(s) => {
+                let escaped = s.replace('\'', "''");
+                if backslash_escapes {
+                    format!("'{}'", escaped.replace('\\', "\\\\"))
+                } else {
+                    format!("'{}'", escaped)
+                }
+            }
+        }
+    }
+}
+
+/// 已编译的列值生成器
+#[derive(Debug, Clone)]
+pub enum CompiledGenerator {
+    Null,
+    Sequence { next: i64, step: i64 },
+    Int { min: i64, max: i64 },
+    Float { min: f64, max: f64, decimals: usize },
+    Name,
+    Email,
+    Date { from: NaiveDate, days: i64, with_time: bool },
+    Pattern(Pattern),
+    List(Vec<String>),
+}
+
+impl CompiledGenerator {
+    /// 生成下一个值
+    pub fn generate(&mut self, rng: &mut Rng) -> GenValue {
+        match self {
+            CompiledGenerator::Null => GenValue::Null,
+            CompiledGenerator::Sequence { next, step } => {
+                let value = *next;
+                *next = next.wrapping_add(*step);
+                GenValue::Number(value.to_string())
+            }
+            CompiledGenerator::Int { min, max } => GenValue::Number(rng.range_i64(*min, *max).to_string()),
+            CompiledGenerator::Float { min, max, decimals } => {
+                let value = *min + (*max - *min) * rng.next_f64();
+                GenValue::Number(format!("{:.*}", *decimals, value))
+            }
+            CompiledGenerator::Name => {
+                GenValue::Text(format!("{} {}", rng.pick(FIRST_NAMES), rng.pick(LAST_NAMES)))
+            }
+            CompiledGenerator::Email => GenValue::Text(format!(
+                "{}.{}{}@{}",
+                rng.pick(FIRST_NAMES).to_lowercase(),
+                rng.pick(LAST_NAMES).to_lowercase(),
+                rng.range_i64(1, 999),
+                rng.pick(EMAIL_DOMAINS)
+            )),
+            CompiledGenerator::Date { from, days, with_time } => {
+                let date = *from + Duration::days(rng.range_i64(0, *days));
+                if *with_time {
+                    let secs = rng.range_i64(0, 86_399);
+                    GenValue::Text(format!(
+                        "{} {:02}:{:02}:{:02}",
+                        date.format("%Y-%m-%d"),
+                        secs / 3600,
+                        secs % 3600 / 60,
+                        secs % 60
+                    ))
+                } else {
+                    GenValue::Text(date.format("%Y-%m-%d").to_string())
+                }
+            }
+            CompiledGenerator::Pattern(pattern) => {
+                let mut out = String::new();
+                pattern.generate(rng, &mut out);
+                GenValue::Text(out)
+            }
+            CompiledGenerator::List(items) => {
+                let item = rng.pick(items);
+                if item.parse::<f64>().is_ok() {
+                    GenValue::Number(item.clone())
+                } else {
+                    GenValue::Text(item.clone())
+                }
+            }
+        }
+    }
+}
+
+/// 按行生成数据
+pub fn generate_rows(generators: &mut [CompiledGenerator], rng: &mut Rng, count: usize) -> Vec<Vec<GenValue>> {
+    (0..count)
+        .map(|_| generators.iter_mut().map(|g| g.generate(rng)).collect())
+        .collect()
+}
+
+// ============================================================================
+// 正则模式
+// ============================================================================
+
+/// 解析后的模式：多个候选分支，每个分支是一串带重复次数的单元
+#[derive(Debug, Clone)]
+pub struct Pattern(Vec<Vec<(PatternNode, u32, u32)>>);
+
+#[derive(Debug, Clone)]
+pub enum PatternNode {
+    /// 候选字符集合
+    Chars(Vec<char>),
+    /// 分组
+    Group(Pattern),
+}
+
+impl Pattern {
+    fn generate(&self, rng: &mut Rng, out: &mut String) {
+        let branch = rng.pick(&self.0);
+        for (node, min, max) in branch {
+            for _ in 0..rng.range_i64(*min as i64, *max as i64) {
+                match node {
+                    PatternNode::Chars(chars) => out.push(*rng.pick(chars)),
+                    PatternNode::Group(group) => group.generate(rng, out),
+                }
+            }
+        }
+    }
+}
+
+/// 解析正则模式
+pub fn parse_pattern(pattern: &str) -> Result<Pattern, String> {
+    let chars: Vec<char> = pattern.chars().collect();
+    let mut pos = 0;
+    let parsed = parse_alternation(&chars, &mut pos)?;
+    if pos < chars.len() {
+        return Err(format!("模式中有多余的 '{}'", chars[pos]));
+    }
+    Ok(parsed)
+}
+
+fn parse_alternation(chars: &[char], pos: &mut usize) -> Result<Pattern, String> {
+    let mut branches = vec![Vec::new()];
+    while *pos < chars.len() {
+        let c = chars[*pos];
+        match c {
+            ')' => break,
+            '|' => {
+                *pos += 1;
+                branches.push(Vec::new());
+                continue;
+            }
+            '^' | '$' => {
+                *pos += 1;
+                continue;
+            }
+            _ => {}
+        }
+        *pos += 1;
+        let node = match c {
+            '(' => {
+                // 忽略非捕获分组标记
+                if chars.get(*pos) == Some(&'?') && chars.get(*pos + 1) == Some(&':') {
+                    *pos += 2;
+                }
+                let group = parse_alternation(chars, pos)?;
+                if chars.get(*pos) != Some(&')') {
+                    return Err("缺少 ')'".to_string());
+                }
+                *pos += 1;
+                PatternNode::Group(group)
+            }
+            '[' => PatternNode::Chars(parse_class(chars, pos)?),
+            '\\' => {
+                let escaped = *chars.get(*pos).ok_or("模式不能以 '\\' 结尾")?;
+                *pos += 1;
+                PatternNode::Chars(escape_class(escaped))
+            }
+            '.' => PatternNode::Chars(('a'..='z').chain('A'..='Z').chain('0'..='9').collect()),
+            '*' | '+' | '?' | '{' => return Err(format!("'{}' 前缺少可重复的内容", c)),
+            c => PatternNode::Chars(vec![c]),
+        };
+        let (min, max) = parse_quantifier(chars, pos)?;
+        if let Some(branch) = branches.last_mut() {
+            branch.push((node, min, max));
+        }
+    }
+    Ok(Pattern(branches))
+}
+
+fn parse_class(chars: &[char], pos: &mut usize) -> Result<Vec<char>, String> {
+    let mut set = Vec::new();
+    if chars.get(*pos) == Some(&'^') {
+        return Err("不支持取反字符类 [^...]".to_string());
+    }
+    while let Some(&c) = chars.get(*pos) {
+        *pos += 1;
+        match c {
+            ']' if !set.is_empty() => return Ok(set),
+            '\\' => {
+                let escaped = *chars.get(*pos).ok_or("字符类未闭合")?;
+                *pos += 1;
+                set.extend(escape_class(escaped));
+            }
+            _ => {
+                if chars.get(*pos) == Some(&'-') && chars.get(*pos + 1).is_some_and(|&e| e != ']') {
+                    let end = chars[*pos + 1];
+                    *pos += 2;
+                    if end < c {
+                        return Err(format!("字符范围无效: {}-{}", c, end));
+                    }
+                    set.extend(c..=end);
+                } else {
+                    set.push(c);
+                }
+            }
+        }
+    }
+    Err("字符类未闭合，缺少 ']'".to_string())
+}
+
+fn escape_class(c: char) -> Vec<char> {
+    match c {
+        'd' => ('0'..='9').collect(),
+        'w' => ('a'..='z').chain('A'..='Z').chain('0'..='9').chain(['_']).collect(),
+        's' => vec![' '],
+        c => vec![c],
+    }
+}
+
+fn parse_quantifier(chars: &[char], pos: &mut usize) -> Result<(u32, u32), String> {
+    let quantifier = match chars.get(*pos) {
+        Some('?') => (0, 1),
+        Some('*') => (0, MAX_REPEAT),
+        Some('+') => (1, MAX_REPEAT),
+        Some('{') => {
+            let end = chars[*pos..]
+                .iter()
+                .position(|&c| c == '}')
+                .ok_or("量词未闭合，缺少 '}'")?;
+            let body: String = chars[*pos + 1..*pos + end].iter().collect();
+            *pos += end;
+            let parse = |s: &str| s.trim().parse::<u32>().map_err(|_| format!("量词无效: {{{}}}", body));
+            match body.split_once(',') {
+                Some((min, "")) => {
+                    let min = parse(min)?;
+                    (min, min + MAX_REPEAT)
+                }
+                Some((min, max)) => {
+                    let (min, max) = (parse(min)?, parse(max)?);
+                    if min > max {
+                        return Err(format!("量词无效: {{{}}}", body));
+                    }
+                    (min, max)
+                }
+                None => {
+                    let n = parse(&body)?;
+                    (n, n)
+                }
+            }
+        }
+        _ => return Ok((1, 1)),
+    };
+    *pos += 1;
+    Ok(quantifier)
+}
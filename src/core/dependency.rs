@@ -0,0 +1,181 @@
+//! 对象依赖关系
+//!
+//! 根据外键、视图定义和触发器计算一个表或视图依赖哪些对象、被哪些对象依赖，
+//! 供依赖关系图使用。视图和触发器对表的引用通过扫描定义中的标识符识别，
+//! 忽略字符串和注释，表名不区分大小写。
+
+use std::collections::HashSet;
+
+use crate::database::{ForeignKeyInfo, TriggerInfo, ViewInfo};
+
+/// 依赖关系图中的对象类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DependencyObjectKind {
+    Table,
+    View,
+    Trigger,
+}
+
+impl DependencyObjectKind {
+    /// 显示名称
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            DependencyObjectKind::Table => "表",
+            DependencyObjectKind::View => "视图",
+            DependencyObjectKind::Trigger => "触发器",
+        }
+    }
+}
+
+/// 与中心对象相连的一个对象
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DependencyLink {
+    /// 对象名
+    pub name: String,
+    pub kind: DependencyObjectKind,
+    /// 关系说明，例如外键列或触发器时机
+    pub detail: String,
+}
+
+/// 一个对象的依赖关系
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DependencyGraph {
+    /// 中心对象
+    pub object: String,
+    pub kind: DependencyObjectKind,
+    /// 中心对象依赖的对象（外键指向的表、视图引用的表和视图）
+    pub depends_on: Vec<DependencyLink>,
+    /// 依赖中心对象的对象（外键指向它的表、引用它的视图、建在它上面的触发器）
+    pub dependents: Vec<DependencyLink>,
+}
+
+impl DependencyGraph {
+    /// 计算 `object` 的依赖关系
+    ///
+    /// `tables` 为当前数据库的表列表，用于识别视图定义中引用的表
+    pub fn build(
+        object: &str,
+        tables: &[String],
+        foreign_keys: &[ForeignKeyInfo],
+        views: &[ViewInfo],
+        triggers: &[TriggerInfo],
+    ) -> Self {
+        let kind = if views.iter().any(|v| v.name.eq_ignore_ascii_case(object)) {
+            DependencyObjectKind::View
+        } else {
+            DependencyObjectKind::Table
+        };
+        let mut graph = Self { object: object.to_string(), kind, depends_on: Vec::new(), dependents: Vec::new() };
+
+        // 外键：多列外键按表合并为一条
+        for fk in foreign_keys {
+            let detail = format!("外键 {} → {}", fk.from_column, fk.to_column);
+            if fk.from_table.eq_ignore_ascii_case(object) && !fk.to_table.eq_ignore_ascii_case(object) {
+                push_link(&mut graph.depends_on, &fk.to_table, DependencyObjectKind::Table, detail);
+            } else if fk.to_table.eq_ignore_ascii_case(object) && !fk.from_table.eq_ignore_ascii_case(object) {
+                push_link(&mut graph.dependents, &fk.from_table, DependencyObjectKind::Table, detail);
+            }
+        }
+
+        // 视图：引用中心对象的视图，以及中心对象（视图）引用的表和视图
+        for view in views {
+            let identifiers = sql_identifiers(&view.definition);
+            if view.name.eq_ignore_ascii_case(object) {
+                for table in tables {
+                    if identifiers.contains(&table.to_lowercase()) {
+                        push_link(&mut graph.depends_on, table, DependencyObjectKind::Table, "视图引用".to_string());
+                    }
+                }
+                for other in views.iter().filter(|v| !v.name.eq_ignore_ascii_case(object)) {
+                    if identifiers.contains(&other.name.to_lowercase()) {
+                        push_link(&mut graph.depends_on, &other.name, DependencyObjectKind::View, "视图引用".to_string());
+                    }
+                }
+            } else if identifiers.contains(&object.to_lowercase()) {
+                push_link(&mut graph.dependents, &view.name, DependencyObjectKind::View, "引用该对象".to_string());
+            }
+        }
+
+        // 触发器：建在中心对象上的，以及触发器体中引用中心对象的
+        for trigger in triggers {
+            let detail = if trigger.table_name.eq_ignore_ascii_case(object) {
+                format!("{} {}", trigger.timing, trigger.event)
+            } else if sql_identifiers(&trigger.definition).contains(&object.to_lowercase()) {
+                format!("{} 上的触发器引用该对象", trigger.table_name)
+            } else {
+                continue;
+            };
+            push_link(&mut graph.dependents, &trigger.name, DependencyObjectKind::Trigger, detail);
+        }
+
+        graph
+    }
+
+    /// 是否没有任何依赖关系
+    pub fn is_empty(&self) -> bool {
+        self.depends_on.is_empty() && self.dependents.is_empty()
+    }
+}
+
+/// 添加关系，同一对象的多条关系合并说明
+fn push_link(links: &mut Vec<DependencyLink>, name: &str, kind: DependencyObjectKind, detail: String) {
+    if let Some(link) = links.iter_mut().find(|l| l.kind == kind && l.name == name) {
+        if !link.detail.split(", ").any(|d| d == detail) {
+            link.detail.push_str(", ");
+            link.detail.push_str(&detail);
+        }
+        return;
+    }
+    links.push(DependencyLink { name: name.to_string(), kind, detail });
+}
+
+/// SQL 中出现的标识符（小写，去掉引号，`schema.table` 拆成两个），跳过字符串和注释
+pub fn sql_identifiers(sql: &str) -> HashSet<String> {
+    let mut identifiers = HashSet::new();
+    let mut chars = sql.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => {
+                // 字符串，'' 为转义的单引号
+                while let Some(c) = chars.next() {
+                    if c == '\'' && chars.next_if_eq(&'\'').is_none() {
+                        break;
+                    }
+                }
+            }
+            '-' if chars.peek() == Some(&'-') => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut prev = ' ';
+                for c in chars.by_ref() {
+                    if prev == '*' && c == '/' {
+                        break;
+                    }
+                    prev = c;
+                }
+            }
+            '"' | '`' | '[' => {
+                let close = if c == '[' { ']' } else { c };
+                let name: String = chars.by_ref().take_while(|&c| c != close).collect();
+                if !name.is_empty() {
+                    identifiers.insert(name.to_lowercase());
+                }
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let mut word = c.to_string();
+                while let Some(c) = chars.next_if(|c| c.is_alphanumeric() || *c == '_' || *c == '$') {
+                    word.push(c);
+                }
+                identifiers.insert(word.to_lowercase());
+            }
+            _ => {}
+        }
+    }
+    identifiers
+}
@@ -0,0 +1,96 @@
+//! 桌面通知
+//!
+//! 通过系统自带的命令发送桌面通知：Linux 使用 `notify-send`，macOS 使用 `osascript`，
+//! Windows 使用 PowerShell 显示托盘气泡。标题和正文作为参数或环境变量传入，不拼接到脚本中。
+
+use std::io;
+use std::process::{Command, Stdio};
+
+/// 正文中 SQL 预览的最大长度
+const SQL_PREVIEW_CHARS: usize = 80;
+
+/// 长时间查询完成时的通知标题和正文
+///
+/// `outcome` 为返回的行数（或影响的行数）或错误信息
+pub fn query_notification_text(
+    tab: Option<&str>,
+    sql: &str,
+    outcome: Result<usize, &str>,
+    elapsed_ms: u64,
+) -> (String, String) {
+    let elapsed = format!("{:.1} s", elapsed_ms as f64 / 1000.0);
+    let title = match (&outcome, tab) {
+        (Ok(_), Some(tab)) => format!("Gridix: 查询完成（{}）", tab),
+        (Ok(_), None) => "Gridix: 查询完成".to_string(),
+        (Err(_), Some(tab)) => format!("Gridix: 查询失败（{}）", tab),
+        (Err(_), None) => "Gridix: 查询失败".to_string(),
+    };
+    let summary = match outcome {
+        Ok(rows) => format!("{} 行，用时 {}", rows, elapsed),
+        Err(error) => format!("用时 {}：{}", elapsed, error.lines().next().unwrap_or_default()),
+    };
+
+    let sql: String = sql.split_whitespace().collect::<Vec<_>>().join(" ");
+    let preview = if sql.chars().count() > SQL_PREVIEW_CHARS {
+        format!("{}…", sql.chars().take(SQL_PREVIEW_CHARS).collect::<String>())
+    } else {
+        sql
+    };
+    (title, format!("{}\n{}", summary, preview))
+}
+
+/// 发送桌面通知（不等待通知命令结束）
+pub fn send_desktop_notification(title: &str, body: &str) -> io::Result<()> {
+    let mut command = notification_command(title, body)?;
+    let mut child = command.stdin(Stdio::null()).stdout(Stdio::null()).stderr(Stdio::null()).spawn()?;
+    // 在后台回收子进程
+    std::thread::spawn(move || {
+        let _ = child.wait();
+    });
+    Ok(())
+}
+
+#[cfg(target_os = "linux")]
+fn notification_command(title: &str, body: &str) -> io::Result<Command> {
+    let mut command = Command::new("notify-send");
+    command.args(["--app-name=Gridix", "--", title, body]);
+    Ok(command)
+}
+
+#[cfg(target_os = "macos")]
+fn notification_command(title: &str, body: &str) -> io::Result<Command> {
+    let mut command = Command::new("osascript");
+    command.args([
+        "-e",
+        "on run argv",
+        "-e",
+        "display notification (item 2 of argv) with title (item 1 of argv)",
+        "-e",
+        "end run",
+        title,
+        body,
+    ]);
+    Ok(command)
+}
+
+#[cfg(target_os = "windows")]
+fn notification_command(title: &str, body: &str) -> io::Result<Command> {
+    const SCRIPT: &str = "Add-Type -AssemblyName System.Windows.Forms; \
+        $n = New-Object System.Windows.Forms.NotifyIcon; \
+        $n.Icon = [System.Drawing.SystemIcons]::Information; \
+        $n.Visible = $true; \
+        $n.ShowBalloonTip(10000, $env:GRIDIX_NOTIFY_TITLE, $env:GRIDIX_NOTIFY_BODY, 'Info'); \
+        Start-Sleep -Seconds 10; \
+        $n.Dispose()";
+    let mut command = Command::new("powershell");
+    command
+        .args(["-NoProfile", "-NonInteractive", "-WindowStyle", "Hidden", "-Command", SCRIPT])
+        .env("GRIDIX_NOTIFY_TITLE", title)
+        .env("GRIDIX_NOTIFY_BODY", body);
+    Ok(command)
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+fn notification_command(_title: &str, _body: &str) -> io::Result<Command> {
+    Err(io::Error::new(io::ErrorKind::Unsupported, "当前系统不支持桌面通知"))
+}
@@ -0,0 +1,334 @@
+//! SQL 编辑器的文本操作
+//!
+//! 括号匹配、行注释切换和多光标列编辑。与 UI 无关，所有位置均为字节偏移。
+
+/// 行注释前缀
+const COMMENT_PREFIX: &str = "--";
+
+/// 查找与光标相邻括号配对的括号
+///
+/// 优先检查光标右侧的字符，其次检查左侧字符。返回（括号位置，配对括号位置），
+/// 找不到配对时返回 `None`。引号内的括号不参与匹配。
+pub fn find_matching_bracket(text: &str, cursor: usize) -> Option<(usize, usize)> {
+    let cursor = floor_char_boundary(text, cursor);
+    let after = text[cursor..].chars().next().map(|c| (cursor, c));
+    let before = text[..cursor].char_indices().next_back();
+
+    [after, before]
+        .into_iter()
+        .flatten()
+        .filter(|&(pos, _)| !in_quotes(text, pos))
+        .find_map(|(pos, c)| match_from(text, pos, c).map(|other| (pos, other)))
+}
+
+/// 从给定括号开始向前或向后扫描，返回配对括号的位置
+fn match_from(text: &str, pos: usize, bracket: char) -> Option<usize> {
+    let (open, close, forward) = match bracket {
+        '(' => ('(', ')', true),
+        '[' => ('[', ']', true),
+        '{' => ('{', '}', true),
+        ')' => ('(', ')', false),
+        ']' => ('[', ']', false),
+        '}' => ('{', '}', false),
+        _ => return None,
+    };
+
+    let quoted = quoted_mask(text);
+    let mut depth = 0usize;
+    let mut step = |i: usize, c: char| -> Option<usize> {
+        if quoted[i] {
+            return None;
+        }
+        if c == open || c == close {
+            let opening = (c == open) == forward;
+            if opening {
+                depth += 1;
+            } else {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+        }
+        None
+    };
+
+    if forward {
+        text[pos..].char_indices().find_map(|(i, c)| step(pos + i, c))
+    } else {
+        text[..pos + bracket.len_utf8()]
+            .char_indices()
+            .rev()
+            .find_map(|(i, c)| step(i, c))
+    }
+}
+
+/// 每个字节是否位于单引号或双引号字符串内（引号本身也算在内）
+fn quoted_mask(text: &str) -> Vec<bool> {
+    let mut mask = vec![false; text.len()];
+    let mut quote: Option<char> = None;
+    for (i, c) in text.char_indices() {
+        match quote {
+            Some(q) => {
+                mask[i..i + c.len_utf8()].fill(true);
+                if c == q {
+                    quote = None;
+                }
+            }
+            None if c == '\'' || c == '"' || c == '`' => {
+                mask[i] = true;
+                quote = Some(c);
+            }
+            None => {}
+        }
+    }
+    mask
+}
+
+fn in_quotes(text: &str, pos: usize) -> bool {
+    quoted_mask(text).get(pos).copied().unwrap_or(false)
+}
+
+/// 切换选区覆盖的各行的 `--` 行注释
+///
+/// 选区内所有非空行都已注释时取消注释，否则为每个非空行添加注释，
+/// 注释符插入在最小缩进处以保持对齐。返回调整后的选区。
+pub fn toggle_line_comment(text: &mut String, start: usize, end: usize) -> (usize, usize) {
+    let (start, end) = (start.min(end), start.max(end));
+    let start = floor_char_boundary(text, start);
+    let end = floor_char_boundary(text, end);
+
+    let first_line = text[..start].rfind('\n').map(|i| i + 1).unwrap_or(0);
+    // 选区结束于行首时不包含该行
+    let last_end = if end > start && text[..end].ends_with('\n') { end - 1 } else { end };
+    let block_end = text[last_end..].find('\n').map(|i| last_end + i).unwrap_or(text.len());
+
+    let block = &text[first_line..block_end];
+    let lines: Vec<&str> = block.split('\n').collect();
+    let non_empty: Vec<&&str> = lines.iter().filter(|l| !l.trim().is_empty()).collect();
+    if non_empty.is_empty() {
+        return (start, end);
+    }
+
+    let uncomment = non_empty.iter().all(|l| l.trim_start().starts_with(COMMENT_PREFIX));
+    let indent = non_empty
+        .iter()
+        .map(|l| l.len() - l.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    // 记录每行的长度变化，用于调整选区
+    let mut new_lines = Vec::with_capacity(lines.len());
+    let mut deltas = Vec::with_capacity(lines.len());
+    for line in &lines {
+        if line.trim().is_empty() {
+            new_lines.push(line.to_string());
+            deltas.push((0usize, 0isize));
+        } else if uncomment {
+            let at = line.len() - line.trim_start().len();
+            let rest = &line[at + COMMENT_PREFIX.len()..];
+            let removed = COMMENT_PREFIX.len() + usize::from(rest.starts_with(' '));
+            new_lines.push(format!("{}{}", &line[..at], &line[at + removed..]));
+            deltas.push((at, -(removed as isize)));
+        } else {
+            new_lines.push(format!("{}{} {}", &line[..indent], COMMENT_PREFIX, &line[indent..]));
+            deltas.push((indent, (COMMENT_PREFIX.len() + 1) as isize));
+        }
+    }
+
+    let adjust = |pos: usize| -> usize {
+        let mut line_start = first_line;
+        let mut shift = 0isize;
+        for (line, &(at, delta)) in lines.iter().zip(&deltas) {
+            let line_end = line_start + line.len();
+            if pos <= line_end {
+                let offset = pos - line_start;
+                if offset > at {
+                    shift += delta.max(-((offset - at) as isize));
+                }
+                break;
+            }
+            shift += delta;
+            line_start = line_end + 1;
+        }
+        (pos as isize + shift).max(0) as usize
+    };
+    let selection = (adjust(start), adjust(end));
+
+    text.replace_range(first_line..block_end, &new_lines.join("\n"));
+    selection
+}
+
+/// 多光标（列编辑）状态
+///
+/// 光标按位置升序保存。第一个光标由用户放置，其余通过「在上/下一行添加光标」产生，
+/// 保持在相同的字符列上，便于同时编辑多行 INSERT 的 VALUES。
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MultiCursor {
+    cursors: Vec<usize>,
+}
+
+impl MultiCursor {
+    /// 是否处于多光标编辑（两个及以上光标）
+    pub fn is_active(&self) -> bool {
+        self.cursors.len() > 1
+    }
+
+    /// 当前所有光标位置（升序）
+    pub fn cursors(&self) -> &[usize] {
+        &self.cursors
+    }
+
+    /// 退出多光标编辑
+    pub fn clear(&mut self) {
+        self.cursors.clear();
+    }
+
+    /// 在最下方光标的下一行同一列添加光标，返回是否添加成功
+    pub fn add_below(&mut self, text: &str, cursor: usize) -> bool {
+        self.ensure_started(text, cursor);
+        let last = *self.cursors.last().unwrap_or(&0);
+        let column = char_column(text, last);
+        let Some(next_line) = text[last..].find('\n').map(|i| last + i + 1) else {
+            return false;
+        };
+        self.push(at_column(text, next_line, column));
+        true
+    }
+
+    /// 在最上方光标的上一行同一列添加光标，返回是否添加成功
+    pub fn add_above(&mut self, text: &str, cursor: usize) -> bool {
+        self.ensure_started(text, cursor);
+        let first = *self.cursors.first().unwrap_or(&0);
+        let column = char_column(text, first);
+        let line_start = line_start(text, first);
+        if line_start == 0 {
+            return false;
+        }
+        let prev_line = self::line_start(text, line_start - 1);
+        self.push(at_column(text, prev_line, column));
+        true
+    }
+
+    /// 在每个光标处插入文本
+    pub fn insert(&mut self, text: &mut String, s: &str) {
+        if s.contains('\n') {
+            return;
+        }
+        for &pos in self.cursors.iter().rev() {
+            text.insert_str(pos, s);
+        }
+        for (i, pos) in self.cursors.iter_mut().enumerate() {
+            *pos += s.len() * (i + 1);
+        }
+    }
+
+    /// 删除每个光标前的一个字符（不跨行）
+    pub fn backspace(&mut self, text: &mut String) {
+        self.remove_each(text, |text, pos| {
+            let (i, c) = text[..pos].char_indices().next_back()?;
+            (c != '\n').then_some((i, pos))
+        });
+    }
+
+    /// 删除每个光标后的一个字符（不跨行）
+    pub fn delete(&mut self, text: &mut String) {
+        self.remove_each(text, |text, pos| {
+            let c = text[pos..].chars().next()?;
+            (c != '\n').then_some((pos, pos + c.len_utf8()))
+        });
+    }
+
+    /// 所有光标左移一个字符（不跨行）
+    pub fn move_left(&mut self, text: &str) {
+        for pos in &mut self.cursors {
+            if let Some((i, c)) = text[..*pos].char_indices().next_back()
+                && c != '\n'
+            {
+                *pos = i;
+            }
+        }
+    }
+
+    /// 所有光标右移一个字符（不跨行）
+    pub fn move_right(&mut self, text: &str) {
+        for pos in &mut self.cursors {
+            if let Some(c) = text[*pos..].chars().next()
+                && c != '\n'
+            {
+                *pos += c.len_utf8();
+            }
+        }
+    }
+
+    /// 所有光标移到各自行首 / 行尾
+    pub fn move_line_edge(&mut self, text: &str, to_end: bool) {
+        for pos in &mut self.cursors {
+            *pos = if to_end {
+                text[*pos..].find('\n').map(|i| *pos + i).unwrap_or(text.len())
+            } else {
+                line_start(text, *pos)
+            };
+        }
+    }
+
+    fn ensure_started(&mut self, text: &str, cursor: usize) {
+        if self.cursors.is_empty() {
+            self.cursors.push(floor_char_boundary(text, cursor));
+        }
+    }
+
+    fn push(&mut self, pos: usize) {
+        if let Err(i) = self.cursors.binary_search(&pos) {
+            self.cursors.insert(i, pos);
+        }
+    }
+
+    /// 对每个光标删除一段范围（由 `range` 计算），并同步调整后续光标位置
+    fn remove_each(&mut self, text: &mut String, range: impl Fn(&str, usize) -> Option<(usize, usize)>) {
+        let ranges: Vec<Option<(usize, usize)>> = self.cursors.iter().map(|&pos| range(text, pos)).collect();
+        for r in ranges.iter().rev().flatten() {
+            text.replace_range(r.0..r.1, "");
+        }
+        let mut removed = 0;
+        for (pos, r) in self.cursors.iter_mut().zip(&ranges) {
+            match r {
+                Some((start, end)) => {
+                    *pos = start - removed;
+                    removed += end - start;
+                }
+                None => *pos -= removed,
+            }
+        }
+        self.cursors.dedup();
+    }
+}
+
+/// 位置所在行的起始字节偏移
+fn line_start(text: &str, pos: usize) -> usize {
+    text[..pos].rfind('\n').map(|i| i + 1).unwrap_or(0)
+}
+
+/// 位置在所在行中的字符列（从 0 开始）
+fn char_column(text: &str, pos: usize) -> usize {
+    text[line_start(text, pos)..pos].chars().count()
+}
+
+/// 从行首开始第 `column` 个字符的字节偏移，行较短时取行尾
+fn at_column(text: &str, line_start: usize, column: usize) -> usize {
+    let line = &text[line_start..];
+    let line_len = line.find('\n').unwrap_or(line.len());
+    line[..line_len]
+        .char_indices()
+        .nth(column)
+        .map(|(i, _)| line_start + i)
+        .unwrap_or(line_start + line_len)
+}
+
+fn floor_char_boundary(text: &str, pos: usize) -> usize {
+    let mut pos = pos.min(text.len());
+    while !text.is_char_boundary(pos) {
+        pos -= 1;
+    }
+    pos
+}
@@ -2,7 +2,9 @@
 //!
 //! 支持 CSV、SQL、JSON 格式的数据导入导出。
 
+use super::upsert::{insert_row_sql, Upsert};
 use crate::database::QueryResult;
+use serde::{Deserialize, Serialize};
 use std::fs::File;
 use std::io::{BufRead, BufReader, Write};
 use std::path::Path;
@@ -11,7 +13,8 @@ use std::path::Path;
 // 导出格式
 // ============================================================================
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum ExportFormat {
     Csv,
     Sql,
@@ -34,6 +37,16 @@ impl ExportFormat {
             ExportFormat::Json => "JSON",
         }
     }
+
+    /// 根据格式名或文件扩展名（不区分大小写）解析格式
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "csv" => Some(ExportFormat::Csv),
+            "sql" => Some(ExportFormat::Sql),
+            "json" => Some(ExportFormat::Json),
+            _ => None,
+        }
+    }
 }
 
 // ============================================================================
@@ -95,6 +108,8 @@ pub struct CsvImportConfig {
     pub table_name: String,
     /// 自定义列名 (如果 has_header = false)
     pub column_names: Vec<String>,
+    /// 主键已存在时更新（冲突键列为未加引号的列名），`None` 时生成普通 INSERT
+    pub upsert: Option<Upsert>,
 }
 
 impl Default for CsvImportConfig {
@@ -107,6 +122,7 @@ impl Default for CsvImportConfig {
             max_rows: 0,
             table_name: String::new(),
             column_names: Vec::new(),
+            upsert: None,
         }
     }
 }
@@ -120,6 +136,8 @@ pub struct JsonImportConfig {
     pub max_rows: usize,
     /// JSON 路径 (如 "data.items" 表示从 data.items 开始读取)
     pub json_path: Option<String>,
+    /// 主键已存在时更新（冲突键列为未加引号的列名），`None` 时生成普通 INSERT
+    pub upsert: Option<Upsert>,
 }
 
 // ============================================================================
@@ -161,6 +179,12 @@ pub struct ImportResult {
 #[allow(dead_code)] // 公开 API，供外部使用
 pub fn export_to_csv(result: &QueryResult, path: &Path) -> Result<(), String> {
     let mut file = File::create(path).map_err(|e| e.to_string())?;
+    write_csv(result, &mut file)
+}
+
+/// 将查询结果以 CSV 格式写入
+#[allow(dead_code)] // 公开 API，供外部使用
+pub fn write_csv(result: &QueryResult, out: &mut dyn Write) -> Result<(), String> {
 
     // 写入列头
     let header = result
@@ -169,7 +193,7 @@ pub fn export_to_csv(result: &QueryResult, path: &Path) -> Result<(), String> {
         .map(|c| escape_csv_field(c))
         .collect::<Vec<_>>()
         .join(",");
-    writeln!(file, "{}", header).map_err(|e| e.to_string())?;
+    writeln!(out, "{}", header).map_err(|e| e.to_string())?;
 
     // 写入数据行
     for row in &result.rows {
@@ -178,7 +202,7 @@ pub fn export_to_csv(result: &QueryResult, path: &Path) -> Result<(), String> {
             .map(|cell| escape_csv_field(cell))
             .collect::<Vec<_>>()
             .join(",");
-        writeln!(file, "{}", line).map_err(|e| e.to_string())?;
+        writeln!(out, "{}", line).map_err(|e| e.to_string())?;
     }
 
     Ok(())
@@ -188,15 +212,21 @@ pub fn export_to_csv(result: &QueryResult, path: &Path) -> Result<(), String> {
 #[allow(dead_code)] // 公开 API，供外部使用
 pub fn export_to_sql(result: &QueryResult, table_name: &str, path: &Path) -> Result<(), String> {
     let mut file = File::create(path).map_err(|e| e.to_string())?;
+    write_sql(result, table_name, &mut file)
+}
+
+/// 将查询结果以 SQL INSERT 语句写入
+#[allow(dead_code)] // 公开 API，供外部使用
+pub fn write_sql(result: &QueryResult, table_name: &str, out: &mut dyn Write) -> Result<(), String> {
 
     let escaped_table_name = escape_sql_identifier(table_name);
     
-    writeln!(file, "-- Exported from Rust DB Manager").map_err(|e| e.to_string())?;
-    writeln!(file, "-- Table: {}", table_name).map_err(|e| e.to_string())?;
-    writeln!(file, "-- Rows: {}\n", result.rows.len()).map_err(|e| e.to_string())?;
+    writeln!(out, "-- Exported from Rust DB Manager").map_err(|e| e.to_string())?;
+    writeln!(out, "-- Table: {}", table_name).map_err(|e| e.to_string())?;
+    writeln!(out, "-- Rows: {}\n", result.rows.len()).map_err(|e| e.to_string())?;
 
     if result.columns.is_empty() || result.rows.is_empty() {
-        writeln!(file, "-- No data to export").map_err(|e| e.to_string())?;
+        writeln!(out, "-- No data to export").map_err(|e| e.to_string())?;
         return Ok(());
     }
 
@@ -221,7 +251,7 @@ pub fn export_to_sql(result: &QueryResult, table_name: &str, path: &Path) -> Res
             .join(", ");
 
         writeln!(
-            file,
+            out,
             "INSERT INTO `{}` ({}) VALUES ({});",
             escaped_table_name, columns_str, values
         )
@@ -235,6 +265,12 @@ pub fn export_to_sql(result: &QueryResult, table_name: &str, path: &Path) -> Res
 #[allow(dead_code)] // 公开 API，供外部使用
 pub fn export_to_json(result: &QueryResult, path: &Path) -> Result<(), String> {
     let mut file = File::create(path).map_err(|e| e.to_string())?;
+    write_json(result, &mut file)
+}
+
+/// 将查询结果以 JSON 格式写入
+#[allow(dead_code)] // 公开 API，供外部使用
+pub fn write_json(result: &QueryResult, out: &mut dyn Write) -> Result<(), String> {
 
     let json_rows: Vec<serde_json::Map<String, serde_json::Value>> = result
         .rows
@@ -261,7 +297,7 @@ pub fn export_to_json(result: &QueryResult, path: &Path) -> Result<(), String> {
         .collect();
 
     let json = serde_json::to_string_pretty(&json_rows).map_err(|e| e.to_string())?;
-    write!(file, "{}", json).map_err(|e| e.to_string())?;
+    write!(out, "{}", json).map_err(|e| e.to_string())?;
 
     Ok(())
 }
@@ -385,18 +421,10 @@ pub fn import_csv_to_sql(
     
     // 生成列名部分
     let quote_char = if use_mysql_syntax { '`' } else { '"' };
-    let columns_str = columns
-        .iter()
-        .map(|c| format!("{}{}{}", quote_char, escape_sql_identifier(c), quote_char))
-        .collect::<Vec<_>>()
-        .join(", ");
-    
-    let table_name = format!(
-        "{}{}{}",
-        quote_char,
-        escape_sql_identifier(&config.table_name),
-        quote_char
-    );
+    let quote = |name: &str| format!("{}{}{}", quote_char, escape_sql_identifier(name), quote_char);
+    let quoted_columns = columns.iter().map(|c| quote(c)).collect::<Vec<_>>();
+    let table_name = quote(&config.table_name);
+    let upsert = quoted_upsert(config.upsert.as_ref(), quote);
     
     // 处理数据行
     for (idx, line_result) in lines.enumerate() {
@@ -436,10 +464,7 @@ pub fn import_csv_to_sql(
             .collect::<Vec<_>>()
             .join(", ");
         
-        sql_statements.push(format!(
-            "INSERT INTO {} ({}) VALUES ({});",
-            table_name, columns_str, values
-        ));
+        sql_statements.push(insert_row_sql(&table_name, &quoted_columns, &values, upsert.as_ref()));
         
         rows_imported += 1;
     }
@@ -452,6 +477,14 @@ pub fn import_csv_to_sql(
     })
 }
 
+/// 按导入语句的引号方式引用 upsert 的冲突键列
+fn quoted_upsert(upsert: Option<&Upsert>, quote: impl Fn(&str) -> String) -> Option<Upsert> {
+    upsert.map(|u| Upsert {
+        syntax: u.syntax,
+        key_columns: u.key_columns.iter().map(|c| quote(c)).collect(),
+    })
+}
+
 /// 解析 CSV 行
 /// 解析 CSV 行，处理引号转义
 pub fn parse_csv_line(line: &str, delimiter: char, quote_char: char) -> Vec<String> {
@@ -590,18 +623,10 @@ pub fn import_json_to_sql(
     };
     
     let quote_char = if use_mysql_syntax { '`' } else { '"' };
-    let columns_str = columns
-        .iter()
-        .map(|c| format!("{}{}{}", quote_char, escape_sql_identifier(c), quote_char))
-        .collect::<Vec<_>>()
-        .join(", ");
-    
-    let table_name = format!(
-        "{}{}{}",
-        quote_char,
-        escape_sql_identifier(&config.table_name),
-        quote_char
-    );
+    let quote = |name: &str| format!("{}{}{}", quote_char, escape_sql_identifier(name), quote_char);
+    let quoted_columns = columns.iter().map(|c| quote(c)).collect::<Vec<_>>();
+    let table_name = quote(&config.table_name);
+    let upsert = quoted_upsert(config.upsert.as_ref(), quote);
     
     for (idx, item) in array.iter().enumerate() {
         if config.max_rows > 0 && rows_imported >= config.max_rows {
@@ -625,10 +650,7 @@ pub fn import_json_to_sql(
             continue;
         }
         
-        sql_statements.push(format!(
-            "INSERT INTO {} ({}) VALUES ({});",
-            table_name, columns_str, values
-        ));
+        sql_statements.push(insert_row_sql(&table_name, &quoted_columns, &values, upsert.as_ref()));
         
         rows_imported += 1;
     }
@@ -0,0 +1,36 @@
+//! 拖放到窗口的文件
+//!
+//! 按扩展名决定拖入文件的处理方式：SQLite 数据库建立连接、.sql 在标签页中打开、
+//! CSV/JSON 启动导入向导。
+
+use std::path::Path;
+
+/// 拖入文件的处理方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DroppedFileKind {
+    /// SQLite 数据库文件，新建并连接 SQLite 连接
+    SqliteDatabase,
+    /// SQL 脚本，在新标签页中打开
+    SqlScript,
+    /// CSV / JSON 数据，导入到当前数据库
+    ImportData,
+    /// 不支持的文件
+    Unsupported,
+}
+
+impl DroppedFileKind {
+    /// 根据扩展名（不区分大小写）判断处理方式
+    pub fn from_path(path: &Path) -> Self {
+        let ext = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(|e| e.to_ascii_lowercase())
+            .unwrap_or_default();
+        match ext.as_str() {
+            "db" | "sqlite" | "sqlite3" => Self::SqliteDatabase,
+            "sql" => Self::SqlScript,
+            "csv" | "tsv" | "json" => Self::ImportData,
+            _ => Self::Unsupported,
+        }
+    }
+}
@@ -0,0 +1,118 @@
+//! 筛选预设
+//!
+//! 按表保存命名的筛选条件组合（随配置持久化），在筛选面板中一键应用。
+//! 操作符以稳定的标识符保存，与界面中的筛选条件类型互相转换。
+
+use serde::{Deserialize, Serialize};
+
+fn default_true() -> bool {
+    true
+}
+
+/// 保存的单个筛选条件
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedFilter {
+    /// 列名
+    pub column: String,
+    /// 操作符标识符（如 `contains`、`between`）
+    pub operator: String,
+    /// 筛选值
+    #[serde(default)]
+    pub value: String,
+    /// 第二个值（BETWEEN）
+    #[serde(default)]
+    pub value2: String,
+    /// 是否启用
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// 是否区分大小写
+    #[serde(default)]
+    pub case_sensitive: bool,
+    /// 与下一个条件是否为 OR 关系（否则为 AND）
+    #[serde(default)]
+    pub or_next: bool,
+}
+
+/// 命名的筛选预设
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FilterPreset {
+    /// 预设名称
+    pub name: String,
+    /// 筛选条件
+    #[serde(default)]
+    pub filters: Vec<SavedFilter>,
+}
+
+/// 一张表的预设
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TablePresets {
+    connection: String,
+    #[serde(default)]
+    database: Option<String>,
+    table: String,
+    #[serde(default)]
+    presets: Vec<FilterPreset>,
+}
+
+impl TablePresets {
+    fn is(&self, connection: &str, database: Option<&str>, table: &str) -> bool {
+        self.connection == connection && self.database.as_deref() == database && self.table == table
+    }
+}
+
+/// 所有表的筛选预设
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FilterPresets {
+    #[serde(default)]
+    tables: Vec<TablePresets>,
+}
+
+impl FilterPresets {
+    /// 某张表的预设（按保存顺序）
+    pub fn for_table(&self, connection: &str, database: Option<&str>, table: &str) -> &[FilterPreset] {
+        self.tables
+            .iter()
+            .find(|t| t.is(connection, database, table))
+            .map(|t| t.presets.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// 保存预设，同名预设会被覆盖
+    pub fn save(&mut self, connection: &str, database: Option<&str>, table: &str, preset: FilterPreset) {
+        let idx = match self.tables.iter().position(|t| t.is(connection, database, table)) {
+            Some(idx) => idx,
+            None => {
+                self.tables.push(TablePresets {
+                    connection: connection.to_string(),
+                    database: database.map(str::to_string),
+                    table: table.to_string(),
+                    presets: Vec::new(),
+                });
+                self.tables.len() - 1
+            }
+        };
+        let presets = &mut self.tables[idx].presets;
+        match presets.iter_mut().find(|p| p.name == preset.name) {
+            Some(existing) => *existing = preset,
+            None => presets.push(preset),
+        }
+    }
+
+    /// 删除某张表的一个预设
+    pub fn remove(&mut self, connection: &str, database: Option<&str>, table: &str, name: &str) {
+        if let Some(entry) = self.tables.iter_mut().find(|t| t.is(connection, database, table)) {
+            entry.presets.retain(|p| p.name != name);
+        }
+        self.tables.retain(|t| !t.presets.is_empty());
+    }
+
+    /// 删除某张表的所有预设（表已不存在时）
+    pub fn remove_table(&mut self, connection: &str, database: Option<&str>, table: &str) {
+        self.tables.retain(|t| !t.is(connection, database, table));
+    }
+
+    /// 删除某个连接的所有预设
+    pub fn remove_connection(&mut self, connection: &str) {
+        self.tables.retain(|t| t.connection != connection);
+    }
+}
@@ -0,0 +1,302 @@
+//! 字体设置
+//!
+//! 分别配置界面、SQL 编辑器和数据表格使用的字体（系统字体或内置字体）及字号。
+//! 编辑器和表格使用独立的命名字体族和文本样式，通过 `FontDefinitions` 统一应用。
+
+use super::constants;
+use egui::{FontData, FontDefinitions, FontFamily, FontId, TextStyle};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// 内嵌的 Noto Sans SC 字体（思源黑体，支持完整 Unicode）
+const EMBEDDED_NOTO_SANS_SC: &[u8] = include_bytes!("../../assets/fonts/NotoSansSC-Regular.ttf");
+
+/// 内嵌的 Noto Emoji 字体（支持 Unicode Emoji）
+const EMBEDDED_NOTO_EMOJI: &[u8] = include_bytes!("../../assets/fonts/NotoEmoji-Regular.ttf");
+
+/// SQL 编辑器字体族 / 文本样式名称
+pub const EDITOR_FONT: &str = "editor";
+/// 数据表格字体族 / 文本样式名称
+pub const GRID_FONT: &str = "grid";
+
+/// egui 默认正文字号，界面字号按它的比例缩放其他文本样式
+const DEFAULT_BODY_SIZE: f32 = 12.5;
+
+/// 识别为等宽字体的名称关键字
+const MONOSPACE_HINTS: &[&str] = &[
+    "mono", "code", "consol", "courier", "menlo", "hack", "fixed", "terminal", "iosevka", "jetbrains",
+];
+
+// ============================================================================
+// 配置
+// ============================================================================
+
+/// 单个界面元素的字体选择
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FontChoice {
+    /// 系统字体名称（为空时使用内置字体）
+    #[serde(default)]
+    pub family: Option<String>,
+    /// 字号（点）
+    pub size: f32,
+}
+
+impl FontChoice {
+    fn builtin(size: f32) -> Self {
+        Self { family: None, size }
+    }
+
+    /// 限制在允许范围内的字号
+    pub fn clamped_size(&self) -> f32 {
+        if self.size.is_finite() {
+            self.size.clamp(constants::ui::FONT_SIZE_MIN, constants::ui::FONT_SIZE_MAX)
+        } else {
+            DEFAULT_BODY_SIZE
+        }
+    }
+}
+
+/// 界面、编辑器、表格的字体设置
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FontSettings {
+    /// 界面字体
+    #[serde(default = "default_ui_font")]
+    pub ui: FontChoice,
+    /// SQL 编辑器字体
+    #[serde(default = "default_editor_font")]
+    pub editor: FontChoice,
+    /// 数据表格字体
+    #[serde(default = "default_grid_font")]
+    pub grid: FontChoice,
+}
+
+fn default_ui_font() -> FontChoice {
+    FontChoice::builtin(DEFAULT_BODY_SIZE)
+}
+
+fn default_editor_font() -> FontChoice {
+    FontChoice::builtin(14.0)
+}
+
+fn default_grid_font() -> FontChoice {
+    FontChoice::builtin(DEFAULT_BODY_SIZE)
+}
+
+impl Default for FontSettings {
+    fn default() -> Self {
+        Self {
+            ui: default_ui_font(),
+            editor: default_editor_font(),
+            grid: default_grid_font(),
+        }
+    }
+}
+
+// ============================================================================
+// 系统字体
+// ============================================================================
+
+/// 系统中找到的字体文件
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SystemFont {
+    /// 字体名称（文件名）
+    pub name: String,
+    /// 字体文件路径
+    pub path: PathBuf,
+}
+
+impl SystemFont {
+    /// 根据名称粗略判断是否为等宽字体
+    pub fn looks_monospace(&self) -> bool {
+        let name = self.name.to_lowercase();
+        MONOSPACE_HINTS.iter().any(|hint| name.contains(hint))
+    }
+}
+
+/// 当前平台的字体目录
+fn font_dirs() -> Vec<PathBuf> {
+    let mut paths = Vec::new();
+    if cfg!(target_os = "windows") {
+        let windir = std::env::var_os("WINDIR").map(PathBuf::from).unwrap_or_else(|| PathBuf::from(r"C:\Windows"));
+        paths.push(windir.join("Fonts"));
+        if let Some(local) = dirs::data_local_dir() {
+            paths.push(local.join("Microsoft").join("Windows").join("Fonts"));
+        }
+    } else if cfg!(target_os = "macos") {
+        paths.push(PathBuf::from("/System/Library/Fonts"));
+        paths.push(PathBuf::from("/Library/Fonts"));
+        if let Some(home) = dirs::home_dir() {
+            paths.push(home.join("Library").join("Fonts"));
+        }
+    } else {
+        paths.push(PathBuf::from("/usr/share/fonts"));
+        paths.push(PathBuf::from("/usr/local/share/fonts"));
+        if let Some(data) = dirs::data_dir() {
+            paths.push(data.join("fonts"));
+        }
+        if let Some(home) = dirs::home_dir() {
+            paths.push(home.join(".fonts"));
+        }
+    }
+    paths
+}
+
+/// 递归扫描目录中的字体文件（.ttf / .otf / .ttc），按名称排序去重
+pub fn scan_font_dirs(dirs: &[PathBuf]) -> Vec<SystemFont> {
+    fn visit(dir: &Path, depth: usize, found: &mut BTreeMap<String, SystemFont>) {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                if depth < 6 {
+                    visit(&path, depth + 1, found);
+                }
+                continue;
+            }
+            let is_font = path
+                .extension()
+                .and_then(|e| e.to_str())
+                .is_some_and(|e| matches!(e.to_ascii_lowercase().as_str(), "ttf" | "otf" | "ttc"));
+            let Some(name) = path.file_stem().and_then(|s| s.to_str()) else {
+                continue;
+            };
+            if is_font {
+                found
+                    .entry(name.to_lowercase())
+                    .or_insert_with(|| SystemFont { name: name.to_string(), path: path.clone() });
+            }
+        }
+    }
+
+    let mut found = BTreeMap::new();
+    for dir in dirs {
+        visit(dir, 0, &mut found);
+    }
+    found.into_values().collect()
+}
+
+/// 系统字体列表（首次调用时扫描）
+pub fn system_fonts() -> &'static [SystemFont] {
+    static FONTS: Lazy<Vec<SystemFont>> = Lazy::new(|| scan_font_dirs(&font_dirs()));
+    &FONTS
+}
+
+// ============================================================================
+// 应用字体
+// ============================================================================
+
+/// 编辑器文本样式
+pub fn editor_text_style() -> TextStyle {
+    TextStyle::Name(EDITOR_FONT.into())
+}
+
+/// 表格文本样式
+pub fn grid_text_style() -> TextStyle {
+    TextStyle::Name(GRID_FONT.into())
+}
+
+/// 加载所选系统字体，返回字体数据的键名；找不到或读取失败时返回 None（回退到内置字体）
+fn load_choice(fonts: &mut FontDefinitions, choice: &FontChoice, available: &[SystemFont]) -> Option<String> {
+    let family = choice.family.as_deref()?;
+    let Some(font) = available.iter().find(|f| f.name == family) else {
+        tracing::warn!("未找到字体 '{}'，使用内置字体", family);
+        return None;
+    };
+    let key = format!("system:{}", font.name);
+    if !fonts.font_data.contains_key(&key) {
+        match std::fs::read(&font.path) {
+            Ok(bytes) => {
+                fonts.font_data.insert(key.clone(), Arc::new(FontData::from_owned(bytes)));
+            }
+            Err(e) => {
+                tracing::warn!("读取字体 {:?} 失败: {}", font.path, e);
+                return None;
+            }
+        }
+    }
+    Some(key)
+}
+
+/// 根据设置构建字体定义
+///
+/// 内置的 Noto Sans SC 保证完整 Unicode 覆盖，Noto Emoji 作为最后的后备；
+/// 选择的系统字体插入到对应字体族的最前面，缺失的字形仍由内置字体补齐。
+/// 编辑器默认使用内置的等宽字体 Hack。
+pub fn build_font_definitions(settings: &FontSettings, available: &[SystemFont]) -> FontDefinitions {
+    let mut fonts = FontDefinitions::default();
+    fonts
+        .font_data
+        .insert("noto_sans_sc".to_owned(), FontData::from_static(EMBEDDED_NOTO_SANS_SC).into());
+    fonts
+        .font_data
+        .insert("noto_emoji".to_owned(), FontData::from_static(EMBEDDED_NOTO_EMOJI).into());
+
+    let hack = fonts.families.get(&FontFamily::Monospace).and_then(|f| f.first().cloned());
+    for family in [FontFamily::Proportional, FontFamily::Monospace] {
+        let list = fonts.families.entry(family).or_default();
+        list.insert(0, "noto_sans_sc".to_owned());
+        list.push("noto_emoji".to_owned());
+    }
+    let proportional = fonts.families[&FontFamily::Proportional].clone();
+    let monospace = fonts.families[&FontFamily::Monospace].clone();
+
+    let mut editor = monospace;
+    if let Some(hack) = hack {
+        editor.retain(|f| f != &hack);
+        editor.insert(0, hack);
+    }
+    let mut grid = proportional.clone();
+
+    if let Some(key) = load_choice(&mut fonts, &settings.ui, available) {
+        fonts.families.entry(FontFamily::Proportional).or_default().insert(0, key.clone());
+        grid.insert(0, key);
+    }
+    if let Some(key) = load_choice(&mut fonts, &settings.editor, available) {
+        editor.insert(0, key);
+    }
+    if let Some(key) = load_choice(&mut fonts, &settings.grid, available) {
+        // 表格字体优先于界面字体
+        grid.retain(|f| f != &key);
+        grid.insert(0, key);
+    }
+
+    fonts.families.insert(FontFamily::Name(EDITOR_FONT.into()), editor);
+    fonts.families.insert(FontFamily::Name(GRID_FONT.into()), grid);
+    fonts
+}
+
+/// 按设置更新所有文本样式的字号
+pub fn apply_text_styles(style: &mut egui::Style, settings: &FontSettings) {
+    let ui_size = settings.ui.clamped_size();
+    let scale = ui_size / DEFAULT_BODY_SIZE;
+    let styles = &mut style.text_styles;
+    styles.insert(TextStyle::Body, FontId::proportional(ui_size));
+    styles.insert(TextStyle::Button, FontId::proportional(ui_size));
+    styles.insert(TextStyle::Small, FontId::proportional(9.0 * scale));
+    styles.insert(TextStyle::Heading, FontId::proportional(18.0 * scale));
+    styles.insert(TextStyle::Monospace, FontId::monospace(12.0 * scale));
+    styles.insert(
+        editor_text_style(),
+        FontId::new(settings.editor.clamped_size(), FontFamily::Name(EDITOR_FONT.into())),
+    );
+    styles.insert(
+        grid_text_style(),
+        FontId::new(settings.grid.clamped_size(), FontFamily::Name(GRID_FONT.into())),
+    );
+}
+
+/// 将字体设置应用到界面
+pub fn apply_fonts(ctx: &egui::Context, settings: &FontSettings) {
+    let available = if [&settings.ui, &settings.editor, &settings.grid].iter().any(|c| c.family.is_some()) {
+        system_fonts()
+    } else {
+        &[]
+    };
+    ctx.set_fonts(build_font_definitions(settings, available));
+    ctx.all_styles_mut(|style| apply_text_styles(style, settings));
+}
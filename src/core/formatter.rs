@@ -1,7 +1,43 @@
 //! SQL 格式化模块
 
+use crate::database::DatabaseType;
+
+/// Oracle 方言中需要换行的额外关键字
+const ORACLE_KEYWORDS: &[&str] = &[
+    "FETCH FIRST",
+    "FETCH NEXT",
+    "CONNECT BY",
+    "START WITH",
+    "MERGE INTO",
+    "WHEN MATCHED",
+    "WHEN NOT MATCHED",
+    "MINUS",
+];
+
+/// ClickHouse 方言中需要换行的额外关键字
+const CLICKHOUSE_KEYWORDS: &[&str] = &[
+    "PREWHERE",
+    "LEFT ARRAY JOIN",
+    "ARRAY JOIN",
+    "SETTINGS",
+];
+
 /// SQL 格式化 - 美化 SQL 语句
 pub fn format_sql(sql: &str) -> String {
+    format_sql_with_keywords(sql, &[])
+}
+
+/// 按数据库方言格式化 SQL
+pub fn format_sql_for(sql: &str, db_type: &DatabaseType) -> String {
+    match db_type {
+        DatabaseType::Oracle => format_sql_with_keywords(sql, ORACLE_KEYWORDS),
+        DatabaseType::ClickHouse => format_sql_with_keywords(sql, CLICKHOUSE_KEYWORDS),
+        _ => format_sql(sql),
+    }
+}
+
+/// 格式化 SQL，`dialect_keywords` 优先于通用关键字匹配
+fn format_sql_with_keywords(sql: &str, dialect_keywords: &[&str]) -> String {
     let mut result = String::new();
     let mut indent_level: usize = 0;
     let mut in_string = false;
@@ -121,7 +157,7 @@ pub fn format_sql(sql: &str) -> String {
         let remaining = &upper[i..];
         let mut found_keyword = false;
 
-        for keyword in &major_keywords {
+        for keyword in dialect_keywords.iter().chain(major_keywords.iter()) {
             if remaining.starts_with(keyword) {
                 // 确保是完整的关键字（后面是空格或结束）
                 let kw_len = keyword.len();
@@ -0,0 +1,75 @@
+//! 模糊匹配
+//!
+//! 用于命令面板等搜索场景：模式中的字符按顺序出现在文本中即视为匹配（不区分大小写），
+//! 连续匹配、单词开头匹配得分更高。
+
+/// 连续匹配的加分
+const CONSECUTIVE_BONUS: i32 = 5;
+/// 在单词开头匹配的加分
+const WORD_START_BONUS: i32 = 8;
+/// 文本以模式开头的加分
+const PREFIX_BONUS: i32 = 10;
+
+/// 计算模式与文本的匹配得分，不匹配时返回 None
+///
+/// 模式中的空白被忽略，空模式匹配任何文本（得分为 0）。
+pub fn fuzzy_score(pattern: &str, text: &str) -> Option<i32> {
+    let pattern: Vec<char> = pattern
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect();
+    if pattern.is_empty() {
+        return Some(0);
+    }
+    let text: Vec<char> = text.chars().flat_map(char::to_lowercase).collect();
+
+    let mut score = 0;
+    let mut pi = 0;
+    let mut last_match: Option<usize> = None;
+    for (ti, &c) in text.iter().enumerate() {
+        if pi == pattern.len() {
+            break;
+        }
+        if c != pattern[pi] {
+            continue;
+        }
+        score += 1;
+        match last_match {
+            Some(last) if last + 1 == ti => score += CONSECUTIVE_BONUS,
+            // 跳过的字符越多得分越低
+            Some(last) => score -= ((ti - last - 1) as i32).min(5),
+            None => score -= (ti as i32).min(5),
+        }
+        if ti == 0 || is_separator(text[ti - 1]) {
+            score += WORD_START_BONUS;
+        }
+        last_match = Some(ti);
+        pi += 1;
+    }
+
+    if pi < pattern.len() {
+        return None;
+    }
+    if text.starts_with(&pattern) {
+        score += PREFIX_BONUS;
+    }
+    // 文本越长得分略低，使更精确的结果排在前面
+    score -= ((text.len() - pattern.len()) as i32 / 4).min(10);
+    Some(score)
+}
+
+/// 与模式匹配的项的序号，按得分从高到低排序，得分相同时保持原有顺序
+pub fn fuzzy_rank<S: AsRef<str>>(pattern: &str, items: &[S]) -> Vec<usize> {
+    let mut scored: Vec<(i32, usize)> = items
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, item)| fuzzy_score(pattern, item.as_ref()).map(|score| (score, idx)))
+        .collect();
+    scored.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+    scored.into_iter().map(|(_, idx)| idx).collect()
+}
+
+fn is_separator(c: char) -> bool {
+    c.is_whitespace() || matches!(c, '_' | '-' | '.' | ':' | '/' | '(' | '（')
+}
@@ -0,0 +1,464 @@
+//! 空间（GIS）值解析
+//!
+//! 识别单元格中的几何值并解析为坐标，用于表格中显示 WKT 摘要和预览图形：
+//! - WKT / EWKT 文本：`POINT (1 2)`、`SRID=4326;POLYGON ((...))`
+//! - WKB / EWKB 十六进制：PostGIS 的 geometry、geography 列
+//! - 二进制单元格（`\x...`）：MySQL 的内部格式（4 字节 SRID + WKB）或普通 WKB
+//!
+//! 只保留 X/Y 坐标，Z 和 M 值在解析时丢弃。
+
+use super::blob::decode_blob;
+
+/// 坐标
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Coord {
+    pub x: f64,
+    pub y: f64,
+}
+
+/// 几何图形
+#[derive(Debug, Clone, PartialEq)]
+pub enum Geometry {
+    /// 点（空点为 `None`）
+    Point(Option<Coord>),
+    LineString(Vec<Coord>),
+    /// 多边形（第一个环是外环，其余是内环）
+    Polygon(Vec<Vec<Coord>>),
+    MultiPoint(Vec<Coord>),
+    MultiLineString(Vec<Vec<Coord>>),
+    MultiPolygon(Vec<Vec<Vec<Coord>>>),
+    Collection(Vec<Geometry>),
+}
+
+/// 带坐标系的几何值
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpatialValue {
+    /// 空间参考标识（未指定时为 `None`）
+    pub srid: Option<u32>,
+    pub geometry: Geometry,
+}
+
+/// 坐标范围
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bounds {
+    pub min: Coord,
+    pub max: Coord,
+}
+
+/// 解析单元格中的几何值，不是几何值时返回 `None`
+pub fn parse_geometry(cell: &str) -> Option<SpatialValue> {
+    let cell = cell.trim();
+    let first = cell.as_bytes().first()?;
+    if first.is_ascii_alphabetic() && !cell.as_bytes().iter().all(u8::is_ascii_hexdigit) {
+        return parse_ewkt(cell);
+    }
+    if let Some(bytes) = decode_blob(cell) {
+        // MySQL 在 WKB 前加 4 字节的 SRID（小端）
+        return parse_wkb(&bytes).or_else(|| {
+            let (srid, wkb) = bytes.split_first_chunk::<4>()?;
+            let mut value = parse_wkb(wkb)?;
+            value.srid = Some(u32::from_le_bytes(*srid)).filter(|&s| s != 0);
+            Some(value)
+        });
+    }
+    // 最短的 WKB（空的多点等集合）为 9 字节
+    if cell.len() >= 18 && cell.len().is_multiple_of(2) && cell.bytes().all(|b| b.is_ascii_hexdigit()) {
+        let bytes: Option<Vec<u8>> = (0..cell.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&cell[i..i + 2], 16).ok())
+            .collect();
+        return parse_wkb(&bytes?);
+    }
+    None
+}
+
+impl Geometry {
+    /// WKT 类型名
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Self::Point(_) => "POINT",
+            Self::LineString(_) => "LINESTRING",
+            Self::Polygon(_) => "POLYGON",
+            Self::MultiPoint(_) => "MULTIPOINT",
+            Self::MultiLineString(_) => "MULTILINESTRING",
+            Self::MultiPolygon(_) => "MULTIPOLYGON",
+            Self::Collection(_) => "GEOMETRYCOLLECTION",
+        }
+    }
+
+    /// 所有坐标
+    pub fn coords(&self) -> Vec<Coord> {
+        match self {
+            Self::Point(p) => p.iter().copied().collect(),
+            Self::LineString(c) | Self::MultiPoint(c) => c.clone(),
+            Self::Polygon(rings) | Self::MultiLineString(rings) => rings.concat(),
+            Self::MultiPolygon(polygons) => polygons.iter().flat_map(|p| p.concat()).collect(),
+            Self::Collection(items) => items.iter().flat_map(Geometry::coords).collect(),
+        }
+    }
+
+    /// 坐标范围（没有坐标时为 `None`）
+    pub fn bounds(&self) -> Option<Bounds> {
+        self.coords().into_iter().fold(None, |bounds, c| {
+            Some(match bounds {
+                None => Bounds { min: c, max: c },
+                Some(Bounds { min, max }) => Bounds {
+                    min: Coord { x: min.x.min(c.x), y: min.y.min(c.y) },
+                    max: Coord { x: max.x.max(c.x), y: max.y.max(c.y) },
+                },
+            })
+        })
+    }
+
+    /// 紧凑的 WKT 文本
+    pub fn to_wkt(&self) -> String {
+        let body = match self {
+            Self::Point(Some(c)) => format!("({})", coord_text(c)),
+            Self::LineString(c) | Self::MultiPoint(c) => coords_text(c),
+            Self::Polygon(rings) | Self::MultiLineString(rings) => rings_text(rings),
+            Self::MultiPolygon(polygons) => list_text(polygons, |p| rings_text(p)),
+            Self::Collection(items) => list_text(items, Geometry::to_wkt),
+            Self::Point(None) => String::new(),
+        };
+        if body.is_empty() || body == "()" {
+            format!("{} EMPTY", self.type_name())
+        } else {
+            format!("{} {}", self.type_name(), body)
+        }
+    }
+
+    /// 逐行列出坐标（每部分、每个环单独列出）
+    fn pretty_lines(&self, indent: usize, lines: &mut Vec<String>) {
+        let pad = "  ".repeat(indent);
+        let push_coords = |lines: &mut Vec<String>, label: String, coords: &[Coord]| {
+            lines.push(format!("{}{}（{} 个点）", pad, label, coords.len()));
+            lines.extend(coords.iter().map(|c| format!("{}  {}", pad, coord_text(c))));
+        };
+        match self {
+            Self::Point(Some(c)) => lines.push(format!("{}点 {}", pad, coord_text(c))),
+            Self::Point(None) => lines.push(format!("{}空点", pad)),
+            Self::LineString(c) => push_coords(lines, "线".to_string(), c),
+            Self::MultiPoint(c) => push_coords(lines, "点集".to_string(), c),
+            Self::Polygon(rings) => {
+                for (i, ring) in rings.iter().enumerate() {
+                    let label = if i == 0 { "外环".to_string() } else { format!("内环 {}", i) };
+                    push_coords(lines, label, ring);
+                }
+            }
+            Self::MultiLineString(parts) => {
+                for (i, part) in parts.iter().enumerate() {
+                    push_coords(lines, format!("线 {}", i + 1), part);
+                }
+            }
+            Self::MultiPolygon(polygons) => {
+                for (i, polygon) in polygons.iter().enumerate() {
+                    lines.push(format!("{}多边形 {}", pad, i + 1));
+                    Self::Polygon(polygon.clone()).pretty_lines(indent + 1, lines);
+                }
+            }
+            Self::Collection(items) => {
+                for item in items {
+                    lines.push(format!("{}{}", pad, item.type_name()));
+                    item.pretty_lines(indent + 1, lines);
+                }
+            }
+        }
+    }
+}
+
+impl SpatialValue {
+    /// 带 SRID 的 WKT（EWKT）
+    pub fn to_ewkt(&self) -> String {
+        match self.srid {
+            Some(srid) => format!("SRID={};{}", srid, self.geometry.to_wkt()),
+            None => self.geometry.to_wkt(),
+        }
+    }
+
+    /// 格式化的坐标列表
+    pub fn pretty(&self) -> String {
+        let mut lines = vec![match self.srid {
+            Some(srid) => format!("{}（SRID {}）", self.geometry.type_name(), srid),
+            None => self.geometry.type_name().to_string(),
+        }];
+        if let Some(b) = self.geometry.bounds() {
+            lines.push(format!("范围: {} - {}", coord_text(&b.min), coord_text(&b.max)));
+        }
+        self.geometry.pretty_lines(0, &mut lines);
+        lines.join("\n")
+    }
+}
+
+/// 坐标文本（去掉多余的 0，最多 7 位小数，约 1 厘米精度的经纬度）
+fn coord_text(c: &Coord) -> String {
+    format!("{} {}", number_text(c.x), number_text(c.y))
+}
+
+fn number_text(value: f64) -> String {
+    let text = format!("{:.7}", value);
+    let text = text.trim_end_matches('0').trim_end_matches('.');
+    if text == "-0" { "0".to_string() } else { text.to_string() }
+}
+
+fn coords_text(coords: &[Coord]) -> String {
+    format!("({})", coords.iter().map(coord_text).collect::<Vec<_>>().join(", "))
+}
+
+fn rings_text(rings: &[Vec<Coord>]) -> String {
+    list_text(rings, |r| coords_text(r))
+}
+
+fn list_text<T>(items: &[T], f: impl Fn(&T) -> String) -> String {
+    format!("({})", items.iter().map(f).collect::<Vec<_>>().join(", "))
+}
+
+// ============================================================================
+// WKT
+// ============================================================================
+
+/// 解析 WKT，支持 `SRID=n;` 前缀
+fn parse_ewkt(text: &str) -> Option<SpatialValue> {
+    let (srid, wkt) = match text.split_once(';') {
+        Some((prefix, wkt)) if prefix.to_ascii_uppercase().starts_with("SRID=") => {
+            (Some(prefix[5..].trim().parse().ok()?), wkt)
+        }
+        _ => (None, text),
+    };
+    let mut parser = WktParser { text: wkt, pos: 0 };
+    let geometry = parser.geometry()?;
+    parser.skip_ws();
+    (parser.pos == wkt.len()).then_some(SpatialValue { srid, geometry })
+}
+
+struct WktParser<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl WktParser<'_> {
+    fn skip_ws(&mut self) {
+        let rest = &self.text[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn word(&mut self) -> String {
+        self.skip_ws();
+        let rest = &self.text[self.pos..];
+        let len = rest.find(|c: char| !c.is_ascii_alphabetic()).unwrap_or(rest.len());
+        self.pos += len;
+        rest[..len].to_ascii_uppercase()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        self.skip_ws();
+        if self.text[self.pos..].starts_with(c) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// `EMPTY` 或左括号（返回 `false` 表示空）
+    fn open(&mut self) -> Option<bool> {
+        let save = self.pos;
+        if self.word() == "EMPTY" {
+            return Some(false);
+        }
+        self.pos = save;
+        self.eat('(').then_some(true)
+    }
+
+    /// 逗号分隔的列表，直到右括号
+    fn list<T>(&mut self, mut item: impl FnMut(&mut Self) -> Option<T>) -> Option<Vec<T>> {
+        if !self.open()? {
+            return Some(Vec::new());
+        }
+        let mut items = vec![item(self)?];
+        while self.eat(',') {
+            items.push(item(self)?);
+        }
+        self.eat(')').then_some(items)
+    }
+
+    fn number(&mut self) -> Option<f64> {
+        self.skip_ws();
+        let rest = &self.text[self.pos..];
+        let len = rest
+            .find(|c: char| !(c.is_ascii_digit() || matches!(c, '.' | '-' | '+' | 'e' | 'E')))
+            .unwrap_or(rest.len());
+        let value = rest[..len].parse().ok()?;
+        self.pos += len;
+        Some(value)
+    }
+
+    /// 坐标（忽略 Z、M）
+    fn coord(&mut self) -> Option<Coord> {
+        let x = self.number()?;
+        let y = self.number()?;
+        while self.number().is_some() {}
+        Some(Coord { x, y })
+    }
+
+    /// MULTIPOINT 的点可以带括号也可以不带
+    fn multipoint_item(&mut self) -> Option<Coord> {
+        if self.eat('(') {
+            let c = self.coord()?;
+            self.eat(')').then_some(c)
+        } else {
+            self.coord()
+        }
+    }
+
+    fn geometry(&mut self) -> Option<Geometry> {
+        let kind = self.word();
+        // 维度标记 Z / M / ZM
+        let save = self.pos;
+        if !matches!(self.word().as_str(), "Z" | "M" | "ZM") {
+            self.pos = save;
+        }
+        Some(match kind.as_str() {
+            "POINT" => {
+                if self.open()? {
+                    let c = self.coord()?;
+                    self.eat(')').then_some(Geometry::Point(Some(c)))?
+                } else {
+                    Geometry::Point(None)
+                }
+            }
+            "LINESTRING" => Geometry::LineString(self.list(Self::coord)?),
+            "POLYGON" => Geometry::Polygon(self.list(|p| p.list(Self::coord))?),
+            "MULTIPOINT" => Geometry::MultiPoint(self.list(Self::multipoint_item)?),
+            "MULTILINESTRING" => Geometry::MultiLineString(self.list(|p| p.list(Self::coord))?),
+            "MULTIPOLYGON" => Geometry::MultiPolygon(self.list(|p| p.list(|p| p.list(Self::coord)))?),
+            "GEOMETRYCOLLECTION" => Geometry::Collection(self.list(Self::geometry)?),
+            _ => return None,
+        })
+    }
+}
+
+// ============================================================================
+// WKB
+// ============================================================================
+
+/// 集合元素数量的上限（防止损坏的数据导致分配过大）
+const WKB_MAX_ITEMS: u32 = 10_000_000;
+
+/// 解析 WKB / EWKB（必须恰好用完所有字节）
+fn parse_wkb(bytes: &[u8]) -> Option<SpatialValue> {
+    let mut reader = WkbReader { bytes, pos: 0 };
+    let (geometry, srid) = reader.geometry()?;
+    (reader.pos == bytes.len()).then_some(SpatialValue { srid, geometry })
+}
+
+struct WkbReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl WkbReader<'_> {
+    fn take<const N: usize>(&mut self) -> Option<[u8; N]> {
+        let chunk = self.bytes.get(self.pos..self.pos + N)?.try_into().ok()?;
+        self.pos += N;
+        Some(chunk)
+    }
+
+    fn u32(&mut self, little: bool) -> Option<u32> {
+        let b = self.take::<4>()?;
+        Some(if little { u32::from_le_bytes(b) } else { u32::from_be_bytes(b) })
+    }
+
+    fn f64(&mut self, little: bool) -> Option<f64> {
+        let b = self.take::<8>()?;
+        Some(if little { f64::from_le_bytes(b) } else { f64::from_be_bytes(b) })
+    }
+
+    fn count(&mut self, little: bool) -> Option<usize> {
+        self.u32(little).filter(|&n| n <= WKB_MAX_ITEMS).map(|n| n as usize)
+    }
+
+    fn coord(&mut self, little: bool, dims: usize) -> Option<Coord> {
+        let x = self.f64(little)?;
+        let y = self.f64(little)?;
+        for _ in 2..dims {
+            self.f64(little)?;
+        }
+        Some(Coord { x, y })
+    }
+
+    fn coords(&mut self, little: bool, dims: usize) -> Option<Vec<Coord>> {
+        let n = self.count(little)?;
+        (0..n).map(|_| self.coord(little, dims)).collect()
+    }
+
+    fn rings(&mut self, little: bool, dims: usize) -> Option<Vec<Vec<Coord>>> {
+        let n = self.count(little)?;
+        (0..n).map(|_| self.coords(little, dims)).collect()
+    }
+
+    /// 集合的各个元素（每个元素都是完整的 WKB）
+    fn items(&mut self, little: bool) -> Option<Vec<Geometry>> {
+        let n = self.count(little)?;
+        (0..n).map(|_| self.geometry().map(|(g, _)| g)).collect()
+    }
+
+    fn geometry(&mut self) -> Option<(Geometry, Option<u32>)> {
+        let little = match self.take::<1>()?[0] {
+            0 => false,
+            1 => true,
+            _ => return None,
+        };
+        let raw = self.u32(little)?;
+        // EWKB 用高位标记 Z、M、SRID，ISO WKB 用 1000/2000/3000 的偏移
+        let has_srid = raw & 0x2000_0000 != 0;
+        let ewkb_dims = usize::from(raw & 0x8000_0000 != 0) + usize::from(raw & 0x4000_0000 != 0);
+        let code = raw & 0x0FFF_FFFF;
+        let (kind, iso_dims) = (code % 1000, match code / 1000 {
+            0 => 0,
+            1 | 2 => 1,
+            3 => 2,
+            _ => return None,
+        });
+        let dims = 2 + ewkb_dims + iso_dims;
+        let srid = if has_srid { Some(self.u32(little)?) } else { None };
+
+        let geometry = match kind {
+            1 => {
+                let c = self.coord(little, dims)?;
+                Geometry::Point(Some(c).filter(|c| !c.x.is_nan()))
+            }
+            2 => Geometry::LineString(self.coords(little, dims)?),
+            3 => Geometry::Polygon(self.rings(little, dims)?),
+            4 => Geometry::MultiPoint(
+                self.items(little)?
+                    .into_iter()
+                    .map(|g| match g {
+                        Geometry::Point(p) => p,
+                        _ => None,
+                    })
+                    .collect::<Option<_>>()?,
+            ),
+            5 => Geometry::MultiLineString(
+                self.items(little)?
+                    .into_iter()
+                    .map(|g| match g {
+                        Geometry::LineString(c) => Some(c),
+                        _ => None,
+                    })
+                    .collect::<Option<_>>()?,
+            ),
+            6 => Geometry::MultiPolygon(
+                self.items(little)?
+                    .into_iter()
+                    .map(|g| match g {
+                        Geometry::Polygon(r) => Some(r),
+                        _ => None,
+                    })
+                    .collect::<Option<_>>()?,
+            ),
+            7 => Geometry::Collection(self.items(little)?),
+            _ => return None,
+        };
+        Some((geometry, srid))
+    }
+}
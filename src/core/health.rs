@@ -0,0 +1,123 @@
+//! 连接健康检查
+//!
+//! 按首选项中的间隔向已连接的连接发送轻量的保活查询，记录响应时间和连续失败次数，
+//! 侧边栏按状态显示绿色（正常）、黄色（响应缓慢或正在重试）、红色（已断开）圆点。
+
+use std::time::{Duration, Instant};
+
+use super::constants;
+
+/// 连续失败多少次后判定连接已断开
+pub const KEEP_ALIVE_DOWN_AFTER: u32 = 2;
+
+/// 连接健康状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HealthStatus {
+    /// 尚未检查
+    #[default]
+    Unknown,
+    /// 正常
+    Healthy,
+    /// 响应缓慢，或检查失败正在重试
+    Degraded,
+    /// 已断开
+    Down,
+}
+
+impl HealthStatus {
+    /// 状态说明
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Unknown => "尚未检查",
+            Self::Healthy => "连接正常",
+            Self::Degraded => "响应缓慢",
+            Self::Down => "连接已断开",
+        }
+    }
+}
+
+/// 单个连接的保活检查计时和结果
+#[derive(Debug, Clone, Default)]
+pub struct KeepAlive {
+    /// 上次检查（或开始计时）的时间
+    last_check: Option<Instant>,
+    /// 正在检查
+    in_flight: bool,
+    /// 连续失败次数
+    failures: u32,
+    /// 上次成功检查的耗时（毫秒）
+    latency_ms: Option<u64>,
+    /// 上次失败的错误信息
+    last_error: Option<String>,
+}
+
+impl KeepAlive {
+    /// 是否应该开始检查；第一次调用时开始计时，连接建立一个间隔后才检查
+    pub fn is_due(&mut self, interval_secs: u64, now: Instant) -> bool {
+        let last = *self.last_check.get_or_insert(now);
+        interval_secs > 0 && !self.in_flight && now.duration_since(last) >= Duration::from_secs(interval_secs)
+    }
+
+    /// 距离下次检查的时间（用于安排重绘）
+    pub fn time_until_next(&self, interval_secs: u64, now: Instant) -> Option<Duration> {
+        if interval_secs == 0 || self.in_flight {
+            return None;
+        }
+        let last = self.last_check?;
+        Some(Duration::from_secs(interval_secs).saturating_sub(now.duration_since(last)))
+    }
+
+    /// 开始一次检查
+    pub fn start(&mut self, now: Instant) {
+        self.in_flight = true;
+        self.last_check = Some(now);
+    }
+
+    /// 记录检查结果（成功时为耗时毫秒），返回状态是否变化
+    pub fn record(&mut self, result: Result<u64, String>) -> bool {
+        let before = self.status();
+        self.in_flight = false;
+        match result {
+            Ok(latency_ms) => {
+                self.failures = 0;
+                self.latency_ms = Some(latency_ms);
+                self.last_error = None;
+            }
+            Err(e) => {
+                self.failures += 1;
+                self.last_error = Some(e);
+            }
+        }
+        self.status() != before
+    }
+
+    /// 当前状态
+    pub fn status(&self) -> HealthStatus {
+        if self.failures >= KEEP_ALIVE_DOWN_AFTER {
+            HealthStatus::Down
+        } else if self.failures > 0 {
+            HealthStatus::Degraded
+        } else {
+            match self.latency_ms {
+                None => HealthStatus::Unknown,
+                Some(ms) if ms > constants::database::KEEP_ALIVE_SLOW_MS => HealthStatus::Degraded,
+                Some(_) => HealthStatus::Healthy,
+            }
+        }
+    }
+
+    /// 上次失败的错误信息
+    pub fn last_error(&self) -> Option<&str> {
+        self.last_error.as_deref()
+    }
+
+    /// 悬停提示：状态、耗时和错误信息
+    pub fn describe(&self) -> String {
+        let status = self.status();
+        match (&self.last_error, self.latency_ms) {
+            (Some(e), _) => format!("{}（连续失败 {} 次）: {}", status.label(), self.failures, e),
+            (None, Some(ms)) => format!("{}（{} ms）", status.label(), ms),
+            (None, None) => status.label().to_string(),
+        }
+    }
+}
@@ -1,5 +1,17 @@
+//! 查询历史
+//!
+//! 记录执行过的查询，支持全文搜索、按连接筛选和收藏。
+//! 连续执行相同语句时合并为一条记录；收藏的记录不受条数上限限制。
+//! 历史保存在配置目录下独立的 `history.toml` 中，重启后恢复。
+
 use chrono::{DateTime, Local};
 use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn default_run_count() -> u32 {
+    1
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryHistoryItem {
@@ -8,11 +20,80 @@ pub struct QueryHistoryItem {
     pub database_type: String,
     pub success: bool,
     pub rows_affected: Option<u64>,
+    /// 执行查询的连接名
+    #[serde(default)]
+    pub connection: Option<String>,
+    /// 是否已收藏（收藏的记录不会被淘汰）
+    #[serde(default)]
+    pub favorite: bool,
+    /// 连续执行的次数
+    #[serde(default = "default_run_count")]
+    pub run_count: u32,
+    /// 执行耗时（毫秒）
+    #[serde(default)]
+    pub elapsed_ms: Option<u64>,
+    /// 返回的行数（仅查询）
+    #[serde(default)]
+    pub row_count: Option<usize>,
+}
+
+impl QueryHistoryItem {
+    /// 创建一条记录，时间为当前时间
+    pub fn new(sql: String, database_type: String, connection: Option<String>, success: bool) -> Self {
+        Self {
+            sql,
+            timestamp: Local::now(),
+            database_type,
+            success,
+            rows_affected: None,
+            connection,
+            favorite: false,
+            run_count: 1,
+            elapsed_ms: None,
+            row_count: None,
+        }
+    }
+}
+
+/// 历史记录筛选条件
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HistoryFilter {
+    /// 搜索文本（空格分隔的多个词须全部出现，不区分大小写）
+    pub text: String,
+    /// 只显示该连接的记录
+    pub connection: Option<String>,
+    /// 只显示收藏的记录
+    pub favorites_only: bool,
+}
+
+impl HistoryFilter {
+    /// 是否没有任何筛选条件
+    pub fn is_empty(&self) -> bool {
+        self.text.trim().is_empty() && self.connection.is_none() && !self.favorites_only
+    }
+
+    /// 记录是否符合筛选条件
+    pub fn matches(&self, item: &QueryHistoryItem) -> bool {
+        if self.favorites_only && !item.favorite {
+            return false;
+        }
+        if let Some(connection) = &self.connection
+            && item.connection.as_ref() != Some(connection)
+        {
+            return false;
+        }
+        let sql = item.sql.to_lowercase();
+        self.text
+            .split_whitespace()
+            .all(|term| sql.contains(&term.to_lowercase()))
+    }
 }
 
 #[derive(Debug, Default, Serialize, Deserialize)]
 pub struct QueryHistory {
     items: Vec<QueryHistoryItem>,
+    /// 非收藏记录的最大条数（由配置决定，不写入历史文件）
+    #[serde(skip)]
     max_size: usize,
 }
 
@@ -24,35 +105,88 @@ impl QueryHistory {
         }
     }
 
-    pub fn add(
-        &mut self,
-        sql: String,
-        database_type: String,
-        success: bool,
-        rows_affected: Option<u64>,
-    ) {
-        let item = QueryHistoryItem {
-            sql,
-            timestamp: Local::now(),
-            database_type,
-            success,
-            rows_affected,
-        };
+    /// 添加一条记录；与最新一条记录语句和连接都相同时合并为一条
+    pub fn add(&mut self, item: QueryHistoryItem) {
+        if let Some(latest) = self.items.first_mut()
+            && latest.sql.trim() == item.sql.trim()
+            && latest.connection == item.connection
+        {
+            latest.timestamp = item.timestamp;
+            latest.database_type = item.database_type;
+            latest.success = item.success;
+            latest.rows_affected = item.rows_affected;
+            latest.elapsed_ms = item.elapsed_ms;
+            latest.row_count = item.row_count;
+            latest.run_count = latest.run_count.saturating_add(1);
+            return;
+        }
 
         self.items.insert(0, item);
+        self.trim();
+    }
 
-        // 保持最大历史记录数量
-        if self.items.len() > self.max_size {
-            self.items.truncate(self.max_size);
-        }
+    /// 淘汰超出上限的最旧的非收藏记录
+    fn trim(&mut self) {
+        let mut kept = 0;
+        let max_size = self.max_size;
+        self.items.retain(|item| {
+            if item.favorite {
+                return true;
+            }
+            kept += 1;
+            kept <= max_size
+        });
     }
 
     pub fn items(&self) -> &[QueryHistoryItem] {
         &self.items
     }
 
+    /// 非收藏记录的最大条数
+    pub fn max_size(&self) -> usize {
+        self.max_size
+    }
+
+    /// 修改最大条数，超出的旧记录立即淘汰
+    pub fn set_max_size(&mut self, max_size: usize) {
+        self.max_size = max_size;
+        self.trim();
+    }
+
+    /// 按筛选条件搜索，返回符合条件的记录索引（从新到旧）
+    pub fn search(&self, filter: &HistoryFilter) -> Vec<usize> {
+        self.items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| filter.matches(item))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// 历史中出现过的连接名（按名称排序）
+    pub fn connections(&self) -> Vec<String> {
+        let mut connections: Vec<String> = self.items.iter().filter_map(|i| i.connection.clone()).collect();
+        connections.sort();
+        connections.dedup();
+        connections
+    }
+
+    /// 切换收藏状态；取消收藏后可能因超出上限被淘汰
+    pub fn toggle_favorite(&mut self, index: usize) {
+        if let Some(item) = self.items.get_mut(index) {
+            item.favorite = !item.favorite;
+            self.trim();
+        }
+    }
+
+    /// 删除一条记录
+    pub fn remove(&mut self, index: usize) -> Option<QueryHistoryItem> {
+        (index < self.items.len()).then(|| self.items.remove(index))
+    }
+
+    /// 清空历史（保留收藏）
     pub fn clear(&mut self) {
-        self.items.clear();
+        self.items.retain(|item| item.favorite);
     }
 
     pub fn is_empty(&self) -> bool {
@@ -62,4 +196,70 @@ impl QueryHistory {
     pub fn len(&self) -> usize {
         self.items.len()
     }
+
+    /// 获取历史文件路径
+    fn history_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|p| p.join("gridix").join("history.toml"))
+    }
+
+    /// 从历史文件加载；文件不存在或无法解析时返回空历史
+    pub fn load(max_size: usize) -> Self {
+        match Self::history_path() {
+            Some(path) => Self::load_from(&path, max_size),
+            None => Self::new(max_size),
+        }
+    }
+
+    /// 从指定文件加载
+    pub fn load_from(path: &Path, max_size: usize) -> Self {
+        if !path.exists() {
+            return Self::new(max_size);
+        }
+
+        let content = match fs::read_to_string(path) {
+            Ok(c) => c,
+            Err(e) => {
+                tracing::warn!(error = %e, "读取查询历史失败");
+                return Self::new(max_size);
+            }
+        };
+
+        match toml::from_str::<Self>(&content) {
+            Ok(mut history) => {
+                history.set_max_size(max_size);
+                history
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, "解析查询历史失败");
+                Self::new(max_size)
+            }
+        }
+    }
+
+    /// 保存到历史文件
+    pub fn save(&self) -> Result<(), String> {
+        let path = Self::history_path().ok_or("无法获取查询历史文件路径")?;
+        self.save_to(&path)
+    }
+
+    /// 保存到指定文件（原子写入，权限限制为仅当前用户可读写）
+    pub fn save_to(&self, path: &Path) -> Result<(), String> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| format!("创建目录失败: {}", e))?;
+        }
+
+        let toml_str = toml::to_string_pretty(self).map_err(|e| format!("序列化失败: {}", e))?;
+
+        let temp_path = path.with_extension("toml.tmp");
+        fs::write(&temp_path, &toml_str).map_err(|e| format!("写入失败: {}", e))?;
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&temp_path, fs::Permissions::from_mode(0o600)).map_err(|e| e.to_string())?;
+        }
+
+        fs::rename(&temp_path, path).map_err(|e| format!("重命名失败: {}", e))?;
+        Ok(())
+    }
 }
@@ -0,0 +1,1530 @@
+//! 国际化
+//!
+//! 界面文本通过键名在语言目录中查找。当前语言保存在全局状态中，切换语言后下一帧生效。
+//! 目录中缺少的翻译回退到简体中文，键名本身不存在时直接显示键名，便于发现遗漏。
+//!
+//! 目前覆盖工具栏、侧边栏、首选项、命令面板、搜索栏、应用通知和大部分对话框；
+//! 表格内的提示和部分较少使用的对话框仍直接使用中文文本，逐步迁移到目录中。
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// 界面语言
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum Language {
+    /// 简体中文
+    #[default]
+    #[serde(rename = "zh-CN")]
+    ZhCn,
+    /// 英语
+    #[serde(rename = "en-US")]
+    EnUs,
+}
+
+impl Language {
+    /// 所有支持的语言
+    pub const ALL: [Language; 2] = [Language::ZhCn, Language::EnUs];
+
+    /// 语言代码
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::ZhCn => "zh-CN",
+            Language::EnUs => "en-US",
+        }
+    }
+
+    /// 以该语言自身书写的名称
+    pub fn native_name(&self) -> &'static str {
+        match self {
+            Language::ZhCn => "简体中文",
+            Language::EnUs => "English",
+        }
+    }
+
+    /// 根据语言代码查找（不区分大小写，`en` 匹配 `en-US`）
+    #[allow(dead_code)] // 公开 API，供外部使用
+    pub fn from_code(code: &str) -> Option<Language> {
+        let code = code.trim().replace('_', "-").to_lowercase();
+        Language::ALL
+            .into_iter()
+            .find(|lang| lang.code().to_lowercase() == code || lang.code()[..2] == code)
+    }
+
+    fn index(&self) -> usize {
+        match self {
+            Language::ZhCn => 0,
+            Language::EnUs => 1,
+        }
+    }
+}
+
+/// 当前语言
+static CURRENT: AtomicU8 = AtomicU8::new(0);
+
+/// 设置当前界面语言
+pub fn set_language(lang: Language) {
+    CURRENT.store(lang.index() as u8, Ordering::Relaxed);
+}
+
+/// 当前界面语言
+pub fn language() -> Language {
+    Language::ALL
+        .get(CURRENT.load(Ordering::Relaxed) as usize)
+        .copied()
+        .unwrap_or_default()
+}
+
+/// 查找翻译文本
+pub fn t(key: &str) -> &str {
+    translate(language(), key)
+}
+
+/// 查找翻译文本并按顺序替换 `{}` 占位符
+pub fn tf(key: &str, args: &[&str]) -> String {
+    format_args_into(t(key), args)
+}
+
+/// 在指定语言中查找翻译文本
+pub fn translate(lang: Language, key: &str) -> &str {
+    match CATALOG.get(key) {
+        Some(texts) => {
+            let text = texts[lang.index()];
+            if text.is_empty() { texts[Language::ZhCn.index()] } else { text }
+        }
+        None => key,
+    }
+}
+
+/// 按顺序替换 `{}` 占位符，多余的占位符保持原样
+fn format_args_into(template: &str, args: &[&str]) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut args = args.iter();
+    let mut rest = template;
+    while let Some(pos) = rest.find("{}") {
+        out.push_str(&rest[..pos]);
+        match args.next() {
+            Some(arg) => out.push_str(arg),
+            None => out.push_str("{}"),
+        }
+        rest = &rest[pos + 2..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// 目录中的所有键名
+#[allow(dead_code)] // 公开 API，供外部使用
+pub fn catalog_keys() -> impl Iterator<Item = &'static str> {
+    ENTRIES.iter().map(|(key, _)| *key)
+}
+
+static CATALOG: Lazy<HashMap<&'static str, [&'static str; 2]>> = Lazy::new(|| ENTRIES.iter().copied().collect());
+
+/// 语言目录：键名 -> [简体中文, English]
+const ENTRIES: &[(&str, [&str; 2])] = &[
+    // 通用
+    ("common.ok", ["确定", "OK"]),
+    ("common.apply", ["应用", "Apply"]),
+    ("common.cancel", ["取消", "Cancel"]),
+    ("common.cancel_esc", ["取消 [Esc]", "Cancel [Esc]"]),
+    ("common.reset_defaults", ["恢复默认", "Restore defaults"]),
+    ("common.filter", ["筛选:", "Filter:"]),
+    ("common.confirm_hint", ["按 y 确认，n 取消", "Press y to confirm, n to cancel"]),
+    ("common.need_connection", ["请先连接数据库", "Connect to a database first"]),
+    ("common.need_database", ["请先选择数据库", "Select a database first"]),
+    ("common.need_table", ["请先在侧边栏选择表", "Select a table in the sidebar first"]),
+    ("common.export_failed", ["导出失败: {}", "Export failed: {}"]),
+    ("common.save_config_failed", ["保存配置失败: {}", "Failed to save settings: {}"]),
+    ("common.day_short", ["日", "D"]),
+    ("common.preview_sql", ["预览 SQL", "Preview SQL"]),
+    ("common.create_enter", ["创建 [Enter]", "Create [Enter]"]),
+    ("common.create_shortcuts", ["快捷键: Esc/q 关闭 | Enter 创建", "Shortcuts: Esc/q close | Enter create"]),
+    ("common.save_icon", ["💾 保存", "💾 Save"]),
+    ("common.save_icon_changed", ["💾 保存 *", "💾 Save *"]),
+    ("common.name", ["名称:", "Name:"]),
+    ("common.connection", ["连接:", "Connection:"]),
+    ("common.select_connection", ["选择连接", "Select a connection"]),
+    ("common.delete", ["删除", "Delete"]),
+    ("common.name_required", ["名称不能为空", "Name is required"]),
+    ("common.running", ["执行中...", "Running..."]),
+    // 工具栏
+    ("toolbar.actions", ["操作菜单", "Actions"]),
+    ("toolbar.export", ["导出", "Export"]),
+    ("toolbar.import", ["导入", "Import"]),
+    ("toolbar.er_diagram", ["ER图", "ER diagram"]),
+    ("toolbar.history", ["历史", "History"]),
+    ("toolbar.privileges", ["权限", "Privileges"]),
+    ("toolbar.size_overview", ["大小概览", "Size overview"]),
+    ("toolbar.session_monitor", ["会话监控", "Sessions"]),
+    ("toolbar.copy_table", ["复制表", "Copy table"]),
+    ("toolbar.truncate_table", ["清空表", "Truncate table"]),
+    ("toolbar.generate_data", ["生成测试数据", "Generate test data"]),
+    ("toolbar.scheduled_queries", ["定时查询", "Scheduled queries"]),
+    ("toolbar.pin_snapshot", ["固定结果快照", "Pin result snapshot"]),
+    ("toolbar.compare_snapshot", ["与快照对比", "Compare with snapshot"]),
+    ("toolbar.audit_log", ["审计日志", "Audit log"]),
+    ("toolbar.open_file", ["打开 SQL 文件", "Open SQL file"]),
+    ("toolbar.save_file", ["保存 SQL 文件", "Save SQL file"]),
+    ("toolbar.workflows", ["工作流", "Workflows"]),
+    ("toolbar.api_server", ["API 服务", "API server"]),
+    ("toolbar.notifications", ["通知中心", "Notifications"]),
+    ("toolbar.log_viewer", ["运行日志", "Application log"]),
+    ("toolbar.data_search", ["全库数据搜索", "Search in database"]),
+    ("toolbar.pool_stats", ["连接池", "Connection pools"]),
+    ("toolbar.trash", ["回收站", "Trash"]),
+    ("toolbar.master_password", ["主密码", "Master password"]),
+    ("toolbar.benchmark", ["基准测试", "Benchmark query"]),
+    ("toolbar.profile", ["查询分析", "Query profile"]),
+    ("toolbar.row_bookmarks", ["行书签", "Row bookmarks"]),
+    ("toolbar.unseen_errors", ["通知中心（{} 个未查看的错误）", "Notifications ({} unseen errors)"]),
+    ("toolbar.create_menu", ["新建菜单", "New"]),
+    ("toolbar.new_table", ["新建表", "New table"]),
+    ("toolbar.new_database", ["新建库", "New database"]),
+    ("toolbar.new_user", ["新建用户", "New user"]),
+    ("toolbar.about", ["关于我们", "About"]),
+    ("toolbar.start_tour", ["新手引导", "Guided tour"]),
+    ("toolbar.edit_theme", ["编辑自定义主题", "Edit custom theme"]),
+    ("toolbar.font_settings", ["字体设置", "Font settings"]),
+    ("toolbar.language", ["界面语言", "Language"]),
+    ("toolbar.to_light", ["切换到日间模式 (Ctrl+D)", "Switch to light mode (Ctrl+D)"]),
+    ("toolbar.to_dark", ["切换到夜间模式 (Ctrl+D)", "Switch to dark mode (Ctrl+D)"]),
+    ("toolbar.sidebar", ["侧边栏 (Ctrl+B)", "Sidebar (Ctrl+B)"]),
+    ("toolbar.editor", ["编辑器 (Ctrl+J)", "Editor (Ctrl+J)"]),
+    ("toolbar.zoom_out", ["缩小 (Ctrl+-)", "Zoom out (Ctrl+-)"]),
+    ("toolbar.zoom_reset", ["重置缩放 (Ctrl+0)", "Reset zoom (Ctrl+0)"]),
+    ("toolbar.zoom_in", ["放大 (Ctrl++)", "Zoom in (Ctrl++)"]),
+    ("toolbar.refresh", ["刷新 (F5)", "Refresh (F5)"]),
+    ("toolbar.keybindings", ["快捷键设置", "Keyboard shortcuts"]),
+    ("toolbar.help", ["帮助 (F1)", "Help (F1)"]),
+    ("toolbar.select_theme", ["选择主题 (Ctrl+Shift+L)", "Select theme (Ctrl+Shift+L)"]),
+    ("toolbar.theme_hint", ["j/k 选择  Enter 确认  Esc 取消", "j/k select  Enter confirm  Esc cancel"]),
+    // 字体设置
+    ("fonts.title", ["🔤 字体设置", "🔤 Font settings"]),
+    ("fonts.filter_hint", ["字体名称", "Font name"]),
+    ("fonts.monospace_only", ["编辑器只列出等宽字体", "Only list monospace fonts for the editor"]),
+    ("fonts.none_found", ["未找到系统字体，只能使用内置字体", "No system fonts found, only built-in fonts are available"]),
+    ("fonts.ui", ["界面", "Interface"]),
+    ("fonts.editor", ["SQL 编辑器", "SQL editor"]),
+    ("fonts.grid", ["数据表格", "Data grid"]),
+    ("fonts.builtin_ui", ["内置 (Noto Sans SC)", "Built-in (Noto Sans SC)"]),
+    ("fonts.builtin_editor", ["内置等宽 (Hack)", "Built-in monospace (Hack)"]),
+    ("fonts.builtin_grid", ["跟随界面字体", "Same as interface"]),
+    ("fonts.fallback_note", [
+        "系统字体缺少的字符（如中文）会自动使用内置字体显示",
+        "Characters missing from a system font (e.g. CJK) fall back to the built-in font",
+    ]),
+    ("fonts.applied", ["字体设置已应用", "Font settings applied"]),
+    ("fonts.save_failed", ["保存字体设置失败: {}", "Failed to save font settings: {}"]),
+    // 首选项
+    ("prefs.title", ["⚙ 首选项", "⚙ Preferences"]),
+    ("prefs.tab_general", ["常规", "General"]),
+    ("prefs.tab_editor", ["编辑器", "Editor"]),
+    ("prefs.tab_grid", ["表格", "Grid"]),
+    ("prefs.tab_database", ["数据库", "Database"]),
+    ("prefs.tab_shortcuts", ["快捷键", "Shortcuts"]),
+    ("prefs.language", ["界面语言:", "Language:"]),
+    ("prefs.language_coverage", [
+        "已翻译工具栏、首选项、命令面板、通知以及连接、导入、导出、建表等对话框，其余界面暂时以中文显示",
+        "The toolbar, preferences, command palette, notifications and the connection, import, export and create-table dialogs are translated; other screens are still shown in Chinese",
+    ]),
+    ("prefs.ui_scale", ["界面缩放:", "UI scale:"]),
+    ("prefs.confirmations", ["确认提示", "Confirmations"]),
+    ("prefs.confirm_delete_connection", ["删除连接前确认", "Confirm before deleting a connection"]),
+    ("prefs.confirm_drop_object", [
+        "删除触发器、存储过程等对象前确认",
+        "Confirm before dropping triggers, routines and other objects",
+    ]),
+    ("prefs.confirm_grid_delete", [
+        "保存表格修改前检查生成的 SQL",
+        "Review generated SQL before saving grid edits",
+    ]),
+    ("prefs.history_limit", ["查询历史条数:", "Query history size:"]),
+    ("prefs.command_history_limit", ["每个连接的命令历史条数:", "Command history per connection:"]),
+    ("prefs.trash_retention_days", ["回收站保留天数:", "Keep trash items (days):"]),
+    ("prefs.open_fonts", ["🔤 字体设置...", "🔤 Font settings..."]),
+    ("prefs.default_query_limit", ["表预览行数 (LIMIT):", "Table preview rows (LIMIT):"]),
+    ("prefs.max_result_rows", ["结果集行数上限:", "Maximum result rows:"]),
+    ("prefs.max_result_rows_hint", [
+        "超出的行会被截断，避免占用过多内存",
+        "Rows beyond this limit are truncated to save memory",
+    ]),
+    ("prefs.memory_warning_mb", ["内存提醒阈值:", "Memory warning threshold:"]),
+    ("prefs.memory_warning_mb_hint", [
+        "所有标签页的查询结果估算占用的内存超过该值时提醒",
+        "Warn when the estimated memory used by the results of all tabs exceeds this value",
+    ]),
+    ("prefs.spill_large_results", ["超过内存阈值时把后台结果写入磁盘", "Move background results to disk above the threshold"]),
+    ("prefs.spill_large_results_hint", [
+        "把其他标签页的大结果集写入临时文件并释放内存，切换回该标签页时再读回",
+        "Write large results of other tabs to temporary files to free memory, and read them back when switching to the tab",
+    ]),
+    ("prefs.image_preview_dir", ["图片预览目录:", "Image preview folder:"]),
+    ("prefs.image_preview_dir_empty", ["不预览", "Disabled"]),
+    ("prefs.image_preview_dir_hint", [
+        "单元格中的 PNG 路径相对于该目录读取并在悬停时预览，目录外的文件不会读取",
+        "PNG paths in cells are read relative to this folder and previewed on hover; files outside it are never read",
+    ]),
+    ("prefs.show_key_hints", ["显示按键提示栏", "Show the key hint bar"]),
+    ("prefs.show_key_hints_hint", [
+        "在窗口底部显示当前区域可用的按键，输入 g 等命令前缀后显示可以继续输入的按键",
+        "Show the keys available in the focused area at the bottom of the window, including the keys that can follow a prefix such as g",
+    ]),
+    ("prefs.show_grid_footer", ["显示底部统计栏", "Show the summary footer"]),
+    ("prefs.show_grid_footer_hint", [
+        "在表格下方显示行数，以及选中单元格或当前列的计数、求和、平均、最小和最大值",
+        "Show the row count and COUNT/SUM/AVG/MIN/MAX of the selected cells or current column below the grid",
+    ]),
+    ("prefs.timestamp_display", ["时间戳显示:", "Timestamp display:"]),
+    ("prefs.timestamp_display_hint", [
+        "时间戳列转换到所选时区后显示，不带时区的值视为服务器时区的时间；编辑和复制仍使用原始值",
+        "Timestamp columns are converted to the chosen time zone; values without an offset are treated as server time. Editing and copying use the original value",
+    ]),
+    ("prefs.timestamp_as_stored", ["原样显示", "As stored"]),
+    ("prefs.timestamp_utc", ["UTC", "UTC"]),
+    ("prefs.timestamp_server", ["服务器时区", "Server time zone"]),
+    ("prefs.timestamp_local", ["本地时区", "Local time zone"]),
+    ("prefs.number_separators", ["数字分隔符:", "Number separators:"]),
+    ("prefs.number_format_hint", [
+        "表格中数字的默认显示格式，可在列头右键菜单中为单独的列设置（按表保存）",
+        "Default display format for numbers in the grid. Individual columns can be set from the header context menu (saved per table)",
+    ]),
+    ("prefs.number_decimals", ["小数位数:", "Decimal places:"]),
+    ("prefs.number_decimals_keep", ["原样", "As stored"]),
+    ("prefs.number_scientific", ["数字使用科学计数法", "Show numbers in scientific notation"]),
+    ("prefs.number_align_right", ["数字右对齐", "Right-align numbers"]),
+    ("prefs.connect_timeout", ["默认连接超时:", "Default connection timeout:"]),
+    ("prefs.query_timeout", ["默认查询超时:", "Default query timeout:"]),
+    ("prefs.metadata_refresh", ["元数据刷新间隔:", "Metadata refresh interval:"]),
+    ("prefs.metadata_refresh_hint", [
+        "在后台定期重新读取表和列信息，0 表示只在执行 DDL 后刷新",
+        "Re-read tables and columns in the background; 0 refreshes only after DDL statements",
+    ]),
+    ("prefs.keep_alive", ["保活检查间隔:", "Keep-alive interval:"]),
+    ("prefs.keep_alive_hint", [
+        "定期向已连接的数据库发送轻量查询，断开时自动重连，0 表示不检查",
+        "Ping connected databases periodically and reconnect when they drop; 0 disables the checks",
+    ]),
+    ("prefs.pool_idle_ttl", ["空闲连接池断开:", "Close idle pools after:"]),
+    ("prefs.pool_idle_ttl_hint", [
+        "连接池超过该时间未使用时自动断开，下次查询时重新连接，0 表示不自动断开",
+        "Disconnect connection pools unused for this long; they reconnect on the next query. 0 keeps them open",
+    ]),
+    ("prefs.desktop_notify", ["长查询桌面通知:", "Notify for queries over:"]),
+    ("prefs.desktop_notify_hint", [
+        "查询超过该时长且窗口不在前台时，完成或失败后发送桌面通知，0 表示不发送",
+        "Show a desktop notification when a query running this long finishes while the window is in the background. 0 disables it",
+    ]),
+    ("prefs.max_parallel_queries", ["同时执行查询数:", "Parallel queries:"]),
+    ("prefs.max_parallel_queries_hint", [
+        "每个连接同时执行的查询数上限，超出的查询排队等待",
+        "Maximum queries running at once per connection; further queries wait in a queue",
+    ]),
+    ("prefs.timeout_hint", [
+        "各连接可在连接设置中单独覆盖超时",
+        "Each connection can override these timeouts in its connection settings",
+    ]),
+    ("prefs.edit_shortcuts", ["⌨ 编辑快捷键...", "⌨ Edit shortcuts..."]),
+    ("prefs.modified", ["已修改，点击确定保存", "Modified, click OK to save"]),
+    ("prefs.saved", ["首选项已保存", "Preferences saved"]),
+    ("toolbar.preferences", ["首选项", "Preferences"]),
+    // 命令面板
+    ("palette.placeholder", ["输入命令、连接、表或主题名称...", "Type a command, connection, table or theme..."]),
+    ("palette.no_match", ["没有匹配的命令", "No matching commands"]),
+    ("palette.hint", ["↑/↓ 选择  Enter 执行  Esc 关闭", "↑/↓ select  Enter run  Esc close"]),
+    ("palette.command", ["命令", "Command"]),
+    ("palette.connect", ["连接", "Connect"]),
+    ("palette.database", ["数据库", "Database"]),
+    ("palette.table", ["打开表", "Open table"]),
+    ("palette.theme", ["主题", "Theme"]),
+    ("palette.favorite", ["运行收藏", "Run favorite"]),
+    ("palette.recent_placeholder", ["输入最近使用的表或数据库名称...", "Type a recently used table or database..."]),
+    // 结果搜索栏和列名补全
+    ("search.label", ["搜索", "Search"]),
+    ("search.placeholder", ["在结果中搜索...", "Search results..."]),
+    ("search.in_column", ["列", "in"]),
+    ("search.all_columns", ["所有列", "All columns"]),
+    ("search.clear", ["清空搜索", "Clear search"]),
+    ("search.regex", ["正则表达式", "Regular expression"]),
+    ("search.whole_word", ["全词匹配", "Match whole word"]),
+    ("search.case_sensitive", ["区分大小写", "Match case"]),
+    ("search.invalid_regex", ["正则表达式无效: {}", "Invalid regular expression: {}"]),
+    ("search.navigate_hint", ["在表格中按 n/N 跳转到下一个/上一个匹配", "Press n/N in the grid to jump to the next/previous match"]),
+    ("column_picker.no_match", ["没有匹配的列", "No matching columns"]),
+    // 编辑器命令行
+    ("cmdline.placeholder", ["输入命令，Tab 补全", "Type a command, Tab to complete"]),
+    ("cmdline.hint", ["Tab 补全  Enter 执行  Esc 取消", "Tab complete  Enter run  Esc cancel"]),
+    ("cmdline.empty", ["请输入命令", "Enter a command"]),
+    ("cmdline.unknown", ["未知命令: {}", "Unknown command: {}"]),
+    ("cmdline.no_arg", ["命令 {} 不接受参数", "Command {} takes no arguments"]),
+    ("cmdline.missing_arg", ["命令 {} 缺少参数", "Command {} requires an argument"]),
+    ("cmdline.unknown_format", ["不支持的导出格式: {}", "Unsupported export format: {}"]),
+    ("cmdline.no_connection", ["连接 {} 不存在", "Connection {} does not exist"]),
+    ("cmdline.no_result", ["没有可导出的查询结果", "No query result to export"]),
+    ("cmdline.run", ["执行 SQL", "Run SQL"]),
+    ("cmdline.explain", ["分析执行计划", "Explain query plan"]),
+    ("cmdline.preview", ["预览 UPDATE/DELETE 影响的行", "Preview rows affected by UPDATE/DELETE"]),
+    ("cmdline.check", ["检查 SQL（不执行）", "Validate SQL without executing"]),
+    ("cmdline.format", ["格式化 SQL", "Format SQL"]),
+    ("cmdline.clear", ["清空编辑器", "Clear the editor"]),
+    ("cmdline.write", ["保存表格修改", "Save table edits"]),
+    ("cmdline.quit", ["关闭当前标签页", "Close the current tab"]),
+    ("cmdline.tabnew", ["新建查询标签页", "Open a new query tab"]),
+    ("cmdline.tabnext", ["下一个标签页", "Next tab"]),
+    ("cmdline.tabprev", ["上一个标签页", "Previous tab"]),
+    ("cmdline.connect", ["切换到连接 <名称>", "Switch to connection <name>"]),
+    ("cmdline.table", ["打开表 <名称>", "Open table <name>"]),
+    ("cmdline.export", ["导出结果 [csv|json|sql]", "Export results [csv|json|sql]"]),
+    ("cmdline.help", ["显示帮助", "Show help"]),
+    // SQL 文件
+    ("file.opened", ["已打开 {}", "Opened {}"]),
+    ("file.saved", ["已保存到 {}", "Saved to {}"]),
+    ("file.reloaded", ["已重新加载 {}", "Reloaded {}"]),
+    ("file.open_failed", ["打开文件失败: {}", "Failed to open file: {}"]),
+    ("file.save_failed", ["保存文件失败: {}", "Failed to save file: {}"]),
+    ("file.too_many_tabs", ["标签页数量已达上限", "Too many tabs open"]),
+    ("file.changed_title", ["文件已在外部修改", "File changed on disk"]),
+    ("file.changed_message", ["{} 已被其他程序修改。", "{} was modified by another program."]),
+    ("file.removed_message", ["{} 已被删除或无法读取。", "{} was deleted or can no longer be read."]),
+    ("file.changed_hint", ["r 重新加载  k/Esc 保留编辑器内容", "r reload  k/Esc keep editor contents"]),
+    ("file.reload", ["重新加载", "Reload"]),
+    ("file.keep", ["保留我的修改", "Keep mine"]),
+    // 拖放文件
+    ("drop.hint", ["释放以打开：SQLite 数据库 / .sql 脚本 / CSV / JSON", "Drop to open: SQLite database / .sql script / CSV / JSON"]),
+    ("drop.unsupported", ["不支持的文件类型: {}", "Unsupported file type: {}"]),
+    ("drop.sqlite_added", ["已添加 SQLite 连接 {}", "Added SQLite connection {}"]),
+    ("drop.import_needs_connection", ["请先连接数据库再导入 CSV / JSON", "Connect to a database before importing CSV / JSON"]),
+    // 主题
+    ("theme.saved", ["主题 {} 已保存", "Theme {} saved"]),
+    ("theme.deleted", ["主题 {} 已删除", "Theme {} deleted"]),
+    ("theme.imported", ["已导入主题 {}，保存后生效", "Imported theme {}, save it to keep it"]),
+    ("theme.exported", ["主题已导出到 {}", "Theme exported to {}"]),
+    ("language.changed", ["界面语言已切换为 {}", "Interface language changed to {}"]),
+    // 主界面通知
+    ("notify.explain_console", ["命令控制台不支持执行计划分析", "The command console cannot analyze execution plans"]),
+    ("notify.explain_running", ["正在分析执行计划...", "Analyzing execution plan..."]),
+    ("notify.json_invalid", ["JSON 格式错误: {}", "Invalid JSON: {}"]),
+    ("notify.create_table_unsupported", ["{} 不支持建表", "{} does not support creating tables"]),
+    ("notify.er_opened", ["ER 关系图已打开", "ER diagram opened"]),
+    ("notify.er_closed", ["ER 关系图已关闭", "ER diagram closed"]),
+    ("notify.users_unsupported", ["{} 不支持用户管理", "{} does not support user management"]),
+    ("notify.privileges_unsupported", ["{} 暂不支持权限管理", "{} does not support privilege management yet"]),
+    ("notify.sequence_next_value", ["自增列 {} 的下一个值: {}", "Next value of auto-increment column {}: {}"]),
+    ("notify.trigger_loaded", ["触发器定义已加载到编辑器", "Trigger definition loaded into the editor"]),
+    ("notify.routine_loaded", ["存储过程/函数定义已加载到编辑器", "Routine definition loaded into the editor"]),
+    ("notify.no_result_to_pin", ["没有可固定的查询结果", "No query result to pin"]),
+    ("notify.snapshot_pinned", [
+        "已固定结果快照（{} 行），再次执行查询后可与快照对比",
+        "Pinned result snapshot ({} rows); run the query again to compare",
+    ]),
+    ("notify.no_snapshot", [
+        "当前标签页没有结果快照，请先固定结果快照",
+        "This tab has no result snapshot; pin one first",
+    ]),
+    ("notify.no_result_to_compare", ["没有可对比的查询结果", "No query result to compare"]),
+    ("notify.diff_exported", ["对比报告已导出到 {}", "Comparison report exported to {}"]),
+    ("notify.switched_to", ["切换到: {}", "Switched to: {}"]),
+    ("notify.no_recent_objects", ["还没有最近使用的表或数据库", "No recently used tables or databases yet"]),
+    ("notify.recent_connection_missing", ["连接 {} 已不存在", "Connection {} no longer exists"]),
+    // 异步任务结果通知
+    ("notify.statement_done", ["执行成功，影响 {} 行 ({}ms)", "Done, {} rows affected ({}ms)"]),
+    ("notify.query_done", ["查询完成，返回 {} 行 ({}ms)", "Query returned {} rows ({}ms)"]),
+    ("notify.query_done_truncated", [
+        "查询完成，返回 {} 行（已截断，原始 {} 行，建议使用 LIMIT）({}ms)",
+        "Query returned {} rows (truncated from {} rows, consider using LIMIT) ({}ms)",
+    ]),
+    ("notify.page_loaded", ["第 {} 页，第 {} - {} 行 ({}ms)", "Page {}, rows {} - {} ({}ms)"]),
+    ("notify.page_load_failed", ["读取第 {} 页失败: {}", "Failed to load page {}: {}"]),
+    ("notify.no_idle_pools", ["没有空闲的连接池", "No idle connection pools"]),
+    ("notify.idle_pools_closed", ["已断开 {} 个空闲连接池", "Closed {} idle connection pools"]),
+    ("notify.scratchpad_saved", ["草稿本已保存到 {}", "Scratchpad saved to {}"]),
+    ("notify.scratchpad_save_failed", ["保存草稿本失败: {}", "Failed to save the scratchpad: {}"]),
+    ("notify.connected_tables", ["已连接到 {} ({} 张表)", "Connected to {} ({} tables)"]),
+    ("notify.connected_databases", ["已连接到 {} ({} 个数据库)", "Connected to {} ({} databases)"]),
+    ("notify.database_selected", ["已选择数据库 {} ({} 张表)", "Selected database {} ({} tables)"]),
+    ("notify.select_database_failed", ["选择数据库失败: {}", "Failed to select the database: {}"]),
+    ("notify.load_more_tables_failed", ["加载更多表失败: {}", "Failed to load more tables: {}"]),
+    ("notify.redis_ttl", ["TTL {} 秒", "TTL {} s"]),
+    ("notify.redis_no_expiry", ["永不过期", "no expiry"]),
+    ("notify.redis_key_info", ["{} [{}] {}，{} 项", "{} [{}] {}, {} items"]),
+    ("notify.load_triggers_failed", ["加载触发器失败: {}", "Failed to load triggers: {}"]),
+    ("notify.load_sequences_failed", ["加载序列失败: {}", "Failed to load sequences: {}"]),
+    ("notify.load_routines_failed", ["加载存储过程失败: {}", "Failed to load routines: {}"]),
+    ("notify.load_foreign_keys_failed", ["加载外键关系失败: {}", "Failed to load foreign keys: {}"]),
+    ("notify.er_summary", ["ER图: {} 张表, {} 个关系", "ER diagram: {} tables, {} relationships"]),
+    ("notify.er_summary_no_fk", ["ER图: {} 张表（未发现外键关系）", "ER diagram: {} tables (no foreign keys found)"]),
+    ("notify.er_inferred", ["ER图: 推断出 {} 个关系", "ER diagram: inferred {} relationships"]),
+    ("notify.table_schema_failed", ["获取表 {} 结构失败: {}", "Failed to load the structure of table {}: {}"]),
+    ("notify.key_changed", ["连接 {} 的加密密钥已修改", "Encryption key of connection {} changed"]),
+    ("notify.key_change_failed", ["修改加密密钥失败: {}", "Failed to change the encryption key: {}"]),
+    ("notify.ddl_done", ["DDL 执行成功", "DDL executed"]),
+    ("notify.ddl_failed", ["DDL 执行失败: {}", "DDL failed: {}"]),
+    ("notify.grid_saved", ["已保存，影响 {} 行 ({}ms)", "Saved, {} rows affected ({}ms)"]),
+    ("notify.grid_save_rolled_back", ["保存失败，所有修改已回滚: {}", "Save failed, all changes were rolled back: {}"]),
+    ("notify.grid_save_failed", ["保存失败: {}", "Save failed: {}"]),
+    ("notify.privileges_updated", ["权限已更新", "Privileges updated"]),
+    ("notify.privileges_failed", ["权限修改失败: {}", "Failed to change privileges: {}"]),
+    ("notify.table_action_done", ["{} {} 成功", "{} {} succeeded"]),
+    ("notify.table_action_failed", ["{} {} 失败: {}", "{} {} failed: {}"]),
+    ("notify.datagen_done", ["已向 {} 插入 {} 行测试数据", "{}: inserted {} rows of test data"]),
+    ("notify.datagen_cancelled", ["已取消生成，{} 已插入 {} 行", "Generation cancelled, {} received {} rows"]),
+    ("notify.datagen_failed", ["生成测试数据失败（已插入 {} 行）: {}", "Test data generation failed ({} rows inserted): {}"]),
+    ("notify.session_kill_sent", ["已发送终止请求", "Termination request sent"]),
+    ("notify.session_kill_failed", ["终止会话失败: {}", "Failed to terminate the session: {}"]),
+    ("notify.maintenance_running", ["正在执行 {}...", "Running {}..."]),
+    ("notify.maintenance_done", ["{} 完成 ({}ms)", "{} finished ({}ms)"]),
+    ("notify.maintenance_partial", ["{} 完成，部分表出错:\n{}", "{} finished with errors on some tables:\n{}"]),
+    ("notify.maintenance_failed", ["{} 失败: {}", "{} failed: {}"]),
+    // 数据库操作通知
+    ("notify.connect_failed", ["连接失败: {}", "Connection failed: {}"]),
+    ("notify.size_overview_unsupported", ["{} 暂不支持大小概览", "{} does not support the size overview yet"]),
+    ("notify.sessions_unsupported", ["{} 暂不支持会话监控", "{} does not support session monitoring yet"]),
+    ("notify.datagen_unsupported", ["{} 不支持生成测试数据", "{} does not support test data generation"]),
+    ("notify.action_unsupported", ["{} 不支持{}", "{} does not support: {}"]),
+    ("notify.ddl_exported", ["建表语句已导出到 {}", "CREATE statement exported to {}"]),
+    ("notify.overwrite_failed", ["无法覆盖文件: {}", "Cannot overwrite the file: {}"]),
+    ("notify.database_attached", ["已附加数据库 {}", "Attached database {}"]),
+    ("notify.database_detached", ["已分离数据库 {}", "Detached database {}"]),
+    // ER 图、预览和对话框通知
+    ("notify.er_no_tables", ["数据库 {} 没有表，请先选择数据库", "Database {} has no tables, select a database first"]),
+    ("notify.er_loading", [
+        "ER图: 加载 {} 张表，正在获取结构... ({})",
+        "ER diagram: loading {} tables, fetching structure... ({})",
+    ]),
+    ("notify.er_exported", ["ER 图已导出到 {}", "ER diagram exported to {}"]),
+    ("notify.er_png_failed", ["导出 PNG 失败: {}", "PNG export failed: {}"]),
+    ("notify.create_sql_generated", [
+        "建表语句已生成，按 Ctrl+Enter 执行",
+        "CREATE statement generated, press Ctrl+Enter to run it",
+    ]),
+    ("notify.sql_generated", ["SQL 已生成，按 Ctrl+Enter 执行", "SQL generated, press Ctrl+Enter to run it"]),
+    ("notify.write_preview_unsupported", ["{} 不支持影响行预览", "{} does not support previewing affected rows"]),
+    ("notify.write_preview_single", [
+        "只能预览单条 UPDATE 或 DELETE 语句",
+        "Only a single UPDATE or DELETE statement can be previewed",
+    ]),
+    ("notify.bulk_edit_unsupported", ["{} 不支持批量修改", "{} does not support bulk edit"]),
+    ("notify.bulk_edit_needs_filter", ["没有启用的筛选条件，批量修改需要先设置筛选", "No active filters, set a filter before bulk editing"]),
+    ("notify.filter_sql_failed", ["无法生成筛选 SQL: {}", "Cannot build the filter SQL: {}"]),
+    ("notify.review_copied", ["SQL 已复制到剪贴板，修改未保存", "SQL copied to the clipboard, changes not saved"]),
+    ("notify.sql_copied_to_editor", ["SQL 已复制到编辑器", "SQL copied to the editor"]),
+    ("notify.sqlite_create_at", ["SQLite 数据库将创建于: {}", "The SQLite database will be created at: {}"]),
+    ("notify.keybindings_saved", ["快捷键设置已保存", "Key bindings saved"]),
+    ("notify.keybindings_save_failed", ["保存快捷键设置失败: {}", "Failed to save key bindings: {}"]),
+    ("notify.log_path_unknown", ["无法获取日志文件路径", "Cannot determine the log file path"]),
+    ("notify.vault_unlocked", ["已解锁 {} 个连接", "Unlocked {} connections"]),
+    ("notify.master_password_set", ["已设置主密码，连接配置已加密保存", "Master password set, connection settings are now encrypted"]),
+    ("notify.master_password_removed", ["已取消主密码", "Master password removed"]),
+    ("notify.vault_still_locked", [
+        "连接配置未解锁，已保存的连接暂不可用",
+        "Connection settings are locked, saved connections are unavailable",
+    ]),
+    ("notify.csv_convert_failed", ["CSV 转换失败: {}", "CSV conversion failed: {}"]),
+    ("notify.json_convert_failed", ["JSON 转换失败: {}", "JSON conversion failed: {}"]),
+    ("notify.import_no_statements", ["没有可执行的 SQL 语句", "No SQL statements to run"]),
+    ("notify.import_no_valid_statements", ["没有有效的 SQL 语句", "No valid SQL statements"]),
+    ("notify.import_started", [
+        "导入中: {} 条语句已提交执行（使用事务: 否）",
+        "Importing: {} statements submitted (without a transaction)",
+    ]),
+    ("notify.import_started_transaction", [
+        "导入中: {} 条语句已提交执行（使用事务: 是）",
+        "Importing: {} statements submitted (in a transaction)",
+    ]),
+    ("notify.api_disabled", [
+        "未启用 API 服务：请使用 --features {} 重新编译",
+        "API server not available: rebuild with --features {}",
+    ]),
+    ("notify.api_settings_saved", ["API 服务设置已保存", "API server settings saved"]),
+    ("notify.api_started", [
+        "API 服务已启动: http://127.0.0.1:{}/queries",
+        "API server started: http://127.0.0.1:{}/queries",
+    ]),
+    ("notify.api_stopped", ["API 服务已停止", "API server stopped"]),
+    ("notify.api_token_generated", [
+        "已生成 API 访问令牌，可在 API 服务面板中复制",
+        "API access token generated, copy it from the API server panel",
+    ]),
+    ("notify.api_start_failed", ["API 服务启动失败: {}", "Failed to start the API server: {}"]),
+    ("notify.api_port_failed", ["API 服务启动失败（端口 {}）: {}", "Failed to start the API server (port {}): {}"]),
+    // 查询队列、定时查询和工作流通知
+    ("notify.query_queued", [
+        "连接 {} 上已有 {} 个查询在执行，当前查询排在第 {} 位",
+        "{} already has {} queries running, this query is number {} in the queue",
+    ]),
+    ("notify.queued_query_cancelled", ["已取消 {} 排队中的查询", "Cancelled the queued query of {}"]),
+    ("notify.schedule_started", ["定时查询「{}」已开始（{}）", "Scheduled query '{}' started ({})"]),
+    ("notify.schedule_deleted", ["已删除定时查询「{}」", "Deleted scheduled query '{}'"]),
+    ("notify.schedule_tab_closed", ["结果标签页已关闭，已停止定时查询「{}」", "The result tab was closed, stopped scheduled query '{}'"]),
+    ("notify.schedule_failed", ["定时查询「{}」执行失败", "Scheduled query '{}' failed"]),
+    ("notify.workflow_saved", ["工作流已保存", "Workflow saved"]),
+    ("notify.workflow_done", ["工作流「{}」已完成", "Workflow '{}' finished"]),
+    ("notify.workflow_failed", ["工作流「{}」有 {} 个步骤失败", "Workflow '{}' had {} failed steps"]),
+    ("notify.validation_unsupported", ["{} 不支持检查 SQL", "{} does not support SQL validation"]),
+    ("notify.validation_passed", ["检查通过：{} 条语句", "Validation passed: {} statements"]),
+    ("notify.server_filter_unsupported", ["{} 不支持服务端筛选", "{} does not support server-side filtering"]),
+    ("notify.data_search_unsupported", ["{} 不支持全库数据搜索", "{} does not support searching the whole database"]),
+    ("notify.data_search_no_tables", ["当前数据库没有可搜索的表", "The current database has no searchable tables"]),
+    ("notify.result_memory_high", [
+        "查询结果约占用 {} 内存，超过了 {} MB 的提醒阈值。可以关闭不需要的标签页，或在首选项中启用磁盘缓存",
+        "Query results use about {} of memory, above the {} MB warning threshold. Close tabs you no longer need or enable disk spilling in Preferences",
+    ]),
+    ("notify.spill_read_failed", ["读取缓存的查询结果失败: {}", "Failed to read the spilled query result: {}"]),
+    // 宏、回收站、书签和审计通知
+    ("notify.macro_recording", ["开始录制宏，再按 Q 停止", "Recording macro, press Q again to stop"]),
+    ("notify.macro_recorded", ["宏录制完成，共 {} 个按键，按 @ 回放", "Macro recorded with {} keys, press @ to replay"]),
+    ("notify.macro_empty", ["没有录制到按键，保留之前的宏", "No keys recorded, keeping the previous macro"]),
+    ("notify.macro_replay", ["回放宏 {} 次 (@)", "Replaying macro {} times (@)"]),
+    ("notify.macro_none", ["还没有录制的宏，按 Q 开始录制", "No macro recorded yet, press Q to start recording"]),
+    ("notify.connection_trashed", ["已将连接「{}」移到回收站", "Moved connection '{}' to the trash"]),
+    ("notify.connection_restored", ["已恢复连接「{}」", "Restored connection '{}'"]),
+    ("notify.no_closed_tabs", ["没有最近关闭的标签页", "No recently closed tabs"]),
+    ("notify.tab_limit", ["标签页数量已达上限，请先关闭一些标签页", "Tab limit reached, close some tabs first"]),
+    ("notify.bookmark_needs_table", ["只能收藏打开的表中的行", "Only rows of an opened table can be bookmarked"]),
+    ("notify.bookmark_null_key", ["主键为 NULL 的行无法收藏", "Rows with a NULL primary key cannot be bookmarked"]),
+    ("notify.bookmark_added", ["已收藏 {}.{} = {}", "Bookmarked {}.{} = {}"]),
+    ("notify.bookmark_removed", ["已取消收藏 {}.{} = {}", "Removed bookmark {}.{} = {}"]),
+    ("notify.audit_path_unknown", ["无法获取审计日志路径", "Cannot determine the audit log path"]),
+    ("notify.audit_exported", ["已导出 {} 条审计记录到 {}", "Exported {} audit records to {}"]),
+    // 标签页、恢复和其他通知
+    // 连接断开和恢复通知
+    ("notify.connection_lost", ["连接 {} 已断开: {}。可在侧边栏重新连接", "Connection {} was lost: {}. Reconnect from the sidebar"]),
+    ("notify.recovery_partial", [
+        "已恢复 {} 个标签页，{} 个因数量上限未恢复",
+        "Restored {} tabs, {} were skipped because of the tab limit",
+    ]),
+    ("notify.tab_pinned", ["已固定标签页「{}」", "Pinned tab '{}'"]),
+    ("notify.tab_unpinned", ["已取消固定标签页「{}」", "Unpinned tab '{}'"]),
+    ("notify.tab_detached", [
+        "已在新窗口中打开「{}」，关闭窗口即合并回主窗口",
+        "Opened '{}' in a new window, close the window to merge it back",
+    ]),
+    ("notify.tab_reattached", ["「{}」已合并回主窗口", "'{}' merged back into the main window"]),
+    ("notify.recovery_tab_limit", ["标签页数量已达上限，无法恢复", "Tab limit reached, cannot restore"]),
+    ("notify.recovery_restored", ["已恢复 {} 个标签页", "Restored {} tabs"]),
+    ("notify.connection_recovered", ["连接 {} 已恢复", "Connection {} is back"]),
+    ("notify.connection_reconnected", ["连接 {} 已自动重新连接", "Connection {} reconnected automatically"]),
+    ("notify.config_reload_failed", ["配置文件有错误，未重新加载: {}", "The config file has errors and was not reloaded: {}"]),
+    ("notify.config_reloaded", ["已重新加载配置文件中的主题和快捷键", "Reloaded the theme and key bindings from the config file"]),
+    ("notify.masking_not_saved", [
+        "未连接数据库，敏感列标记只在当前结果中生效",
+        "Not connected, the sensitive column mark only applies to the current result",
+    ]),
+    ("notify.masking_marked", ["已将 {} 标记为敏感列（{}）", "Marked {} as a sensitive column ({})"]),
+    ("notify.print_view_saved", [
+        "已生成打印视图（{} 行，{} 页）：{}，用浏览器打开后打印",
+        "Print view created ({} rows, {} pages): {}, open it in a browser to print",
+    ]),
+    ("notify.print_view_failed", ["保存打印视图失败: {}", "Failed to save the print view: {}"]),
+    ("notify.pivot_exported", ["透视表已导出到 {}", "Pivot table exported to {}"]),
+    ("notify.filter_preset_saved", ["已保存筛选预设: {}", "Saved filter preset: {}"]),
+    ("notify.stream_cancelled", ["已取消读取，显示已读取的 {} 行", "Reading cancelled, showing the {} rows read so far"]),
+    ("notify.benchmark_no_query", ["编辑器中没有要测试的查询", "No query in the editor to benchmark"]),
+    ("notify.benchmark_read_only", ["基准测试只能执行只读查询", "Benchmarks can only run read-only queries"]),
+    ("notify.tour_reopen_hint", [
+        "可以随时在帮助 (F1) 中重新打开新手引导",
+        "You can reopen the guided tour from Help (F1) at any time",
+    ]),
+    // 侧边栏
+    ("sidebar.queued_queries", [" {} 排队", " {} queued"]),
+    ("sidebar.queued_queries_hint", ["该连接有 {} 个查询等待执行", "{} queries waiting to run on this connection"]),
+    ("sidebar.filter_connections", ["筛选连接", "Filter connections"]),
+    ("sidebar.no_matching_connections", ["没有匹配的连接", "No matching connections"]),
+    ("sidebar.connections_title", ["🔗 连接", "🔗 Connections"]),
+    ("sidebar.section_connections", ["连接", "Connections"]),
+    ("sidebar.section_databases", ["数据库", "Databases"]),
+    ("sidebar.section_tables", ["表", "Tables"]),
+    ("sidebar.section_triggers", ["触发器", "Triggers"]),
+    ("sidebar.section_routines", ["存储过程", "Routines"]),
+    ("sidebar.section_sequences", ["序列", "Sequences"]),
+    ("sidebar.section_filters", ["筛选", "Filters"]),
+    ("sidebar.new_connection_hint", ["新建连接 (Ctrl+N)", "New connection (Ctrl+N)"]),
+    ("sidebar.new_scratchpad_hint", [
+        "新建草稿本（内存 SQLite，断开后数据丢弃）",
+        "New scratchpad (in-memory SQLite, discarded on disconnect)",
+    ]),
+    ("sidebar.no_connections", ["暂无连接", "No connections"]),
+    ("sidebar.no_connections_hint", ["创建一个数据库连接开始使用", "Create a database connection to get started"]),
+    ("sidebar.new_connection", ["+ 新建连接", "+ New connection"]),
+    ("sidebar.key_navigate", ["导航", "Navigate"]),
+    ("sidebar.key_select", ["选择", "Select"]),
+    ("sidebar.key_first_last", ["首/尾", "First/last"]),
+    ("sidebar.key_filter", ["筛选", "Filter"]),
+    ("sidebar.scratchpad", ["草稿本", "Scratchpad"]),
+    ("sidebar.disconnect", ["断开连接", "Disconnect"]),
+    ("sidebar.connect_icon", ["🔗 连接", "🔗 Connect"]),
+    ("sidebar.save_scratchpad", ["💾 保存到文件...", "💾 Save to file..."]),
+    ("sidebar.size_overview", ["📊 大小概览", "📊 Size overview"]),
+    ("sidebar.session_monitor", ["📈 会话监控", "📈 Session monitor"]),
+    ("sidebar.attach_database", ["📎 附加数据库...", "📎 Attach database..."]),
+    ("sidebar.change_key", ["🔑 修改加密密钥", "🔑 Change encryption key"]),
+    ("sidebar.delete_icon", ["🗑 删除", "🗑 Delete"]),
+    ("sidebar.reconnect", ["重新连接", "Reconnect"]),
+    ("sidebar.connect", ["连接", "Connect"]),
+    ("sidebar.delete_connection", ["删除连接", "Delete connection"]),
+    ("sidebar.maintenance", ["🛠 维护", "🛠 Maintenance"]),
+    ("sidebar.loading", ["正在加载...", "Loading..."]),
+    ("sidebar.load_more", ["⬇ 加载更多（已加载 {} 张）", "⬇ Load more ({} loaded)"]),
+    ("sidebar.no_tables", ["暂无数据表", "No tables"]),
+    ("sidebar.tables_title", ["数据表 ({}{})", "Tables ({}{})"]),
+    ("sidebar.tables_title_filtered", ["数据表 ({}/{}{})", "Tables ({}/{}{})"]),
+    ("sidebar.filter_loaded_tables", ["筛选已加载的表", "Filter loaded tables"]),
+    ("sidebar.filter_tables", ["筛选表", "Filter tables"]),
+    ("sidebar.query_top_icon", ["📊 查询前 100 行", "📊 Query the first 100 rows"]),
+    ("sidebar.structure_icon", ["🔍 查看表结构", "🔍 View structure"]),
+    ("sidebar.ddl_icon", ["📜 查看建表语句", "📜 View DDL"]),
+    ("sidebar.dependencies_icon", ["🕸 查看依赖关系", "🕸 View dependencies"]),
+    ("sidebar.rename_icon", ["✏ 重命名 [r]", "✏ Rename [r]"]),
+    ("sidebar.duplicate_icon", ["📑 复制表...", "📑 Duplicate table..."]),
+    ("sidebar.generate_data_icon", ["🎲 生成测试数据...", "🎲 Generate test data..."]),
+    ("sidebar.truncate_icon", ["🧹 清空表...", "🧹 Truncate table..."]),
+    ("sidebar.drop_icon", ["🗑 删除表 [d]", "🗑 Drop table [d]"]),
+    ("sidebar.no_matching_tables", ["没有匹配的表", "No matching tables"]),
+    ("sidebar.query_top", ["查询前 100 行", "Query the first 100 rows"]),
+    ("sidebar.structure", ["查看表结构", "View structure"]),
+    ("sidebar.ddl", ["查看建表语句", "View DDL"]),
+    ("sidebar.dependencies", ["查看依赖关系", "View dependencies"]),
+    ("sidebar.rename", ["重命名 [r]", "Rename [r]"]),
+    ("sidebar.duplicate", ["复制表...", "Duplicate table..."]),
+    ("sidebar.generate_data", ["生成测试数据...", "Generate test data..."]),
+    ("sidebar.truncate", ["清空表...", "Truncate table..."]),
+    ("sidebar.drop", ["删除表 [d]", "Drop table [d]"]),
+    // 确认对话框
+    ("confirm.delete_connection_title", ["删除连接", "Delete connection"]),
+    ("confirm.delete_connection", ["确定要删除连接 '{}' 吗？", "Delete connection '{}'?"]),
+    ("confirm.delete", ["删除", "Delete"]),
+    // 崩溃恢复
+    ("recovery.title", ["恢复未保存的内容", "Restore unsaved work"]),
+    ("recovery.restore", ["恢复", "Restore"]),
+    ("recovery.found_tabs", [
+        "上次没有正常退出（{}），发现 {} 个标签页的 SQL",
+        "The app did not exit normally last time ({}), found SQL in {} tabs",
+    ]),
+    ("recovery.found_edits", ["和表 {} 中 {} 处未保存的修改", "and unsaved changes in table {} ({} statements)"]),
+    ("recovery.question", ["。\n是否恢复？取消将丢弃这些内容。", ".\nRestore them? Cancel discards them."]),
+    // 敏感列
+    ("masking.mark_column", ["🔒 标记为敏感列", "🔒 Mark as sensitive"]),
+    ("masking.unmark_column", ["🔓 取消敏感列", "🔓 Unmark sensitive"]),
+    ("masking.mark_column_hint", [
+        "敏感列在表格中显示脱敏后的值，导出原始值需要确认；对当前连接中同名的列生效",
+        "Sensitive columns show masked values in the grid and exporting their original values needs confirmation; applies to columns with this name on the current connection",
+    ]),
+    ("masking.column_badge", ["敏感列：显示脱敏后的值", "Sensitive column: masked values are shown"]),
+    ("masking.unmask_title", ["导出敏感列原始值", "Export original values of sensitive columns"]),
+    ("masking.unmask_confirm", ["导出原始值", "Export original values"]),
+    ("masking.unmask_message", [
+        "将导出以下敏感列的原始值：{}\n导出的文件不会脱敏，请确认文件只会交给有权限查看这些数据的人。",
+        "The original values of these sensitive columns will be exported: {}\nThe file will not be masked, make sure it only goes to people allowed to see this data.",
+    ]),
+    // 连接对话框
+    ("conn.name_required", ["连接名称不能为空", "Connection name is required"]),
+    ("conn.name_too_long", ["连接名称不能超过 64 个字符", "Connection name cannot exceed 64 characters"]),
+    ("conn.path_required", ["数据库文件路径不能为空", "Database file path is required"]),
+    ("conn.dir_missing", ["目录不存在: {}", "Directory does not exist: {}"]),
+    ("conn.host_or_service_required", ["主机地址和服务名不能同时为空", "Enter a host or a service name"]),
+    ("conn.host_has_spaces", ["主机地址不能包含空格", "Host cannot contain spaces"]),
+    ("conn.oracle_username_required", ["Oracle 连接需要用户名", "Oracle connections require a username"]),
+    ("conn.dsn_required", ["DSN 或连接字符串不能为空", "DSN or connection string is required"]),
+    ("conn.host_required", ["主机地址不能为空", "Host is required"]),
+    ("conn.host_too_long", ["主机地址过长", "Host is too long"]),
+    ("conn.port_zero", ["端口号不能为 0", "Port cannot be 0"]),
+    ("conn.username_too_long", ["用户名过长", "Username is too long"]),
+    ("conn.client_cert_pair", ["客户端证书和私钥必须同时指定", "Client certificate and private key must be set together"]),
+    ("conn.connect_timeout_range", ["连接超时需在 {}-{} 秒之间", "Connection timeout must be between {} and {} seconds"]),
+    ("conn.query_timeout_range", ["查询超时需在 {}-{} 秒之间", "Query timeout must be between {} and {} seconds"]),
+    ("conn.pool_size_range", ["连接池连接数需在 {}-{} 之间", "Pool connections must be between {} and {}"]),
+    ("conn.pool_min_exceeds_max", ["连接池最小连接数不能大于最大连接数", "Minimum pool connections cannot exceed the maximum"]),
+    ("conn.idle_timeout_range", ["空闲连接超时需在 {}-{} 秒之间", "Idle timeout must be between {} and {} seconds"]),
+    ("conn.acquire_timeout_range", ["获取连接超时需在 {}-{} 秒之间", "Acquire timeout must be between {} and {} seconds"]),
+    ("conn.title", ["🔗 新建数据库连接", "🔗 New database connection"]),
+    ("conn.db_type_hint", ["数据库类型 [数字键 或 h/l 切换]", "Database type [number keys or h/l]"]),
+    ("conn.name", ["连接名称", "Name"]),
+    ("conn.name_hint", ["我的数据库", "My database"]),
+    ("conn.data_source", ["数据源", "Data source"]),
+    ("conn.data_source_hint", ["DSN 名称或 Driver={...};Server=...", "DSN name or Driver={...};Server=..."]),
+    ("conn.host", ["主机地址", "Host"]),
+    ("conn.port", ["端口", "Port"]),
+    ("conn.username", ["用户名", "Username"]),
+    ("conn.optional_acl_user", ["可选（ACL 用户）", "Optional (ACL user)"]),
+    ("conn.optional", ["可选", "Optional"]),
+    ("conn.password", ["密码", "Password"]),
+    ("conn.service_name", ["服务名", "Service name"]),
+    ("conn.service_name_hint", ["ORCLPDB1 或 TNS 别名", "ORCLPDB1 or TNS alias"]),
+    ("conn.file_path", ["文件路径", "File path"]),
+    ("conn.in_memory_hint", ["留空使用内存数据库", "Leave empty for an in-memory database"]),
+    ("conn.browse_file", ["浏览 [Ctrl+O]", "Browse [Ctrl+O]"]),
+    ("conn.encryption_key", ["加密密钥", "Encryption key"]),
+    ("conn.encryption_key_hint", ["可选，SQLCipher 加密数据库", "Optional, for SQLCipher-encrypted databases"]),
+    ("conn.tip_sqlite", [
+        "输入 SQLite 数据库文件路径，文件不存在时将自动创建",
+        "Path to the SQLite database file; it is created if it does not exist",
+    ]),
+    ("conn.tip_postgres", ["默认端口 5432，连接后可选择数据库", "Default port 5432; choose a database after connecting"]),
+    ("conn.tip_mysql", ["默认端口 3306，连接后可选择数据库", "Default port 3306; choose a database after connecting"]),
+    ("conn.tip_duckdb", [
+        "留空使用内存数据库，可直接查询 Parquet/CSV 文件",
+        "Leave empty for an in-memory database; Parquet/CSV files can be queried directly",
+    ]),
+    ("conn.tip_oracle", [
+        "默认端口 1521；主机留空时服务名按 TNS 别名解析，需安装 Instant Client",
+        "Default port 1521; without a host the service name is resolved as a TNS alias. Requires Instant Client",
+    ]),
+    ("conn.tip_odbc", [
+        "使用系统中已配置的 DSN，或填写完整的 ODBC 连接字符串",
+        "Use a DSN configured on this system, or enter a full ODBC connection string",
+    ]),
+    ("conn.tip_redis", [
+        "默认端口 6379，连接后选择库，按模式浏览键；查询区为命令控制台",
+        "Default port 6379; choose a database after connecting and browse keys by pattern. The query area is a command console",
+    ]),
+    ("conn.tip_mongodb", [
+        "默认端口 27017，主机也可填写完整的 mongodb:// 或 mongodb+srv:// URI；查询使用 JSON 命令",
+        "Default port 27017; the host may also be a full mongodb:// or mongodb+srv:// URI. Queries use JSON commands",
+    ]),
+    ("conn.tip_clickhouse", [
+        "默认 HTTP 端口 8123，主机可带 https:// 前缀；大结果集按页流式读取",
+        "Default HTTP port 8123; the host may start with https://. Large results are streamed page by page",
+    ]),
+    ("conn.duckdb_files", ["DuckDB 数据库", "DuckDB database"]),
+    ("conn.sqlite_files", ["SQLite 数据库", "SQLite database"]),
+    ("conn.all_files", ["所有文件", "All files"]),
+    ("conn.ssl", ["🔐 SSL/TLS 加密", "🔐 SSL/TLS encryption"]),
+    ("conn.ssl_mode", ["SSL 模式", "SSL mode"]),
+    ("conn.ca_cert", ["CA 证书", "CA certificate"]),
+    ("conn.browse", ["浏览", "Browse"]),
+    ("conn.cert_files", ["证书文件", "Certificate files"]),
+    ("conn.ssl_tip_disabled", ["不使用加密，数据以明文传输", "No encryption; data is sent in plain text"]),
+    ("conn.ssl_tip_preferred", [
+        "优先使用 SSL，如果服务器不支持则回退到明文",
+        "Use SSL when the server supports it, otherwise fall back to plain text",
+    ]),
+    ("conn.ssl_tip_required", ["必须使用 SSL 加密，不验证服务器证书", "Require SSL without verifying the server certificate"]),
+    ("conn.ssl_tip_verify_ca", [
+        "验证服务器 CA 证书，不检查主机名",
+        "Verify the server CA certificate without checking the host name",
+    ]),
+    ("conn.ssl_tip_verify_full", [
+        "完整验证：检查 CA 证书和服务器主机名",
+        "Full verification: check the CA certificate and the server host name",
+    ]),
+    ("conn.client_cert", ["客户端证书", "Client certificate"]),
+    ("conn.client_key", ["客户端私钥", "Client private key"]),
+    ("conn.key_files", ["私钥文件", "Private key files"]),
+    ("conn.timeouts", ["⏱ 超时（可选）", "⏱ Timeouts (optional)"]),
+    ("conn.connect_timeout", ["连接超时", "Connection timeout"]),
+    ("conn.query_timeout", ["查询超时", "Query timeout"]),
+    ("conn.seconds_suffix", [" 秒", " s"]),
+    ("conn.timeouts_tip", [
+        "未单独设置时使用首选项中的默认超时；分析型数据库可适当调大查询超时",
+        "Unset timeouts use the defaults from Preferences; analytical databases may need a longer query timeout",
+    ]),
+    ("conn.pool", ["🔌 连接池（可选）", "🔌 Connection pool (optional)"]),
+    ("conn.pool_min", ["最小连接数", "Minimum connections"]),
+    ("conn.pool_max", ["最大连接数", "Maximum connections"]),
+    ("conn.pool_idle_timeout", ["空闲超时", "Idle timeout"]),
+    ("conn.pool_acquire_timeout", ["获取连接超时", "Acquire timeout"]),
+    ("conn.pool_tip_mysql", [
+        "连接池已满时查询等待空闲连接，超过获取连接超时后报错；修改后下次查询时重建连接池",
+        "When the pool is full, queries wait for a free connection and fail after the acquire timeout. Changes rebuild the pool on the next query",
+    ]),
+    ("conn.pool_tip_postgres", [
+        "PostgreSQL 每个数据库使用一个长连接，获取连接超时限制建立连接的时间",
+        "PostgreSQL keeps one connection per database; the acquire timeout limits how long connecting may take",
+    ]),
+    ("conn.override", ["单独设置", "Override"]),
+    ("conn.default", ["默认", "Default"]),
+    ("conn.ssh", ["🔒 SSH 隧道（可选）", "🔒 SSH tunnel (optional)"]),
+    ("conn.ssh_enable", ["启用 SSH 隧道", "Enable SSH tunnel"]),
+    ("conn.ssh_host", ["SSH 主机", "SSH host"]),
+    ("conn.ssh_host_hint", ["跳板机地址", "Jump host address"]),
+    ("conn.ssh_port", ["SSH 端口", "SSH port"]),
+    ("conn.ssh_username", ["SSH 用户名", "SSH username"]),
+    ("conn.ssh_auth", ["认证方式", "Authentication"]),
+    ("conn.ssh_password", ["SSH 密码", "SSH password"]),
+    ("conn.ssh_key_path", ["私钥路径", "Private key path"]),
+    ("conn.ssh_key_passphrase", ["私钥密码", "Key passphrase"]),
+    ("conn.optional_paren", ["（可选）", "(optional)"]),
+    ("conn.remote_host", ["远程主机", "Remote host"]),
+    ("conn.remote_host_hint", ["数据库主机（如 127.0.0.1）", "Database host (e.g. 127.0.0.1)"]),
+    ("conn.remote_port", ["远程端口", "Remote port"]),
+    ("conn.remote_port_hint", ["数据库端口", "Database port"]),
+    ("conn.ssh_tip", [
+        "提示：启用 SSH 隧道后，连接将通过跳板机转发到远程数据库",
+        "With an SSH tunnel the connection is forwarded through the jump host to the remote database",
+    ]),
+    ("conn.preview", ["🔍 连接字符串预览", "🔍 Connection string preview"]),
+    ("conn.shortcuts", ["快捷键: Esc/q 关闭 | Enter 保存", "Shortcuts: Esc/q close | Enter save"]),
+    ("conn.save_and_connect", ["保存并连接 [Enter]", "Save and connect [Enter]"]),
+    // 导出对话框
+    ("export.title", ["📤 导出数据", "📤 Export data"]),
+    ("export.table", ["表:", "Table:"]),
+    ("export.summary", ["导出: {}列 × {}行", "Export: {} columns × {} rows"]),
+    ("export.total", ["(共{}×{})", "(of {}×{})"]),
+    ("export.format", ["格式:", "Format:"]),
+    ("export.switch_hint", ["h/l 切换", "h/l switch"]),
+    ("export.rows", ["行数:", "Rows:"]),
+    ("export.all_rows", ["全部", "All"]),
+    ("export.custom", ["自定义:", "Custom:"]),
+    ("export.columns_header", ["选择列 ({}/{}) [j/k Space a]", "Columns ({}/{}) [j/k Space a]"]),
+    ("export.deselect_all", ["取消全选 [a]", "Deselect all [a]"]),
+    ("export.select_all", ["全选 [a]", "Select all [a]"]),
+    ("export.column_nav_hint", ["j/k 导航, Space 切换", "j/k navigate, Space toggle"]),
+    ("export.sensitive_column", ["敏感列", "Sensitive column"]),
+    ("export.unmask", ["导出敏感列的原始值", "Export original values of sensitive columns"]),
+    ("export.unmask_hint", [
+        "默认导出脱敏后的值；勾选后导出前需要再次确认",
+        "Masked values are exported by default; exporting originals asks for confirmation",
+    ]),
+    ("export.csv_options", ["CSV 选项", "CSV options"]),
+    ("export.sql_options", ["SQL 选项", "SQL options"]),
+    ("export.json_options", ["JSON 选项", "JSON options"]),
+    ("export.delimiter", ["分隔符:", "Delimiter:"]),
+    ("export.include_header", ["包含表头", "Include header"]),
+    ("export.use_transaction", ["事务包装", "Wrap in a transaction"]),
+    ("export.batch", ["批量:", "Batch:"]),
+    ("export.batch_single", ["单行", "Single row"]),
+    ("export.pretty", ["美化输出", "Pretty print"]),
+    ("export.pretty_hint", ["(带缩进)", "(indented)"]),
+    ("export.compact_hint", ["(紧凑)", "(compact)"]),
+    ("export.nested", ["保留嵌套结构", "Keep nested structure"]),
+    ("export.nested_hint", ["(a.b 列与 JSON 单元格还原为对象)", "(a.b columns and JSON cells become objects)"]),
+    ("export.preview", ["预览", "Preview"]),
+    ("export.no_columns", ["（未选择任何列）", "(no columns selected)"]),
+    ("export.preview_failed", ["预览失败: {}", "Preview failed: {}"]),
+    ("export.more_rows", ["... (+{} 行)", "... (+{} rows)"]),
+    ("export.more_items", ["... (+{} 条)", "... (+{} more)"]),
+    ("export.button", ["导出 {} [Enter]", "Export {} [Enter]"]),
+    ("export.select_columns", ["请选择列", "Select columns"]),
+    // 导入解析
+    ("import.missing_semicolon", ["最后一条语句没有分号", "The last statement has no semicolon"]),
+    ("import.sql_statement_column", ["SQL 语句", "SQL statement"]),
+    // 导入配置
+    ("import.upsert_unsupported", ["当前数据库不支持 upsert", "The current database does not support upsert"]),
+    ("import.conflict_columns_required", ["请填写冲突键列", "Enter the conflict key columns"]),
+    // 导入对话框
+    ("import.title", ["📥 导入数据", "📥 Import data"]),
+    ("import.loading", ["正在加载...", "Loading..."]),
+    ("import.load_preview", ["🔍 加载预览", "🔍 Load preview"]),
+    ("import.file", ["文件:", "File:"]),
+    ("import.no_file", ["未选择文件", "No file selected"]),
+    ("import.browse", ["📂 浏览...", "📂 Browse..."]),
+    ("import.file_size", ["大小: {}", "Size: {}"]),
+    ("import.format_badge", ["{} {} 格式", "{} {} format"]),
+    ("import.format", ["格式:", "Format:"]),
+    ("import.mode", ["模式:", "Mode:"]),
+    ("import.mode_execute", ["🚀 直接执行", "🚀 Execute"]),
+    ("import.mode_execute_hint", ["逐条执行 SQL 语句", "Run the SQL statements one by one"]),
+    ("import.mode_copy", ["📋 复制到编辑器", "📋 Copy to editor"]),
+    ("import.mode_copy_hint", ["将 SQL 复制到编辑器中", "Copy the SQL into the editor"]),
+    ("import.sql_options", ["SQL 导入选项", "SQL import options"]),
+    ("import.strip_comments", ["移除注释", "Remove comments"]),
+    ("import.comment_styles", ["(-- 和 /* */)", "(-- and /* */)"]),
+    ("import.strip_empty_lines", ["移除空行", "Remove empty lines"]),
+    ("import.stop_on_error", ["遇到错误时停止", "Stop on error"]),
+    ("import.use_transaction", ["使用事务", "Use a transaction"]),
+    ("import.transaction_hint", ["(全部成功或全部回滚)", "(all succeed or all roll back)"]),
+    ("import.csv_options", ["CSV 导入选项", "CSV import options"]),
+    ("import.target_table", ["目标表:", "Target table:"]),
+    ("import.table_name", ["表名", "Table name"]),
+    ("import.delimiter", ["分隔符:", "Delimiter:"]),
+    ("import.has_header", ["首行为表头", "First row is a header"]),
+    ("import.skip_rows", ["跳过行:", "Skip rows:"]),
+    ("import.json_options", ["JSON 导入选项", "JSON import options"]),
+    ("import.json_path", ["数据路径:", "Data path:"]),
+    ("import.json_path_hint", ["例如: data.items (留空表示根数组)", "e.g. data.items (empty for the root array)"]),
+    ("import.flatten_nested", ["展平嵌套对象", "Flatten nested objects"]),
+    ("import.upsert", ["主键已存在时更新 (upsert)", "Update rows whose key already exists (upsert)"]),
+    ("import.conflict_columns", ["冲突键列:", "Conflict key columns:"]),
+    ("import.conflict_columns_hint", ["例如: id 或 org_id, user_id", "e.g. id or org_id, user_id"]),
+    ("import.preview_statements", ["预览 ({} 条 SQL 语句)", "Preview ({} SQL statements)"]),
+    ("import.preview_table", ["预览 ({} 列 × {} 行)", "Preview ({} columns × {} rows)"]),
+    ("import.more_statements", ["... 还有 {} 条语句", "... {} more statements"]),
+    ("import.no_data", ["无数据", "No data"]),
+    ("import.more_rows", ["... 还有 {} 行数据", "... {} more rows"]),
+    ("import.refresh_preview", ["🔄 刷新预览 [Ctrl+R]", "🔄 Refresh preview [Ctrl+R]"]),
+    ("import.execute", ["🚀 执行导入 [Enter]", "🚀 Import [Enter]"]),
+    ("import.copy_to_editor", ["📋 复制到编辑器 [Enter]", "📋 Copy to editor [Enter]"]),
+    // 建表对话框
+    ("ddl.table_name_required", ["表名不能为空", "Table name is required"]),
+    ("ddl.columns_required", ["至少需要一个列", "At least one column is required"]),
+    ("ddl.column_name_required", ["列名不能为空", "Column name is required"]),
+    ("ddl.duplicate_column", ["列名 '{}' 重复", "Duplicate column name '{}'"]),
+    ("ddl.single_primary_key", ["只能有一个主键列", "Only one primary key column is allowed"]),
+    ("ddl.foreign_key_column_missing", ["外键列 '{}' 不存在", "Foreign key column '{}' does not exist"]),
+    ("ddl.design_title", ["设计表（ER 图）", "Design table (ER diagram)"]),
+    ("ddl.create_title", ["创建表", "Create table"]),
+    ("ddl.table_name", ["表名:", "Table name:"]),
+    ("ddl.table_name_hint", ["输入表名", "Enter a table name"]),
+    ("ddl.comment", ["注释:", "Comment:"]),
+    ("ddl.optional", ["可选", "Optional"]),
+    ("ddl.columns", ["列定义", "Columns"]),
+    ("ddl.columns_hint", [
+        "[j/k 移动 | o/O 添加 | dd 删除 | Space 切换主键]",
+        "[j/k move | o/O add | dd delete | Space toggle primary key]",
+    ]),
+    ("ddl.add_column", ["+ 添加列 [o]", "+ Add column [o]"]),
+    ("ddl.column_name", ["列名", "Column name"]),
+    ("ddl.column_type", ["类型", "Type"]),
+    ("ddl.default_value", ["默认值", "Default"]),
+    ("ddl.primary_key", ["主键", "Primary key"]),
+    ("ddl.auto_increment", ["自增", "Auto increment"]),
+    ("ddl.not_null", ["非空", "Not null"]),
+    ("ddl.unique", ["唯一", "Unique"]),
+    ("ddl.delete_column", ["删除列 [dd]", "Delete column [dd]"]),
+    ("ddl.preview_sql", ["预览 SQL", "Preview SQL"]),
+    ("ddl.design_shortcuts", ["快捷键: Esc/q 关闭 | Enter 放到 ER 图", "Shortcuts: Esc/q close | Enter add to ER diagram"]),
+    ("ddl.create_shortcuts", ["快捷键: Esc/q 关闭 | Enter 创建", "Shortcuts: Esc/q close | Enter create"]),
+    ("ddl.add_to_diagram", ["放到 ER 图 [Enter]", "Add to ER diagram [Enter]"]),
+    ("ddl.create_button", ["创建表 [Enter]", "Create table [Enter]"]),
+    // 新建数据库对话框
+    ("create_db.name_required", ["数据库名称不能为空", "Database name is required"]),
+    ("create_db.name_invalid", [
+        "数据库名只能包含字母、数字和下划线",
+        "Database names may only contain letters, digits and underscores",
+    ]),
+    ("create_db.oracle_unsupported", [
+        "Oracle 中 schema 即用户，请通过「新建用户」创建",
+        "In Oracle a schema is a user; create it with \"New user\"",
+    ]),
+    ("create_db.odbc_unsupported", ["ODBC 数据源不支持新建数据库", "ODBC data sources cannot create databases"]),
+    ("create_db.redis_unsupported", ["Redis 的库由服务器配置决定，无需新建", "Redis databases are fixed by the server configuration"]),
+    ("create_db.mongodb_unsupported", [
+        "MongoDB 在首次写入集合时自动创建数据库，无需新建",
+        "MongoDB creates databases on the first write to a collection",
+    ]),
+    ("create_db.sqlite_path_required", ["请指定数据库文件路径或名称", "Enter a database file path or name"]),
+    ("create_db.title_mysql", ["新建 MySQL 数据库", "New MySQL database"]),
+    ("create_db.title_postgres", ["新建 PostgreSQL 数据库", "New PostgreSQL database"]),
+    ("create_db.title_sqlite", ["新建 SQLite 数据库", "New SQLite database"]),
+    ("create_db.title_duckdb", ["新建 DuckDB Schema", "New DuckDB schema"]),
+    ("create_db.title_oracle", ["新建 Oracle Schema", "New Oracle schema"]),
+    ("create_db.title_clickhouse", ["新建 ClickHouse 数据库", "New ClickHouse database"]),
+    ("create_db.title", ["新建数据库", "New database"]),
+    ("create_db.name", ["数据库名:", "Database name:"]),
+    ("create_db.name_hint", ["输入数据库名称", "Enter a database name"]),
+    ("create_db.mysql_options", ["MySQL 选项", "MySQL options"]),
+    ("create_db.charset", ["字符集:", "Character set:"]),
+    ("create_db.collation", ["排序规则:", "Collation:"]),
+    ("create_db.postgres_options", ["PostgreSQL 选项", "PostgreSQL options"]),
+    ("create_db.encoding", ["编码:", "Encoding:"]),
+    ("create_db.template", ["模板:", "Template:"]),
+    ("create_db.owner", ["所有者:", "Owner:"]),
+    ("create_db.owner_hint", ["可选，留空使用当前用户", "Optional, defaults to the current user"]),
+    ("create_db.sqlite_options", ["SQLite 选项", "SQLite options"]),
+    ("create_db.file_path", ["文件路径:", "File path:"]),
+    ("create_db.file_path_hint", ["输入完整路径，或留空使用数据库名.db", "Full path, or leave empty for <name>.db"]),
+    ("create_db.sqlite_hint", [
+        "提示: SQLite 数据库将在指定路径创建新文件",
+        "Note: the SQLite database is created as a new file at this path",
+    ]),
+    // 新建用户对话框
+    ("user.priv_select", ["查询数据", "Read data"]),
+    ("user.priv_insert", ["插入数据", "Insert data"]),
+    ("user.priv_update", ["更新数据", "Update data"]),
+    ("user.priv_delete", ["删除数据", "Delete data"]),
+    ("user.priv_create", ["创建表/数据库", "Create tables/databases"]),
+    ("user.priv_drop", ["删除表/数据库", "Drop tables/databases"]),
+    ("user.priv_alter", ["修改表结构", "Alter table structure"]),
+    ("user.priv_index", ["创建/删除索引", "Create/drop indexes"]),
+    ("user.priv_references", ["创建外键", "Create foreign keys"]),
+    ("user.priv_create_view", ["创建视图", "Create views"]),
+    ("user.priv_truncate", ["清空表", "Truncate tables"]),
+    ("user.priv_trigger", ["创建触发器", "Create triggers"]),
+    ("user.priv_create_objects", ["创建对象", "Create objects"]),
+    ("user.priv_connect", ["连接数据库", "Connect to the database"]),
+    ("user.priv_temporary", ["创建临时表", "Create temporary tables"]),
+    ("user.priv_create_session", ["登录数据库", "Log in to the database"]),
+    ("user.priv_create_table", ["创建表", "Create tables"]),
+    ("user.priv_create_sequence", ["创建序列", "Create sequences"]),
+    ("user.priv_create_procedure", ["创建存储过程/函数", "Create procedures/functions"]),
+    ("user.priv_unlimited_tablespace", ["不限表空间配额", "Unlimited tablespace quota"]),
+    ("user.priv_alter_data", ["修改表结构/数据", "Alter table structure/data"]),
+    ("user.priv_create_table_view", ["创建表/视图", "Create tables/views"]),
+    ("user.priv_drop_table_view", ["删除表/视图", "Drop tables/views"]),
+    ("user.priv_optimize", ["合并数据分区", "Merge data parts"]),
+    ("user.priv_show", ["查看对象", "View objects"]),
+    ("user.name_required", ["用户名不能为空", "Username is required"]),
+    ("user.name_invalid", ["用户名只能包含字母、数字和下划线", "Usernames may only contain letters, digits and underscores"]),
+    ("user.password_required", ["密码不能为空", "Password is required"]),
+    ("user.password_mismatch", ["两次输入的密码不一致", "The passwords do not match"]),
+    ("user.password_too_short", ["密码长度至少为 4 位", "The password must be at least 4 characters"]),
+    ("user.unsupported", ["{} 不支持用户管理", "{} does not support user management"]),
+    ("user.privileges_required", ["请至少选择一个权限", "Select at least one privilege"]),
+    ("user.oracle_password_quote", ["Oracle 密码不能包含双引号", "Oracle passwords cannot contain double quotes"]),
+    ("user.title_mysql", ["新建 MySQL 用户", "New MySQL user"]),
+    ("user.title_postgres", ["新建 PostgreSQL 用户", "New PostgreSQL user"]),
+    ("user.title_oracle", ["新建 Oracle 用户", "New Oracle user"]),
+    ("user.title_clickhouse", ["新建 ClickHouse 用户", "New ClickHouse user"]),
+    ("user.title", ["新建用户", "New user"]),
+    ("user.basic_info", ["基本信息", "Account"]),
+    ("user.username", ["用户名:", "Username:"]),
+    ("user.username_hint", ["输入用户名", "Enter a username"]),
+    ("user.password", ["密  码:", "Password:"]),
+    ("user.password_hint", ["输入密码", "Enter a password"]),
+    ("user.confirm", ["确  认:", "Confirm:"]),
+    ("user.confirm_hint", ["再次输入密码", "Enter the password again"]),
+    ("user.host", ["主  机:", "Host:"]),
+    ("user.any_host", ["% (所有主机)", "% (any host)"]),
+    ("user.privileges", ["权限设置", "Privileges"]),
+    ("user.grant_oracle_roles", ["授予 CONNECT, RESOURCE 角色", "Grant the CONNECT and RESOURCE roles"]),
+    ("user.grant_database", ["授权数据库:", "Database:"]),
+    ("user.grant_database_hint", ["选择数据库（可选）", "Select a database (optional)"]),
+    ("user.no_grant", ["不授权", "No grant"]),
+    ("user.grant_all", ["授予所有权限 (ALL PRIVILEGES)", "Grant all privileges (ALL PRIVILEGES)"]),
+    ("user.select_privileges", ["选择权限:", "Privileges:"]),
+    // 关于对话框
+    ("about.title", ["关于", "About"]),
+    ("about.tagline", ["不是吧哥们", "Wait, really?"]),
+    ("about.not_navicat", ["真当我们 Navicat 了？", "Did you mistake us for Navicat?"]),
+    ("about.open_source", ["我们可是开源项目嘿嘿，不收费哈！", "We're open source, so it's free!"]),
+    ("about.author", ["作者: MCB-SMART-BOY", "Author: MCB-SMART-BOY"]),
+    ("about.star", ["欢迎 Star & 贡献代码", "Stars and contributions welcome"]),
+    ("about.close_hint", ["[Esc/q/Enter 关闭]", "[Esc/q/Enter to close]"]),
+    ("about.ok", ["知道啦~ [Enter]", "Got it [Enter]"]),
+    // 快捷键对话框
+    ("keys.missing_modifier", [
+        "快捷键 {} 缺少修饰键（Ctrl/Alt），会干扰文本输入",
+        "Shortcut {} has no modifier (Ctrl/Alt) and would interfere with typing",
+    ]),
+    ("keys.in_use", ["快捷键 {} 已被 \"{}\" 使用", "Shortcut {} is already used by \"{}\""]),
+    ("keys.conflict", ["⚠ \"{}\" 与 \"{}\" 都绑定了 {}", "⚠ \"{}\" and \"{}\" are both bound to {}"]),
+    ("keys.title", ["快捷键设置", "Keyboard shortcuts"]),
+    ("keys.search", ["搜索:", "Search:"]),
+    ("keys.search_hint", ["输入操作名称...", "Action name..."]),
+    ("keys.category", ["分类:", "Category:"]),
+    ("keys.all", ["全部", "All"]),
+    ("keys.reset", ["重置为默认", "Reset to defaults"]),
+    ("keys.replace", ["替换", "Replace"]),
+    ("keys.keep", ["保留原绑定", "Keep existing"]),
+    ("keys.action", ["操作", "Action"]),
+    ("keys.shortcut", ["快捷键", "Shortcut"]),
+    ("keys.category_header", ["分类", "Category"]),
+    ("keys.unset", ["未设置", "Not set"]),
+    ("keys.press_keys", ["按下快捷键...", "Press keys..."]),
+    ("keys.clear", ["清除快捷键", "Clear shortcut"]),
+    ("keys.save", ["保存", "Save"]),
+    ("keys.save_changed", ["保存 *", "Save *"]),
+    ("keys.resolve_conflicts", ["请先解决快捷键冲突", "Resolve the shortcut conflicts first"]),
+    ("keys.hint", [
+        "提示: 选中操作后点击快捷键按钮开始录制，按 ESC 取消",
+        "Tip: select an action and click its shortcut to record; ESC cancels",
+    ]),
+    // 帮助对话框
+    ("help.title", ["Gridix 使用指南", "Gridix guide"]),
+    ("help.scroll", ["滚动", "Scroll"]),
+    ("help.close", ["关闭", "Close"]),
+    ("help.tour", ["▶ 新手引导", "▶ Guided tour"]),
+    ("help.tour_hint", ["逐步介绍主界面和键盘操作", "Walk through the main window and keyboard controls"]),
+    ("help.welcome", ["欢迎使用 Gridix!", "Welcome to Gridix!"]),
+    ("help.quick_start", ["快速上手", "Quick start"]),
+    ("help.step_connect", ["1. 连接数据库", "1. Connect to a database"]),
+    ("help.step_connect_desc", [
+        "   按 Ctrl+N 创建新连接，支持 MySQL、PostgreSQL、SQLite",
+        "   Press Ctrl+N to create a connection to MySQL, PostgreSQL or SQLite",
+    ]),
+    ("help.step_browse", ["2. 浏览数据", "2. Browse data"]),
+    ("help.step_browse_desc", [
+        "   在左侧边栏选择表，点击或按 Enter 查看数据",
+        "   Pick a table in the sidebar and click it or press Enter to view its data",
+    ]),
+    ("help.step_edit", ["3. 编辑数据", "3. Edit data"]),
+    ("help.step_edit_desc", [
+        "   在表格中按 i 进入编辑模式，修改后按 Ctrl+S 保存",
+        "   Press i in the grid to edit, then Ctrl+S to save",
+    ]),
+    ("help.step_sql", ["4. 执行 SQL", "4. Run SQL"]),
+    ("help.step_sql_desc", [
+        "   按 Ctrl+J 打开编辑器，输入 SQL 后按 Ctrl+Enter 执行",
+        "   Press Ctrl+J to open the editor, type SQL and press Ctrl+Enter to run it",
+    ]),
+    ("help.step_drop", ["5. 拖放文件", "5. Drop files"]),
+    ("help.step_drop_desc", [
+        "   拖入 .db/.sqlite 建立连接，.sql 在新标签页打开，.csv/.json 导入到当前数据库",
+        "   Drop .db/.sqlite to connect, .sql to open in a new tab, .csv/.json to import into the current database",
+    ]),
+    ("help.navigation", ["界面导航 (hjkl 全局通用)", "Navigation (hjkl everywhere)"]),
+    ("help.navigation_desc", ["Gridix 的各个区域之间可以用 hjkl 无缝切换：", "Move between the areas of Gridix with hjkl:"]),
+    ("help.nav_h", ["向左移动 / 返回上级", "Move left / go up a level"]),
+    ("help.nav_j", ["向下移动 / 进入下级区域", "Move down / enter the area below"]),
+    ("help.nav_k", ["向上移动 / 进入上级区域", "Move up / enter the area above"]),
+    ("help.nav_l", ["向右移动 / 展开 / 确认", "Move right / expand / confirm"]),
+    ("help.common_keys", ["常用快捷键", "Common shortcuts"]),
+    ("help.window", ["窗口控制", "Windows"]),
+    ("help.toggle_sidebar", ["切换侧边栏", "Toggle the sidebar"]),
+    ("help.toggle_editor", ["切换 SQL 编辑器", "Toggle the SQL editor"]),
+    ("help.toggle_er", ["切换 ER 关系图", "Toggle the ER diagram"]),
+    ("help.new_tab", ["新建查询标签页", "New query tab"]),
+    ("help.close_tab", ["关闭当前标签页", "Close the current tab"]),
+    ("help.reopen_tab", ["重新打开关闭的标签页", "Reopen a closed tab"]),
+    ("help.rename_tab", ["重命名当前标签页（也可双击标题）", "Rename the current tab (or double-click its title)"]),
+    ("help.open_help", ["打开此帮助", "Open this guide"]),
+    ("help.data", ["数据操作", "Data"]),
+    ("help.refresh", ["刷新数据", "Refresh data"]),
+    ("help.save", ["保存表格修改 / 保存 SQL 文件", "Save grid changes / save the SQL file"]),
+    ("help.open_sql", ["打开 SQL 文件", "Open an SQL file"]),
+    ("help.save_sql_as", ["SQL 另存为", "Save SQL as"]),
+    ("help.export", ["导出数据", "Export data"]),
+    ("help.import", ["导入数据", "Import data"]),
+    ("help.grants", ["权限管理 (MySQL/PostgreSQL)", "Manage privileges (MySQL/PostgreSQL)"]),
+    ("help.add_filter", ["添加筛选条件", "Add a filter"]),
+    ("help.next_match", ["跳转到下一个/上一个搜索匹配", "Jump to the next/previous search match"]),
+    ("help.appearance", ["外观设置", "Appearance"]),
+    ("help.toggle_theme", ["切换日间/夜间模式", "Toggle light/dark mode"]),
+    ("help.zoom", ["放大/缩小界面", "Zoom in/out"]),
+    ("help.reset_zoom", ["重置缩放", "Reset zoom"]),
+    ("help.preferences", ["首选项", "Preferences"]),
+    ("help.command_palette", ["命令面板", "Command palette"]),
+    ("help.quick_switch", ["快速切换最近的表和数据库", "Switch to a recent table or database"]),
+    ("help.data_search", ["全库数据搜索", "Search data across the database"]),
+    ("help.keybindings", ["自定义快捷键（以上均为默认值）", "Customize shortcuts (the above are the defaults)"]),
+    ("help.grid", ["表格编辑 (Helix 风格)", "Grid editing (Helix style)"]),
+    ("help.grid_desc", [
+        "表格采用模态编辑，类似 Helix/Vim 编辑器，分为三种模式：",
+        "The grid uses modal editing like Helix/Vim, with three modes:",
+    ]),
+    ("help.normal_mode", ["Normal 模式 - 浏览和导航", "Normal mode - browse and navigate"]),
+    ("help.arrow_keys", ["hjkl / 方向键", "hjkl / arrow keys"]),
+    ("help.move_cursor", ["移动光标", "Move the cursor"]),
+    ("help.first_row", ["跳到第一行", "Jump to the first row"]),
+    ("help.last_row", ["跳到最后一行", "Jump to the last row"]),
+    ("help.half_page", ["上/下翻半页", "Scroll half a page up/down"]),
+    ("help.count_prefix", ["向下移动5行 (数字前缀)", "Move down 5 rows (count prefix)"]),
+    ("help.record_macro", [
+        "开始/停止录制键盘宏（SQL 编辑器 Normal 模式同样可用）",
+        "Start/stop recording a macro (also in the SQL editor's Normal mode)",
+    ]),
+    ("help.replay_macro", ["回放录制的宏（可加次数前缀）", "Replay the recorded macro (accepts a count prefix)"]),
+    ("help.insert_mode", ["Insert 模式 - 编辑内容", "Insert mode - edit content"]),
+    ("help.enter_insert", ["进入编辑模式", "Enter insert mode"]),
+    ("help.append", ["追加模式", "Append"]),
+    ("help.change", ["清空单元格并编辑", "Clear the cell and edit"]),
+    ("help.exit_insert", ["退出编辑", "Leave insert mode"]),
+    ("help.select_mode", ["Select 模式 - 批量操作", "Select mode - bulk operations"]),
+    ("help.enter_select", ["进入选择模式", "Enter select mode"]),
+    ("help.select_row", ["选择整行", "Select the whole row"]),
+    ("help.copy_selection", ["复制选中内容", "Copy the selection"]),
+    ("help.delete_selection", ["删除选中内容", "Delete the selection"]),
+    ("help.fill_down", ["向下填充：第一行的值复制到选中的其余行", "Fill down: copy the first row's value into the other selected rows"]),
+    ("help.fill_series", [
+        "序列填充：数字和日期按前两行的差值递增",
+        "Fill series: numbers and dates step by the difference of the first two rows",
+    ]),
+    ("help.exit_select", ["退出选择", "Leave select mode"]),
+    ("help.rows", ["行操作", "Rows"]),
+    ("help.insert_row", ["在下方/上方插入新行", "Insert a row below/above"]),
+    ("help.delete_row", ["标记删除当前行", "Mark the current row for deletion"]),
+    ("help.copy_row", ["复制整行", "Copy the whole row"]),
+    ("help.paste", ["粘贴", "Paste"]),
+    ("help.paste_rows", ["把复制的行粘贴为新增行", "Paste the copied rows as new rows"]),
+    ("help.undo", ["撤销修改", "Undo changes"]),
+    ("help.pin_row", [
+        "置顶/取消置顶当前行（最多 3 行，滚动时保持可见）",
+        "Pin/unpin the current row (up to 3 rows, kept visible while scrolling)",
+    ]),
+    ("help.move_column", [
+        "左移/右移当前列（也可拖动列名，按表保存）",
+        "Move the current column left/right (or drag its header; saved per table)",
+    ]),
+    ("help.editor", ["SQL 编辑器", "SQL editor"]),
+    ("help.editor_desc", [
+        "编辑器同样支持 Normal/Insert 双模式，左下角显示当前模式。",
+        "The editor also has Normal and Insert modes; the current mode is shown at the bottom left.",
+    ]),
+    ("help.double_click", ["i / 双击", "i / double-click"]),
+    ("help.exit_editor_insert", ["退出编辑模式", "Leave insert mode"]),
+    ("help.run_sql", ["执行 SQL", "Run SQL"]),
+    ("help.explain", ["分析执行计划 (EXPLAIN)", "Explain the query plan (EXPLAIN)"]),
+    ("help.check_sql", ["检查 SQL，不执行", "Check the SQL without running it"]),
+    ("help.accept_completion", ["选择自动补全", "Accept a completion"]),
+    ("help.history", ["浏览历史命令", "Browse the history"]),
+    ("help.toggle_comment", ["注释/取消注释选中行", "Comment/uncomment the selected lines"]),
+    ("help.add_cursor", ["在上/下一行添加光标（列编辑）", "Add a cursor on the line above/below (column editing)"]),
+    ("help.multi_cursor_esc", ["Esc (多光标时)", "Esc (with multiple cursors)"]),
+    ("help.exit_multi_cursor", ["退出多光标", "Leave multi-cursor mode"]),
+    ("help.command_line", ["命令行 (Normal 模式下按 :)", "Command line (press : in Normal mode)"]),
+    ("help.format_explain_clear", ["格式化 / 分析 / 清空", "Format / explain / clear"]),
+    ("help.write_quit", ["保存表格修改 / 关闭标签页", "Save grid changes / close the tab"]),
+    ("help.tab_commands", ["新建 / 下一个 / 上一个标签页", "New / next / previous tab"]),
+    ("help.connect_command", [":connect <连接>", ":connect <connection>"]),
+    ("help.switch_connection", ["切换连接", "Switch connection"]),
+    ("help.table_command", [":table <表> / :e <表>", ":table <table> / :e <table>"]),
+    ("help.open_table", ["打开表", "Open a table"]),
+    ("help.export_result", ["导出结果", "Export the result"]),
+    ("help.complete_command", ["补全命令和参数", "Complete commands and arguments"]),
+    ("help.sidebar", ["侧边栏导航", "Sidebar"]),
+    ("help.sidebar_desc", [
+        "侧边栏分为多个面板：连接、数据库、表、筛选、触发器、存储过程、序列。",
+        "The sidebar has several panels: connections, databases, tables, filters, triggers, routines and sequences.",
+    ]),
+    ("help.sidebar_move", ["上下移动选择", "Move the selection up/down"]),
+    ("help.sidebar_enter", ["展开 / 连接 / 查询表", "Expand / connect / query the table"]),
+    ("help.sidebar_back", ["折叠 / 返回上级面板", "Collapse / back to the parent panel"]),
+    ("help.sidebar_delete", ["删除选中项（删除表需输入表名确认）", "Delete the selection (dropping a table asks for its name)"]),
+    ("help.sidebar_rename", ["重命名选中的表", "Rename the selected table"]),
+    ("help.sidebar_edit", [
+        "编辑连接 / 触发器 / 存储过程，设置序列下一个值",
+        "Edit a connection / trigger / routine, or set a sequence's next value",
+    ]),
+    ("help.sidebar_panels", ["快速切换到对应面板", "Jump to the matching panel"]),
+    ("help.filters", ["筛选功能", "Filters"]),
+    ("help.filters_desc", [
+        "在表格中按 / 或 f 快速添加筛选条件。支持以下操作符：",
+        "Press / or f in the grid to add a filter. Supported operators:",
+    ]),
+    ("help.op_contains", ["~ 包含", "~ contains"]),
+    ("help.op_contains_desc", ["模糊匹配，如 ~john", "Fuzzy match, e.g. ~john"]),
+    ("help.op_equals", ["= 等于", "= equals"]),
+    ("help.op_equals_desc", ["精确匹配，如 =admin", "Exact match, e.g. =admin"]),
+    ("help.op_not_equals", ["!= 不等于", "!= not equal"]),
+    ("help.op_not_equals_desc", ["排除匹配", "Exclude matches"]),
+    ("help.op_compare", ["数值比较", "Numeric comparison"]),
+    ("help.op_null", ["为空 / 不为空", "is empty / not empty"]),
+    ("help.op_null_desc", ["NULL 判断", "NULL checks"]),
+    ("help.databases", ["支持的数据库", "Supported databases"]),
+    ("help.db_mysql", ["默认端口 3306，支持 SSH 隧道", "Default port 3306, SSH tunnels supported"]),
+    ("help.db_postgres", ["默认端口 5432，支持 SSH 隧道", "Default port 5432, SSH tunnels supported"]),
+    ("help.db_sqlite", ["本地文件数据库，无需网络", "Local file database, no network needed"]),
+    ("help.about", ["一款采用 Helix 风格键位的现代数据库管理工具", "A modern database manager with Helix-style keys"]),
+    ("help.built_with", ["使用 Rust + egui 构建 | 开源免费", "Built with Rust + egui | free and open source"]),
+    ("help.diagram_toolbar", [
+        "    │              [工具栏]                   │",
+        "    │                [Toolbar]                │",
+    ]),
+    ("help.diagram_tabs", [
+        "    │            [查询标签栏]                 │",
+        "    │               [Query tabs]              │",
+    ]),
+    ("help.diagram_sidebar_grid", [
+        "│[侧边栏]│ l→ │          [数据表格]            │",
+        "│ Sidebar│ l→ │          [Data grid]           │",
+    ]),
+    ("help.diagram_editor", [
+        "              │        [SQL 编辑器]            │",
+        "              │          [SQL editor]          │",
+    ]),
+    ("help.intro", [
+        "Gridix 是一款采用 Helix/Vim 风格键位的数据库管理工具。\n无需频繁使用鼠标，双手始终保持在键盘上，高效管理你的数据。",
+        "Gridix is a database manager with Helix/Vim-style keys.\nKeep your hands on the keyboard and manage your data without reaching for the mouse.",
+    ]),
+    // API 服务对话框
+    ("api.title", ["🌐 API 服务", "🌐 API server"]),
+    ("api.stop", ["⏹ 停止服务", "⏹ Stop server"]),
+    ("api.running", ["运行中: http://127.0.0.1:{}/queries", "Running: http://127.0.0.1:{}/queries"]),
+    ("api.start", ["▶ 启动服务", "▶ Start server"]),
+    ("api.start_hint", ["保存设置并在本机启动只读 HTTP 服务", "Save the settings and start a local read-only HTTP server"]),
+    ("api.stopped", ["未运行", "Stopped"]),
+    ("api.port", ["端口:", "Port:"]),
+    ("api.token", ["令牌:", "Token:"]),
+    ("api.token_hint", ["留空启动时自动生成", "Leave empty to generate one on start"]),
+    ("api.token_header_hint", ["请求需带 Authorization: Bearer <令牌>", "Requests must send Authorization: Bearer <token>"]),
+    ("api.new_token", ["生成新令牌", "Generate a new token"]),
+    ("api.copy_token", ["复制令牌", "Copy the token"]),
+    ("api.auto_start", ["启动时自动运行", "Start automatically"]),
+    ("api.scope_hint", [
+        "服务只监听 127.0.0.1，只允许只读查询；`.csv` 后缀或 ?format=csv 返回 CSV",
+        "The server only listens on 127.0.0.1 and only runs read-only queries; a `.csv` suffix or ?format=csv returns CSV",
+    ]),
+    ("api.shared_queries", ["共享的查询", "Shared queries"]),
+    ("api.add_query_hint", ["使用当前编辑器中的 SQL 和活动连接新建", "Create one from the editor's SQL and the active connection"]),
+    ("api.no_queries", ["暂无共享的查询", "No shared queries"]),
+    ("api.copy_url", ["复制访问地址", "Copy the URL"]),
+    // 定时查询对话框
+    ("scheduler.default_name", ["定时查询", "Scheduled query"]),
+    ("scheduler.need_query", ["请先在编辑器中输入查询", "Enter a query in the editor first"]),
+    ("scheduler.title", ["⏱ 定时查询", "⏱ Scheduled queries"]),
+    ("scheduler.intro", [
+        "连接数据库并在编辑器中输入查询后，可将其设置为定时查询",
+        "Connect to a database and enter a query in the editor to schedule it",
+    ]),
+    ("scheduler.query", ["查询:", "Query:"]),
+    ("scheduler.schedule", ["执行计划:", "Schedule:"]),
+    ("scheduler.schedule_hint", [
+        "间隔: 30s / 5m / 1h\ncron: 分 时 日 月 周，如 */10 9-18 * * 1-5",
+        "Interval: 30s / 5m / 1h\ncron: minute hour day month weekday, e.g. */10 9-18 * * 1-5",
+    ]),
+    ("scheduler.threshold", ["阈值提醒:", "Alert threshold:"]),
+    ("scheduler.rows", ["行数", "Rows"]),
+    ("scheduler.threshold_hint", [
+        "留空检查结果行数，填写列名检查第一行该列的数值",
+        "Leave empty to check the row count, or enter a column to check its value in the first row",
+    ]),
+    ("scheduler.start", ["⏱ 开始定时执行 [Enter]", "⏱ Schedule [Enter]"]),
+    ("scheduler.empty", ["暂无定时查询", "No scheduled queries"]),
+    ("scheduler.name_header", ["名称", "Name"]),
+    ("scheduler.schedule_header", ["计划", "Schedule"]),
+    ("scheduler.threshold_header", ["阈值", "Threshold"]),
+    ("scheduler.status_header", ["状态", "Status"]),
+    ("scheduler.error", ["错误: {}", "Error: {}"]),
+    ("scheduler.paused", ["已暂停", "Paused"]),
+    ("scheduler.next_run", ["下次 {}", "Next {}"]),
+    ("scheduler.no_next_run", ["无后续执行时间", "No upcoming run"]),
+    ("scheduler.run_count", ["已执行 {} 次", "Ran {} times"]),
+    ("scheduler.pause", ["暂停", "Pause"]),
+    ("scheduler.resume", ["恢复", "Resume"]),
+    ("scheduler.run_now", ["立即执行", "Run now"]),
+    ("scheduler.view_result", ["查看结果", "View the result"]),
+    // 工作流对话框
+    ("workflow.default_name", ["工作流 {}", "Workflow {}"]),
+    ("workflow.name_required", ["工作流名称不能为空", "Workflow name is required"]),
+    ("workflow.duplicate_name", ["工作流名称重复: {}", "Duplicate workflow name: {}"]),
+    ("workflow.title", ["⚙ 工作流", "⚙ Workflows"]),
+    ("workflow.run", ["▶ 运行", "▶ Run"]),
+    ("workflow.run_hint", ["运行当前工作流（使用面板中的内容，无需先保存）", "Run the current workflow as shown here, without saving first"]),
+    ("workflow.cli_hint", ["命令行: gridix --workflow <名称>", "Command line: gridix --workflow <name>"]),
+    ("workflow.list", ["工作流", "Workflows"]),
+    ("workflow.new", ["新建工作流", "New workflow"]),
+    ("workflow.delete", ["删除工作流", "Delete the workflow"]),
+    ("workflow.empty", ["暂无工作流", "No workflows"]),
+    ("workflow.start_hint", ["新建一个工作流开始编辑", "Create a workflow to start editing"]),
+    ("workflow.continue_on_error", ["失败后继续", "Continue on error"]),
+    ("workflow.continue_on_error_hint", ["某个步骤失败时仍执行后续步骤", "Keep running later steps when a step fails"]),
+    ("workflow.delete_step", ["删除步骤", "Delete the step"]),
+    ("workflow.no_steps", ["添加查询或导出步骤", "Add a query or export step"]),
+    ("workflow.add_query", ["➕ 查询步骤", "➕ Query step"]),
+    ("workflow.add_export", ["➕ 导出步骤", "➕ Export step"]),
+    ("workflow.query_connection", ["查询 · 连接:", "Query · connection:"]),
+    ("workflow.export_previous", ["导出上一步结果:", "Export the previous result:"]),
+    ("workflow.path", ["路径", "Path"]),
+    ("workflow.path_hint", [
+        "支持 {date}（YYYYMMDD）和 {time}（HHMMSS）占位符",
+        "Supports the {date} (YYYYMMDD) and {time} (HHMMSS) placeholders",
+    ]),
+    ("workflow.choose_path", ["选择保存位置", "Choose where to save"]),
+    ("workflow.table", ["表名", "Table name"]),
+    ("workflow.run_progress", ["运行「{}」: {}/{}", "Run \"{}\": {}/{}"]),
+    ("workflow.skipped", ["已跳过", "Skipped"]),
+    // 主题编辑器
+    ("theme_editor.no_theme", ["没有正在编辑的主题", "No theme is being edited"]),
+    ("theme_editor.name_required", ["主题名称不能为空", "Theme name is required"]),
+    ("theme_editor.name_exists", ["主题 '{}' 已存在", "Theme '{}' already exists"]),
+    ("theme_editor.title", ["🎨 主题编辑器", "🎨 Theme editor"]),
+    ("theme_editor.unsaved", ["（未保存）", "(unsaved)"]),
+    ("theme_editor.no_custom", ["还没有自定义主题", "No custom themes yet"]),
+    ("theme_editor.import", ["📂 导入...", "📂 Import..."]),
+    ("theme_editor.export", ["💾 导出...", "💾 Export..."]),
+    ("theme_editor.export_hint", ["导出为 TOML 或 JSON 文件", "Export as a TOML or JSON file"]),
+    ("theme_editor.delete", ["🗑 删除", "🗑 Delete"]),
+    ("theme_editor.based_on", ["基于:", "Based on:"]),
+    ("theme_editor.based_on_hint", ["选择预设主题会重置所有颜色", "Picking a preset resets every color"]),
+    ("theme_editor.dark", ["暗色:", "Dark:"]),
+    ("theme_editor.dark_style", ["基于暗色界面样式", "Use the dark widget style"]),
+    ("theme_editor.background", ["背景", "Background"]),
+    ("theme_editor.bg_primary", ["面板背景", "Panel background"]),
+    ("theme_editor.bg_secondary", ["窗口背景", "Window background"]),
+    ("theme_editor.bg_tertiary", ["输入框/代码背景", "Input/code background"]),
+    ("theme_editor.text", ["文字", "Text"]),
+    ("theme_editor.fg_primary", ["主要文字", "Primary text"]),
+    ("theme_editor.fg_secondary", ["次要文字", "Secondary text"]),
+    ("theme_editor.fg_muted", ["弱化文字", "Muted text"]),
+    ("theme_editor.accent", ["强调色", "Accent"]),
+    ("theme_editor.accent_hover", ["强调色（悬停）", "Accent (hover)"]),
+    ("theme_editor.status", ["状态", "Status"]),
+    ("theme_editor.success", ["成功", "Success"]),
+    ("theme_editor.warning", ["警告", "Warning"]),
+    ("theme_editor.error", ["错误", "Error"]),
+    ("theme_editor.info", ["信息", "Info"]),
+    ("theme_editor.border", ["边框", "Border"]),
+    ("theme_editor.border_hover", ["边框（悬停）", "Border (hover)"]),
+    ("theme_editor.selection_section", ["选中", "Selection"]),
+    ("theme_editor.selection", ["选中背景（表格/文本）", "Selection background (grid/text)"]),
+    ("theme_editor.highlight", ["高亮", "Highlight"]),
+    ("theme_editor.syntax", ["SQL 语法", "SQL syntax"]),
+    ("theme_editor.keyword", ["关键字", "Keywords"]),
+    ("theme_editor.function", ["函数", "Functions"]),
+    ("theme_editor.string", ["字符串", "Strings"]),
+    ("theme_editor.number", ["数字", "Numbers"]),
+    ("theme_editor.operator", ["运算符", "Operators"]),
+    ("theme_editor.comment", ["注释", "Comments"]),
+    ("theme_editor.identifier", ["标识符", "Identifiers"]),
+    ("theme_editor.punctuation", ["标点", "Punctuation"]),
+    ("theme_editor.save", ["保存并使用", "Save and apply"]),
+    ("theme_editor.close", ["关闭 [Esc]", "Close [Esc]"]),
+    ("theme_editor.close_hint", ["未保存的修改会被丢弃", "Unsaved changes are discarded"]),
+    // 基准测试对话框
+    ("benchmark.title", ["⏱ 基准测试", "⏱ Benchmark"]),
+    ("benchmark.runs", ["执行次数:", "Runs:"]),
+    ("benchmark.warmup", ["预热次数:", "Warm-up runs:"]),
+    ("benchmark.warmup_hint", [
+        "预热执行不计入统计，用于让数据库加载缓存",
+        "Warm-up runs are not counted; they let the database warm its caches",
+    ]),
+    ("benchmark.stop", ["⏹ 停止", "⏹ Stop"]),
+    ("benchmark.warming_up", ["预热 {}/{}", "Warming up {}/{}"]),
+    ("benchmark.start", ["▶ 开始", "▶ Start"]),
+    ("benchmark.error", ["执行出错: {}", "Run failed: {}"]),
+    ("benchmark.intro", [
+        "重复执行查询并统计耗时，适合在调整索引前后比较。只能测试只读查询。",
+        "Runs a query repeatedly and reports timings, e.g. to compare before and after an index change. Only read-only queries can be benchmarked.",
+    ]),
+    ("benchmark.count", ["次数", "Runs"]),
+    ("benchmark.min", ["最小", "Min"]),
+    ("benchmark.avg", ["平均", "Avg"]),
+    ("benchmark.max", ["最大", "Max"]),
+    ("benchmark.rows_per_sec", ["行/秒", "Rows/s"]),
+    ("benchmark.sample", ["第 {} 次: {} · {} 行", "Run {}: {} · {} rows"]),
+    // 批量修改对话框
+    ("bulk_edit.expression_required", ["表达式不能为空", "Expression is required"]),
+    ("bulk_edit.single_statement", ["表达式不能包含多条语句", "The expression cannot contain multiple statements"]),
+    ("bulk_edit.no_filters", ["没有启用的筛选条件", "No filters are enabled"]),
+    ("bulk_edit.title", ["批量修改", "Bulk edit"]),
+    ("bulk_edit.intro", [
+        "修改当前筛选条件匹配的所有行（不只是已读取的行）",
+        "Updates every row matching the current filters, not just the loaded rows",
+    ]),
+    ("bulk_edit.column", ["列:", "Column:"]),
+    ("bulk_edit.value", ["新值:", "New value:"]),
+    ("bulk_edit.literal", ["文本", "Text"]),
+    ("bulk_edit.expression", ["表达式", "Expression"]),
+    ("bulk_edit.set_null", ["设为 NULL", "Set to NULL"]),
+    ("bulk_edit.expression_hint", ["如 price * 1.1、UPPER(name)", "e.g. price * 1.1, UPPER(name)"]),
+    ("bulk_edit.value_hint", ["新值", "New value"]),
+    ("bulk_edit.preview", ["预览影响行 [Enter]", "Preview affected rows [Enter]"]),
+    // 全库数据搜索对话框
+    ("data_search.title", ["🔎 全库数据搜索", "🔎 Search all data"]),
+    ("data_search.keyword", ["关键字:", "Text:"]),
+    ("data_search.keyword_hint", ["要查找的文本", "Text to find"]),
+    ("data_search.column_filter", ["列名包含:", "Column contains:"]),
+    ("data_search.column_filter_hint", ["全部文本列", "All text columns"]),
+    ("data_search.case_sensitive", ["区分大小写", "Case sensitive"]),
+    ("data_search.search", ["搜索 [Enter]", "Search [Enter]"]),
+    ("data_search.tables", ["表 ({}/{})", "Tables ({}/{})"]),
+    ("data_search.select_all", ["全选", "All"]),
+    ("data_search.clear", ["清空", "None"]),
+    ("data_search.filter_tables", ["筛选表", "Filter tables"]),
+    ("data_search.summary", ["{}/{} 张表 · {} 张表命中 {} 行", "{}/{} tables · {} tables matched {} rows"]),
+    ("data_search.skipped", [" · {} 张表无文本列", " · {} tables without text columns"]),
+    ("data_search.no_hits", ["没有找到匹配的数据", "No matching data"]),
+    ("data_search.hit_rows_truncated", ["{} ({}+ 行)", "{} ({}+ rows)"]),
+    ("data_search.hit_rows", ["{} ({} 行)", "{} ({} rows)"]),
+    ("data_search.hit_error", ["{} (出错)", "{} (failed)"]),
+    ("data_search.open", ["在表格中打开", "Open in grid"]),
+    ("data_search.matched", ["(匹配)", "(match)"]),
+    ("data_search.open_hint", ["点击在表格中打开", "Click to open in the grid"]),
+    // 会话监控对话框
+    ("sessions.title", ["会话监控 - {}", "Sessions - {}"]),
+    ("sessions.legend", [
+        "执行超过 {}s 黄色、超过 {}s 红色显示；🔒 表示被阻塞，⛔ 表示正在阻塞其他会话",
+        "Yellow after {}s, red after {}s; 🔒 means blocked, ⛔ means blocking other sessions",
+    ]),
+    ("sessions.summary", ["{} 个会话 · 活跃 {}", "{} sessions · {} active"]),
+    ("sessions.blocked", [" · 被阻塞 {}", " · {} blocked"]),
+    ("sessions.show_idle", ["显示空闲会话", "Show idle sessions"]),
+    ("sessions.refresh", ["🔄 刷新 [r]", "🔄 Refresh [r]"]),
+    ("sessions.off", ["关闭", "Off"]),
+    ("sessions.auto_refresh", ["自动刷新:", "Auto refresh:"]),
+    ("sessions.confirm_cancel", ["确认取消会话 {} 的当前查询？", "Cancel the current query of session {}?"]),
+    ("sessions.confirm_kill", ["确认终止会话 {}？", "Terminate session {}?"]),
+    ("sessions.confirm", ["确认", "Confirm"]),
+    ("sessions.empty", ["没有活跃的会话", "No active sessions"]),
+    ("sessions.user", ["用户", "User"]),
+    ("sessions.database", ["数据库", "Database"]),
+    ("sessions.client", ["客户端", "Client"]),
+    ("sessions.state", ["状态", "State"]),
+    ("sessions.duration", ["时长", "Duration"]),
+    ("sessions.wait", ["等待", "Wait"]),
+    ("sessions.query", ["查询", "Query"]),
+    ("sessions.blocked_by", ["被 {} 阻塞 {}", "Blocked by {} {}"]),
+    ("sessions.cancel_query", ["取消当前查询", "Cancel the current query"]),
+    ("sessions.kill", ["终止会话", "Terminate the session"]),
+];
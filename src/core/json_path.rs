@@ -0,0 +1,247 @@
+//! JSON 值检测与路径筛选
+//!
+//! 识别包含 JSON 的单元格和列，并解析 `$.user.id = 5` 形式的路径条件：
+//! 路径支持 `.key`、`["key"]` 和 `[0]`，比较符可省略（表示路径存在）。
+//! 客户端直接在单元格 JSON 上求值，服务端筛选由调用方转换为各数据库的 JSON 函数。
+
+use crate::database::QueryResult;
+use serde_json::Value;
+use std::cmp::Ordering;
+
+/// 检测 JSON 列时每列最多采样的值数量
+const JSON_SAMPLE_SIZE: usize = 50;
+
+/// 值是否像 JSON 对象或数组（先做廉价的首尾字符判断再解析）
+pub fn looks_like_json(value: &str) -> bool {
+    let value = value.trim();
+    let bracketed = (value.starts_with('{') && value.ends_with('}')) || (value.starts_with('[') && value.ends_with(']'));
+    bracketed && serde_json::from_str::<Value>(value).is_ok()
+}
+
+/// 格式化 JSON 对象或数组，不是 JSON 时返回 `None`
+pub fn pretty_json(value: &str) -> Option<String> {
+    if !looks_like_json(value) {
+        return None;
+    }
+    let parsed: Value = serde_json::from_str(value.trim()).ok()?;
+    serde_json::to_string_pretty(&parsed).ok()
+}
+
+/// 检测每列是否为 JSON 列：采样的非空值都是 JSON 对象或数组
+pub fn detect_json_columns(result: &QueryResult) -> Vec<bool> {
+    (0..result.columns.len())
+        .map(|col| {
+            let mut samples = result
+                .rows
+                .iter()
+                .filter_map(|row| row.get(col))
+                .filter(|v| !v.is_empty() && v.as_str() != "NULL")
+                .take(JSON_SAMPLE_SIZE)
+                .peekable();
+            samples.peek().is_some() && samples.all(|v| looks_like_json(v))
+        })
+        .collect()
+}
+
+/// 路径中的一段
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JsonPathSegment {
+    /// 对象键
+    Key(String),
+    /// 数组下标
+    Index(usize),
+}
+
+/// JSON 路径
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonPath {
+    /// 路径各段（为空表示根）
+    pub segments: Vec<JsonPathSegment>,
+}
+
+impl JsonPath {
+    /// 解析 `$.a.b[0]["c d"]` 形式的路径
+    pub fn parse(path: &str) -> Result<Self, String> {
+        let path = path.trim();
+        let mut rest = path
+            .strip_prefix('$')
+            .ok_or_else(|| format!("JSON 路径必须以 $ 开头: {}", path))?;
+        let mut segments = Vec::new();
+        while !rest.is_empty() {
+            if let Some(after) = rest.strip_prefix('.') {
+                let end = after.find(['.', '[']).unwrap_or(after.len());
+                let key = &after[..end];
+                if key.is_empty() {
+                    return Err(format!("JSON 路径中有空的键: {}", path));
+                }
+                segments.push(JsonPathSegment::Key(key.to_string()));
+                rest = &after[end..];
+            } else if let Some(after) = rest.strip_prefix('[') {
+                let end = after.find(']').ok_or_else(|| format!("JSON 路径缺少 ]: {}", path))?;
+                let inner = after[..end].trim();
+                let segment = if let Some(quoted) = inner.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
+                    JsonPathSegment::Key(quoted.to_string())
+                } else {
+                    JsonPathSegment::Index(
+                        inner.parse().map_err(|_| format!("无效的数组下标 [{}]: {}", inner, path))?,
+                    )
+                };
+                segments.push(segment);
+                rest = &after[end + 1..];
+            } else {
+                return Err(format!("无效的 JSON 路径: {}", path));
+            }
+        }
+        Ok(Self { segments })
+    }
+
+    /// 在 JSON 值中查找路径指向的值
+    pub fn get<'a>(&self, value: &'a Value) -> Option<&'a Value> {
+        self.segments.iter().try_fold(value, |current, segment| match segment {
+            JsonPathSegment::Key(key) => current.get(key.as_str()),
+            JsonPathSegment::Index(idx) => current.get(*idx),
+        })
+    }
+
+    /// 标准 SQL/JSON 路径文本（键统一加双引号，用于 MySQL、SQLite、DuckDB、Oracle）
+    pub fn to_sql_path(&self) -> String {
+        let mut text = String::from("$");
+        for segment in &self.segments {
+            match segment {
+                JsonPathSegment::Key(key) => {
+                    text.push_str(&format!(".\"{}\"", key.replace('\\', "\\\\").replace('"', "\\\"")));
+                }
+                JsonPathSegment::Index(idx) => text.push_str(&format!("[{}]", idx)),
+            }
+        }
+        text
+    }
+}
+
+/// 路径条件的比较符
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsonCompare {
+    /// 路径存在
+    Exists,
+    Eq,
+    Ne,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+}
+
+impl JsonCompare {
+    /// SQL 比较符（`Exists` 没有比较符）
+    pub fn sql_operator(&self) -> &'static str {
+        match self {
+            Self::Exists => "",
+            Self::Eq => "=",
+            Self::Ne => "<>",
+            Self::Gt => ">",
+            Self::Ge => ">=",
+            Self::Lt => "<",
+            Self::Le => "<=",
+        }
+    }
+
+    fn accepts(&self, ord: Ordering) -> bool {
+        match self {
+            Self::Exists => true,
+            Self::Eq => ord == Ordering::Equal,
+            Self::Ne => ord != Ordering::Equal,
+            Self::Gt => ord == Ordering::Greater,
+            Self::Ge => ord != Ordering::Less,
+            Self::Lt => ord == Ordering::Less,
+            Self::Le => ord != Ordering::Greater,
+        }
+    }
+}
+
+/// JSON 路径条件，如 `$.user.id = 5`、`$.tags[0] != "x"`、`$.deleted_at`
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonPathCondition {
+    /// 路径
+    pub path: JsonPath,
+    /// 比较符
+    pub compare: JsonCompare,
+    /// 比较的值（JSON 字面量，无法解析时作为字符串）
+    pub value: Value,
+}
+
+impl JsonPathCondition {
+    /// 解析路径条件
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let text = text.trim();
+        // 跳过带引号的键中出现的比较符字符
+        let mut in_quotes = false;
+        let op_start = text.char_indices().find_map(|(i, c)| match c {
+            '"' => {
+                in_quotes = !in_quotes;
+                None
+            }
+            '=' | '!' | '<' | '>' if !in_quotes => Some(i),
+            _ => None,
+        });
+        let Some(start) = op_start else {
+            return Ok(Self {
+                path: JsonPath::parse(text)?,
+                compare: JsonCompare::Exists,
+                value: Value::Null,
+            });
+        };
+        let (path, rest) = text.split_at(start);
+        let (compare, literal) = [
+            ("!=", JsonCompare::Ne),
+            ("<>", JsonCompare::Ne),
+            (">=", JsonCompare::Ge),
+            ("<=", JsonCompare::Le),
+            ("==", JsonCompare::Eq),
+            ("=", JsonCompare::Eq),
+            (">", JsonCompare::Gt),
+            ("<", JsonCompare::Lt),
+        ]
+        .iter()
+        .find_map(|(symbol, compare)| rest.strip_prefix(symbol).map(|literal| (*compare, literal.trim())))
+        .ok_or_else(|| format!("无效的比较符: {}", rest))?;
+        if literal.is_empty() {
+            return Err("缺少比较的值".to_string());
+        }
+        let value = serde_json::from_str(literal)
+            .unwrap_or_else(|_| Value::String(literal.trim_matches('\'').to_string()));
+        Ok(Self {
+            path: JsonPath::parse(path)?,
+            compare,
+            value,
+        })
+    }
+
+    /// 单元格是否满足条件（不是 JSON 或路径不存在时只有 `!=` 成立）
+    pub fn matches(&self, cell: &str) -> bool {
+        let found = serde_json::from_str::<Value>(cell.trim())
+            .ok()
+            .and_then(|json| self.path.get(&json).cloned());
+        match (self.compare, found) {
+            (JsonCompare::Exists, found) => found.is_some(),
+            (JsonCompare::Ne, None) => true,
+            (_, None) => false,
+            (compare, Some(found)) => compare_json(&found, &self.value).is_some_and(|ord| compare.accepts(ord)),
+        }
+    }
+}
+
+/// JSON 标量的文本形式（字符串不带引号）
+pub fn json_scalar_text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// 比较两个 JSON 值：都是数字时按数值，否则按文本
+fn compare_json(a: &Value, b: &Value) -> Option<Ordering> {
+    match (a.as_f64(), b.as_f64()) {
+        (Some(x), Some(y)) => x.partial_cmp(&y),
+        _ => Some(json_scalar_text(a).cmp(&json_scalar_text(b))),
+    }
+}
@@ -0,0 +1,103 @@
+//! 键盘宏录制与回放
+//!
+//! 录制期间逐帧保存键盘事件（按键和输入的文本），回放时每帧注入一帧录制的事件，
+//! 表格和编辑器按正常的键盘处理逻辑执行，与手动按键的效果相同。
+
+use egui::Event;
+use std::collections::VecDeque;
+
+/// 键盘宏
+#[derive(Debug, Default)]
+pub struct KeyMacro {
+    /// 是否正在录制
+    recording: bool,
+    /// 正在录制的帧
+    current: Vec<Vec<Event>>,
+    /// 上一次录制完成的宏
+    recorded: Vec<Vec<Event>>,
+    /// 待回放的帧
+    queue: VecDeque<Vec<Event>>,
+    /// 当前帧是否注入了回放的事件
+    injected: bool,
+}
+
+impl KeyMacro {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 开始录制（回放期间不能录制）
+    pub fn start(&mut self) -> bool {
+        if self.is_replaying() {
+            return false;
+        }
+        self.recording = true;
+        self.current.clear();
+        true
+    }
+
+    /// 停止录制，返回录制的按键数（没有录制到按键时保留之前的宏）
+    ///
+    /// 最后一帧包含停止录制的按键，不属于宏。
+    pub fn stop(&mut self) -> usize {
+        self.recording = false;
+        self.current.pop();
+        let frames = std::mem::take(&mut self.current);
+        let keys = count_key_presses(&frames);
+        if keys > 0 {
+            self.recorded = frames;
+        }
+        keys
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.recording
+    }
+
+    /// 正在回放（包括注入了最后一帧的当前帧）
+    pub fn is_replaying(&self) -> bool {
+        self.injected || !self.queue.is_empty()
+    }
+
+    /// 记录一帧的输入事件（只保留按键、文本和粘贴）
+    pub fn record_frame(&mut self, events: &[Event]) {
+        if !self.recording {
+            return;
+        }
+        let frame: Vec<Event> = events
+            .iter()
+            .filter(|e| matches!(e, Event::Key { .. } | Event::Text(_) | Event::Paste(_)))
+            .cloned()
+            .collect();
+        if !frame.is_empty() {
+            self.current.push(frame);
+        }
+    }
+
+    /// 回放录制的宏 `count` 次，没有录制的宏或正在录制、回放时返回 `false`
+    pub fn replay(&mut self, count: usize) -> bool {
+        if self.recorded.is_empty() || self.recording || self.is_replaying() {
+            return false;
+        }
+        for _ in 0..count.max(1) {
+            self.queue.extend(self.recorded.iter().cloned());
+        }
+        true
+    }
+
+    /// 取出下一帧要注入的事件（每帧调用一次）
+    pub fn next_frame(&mut self) -> Option<Vec<Event>> {
+        let frame = self.queue.pop_front();
+        self.injected = frame.is_some();
+        frame
+    }
+}
+
+/// 帧中按下的按键数（不含重复）
+fn count_key_presses(frames: &[Vec<Event>]) -> usize {
+    frames
+        .iter()
+        .flatten()
+        .filter(|e| matches!(e, Event::Key { pressed: true, repeat: false, .. }))
+        .count()
+}
@@ -1,12 +1,14 @@
 //! 可配置快捷键系统
 //!
 //! 支持用户自定义快捷键绑定，并持久化到配置文件。
+//! 配置文件中以 `操作 = "快捷键"` 的形式保存，空字符串表示清除该操作的快捷键，
+//! 未出现的操作使用默认快捷键。
 
 #![allow(dead_code)] // 公开 API，供未来使用
 
-use egui::{Key, Modifiers};
+use egui::{InputState, Key, Modifiers};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt;
 
 /// 快捷键绑定
@@ -234,13 +236,16 @@ impl KeyBinding {
 
     /// 从字符串解析快捷键 (如 "Ctrl+Shift+N")
     pub fn parse(s: &str) -> Option<Self> {
-        let parts: Vec<&str> = s.split('+').map(|p| p.trim()).collect();
-        if parts.is_empty() {
-            return None;
-        }
+        let s = s.trim();
+        // 主键本身是 '+' 时（如 "Ctrl++"），单独取出
+        let (mods_str, plus_key) = match s.strip_suffix('+') {
+            Some(rest) if rest.is_empty() || rest.ends_with('+') => (rest.trim_end_matches('+'), true),
+            _ => (s, false),
+        };
+        let parts: Vec<&str> = mods_str.split('+').map(|p| p.trim()).filter(|p| !p.is_empty()).collect();
 
         let mut modifiers = KeyModifiers::NONE;
-        let mut key_str = "";
+        let mut key_str = if plus_key { "+" } else { "" };
 
         for part in &parts {
             let part_lower = part.to_lowercase();
@@ -301,9 +306,28 @@ impl KeyBinding {
 
     /// 检查快捷键是否在当前帧被按下
     pub fn is_pressed(&self, ctx: &egui::Context) -> bool {
-        ctx.input(|i| {
-            self.modifiers.matches(&i.modifiers) && i.key_pressed(self.key.to_egui_key())
-        })
+        ctx.input(|i| self.matches(i))
+    }
+
+    /// 检查输入状态中快捷键是否被按下
+    ///
+    /// `+` 与 `=` 位于同一物理按键，绑定 `+` 时按 `=` 也会触发
+    pub fn matches(&self, input: &InputState) -> bool {
+        self.modifiers.matches(&input.modifiers)
+            && (input.key_pressed(self.key.to_egui_key())
+                || (self.key == KeyCode::Plus && input.key_pressed(Key::Equals)))
+    }
+
+    /// 是否可以作为全局快捷键
+    ///
+    /// 不带修饰键的普通按键会干扰文本输入，只有功能键允许单独使用
+    pub fn is_valid_shortcut(&self) -> bool {
+        let has_modifier = self.modifiers.ctrl || self.modifiers.alt || self.modifiers.mac_cmd;
+        has_modifier || matches!(
+            self.key,
+            KeyCode::F1 | KeyCode::F2 | KeyCode::F3 | KeyCode::F4 | KeyCode::F5 | KeyCode::F6
+                | KeyCode::F7 | KeyCode::F8 | KeyCode::F9 | KeyCode::F10 | KeyCode::F11 | KeyCode::F12
+        )
     }
 
     /// 显示快捷键字符串
@@ -351,6 +375,38 @@ pub enum Action {
     ClearCommandLine,
     /// 清空搜索
     ClearSearch,
+    /// 权限管理
+    ManagePrivileges,
+    /// 切换日/夜模式
+    ToggleDarkMode,
+    /// 打开主题选择器
+    ShowThemeSelector,
+    /// 打开首选项
+    ShowPreferences,
+    /// 打开快捷键设置
+    ShowKeyBindings,
+    /// 打开命令面板
+    ShowCommandPalette,
+    /// 打开最近使用的表和数据库快速切换器
+    ShowQuickSwitcher,
+    /// 打开全库数据搜索
+    ShowDataSearch,
+
+    // === 侧边栏 ===
+    /// 切换到连接列表
+    SidebarConnections,
+    /// 切换到数据库列表
+    SidebarDatabases,
+    /// 切换到表列表
+    SidebarTables,
+    /// 切换到筛选面板
+    SidebarFilters,
+    /// 切换到触发器列表
+    SidebarTriggers,
+    /// 切换到存储过程列表
+    SidebarRoutines,
+    /// 切换到序列列表
+    SidebarSequences,
 
     // === 创建操作 ===
     /// 新建表
@@ -369,6 +425,12 @@ pub enum Action {
     NextTab,
     /// 上一个 Tab
     PrevTab,
+    /// 重命名当前 Tab
+    RenameTab,
+    /// 固定/取消固定当前 Tab
+    PinTab,
+    /// 重新打开最近关闭的 Tab
+    ReopenTab,
 
     // === 编辑操作 ===
     /// 保存
@@ -377,8 +439,14 @@ pub enum Action {
     AddFilter,
     /// 清空筛选
     ClearFilters,
+    /// 按内容自动调整所有列宽
+    AutoFitColumns,
     /// 跳转到行
     GotoLine,
+    /// 打开 SQL 文件
+    OpenFile,
+    /// SQL 另存为文件
+    SaveFileAs,
 
     // === 缩放 ===
     /// 放大
@@ -404,6 +472,21 @@ impl Action {
             Action::Refresh,
             Action::ClearCommandLine,
             Action::ClearSearch,
+            Action::ManagePrivileges,
+            Action::ToggleDarkMode,
+            Action::ShowThemeSelector,
+            Action::ShowPreferences,
+            Action::ShowKeyBindings,
+            Action::ShowCommandPalette,
+            Action::ShowQuickSwitcher,
+            Action::ShowDataSearch,
+            Action::SidebarConnections,
+            Action::SidebarDatabases,
+            Action::SidebarTables,
+            Action::SidebarFilters,
+            Action::SidebarTriggers,
+            Action::SidebarRoutines,
+            Action::SidebarSequences,
             Action::NewTable,
             Action::NewDatabase,
             Action::NewUser,
@@ -411,10 +494,16 @@ impl Action {
             Action::CloseTab,
             Action::NextTab,
             Action::PrevTab,
+            Action::RenameTab,
+            Action::PinTab,
+            Action::ReopenTab,
             Action::Save,
             Action::AddFilter,
             Action::ClearFilters,
+            Action::AutoFitColumns,
             Action::GotoLine,
+            Action::OpenFile,
+            Action::SaveFileAs,
             Action::ZoomIn,
             Action::ZoomOut,
             Action::ZoomReset,
@@ -435,6 +524,21 @@ impl Action {
             Action::Refresh => "刷新",
             Action::ClearCommandLine => "清空命令行",
             Action::ClearSearch => "清空搜索",
+            Action::ManagePrivileges => "权限管理",
+            Action::ToggleDarkMode => "切换日/夜模式",
+            Action::ShowThemeSelector => "选择主题",
+            Action::ShowPreferences => "首选项",
+            Action::ShowKeyBindings => "快捷键设置",
+            Action::ShowCommandPalette => "命令面板",
+            Action::ShowQuickSwitcher => "快速切换最近的表",
+            Action::ShowDataSearch => "全库数据搜索",
+            Action::SidebarConnections => "连接列表",
+            Action::SidebarDatabases => "数据库列表",
+            Action::SidebarTables => "表列表",
+            Action::SidebarFilters => "筛选面板",
+            Action::SidebarTriggers => "触发器列表",
+            Action::SidebarRoutines => "存储过程列表",
+            Action::SidebarSequences => "序列列表",
             Action::NewTable => "新建表",
             Action::NewDatabase => "新建数据库",
             Action::NewUser => "新建用户",
@@ -442,33 +546,110 @@ impl Action {
             Action::CloseTab => "关闭 Tab",
             Action::NextTab => "下一个 Tab",
             Action::PrevTab => "上一个 Tab",
+            Action::RenameTab => "重命名 Tab",
+            Action::PinTab => "固定/取消固定 Tab",
+            Action::ReopenTab => "重新打开关闭的 Tab",
             Action::Save => "保存",
             Action::AddFilter => "添加筛选",
             Action::ClearFilters => "清空筛选",
+            Action::AutoFitColumns => "自动调整所有列宽",
             Action::GotoLine => "跳转到行",
+            Action::OpenFile => "打开 SQL 文件",
+            Action::SaveFileAs => "SQL 另存为",
             Action::ZoomIn => "放大",
             Action::ZoomOut => "缩小",
             Action::ZoomReset => "重置缩放",
         }
     }
 
+    /// 配置文件中使用的操作名称
+    pub fn id(&self) -> &'static str {
+        match self {
+            Action::NewConnection => "new_connection",
+            Action::ToggleSidebar => "toggle_sidebar",
+            Action::ToggleEditor => "toggle_editor",
+            Action::ToggleErDiagram => "toggle_er_diagram",
+            Action::ShowHelp => "show_help",
+            Action::ShowHistory => "show_history",
+            Action::Export => "export",
+            Action::Import => "import",
+            Action::Refresh => "refresh",
+            Action::ClearCommandLine => "clear_command_line",
+            Action::ClearSearch => "clear_search",
+            Action::ManagePrivileges => "manage_privileges",
+            Action::ToggleDarkMode => "toggle_dark_mode",
+            Action::ShowThemeSelector => "show_theme_selector",
+            Action::ShowPreferences => "show_preferences",
+            Action::ShowKeyBindings => "show_key_bindings",
+            Action::ShowCommandPalette => "show_command_palette",
+            Action::ShowQuickSwitcher => "show_quick_switcher",
+            Action::ShowDataSearch => "show_data_search",
+            Action::SidebarConnections => "sidebar_connections",
+            Action::SidebarDatabases => "sidebar_databases",
+            Action::SidebarTables => "sidebar_tables",
+            Action::SidebarFilters => "sidebar_filters",
+            Action::SidebarTriggers => "sidebar_triggers",
+            Action::SidebarRoutines => "sidebar_routines",
+            Action::SidebarSequences => "sidebar_sequences",
+            Action::NewTable => "new_table",
+            Action::NewDatabase => "new_database",
+            Action::NewUser => "new_user",
+            Action::NewTab => "new_tab",
+            Action::CloseTab => "close_tab",
+            Action::NextTab => "next_tab",
+            Action::PrevTab => "prev_tab",
+            Action::RenameTab => "rename_tab",
+            Action::PinTab => "pin_tab",
+            Action::ReopenTab => "reopen_tab",
+            Action::Save => "save",
+            Action::AddFilter => "add_filter",
+            Action::ClearFilters => "clear_filters",
+            Action::AutoFitColumns => "auto_fit_columns",
+            Action::GotoLine => "goto_line",
+            Action::OpenFile => "open_file",
+            Action::SaveFileAs => "save_file_as",
+            Action::ZoomIn => "zoom_in",
+            Action::ZoomOut => "zoom_out",
+            Action::ZoomReset => "zoom_reset",
+        }
+    }
+
+    /// 根据配置文件中的名称查找操作
+    pub fn from_id(id: &str) -> Option<Action> {
+        Self::all().iter().copied().find(|a| a.id() == id)
+    }
+
+    /// 所有分类（按显示顺序）
+    pub fn categories() -> &'static [&'static str] {
+        &["全局", "侧边栏", "创建", "Tab", "编辑", "缩放"]
+    }
+
     /// 获取操作的分类
     pub fn category(&self) -> &'static str {
         match self {
             Action::NewConnection | Action::ToggleSidebar | Action::ToggleEditor
             | Action::ToggleErDiagram | Action::ShowHelp | Action::ShowHistory
             | Action::Export | Action::Import | Action::Refresh
-            | Action::ClearCommandLine | Action::ClearSearch => "全局",
+            | Action::ClearCommandLine | Action::ClearSearch | Action::ManagePrivileges
+            | Action::ToggleDarkMode | Action::ShowThemeSelector | Action::ShowPreferences
+            | Action::ShowKeyBindings | Action::ShowCommandPalette | Action::ShowQuickSwitcher
+            | Action::ShowDataSearch => "全局",
+            Action::SidebarConnections | Action::SidebarDatabases | Action::SidebarTables
+            | Action::SidebarFilters | Action::SidebarTriggers | Action::SidebarRoutines
+            | Action::SidebarSequences => "侧边栏",
             Action::NewTable | Action::NewDatabase | Action::NewUser => "创建",
-            Action::NewTab | Action::CloseTab | Action::NextTab | Action::PrevTab => "Tab",
-            Action::Save | Action::AddFilter | Action::ClearFilters | Action::GotoLine => "编辑",
+            Action::NewTab | Action::CloseTab | Action::NextTab | Action::PrevTab | Action::RenameTab
+            | Action::PinTab | Action::ReopenTab => "Tab",
+            Action::Save | Action::AddFilter | Action::ClearFilters | Action::AutoFitColumns
+            | Action::GotoLine | Action::OpenFile | Action::SaveFileAs => "编辑",
             Action::ZoomIn | Action::ZoomOut | Action::ZoomReset => "缩放",
         }
     }
 }
 
 /// 快捷键绑定管理器
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(into = "BTreeMap<String, String>", from = "BTreeMap<String, String>")]
 pub struct KeyBindings {
     /// 操作到快捷键的映射
     bindings: HashMap<Action, KeyBinding>,
@@ -490,6 +671,23 @@ impl Default for KeyBindings {
         bindings.insert(Action::Refresh, KeyBinding::key_only(KeyCode::F5));
         bindings.insert(Action::ClearCommandLine, KeyBinding::ctrl(KeyCode::L));
         bindings.insert(Action::ClearSearch, KeyBinding::ctrl(KeyCode::K));
+        bindings.insert(Action::ManagePrivileges, KeyBinding::ctrl_shift(KeyCode::M));
+        bindings.insert(Action::ToggleDarkMode, KeyBinding::ctrl(KeyCode::D));
+        bindings.insert(Action::ShowThemeSelector, KeyBinding::ctrl_shift(KeyCode::L));
+        bindings.insert(Action::ShowPreferences, KeyBinding::ctrl(KeyCode::Comma));
+        bindings.insert(Action::ShowKeyBindings, KeyBinding::new(KeyCode::K, KeyModifiers::ALT));
+        bindings.insert(Action::ShowCommandPalette, KeyBinding::ctrl_shift(KeyCode::P));
+        bindings.insert(Action::ShowQuickSwitcher, KeyBinding::ctrl(KeyCode::P));
+        bindings.insert(Action::ShowDataSearch, KeyBinding::ctrl_shift(KeyCode::G));
+
+        // 侧边栏
+        bindings.insert(Action::SidebarConnections, KeyBinding::ctrl(KeyCode::Num1));
+        bindings.insert(Action::SidebarDatabases, KeyBinding::ctrl(KeyCode::Num2));
+        bindings.insert(Action::SidebarTables, KeyBinding::ctrl(KeyCode::Num3));
+        bindings.insert(Action::SidebarFilters, KeyBinding::ctrl(KeyCode::Num4));
+        bindings.insert(Action::SidebarTriggers, KeyBinding::ctrl(KeyCode::Num5));
+        bindings.insert(Action::SidebarRoutines, KeyBinding::ctrl(KeyCode::Num6));
+        bindings.insert(Action::SidebarSequences, KeyBinding::ctrl(KeyCode::Num7));
 
         // 创建操作
         bindings.insert(Action::NewTable, KeyBinding::ctrl_shift(KeyCode::N));
@@ -497,15 +695,21 @@ impl Default for KeyBindings {
         bindings.insert(Action::NewUser, KeyBinding::ctrl_shift(KeyCode::U));
 
         // Tab 操作
+        bindings.insert(Action::NewTab, KeyBinding::ctrl(KeyCode::T));
         bindings.insert(Action::CloseTab, KeyBinding::ctrl(KeyCode::W));
         bindings.insert(Action::NextTab, KeyBinding::new(KeyCode::Tab, KeyModifiers::CTRL));
         bindings.insert(Action::PrevTab, KeyBinding::new(KeyCode::Tab, KeyModifiers::CTRL_SHIFT));
+        bindings.insert(Action::RenameTab, KeyBinding::key_only(KeyCode::F2));
+        bindings.insert(Action::ReopenTab, KeyBinding::ctrl_shift(KeyCode::T));
 
         // 编辑操作
         bindings.insert(Action::Save, KeyBinding::ctrl(KeyCode::S));
         bindings.insert(Action::AddFilter, KeyBinding::ctrl(KeyCode::F));
         bindings.insert(Action::ClearFilters, KeyBinding::ctrl_shift(KeyCode::F));
+        bindings.insert(Action::AutoFitColumns, KeyBinding::ctrl_shift(KeyCode::W));
         bindings.insert(Action::GotoLine, KeyBinding::ctrl(KeyCode::G));
+        bindings.insert(Action::OpenFile, KeyBinding::ctrl(KeyCode::O));
+        bindings.insert(Action::SaveFileAs, KeyBinding::ctrl_shift(KeyCode::S));
 
         // 缩放
         bindings.insert(Action::ZoomIn, KeyBinding::ctrl(KeyCode::Plus));
@@ -545,6 +749,23 @@ impl KeyBindings {
             .unwrap_or(false)
     }
 
+    /// 当前输入中被触发的所有操作（按 `Action::all()` 的顺序）
+    pub fn triggered(&self, input: &InputState) -> Vec<Action> {
+        Action::all()
+            .iter()
+            .copied()
+            .filter(|action| self.bindings.get(action).is_some_and(|b| b.matches(input)))
+            .collect()
+    }
+
+    /// 查找已使用该快捷键的其他操作
+    pub fn conflict_with(&self, action: Action, binding: &KeyBinding) -> Option<Action> {
+        Action::all()
+            .iter()
+            .copied()
+            .find(|a| *a != action && self.bindings.get(a) == Some(binding))
+    }
+
     /// 查找被触发的操作
     pub fn find_triggered(&self, ctx: &egui::Context) -> Option<Action> {
         for (&action, binding) in &self.bindings {
@@ -580,7 +801,7 @@ impl KeyBindings {
         }
 
         let mut result: Vec<_> = categories.into_iter().collect();
-        result.sort_by_key(|(cat, _)| *cat);
+        result.sort_by_key(|(cat, _)| Action::categories().iter().position(|c| c == cat));
         
         // 对每个分类内的操作排序
         for (_, actions) in &mut result {
@@ -598,7 +819,10 @@ impl KeyBindings {
     /// 检查是否有冲突的快捷键
     pub fn find_conflicts(&self) -> Vec<(Action, Action, KeyBinding)> {
         let mut conflicts = Vec::new();
-        let actions: Vec<_> = self.bindings.iter().collect();
+        let actions: Vec<_> = Action::all()
+            .iter()
+            .filter_map(|a| self.bindings.get_key_value(a))
+            .collect();
 
         for i in 0..actions.len() {
             for j in (i + 1)..actions.len() {
@@ -613,3 +837,47 @@ impl KeyBindings {
         conflicts
     }
 }
+
+impl From<KeyBindings> for BTreeMap<String, String> {
+    fn from(bindings: KeyBindings) -> Self {
+        Action::all()
+            .iter()
+            .map(|action| (action.id().to_string(), bindings.display(*action)))
+            .collect()
+    }
+}
+
+impl From<BTreeMap<String, String>> for KeyBindings {
+    /// 在默认快捷键的基础上应用配置，无法识别的操作或快捷键会被忽略
+    ///
+    /// 配置中没有的操作（新版本增加的操作）如果默认快捷键已被配置给其他操作，则不绑定
+    fn from(map: BTreeMap<String, String>) -> Self {
+        let mut bindings = Self::default();
+        let configured: Vec<Action> = map.keys().filter_map(|id| Action::from_id(id)).collect();
+        for (id, value) in map {
+            let Some(action) = Action::from_id(&id) else {
+                tracing::warn!("未知的快捷键操作 '{}'，已忽略", id);
+                continue;
+            };
+            if value.trim().is_empty() {
+                bindings.remove(action);
+            } else if let Some(binding) = KeyBinding::parse(&value) {
+                bindings.set(action, binding);
+            } else {
+                tracing::warn!("无法解析快捷键 '{}'（{}），使用默认值", value, id);
+            }
+        }
+        for &action in Action::all() {
+            if configured.contains(&action) {
+                continue;
+            }
+            if let Some(binding) = bindings.get(action).cloned()
+                && bindings.conflict_with(action, &binding).is_some_and(|other| configured.contains(&other))
+            {
+                tracing::info!("'{}' 的默认快捷键 {} 已被占用，未绑定", action.id(), binding.display());
+                bindings.remove(action);
+            }
+        }
+        bindings
+    }
+}
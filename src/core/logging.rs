@@ -0,0 +1,363 @@
+//! 应用日志
+//!
+//! tracing 日志以 JSON 行写入配置目录下的 `logs/gridix.log`，超过大小上限时
+//! 轮转为 `gridix.log.1`、`gridix.log.2`……，只保留固定数量的旧文件。
+//! 日志查看器按行解析记录，并可增量读取新追加的内容。
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use egui::Color32;
+use parking_lot::Mutex;
+use serde_json::Value;
+
+/// 日志文件名
+pub const LOG_FILE_NAME: &str = "gridix.log";
+
+/// 单个日志文件的大小上限，超过后轮转
+pub const MAX_LOG_FILE_SIZE: u64 = 5 * 1024 * 1024;
+
+/// 保留的旧日志文件数量
+pub const MAX_LOG_BACKUPS: usize = 3;
+
+/// 首次读取时最多读取文件末尾的字节数
+const MAX_TAIL_BYTES: u64 = 1024 * 1024;
+
+/// 默认日志文件（配置目录下的 `logs/gridix.log`）
+pub fn default_log_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|p| p.join("gridix").join("logs").join(LOG_FILE_NAME))
+}
+
+/// 第 `n` 个轮转后的日志文件（`gridix.log.n`）
+pub fn rotated_log_path(path: &Path, n: usize) -> PathBuf {
+    let name = path.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+    path.with_file_name(format!("{}.{}", name, n))
+}
+
+// ============================================================================
+// 日志级别与记录
+// ============================================================================
+
+/// 日志级别（按严重程度递增）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl LogLevel {
+    /// 所有级别
+    pub const ALL: [LogLevel; 5] = [
+        LogLevel::Trace,
+        LogLevel::Debug,
+        LogLevel::Info,
+        LogLevel::Warn,
+        LogLevel::Error,
+    ];
+
+    /// 解析级别名称（不区分大小写）
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_ascii_uppercase().as_str() {
+            "TRACE" => Some(LogLevel::Trace),
+            "DEBUG" => Some(LogLevel::Debug),
+            "INFO" => Some(LogLevel::Info),
+            "WARN" | "WARNING" => Some(LogLevel::Warn),
+            "ERROR" => Some(LogLevel::Error),
+            _ => None,
+        }
+    }
+
+    /// 显示名称
+    pub fn label(&self) -> &'static str {
+        match self {
+            LogLevel::Trace => "TRACE",
+            LogLevel::Debug => "DEBUG",
+            LogLevel::Info => "INFO",
+            LogLevel::Warn => "WARN",
+            LogLevel::Error => "ERROR",
+        }
+    }
+
+    /// 显示颜色
+    pub fn color(&self) -> Color32 {
+        match self {
+            LogLevel::Trace => Color32::from_rgb(120, 120, 120),
+            LogLevel::Debug => Color32::from_rgb(150, 150, 170),
+            LogLevel::Info => Color32::from_rgb(100, 180, 255),
+            LogLevel::Warn => Color32::from_rgb(255, 200, 80),
+            LogLevel::Error => Color32::from_rgb(255, 100, 100),
+        }
+    }
+}
+
+/// 一条日志记录
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogEntry {
+    /// 时间（保持日志中的原始文本）
+    pub timestamp: String,
+    pub level: LogLevel,
+    /// 产生日志的模块
+    pub target: String,
+    pub message: String,
+    /// 其余结构化字段
+    pub fields: Vec<(String, String)>,
+}
+
+impl LogEntry {
+    /// 解析一行日志；不是 JSON 的行作为 INFO 级别的纯文本保留
+    pub fn parse(line: &str) -> Option<Self> {
+        let line = line.trim();
+        if line.is_empty() {
+            return None;
+        }
+        let Ok(Value::Object(obj)) = serde_json::from_str::<Value>(line) else {
+            return Some(Self {
+                timestamp: String::new(),
+                level: LogLevel::Info,
+                target: String::new(),
+                message: line.to_string(),
+                fields: Vec::new(),
+            });
+        };
+
+        let text = |key: &str| obj.get(key).and_then(Value::as_str).unwrap_or_default().to_string();
+        let mut message = String::new();
+        let mut fields = Vec::new();
+        if let Some(Value::Object(map)) = obj.get("fields") {
+            for (key, value) in map {
+                let value = match value {
+                    Value::String(s) => s.clone(),
+                    other => other.to_string(),
+                };
+                if key == "message" {
+                    message = value;
+                } else {
+                    fields.push((key.clone(), value));
+                }
+            }
+        }
+
+        Some(Self {
+            timestamp: text("timestamp"),
+            level: obj
+                .get("level")
+                .and_then(Value::as_str)
+                .and_then(LogLevel::parse)
+                .unwrap_or(LogLevel::Info),
+            target: text("target"),
+            message,
+            fields,
+        })
+    }
+
+    /// 单行文本形式，用于显示和复制
+    pub fn to_line(&self) -> String {
+        let mut line = String::new();
+        if !self.timestamp.is_empty() {
+            line.push_str(&self.timestamp);
+            line.push(' ');
+        }
+        line.push_str(&format!("{:5} ", self.level.label()));
+        if !self.target.is_empty() {
+            line.push_str(&self.target);
+            line.push_str(": ");
+        }
+        line.push_str(&self.message);
+        for (key, value) in &self.fields {
+            line.push_str(&format!(" {}={}", key, value));
+        }
+        line
+    }
+}
+
+// ============================================================================
+// 轮转写入
+// ============================================================================
+
+/// 按大小轮转的日志文件，可作为 tracing 的写入目标
+#[derive(Debug, Clone)]
+pub struct RotatingFileWriter {
+    inner: Arc<Mutex<RotatingFile>>,
+}
+
+#[derive(Debug)]
+struct RotatingFile {
+    path: PathBuf,
+    max_size: u64,
+    max_backups: usize,
+    file: Option<File>,
+    size: u64,
+}
+
+impl RotatingFileWriter {
+    /// 打开日志文件（追加写入），超过 `max_size` 时轮转，保留 `max_backups` 个旧文件
+    pub fn new(path: impl Into<PathBuf>, max_size: u64, max_backups: usize) -> io::Result<Self> {
+        let path = path.into();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let file = open_log_file(&path)?;
+        let size = file.metadata()?.len();
+        Ok(Self {
+            inner: Arc::new(Mutex::new(RotatingFile {
+                path,
+                max_size,
+                max_backups,
+                file: Some(file),
+                size,
+            })),
+        })
+    }
+}
+
+impl RotatingFile {
+    /// 轮转后重新打开日志文件；改名失败时继续追加到原文件
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file = None;
+        let shifted = self.shift_files();
+        let file = open_log_file(&self.path)?;
+        self.size = file.metadata()?.len();
+        self.file = Some(file);
+        shifted
+    }
+
+    /// 当前文件改名为 `.1`，已有的旧文件依次后移，超出数量的删除
+    fn shift_files(&self) -> io::Result<()> {
+        if self.max_backups == 0 {
+            return fs::remove_file(&self.path);
+        }
+        let _ = fs::remove_file(rotated_log_path(&self.path, self.max_backups));
+        for n in (1..self.max_backups).rev() {
+            let from = rotated_log_path(&self.path, n);
+            if from.exists() {
+                fs::rename(&from, rotated_log_path(&self.path, n + 1))?;
+            }
+        }
+        fs::rename(&self.path, rotated_log_path(&self.path, 1))
+    }
+}
+
+impl Write for RotatingFileWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut inner = self.inner.lock();
+        if inner.size > 0 && inner.size + buf.len() as u64 > inner.max_size {
+            // 轮转失败不影响本次写入
+            let _ = inner.rotate();
+        }
+        let Some(file) = inner.file.as_mut() else {
+            return Err(io::Error::other("日志文件未打开"));
+        };
+        let n = file.write(buf)?;
+        inner.size += n as u64;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        let mut inner = self.inner.lock();
+        match inner.file.as_mut() {
+            Some(file) => file.flush(),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<'a> tracing_subscriber::fmt::MakeWriter<'a> for RotatingFileWriter {
+    type Writer = RotatingFileWriter;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        self.clone()
+    }
+}
+
+/// 以追加方式打开日志文件（Unix 上仅当前用户可读写）
+fn open_log_file(path: &Path) -> io::Result<File> {
+    let mut options = OpenOptions::new();
+    options.create(true).append(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    options.open(path)
+}
+
+// ============================================================================
+// 增量读取
+// ============================================================================
+
+/// 增量读取日志文件的新内容
+#[derive(Debug, Clone)]
+pub struct LogTail {
+    path: PathBuf,
+    /// 已读取到的位置
+    offset: u64,
+    /// 尚未读到换行的行尾
+    partial: String,
+}
+
+impl LogTail {
+    /// 从头开始读取指定文件
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            offset: 0,
+            partial: String::new(),
+        }
+    }
+
+    /// 日志文件路径
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// 读取上次之后新追加的记录
+    ///
+    /// 文件不存在时返回空；文件变小（已轮转）时从头读取；
+    /// 首次读取只读文件末尾的一部分。
+    pub fn poll(&mut self) -> io::Result<Vec<LogEntry>> {
+        let mut file = match File::open(&self.path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+        let len = file.metadata()?.len();
+        if len < self.offset {
+            self.offset = 0;
+            self.partial.clear();
+        }
+        if len == self.offset {
+            return Ok(Vec::new());
+        }
+
+        // 首次读取大文件时从末尾开始，并丢弃第一行不完整的内容
+        let skip_first = self.offset == 0 && len > MAX_TAIL_BYTES;
+        if skip_first {
+            self.offset = len - MAX_TAIL_BYTES;
+        }
+        file.seek(SeekFrom::Start(self.offset))?;
+        let mut buf = Vec::new();
+        file.take(len - self.offset).read_to_end(&mut buf)?;
+        self.offset = len;
+
+        let mut text = std::mem::take(&mut self.partial);
+        text.push_str(&String::from_utf8_lossy(&buf));
+        if !text.ends_with('\n')
+            && let Some(pos) = text.rfind('\n')
+        {
+            self.partial = text.split_off(pos + 1);
+        } else if !text.ends_with('\n') {
+            self.partial = std::mem::take(&mut text);
+        }
+
+        let mut lines = text.lines();
+        if skip_first {
+            lines.next();
+        }
+        Ok(lines.filter_map(LogEntry::parse).collect())
+    }
+}
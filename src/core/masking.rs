@@ -0,0 +1,93 @@
+//! 敏感列脱敏
+//!
+//! 按连接标记敏感列（按列名匹配，不区分大小写，对该连接的所有表和查询结果生效），
+//! 表格中显示脱敏后的值，导出时默认导出脱敏值，需要确认后才导出原始值。
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashSet};
+
+/// 脱敏后保留的末尾字母数字个数（值较长时）
+const KEEP_TAIL: usize = 4;
+
+/// 脱敏显示值
+///
+/// 邮箱保留用户名前两位和顶级域名（`jo***@***.com`）；其他值保留分隔符，
+/// 字母数字替换为 `*`，至少 8 位时保留最后 4 位（`***-**-6789`）。NULL 和空值不变。
+pub fn mask_value(value: &str) -> String {
+    if value.is_empty() || value == "NULL" {
+        return value.to_string();
+    }
+
+    if let Some((local, domain)) = value.split_once('@')
+        && let Some((_, tld)) = domain.rsplit_once('.')
+    {
+        let keep = (local.chars().count() / 2).min(2);
+        let head: String = local.chars().take(keep).collect();
+        return format!("{}***@***.{}", head, tld);
+    }
+
+    let alphanumeric = value.chars().filter(|c| c.is_alphanumeric()).count();
+    let keep = if alphanumeric >= KEEP_TAIL * 2 { KEEP_TAIL } else { 0 };
+    let mut seen = 0;
+    value
+        .chars()
+        .map(|c| {
+            if !c.is_alphanumeric() {
+                return c;
+            }
+            seen += 1;
+            if seen > alphanumeric - keep { c } else { '*' }
+        })
+        .collect()
+}
+
+/// 一个连接的敏感列
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ConnectionMasking {
+    connection: String,
+    /// 小写的列名
+    #[serde(default)]
+    columns: BTreeSet<String>,
+}
+
+/// 按连接保存的敏感列（随配置持久化）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MaskingRules {
+    #[serde(default)]
+    connections: Vec<ConnectionMasking>,
+}
+
+impl MaskingRules {
+    /// 某个连接的敏感列（小写列名）
+    pub fn columns(&self, connection: &str) -> HashSet<String> {
+        self.connections
+            .iter()
+            .find(|c| c.connection == connection)
+            .map(|c| c.columns.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// 标记或取消标记敏感列
+    pub fn set_masked(&mut self, connection: &str, column: &str, masked: bool) {
+        let column = column.to_lowercase();
+        match self.connections.iter_mut().find(|c| c.connection == connection) {
+            Some(entry) if masked => {
+                entry.columns.insert(column);
+            }
+            Some(entry) => {
+                entry.columns.remove(&column);
+            }
+            None if masked => self.connections.push(ConnectionMasking {
+                connection: connection.to_string(),
+                columns: BTreeSet::from([column]),
+            }),
+            None => {}
+        }
+        self.connections.retain(|c| !c.columns.is_empty());
+    }
+
+    /// 删除某个连接的敏感列
+    pub fn remove_connection(&mut self, connection: &str) {
+        self.connections.retain(|c| c.connection != connection);
+    }
+}
@@ -0,0 +1,262 @@
+//! 后台元数据刷新
+//!
+//! 连接后按固定间隔（以及执行 DDL 之后）在后台重新读取表列表，
+//! 并分批补全表的列信息，供侧边栏和自动补全使用。
+//! 本模块只负责调度和比较，读取由应用层在异步任务中完成。
+
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+
+use super::audit::strip_leading_comments;
+
+/// 每次刷新最多读取列信息的表数量
+pub const METADATA_COLUMN_BATCH: usize = 50;
+
+// ============================================================================
+// 表目录差异
+// ============================================================================
+
+/// 新旧表列表的差异
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CatalogDiff {
+    /// 新增的表
+    pub added: Vec<String>,
+    /// 已删除的表
+    pub removed: Vec<String>,
+}
+
+impl CatalogDiff {
+    /// 比较两个表列表（保持各自的顺序）
+    pub fn between(old: &[String], new: &[String]) -> Self {
+        let old_set: HashSet<&str> = old.iter().map(String::as_str).collect();
+        let new_set: HashSet<&str> = new.iter().map(String::as_str).collect();
+        Self {
+            added: new.iter().filter(|t| !old_set.contains(t.as_str())).cloned().collect(),
+            removed: old.iter().filter(|t| !new_set.contains(t.as_str())).cloned().collect(),
+        }
+    }
+
+    /// 是否没有变化
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// 表列表变化后，原来选中的表在新列表中的位置（已删除时保持原索引并限制在范围内）
+pub fn remap_selection(old: &[String], new: &[String], index: usize) -> usize {
+    old.get(index)
+        .and_then(|name| new.iter().position(|t| t == name))
+        .unwrap_or_else(|| index.min(new.len().saturating_sub(1)))
+}
+
+// ============================================================================
+// DDL 识别
+// ============================================================================
+
+/// 修改数据库结构的语句开头的关键字
+const DDL_VERBS: [&str; 4] = ["create", "alter", "drop", "rename"];
+
+/// 语句是否为修改数据库结构的 DDL（CREATE、ALTER、DROP、RENAME，跳过开头的注释）
+pub fn is_ddl_statement(sql: &str) -> bool {
+    let verb = strip_leading_comments(sql)
+        .split(|c: char| !c.is_ascii_alphabetic())
+        .next()
+        .unwrap_or("")
+        .to_ascii_lowercase();
+    DDL_VERBS.contains(&verb.as_str())
+}
+
+/// 若语句是修改表或视图结构的 DDL，返回涉及的表名（可能为空）；否则返回 `None`
+///
+/// 识别 `CREATE/ALTER/DROP TABLE|VIEW`、`RENAME TABLE` 以及 `ALTER TABLE ... RENAME TO`，
+/// 表名去掉引号和 schema 前缀。
+pub fn ddl_tables(sql: &str) -> Option<Vec<String>> {
+    let text = strip_leading_comments(sql);
+    let mut tokens = tokenize(text).into_iter();
+    let verb = tokens.next()?.to_lowercase();
+    if !DDL_VERBS.contains(&verb.as_str()) {
+        return None;
+    }
+
+    // 跳过对象类型前的修饰词（OR REPLACE、TEMPORARY、MATERIALIZED 等）
+    let object = loop {
+        let word = tokens.next()?.to_lowercase();
+        match word.as_str() {
+            "or" | "replace" | "temporary" | "temp" | "unlogged" | "global" | "local" | "materialized"
+            | "external" | "virtual" | "recursive" => continue,
+            _ => break word,
+        }
+    };
+    if !matches!(object.as_str(), "table" | "tables" | "view") {
+        return None;
+    }
+
+    let rest: Vec<String> = tokens
+        .skip_while(|t| matches!(t.to_lowercase().as_str(), "if" | "not" | "exists" | "only"))
+        .collect();
+    let mut tables = Vec::new();
+    if verb == "drop" || verb == "rename" {
+        // DROP TABLE a, b CASCADE / RENAME TABLE a TO b, c TO d
+        let mut expect_name = true;
+        for token in &rest {
+            match token.to_lowercase().as_str() {
+                "," | "to" => expect_name = true,
+                _ if expect_name => {
+                    tables.push(unquote_name(token));
+                    expect_name = false;
+                }
+                _ => break,
+            }
+        }
+    } else if let Some(name) = rest.first() {
+        tables.push(unquote_name(name));
+        // ALTER TABLE a RENAME [TO|AS] b（RENAME COLUMN 不改变表名）
+        if let Some(pos) = rest.iter().position(|t| t.eq_ignore_ascii_case("rename")) {
+            let mut after = rest[pos + 1..].iter();
+            let next = after.next().map(|t| t.to_lowercase());
+            let new_name = match next.as_deref() {
+                Some("to") | Some("as") => after.next(),
+                Some("column") | Some("constraint") | Some("index") | Some("key") | None => None,
+                Some(_) => rest.get(pos + 1),
+            };
+            if let Some(new_name) = new_name {
+                tables.push(unquote_name(new_name));
+            }
+        }
+    }
+    tables.dedup();
+    Some(tables)
+}
+
+/// 按空白和 `(`、`,`、`;` 切分，逗号单独作为一个词
+fn tokenize(text: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    for c in text.chars() {
+        match c {
+            c if c.is_whitespace() || c == '(' || c == ';' => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+                if c == '(' || c == ';' {
+                    break;
+                }
+            }
+            ',' => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+                tokens.push(",".to_string());
+            }
+            c => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+    tokens
+}
+
+/// 去掉 schema 前缀和引号
+fn unquote_name(name: &str) -> String {
+    let last = name.rsplit('.').next().unwrap_or(name);
+    last.trim_matches(|c| matches!(c, '"' | '`' | '[' | ']')).to_string()
+}
+
+// ============================================================================
+// 刷新调度
+// ============================================================================
+
+/// 后台刷新的调度状态
+#[derive(Debug, Default)]
+pub struct MetadataRefresh {
+    /// 上次刷新（或切换连接/数据库）的时间
+    last: Option<Instant>,
+    /// 正在刷新
+    in_flight: bool,
+    /// 请求尽快刷新（执行 DDL 后）
+    requested: bool,
+    /// 需要重新读取列信息的表
+    stale: HashSet<String>,
+    /// 每次重置时递增，用于丢弃切换前发出的刷新结果
+    generation: u64,
+}
+
+impl MetadataRefresh {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 切换连接或数据库后重新计时，丢弃待刷新的请求和进行中的结果
+    pub fn reset(&mut self, now: Instant) {
+        self.generation += 1;
+        self.last = Some(now);
+        self.in_flight = false;
+        self.requested = false;
+        self.stale.clear();
+    }
+
+    /// 请求尽快刷新，并标记需要重新读取列信息的表
+    pub fn request(&mut self, tables: impl IntoIterator<Item = String>) {
+        self.requested = true;
+        self.stale.extend(tables);
+    }
+
+    /// 是否应该开始刷新；`interval_secs` 为 0 时只响应请求
+    pub fn is_due(&self, interval_secs: u64, now: Instant) -> bool {
+        if self.in_flight {
+            return false;
+        }
+        self.requested
+            || (interval_secs > 0
+                && self
+                    .last
+                    .is_some_and(|last| now.duration_since(last) >= Duration::from_secs(interval_secs)))
+    }
+
+    /// 距离下次定时刷新的时间（用于安排重绘）
+    pub fn time_until_next(&self, interval_secs: u64, now: Instant) -> Option<Duration> {
+        if interval_secs == 0 || self.in_flight {
+            return None;
+        }
+        let last = self.last?;
+        Some(Duration::from_secs(interval_secs).saturating_sub(now.duration_since(last)))
+    }
+
+    /// 开始刷新，返回本次刷新的编号和需要重新读取列信息的表
+    pub fn start(&mut self) -> (u64, Vec<String>) {
+        self.in_flight = true;
+        self.requested = false;
+        let mut stale: Vec<String> = self.stale.drain().collect();
+        stale.sort();
+        (self.generation, stale)
+    }
+
+    /// 刷新完成；结果在重置之前发出时返回 `false`，应丢弃
+    pub fn finish(&mut self, generation: u64, now: Instant) -> bool {
+        if generation != self.generation {
+            return false;
+        }
+        self.in_flight = false;
+        self.last = Some(now);
+        true
+    }
+}
+
+/// 选择本次需要读取列信息的表：先是被 DDL 修改过的，再是还没有缓存的，最多 `limit` 个
+pub fn pick_column_tables(
+    tables: &[String],
+    stale: &[String],
+    has_columns: impl Fn(&str) -> bool,
+    limit: usize,
+) -> Vec<String> {
+    let mut picked: Vec<String> = stale.iter().filter(|t| tables.contains(t)).cloned().collect();
+    picked.extend(
+        tables
+            .iter()
+            .filter(|t| !has_columns(t) && !stale.contains(t))
+            .cloned(),
+    );
+    picked.truncate(limit);
+    picked
+}
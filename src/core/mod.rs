@@ -1,20 +1,89 @@
 //! 核心模块 - 包含配置、主题、语法高亮、历史记录、导出等核心功能
 
+mod api;
+mod audit;
 mod autocomplete;
+mod benchmark;
+mod blob;
+mod cli;
+mod column_layout;
 mod config;
+mod data_search;
 pub mod constants;
+mod datagen;
+mod dependency;
+mod desktop_notify;
+mod editing;
 mod export;
+mod file_drop;
+mod filter_preset;
+mod fonts;
 mod formatter;
+mod fuzzy;
+mod geometry;
+mod health;
 mod history;
+mod i18n;
+mod json_path;
+mod key_macro;
 mod keybindings;
+mod logging;
+mod masking;
+mod metadata;
 mod notification;
+mod pivot;
+mod plugin;
+mod print_report;
 mod progress;
+mod query_queue;
+mod recent;
+mod recovery;
+mod result_diff;
+mod result_spill;
+mod row_bookmark;
+mod scheduler;
 mod session;
+mod sql_error;
+mod sql_file;
 mod syntax;
+mod text_diff;
 mod theme;
+mod tour;
+mod trash;
+mod upsert;
+mod value_format;
+mod vault;
+mod workflow;
+mod write_preview;
 
+#[allow(unused_imports)] // 公开 API
+pub use api::{
+    generate_api_token, http_error, http_response, percent_decode, ApiFormat, ApiRequest, ApiRoute, ApiServerConfig, SavedQuery,
+    DEFAULT_API_PORT, MAX_REQUEST_HEAD,
+};
+#[allow(unused_imports)] // 公开 API
+pub use audit::{
+    audit_report, is_read_only_statement, is_write_statement, strip_leading_comments, AuditEntry, AuditLog, AuditRecorder, AuditSource,
+};
 pub use autocomplete::{AutoComplete, CompletionKind};
-pub use config::AppConfig;
+#[allow(unused_imports)] // 公开 API
+pub use benchmark::{BenchmarkSample, BenchmarkStats};
+#[allow(unused_imports)] // 公开 API
+pub use blob::{blob_label, blob_len, blob_literal, decode_blob, encode_blob, hex_dump, is_blob};
+#[allow(unused_imports)] // 公开 API
+pub use cli::{parse_cli_args, split_config_args, CliArgs, CliCommand, CLI_USAGE};
+#[allow(unused_imports)] // 公开 API
+pub use column_layout::{ColumnOrders, ColumnWidths};
+#[allow(unused_imports)] // 公开 API
+pub use config::{AppConfig, ConfigLocation, ConfigReload, Preferences, CONFIG_CHECK_INTERVAL_SECS};
+#[allow(unused_imports)] // 公开 API
+pub use datagen::{generate_rows, parse_pattern, CompiledGenerator, GenValue, Rng, ValueGenerator};
+#[allow(unused_imports)] // 公开 API
+pub use dependency::{sql_identifiers, DependencyGraph, DependencyLink, DependencyObjectKind};
+#[allow(unused_imports)] // 公开 API
+pub use desktop_notify::{query_notification_text, send_desktop_notification};
+#[allow(unused_imports)] // 公开 API
+pub use editing::{find_matching_bracket, toggle_line_comment, MultiCursor};
 #[allow(unused_imports)] // 公开 API，供外部使用
 pub use export::{
     // 导出格式
@@ -29,18 +98,108 @@ pub use export::{
     CsvImportConfig, JsonImportConfig,
     // 导出功能
     export_to_csv, export_to_json, export_to_sql,
+    write_csv, write_json, write_sql,
     // 辅助函数（测试用）
     parse_csv_line, sql_value_from_string, json_value_to_sql,
 };
+pub use file_drop::DroppedFileKind;
+#[allow(unused_imports)] // 公开 API
+pub use fonts::{
+    apply_fonts, apply_text_styles, build_font_definitions, editor_text_style, grid_text_style, scan_font_dirs,
+    system_fonts, FontChoice, FontSettings, SystemFont, EDITOR_FONT, GRID_FONT,
+};
+#[allow(unused_imports)] // 公开 API
 pub use formatter::format_sql;
-pub use history::QueryHistory;
-pub use notification::{Notification, NotificationLevel, NotificationManager};
+pub use formatter::format_sql_for;
+#[allow(unused_imports)] // 公开 API
+pub use data_search::{data_search_sql, is_text_type, searchable_columns};
+#[allow(unused_imports)] // 公开 API
+pub use filter_preset::{FilterPreset, FilterPresets, SavedFilter};
+pub use fuzzy::{fuzzy_rank, fuzzy_score};
+#[allow(unused_imports)] // 公开 API
+pub use geometry::{parse_geometry, Bounds, Coord, Geometry, SpatialValue};
+#[allow(unused_imports)] // 公开 API
+pub use health::{HealthStatus, KeepAlive, KEEP_ALIVE_DOWN_AFTER};
+#[allow(unused_imports)] // 公开 API
+pub use history::{HistoryFilter, QueryHistory, QueryHistoryItem};
+#[allow(unused_imports)] // 公开 API
+pub use i18n::{catalog_keys, language, set_language, t, tf, translate, Language};
+#[allow(unused_imports)] // 公开 API
+pub use json_path::{
+    detect_json_columns, json_scalar_text, looks_like_json, pretty_json, JsonCompare, JsonPath, JsonPathCondition,
+    JsonPathSegment,
+};
+pub use key_macro::KeyMacro;
+#[allow(unused_imports)] // 公开 API
+pub use logging::{
+    default_log_path, rotated_log_path, LogEntry, LogLevel, LogTail, RotatingFileWriter, LOG_FILE_NAME,
+    MAX_LOG_BACKUPS, MAX_LOG_FILE_SIZE,
+};
+#[allow(unused_imports)] // 公开 API
+pub use masking::{mask_value, MaskingRules};
+#[allow(unused_imports)] // 公开 API
+pub use metadata::{
+    ddl_tables, is_ddl_statement, pick_column_tables, remap_selection, CatalogDiff, MetadataRefresh,
+    METADATA_COLUMN_BATCH,
+};
+#[allow(unused_imports)] // 公开 API
+pub use notification::{
+    Notification, NotificationLevel, NotificationManager, NotificationRecord, NOTIFICATION_HISTORY_LIMIT,
+};
+#[allow(unused_imports)] // 公开 API
+pub use pivot::{PivotAggregate, PivotCell, PivotConfig, PivotGroup, PivotTable, MAX_PIVOT_COLUMNS};
+#[allow(unused_imports)] // 公开 API
+pub use plugin::{
+    CellRenderer, ExporterPlugin, ImagePathRenderer, MarkdownExporter, PanelContext, PluginRegistry, SidebarPanel,
+};
+#[allow(unused_imports)] // 公开 API
+pub use print_report::{PrintReport, DEFAULT_ROWS_PER_PAGE};
 #[allow(unused_imports)] // 公开 API，供外部使用
 pub use progress::{ProgressManager, ProgressTask};
 #[allow(unused_imports)] // 公开 API
+pub use query_queue::QueryQueue;
+#[allow(unused_imports)] // 公开 API
+pub use recent::{RecentEntry, RecentObjects, RecentTarget};
+#[allow(unused_imports)] // 公开 API
+pub use recovery::{RecoveredEdits, RecoveryState, RECOVERY_AUTOSAVE_SECS};
+#[allow(unused_imports)] // 公开 API
+pub use result_diff::{diff_results, DiffKind, DiffRow, ResultDiff};
+#[allow(unused_imports)] // 公开 API
+pub use result_spill::{SpillStore, SpilledResult, SPILL_PAGE_ROWS};
+#[allow(unused_imports)] // 公开 API
+pub use row_bookmark::{RowBookmark, RowBookmarks};
+#[allow(unused_imports)] // 公开 API
+pub use scheduler::{
+    QueryScheduler, Schedule, ScheduledQuery, Threshold, ThresholdOp, ThresholdTarget, MIN_INTERVAL_SECS,
+};
+#[allow(unused_imports)] // 公开 API
+pub use sql_error::{analyze_sql_error, suggest_for_unknown, suggest_name, SqlErrorInfo, UnknownObject};
+#[allow(unused_imports)] // 公开 API
+pub use sql_file::{ExternalChange, SqlFile, FILE_CHECK_INTERVAL_SECS};
+#[allow(unused_imports)] // 公开 API
+pub use upsert::{insert_row_sql, Upsert, UpsertSyntax};
+#[allow(unused_imports)] // 公开 API
 pub use syntax::{clear_highlight_cache, highlight_sql, HighlightColors, SqlHighlighter};
-pub use theme::{ThemeManager, ThemePreset};
+#[allow(unused_imports)] // 公开 API
+pub use text_diff::{side_by_side_diff, LineChange, SideBySideLine};
+#[allow(unused_imports)] // 公开 API
+pub use theme::{CustomTheme, SyntaxColors, ThemeColors, ThemeManager, ThemePreset};
+#[allow(unused_imports)] // 公开 API
+pub use tour::{Tour, TourStep, TourTarget, TOUR_STEPS};
+#[allow(unused_imports)] // 公开 API
+pub use trash::{Trash, TrashEntry, TrashItem};
+#[allow(unused_imports)] // 公开 API
+pub use vault::{MasterKey, Vault, VaultContent, MASTER_PASSWORD_ENV};
 #[allow(unused_imports)] // 公开 API，供未来使用
 pub use keybindings::{Action, KeyBinding, KeyBindings, KeyCode, KeyModifiers};
 #[allow(unused_imports)] // 公开 API，供未来使用
 pub use session::{SessionManager, SessionState, TabState, WindowState};
+#[allow(unused_imports)] // 公开 API
+pub use value_format::{
+    detect_timestamp_columns, format_offset, is_number, parse_timestamp, ColumnFormats, NumberFormat, NumberSeparators,
+    TimestampDisplay, ValueFormatter,
+};
+#[allow(unused_imports)] // 公开 API
+pub use workflow::{expand_export_path, StepStatus, Workflow, WorkflowRun, WorkflowStep};
+#[allow(unused_imports)] // 公开 API
+pub use write_preview::{write_preview, WriteKind, WritePreview};
@@ -1,12 +1,17 @@
 //! 消息通知系统
 //!
 //! 提供统一的通知管理，支持多种级别的消息和自动过期。
+//! 过期的通知保留在历史记录中，供通知中心查看。
 
 #![allow(dead_code)] // 公开 API，供外部使用
 
+use chrono::{DateTime, Local};
 use std::collections::VecDeque;
 use std::time::{Duration, Instant};
 
+/// 通知历史最多保留的条数
+pub const NOTIFICATION_HISTORY_LIMIT: usize = 200;
+
 /// 通知级别
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum NotificationLevel {
@@ -21,6 +26,24 @@ pub enum NotificationLevel {
 }
 
 impl NotificationLevel {
+    /// 所有级别
+    pub const ALL: [NotificationLevel; 4] = [
+        NotificationLevel::Info,
+        NotificationLevel::Success,
+        NotificationLevel::Warning,
+        NotificationLevel::Error,
+    ];
+
+    /// 显示名称
+    pub fn label(&self) -> &'static str {
+        match self {
+            NotificationLevel::Info => "信息",
+            NotificationLevel::Success => "成功",
+            NotificationLevel::Warning => "警告",
+            NotificationLevel::Error => "错误",
+        }
+    }
+
     /// 获取默认显示时长
     pub fn default_duration(&self) -> Duration {
         match self {
@@ -109,6 +132,17 @@ impl Notification {
     }
 }
 
+/// 通知历史记录
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotificationRecord {
+    /// 对应通知的 ID
+    pub id: u64,
+    pub level: NotificationLevel,
+    pub message: String,
+    /// 发出时间
+    pub timestamp: DateTime<Local>,
+}
+
 /// 通知管理器
 ///
 /// 管理所有通知的生命周期，支持：
@@ -116,6 +150,7 @@ impl Notification {
 /// - 手动关闭通知
 /// - 自动清理过期通知
 /// - 限制最大通知数量
+/// - 保留历史记录并统计未查看的错误
 #[derive(Debug)]
 pub struct NotificationManager {
     /// 通知队列（新通知在前）
@@ -124,6 +159,10 @@ pub struct NotificationManager {
     next_id: u64,
     /// 最大同时显示的通知数量
     max_notifications: usize,
+    /// 历史记录（新记录在前）
+    history: VecDeque<NotificationRecord>,
+    /// 上次查看通知中心后新增的错误数
+    unseen_errors: usize,
 }
 
 impl Default for NotificationManager {
@@ -139,6 +178,8 @@ impl NotificationManager {
             notifications: VecDeque::new(),
             next_id: 1,
             max_notifications: 5,
+            history: VecDeque::new(),
+            unseen_errors: 0,
         }
     }
 
@@ -174,13 +215,7 @@ impl NotificationManager {
         self.next_id += 1;
 
         let notification = Notification::new(id, level, message);
-        self.notifications.push_front(notification);
-
-        // 限制最大数量
-        while self.notifications.len() > self.max_notifications {
-            self.notifications.pop_back();
-        }
-
+        self.insert(notification);
         id
     }
 
@@ -195,14 +230,29 @@ impl NotificationManager {
         self.next_id += 1;
 
         let notification = Notification::with_duration(id, level, message, duration);
+        self.insert(notification);
+        id
+    }
+
+    /// 显示通知并写入历史记录
+    fn insert(&mut self, notification: Notification) {
+        self.history.push_front(NotificationRecord {
+            id: notification.id,
+            level: notification.level,
+            message: notification.message.clone(),
+            timestamp: Local::now(),
+        });
+        self.history.truncate(NOTIFICATION_HISTORY_LIMIT);
+        if notification.level == NotificationLevel::Error {
+            self.unseen_errors += 1;
+        }
+
         self.notifications.push_front(notification);
 
         // 限制最大数量
         while self.notifications.len() > self.max_notifications {
             self.notifications.pop_back();
         }
-
-        id
     }
 
     /// 手动关闭指定通知
@@ -246,4 +296,30 @@ impl NotificationManager {
     pub fn latest_message(&self) -> Option<&str> {
         self.notifications.front().map(|n| n.message.as_str())
     }
+
+    /// 历史记录（新记录在前）
+    pub fn history(&self) -> impl Iterator<Item = &NotificationRecord> {
+        self.history.iter()
+    }
+
+    /// 历史记录条数
+    pub fn history_len(&self) -> usize {
+        self.history.len()
+    }
+
+    /// 清空历史记录
+    pub fn clear_history(&mut self) {
+        self.history.clear();
+        self.unseen_errors = 0;
+    }
+
+    /// 上次查看通知中心后新增的错误数
+    pub fn unseen_errors(&self) -> usize {
+        self.unseen_errors
+    }
+
+    /// 标记所有通知为已查看
+    pub fn mark_seen(&mut self) {
+        self.unseen_errors = 0;
+    }
 }
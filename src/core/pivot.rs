@@ -0,0 +1,271 @@
+//! 透视表
+//!
+//! 在客户端对查询结果做交叉汇总：按一列或多列分组作为行（多级分组显示小计），
+//! 按一列的不同值展开为列，单元格为计数、求和或平均值。分组按值排序，
+//! 都是数字时按数值排序。求和和平均值忽略 NULL 和非数字的值。
+
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+
+use crate::database::QueryResult;
+
+/// 列分组最多展开的不同值个数
+pub const MAX_PIVOT_COLUMNS: usize = 200;
+
+/// 汇总方式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PivotAggregate {
+    #[default]
+    Count,
+    Sum,
+    Avg,
+}
+
+impl PivotAggregate {
+    /// 所有汇总方式
+    pub const ALL: [Self; 3] = [Self::Count, Self::Sum, Self::Avg];
+
+    /// 显示名称
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            PivotAggregate::Count => "计数",
+            PivotAggregate::Sum => "求和",
+            PivotAggregate::Avg => "平均值",
+        }
+    }
+
+    /// 是否需要选择汇总的值列
+    pub fn needs_value(&self) -> bool {
+        *self != PivotAggregate::Count
+    }
+}
+
+/// 透视设置（列索引）
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PivotConfig {
+    /// 行分组列（按层级顺序）
+    pub row_columns: Vec<usize>,
+    /// 列分组列（为空时只有合计列）
+    pub column_column: Option<usize>,
+    /// 求和和平均值使用的列
+    pub value_column: Option<usize>,
+    pub aggregate: PivotAggregate,
+}
+
+/// 一个单元格的累计值
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PivotCell {
+    /// 行数
+    pub count: usize,
+    sum: f64,
+    /// 参与求和的数字个数
+    numeric: usize,
+}
+
+impl PivotCell {
+    fn add(&mut self, value: Option<f64>) {
+        self.count += 1;
+        if let Some(v) = value {
+            self.sum += v;
+            self.numeric += 1;
+        }
+    }
+
+    /// 按汇总方式计算的值，没有可汇总的数字时为 `None`
+    pub fn value(&self, aggregate: PivotAggregate) -> Option<f64> {
+        match aggregate {
+            PivotAggregate::Count => Some(self.count as f64),
+            PivotAggregate::Sum => (self.numeric > 0).then_some(self.sum),
+            PivotAggregate::Avg => (self.numeric > 0).then(|| self.sum / self.numeric as f64),
+        }
+    }
+}
+
+/// 一个行分组
+#[derive(Debug, Clone, PartialEq)]
+pub struct PivotGroup {
+    /// 分组的值
+    pub key: String,
+    /// 每个列分组的值
+    pub cells: Vec<PivotCell>,
+    /// 行合计
+    pub total: PivotCell,
+    /// 下一级分组（最后一级为空）
+    pub children: Vec<PivotGroup>,
+}
+
+/// 计算中的分组
+#[derive(Default)]
+struct GroupNode {
+    cells: Vec<PivotCell>,
+    total: PivotCell,
+    children: HashMap<String, GroupNode>,
+}
+
+impl GroupNode {
+    fn add(&mut self, column: usize, width: usize, value: Option<f64>) {
+        if self.cells.is_empty() {
+            self.cells = vec![PivotCell::default(); width];
+        }
+        self.cells[column].add(value);
+        self.total.add(value);
+    }
+
+    fn into_groups(children: HashMap<String, GroupNode>) -> Vec<PivotGroup> {
+        let mut groups: Vec<PivotGroup> = children
+            .into_iter()
+            .map(|(key, node)| PivotGroup {
+                key,
+                cells: node.cells,
+                total: node.total,
+                children: Self::into_groups(node.children),
+            })
+            .collect();
+        groups.sort_by(|a, b| compare_keys(&a.key, &b.key));
+        groups
+    }
+}
+
+/// 透视结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct PivotTable {
+    /// 行分组列名
+    pub row_headers: Vec<String>,
+    /// 列分组的值（没有列分组时为空）
+    pub column_keys: Vec<String>,
+    pub aggregate: PivotAggregate,
+    /// 第一级分组
+    pub groups: Vec<PivotGroup>,
+    /// 每个列分组的合计
+    pub totals: Vec<PivotCell>,
+    /// 总计
+    pub grand_total: PivotCell,
+}
+
+impl PivotTable {
+    /// 计算透视表
+    pub fn compute(result: &QueryResult, config: &PivotConfig) -> Result<Self, String> {
+        let column_count = result.columns.len();
+        let in_range = |idx: &usize| *idx < column_count;
+        if config.row_columns.is_empty() {
+            return Err("请至少选择一个行分组列".to_string());
+        }
+        if !config.row_columns.iter().all(in_range) || !config.column_column.iter().all(in_range) {
+            return Err("分组列不在结果中".to_string());
+        }
+        let value_column = if config.aggregate.needs_value() {
+            match config.value_column.filter(in_range) {
+                Some(idx) => Some(idx),
+                None => return Err(format!("{}需要选择值列", config.aggregate.display_name())),
+            }
+        } else {
+            None
+        };
+
+        // 列分组的不同值
+        let mut column_keys: Vec<String> = Vec::new();
+        if let Some(col) = config.column_column {
+            let mut seen = HashSet::new();
+            for row in &result.rows {
+                let key = cell(row, col);
+                if seen.insert(key) {
+                    if seen.len() > MAX_PIVOT_COLUMNS {
+                        return Err(format!(
+                            "{} 的不同值超过 {} 个，请选择其他列分组列",
+                            result.columns[col], MAX_PIVOT_COLUMNS
+                        ));
+                    }
+                    column_keys.push(key.to_string());
+                }
+            }
+            column_keys.sort_by(|a, b| compare_keys(a, b));
+        }
+        let width = column_keys.len().max(1);
+        let key_index: HashMap<&str, usize> = column_keys.iter().enumerate().map(|(i, k)| (k.as_str(), i)).collect();
+
+        let mut root = GroupNode::default();
+        let mut totals = vec![PivotCell::default(); width];
+        let mut grand_total = PivotCell::default();
+        for row in &result.rows {
+            let column = config.column_column.map_or(0, |col| key_index[cell(row, col)]);
+            let value = value_column.and_then(|col| cell(row, col).trim().parse::<f64>().ok()).filter(|v| v.is_finite());
+            totals[column].add(value);
+            grand_total.add(value);
+            let mut node = &mut root;
+            for &col in &config.row_columns {
+                node = node.children.entry(cell(row, col).to_string()).or_default();
+                node.add(column, width, value);
+            }
+        }
+
+        Ok(Self {
+            row_headers: config.row_columns.iter().map(|&i| result.columns[i].clone()).collect(),
+            column_keys,
+            aggregate: config.aggregate,
+            groups: GroupNode::into_groups(root.children),
+            totals,
+            grand_total,
+        })
+    }
+
+    /// 单元格的显示文本
+    pub fn format(&self, cell: &PivotCell) -> String {
+        match cell.value(self.aggregate) {
+            None => String::new(),
+            Some(v) if self.aggregate == PivotAggregate::Count => format!("{}", v as u64),
+            Some(v) => {
+                let text = format!("{:.4}", v);
+                text.trim_end_matches('0').trim_end_matches('.').to_string()
+            }
+        }
+    }
+
+    /// 转换为结果集（用于导出）：每个分组一行，上级分组为小计行，最后一行为总计
+    pub fn to_query_result(&self) -> QueryResult {
+        let mut columns = self.row_headers.clone();
+        columns.extend(self.column_keys.iter().cloned());
+        columns.push("合计".to_string());
+
+        let mut rows = Vec::new();
+        let mut path = Vec::new();
+        self.push_rows(&self.groups, &mut path, &mut rows);
+
+        let mut total_row = vec![String::new(); self.row_headers.len()];
+        total_row[0] = "总计".to_string();
+        if !self.column_keys.is_empty() {
+            total_row.extend(self.totals.iter().map(|c| self.format(c)));
+        }
+        total_row.push(self.format(&self.grand_total));
+        rows.push(total_row);
+
+        QueryResult { columns, rows, ..Default::default() }
+    }
+
+    fn push_rows<'a>(&self, groups: &'a [PivotGroup], path: &mut Vec<&'a str>, rows: &mut Vec<Vec<String>>) {
+        for group in groups {
+            path.push(&group.key);
+            let mut row: Vec<String> = path.iter().map(|k| k.to_string()).collect();
+            row.resize(self.row_headers.len(), String::new());
+            if !self.column_keys.is_empty() {
+                row.extend(group.cells.iter().map(|c| self.format(c)));
+            }
+            row.push(self.format(&group.total));
+            rows.push(row);
+            self.push_rows(&group.children, path, rows);
+            path.pop();
+        }
+    }
+}
+
+/// 行中的单元格（缺少的列按空值处理）
+fn cell(row: &[String], col: usize) -> &str {
+    row.get(col).map_or("", String::as_str)
+}
+
+/// 分组值排序：都是数字时按数值比较，否则按文本比较
+fn compare_keys(a: &str, b: &str) -> Ordering {
+    match (a.trim().parse::<f64>(), b.trim().parse::<f64>()) {
+        (Ok(x), Ok(y)) => x.partial_cmp(&y).unwrap_or(Ordering::Equal),
+        _ => a.cmp(b),
+    }
+}
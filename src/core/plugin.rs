@@ -0,0 +1,240 @@
+//! 插件注册表
+//!
+//! 第三方通过编译期注册扩展应用，无需修改核心模块：
+//! - 导出插件（[`ExporterPlugin`]）：新增导出格式，出现在导出对话框和命令行 `--format` 中
+//! - 单元格渲染器（[`CellRenderer`]）：为匹配的单元格提供悬停预览，例如图片路径预览
+//!   （[`ImagePathRenderer`] 会读取本地文件，只在首选项中设置了预览目录时注册）
+//! - 侧边栏面板（[`SidebarPanel`]）：在侧边栏末尾追加可折叠面板
+//!
+//! 自定义二进制在启动时构造 [`PluginRegistry`]，注册插件后传给 `DbManagerApp::with_plugins`。
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::database::QueryResult;
+
+/// 导出插件
+pub trait ExporterPlugin: Send + Sync {
+    /// 唯一标识，用于命令行 `--format` 和配置
+    fn id(&self) -> &str;
+    /// 显示名称
+    fn name(&self) -> &str;
+    /// 默认文件扩展名（不含点）
+    fn extension(&self) -> &str;
+    /// 将查询结果写到输出
+    fn export(&self, result: &QueryResult, out: &mut dyn Write) -> Result<(), String>;
+}
+
+/// 单元格渲染器
+///
+/// `matches` 在每个可见单元格上调用，应只做廉价的字符串判断。
+pub trait CellRenderer: Send + Sync {
+    /// 唯一标识，注册时按 id 去重
+    fn id(&self) -> &str;
+    /// 是否处理该列的值
+    fn matches(&self, column: &str, value: &str) -> bool;
+    /// 在悬停提示中渲染预览
+    fn show(&self, ui: &mut egui::Ui, column: &str, value: &str);
+}
+
+/// 侧边栏面板可见的上下文
+#[allow(dead_code)] // 公开 API，供第三方面板使用
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PanelContext<'a> {
+    /// 当前活动连接名称
+    pub connection: Option<&'a str>,
+    /// 当前选中的数据库
+    pub database: Option<&'a str>,
+    /// 当前选中的表
+    pub table: Option<&'a str>,
+}
+
+/// 侧边栏面板
+pub trait SidebarPanel: Send + Sync {
+    /// 唯一标识
+    fn id(&self) -> &str;
+    /// 面板标题
+    fn title(&self) -> &str;
+    /// 渲染面板内容
+    fn show(&self, ui: &mut egui::Ui, ctx: &PanelContext<'_>);
+}
+
+/// 插件注册表
+#[derive(Clone)]
+pub struct PluginRegistry {
+    exporters: Vec<Arc<dyn ExporterPlugin>>,
+    renderers: Vec<Arc<dyn CellRenderer>>,
+    panels: Vec<Arc<dyn SidebarPanel>>,
+}
+
+impl PluginRegistry {
+    /// 空注册表
+    pub fn empty() -> Self {
+        Self {
+            exporters: Vec::new(),
+            renderers: Vec::new(),
+            panels: Vec::new(),
+        }
+    }
+
+    /// 包含内置插件的注册表
+    pub fn new() -> Self {
+        let mut registry = Self::empty();
+        registry.register_exporter(MarkdownExporter);
+        registry
+    }
+
+    /// 注册导出插件，同 id 的插件会被替换
+    pub fn register_exporter(&mut self, exporter: impl ExporterPlugin + 'static) {
+        self.exporters.retain(|e| e.id() != exporter.id());
+        self.exporters.push(Arc::new(exporter));
+    }
+
+    /// 注册单元格渲染器，先注册的优先匹配
+    pub fn register_renderer(&mut self, renderer: impl CellRenderer + 'static) {
+        self.renderers.retain(|r| r.id() != renderer.id());
+        self.renderers.push(Arc::new(renderer));
+    }
+
+    /// 注册侧边栏面板
+    #[allow(dead_code)] // 公开 API，供第三方插件使用
+    pub fn register_panel(&mut self, panel: impl SidebarPanel + 'static) {
+        self.panels.retain(|p| p.id() != panel.id());
+        self.panels.push(Arc::new(panel));
+    }
+
+    /// 设置图片路径预览目录：`None` 时移除预览，否则只预览该目录中的图片
+    pub fn set_image_preview_dir(&mut self, dir: Option<&Path>) {
+        self.renderers.retain(|r| r.id() != ImagePathRenderer::ID);
+        if let Some(dir) = dir {
+            self.register_renderer(ImagePathRenderer::new(dir));
+        }
+    }
+
+    /// 所有导出插件
+    pub fn exporters(&self) -> &[Arc<dyn ExporterPlugin>] {
+        &self.exporters
+    }
+
+    /// 按 id 查找导出插件（不区分大小写）
+    pub fn exporter(&self, id: &str) -> Option<&dyn ExporterPlugin> {
+        self.exporters
+            .iter()
+            .find(|e| e.id().eq_ignore_ascii_case(id))
+            .map(|e| e.as_ref())
+    }
+
+    /// 第一个匹配该单元格的渲染器
+    pub fn renderer_for(&self, column: &str, value: &str) -> Option<&dyn CellRenderer> {
+        self.renderers
+            .iter()
+            .find(|r| r.matches(column, value))
+            .map(|r| r.as_ref())
+    }
+
+    /// 所有侧边栏面板
+    pub fn panels(&self) -> &[Arc<dyn SidebarPanel>] {
+        &self.panels
+    }
+}
+
+impl Default for PluginRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ============================================================================
+// 内置插件
+// ============================================================================
+
+/// Markdown 表格导出
+pub struct MarkdownExporter;
+
+impl ExporterPlugin for MarkdownExporter {
+    fn id(&self) -> &str {
+        "markdown"
+    }
+
+    fn name(&self) -> &str {
+        "Markdown"
+    }
+
+    fn extension(&self) -> &str {
+        "md"
+    }
+
+    fn export(&self, result: &QueryResult, out: &mut dyn Write) -> Result<(), String> {
+        let escape = |s: &str| s.replace('|', "\\|").replace('\n', " ");
+        let header: Vec<String> = result.columns.iter().map(|c| escape(c)).collect();
+        let mut text = format!("| {} |\n", header.join(" | "));
+        text.push_str(&format!("|{}\n", " --- |".repeat(result.columns.len())));
+        for row in &result.rows {
+            let cells: Vec<String> = row.iter().map(|c| escape(c)).collect();
+            text.push_str(&format!("| {} |\n", cells.join(" | ")));
+        }
+        out.write_all(text.as_bytes()).map_err(|e| e.to_string())
+    }
+}
+
+/// 本地 PNG 图片路径预览
+///
+/// 单元格的值来自数据库，不能信任：只读取指定目录（含子目录）中的图片，相对路径相对于该目录，
+/// 通过 `..` 或符号链接指向目录外的路径不预览。默认不注册，见 [`PluginRegistry::set_image_preview_dir`]
+pub struct ImagePathRenderer {
+    root: PathBuf,
+}
+
+impl ImagePathRenderer {
+    /// 渲染器标识
+    const ID: &'static str = "image_path";
+    /// 预览图片的最大边长
+    const MAX_SIZE: f32 = 240.0;
+
+    /// 只预览 `root` 目录中的图片
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// 单元格值对应的图片文件，文件不存在或不在目录中时返回 `None`
+    pub fn resolve(&self, value: &str) -> Option<PathBuf> {
+        let root = self.root.canonicalize().ok()?;
+        let path = root.join(value.trim()).canonicalize().ok()?;
+        path.starts_with(&root).then_some(path)
+    }
+}
+
+impl CellRenderer for ImagePathRenderer {
+    fn id(&self) -> &str {
+        Self::ID
+    }
+
+    fn matches(&self, _column: &str, value: &str) -> bool {
+        let value = value.trim();
+        !value.contains("://")
+            && std::path::Path::new(value)
+                .extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("png"))
+    }
+
+    fn show(&self, ui: &mut egui::Ui, _column: &str, value: &str) {
+        let Some(path) = self.resolve(value) else {
+            ui.label(format!("图片不在预览目录 {} 中", self.root.display()));
+            return;
+        };
+        let uri = format!("bytes://{}", path.display());
+        // 只在首次悬停时读取文件，之后使用 egui 的缓存
+        if ui.ctx().try_load_bytes(&uri).is_err() {
+            match std::fs::read(&path) {
+                Ok(bytes) => ui.ctx().include_bytes(uri.clone(), bytes),
+                Err(e) => {
+                    ui.label(format!("无法读取图片: {}", e));
+                    return;
+                }
+            }
+        }
+        ui.add(egui::Image::new(uri).max_size(egui::vec2(Self::MAX_SIZE, Self::MAX_SIZE)));
+        ui.label(path.display().to_string());
+    }
+}
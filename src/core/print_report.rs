@@ -0,0 +1,124 @@
+//! 表格打印视图
+//!
+//! 把当前（筛选后的）结果生成为分页的 HTML 报表，包含标题、生成时间、筛选条件和执行的 SQL。
+//! 在浏览器中打开后可以直接打印或打印为 PDF，每页重复列头。
+
+/// 每页默认行数
+pub const DEFAULT_ROWS_PER_PAGE: usize = 40;
+
+/// 打印报表的说明信息
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrintReport {
+    /// 标题（表名或查询名）
+    pub title: String,
+    /// 生成时间（已格式化）
+    pub generated_at: String,
+    /// 执行的 SQL
+    pub sql: Option<String>,
+    /// 启用的筛选条件描述（按顺序以空格连接，连接词包含在描述中）
+    pub filters: Vec<String>,
+    /// 每页行数（0 表示不分页）
+    pub rows_per_page: usize,
+}
+
+impl PrintReport {
+    /// 创建报表，使用默认的每页行数
+    pub fn new(title: impl Into<String>, generated_at: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            generated_at: generated_at.into(),
+            sql: None,
+            filters: Vec::new(),
+            rows_per_page: DEFAULT_ROWS_PER_PAGE,
+        }
+    }
+
+    /// 页数（没有数据时仍有一页，显示空表格）
+    pub fn page_count(&self, row_count: usize) -> usize {
+        if self.rows_per_page == 0 {
+            1
+        } else {
+            row_count.div_ceil(self.rows_per_page).max(1)
+        }
+    }
+
+    /// 生成 HTML 报表
+    pub fn to_html(&self, columns: &[String], rows: &[&[String]]) -> String {
+        let mut html = String::new();
+        html.push_str("<!DOCTYPE html>\n<html lang=\"zh\">\n<head>\n<meta charset=\"utf-8\">\n");
+        html.push_str(&format!("<title>{}</title>\n", html_escape(&self.title)));
+        html.push_str(REPORT_STYLE);
+        html.push_str("</head>\n<body>\n<header class=\"report-header\">\n");
+        html.push_str(&format!("<h1>{}</h1>\n", html_escape(&self.title)));
+        html.push_str(&format!(
+            "<p class=\"meta\">生成时间 {} · {} 行</p>\n",
+            html_escape(&self.generated_at),
+            rows.len()
+        ));
+        if !self.filters.is_empty() {
+            let filters: Vec<String> = self.filters.iter().map(|f| html_escape(f)).collect();
+            html.push_str(&format!("<p class=\"meta\">筛选条件：{}</p>\n", filters.join(" ")));
+        }
+        if let Some(sql) = self.sql.as_deref().filter(|s| !s.trim().is_empty()) {
+            html.push_str(&format!("<pre class=\"sql\">{}</pre>\n", html_escape(sql.trim())));
+        }
+        html.push_str("</header>\n");
+
+        let header: String = columns
+            .iter()
+            .map(|c| format!("<th>{}</th>", html_escape(c)))
+            .collect();
+        let pages = self.page_count(rows.len());
+        let page_size = if self.rows_per_page == 0 { rows.len().max(1) } else { self.rows_per_page };
+        for page in 0..pages {
+            html.push_str("<section class=\"page\">\n<table>\n");
+            html.push_str(&format!("<thead><tr><th class=\"row-number\">#</th>{}</tr></thead>\n<tbody>\n", header));
+            let start = page * page_size;
+            for (offset, row) in rows.iter().skip(start).take(page_size).enumerate() {
+                html.push_str(&format!("<tr><td class=\"row-number\">{}</td>", start + offset + 1));
+                for col in 0..columns.len() {
+                    match row.get(col).map(String::as_str) {
+                        Some("NULL") => html.push_str("<td class=\"null\">NULL</td>"),
+                        Some(cell) => html.push_str(&format!("<td>{}</td>", html_escape(cell))),
+                        None => html.push_str("<td></td>"),
+                    }
+                }
+                html.push_str("</tr>\n");
+            }
+            html.push_str("</tbody>\n</table>\n");
+            html.push_str(&format!("<p class=\"page-number\">第 {} / {} 页</p>\n</section>\n", page + 1, pages));
+        }
+        html.push_str("</body>\n</html>\n");
+        html
+    }
+}
+
+/// 报表样式：打印时每页一节，列头在每页重复
+const REPORT_STYLE: &str = "<style>
+body { font-family: sans-serif; font-size: 12px; color: #222; margin: 24px; }
+h1 { font-size: 18px; margin: 0 0 4px; }
+.meta { color: #666; margin: 2px 0; }
+.sql { background: #f4f4f4; border: 1px solid #ddd; padding: 6px 8px; white-space: pre-wrap; font-size: 11px; }
+table { border-collapse: collapse; width: 100%; margin-top: 12px; }
+th, td { border: 1px solid #ccc; padding: 3px 6px; text-align: left; vertical-align: top; word-break: break-word; }
+th { background: #eee; }
+thead { display: table-header-group; }
+tr { break-inside: avoid; }
+.row-number { color: #888; width: 1%; white-space: nowrap; }
+.null { color: #999; font-style: italic; }
+.page-number { color: #888; text-align: right; margin: 4px 0 0; }
+@media print {
+  body { margin: 0; }
+  .page { break-after: page; }
+  .page:last-child { break-after: auto; }
+}
+</style>
+";
+
+/// 转义 HTML 文本
+fn html_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
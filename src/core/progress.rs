@@ -113,6 +113,13 @@ impl ProgressManager {
         }
     }
 
+    /// 更新任务描述（用于显示不确定进度任务的当前状态）
+    pub fn set_description(&mut self, id: u64, description: impl Into<String>) {
+        if let Some(task) = self.tasks.get_mut(&id) {
+            task.description = description.into();
+        }
+    }
+
     /// 完成任务
     pub fn finish(&mut self, id: u64) {
         self.tasks.remove(&id);
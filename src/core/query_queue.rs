@@ -0,0 +1,132 @@
+//! 查询执行队列
+//!
+//! 每个连接同时执行的查询数不超过上限，超出的查询按提交顺序排队；
+//! 同一 Tab 同时只执行一个查询，在执行或排队期间再次提交的查询依次排在后面（如表格保存的多条语句）。
+
+use std::collections::VecDeque;
+
+/// 排队中的查询
+struct Pending<J> {
+    connection: String,
+    tab_id: String,
+    job: J,
+}
+
+/// 按连接限制并发数的查询队列，`J` 为启动查询所需的数据
+pub struct QueryQueue<J> {
+    /// 正在执行的查询 (连接名, Tab ID)
+    running: Vec<(String, String)>,
+    /// 排队中的查询（按提交顺序）
+    pending: VecDeque<Pending<J>>,
+    /// 每个连接同时执行的查询数上限
+    limit: usize,
+}
+
+impl<J> QueryQueue<J> {
+    /// 创建每个连接最多同时执行 `limit` 个查询的队列
+    pub fn new(limit: usize) -> Self {
+        Self {
+            running: Vec::new(),
+            pending: VecDeque::new(),
+            limit: limit.max(1),
+        }
+    }
+
+    /// 修改并发上限，返回因上限提高可以立即开始的查询
+    pub fn set_limit(&mut self, limit: usize) -> Vec<(String, String, J)> {
+        self.limit = limit.max(1);
+        self.dispatch()
+    }
+
+    /// 提交查询：可以立即执行时返回 `Some(job)`（已记为执行中），否则排队并返回 `None`
+    pub fn submit(&mut self, connection: &str, tab_id: &str, job: J) -> Option<J> {
+        // 同一 Tab 已有排队的查询时排在其后，保证按提交顺序执行
+        if !self.pending.iter().any(|p| p.tab_id == tab_id) && self.can_start(connection, tab_id) {
+            self.running.push((connection.to_string(), tab_id.to_string()));
+            return Some(job);
+        }
+        self.pending.push_back(Pending {
+            connection: connection.to_string(),
+            tab_id: tab_id.to_string(),
+            job,
+        });
+        None
+    }
+
+    /// 标记 Tab 的查询已完成，返回因此可以开始的查询 (连接名, Tab ID, 数据)
+    pub fn finish(&mut self, tab_id: &str) -> Vec<(String, String, J)> {
+        self.running.retain(|(_, id)| id != tab_id);
+        self.dispatch()
+    }
+
+    /// 取消 Tab 所有排队中的查询，返回是否有查询被取消
+    pub fn cancel(&mut self, tab_id: &str) -> bool {
+        let before = self.pending.len();
+        self.pending.retain(|p| p.tab_id != tab_id);
+        self.pending.len() != before
+    }
+
+    /// 只保留 `keep` 返回 true 的 Tab 排队中的查询（用于关闭 Tab 后清理）
+    pub fn retain_tabs(&mut self, keep: impl Fn(&str) -> bool) {
+        self.pending.retain(|p| keep(&p.tab_id));
+    }
+
+    /// 连接上排队中的查询数
+    pub fn queued(&self, connection: &str) -> usize {
+        self.pending.iter().filter(|p| p.connection == connection).count()
+    }
+
+    /// 连接上正在执行的查询数
+    pub fn running(&self, connection: &str) -> usize {
+        self.running.iter().filter(|(c, _)| c == connection).count()
+    }
+
+    /// Tab 在所属连接队列中的位置（从 1 开始），未排队时返回 `None`
+    pub fn position(&self, tab_id: &str) -> Option<usize> {
+        let pending = self.pending.iter().find(|p| p.tab_id == tab_id)?;
+        let ahead = self
+            .pending
+            .iter()
+            .take_while(|p| p.tab_id != tab_id)
+            .filter(|p| p.connection == pending.connection)
+            .count();
+        Some(ahead + 1)
+    }
+
+    /// Tab 正在执行的查询所属的连接
+    pub fn connection_of(&self, tab_id: &str) -> Option<&str> {
+        self.running.iter().find(|(_, id)| id == tab_id).map(|(c, _)| c.as_str())
+    }
+
+    /// Tab 是否有查询正在执行
+    pub fn is_running(&self, tab_id: &str) -> bool {
+        self.running.iter().any(|(_, id)| id == tab_id)
+    }
+
+    /// 没有正在执行或排队中的查询
+    pub fn is_idle(&self) -> bool {
+        self.running.is_empty() && self.pending.is_empty()
+    }
+
+    fn can_start(&self, connection: &str, tab_id: &str) -> bool {
+        !self.is_running(tab_id) && self.running(connection) < self.limit
+    }
+
+    /// 按提交顺序开始所有可以开始的排队查询
+    fn dispatch(&mut self) -> Vec<(String, String, J)> {
+        let mut started = Vec::new();
+        let mut index = 0;
+        while index < self.pending.len() {
+            let p = &self.pending[index];
+            if self.can_start(&p.connection, &p.tab_id) {
+                if let Some(p) = self.pending.remove(index) {
+                    self.running.push((p.connection.clone(), p.tab_id.clone()));
+                    started.push((p.connection, p.tab_id, p.job));
+                }
+            } else {
+                index += 1;
+            }
+        }
+        started
+    }
+}
@@ -0,0 +1,131 @@
+//! 最近使用的表和数据库
+//!
+//! 按连接记录打开过的表和切换过的数据库，快速切换器按使用频率和最近使用时间
+//! 综合排序（最近一次使用越近、使用次数越多越靠前）。
+
+use super::constants;
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+/// 快速切换器的目标（连接、数据库、表）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecentTarget {
+    /// 连接名
+    pub connection: String,
+    /// 数据库名（SQLite 等没有数据库列表的连接为空）
+    pub database: Option<String>,
+    /// 表名（为空时表示切换数据库）
+    pub table: Option<String>,
+}
+
+/// 一条最近使用记录
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecentEntry {
+    /// 连接名
+    pub connection: String,
+    /// 数据库名
+    #[serde(default)]
+    pub database: Option<String>,
+    /// 表名（为空时表示数据库）
+    #[serde(default)]
+    pub table: Option<String>,
+    /// 最近一次使用时间
+    pub last_used: DateTime<Local>,
+    /// 使用次数
+    pub count: u32,
+}
+
+impl RecentEntry {
+    /// 是否为同一个对象
+    fn is(&self, connection: &str, database: Option<&str>, table: Option<&str>) -> bool {
+        self.connection == connection && self.database.as_deref() == database && self.table.as_deref() == table
+    }
+
+    /// 对应的切换目标
+    pub fn target(&self) -> RecentTarget {
+        RecentTarget {
+            connection: self.connection.clone(),
+            database: self.database.clone(),
+            table: self.table.clone(),
+        }
+    }
+
+    /// 显示名称（表显示为 `数据库.表`）
+    pub fn label(&self) -> String {
+        match (&self.database, &self.table) {
+            (Some(db), Some(table)) => format!("{}.{}", db, table),
+            (None, Some(table)) => table.clone(),
+            (Some(db), None) => db.clone(),
+            (None, None) => self.connection.clone(),
+        }
+    }
+
+    /// 排序得分：使用次数乘以按距上次使用时间衰减的权重
+    pub fn score(&self, now: DateTime<Local>) -> f64 {
+        let hours = (now - self.last_used).num_hours();
+        let weight = match hours {
+            ..=1 => 8.0,
+            2..=24 => 4.0,
+            25..=168 => 2.0,
+            169..=720 => 1.0,
+            _ => 0.5,
+        };
+        f64::from(self.count) * weight
+    }
+}
+
+/// 最近使用的表和数据库
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RecentObjects {
+    #[serde(default)]
+    entries: Vec<RecentEntry>,
+}
+
+impl RecentObjects {
+    /// 记录一次使用，超出上限时淘汰得分最低的记录
+    pub fn record(&mut self, connection: &str, database: Option<&str>, table: Option<&str>, now: DateTime<Local>) {
+        if let Some(entry) = self.entries.iter_mut().find(|e| e.is(connection, database, table)) {
+            entry.count = entry.count.saturating_add(1);
+            entry.last_used = now;
+            return;
+        }
+        self.entries.push(RecentEntry {
+            connection: connection.to_string(),
+            database: database.map(str::to_string),
+            table: table.map(str::to_string),
+            last_used: now,
+            count: 1,
+        });
+        while self.entries.len() > constants::history::MAX_RECENT_OBJECTS {
+            let ranked = self.ranked(now);
+            let Some(&last) = ranked.last() else { break };
+            self.entries.remove(last);
+        }
+    }
+
+    /// 按得分从高到低排序的记录序号，得分相同时最近使用的在前
+    pub fn ranked(&self, now: DateTime<Local>) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.entries.len()).collect();
+        order.sort_by(|&a, &b| {
+            let (a, b) = (&self.entries[a], &self.entries[b]);
+            b.score(now).total_cmp(&a.score(now)).then(b.last_used.cmp(&a.last_used))
+        });
+        order
+    }
+
+    /// 按排序返回的记录
+    pub fn ranked_entries(&self, now: DateTime<Local>) -> Vec<&RecentEntry> {
+        self.ranked(now).into_iter().map(|idx| &self.entries[idx]).collect()
+    }
+
+    /// 删除某个连接的所有记录
+    pub fn remove_connection(&mut self, connection: &str) {
+        self.entries.retain(|e| e.connection != connection);
+    }
+
+    /// 删除一条记录（对象已不存在时）
+    pub fn remove(&mut self, target: &RecentTarget) {
+        self.entries
+            .retain(|e| !e.is(&target.connection, target.database.as_deref(), target.table.as_deref()));
+    }
+}
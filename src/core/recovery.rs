@@ -0,0 +1,127 @@
+//! 崩溃恢复
+//!
+//! 运行期间定期把各标签页的 SQL 和表格中未保存的修改写入恢复文件，正常退出时删除。
+//! 启动时发现恢复文件说明上次没有正常退出，可以选择恢复。表格修改以待检查的 SQL
+//! 形式恢复到新的标签页，不会自动执行。设置了主密码时恢复文件用主密码的密钥加密。
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+use super::config::AppConfig;
+use super::session::TabState;
+use super::vault::{MasterKey, Vault};
+
+/// 自动保存恢复文件的间隔（秒）
+pub const RECOVERY_AUTOSAVE_SECS: u64 = 30;
+
+/// 表格中未保存的修改
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecoveredEdits {
+    /// 连接名称
+    pub connection: String,
+    /// 表名
+    pub table: String,
+    /// 保存修改需要执行的 SQL
+    pub statements: Vec<String>,
+}
+
+/// 加密的恢复文件
+#[derive(Serialize, Deserialize)]
+struct EncryptedRecovery {
+    vault: Vault,
+}
+
+/// 恢复文件的内容
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecoveryState {
+    /// 保存时间
+    pub saved_at: DateTime<Local>,
+    /// 标签页
+    #[serde(default)]
+    pub tabs: Vec<TabState>,
+    /// 表格中未保存的修改
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grid_edits: Option<RecoveredEdits>,
+}
+
+impl RecoveryState {
+    /// 恢复文件路径（与配置文件放在一起，每个命名配置各自一份）
+    pub fn path() -> Option<PathBuf> {
+        AppConfig::config_path().map(|p| p.with_extension("recovery.toml"))
+    }
+
+    /// 读取恢复文件，文件不存在、无法解析或加密的文件无法用 `key` 解密时返回 `None`
+    pub fn load(path: &Path, key: Option<&MasterKey>) -> Option<Self> {
+        let mut content = fs::read_to_string(path).ok()?;
+        if let Ok(encrypted) = toml::from_str::<EncryptedRecovery>(&content) {
+            content = match encrypted.vault.open_with(key?) {
+                Ok(plain) => plain,
+                Err(e) => {
+                    tracing::warn!(error = %e, path = ?path, "解密恢复文件失败");
+                    return None;
+                }
+            };
+        }
+        match toml::from_str(&content) {
+            Ok(state) => Some(state),
+            Err(e) => {
+                tracing::warn!(error = %e, path = ?path, "解析恢复文件失败");
+                None
+            }
+        }
+    }
+
+    /// 恢复文件是否已加密
+    pub fn is_encrypted(path: &Path) -> bool {
+        fs::read_to_string(path).is_ok_and(|content| toml::from_str::<EncryptedRecovery>(&content).is_ok())
+    }
+
+    /// 写入恢复文件，指定 `key` 时加密保存
+    pub fn save(&self, path: &Path, key: Option<&MasterKey>) -> Result<(), String> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+        }
+        let mut content = toml::to_string(self).map_err(|e| e.to_string())?;
+        if let Some(key) = key {
+            let encrypted = EncryptedRecovery { vault: key.seal_text(&content)? };
+            content = toml::to_string(&encrypted).map_err(|e| e.to_string())?;
+        }
+        let temp_path = path.with_extension("tmp");
+        fs::write(&temp_path, content).map_err(|e| e.to_string())?;
+        fs::rename(&temp_path, path).map_err(|e| e.to_string())
+    }
+
+    /// 删除恢复文件
+    pub fn remove(path: &Path) {
+        if let Err(e) = fs::remove_file(path)
+            && e.kind() != std::io::ErrorKind::NotFound
+        {
+            tracing::warn!(error = %e, path = ?path, "删除恢复文件失败");
+        }
+    }
+
+    /// 是否没有需要恢复的内容（所有标签页都是空白的，也没有表格修改）
+    pub fn is_empty(&self) -> bool {
+        self.tabs.iter().all(|tab| tab.sql.trim().is_empty()) && self.grid_edits.is_none()
+    }
+
+    /// 内容是否与另一份相同（忽略保存时间）
+    pub fn same_content(&self, other: &Self) -> bool {
+        self.tabs == other.tabs && self.grid_edits == other.grid_edits
+    }
+}
+
+impl RecoveredEdits {
+    /// 恢复到标签页中的 SQL 脚本
+    pub fn script(&self) -> String {
+        format!(
+            "-- 恢复的未保存修改（连接: {}，表: {}），检查后执行\n{}\n",
+            self.connection,
+            self.table,
+            self.statements.join("\n")
+        )
+    }
+}
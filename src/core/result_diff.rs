@@ -0,0 +1,198 @@
+//! 结果集对比
+//!
+//! 将固定的结果快照与之后再次执行的结果进行对比：按指定的键列匹配行
+//! （找出新增、删除和修改的行），未指定键列时按整行匹配（只有新增和删除）。
+
+use std::collections::HashMap;
+
+use crate::database::QueryResult;
+
+/// 行的变化类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffKind {
+    /// 新结果中新增的行
+    Added,
+    /// 新结果中不存在的行
+    Removed,
+    /// 键相同但值有变化的行
+    Changed,
+    /// 未变化的行
+    Unchanged,
+}
+
+impl DiffKind {
+    /// 报告中使用的状态文本
+    pub fn label(&self) -> &'static str {
+        match self {
+            DiffKind::Added => "新增",
+            DiffKind::Removed => "删除",
+            DiffKind::Changed => "修改",
+            DiffKind::Unchanged => "未变",
+        }
+    }
+}
+
+/// 对比结果中的一行
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiffRow {
+    /// 变化类型
+    pub kind: DiffKind,
+    /// 行的值（删除的行为快照中的值，其他为新结果中的值）
+    pub values: Vec<String>,
+    /// 修改前的值（仅修改的行）
+    pub old_values: Option<Vec<String>>,
+    /// 有变化的列索引（仅修改的行）
+    pub changed_columns: Vec<usize>,
+}
+
+/// 结果集对比
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ResultDiff {
+    /// 列名（按新结果的顺序）
+    pub columns: Vec<String>,
+    /// 所有行：按新结果的顺序，删除的行排在最后
+    pub rows: Vec<DiffRow>,
+}
+
+impl ResultDiff {
+    /// 指定类型的行数
+    pub fn count(&self, kind: DiffKind) -> usize {
+        self.rows.iter().filter(|r| r.kind == kind).count()
+    }
+
+    /// 两次结果是否完全相同
+    pub fn is_identical(&self) -> bool {
+        self.rows.iter().all(|r| r.kind == DiffKind::Unchanged)
+    }
+
+    /// 生成对比报告（首列为变化类型，修改的行依次列出修改前和修改后的值；不含未变化的行）
+    pub fn to_report(&self) -> QueryResult {
+        let mut columns = vec!["变化".to_string()];
+        columns.extend(self.columns.iter().cloned());
+
+        let mut rows = Vec::new();
+        for row in &self.rows {
+            let line = |label: &str, values: &[String]| {
+                let mut line = vec![label.to_string()];
+                line.extend(values.iter().cloned());
+                line
+            };
+            match row.kind {
+                DiffKind::Unchanged => {}
+                DiffKind::Changed => {
+                    if let Some(old) = &row.old_values {
+                        rows.push(line("修改前", old));
+                    }
+                    rows.push(line("修改后", &row.values));
+                }
+                kind => rows.push(line(kind.label(), &row.values)),
+            }
+        }
+
+        QueryResult {
+            columns,
+            rows,
+            ..Default::default()
+        }
+    }
+}
+
+/// 对比两次查询结果
+///
+/// `key_columns` 为空时按整行匹配；列名按名称对应，允许顺序不同，但两次结果的列必须相同
+pub fn diff_results(old: &QueryResult, new: &QueryResult, key_columns: &[String]) -> Result<ResultDiff, String> {
+    // 将快照的列映射到新结果的列顺序
+    let mapping: Vec<usize> = new
+        .columns
+        .iter()
+        .map(|c| old.columns.iter().position(|o| o == c))
+        .collect::<Option<_>>()
+        .filter(|_| old.columns.len() == new.columns.len())
+        .ok_or_else(|| {
+            format!(
+                "两次结果的列不同，无法对比（快照: {}；当前: {}）",
+                old.columns.join(", "),
+                new.columns.join(", ")
+            )
+        })?;
+    let old_rows: Vec<Vec<String>> = old
+        .rows
+        .iter()
+        .map(|row| mapping.iter().map(|&i| row.get(i).cloned().unwrap_or_default()).collect())
+        .collect();
+
+    let key_indices: Vec<usize> = key_columns
+        .iter()
+        .map(|k| new.columns.iter().position(|c| c == k).ok_or_else(|| format!("键列不存在: {}", k)))
+        .collect::<Result<_, _>>()?;
+    let key_of = |row: &[String]| -> Vec<String> {
+        if key_indices.is_empty() {
+            row.to_vec()
+        } else {
+            key_indices.iter().map(|&i| row.get(i).cloned().unwrap_or_default()).collect()
+        }
+    };
+
+    // 快照中每个键对应的行（整行匹配时允许重复行，按出现次数匹配）
+    let mut remaining: HashMap<Vec<String>, Vec<usize>> = HashMap::new();
+    for (i, row) in old_rows.iter().enumerate() {
+        let entry = remaining.entry(key_of(row)).or_default();
+        if !key_indices.is_empty() && !entry.is_empty() {
+            return Err(format!("快照中的键存在重复值: {}", key_of(row).join(", ")));
+        }
+        entry.push(i);
+    }
+
+    let mut rows = Vec::with_capacity(new.rows.len());
+    let mut seen_keys = std::collections::HashSet::new();
+    for row in &new.rows {
+        let key = key_of(row);
+        if !key_indices.is_empty() && !seen_keys.insert(key.clone()) {
+            return Err(format!("当前结果中的键存在重复值: {}", key.join(", ")));
+        }
+        let matched = remaining.get_mut(&key).and_then(|indices| indices.pop());
+        let diff_row = match matched {
+            None => DiffRow {
+                kind: DiffKind::Added,
+                values: row.clone(),
+                old_values: None,
+                changed_columns: Vec::new(),
+            },
+            Some(i) => {
+                let old_row = &old_rows[i];
+                let changed_columns: Vec<usize> = (0..row.len()).filter(|&c| old_row.get(c) != row.get(c)).collect();
+                if changed_columns.is_empty() {
+                    DiffRow {
+                        kind: DiffKind::Unchanged,
+                        values: row.clone(),
+                        old_values: None,
+                        changed_columns,
+                    }
+                } else {
+                    DiffRow {
+                        kind: DiffKind::Changed,
+                        values: row.clone(),
+                        old_values: Some(old_row.clone()),
+                        changed_columns,
+                    }
+                }
+            }
+        };
+        rows.push(diff_row);
+    }
+
+    // 未匹配的快照行为删除的行，保持快照中的顺序
+    let mut removed: Vec<usize> = remaining.into_values().flatten().collect();
+    removed.sort_unstable();
+    rows.extend(removed.into_iter().map(|i| DiffRow {
+        kind: DiffKind::Removed,
+        values: old_rows[i].clone(),
+        old_values: None,
+        changed_columns: Vec::new(),
+    }));
+
+    Ok(ResultDiff {
+        columns: new.columns.clone(),
+        rows,
+    })
+}
@@ -0,0 +1,430 @@
+//! 查询结果的磁盘缓存
+//!
+//! 查询结果占用的内存超过阈值时把结果写入临时文件并释放内存：
+//! - 后台标签页的整个结果写入磁盘，切换回该标签页时读回；
+//! - 活动标签页的大结果按页写入磁盘，只保留表格显示位置附近的页，滚动到其他页时再读回。
+//!   写入磁盘的行在结果中留下空行占位，行号不变。
+//!
+//! 缓存文件保存在首次写入时创建的私有临时目录中（Unix 上权限为 0700），文件以 `create_new`
+//! 方式创建（不会跟随已存在的文件或符号链接），程序退出时删除整个目录。
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Weak};
+
+use tempfile::TempDir;
+
+use crate::database::QueryResult;
+
+/// 整个结果的缓存文件格式标识
+const SPILL_MAGIC: &[u8; 8] = b"GRIDIXR1";
+
+/// 分页缓存文件的格式标识
+const PAGE_MAGIC: &[u8; 8] = b"GRIDIXP1";
+
+/// 按页写入磁盘时每页的行数
+pub const SPILL_PAGE_ROWS: usize = 1000;
+
+/// 已写入磁盘的结果
+#[derive(Debug, Clone)]
+pub struct SpilledResult {
+    /// 缓存文件
+    pub path: PathBuf,
+    /// 行数
+    pub rows: usize,
+}
+
+/// 一个结果中写入磁盘的页
+#[derive(Debug)]
+struct SpilledPages {
+    /// 所属的结果（只用于判断是否为同一结果，不持有数据）
+    result: Weak<QueryResult>,
+    /// 页号 -> 缓存文件
+    files: BTreeMap<usize, PathBuf>,
+}
+
+/// 查询结果的磁盘缓存（整个结果按标签页 ID 保存，分页按结果保存）
+#[derive(Debug)]
+pub struct SpillStore {
+    /// 在此目录下创建私有临时目录
+    parent: PathBuf,
+    /// 私有临时目录（首次写入时创建）
+    dir: Option<TempDir>,
+    /// 下一个缓存文件的编号
+    next_file: u64,
+    entries: HashMap<String, SpilledResult>,
+    pages: Vec<SpilledPages>,
+}
+
+impl Default for SpillStore {
+    fn default() -> Self {
+        Self::new(std::env::temp_dir())
+    }
+}
+
+impl SpillStore {
+    /// 在 `parent` 下创建私有临时目录缓存结果
+    pub fn new(parent: PathBuf) -> Self {
+        Self {
+            parent,
+            dir: None,
+            next_file: 0,
+            entries: HashMap::new(),
+            pages: Vec::new(),
+        }
+    }
+
+    /// 缓存目录（尚未写入过时为 `None`）
+    #[allow(dead_code)] // 公开 API，供外部使用
+    pub fn dir(&self) -> Option<&Path> {
+        self.dir.as_ref().map(TempDir::path)
+    }
+
+    /// 在缓存目录中新建文件（只有当前用户可读写）
+    fn create_file(&mut self) -> io::Result<(PathBuf, File)> {
+        if self.dir.is_none() {
+            let mut builder = tempfile::Builder::new();
+            builder.prefix("gridix-spill-");
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::PermissionsExt;
+                builder.permissions(std::fs::Permissions::from_mode(0o700));
+            }
+            self.dir = Some(builder.tempdir_in(&self.parent)?);
+        }
+        let dir = self.dir.as_ref().map(TempDir::path).unwrap_or(&self.parent);
+        let path = dir.join(format!("{}.rows", self.next_file));
+        self.next_file += 1;
+
+        let mut options = OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        let file = options.open(&path)?;
+        Ok((path, file))
+    }
+
+    /// 写入缓存文件，失败时删除写了一半的文件
+    fn write_file(&mut self, write: impl FnOnce(&mut BufWriter<File>) -> io::Result<()>) -> io::Result<PathBuf> {
+        let (path, file) = self.create_file()?;
+        let mut writer = BufWriter::new(file);
+        match write(&mut writer).and_then(|()| writer.flush()) {
+            Ok(()) => Ok(path),
+            Err(e) => {
+                let _ = fs::remove_file(&path);
+                Err(e)
+            }
+        }
+    }
+
+    /// 指定标签页的结果是否已写入磁盘
+    pub fn contains(&self, key: &str) -> bool {
+        self.entries.contains_key(key)
+    }
+
+    /// 把整个结果写入磁盘
+    pub fn spill(&mut self, key: &str, result: &QueryResult) -> io::Result<&SpilledResult> {
+        let path = self.write_file(|writer| write_result(writer, result))?;
+        let entry = SpilledResult {
+            path,
+            rows: result.rows.len(),
+        };
+        if let Some(old) = self.entries.insert(key.to_string(), entry) {
+            let _ = fs::remove_file(old.path);
+        }
+        Ok(&self.entries[key])
+    }
+
+    /// 从磁盘读回结果并删除缓存文件；结果未写入磁盘时返回 `None`
+    pub fn restore(&mut self, key: &str) -> Option<io::Result<QueryResult>> {
+        let entry = self.entries.remove(key)?;
+        let result = File::open(&entry.path).and_then(|file| read_result(&mut BufReader::new(file)));
+        let _ = fs::remove_file(&entry.path);
+        Some(result)
+    }
+
+    /// 删除缓存的结果（标签页关闭或重新执行查询时）
+    pub fn discard(&mut self, key: &str) {
+        if let Some(entry) = self.entries.remove(key) {
+            let _ = fs::remove_file(entry.path);
+        }
+    }
+
+    /// 只保留满足条件的缓存
+    pub fn retain(&mut self, mut keep: impl FnMut(&str) -> bool) {
+        let removed: Vec<String> = self.entries.keys().filter(|key| !keep(key)).cloned().collect();
+        for key in removed {
+            self.discard(&key);
+        }
+    }
+
+    /// 结果中已写入磁盘的页数
+    pub fn spilled_pages(&self, result: &Arc<QueryResult>) -> usize {
+        self.pages
+            .iter()
+            .find(|p| std::ptr::eq(p.result.as_ptr(), Arc::as_ptr(result)))
+            .map_or(0, |p| p.files.len())
+    }
+
+    /// 取出结果的分页记录（释放其中的弱引用，之后才能修改结果）
+    fn take_pages(&mut self, result: &Arc<QueryResult>) -> BTreeMap<usize, PathBuf> {
+        match self.pages.iter().position(|p| std::ptr::eq(p.result.as_ptr(), Arc::as_ptr(result))) {
+            Some(index) => self.pages.swap_remove(index).files,
+            None => BTreeMap::new(),
+        }
+    }
+
+    /// 放回结果的分页记录
+    fn put_pages(&mut self, result: &Arc<QueryResult>, files: BTreeMap<usize, PathBuf>) {
+        if !files.is_empty() {
+            self.pages.push(SpilledPages {
+                result: Arc::downgrade(result),
+                files,
+            });
+        }
+    }
+
+    /// 把结果中不与 `keep` 重叠的页写入磁盘（从离 `keep` 最远的页开始），释放至少 `target` 字节后停止
+    ///
+    /// 结果还被其他地方引用时无法修改，不写入。返回释放的字节数
+    pub fn spill_pages(&mut self, result: &mut Arc<QueryResult>, keep: Range<usize>, target: usize) -> io::Result<usize> {
+        let mut files = self.take_pages(result);
+        let outcome = self.spill_pages_into(result, &mut files, keep, target);
+        self.put_pages(result, files);
+        outcome
+    }
+
+    fn spill_pages_into(
+        &mut self,
+        result: &mut Arc<QueryResult>,
+        files: &mut BTreeMap<usize, PathBuf>,
+        keep: Range<usize>,
+        target: usize,
+    ) -> io::Result<usize> {
+        let Some(data) = Arc::get_mut(result) else {
+            return Ok(0);
+        };
+        let page_count = data.rows.len().div_ceil(SPILL_PAGE_ROWS);
+        let distance = |page: usize| {
+            let rows = page_rows(page, usize::MAX);
+            if rows.end <= keep.start {
+                keep.start - rows.end
+            } else {
+                rows.start.saturating_sub(keep.end)
+            }
+        };
+        let mut candidates: Vec<usize> = (0..page_count)
+            .filter(|page| !files.contains_key(page))
+            .filter(|&page| {
+                let rows = page_rows(page, usize::MAX);
+                rows.end <= keep.start || rows.start >= keep.end
+            })
+            .collect();
+        candidates.sort_by_key(|&page| std::cmp::Reverse(distance(page)));
+
+        let mut freed = 0;
+        for page in candidates {
+            if freed >= target {
+                break;
+            }
+            let range = page_rows(page, data.rows.len());
+            let rows = &data.rows[range.clone()];
+            let path = self.write_file(|writer| write_page(writer, rows))?;
+            for row in &mut data.rows[range] {
+                freed += row_size(row);
+                // 写入磁盘的行留下空行占位
+                *row = Vec::new();
+            }
+            files.insert(page, path);
+        }
+        Ok(freed)
+    }
+
+    /// 读回结果中与 `rows` 重叠的已写入磁盘的页，返回读回的页数
+    ///
+    /// 结果还被其他地方引用时无法修改，不读回
+    pub fn load_pages(&mut self, result: &mut Arc<QueryResult>, rows: Range<usize>) -> io::Result<usize> {
+        let mut files = self.take_pages(result);
+        let outcome = Self::load_pages_from(result, &mut files, rows);
+        self.put_pages(result, files);
+        outcome
+    }
+
+    fn load_pages_from(
+        result: &mut Arc<QueryResult>,
+        files: &mut BTreeMap<usize, PathBuf>,
+        rows: Range<usize>,
+    ) -> io::Result<usize> {
+        if files.is_empty() {
+            return Ok(0);
+        }
+        let Some(data) = Arc::get_mut(result) else {
+            return Ok(0);
+        };
+        let pages: Vec<usize> = files
+            .keys()
+            .copied()
+            .filter(|&page| {
+                let range = page_rows(page, usize::MAX);
+                range.start < rows.end && rows.start < range.end
+            })
+            .collect();
+        for &page in &pages {
+            let path = &files[&page];
+            let loaded = File::open(path).and_then(|file| read_page(&mut BufReader::new(file)))?;
+            let range = page_rows(page, data.rows.len());
+            if loaded.len() != range.len() {
+                return Err(io::Error::new(io::ErrorKind::InvalidData, "结果缓存文件的行数不匹配"));
+            }
+            for (row, value) in data.rows[range].iter_mut().zip(loaded) {
+                *row = value;
+            }
+            let _ = fs::remove_file(path);
+            files.remove(&page);
+        }
+        Ok(pages.len())
+    }
+
+    /// 删除已释放的结果的分页缓存（结果被新的查询替换或标签页关闭后）
+    pub fn prune_pages(&mut self) {
+        self.pages.retain(|pages| {
+            if pages.result.strong_count() > 0 {
+                return true;
+            }
+            for path in pages.files.values() {
+                let _ = fs::remove_file(path);
+            }
+            false
+        });
+    }
+
+    /// 删除所有缓存和缓存目录
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.pages.clear();
+        if let Some(dir) = self.dir.take() {
+            let path = dir.path().to_path_buf();
+            if let Err(e) = dir.close() {
+                tracing::warn!(error = %e, dir = ?path, "删除结果缓存目录失败");
+            }
+        }
+    }
+}
+
+/// 第 `page` 页的行范围（不超过 `len`）
+fn page_rows(page: usize, len: usize) -> Range<usize> {
+    let start = page.saturating_mul(SPILL_PAGE_ROWS).min(len);
+    start..start.saturating_add(SPILL_PAGE_ROWS).min(len)
+}
+
+/// 一行中字符串占用的内存（与 `QueryResult::estimated_size` 的估算方式一致）
+fn row_size(row: &[String]) -> usize {
+    row.iter().map(|s| std::mem::size_of::<String>() + s.capacity()).sum()
+}
+
+fn write_len(writer: &mut impl Write, len: usize) -> io::Result<()> {
+    let len = u32::try_from(len).map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "数据过大"))?;
+    writer.write_all(&len.to_le_bytes())
+}
+
+fn write_str(writer: &mut impl Write, value: &str) -> io::Result<()> {
+    write_len(writer, value.len())?;
+    writer.write_all(value.as_bytes())
+}
+
+fn write_rows(writer: &mut impl Write, rows: &[Vec<String>]) -> io::Result<()> {
+    write_len(writer, rows.len())?;
+    for row in rows {
+        write_len(writer, row.len())?;
+        for cell in row {
+            write_str(writer, cell)?;
+        }
+    }
+    Ok(())
+}
+
+fn write_result(writer: &mut impl Write, result: &QueryResult) -> io::Result<()> {
+    writer.write_all(SPILL_MAGIC)?;
+    writer.write_all(&result.affected_rows.to_le_bytes())?;
+    writer.write_all(&[u8::from(result.truncated)])?;
+    let original = result.original_row_count.map_or(u64::MAX, |count| count as u64);
+    writer.write_all(&original.to_le_bytes())?;
+    write_len(writer, result.columns.len())?;
+    for column in &result.columns {
+        write_str(writer, column)?;
+    }
+    write_rows(writer, &result.rows)
+}
+
+fn write_page(writer: &mut impl Write, rows: &[Vec<String>]) -> io::Result<()> {
+    writer.write_all(PAGE_MAGIC)?;
+    write_rows(writer, rows)
+}
+
+fn read_u64(reader: &mut impl Read) -> io::Result<u64> {
+    let mut buf = [0; 8];
+    reader.read_exact(&mut buf)?;
+    Ok(u64::from_le_bytes(buf))
+}
+
+fn read_len(reader: &mut impl Read) -> io::Result<usize> {
+    let mut buf = [0; 4];
+    reader.read_exact(&mut buf)?;
+    Ok(u32::from_le_bytes(buf) as usize)
+}
+
+fn read_str(reader: &mut impl Read) -> io::Result<String> {
+    let len = read_len(reader)?;
+    let mut buf = vec![0; len];
+    reader.read_exact(&mut buf)?;
+    String::from_utf8(buf).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+fn read_magic(reader: &mut impl Read, expected: &[u8; 8]) -> io::Result<()> {
+    let mut magic = [0; 8];
+    reader.read_exact(&mut magic)?;
+    if &magic != expected {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "结果缓存文件格式错误"));
+    }
+    Ok(())
+}
+
+fn read_rows(reader: &mut impl Read) -> io::Result<Vec<Vec<String>>> {
+    let row_count = read_len(reader)?;
+    let mut rows = Vec::with_capacity(row_count);
+    for _ in 0..row_count {
+        let len = read_len(reader)?;
+        rows.push((0..len).map(|_| read_str(reader)).collect::<io::Result<Vec<_>>>()?);
+    }
+    Ok(rows)
+}
+
+fn read_result(reader: &mut impl Read) -> io::Result<QueryResult> {
+    read_magic(reader, SPILL_MAGIC)?;
+    let affected_rows = read_u64(reader)?;
+    let mut truncated = [0; 1];
+    reader.read_exact(&mut truncated)?;
+    let original = read_u64(reader)?;
+
+    let column_count = read_len(reader)?;
+    let columns = (0..column_count).map(|_| read_str(reader)).collect::<io::Result<Vec<_>>>()?;
+    let rows = read_rows(reader)?;
+    Ok(QueryResult {
+        columns,
+        rows,
+        affected_rows,
+        truncated: truncated[0] != 0,
+        original_row_count: (original != u64::MAX).then_some(original as usize),
+    })
+}
+
+fn read_page(reader: &mut impl Read) -> io::Result<Vec<Vec<String>>> {
+    read_magic(reader, PAGE_MAGIC)?;
+    read_rows(reader)
+}
@@ -0,0 +1,112 @@
+//! 行书签
+//!
+//! 按连接、数据库和表保存按主键收藏的行（随配置持久化），在书签列表中选择后
+//! 按主键重新查询该表，定位到收藏的行。
+
+use serde::{Deserialize, Serialize};
+
+/// 收藏的一行
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RowBookmark {
+    /// 主键列名
+    pub key_column: String,
+    /// 主键值
+    pub key_value: String,
+    /// 显示的说明（收藏时该行的其他列的值）
+    #[serde(default)]
+    pub label: String,
+}
+
+impl RowBookmark {
+    /// 是否为同一行（主键列和主键值相同）
+    pub fn same_row(&self, key_column: &str, key_value: &str) -> bool {
+        self.key_column == key_column && self.key_value == key_value
+    }
+}
+
+/// 一张表的行书签
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TableBookmarks {
+    connection: String,
+    #[serde(default)]
+    database: Option<String>,
+    table: String,
+    #[serde(default)]
+    rows: Vec<RowBookmark>,
+}
+
+impl TableBookmarks {
+    fn is(&self, connection: &str, database: Option<&str>, table: &str) -> bool {
+        self.connection == connection && self.database.as_deref() == database && self.table == table
+    }
+}
+
+/// 所有表的行书签
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RowBookmarks {
+    #[serde(default)]
+    tables: Vec<TableBookmarks>,
+}
+
+impl RowBookmarks {
+    /// 某张表的书签（按收藏顺序）
+    pub fn for_table(&self, connection: &str, database: Option<&str>, table: &str) -> &[RowBookmark] {
+        self.tables
+            .iter()
+            .find(|t| t.is(connection, database, table))
+            .map(|t| t.rows.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// 某个数据库中有书签的表及其书签（按表名排序）
+    pub fn for_database(&self, connection: &str, database: Option<&str>) -> Vec<(&str, &[RowBookmark])> {
+        let mut tables: Vec<(&str, &[RowBookmark])> = self
+            .tables
+            .iter()
+            .filter(|t| t.connection == connection && t.database.as_deref() == database)
+            .map(|t| (t.table.as_str(), t.rows.as_slice()))
+            .collect();
+        tables.sort_by_key(|(table, _)| *table);
+        tables
+    }
+
+    /// 收藏或取消收藏一行，返回操作后是否已收藏
+    pub fn toggle(&mut self, connection: &str, database: Option<&str>, table: &str, bookmark: RowBookmark) -> bool {
+        if self
+            .for_table(connection, database, table)
+            .iter()
+            .any(|b| b.same_row(&bookmark.key_column, &bookmark.key_value))
+        {
+            self.remove(connection, database, table, &bookmark.key_column, &bookmark.key_value);
+            return false;
+        }
+        match self.tables.iter_mut().find(|t| t.is(connection, database, table)) {
+            Some(entry) => entry.rows.push(bookmark),
+            None => self.tables.push(TableBookmarks {
+                connection: connection.to_string(),
+                database: database.map(str::to_string),
+                table: table.to_string(),
+                rows: vec![bookmark],
+            }),
+        }
+        true
+    }
+
+    /// 删除某张表的一个书签
+    pub fn remove(&mut self, connection: &str, database: Option<&str>, table: &str, key_column: &str, key_value: &str) {
+        if let Some(entry) = self.tables.iter_mut().find(|t| t.is(connection, database, table)) {
+            entry.rows.retain(|b| !b.same_row(key_column, key_value));
+        }
+        self.tables.retain(|t| !t.rows.is_empty());
+    }
+
+    /// 删除某张表的所有书签（表已不存在时）
+    pub fn remove_table(&mut self, connection: &str, database: Option<&str>, table: &str) {
+        self.tables.retain(|t| !t.is(connection, database, table));
+    }
+
+    /// 删除某个连接的所有书签
+    pub fn remove_connection(&mut self, connection: &str) {
+        self.tables.retain(|t| t.connection != connection);
+    }
+}
@@ -0,0 +1,441 @@
+//! 定时查询
+//!
+//! 在应用运行期间按固定间隔（如 `30s`、`5m`）或类 cron 表达式（`分 时 日 月 周`）
+//! 重复执行查询，结果更新到固定的结果标签页；可设置行数或某列数值的阈值，
+//! 越过阈值时提醒一次，恢复后再次越过时重新提醒。
+
+use crate::database::QueryResult;
+use chrono::{Datelike, Duration, NaiveDateTime, Timelike};
+
+/// 最短执行间隔（秒）
+pub const MIN_INTERVAL_SECS: u64 = 5;
+
+// ============================================================================
+// 执行计划
+// ============================================================================
+
+/// cron 表达式中的一个字段
+#[derive(Debug, Clone, PartialEq)]
+struct CronField {
+    /// 允许的取值
+    values: Vec<u32>,
+    /// 是否为 `*`（不限制）
+    any: bool,
+}
+
+impl CronField {
+    fn parse(text: &str, min: u32, max: u32, name: &str) -> Result<Self, String> {
+        let invalid = || format!("cron 字段 {} 无效: {}", name, text);
+        let mut values = Vec::new();
+        for part in text.split(',') {
+            let (range, step) = match part.split_once('/') {
+                Some((range, step)) => (range, step.parse::<u32>().map_err(|_| invalid())?),
+                None => (part, 1),
+            };
+            if step == 0 {
+                return Err(invalid());
+            }
+            let (start, end) = if range == "*" {
+                (min, max)
+            } else if let Some((a, b)) = range.split_once('-') {
+                (a.parse().map_err(|_| invalid())?, b.parse().map_err(|_| invalid())?)
+            } else {
+                let value = range.parse().map_err(|_| invalid())?;
+                // `5/10` 表示从 5 开始每 10 个
+                (value, if part.contains('/') { max } else { value })
+            };
+            if start < min || end > max || start > end {
+                return Err(invalid());
+            }
+            values.extend((start..=end).step_by(step as usize));
+        }
+        values.sort_unstable();
+        values.dedup();
+        Ok(Self { values, any: text.starts_with('*') })
+    }
+
+    fn matches(&self, value: u32) -> bool {
+        self.values.binary_search(&value).is_ok()
+    }
+}
+
+/// 查询执行计划
+#[derive(Debug, Clone, PartialEq)]
+pub enum Schedule {
+    /// 固定间隔（秒）
+    Interval(u64),
+    /// 类 cron 表达式：分 时 日 月 周（周日为 0 或 7）
+    Cron {
+        expression: String,
+        fields: CronFieldSet,
+    },
+}
+
+/// cron 表达式的五个字段
+#[derive(Debug, Clone, PartialEq)]
+pub struct CronFieldSet {
+    minute: CronField,
+    hour: CronField,
+    day: CronField,
+    month: CronField,
+    weekday: CronField,
+}
+
+impl Schedule {
+    /// 解析执行计划：`30s`、`5m`、`1h` 形式的间隔，或 5 个字段的 cron 表达式
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let text = text.trim();
+        if text.is_empty() {
+            return Err("执行计划不能为空".to_string());
+        }
+
+        let fields: Vec<&str> = text.split_whitespace().collect();
+        if fields.len() == 1 {
+            let (number, unit) = text.split_at(text.find(|c: char| !c.is_ascii_digit()).unwrap_or(text.len()));
+            let number: u64 = number.parse().map_err(|_| format!("无法识别的执行计划: {}", text))?;
+            let secs = match unit.trim() {
+                "" | "s" | "秒" => number,
+                "m" | "分" | "分钟" => number * 60,
+                "h" | "时" | "小时" => number * 3600,
+                _ => return Err(format!("无法识别的时间单位: {}", unit)),
+            };
+            if secs < MIN_INTERVAL_SECS {
+                return Err(format!("执行间隔不能小于 {} 秒", MIN_INTERVAL_SECS));
+            }
+            return Ok(Schedule::Interval(secs));
+        }
+
+        if fields.len() != 5 {
+            return Err("cron 表达式需要 5 个字段：分 时 日 月 周".to_string());
+        }
+        let mut weekday = CronField::parse(fields[4], 0, 7, "周")?;
+        // 7 与 0 都表示周日
+        if weekday.matches(7) {
+            weekday.values.retain(|&v| v != 7);
+            if !weekday.matches(0) {
+                weekday.values.insert(0, 0);
+            }
+        }
+        Ok(Schedule::Cron {
+            expression: fields.join(" "),
+            fields: CronFieldSet {
+                minute: CronField::parse(fields[0], 0, 59, "分")?,
+                hour: CronField::parse(fields[1], 0, 23, "时")?,
+                day: CronField::parse(fields[2], 1, 31, "日")?,
+                month: CronField::parse(fields[3], 1, 12, "月")?,
+                weekday,
+            },
+        })
+    }
+
+    /// 计划描述
+    pub fn describe(&self) -> String {
+        match self {
+            Schedule::Interval(secs) if secs % 3600 == 0 => format!("每 {} 小时", secs / 3600),
+            Schedule::Interval(secs) if secs % 60 == 0 => format!("每 {} 分钟", secs / 60),
+            Schedule::Interval(secs) => format!("每 {} 秒", secs),
+            Schedule::Cron { expression, .. } => format!("cron: {}", expression),
+        }
+    }
+
+    /// 计算 `after` 之后的下一次执行时间（cron 表达式一年内无匹配时返回 None）
+    pub fn next_after(&self, after: NaiveDateTime) -> Option<NaiveDateTime> {
+        let fields = match self {
+            Schedule::Interval(secs) => return Some(after + Duration::seconds(*secs as i64)),
+            Schedule::Cron { fields, .. } => fields,
+        };
+
+        // 从下一整分钟开始查找
+        let mut time = after.with_second(0)?.with_nanosecond(0)? + Duration::minutes(1);
+        let limit = after + Duration::days(366);
+        while time <= limit {
+            let day_matches = {
+                let day = fields.day.matches(time.day());
+                let weekday = fields.weekday.matches(time.weekday().num_days_from_sunday());
+                // 与标准 cron 一致：日和周都有限制时满足其一即可
+                match (fields.day.any, fields.weekday.any) {
+                    (false, false) => day || weekday,
+                    _ => day && weekday,
+                }
+            };
+            if !fields.month.matches(time.month()) || !day_matches {
+                time = time.date().succ_opt()?.and_hms_opt(0, 0, 0)?;
+            } else if !fields.hour.matches(time.hour()) {
+                time = time.with_minute(0)? + Duration::hours(1);
+            } else if !fields.minute.matches(time.minute()) {
+                time += Duration::minutes(1);
+            } else {
+                return Some(time);
+            }
+        }
+        None
+    }
+}
+
+// ============================================================================
+// 阈值
+// ============================================================================
+
+/// 阈值比较运算
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThresholdOp {
+    Gt,
+    Ge,
+    Lt,
+    Le,
+    Eq,
+    Ne,
+}
+
+impl ThresholdOp {
+    /// 所有运算
+    pub const ALL: [ThresholdOp; 6] = [
+        ThresholdOp::Gt,
+        ThresholdOp::Ge,
+        ThresholdOp::Lt,
+        ThresholdOp::Le,
+        ThresholdOp::Eq,
+        ThresholdOp::Ne,
+    ];
+
+    /// 运算符号
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            ThresholdOp::Gt => ">",
+            ThresholdOp::Ge => ">=",
+            ThresholdOp::Lt => "<",
+            ThresholdOp::Le => "<=",
+            ThresholdOp::Eq => "=",
+            ThresholdOp::Ne => "!=",
+        }
+    }
+
+    fn compare(&self, left: f64, right: f64) -> bool {
+        match self {
+            ThresholdOp::Gt => left > right,
+            ThresholdOp::Ge => left >= right,
+            ThresholdOp::Lt => left < right,
+            ThresholdOp::Le => left <= right,
+            ThresholdOp::Eq => left == right,
+            ThresholdOp::Ne => left != right,
+        }
+    }
+}
+
+/// 阈值检查对象
+#[derive(Debug, Clone, PartialEq)]
+pub enum ThresholdTarget {
+    /// 结果行数
+    RowCount,
+    /// 第一行中指定列的数值
+    Column(String),
+}
+
+/// 结果阈值
+#[derive(Debug, Clone, PartialEq)]
+pub struct Threshold {
+    pub target: ThresholdTarget,
+    pub op: ThresholdOp,
+    pub value: f64,
+}
+
+impl Threshold {
+    /// 从结果中取出被检查的数值（列不存在或不是数值时返回 None）
+    pub fn observe(&self, result: &QueryResult) -> Option<f64> {
+        match &self.target {
+            ThresholdTarget::RowCount => Some(result.original_row_count.unwrap_or(result.rows.len()) as f64),
+            ThresholdTarget::Column(name) => {
+                let index = result.columns.iter().position(|c| c.eq_ignore_ascii_case(name))?;
+                result.rows.first()?.get(index)?.trim().parse().ok()
+            }
+        }
+    }
+
+    /// 结果是否越过阈值
+    pub fn is_crossed(&self, result: &QueryResult) -> bool {
+        self.observe(result).is_some_and(|v| self.op.compare(v, self.value))
+    }
+
+    /// 阈值描述
+    pub fn describe(&self) -> String {
+        let target = match &self.target {
+            ThresholdTarget::RowCount => "行数".to_string(),
+            ThresholdTarget::Column(name) => name.clone(),
+        };
+        format!("{} {} {}", target, self.op.symbol(), self.value)
+    }
+}
+
+// ============================================================================
+// 调度器
+// ============================================================================
+
+/// 定时查询任务
+#[derive(Debug, Clone)]
+pub struct ScheduledQuery {
+    /// 任务 ID
+    pub id: u64,
+    /// 任务名称
+    pub name: String,
+    /// 执行查询的连接名
+    pub connection: String,
+    /// 查询语句
+    pub sql: String,
+    /// 执行计划
+    pub schedule: Schedule,
+    /// 结果阈值
+    pub threshold: Option<Threshold>,
+    /// 是否启用
+    pub enabled: bool,
+    /// 显示结果的标签页 ID
+    pub tab_id: Option<String>,
+    /// 下次执行时间
+    pub next_run: Option<NaiveDateTime>,
+    /// 上次执行时间
+    pub last_run: Option<NaiveDateTime>,
+    /// 上次执行的错误
+    pub last_error: Option<String>,
+    /// 执行次数
+    pub run_count: u64,
+    /// 是否正在执行
+    pub running: bool,
+    /// 当前是否处于越过阈值状态（用于只在越过时提醒一次）
+    pub alerting: bool,
+}
+
+impl ScheduledQuery {
+    /// 创建任务
+    pub fn new(name: impl Into<String>, connection: impl Into<String>, sql: impl Into<String>, schedule: Schedule) -> Self {
+        Self {
+            id: 0,
+            name: name.into(),
+            connection: connection.into(),
+            sql: sql.into(),
+            schedule,
+            threshold: None,
+            enabled: true,
+            tab_id: None,
+            next_run: None,
+            last_run: None,
+            last_error: None,
+            run_count: 0,
+            running: false,
+            alerting: false,
+        }
+    }
+}
+
+/// 定时查询调度器
+#[derive(Debug, Default)]
+pub struct QueryScheduler {
+    jobs: Vec<ScheduledQuery>,
+    next_id: u64,
+}
+
+impl QueryScheduler {
+    /// 创建调度器
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 添加任务并计算首次执行时间（间隔任务立即执行一次），返回任务 ID
+    pub fn add(&mut self, mut job: ScheduledQuery, now: NaiveDateTime) -> u64 {
+        self.next_id += 1;
+        job.id = self.next_id;
+        job.next_run = match job.schedule {
+            Schedule::Interval(_) => Some(now),
+            Schedule::Cron { .. } => job.schedule.next_after(now),
+        };
+        self.jobs.push(job);
+        self.next_id
+    }
+
+    /// 删除任务
+    pub fn remove(&mut self, id: u64) -> Option<ScheduledQuery> {
+        let index = self.jobs.iter().position(|j| j.id == id)?;
+        Some(self.jobs.remove(index))
+    }
+
+    /// 所有任务
+    pub fn jobs(&self) -> &[ScheduledQuery] {
+        &self.jobs
+    }
+
+    /// 获取任务
+    pub fn get(&self, id: u64) -> Option<&ScheduledQuery> {
+        self.jobs.iter().find(|j| j.id == id)
+    }
+
+    /// 是否没有任务
+    pub fn is_empty(&self) -> bool {
+        self.jobs.is_empty()
+    }
+
+    /// 启用或暂停任务；重新启用时重新计算下次执行时间
+    pub fn set_enabled(&mut self, id: u64, enabled: bool, now: NaiveDateTime) {
+        if let Some(job) = self.jobs.iter_mut().find(|j| j.id == id) {
+            job.enabled = enabled;
+            job.next_run = if enabled { job.schedule.next_after(now) } else { None };
+        }
+    }
+
+    /// 安排任务立即执行
+    pub fn run_now(&mut self, id: u64, now: NaiveDateTime) {
+        if let Some(job) = self.jobs.iter_mut().find(|j| j.id == id && j.enabled) {
+            job.next_run = Some(now);
+        }
+    }
+
+    /// 取出到期的任务并标记为执行中（上次还未完成的任务不会重复执行）
+    pub fn take_due(&mut self, now: NaiveDateTime) -> Vec<ScheduledQuery> {
+        self.jobs
+            .iter_mut()
+            .filter(|j| j.enabled && !j.running && j.next_run.is_some_and(|t| t <= now))
+            .map(|job| {
+                job.running = true;
+                job.clone()
+            })
+            .collect()
+    }
+
+    /// 距离下一个任务到期的时间（用于安排重绘）
+    pub fn time_until_next(&self, now: NaiveDateTime) -> Option<std::time::Duration> {
+        self.jobs
+            .iter()
+            .filter(|j| j.enabled && !j.running)
+            .filter_map(|j| j.next_run)
+            .min()
+            .map(|t| (t - now).to_std().unwrap_or_default())
+    }
+
+    /// 记录执行结果并安排下次执行；结果从未越过变为越过阈值时返回提醒信息
+    pub fn finish(&mut self, id: u64, now: NaiveDateTime, result: Result<&QueryResult, &str>) -> Option<String> {
+        let job = self.jobs.iter_mut().find(|j| j.id == id)?;
+        job.running = false;
+        job.last_run = Some(now);
+        job.run_count += 1;
+        if job.enabled {
+            job.next_run = job.schedule.next_after(now);
+        }
+
+        let result = match result {
+            Ok(result) => {
+                job.last_error = None;
+                result
+            }
+            Err(e) => {
+                job.last_error = Some(e.to_string());
+                return None;
+            }
+        };
+
+        let threshold = job.threshold.as_ref()?;
+        let crossed = threshold.is_crossed(result);
+        let newly_crossed = crossed && !job.alerting;
+        job.alerting = crossed;
+        newly_crossed.then(|| {
+            let observed = threshold.observe(result).unwrap_or_default();
+            format!("定时查询「{}」越过阈值: {}（当前 {}）", job.name, threshold.describe(), observed)
+        })
+    }
+}
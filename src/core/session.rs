@@ -3,16 +3,18 @@
 //! 保存和恢复用户的工作会话，包括：
 //! - 打开的查询 Tab
 //! - 上次连接的数据库
+//! - 上次查看的表的筛选条件
 //! - UI 布局状态
 
 #![allow(dead_code)] // 公开 API，供未来使用
 
+use super::filter_preset::SavedFilter;
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::PathBuf;
 
 /// Tab 状态
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TabState {
     /// Tab 标题
     pub title: String,
@@ -20,6 +22,12 @@ pub struct TabState {
     pub sql: String,
     /// 关联的表名（如果有）
     pub associated_table: Option<String>,
+    /// 是否已固定
+    #[serde(default)]
+    pub pinned: bool,
+    /// 标题是否由用户重命名
+    #[serde(default)]
+    pub renamed: bool,
 }
 
 impl TabState {
@@ -29,6 +37,8 @@ impl TabState {
             title: title.into(),
             sql: sql.into(),
             associated_table: None,
+            pinned: false,
+            renamed: false,
         }
     }
 
@@ -38,6 +48,8 @@ impl TabState {
             title: title.into(),
             sql: sql.into(),
             associated_table: Some(table.into()),
+            pinned: false,
+            renamed: false,
         }
     }
 }
@@ -64,6 +76,10 @@ pub struct SessionState {
     /// 上次选择的表
     #[serde(default)]
     pub last_table: Option<String>,
+
+    /// 上次选择的表上生效的筛选条件
+    #[serde(default)]
+    pub active_filters: Vec<SavedFilter>,
     
     /// 侧边栏宽度
     #[serde(default = "default_sidebar_width")]
@@ -222,6 +238,11 @@ impl SessionState {
         self.last_table = table;
     }
 
+    /// 记录上次选择的表上的筛选条件
+    pub fn record_filters(&mut self, filters: Vec<SavedFilter>) {
+        self.active_filters = filters;
+    }
+
     /// 记录 UI 布局
     pub fn record_layout(&mut self, sidebar_width: f32, central_panel_ratio: f32, show_sidebar: bool, show_sql_editor: bool) {
         self.sidebar_width = sidebar_width;
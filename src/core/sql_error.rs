@@ -0,0 +1,247 @@
+//! SQL 错误信息分析
+//!
+//! 从数据库返回的错误信息中找出出错位置：PostgreSQL 报告的字符位置、SQLite 的偏移和 `near "..."`
+//! 片段、MySQL 的 `near '...' at line N`。未知列和表的错误还会取出名称，
+//! 由调用方根据已缓存的表结构给出拼写相近的候选名称。
+
+use std::ops::Range;
+
+use super::AutoComplete;
+
+/// 错误信息中不存在的对象
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UnknownObject {
+    /// 列（不含表名前缀）
+    Column(String),
+    /// 表（不含库名或模式前缀）
+    Table(String),
+}
+
+impl UnknownObject {
+    /// 对象名称
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Column(name) | Self::Table(name) => name,
+        }
+    }
+}
+
+/// 错误信息的分析结果
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SqlErrorInfo {
+    /// 出错位置在 SQL 中的字节范围（通常是一个单词）
+    pub span: Option<Range<usize>>,
+    /// 出错的行号（从 1 开始）
+    pub line: Option<usize>,
+    /// 不存在的列或表
+    pub unknown: Option<UnknownObject>,
+}
+
+/// 分析错误信息，`sql` 为执行的 SQL
+pub fn analyze_sql_error(message: &str, sql: &str) -> SqlErrorInfo {
+    let unknown = unknown_object(message);
+    let mut line = number_after(message, "at line ");
+    let mut span = None;
+
+    if let Some(position) = number_after(message, "位置: ").or_else(|| number_after(&message.to_lowercase(), "position: ")) {
+        // PostgreSQL：从 1 开始的字符位置
+        span = char_to_byte(sql, position.saturating_sub(1)).map(|start| word_at(sql, start));
+    } else if let Some(offset) = number_after(message, "at offset ") {
+        // SQLite：字节偏移
+        span = (offset <= sql.len() && sql.is_char_boundary(offset)).then(|| word_at(sql, offset));
+    }
+
+    if span.is_none()
+        && let Some(near) = quoted_after(message, "near ")
+    {
+        let from = line.and_then(|l| line_start(sql, l)).unwrap_or(0);
+        span = if near.is_empty() {
+            // MySQL：near '' 表示语句在末尾不完整
+            let end = sql.trim_end().len();
+            Some(end..end)
+        } else {
+            find_snippet(sql, from, &near).map(|start| word_at(sql, start))
+        };
+    }
+
+    if span.is_none()
+        && let Some(object) = &unknown
+    {
+        span = find_word(sql, object.name());
+    }
+
+    // 只有行号时标记整行
+    if span.is_none()
+        && let Some(start) = line.and_then(|l| line_start(sql, l))
+    {
+        let text = sql[start..].lines().next().unwrap_or("");
+        let indent = text.len() - text.trim_start().len();
+        span = Some(start + indent..start + text.trim_end().len());
+    }
+
+    if let Some(span) = &span {
+        line = Some(sql[..span.start].matches('\n').count() + 1);
+    }
+    SqlErrorInfo { span, line, unknown }
+}
+
+/// 从候选名称中找出与 `name` 拼写最接近的一个（忽略大小写，差异过大时返回 `None`）
+pub fn suggest_name<'a>(name: &str, candidates: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+    let target = name.to_lowercase();
+    let max_distance = (target.chars().count() / 3).max(1);
+    candidates
+        .into_iter()
+        .filter(|c| *c != name)
+        .map(|c| (edit_distance(&target, &c.to_lowercase()), c))
+        .filter(|(d, _)| *d <= max_distance)
+        .min_by_key(|(d, _)| *d)
+        .map(|(_, c)| c)
+}
+
+/// 为不存在的列或表找出拼写相近的名称
+///
+/// 列名优先在语句引用的表中查找，这些表都没有缓存列信息时查找所有已缓存的列。
+pub fn suggest_for_unknown(object: &UnknownObject, sql: &str, autocomplete: &AutoComplete) -> Option<String> {
+    let tables = autocomplete.tables();
+    let suggestion = match object {
+        UnknownObject::Table(name) => suggest_name(name, tables.iter().map(String::as_str)),
+        UnknownObject::Column(name) => {
+            let referenced: Vec<&[String]> = tables
+                .iter()
+                .filter(|table| find_word(sql, table).is_some())
+                .filter_map(|table| autocomplete.columns(table))
+                .collect();
+            if referenced.is_empty() {
+                suggest_name(name, autocomplete.all_columns())
+            } else {
+                suggest_name(name, referenced.into_iter().flatten().map(String::as_str))
+            }
+        }
+    };
+    suggestion.map(str::to_string)
+}
+
+/// 错误信息中不存在的列或表
+fn unknown_object(message: &str) -> Option<UnknownObject> {
+    let column = between(message, "column ", " does not exist")
+        .or_else(|| quoted_after(message, "Unknown column "))
+        .or_else(|| rest_after(message, "no such column: "));
+    if let Some(name) = column {
+        return Some(UnknownObject::Column(last_part(&name)));
+    }
+    let table = between(message, "relation ", " does not exist")
+        .or_else(|| between(message, "Table ", " doesn't exist"))
+        .or_else(|| rest_after(message, "no such table: "));
+    table.map(|name| UnknownObject::Table(last_part(&name)))
+}
+
+/// 去掉引号和表名/库名前缀
+fn last_part(name: &str) -> String {
+    let name = name.trim().trim_matches(|c| matches!(c, '"' | '\'' | '`'));
+    name.rsplit('.').next().unwrap_or(name).trim_matches(|c| matches!(c, '"' | '`')).to_string()
+}
+
+/// `prefix` 之后的数字
+fn number_after(message: &str, prefix: &str) -> Option<usize> {
+    let start = message.find(prefix)? + prefix.len();
+    let digits: String = message[start..].chars().take_while(char::is_ascii_digit).collect();
+    digits.parse().ok()
+}
+
+/// `prefix` 之后用引号括起的文本
+fn quoted_after(message: &str, prefix: &str) -> Option<String> {
+    let start = message.find(prefix)? + prefix.len();
+    let rest = &message[start..];
+    let quote = rest.chars().next().filter(|c| matches!(c, '"' | '\'' | '`'))?;
+    let end = rest[1..].find(quote)?;
+    Some(rest[1..1 + end].to_string())
+}
+
+/// `prefix` 和 `suffix` 之间的文本
+fn between(message: &str, prefix: &str, suffix: &str) -> Option<String> {
+    let start = message.find(prefix)? + prefix.len();
+    let end = message[start..].find(suffix)?;
+    Some(message[start..start + end].to_string())
+}
+
+/// `prefix` 之后到行尾的单词
+fn rest_after(message: &str, prefix: &str) -> Option<String> {
+    let start = message.find(prefix)? + prefix.len();
+    message[start..].split_whitespace().next().map(str::to_string)
+}
+
+/// 第 `index` 个字符的字节位置（可以是末尾）
+fn char_to_byte(sql: &str, index: usize) -> Option<usize> {
+    sql.char_indices().map(|(i, _)| i).chain(std::iter::once(sql.len())).nth(index)
+}
+
+/// 第 `line` 行（从 1 开始）的起始字节位置
+fn line_start(sql: &str, line: usize) -> Option<usize> {
+    if line <= 1 {
+        return Some(0);
+    }
+    sql.match_indices('\n').nth(line - 2).map(|(i, _)| i + 1)
+}
+
+/// 从 `start` 开始的单词（标识符、带引号的名称或单个符号）
+fn word_at(sql: &str, start: usize) -> Range<usize> {
+    let rest = &sql[start..];
+    let Some(first) = rest.chars().next() else {
+        return start..start;
+    };
+    let len = if matches!(first, '"' | '`' | '\'') {
+        rest[1..].find(first).map_or(rest.len(), |i| i + 2)
+    } else if first.is_alphanumeric() || first == '_' {
+        rest.find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '.')).unwrap_or(rest.len())
+    } else {
+        first.len_utf8()
+    };
+    start..start + len
+}
+
+/// 从 `from` 开始查找错误信息中的 SQL 片段（MySQL 的片段可能被截断，只用第一行）
+fn find_snippet(sql: &str, from: usize, snippet: &str) -> Option<usize> {
+    let snippet = snippet.lines().next().unwrap_or(snippet).trim();
+    if snippet.is_empty() {
+        return None;
+    }
+    sql[from..].find(snippet).map(|i| from + i).or_else(|| sql.find(snippet))
+}
+
+/// 查找作为完整单词出现的名称（忽略大小写）
+fn find_word(sql: &str, name: &str) -> Option<Range<usize>> {
+    if name.is_empty() {
+        return None;
+    }
+    let lower = sql.to_lowercase();
+    // 转小写可能改变字节长度，此时只能按原文查找
+    let haystack = if lower.len() == sql.len() { lower } else { sql.to_string() };
+    let needle = name.to_lowercase();
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    haystack.match_indices(&needle).map(|(i, _)| i).find_map(|start| {
+        let end = start + needle.len();
+        let before = sql[..start].chars().next_back().is_none_or(|c| !is_word(c));
+        let after = sql[end..].chars().next().is_none_or(|c| !is_word(c));
+        (before && after).then_some(start..end)
+    })
+}
+
+/// 编辑距离（相邻字符交换算作一次编辑，常见的拼写错误如 `emial`）
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut before_prev: Vec<usize> = Vec::new();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for i in 0..a.len() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for j in 0..b.len() {
+            let cost = usize::from(a[i] != b[j]);
+            current[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(current[j] + 1);
+            if i > 0 && j > 0 && a[i] == b[j - 1] && a[i - 1] == b[j] {
+                current[j + 1] = current[j + 1].min(before_prev[j - 1] + 1);
+            }
+        }
+        before_prev = std::mem::replace(&mut prev, current);
+    }
+    prev[b.len()]
+}
@@ -0,0 +1,116 @@
+//! 磁盘上的 .sql 文件
+//!
+//! 查询 Tab 可以关联一个文件：记录最后一次读写时的内容和修改时间，
+//! 用于显示未保存标记，并通过轮询修改时间发现外部修改。
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// 外部修改检查间隔（秒）
+pub const FILE_CHECK_INTERVAL_SECS: u64 = 2;
+
+/// 文件在外部发生的变化
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExternalChange {
+    /// 内容被修改，附带磁盘上的新内容
+    Modified(String),
+    /// 文件被删除或无法读取
+    Removed,
+}
+
+/// 与查询 Tab 关联的 SQL 文件
+#[derive(Debug, Clone)]
+pub struct SqlFile {
+    path: PathBuf,
+    /// 最后一次读取或保存时的内容
+    saved_content: String,
+    /// 最后一次读取或保存时的修改时间（文件不存在时为 None）
+    modified_at: Option<SystemTime>,
+}
+
+impl SqlFile {
+    /// 打开文件，返回文件记录和内容
+    pub fn open(path: &Path) -> io::Result<(Self, String)> {
+        let content = fs::read_to_string(path)?;
+        let file = Self {
+            path: path.to_path_buf(),
+            saved_content: content.clone(),
+            modified_at: modified_time(path),
+        };
+        Ok((file, content))
+    }
+
+    /// 为尚不存在的文件创建记录（另存为时使用），需随后调用 [`Self::save`]
+    pub fn new(path: &Path) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            saved_content: String::new(),
+            modified_at: None,
+        }
+    }
+
+    /// 文件路径
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// 文件名（用作 Tab 标题）
+    pub fn file_name(&self) -> String {
+        self.path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_else(|| self.path.display().to_string())
+    }
+
+    /// 编辑器内容与磁盘内容不同
+    pub fn is_dirty(&self, content: &str) -> bool {
+        self.saved_content != content
+    }
+
+    /// 将内容写回文件
+    pub fn save(&mut self, content: &str) -> io::Result<()> {
+        fs::write(&self.path, content)?;
+        self.saved_content = content.to_string();
+        self.modified_at = modified_time(&self.path);
+        Ok(())
+    }
+
+    /// 检查文件是否在外部被修改
+    ///
+    /// 只有修改时间变化且内容确实不同才报告修改；同一变化只报告一次。
+    pub fn check_external_change(&mut self) -> Option<ExternalChange> {
+        let current = modified_time(&self.path);
+        if current == self.modified_at {
+            return None;
+        }
+        self.modified_at = current;
+
+        if current.is_none() {
+            return Some(ExternalChange::Removed);
+        }
+        match fs::read_to_string(&self.path) {
+            Ok(content) if content == self.saved_content => None,
+            Ok(content) => Some(ExternalChange::Modified(content)),
+            Err(_) => Some(ExternalChange::Removed),
+        }
+    }
+
+    /// 以磁盘上的新内容为准（重新加载后调用）
+    pub fn reloaded(&mut self, content: &str) {
+        self.saved_content = content.to_string();
+    }
+
+    /// 保留编辑器内容：记录磁盘上的新内容，编辑器内容随之视为未保存
+    pub fn keep_local(&mut self, change: &ExternalChange) {
+        match change {
+            ExternalChange::Modified(content) => self.saved_content = content.clone(),
+            ExternalChange::Removed => self.saved_content.clear(),
+        }
+    }
+}
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
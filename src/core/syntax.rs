@@ -2,7 +2,7 @@
 //!
 //! 使用 syntect 库提供专业级的 SQL 语法高亮，支持多种主题。
 
-use super::theme::ThemeColors;
+use super::theme::{CustomTheme, ThemeColors, ThemeManager};
 use egui::{text::LayoutJob, Color32, FontFamily, FontId, TextFormat};
 use once_cell::sync::Lazy;
 use parking_lot::RwLock;
@@ -89,8 +89,8 @@ impl HighlightCache {
         let key = Self::make_key(text, theme_name);
         
         // 如果键已存在，直接更新
-        if self.cache.contains_key(&key) {
-            self.cache.insert(key, job);
+        if let std::collections::hash_map::Entry::Occupied(mut e) = self.cache.entry(key) {
+            e.insert(job);
             self.position_counter += 1;
             self.key_positions.insert(key, self.position_counter);
             return;
@@ -146,6 +146,7 @@ pub struct HighlightColors {
     pub punctuation: Color32, // 标点符号
     pub default: Color32,     // 默认文本
     pub theme_name: String,   // 当前主题名称（用于缓存键）
+    pub custom: bool,         // 是否使用自定义颜色（不使用 syntect 内置配色）
 }
 
 impl HighlightColors {
@@ -162,6 +163,33 @@ impl HighlightColors {
             punctuation: theme.fg_secondary,
             default: theme.fg_primary,
             theme_name: format!("{:?}", theme.accent), // 使用 accent 颜色作为主题标识
+            custom: false,
+        }
+    }
+
+    /// 根据主题管理器的当前主题（预设或自定义）创建高亮配置
+    pub fn from_manager(manager: &ThemeManager) -> Self {
+        match &manager.custom {
+            Some(theme) => Self::from_custom(theme),
+            None => Self::from_theme(&manager.colors),
+        }
+    }
+
+    /// 从自定义主题创建高亮配置
+    pub fn from_custom(theme: &CustomTheme) -> Self {
+        let syntax = &theme.syntax;
+        Self {
+            keyword: syntax.keyword,
+            function: syntax.function,
+            string: syntax.string,
+            number: syntax.number,
+            operator: syntax.operator,
+            comment: syntax.comment,
+            identifier: syntax.identifier,
+            punctuation: syntax.punctuation,
+            default: theme.colors.fg_primary,
+            theme_name: format!("custom:{}:{:?}", theme.name, syntax),
+            custom: true,
         }
     }
 }
@@ -180,6 +208,7 @@ impl Default for HighlightColors {
             punctuation: Color32::from_rgb(169, 177, 214), // 灰蓝
             default: Color32::from_rgb(192, 202, 245),     // 浅蓝
             theme_name: "tokyo-night".to_string(),
+            custom: false,
         }
     }
 }
@@ -281,7 +310,7 @@ pub struct SqlHighlighter {
 impl SqlHighlighter {
     pub fn new(colors: HighlightColors) -> Self {
         // 检查 syntect 是否可用
-        let use_syntect = SYNTAX_SET.find_syntax_by_extension("sql").is_some();
+        let use_syntect = !colors.custom && SYNTAX_SET.find_syntax_by_extension("sql").is_some();
         
         Self { colors, use_syntect }
     }
@@ -0,0 +1,92 @@
+//! 文本逐行对比
+//!
+//! 基于最长公共子序列计算两段文本的行级差异，并整理为左右并排显示的行。
+
+/// 超过该规模（行数乘积）时不再计算公共子序列，直接视为整体替换
+const MAX_DIFF_CELLS: usize = 4_000_000;
+
+/// 并排对比中一行的变化类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineChange {
+    /// 两侧相同
+    Same,
+    /// 两侧都有内容但不同
+    Changed,
+    /// 仅左侧（旧文本）有
+    Removed,
+    /// 仅右侧（新文本）有
+    Added,
+}
+
+/// 并排对比的一行：两侧分别为（行号，内容），行号从 1 开始
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SideBySideLine {
+    pub change: LineChange,
+    pub left: Option<(usize, String)>,
+    pub right: Option<(usize, String)>,
+}
+
+/// 逐行对比两段文本，返回并排显示的行
+///
+/// 相邻的删除行和新增行按顺序配对为「修改」，多出的部分单独成行。
+pub fn side_by_side_diff(old: &str, new: &str) -> Vec<SideBySideLine> {
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+    let (n, m) = (old_lines.len(), new_lines.len());
+
+    // lcs[i][j]：old_lines[i..] 与 new_lines[j..] 的最长公共子序列长度
+    let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+    if n.saturating_mul(m) <= MAX_DIFF_CELLS {
+        for i in (0..n).rev() {
+            for j in (0..m).rev() {
+                lcs[i][j] = if old_lines[i] == new_lines[j] {
+                    lcs[i + 1][j + 1] + 1
+                } else {
+                    lcs[i + 1][j].max(lcs[i][j + 1])
+                };
+            }
+        }
+    }
+
+    let mut lines = Vec::new();
+    let mut removed: Vec<(usize, String)> = Vec::new();
+    let mut added: Vec<(usize, String)> = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        if i < n && j < m && old_lines[i] == new_lines[j] && lcs[i][j] == lcs[i + 1][j + 1] + 1 {
+            flush_changes(&mut lines, &mut removed, &mut added);
+            lines.push(SideBySideLine {
+                change: LineChange::Same,
+                left: Some((i + 1, old_lines[i].to_string())),
+                right: Some((j + 1, new_lines[j].to_string())),
+            });
+            i += 1;
+            j += 1;
+        } else if j < m && (i == n || lcs[i][j + 1] >= lcs[i + 1][j]) {
+            added.push((j + 1, new_lines[j].to_string()));
+            j += 1;
+        } else {
+            removed.push((i + 1, old_lines[i].to_string()));
+            i += 1;
+        }
+    }
+    flush_changes(&mut lines, &mut removed, &mut added);
+    lines
+}
+
+/// 将累积的删除行与新增行配对输出
+fn flush_changes(lines: &mut Vec<SideBySideLine>, removed: &mut Vec<(usize, String)>, added: &mut Vec<(usize, String)>) {
+    let count = removed.len().max(added.len());
+    let mut removed = removed.drain(..);
+    let mut added = added.drain(..);
+    for _ in 0..count {
+        let left = removed.next();
+        let right = added.next();
+        let change = match (&left, &right) {
+            (Some(_), Some(_)) => LineChange::Changed,
+            (Some(_), None) => LineChange::Removed,
+            _ => LineChange::Added,
+        };
+        lines.push(SideBySideLine { change, left, right });
+    }
+}
@@ -1,35 +1,69 @@
+use std::fs;
+use std::path::Path;
+
 use egui::{Color32, CornerRadius, Stroke, Visuals};
 use serde::{Deserialize, Serialize};
 
+/// 颜色以 `#RRGGBBAA` 十六进制字符串序列化，便于手动编辑和分享
+mod hex_color {
+    use egui::Color32;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(color: &Color32, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&color.to_hex())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color32, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        Color32::from_hex(text.trim()).map_err(|_| serde::de::Error::custom(format!("无效的颜色: {}", text)))
+    }
+}
+
 /// 主题颜色定义
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ThemeColors {
     /// 背景色
+    #[serde(with = "hex_color")]
     pub bg_primary: Color32,
+    #[serde(with = "hex_color")]
     pub bg_secondary: Color32,
+    #[serde(with = "hex_color")]
     pub bg_tertiary: Color32,
 
     /// 前景/文字色
+    #[serde(with = "hex_color")]
     pub fg_primary: Color32,
+    #[serde(with = "hex_color")]
     pub fg_secondary: Color32,
+    #[serde(with = "hex_color")]
     pub fg_muted: Color32,
 
     /// 强调色
+    #[serde(with = "hex_color")]
     pub accent: Color32,
+    #[serde(with = "hex_color")]
     pub accent_hover: Color32,
 
     /// 语义色
+    #[serde(with = "hex_color")]
     pub success: Color32,
+    #[serde(with = "hex_color")]
     pub warning: Color32,
+    #[serde(with = "hex_color")]
     pub error: Color32,
+    #[serde(with = "hex_color")]
     pub info: Color32,
 
     /// 边框色
+    #[serde(with = "hex_color")]
     pub border: Color32,
+    #[serde(with = "hex_color")]
     pub border_hover: Color32,
 
     /// 选中/高亮
+    #[serde(with = "hex_color")]
     pub selection: Color32,
+    #[serde(with = "hex_color")]
     pub highlight: Color32,
 }
 
@@ -59,6 +93,29 @@ pub enum ThemePreset {
 }
 
 impl ThemePreset {
+    /// 所有预设主题
+    pub const ALL: [ThemePreset; 19] = [
+        ThemePreset::TokyoNight,
+        ThemePreset::TokyoNightStorm,
+        ThemePreset::TokyoNightLight,
+        ThemePreset::CatppuccinMocha,
+        ThemePreset::CatppuccinMacchiato,
+        ThemePreset::CatppuccinFrappe,
+        ThemePreset::CatppuccinLatte,
+        ThemePreset::OneDark,
+        ThemePreset::OneDarkVivid,
+        ThemePreset::OneLight,
+        ThemePreset::GruvboxDark,
+        ThemePreset::GruvboxLight,
+        ThemePreset::Dracula,
+        ThemePreset::Nord,
+        ThemePreset::SolarizedDark,
+        ThemePreset::SolarizedLight,
+        ThemePreset::MonokaiPro,
+        ThemePreset::GithubDark,
+        ThemePreset::GithubLight,
+    ];
+
     /// 显示名称
     pub fn display_name(&self) -> &'static str {
         match self {
@@ -463,20 +520,121 @@ impl ThemePreset {
     }
 }
 
+/// SQL 语法高亮颜色
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyntaxColors {
+    #[serde(with = "hex_color")]
+    pub keyword: Color32,
+    #[serde(with = "hex_color")]
+    pub function: Color32,
+    #[serde(with = "hex_color")]
+    pub string: Color32,
+    #[serde(with = "hex_color")]
+    pub number: Color32,
+    #[serde(with = "hex_color")]
+    pub operator: Color32,
+    #[serde(with = "hex_color")]
+    pub comment: Color32,
+    #[serde(with = "hex_color")]
+    pub identifier: Color32,
+    #[serde(with = "hex_color")]
+    pub punctuation: Color32,
+}
+
+impl SyntaxColors {
+    /// 由主题颜色推导（与预设主题的高亮配色一致）
+    pub fn from_theme(colors: &ThemeColors) -> Self {
+        Self {
+            keyword: colors.accent,
+            function: colors.info,
+            string: colors.success,
+            number: colors.warning,
+            operator: colors.fg_secondary,
+            comment: colors.fg_muted,
+            identifier: colors.fg_primary,
+            punctuation: colors.fg_secondary,
+        }
+    }
+}
+
+/// 用户自定义主题
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CustomTheme {
+    /// 主题名称
+    pub name: String,
+    /// 基于的预设主题
+    #[serde(default)]
+    pub base: ThemePreset,
+    /// 是否为暗色主题
+    pub dark: bool,
+    /// 界面颜色
+    pub colors: ThemeColors,
+    /// 语法高亮颜色
+    pub syntax: SyntaxColors,
+}
+
+impl CustomTheme {
+    /// 以预设主题为起点创建自定义主题
+    pub fn from_preset(name: impl Into<String>, preset: ThemePreset) -> Self {
+        let colors = preset.colors();
+        Self {
+            name: name.into(),
+            base: preset,
+            dark: preset.is_dark(),
+            syntax: SyntaxColors::from_theme(&colors),
+            colors,
+        }
+    }
+
+    /// 序列化为 TOML
+    pub fn to_toml(&self) -> Result<String, String> {
+        toml::to_string_pretty(self).map_err(|e| format!("序列化主题失败: {}", e))
+    }
+
+    /// 序列化为 JSON
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| format!("序列化主题失败: {}", e))
+    }
+
+    /// 解析 TOML 或 JSON 格式的主题
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let theme: Self = if content.trim_start().starts_with('{') {
+            serde_json::from_str(content).map_err(|e| format!("解析主题失败: {}", e))?
+        } else {
+            toml::from_str(content).map_err(|e| format!("解析主题失败: {}", e))?
+        };
+        if theme.name.trim().is_empty() {
+            return Err("主题名称不能为空".to_string());
+        }
+        Ok(theme)
+    }
+
+    /// 导出到文件（扩展名为 `.json` 时使用 JSON，否则使用 TOML）
+    pub fn export_to(&self, path: &Path) -> Result<(), String> {
+        let is_json = path.extension().is_some_and(|e| e.eq_ignore_ascii_case("json"));
+        let content = if is_json { self.to_json()? } else { self.to_toml()? };
+        fs::write(path, content).map_err(|e| format!("写入文件失败: {}", e))
+    }
+
+    /// 从文件导入
+    pub fn import_from(path: &Path) -> Result<Self, String> {
+        let content = fs::read_to_string(path).map_err(|e| format!("读取文件失败: {}", e))?;
+        Self::parse(&content)
+    }
+}
+
 /// 主题管理器
 #[derive(Debug, Clone)]
 pub struct ThemeManager {
     pub current: ThemePreset,
     pub colors: ThemeColors,
+    /// 当前使用的自定义主题
+    pub custom: Option<CustomTheme>,
 }
 
 impl Default for ThemeManager {
     fn default() -> Self {
-        let preset = ThemePreset::default();
-        Self {
-            current: preset,
-            colors: preset.colors(),
-        }
+        Self::new(ThemePreset::default())
     }
 }
 
@@ -485,12 +643,31 @@ impl ThemeManager {
         Self {
             current: preset,
             colors: preset.colors(),
+            custom: None,
         }
     }
 
     pub fn set_theme(&mut self, preset: ThemePreset) {
         self.current = preset;
         self.colors = preset.colors();
+        self.custom = None;
+    }
+
+    /// 使用自定义主题
+    pub fn set_custom(&mut self, theme: CustomTheme) {
+        self.current = theme.base;
+        self.colors = theme.colors.clone();
+        self.custom = Some(theme);
+    }
+
+    /// 是否为暗色主题
+    pub fn is_dark(&self) -> bool {
+        self.custom.as_ref().map_or(self.current.is_dark(), |t| t.dark)
+    }
+
+    /// 当前主题的显示名称
+    pub fn display_name(&self) -> &str {
+        self.custom.as_ref().map_or(self.current.display_name(), |t| t.name.as_str())
     }
 
     /// 应用主题到 egui 上下文
@@ -498,7 +675,7 @@ impl ThemeManager {
         let colors = &self.colors;
 
         // 基于是否为暗色主题选择基础视觉样式
-        let mut visuals = if self.current.is_dark() {
+        let mut visuals = if self.is_dark() {
             Visuals::dark()
         } else {
             Visuals::light()
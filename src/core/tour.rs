@@ -0,0 +1,140 @@
+//! 新手引导
+//!
+//! 按步骤依次介绍主界面的各个区域（侧边栏、工具栏、SQL 编辑器、模态表格和导出），
+//! 每一步在对应区域旁显示说明。首次启动时自动开始，之后可以在帮助中重新打开。
+
+/// 引导步骤指向的界面区域
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TourTarget {
+    /// 左侧边栏
+    Sidebar,
+    /// 顶部工具栏
+    Toolbar,
+    /// SQL 编辑器
+    Editor,
+    /// 数据表格区域
+    Grid,
+}
+
+/// 一个引导步骤
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TourStep {
+    /// 高亮的区域（`None` 时说明显示在窗口中央）
+    pub target: Option<TourTarget>,
+    /// 标题
+    pub title: &'static str,
+    /// 说明
+    pub body: &'static str,
+    /// 相关按键 (按键, 说明)
+    pub keys: &'static [(&'static str, &'static str)],
+}
+
+/// 引导的全部步骤
+pub const TOUR_STEPS: &[TourStep] = &[
+    TourStep {
+        target: None,
+        title: "欢迎使用 Gridix",
+        body: "接下来用几步介绍主界面和键盘操作。Gridix 的表格采用 Helix/Vim 风格的模态编辑，\
+               和常见的数据库工具不太一样，建议花一分钟看完。",
+        keys: &[("Enter / →", "下一步"), ("←", "上一步"), ("Esc", "退出引导")],
+    },
+    TourStep {
+        target: Some(TourTarget::Sidebar),
+        title: "连接、数据库和表",
+        body: "侧边栏管理连接并浏览数据库和表，选中表后按 Enter 查看数据。各区域之间可以用 hjkl 切换。",
+        keys: &[("Ctrl+N", "新建连接"), ("j / k", "上下移动"), ("Enter / l", "展开或打开"), ("Ctrl+B", "显示/隐藏侧边栏")],
+    },
+    TourStep {
+        target: Some(TourTarget::Toolbar),
+        title: "工具栏和标签页",
+        body: "工具栏可以切换连接、数据库和表，\"操作\"菜单中有导入导出等功能。下方的标签页各自保存一条查询和结果。",
+        keys: &[("Ctrl+T", "新建标签页"), ("Ctrl+Shift+P", "命令面板"), ("Ctrl+P", "快速切换最近的表")],
+    },
+    TourStep {
+        target: Some(TourTarget::Editor),
+        title: "SQL 编辑器",
+        body: "编辑器同样分为 Normal 和 Insert 模式：按 i 开始输入，Esc 返回 Normal 模式后可以用 : 输入命令。",
+        keys: &[("Ctrl+J", "显示/隐藏编辑器"), ("Ctrl+Enter / F5", "执行 SQL"), ("Tab", "选择自动补全")],
+    },
+    TourStep {
+        target: Some(TourTarget::Grid),
+        title: "模态表格",
+        body: "表格默认处于 Normal 模式，按键是命令而不是输入：hjkl 移动，i 编辑单元格，v 选择多个单元格。\
+               修改会先标记出来，按 Ctrl+S 才写入数据库。",
+        keys: &[("hjkl", "移动光标"), ("i / Esc", "编辑 / 退出编辑"), ("v", "选择模式"), ("dd / u", "标记删除 / 撤销"), ("Ctrl+S", "保存修改")],
+    },
+    TourStep {
+        target: Some(TourTarget::Toolbar),
+        title: "导出结果",
+        body: "查询结果可以导出为 CSV、JSON、SQL 等格式，也可以在编辑器中输入 :export 命令。",
+        keys: &[("Ctrl+E", "导出数据"), ("Ctrl+I", "导入数据")],
+    },
+    TourStep {
+        target: None,
+        title: "开始使用",
+        body: "引导结束。完整的快捷键说明在帮助中，需要时也可以在帮助中重新打开本引导。",
+        keys: &[("F1", "打开帮助")],
+    },
+];
+
+/// 引导的进度
+#[derive(Debug, Clone, Default)]
+pub struct Tour {
+    /// 是否正在显示
+    active: bool,
+    /// 当前步骤
+    step: usize,
+}
+
+impl Tour {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 从第一步开始
+    pub fn start(&mut self) {
+        self.active = true;
+        self.step = 0;
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// 当前步骤的序号（从 0 开始）
+    pub fn step_index(&self) -> usize {
+        self.step
+    }
+
+    /// 当前步骤（未开始时为 `None`）
+    pub fn current(&self) -> Option<&'static TourStep> {
+        if self.active { TOUR_STEPS.get(self.step) } else { None }
+    }
+
+    pub fn is_last(&self) -> bool {
+        self.step + 1 >= TOUR_STEPS.len()
+    }
+
+    /// 下一步，返回引导是否因此结束
+    pub fn advance(&mut self) -> bool {
+        if !self.active {
+            return false;
+        }
+        if self.is_last() {
+            self.active = false;
+            return true;
+        }
+        self.step += 1;
+        false
+    }
+
+    /// 上一步
+    pub fn prev(&mut self) {
+        self.step = self.step.saturating_sub(1);
+    }
+
+    /// 结束引导
+    pub fn finish(&mut self) {
+        self.active = false;
+    }
+}
@@ -0,0 +1,128 @@
+//! 回收站
+//!
+//! 删除的连接配置和关闭的查询 Tab 先放入回收站，可以在回收站面板中恢复，
+//! 最近关闭的 Tab 可以用 Ctrl+Shift+T 重新打开。超过保留天数的项目在启动时清除。
+
+use super::constants;
+use super::session::TabState;
+use crate::database::ConnectionConfig;
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+/// 回收站中的项目
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum TrashItem {
+    /// 删除的连接（连同该连接的命令历史）
+    Connection {
+        config: Box<ConnectionConfig>,
+        #[serde(default)]
+        history: Vec<String>,
+    },
+    /// 关闭的查询 Tab
+    Tab(TabState),
+}
+
+impl TrashItem {
+    /// 显示名称
+    pub fn label(&self) -> &str {
+        match self {
+            Self::Connection { config, .. } => &config.name,
+            Self::Tab(tab) => &tab.title,
+        }
+    }
+
+    /// 是否为关闭的 Tab
+    pub fn is_tab(&self) -> bool {
+        matches!(self, Self::Tab(_))
+    }
+}
+
+/// 一条回收站记录
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrashEntry {
+    /// 删除时间
+    pub deleted_at: DateTime<Local>,
+    /// 删除的项目
+    pub item: TrashItem,
+}
+
+/// 回收站（按删除时间排序，最近删除的在最后）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Trash {
+    #[serde(default)]
+    entries: Vec<TrashEntry>,
+}
+
+impl Trash {
+    /// 所有记录
+    pub fn entries(&self) -> &[TrashEntry] {
+        &self.entries
+    }
+
+    /// 回收站是否为空
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// 放入删除的连接
+    pub fn push_connection(&mut self, config: ConnectionConfig, history: Vec<String>, now: DateTime<Local>) {
+        self.entries.push(TrashEntry { deleted_at: now, item: TrashItem::Connection { config: Box::new(config), history } });
+    }
+
+    /// 放入关闭的 Tab（只保留最近关闭的若干个）
+    pub fn push_tab(&mut self, tab: TabState, now: DateTime<Local>) {
+        self.entries.push(TrashEntry { deleted_at: now, item: TrashItem::Tab(tab) });
+        let tabs = self.entries.iter().filter(|e| e.item.is_tab()).count();
+        let mut excess = tabs.saturating_sub(constants::history::MAX_TRASHED_TABS);
+        self.entries.retain(|e| {
+            if excess > 0 && e.item.is_tab() {
+                excess -= 1;
+                return false;
+            }
+            true
+        });
+    }
+
+    /// 取出最近关闭的 Tab
+    pub fn pop_tab(&mut self) -> Option<TabState> {
+        let index = self.entries.iter().rposition(|e| e.item.is_tab())?;
+        match self.entries.remove(index).item {
+            TrashItem::Tab(tab) => Some(tab),
+            TrashItem::Connection { .. } => None,
+        }
+    }
+
+    /// 删除的连接的记录（设置主密码时加密保存）
+    pub fn connection_entries(&self) -> Vec<TrashEntry> {
+        self.entries.iter().filter(|e| !e.item.is_tab()).cloned().collect()
+    }
+
+    /// 只包含关闭的 Tab 的回收站（设置主密码时明文保存的部分）
+    pub fn without_connections(&self) -> Self {
+        Self { entries: self.entries.iter().filter(|e| e.item.is_tab()).cloned().collect() }
+    }
+
+    /// 放回解锁出的记录，按删除时间排序
+    pub fn restore(&mut self, entries: Vec<TrashEntry>) {
+        self.entries.extend(entries);
+        self.entries.sort_by_key(|e| e.deleted_at);
+    }
+
+    /// 取出一条记录（恢复或永久删除）
+    pub fn take(&mut self, index: usize) -> Option<TrashEntry> {
+        (index < self.entries.len()).then(|| self.entries.remove(index))
+    }
+
+    /// 清空回收站，返回清除的记录
+    pub fn clear(&mut self) -> Vec<TrashEntry> {
+        std::mem::take(&mut self.entries)
+    }
+
+    /// 清除删除时间超过 `days` 天的记录，返回清除的记录
+    pub fn purge(&mut self, now: DateTime<Local>, days: u32) -> Vec<TrashEntry> {
+        let cutoff = now - chrono::Duration::days(i64::from(days));
+        let (expired, kept): (Vec<_>, Vec<_>) = std::mem::take(&mut self.entries).into_iter().partition(|e| e.deleted_at < cutoff);
+        self.entries = kept;
+        expired
+    }
+}
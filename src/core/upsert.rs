@@ -0,0 +1,87 @@
+//! 按主键的 upsert 语句生成
+//!
+//! 导入或粘贴的行的主键可能已存在，这时可以生成 upsert 代替普通 INSERT：
+//! PostgreSQL/DuckDB 使用 `ON CONFLICT ... DO UPDATE`，MySQL 使用 `ON DUPLICATE KEY UPDATE`，
+//! SQLite 使用 `INSERT OR REPLACE`。
+
+use crate::database::DatabaseType;
+
+/// upsert 的语法
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpsertSyntax {
+    /// `INSERT ... ON CONFLICT (键) DO UPDATE SET ...`（PostgreSQL、DuckDB）
+    OnConflict,
+    /// `INSERT ... ON DUPLICATE KEY UPDATE ...`（MySQL）
+    OnDuplicateKey,
+    /// `INSERT OR REPLACE INTO ...`（SQLite）
+    InsertOrReplace,
+}
+
+impl UpsertSyntax {
+    /// 数据库使用的 upsert 语法，不支持时返回 `None`
+    pub fn for_db(db_type: DatabaseType) -> Option<Self> {
+        match db_type {
+            DatabaseType::PostgreSQL | DatabaseType::DuckDB => Some(Self::OnConflict),
+            DatabaseType::MySQL => Some(Self::OnDuplicateKey),
+            DatabaseType::SQLite => Some(Self::InsertOrReplace),
+            _ => None,
+        }
+    }
+
+    /// 是否需要指定冲突键列（MySQL 和 SQLite 按表上的主键/唯一键判断冲突）
+    pub fn needs_key(self) -> bool {
+        self == Self::OnConflict
+    }
+}
+
+/// upsert 配置
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Upsert {
+    pub syntax: UpsertSyntax,
+    /// 冲突键列（与传给 `statement` 的列名形式相同）
+    pub key_columns: Vec<String>,
+}
+
+impl Upsert {
+    /// 生成单行 upsert 语句，`columns` 为已加引号的列名，`values` 为逗号分隔的值
+    pub fn statement(&self, table: &str, columns: &[String], values: &str) -> String {
+        let cols = columns.join(", ");
+        // 非键列在冲突时更新
+        let updates: Vec<&String> = columns.iter().filter(|c| !self.key_columns.contains(c)).collect();
+        match self.syntax {
+            UpsertSyntax::InsertOrReplace => {
+                format!("INSERT OR REPLACE INTO {} ({}) VALUES ({});", table, cols, values)
+            }
+            UpsertSyntax::OnDuplicateKey if updates.is_empty() => {
+                format!("INSERT IGNORE INTO {} ({}) VALUES ({});", table, cols, values)
+            }
+            UpsertSyntax::OnDuplicateKey => format!(
+                "INSERT INTO {} ({}) VALUES ({}) ON DUPLICATE KEY UPDATE {};",
+                table,
+                cols,
+                values,
+                updates.iter().map(|c| format!("{0} = VALUES({0})", c)).collect::<Vec<_>>().join(", ")
+            ),
+            UpsertSyntax::OnConflict if updates.is_empty() || self.key_columns.is_empty() => format!(
+                "INSERT INTO {} ({}) VALUES ({}) ON CONFLICT DO NOTHING;",
+                table, cols, values
+            ),
+            UpsertSyntax::OnConflict => format!(
+                "INSERT INTO {} ({}) VALUES ({}) ON CONFLICT ({}) DO UPDATE SET {};",
+                table,
+                cols,
+                values,
+                self.key_columns.join(", "),
+                updates.iter().map(|c| format!("{0} = EXCLUDED.{0}", c)).collect::<Vec<_>>().join(", ")
+            ),
+        }
+    }
+}
+
+/// 生成单行插入语句：有 upsert 配置时生成 upsert，否则生成普通 INSERT
+pub fn insert_row_sql(table: &str, columns: &[String], values: &str, upsert: Option<&Upsert>) -> String {
+    match upsert {
+        Some(upsert) => upsert.statement(table, columns, values),
+        None => format!("INSERT INTO {} ({}) VALUES ({});", table, columns.join(", "), values),
+    }
+}
@@ -0,0 +1,347 @@
+//! 单元格值的显示格式
+//!
+//! 查询结果按数据库返回的文本保存，表格显示前在这一层做转换，编辑和复制仍使用原始值。
+//! - 时间戳的时区转换：带偏移量的值直接换算，不带偏移量的值视为服务器时区的时间
+//! - 数字格式：千位分隔符、固定小数位、科学计数法和右对齐，可全局设置或按表的列设置
+
+use crate::database::QueryResult;
+use chrono::{DateTime, FixedOffset, Local, NaiveDateTime, Offset, TimeZone};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+/// 检测时间戳列时每列最多采样的值数量
+const TIMESTAMP_SAMPLE_SIZE: usize = 20;
+
+/// 时间戳的显示时区
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimestampDisplay {
+    /// 按数据库返回的原样显示
+    #[default]
+    AsStored,
+    /// 转换为 UTC
+    Utc,
+    /// 转换为服务器时区
+    Server,
+    /// 转换为本机时区
+    Local,
+}
+
+impl TimestampDisplay {
+    pub fn all() -> &'static [Self] {
+        &[Self::AsStored, Self::Utc, Self::Server, Self::Local]
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::AsStored => "原样显示",
+            Self::Utc => "UTC",
+            Self::Server => "服务器时区",
+            Self::Local => "本地时区",
+        }
+    }
+
+    /// 首选项中选项名称的翻译键
+    pub fn i18n_key(&self) -> &'static str {
+        match self {
+            Self::AsStored => "prefs.timestamp_as_stored",
+            Self::Utc => "prefs.timestamp_utc",
+            Self::Server => "prefs.timestamp_server",
+            Self::Local => "prefs.timestamp_local",
+        }
+    }
+}
+
+/// 单元格显示格式设置
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ValueFormatter {
+    /// 时间戳的显示时区
+    pub timestamp_display: TimestampDisplay,
+    /// 服务器相对 UTC 的偏移（秒），未知时为 `None`
+    pub server_offset: Option<i32>,
+    /// 全局数字格式（列没有单独设置时使用）
+    pub number: NumberFormat,
+}
+
+impl ValueFormatter {
+    /// 是否需要转换时间戳
+    pub fn converts_timestamps(&self) -> bool {
+        self.timestamp_display != TimestampDisplay::AsStored
+    }
+
+    /// 转换后的时间戳文本；不是时间戳、无需转换或缺少时区信息时返回 `None`
+    pub fn format_timestamp(&self, cell: &str) -> Option<String> {
+        if !self.converts_timestamps() {
+            return None;
+        }
+        let (naive, offset) = parse_timestamp(cell)?;
+        let source = match offset {
+            Some(offset) => offset,
+            None => FixedOffset::east_opt(self.server_offset?)?,
+        };
+        let instant = source.from_local_datetime(&naive).single()?;
+        let target = self.target_offset(&instant)?;
+        let format = if naive.and_utc().timestamp_subsec_nanos() == 0 {
+            "%Y-%m-%d %H:%M:%S"
+        } else {
+            "%Y-%m-%d %H:%M:%S%.f"
+        };
+        Some(instant.with_timezone(&target).format(format).to_string())
+    }
+
+    /// 列标题提示中的时区说明
+    pub fn timestamp_hint(&self) -> String {
+        let offset = match self.timestamp_display {
+            TimestampDisplay::AsStored => return "时间按数据库返回的原样显示".to_string(),
+            TimestampDisplay::Utc => Some(0),
+            TimestampDisplay::Server => self.server_offset,
+            TimestampDisplay::Local => Some(Local::now().offset().fix().local_minus_utc()),
+        };
+        match offset {
+            Some(secs) => format!("时间显示为{}（{}）", self.timestamp_display.display_name(), format_offset(secs)),
+            None => format!("时间显示为{}（服务器时区未知，按原样显示）", self.timestamp_display.display_name()),
+        }
+    }
+
+    fn target_offset(&self, instant: &DateTime<FixedOffset>) -> Option<FixedOffset> {
+        match self.timestamp_display {
+            TimestampDisplay::AsStored => None,
+            TimestampDisplay::Utc => FixedOffset::east_opt(0),
+            TimestampDisplay::Server => FixedOffset::east_opt(self.server_offset?),
+            TimestampDisplay::Local => Some(Local.offset_from_utc_datetime(&instant.naive_utc()).fix()),
+        }
+    }
+}
+
+/// 解析 `2024-01-02 03:04:05[.fff][+08:00|Z]` 形式的时间戳（日期和时间之间也可以是 `T`）
+pub fn parse_timestamp(cell: &str) -> Option<(NaiveDateTime, Option<FixedOffset>)> {
+    let text = cell.trim();
+    if text.len() < 19 || !text.is_char_boundary(10) || !matches!(text.as_bytes()[10], b' ' | b'T') {
+        return None;
+    }
+    let text = format!("{} {}", &text[..10], &text[11..]);
+    if let Some(naive) = text.strip_suffix('Z') {
+        return NaiveDateTime::parse_from_str(naive, "%Y-%m-%d %H:%M:%S%.f")
+            .ok()
+            .map(|n| (n, FixedOffset::east_opt(0)));
+    }
+    if let Ok(dt) = DateTime::parse_from_str(&text, "%Y-%m-%d %H:%M:%S%.f%#z") {
+        return Some((dt.naive_local(), Some(*dt.offset())));
+    }
+    NaiveDateTime::parse_from_str(&text, "%Y-%m-%d %H:%M:%S%.f").ok().map(|n| (n, None))
+}
+
+/// 检测每列是否为时间戳列：采样的非空值都能解析为时间戳
+pub fn detect_timestamp_columns(result: &QueryResult) -> Vec<bool> {
+    (0..result.columns.len())
+        .map(|col| {
+            let mut samples = result
+                .rows
+                .iter()
+                .filter_map(|row| row.get(col))
+                .filter(|v| !v.is_empty() && v.as_str() != "NULL")
+                .take(TIMESTAMP_SAMPLE_SIZE)
+                .peekable();
+            samples.peek().is_some() && samples.all(|v| parse_timestamp(v).is_some())
+        })
+        .collect()
+}
+
+/// `UTC+08:00` 形式的偏移量说明
+pub fn format_offset(secs: i32) -> String {
+    let sign = if secs < 0 { '-' } else { '+' };
+    let abs = secs.unsigned_abs();
+    format!("UTC{}{:02}:{:02}", sign, abs / 3600, abs % 3600 / 60)
+}
+
+/// 数字的千位和小数分隔符
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NumberSeparators {
+    /// 不分组：`1234567.89`
+    #[default]
+    Plain,
+    /// `1,234,567.89`
+    Comma,
+    /// `1.234.567,89`
+    Period,
+    /// `1 234 567,89`
+    Space,
+}
+
+impl NumberSeparators {
+    pub fn all() -> &'static [Self] {
+        &[Self::Plain, Self::Comma, Self::Period, Self::Space]
+    }
+
+    /// 示例文本（用于菜单和首选项）
+    pub fn example(&self) -> &'static str {
+        match self {
+            Self::Plain => "1234567.89",
+            Self::Comma => "1,234,567.89",
+            Self::Period => "1.234.567,89",
+            Self::Space => "1 234 567,89",
+        }
+    }
+
+    /// (千位分隔符, 小数点)
+    fn chars(&self) -> (Option<char>, char) {
+        match self {
+            Self::Plain => (None, '.'),
+            Self::Comma => (Some(','), '.'),
+            Self::Period => (Some('.'), ','),
+            Self::Space => (Some(' '), ','),
+        }
+    }
+}
+
+/// 数字的显示格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NumberFormat {
+    /// 千位和小数分隔符
+    pub separators: NumberSeparators,
+    /// 固定小数位数（`None` 表示保持原样）
+    pub decimals: Option<u8>,
+    /// 使用科学计数法
+    pub scientific: bool,
+    /// 数字右对齐
+    pub align_right: bool,
+}
+
+impl NumberFormat {
+    /// 是否不改变数字文本（对齐方式不影响文本）
+    pub fn is_plain(&self) -> bool {
+        self.separators == NumberSeparators::Plain && self.decimals.is_none() && !self.scientific
+    }
+
+    /// 格式化后的数字文本；不是数字或无需格式化时返回 `None`
+    pub fn format(&self, cell: &str) -> Option<String> {
+        if self.is_plain() {
+            return None;
+        }
+        let text = cell.trim();
+        let value = parse_number(text)?;
+        if self.scientific {
+            let text = format!("{:.*e}", self.decimals.unwrap_or(3) as usize, value);
+            let (_, decimal_point) = self.separators.chars();
+            return Some(text.replace('.', &decimal_point.to_string()));
+        }
+        // 没有固定小数位时保留原文本的数字，避免大整数经过浮点数丢失精度
+        let plain = match self.decimals {
+            Some(decimals) => format!("{:.*}", decimals as usize, value),
+            None if text.contains(['e', 'E']) => value.to_string(),
+            None => text.trim_start_matches('+').to_string(),
+        };
+        Some(self.group(&plain))
+    }
+
+    fn group(&self, plain: &str) -> String {
+        let (thousands, decimal_point) = self.separators.chars();
+        let (sign, digits) = match plain.strip_prefix('-') {
+            Some(rest) => ("-", rest),
+            None => ("", plain),
+        };
+        let (int_part, frac_part) = match digits.split_once('.') {
+            Some((int_part, frac_part)) => (int_part, Some(frac_part)),
+            None => (digits, None),
+        };
+        let mut text = String::from(sign);
+        for (i, c) in int_part.chars().enumerate() {
+            if let Some(sep) = thousands
+                && i > 0
+                && (int_part.len() - i).is_multiple_of(3)
+            {
+                text.push(sep);
+            }
+            text.push(c);
+        }
+        if let Some(frac_part) = frac_part {
+            text.push(decimal_point);
+            text.push_str(frac_part);
+        }
+        text
+    }
+}
+
+/// 单元格是否为十进制数字
+pub fn is_number(cell: &str) -> bool {
+    parse_number(cell.trim()).is_some()
+}
+
+/// 解析十进制数字；有前导零的整数部分（如编号 `007`）不视为数字
+fn parse_number(text: &str) -> Option<f64> {
+    let unsigned = text.strip_prefix(['-', '+']).unwrap_or(text);
+    let int_part = unsigned.split(['.', 'e', 'E']).next().unwrap_or_default();
+    let valid = unsigned.starts_with(|c: char| c.is_ascii_digit())
+        && unsigned.chars().all(|c| c.is_ascii_digit() || matches!(c, '.' | 'e' | 'E' | '-' | '+'))
+        && !(int_part.len() > 1 && int_part.starts_with('0'));
+    if !valid {
+        return None;
+    }
+    text.parse::<f64>().ok().filter(|v| v.is_finite())
+}
+
+/// 一张表各列的数字格式
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct TableColumnFormats {
+    connection: String,
+    #[serde(default)]
+    database: Option<String>,
+    table: String,
+    #[serde(default)]
+    columns: BTreeMap<String, NumberFormat>,
+}
+
+impl TableColumnFormats {
+    fn is(&self, connection: &str, database: Option<&str>, table: &str) -> bool {
+        self.connection == connection && self.database.as_deref() == database && self.table == table
+    }
+}
+
+/// 按表保存的列数字格式（随配置持久化）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ColumnFormats {
+    #[serde(default)]
+    tables: Vec<TableColumnFormats>,
+}
+
+impl ColumnFormats {
+    /// 某张表各列的格式
+    pub fn for_table(&self, connection: &str, database: Option<&str>, table: &str) -> HashMap<String, NumberFormat> {
+        self.tables
+            .iter()
+            .find(|t| t.is(connection, database, table))
+            .map(|t| t.columns.iter().map(|(k, v)| (k.clone(), *v)).collect())
+            .unwrap_or_default()
+    }
+
+    /// 替换某张表各列的格式（为空时删除该表的记录）
+    pub fn set_table(
+        &mut self,
+        connection: &str,
+        database: Option<&str>,
+        table: &str,
+        columns: &HashMap<String, NumberFormat>,
+    ) {
+        self.remove_table(connection, database, table);
+        if !columns.is_empty() {
+            self.tables.push(TableColumnFormats {
+                connection: connection.to_string(),
+                database: database.map(str::to_string),
+                table: table.to_string(),
+                columns: columns.iter().map(|(k, v)| (k.clone(), *v)).collect(),
+            });
+        }
+    }
+
+    /// 删除某张表的格式（表已不存在时）
+    pub fn remove_table(&mut self, connection: &str, database: Option<&str>, table: &str) {
+        self.tables.retain(|t| !t.is(connection, database, table));
+    }
+
+    /// 删除某个连接的所有格式
+    pub fn remove_connection(&mut self, connection: &str) {
+        self.tables.retain(|t| t.connection != connection);
+    }
+}
@@ -0,0 +1,142 @@
+//! 主密码加密的连接配置
+//!
+//! 设置主密码后，配置文件中的整个连接列表用主密码派生的密钥（Argon2id）以 AES-256-GCM
+//! 加密保存，回收站中删除的连接和崩溃恢复文件也一起加密，启动时需要输入主密码解锁。
+//! 未设置主密码时连接中的密码仍使用机器密钥加密。
+
+use argon2::Argon2;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM};
+use ring::rand::{SecureRandom, SystemRandom};
+use serde::{Deserialize, Serialize};
+
+use super::trash::TrashEntry;
+use crate::database::ConnectionConfig;
+
+/// 无界面模式读取主密码的环境变量
+pub const MASTER_PASSWORD_ENV: &str = "GRIDIX_MASTER_PASSWORD";
+
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+
+/// 加密保存的连接列表
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Vault {
+    /// 派生密钥使用的盐（base64）
+    pub salt: String,
+    /// nonce 和密文（base64）
+    pub data: String,
+}
+
+/// 加密保存的内容
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct VaultContent {
+    /// 连接列表
+    #[serde(default)]
+    pub connections: Vec<ConnectionConfig>,
+    /// 回收站中删除的连接
+    #[serde(default)]
+    pub trash: Vec<TrashEntry>,
+}
+
+/// 由主密码派生的密钥
+#[derive(Clone)]
+pub struct MasterKey {
+    key: [u8; 32],
+    salt: [u8; SALT_LEN],
+}
+
+impl std::fmt::Debug for MasterKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("MasterKey(..)")
+    }
+}
+
+impl MasterKey {
+    /// 使用新的随机盐从主密码派生密钥（设置或修改主密码）
+    pub fn create(password: &str) -> Result<Self, String> {
+        if password.is_empty() {
+            return Err("主密码不能为空".to_string());
+        }
+        let mut salt = [0u8; SALT_LEN];
+        SystemRandom::new().fill(&mut salt).map_err(|_| "无法生成随机盐")?;
+        Self::derive(password, salt)
+    }
+
+    /// 使用已保存的盐从主密码派生密钥
+    fn derive(password: &str, salt: [u8; SALT_LEN]) -> Result<Self, String> {
+        let mut key = [0u8; 32];
+        Argon2::default()
+            .hash_password_into(password.as_bytes(), &salt, &mut key)
+            .map_err(|e| format!("派生密钥失败: {}", e))?;
+        Ok(Self { key, salt })
+    }
+
+    fn cipher(&self) -> Result<LessSafeKey, String> {
+        let unbound = UnboundKey::new(&AES_256_GCM, &self.key).map_err(|_| "无法创建加密密钥")?;
+        Ok(LessSafeKey::new(unbound))
+    }
+
+    /// 加密连接列表和回收站中删除的连接
+    pub fn seal(&self, connections: &[ConnectionConfig], trash: &[TrashEntry]) -> Result<Vault, String> {
+        #[derive(Serialize)]
+        struct Content<'a> {
+            connections: &'a [ConnectionConfig],
+            trash: &'a [TrashEntry],
+        }
+        let plain = toml::to_string(&Content { connections, trash }).map_err(|e| e.to_string())?;
+        self.seal_text(&plain)
+    }
+
+    /// 加密任意文本（如崩溃恢复文件），使用与连接配置相同的密钥
+    pub fn seal_text(&self, plain: &str) -> Result<Vault, String> {
+        let mut nonce = [0u8; NONCE_LEN];
+        SystemRandom::new().fill(&mut nonce).map_err(|_| "无法生成 nonce")?;
+        let mut in_out = plain.as_bytes().to_vec();
+        self.cipher()?
+            .seal_in_place_append_tag(Nonce::assume_unique_for_key(nonce), Aad::empty(), &mut in_out)
+            .map_err(|_| "加密失败")?;
+
+        let mut data = nonce.to_vec();
+        data.extend(in_out);
+        Ok(Vault { salt: STANDARD.encode(self.salt), data: STANDARD.encode(data) })
+    }
+}
+
+impl Vault {
+    /// 用主密码解密，返回密钥和加密保存的内容
+    pub fn open(&self, password: &str) -> Result<(MasterKey, VaultContent), String> {
+        let key = MasterKey::derive(password, self.decode_salt()?)?;
+        let plain = self.open_with(&key)?;
+        let content: VaultContent = toml::from_str(&plain).map_err(|e| format!("解析连接配置失败: {}", e))?;
+        Ok((key, content))
+    }
+
+    /// 用已解锁的密钥解密 [`MasterKey::seal_text`] 加密的文本（修改主密码后旧的密文无法解密）
+    pub fn open_with(&self, key: &MasterKey) -> Result<String, String> {
+        if self.decode_salt()? != key.salt {
+            return Err("主密码错误".to_string());
+        }
+        let data = STANDARD.decode(&self.data).map_err(|_| "加密的连接数据已损坏")?;
+        if data.len() < NONCE_LEN {
+            return Err("加密的连接数据已损坏".to_string());
+        }
+
+        let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+        let nonce = Nonce::try_assume_unique_for_key(nonce).map_err(|_| "加密的连接数据已损坏")?;
+        let mut in_out = ciphertext.to_vec();
+        let plain = key
+            .cipher()?
+            .open_in_place(nonce, Aad::empty(), &mut in_out)
+            .map_err(|_| "主密码错误")?;
+        String::from_utf8(plain.to_vec()).map_err(|_| "加密的连接数据已损坏".to_string())
+    }
+
+    fn decode_salt(&self) -> Result<[u8; SALT_LEN], String> {
+        STANDARD
+            .decode(&self.salt)
+            .ok()
+            .and_then(|s| s.try_into().ok())
+            .ok_or_else(|| "加密的连接数据已损坏".to_string())
+    }
+}
@@ -0,0 +1,209 @@
+//! 工作流
+//!
+//! 工作流由依次执行的步骤组成：执行查询、将上一次查询的结果导出到文件。
+//! 保存在配置中，可以在工作流面板或命令行 `--workflow` 中运行。
+//! 本模块只负责定义和执行进度的记录，实际执行由应用层完成。
+
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+use super::ExportFormat;
+
+/// 工作流步骤
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum WorkflowStep {
+    /// 在指定连接上执行 SQL
+    Query { connection: String, sql: String },
+    /// 导出最近一次查询的结果
+    Export {
+        format: ExportFormat,
+        /// 输出路径，支持 `{date}`（YYYYMMDD）和 `{time}`（HHMMSS）占位符
+        path: String,
+        /// SQL 格式 INSERT 语句使用的表名
+        #[serde(default)]
+        table: String,
+        /// 导出插件 id（见 `PluginRegistry`），设置时代替 `format`
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        plugin: Option<String>,
+    },
+}
+
+impl WorkflowStep {
+    /// 新的查询步骤
+    pub fn query(connection: impl Into<String>, sql: impl Into<String>) -> Self {
+        Self::Query {
+            connection: connection.into(),
+            sql: sql.into(),
+        }
+    }
+
+    /// 新的导出步骤
+    pub fn export(format: ExportFormat, path: impl Into<String>) -> Self {
+        Self::Export {
+            format,
+            path: path.into(),
+            table: String::new(),
+            plugin: None,
+        }
+    }
+
+    /// 使用导出插件的导出步骤
+    #[allow(dead_code)] // 公开 API
+    pub fn export_plugin(plugin: impl Into<String>, path: impl Into<String>) -> Self {
+        Self::Export {
+            format: ExportFormat::Csv,
+            path: path.into(),
+            table: String::new(),
+            plugin: Some(plugin.into()),
+        }
+    }
+
+    /// 单行描述
+    pub fn describe(&self) -> String {
+        match self {
+            Self::Query { connection, sql } => {
+                let first_line = sql.lines().map(str::trim).find(|l| !l.is_empty()).unwrap_or_default();
+                format!("查询 [{}] {}", connection, first_line)
+            }
+            Self::Export { plugin: Some(plugin), path, .. } => format!("导出 {} → {}", plugin, path),
+            Self::Export { format, path, .. } => format!("导出 {} → {}", format.display_name(), path),
+        }
+    }
+}
+
+/// 工作流定义
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Workflow {
+    pub name: String,
+    /// 步骤失败后是否继续执行后续步骤
+    #[serde(default)]
+    pub continue_on_error: bool,
+    #[serde(default)]
+    pub steps: Vec<WorkflowStep>,
+}
+
+impl Workflow {
+    /// 创建空工作流
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            continue_on_error: false,
+            steps: Vec::new(),
+        }
+    }
+
+    /// 校验工作流是否可以运行
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("工作流名称不能为空".to_string());
+        }
+        if self.steps.is_empty() {
+            return Err(format!("工作流「{}」没有步骤", self.name));
+        }
+        let mut has_query = false;
+        for (i, step) in self.steps.iter().enumerate() {
+            match step {
+                WorkflowStep::Query { connection, sql } => {
+                    if connection.is_empty() {
+                        return Err(format!("第 {} 步未选择连接", i + 1));
+                    }
+                    if sql.trim().is_empty() {
+                        return Err(format!("第 {} 步 SQL 为空", i + 1));
+                    }
+                    has_query = true;
+                }
+                WorkflowStep::Export { path, .. } => {
+                    if !has_query {
+                        return Err(format!("第 {} 步导出之前没有查询步骤", i + 1));
+                    }
+                    if path.trim().is_empty() {
+                        return Err(format!("第 {} 步未填写导出路径", i + 1));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 展开导出路径中的 `{date}` 和 `{time}` 占位符
+pub fn expand_export_path(path: &str, now: DateTime<Local>) -> String {
+    path.replace("{date}", &now.format("%Y%m%d").to_string())
+        .replace("{time}", &now.format("%H%M%S").to_string())
+}
+
+/// 步骤执行状态
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StepStatus {
+    /// 等待执行
+    Pending,
+    /// 执行中
+    Running,
+    /// 已完成，附带结果说明
+    Done(String),
+    /// 失败，附带错误信息
+    Failed(String),
+    /// 因前面的步骤失败而跳过
+    Skipped,
+}
+
+/// 一次工作流运行的进度
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkflowRun {
+    pub workflow: Workflow,
+    /// 与 `workflow.steps` 一一对应
+    pub statuses: Vec<StepStatus>,
+}
+
+impl WorkflowRun {
+    /// 开始运行
+    pub fn new(workflow: Workflow) -> Self {
+        let statuses = vec![StepStatus::Pending; workflow.steps.len()];
+        Self { workflow, statuses }
+    }
+
+    /// 将下一个等待中的步骤标记为执行中，返回其序号
+    pub fn start_next(&mut self) -> Option<usize> {
+        let idx = self.statuses.iter().position(|s| *s == StepStatus::Pending)?;
+        self.statuses[idx] = StepStatus::Running;
+        Some(idx)
+    }
+
+    /// 记录步骤结果；失败且不继续执行时跳过剩余步骤
+    pub fn finish_step(&mut self, idx: usize, outcome: Result<String, String>) {
+        let failed = outcome.is_err();
+        self.statuses[idx] = match outcome {
+            Ok(message) => StepStatus::Done(message),
+            Err(e) => StepStatus::Failed(e),
+        };
+        if failed && !self.workflow.continue_on_error {
+            for status in &mut self.statuses[idx + 1..] {
+                if *status == StepStatus::Pending {
+                    *status = StepStatus::Skipped;
+                }
+            }
+        }
+    }
+
+    /// 所有步骤都已结束
+    pub fn is_finished(&self) -> bool {
+        !self
+            .statuses
+            .iter()
+            .any(|s| matches!(s, StepStatus::Pending | StepStatus::Running))
+    }
+
+    /// 已结束的步骤数
+    pub fn finished_count(&self) -> usize {
+        self.statuses
+            .iter()
+            .filter(|s| !matches!(s, StepStatus::Pending | StepStatus::Running))
+            .count()
+    }
+
+    /// 失败的步骤数
+    pub fn failed_count(&self) -> usize {
+        self.statuses.iter().filter(|s| matches!(s, StepStatus::Failed(_))).count()
+    }
+}
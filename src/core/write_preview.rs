@@ -0,0 +1,193 @@
+//! 写操作影响行预览
+//!
+//! 把 UPDATE/DELETE 改写为条件相同的 SELECT，执行前先查看将被修改的行。
+//! 支持 MySQL 的多表 UPDATE/DELETE、ORDER BY/LIMIT，以及 PostgreSQL 的 `UPDATE ... FROM`、
+//! `DELETE ... USING` 和 `RETURNING`；无法识别的语句返回 `None`。
+
+use super::audit::strip_leading_comments;
+
+/// 写操作类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WriteKind {
+    Update,
+    Delete,
+}
+
+/// 改写得到的预览查询
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WritePreview {
+    pub kind: WriteKind,
+    /// 查询将被修改的行的 SELECT
+    pub select_sql: String,
+    /// 原语句是否带 WHERE 条件（没有时影响整张表）
+    pub has_where: bool,
+}
+
+/// 把 UPDATE/DELETE 改写为查询将被修改的行的 SELECT
+pub fn write_preview(sql: &str) -> Option<WritePreview> {
+    let sql = strip_leading_comments(sql).trim_end().trim_end_matches(';').trim_end();
+    // 多条语句不改写
+    if find_keyword(sql, ";", 0).is_some() {
+        return None;
+    }
+    let verb = sql.split(|c: char| !c.is_ascii_alphabetic()).next()?.to_ascii_lowercase();
+    match verb.as_str() {
+        "update" => update_preview(sql),
+        "delete" => delete_preview(sql),
+        _ => None,
+    }
+}
+
+/// UPDATE [修饰词] 表 SET 赋值 [FROM 表] [WHERE ...] [ORDER BY ...] [LIMIT ...] [RETURNING ...]
+fn update_preview(sql: &str) -> Option<WritePreview> {
+    let set = find_keyword(sql, "set", 0)?;
+    let target = skip_modifiers(&sql["update".len()..set]);
+    if target.is_empty() {
+        return None;
+    }
+    let after_set = set + "set".len();
+    let tail_start = ["where", "order", "limit", "returning"]
+        .iter()
+        .filter_map(|kw| find_keyword(sql, kw, after_set))
+        .min()
+        .unwrap_or(sql.len());
+    let extra_from = update_from(sql, after_set, tail_start).map(|f| sql[f + "from".len()..tail_start].trim());
+    build_preview(WriteKind::Update, target, extra_from, &sql[tail_start..])
+}
+
+/// 赋值之后、条件之前的 `FROM` 子句位置（赋值中的 `IS [NOT] DISTINCT FROM` 不是子句）
+fn update_from(sql: &str, start: usize, end: usize) -> Option<usize> {
+    let mut pos = start;
+    while let Some(from) = find_keyword(sql, "from", pos).filter(|&f| f < end) {
+        let prev_word = sql[..from]
+            .trim_end()
+            .rsplit(|c: char| !c.is_alphanumeric() && c != '_')
+            .next()
+            .unwrap_or_default();
+        if !prev_word.eq_ignore_ascii_case("distinct") {
+            return Some(from);
+        }
+        pos = from + "from".len();
+    }
+    None
+}
+
+/// DELETE [修饰词] [目标表] FROM 表 [USING 表] [WHERE ...] [ORDER BY ...] [LIMIT ...] [RETURNING ...]
+fn delete_preview(sql: &str) -> Option<WritePreview> {
+    let from = find_keyword(sql, "from", 0)?;
+    // MySQL 多表删除：DELETE t1 FROM t1 JOIN t2 ...
+    let targets = skip_modifiers(&sql["delete".len()..from]);
+    let after_from = from + "from".len();
+    let using = find_keyword(sql, "using", after_from);
+    let tail_start = ["where", "order", "limit", "returning"]
+        .iter()
+        .filter_map(|kw| find_keyword(sql, kw, using.unwrap_or(after_from)))
+        .min()
+        .unwrap_or(sql.len());
+    let table = sql[after_from..using.unwrap_or(tail_start)].trim();
+    if table.is_empty() {
+        return None;
+    }
+    let extra_from = using.map(|u| sql[u + "using".len()..tail_start].trim());
+
+    if targets.is_empty() {
+        return build_preview(WriteKind::Delete, table, extra_from, &sql[tail_start..]);
+    }
+    let columns = targets
+        .split(',')
+        .map(|t| format!("{}.*", t.trim().trim_end_matches(".*")))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let preview = build_preview(WriteKind::Delete, table, extra_from, &sql[tail_start..])?;
+    Some(WritePreview {
+        select_sql: preview.select_sql.replacen("SELECT *", &format!("SELECT {}", columns), 1),
+        ..preview
+    })
+}
+
+/// 组合 SELECT：有额外的表（FROM/USING）时只选择目标表的列
+fn build_preview(kind: WriteKind, target: &str, extra_from: Option<&str>, tail: &str) -> Option<WritePreview> {
+    // RETURNING 之后的内容不属于条件
+    let tail = match find_keyword(tail, "returning", 0) {
+        Some(pos) => tail[..pos].trim(),
+        None => tail.trim(),
+    };
+    let (columns, tables) = match extra_from.filter(|f| !f.is_empty()) {
+        Some(extra) => (format!("{}.*", table_alias(target)), format!("{}, {}", target, extra)),
+        None => ("*".to_string(), target.to_string()),
+    };
+    let mut select_sql = format!("SELECT {} FROM {}", columns, tables);
+    if !tail.is_empty() {
+        select_sql.push(' ');
+        select_sql.push_str(tail);
+    }
+    Some(WritePreview {
+        kind,
+        select_sql,
+        has_where: find_keyword(tail, "where", 0).is_some(),
+    })
+}
+
+/// 表引用的别名（`users AS u`、`users u` 取 `u`，否则为表名）
+fn table_alias(target: &str) -> &str {
+    target.split_whitespace().last().unwrap_or(target)
+}
+
+/// 去掉 MySQL 的 LOW_PRIORITY、QUICK、IGNORE 修饰词
+fn skip_modifiers(text: &str) -> &str {
+    let mut text = text.trim();
+    loop {
+        let word = text.split_whitespace().next().unwrap_or_default();
+        if ["low_priority", "quick", "ignore"].iter().any(|m| word.eq_ignore_ascii_case(m)) {
+            text = text[word.len()..].trim_start();
+        } else {
+            return text;
+        }
+    }
+}
+
+/// 从 `start` 开始查找不在引号、注释和括号内的关键字（不区分大小写，按单词匹配），返回字节位置
+fn find_keyword(sql: &str, keyword: &str, start: usize) -> Option<usize> {
+    let bytes = sql.as_bytes();
+    let is_word = |b: u8| b.is_ascii_alphanumeric() || b == b'_';
+    // 单词形式的关键字要求前后不是单词字符，符号（如 `;`）直接匹配
+    let word_keyword = keyword.bytes().next().is_some_and(is_word);
+    let mut depth = 0usize;
+    let mut i = start;
+    while i < bytes.len() {
+        match bytes[i] {
+            quote @ (b'\'' | b'"' | b'`') => {
+                i += 1;
+                while i < bytes.len() && bytes[i] != quote {
+                    i += 1;
+                }
+            }
+            b'-' if bytes.get(i + 1) == Some(&b'-') => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i += 2;
+                while i + 1 < bytes.len() && !(bytes[i] == b'*' && bytes[i + 1] == b'/') {
+                    i += 1;
+                }
+                i += 1;
+            }
+            b'(' => depth += 1,
+            b')' => depth = depth.saturating_sub(1),
+            _ if depth == 0
+                && bytes[i..].len() >= keyword.len()
+                && bytes[i..i + keyword.len()].eq_ignore_ascii_case(keyword.as_bytes())
+                && (!word_keyword
+                    || ((i == 0 || !is_word(bytes[i - 1]))
+                        && bytes.get(i + keyword.len()).is_none_or(|&b| !is_word(b)))) =>
+            {
+                return Some(i);
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
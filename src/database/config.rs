@@ -2,6 +2,7 @@
 
 use super::ssh_tunnel::SshTunnelConfig;
 use super::types::{DatabaseType, MySqlSslMode, PostgresSslMode};
+use crate::core::constants;
 use serde::{Deserialize, Serialize};
 
 // ============================================================================
@@ -30,6 +31,69 @@ pub(crate) fn url_encode(s: &str) -> String {
     result
 }
 
+/// 转义 ODBC 连接字符串中的属性值（含 `;`、`{`、`}` 或首尾空白时用花括号包裹）
+fn odbc_escape(value: &str) -> String {
+    let needs_braces = value.contains([';', '{', '}']) || value.trim() != value;
+    if needs_braces {
+        format!("{{{}}}", value.replace('}', "}}"))
+    } else {
+        value.to_string()
+    }
+}
+
+/// 按 `;` 拆分 ODBC 连接字符串属性（花括号内的 `;` 不拆分）
+fn split_odbc_attributes(conn_str: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut in_braces = false;
+    let mut start = 0;
+    let mut chars = conn_str.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '{' if !in_braces => in_braces = true,
+            // `}}` 是花括号内的转义
+            '}' if in_braces => {
+                if chars.peek().is_some_and(|(_, next)| *next == '}') {
+                    chars.next();
+                } else {
+                    in_braces = false;
+                }
+            }
+            ';' if !in_braces => {
+                parts.push(&conn_str[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&conn_str[start..]);
+    parts
+}
+
+/// ODBC 连接字符串中是否已有指定属性（键名不区分大小写）
+fn has_odbc_attribute(conn_str: &str, keys: &[&str]) -> bool {
+    split_odbc_attributes(conn_str).into_iter().any(|part| {
+        part.split_once('=')
+            .is_some_and(|(key, _)| keys.iter().any(|k| key.trim().eq_ignore_ascii_case(k)))
+    })
+}
+
+/// 遮蔽 ODBC 连接字符串中的密码（PWD/Password 属性）
+fn mask_odbc_password(conn_str: &str) -> String {
+    split_odbc_attributes(conn_str)
+        .into_iter()
+        .map(|part| match part.split_once('=') {
+            Some((key, _))
+                if key.trim().eq_ignore_ascii_case("PWD")
+                    || key.trim().eq_ignore_ascii_case("PASSWORD") =>
+            {
+                format!("{}=****", key)
+            }
+            _ => part.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(";")
+}
+
 // ============================================================================
 // 密码加密
 // ============================================================================
@@ -223,6 +287,69 @@ where
     decrypt_password(&s).map_err(D::Error::custom)
 }
 
+// ============================================================================
+// SQLite 附加数据库
+// ============================================================================
+
+/// SQLite 附加数据库（ATTACH DATABASE ... AS alias）
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq, Hash)]
+pub struct SqliteAttachment {
+    /// Schema 别名
+    pub alias: String,
+    /// 数据库文件路径
+    pub path: String,
+}
+
+impl SqliteAttachment {
+    /// 创建附加数据库配置
+    pub fn new(alias: impl Into<String>, path: impl Into<String>) -> Self {
+        Self {
+            alias: alias.into(),
+            path: path.into(),
+        }
+    }
+
+    /// 检查别名是否合法（字母、数字、下划线，不能以数字开头，不能为保留 schema）
+    pub fn is_valid_alias(alias: &str) -> bool {
+        let mut chars = alias.chars();
+        let Some(first) = chars.next() else {
+            return false;
+        };
+        if !(first.is_alphabetic() || first == '_') {
+            return false;
+        }
+        if !chars.all(|c| c.is_alphanumeric() || c == '_') {
+            return false;
+        }
+        !alias.eq_ignore_ascii_case("main") && !alias.eq_ignore_ascii_case("temp")
+    }
+
+    /// 根据文件名生成一个不与现有附加库冲突的别名
+    pub fn suggest_alias(path: &str, existing: &[SqliteAttachment]) -> String {
+        let stem = std::path::Path::new(path)
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        let mut base: String = stem
+            .chars()
+            .map(|c| if c.is_alphanumeric() || c == '_' { c } else { '_' })
+            .collect();
+        if !Self::is_valid_alias(&base) {
+            base = format!("db_{}", base);
+        }
+
+        let taken = |alias: &str| existing.iter().any(|a| a.alias.eq_ignore_ascii_case(alias));
+        if !taken(&base) {
+            return base;
+        }
+        (2..)
+            .map(|n| format!("{}_{}", base, n))
+            .find(|alias| !taken(alias))
+            .unwrap_or(base)
+    }
+}
+
 // ============================================================================
 // 连接配置
 // ============================================================================
@@ -246,6 +373,26 @@ pub struct ConnectionConfig {
     /// 数据库名（SQLite 为文件路径，MySQL/PostgreSQL 为可选的默认数据库）
     #[serde(default)]
     pub database: String,
+    /// SQLite 加密密钥（SQLCipher），与密码相同方式加密存储
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        serialize_with = "encode_password",
+        deserialize_with = "decode_password"
+    )]
+    pub sqlite_key: String,
+    /// SQLite 附加数据库列表（每次打开连接时自动 ATTACH）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sqlite_attachments: Vec<SqliteAttachment>,
+    /// Oracle 服务名（EZConnect），主机为空时按 TNS 别名或完整连接描述符解析
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub oracle_service_name: String,
+    /// Redis 键浏览的匹配模式（SCAN MATCH，留空等同于 `*`）
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub redis_key_pattern: String,
+    /// MongoDB 查询结果是否将嵌套文档展开为 `a.b` 形式的列
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub mongodb_flatten: bool,
     /// SSH 隧道配置
     #[serde(default)]
     pub ssh_config: SshTunnelConfig,
@@ -258,10 +405,232 @@ pub struct ConnectionConfig {
     /// CA 证书路径（可选，用于 VerifyCa/VerifyIdentity 模式）
     #[serde(default, skip_serializing_if = "String::is_empty")]
     pub ssl_ca_cert: String,
+    /// 客户端证书路径（可选，PEM 格式，用于 PostgreSQL 双向认证）
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub ssl_client_cert: String,
+    /// 客户端私钥路径（可选，PEM 格式，支持 PKCS#1、PKCS#8 和 SEC1）
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub ssl_client_key: String,
+    /// 连接超时（秒），为空时使用首选项中的默认值
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connect_timeout: Option<u64>,
+    /// 查询超时（秒），为空时使用首选项中的默认值
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub query_timeout: Option<u64>,
+    /// 连接池配置（MySQL/PostgreSQL）
+    #[serde(default, skip_serializing_if = "PoolSettings::is_default")]
+    pub pool: PoolSettings,
+    /// 是否为草稿本连接（只存在于本次会话，不写入配置文件）
+    #[serde(skip)]
+    pub scratchpad: bool,
+}
+
+/// 连接池配置，为空的项使用内置默认值
+///
+/// 连接数和空闲超时只对 MySQL 连接池有效；PostgreSQL 每个数据库使用一个长连接，只使用获取超时
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq, Hash)]
+pub struct PoolSettings {
+    /// 最小连接数
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_connections: Option<usize>,
+    /// 最大连接数
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_connections: Option<usize>,
+    /// 空闲连接超时（秒），超过此时间未使用的连接将被关闭
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub idle_timeout_secs: Option<u64>,
+    /// 获取连接的超时（秒），为空时使用连接超时
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub acquire_timeout_secs: Option<u64>,
 }
 
+impl PoolSettings {
+    /// 是否全部使用默认值
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// 实际使用的最小连接数
+    pub fn min_connections(&self) -> usize {
+        self.min_connections
+            .unwrap_or(constants::database::pool::MYSQL_POOL_MIN_CONNECTIONS)
+    }
+
+    /// 实际使用的最大连接数（不小于最小连接数）
+    pub fn max_connections(&self) -> usize {
+        self.max_connections
+            .unwrap_or(constants::database::pool::MYSQL_POOL_MAX_CONNECTIONS)
+            .max(self.min_connections())
+    }
+
+    /// 实际使用的空闲连接超时（秒）
+    pub fn idle_timeout_secs(&self) -> u64 {
+        self.idle_timeout_secs
+            .unwrap_or(constants::database::pool::MYSQL_IDLE_TIMEOUT_SECS)
+    }
+}
+
+/// 草稿本（内存 SQLite）使用的特殊数据库路径
+pub const SCRATCHPAD_DATABASE: &str = ":memory:";
+
 #[allow(dead_code)] // 公开 API，供外部使用
 impl ConnectionConfig {
+    /// 创建草稿本连接配置（内存 SQLite，断开后数据丢弃）
+    pub fn scratchpad(name: impl Into<String>) -> Self {
+        Self {
+            database: SCRATCHPAD_DATABASE.to_string(),
+            scratchpad: true,
+            ..Self::new(name, DatabaseType::SQLite)
+        }
+    }
+
+    /// 实际使用的连接超时（秒），未单独配置时使用内置默认值
+    pub fn connect_timeout_secs(&self) -> u64 {
+        self.connect_timeout.unwrap_or(constants::database::CONNECTION_TIMEOUT_SECS)
+    }
+
+    /// 实际使用的查询超时（秒），未单独配置时使用内置默认值
+    pub fn query_timeout_secs(&self) -> u64 {
+        self.query_timeout.unwrap_or(constants::database::QUERY_TIMEOUT_SECS)
+    }
+
+    /// 实际使用的获取连接超时（秒），未单独配置时与连接超时相同
+    pub fn acquire_timeout_secs(&self) -> u64 {
+        self.pool.acquire_timeout_secs.unwrap_or_else(|| self.connect_timeout_secs())
+    }
+
+    /// 用默认值补全未单独配置的超时，返回补全后的配置
+    pub fn with_default_timeouts(mut self, connect_secs: u64, query_secs: u64) -> Self {
+        self.connect_timeout.get_or_insert(connect_secs);
+        self.query_timeout.get_or_insert(query_secs);
+        self
+    }
+
+    /// 是否为草稿本连接
+    ///
+    /// 由 [`ConnectionConfig::scratchpad`] 显式标记；用户自建的 `:memory:` 连接是普通连接，照常保存
+    pub fn is_scratchpad(&self) -> bool {
+        self.scratchpad && self.db_type == DatabaseType::SQLite
+    }
+
+    /// SQLite 实际打开的路径
+    ///
+    /// 草稿本使用按连接名区分的共享内存 URI，使多次打开的连接访问同一个内存数据库
+    pub fn sqlite_open_path(&self) -> String {
+        if self.is_scratchpad() {
+            format!("file:gridix_scratch_{}?mode=memory&cache=shared", url_encode(&self.name))
+        } else {
+            self.database.clone()
+        }
+    }
+
+    /// 生成 Oracle 连接字符串
+    ///
+    /// - 服务名是完整连接描述符 `(DESCRIPTION=...)` 或未填写主机：原样使用（TNS 别名）
+    /// - 否则使用 EZConnect 格式 `//host:port/service`
+    pub fn oracle_connect_string(&self) -> String {
+        let service = self.oracle_service_name.trim();
+        if service.starts_with('(') || self.host.is_empty() {
+            service.to_string()
+        } else if service.is_empty() {
+            format!("//{}:{}", self.host, self.port)
+        } else {
+            format!("//{}:{}/{}", self.host, self.port, service)
+        }
+    }
+
+    /// 生成 ODBC 连接字符串
+    ///
+    /// 数据库字段可以是 DSN 名称，也可以是完整连接字符串（包含 `=`）；
+    /// 用户名和密码未在连接字符串中出现时自动追加。
+    pub fn odbc_connection_string(&self) -> String {
+        let target = self.database.trim();
+        let mut conn_str = if target.contains('=') {
+            target.trim_end_matches(';').to_string()
+        } else {
+            format!("DSN={}", odbc_escape(target))
+        };
+
+        if !self.username.is_empty() && !has_odbc_attribute(&conn_str, &["UID", "USER"]) {
+            conn_str.push_str(&format!(";UID={}", odbc_escape(&self.username)));
+        }
+        if !self.password.is_empty() && !has_odbc_attribute(&conn_str, &["PWD", "PASSWORD"]) {
+            conn_str.push_str(&format!(";PWD={}", odbc_escape(&self.password)));
+        }
+        conn_str
+    }
+
+    /// 生成 Redis 连接 URL（`redis://[用户名][:密码@]主机:端口/库号`）
+    ///
+    /// `database` 为 `db3` 或 `3` 形式的库号，留空时使用配置中的默认库
+    pub fn redis_url(&self, database: Option<&str>) -> String {
+        let db = database
+            .filter(|s| !s.is_empty())
+            .unwrap_or(&self.database);
+        let db_index = db.trim().trim_start_matches("db").parse::<u16>().unwrap_or(0);
+        let auth = match (self.username.is_empty(), self.password.is_empty()) {
+            (true, true) => String::new(),
+            (true, false) => format!(":{}@", url_encode(&self.password)),
+            (false, true) => format!("{}@", url_encode(&self.username)),
+            (false, false) => format!(
+                "{}:{}@",
+                url_encode(&self.username),
+                url_encode(&self.password)
+            ),
+        };
+        format!("redis://{}{}:{}/{}", auth, self.host, self.port, db_index)
+    }
+
+    /// Redis 键浏览使用的匹配模式
+    pub fn redis_pattern(&self) -> &str {
+        match self.redis_key_pattern.trim() {
+            "" => "*",
+            pattern => pattern,
+        }
+    }
+
+    /// 生成 MongoDB 连接 URI
+    ///
+    /// 主机字段填写完整的 `mongodb://` 或 `mongodb+srv://` URI（如 Atlas 集群）时原样使用，
+    /// 否则按主机、端口和凭据拼接，认证库使用服务器默认的 `admin`
+    pub fn mongodb_uri(&self) -> String {
+        let host = self.host.trim();
+        if host.starts_with("mongodb://") || host.starts_with("mongodb+srv://") {
+            return host.to_string();
+        }
+        let auth = if self.username.is_empty() {
+            String::new()
+        } else {
+            format!("{}:{}@", url_encode(&self.username), url_encode(&self.password))
+        };
+        format!("mongodb://{}{}:{}/", auth, host, self.port)
+    }
+
+    /// 生成 ClickHouse HTTP 接口地址（不含路径和查询参数）
+    ///
+    /// 主机字段带 `http://` 或 `https://` 前缀时按其协议访问（可省略端口），否则使用 `http://主机:端口`
+    pub fn clickhouse_url(&self) -> String {
+        let host = self.host.trim().trim_end_matches('/');
+        if host.starts_with("http://") || host.starts_with("https://") {
+            let has_port = host
+                .split_once("://")
+                .is_some_and(|(_, rest)| rest.rsplit_once(':').is_some_and(|(_, p)| p.parse::<u16>().is_ok()));
+            if has_port {
+                host.to_string()
+            } else {
+                format!("{}:{}", host, self.port)
+            }
+        } else {
+            format!("http://{}:{}", host, self.port)
+        }
+    }
+
+    /// 是否为 DuckDB 内存数据库（路径为空或 `:memory:`）
+    pub fn is_duckdb_in_memory(&self) -> bool {
+        self.db_type == DatabaseType::DuckDB
+            && (self.database.is_empty() || self.database == SCRATCHPAD_DATABASE)
+    }
+
     /// 创建新的连接配置
     pub fn new(name: impl Into<String>, db_type: DatabaseType) -> Self {
         let db_type_clone = db_type;
@@ -286,7 +655,12 @@ impl ConnectionConfig {
     /// 生成连接字符串（可指定数据库名）
     pub fn connection_string_with_db(&self, database: Option<&str>) -> String {
         match self.db_type {
-            DatabaseType::SQLite => self.database.clone(),
+            DatabaseType::SQLite | DatabaseType::DuckDB => self.database.clone(),
+            DatabaseType::Oracle => self.oracle_connect_string(),
+            DatabaseType::Odbc => self.odbc_connection_string(),
+            DatabaseType::Redis => self.redis_url(database),
+            DatabaseType::MongoDB => self.mongodb_uri(),
+            DatabaseType::ClickHouse => self.clickhouse_url(),
             DatabaseType::PostgreSQL => {
                 let db = database.filter(|s| !s.is_empty()).unwrap_or("postgres");
                 format!(
@@ -316,7 +690,26 @@ impl ConnectionConfig {
     /// 生成唯一的连接标识符（用于连接池缓存，按用户+主机+数据库区分）
     pub fn pool_key(&self) -> String {
         match self.db_type {
+            DatabaseType::SQLite if self.is_scratchpad() => format!("sqlite:memory:{}", self.name),
             DatabaseType::SQLite => format!("sqlite:{}", self.database),
+            DatabaseType::DuckDB if self.is_duckdb_in_memory() => format!("duckdb:memory:{}", self.name),
+            DatabaseType::DuckDB => format!("duckdb:{}", self.database),
+            DatabaseType::Oracle => format!(
+                "oracle:{}:{}:{}",
+                self.oracle_connect_string(),
+                self.username,
+                self.database
+            ),
+            DatabaseType::Odbc => format!("odbc:{}:{}", self.database, self.username),
+            DatabaseType::Redis => format!("redis:{}:{}:{}", self.host, self.port, self.username),
+            // MongoDB 客户端与库无关，同一服务器共用一个客户端
+            DatabaseType::MongoDB => format!("mongodb:{}:{}:{}", self.host, self.port, self.username),
+            DatabaseType::ClickHouse => format!(
+                "clickhouse:{}:{}:{}",
+                self.clickhouse_url(),
+                self.username,
+                self.database
+            ),
             DatabaseType::PostgreSQL => {
                 // 包含数据库名，确保不同数据库使用不同连接
                 format!(
@@ -338,6 +731,29 @@ impl ConnectionConfig {
     pub fn connection_string_masked(&self) -> String {
         match self.db_type {
             DatabaseType::SQLite => format!("sqlite://{}", self.database),
+            DatabaseType::DuckDB => format!("duckdb://{}", self.database),
+            DatabaseType::Oracle => format!("oracle://{}:****@{}", self.username, self.oracle_connect_string()),
+            DatabaseType::Odbc => format!("odbc://{}", mask_odbc_password(&self.odbc_connection_string())),
+            DatabaseType::Redis => {
+                format!(
+                    "redis://{}:****@{}:{}/{}",
+                    self.username, self.host, self.port, self.database
+                )
+            }
+            DatabaseType::MongoDB => {
+                format!(
+                    "mongodb://{}:****@{}:{}/{}",
+                    self.username, self.host, self.port, self.database
+                )
+            }
+            DatabaseType::ClickHouse => {
+                format!(
+                    "clickhouse://{}:****@{}/{}",
+                    self.username,
+                    self.clickhouse_url().split_once("://").map_or("", |(_, rest)| rest),
+                    self.database
+                )
+            }
             DatabaseType::PostgreSQL => {
                 format!(
                     "postgres://{}:****@{}:{}/{}",
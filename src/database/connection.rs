@@ -1,6 +1,7 @@
 //! 连接状态和连接管理器
 
 use super::config::ConnectionConfig;
+use crate::core::KeepAlive;
 use std::collections::HashMap;
 
 // ============================================================================
@@ -18,6 +19,12 @@ pub struct Connection {
     pub selected_database: Option<String>,
     /// 当前数据库的表列表
     pub tables: Vec<String>,
+    /// 表列表是分批读取的，且还有未读取的表
+    pub has_more_tables: bool,
+    /// 服务器时区相对 UTC 的偏移（秒），连接后异步获取
+    pub server_utc_offset: Option<i32>,
+    /// 保活检查状态
+    pub keep_alive: KeepAlive,
     pub error: Option<String>,
 }
 
@@ -36,7 +43,9 @@ impl Connection {
         self.databases.clear();
         self.selected_database = None;
         self.tables.clear();
+        self.has_more_tables = false;
         self.error = None;
+        self.keep_alive = KeepAlive::default();
     }
 
     /// 设置连接成功（带数据库列表）
@@ -44,7 +53,9 @@ impl Connection {
         self.connected = true;
         self.databases = databases;
         self.tables.clear();
+        self.has_more_tables = false;
         self.error = None;
+        self.keep_alive = KeepAlive::default();
     }
 
     /// 设置连接成功（SQLite 模式，直接设置表）
@@ -53,14 +64,26 @@ impl Connection {
         self.databases.clear();
         self.selected_database = None;
         self.tables = tables;
+        self.has_more_tables = false;
         self.error = None;
+        self.keep_alive = KeepAlive::default();
     }
 
     /// 设置选中的数据库及其表列表
     pub fn set_database(&mut self, database: String, tables: Vec<String>) {
         self.selected_database = Some(database.clone());
-        self.config.database = database;
+        // SQLite/DuckDB 的"数据库"是 schema，不能覆盖文件路径
+        if !self.config.db_type.is_file_based() {
+            self.config.database = database;
+        }
         self.tables = tables;
+        self.has_more_tables = false;
+    }
+
+    /// 追加分批读取的表，`has_more` 表示是否还有未读取的表
+    pub fn append_tables(&mut self, tables: Vec<String>, has_more: bool) {
+        self.tables.extend(tables);
+        self.has_more_tables = has_more;
     }
 
     /// 设置连接失败
@@ -69,6 +92,7 @@ impl Connection {
         self.databases.clear();
         self.selected_database = None;
         self.tables.clear();
+        self.has_more_tables = false;
         self.error = Some(error);
     }
 }
@@ -6,6 +6,10 @@
 
 use async_trait::async_trait;
 use super::{ConnectionConfig, DatabaseType, DbError, QueryResult, TriggerInfo, ForeignKeyInfo};
+use super::{
+    connect_database, execute_query, get_foreign_keys, get_primary_key_column, get_table_columns,
+    get_tables_for_database, get_triggers, ConnectResult, POOL_MANAGER,
+};
 
 /// 列信息
 #[derive(Debug, Clone)]
@@ -107,7 +111,7 @@ pub trait DatabaseDriver: Send + Sync {
 
     /// 检查是否支持用户管理
     fn supports_user_management(&self) -> bool {
-        !matches!(self.db_type(), DatabaseType::SQLite)
+        DriverCapabilities::for_db_type(self.db_type()).user_management
     }
 
     /// 获取 NULL 值的显示文本
@@ -127,10 +131,457 @@ pub trait DatabaseDriver: Send + Sync {
             DatabaseType::SQLite => "SELECT sqlite_version()",
             DatabaseType::PostgreSQL => "SELECT version()",
             DatabaseType::MySQL => "SELECT version()",
+            DatabaseType::DuckDB => "SELECT version()",
+            DatabaseType::Oracle => "SELECT banner FROM v$version WHERE ROWNUM = 1",
+            // ODBC 数据源没有通用的版本查询
+            DatabaseType::Odbc => "",
+            DatabaseType::Redis => "INFO server",
+            DatabaseType::MongoDB => "{\"buildInfo\": 1}",
+            DatabaseType::ClickHouse => "SELECT version()",
         }
     }
 }
 
+/// DuckDB 驱动
+///
+/// 嵌入式分析型数据库，支持文件或内存模式（路径留空），
+/// 可直接查询 Parquet/CSV 文件。需启用 `duckdb` 特性，否则所有操作返回错误。
+pub struct DuckDbDriver;
+
+#[async_trait]
+impl DatabaseDriver for DuckDbDriver {
+    fn db_type(&self) -> DatabaseType {
+        DatabaseType::DuckDB
+    }
+
+    async fn connect(&self, config: &ConnectionConfig) -> Result<ConnectResultType, DbError> {
+        Ok(match connect_database(config).await? {
+            ConnectResult::Tables(tables) => ConnectResultType::Tables(tables),
+            ConnectResult::Databases(databases) => ConnectResultType::Databases(databases),
+        })
+    }
+
+    async fn disconnect(&self, config: &ConnectionConfig) -> Result<(), DbError> {
+        POOL_MANAGER.remove_pool(config).await;
+        Ok(())
+    }
+
+    async fn execute(&self, config: &ConnectionConfig, sql: &str) -> Result<QueryResult, DbError> {
+        execute_query(config, sql).await
+    }
+
+    async fn list_databases(&self, _config: &ConnectionConfig) -> Result<Vec<String>, DbError> {
+        // DuckDB 连接即一个数据库
+        Ok(Vec::new())
+    }
+
+    async fn list_tables(&self, config: &ConnectionConfig, database: Option<&str>) -> Result<Vec<String>, DbError> {
+        get_tables_for_database(config, database.unwrap_or_default()).await
+    }
+
+    async fn describe_table(&self, config: &ConnectionConfig, table: &str) -> Result<TableMeta, DbError> {
+        describe_table_with_columns(config, table).await
+    }
+
+    async fn get_primary_key(&self, config: &ConnectionConfig, table: &str) -> Result<Option<String>, DbError> {
+        get_primary_key_column(config, table).await
+    }
+
+    async fn get_foreign_keys(&self, config: &ConnectionConfig) -> Result<Vec<ForeignKeyInfo>, DbError> {
+        get_foreign_keys(config).await
+    }
+
+    async fn get_triggers(&self, _config: &ConnectionConfig) -> Result<Vec<TriggerInfo>, DbError> {
+        // DuckDB 不支持触发器
+        Ok(Vec::new())
+    }
+
+    fn quote_identifier(&self, name: &str) -> String {
+        format!("\"{}\"", name.replace('"', "\"\""))
+    }
+}
+
+/// Oracle 驱动
+///
+/// 支持 EZConnect（`//host:port/service`）和 TNS 别名，schema 作为数据库列表。
+/// 需启用 `oracle` 特性，运行时需要 Oracle Instant Client。
+pub struct OracleDriver;
+
+impl OracleDriver {
+    /// 使用 ROWNUM 限制行数（兼容不支持 FETCH FIRST 的 11g 及更早版本）
+    pub fn rownum_limit(sql: &str, limit: usize) -> String {
+        format!("SELECT * FROM ({}) WHERE ROWNUM <= {}", sql.trim().trim_end_matches(';'), limit)
+    }
+}
+
+#[async_trait]
+impl DatabaseDriver for OracleDriver {
+    fn db_type(&self) -> DatabaseType {
+        DatabaseType::Oracle
+    }
+
+    async fn connect(&self, config: &ConnectionConfig) -> Result<ConnectResultType, DbError> {
+        Ok(match connect_database(config).await? {
+            ConnectResult::Tables(tables) => ConnectResultType::Tables(tables),
+            ConnectResult::Databases(databases) => ConnectResultType::Databases(databases),
+        })
+    }
+
+    async fn disconnect(&self, _config: &ConnectionConfig) -> Result<(), DbError> {
+        // 每次操作独立连接，无需清理
+        Ok(())
+    }
+
+    async fn execute(&self, config: &ConnectionConfig, sql: &str) -> Result<QueryResult, DbError> {
+        execute_query(config, sql).await
+    }
+
+    async fn list_databases(&self, config: &ConnectionConfig) -> Result<Vec<String>, DbError> {
+        match connect_database(config).await? {
+            ConnectResult::Databases(schemas) => Ok(schemas),
+            ConnectResult::Tables(_) => Ok(Vec::new()),
+        }
+    }
+
+    async fn list_tables(&self, config: &ConnectionConfig, database: Option<&str>) -> Result<Vec<String>, DbError> {
+        get_tables_for_database(config, database.unwrap_or_default()).await
+    }
+
+    async fn describe_table(&self, config: &ConnectionConfig, table: &str) -> Result<TableMeta, DbError> {
+        describe_table_with_columns(config, table).await
+    }
+
+    async fn get_primary_key(&self, config: &ConnectionConfig, table: &str) -> Result<Option<String>, DbError> {
+        get_primary_key_column(config, table).await
+    }
+
+    async fn get_foreign_keys(&self, config: &ConnectionConfig) -> Result<Vec<ForeignKeyInfo>, DbError> {
+        get_foreign_keys(config).await
+    }
+
+    async fn get_triggers(&self, config: &ConnectionConfig) -> Result<Vec<TriggerInfo>, DbError> {
+        get_triggers(config).await
+    }
+
+    fn quote_identifier(&self, name: &str) -> String {
+        format!("\"{}\"", name.replace('"', "\"\""))
+    }
+
+    /// Oracle 12c+ 使用 FETCH FIRST 语法
+    fn limit_clause(&self, limit: usize) -> String {
+        format!("FETCH FIRST {} ROWS ONLY", limit)
+    }
+
+    fn offset_clause(&self, offset: usize, limit: usize) -> String {
+        format!("OFFSET {} ROWS FETCH NEXT {} ROWS ONLY", offset, limit)
+    }
+}
+
+/// ODBC 通用驱动
+///
+/// 通过 DSN 或连接字符串访问没有原生驱动的数据库（DB2、Access、Snowflake 等）。
+/// 需启用 `odbc` 特性，运行时需要系统 ODBC 驱动管理器及对应数据库的 ODBC 驱动。
+pub struct OdbcDriver;
+
+#[async_trait]
+impl DatabaseDriver for OdbcDriver {
+    fn db_type(&self) -> DatabaseType {
+        DatabaseType::Odbc
+    }
+
+    async fn connect(&self, config: &ConnectionConfig) -> Result<ConnectResultType, DbError> {
+        Ok(match connect_database(config).await? {
+            ConnectResult::Tables(tables) => ConnectResultType::Tables(tables),
+            ConnectResult::Databases(databases) => ConnectResultType::Databases(databases),
+        })
+    }
+
+    async fn disconnect(&self, _config: &ConnectionConfig) -> Result<(), DbError> {
+        // 每次操作独立连接，无需清理
+        Ok(())
+    }
+
+    async fn execute(&self, config: &ConnectionConfig, sql: &str) -> Result<QueryResult, DbError> {
+        execute_query(config, sql).await
+    }
+
+    async fn list_databases(&self, _config: &ConnectionConfig) -> Result<Vec<String>, DbError> {
+        // 数据源由 DSN 决定，不提供数据库切换
+        Ok(Vec::new())
+    }
+
+    async fn list_tables(&self, config: &ConnectionConfig, _database: Option<&str>) -> Result<Vec<String>, DbError> {
+        get_tables_for_database(config, "").await
+    }
+
+    async fn describe_table(&self, config: &ConnectionConfig, table: &str) -> Result<TableMeta, DbError> {
+        describe_table_with_columns(config, table).await
+    }
+
+    async fn get_primary_key(&self, config: &ConnectionConfig, table: &str) -> Result<Option<String>, DbError> {
+        get_primary_key_column(config, table).await
+    }
+
+    async fn get_foreign_keys(&self, config: &ConnectionConfig) -> Result<Vec<ForeignKeyInfo>, DbError> {
+        get_foreign_keys(config).await
+    }
+
+    async fn get_triggers(&self, _config: &ConnectionConfig) -> Result<Vec<TriggerInfo>, DbError> {
+        Ok(Vec::new())
+    }
+
+    /// 使用 ANSI 标准双引号
+    fn quote_identifier(&self, name: &str) -> String {
+        format!("\"{}\"", name.replace('"', "\"\""))
+    }
+
+    /// 使用 SQL:2008 标准语法，兼容面最广
+    fn limit_clause(&self, limit: usize) -> String {
+        format!("FETCH FIRST {} ROWS ONLY", limit)
+    }
+
+    fn offset_clause(&self, offset: usize, limit: usize) -> String {
+        format!("OFFSET {} ROWS FETCH NEXT {} ROWS ONLY", offset, limit)
+    }
+}
+
+/// Redis 驱动
+///
+/// 键值数据库，不使用 SQL：库列表为 `db0..dbN`，表列表为匹配模式的键，
+/// 查询通过命令控制台逐行执行 Redis 命令。需启用 `redis` 特性。
+pub struct RedisDriver;
+
+#[async_trait]
+impl DatabaseDriver for RedisDriver {
+    fn db_type(&self) -> DatabaseType {
+        DatabaseType::Redis
+    }
+
+    async fn connect(&self, config: &ConnectionConfig) -> Result<ConnectResultType, DbError> {
+        Ok(match connect_database(config).await? {
+            ConnectResult::Tables(tables) => ConnectResultType::Tables(tables),
+            ConnectResult::Databases(databases) => ConnectResultType::Databases(databases),
+        })
+    }
+
+    async fn disconnect(&self, _config: &ConnectionConfig) -> Result<(), DbError> {
+        // 每次操作独立连接，无需清理
+        Ok(())
+    }
+
+    async fn execute(&self, config: &ConnectionConfig, command: &str) -> Result<QueryResult, DbError> {
+        execute_query(config, command).await
+    }
+
+    async fn list_databases(&self, config: &ConnectionConfig) -> Result<Vec<String>, DbError> {
+        match connect_database(config).await? {
+            ConnectResult::Databases(databases) => Ok(databases),
+            ConnectResult::Tables(_) => Ok(Vec::new()),
+        }
+    }
+
+    async fn list_tables(&self, config: &ConnectionConfig, database: Option<&str>) -> Result<Vec<String>, DbError> {
+        get_tables_for_database(config, database.unwrap_or_default()).await
+    }
+
+    async fn describe_table(&self, _config: &ConnectionConfig, table: &str) -> Result<TableMeta, DbError> {
+        // 键没有列结构
+        Ok(TableMeta {
+            name: table.to_string(),
+            columns: Vec::new(),
+            primary_key: None,
+            row_count_estimate: None,
+        })
+    }
+
+    async fn get_primary_key(&self, _config: &ConnectionConfig, _table: &str) -> Result<Option<String>, DbError> {
+        Ok(None)
+    }
+
+    async fn get_foreign_keys(&self, _config: &ConnectionConfig) -> Result<Vec<ForeignKeyInfo>, DbError> {
+        Ok(Vec::new())
+    }
+
+    async fn get_triggers(&self, _config: &ConnectionConfig) -> Result<Vec<TriggerInfo>, DbError> {
+        Ok(Vec::new())
+    }
+
+    /// 包含空白或引号的键名使用双引号包裹（与控制台的参数解析一致）
+    fn quote_identifier(&self, name: &str) -> String {
+        if !name.is_empty() && !name.chars().any(|c| c.is_whitespace() || c == '"' || c == '\'') {
+            name.to_string()
+        } else {
+            format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""))
+        }
+    }
+
+    /// MULTI/EXEC 不支持回滚，不视为事务
+    fn supports_transactions(&self) -> bool {
+        false
+    }
+}
+
+/// MongoDB 驱动
+///
+/// 文档数据库，不使用 SQL：表列表为当前库的集合，查询为 JSON 格式的数据库命令
+/// （如 `{"find": "users", "filter": {"age": {"$gt": 18}}}`）。需启用 `mongodb` 特性。
+pub struct MongoDriver;
+
+#[async_trait]
+impl DatabaseDriver for MongoDriver {
+    fn db_type(&self) -> DatabaseType {
+        DatabaseType::MongoDB
+    }
+
+    async fn connect(&self, config: &ConnectionConfig) -> Result<ConnectResultType, DbError> {
+        Ok(match connect_database(config).await? {
+            ConnectResult::Tables(tables) => ConnectResultType::Tables(tables),
+            ConnectResult::Databases(databases) => ConnectResultType::Databases(databases),
+        })
+    }
+
+    async fn disconnect(&self, config: &ConnectionConfig) -> Result<(), DbError> {
+        POOL_MANAGER.remove_pool(config).await;
+        Ok(())
+    }
+
+    async fn execute(&self, config: &ConnectionConfig, command: &str) -> Result<QueryResult, DbError> {
+        execute_query(config, command).await
+    }
+
+    async fn list_databases(&self, config: &ConnectionConfig) -> Result<Vec<String>, DbError> {
+        match connect_database(config).await? {
+            ConnectResult::Databases(databases) => Ok(databases),
+            ConnectResult::Tables(_) => Ok(Vec::new()),
+        }
+    }
+
+    async fn list_tables(&self, config: &ConnectionConfig, database: Option<&str>) -> Result<Vec<String>, DbError> {
+        get_tables_for_database(config, database.unwrap_or_default()).await
+    }
+
+    /// 字段由抽样文档推断
+    async fn describe_table(&self, config: &ConnectionConfig, table: &str) -> Result<TableMeta, DbError> {
+        describe_table_with_columns(config, table).await
+    }
+
+    async fn get_primary_key(&self, _config: &ConnectionConfig, _table: &str) -> Result<Option<String>, DbError> {
+        Ok(Some("_id".to_string()))
+    }
+
+    async fn get_foreign_keys(&self, _config: &ConnectionConfig) -> Result<Vec<ForeignKeyInfo>, DbError> {
+        Ok(Vec::new())
+    }
+
+    async fn get_triggers(&self, _config: &ConnectionConfig) -> Result<Vec<TriggerInfo>, DbError> {
+        Ok(Vec::new())
+    }
+
+    /// 集合名引用为 JSON 字符串（用于拼接命令文档）
+    fn quote_identifier(&self, name: &str) -> String {
+        serde_json::Value::String(name.to_string()).to_string()
+    }
+
+    /// 多文档事务需要副本集，浏览场景不使用
+    fn supports_transactions(&self) -> bool {
+        false
+    }
+}
+
+/// ClickHouse 驱动
+///
+/// 列式分析数据库，通过 HTTP 接口访问，元数据来自 `system.tables`/`system.columns`。
+/// SELECT 结果按页流式读取，不会一次性加载整个结果集。需启用 `clickhouse` 特性。
+pub struct ClickHouseDriver;
+
+#[async_trait]
+impl DatabaseDriver for ClickHouseDriver {
+    fn db_type(&self) -> DatabaseType {
+        DatabaseType::ClickHouse
+    }
+
+    async fn connect(&self, config: &ConnectionConfig) -> Result<ConnectResultType, DbError> {
+        Ok(match connect_database(config).await? {
+            ConnectResult::Tables(tables) => ConnectResultType::Tables(tables),
+            ConnectResult::Databases(databases) => ConnectResultType::Databases(databases),
+        })
+    }
+
+    async fn disconnect(&self, _config: &ConnectionConfig) -> Result<(), DbError> {
+        // HTTP 接口无状态，无需清理
+        Ok(())
+    }
+
+    async fn execute(&self, config: &ConnectionConfig, sql: &str) -> Result<QueryResult, DbError> {
+        execute_query(config, sql).await
+    }
+
+    async fn list_databases(&self, config: &ConnectionConfig) -> Result<Vec<String>, DbError> {
+        match connect_database(config).await? {
+            ConnectResult::Databases(databases) => Ok(databases),
+            ConnectResult::Tables(_) => Ok(Vec::new()),
+        }
+    }
+
+    async fn list_tables(&self, config: &ConnectionConfig, database: Option<&str>) -> Result<Vec<String>, DbError> {
+        get_tables_for_database(config, database.unwrap_or_default()).await
+    }
+
+    async fn describe_table(&self, config: &ConnectionConfig, table: &str) -> Result<TableMeta, DbError> {
+        describe_table_with_columns(config, table).await
+    }
+
+    async fn get_primary_key(&self, config: &ConnectionConfig, table: &str) -> Result<Option<String>, DbError> {
+        get_primary_key_column(config, table).await
+    }
+
+    async fn get_foreign_keys(&self, _config: &ConnectionConfig) -> Result<Vec<ForeignKeyInfo>, DbError> {
+        Ok(Vec::new())
+    }
+
+    async fn get_triggers(&self, _config: &ConnectionConfig) -> Result<Vec<TriggerInfo>, DbError> {
+        Ok(Vec::new())
+    }
+
+    /// 使用反引号（ClickHouse 同时支持双引号，反引号与 MySQL 写法一致）
+    fn quote_identifier(&self, name: &str) -> String {
+        format!("`{}`", name.replace('\\', "\\\\").replace('`', "\\`"))
+    }
+
+    /// 反斜杠和单引号都需要转义
+    fn escape_string(&self, value: &str) -> String {
+        value.replace('\\', "\\\\").replace('\'', "\\'")
+    }
+
+    /// MergeTree 引擎不支持事务
+    fn supports_transactions(&self) -> bool {
+        false
+    }
+}
+
+/// 通过列信息构建表结构（各驱动共用）
+async fn describe_table_with_columns(config: &ConnectionConfig, table: &str) -> Result<TableMeta, DbError> {
+    let columns: Vec<ColumnMeta> = get_table_columns(config, table)
+        .await?
+        .into_iter()
+        .map(|c| ColumnMeta {
+            name: c.name,
+            data_type: c.data_type,
+            nullable: c.is_nullable,
+            is_primary_key: c.is_primary_key,
+            default_value: c.default_value,
+        })
+        .collect();
+    let primary_key = columns.iter().find(|c| c.is_primary_key).map(|c| c.name.clone());
+
+    Ok(TableMeta {
+        name: table.to_string(),
+        columns,
+        primary_key,
+        row_count_estimate: None,
+    })
+}
+
 /// 驱动注册表
 ///
 /// 用于管理和查找数据库驱动实例。
@@ -261,12 +712,90 @@ impl DriverCapabilities {
         batch_insert: true,
     };
 
+    /// DuckDB 的默认能力
+    pub const DUCKDB: Self = Self {
+        transactions: true,
+        stored_procedures: false,
+        triggers: false,
+        views: true,
+        foreign_keys: true,
+        user_management: false,
+        database_creation: false,
+        batch_insert: true,
+    };
+
+    /// Oracle 的默认能力
+    pub const ORACLE: Self = Self {
+        transactions: true,
+        stored_procedures: true,
+        triggers: true,
+        views: true,
+        foreign_keys: true,
+        user_management: true,
+        database_creation: false,
+        batch_insert: true,
+    };
+
+    /// ODBC 的默认能力（仅保证标准目录函数可用）
+    pub const ODBC: Self = Self {
+        transactions: true,
+        stored_procedures: false,
+        triggers: false,
+        views: true,
+        foreign_keys: true,
+        user_management: false,
+        database_creation: false,
+        batch_insert: false,
+    };
+
+    /// Redis 的默认能力（非关系型，仅支持键值浏览和命令）
+    pub const REDIS: Self = Self {
+        transactions: false,
+        stored_procedures: false,
+        triggers: false,
+        views: false,
+        foreign_keys: false,
+        user_management: false,
+        database_creation: false,
+        batch_insert: false,
+    };
+
+    /// MongoDB 的默认能力（文档型，仅支持视图浏览）
+    pub const MONGODB: Self = Self {
+        transactions: false,
+        stored_procedures: false,
+        triggers: false,
+        views: true,
+        foreign_keys: false,
+        user_management: false,
+        database_creation: false,
+        batch_insert: false,
+    };
+
+    /// ClickHouse 的默认能力（分析型，无事务和外键）
+    pub const CLICKHOUSE: Self = Self {
+        transactions: false,
+        stored_procedures: false,
+        triggers: false,
+        views: true,
+        foreign_keys: false,
+        user_management: true,
+        database_creation: true,
+        batch_insert: true,
+    };
+
     /// 根据数据库类型获取默认能力
     pub fn for_db_type(db_type: DatabaseType) -> Self {
         match db_type {
             DatabaseType::SQLite => Self::SQLITE,
             DatabaseType::PostgreSQL => Self::POSTGRESQL,
             DatabaseType::MySQL => Self::MYSQL,
+            DatabaseType::DuckDB => Self::DUCKDB,
+            DatabaseType::Oracle => Self::ORACLE,
+            DatabaseType::Odbc => Self::ODBC,
+            DatabaseType::Redis => Self::REDIS,
+            DatabaseType::MongoDB => Self::MONGODB,
+            DatabaseType::ClickHouse => Self::CLICKHOUSE,
         }
     }
 }
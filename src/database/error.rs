@@ -3,6 +3,19 @@
 use super::DatabaseType;
 use thiserror::Error;
 
+/// 连接已失效时驱动返回的错误信息片段（小写）
+const STALE_CONNECTION_PATTERNS: &[&str] = &[
+    "connection closed",
+    "connection reset",
+    "connection was closed",
+    "closed connection",
+    "broken pipe",
+    "unexpected eof",
+    "server has gone away",
+    "lost connection",
+    "error communicating with the server",
+];
+
 /// 数据库操作错误
 #[allow(dead_code)] // 公开 API，供外部使用
 #[derive(Error, Debug)]
@@ -33,6 +46,32 @@ impl DbError {
         }
     }
 
+    /// 是否为连接已失效的错误（连接池中的连接被服务器关闭、网络中断等），重新连接后可以重试
+    pub fn is_stale_connection(&self) -> bool {
+        let message = match self {
+            Self::Connection(message) | Self::Query(message) | Self::Pool(message) => message,
+            Self::ConnectionTyped { message, .. } | Self::QueryWithContext { message, .. } => message,
+        }
+        .to_lowercase();
+        STALE_CONNECTION_PATTERNS.iter().any(|pattern| message.contains(pattern))
+    }
+
+    /// 是否为获取连接时的错误（语句还没有发送到服务器）
+    pub fn is_acquire_error(&self) -> bool {
+        matches!(self, Self::Connection(_) | Self::ConnectionTyped { .. } | Self::Pool(_))
+    }
+
+    /// PostgreSQL 查询错误
+    ///
+    /// 驱动的错误信息不含出错位置，服务器报告了位置时附加在末尾（从 1 开始的字符位置）
+    pub fn pg_query(error: tokio_postgres::Error) -> Self {
+        use tokio_postgres::error::ErrorPosition;
+        match error.as_db_error().and_then(|e| e.position()) {
+            Some(ErrorPosition::Original(position)) => Self::Query(format!("{} (位置: {})", error, position)),
+            _ => Self::Query(error.to_string()),
+        }
+    }
+
     /// 创建带上下文的查询错误
     pub fn query_with_context(
         db_type: &DatabaseType,
@@ -0,0 +1,124 @@
+//! 表维护操作
+//!
+//! 将 VACUUM、ANALYZE、OPTIMIZE、REINDEX 映射为各数据库的命令：
+//! - SQLite：VACUUM（仅整库）、ANALYZE、REINDEX
+//! - PostgreSQL：VACUUM、ANALYZE、REINDEX
+//! - MySQL：OPTIMIZE TABLE、ANALYZE TABLE
+
+use super::{DatabaseType, QueryResult};
+
+/// 维护操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MaintenanceOp {
+    /// 回收空间
+    Vacuum,
+    /// 更新统计信息
+    Analyze,
+    /// 整理表碎片（MySQL）
+    Optimize,
+    /// 重建索引
+    Reindex,
+}
+
+impl MaintenanceOp {
+    /// 操作名称
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            MaintenanceOp::Vacuum => "VACUUM",
+            MaintenanceOp::Analyze => "ANALYZE",
+            MaintenanceOp::Optimize => "OPTIMIZE",
+            MaintenanceOp::Reindex => "REINDEX",
+        }
+    }
+
+    /// 操作说明
+    pub fn description(&self) -> &'static str {
+        match self {
+            MaintenanceOp::Vacuum => "回收空间",
+            MaintenanceOp::Analyze => "更新统计信息",
+            MaintenanceOp::Optimize => "整理碎片",
+            MaintenanceOp::Reindex => "重建索引",
+        }
+    }
+
+    /// 数据库支持的维护操作，`per_table` 为 true 时返回表级操作
+    pub fn available(db_type: DatabaseType, per_table: bool) -> &'static [MaintenanceOp] {
+        match db_type {
+            // SQLite 的 VACUUM 只能作用于整个数据库
+            DatabaseType::SQLite if per_table => &[MaintenanceOp::Analyze, MaintenanceOp::Reindex],
+            DatabaseType::SQLite | DatabaseType::PostgreSQL => {
+                &[MaintenanceOp::Vacuum, MaintenanceOp::Analyze, MaintenanceOp::Reindex]
+            }
+            DatabaseType::MySQL => &[MaintenanceOp::Optimize, MaintenanceOp::Analyze],
+            _ => &[],
+        }
+    }
+
+    /// 生成表级维护 SQL，`schema` 为调用方确认过的 schema（如 SQLite 附加数据库的别名）
+    pub fn table_sql(&self, db_type: DatabaseType, schema: Option<&str>, table: &str) -> Result<String, String> {
+        if !Self::available(db_type, true).contains(self) {
+            return Err(self.unsupported(db_type));
+        }
+        let quoted = db_type.quote_table(schema, table);
+        let sql = match (db_type, self) {
+            (DatabaseType::SQLite, _) => format!("{} {};", self.display_name(), quoted),
+            (DatabaseType::PostgreSQL, MaintenanceOp::Reindex) => format!("REINDEX TABLE {};", quoted),
+            (DatabaseType::PostgreSQL, _) => format!("{} {};", self.display_name(), quoted),
+            _ => format!("{} TABLE {};", self.display_name(), quoted),
+        };
+        Ok(sql)
+    }
+
+    /// 生成整库维护 SQL
+    ///
+    /// `database` 为当前数据库（SQLite 为 schema 名）；MySQL 没有整库命令，对 `tables` 逐一列出
+    pub fn database_sql(&self, db_type: DatabaseType, database: &str, tables: &[String]) -> Result<String, String> {
+        if !Self::available(db_type, false).contains(self) {
+            return Err(self.unsupported(db_type));
+        }
+        let sql = match (db_type, self) {
+            // 不带参数的 REINDEX 作用于所有附加的数据库
+            (DatabaseType::SQLite, MaintenanceOp::Reindex) => "REINDEX;".to_string(),
+            (DatabaseType::SQLite, _) => format!("{} {};", self.display_name(), db_type.quote_identifier(database)),
+            (DatabaseType::PostgreSQL, MaintenanceOp::Reindex) => {
+                format!("REINDEX DATABASE {};", db_type.quote_identifier(database))
+            }
+            (DatabaseType::PostgreSQL, _) => format!("{};", self.display_name()),
+            _ => {
+                if tables.is_empty() {
+                    return Err("当前数据库没有表".to_string());
+                }
+                let list: Vec<String> = tables.iter().map(|t| db_type.quote_table(Some(database), t)).collect();
+                format!("{} TABLE {};", self.display_name(), list.join(", "))
+            }
+        };
+        Ok(sql)
+    }
+
+    fn unsupported(&self, db_type: DatabaseType) -> String {
+        format!("{} 不支持 {} 操作", db_type.display_name(), self.display_name())
+    }
+}
+
+/// 从维护命令的结果中提取错误信息
+///
+/// MySQL 的 OPTIMIZE/ANALYZE TABLE 执行失败时不报错，而是在结果集中返回
+/// `Msg_type = error` 的行
+pub fn maintenance_errors(result: &QueryResult) -> Vec<String> {
+    let column = |name: &str| result.columns.iter().position(|c| c.eq_ignore_ascii_case(name));
+    let (Some(table), Some(msg_type), Some(msg_text)) = (column("Table"), column("Msg_type"), column("Msg_text")) else {
+        return Vec::new();
+    };
+    result
+        .rows
+        .iter()
+        .filter(|row| row.get(msg_type).is_some_and(|t| t.eq_ignore_ascii_case("error")))
+        .map(|row| {
+            format!(
+                "{}: {}",
+                row.get(table).map(String::as_str).unwrap_or_default(),
+                row.get(msg_text).map(String::as_str).unwrap_or_default()
+            )
+        })
+        .collect()
+}
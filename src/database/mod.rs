@@ -10,9 +10,12 @@ mod config;
 mod connection;
 mod driver;
 mod error;
+mod maintenance;
 mod pool;
+mod pool_cache;
 mod query;
 pub mod ssh_tunnel;
+mod statement_cache;
 mod types;
 
 // ============================================================================
@@ -25,8 +28,12 @@ pub use types::{DatabaseType, MySqlSslMode, PostgresSslMode, QueryResult};
 // 错误
 pub use error::DbError;
 
+// 维护操作
+pub use maintenance::{maintenance_errors, MaintenanceOp};
+
 // 配置
-pub use config::ConnectionConfig;
+#[allow(unused_imports)] // PoolSettings 公开 API
+pub use config::{ConnectionConfig, PoolSettings, SqliteAttachment};
 
 // 连接管理
 #[allow(unused_imports)] // Connection 公开 API
@@ -34,14 +41,20 @@ pub use connection::{Connection, ConnectionManager};
 
 // 连接池
 #[allow(unused_imports)] // PoolManager 公开 API
-pub use pool::{PoolManager, POOL_MANAGER};
+pub use pool::{PoolManager, PoolStats, POOL_MANAGER};
+#[allow(unused_imports)] // 公开 API
+pub use pool_cache::PoolCache;
+#[allow(unused_imports)] // 公开 API
+pub use statement_cache::{invalidates_statements, StatementCache};
 
 // 查询
 #[allow(unused_imports)] // get_primary_key_column 预留供将来使用
 pub use query::{
-    connect_database, execute_query, get_foreign_keys, get_primary_key_column, get_routines,
-    get_table_columns, get_tables_for_database, get_triggers, ColumnInfo, ConnectResult,
-    ForeignKeyInfo, RoutineInfo, RoutineType, TriggerInfo,
+    change_sqlite_key, connect_database, documents_to_result, execute_batch, execute_query, execute_query_page, execute_read_only, execute_query_streaming, get_all_sqlite_tables,
+    get_foreign_keys, get_grants, get_primary_key_column, get_redis_key_value, get_routines, create_table_from_columns, get_table_columns, get_table_ddl, get_users,
+    get_sequences, get_server_utc_offset, ping, ping_statement, retries_stale_connection, get_sessions, get_table_sizes, get_tables_for_database, get_tables_page, get_triggers, get_views, paged_sql, save_sqlite_to_file, split_command_line, split_statements, supports_batch_transaction, supports_paging, supports_streaming, supports_validation, validate_sql, ColumnInfo,
+    profile_query, supports_profiling, QueryProfile,
+    ConnectResult, ForeignKeyInfo, GrantInfo, RedisKeyValue, RoutineInfo, RoutineType, SequenceInfo, SessionInfo, TableSizeInfo, TriggerInfo, ViewInfo,
 };
 
 // SSH 隧道
@@ -51,6 +64,6 @@ pub use ssh_tunnel::{SshAuthMethod, SshTunnelConfig};
 // 驱动抽象
 #[allow(unused_imports)] // 驱动抽象 API，供未来扩展使用
 pub use driver::{
-    ColumnMeta, ConnectResultType, DatabaseDriver, DriverCapabilities, DriverInfo, DriverRegistry,
-    TableMeta,
+    ColumnMeta, ConnectResultType, DatabaseDriver, DriverCapabilities, DriverInfo, DriverRegistry, ClickHouseDriver, DuckDbDriver, OdbcDriver, MongoDriver, OracleDriver,
+    RedisDriver, TableMeta,
 };
@@ -1,32 +1,132 @@
 //! 连接池管理
 
-use super::config::ConnectionConfig;
+use super::config::{ConnectionConfig, PoolSettings};
 use super::error::DbError;
+use super::pool_cache::PoolCache;
+use super::statement_cache::StatementCache;
 use super::types::{DatabaseType, MySqlSslMode, PostgresSslMode};
 use crate::core::constants;
 use std::collections::HashMap;
-use std::sync::Arc;
+use std::sync::atomic::Ordering;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
 
+/// 缓存的 MySQL 连接池及创建时使用的配置
+struct MySqlPool {
+    pool: mysql_async::Pool,
+    settings: PoolSettings,
+}
+
+impl MySqlPool {
+    /// 没有被取出的连接，也没有等待获取连接的请求
+    fn is_idle(&self) -> bool {
+        let metrics = self.pool.metrics();
+        metrics.connection_count.load(Ordering::Relaxed) == metrics.connections_in_pool.load(Ordering::Relaxed)
+            && metrics.active_wait_requests.load(Ordering::Relaxed) == 0
+    }
+}
+
+/// 缓存的 PostgreSQL 预处理语句及准备它的客户端（语句只能在该客户端上使用）
+#[derive(Clone)]
+struct PgStatement {
+    client: std::sync::Weak<tokio_postgres::Client>,
+    statement: tokio_postgres::Statement,
+}
+
+/// 连接池使用情况（用于诊断面板）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PoolStats {
+    /// 连接池键（类型、主机、端口、用户名、数据库）
+    pub key: String,
+    pub db_type: DatabaseType,
+    /// 已建立的连接数
+    pub connections: usize,
+    /// 空闲连接数
+    pub idle: usize,
+    /// 等待获取连接的请求数
+    pub waiting: usize,
+    /// 最大连接数
+    pub max_connections: usize,
+    /// 距最近一次使用的时间（秒）
+    pub idle_secs: u64,
+}
+
+impl PoolStats {
+    /// 正在使用的连接数
+    pub fn active(&self) -> usize {
+        self.connections.saturating_sub(self.idle)
+    }
+}
+
 /// 全局连接池管理器
 ///
 /// 使用 lazy_static 模式实现单例，避免每次查询都创建新连接
 pub struct PoolManager {
-    /// MySQL 连接池缓存
-    mysql_pools: RwLock<HashMap<String, mysql_async::Pool>>,
-    /// PostgreSQL 客户端缓存（tokio-postgres 使用长连接）
-    pg_clients: RwLock<HashMap<String, Arc<tokio_postgres::Client>>>,
+    /// MySQL 连接池缓存（已满时淘汰最久未使用的连接池）
+    mysql_pools: RwLock<PoolCache<MySqlPool>>,
+    /// PostgreSQL 客户端缓存（tokio-postgres 使用长连接，已满时淘汰最久未使用的连接）
+    pg_clients: RwLock<PoolCache<Arc<tokio_postgres::Client>>>,
+    /// PostgreSQL 预处理语句缓存，按 (连接池键, SQL) 索引
+    ///
+    /// MySQL 的预处理语句属于单个连接，由驱动在每个连接内按 SQL 缓存
+    pg_statements: Mutex<StatementCache<PgStatement>>,
+    /// SQLite 内存数据库保活连接（草稿本），最后一个连接关闭时内存数据库会被销毁
+    sqlite_memory: Mutex<HashMap<String, rusqlite::Connection>>,
+    /// DuckDB 数据库句柄缓存（同一文件只能被一个实例打开，内存库也需要保活）
+    #[cfg(feature = "duckdb")]
+    duckdb: Mutex<HashMap<String, duckdb::Connection>>,
+    /// MongoDB 客户端缓存（客户端内部自带连接池，同一服务器复用）
+    #[cfg(feature = "mongodb")]
+    mongo_clients: RwLock<HashMap<String, mongodb::Client>>,
 }
 
 impl PoolManager {
     /// 创建新的连接池管理器
     pub fn new() -> Self {
         Self {
-            mysql_pools: RwLock::new(HashMap::new()),
-            pg_clients: RwLock::new(HashMap::new()),
+            mysql_pools: RwLock::new(PoolCache::new(constants::database::pool::MAX_MYSQL_POOLS)),
+            pg_clients: RwLock::new(PoolCache::new(constants::database::pool::MAX_POSTGRES_CLIENTS)),
+            pg_statements: Mutex::new(StatementCache::new(constants::database::pool::MAX_CACHED_STATEMENTS)),
+            sqlite_memory: Mutex::new(HashMap::new()),
+            #[cfg(feature = "duckdb")]
+            duckdb: Mutex::new(HashMap::new()),
+            #[cfg(feature = "mongodb")]
+            mongo_clients: RwLock::new(HashMap::new()),
         }
     }
 
+    /// 确保草稿本的内存数据库保持存活
+    pub fn retain_sqlite_memory(&self, config: &ConnectionConfig) -> Result<(), DbError> {
+        let key = config.pool_key();
+        let mut conns = self.sqlite_memory.lock().unwrap_or_else(|e| e.into_inner());
+        if let std::collections::hash_map::Entry::Vacant(entry) = conns.entry(key) {
+            let conn = rusqlite::Connection::open(config.sqlite_open_path())
+                .map_err(|e| DbError::Connection(format!("创建内存数据库失败: {}", e)))?;
+            entry.insert(conn);
+        }
+        Ok(())
+    }
+
+    /// 获取 DuckDB 连接（首次打开数据库后缓存句柄，之后克隆出共享同一数据库的新连接）
+    #[cfg(feature = "duckdb")]
+    pub fn get_duckdb_connection(&self, config: &ConnectionConfig) -> Result<duckdb::Connection, DbError> {
+        let key = config.pool_key();
+        let mut conns = self.duckdb.lock().unwrap_or_else(|e| e.into_inner());
+        if let std::collections::hash_map::Entry::Vacant(entry) = conns.entry(key.clone()) {
+            let conn = if config.is_duckdb_in_memory() {
+                duckdb::Connection::open_in_memory()
+            } else {
+                duckdb::Connection::open(&config.database)
+            }
+            .map_err(|e| DbError::Connection(format!("DuckDB 连接失败: {}", e)))?;
+            entry.insert(conn);
+        }
+        conns[&key]
+            .try_clone()
+            .map_err(|e| DbError::Connection(format!("DuckDB 连接失败: {}", e)))
+    }
+
     /// 获取或创建 MySQL 连接池
     pub async fn get_mysql_pool(
         &self,
@@ -37,10 +137,11 @@ impl PoolManager {
         // 先尝试读取缓存并验证连接池健康
         {
             let pools = self.mysql_pools.read().await;
-            if let Some(pool) = pools.get(&key) {
+            // 连接池配置修改后重新创建
+            if let Some(cached) = pools.get(&key).filter(|cached| cached.settings == config.pool) {
                 // 尝试获取连接以验证连接池是否健康
-                match pool.get_conn().await {
-                    Ok(_) => return Ok(pool.clone()),
+                match cached.pool.get_conn().await {
+                    Ok(_) => return Ok(cached.pool.clone()),
                     Err(_) => {
                         // 连接池不健康，稍后会重新创建
                     }
@@ -48,27 +149,27 @@ impl PoolManager {
             }
         }
 
-        // 移除失效的连接池
+        // 移除失效或配置已修改的连接池
         {
             let mut pools = self.mysql_pools.write().await;
-            pools.remove(&key);
+            if let Some(stale) = pools.remove(&key) {
+                stale.pool.disconnect().await.ok();
+            }
         }
 
-        // 创建新连接池，使用常量配置连接池参数
+        // 创建新连接池，未单独配置的参数使用常量
         let pool_opts = mysql_async::PoolOpts::default()
             .with_constraints(
                 mysql_async::PoolConstraints::new(
-                    constants::database::pool::MYSQL_POOL_MIN_CONNECTIONS,
-                    constants::database::pool::MYSQL_POOL_MAX_CONNECTIONS,
+                    config.pool.min_connections(),
+                    config.pool.max_connections(),
                 )
                 .expect("连接池约束无效"),
             )
             // 空闲连接超时：超过此时间未使用的连接将被关闭
-            .with_inactive_connection_ttl(std::time::Duration::from_secs(
-                constants::database::pool::MYSQL_IDLE_TIMEOUT_SECS,
-            ))
+            .with_inactive_connection_ttl(Duration::from_secs(config.pool.idle_timeout_secs()))
             // 连接最大生存时间：无论是否活跃，超过此时间的连接将被回收
-            .with_abs_conn_ttl(Some(std::time::Duration::from_secs(
+            .with_abs_conn_ttl(Some(Duration::from_secs(
                 constants::database::pool::MYSQL_MAX_LIFETIME_SECS,
             )));
 
@@ -76,7 +177,8 @@ impl PoolManager {
             mysql_async::Opts::from_url(config.connection_string().as_str())
                 .map_err(|e| DbError::Connection(format!("MySQL URL 解析失败: {}", e)))?,
         )
-        .pool_opts(pool_opts);
+        .pool_opts(pool_opts)
+        .stmt_cache_size(constants::database::pool::MYSQL_STMT_CACHE_SIZE);
 
         // 配置 SSL 选项
         opts = Self::configure_mysql_ssl(opts, config)?;
@@ -89,24 +191,36 @@ impl PoolManager {
             .await
             .map_err(|e| DbError::Connection(format!("MySQL 连接失败: {}", e)))?;
 
-        // 存入缓存（限制缓存数量，防止内存溢出）
-        {
-            let mut pools = self.mysql_pools.write().await;
-
-            // 如果缓存已满，移除最早的连接池
-            if pools.len() >= constants::database::pool::MAX_MYSQL_POOLS {
-                // 移除第一个键（HashMap 无序，但这里只是简单清理）
-                if let Some(oldest_key) = pools.keys().next().cloned() {
-                    pools.remove(&oldest_key);
-                }
-            }
-
-            pools.insert(key, pool.clone());
+        // 存入缓存（限制缓存数量，已满时断开最久未使用的连接池）
+        let evicted = self.mysql_pools.write().await.insert(
+            key,
+            MySqlPool {
+                pool: pool.clone(),
+                settings: config.pool,
+            },
+        );
+        if let Some((evicted_key, evicted)) = evicted {
+            tracing::info!(connection = %evicted_key, "连接池缓存已满，断开最久未使用的连接池");
+            evicted.pool.disconnect().await.ok();
         }
 
         Ok(pool)
     }
 
+    /// 从连接池获取 MySQL 连接，超过获取超时（连接池已满或服务器无响应）时返回错误
+    pub async fn get_mysql_conn(&self, config: &ConnectionConfig) -> Result<mysql_async::Conn, DbError> {
+        let timeout_secs = config.acquire_timeout_secs();
+        let acquire = async {
+            let pool = self.get_mysql_pool(config).await?;
+            pool.get_conn()
+                .await
+                .map_err(|e| DbError::Connection(format!("MySQL 获取连接失败: {}", e)))
+        };
+        tokio::time::timeout(Duration::from_secs(timeout_secs), acquire)
+            .await
+            .map_err(|_| DbError::Pool(format!("获取连接超时 ({}秒)，连接池可能已满", timeout_secs)))?
+    }
+
     /// 配置 MySQL SSL 选项
     fn configure_mysql_ssl(
         opts: mysql_async::OptsBuilder,
@@ -175,6 +289,28 @@ impl PoolManager {
         }
     }
 
+    /// 获取或创建 MongoDB 客户端
+    #[cfg(feature = "mongodb")]
+    pub async fn get_mongo_client(&self, config: &ConnectionConfig) -> Result<mongodb::Client, DbError> {
+        let key = config.pool_key();
+        if let Some(client) = self.mongo_clients.read().await.get(&key) {
+            return Ok(client.clone());
+        }
+
+        let mut options = mongodb::options::ClientOptions::parse(config.mongodb_uri())
+            .await
+            .map_err(|e| DbError::Connection(format!("MongoDB 连接参数无效: {}", e)))?;
+        let timeout = std::time::Duration::from_secs(config.connect_timeout_secs());
+        options.connect_timeout = Some(timeout);
+        options.server_selection_timeout = Some(timeout);
+        options.app_name = Some("gridix".to_string());
+        let client = mongodb::Client::with_options(options)
+            .map_err(|e| DbError::Connection(format!("MongoDB 连接失败: {}", e)))?;
+
+        self.mongo_clients.write().await.insert(key, client.clone());
+        Ok(client)
+    }
+
     /// 获取或创建 PostgreSQL 客户端
     pub async fn get_pg_client(
         &self,
@@ -200,23 +336,72 @@ impl PoolManager {
         }
 
         // 创建新连接（根据 SSL 模式选择连接方式）
-        let client = Self::connect_pg_with_ssl(config).await?;
+        let timeout_secs = config.acquire_timeout_secs();
+        let client = tokio::time::timeout(Duration::from_secs(timeout_secs), Self::connect_pg_with_ssl(config))
+            .await
+            .map_err(|_| DbError::Pool(format!("获取连接超时 ({}秒)", timeout_secs)))??;
         let client = Arc::new(client);
 
-        // 存入缓存（限制缓存数量，防止内存溢出）
-        {
-            let mut clients = self.pg_clients.write().await;
+        // 存入缓存（限制缓存数量，已满时断开最久未使用的连接）
+        if let Some((evicted_key, _)) = self.pg_clients.write().await.insert(key, client.clone()) {
+            tracing::info!(connection = %evicted_key, "连接缓存已满，断开最久未使用的 PostgreSQL 连接");
+        }
 
-            // 如果缓存已满，移除最早的客户端
-            if clients.len() >= constants::database::pool::MAX_POSTGRES_CLIENTS
-                && let Some(oldest_key) = clients.keys().next().cloned() {
-                    clients.remove(&oldest_key);
-                }
+        Ok(client)
+    }
 
-            clients.insert(key, client.clone());
+    /// 获取 PostgreSQL 预处理语句：复用在同一客户端上准备过的语句，否则准备并缓存
+    ///
+    /// 返回语句及是否来自缓存
+    pub async fn prepare_pg(
+        &self,
+        config: &ConnectionConfig,
+        client: &Arc<tokio_postgres::Client>,
+        sql: &str,
+    ) -> Result<(tokio_postgres::Statement, bool), DbError> {
+        let key = config.pool_key();
+        let cached = self.pg_statements.lock().unwrap_or_else(|e| e.into_inner()).get(&key, sql);
+        // 重新连接后旧客户端上准备的语句不再可用
+        if let Some(cached) = cached.filter(|cached| std::ptr::eq(cached.client.as_ptr(), Arc::as_ptr(client))) {
+            return Ok((cached.statement, true));
         }
 
-        Ok(client)
+        let statement = client.prepare(sql).await.map_err(DbError::pg_query)?;
+        self.pg_statements.lock().unwrap_or_else(|e| e.into_inner()).insert(
+            &key,
+            sql,
+            PgStatement {
+                client: Arc::downgrade(client),
+                statement: statement.clone(),
+            },
+        );
+        Ok((statement, false))
+    }
+
+    /// 丢弃缓存的一条 PostgreSQL 预处理语句
+    pub fn discard_pg_statement(&self, config: &ConnectionConfig, sql: &str) {
+        self.pg_statements
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&config.pool_key(), sql);
+    }
+
+    /// 使连接池缓存的预处理语句失效（执行修改表结构的语句后调用）
+    pub fn invalidate_statements(&self, config: &ConnectionConfig) {
+        let removed = self
+            .pg_statements
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .invalidate(&config.pool_key());
+        if removed > 0 {
+            tracing::debug!(connection = %config.name, removed, "表结构已修改，丢弃缓存的预处理语句");
+        }
+    }
+
+    /// 缓存的 PostgreSQL 预处理语句数量
+    #[allow(dead_code)] // 公开 API
+    pub fn cached_statements(&self) -> usize {
+        self.pg_statements.lock().unwrap_or_else(|e| e.into_inner()).len()
     }
 
     /// 根据 SSL 模式连接 PostgreSQL
@@ -269,19 +454,15 @@ impl PoolManager {
         config: &ConnectionConfig,
         accept_invalid_certs: bool,
     ) -> Result<tokio_postgres::Client, DbError> {
-        use native_tls::TlsConnector as NativeTlsConnector;
-        use postgres_native_tls::MakeTlsConnector;
+        use rustls::pki_types::pem::PemObject;
+        use rustls::pki_types::{CertificateDer, PrivateKeyDer};
         use std::path::Path;
+        use tokio_postgres_rustls::MakeRustlsConnect;
 
-        let mut builder = NativeTlsConnector::builder();
+        let provider = Arc::new(rustls::crypto::ring::default_provider());
 
-        // 配置证书验证
-        if accept_invalid_certs {
-            builder.danger_accept_invalid_certs(true);
-            builder.danger_accept_invalid_hostnames(true);
-        }
-
-        // 如果指定了 CA 证书
+        // 如果指定了 CA 证书则只信任它，否则使用内置的公共根证书
+        let mut roots = rustls::RootCertStore::empty();
         if !config.ssl_ca_cert.is_empty() {
             let ca_path = Path::new(&config.ssl_ca_cert);
             if !ca_path.exists() {
@@ -291,27 +472,51 @@ impl PoolManager {
                 )));
             }
 
-            let ca_data = std::fs::read(&config.ssl_ca_cert).map_err(|e| {
-                DbError::Connection(format!("读取 CA 证书失败: {}", e))
-            })?;
-
-            let cert = native_tls::Certificate::from_pem(&ca_data).map_err(|e| {
-                DbError::Connection(format!("解析 CA 证书失败: {}", e))
-            })?;
-
-            builder.add_root_certificate(cert);
+            let certs = CertificateDer::pem_file_iter(ca_path)
+                .and_then(|iter| iter.collect::<Result<Vec<_>, _>>())
+                .map_err(|e| DbError::Connection(format!("读取 CA 证书失败: {}", e)))?;
+            for cert in certs {
+                roots.add(cert).map_err(|e| {
+                    DbError::Connection(format!("解析 CA 证书失败: {}", e))
+                })?;
+            }
+        } else {
+            roots.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
         }
 
-        // 验证主机名（仅 VerifyFull 模式）
-        if config.postgres_ssl_mode != PostgresSslMode::VerifyFull {
-            builder.danger_accept_invalid_hostnames(true);
-        }
+        // 配置证书验证：VerifyFull 完整校验，VerifyCa 跳过主机名，其余模式不校验证书
+        let builder = rustls::ClientConfig::builder_with_provider(provider.clone())
+            .with_safe_default_protocol_versions()
+            .map_err(|e| DbError::Connection(format!("TLS 连接器构建失败: {}", e)))?;
+        let builder = if !accept_invalid_certs && config.postgres_ssl_mode == PostgresSslMode::VerifyFull {
+            builder.with_root_certificates(roots)
+        } else {
+            let verifier = PgCertVerifier::new(roots, provider, !accept_invalid_certs)?;
+            builder.dangerous().with_custom_certificate_verifier(Arc::new(verifier))
+        };
+
+        // 如果指定了客户端证书（双向 TLS）
+        let tls_config = if !config.ssl_client_cert.is_empty() || !config.ssl_client_key.is_empty() {
+            if config.ssl_client_cert.is_empty() || config.ssl_client_key.is_empty() {
+                return Err(DbError::Connection(
+                    "客户端证书和私钥必须同时指定".to_string(),
+                ));
+            }
 
-        let connector = builder.build().map_err(|e| {
-            DbError::Connection(format!("TLS 连接器构建失败: {}", e))
-        })?;
+            let cert_chain = CertificateDer::pem_file_iter(&config.ssl_client_cert)
+                .and_then(|iter| iter.collect::<Result<Vec<_>, _>>())
+                .map_err(|e| DbError::Connection(format!("读取客户端证书失败: {}", e)))?;
+            let key = PrivateKeyDer::from_pem_file(&config.ssl_client_key)
+                .map_err(|e| DbError::Connection(format!("读取客户端私钥失败: {}", e)))?;
 
-        let tls = MakeTlsConnector::new(connector);
+            builder.with_client_auth_cert(cert_chain, key).map_err(|e| {
+                DbError::Connection(format!("解析客户端证书失败: {}", e))
+            })?
+        } else {
+            builder.with_no_client_auth()
+        };
+
+        let tls = MakeRustlsConnect::new(tls_config);
 
         let (client, conn) = tokio_postgres::connect(&config.connection_string(), tls)
             .await
@@ -342,32 +547,118 @@ impl PoolManager {
         match config.db_type {
             DatabaseType::MySQL => {
                 let mut pools = self.mysql_pools.write().await;
-                if let Some(pool) = pools.remove(&key) {
+                if let Some(cached) = pools.remove(&key) {
                     // 断开连接池
-                    pool.disconnect().await.ok();
+                    cached.pool.disconnect().await.ok();
                 }
             }
             DatabaseType::PostgreSQL => {
                 let mut clients = self.pg_clients.write().await;
                 clients.remove(&key);
+                self.invalidate_statements(config);
             }
             DatabaseType::SQLite => {
-                // SQLite 不需要连接池
+                // SQLite 不需要连接池，草稿本释放保活连接（内存数据随之丢弃）
+                self.sqlite_memory
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .remove(&key);
+            }
+            DatabaseType::Oracle | DatabaseType::Odbc | DatabaseType::Redis | DatabaseType::ClickHouse => {
+                // 每次操作独立连接，无需清理
+            }
+            DatabaseType::MongoDB => {
+                #[cfg(feature = "mongodb")]
+                if let Some(client) = self.mongo_clients.write().await.remove(&key) {
+                    client.shutdown().await;
+                }
+            }
+            DatabaseType::DuckDB => {
+                #[cfg(feature = "duckdb")]
+                self.duckdb
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .remove(&key);
             }
         }
     }
 
+    /// 当前各连接池的使用情况（按键排序）；正在被修改的缓存跳过，下次读取时再显示
+    pub fn stats(&self) -> Vec<PoolStats> {
+        let mut stats = Vec::new();
+        if let Ok(pools) = self.mysql_pools.try_read() {
+            stats.extend(pools.iter().map(|(key, cached, idle)| {
+                let metrics = cached.pool.metrics();
+                PoolStats {
+                    key: key.clone(),
+                    db_type: DatabaseType::MySQL,
+                    connections: metrics.connection_count.load(Ordering::Relaxed),
+                    idle: metrics.connections_in_pool.load(Ordering::Relaxed),
+                    waiting: metrics.active_wait_requests.load(Ordering::Relaxed),
+                    max_connections: cached.settings.max_connections(),
+                    idle_secs: idle.as_secs(),
+                }
+            }));
+        }
+        if let Ok(clients) = self.pg_clients.try_read() {
+            // 除缓存外没有其他引用时连接空闲
+            stats.extend(clients.iter().map(|(key, client, idle)| {
+                let connections = usize::from(!client.is_closed());
+                PoolStats {
+                    key: key.clone(),
+                    db_type: DatabaseType::PostgreSQL,
+                    connections,
+                    idle: if Arc::strong_count(client) == 1 { connections } else { 0 },
+                    waiting: 0,
+                    max_connections: 1,
+                    idle_secs: idle.as_secs(),
+                }
+            }));
+        }
+        stats.sort_by(|a, b| a.key.cmp(&b.key));
+        stats
+    }
+
+    /// 断开空闲至少 `ttl` 的 MySQL/PostgreSQL 连接池，返回断开的数量；有查询正在使用的连接池保留
+    ///
+    /// 下次查询时会自动重新建立连接
+    pub async fn close_idle(&self, ttl: Duration) -> usize {
+        let now = Instant::now();
+        let mysql = self.mysql_pools.write().await.remove_idle(ttl, now, MySqlPool::is_idle);
+        // 除缓存外没有其他引用时连接未被使用
+        let pg = self.pg_clients.write().await.remove_idle(ttl, now, |client| Arc::strong_count(client) == 1);
+        for key in mysql.iter().map(|(key, _)| key).chain(pg.iter().map(|(key, _)| key)) {
+            tracing::info!(connection = %key, "断开空闲连接池");
+        }
+        let closed = mysql.len() + pg.len();
+        for (_, cached) in mysql {
+            cached.pool.disconnect().await.ok();
+        }
+        closed
+    }
+
     /// 清除所有连接池
     pub async fn clear_all(&self) {
         {
             let mut pools = self.mysql_pools.write().await;
-            for (_, pool) in pools.drain() {
-                pool.disconnect().await.ok();
+            for (_, cached) in pools.drain() {
+                cached.pool.disconnect().await.ok();
             }
         }
-        {
-            let mut clients = self.pg_clients.write().await;
-            clients.clear();
+        self.pg_clients.write().await.drain().for_each(drop);
+        self.pg_statements.lock().unwrap_or_else(|e| e.into_inner()).clear();
+        self.sqlite_memory
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+        #[cfg(feature = "duckdb")]
+        self.duckdb
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+        #[cfg(feature = "mongodb")]
+        for (_, client) in self.mongo_clients.write().await.drain() {
+            client.shutdown().await;
         }
     }
 }
@@ -382,3 +673,72 @@ impl Default for PoolManager {
 lazy_static::lazy_static! {
     pub static ref POOL_MANAGER: PoolManager = PoolManager::new();
 }
+
+/// 按 SSL 模式放宽校验的服务器证书验证器
+///
+/// `verify_chain` 为真时（VerifyCa）校验证书链但接受与主机名不符的证书，
+/// 否则（Prefer/Require）接受任意证书；两种情况下都校验握手签名。
+#[derive(Debug)]
+struct PgCertVerifier {
+    inner: Arc<rustls::client::WebPkiServerVerifier>,
+    provider: Arc<rustls::crypto::CryptoProvider>,
+    verify_chain: bool,
+}
+
+impl PgCertVerifier {
+    fn new(
+        roots: rustls::RootCertStore,
+        provider: Arc<rustls::crypto::CryptoProvider>,
+        verify_chain: bool,
+    ) -> Result<Self, DbError> {
+        let inner = rustls::client::WebPkiServerVerifier::builder_with_provider(Arc::new(roots), provider.clone())
+            .build()
+            .map_err(|e| DbError::Connection(format!("TLS 连接器构建失败: {}", e)))?;
+        Ok(Self { inner, provider, verify_chain })
+    }
+}
+
+impl rustls::client::danger::ServerCertVerifier for PgCertVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &rustls::pki_types::CertificateDer<'_>,
+        intermediates: &[rustls::pki_types::CertificateDer<'_>],
+        server_name: &rustls::pki_types::ServerName<'_>,
+        ocsp_response: &[u8],
+        now: rustls::pki_types::UnixTime,
+    ) -> Result<rustls::client::danger::ServerCertVerified, rustls::Error> {
+        use rustls::CertificateError;
+
+        if !self.verify_chain {
+            return Ok(rustls::client::danger::ServerCertVerified::assertion());
+        }
+        match self.inner.verify_server_cert(end_entity, intermediates, server_name, ocsp_response, now) {
+            Err(rustls::Error::InvalidCertificate(
+                CertificateError::NotValidForName | CertificateError::NotValidForNameContext { .. },
+            )) => Ok(rustls::client::danger::ServerCertVerified::assertion()),
+            result => result,
+        }
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &rustls::pki_types::CertificateDer<'_>,
+        dss: &rustls::DigitallySignedStruct,
+    ) -> Result<rustls::client::danger::HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls12_signature(message, cert, dss, &self.provider.signature_verification_algorithms)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &rustls::pki_types::CertificateDer<'_>,
+        dss: &rustls::DigitallySignedStruct,
+    ) -> Result<rustls::client::danger::HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls13_signature(message, cert, dss, &self.provider.signature_verification_algorithms)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<rustls::SignatureScheme> {
+        self.provider.signature_verification_algorithms.supported_schemes()
+    }
+}
@@ -0,0 +1,115 @@
+//! 连接池缓存
+//!
+//! 按最近使用顺序淘汰：缓存已满时移除最久未使用的连接池，
+//! 并可移除空闲超过指定时间的连接池。读取时在读锁下更新使用时间。
+
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+/// 缓存项
+struct Entry<T> {
+    value: T,
+    /// 使用顺序（越大越近）
+    order: AtomicU64,
+    /// 最近一次使用的时间
+    last_used: Mutex<Instant>,
+}
+
+/// 按最近使用顺序淘汰的连接池缓存
+pub struct PoolCache<T> {
+    entries: HashMap<String, Entry<T>>,
+    capacity: usize,
+    /// 递增的使用计数，用于确定使用顺序
+    clock: AtomicU64,
+}
+
+impl<T> PoolCache<T> {
+    /// 创建最多保留 `capacity` 项的缓存
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: HashMap::new(),
+            capacity: capacity.max(1),
+            clock: AtomicU64::new(0),
+        }
+    }
+
+    fn tick(&self) -> u64 {
+        self.clock.fetch_add(1, Ordering::Relaxed)
+    }
+
+    /// 获取缓存项并标记为刚使用过
+    pub fn get(&self, key: &str) -> Option<&T> {
+        let entry = self.entries.get(key)?;
+        entry.order.store(self.tick(), Ordering::Relaxed);
+        *entry.last_used.lock() = Instant::now();
+        Some(&entry.value)
+    }
+
+    /// 插入缓存项；缓存已满时移除并返回最久未使用的项
+    pub fn insert(&mut self, key: String, value: T) -> Option<(String, T)> {
+        let evicted = if !self.entries.contains_key(&key) && self.entries.len() >= self.capacity {
+            self.entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.order.load(Ordering::Relaxed))
+                .map(|(key, _)| key.clone())
+                .and_then(|oldest| self.remove(&oldest).map(|value| (oldest, value)))
+        } else {
+            None
+        };
+        let entry = Entry {
+            value,
+            order: AtomicU64::new(self.tick()),
+            last_used: Mutex::new(Instant::now()),
+        };
+        self.entries.insert(key, entry);
+        evicted
+    }
+
+    /// 移除缓存项
+    pub fn remove(&mut self, key: &str) -> Option<T> {
+        self.entries.remove(key).map(|entry| entry.value)
+    }
+
+    /// 移除在 `now` 时已空闲至少 `ttl` 且 `can_remove` 返回 true 的项（正在使用的连接池不移除）
+    pub fn remove_idle(&mut self, ttl: Duration, now: Instant, can_remove: impl Fn(&T) -> bool) -> Vec<(String, T)> {
+        let idle: Vec<String> = self
+            .entries
+            .iter()
+            .filter(|(_, entry)| {
+                let last_used = *entry.last_used.lock();
+                now.saturating_duration_since(last_used) >= ttl && can_remove(&entry.value)
+            })
+            .map(|(key, _)| key.clone())
+            .collect();
+        idle.into_iter()
+            .filter_map(|key| self.remove(&key).map(|value| (key, value)))
+            .collect()
+    }
+
+    /// 移除所有缓存项
+    pub fn drain(&mut self) -> impl Iterator<Item = (String, T)> + '_ {
+        self.entries.drain().map(|(key, entry)| (key, entry.value))
+    }
+
+    /// 所有缓存项及其空闲时间
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &T, Duration)> {
+        self.entries.iter().map(|(key, entry)| {
+            let last_used = *entry.last_used.lock();
+            (key, &entry.value, last_used.elapsed())
+        })
+    }
+
+    /// 缓存项数量
+    #[allow(dead_code)] // 公开 API
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// 缓存是否为空
+    #[allow(dead_code)] // 公开 API
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
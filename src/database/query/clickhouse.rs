@@ -0,0 +1,215 @@
+//! ClickHouse 查询实现
+//!
+//! 通过 HTTP 接口执行 SQL，结果使用 `JSONCompactEachRowWithNamesAndTypes` 格式逐行读取：
+//! 第一行为列名，第二行为列类型，之后每行一条记录。读取达到行数上限后直接断开响应，
+//! 服务器随之取消查询，大结果集不会被完整传输到本地。
+
+use std::io::{BufRead, BufReader, Read};
+use std::time::Duration;
+
+use serde_json::Value;
+use ureq::tls::{RootCerts, TlsConfig, TlsProvider};
+use crate::core::constants;
+use crate::database::{ColumnInfo, ConnectionConfig, DbError, QueryResult};
+use super::document::cell_text;
+use super::{exec_result, paged_sql, query_result};
+
+/// 结果输出格式（逐行 JSON 数组，前两行为列名和类型）
+const OUTPUT_FORMAT: &str = "JSONCompactEachRowWithNamesAndTypes";
+
+/// 创建 HTTP 客户端（非 2xx 状态码按响应正文报告错误，HTTPS 使用系统证书）
+fn agent(config: &ConnectionConfig) -> ureq::Agent {
+    let tls = TlsConfig::builder()
+        .provider(TlsProvider::NativeTls)
+        .root_certs(RootCerts::PlatformVerifier)
+        .build();
+    ureq::Agent::config_builder()
+        .timeout_connect(Some(Duration::from_secs(config.connect_timeout_secs())))
+        .timeout_global(Some(Duration::from_secs(config.query_timeout_secs())))
+        .http_status_as_error(false)
+        .tls_config(tls)
+        .build()
+        .into()
+}
+
+/// 发送 SQL，`params` 为服务端查询参数（SQL 中以 `{name:Type}` 引用）
+fn send(
+    config: &ConnectionConfig,
+    sql: &str,
+    params: &[(&str, &str)],
+) -> Result<ureq::http::Response<ureq::Body>, DbError> {
+    let user = if config.username.is_empty() { "default" } else { &config.username };
+    let mut request = agent(config)
+        .post(format!("{}/", config.clickhouse_url()))
+        .query("default_format", OUTPUT_FORMAT)
+        .header("X-ClickHouse-User", user);
+    if !config.password.is_empty() {
+        request = request.header("X-ClickHouse-Key", &config.password);
+    }
+    if !config.database.is_empty() {
+        request = request.query("database", &config.database);
+    }
+    for (name, value) in params {
+        request = request.query(format!("param_{}", name), *value);
+    }
+
+    let response = request
+        .send(sql)
+        .map_err(|e| DbError::Connection(format!("ClickHouse 连接失败: {}", e)))?;
+    if !response.status().is_success() {
+        let status = response.status();
+        let message = response.into_body().read_to_string().unwrap_or_default();
+        return Err(DbError::Query(match message.trim() {
+            "" => format!("ClickHouse 返回错误状态: {}", status),
+            message => message.to_string(),
+        }));
+    }
+    Ok(response)
+}
+
+/// 从 `X-ClickHouse-Summary` 响应头读取写入行数（值为字符串形式的数字）
+fn written_rows(response: &ureq::http::Response<ureq::Body>) -> u64 {
+    response
+        .headers()
+        .get("X-ClickHouse-Summary")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| serde_json::from_str::<Value>(v).ok())
+        .and_then(|summary| summary.get("written_rows").and_then(Value::as_str).and_then(|n| n.parse().ok()))
+        .unwrap_or(0)
+}
+
+/// 逐行读取结果，最多读取 `limit` 行，还有更多行时标记 `truncated`
+///
+/// 响应为空（DDL、INSERT 等）时返回 `None`
+fn read_rows(reader: impl Read, limit: usize) -> Result<Option<QueryResult>, DbError> {
+    let mut lines = BufReader::new(reader).lines();
+    let mut next_line = || lines.next().transpose().map_err(|e| DbError::Query(format!("读取结果失败: {}", e)));
+
+    let Some(header) = next_line()? else {
+        return Ok(None);
+    };
+    // SQL 中自带 FORMAT 子句时输出不是 JSON，按文本行显示
+    let Ok(Value::Array(names)) = serde_json::from_str::<Value>(&header) else {
+        let mut rows = vec![vec![header]];
+        while rows.len() < limit {
+            match next_line()? {
+                Some(line) => rows.push(vec![line]),
+                None => break,
+            }
+        }
+        return Ok(Some(query_result(vec!["result".to_string()], rows)));
+    };
+    let columns: Vec<String> = names.iter().map(cell_text).collect();
+    // 第二行为列类型
+    next_line()?;
+
+    let mut rows = Vec::new();
+    let mut truncated = false;
+    while let Some(line) = next_line()? {
+        if line.is_empty() {
+            continue;
+        }
+        if rows.len() >= limit {
+            truncated = true;
+            break;
+        }
+        // 执行中途出错时，服务器会在已输出的结果后追加异常信息
+        let Ok(Value::Array(values)) = serde_json::from_str::<Value>(&line) else {
+            return Err(DbError::Query(line));
+        };
+        rows.push(values.iter().map(cell_text).collect());
+    }
+
+    let mut result = query_result(columns, rows);
+    result.truncated = truncated;
+    Ok(Some(result))
+}
+
+/// 执行元数据查询并返回全部行
+fn fetch(config: &ConnectionConfig, sql: &str, params: &[(&str, &str)]) -> Result<Vec<Vec<String>>, DbError> {
+    let response = send(config, sql, params)?;
+    let result = read_rows(response.into_body().into_reader(), usize::MAX)?;
+    Ok(result.map(|r| r.rows).unwrap_or_default())
+}
+
+/// 获取数据库列表
+pub fn get_databases(config: &ConnectionConfig) -> Result<Vec<String>, DbError> {
+    Ok(fetch(config, "SELECT name FROM system.databases ORDER BY name", &[])?
+        .into_iter()
+        .filter_map(|row| row.into_iter().next())
+        .collect())
+}
+
+/// 获取指定数据库的表列表（含视图）
+pub fn get_tables(config: &ConnectionConfig, database: &str) -> Result<Vec<String>, DbError> {
+    Ok(fetch(
+        config,
+        "SELECT name FROM system.tables WHERE database = {db:String} ORDER BY name",
+        &[("db", database)],
+    )?
+    .into_iter()
+    .filter_map(|row| row.into_iter().next())
+    .collect())
+}
+
+/// 获取表的列信息
+pub fn get_columns(config: &ConnectionConfig, table: &str) -> Result<Vec<ColumnInfo>, DbError> {
+    let rows = fetch(
+        config,
+        "SELECT name, type, default_kind, default_expression, is_in_primary_key \
+         FROM system.columns WHERE database = currentDatabase() AND table = {table:String} \
+         ORDER BY position",
+        &[("table", table)],
+    )?;
+    Ok(rows
+        .into_iter()
+        .filter(|row| row.len() >= 5)
+        .map(|row| {
+            let default_value = match row[2].as_str() {
+                "" => None,
+                "DEFAULT" => Some(row[3].clone()),
+                // MATERIALIZED/ALIAS/EPHEMERAL 保留类型前缀
+                kind => Some(format!("{} {}", kind, row[3])),
+            };
+            ColumnInfo {
+                is_nullable: row[1].starts_with("Nullable(") || row[1].starts_with("LowCardinality(Nullable("),
+                is_primary_key: row[4] == "1",
+                data_type: row[1].clone(),
+                name: row[0].clone(),
+                default_value,
+            }
+        })
+        .collect())
+}
+
+/// 获取表的主键列（复合主键取第一列）
+pub fn get_primary_key(config: &ConnectionConfig, table: &str) -> Result<Option<String>, DbError> {
+    Ok(fetch(
+        config,
+        "SELECT name FROM system.columns WHERE database = currentDatabase() AND table = {table:String} \
+         AND is_in_primary_key ORDER BY position LIMIT 1",
+        &[("table", table)],
+    )?
+    .into_iter()
+    .next()
+    .and_then(|row| row.into_iter().next()))
+}
+
+/// 执行 SQL（结果最多读取 `MAX_RESULT_SET_ROWS` 行）
+pub fn execute(config: &ConnectionConfig, sql: &str) -> Result<QueryResult, DbError> {
+    let response = send(config, sql, &[])?;
+    let written = written_rows(&response);
+    let result = read_rows(
+        response.into_body().into_reader(),
+        constants::database::MAX_RESULT_SET_ROWS,
+    )?;
+    Ok(result.unwrap_or_else(|| exec_result(written)))
+}
+
+/// 读取查询结果的一页（`truncated` 表示还有下一页）
+pub fn execute_page(config: &ConnectionConfig, sql: &str, offset: usize, limit: usize) -> Result<QueryResult, DbError> {
+    // 多取一行用于判断是否还有下一页
+    let response = send(config, &paged_sql(sql, offset, limit + 1), &[])?;
+    let result = read_rows(response.into_body().into_reader(), limit)?;
+    Ok(result.unwrap_or_else(|| exec_result(0)))
+}
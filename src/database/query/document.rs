@@ -0,0 +1,95 @@
+//! 文档型结果转换
+//!
+//! 将 JSON 文档列表转换为表格结果：列为所有文档顶层字段的并集（`_id` 固定在首列），
+//! 嵌套文档和数组以紧凑 JSON 显示，开启展开后嵌套文档拆分为 `a.b` 形式的列。
+
+use std::collections::HashMap;
+
+use serde_json::{Map, Value};
+
+use super::query_result;
+use crate::database::QueryResult;
+
+/// 将文档列表转换为表格结果
+///
+/// 缺失的字段显示为 `NULL`；非对象的文档放在 `value` 列
+pub fn documents_to_result(documents: &[Value], flatten: bool) -> QueryResult {
+    let mut columns: Vec<String> = Vec::new();
+    let mut index: HashMap<String, usize> = HashMap::new();
+    let mut records: Vec<Vec<(String, &Value)>> = Vec::with_capacity(documents.len());
+
+    for doc in documents {
+        let mut fields = Vec::new();
+        match doc {
+            Value::Object(map) => collect_fields("", map, flatten, &mut fields),
+            other => fields.push(("value".to_string(), other)),
+        }
+        for (name, _) in &fields {
+            if !index.contains_key(name) {
+                index.insert(name.clone(), columns.len());
+                columns.push(name.clone());
+            }
+        }
+        records.push(fields);
+    }
+
+    // `_id` 固定在首列，其余保持首次出现的顺序
+    if let Some(pos) = columns.iter().position(|c| c == "_id")
+        && pos > 0
+    {
+        let id = columns.remove(pos);
+        columns.insert(0, id);
+        for (i, name) in columns.iter().enumerate() {
+            index.insert(name.clone(), i);
+        }
+    }
+
+    let rows = records
+        .into_iter()
+        .map(|fields| {
+            let mut row = vec!["NULL".to_string(); columns.len()];
+            for (name, value) in fields {
+                row[index[&name]] = cell_text(value);
+            }
+            row
+        })
+        .collect();
+
+    query_result(columns, rows)
+}
+
+/// 收集文档字段，`flatten` 时递归展开嵌套文档（扩展 JSON 包装如 `{"$oid": ..}` 不展开）
+fn collect_fields<'a>(
+    prefix: &str,
+    map: &'a Map<String, Value>,
+    flatten: bool,
+    out: &mut Vec<(String, &'a Value)>,
+) {
+    for (key, value) in map {
+        let name = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", prefix, key)
+        };
+        match value {
+            Value::Object(nested) if flatten && !nested.is_empty() && !is_extjson_wrapper(nested) => {
+                collect_fields(&name, nested, flatten, out);
+            }
+            _ => out.push((name, value)),
+        }
+    }
+}
+
+/// 是否为扩展 JSON 的类型包装（ObjectId、日期、Decimal128 等）
+fn is_extjson_wrapper(map: &Map<String, Value>) -> bool {
+    map.keys().all(|k| k.starts_with('$'))
+}
+
+/// 单元格文本：字符串原样显示，嵌套值使用紧凑 JSON
+pub(super) fn cell_text(value: &Value) -> String {
+    match value {
+        Value::Null => "NULL".to_string(),
+        Value::String(s) => s.clone(),
+        _ => value.to_string(),
+    }
+}
@@ -0,0 +1,233 @@
+//! DuckDB 查询实现
+//!
+//! DuckDB 是嵌入式分析型数据库，支持文件或内存模式，
+//! 可直接查询 Parquet/CSV 文件（如 `SELECT * FROM 'data.parquet'`）。
+
+use ::duckdb::types::{Value, ValueRef};
+use ::duckdb::Connection as DuckConn;
+use crate::core::encode_blob;
+use crate::database::{ConnectionConfig, DbError, QueryResult, DatabaseType, POOL_MANAGER};
+use super::{query_result, exec_result, is_query_statement, ForeignKeyInfo, ColumnInfo};
+
+/// 打开 DuckDB 连接（共享连接池中缓存的数据库实例）
+fn open_connection(config: &ConnectionConfig) -> Result<DuckConn, DbError> {
+    POOL_MANAGER.get_duckdb_connection(config)
+}
+
+/// 拆分可能带 schema 前缀的表名（`schema.table` -> (`schema`, `table`)）
+///
+/// 表列表来自 `current_schema()` 且不带前缀，因此当前 schema 中存在同名表、
+/// 或点号前缀不是已有 schema 时，整个名称作为当前 schema 中的表名
+fn split_table_name(conn: &DuckConn, table: &str) -> Result<(String, String), DbError> {
+    let current: String = conn
+        .query_row("SELECT current_schema()", [], |row| row.get(0))
+        .map_err(|e| DbError::Query(e.to_string()))?;
+    if let Some((schema, name)) = table.split_once('.') {
+        let split: bool = conn
+            .query_row(
+                "SELECT EXISTS (SELECT 1 FROM information_schema.schemata WHERE schema_name = ?1) \
+                 AND NOT EXISTS (SELECT 1 FROM information_schema.tables WHERE table_schema = ?2 AND table_name = ?3)",
+                [schema, current.as_str(), table],
+                |row| row.get(0),
+            )
+            .map_err(|e| DbError::Query(e.to_string()))?;
+        if split {
+            return Ok((schema.to_string(), name.to_string()));
+        }
+    }
+    Ok((current, table.to_string()))
+}
+
+/// 连接 DuckDB 并获取表列表（含视图）
+pub fn connect(config: &ConnectionConfig) -> Result<Vec<String>, DbError> {
+    let conn = open_connection(config)?;
+    let mut stmt = conn
+        .prepare(
+            "SELECT table_name FROM information_schema.tables \
+             WHERE table_schema = current_schema() ORDER BY table_name",
+        )
+        .map_err(|e| DbError::Query(e.to_string()))?;
+
+    let tables: Result<Vec<String>, _> = stmt
+        .query_map([], |row| row.get(0))
+        .map_err(|e| DbError::Query(e.to_string()))?
+        .collect();
+
+    tables.map_err(|e| DbError::Query(e.to_string()))
+}
+
+/// 获取 DuckDB 表的主键列名
+pub fn get_primary_key(config: &ConnectionConfig, table: &str) -> Result<Option<String>, DbError> {
+    Ok(get_columns(config, table)?
+        .into_iter()
+        .find(|c| c.is_primary_key)
+        .map(|c| c.name))
+}
+
+/// 执行 DuckDB 查询
+pub fn execute(config: &ConnectionConfig, sql: &str) -> Result<QueryResult, DbError> {
+    let conn = open_connection(config)?;
+
+    if is_query_statement(sql, &DatabaseType::DuckDB) {
+        let mut stmt = conn
+            .prepare(sql)
+            .map_err(|e| DbError::Query(e.to_string()))?;
+        let mut rows = stmt.query([]).map_err(|e| DbError::Query(e.to_string()))?;
+
+        // DuckDB 的列信息在语句执行后才可用
+        let columns: Vec<String> = rows
+            .as_ref()
+            .map(|s| s.column_names())
+            .unwrap_or_default();
+
+        let mut data = Vec::new();
+        while let Some(row) = rows.next().map_err(|e| DbError::Query(e.to_string()))? {
+            let values = (0..columns.len())
+                .map(|i| row.get_ref(i).map(value_to_string))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| DbError::Query(e.to_string()))?;
+            data.push(values);
+        }
+
+        Ok(query_result(columns, data))
+    } else {
+        let affected = conn
+            .execute(sql, [])
+            .map_err(|e| DbError::Query(e.to_string()))? as u64;
+        Ok(exec_result(affected))
+    }
+}
+
+/// 将 DuckDB 值转换为字符串
+fn value_to_string(val: ValueRef<'_>) -> String {
+    match val {
+        ValueRef::Null => String::from("NULL"),
+        ValueRef::Boolean(b) => b.to_string(),
+        ValueRef::TinyInt(i) => i.to_string(),
+        ValueRef::SmallInt(i) => i.to_string(),
+        ValueRef::Int(i) => i.to_string(),
+        ValueRef::BigInt(i) => i.to_string(),
+        ValueRef::HugeInt(i) => i.to_string(),
+        ValueRef::UTinyInt(i) => i.to_string(),
+        ValueRef::USmallInt(i) => i.to_string(),
+        ValueRef::UInt(i) => i.to_string(),
+        ValueRef::UBigInt(i) => i.to_string(),
+        ValueRef::Float(f) => f.to_string(),
+        ValueRef::Double(f) => f.to_string(),
+        ValueRef::Decimal(d) => d.to_string(),
+        ValueRef::Text(t) => String::from_utf8_lossy(t).into_owned(),
+        ValueRef::Blob(b) => encode_blob(b),
+        ValueRef::Date32(days) => chrono::NaiveDate::from_num_days_from_ce_opt(days + 719_163)
+            .map(|d| d.to_string())
+            .unwrap_or_else(|| days.to_string()),
+        ValueRef::Timestamp(unit, v) => chrono::DateTime::from_timestamp_micros(unit.to_micros(v))
+            .map(|dt| dt.naive_utc().to_string())
+            .unwrap_or_else(|| v.to_string()),
+        ValueRef::Time64(unit, v) => {
+            let micros = unit.to_micros(v);
+            chrono::NaiveTime::from_num_seconds_from_midnight_opt(
+                (micros / 1_000_000) as u32,
+                ((micros % 1_000_000) * 1000) as u32,
+            )
+            .map(|t| t.to_string())
+            .unwrap_or_else(|| v.to_string())
+        }
+        // 列表、结构体等复杂类型使用调试格式显示
+        other => format!("{:?}", other.to_owned()),
+    }
+}
+
+/// 获取 DuckDB 外键（来自 duckdb_constraints()）
+pub fn get_foreign_keys(config: &ConnectionConfig) -> Result<Vec<ForeignKeyInfo>, DbError> {
+    let conn = open_connection(config)?;
+    let mut stmt = conn
+        .prepare(
+            "SELECT table_name, constraint_column_names, referenced_table, referenced_column_names \
+             FROM duckdb_constraints() \
+             WHERE constraint_type = 'FOREIGN KEY' AND schema_name = current_schema()",
+        )
+        .map_err(|e| DbError::Query(e.to_string()))?;
+
+    let mut rows = stmt.query([]).map_err(|e| DbError::Query(e.to_string()))?;
+    let mut fks = Vec::new();
+    while let Some(row) = rows.next().map_err(|e| DbError::Query(e.to_string()))? {
+        let from_table: String = row.get(0).map_err(|e| DbError::Query(e.to_string()))?;
+        let to_table: String = row.get(2).map_err(|e| DbError::Query(e.to_string()))?;
+        let from_columns = list_to_strings(row.get_ref(1).map_err(|e| DbError::Query(e.to_string()))?);
+        let to_columns = list_to_strings(row.get_ref(3).map_err(|e| DbError::Query(e.to_string()))?);
+
+        // 复合外键拆分为逐列对应关系
+        for (from_column, to_column) in from_columns.into_iter().zip(to_columns) {
+            fks.push(ForeignKeyInfo {
+                from_table: from_table.clone(),
+                from_column,
+                to_table: to_table.clone(),
+                to_column,
+            });
+        }
+    }
+
+    Ok(fks)
+}
+
+/// 将 VARCHAR[] 列表值转换为字符串数组
+fn list_to_strings(val: ValueRef<'_>) -> Vec<String> {
+    match val.to_owned() {
+        Value::List(items) => items
+            .into_iter()
+            .filter_map(|v| match v {
+                Value::Text(s) => Some(s),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// 获取 DuckDB 表的列信息
+pub fn get_columns(config: &ConnectionConfig, table: &str) -> Result<Vec<ColumnInfo>, DbError> {
+    let conn = open_connection(config)?;
+    let (schema, table) = split_table_name(&conn, table)?;
+
+    // 主键列
+    let mut pk_stmt = conn
+        .prepare(
+            "SELECT constraint_column_names FROM duckdb_constraints() \
+             WHERE constraint_type = 'PRIMARY KEY' AND schema_name = ?1 AND table_name = ?2",
+        )
+        .map_err(|e| DbError::Query(e.to_string()))?;
+    let mut pk_columns = Vec::new();
+    let mut pk_rows = pk_stmt
+        .query([&schema, &table])
+        .map_err(|e| DbError::Query(e.to_string()))?;
+    while let Some(row) = pk_rows.next().map_err(|e| DbError::Query(e.to_string()))? {
+        pk_columns.extend(list_to_strings(
+            row.get_ref(0).map_err(|e| DbError::Query(e.to_string()))?,
+        ));
+    }
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT column_name, data_type, is_nullable, column_default \
+             FROM information_schema.columns \
+             WHERE table_schema = ?1 AND table_name = ?2 ORDER BY ordinal_position",
+        )
+        .map_err(|e| DbError::Query(e.to_string()))?;
+
+    let columns: Result<Vec<ColumnInfo>, _> = stmt
+        .query_map([&schema, &table], |row| {
+            let name: String = row.get(0)?;
+            let is_nullable: String = row.get(2)?;
+            Ok(ColumnInfo {
+                is_primary_key: pk_columns.contains(&name),
+                name,
+                data_type: row.get(1)?,
+                is_nullable: is_nullable == "YES",
+                default_value: row.get(3)?,
+            })
+        })
+        .map_err(|e| DbError::Query(e.to_string()))?
+        .collect();
+
+    columns.map_err(|e| DbError::Query(e.to_string()))
+}
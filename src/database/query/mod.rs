@@ -1,17 +1,32 @@
 //! 数据库查询执行模块
 //!
-//! 提供对 SQLite、PostgreSQL、MySQL 的统一查询接口。
+//! 提供对 SQLite、PostgreSQL、MySQL、DuckDB、Oracle、ClickHouse、ODBC 数据源及 Redis、MongoDB 的统一查询接口。
 //! PostgreSQL 和 MySQL 使用连接池优化性能。
 
 #![allow(dead_code)] // 公开 API，部分功能预留
 
+#[cfg(feature = "clickhouse")]
+mod clickhouse;
+mod document;
+#[cfg(feature = "duckdb")]
+mod duckdb;
+#[cfg(feature = "mongodb")]
+mod mongodb;
 mod mysql;
+#[cfg(feature = "odbc")]
+mod odbc;
+#[cfg(feature = "oracle")]
+mod oracle;
 mod postgres;
+#[cfg(feature = "redis")]
+mod redis;
 mod sqlite;
 
+pub use document::documents_to_result;
+
 use super::ssh_tunnel::{SshTunnel, SSH_TUNNEL_MANAGER};
 use super::*;
-use crate::core::constants;
+use crate::core::{constants, is_read_only_statement};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::task;
@@ -39,6 +54,13 @@ pub async fn connect_database(config: &ConnectionConfig) -> Result<ConnectResult
     let (effective_config, _tunnel) = setup_ssh_tunnel_if_enabled(config).await?;
 
     match effective_config.db_type {
+        // 附加了其他数据库时，按 schema 分组显示
+        DatabaseType::SQLite if !effective_config.sqlite_attachments.is_empty() => {
+            let schemas = task::spawn_blocking(move || sqlite::get_schemas(&effective_config))
+                .await
+                .map_err(|e| DbError::Connection(format!("任务执行失败: {}", e)))??;
+            Ok(ConnectResult::Databases(schemas))
+        }
         DatabaseType::SQLite => {
             let tables = task::spawn_blocking(move || sqlite::connect(&effective_config))
                 .await
@@ -53,6 +75,58 @@ pub async fn connect_database(config: &ConnectionConfig) -> Result<ConnectResult
             let databases = mysql::get_databases(&effective_config).await?;
             Ok(ConnectResult::Databases(databases))
         }
+        #[cfg(feature = "duckdb")]
+        DatabaseType::DuckDB => {
+            let tables = task::spawn_blocking(move || duckdb::connect(&effective_config))
+                .await
+                .map_err(|e| DbError::Connection(format!("任务执行失败: {}", e)))??;
+            Ok(ConnectResult::Tables(tables))
+        }
+        #[cfg(not(feature = "duckdb"))]
+        DatabaseType::DuckDB => Err(duckdb_disabled()),
+        #[cfg(feature = "oracle")]
+        DatabaseType::Oracle => {
+            let schemas = task::spawn_blocking(move || oracle::get_databases(&effective_config))
+                .await
+                .map_err(|e| DbError::Connection(format!("任务执行失败: {}", e)))??;
+            Ok(ConnectResult::Databases(schemas))
+        }
+        #[cfg(not(feature = "oracle"))]
+        DatabaseType::Oracle => Err(oracle_disabled()),
+        #[cfg(feature = "odbc")]
+        DatabaseType::Odbc => {
+            let tables = task::spawn_blocking(move || odbc::connect(&effective_config))
+                .await
+                .map_err(|e| DbError::Connection(format!("任务执行失败: {}", e)))??;
+            Ok(ConnectResult::Tables(tables))
+        }
+        #[cfg(not(feature = "odbc"))]
+        DatabaseType::Odbc => Err(odbc_disabled()),
+        #[cfg(feature = "redis")]
+        DatabaseType::Redis => {
+            let databases = task::spawn_blocking(move || redis::get_databases(&effective_config))
+                .await
+                .map_err(|e| DbError::Connection(format!("任务执行失败: {}", e)))??;
+            Ok(ConnectResult::Databases(databases))
+        }
+        #[cfg(not(feature = "redis"))]
+        DatabaseType::Redis => Err(redis_disabled()),
+        #[cfg(feature = "mongodb")]
+        DatabaseType::MongoDB => {
+            let databases = mongodb::get_databases(&effective_config).await?;
+            Ok(ConnectResult::Databases(databases))
+        }
+        #[cfg(not(feature = "mongodb"))]
+        DatabaseType::MongoDB => Err(mongodb_disabled()),
+        #[cfg(feature = "clickhouse")]
+        DatabaseType::ClickHouse => {
+            let databases = task::spawn_blocking(move || clickhouse::get_databases(&effective_config))
+                .await
+                .map_err(|e| DbError::Connection(format!("任务执行失败: {}", e)))??;
+            Ok(ConnectResult::Databases(databases))
+        }
+        #[cfg(not(feature = "clickhouse"))]
+        DatabaseType::ClickHouse => Err(clickhouse_disabled()),
     }
 }
 
@@ -66,12 +140,111 @@ pub async fn get_tables_for_database(
     let database = database.to_string();
 
     match effective_config.db_type {
-        DatabaseType::SQLite => task::spawn_blocking(move || sqlite::connect(&effective_config))
-            .await
-            .map_err(|e| DbError::Connection(format!("任务执行失败: {}", e)))?,
+        DatabaseType::SQLite => {
+            task::spawn_blocking(move || sqlite::get_tables(&effective_config, &database))
+                .await
+                .map_err(|e| DbError::Connection(format!("任务执行失败: {}", e)))?
+        }
         DatabaseType::PostgreSQL => postgres::get_tables(&effective_config, &database).await,
         DatabaseType::MySQL => mysql::get_tables(&effective_config, &database).await,
+        #[cfg(feature = "oracle")]
+        DatabaseType::Oracle => {
+            task::spawn_blocking(move || oracle::get_tables(&effective_config, &database))
+                .await
+                .map_err(|e| DbError::Connection(format!("任务执行失败: {}", e)))?
+        }
+        #[cfg(not(feature = "oracle"))]
+        DatabaseType::Oracle => Err(oracle_disabled()),
+        #[cfg(feature = "odbc")]
+        DatabaseType::Odbc => {
+            task::spawn_blocking(move || odbc::connect(&effective_config))
+                .await
+                .map_err(|e| DbError::Connection(format!("任务执行失败: {}", e)))?
+        }
+        #[cfg(not(feature = "odbc"))]
+        DatabaseType::Odbc => Err(odbc_disabled()),
+        // DuckDB 不区分数据库，直接返回当前 schema 的表
+        #[cfg(feature = "duckdb")]
+        DatabaseType::DuckDB => {
+            task::spawn_blocking(move || duckdb::connect(&effective_config))
+                .await
+                .map_err(|e| DbError::Connection(format!("任务执行失败: {}", e)))?
+        }
+        #[cfg(not(feature = "duckdb"))]
+        DatabaseType::DuckDB => Err(duckdb_disabled()),
+        // Redis 的"表"是当前库中匹配模式的键
+        #[cfg(feature = "redis")]
+        DatabaseType::Redis => {
+            task::spawn_blocking(move || redis::get_keys(&effective_config, &database))
+                .await
+                .map_err(|e| DbError::Connection(format!("任务执行失败: {}", e)))?
+        }
+        #[cfg(not(feature = "redis"))]
+        DatabaseType::Redis => Err(redis_disabled()),
+        // MongoDB 的"表"是集合
+        #[cfg(feature = "mongodb")]
+        DatabaseType::MongoDB => mongodb::get_collections(&effective_config, &database).await,
+        #[cfg(not(feature = "mongodb"))]
+        DatabaseType::MongoDB => Err(mongodb_disabled()),
+        #[cfg(feature = "clickhouse")]
+        DatabaseType::ClickHouse => {
+            task::spawn_blocking(move || clickhouse::get_tables(&effective_config, &database))
+                .await
+                .map_err(|e| DbError::Connection(format!("任务执行失败: {}", e)))?
+        }
+        #[cfg(not(feature = "clickhouse"))]
+        DatabaseType::ClickHouse => Err(clickhouse_disabled()),
+    }
+}
+
+/// 分批获取指定数据库的表列表，从第 `offset` 张表开始最多返回 `limit` 张
+///
+/// 返回 (表列表, 是否还有更多)。MySQL/PostgreSQL 在 information_schema 上分页查询；
+/// 其他数据库不分批，`offset` 为 0 时返回完整列表。
+pub async fn get_tables_page(
+    config: &ConnectionConfig,
+    database: &str,
+    offset: usize,
+    limit: usize,
+) -> Result<(Vec<String>, bool), DbError> {
+    if !matches!(config.db_type, DatabaseType::PostgreSQL | DatabaseType::MySQL) {
+        if offset > 0 {
+            return Ok((Vec::new(), false));
+        }
+        return Ok((get_tables_for_database(config, database).await?, false));
+    }
+
+    let (effective_config, _tunnel) = setup_ssh_tunnel_if_enabled(config).await?;
+    // 多读一张表用于判断是否还有更多
+    let mut tables = if effective_config.db_type == DatabaseType::PostgreSQL {
+        postgres::get_tables_page(&effective_config, database, offset, limit + 1).await?
+    } else {
+        mysql::get_tables_page(&effective_config, database, offset, limit + 1).await?
+    };
+    let has_more = tables.len() > limit;
+    tables.truncate(limit);
+    Ok((tables, has_more))
+}
+
+/// 将 SQLite 数据库保存为文件（草稿本持久化）
+pub async fn save_sqlite_to_file(config: &ConnectionConfig, path: &str) -> Result<(), DbError> {
+    if !matches!(config.db_type, DatabaseType::SQLite) {
+        return Err(DbError::Query("仅 SQLite 数据库支持保存到文件".to_string()));
     }
+
+    let config = config.clone();
+    let path = path.to_string();
+    task::spawn_blocking(move || sqlite::save_to_file(&config, &path))
+        .await
+        .map_err(|e| DbError::Query(format!("任务执行失败: {}", e)))?
+}
+
+/// 获取 SQLite 连接中所有 schema 的表（附加数据库的表带前缀，用于自动补全）
+pub async fn get_all_sqlite_tables(config: &ConnectionConfig) -> Result<Vec<String>, DbError> {
+    let config = config.clone();
+    task::spawn_blocking(move || sqlite::get_all_tables(&config))
+        .await
+        .map_err(|e| DbError::Query(format!("任务执行失败: {}", e)))?
 }
 
 /// 获取表的主键列名
@@ -93,6 +266,42 @@ pub async fn get_primary_key_column(
         }
         DatabaseType::PostgreSQL => postgres::get_primary_key(&effective_config, &table).await,
         DatabaseType::MySQL => mysql::get_primary_key(&effective_config, &table).await,
+        #[cfg(feature = "oracle")]
+        DatabaseType::Oracle => {
+            task::spawn_blocking(move || oracle::get_primary_key(&effective_config, &table))
+                .await
+                .map_err(|e| DbError::Query(format!("任务执行失败: {}", e)))?
+        }
+        #[cfg(not(feature = "oracle"))]
+        DatabaseType::Oracle => Err(oracle_disabled()),
+        #[cfg(feature = "odbc")]
+        DatabaseType::Odbc => {
+            task::spawn_blocking(move || odbc::get_primary_key(&effective_config, &table))
+                .await
+                .map_err(|e| DbError::Query(format!("任务执行失败: {}", e)))?
+        }
+        #[cfg(not(feature = "odbc"))]
+        DatabaseType::Odbc => Err(odbc_disabled()),
+        #[cfg(feature = "duckdb")]
+        DatabaseType::DuckDB => {
+            task::spawn_blocking(move || duckdb::get_primary_key(&effective_config, &table))
+                .await
+                .map_err(|e| DbError::Query(format!("任务执行失败: {}", e)))?
+        }
+        #[cfg(not(feature = "duckdb"))]
+        DatabaseType::DuckDB => Err(duckdb_disabled()),
+        // Redis 键没有主键概念
+        DatabaseType::Redis => Ok(None),
+        // MongoDB 文档固定以 _id 为主键
+        DatabaseType::MongoDB => Ok(Some("_id".to_string())),
+        #[cfg(feature = "clickhouse")]
+        DatabaseType::ClickHouse => {
+            task::spawn_blocking(move || clickhouse::get_primary_key(&effective_config, &table))
+                .await
+                .map_err(|e| DbError::Query(format!("任务执行失败: {}", e)))?
+        }
+        #[cfg(not(feature = "clickhouse"))]
+        DatabaseType::ClickHouse => Err(clickhouse_disabled()),
     }
 }
 
@@ -107,6 +316,55 @@ pub async fn get_primary_key_column(
 pub async fn execute_query(config: &ConnectionConfig, sql: &str) -> Result<QueryResult, DbError> {
     // 如果启用了 SSH 隧道，先建立隧道并修改连接配置
     let (effective_config, _tunnel) = setup_ssh_tunnel_if_enabled(config).await?;
+
+    match execute_on(&effective_config, sql).await {
+        Err(e) if retries_stale_connection(effective_config.db_type, sql, &e) => {
+            discard_stale_connection(&effective_config, &e).await;
+            execute_on(&effective_config, sql).await
+        }
+        result => result,
+    }
+}
+
+/// 在只读事务中执行查询，语句试图修改数据时由数据库拒绝
+///
+/// SQLite 使用 `query_only` 模式，PostgreSQL、MySQL 使用 `READ ONLY` 事务并在结束后回滚；
+/// 其他数据库不支持只读事务，直接执行
+pub async fn execute_read_only(config: &ConnectionConfig, sql: &str) -> Result<QueryResult, DbError> {
+    let (effective_config, _tunnel) = setup_ssh_tunnel_if_enabled(config).await?;
+
+    match effective_config.db_type {
+        DatabaseType::SQLite => {
+            let sql = sql.to_string();
+            task::spawn_blocking(move || sqlite::execute_read_only(&effective_config, &sql))
+                .await
+                .map_err(|e| DbError::Query(format!("任务执行失败: {}", e)))?
+        }
+        DatabaseType::PostgreSQL => postgres::execute_read_only(&effective_config, sql).await,
+        DatabaseType::MySQL => mysql::execute_read_only(&effective_config, sql).await,
+        _ => execute_on(&effective_config, sql).await,
+    }
+}
+
+/// 连接池中的连接失效时（服务器重启、空闲超时被关闭等）是否重新连接后重试一次
+///
+/// 语句发送后连接才断开时服务器可能已经执行了语句，只有获取连接时失败或所有语句都在只读白名单中时
+/// 才重试，避免写操作（包括存储过程调用）重复执行
+pub fn retries_stale_connection(db_type: DatabaseType, sql: &str, error: &DbError) -> bool {
+    matches!(db_type, DatabaseType::PostgreSQL | DatabaseType::MySQL)
+        && error.is_stale_connection()
+        && (error.is_acquire_error() || split_statements(sql).iter().all(|s| is_read_only_statement(s)))
+}
+
+/// 丢弃失效的连接，下次获取连接时重新建立
+async fn discard_stale_connection(config: &ConnectionConfig, error: &DbError) {
+    tracing::warn!(connection = %config.name, error = %error, "连接已失效，重新连接后重试");
+    POOL_MANAGER.remove_pool(config).await;
+}
+
+/// 在已建立隧道的连接配置上执行 SQL
+async fn execute_on(config: &ConnectionConfig, sql: &str) -> Result<QueryResult, DbError> {
+    let effective_config = config.clone();
     let sql = sql.to_string();
 
     match effective_config.db_type {
@@ -117,9 +375,440 @@ pub async fn execute_query(config: &ConnectionConfig, sql: &str) -> Result<Query
         }
         DatabaseType::PostgreSQL => postgres::execute(&effective_config, &sql).await,
         DatabaseType::MySQL => mysql::execute(&effective_config, &sql).await,
+        #[cfg(feature = "oracle")]
+        DatabaseType::Oracle => {
+            task::spawn_blocking(move || oracle::execute(&effective_config, &sql))
+                .await
+                .map_err(|e| DbError::Query(format!("任务执行失败: {}", e)))?
+        }
+        #[cfg(not(feature = "oracle"))]
+        DatabaseType::Oracle => Err(oracle_disabled()),
+        #[cfg(feature = "odbc")]
+        DatabaseType::Odbc => {
+            task::spawn_blocking(move || odbc::execute(&effective_config, &sql))
+                .await
+                .map_err(|e| DbError::Query(format!("任务执行失败: {}", e)))?
+        }
+        #[cfg(not(feature = "odbc"))]
+        DatabaseType::Odbc => Err(odbc_disabled()),
+        #[cfg(feature = "duckdb")]
+        DatabaseType::DuckDB => {
+            task::spawn_blocking(move || duckdb::execute(&effective_config, &sql))
+                .await
+                .map_err(|e| DbError::Query(format!("任务执行失败: {}", e)))?
+        }
+        #[cfg(not(feature = "duckdb"))]
+        DatabaseType::DuckDB => Err(duckdb_disabled()),
+        #[cfg(feature = "redis")]
+        DatabaseType::Redis => {
+            task::spawn_blocking(move || redis::execute(&effective_config, &sql))
+                .await
+                .map_err(|e| DbError::Query(format!("任务执行失败: {}", e)))?
+        }
+        #[cfg(not(feature = "redis"))]
+        DatabaseType::Redis => Err(redis_disabled()),
+        #[cfg(feature = "mongodb")]
+        DatabaseType::MongoDB => mongodb::execute(&effective_config, &sql).await,
+        #[cfg(not(feature = "mongodb"))]
+        DatabaseType::MongoDB => Err(mongodb_disabled()),
+        #[cfg(feature = "clickhouse")]
+        DatabaseType::ClickHouse => {
+            task::spawn_blocking(move || clickhouse::execute(&effective_config, &sql))
+                .await
+                .map_err(|e| DbError::Query(format!("任务执行失败: {}", e)))?
+        }
+        #[cfg(not(feature = "clickhouse"))]
+        DatabaseType::ClickHouse => Err(clickhouse_disabled()),
     }
 }
 
+/// 是否支持在一个事务中批量执行多条语句（SQLite、PostgreSQL、MySQL）
+pub fn supports_batch_transaction(db_type: DatabaseType) -> bool {
+    matches!(db_type, DatabaseType::SQLite | DatabaseType::PostgreSQL | DatabaseType::MySQL)
+}
+
+/// 在同一连接上依次执行多条写语句，返回影响的总行数
+///
+/// `transaction` 为 true 时所有语句在一个事务中执行，任一语句失败时全部回滚；
+/// 只有 [`supports_batch_transaction`] 返回 true 的数据库支持事务执行
+pub async fn execute_batch(config: &ConnectionConfig, statements: &[String], transaction: bool) -> Result<u64, DbError> {
+    if transaction && !supports_batch_transaction(config.db_type) {
+        return Err(DbError::Query(format!("{} 不支持在事务中批量执行", config.db_type.display_name())));
+    }
+    let (effective_config, _tunnel) = setup_ssh_tunnel_if_enabled(config).await?;
+
+    match effective_config.db_type {
+        DatabaseType::SQLite => {
+            let statements = statements.to_vec();
+            task::spawn_blocking(move || sqlite::execute_batch(&effective_config, &statements, transaction))
+                .await
+                .map_err(|e| DbError::Query(format!("任务执行失败: {}", e)))?
+        }
+        DatabaseType::PostgreSQL => postgres::execute_batch(&effective_config, statements, transaction).await,
+        DatabaseType::MySQL => mysql::execute_batch(&effective_config, statements, transaction).await,
+        _ => {
+            let mut affected = 0;
+            for sql in statements {
+                affected += execute_on(&effective_config, sql).await?.affected_rows;
+            }
+            Ok(affected)
+        }
+    }
+}
+
+/// 保活检查使用的语句；本地数据库（SQLite、DuckDB）不会断开，返回 `None`
+pub fn ping_statement(db_type: DatabaseType) -> Option<&'static str> {
+    match db_type {
+        DatabaseType::SQLite | DatabaseType::DuckDB => None,
+        DatabaseType::Oracle => Some("SELECT 1 FROM DUAL"),
+        DatabaseType::Redis => Some("PING"),
+        DatabaseType::MongoDB => Some(r#"{"ping": 1}"#),
+        DatabaseType::PostgreSQL | DatabaseType::MySQL | DatabaseType::ClickHouse | DatabaseType::Odbc => {
+            Some("SELECT 1")
+        }
+    }
+}
+
+/// 发送保活查询，返回耗时（毫秒）及是否重新建立了连接
+///
+/// 失败时先丢弃连接池中可能已失效的连接，再重试一次，服务器重启后可以透明地重新连接
+pub async fn ping(config: &ConnectionConfig) -> Result<(u64, bool), DbError> {
+    let Some(sql) = ping_statement(config.db_type) else {
+        return Ok((0, false));
+    };
+    let start = std::time::Instant::now();
+    if execute_query(config, sql).await.is_ok() {
+        return Ok((start.elapsed().as_millis() as u64, false));
+    }
+    POOL_MANAGER.remove_pool(config).await;
+    let start = std::time::Instant::now();
+    execute_query(config, sql).await?;
+    Ok((start.elapsed().as_millis() as u64, true))
+}
+
+/// 是否支持不执行而检查 SQL（[`validate_sql`]）
+pub fn supports_validation(db_type: DatabaseType) -> bool {
+    matches!(
+        db_type,
+        DatabaseType::SQLite | DatabaseType::PostgreSQL | DatabaseType::MySQL | DatabaseType::DuckDB | DatabaseType::ClickHouse
+    )
+}
+
+/// 检查一条语句能否执行而不实际执行
+///
+/// SQLite、PostgreSQL、MySQL 只预处理语句，DuckDB、ClickHouse 使用 EXPLAIN；
+/// 数据库据此检查语法、表和列是否存在以及类型是否匹配
+pub async fn validate_sql(config: &ConnectionConfig, sql: &str) -> Result<(), DbError> {
+    let (effective_config, _tunnel) = setup_ssh_tunnel_if_enabled(config).await?;
+
+    match effective_config.db_type {
+        DatabaseType::SQLite => {
+            let sql = sql.to_string();
+            task::spawn_blocking(move || sqlite::validate(&effective_config, &sql))
+                .await
+                .map_err(|e| DbError::Query(format!("任务执行失败: {}", e)))?
+        }
+        DatabaseType::PostgreSQL => postgres::validate(&effective_config, sql).await,
+        DatabaseType::MySQL => mysql::validate(&effective_config, sql).await,
+        DatabaseType::DuckDB | DatabaseType::ClickHouse => {
+            execute_on(&effective_config, &format!("EXPLAIN {}", sql)).await.map(|_| ())
+        }
+        db_type => Err(DbError::Query(format!("{} 不支持检查 SQL", db_type.display_name()))),
+    }
+}
+
+/// 是否支持读取查询的运行时统计（[`profile_query`]）
+pub fn supports_profiling(db_type: DatabaseType) -> bool {
+    matches!(db_type, DatabaseType::SQLite | DatabaseType::PostgreSQL | DatabaseType::MySQL)
+}
+
+/// 再次执行查询并读取运行时统计
+///
+/// SQLite 读取语句计数器（全表扫描步数、排序、自动索引），PostgreSQL 使用
+/// `EXPLAIN (ANALYZE, BUFFERS)`（缓冲区、临时文件、被过滤的行），MySQL 比较执行前后的
+/// 会话状态变量（读取的行、临时表、排序）。查询会被真正执行，调用方需只传入只读查询
+pub async fn profile_query(config: &ConnectionConfig, sql: &str) -> Result<QueryProfile, DbError> {
+    let (effective_config, _tunnel) = setup_ssh_tunnel_if_enabled(config).await?;
+
+    match effective_config.db_type {
+        DatabaseType::SQLite => {
+            let sql = sql.to_string();
+            task::spawn_blocking(move || sqlite::profile(&effective_config, &sql))
+                .await
+                .map_err(|e| DbError::Query(format!("任务执行失败: {}", e)))?
+        }
+        DatabaseType::PostgreSQL => postgres::profile(&effective_config, sql).await,
+        DatabaseType::MySQL => mysql::profile(&effective_config, sql).await,
+        db_type => Err(DbError::Query(format!("{} 不支持查询分析", db_type.display_name()))),
+    }
+}
+
+/// 查询是否逐批读取行（SQLite、PostgreSQL、MySQL 的查询语句）
+pub fn supports_streaming(db_type: DatabaseType, sql: &str) -> bool {
+    matches!(db_type, DatabaseType::SQLite | DatabaseType::PostgreSQL | DatabaseType::MySQL)
+        && is_query_statement(sql, &db_type)
+}
+
+/// 流式执行 SQL 查询
+///
+/// 支持流式读取的查询每读取 `chunk_size` 行调用一次 `on_chunk(列名, 本批行)`，
+/// 回调返回 `false` 时停止读取；返回的结果只包含列名，`original_row_count` 为已读取的总行数。
+/// 其他查询退回 [`execute_query`]，行数据在返回的结果中
+pub async fn execute_query_streaming<F>(
+    config: &ConnectionConfig,
+    sql: &str,
+    chunk_size: usize,
+    on_chunk: F,
+) -> Result<QueryResult, DbError>
+where
+    F: FnMut(&[String], Vec<Vec<String>>) -> bool + Send + 'static,
+{
+    if !supports_streaming(config.db_type, sql) {
+        return execute_query(config, sql).await;
+    }
+
+    let (effective_config, _tunnel) = setup_ssh_tunnel_if_enabled(config).await?;
+    let sql = sql.to_string();
+    let chunk_size = chunk_size.max(1);
+    match effective_config.db_type {
+        DatabaseType::SQLite => {
+            task::spawn_blocking(move || sqlite::execute_streaming(&effective_config, &sql, chunk_size, on_chunk))
+                .await
+                .map_err(|e| DbError::Query(format!("任务执行失败: {}", e)))?
+        }
+        DatabaseType::PostgreSQL | DatabaseType::MySQL => {
+            let mut on_chunk = on_chunk;
+            let mut delivered = false;
+            let result = stream_on(&effective_config, &sql, chunk_size, |columns, rows| {
+                delivered = true;
+                on_chunk(columns, rows)
+            })
+            .await;
+            // 已发送过行时重试会重复发送，只在第一批行之前失效时重试
+            match result {
+                Err(e) if !delivered && retries_stale_connection(effective_config.db_type, &sql, &e) => {
+                    discard_stale_connection(&effective_config, &e).await;
+                    stream_on(&effective_config, &sql, chunk_size, on_chunk).await
+                }
+                result => result,
+            }
+        }
+        _ => unreachable!("supports_streaming 已排除其他数据库"),
+    }
+}
+
+/// 在已建立隧道的 PostgreSQL/MySQL 连接上流式执行查询
+async fn stream_on(
+    config: &ConnectionConfig,
+    sql: &str,
+    chunk_size: usize,
+    on_chunk: impl FnMut(&[String], Vec<Vec<String>>) -> bool,
+) -> Result<QueryResult, DbError> {
+    match config.db_type {
+        DatabaseType::PostgreSQL => postgres::execute_streaming(config, sql, chunk_size, on_chunk).await,
+        _ => mysql::execute_streaming(config, sql, chunk_size, on_chunk).await,
+    }
+}
+
+/// 流式读取结束后的结果（行已通过回调发送）
+pub(crate) fn streamed_result(columns: Vec<String>, total_rows: usize) -> QueryResult {
+    QueryResult {
+        original_row_count: Some(total_rows),
+        ..query_result(columns, vec![])
+    }
+}
+
+/// 查询是否按页流式读取（ClickHouse 的 SELECT/WITH 查询，自带 FORMAT 子句的除外）
+pub fn supports_paging(db_type: DatabaseType, sql: &str) -> bool {
+    let sql_lower = sql.trim().to_lowercase();
+    db_type == DatabaseType::ClickHouse
+        && (sql_lower.starts_with("select") || sql_lower.starts_with("with"))
+        && !sql_lower.split_whitespace().any(|word| word == "format")
+}
+
+/// 将查询包装为分页子查询（去掉末尾分号，换行包裹以兼容最后一行的行尾注释）
+pub fn paged_sql(sql: &str, offset: usize, limit: usize) -> String {
+    let inner = sql.trim().trim_end_matches(';').trim_end();
+    format!("SELECT * FROM (\n{}\n) LIMIT {} OFFSET {}", inner, limit, offset)
+}
+
+/// 读取查询结果的一页
+///
+/// 仅读取 `offset` 之后的 `limit` 行，结果的 `truncated` 表示还有下一页
+pub async fn execute_query_page(
+    config: &ConnectionConfig,
+    sql: &str,
+    offset: usize,
+    limit: usize,
+) -> Result<QueryResult, DbError> {
+    if !supports_paging(config.db_type, sql) {
+        return Err(DbError::Query("该查询不支持分页读取".to_string()));
+    }
+
+    let (effective_config, _tunnel) = setup_ssh_tunnel_if_enabled(config).await?;
+    #[cfg(feature = "clickhouse")]
+    {
+        let sql = sql.to_string();
+        task::spawn_blocking(move || clickhouse::execute_page(&effective_config, &sql, offset, limit))
+            .await
+            .map_err(|e| DbError::Query(format!("任务执行失败: {}", e)))?
+    }
+    #[cfg(not(feature = "clickhouse"))]
+    {
+        let _ = (effective_config, offset, limit);
+        Err(clickhouse_disabled())
+    }
+}
+
+/// Redis 键的值（按类型展开为表格）
+#[derive(Debug, Clone)]
+pub struct RedisKeyValue {
+    /// 键类型（string/hash/list/set/zset/stream）
+    pub key_type: String,
+    /// 剩余生存时间（秒），`None` 表示永不过期
+    pub ttl: Option<i64>,
+    /// 按类型展开的值
+    pub result: QueryResult,
+}
+
+/// 读取 Redis 键的值（按类型选择读取命令并附带 TTL）
+pub async fn get_redis_key_value(config: &ConnectionConfig, key: &str) -> Result<RedisKeyValue, DbError> {
+    if !matches!(config.db_type, DatabaseType::Redis) {
+        return Err(DbError::Query("仅 Redis 连接支持键值浏览".to_string()));
+    }
+
+    let (effective_config, _tunnel) = setup_ssh_tunnel_if_enabled(config).await?;
+    #[cfg(feature = "redis")]
+    {
+        let key = key.to_string();
+        task::spawn_blocking(move || redis::get_key_value(&effective_config, &key))
+            .await
+            .map_err(|e| DbError::Query(format!("任务执行失败: {}", e)))?
+    }
+    #[cfg(not(feature = "redis"))]
+    {
+        let _ = (effective_config, key);
+        Err(redis_disabled())
+    }
+}
+
+/// 将一行控制台命令拆分为参数
+///
+/// 按空白分隔，支持单引号、双引号包裹（双引号内支持 `\"`、`\n` 等转义），与 redis-cli 的写法一致
+pub fn split_command_line(line: &str) -> Result<Vec<String>, DbError> {
+    let mut args = Vec::new();
+    let mut chars = line.chars().peekable();
+
+    loop {
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        let Some(&first) = chars.peek() else {
+            break;
+        };
+
+        let mut arg = String::new();
+        if first == '"' || first == '\'' {
+            chars.next();
+            let mut closed = false;
+            while let Some(c) = chars.next() {
+                match c {
+                    c if c == first => {
+                        closed = true;
+                        break;
+                    }
+                    '\\' if first == '"' => match chars.next() {
+                        Some('n') => arg.push('\n'),
+                        Some('r') => arg.push('\r'),
+                        Some('t') => arg.push('\t'),
+                        Some(other) => arg.push(other),
+                        None => break,
+                    },
+                    c => arg.push(c),
+                }
+            }
+            if !closed {
+                return Err(DbError::Query(format!("引号未闭合: {}", line)));
+            }
+        } else {
+            while let Some(c) = chars.next_if(|c| !c.is_whitespace()) {
+                arg.push(c);
+            }
+        }
+        args.push(arg);
+    }
+    Ok(args)
+}
+
+/// 按顶层分号把 SQL 脚本拆分为语句（忽略引号和注释中的分号）
+///
+/// 去掉首尾空白和末尾分号，跳过空语句和只有注释的语句
+pub fn split_statements(script: &str) -> Vec<String> {
+    let mut statements = Vec::new();
+    let mut current = String::new();
+    // 当前语句中是否有注释以外的内容
+    let mut has_code = false;
+    let mut chars = script.chars().peekable();
+
+    let mut finish = |current: &mut String, has_code: &mut bool| {
+        if *has_code {
+            statements.push(current.trim().to_string());
+        }
+        current.clear();
+        *has_code = false;
+    };
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' | '"' | '`' => {
+                has_code = true;
+                current.push(c);
+                for next in chars.by_ref() {
+                    current.push(next);
+                    if next == c {
+                        break;
+                    }
+                }
+            }
+            '-' if chars.peek() == Some(&'-') => {
+                current.push(c);
+                while let Some(next) = chars.next_if(|&next| next != '\n') {
+                    current.push(next);
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                current.push(c);
+                let mut prev = '\0';
+                for next in chars.by_ref() {
+                    current.push(next);
+                    if prev == '*' && next == '/' {
+                        break;
+                    }
+                    prev = next;
+                }
+            }
+            ';' => finish(&mut current, &mut has_code),
+            c => {
+                has_code |= !c.is_whitespace();
+                current.push(c);
+            }
+        }
+    }
+    finish(&mut current, &mut has_code);
+    statements
+}
+
+/// 修改 SQLite（SQLCipher）数据库的加密密钥
+pub async fn change_sqlite_key(config: &ConnectionConfig, new_key: &str) -> Result<(), DbError> {
+    if !matches!(config.db_type, DatabaseType::SQLite) {
+        return Err(DbError::Query("仅 SQLite 数据库支持修改加密密钥".to_string()));
+    }
+
+    let config = config.clone();
+    let new_key = new_key.to_string();
+    task::spawn_blocking(move || sqlite::change_key(&config, &new_key))
+        .await
+        .map_err(|e| DbError::Query(format!("任务执行失败: {}", e)))?
+}
+
 // ============================================================================
 // 辅助函数
 // ============================================================================
@@ -131,8 +820,8 @@ pub async fn execute_query(config: &ConnectionConfig, sql: &str) -> Result<Query
 async fn setup_ssh_tunnel_if_enabled(
     config: &ConnectionConfig,
 ) -> Result<(ConnectionConfig, Option<Arc<SshTunnel>>), DbError> {
-    // SQLite 不需要 SSH 隧道
-    if matches!(config.db_type, DatabaseType::SQLite) {
+    // 本地文件型数据库不需要 SSH 隧道
+    if config.db_type.is_file_based() {
         return Ok((config.clone(), None));
     }
 
@@ -182,6 +871,42 @@ async fn setup_ssh_tunnel_if_enabled(
     Ok((effective_config, Some(tunnel)))
 }
 
+/// 未启用 `duckdb` 特性时的错误
+#[cfg(not(feature = "duckdb"))]
+fn duckdb_disabled() -> DbError {
+    DbError::Connection("当前版本未启用 DuckDB 支持，请使用 --features duckdb 重新编译".to_string())
+}
+
+/// 未启用 `oracle` 特性时的错误
+#[cfg(not(feature = "oracle"))]
+fn oracle_disabled() -> DbError {
+    DbError::Connection("当前版本未启用 Oracle 支持，请使用 --features oracle 重新编译".to_string())
+}
+
+/// 未启用 `odbc` 特性时的错误
+#[cfg(not(feature = "odbc"))]
+fn odbc_disabled() -> DbError {
+    DbError::Connection("当前版本未启用 ODBC 支持，请使用 --features odbc 重新编译".to_string())
+}
+
+/// 未启用 `redis` 特性时的错误
+#[cfg(not(feature = "redis"))]
+fn redis_disabled() -> DbError {
+    DbError::Connection("当前版本未启用 Redis 支持，请使用 --features redis 重新编译".to_string())
+}
+
+/// 未启用 `mongodb` 特性时的错误
+#[cfg(not(feature = "mongodb"))]
+fn mongodb_disabled() -> DbError {
+    DbError::Connection("当前版本未启用 MongoDB 支持，请使用 --features mongodb 重新编译".to_string())
+}
+
+/// 未启用 `clickhouse` 特性时的错误
+#[cfg(not(feature = "clickhouse"))]
+fn clickhouse_disabled() -> DbError {
+    DbError::Connection("当前版本未启用 ClickHouse 支持，请使用 --features clickhouse 重新编译".to_string())
+}
+
 /// 判断 SQL 是否为查询语句（返回结果集）
 #[inline]
 pub(crate) fn is_query_statement(sql: &str, db_type: &DatabaseType) -> bool {
@@ -197,6 +922,21 @@ pub(crate) fn is_query_statement(sql: &str, db_type: &DatabaseType) -> bool {
         DatabaseType::MySQL => {
             common || sql_lower.starts_with("show") || sql_lower.starts_with("describe")
         }
+        DatabaseType::DuckDB => {
+            common
+                || ["pragma", "show", "describe", "summarize", "from", "pivot", "unpivot"]
+                    .iter()
+                    .any(|kw| sql_lower.starts_with(kw))
+        }
+        DatabaseType::Oracle | DatabaseType::Odbc => common,
+        DatabaseType::ClickHouse => {
+            common
+                || ["show", "describe", "desc", "exists"]
+                    .iter()
+                    .any(|kw| sql_lower.starts_with(kw))
+        }
+        // Redis/MongoDB 命令都会返回结果
+        DatabaseType::Redis | DatabaseType::MongoDB => true,
     }
 }
 
@@ -288,6 +1028,47 @@ pub async fn get_triggers(config: &ConnectionConfig) -> Result<Vec<TriggerInfo>,
         }
         DatabaseType::PostgreSQL => postgres::get_triggers(&effective_config).await,
         DatabaseType::MySQL => mysql::get_triggers(&effective_config).await,
+        #[cfg(feature = "oracle")]
+        DatabaseType::Oracle => {
+            task::spawn_blocking(move || oracle::get_triggers(&effective_config))
+                .await
+                .map_err(|e| DbError::Query(format!("任务执行失败: {}", e)))?
+        }
+        #[cfg(not(feature = "oracle"))]
+        DatabaseType::Oracle => Err(oracle_disabled()),
+        // ODBC 没有通用的触发器目录函数
+        DatabaseType::Odbc => Ok(Vec::new()),
+        // DuckDB/ClickHouse/Redis/MongoDB 不支持触发器
+        DatabaseType::DuckDB | DatabaseType::ClickHouse | DatabaseType::Redis | DatabaseType::MongoDB => Ok(Vec::new()),
+    }
+}
+
+// ============================================================================
+// 视图查询（用于依赖关系图）
+// ============================================================================
+
+/// 视图信息
+#[derive(Debug, Clone)]
+pub struct ViewInfo {
+    pub name: String,
+    /// 视图的 SELECT 定义
+    pub definition: String,
+}
+
+/// 获取数据库的视图列表及定义
+pub async fn get_views(config: &ConnectionConfig) -> Result<Vec<ViewInfo>, DbError> {
+    let (effective_config, _tunnel) = setup_ssh_tunnel_if_enabled(config).await?;
+
+    match effective_config.db_type {
+        DatabaseType::SQLite => {
+            task::spawn_blocking(move || sqlite::get_views(&effective_config))
+                .await
+                .map_err(|e| DbError::Query(format!("任务执行失败: {}", e)))?
+        }
+        DatabaseType::PostgreSQL => postgres::get_views(&effective_config).await,
+        DatabaseType::MySQL => mysql::get_views(&effective_config).await,
+        // 其他数据库暂不读取视图定义，依赖关系图只显示外键和触发器
+        _ => Ok(Vec::new()),
     }
 }
 
@@ -296,10 +1077,261 @@ pub async fn get_routines(config: &ConnectionConfig) -> Result<Vec<RoutineInfo>,
     let (effective_config, _tunnel) = setup_ssh_tunnel_if_enabled(config).await?;
 
     match effective_config.db_type {
-        // SQLite 不支持存储过程
-        DatabaseType::SQLite => Ok(Vec::new()),
+        // SQLite/DuckDB/ClickHouse 不支持存储过程（ClickHouse 的 UDF 为 lambda 表达式）
+        DatabaseType::SQLite | DatabaseType::DuckDB | DatabaseType::ClickHouse => Ok(Vec::new()),
         DatabaseType::PostgreSQL => postgres::get_routines(&effective_config).await,
         DatabaseType::MySQL => mysql::get_routines(&effective_config).await,
+        // Oracle 存储过程暂不支持浏览，ODBC 无通用目录
+        DatabaseType::Oracle | DatabaseType::Odbc => Ok(Vec::new()),
+        // Redis 脚本、MongoDB 服务端函数不属于存储过程
+        DatabaseType::Redis | DatabaseType::MongoDB => Ok(Vec::new()),
+    }
+}
+
+// ============================================================================
+// 序列与自增计数器
+// ============================================================================
+
+/// 序列信息（PostgreSQL 序列，MySQL/SQLite 表的自增计数器）
+#[derive(Debug, Clone)]
+pub struct SequenceInfo {
+    /// 序列名（MySQL/SQLite 为表名）
+    pub name: String,
+    /// 所属表（PostgreSQL 未绑定到列的序列为 `None`）
+    pub table_name: Option<String>,
+    /// 所属列
+    pub column_name: Option<String>,
+    /// 最近生成的值（尚未使用时为 `None`）
+    pub last_value: Option<i64>,
+    /// 下一个值
+    pub next_value: Option<i64>,
+}
+
+impl SequenceInfo {
+    /// 生成将下一个值设置为 `next_value` 的 SQL
+    ///
+    /// - PostgreSQL：`ALTER SEQUENCE ... RESTART WITH`
+    /// - MySQL：`ALTER TABLE ... AUTO_INCREMENT =`（InnoDB 不会低于当前最大值 + 1）
+    /// - SQLite：更新 `sqlite_sequence` 中的计数（实际取值不会低于当前最大 rowid + 1）
+    pub fn restart_sql(&self, db_type: DatabaseType, next_value: i64) -> Result<String, String> {
+        // MySQL/SQLite 的自增计数器属于表
+        let table = self.table_name.as_deref().unwrap_or(&self.name);
+        match db_type {
+            DatabaseType::PostgreSQL => Ok(format!(
+                "ALTER SEQUENCE \"{}\" RESTART WITH {};",
+                self.name.replace('"', "\"\""),
+                next_value
+            )),
+            DatabaseType::MySQL => Ok(format!(
+                "ALTER TABLE `{}` AUTO_INCREMENT = {};",
+                table.replace('`', "``"),
+                next_value
+            )),
+            DatabaseType::SQLite => Ok(format!(
+                "UPDATE sqlite_sequence SET seq = {} WHERE name = '{}';",
+                next_value - 1,
+                table.replace('\'', "''")
+            )),
+            _ => Err(format!("{} 不支持修改序列", db_type.display_name())),
+        }
+    }
+}
+
+/// 获取当前数据库的序列（PostgreSQL）或自增计数器（MySQL/SQLite）
+pub async fn get_sequences(config: &ConnectionConfig) -> Result<Vec<SequenceInfo>, DbError> {
+    let (effective_config, _tunnel) = setup_ssh_tunnel_if_enabled(config).await?;
+
+    match effective_config.db_type {
+        DatabaseType::SQLite => {
+            task::spawn_blocking(move || sqlite::get_sequences(&effective_config))
+                .await
+                .map_err(|e| DbError::Query(format!("任务执行失败: {}", e)))?
+        }
+        DatabaseType::PostgreSQL => postgres::get_sequences(&effective_config).await,
+        DatabaseType::MySQL => mysql::get_sequences(&effective_config).await,
+        // 其他数据库暂不支持序列浏览
+        DatabaseType::DuckDB
+        | DatabaseType::Oracle
+        | DatabaseType::Odbc
+        | DatabaseType::ClickHouse
+        | DatabaseType::Redis
+        | DatabaseType::MongoDB => Ok(Vec::new()),
+    }
+}
+
+/// 获取服务器时区相对 UTC 的偏移（秒），不支持的数据库返回 `None`
+///
+/// SQLite 没有服务器时区，DuckDB 的 TIMESTAMP 按 UTC 存储，都视为 UTC。
+pub async fn get_server_utc_offset(config: &ConnectionConfig) -> Result<Option<i32>, DbError> {
+    let sql = match config.db_type {
+        DatabaseType::SQLite | DatabaseType::DuckDB => return Ok(Some(0)),
+        DatabaseType::PostgreSQL => "SELECT CAST(EXTRACT(TIMEZONE FROM now()) AS INTEGER)",
+        DatabaseType::MySQL => "SELECT TIMESTAMPDIFF(SECOND, UTC_TIMESTAMP(), NOW())",
+        DatabaseType::Oracle => {
+            "SELECT EXTRACT(TIMEZONE_HOUR FROM SYSTIMESTAMP) * 3600 + EXTRACT(TIMEZONE_MINUTE FROM SYSTIMESTAMP) * 60 FROM DUAL"
+        }
+        DatabaseType::ClickHouse => "SELECT timeZoneOffset(now())",
+        DatabaseType::Odbc | DatabaseType::Redis | DatabaseType::MongoDB => return Ok(None),
+    };
+    let result = execute_query(config, sql).await?;
+    Ok(result
+        .rows
+        .first()
+        .and_then(|row| row.first())
+        .and_then(|v| v.trim().parse::<f64>().ok())
+        .map(|secs| secs.round() as i32))
+}
+
+// ============================================================================
+// 查询分析
+// ============================================================================
+
+/// 查询的运行时统计（由 [`profile_query`] 读取）
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct QueryProfile {
+    /// 分析时执行查询的耗时（毫秒）
+    pub elapsed_ms: u64,
+    /// 返回的行数
+    pub rows: usize,
+    /// 统计项 (名称, 值)
+    pub stats: Vec<(String, String)>,
+    /// 执行计划等详细信息
+    pub details: Option<String>,
+}
+
+// ============================================================================
+// 表大小概览
+// ============================================================================
+
+/// 表的大小与行数（来自系统目录，行数为估算值）
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TableSizeInfo {
+    /// 表名
+    pub name: String,
+    /// 行数（PostgreSQL/MySQL 为统计信息中的估算值，未统计时为 `None`）
+    pub row_count: Option<i64>,
+    /// 数据大小（字节）
+    pub data_bytes: Option<i64>,
+    /// 索引大小（字节）
+    pub index_bytes: Option<i64>,
+}
+
+impl TableSizeInfo {
+    /// 数据与索引的总大小（字节）
+    pub fn total_bytes(&self) -> Option<i64> {
+        match (self.data_bytes, self.index_bytes) {
+            (None, None) => None,
+            (data, index) => Some(data.unwrap_or(0) + index.unwrap_or(0)),
+        }
+    }
+}
+
+/// 获取当前数据库各表的大小和行数
+pub async fn get_table_sizes(config: &ConnectionConfig) -> Result<Vec<TableSizeInfo>, DbError> {
+    let (effective_config, _tunnel) = setup_ssh_tunnel_if_enabled(config).await?;
+
+    match effective_config.db_type {
+        DatabaseType::SQLite => {
+            task::spawn_blocking(move || sqlite::get_table_sizes(&effective_config))
+                .await
+                .map_err(|e| DbError::Query(format!("任务执行失败: {}", e)))?
+        }
+        DatabaseType::PostgreSQL => postgres::get_table_sizes(&effective_config).await,
+        DatabaseType::MySQL => mysql::get_table_sizes(&effective_config).await,
+        db_type => Err(DbError::Query(format!("{} 暂不支持大小概览", db_type.display_name()))),
+    }
+}
+
+// ============================================================================
+// 会话监控
+// ============================================================================
+
+/// 服务器会话信息（PostgreSQL `pg_stat_activity`，MySQL `PROCESSLIST`）
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SessionInfo {
+    /// 会话 ID（PostgreSQL 为 pid，MySQL 为连接 ID）
+    pub id: i64,
+    /// 用户
+    pub user: String,
+    /// 数据库
+    pub database: Option<String>,
+    /// 客户端地址
+    pub client: Option<String>,
+    /// 状态（PostgreSQL 的 state，MySQL 的 COMMAND）
+    pub state: String,
+    /// 当前状态持续的秒数
+    pub duration_secs: Option<i64>,
+    /// 正在执行或最近执行的 SQL
+    pub query: String,
+    /// 等待事件（PostgreSQL 的 wait_event，MySQL 的 STATE）
+    pub wait: Option<String>,
+    /// 阻塞此会话的会话 ID（仅 PostgreSQL）
+    pub blocked_by: Vec<i64>,
+}
+
+impl SessionInfo {
+    /// 是否正在执行查询
+    pub fn is_active(&self) -> bool {
+        !matches!(self.state.as_str(), "idle" | "Sleep" | "Daemon" | "Binlog Dump" | "")
+    }
+
+    /// 生成终止会话的 SQL，`query_only` 为 true 时只取消当前查询
+    pub fn kill_sql(&self, db_type: DatabaseType, query_only: bool) -> Result<String, String> {
+        match (db_type, query_only) {
+            (DatabaseType::PostgreSQL, true) => Ok(format!("SELECT pg_cancel_backend({});", self.id)),
+            (DatabaseType::PostgreSQL, false) => Ok(format!("SELECT pg_terminate_backend({});", self.id)),
+            (DatabaseType::MySQL, true) => Ok(format!("KILL QUERY {};", self.id)),
+            (DatabaseType::MySQL, false) => Ok(format!("KILL {};", self.id)),
+            _ => Err(format!("{} 不支持终止会话", db_type.display_name())),
+        }
+    }
+}
+
+/// 获取服务器上的会话（不含本工具用于查询的会话）
+pub async fn get_sessions(config: &ConnectionConfig) -> Result<Vec<SessionInfo>, DbError> {
+    let (effective_config, _tunnel) = setup_ssh_tunnel_if_enabled(config).await?;
+
+    match effective_config.db_type {
+        DatabaseType::PostgreSQL => postgres::get_sessions(&effective_config).await,
+        DatabaseType::MySQL => mysql::get_sessions(&effective_config).await,
+        db_type => Err(DbError::Query(format!("{} 暂不支持会话监控", db_type.display_name()))),
+    }
+}
+
+// ============================================================================
+// 用户与权限
+// ============================================================================
+
+/// 权限授予记录（当前数据库范围内）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GrantInfo {
+    /// 授权的表（数据库级权限为空字符串）
+    pub table_name: String,
+    /// 权限类型（如 `SELECT`）
+    pub privilege: String,
+}
+
+/// 获取用户/角色列表
+///
+/// MySQL 返回 `'user'@'host'` 形式的账户名，PostgreSQL 返回角色名（不含 `pg_` 内置角色）
+pub async fn get_users(config: &ConnectionConfig) -> Result<Vec<String>, DbError> {
+    let (effective_config, _tunnel) = setup_ssh_tunnel_if_enabled(config).await?;
+
+    match effective_config.db_type {
+        DatabaseType::MySQL => mysql::get_users(&effective_config).await,
+        DatabaseType::PostgreSQL => postgres::get_users(&effective_config).await,
+        db_type => Err(DbError::Query(format!("{} 暂不支持权限管理", db_type.display_name()))),
+    }
+}
+
+/// 获取用户在当前数据库中的权限（数据库级和表级）
+pub async fn get_grants(config: &ConnectionConfig, user: &str) -> Result<Vec<GrantInfo>, DbError> {
+    let (effective_config, _tunnel) = setup_ssh_tunnel_if_enabled(config).await?;
+
+    match effective_config.db_type {
+        DatabaseType::MySQL => mysql::get_grants(&effective_config, user).await,
+        DatabaseType::PostgreSQL => postgres::get_grants(&effective_config, user).await,
+        db_type => Err(DbError::Query(format!("{} 暂不支持权限管理", db_type.display_name()))),
     }
 }
 
@@ -339,7 +1371,114 @@ pub async fn get_foreign_keys(config: &ConnectionConfig) -> Result<Vec<ForeignKe
         }
         DatabaseType::PostgreSQL => postgres::get_foreign_keys(&effective_config).await,
         DatabaseType::MySQL => mysql::get_foreign_keys(&effective_config).await,
+        #[cfg(feature = "oracle")]
+        DatabaseType::Oracle => {
+            task::spawn_blocking(move || oracle::get_foreign_keys(&effective_config))
+                .await
+                .map_err(|e| DbError::Query(format!("任务执行失败: {}", e)))?
+        }
+        #[cfg(not(feature = "oracle"))]
+        DatabaseType::Oracle => Err(oracle_disabled()),
+        #[cfg(feature = "odbc")]
+        DatabaseType::Odbc => {
+            task::spawn_blocking(move || odbc::get_foreign_keys(&effective_config))
+                .await
+                .map_err(|e| DbError::Query(format!("任务执行失败: {}", e)))?
+        }
+        #[cfg(not(feature = "odbc"))]
+        DatabaseType::Odbc => Err(odbc_disabled()),
+        #[cfg(feature = "duckdb")]
+        DatabaseType::DuckDB => {
+            task::spawn_blocking(move || duckdb::get_foreign_keys(&effective_config))
+                .await
+                .map_err(|e| DbError::Query(format!("任务执行失败: {}", e)))?
+        }
+        #[cfg(not(feature = "duckdb"))]
+        DatabaseType::DuckDB => Err(duckdb_disabled()),
+        // Redis 没有表结构和外键，MongoDB 文档之间、ClickHouse 表之间没有外键约束
+        DatabaseType::Redis | DatabaseType::MongoDB | DatabaseType::ClickHouse => Ok(Vec::new()),
+    }
+}
+
+/// 获取表的建表语句（CREATE TABLE 及相关索引）
+///
+/// MySQL/ClickHouse 使用 SHOW CREATE TABLE，SQLite/DuckDB 读取系统目录中保存的原始语句，
+/// PostgreSQL 按系统目录重建；其他关系型数据库根据列信息重建，不包含索引和约束
+pub async fn get_table_ddl(config: &ConnectionConfig, table: &str) -> Result<String, DbError> {
+    match config.db_type {
+        DatabaseType::Redis | DatabaseType::MongoDB => {
+            return Err(DbError::Query(format!("{} 没有建表语句", config.db_type.display_name())));
+        }
+        DatabaseType::ClickHouse => {
+            let sql = format!("SHOW CREATE TABLE `{}`", table.replace('`', "``"));
+            return first_cell(execute_query(config, &sql).await?, table);
+        }
+        DatabaseType::DuckDB => {
+            let sql = format!(
+                "SELECT sql FROM duckdb_tables() WHERE table_name = '{}'",
+                table.replace('\'', "''")
+            );
+            return first_cell(execute_query(config, &sql).await?, table);
+        }
+        DatabaseType::Oracle | DatabaseType::Odbc => {
+            let columns = get_table_columns(config, table).await?;
+            if columns.is_empty() {
+                return Err(DbError::Query(format!("表 {} 不存在", table)));
+            }
+            return Ok(format!(
+                "-- 根据列信息重建，不包含索引和约束\n{}",
+                create_table_from_columns(table, &columns)
+            ));
+        }
+        _ => {}
+    }
+
+    let (effective_config, _tunnel) = setup_ssh_tunnel_if_enabled(config).await?;
+    let table = table.to_string();
+
+    match effective_config.db_type {
+        DatabaseType::SQLite => {
+            task::spawn_blocking(move || sqlite::get_table_ddl(&effective_config, &table))
+                .await
+                .map_err(|e| DbError::Query(format!("任务执行失败: {}", e)))?
+        }
+        DatabaseType::PostgreSQL => postgres::get_table_ddl(&effective_config, &table).await,
+        _ => mysql::get_table_ddl(&effective_config, &table).await,
+    }
+}
+
+/// 取查询结果第一行第一列作为建表语句
+fn first_cell(result: QueryResult, table: &str) -> Result<String, DbError> {
+    result
+        .rows
+        .into_iter()
+        .next()
+        .and_then(|row| row.into_iter().next())
+        .filter(|sql| !sql.is_empty() && sql != "NULL")
+        .ok_or_else(|| DbError::Query(format!("表 {} 不存在", table)))
+}
+
+/// 根据列信息重建 CREATE TABLE 语句
+pub fn create_table_from_columns(table: &str, columns: &[ColumnInfo]) -> String {
+    let quote = |name: &str| format!("\"{}\"", name.replace('"', "\"\""));
+    let mut lines: Vec<String> = columns
+        .iter()
+        .map(|c| {
+            let mut line = format!("    {} {}", quote(&c.name), c.data_type);
+            if !c.is_nullable {
+                line.push_str(" NOT NULL");
+            }
+            if let Some(default) = &c.default_value {
+                line.push_str(&format!(" DEFAULT {}", default));
+            }
+            line
+        })
+        .collect();
+    let primary_key: Vec<String> = columns.iter().filter(|c| c.is_primary_key).map(|c| quote(&c.name)).collect();
+    if !primary_key.is_empty() {
+        lines.push(format!("    PRIMARY KEY ({})", primary_key.join(", ")));
     }
+    format!("CREATE TABLE {} (\n{}\n);", quote(table), lines.join(",\n"))
 }
 
 /// 获取指定表的列信息
@@ -358,5 +1497,44 @@ pub async fn get_table_columns(
         }
         DatabaseType::PostgreSQL => postgres::get_columns(&effective_config, &table).await,
         DatabaseType::MySQL => mysql::get_columns(&effective_config, &table).await,
+        #[cfg(feature = "oracle")]
+        DatabaseType::Oracle => {
+            task::spawn_blocking(move || oracle::get_columns(&effective_config, &table))
+                .await
+                .map_err(|e| DbError::Query(format!("任务执行失败: {}", e)))?
+        }
+        #[cfg(not(feature = "oracle"))]
+        DatabaseType::Oracle => Err(oracle_disabled()),
+        #[cfg(feature = "odbc")]
+        DatabaseType::Odbc => {
+            task::spawn_blocking(move || odbc::get_columns(&effective_config, &table))
+                .await
+                .map_err(|e| DbError::Query(format!("任务执行失败: {}", e)))?
+        }
+        #[cfg(not(feature = "odbc"))]
+        DatabaseType::Odbc => Err(odbc_disabled()),
+        #[cfg(feature = "duckdb")]
+        DatabaseType::DuckDB => {
+            task::spawn_blocking(move || duckdb::get_columns(&effective_config, &table))
+                .await
+                .map_err(|e| DbError::Query(format!("任务执行失败: {}", e)))?
+        }
+        #[cfg(not(feature = "duckdb"))]
+        DatabaseType::DuckDB => Err(duckdb_disabled()),
+        // Redis 没有表结构和外键
+        DatabaseType::Redis => Ok(Vec::new()),
+        // MongoDB 按抽样文档推断字段
+        #[cfg(feature = "mongodb")]
+        DatabaseType::MongoDB => mongodb::get_columns(&effective_config, &table).await,
+        #[cfg(not(feature = "mongodb"))]
+        DatabaseType::MongoDB => Err(mongodb_disabled()),
+        #[cfg(feature = "clickhouse")]
+        DatabaseType::ClickHouse => {
+            task::spawn_blocking(move || clickhouse::get_columns(&effective_config, &table))
+                .await
+                .map_err(|e| DbError::Query(format!("任务执行失败: {}", e)))?
+        }
+        #[cfg(not(feature = "clickhouse"))]
+        DatabaseType::ClickHouse => Err(clickhouse_disabled()),
     }
 }
@@ -0,0 +1,173 @@
+//! MongoDB 查询实现
+//!
+//! 文档数据库按以下方式映射到通用的浏览模型：
+//! - 数据库列表：listDatabases
+//! - 表列表：当前库中的集合
+//! - 查询：编辑器中输入 JSON 格式的数据库命令（`find`、`aggregate` 等），
+//!   返回游标的命令逐批读取全部文档，其他命令显示响应文档
+
+use ::mongodb::bson::{Bson, Document};
+use crate::core::constants;
+use crate::database::{ColumnInfo, ConnectionConfig, DbError, QueryResult, POOL_MANAGER};
+use super::document::documents_to_result;
+
+/// 返回游标的命令（结果需要通过 getMore 逐批读取）
+const CURSOR_COMMANDS: &[&str] = &["find", "aggregate", "listCollections", "listIndexes"];
+
+/// 推断集合字段时抽样的文档数量
+const SCHEMA_SAMPLE_SIZE: i64 = 100;
+
+fn query_err(e: ::mongodb::error::Error) -> DbError {
+    DbError::Query(e.to_string())
+}
+
+/// 当前选中的数据库
+fn current_database(config: &ConnectionConfig) -> Result<&str, DbError> {
+    match config.database.trim() {
+        "" => Err(DbError::Query("请先在侧边栏选择数据库".to_string())),
+        name => Ok(name),
+    }
+}
+
+/// 获取数据库列表
+pub async fn get_databases(config: &ConnectionConfig) -> Result<Vec<String>, DbError> {
+    let client = POOL_MANAGER.get_mongo_client(config).await?;
+    let mut names = client
+        .list_database_names()
+        .await
+        .map_err(|e| DbError::Connection(format!("MongoDB 连接失败: {}", e)))?;
+    names.sort();
+    Ok(names)
+}
+
+/// 获取指定数据库的集合列表
+pub async fn get_collections(config: &ConnectionConfig, database: &str) -> Result<Vec<String>, DbError> {
+    let client = POOL_MANAGER.get_mongo_client(config).await?;
+    let mut names = client
+        .database(database)
+        .list_collection_names()
+        .await
+        .map_err(query_err)?;
+    names.sort();
+    Ok(names)
+}
+
+/// 解析编辑器中的 JSON 命令（支持扩展 JSON，如 `{"$oid": "..."}`）
+fn parse_command(input: &str) -> Result<Document, DbError> {
+    let value: serde_json::Value = serde_json::from_str(input.trim().trim_end_matches(';'))
+        .map_err(|e| DbError::Query(format!("JSON 格式错误: {}", e)))?;
+    let serde_json::Value::Object(map) = value else {
+        return Err(DbError::Query(
+            "MongoDB 命令必须是 JSON 对象，如 {\"find\": \"集合名\", \"filter\": {}}".to_string(),
+        ));
+    };
+    Document::try_from(map).map_err(|e| DbError::Query(format!("无法转换为 BSON: {}", e)))
+}
+
+/// 执行 JSON 命令
+pub async fn execute(config: &ConnectionConfig, input: &str) -> Result<QueryResult, DbError> {
+    let mut command = parse_command(input)?;
+    let Some(name) = command.keys().next().cloned() else {
+        return Err(DbError::Query("命令为空".to_string()));
+    };
+    let client = POOL_MANAGER.get_mongo_client(config).await?;
+    let db = client.database(current_database(config)?);
+
+    if !CURSOR_COMMANDS.contains(&name.as_str()) {
+        let reply = db.run_command(command).await.map_err(query_err)?;
+        return Ok(documents_to_result(
+            &[Bson::Document(reply).into_relaxed_extjson()],
+            config.mongodb_flatten,
+        ));
+    }
+
+    // aggregate 命令必须带 cursor 选项
+    if name == "aggregate" && !command.contains_key("cursor") {
+        command.insert("cursor", Document::new());
+    }
+
+    let limit = constants::database::MAX_RESULT_SET_ROWS;
+    let mut cursor = db.run_cursor_command(command).await.map_err(query_err)?;
+    let mut documents = Vec::new();
+    let mut truncated = false;
+    while cursor.advance().await.map_err(query_err)? {
+        if documents.len() >= limit {
+            truncated = true;
+            break;
+        }
+        let doc = cursor.deserialize_current().map_err(query_err)?;
+        documents.push(Bson::Document(doc).into_relaxed_extjson());
+    }
+
+    let mut result = documents_to_result(&documents, config.mongodb_flatten);
+    result.truncated = truncated;
+    Ok(result)
+}
+
+/// 按抽样文档推断集合字段（字段名、出现的 BSON 类型、是否在所有文档中出现）
+pub async fn get_columns(config: &ConnectionConfig, collection: &str) -> Result<Vec<ColumnInfo>, DbError> {
+    let client = POOL_MANAGER.get_mongo_client(config).await?;
+    let coll = client
+        .database(current_database(config)?)
+        .collection::<Document>(collection);
+    let mut cursor = coll
+        .find(Document::new())
+        .limit(SCHEMA_SAMPLE_SIZE)
+        .await
+        .map_err(query_err)?;
+
+    // (字段名, 出现过的类型, 出现次数)
+    let mut fields: Vec<(String, Vec<&'static str>, usize)> = Vec::new();
+    let mut sampled = 0usize;
+    while cursor.advance().await.map_err(query_err)? {
+        let doc = cursor.deserialize_current().map_err(query_err)?;
+        sampled += 1;
+        for (key, value) in &doc {
+            let type_name = bson_type_name(value);
+            match fields.iter_mut().find(|(name, _, _)| name == key) {
+                Some((_, types, count)) => {
+                    if !types.contains(&type_name) {
+                        types.push(type_name);
+                    }
+                    *count += 1;
+                }
+                None => fields.push((key.clone(), vec![type_name], 1)),
+            }
+        }
+    }
+
+    Ok(fields
+        .into_iter()
+        .map(|(name, types, count)| ColumnInfo {
+            is_primary_key: name == "_id",
+            is_nullable: count < sampled || types.contains(&"null"),
+            data_type: types.join(" | "),
+            default_value: None,
+            name,
+        })
+        .collect())
+}
+
+/// BSON 类型名（与 `$type` 操作符使用的别名一致）
+fn bson_type_name(value: &Bson) -> &'static str {
+    match value {
+        Bson::Double(_) => "double",
+        Bson::String(_) => "string",
+        Bson::Document(_) => "object",
+        Bson::Array(_) => "array",
+        Bson::Binary(_) => "binData",
+        Bson::ObjectId(_) => "objectId",
+        Bson::Boolean(_) => "bool",
+        Bson::DateTime(_) => "date",
+        Bson::Null => "null",
+        Bson::RegularExpression(_) => "regex",
+        Bson::JavaScriptCode(_) | Bson::JavaScriptCodeWithScope(_) => "javascript",
+        Bson::Int32(_) => "int",
+        Bson::Timestamp(_) => "timestamp",
+        Bson::Int64(_) => "long",
+        Bson::Decimal128(_) => "decimal",
+        Bson::MinKey => "minKey",
+        Bson::MaxKey => "maxKey",
+        _ => "unknown",
+    }
+}
@@ -1,17 +1,13 @@
 //! MySQL 查询实现
 
 use mysql_async::prelude::*;
+use crate::core::encode_blob;
 use crate::database::{ConnectionConfig, DbError, QueryResult, DatabaseType, POOL_MANAGER};
-use super::{query_result, exec_result, empty_result, is_query_statement, TriggerInfo, ForeignKeyInfo, ColumnInfo, RoutineInfo, RoutineType};
+use super::{query_result, exec_result, empty_result, streamed_result, is_query_statement, TriggerInfo, ViewInfo, ForeignKeyInfo, ColumnInfo, RoutineInfo, RoutineType, SequenceInfo, GrantInfo, SessionInfo, TableSizeInfo, QueryProfile};
 
 /// 获取 MySQL 数据库列表
 pub async fn get_databases(config: &ConnectionConfig) -> Result<Vec<String>, DbError> {
-    let pool = POOL_MANAGER.get_mysql_pool(config).await?;
-
-    let mut conn = pool
-        .get_conn()
-        .await
-        .map_err(|e| DbError::Connection(format!("MySQL 获取连接失败: {}", e)))?;
+    let mut conn = POOL_MANAGER.get_mysql_conn(config).await?;
 
     let databases: Vec<String> = conn
         .query("SHOW DATABASES")
@@ -31,15 +27,35 @@ pub async fn get_tables(config: &ConnectionConfig, database: &str) -> Result<Vec
     let mut db_config = config.clone();
     db_config.database = database.to_string();
     
-    let pool = POOL_MANAGER.get_mysql_pool(&db_config).await?;
+    let mut conn = POOL_MANAGER.get_mysql_conn(&db_config).await?;
 
-    let mut conn = pool
-        .get_conn()
+    let tables: Vec<String> = conn
+        .query("SHOW TABLES")
         .await
-        .map_err(|e| DbError::Connection(format!("MySQL 获取连接失败: {}", e)))?;
+        .map_err(|e| DbError::Query(e.to_string()))?;
+
+    Ok(tables)
+}
+
+/// 分批获取 MySQL 指定数据库的表列表（按名称排序）
+pub async fn get_tables_page(
+    config: &ConnectionConfig,
+    database: &str,
+    offset: usize,
+    limit: usize,
+) -> Result<Vec<String>, DbError> {
+    let mut db_config = config.clone();
+    db_config.database = database.to_string();
+
+    let mut conn = POOL_MANAGER.get_mysql_conn(&db_config).await?;
 
+    let sql = format!(
+        "SELECT TABLE_NAME FROM INFORMATION_SCHEMA.TABLES WHERE TABLE_SCHEMA = DATABASE() \
+         ORDER BY TABLE_NAME LIMIT {} OFFSET {}",
+        limit, offset
+    );
     let tables: Vec<String> = conn
-        .query("SHOW TABLES")
+        .query(sql)
         .await
         .map_err(|e| DbError::Query(e.to_string()))?;
 
@@ -48,12 +64,7 @@ pub async fn get_tables(config: &ConnectionConfig, database: &str) -> Result<Vec
 
 /// 获取 MySQL 表的主键列名
 pub async fn get_primary_key(config: &ConnectionConfig, table: &str) -> Result<Option<String>, DbError> {
-    let pool = POOL_MANAGER.get_mysql_pool(config).await?;
-    
-    let mut conn = pool
-        .get_conn()
-        .await
-        .map_err(|e| DbError::Connection(format!("MySQL 获取连接失败: {}", e)))?;
+    let mut conn = POOL_MANAGER.get_mysql_conn(config).await?;
     
     // 使用 SHOW KEYS 查询主键列
     let escaped_table = table.replace('`', "``").replace('.', "_");
@@ -76,37 +87,85 @@ pub async fn get_primary_key(config: &ConnectionConfig, table: &str) -> Result<O
     Ok(None)
 }
 
-/// 执行 MySQL 查询
-pub async fn execute(config: &ConnectionConfig, sql: &str) -> Result<QueryResult, DbError> {
-    let pool = POOL_MANAGER.get_mysql_pool(config).await?;
+/// 服务器不支持预处理的语句（ER_UNSUPPORTED_PS）
+const ER_UNSUPPORTED_PS: u16 = 1295;
+
+/// 检查 MySQL 语句（服务器预处理语句时解析语句并检查表和列，不执行）
+///
+/// 不支持预处理的语句（部分 DDL 和管理语句）无法检查，视为通过
+pub async fn validate(config: &ConnectionConfig, sql: &str) -> Result<(), DbError> {
+    let mut conn = POOL_MANAGER.get_mysql_conn(config).await?;
+    match conn.prep(sql).await {
+        Ok(_) => Ok(()),
+        Err(mysql_async::Error::Server(e)) if e.code == ER_UNSUPPORTED_PS => Ok(()),
+        Err(e) => Err(DbError::Query(e.to_string())),
+    }
+}
 
-    let mut conn = pool
-        .get_conn()
+/// 读取查询统计的会话状态变量 (变量名, 显示名称)
+const PROFILE_STATUS: &[(&str, &str)] = &[
+    ("Handler_read_rnd_next", "全表扫描读取的行"),
+    ("Handler_read_next", "按索引顺序读取的行"),
+    ("Handler_read_key", "索引查找次数"),
+    ("Select_scan", "全表扫描次数"),
+    ("Select_full_join", "未使用索引的联接"),
+    ("Created_tmp_tables", "内存临时表"),
+    ("Created_tmp_disk_tables", "磁盘临时表"),
+    ("Sort_rows", "排序的行"),
+    ("Sort_merge_passes", "排序归并次数"),
+];
+
+/// 读取会话状态变量
+async fn session_status(conn: &mut mysql_async::Conn) -> Result<std::collections::HashMap<String, i64>, DbError> {
+    let rows: Vec<(String, String)> = conn
+        .query("SHOW SESSION STATUS")
         .await
-        .map_err(|e| DbError::Connection(format!("MySQL 获取连接失败: {}", e)))?;
-
-    if is_query_statement(sql, &DatabaseType::MySQL) {
-        let result: Vec<mysql_async::Row> = conn
-            .query(sql)
-            .await
-            .map_err(|e| DbError::Query(e.to_string()))?;
-
-        if result.is_empty() {
-            return Ok(empty_result());
-        }
+        .map_err(|e| DbError::Query(e.to_string()))?;
+    Ok(rows
+        .into_iter()
+        .filter_map(|(name, value)| Some((name, value.parse().ok()?)))
+        .collect())
+}
 
-        let columns: Vec<String> = result[0]
-            .columns_ref()
+/// 在同一连接上执行查询，比较执行前后的会话状态变量
+///
+/// 读取状态变量本身也会计入少量读取，结果为近似值
+pub async fn profile(config: &ConnectionConfig, sql: &str) -> Result<QueryProfile, DbError> {
+    let mut conn = POOL_MANAGER.get_mysql_conn(config).await?;
+    let before = session_status(&mut conn).await?;
+    let start = std::time::Instant::now();
+    let rows: Vec<mysql_async::Row> = conn.query(sql).await.map_err(|e| DbError::Query(e.to_string()))?;
+    let elapsed_ms = start.elapsed().as_millis() as u64;
+    let after = session_status(&mut conn).await?;
+
+    let delta = |name: &str| after.get(name).zip(before.get(name)).map(|(a, b)| a - b);
+    let examined: i64 = after
+        .keys()
+        .filter(|name| name.starts_with("Handler_read_"))
+        .filter_map(|name| delta(name))
+        .sum();
+    let mut stats = vec![("检查的行（近似）".to_string(), examined.to_string())];
+    stats.extend(
+        PROFILE_STATUS
             .iter()
-            .map(|c| c.name_str().into_owned())
-            .collect();
+            .filter_map(|(name, label)| delta(name).map(|value| (label.to_string(), value.to_string()))),
+    );
+    Ok(QueryProfile { elapsed_ms, rows: rows.len(), stats, details: None })
+}
 
-        let data: Vec<Vec<String>> = result
-            .iter()
-            .map(|row| row_to_strings(row, columns.len()))
-            .collect();
+/// 执行 MySQL 查询
+pub async fn execute(config: &ConnectionConfig, sql: &str) -> Result<QueryResult, DbError> {
+    let mut conn = POOL_MANAGER.get_mysql_conn(config).await?;
 
-        Ok(query_result(columns, data))
+    if is_query_statement(sql, &DatabaseType::MySQL) {
+        // 查询以预处理语句执行（驱动在连接内按 SQL 缓存），不支持预处理的语句使用文本协议
+        let result: Vec<mysql_async::Row> = match conn.prep(sql).await {
+            Ok(statement) => conn.exec(&statement, ()).await,
+            Err(_) => conn.query(sql).await,
+        }
+        .map_err(|e| DbError::Query(e.to_string()))?;
+
+        Ok(rows_result(&result))
     } else {
         // 使用 query_iter 来获取影响行数
         let result = conn
@@ -122,13 +181,177 @@ pub async fn execute(config: &ConnectionConfig, sql: &str) -> Result<QueryResult
     }
 }
 
-/// 将 MySQL 行转换为字符串向量
-fn row_to_strings(row: &mysql_async::Row, col_count: usize) -> Vec<String> {
-    (0..col_count)
-        .map(|i| {
-            row.get::<mysql_async::Value, _>(i)
-                .map(value_to_string)
-                .unwrap_or_else(|| String::from("NULL"))
+/// 在只读事务中执行查询，结束后回滚
+pub async fn execute_read_only(config: &ConnectionConfig, sql: &str) -> Result<QueryResult, DbError> {
+    let mut conn = POOL_MANAGER.get_mysql_conn(config).await?;
+    let mut options = mysql_async::TxOpts::default();
+    options.with_readonly(true);
+    let mut tx = conn
+        .start_transaction(options)
+        .await
+        .map_err(|e| DbError::Query(e.to_string()))?;
+    let result: Vec<mysql_async::Row> = tx.query(sql).await.map_err(|e| DbError::Query(e.to_string()))?;
+    tx.rollback().await.map_err(|e| DbError::Query(e.to_string()))?;
+    Ok(rows_result(&result))
+}
+
+/// 将查询返回的行转换为查询结果
+fn rows_result(result: &[mysql_async::Row]) -> QueryResult {
+    if result.is_empty() {
+        return empty_result();
+    }
+
+    let columns: Vec<String> = result[0]
+        .columns_ref()
+        .iter()
+        .map(|c| c.name_str().into_owned())
+        .collect();
+
+    let kinds: Vec<ColumnKind> = result[0].columns_ref().iter().map(ColumnKind::of).collect();
+
+    let data: Vec<Vec<String>> = result
+        .iter()
+        .map(|row| row_to_strings(row, &kinds))
+        .collect();
+
+    query_result(columns, data)
+}
+
+/// 在同一连接上执行多条写语句，返回影响的总行数；事务执行时任一语句失败则全部回滚
+pub async fn execute_batch(config: &ConnectionConfig, statements: &[String], transaction: bool) -> Result<u64, DbError> {
+    let mut conn = POOL_MANAGER.get_mysql_conn(config).await?;
+    let mut affected = 0;
+
+    if transaction {
+        let mut tx = conn
+            .start_transaction(mysql_async::TxOpts::default())
+            .await
+            .map_err(|e| DbError::Query(e.to_string()))?;
+        // 出错时未提交的事务在释放时回滚
+        for sql in statements {
+            tx.query_drop(sql.as_str()).await.map_err(|e| DbError::Query(e.to_string()))?;
+            affected += tx.affected_rows();
+        }
+        tx.commit().await.map_err(|e| DbError::Query(e.to_string()))?;
+    } else {
+        for sql in statements {
+            conn.query_drop(sql.as_str()).await.map_err(|e| DbError::Query(e.to_string()))?;
+            affected += conn.affected_rows();
+        }
+    }
+    Ok(affected)
+}
+
+/// 逐批读取 MySQL 查询结果，每 `chunk_size` 行调用一次 `on_chunk`
+pub async fn execute_streaming(
+    config: &ConnectionConfig,
+    sql: &str,
+    chunk_size: usize,
+    on_chunk: impl FnMut(&[String], Vec<Vec<String>>) -> bool,
+) -> Result<QueryResult, DbError> {
+    let mut conn = POOL_MANAGER.get_mysql_conn(config).await?;
+
+    match conn.prep(sql).await {
+        Ok(statement) => {
+            let result = conn
+                .exec_iter(statement, ())
+                .await
+                .map_err(|e| DbError::Query(e.to_string()))?;
+            read_chunks(result, chunk_size, on_chunk).await
+        }
+        Err(_) => {
+            let result = conn
+                .query_iter(sql)
+                .await
+                .map_err(|e| DbError::Query(e.to_string()))?;
+            read_chunks(result, chunk_size, on_chunk).await
+        }
+    }
+}
+
+/// 逐批读取结果集（文本协议和预处理语句的二进制协议）
+async fn read_chunks<P: Protocol>(
+    mut result: mysql_async::QueryResult<'_, 'static, P>,
+    chunk_size: usize,
+    mut on_chunk: impl FnMut(&[String], Vec<Vec<String>>) -> bool,
+) -> Result<QueryResult, DbError> {
+    let columns: Vec<String> = result.columns_ref().iter().map(|c| c.name_str().into_owned()).collect();
+    let kinds: Vec<ColumnKind> = result.columns_ref().iter().map(ColumnKind::of).collect();
+
+    let mut chunk = Vec::with_capacity(chunk_size);
+    let mut total = 0;
+    while let Some(row) = result.next().await.map_err(|e| DbError::Query(e.to_string()))? {
+        chunk.push(row_to_strings(&row, &kinds));
+        total += 1;
+        if chunk.len() >= chunk_size
+            && !on_chunk(&columns, std::mem::replace(&mut chunk, Vec::with_capacity(chunk_size)))
+        {
+            return Ok(streamed_result(columns, total));
+        }
+    }
+    if !chunk.is_empty() {
+        on_chunk(&columns, chunk);
+    }
+    Ok(streamed_result(columns, total))
+}
+
+/// 列值的显示方式
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ColumnKind {
+    /// 二进制列，编码为十六进制
+    Binary,
+    /// 日期时间列，预处理语句返回的零点时间也显示时间部分
+    DateTime,
+    Other,
+}
+
+impl ColumnKind {
+    fn of(column: &mysql_async::Column) -> Self {
+        use mysql_async::consts::ColumnType::*;
+        if is_binary_column(column) {
+            Self::Binary
+        } else if matches!(
+            column.column_type(),
+            MYSQL_TYPE_DATETIME | MYSQL_TYPE_DATETIME2 | MYSQL_TYPE_TIMESTAMP | MYSQL_TYPE_TIMESTAMP2
+        ) {
+            Self::DateTime
+        } else {
+            Self::Other
+        }
+    }
+}
+
+/// 是否为二进制列（BLOB、BINARY、VARBINARY、空间类型使用 binary 字符集）
+fn is_binary_column(column: &mysql_async::Column) -> bool {
+    use mysql_async::consts::ColumnType::*;
+    const BINARY_CHARSET: u16 = 63;
+    column.character_set() == BINARY_CHARSET
+        && matches!(
+            column.column_type(),
+            MYSQL_TYPE_TINY_BLOB
+                | MYSQL_TYPE_MEDIUM_BLOB
+                | MYSQL_TYPE_LONG_BLOB
+                | MYSQL_TYPE_BLOB
+                | MYSQL_TYPE_STRING
+                | MYSQL_TYPE_VAR_STRING
+                | MYSQL_TYPE_VARCHAR
+                | MYSQL_TYPE_GEOMETRY
+        )
+}
+
+/// 将 MySQL 行转换为字符串向量（二进制列编码为十六进制）
+fn row_to_strings(row: &mysql_async::Row, kinds: &[ColumnKind]) -> Vec<String> {
+    use mysql_async::Value;
+    kinds
+        .iter()
+        .enumerate()
+        .map(|(i, &kind)| match row.get::<Value, _>(i) {
+            Some(Value::Bytes(b)) if kind == ColumnKind::Binary => encode_blob(&b),
+            Some(Value::Date(y, m, d, h, mi, s, 0)) if kind == ColumnKind::DateTime => {
+                format!("{:04}-{:02}-{:02} {:02}:{:02}:{:02}", y, m, d, h, mi, s)
+            }
+            Some(value) => value_to_string(value),
+            None => String::from("NULL"),
         })
         .collect()
 }
@@ -170,12 +393,7 @@ fn value_to_string(val: mysql_async::Value) -> String {
 
 /// 获取 MySQL 触发器
 pub async fn get_triggers(config: &ConnectionConfig) -> Result<Vec<TriggerInfo>, DbError> {
-    let pool = POOL_MANAGER.get_mysql_pool(config).await?;
-
-    let mut conn = pool
-        .get_conn()
-        .await
-        .map_err(|e| DbError::Connection(format!("MySQL 获取连接失败: {}", e)))?;
+    let mut conn = POOL_MANAGER.get_mysql_conn(config).await?;
 
     let sql = r#"
         SELECT 
@@ -222,14 +440,210 @@ pub async fn get_triggers(config: &ConnectionConfig) -> Result<Vec<TriggerInfo>,
     Ok(triggers)
 }
 
-/// 获取 MySQL 外键
-pub async fn get_foreign_keys(config: &ConnectionConfig) -> Result<Vec<ForeignKeyInfo>, DbError> {
-    let pool = POOL_MANAGER.get_mysql_pool(config).await?;
+/// 获取 MySQL 当前数据库的视图
+pub async fn get_views(config: &ConnectionConfig) -> Result<Vec<ViewInfo>, DbError> {
+    let mut conn = POOL_MANAGER.get_mysql_conn(config).await?;
+
+    let sql = r#"
+        SELECT TABLE_NAME, VIEW_DEFINITION
+        FROM INFORMATION_SCHEMA.VIEWS
+        WHERE TABLE_SCHEMA = DATABASE()
+        ORDER BY TABLE_NAME
+    "#;
 
-    let mut conn = pool
-        .get_conn()
+    let rows: Vec<(String, Option<String>)> = conn
+        .query(sql)
         .await
-        .map_err(|e| DbError::Connection(format!("MySQL 获取连接失败: {}", e)))?;
+        .map_err(|e| DbError::Query(format!("查询视图失败: {}", e)))?;
+
+    Ok(rows
+        .into_iter()
+        .map(|(name, definition)| ViewInfo { name, definition: definition.unwrap_or_default() })
+        .collect())
+}
+
+/// 获取 MySQL 表的 AUTO_INCREMENT 计数器
+pub async fn get_sequences(config: &ConnectionConfig) -> Result<Vec<SequenceInfo>, DbError> {
+    let mut conn = POOL_MANAGER.get_mysql_conn(config).await?;
+
+    // MySQL 8 默认缓存 INFORMATION_SCHEMA 统计信息，关闭缓存以读取实时计数（5.7 无此变量，忽略错误）
+    let _ = conn.query_drop("SET SESSION information_schema_stats_expiry = 0").await;
+
+    let sql = r#"
+        SELECT 
+            t.TABLE_NAME,
+            c.COLUMN_NAME,
+            t.AUTO_INCREMENT
+        FROM INFORMATION_SCHEMA.TABLES t
+        LEFT JOIN INFORMATION_SCHEMA.COLUMNS c
+            ON c.TABLE_SCHEMA = t.TABLE_SCHEMA
+            AND c.TABLE_NAME = t.TABLE_NAME
+            AND c.EXTRA LIKE '%auto_increment%'
+        WHERE t.TABLE_SCHEMA = DATABASE()
+          AND t.AUTO_INCREMENT IS NOT NULL
+        ORDER BY t.TABLE_NAME
+    "#;
+
+    let result: Vec<mysql_async::Row> = conn
+        .query(sql)
+        .await
+        .map_err(|e| DbError::Query(format!("查询自增计数器失败: {}", e)))?;
+
+    Ok(result
+        .iter()
+        .map(|row| {
+            let name: String = row.get(0).unwrap_or_default();
+            // AUTO_INCREMENT 即下一个将分配的值
+            let next_value = row
+                .get::<Option<u64>, _>(2)
+                .flatten()
+                .map(|v| i64::try_from(v).unwrap_or(i64::MAX));
+            SequenceInfo {
+                table_name: Some(name.clone()),
+                column_name: row.get::<Option<String>, _>(1).flatten(),
+                last_value: next_value.map(|v| v - 1).filter(|v| *v > 0),
+                next_value,
+                name,
+            }
+        })
+        .collect())
+}
+
+/// 获取当前数据库各表的大小和估算行数（InnoDB 的 TABLE_ROWS 为估算值）
+pub async fn get_table_sizes(config: &ConnectionConfig) -> Result<Vec<TableSizeInfo>, DbError> {
+    let mut conn = POOL_MANAGER.get_mysql_conn(config).await?;
+
+    let sql = r#"
+        SELECT
+            TABLE_NAME,
+            TABLE_ROWS,
+            DATA_LENGTH,
+            INDEX_LENGTH
+        FROM INFORMATION_SCHEMA.TABLES
+        WHERE TABLE_SCHEMA = DATABASE()
+          AND TABLE_TYPE = 'BASE TABLE'
+        ORDER BY DATA_LENGTH + INDEX_LENGTH DESC
+    "#;
+
+    let result: Vec<mysql_async::Row> = conn
+        .query(sql)
+        .await
+        .map_err(|e| DbError::Query(format!("查询表大小失败: {}", e)))?;
+
+    let column = |row: &mysql_async::Row, idx: usize| {
+        row.get::<Option<u64>, _>(idx)
+            .flatten()
+            .map(|v| i64::try_from(v).unwrap_or(i64::MAX))
+    };
+    Ok(result
+        .iter()
+        .map(|row| TableSizeInfo {
+            name: row.get(0).unwrap_or_default(),
+            row_count: column(row, 1),
+            data_bytes: column(row, 2),
+            index_bytes: column(row, 3),
+        })
+        .collect())
+}
+
+/// 获取 MySQL 表的建表语句（SHOW CREATE TABLE）
+pub async fn get_table_ddl(config: &ConnectionConfig, table: &str) -> Result<String, DbError> {
+    let mut conn = POOL_MANAGER.get_mysql_conn(config).await?;
+
+    let sql = format!("SHOW CREATE TABLE `{}`", table.replace('`', "``"));
+    // 视图返回 4 列，第 2 列同样是定义语句
+    let row: Option<mysql_async::Row> = conn
+        .query_first(sql)
+        .await
+        .map_err(|e| DbError::Query(format!("获取建表语句失败: {}", e)))?;
+
+    row.and_then(|row| row.get::<Option<String>, _>(1).flatten())
+        .map(|ddl| format!("{};", ddl))
+        .ok_or_else(|| DbError::Query(format!("表 {} 不存在", table)))
+}
+
+/// 获取 MySQL 会话（`INFORMATION_SCHEMA.PROCESSLIST`）
+pub async fn get_sessions(config: &ConnectionConfig) -> Result<Vec<SessionInfo>, DbError> {
+    let mut conn = POOL_MANAGER.get_mysql_conn(config).await?;
+
+    let sql = r#"
+        SELECT ID, USER, DB, HOST, COMMAND, TIME, INFO, STATE
+        FROM INFORMATION_SCHEMA.PROCESSLIST
+        WHERE ID <> CONNECTION_ID()
+        ORDER BY COMMAND = 'Sleep', TIME DESC
+    "#;
+
+    let result: Vec<mysql_async::Row> = conn
+        .query(sql)
+        .await
+        .map_err(|e| DbError::Query(format!("查询会话失败: {}", e)))?;
+
+    Ok(result
+        .iter()
+        .map(|row| SessionInfo {
+            id: row
+                .get::<Option<u64>, _>(0)
+                .flatten()
+                .map_or(0, |v| i64::try_from(v).unwrap_or(i64::MAX)),
+            user: row.get::<Option<String>, _>(1).flatten().unwrap_or_default(),
+            database: row.get::<Option<String>, _>(2).flatten(),
+            client: row.get::<Option<String>, _>(3).flatten(),
+            state: row.get::<Option<String>, _>(4).flatten().unwrap_or_default(),
+            duration_secs: row.get::<Option<i64>, _>(5).flatten(),
+            query: row.get::<Option<String>, _>(6).flatten().unwrap_or_default(),
+            wait: row.get::<Option<String>, _>(7).flatten().filter(|s| !s.is_empty()),
+            blocked_by: Vec::new(),
+        })
+        .collect())
+}
+
+/// 获取 MySQL 账户列表（`'user'@'host'` 形式，与 INFORMATION_SCHEMA 中的 GRANTEE 一致）
+pub async fn get_users(config: &ConnectionConfig) -> Result<Vec<String>, DbError> {
+    let mut conn = POOL_MANAGER.get_mysql_conn(config).await?;
+
+    let result: Vec<(String, String)> = conn
+        .query("SELECT User, Host FROM mysql.user ORDER BY User, Host")
+        .await
+        .map_err(|e| DbError::Query(format!("查询用户失败: {}", e)))?;
+
+    Ok(result
+        .into_iter()
+        .map(|(user, host)| format!("'{}'@'{}'", user.replace('\'', "''"), host.replace('\'', "''")))
+        .collect())
+}
+
+/// 获取 MySQL 账户在当前数据库中的库级和表级权限
+pub async fn get_grants(config: &ConnectionConfig, user: &str) -> Result<Vec<GrantInfo>, DbError> {
+    let mut conn = POOL_MANAGER.get_mysql_conn(config).await?;
+
+    let grantee = user.replace('\\', "\\\\").replace('\'', "''");
+    let sql = format!(
+        r#"
+        SELECT '' AS TABLE_NAME, PRIVILEGE_TYPE
+        FROM INFORMATION_SCHEMA.SCHEMA_PRIVILEGES
+        WHERE GRANTEE = '{grantee}' AND TABLE_SCHEMA = DATABASE()
+        UNION ALL
+        SELECT TABLE_NAME, PRIVILEGE_TYPE
+        FROM INFORMATION_SCHEMA.TABLE_PRIVILEGES
+        WHERE GRANTEE = '{grantee}' AND TABLE_SCHEMA = DATABASE()
+        ORDER BY 1, 2
+        "#
+    );
+
+    let result: Vec<(String, String)> = conn
+        .query(&sql)
+        .await
+        .map_err(|e| DbError::Query(format!("查询权限失败: {}", e)))?;
+
+    Ok(result
+        .into_iter()
+        .map(|(table_name, privilege)| GrantInfo { table_name, privilege })
+        .collect())
+}
+
+/// 获取 MySQL 外键
+pub async fn get_foreign_keys(config: &ConnectionConfig) -> Result<Vec<ForeignKeyInfo>, DbError> {
+    let mut conn = POOL_MANAGER.get_mysql_conn(config).await?;
 
     let sql = r#"
         SELECT 
@@ -263,12 +677,7 @@ pub async fn get_foreign_keys(config: &ConnectionConfig) -> Result<Vec<ForeignKe
 
 /// 获取 MySQL 表的列信息
 pub async fn get_columns(config: &ConnectionConfig, table: &str) -> Result<Vec<ColumnInfo>, DbError> {
-    let pool = POOL_MANAGER.get_mysql_pool(config).await?;
-
-    let mut conn = pool
-        .get_conn()
-        .await
-        .map_err(|e| DbError::Connection(format!("MySQL 获取连接失败: {}", e)))?;
+    let mut conn = POOL_MANAGER.get_mysql_conn(config).await?;
 
     let sql = format!(
         r#"
@@ -312,12 +721,7 @@ pub async fn get_columns(config: &ConnectionConfig, table: &str) -> Result<Vec<C
 
 /// 获取 MySQL 存储过程和函数
 pub async fn get_routines(config: &ConnectionConfig) -> Result<Vec<RoutineInfo>, DbError> {
-    let pool = POOL_MANAGER.get_mysql_pool(config).await?;
-
-    let mut conn = pool
-        .get_conn()
-        .await
-        .map_err(|e| DbError::Connection(format!("MySQL 获取连接失败: {}", e)))?;
+    let mut conn = POOL_MANAGER.get_mysql_conn(config).await?;
 
     let sql = r#"
         SELECT 
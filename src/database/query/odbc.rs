@@ -0,0 +1,190 @@
+//! ODBC 通用查询实现
+//!
+//! 通过系统 ODBC 驱动管理器（unixODBC/iODBC/Windows ODBC）连接 DSN，
+//! 用于 DB2、Access、Snowflake 等没有原生驱动的数据库。
+//! 元数据来自 ODBC 目录函数（SQLTables/SQLColumns/SQLPrimaryKeys/SQLForeignKeys）。
+
+use ::odbc_api::buffers::TextRowSet;
+use ::odbc_api::parameter::VarCharArray;
+use ::odbc_api::{Connection as OdbcConn, ConnectionOptions, Cursor, ResultSetMetadata};
+use crate::database::{ConnectionConfig, DbError, QueryResult};
+use super::{query_result, exec_result, ForeignKeyInfo, ColumnInfo};
+
+/// 每批读取的行数
+const BATCH_SIZE: usize = 1000;
+
+/// 单个文本值的最大长度（超长的 CLOB/TEXT 会被截断）
+const MAX_TEXT_LEN: usize = 64 * 1024;
+
+/// 打开 ODBC 连接
+fn open_connection(config: &ConnectionConfig) -> Result<OdbcConn<'static>, DbError> {
+    let env = ::odbc_api::environment()
+        .map_err(|e| DbError::Connection(format!("ODBC 环境初始化失败: {}", e)))?;
+    env.connect_with_connection_string(&config.odbc_connection_string(), ConnectionOptions::default())
+        .map_err(|e| DbError::Connection(format!("ODBC 连接失败: {}", e)))
+}
+
+/// 将 ODBC 目录返回的定长文本转换为字符串
+fn text<const N: usize>(value: &VarCharArray<N>) -> String {
+    value
+        .as_str()
+        .ok()
+        .flatten()
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// 拆分可能带 schema 前缀的表名
+fn split_table_name(table: &str) -> (&str, &str) {
+    table.split_once('.').unwrap_or(("", table))
+}
+
+/// 连接并获取表和视图列表（有 schema 时以 `schema.table` 形式返回）
+pub fn connect(config: &ConnectionConfig) -> Result<Vec<String>, DbError> {
+    let conn = open_connection(config)?;
+    let rows = conn
+        .tables("", "", "", "TABLE,VIEW")
+        .map_err(|e| DbError::Query(e.to_string()))?;
+
+    let mut tables = Vec::new();
+    for row in rows {
+        let row = row.map_err(|e| DbError::Query(e.to_string()))?;
+        let schema = text(&row.schema);
+        let table = text(&row.table);
+        // 跳过标准的系统 schema
+        if schema.eq_ignore_ascii_case("INFORMATION_SCHEMA") {
+            continue;
+        }
+        tables.push(if schema.is_empty() {
+            table
+        } else {
+            format!("{}.{}", schema, table)
+        });
+    }
+    tables.sort();
+    Ok(tables)
+}
+
+/// 获取表的主键列名（复合主键取第一列）
+pub fn get_primary_key(config: &ConnectionConfig, table: &str) -> Result<Option<String>, DbError> {
+    let conn = open_connection(config)?;
+    Ok(primary_key_columns(&conn, table)?.into_iter().next())
+}
+
+/// 按 KEY_SEQ 顺序获取主键列
+fn primary_key_columns(conn: &OdbcConn<'_>, table: &str) -> Result<Vec<String>, DbError> {
+    let (schema, table) = split_table_name(table);
+    let schema = (!schema.is_empty()).then_some(schema);
+    let rows = conn
+        .primary_keys(None, schema, table)
+        .map_err(|e| DbError::Query(e.to_string()))?;
+
+    let mut keys = Vec::new();
+    for row in rows {
+        let row = row.map_err(|e| DbError::Query(e.to_string()))?;
+        keys.push((row.key_seq, text(&row.column)));
+    }
+    keys.sort_by_key(|(seq, _)| *seq);
+    Ok(keys.into_iter().map(|(_, name)| name).collect())
+}
+
+/// 执行 ODBC 查询（所有值按文本读取）
+pub fn execute(config: &ConnectionConfig, sql: &str) -> Result<QueryResult, DbError> {
+    let conn = open_connection(config)?;
+    let mut stmt = conn.preallocate().map_err(|e| DbError::Query(e.to_string()))?;
+
+    if let Some(mut cursor) = stmt.execute(sql, ()).map_err(|e| DbError::Query(e.to_string()))? {
+        let columns = cursor
+            .column_names()
+            .map_err(|e| DbError::Query(e.to_string()))?
+            .collect::<Result<Vec<String>, _>>()
+            .map_err(|e| DbError::Query(e.to_string()))?;
+
+        let buffer = TextRowSet::for_cursor(BATCH_SIZE, &mut cursor, Some(MAX_TEXT_LEN))
+            .map_err(|e| DbError::Query(e.to_string()))?;
+        let mut row_set_cursor = cursor
+            .bind_buffer(buffer)
+            .map_err(|e| DbError::Query(e.to_string()))?;
+
+        let mut data = Vec::new();
+        while let Some(batch) = row_set_cursor.fetch().map_err(|e| DbError::Query(e.to_string()))? {
+            for row_index in 0..batch.num_rows() {
+                let row = (0..batch.num_cols())
+                    .map(|col_index| {
+                        batch
+                            .at(col_index, row_index)
+                            .map(|bytes| String::from_utf8_lossy(bytes).into_owned())
+                            .unwrap_or_else(|| "NULL".to_string())
+                    })
+                    .collect();
+                data.push(row);
+            }
+        }
+        return Ok(query_result(columns, data));
+    }
+
+    let affected = stmt.row_count().map_err(|e| DbError::Query(e.to_string()))?;
+    Ok(exec_result(affected.unwrap_or(0) as u64))
+}
+
+/// 获取外键关系（逐表调用 SQLForeignKeys）
+pub fn get_foreign_keys(config: &ConnectionConfig) -> Result<Vec<ForeignKeyInfo>, DbError> {
+    let tables = connect(config)?;
+    let conn = open_connection(config)?;
+
+    let mut fks = Vec::new();
+    for qualified in &tables {
+        let (schema, table) = split_table_name(qualified);
+        let rows = match conn.foreign_keys("", "", "", "", schema, table) {
+            Ok(rows) => rows,
+            // 部分驱动（如 Access）不支持外键目录函数，保留已经读取到的外键
+            Err(e) => {
+                tracing::warn!(table = %qualified, error = %e, "ODBC 外键读取失败");
+                break;
+            }
+        };
+        for row in rows {
+            let row = row.map_err(|e| DbError::Query(e.to_string()))?;
+            let pk_schema = text(&row.pk_schema);
+            let pk_table = text(&row.pk_table);
+            fks.push(ForeignKeyInfo {
+                from_table: qualified.clone(),
+                from_column: text(&row.fk_column),
+                to_table: if pk_schema.is_empty() {
+                    pk_table
+                } else {
+                    format!("{}.{}", pk_schema, pk_table)
+                },
+                to_column: text(&row.pk_column),
+            });
+        }
+    }
+    Ok(fks)
+}
+
+/// 获取表的列信息
+pub fn get_columns(config: &ConnectionConfig, table: &str) -> Result<Vec<ColumnInfo>, DbError> {
+    let conn = open_connection(config)?;
+    let pk_columns = primary_key_columns(&conn, table).unwrap_or_default();
+    let (schema, table) = split_table_name(table);
+
+    let rows = conn
+        .columns("", schema, table, "")
+        .map_err(|e| DbError::Query(e.to_string()))?;
+
+    let mut columns = Vec::new();
+    for row in rows {
+        let row = row.map_err(|e| DbError::Query(e.to_string()))?;
+        let name = text(&row.column_name);
+        let default_value = text(&row.column_default);
+        columns.push(ColumnInfo {
+            is_primary_key: pk_columns.contains(&name),
+            name,
+            data_type: text(&row.type_name),
+            // SQL_NO_NULLS = 0
+            is_nullable: row.nullable != 0,
+            default_value: (!default_value.is_empty()).then_some(default_value),
+        });
+    }
+    Ok(columns)
+}
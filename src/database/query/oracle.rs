@@ -0,0 +1,293 @@
+//! Oracle 查询实现
+//!
+//! 使用 ODPI-C（oracle crate），运行时需要 Oracle Instant Client。
+//! 连接后以 schema（用户）作为"数据库"列表，元数据来自 ALL_* 数据字典视图。
+
+use ::oracle::{Connection as OracleConn, ResultSet, Row};
+use crate::database::{ConnectionConfig, DbError, QueryResult};
+use super::{query_result, exec_result, TriggerInfo, ForeignKeyInfo, ColumnInfo};
+
+/// 打开 Oracle 连接，并切换到当前选择的 schema
+fn open_connection(config: &ConnectionConfig) -> Result<OracleConn, DbError> {
+    let conn = OracleConn::connect(&config.username, &config.password, config.oracle_connect_string())
+        .map_err(|e| DbError::Connection(format!("Oracle 连接失败: {}", e)))?;
+
+    if !config.database.is_empty() {
+        conn.execute(
+            &format!("ALTER SESSION SET CURRENT_SCHEMA = \"{}\"", config.database.replace('"', "")),
+            &[],
+        )
+        .map_err(|e| DbError::Connection(format!("切换 schema 失败: {}", e)))?;
+    }
+
+    Ok(conn)
+}
+
+/// 当前 schema（未选择时为登录用户）
+fn current_owner(config: &ConnectionConfig) -> String {
+    if config.database.is_empty() {
+        config.username.to_uppercase()
+    } else {
+        config.database.clone()
+    }
+}
+
+/// 拆分可能带 schema 前缀的表名
+fn split_table_name(config: &ConnectionConfig, table: &str) -> (String, String) {
+    match table.split_once('.') {
+        Some((owner, name)) => (owner.to_string(), name.to_string()),
+        None => (current_owner(config), table.to_string()),
+    }
+}
+
+/// 去掉语句末尾的分号（OCI 不接受，PL/SQL 块除外）
+fn strip_terminator(sql: &str) -> &str {
+    let trimmed = sql.trim();
+    let upper = trimmed.to_uppercase();
+    let is_plsql = upper.starts_with("BEGIN")
+        || upper.starts_with("DECLARE")
+        || (upper.starts_with("CREATE") && upper.ends_with("END;"));
+    if is_plsql {
+        trimmed
+    } else {
+        trimmed.trim_end_matches(';').trim_end()
+    }
+}
+
+/// 收集结果集的第一列为字符串列表
+fn collect_strings(rows: ResultSet<'_, Row>) -> Result<Vec<String>, DbError> {
+    rows.map(|row| {
+        row.and_then(|r| r.get::<_, String>(0))
+            .map_err(|e| DbError::Query(e.to_string()))
+    })
+    .collect()
+}
+
+/// Oracle 11g 自带的系统用户（11g 的 ALL_USERS 没有 ORACLE_MAINTAINED 列，只能按名单排除）
+const LEGACY_SYSTEM_USERS: &[&str] = &[
+    "ANONYMOUS", "APEX_PUBLIC_USER", "APPQOSSYS", "CTXSYS", "DBSNMP", "DIP", "EXFSYS",
+    "FLOWS_FILES", "MDDATA", "MDSYS", "MGMT_VIEW", "OLAPSYS", "ORACLE_OCM", "ORDDATA",
+    "ORDPLUGINS", "ORDSYS", "OUTLN", "OWBSYS", "OWBSYS_AUDIT", "SI_INFORMTN_SCHEMA",
+    "SPATIAL_CSW_ADMIN_USR", "SPATIAL_WFS_ADMIN_USR", "SYS", "SYSMAN", "SYSTEM", "WMSYS",
+    "XDB", "XS$NULL",
+];
+
+/// 获取 schema 列表（排除 Oracle 内置用户）
+///
+/// `ORACLE_MAINTAINED` 列从 12c 开始才有，11g 上报 ORA-00904 时改用内置用户名单排除
+pub fn get_databases(config: &ConnectionConfig) -> Result<Vec<String>, DbError> {
+    let conn = open_connection(config)?;
+    let rows = match conn.query(
+        "SELECT username FROM all_users WHERE oracle_maintained = 'N' ORDER BY username",
+        &[],
+    ) {
+        Ok(rows) => rows,
+        Err(e) if e.to_string().contains("ORA-00904") => {
+            let excluded = LEGACY_SYSTEM_USERS
+                .iter()
+                .map(|user| format!("'{}'", user))
+                .collect::<Vec<_>>()
+                .join(", ");
+            conn.query(
+                &format!(
+                    "SELECT username FROM all_users WHERE username NOT IN ({}) \
+                     AND username NOT LIKE 'APEX\\_%' ESCAPE '\\' ORDER BY username",
+                    excluded
+                ),
+                &[],
+            )
+            .map_err(|e| DbError::Query(e.to_string()))?
+        }
+        Err(e) => return Err(DbError::Query(e.to_string())),
+    };
+    collect_strings(rows)
+}
+
+/// 获取指定 schema 的表列表
+pub fn get_tables(config: &ConnectionConfig, schema: &str) -> Result<Vec<String>, DbError> {
+    let conn = open_connection(config)?;
+    let owner = if schema.is_empty() { current_owner(config) } else { schema.to_string() };
+    let rows = conn
+        .query(
+            "SELECT table_name FROM all_tables WHERE owner = :1 ORDER BY table_name",
+            &[&owner],
+        )
+        .map_err(|e| DbError::Query(e.to_string()))?;
+    collect_strings(rows)
+}
+
+/// 获取 Oracle 表的主键列名
+pub fn get_primary_key(config: &ConnectionConfig, table: &str) -> Result<Option<String>, DbError> {
+    Ok(get_columns(config, table)?
+        .into_iter()
+        .find(|c| c.is_primary_key)
+        .map(|c| c.name))
+}
+
+/// 执行 Oracle 查询
+///
+/// `EXPLAIN PLAN FOR ...` 会在同一会话中读取 DBMS_XPLAN 的输出作为结果
+pub fn execute(config: &ConnectionConfig, sql: &str) -> Result<QueryResult, DbError> {
+    let conn = open_connection(config)?;
+    let sql = strip_terminator(sql);
+
+    if sql.to_uppercase().starts_with("EXPLAIN PLAN") {
+        conn.execute(sql, &[]).map_err(|e| DbError::Query(e.to_string()))?;
+        let rows = conn
+            .query("SELECT plan_table_output FROM TABLE(DBMS_XPLAN.DISPLAY())", &[])
+            .map_err(|e| DbError::Query(e.to_string()))?;
+        let lines = collect_strings(rows)?;
+        return Ok(query_result(
+            vec!["PLAN_TABLE_OUTPUT".to_string()],
+            lines.into_iter().map(|l| vec![l]).collect(),
+        ));
+    }
+
+    let mut stmt = conn
+        .statement(sql)
+        .build()
+        .map_err(|e| DbError::Query(e.to_string()))?;
+
+    if stmt.is_query() {
+        let rows = stmt.query(&[]).map_err(|e| DbError::Query(e.to_string()))?;
+        let columns: Vec<String> = rows
+            .column_info()
+            .iter()
+            .map(|c| c.name().to_string())
+            .collect();
+
+        let mut data = Vec::new();
+        for row in rows {
+            let row = row.map_err(|e| DbError::Query(e.to_string()))?;
+            data.push(row.sql_values().iter().map(|v| v.to_string()).collect());
+        }
+        Ok(query_result(columns, data))
+    } else {
+        stmt.execute(&[]).map_err(|e| DbError::Query(e.to_string()))?;
+        let affected = stmt.row_count().map_err(|e| DbError::Query(e.to_string()))?;
+        conn.commit().map_err(|e| DbError::Query(e.to_string()))?;
+        Ok(exec_result(affected))
+    }
+}
+
+/// 获取 Oracle 触发器
+pub fn get_triggers(config: &ConnectionConfig) -> Result<Vec<TriggerInfo>, DbError> {
+    let conn = open_connection(config)?;
+    let owner = current_owner(config);
+    let rows = conn
+        .query(
+            "SELECT trigger_name, table_name, triggering_event, trigger_type, description \
+             FROM all_triggers WHERE owner = :1 ORDER BY trigger_name",
+            &[&owner],
+        )
+        .map_err(|e| DbError::Query(e.to_string()))?;
+
+    let mut triggers = Vec::new();
+    for row in rows {
+        let row = row.map_err(|e| DbError::Query(e.to_string()))?;
+        let (name, table_name, event, trigger_type, description): (
+            String,
+            Option<String>,
+            String,
+            String,
+            String,
+        ) = row.get_as().map_err(|e| DbError::Query(e.to_string()))?;
+        triggers.push(TriggerInfo {
+            name,
+            table_name: table_name.unwrap_or_default(),
+            event,
+            // trigger_type 形如 "BEFORE EACH ROW"
+            timing: trigger_type.split_whitespace().next().unwrap_or_default().to_string(),
+            definition: format!("CREATE OR REPLACE TRIGGER {}", description.trim()),
+        });
+    }
+    Ok(triggers)
+}
+
+/// 获取 Oracle 外键关系
+pub fn get_foreign_keys(config: &ConnectionConfig) -> Result<Vec<ForeignKeyInfo>, DbError> {
+    let conn = open_connection(config)?;
+    let owner = current_owner(config);
+    let rows = conn
+        .query(
+            "SELECT c.table_name, cc.column_name, rc.table_name, rcc.column_name \
+             FROM all_constraints c \
+             JOIN all_cons_columns cc ON cc.owner = c.owner AND cc.constraint_name = c.constraint_name \
+             JOIN all_constraints rc ON rc.owner = c.r_owner AND rc.constraint_name = c.r_constraint_name \
+             JOIN all_cons_columns rcc ON rcc.owner = rc.owner AND rcc.constraint_name = rc.constraint_name \
+                 AND rcc.position = cc.position \
+             WHERE c.constraint_type = 'R' AND c.owner = :1",
+            &[&owner],
+        )
+        .map_err(|e| DbError::Query(e.to_string()))?;
+
+    let mut fks = Vec::new();
+    for row in rows {
+        let row = row.map_err(|e| DbError::Query(e.to_string()))?;
+        let (from_table, from_column, to_table, to_column) = row
+            .get_as::<(String, String, String, String)>()
+            .map_err(|e| DbError::Query(e.to_string()))?;
+        fks.push(ForeignKeyInfo {
+            from_table,
+            from_column,
+            to_table,
+            to_column,
+        });
+    }
+    Ok(fks)
+}
+
+/// 获取 Oracle 表的列信息
+pub fn get_columns(config: &ConnectionConfig, table: &str) -> Result<Vec<ColumnInfo>, DbError> {
+    let conn = open_connection(config)?;
+    let (owner, table) = split_table_name(config, table);
+
+    let pk_rows = conn
+        .query(
+            "SELECT cc.column_name FROM all_constraints c \
+             JOIN all_cons_columns cc ON cc.owner = c.owner AND cc.constraint_name = c.constraint_name \
+             WHERE c.constraint_type = 'P' AND c.owner = :1 AND c.table_name = :2",
+            &[&owner, &table],
+        )
+        .map_err(|e| DbError::Query(e.to_string()))?;
+    let pk_columns = collect_strings(pk_rows)?;
+
+    let rows = conn
+        .query(
+            "SELECT column_name, data_type, data_length, data_precision, data_scale, nullable, data_default \
+             FROM all_tab_columns WHERE owner = :1 AND table_name = :2 ORDER BY column_id",
+            &[&owner, &table],
+        )
+        .map_err(|e| DbError::Query(e.to_string()))?;
+
+    let mut columns = Vec::new();
+    for row in rows {
+        let row = row.map_err(|e| DbError::Query(e.to_string()))?;
+        let (name, data_type, length, precision, scale, nullable, default_value): (
+            String,
+            String,
+            i64,
+            Option<i64>,
+            Option<i64>,
+            String,
+            Option<String>,
+        ) = row.get_as().map_err(|e| DbError::Query(e.to_string()))?;
+
+        // 组合出完整类型，如 VARCHAR2(100)、NUMBER(10,2)
+        let data_type = match (data_type.as_str(), precision, scale) {
+            ("NUMBER", Some(p), Some(s)) if s > 0 => format!("NUMBER({},{})", p, s),
+            ("NUMBER", Some(p), _) => format!("NUMBER({})", p),
+            ("VARCHAR2" | "NVARCHAR2" | "CHAR" | "NCHAR" | "RAW", _, _) => format!("{}({})", data_type, length),
+            _ => data_type,
+        };
+
+        columns.push(ColumnInfo {
+            is_primary_key: pk_columns.contains(&name),
+            name,
+            data_type,
+            is_nullable: nullable == "Y",
+            default_value: default_value.map(|d| d.trim().to_string()),
+        });
+    }
+    Ok(columns)
+}
@@ -1,7 +1,10 @@
 //! PostgreSQL 查询实现
 
-use crate::database::{ConnectionConfig, DbError, QueryResult, DatabaseType, POOL_MANAGER};
-use super::{query_result, exec_result, empty_result, is_query_statement, TriggerInfo, ForeignKeyInfo, ColumnInfo, RoutineInfo, RoutineType};
+use crate::core::encode_blob;
+use std::sync::Arc;
+
+use crate::database::{invalidates_statements, ConnectionConfig, DbError, QueryResult, DatabaseType, POOL_MANAGER};
+use super::{query_result, exec_result, empty_result, streamed_result, is_query_statement, TriggerInfo, ViewInfo, ForeignKeyInfo, ColumnInfo, RoutineInfo, RoutineType, SequenceInfo, GrantInfo, SessionInfo, TableSizeInfo, QueryProfile};
 
 /// 获取 PostgreSQL 数据库列表
 pub async fn get_databases(config: &ConnectionConfig) -> Result<Vec<String>, DbError> {
@@ -13,7 +16,7 @@ pub async fn get_databases(config: &ConnectionConfig) -> Result<Vec<String>, DbE
             &[],
         )
         .await
-        .map_err(|e| DbError::Query(e.to_string()))?;
+        .map_err(DbError::pg_query)?;
 
     Ok(rows.iter().map(|r| r.get(0)).collect())
 }
@@ -32,7 +35,32 @@ pub async fn get_tables(config: &ConnectionConfig, database: &str) -> Result<Vec
             &[],
         )
         .await
-        .map_err(|e| DbError::Query(e.to_string()))?;
+        .map_err(DbError::pg_query)?;
+
+    Ok(rows.iter().map(|r| r.get(0)).collect())
+}
+
+/// 分批获取 PostgreSQL 指定数据库的表列表（按名称排序）
+pub async fn get_tables_page(
+    config: &ConnectionConfig,
+    database: &str,
+    offset: usize,
+    limit: usize,
+) -> Result<Vec<String>, DbError> {
+    let mut db_config = config.clone();
+    db_config.database = database.to_string();
+
+    let client = POOL_MANAGER.get_pg_client(&db_config).await?;
+
+    let rows = client
+        .query(
+            "SELECT table_name::text FROM information_schema.tables
+             WHERE table_schema = 'public' AND table_type = 'BASE TABLE'
+             ORDER BY table_name LIMIT $1 OFFSET $2",
+            &[&(limit as i64), &(offset as i64)],
+        )
+        .await
+        .map_err(DbError::pg_query)?;
 
     Ok(rows.iter().map(|r| r.get(0)).collect())
 }
@@ -58,15 +86,115 @@ pub async fn get_primary_key(config: &ConnectionConfig, table: &str) -> Result<O
     Ok(rows.first().map(|r| r.get(0)))
 }
 
+/// 检查 PostgreSQL 语句（服务器解析并分析语句，不执行）
+pub async fn validate(config: &ConnectionConfig, sql: &str) -> Result<(), DbError> {
+    let client = POOL_MANAGER.get_pg_client(config).await?;
+    client.prepare(sql).await.map(|_| ()).map_err(DbError::pg_query)
+}
+
+/// 用 `EXPLAIN (ANALYZE, BUFFERS, FORMAT JSON)` 执行查询并读取运行时统计
+pub async fn profile(config: &ConnectionConfig, sql: &str) -> Result<QueryProfile, DbError> {
+    let client = POOL_MANAGER.get_pg_client(config).await?;
+    let explain = format!("EXPLAIN (ANALYZE, BUFFERS, FORMAT JSON) {}", sql.trim().trim_end_matches(';'));
+    let messages = client.simple_query(&explain).await.map_err(DbError::pg_query)?;
+    let json: String = messages
+        .iter()
+        .filter_map(|message| match message {
+            tokio_postgres::SimpleQueryMessage::Row(row) => row.get(0),
+            _ => None,
+        })
+        .collect();
+    parse_explain_profile(&json).ok_or_else(|| DbError::Query("无法解析执行计划".to_string()))
+}
+
+/// 解析 JSON 格式的 EXPLAIN ANALYZE 输出
+fn parse_explain_profile(json: &str) -> Option<QueryProfile> {
+    use serde_json::Value;
+
+    let value: Value = serde_json::from_str(json).ok()?;
+    let root = value.get(0)?;
+    let plan = root.get("Plan")?;
+    let number = |node: &Value, key: &str| node.get(key).and_then(Value::as_f64);
+
+    // 各节点被过滤掉的行（每次循环的平均值乘以循环次数）
+    fn removed_rows(node: &Value) -> f64 {
+        let loops = node.get("Actual Loops").and_then(Value::as_f64).unwrap_or(1.0);
+        let own: f64 = ["Rows Removed by Filter", "Rows Removed by Join Filter", "Rows Removed by Index Recheck"]
+            .iter()
+            .filter_map(|key| node.get(*key).and_then(Value::as_f64))
+            .sum();
+        let children: f64 = node
+            .get("Plans")
+            .and_then(Value::as_array)
+            .map_or(0.0, |plans| plans.iter().map(removed_rows).sum());
+        own * loops + children
+    }
+
+    // 执行计划树：节点类型、对象、实际耗时、行数和缓冲区
+    fn describe(node: &Value, depth: usize, out: &mut String) {
+        let text = |key: &str| node.get(key).and_then(Value::as_str);
+        let number = |key: &str| node.get(key).and_then(Value::as_f64).unwrap_or(0.0);
+        out.push_str(&"  ".repeat(depth));
+        out.push_str(text("Node Type").unwrap_or("?"));
+        if let Some(relation) = text("Relation Name") {
+            out.push_str(&format!(" on {}", relation));
+        }
+        if let Some(index) = text("Index Name") {
+            out.push_str(&format!(" using {}", index));
+        }
+        out.push_str(&format!(
+            "  (time={:.3} ms rows={} loops={} hit={} read={})\n",
+            number("Actual Total Time"),
+            number("Actual Rows"),
+            number("Actual Loops"),
+            number("Shared Hit Blocks"),
+            number("Shared Read Blocks"),
+        ));
+        if let Some(plans) = node.get("Plans").and_then(Value::as_array) {
+            for child in plans {
+                describe(child, depth + 1, out);
+            }
+        }
+    }
+
+    let mut stats = Vec::new();
+    let mut push = |name: &str, value: Option<String>| {
+        if let Some(value) = value {
+            stats.push((name.to_string(), value));
+        }
+    };
+    let ms = |v: f64| format!("{:.3} ms", v);
+    let blocks = |v: f64| format!("{} 块", v);
+    push("规划耗时", number(root, "Planning Time").map(ms));
+    push("执行耗时", number(root, "Execution Time").map(ms));
+    push("被过滤掉的行", Some(format!("{}", removed_rows(plan))));
+    push("共享缓冲区命中", number(plan, "Shared Hit Blocks").map(blocks));
+    push("共享缓冲区读取", number(plan, "Shared Read Blocks").map(blocks));
+    push("共享缓冲区写脏", number(plan, "Shared Dirtied Blocks").map(blocks));
+    push("共享缓冲区写出", number(plan, "Shared Written Blocks").map(blocks));
+    push("临时文件读取", number(plan, "Temp Read Blocks").map(blocks));
+    push("临时文件写入", number(plan, "Temp Written Blocks").map(blocks));
+    // track_io_timing 开启时才有 I/O 耗时（PostgreSQL 16 起键名带 Shared 前缀）
+    push("I/O 读耗时", number(plan, "I/O Read Time").or(number(plan, "Shared I/O Read Time")).map(ms));
+    push("I/O 写耗时", number(plan, "I/O Write Time").or(number(plan, "Shared I/O Write Time")).map(ms));
+
+    let mut details = String::new();
+    describe(plan, 0, &mut details);
+    let execution_ms = number(root, "Execution Time").unwrap_or(0.0);
+    Some(QueryProfile {
+        elapsed_ms: execution_ms.round() as u64,
+        rows: (number(plan, "Actual Rows").unwrap_or(0.0) * number(plan, "Actual Loops").unwrap_or(1.0)) as usize,
+        stats,
+        details: Some(details),
+    })
+}
+
 /// 执行 PostgreSQL 查询
 pub async fn execute(config: &ConnectionConfig, sql: &str) -> Result<QueryResult, DbError> {
     let client = POOL_MANAGER.get_pg_client(config).await?;
 
     if is_query_statement(sql, &DatabaseType::PostgreSQL) {
-        let rows = client
-            .query(sql, &[])
-            .await
-            .map_err(|e| DbError::Query(e.to_string()))?;
+        let rows = query_cached(config, &client, sql).await?;
 
         if rows.is_empty() {
             return Ok(empty_result());
@@ -88,11 +216,173 @@ pub async fn execute(config: &ConnectionConfig, sql: &str) -> Result<QueryResult
         let affected = client
             .execute(sql, &[])
             .await
-            .map_err(|e| DbError::Query(e.to_string()))?;
+            .map_err(DbError::pg_query)?;
+        if invalidates_statements(sql) {
+            POOL_MANAGER.invalidate_statements(config);
+        }
         Ok(exec_result(affected))
     }
 }
 
+/// 在同一客户端上执行多条写语句，返回影响的总行数
+///
+/// 事务执行时把所有语句作为一次简单查询发送：服务端将其作为一个事务执行，任一语句失败时全部回滚，
+/// 共享同一客户端的其他查询也不会插入到事务中间
+pub async fn execute_batch(config: &ConnectionConfig, statements: &[String], transaction: bool) -> Result<u64, DbError> {
+    let client = POOL_MANAGER.get_pg_client(config).await?;
+
+    let affected = if transaction {
+        client
+            .simple_query(&statements.join(";\n"))
+            .await
+            .map_err(DbError::pg_query)?
+            .iter()
+            .map(|message| match message {
+                tokio_postgres::SimpleQueryMessage::CommandComplete(rows) => *rows,
+                _ => 0,
+            })
+            .sum()
+    } else {
+        let mut affected = 0;
+        for sql in statements {
+            affected += client
+                .execute(sql.as_str(), &[])
+                .await
+                .map_err(DbError::pg_query)?;
+        }
+        affected
+    };
+    if statements.iter().any(|sql| invalidates_statements(sql)) {
+        POOL_MANAGER.invalidate_statements(config);
+    }
+    Ok(affected)
+}
+
+/// 在只读事务中执行查询，结束后回滚
+///
+/// 与事务批量执行相同，`BEGIN`、查询和 `ROLLBACK` 作为一次简单查询发送，共享同一客户端的其他查询
+/// 不会插入到事务中间；简单查询以文本格式返回结果
+pub async fn execute_read_only(config: &ConnectionConfig, sql: &str) -> Result<QueryResult, DbError> {
+    use tokio_postgres::SimpleQueryMessage;
+
+    let client = POOL_MANAGER.get_pg_client(config).await?;
+    let messages = match client
+        .simple_query(&format!("BEGIN READ ONLY;\n{};\nROLLBACK", sql.trim().trim_end_matches(';')))
+        .await
+    {
+        Ok(messages) => messages,
+        Err(e) => {
+            // 出错后剩余语句不再执行，事务停留在失败状态，需要单独回滚
+            let _ = client.simple_query("ROLLBACK").await;
+            return Err(DbError::pg_query(e));
+        }
+    };
+
+    let mut columns = Vec::new();
+    let mut rows = Vec::new();
+    for message in &messages {
+        match message {
+            SimpleQueryMessage::RowDescription(description) => {
+                columns = description.iter().map(|c| c.name().to_owned()).collect();
+            }
+            SimpleQueryMessage::Row(row) => rows.push(
+                (0..row.len())
+                    .map(|i| row.get(i).map_or_else(|| String::from("NULL"), str::to_owned))
+                    .collect(),
+            ),
+            _ => {}
+        }
+    }
+    if columns.is_empty() {
+        return Ok(empty_result());
+    }
+    Ok(query_result(columns, rows))
+}
+
+/// 缓存的语句因表结构被其他会话修改而无法执行（cached plan must not change result type）
+fn is_stale_statement(error: &tokio_postgres::Error) -> bool {
+    error.code() == Some(&tokio_postgres::error::SqlState::FEATURE_NOT_SUPPORTED)
+}
+
+/// 使用缓存的预处理语句执行查询，缓存的语句已失效时重新准备后重试一次
+async fn query_cached(
+    config: &ConnectionConfig,
+    client: &Arc<tokio_postgres::Client>,
+    sql: &str,
+) -> Result<Vec<tokio_postgres::Row>, DbError> {
+    let (statement, cached) = POOL_MANAGER.prepare_pg(config, client, sql).await?;
+    match client.query(&statement, &[]).await {
+        Err(e) if cached && is_stale_statement(&e) => {
+            POOL_MANAGER.discard_pg_statement(config, sql);
+            let (statement, _) = POOL_MANAGER.prepare_pg(config, client, sql).await?;
+            client.query(&statement, &[]).await.map_err(DbError::pg_query)
+        }
+        result => result.map_err(DbError::pg_query),
+    }
+}
+
+/// 逐批读取 PostgreSQL 查询结果，每 `chunk_size` 行调用一次 `on_chunk`
+pub async fn execute_streaming(
+    config: &ConnectionConfig,
+    sql: &str,
+    chunk_size: usize,
+    mut on_chunk: impl FnMut(&[String], Vec<Vec<String>>) -> bool,
+) -> Result<QueryResult, DbError> {
+    use futures_util::{pin_mut, TryStreamExt};
+
+    let client = POOL_MANAGER.get_pg_client(config).await?;
+    let no_params = || std::iter::empty::<&(dyn tokio_postgres::types::ToSql + Sync)>();
+    let (mut statement, cached) = POOL_MANAGER.prepare_pg(config, &client, sql).await?;
+    let stream = match client.query_raw(&statement, no_params()).await {
+        Err(e) if cached && is_stale_statement(&e) => {
+            POOL_MANAGER.discard_pg_statement(config, sql);
+            statement = POOL_MANAGER.prepare_pg(config, &client, sql).await?.0;
+            client.query_raw(&statement, no_params()).await
+        }
+        result => result,
+    }
+    .map_err(DbError::pg_query)?;
+    let columns: Vec<String> = statement.columns().iter().map(|c| c.name().to_owned()).collect();
+    pin_mut!(stream);
+
+    let mut chunk = Vec::with_capacity(chunk_size);
+    let mut total = 0;
+    while let Some(row) = stream.try_next().await.map_err(DbError::pg_query)? {
+        chunk.push(row_to_strings(&row, columns.len()));
+        total += 1;
+        if chunk.len() >= chunk_size
+            && !on_chunk(&columns, std::mem::replace(&mut chunk, Vec::with_capacity(chunk_size)))
+        {
+            return Ok(streamed_result(columns, total));
+        }
+    }
+    if !chunk.is_empty() {
+        on_chunk(&columns, chunk);
+    }
+    Ok(streamed_result(columns, total))
+}
+
+/// PostGIS 的 geometry、geography 值（二进制格式即 EWKB）
+struct SpatialBytes(Vec<u8>);
+
+impl<'a> tokio_postgres::types::FromSql<'a> for SpatialBytes {
+    fn from_sql(
+        _ty: &tokio_postgres::types::Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        Ok(Self(raw.to_vec()))
+    }
+
+    fn accepts(ty: &tokio_postgres::types::Type) -> bool {
+        matches!(ty.name(), "geometry" | "geography")
+    }
+}
+
+/// 与 PostGIS 文本输出一致的大写十六进制 EWKB
+fn hex_upper(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02X}", b)).collect()
+}
+
 /// 将 PostgreSQL 行转换为字符串向量
 fn row_to_strings(row: &tokio_postgres::Row, col_count: usize) -> Vec<String> {
     (0..col_count)
@@ -109,6 +399,11 @@ fn row_to_strings(row: &tokio_postgres::Row, col_count: usize) -> Vec<String> {
                     row.try_get::<_, chrono::NaiveDateTime>(i)
                         .map(|v| v.format("%Y-%m-%d %H:%M:%S").to_string())
                 })
+                .or_else(|_| {
+                    // timestamptz 以 UTC 读取，保留偏移量供显示层换算时区
+                    row.try_get::<_, chrono::DateTime<chrono::Utc>>(i)
+                        .map(|v| v.format("%Y-%m-%d %H:%M:%S%:z").to_string())
+                })
                 .or_else(|_| {
                     row.try_get::<_, chrono::NaiveDate>(i)
                         .map(|v| v.format("%Y-%m-%d").to_string())
@@ -117,6 +412,8 @@ fn row_to_strings(row: &tokio_postgres::Row, col_count: usize) -> Vec<String> {
                     row.try_get::<_, chrono::NaiveTime>(i)
                         .map(|v| v.format("%H:%M:%S").to_string())
                 })
+                .or_else(|_| row.try_get::<_, Vec<u8>>(i).map(|v| encode_blob(&v)))
+                .or_else(|_| row.try_get::<_, SpatialBytes>(i).map(|v| hex_upper(&v.0)))
                 .unwrap_or_else(|_| String::from("NULL"))
         })
         .collect()
@@ -169,6 +466,346 @@ pub async fn get_triggers(config: &ConnectionConfig) -> Result<Vec<TriggerInfo>,
     Ok(triggers)
 }
 
+/// 获取 PostgreSQL public 模式下的视图和物化视图
+pub async fn get_views(config: &ConnectionConfig) -> Result<Vec<ViewInfo>, DbError> {
+    let client = POOL_MANAGER.get_pg_client(config).await?;
+
+    let sql = r#"
+        SELECT c.relname::text, pg_get_viewdef(c.oid, true)
+        FROM pg_class c
+        JOIN pg_namespace n ON c.relnamespace = n.oid
+        WHERE c.relkind IN ('v', 'm')
+          AND n.nspname = 'public'
+        ORDER BY c.relname
+    "#;
+
+    let rows = client
+        .query(sql, &[])
+        .await
+        .map_err(|e| DbError::Query(format!("查询视图失败: {}", e)))?;
+
+    Ok(rows
+        .iter()
+        .map(|row| ViewInfo {
+            name: row.get(0),
+            definition: row.get::<_, Option<String>>(1).unwrap_or_default(),
+        })
+        .collect())
+}
+
+/// 获取 PostgreSQL 序列（通过 pg_depend 找到 serial/identity 列所属的表和列）
+pub async fn get_sequences(config: &ConnectionConfig) -> Result<Vec<SequenceInfo>, DbError> {
+    let client = POOL_MANAGER.get_pg_client(config).await?;
+
+    let sql = r#"
+        SELECT 
+            s.sequencename,
+            t.relname AS table_name,
+            a.attname AS column_name,
+            s.last_value,
+            s.increment_by,
+            s.start_value
+        FROM pg_sequences s
+        JOIN pg_namespace n ON n.nspname = s.schemaname
+        JOIN pg_class c ON c.relnamespace = n.oid AND c.relname = s.sequencename
+        LEFT JOIN pg_depend d ON d.objid = c.oid
+            AND d.classid = 'pg_class'::regclass
+            AND d.refclassid = 'pg_class'::regclass
+            AND d.deptype IN ('a', 'i')
+        LEFT JOIN pg_class t ON t.oid = d.refobjid
+        LEFT JOIN pg_attribute a ON a.attrelid = d.refobjid AND a.attnum = d.refobjsubid
+        WHERE s.schemaname = 'public'
+        ORDER BY s.sequencename
+    "#;
+
+    let rows = client
+        .query(sql, &[])
+        .await
+        .map_err(|e| DbError::Query(format!("查询序列失败: {}", e)))?;
+
+    Ok(rows
+        .iter()
+        .map(|row| {
+            // last_value 在序列尚未使用（或无权限）时为 NULL，下一个值即起始值
+            let last_value: Option<i64> = row.get(3);
+            let increment: i64 = row.get(4);
+            let start: i64 = row.get(5);
+            SequenceInfo {
+                name: row.get(0),
+                table_name: row.get(1),
+                column_name: row.get(2),
+                last_value,
+                next_value: Some(last_value.map_or(start, |v| v.saturating_add(increment))),
+            }
+        })
+        .collect())
+}
+
+/// 获取 PostgreSQL public schema 中各表的大小和估算行数
+pub async fn get_table_sizes(config: &ConnectionConfig) -> Result<Vec<TableSizeInfo>, DbError> {
+    let client = POOL_MANAGER.get_pg_client(config).await?;
+
+    let sql = r#"
+        SELECT
+            c.relname,
+            c.reltuples::bigint,
+            pg_table_size(c.oid),
+            pg_indexes_size(c.oid)
+        FROM pg_class c
+        JOIN pg_namespace n ON n.oid = c.relnamespace
+        WHERE n.nspname = 'public'
+          AND c.relkind IN ('r', 'p', 'm')
+        ORDER BY pg_total_relation_size(c.oid) DESC
+    "#;
+
+    let rows = client
+        .query(sql, &[])
+        .await
+        .map_err(|e| DbError::Query(format!("查询表大小失败: {}", e)))?;
+
+    Ok(rows
+        .iter()
+        .map(|row| {
+            // 从未 VACUUM/ANALYZE 的表 reltuples 为 -1（PostgreSQL 14+）
+            let rows: i64 = row.get(1);
+            TableSizeInfo {
+                name: row.get(0),
+                row_count: (rows >= 0).then_some(rows),
+                data_bytes: Some(row.get(2)),
+                index_bytes: Some(row.get(3)),
+            }
+        })
+        .collect())
+}
+
+/// 按系统目录重建 PostgreSQL 表的建表语句（列、约束、索引及注释）
+pub async fn get_table_ddl(config: &ConnectionConfig, table: &str) -> Result<String, DbError> {
+    let client = POOL_MANAGER.get_pg_client(config).await?;
+    let map_err = |e: tokio_postgres::Error| DbError::Query(format!("获取建表语句失败: {}", e));
+
+    let relation = client
+        .query_opt(
+            r#"
+            SELECT c.oid, obj_description(c.oid, 'pg_class')
+            FROM pg_class c
+            JOIN pg_namespace n ON n.oid = c.relnamespace
+            WHERE n.nspname = 'public' AND c.relname = $1 AND c.relkind IN ('r', 'p')
+            "#,
+            &[&table],
+        )
+        .await
+        .map_err(map_err)?
+        .ok_or_else(|| DbError::Query(format!("表 {} 不存在", table)))?;
+    let oid: u32 = relation.get(0);
+    let table_comment: Option<String> = relation.get(1);
+
+    let columns = client
+        .query(
+            r#"
+            SELECT
+                a.attname::text,
+                format_type(a.atttypid, a.atttypmod),
+                pg_get_expr(d.adbin, d.adrelid),
+                a.attnotnull,
+                a.attidentity::text,
+                col_description(a.attrelid, a.attnum)
+            FROM pg_attribute a
+            LEFT JOIN pg_attrdef d ON d.adrelid = a.attrelid AND d.adnum = a.attnum
+            WHERE a.attrelid = $1 AND a.attnum > 0 AND NOT a.attisdropped
+            ORDER BY a.attnum
+            "#,
+            &[&oid],
+        )
+        .await
+        .map_err(map_err)?;
+
+    let constraints = client
+        .query(
+            r#"
+            SELECT conname::text, pg_get_constraintdef(oid)
+            FROM pg_constraint
+            WHERE conrelid = $1
+            ORDER BY contype <> 'p', contype, conname
+            "#,
+            &[&oid],
+        )
+        .await
+        .map_err(map_err)?;
+
+    // 约束自带的索引已包含在约束定义中
+    let indexes = client
+        .query(
+            r#"
+            SELECT pg_get_indexdef(i.indexrelid)
+            FROM pg_index i
+            WHERE i.indrelid = $1
+              AND NOT EXISTS (SELECT 1 FROM pg_constraint c WHERE c.conindid = i.indexrelid)
+            ORDER BY i.indexrelid
+            "#,
+            &[&oid],
+        )
+        .await
+        .map_err(map_err)?;
+
+    let quote = |name: &str| format!("\"{}\"", name.replace('"', "\"\""));
+    let qualified = format!("\"public\".{}", quote(table));
+
+    let mut lines = Vec::new();
+    let mut comments = Vec::new();
+    if let Some(comment) = table_comment {
+        comments.push(format!("COMMENT ON TABLE {} IS {};", qualified, quote_literal(&comment)));
+    }
+    for row in &columns {
+        let name: String = row.get(0);
+        let data_type: String = row.get(1);
+        let default: Option<String> = row.get(2);
+        let not_null: bool = row.get(3);
+        let identity: String = row.get(4);
+        let comment: Option<String> = row.get(5);
+
+        let mut line = format!("    {} {}", quote(&name), data_type);
+        match identity.as_str() {
+            "a" => line.push_str(" GENERATED ALWAYS AS IDENTITY"),
+            "d" => line.push_str(" GENERATED BY DEFAULT AS IDENTITY"),
+            _ => {
+                if let Some(default) = default {
+                    line.push_str(&format!(" DEFAULT {}", default));
+                }
+            }
+        }
+        if not_null {
+            line.push_str(" NOT NULL");
+        }
+        lines.push(line);
+
+        if let Some(comment) = comment {
+            comments.push(format!(
+                "COMMENT ON COLUMN {}.{} IS {};",
+                qualified,
+                quote(&name),
+                quote_literal(&comment)
+            ));
+        }
+    }
+    for row in &constraints {
+        let name: String = row.get(0);
+        let definition: String = row.get(1);
+        lines.push(format!("    CONSTRAINT {} {}", quote(&name), definition));
+    }
+
+    let mut ddl = format!("CREATE TABLE {} (\n{}\n);", qualified, lines.join(",\n"));
+    for row in &indexes {
+        let definition: String = row.get(0);
+        ddl.push_str(&format!("\n\n{};", definition));
+    }
+    if !comments.is_empty() {
+        ddl.push_str("\n\n");
+        ddl.push_str(&comments.join("\n"));
+    }
+    Ok(ddl)
+}
+
+fn quote_literal(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+/// 获取 PostgreSQL 客户端会话（`pg_stat_activity`）
+pub async fn get_sessions(config: &ConnectionConfig) -> Result<Vec<SessionInfo>, DbError> {
+    let client = POOL_MANAGER.get_pg_client(config).await?;
+
+    // 活跃会话从查询开始计时，其余从进入当前状态计时
+    let sql = r#"
+        SELECT
+            pid,
+            COALESCE(usename::text, ''),
+            datname::text,
+            client_addr::text,
+            COALESCE(state, ''),
+            EXTRACT(EPOCH FROM now() - CASE WHEN state = 'active' THEN query_start ELSE state_change END)::bigint,
+            COALESCE(query, ''),
+            CASE WHEN wait_event IS NULL THEN NULL ELSE wait_event_type || ': ' || wait_event END,
+            pg_blocking_pids(pid)
+        FROM pg_stat_activity
+        WHERE backend_type = 'client backend'
+          AND pid <> pg_backend_pid()
+        ORDER BY state = 'active' DESC, query_start
+    "#;
+
+    let rows = client
+        .query(sql, &[])
+        .await
+        .map_err(|e| DbError::Query(format!("查询会话失败: {}", e)))?;
+
+    Ok(rows
+        .iter()
+        .map(|row| {
+            let pid: i32 = row.get(0);
+            let blocked_by: Vec<i32> = row.get(8);
+            SessionInfo {
+                id: i64::from(pid),
+                user: row.get(1),
+                database: row.get(2),
+                client: row.get(3),
+                state: row.get(4),
+                duration_secs: row.get(5),
+                query: row.get(6),
+                wait: row.get(7),
+                blocked_by: blocked_by.into_iter().map(i64::from).collect(),
+            }
+        })
+        .collect())
+}
+
+/// 获取 PostgreSQL 角色列表
+pub async fn get_users(config: &ConnectionConfig) -> Result<Vec<String>, DbError> {
+    let client = POOL_MANAGER.get_pg_client(config).await?;
+
+    let rows = client
+        .query("SELECT rolname FROM pg_roles WHERE rolname !~ '^pg_' ORDER BY rolname", &[])
+        .await
+        .map_err(|e| DbError::Query(format!("查询角色失败: {}", e)))?;
+
+    Ok(rows.iter().map(|row| row.get(0)).collect())
+}
+
+/// 获取 PostgreSQL 角色在当前数据库及 public schema 表上的权限
+///
+/// 读取 ACL 而非 information_schema，后者只显示与当前用户相关的授权
+pub async fn get_grants(config: &ConnectionConfig, user: &str) -> Result<Vec<GrantInfo>, DbError> {
+    let client = POOL_MANAGER.get_pg_client(config).await?;
+
+    let sql = r#"
+        SELECT '' AS table_name, a.privilege_type
+        FROM pg_database d
+        CROSS JOIN LATERAL aclexplode(d.datacl) a
+        JOIN pg_roles r ON r.oid = a.grantee
+        WHERE d.datname = current_database() AND r.rolname = $1
+        UNION ALL
+        SELECT c.relname, a.privilege_type
+        FROM pg_class c
+        JOIN pg_namespace n ON n.oid = c.relnamespace
+        CROSS JOIN LATERAL aclexplode(c.relacl) a
+        JOIN pg_roles r ON r.oid = a.grantee
+        WHERE n.nspname = 'public'
+          AND c.relkind IN ('r', 'v', 'm', 'p')
+          AND r.rolname = $1
+        ORDER BY 1, 2
+    "#;
+
+    let rows = client
+        .query(sql, &[&user])
+        .await
+        .map_err(|e| DbError::Query(format!("查询权限失败: {}", e)))?;
+
+    Ok(rows
+        .iter()
+        .map(|row| GrantInfo {
+            table_name: row.get(0),
+            privilege: row.get(1),
+        })
+        .collect())
+}
+
 /// 获取 PostgreSQL 外键
 pub async fn get_foreign_keys(config: &ConnectionConfig) -> Result<Vec<ForeignKeyInfo>, DbError> {
     let client = POOL_MANAGER.get_pg_client(config).await?;
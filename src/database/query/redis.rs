@@ -0,0 +1,285 @@
+//! Redis 查询实现
+//!
+//! Redis 没有表结构，按以下方式映射到通用的浏览模型：
+//! - 数据库列表：`db0`..`dbN`（由 `CONFIG GET databases` 决定）
+//! - 表列表：当前库中匹配模式的键（SCAN MATCH）
+//! - 查询：命令控制台，每行一条 Redis 命令
+
+use std::time::Duration;
+
+use ::redis::{Connection as RedisConn, Value};
+use crate::core::constants;
+use crate::database::{ConnectionConfig, DbError, QueryResult};
+use super::{exec_result, query_result, RedisKeyValue};
+
+/// SCAN 每批建议返回的数量
+const SCAN_COUNT: usize = 1000;
+
+/// 打开 Redis 连接（`database` 为空时使用配置中的默认库）
+fn open_connection(config: &ConnectionConfig, database: Option<&str>) -> Result<RedisConn, DbError> {
+    let client = ::redis::Client::open(config.redis_url(database))
+        .map_err(|e| DbError::Connection(format!("Redis 连接参数无效: {}", e)))?;
+    client
+        .get_connection_with_timeout(Duration::from_secs(config.connect_timeout_secs()))
+        .map_err(|e| DbError::Connection(format!("Redis 连接失败: {}", e)))
+}
+
+fn query_err(e: ::redis::RedisError) -> DbError {
+    DbError::Query(e.to_string())
+}
+
+/// 获取逻辑库列表（db0..dbN）
+pub fn get_databases(config: &ConnectionConfig) -> Result<Vec<String>, DbError> {
+    let mut conn = open_connection(config, None)?;
+    // 云托管的 Redis 通常禁用 CONFIG 命令，此时按默认的 16 个库处理
+    let count = ::redis::cmd("CONFIG")
+        .arg("GET")
+        .arg("databases")
+        .query::<Vec<String>>(&mut conn)
+        .ok()
+        .and_then(|reply| reply.get(1).and_then(|n| n.parse::<usize>().ok()))
+        .unwrap_or(16);
+    Ok((0..count).map(|i| format!("db{}", i)).collect())
+}
+
+/// 按 SCAN 系列命令迭代，最多收集 `limit` 个元素（HSCAN 的字段和值各算一个）
+fn scan(
+    conn: &mut RedisConn,
+    command: &str,
+    key: Option<&str>,
+    pattern: Option<&str>,
+    limit: usize,
+) -> Result<Vec<Vec<u8>>, DbError> {
+    let mut items = Vec::new();
+    let mut cursor = 0u64;
+    loop {
+        let mut cmd = ::redis::cmd(command);
+        if let Some(key) = key {
+            cmd.arg(key);
+        }
+        cmd.arg(cursor);
+        if let Some(pattern) = pattern {
+            cmd.arg("MATCH").arg(pattern);
+        }
+        cmd.arg("COUNT").arg(SCAN_COUNT);
+
+        let (next, batch): (u64, Vec<Vec<u8>>) = cmd.query(conn).map_err(query_err)?;
+        items.extend(batch);
+        cursor = next;
+        if cursor == 0 || items.len() >= limit {
+            break;
+        }
+    }
+    items.truncate(limit);
+    Ok(items)
+}
+
+/// 获取指定库中匹配模式的键列表
+pub fn get_keys(config: &ConnectionConfig, database: &str) -> Result<Vec<String>, DbError> {
+    let mut conn = open_connection(config, Some(database))?;
+    let mut keys: Vec<String> = scan(
+        &mut conn,
+        "SCAN",
+        None,
+        Some(config.redis_pattern()),
+        constants::database::REDIS_MAX_SCAN_KEYS,
+    )?
+    .into_iter()
+    .map(|k| String::from_utf8_lossy(&k).into_owned())
+    .collect();
+    keys.sort();
+    keys.dedup();
+    Ok(keys)
+}
+
+/// 按类型读取键的值
+pub fn get_key_value(config: &ConnectionConfig, key: &str) -> Result<RedisKeyValue, DbError> {
+    let mut conn = open_connection(config, None)?;
+    let limit = constants::database::REDIS_MAX_VALUE_ITEMS;
+
+    let key_type: String = ::redis::cmd("TYPE").arg(key).query(&mut conn).map_err(query_err)?;
+    let ttl: i64 = ::redis::cmd("TTL").arg(key).query(&mut conn).map_err(query_err)?;
+
+    let length_command = match key_type.as_str() {
+        "hash" => "HLEN",
+        "list" => "LLEN",
+        "set" => "SCARD",
+        "zset" => "ZCARD",
+        "stream" => "XLEN",
+        _ => "",
+    };
+    let total: usize = if length_command.is_empty() {
+        1
+    } else {
+        ::redis::cmd(length_command).arg(key).query(&mut conn).map_err(query_err)?
+    };
+
+    let mut result = match key_type.as_str() {
+        "none" => return Err(DbError::Query(format!("键不存在: {}", key))),
+        "string" => {
+            let value: Vec<u8> = ::redis::cmd("GET").arg(key).query(&mut conn).map_err(query_err)?;
+            query_result(vec!["value".to_string()], vec![vec![lossy(&value)]])
+        }
+        "hash" => {
+            let items = scan(&mut conn, "HSCAN", Some(key), None, limit.saturating_mul(2))?;
+            let mut rows: Vec<Vec<String>> = items
+                .chunks(2)
+                .map(|pair| pair.iter().map(|v| lossy(v)).collect())
+                .collect();
+            rows.sort();
+            query_result(vec!["field".to_string(), "value".to_string()], rows)
+        }
+        "list" => {
+            let items: Vec<Vec<u8>> = ::redis::cmd("LRANGE")
+                .arg(key)
+                .arg(0)
+                .arg(limit as i64 - 1)
+                .query(&mut conn)
+                .map_err(query_err)?;
+            let rows = items
+                .iter()
+                .enumerate()
+                .map(|(i, v)| vec![i.to_string(), lossy(v)])
+                .collect();
+            query_result(vec!["index".to_string(), "value".to_string()], rows)
+        }
+        "set" => {
+            let mut rows: Vec<Vec<String>> = scan(&mut conn, "SSCAN", Some(key), None, limit)?
+                .iter()
+                .map(|v| vec![lossy(v)])
+                .collect();
+            rows.sort();
+            query_result(vec!["member".to_string()], rows)
+        }
+        "zset" => {
+            let items: Vec<(Vec<u8>, f64)> = ::redis::cmd("ZRANGE")
+                .arg(key)
+                .arg(0)
+                .arg(limit as i64 - 1)
+                .arg("WITHSCORES")
+                .query(&mut conn)
+                .map_err(query_err)?;
+            let rows = items
+                .iter()
+                .map(|(member, score)| vec![lossy(member), score.to_string()])
+                .collect();
+            query_result(vec!["member".to_string(), "score".to_string()], rows)
+        }
+        "stream" => {
+            let entries: Vec<(String, Vec<Vec<u8>>)> = ::redis::cmd("XRANGE")
+                .arg(key)
+                .arg("-")
+                .arg("+")
+                .arg("COUNT")
+                .arg(limit)
+                .query(&mut conn)
+                .map_err(query_err)?;
+            let rows = entries
+                .into_iter()
+                .map(|(id, fields)| {
+                    let fields = fields
+                        .chunks(2)
+                        .map(|pair| match pair {
+                            [k, v] => format!("{}={}", lossy(k), lossy(v)),
+                            [k] => lossy(k),
+                            _ => String::new(),
+                        })
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    vec![id, fields]
+                })
+                .collect();
+            query_result(vec!["id".to_string(), "fields".to_string()], rows)
+        }
+        other => query_result(
+            vec!["value".to_string()],
+            vec![vec![format!("(暂不支持预览 {} 类型，请使用命令控制台)", other)]],
+        ),
+    };
+
+    if total > result.rows.len() {
+        result.truncated = true;
+        result.original_row_count = Some(total);
+    }
+
+    Ok(RedisKeyValue {
+        key_type,
+        ttl: (ttl >= 0).then_some(ttl),
+        result,
+    })
+}
+
+/// 执行控制台命令（每行一条，返回最后一条命令的结果）
+pub fn execute(config: &ConnectionConfig, input: &str) -> Result<QueryResult, DbError> {
+    let mut conn = open_connection(config, None)?;
+    let mut last = exec_result(0);
+
+    for line in input.lines().map(str::trim).filter(|l| !l.is_empty() && !l.starts_with('#')) {
+        let args = super::split_command_line(line)?;
+        let Some((name, rest)) = args.split_first() else {
+            continue;
+        };
+        let mut cmd = ::redis::cmd(name);
+        for arg in rest {
+            cmd.arg(arg);
+        }
+        let value: Value = cmd.query(&mut conn).map_err(query_err)?;
+        last = value_to_result(value);
+    }
+    Ok(last)
+}
+
+/// 将命令返回值转换为表格结果
+fn value_to_result(value: Value) -> QueryResult {
+    match value {
+        Value::Attribute { data, .. } => value_to_result(*data),
+        Value::Array(items) | Value::Set(items) => {
+            let rows = items
+                .iter()
+                .enumerate()
+                .map(|(i, v)| vec![(i + 1).to_string(), value_to_string(v)])
+                .collect();
+            query_result(vec!["#".to_string(), "value".to_string()], rows)
+        }
+        Value::Map(pairs) => {
+            let rows = pairs
+                .iter()
+                .map(|(k, v)| vec![value_to_string(k), value_to_string(v)])
+                .collect();
+            query_result(vec!["key".to_string(), "value".to_string()], rows)
+        }
+        other => query_result(vec!["result".to_string()], vec![vec![value_to_string(&other)]]),
+    }
+}
+
+/// 将返回值格式化为单元格文本（嵌套集合使用紧凑的列表形式）
+fn value_to_string(value: &Value) -> String {
+    match value {
+        Value::Nil => "NULL".to_string(),
+        Value::Int(i) => i.to_string(),
+        Value::BulkString(bytes) => lossy(bytes),
+        Value::SimpleString(s) => s.clone(),
+        Value::Okay => "OK".to_string(),
+        Value::Double(d) => d.to_string(),
+        Value::Boolean(b) => b.to_string(),
+        Value::VerbatimString { text, .. } => text.clone(),
+        Value::Attribute { data, .. } => value_to_string(data),
+        Value::Array(items) | Value::Set(items) => format!(
+            "[{}]",
+            items.iter().map(value_to_string).collect::<Vec<_>>().join(", ")
+        ),
+        Value::Map(pairs) => format!(
+            "{{{}}}",
+            pairs
+                .iter()
+                .map(|(k, v)| format!("{}: {}", value_to_string(k), value_to_string(v)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        other => format!("{:?}", other),
+    }
+}
+
+fn lossy(bytes: &[u8]) -> String {
+    String::from_utf8_lossy(bytes).into_owned()
+}
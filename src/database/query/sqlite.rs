@@ -1,17 +1,72 @@
 //! SQLite 查询实现
 
-use rusqlite::{types::ValueRef, Connection as SqliteConn};
-use crate::database::{ConnectionConfig, DbError, QueryResult, DatabaseType};
-use super::{query_result, exec_result, is_query_statement, TriggerInfo, ForeignKeyInfo, ColumnInfo};
+use rusqlite::{types::ValueRef, Connection as SqliteConn, OptionalExtension};
+use crate::core::encode_blob;
+use crate::database::{
+    ConnectionConfig, DbError, QueryResult, DatabaseType, SqliteAttachment, POOL_MANAGER,
+};
+use super::{query_result, exec_result, streamed_result, is_query_statement, TriggerInfo, ViewInfo, ForeignKeyInfo, ColumnInfo, SequenceInfo, TableSizeInfo, QueryProfile};
 
-/// 连接 SQLite 并获取表列表
-pub fn connect(config: &ConnectionConfig) -> Result<Vec<String>, DbError> {
-    let conn = SqliteConn::open(&config.database)
+/// 打开 SQLite 连接
+///
+/// 如果配置了加密密钥，会通过 `PRAGMA key` 解密 SQLCipher 数据库并校验密钥
+fn open_connection(config: &ConnectionConfig) -> Result<SqliteConn, DbError> {
+    // 草稿本：先确保内存数据库存活，否则每次打开都是空库
+    if config.is_scratchpad() {
+        POOL_MANAGER.retain_sqlite_memory(config)?;
+    }
+
+    let conn = SqliteConn::open(config.sqlite_open_path())
         .map_err(|e| DbError::Connection(format!("SQLite 连接失败: {}", e)))?;
 
-    let mut stmt = conn.prepare(
-        "SELECT name FROM sqlite_master WHERE type='table' AND name NOT LIKE 'sqlite_%' ORDER BY name"
-    ).map_err(|e| DbError::Query(e.to_string()))?;
+    if !config.sqlite_key.is_empty() {
+        ensure_sqlcipher(&conn)?;
+        conn.pragma_update(None, "key", &config.sqlite_key)
+            .map_err(|e| DbError::Connection(format!("设置加密密钥失败: {}", e)))?;
+
+        // PRAGMA key 本身不会校验密钥，需要读取一次 schema 才能确认
+        conn.query_row("SELECT count(*) FROM sqlite_master", [], |row| row.get::<_, i64>(0))
+            .map_err(|_| {
+                DbError::Connection("无法解密数据库：密钥错误或文件不是 SQLCipher 数据库".to_string())
+            })?;
+    }
+
+    // 附加其他数据库文件
+    for attachment in &config.sqlite_attachments {
+        if !SqliteAttachment::is_valid_alias(&attachment.alias) {
+            return Err(DbError::Connection(format!("附加数据库别名无效: {}", attachment.alias)));
+        }
+        conn.execute(
+            &format!("ATTACH DATABASE ?1 AS \"{}\"", attachment.alias),
+            [&attachment.path],
+        )
+        .map_err(|e| {
+            DbError::Connection(format!("附加数据库 {} 失败: {}", attachment.alias, e))
+        })?;
+    }
+
+    Ok(conn)
+}
+
+/// 拆分可能带 schema 前缀的表名（`alias.table` -> (`alias`, `table`)）
+///
+/// 仅当前缀是已附加的 schema 时才拆分，避免误伤名称中带点的表
+fn split_table_name<'a>(config: &ConnectionConfig, table: &'a str) -> (&'a str, &'a str) {
+    if let Some((schema, name)) = table.split_once('.')
+        && config.sqlite_attachments.iter().any(|a| a.alias == schema)
+    {
+        return (schema, name);
+    }
+    ("main", table)
+}
+
+/// 列出指定 schema 下的表
+fn list_tables(conn: &SqliteConn, schema: &str) -> Result<Vec<String>, DbError> {
+    let sql = format!(
+        "SELECT name FROM \"{}\".sqlite_master WHERE type='table' AND name NOT LIKE 'sqlite_%' ORDER BY name",
+        schema
+    );
+    let mut stmt = conn.prepare(&sql).map_err(|e| DbError::Query(e.to_string()))?;
 
     let tables: Result<Vec<String>, _> = stmt
         .query_map([], |row| row.get(0))
@@ -21,14 +76,106 @@ pub fn connect(config: &ConnectionConfig) -> Result<Vec<String>, DbError> {
     tables.map_err(|e| DbError::Query(e.to_string()))
 }
 
+/// 为附加 schema 的表名加上前缀（main 保持原样）
+fn qualify(schema: &str, table: String) -> String {
+    if schema == "main" {
+        table
+    } else {
+        format!("{}.{}", schema, table)
+    }
+}
+
+/// 获取所有 schema（main + 附加数据库）
+///
+/// 会实际打开连接并执行 ATTACH，确保附加的文件都可用
+pub fn get_schemas(config: &ConnectionConfig) -> Result<Vec<String>, DbError> {
+    open_connection(config)?;
+    let mut schemas = vec!["main".to_string()];
+    schemas.extend(config.sqlite_attachments.iter().map(|a| a.alias.clone()));
+    Ok(schemas)
+}
+
+/// 获取指定 schema 的表列表（附加 schema 的表名带前缀）
+pub fn get_tables(config: &ConnectionConfig, schema: &str) -> Result<Vec<String>, DbError> {
+    let conn = open_connection(config)?;
+    let schema = if schema.is_empty() { "main" } else { schema };
+    Ok(list_tables(&conn, schema)?
+        .into_iter()
+        .map(|t| qualify(schema, t))
+        .collect())
+}
+
+/// 获取所有 schema 的表列表（用于自动补全）
+pub fn get_all_tables(config: &ConnectionConfig) -> Result<Vec<String>, DbError> {
+    let conn = open_connection(config)?;
+    let mut tables = list_tables(&conn, "main")?;
+    for attachment in &config.sqlite_attachments {
+        tables.extend(
+            list_tables(&conn, &attachment.alias)?
+                .into_iter()
+                .map(|t| qualify(&attachment.alias, t)),
+        );
+    }
+    Ok(tables)
+}
+
+/// 检查当前 SQLite 是否为 SQLCipher 构建
+///
+/// 普通 SQLite 会静默忽略 `PRAGMA key`，因此需要提前检测，避免误以为已加密
+fn ensure_sqlcipher(conn: &SqliteConn) -> Result<(), DbError> {
+    let version: Option<String> = conn
+        .query_row("PRAGMA cipher_version", [], |row| row.get(0))
+        .optional()
+        .map_err(|e| DbError::Query(e.to_string()))?;
+
+    match version {
+        Some(_) => Ok(()),
+        None => Err(DbError::Connection(
+            "当前版本未启用 SQLCipher 支持，请使用 --features sqlcipher 重新编译".to_string(),
+        )),
+    }
+}
+
+/// 修改 SQLCipher 数据库的加密密钥
+pub fn change_key(config: &ConnectionConfig, new_key: &str) -> Result<(), DbError> {
+    if config.sqlite_key.is_empty() {
+        return Err(DbError::Query("当前数据库未加密，无法修改密钥".to_string()));
+    }
+    if new_key.is_empty() {
+        return Err(DbError::Query("新密钥不能为空".to_string()));
+    }
+
+    let conn = open_connection(config)?;
+    conn.pragma_update(None, "rekey", new_key)
+        .map_err(|e| DbError::Query(format!("修改密钥失败: {}", e)))
+}
+
+/// 将 SQLite 数据库（通常是草稿本）保存到文件
+pub fn save_to_file(config: &ConnectionConfig, path: &str) -> Result<(), DbError> {
+    if std::path::Path::new(path).exists() {
+        return Err(DbError::Query(format!("目标文件已存在: {}", path)));
+    }
+
+    let conn = open_connection(config)?;
+    conn.execute("VACUUM main INTO ?1", [path])
+        .map_err(|e| DbError::Query(format!("保存数据库失败: {}", e)))?;
+    Ok(())
+}
+
+/// 连接 SQLite 并获取表列表
+pub fn connect(config: &ConnectionConfig) -> Result<Vec<String>, DbError> {
+    let conn = open_connection(config)?;
+    list_tables(&conn, "main")
+}
+
 /// 获取 SQLite 表的主键列名
 pub fn get_primary_key(config: &ConnectionConfig, table: &str) -> Result<Option<String>, DbError> {
-    let conn = SqliteConn::open(&config.database)
-        .map_err(|e| DbError::Connection(format!("SQLite 连接失败: {}", e)))?;
+    let conn = open_connection(config)?;
     
     // 使用 PRAGMA table_info 查询主键列（pk 字段 > 0 表示是主键）
+    let (schema, table) = split_table_name(config, table);
     let escaped_table = table.replace('\'', "''");
-    let sql = format!("PRAGMA table_info('{}')", escaped_table);
+    let sql = format!("PRAGMA \"{}\".table_info('{}')", schema, escaped_table);
     
     let mut stmt = conn.prepare(&sql)
         .map_err(|e| DbError::Query(e.to_string()))?;
@@ -50,29 +197,48 @@ pub fn get_primary_key(config: &ConnectionConfig, table: &str) -> Result<Option<
     Ok(pk_columns.into_iter().next())
 }
 
-/// 执行 SQLite 查询
-pub fn execute(config: &ConnectionConfig, sql: &str) -> Result<QueryResult, DbError> {
-    let conn = SqliteConn::open(&config.database)
-        .map_err(|e| DbError::Connection(format!("SQLite 连接失败: {}", e)))?;
+/// 检查 SQLite 语句（只编译语句，不执行）
+pub fn validate(config: &ConnectionConfig, sql: &str) -> Result<(), DbError> {
+    let conn = open_connection(config)?;
+    conn.prepare(sql).map(|_| ()).map_err(|e| DbError::Query(e.to_string()))
+}
 
-    if is_query_statement(sql, &DatabaseType::SQLite) {
-        let mut stmt = conn
-            .prepare(sql)
-            .map_err(|e| DbError::Query(e.to_string()))?;
+/// 执行 SQLite 查询并读取语句计数器
+pub fn profile(config: &ConnectionConfig, sql: &str) -> Result<QueryProfile, DbError> {
+    use rusqlite::StatementStatus;
 
-        let columns: Vec<String> = stmt.column_names().into_iter().map(String::from).collect();
+    let conn = open_connection(config)?;
+    let mut stmt = conn.prepare(sql).map_err(|e| DbError::Query(e.to_string()))?;
+    let start = std::time::Instant::now();
+    let mut count = 0;
+    {
+        // 计数器在语句重置后仍然保留，读取前先释放结果集
+        let mut rows = stmt.raw_query();
+        while rows.next().map_err(|e| DbError::Query(e.to_string()))?.is_some() {
+            count += 1;
+        }
+    }
+    let elapsed_ms = start.elapsed().as_millis() as u64;
 
-        let rows: Result<Vec<Vec<String>>, _> = stmt
-            .query_map([], |row| {
-                (0..columns.len())
-                    .map(|i| value_to_string(row.get_ref(i)))
-                    .collect::<Result<Vec<_>, _>>()
-            })
-            .map_err(|e| DbError::Query(e.to_string()))?
-            .collect();
+    let stats = [
+        ("全表扫描步数", StatementStatus::FullscanStep),
+        ("排序次数", StatementStatus::Sort),
+        ("自动索引插入的行", StatementStatus::AutoIndex),
+        ("虚拟机指令数", StatementStatus::VmStep),
+        ("语句占用内存（字节）", StatementStatus::MemUsed),
+    ]
+    .into_iter()
+    .map(|(name, status)| (name.to_string(), stmt.get_status(status).to_string()))
+    .collect();
+    Ok(QueryProfile { elapsed_ms, rows: count, stats, details: None })
+}
 
-        let rows = rows.map_err(|e| DbError::Query(e.to_string()))?;
-        Ok(query_result(columns, rows))
+/// 执行 SQLite 查询
+pub fn execute(config: &ConnectionConfig, sql: &str) -> Result<QueryResult, DbError> {
+    let conn = open_connection(config)?;
+
+    if is_query_statement(sql, &DatabaseType::SQLite) {
+        query_rows(&conn, sql)
     } else {
         let affected = conn
             .execute(sql, [])
@@ -81,6 +247,92 @@ pub fn execute(config: &ConnectionConfig, sql: &str) -> Result<QueryResult, DbEr
     }
 }
 
+/// 以 `query_only` 模式执行查询，语句试图修改数据库时报错
+pub fn execute_read_only(config: &ConnectionConfig, sql: &str) -> Result<QueryResult, DbError> {
+    let conn = open_connection(config)?;
+    conn.pragma_update(None, "query_only", true)
+        .map_err(|e| DbError::Query(e.to_string()))?;
+    query_rows(&conn, sql)
+}
+
+/// 执行查询并读取全部结果行
+fn query_rows(conn: &SqliteConn, sql: &str) -> Result<QueryResult, DbError> {
+    let mut stmt = conn
+        .prepare(sql)
+        .map_err(|e| DbError::Query(e.to_string()))?;
+
+    let columns: Vec<String> = stmt.column_names().into_iter().map(String::from).collect();
+
+    let rows: Result<Vec<Vec<String>>, _> = stmt
+        .query_map([], |row| {
+            (0..columns.len())
+                .map(|i| value_to_string(row.get_ref(i)))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .map_err(|e| DbError::Query(e.to_string()))?
+        .collect();
+
+    let rows = rows.map_err(|e| DbError::Query(e.to_string()))?;
+    Ok(query_result(columns, rows))
+}
+
+/// 在同一连接上执行多条写语句，返回影响的总行数；事务执行时任一语句失败则全部回滚
+pub fn execute_batch(config: &ConnectionConfig, statements: &[String], transaction: bool) -> Result<u64, DbError> {
+    let mut conn = open_connection(config)?;
+    let execute_all = |conn: &SqliteConn| -> Result<u64, DbError> {
+        let mut affected = 0;
+        for sql in statements {
+            affected += conn.execute(sql, []).map_err(|e| DbError::Query(e.to_string()))? as u64;
+        }
+        Ok(affected)
+    };
+
+    if transaction {
+        // 未提交的事务在释放时回滚
+        let tx = conn.transaction().map_err(|e| DbError::Query(e.to_string()))?;
+        let affected = execute_all(&tx)?;
+        tx.commit().map_err(|e| DbError::Query(e.to_string()))?;
+        Ok(affected)
+    } else {
+        execute_all(&conn)
+    }
+}
+
+/// 逐批读取 SQLite 查询结果，每 `chunk_size` 行调用一次 `on_chunk`
+pub fn execute_streaming(
+    config: &ConnectionConfig,
+    sql: &str,
+    chunk_size: usize,
+    mut on_chunk: impl FnMut(&[String], Vec<Vec<String>>) -> bool,
+) -> Result<QueryResult, DbError> {
+    let conn = open_connection(config)?;
+    let mut stmt = conn
+        .prepare(sql)
+        .map_err(|e| DbError::Query(e.to_string()))?;
+    let columns: Vec<String> = stmt.column_names().into_iter().map(String::from).collect();
+
+    let mut rows = stmt.query([]).map_err(|e| DbError::Query(e.to_string()))?;
+    let mut chunk = Vec::with_capacity(chunk_size);
+    let mut total = 0;
+    while let Some(row) = rows.next().map_err(|e| DbError::Query(e.to_string()))? {
+        let values = (0..columns.len())
+            .map(|i| value_to_string(row.get_ref(i)))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| DbError::Query(e.to_string()))?;
+        chunk.push(values);
+        total += 1;
+        if chunk.len() >= chunk_size
+            && !on_chunk(&columns, std::mem::replace(&mut chunk, Vec::with_capacity(chunk_size)))
+        {
+            return Ok(streamed_result(columns, total));
+        }
+    }
+    if !chunk.is_empty() {
+        on_chunk(&columns, chunk);
+    }
+    Ok(streamed_result(columns, total))
+}
+
 /// 将 SQLite 值转换为字符串
 fn value_to_string(
     val: Result<ValueRef<'_>, rusqlite::Error>,
@@ -90,14 +342,13 @@ fn value_to_string(
         ValueRef::Integer(i) => i.to_string(),
         ValueRef::Real(f) => f.to_string(),
         ValueRef::Text(t) => String::from_utf8_lossy(t).into_owned(),
-        ValueRef::Blob(b) => format!("<Blob {} bytes>", b.len()),
+        ValueRef::Blob(b) => encode_blob(b),
     })
 }
 
 /// 获取 SQLite 触发器
 pub fn get_triggers(config: &ConnectionConfig) -> Result<Vec<TriggerInfo>, DbError> {
-    let conn = SqliteConn::open(&config.database)
-        .map_err(|e| DbError::Connection(format!("SQLite 连接失败: {}", e)))?;
+    let conn = open_connection(config)?;
 
     let mut stmt = conn
         .prepare("SELECT name, tbl_name, sql FROM sqlite_master WHERE type='trigger' ORDER BY name")
@@ -147,60 +398,209 @@ pub fn get_triggers(config: &ConnectionConfig) -> Result<Vec<TriggerInfo>, DbErr
     triggers.map_err(|e| DbError::Query(e.to_string()))
 }
 
-/// 获取 SQLite 外键
-pub fn get_foreign_keys(config: &ConnectionConfig) -> Result<Vec<ForeignKeyInfo>, DbError> {
-    let conn = SqliteConn::open(&config.database)
-        .map_err(|e| DbError::Connection(format!("SQLite 连接失败: {}", e)))?;
+/// 获取 SQLite 视图（定义为完整的 CREATE VIEW 语句）
+pub fn get_views(config: &ConnectionConfig) -> Result<Vec<ViewInfo>, DbError> {
+    let conn = open_connection(config)?;
 
-    // 首先获取所有表
-    let mut tables_stmt = conn
-        .prepare("SELECT name FROM sqlite_master WHERE type='table' AND name NOT LIKE 'sqlite_%'")
+    let mut stmt = conn
+        .prepare("SELECT name, sql FROM sqlite_master WHERE type='view' ORDER BY name")
         .map_err(|e| DbError::Query(e.to_string()))?;
 
-    let tables: Vec<String> = tables_stmt
+    stmt.query_map([], |row| {
+        Ok(ViewInfo {
+            name: row.get(0)?,
+            definition: row.get::<_, Option<String>>(1)?.unwrap_or_default(),
+        })
+    })
+    .and_then(|rows| rows.collect())
+    .map_err(|e| DbError::Query(e.to_string()))
+}
+
+/// 获取 main 数据库各表的行数和大小
+///
+/// 行数通过 `COUNT(*)` 精确统计；大小来自 `dbstat` 虚拟表，SQLite 编译时未启用时为 `None`
+pub fn get_table_sizes(config: &ConnectionConfig) -> Result<Vec<TableSizeInfo>, DbError> {
+    let conn = open_connection(config)?;
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT name FROM main.sqlite_master \
+             WHERE type = 'table' AND name NOT LIKE 'sqlite_%' ORDER BY name",
+        )
+        .map_err(|e| DbError::Query(e.to_string()))?;
+    let names: Vec<String> = stmt
         .query_map([], |row| row.get(0))
+        .and_then(|rows| rows.collect())
+        .map_err(|e| DbError::Query(e.to_string()))?;
+
+    // 按表汇总数据页和索引页大小（表名, 是否为索引, 字节数）
+    let sizes: Option<Vec<(String, bool, i64)>> = conn
+        .prepare(
+            "SELECT m.tbl_name, m.type = 'index', SUM(s.pgsize) \
+             FROM dbstat('main') s JOIN main.sqlite_master m ON m.name = s.name \
+             GROUP BY m.tbl_name, m.type",
+        )
+        .and_then(|mut stmt| {
+            stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?
+                .collect()
+        })
+        .ok();
+
+    names
+        .into_iter()
+        .map(|name| {
+            let row_count: i64 = conn
+                .query_row(&format!("SELECT COUNT(*) FROM main.\"{}\"", name.replace('"', "\"\"")), [], |row| {
+                    row.get(0)
+                })
+                .map_err(|e| DbError::Query(e.to_string()))?;
+            let size_of = |index: bool| {
+                sizes.as_ref().map(|sizes| {
+                    sizes
+                        .iter()
+                        .filter(|(table, is_index, _)| *table == name && *is_index == index)
+                        .map(|(_, _, bytes)| bytes)
+                        .sum::<i64>()
+                })
+            };
+            Ok(TableSizeInfo {
+                data_bytes: size_of(false),
+                index_bytes: size_of(true),
+                row_count: Some(row_count),
+                name,
+            })
+        })
+        .collect()
+}
+
+/// 获取 SQLite 的 AUTOINCREMENT 计数器（`sqlite_sequence` 表，没有 AUTOINCREMENT 表时不存在）
+pub fn get_sequences(config: &ConnectionConfig) -> Result<Vec<SequenceInfo>, DbError> {
+    let conn = open_connection(config)?;
+
+    let has_sequence_table = conn
+        .query_row(
+            "SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'sqlite_sequence'",
+            [],
+            |_| Ok(()),
+        )
+        .optional()
+        .map_err(|e| DbError::Query(e.to_string()))?
+        .is_some();
+    if !has_sequence_table {
+        return Ok(Vec::new());
+    }
+
+    // AUTOINCREMENT 列即 INTEGER PRIMARY KEY 列
+    let mut stmt = conn
+        .prepare(
+            "SELECT s.name, s.seq, \
+             (SELECT p.name FROM pragma_table_info(s.name) p WHERE p.pk = 1) \
+             FROM sqlite_sequence s ORDER BY s.name",
+        )
+        .map_err(|e| DbError::Query(e.to_string()))?;
+
+    let sequences: Result<Vec<SequenceInfo>, _> = stmt
+        .query_map([], |row| {
+            let name: String = row.get(0)?;
+            let seq: i64 = row.get(1)?;
+            Ok(SequenceInfo {
+                table_name: Some(name.clone()),
+                column_name: row.get(2)?,
+                last_value: Some(seq).filter(|v| *v > 0),
+                next_value: Some(seq.saturating_add(1)),
+                name,
+            })
+        })
         .map_err(|e| DbError::Query(e.to_string()))?
-        .filter_map(|r| r.ok())
         .collect();
 
-    let mut foreign_keys = Vec::new();
+    sequences.map_err(|e| DbError::Query(e.to_string()))
+}
 
-    // 对每个表查询外键
-    for table in tables {
-        let sql = format!("PRAGMA foreign_key_list('{}')", table.replace('\'', "''"));
-        let mut fk_stmt = conn
-            .prepare(&sql)
-            .map_err(|e| DbError::Query(e.to_string()))?;
+/// 获取 SQLite 外键
+pub fn get_foreign_keys(config: &ConnectionConfig) -> Result<Vec<ForeignKeyInfo>, DbError> {
+    let conn = open_connection(config)?;
 
-        // foreign_key_list 返回: id, seq, table, from, to, on_update, on_delete, match
-        let fks: Vec<ForeignKeyInfo> = fk_stmt
-            .query_map([], |row| {
-                let to_table: String = row.get(2)?;
-                let from_column: String = row.get(3)?;
-                let to_column: String = row.get(4)?;
-                Ok(ForeignKeyInfo {
-                    from_table: table.clone(),
-                    from_column,
-                    to_table,
-                    to_column,
-                })
-            })
-            .map_err(|e| DbError::Query(e.to_string()))?
-            .filter_map(|r| r.ok())
-            .collect();
+    // 首先获取所有 schema 的表（外键不能跨 schema）
+    let mut schemas = vec!["main".to_string()];
+    schemas.extend(config.sqlite_attachments.iter().map(|a| a.alias.clone()));
 
-        foreign_keys.extend(fks);
+    let mut foreign_keys = Vec::new();
+
+    for schema in &schemas {
+        for table in list_tables(&conn, schema)? {
+            foreign_keys.extend(table_foreign_keys(&conn, schema, &table)?);
+        }
     }
 
     Ok(foreign_keys)
 }
 
+/// 查询单个表的外键（表名按 schema 加前缀）
+fn table_foreign_keys(
+    conn: &SqliteConn,
+    schema: &str,
+    table: &str,
+) -> Result<Vec<ForeignKeyInfo>, DbError> {
+    let sql = format!(
+        "PRAGMA \"{}\".foreign_key_list('{}')",
+        schema,
+        table.replace('\'', "''")
+    );
+    let mut fk_stmt = conn
+        .prepare(&sql)
+        .map_err(|e| DbError::Query(e.to_string()))?;
+
+    // foreign_key_list 返回: id, seq, table, from, to, on_update, on_delete, match
+    let fks: Vec<ForeignKeyInfo> = fk_stmt
+        .query_map([], |row| {
+            let to_table: String = row.get(2)?;
+            let from_column: String = row.get(3)?;
+            let to_column: String = row.get(4)?;
+            Ok(ForeignKeyInfo {
+                from_table: qualify(schema, table.to_string()),
+                from_column,
+                to_table: qualify(schema, to_table),
+                to_column,
+            })
+        })
+        .map_err(|e| DbError::Query(e.to_string()))?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    Ok(fks)
+}
+
+/// 获取 SQLite 表的建表语句及其索引、触发器（来自 sqlite_master）
+pub fn get_table_ddl(config: &ConnectionConfig, table: &str) -> Result<String, DbError> {
+    let conn = open_connection(config)?;
+
+    let (schema, table) = split_table_name(config, table);
+    // 先输出表本身，再输出索引和触发器；自动索引的 sql 为 NULL
+    let sql = format!(
+        "SELECT sql FROM \"{}\".sqlite_master WHERE tbl_name = ?1 AND sql IS NOT NULL \
+         ORDER BY type <> 'table', type, name",
+        schema.replace('"', "\"\"")
+    );
+    let mut stmt = conn.prepare(&sql).map_err(|e| DbError::Query(e.to_string()))?;
+    let statements: Vec<String> = stmt
+        .query_map([table], |row| row.get(0))
+        .map_err(|e| DbError::Query(e.to_string()))?
+        .collect::<Result<_, _>>()
+        .map_err(|e| DbError::Query(e.to_string()))?;
+
+    if statements.is_empty() {
+        return Err(DbError::Query(format!("表 {} 不存在", table)));
+    }
+    Ok(statements.iter().map(|s| format!("{};", s)).collect::<Vec<_>>().join("\n\n"))
+}
+
 /// 获取 SQLite 表的列信息
 pub fn get_columns(config: &ConnectionConfig, table: &str) -> Result<Vec<ColumnInfo>, DbError> {
-    let conn = SqliteConn::open(&config.database)
-        .map_err(|e| DbError::Connection(format!("SQLite 连接失败: {}", e)))?;
+    let conn = open_connection(config)?;
 
-    let sql = format!("PRAGMA table_info('{}')", table.replace('\'', "''"));
+    let (schema, table) = split_table_name(config, table);
+    let sql = format!("PRAGMA \"{}\".table_info('{}')", schema, table.replace('\'', "''"));
     let mut stmt = conn
         .prepare(&sql)
         .map_err(|e| DbError::Query(e.to_string()))?;
@@ -0,0 +1,86 @@
+//! 预处理语句缓存
+//!
+//! 按 (连接池键, SQL) 缓存服务端预处理语句，重复执行同一查询（如刷新表格）时省去准备语句的往返；
+//! 缓存已满时淘汰最久未使用的语句，执行修改表结构的语句后整个连接池的缓存失效。
+
+use crate::core::is_ddl_statement;
+use std::collections::HashMap;
+
+/// 按最近使用顺序淘汰的预处理语句缓存
+pub struct StatementCache<S> {
+    /// (连接池键, SQL) -> (语句, 使用顺序)
+    entries: HashMap<(String, String), (S, u64)>,
+    capacity: usize,
+    /// 递增的使用计数，用于确定使用顺序
+    clock: u64,
+}
+
+impl<S: Clone> StatementCache<S> {
+    /// 创建最多保留 `capacity` 条语句的缓存
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: HashMap::new(),
+            capacity: capacity.max(1),
+            clock: 0,
+        }
+    }
+
+    fn tick(&mut self) -> u64 {
+        self.clock += 1;
+        self.clock
+    }
+
+    /// 获取缓存的语句并标记为刚使用过
+    pub fn get(&mut self, pool_key: &str, sql: &str) -> Option<S> {
+        let order = self.tick();
+        let (statement, used) = self.entries.get_mut(&(pool_key.to_string(), sql.to_string()))?;
+        *used = order;
+        Some(statement.clone())
+    }
+
+    /// 缓存语句，已满时淘汰最久未使用的语句
+    pub fn insert(&mut self, pool_key: &str, sql: &str, statement: S) {
+        let key = (pool_key.to_string(), sql.to_string());
+        if !self.entries.contains_key(&key)
+            && self.entries.len() >= self.capacity
+            && let Some(oldest) = self.entries.iter().min_by_key(|(_, (_, used))| *used).map(|(k, _)| k.clone())
+        {
+            self.entries.remove(&oldest);
+        }
+        let order = self.tick();
+        self.entries.insert(key, (statement, order));
+    }
+
+    /// 移除一条语句（执行失败的语句不再复用）
+    pub fn remove(&mut self, pool_key: &str, sql: &str) {
+        self.entries.remove(&(pool_key.to_string(), sql.to_string()));
+    }
+
+    /// 移除连接池的所有语句，返回移除的数量
+    pub fn invalidate(&mut self, pool_key: &str) -> usize {
+        let before = self.entries.len();
+        self.entries.retain(|(key, _), _| key != pool_key);
+        before - self.entries.len()
+    }
+
+    /// 移除所有语句
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// 缓存的语句数量
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// 缓存是否为空
+    #[allow(dead_code)] // 公开 API
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// 语句是否会修改表结构而使已准备的语句失效（与元数据刷新使用相同的 DDL 判断）
+pub fn invalidates_statements(sql: &str) -> bool {
+    is_ddl_statement(sql)
+}
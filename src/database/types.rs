@@ -1,5 +1,6 @@
 //! 数据库类型定义
 
+use once_cell::sync::Lazy;
 use serde::{Deserialize, Serialize};
 
 // ============================================================================
@@ -13,6 +14,18 @@ pub enum DatabaseType {
     SQLite,
     PostgreSQL,
     MySQL,
+    /// DuckDB 分析型数据库（需启用 `duckdb` 特性）
+    DuckDB,
+    /// Oracle 数据库（需启用 `oracle` 特性，运行时需要 Oracle Instant Client）
+    Oracle,
+    /// 通用 ODBC 数据源（需启用 `odbc` 特性），用于没有原生驱动的数据库
+    Odbc,
+    /// Redis 键值数据库（需启用 `redis` 特性），使用命令控制台而非 SQL
+    Redis,
+    /// MongoDB 文档数据库（需启用 `mongodb` 特性），查询使用 JSON 格式的数据库命令
+    MongoDB,
+    /// ClickHouse 列式分析数据库（需启用 `clickhouse` 特性），通过 HTTP 接口访问
+    ClickHouse,
 }
 
 impl DatabaseType {
@@ -22,27 +35,121 @@ impl DatabaseType {
             Self::SQLite => "SQLite",
             Self::PostgreSQL => "PostgreSQL",
             Self::MySQL => "MySQL",
+            Self::DuckDB => "DuckDB",
+            Self::Oracle => "Oracle",
+            Self::Odbc => "ODBC",
+            Self::Redis => "Redis",
+            Self::MongoDB => "MongoDB",
+            Self::ClickHouse => "ClickHouse",
         }
     }
 
-    /// 获取所有数据库类型
-    pub const fn all() -> &'static [DatabaseType] {
-        &[Self::SQLite, Self::PostgreSQL, Self::MySQL]
+    /// 获取所有数据库类型（仅包含当前构建已启用驱动的类型）
+    pub fn all() -> &'static [DatabaseType] {
+        static ENABLED: Lazy<Vec<DatabaseType>> = Lazy::new(|| {
+            [
+                DatabaseType::SQLite,
+                DatabaseType::PostgreSQL,
+                DatabaseType::MySQL,
+                DatabaseType::DuckDB,
+                DatabaseType::Oracle,
+                DatabaseType::Odbc,
+                DatabaseType::Redis,
+                DatabaseType::MongoDB,
+                DatabaseType::ClickHouse,
+            ]
+            .into_iter()
+            .filter(|t| t.is_enabled())
+            .collect()
+        });
+        &ENABLED
+    }
+
+    /// 当前构建是否启用了该类型的驱动（可选驱动由 cargo 特性控制）
+    #[allow(clippy::match_like_matches_macro)] // 结果随特性变化，保持 match 便于扩展
+    pub const fn is_enabled(&self) -> bool {
+        match self {
+            Self::DuckDB => cfg!(feature = "duckdb"),
+            Self::Oracle => cfg!(feature = "oracle"),
+            Self::Odbc => cfg!(feature = "odbc"),
+            Self::Redis => cfg!(feature = "redis"),
+            Self::MongoDB => cfg!(feature = "mongodb"),
+            Self::ClickHouse => cfg!(feature = "clickhouse"),
+            _ => true,
+        }
     }
 
     /// 获取默认端口
     pub const fn default_port(&self) -> u16 {
         match self {
-            Self::SQLite => 0,
+            Self::SQLite | Self::DuckDB | Self::Odbc => 0,
             Self::PostgreSQL => 5432,
             Self::MySQL => 3306,
+            Self::Oracle => 1521,
+            Self::Redis => 6379,
+            Self::MongoDB => 27017,
+            Self::ClickHouse => 8123,
         }
     }
 
-    /// 是否需要网络连接
-    #[allow(dead_code)] // 公开 API，供外部使用
+    /// 是否需要配置网络连接（主机/端口、SSH 隧道）
+    ///
+    /// ODBC 的网络参数由 DSN 管理，不在此配置
     pub const fn requires_network(&self) -> bool {
-        !matches!(self, Self::SQLite)
+        !self.is_file_based() && !matches!(self, Self::Odbc)
+    }
+
+    /// 是否为本地文件型数据库（SQLite/DuckDB）
+    pub const fn is_file_based(&self) -> bool {
+        matches!(self, Self::SQLite | Self::DuckDB)
+    }
+
+    /// 是否使用 SQL 查询（Redis、MongoDB 使用各自的命令语法）
+    pub const fn uses_sql(&self) -> bool {
+        !matches!(self, Self::Redis | Self::MongoDB)
+    }
+
+    /// 生成带行数限制的整表查询（Oracle/ODBC 使用标准 FETCH FIRST，其余使用 LIMIT）
+    ///
+    /// MongoDB 生成 find 命令，`quoted_table` 为 JSON 字符串形式的集合名
+    pub fn select_all_sql(&self, quoted_table: &str, limit: usize) -> String {
+        match self {
+            Self::MongoDB => format!("{{\"find\": {}, \"limit\": {}}}", quoted_table, limit),
+            Self::Oracle | Self::Odbc => format!("SELECT * FROM {} FETCH FIRST {} ROWS ONLY;", quoted_table, limit),
+            _ => format!("SELECT * FROM {} LIMIT {};", quoted_table, limit),
+        }
+    }
+
+    /// 按条件读取表数据的 SQL（`where_clause` 不含 `WHERE`）
+    pub fn select_where_sql(&self, quoted_table: &str, where_clause: &str, limit: usize) -> String {
+        match self {
+            Self::Oracle | Self::Odbc => format!(
+                "SELECT * FROM {} WHERE {} FETCH FIRST {} ROWS ONLY;",
+                quoted_table, where_clause, limit
+            ),
+            _ => format!("SELECT * FROM {} WHERE {} LIMIT {};", quoted_table, where_clause, limit),
+        }
+    }
+
+    /// 按方言引用标识符
+    ///
+    /// MySQL 使用反引号（内部反引号加倍），ClickHouse 使用反引号并以反斜杠转义，其余使用双引号
+    pub fn quote_identifier(&self, name: &str) -> String {
+        match self {
+            Self::MySQL => format!("`{}`", name.replace('`', "``")),
+            Self::ClickHouse => format!("`{}`", name.replace('\\', "\\\\").replace('`', "\\`")),
+            _ => format!("\"{}\"", name.replace('"', "\"\"")),
+        }
+    }
+
+    /// 按方言引用表名，`schema` 为调用方确认过的 schema（如 SQLite 附加数据库的别名）
+    ///
+    /// 表名本身带点时不拆分，由调用方决定点号前缀是否为 schema
+    pub fn quote_table(&self, schema: Option<&str>, table: &str) -> String {
+        match schema {
+            Some(schema) => format!("{}.{}", self.quote_identifier(schema), self.quote_identifier(table)),
+            None => self.quote_identifier(table),
+        }
     }
 }
 
@@ -177,3 +284,14 @@ pub struct QueryResult {
     /// 原始总行数（如果被截断）
     pub original_row_count: Option<usize>,
 }
+
+impl QueryResult {
+    /// 估算结果占用的内存（字节），包括列名、行和每个单元格的字符串
+    pub fn estimated_size(&self) -> usize {
+        use std::mem::size_of;
+        let strings = |values: &[String]| values.iter().map(|s| size_of::<String>() + s.capacity()).sum::<usize>();
+        size_of::<Self>()
+            + strings(&self.columns)
+            + self.rows.iter().map(|row| size_of::<Vec<String>>() + strings(row)).sum::<usize>()
+    }
+}
@@ -40,13 +40,13 @@ pub use core::{
     // 配置
     AppConfig,
     // 主题
-    ThemeManager, ThemePreset,
+    CustomTheme, ThemeManager, ThemePreset,
     // SQL 处理
-    format_sql, highlight_sql,
+    format_sql, format_sql_for, highlight_sql,
     // 自动补全
     AutoComplete, CompletionKind,
     // 历史记录
-    QueryHistory,
+    HistoryFilter, QueryHistory, QueryHistoryItem,
     // 导出功能
     ExportFormat,
 };
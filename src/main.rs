@@ -10,6 +10,8 @@
 //! - 查询结果导出 (CSV/SQL/JSON)
 //! - 19 种主题预设
 //! - 查询历史记录
+//! - 无界面模式：`gridix -c <连接> -q <SQL> -o out.csv`
+//! - 运行日志写入配置目录下的 `logs/gridix.log`，可在日志查看器中浏览
 //!
 //! ## 模块结构
 //!
@@ -23,7 +25,11 @@ mod core;
 mod database;
 mod ui;
 
-use app::DbManagerApp;
+use crate::app::{run_headless, run_workflow_headless, DbManagerApp};
+use crate::core::{
+    default_log_path, parse_cli_args, split_config_args, AppConfig, CliCommand, ConfigLocation, RotatingFileWriter, CLI_USAGE,
+    MAX_LOG_BACKUPS, MAX_LOG_FILE_SIZE,
+};
 use eframe::egui;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
 
@@ -32,22 +38,93 @@ fn init_tracing() {
     let filter = EnvFilter::try_from_default_env()
         .unwrap_or_else(|_| EnvFilter::new("gridix=info,warn"));
 
+    // 同时以 JSON 行写入轮转的日志文件，供日志查看器读取；无法打开时只输出到标准错误
+    let (file_layer, file_error) = match default_log_path().map(|path| {
+        RotatingFileWriter::new(path, MAX_LOG_FILE_SIZE, MAX_LOG_BACKUPS)
+    }) {
+        Some(Ok(writer)) => (
+            Some(
+                tracing_subscriber::fmt::layer()
+                    .json()
+                    .with_current_span(false)
+                    .with_span_list(false)
+                    .with_writer(writer),
+            ),
+            None,
+        ),
+        Some(Err(e)) => (None, Some(e)),
+        None => (None, None),
+    };
+
     tracing_subscriber::registry()
         .with(filter)
-        .with(tracing_subscriber::fmt::layer().with_target(true))
+        // 日志写到标准错误，避免混入无界面模式输出到标准输出的结果
+        .with(tracing_subscriber::fmt::layer().with_target(true).with_writer(std::io::stderr))
+        .with(file_layer)
         .init();
+
+    if let Some(e) = file_error {
+        tracing::warn!(error = %e, "无法打开日志文件");
+    }
 }
 
 /// 程序入口点
 fn main() -> eframe::Result<()> {
     // 初始化日志系统
     init_tracing();
-    tracing::info!("Gridix 启动中...");
+
+    let args = match split_config_args(std::env::args().skip(1)) {
+        Ok((location, args)) => {
+            AppConfig::set_location(location);
+            args
+        }
+        Err(e) => {
+            eprintln!("错误: {}\n\n{}", e, CLI_USAGE);
+            std::process::exit(2);
+        }
+    };
+
+    match parse_cli_args(args) {
+        Ok(CliCommand::Gui) => {}
+        Ok(CliCommand::Help) => {
+            println!("{}", CLI_USAGE);
+            return Ok(());
+        }
+        Ok(CliCommand::Version) => {
+            println!("gridix {}", env!("CARGO_PKG_VERSION"));
+            return Ok(());
+        }
+        Ok(CliCommand::Run(args)) => {
+            if let Err(e) = run_headless(&args) {
+                eprintln!("错误: {}", e);
+                std::process::exit(1);
+            }
+            return Ok(());
+        }
+        Ok(CliCommand::Workflow(name)) => {
+            if let Err(e) = run_workflow_headless(&name) {
+                eprintln!("错误: {}", e);
+                std::process::exit(1);
+            }
+            return Ok(());
+        }
+        Err(e) => {
+            eprintln!("错误: {}\n\n{}", e, CLI_USAGE);
+            std::process::exit(2);
+        }
+    }
+
+    tracing::info!(config = ?AppConfig::config_path(), "Gridix 启动中...");
+    // 使用命名配置时在标题中显示配置名称
+    let title = match AppConfig::location() {
+        ConfigLocation::Profile(name) => format!("Rust 数据库管理器 [{}]", name),
+        _ => "Rust 数据库管理器".to_string(),
+    };
     let options = eframe::NativeOptions {
         viewport: egui::ViewportBuilder::default()
             .with_inner_size([1200.0, 800.0])
             .with_min_inner_size([800.0, 600.0])
-            .with_title("Rust 数据库管理器"),
+            .with_title(title),
         ..Default::default()
     };
 
@@ -55,63 +132,9 @@ fn main() -> eframe::Result<()> {
         "Rust 数据库管理器",
         options,
         Box::new(|cc| {
-            // 配置字体
-            setup_fonts(&cc.egui_ctx);
             egui_extras::install_image_loaders(&cc.egui_ctx);
 
             Ok(Box::new(DbManagerApp::new(cc)))
         }),
     )
 }
-
-/// 内嵌的 Noto Sans SC 字体（思源黑体，支持完整 Unicode）
-const EMBEDDED_NOTO_SANS_SC: &[u8] = include_bytes!("../assets/fonts/NotoSansSC-Regular.ttf");
-
-/// 内嵌的 Noto Emoji 字体（支持 Unicode Emoji）
-const EMBEDDED_NOTO_EMOJI: &[u8] = include_bytes!("../assets/fonts/NotoEmoji-Regular.ttf");
-
-/// 配置字体
-///
-/// 使用 Noto Sans SC（思源黑体）字体，支持完整的 Unicode 字符集，
-/// 包括中文、日文、韩文以及各种特殊符号。
-/// 同时添加 Noto Emoji 字体作为后备，支持 Unicode Emoji 符号。
-fn setup_fonts(ctx: &egui::Context) {
-    let mut fonts = egui::FontDefinitions::default();
-
-    // 使用 Noto Sans SC 字体（支持完整 Unicode）
-    fonts.font_data.insert(
-        "noto_sans_sc".to_owned(),
-        egui::FontData::from_static(EMBEDDED_NOTO_SANS_SC).into(),
-    );
-
-    // 使用 Noto Emoji 字体（支持 Emoji）
-    fonts.font_data.insert(
-        "noto_emoji".to_owned(),
-        egui::FontData::from_static(EMBEDDED_NOTO_EMOJI).into(),
-    );
-
-    // 配置字体优先级：Noto Sans SC 优先，Noto Emoji 作为后备，最后是默认字体
-    fonts
-        .families
-        .entry(egui::FontFamily::Proportional)
-        .or_default()
-        .insert(0, "noto_sans_sc".to_owned());
-    fonts
-        .families
-        .entry(egui::FontFamily::Proportional)
-        .or_default()
-        .push("noto_emoji".to_owned());
-
-    fonts
-        .families
-        .entry(egui::FontFamily::Monospace)
-        .or_default()
-        .insert(0, "noto_sans_sc".to_owned());
-    fonts
-        .families
-        .entry(egui::FontFamily::Monospace)
-        .or_default()
-        .push("noto_emoji".to_owned());
-
-    ctx.set_fonts(fonts);
-}
@@ -0,0 +1,184 @@
+//! 列名输入框
+//!
+//! 输入时按模糊匹配列出结果集的列名和表结构中的列类型，
+//! ↑/↓ 选择，Enter/Tab 或点击确认，Esc 放弃修改。
+
+use crate::core::{fuzzy_rank, t};
+use crate::ui::styles::GRAY;
+use egui::{self, Key, Modifiers, PopupCloseBehavior, RichText, TextEdit};
+use std::collections::HashMap;
+
+/// 最多列出的候选列数
+const MAX_SUGGESTIONS: usize = 50;
+
+/// 编辑中的输入内容（保存在 egui 临时数据中）
+#[derive(Clone, Default)]
+struct PickerState {
+    /// 是否正在输入
+    editing: bool,
+    /// 输入的内容
+    text: String,
+    /// 选中的候选序号
+    selected: usize,
+}
+
+/// 带补全的列名输入框
+pub struct ColumnPicker;
+
+impl ColumnPicker {
+    /// 显示列名输入框，确认选择后写入 `column` 并返回 true
+    ///
+    /// `all_columns` 不为空时允许清空输入（`column` 为空字符串表示所有列），
+    /// 并作为未选择列时的提示文字。
+    pub fn show(
+        ui: &mut egui::Ui,
+        id: egui::Id,
+        column: &mut String,
+        columns: &[String],
+        types: Option<&HashMap<String, String>>,
+        width: f32,
+        all_columns: Option<&str>,
+    ) -> bool {
+        let edit_id = id.with("edit");
+        let popup_id = id.with("popup");
+        let mut state = ui.ctx().data_mut(|d| d.get_temp::<PickerState>(id).unwrap_or_default());
+
+        let matches: Vec<usize> = if state.editing {
+            fuzzy_rank(&state.text, columns).into_iter().take(MAX_SUGGESTIONS).collect()
+        } else {
+            Vec::new()
+        };
+        if state.selected >= matches.len() {
+            state.selected = matches.len().saturating_sub(1);
+        }
+
+        // 在文本框处理之前截获导航和确认键
+        let has_focus = ui.memory(|m| m.has_focus(edit_id));
+        let (up, down, confirm, escape) = if has_focus && state.editing {
+            ui.input_mut(|i| {
+                (
+                    i.consume_key(Modifiers::NONE, Key::ArrowUp),
+                    i.consume_key(Modifiers::NONE, Key::ArrowDown),
+                    i.consume_key(Modifiers::NONE, Key::Enter) || i.consume_key(Modifiers::NONE, Key::Tab),
+                    i.consume_key(Modifiers::NONE, Key::Escape),
+                )
+            })
+        } else {
+            (false, false, false, false)
+        };
+        if down && !matches.is_empty() {
+            state.selected = (state.selected + 1) % matches.len();
+        }
+        if up && !matches.is_empty() {
+            state.selected = (state.selected + matches.len() - 1) % matches.len();
+        }
+
+        // 未编辑时显示当前列名，开始编辑时清空输入，当前列名作为提示
+        let hint = if column.is_empty() {
+            all_columns.unwrap_or_default().to_string()
+        } else {
+            column.clone()
+        };
+        let mut display = column.clone();
+        let text = if state.editing { &mut state.text } else { &mut display };
+        let response = ui.add(
+            TextEdit::singleline(text)
+                .id(edit_id)
+                .hint_text(RichText::new(hint).color(GRAY))
+                .font(egui::TextStyle::Small)
+                .desired_width(width),
+        );
+        let response = match types.and_then(|t| t.get(column.as_str())) {
+            Some(data_type) if !state.editing => response.on_hover_text(format!("{}: {}", column, data_type)),
+            _ => response,
+        };
+        if response.gained_focus() && !state.editing {
+            state.editing = true;
+            state.text.clear();
+            state.selected = 0;
+        }
+        if response.changed() {
+            state.selected = 0;
+        }
+
+        let mut chosen: Option<usize> = None;
+        let mut changed = false;
+        if confirm {
+            chosen = matches.get(state.selected).copied();
+            // 没有匹配的列时按原样确认：允许清空则表示所有列
+            if chosen.is_none() && state.text.trim().is_empty() && all_columns.is_some() {
+                changed = !column.is_empty();
+                column.clear();
+            }
+            state.editing = false;
+            ui.memory_mut(|m| m.surrender_focus(edit_id));
+        }
+        if escape {
+            state.editing = false;
+            ui.memory_mut(|m| m.surrender_focus(edit_id));
+        }
+
+        let popup = egui::Popup::from_response(&response)
+            .id(popup_id)
+            .open(state.editing)
+            .close_behavior(PopupCloseBehavior::IgnoreClicks)
+            .width(response.rect.width().max(160.0))
+            .show(|ui| {
+                ui.set_max_height(220.0);
+                if matches.is_empty() {
+                    ui.label(RichText::new(t("column_picker.no_match")).small().color(GRAY));
+                    return None;
+                }
+                let mut clicked = None;
+                egui::ScrollArea::vertical().auto_shrink([false, true]).show(ui, |ui| {
+                    for (row, &idx) in matches.iter().enumerate() {
+                        let name = &columns[idx];
+                        let selected = row == state.selected;
+                        let item = ui
+                            .horizontal(|ui| {
+                                let label = ui.selectable_label(selected, RichText::new(name).small());
+                                if let Some(data_type) = types.and_then(|t| t.get(name)) {
+                                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                        ui.label(RichText::new(data_type).small().monospace().color(GRAY));
+                                    });
+                                }
+                                label
+                            })
+                            .inner;
+                        if item.clicked() {
+                            clicked = Some(idx);
+                        }
+                        if selected && (up || down) {
+                            item.scroll_to_me(None);
+                        }
+                    }
+                });
+                clicked
+            });
+        let over_popup = popup.as_ref().is_some_and(|p| p.response.contains_pointer());
+        if let Some(idx) = popup.and_then(|p| p.inner) {
+            chosen = Some(idx);
+            state.editing = false;
+        }
+
+        if let Some(idx) = chosen
+            && let Some(name) = columns.get(idx)
+        {
+            changed = *column != *name;
+            *column = name.clone();
+        }
+
+        // 点击其他位置时放弃修改
+        if state.editing && !response.has_focus() && !response.gained_focus() && !over_popup {
+            state.editing = false;
+        }
+
+        ui.ctx().data_mut(|d| d.insert_temp(id, state));
+        changed
+    }
+
+    /// 是否正在输入（输入时表格等不应响应键盘）
+    pub fn is_editing(ctx: &egui::Context, id: egui::Id) -> bool {
+        ctx.data(|d| d.get_temp::<PickerState>(id)).is_some_and(|s| s.editing)
+    }
+}
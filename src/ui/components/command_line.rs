@@ -0,0 +1,347 @@
+//! SQL 编辑器命令行
+//!
+//! Normal 模式下按 `:` 打开，类似 Vim/Helix 的命令行：
+//! `:run`、`:check`、`:format`、`:tabnew`、`:connect <连接>`、`:table <表>`、`:export csv` 等。
+//! Tab 补全命令名和参数（连接名、表名、导出格式）。
+
+use crate::core::{fuzzy_score, t, tf, ExportFormat};
+use egui::{self, text::CCursor, text::CCursorRange, Color32, Key, Modifiers, RichText, TextEdit};
+
+// ============================================================================
+// 命令
+// ============================================================================
+
+/// 命令行命令
+#[derive(Debug, Clone, PartialEq)]
+pub enum EditorCommand {
+    /// 执行编辑器中的 SQL
+    Run,
+    /// 分析执行计划
+    Explain,
+    /// 预览 UPDATE/DELETE 将修改的行
+    Preview,
+    /// 不执行而检查 SQL
+    Validate,
+    /// 格式化 SQL
+    Format,
+    /// 清空编辑器
+    Clear,
+    /// 保存表格修改
+    Write,
+    /// 关闭当前查询 Tab
+    Quit,
+    /// 新建查询 Tab
+    TabNew,
+    /// 下一个查询 Tab
+    TabNext,
+    /// 上一个查询 Tab
+    TabPrev,
+    /// 切换到连接
+    Connect(String),
+    /// 打开表
+    Table(String),
+    /// 导出结果（未指定格式时使用导出对话框中的格式）
+    Export(Option<ExportFormat>),
+    /// 显示帮助
+    Help,
+}
+
+/// 参数类型（决定补全来源）
+#[derive(Clone, Copy, PartialEq)]
+enum ArgKind {
+    None,
+    Connection,
+    Table,
+    ExportFormat,
+}
+
+/// 命令定义
+struct CommandSpec {
+    /// 命令名
+    name: &'static str,
+    /// 别名
+    aliases: &'static [&'static str],
+    /// 参数类型
+    arg: ArgKind,
+    /// 说明（i18n 键）
+    description: &'static str,
+}
+
+const COMMANDS: &[CommandSpec] = &[
+    CommandSpec { name: "run", aliases: &["r"], arg: ArgKind::None, description: "cmdline.run" },
+    CommandSpec { name: "explain", aliases: &[], arg: ArgKind::None, description: "cmdline.explain" },
+    CommandSpec { name: "preview", aliases: &[], arg: ArgKind::None, description: "cmdline.preview" },
+    CommandSpec { name: "check", aliases: &["validate"], arg: ArgKind::None, description: "cmdline.check" },
+    CommandSpec { name: "format", aliases: &["fmt"], arg: ArgKind::None, description: "cmdline.format" },
+    CommandSpec { name: "clear", aliases: &[], arg: ArgKind::None, description: "cmdline.clear" },
+    CommandSpec { name: "w", aliases: &["write"], arg: ArgKind::None, description: "cmdline.write" },
+    CommandSpec { name: "q", aliases: &["quit", "tabclose"], arg: ArgKind::None, description: "cmdline.quit" },
+    CommandSpec { name: "tabnew", aliases: &[], arg: ArgKind::None, description: "cmdline.tabnew" },
+    CommandSpec { name: "tabnext", aliases: &["tabn"], arg: ArgKind::None, description: "cmdline.tabnext" },
+    CommandSpec { name: "tabprev", aliases: &["tabp"], arg: ArgKind::None, description: "cmdline.tabprev" },
+    CommandSpec { name: "connect", aliases: &["conn"], arg: ArgKind::Connection, description: "cmdline.connect" },
+    CommandSpec { name: "table", aliases: &["e", "edit"], arg: ArgKind::Table, description: "cmdline.table" },
+    CommandSpec { name: "export", aliases: &[], arg: ArgKind::ExportFormat, description: "cmdline.export" },
+    CommandSpec { name: "help", aliases: &["h"], arg: ArgKind::None, description: "cmdline.help" },
+];
+
+/// 导出格式名称
+const EXPORT_FORMATS: &[&str] = &["csv", "json", "sql"];
+
+fn find_command(name: &str) -> Option<&'static CommandSpec> {
+    COMMANDS
+        .iter()
+        .find(|c| c.name.eq_ignore_ascii_case(name) || c.aliases.iter().any(|a| a.eq_ignore_ascii_case(name)))
+}
+
+/// 拆分为命令名和参数（参数去除首尾空白）
+fn split_command(input: &str) -> (&str, &str) {
+    let input = input.trim_start().trim_start_matches(':');
+    match input.split_once(char::is_whitespace) {
+        Some((name, arg)) => (name, arg.trim()),
+        None => (input.trim_end(), ""),
+    }
+}
+
+/// 解析命令行输入，错误信息可直接显示给用户
+pub fn parse_editor_command(input: &str) -> Result<EditorCommand, String> {
+    let (name, arg) = split_command(input);
+    if name.is_empty() {
+        return Err(t("cmdline.empty").to_string());
+    }
+    let spec = find_command(name).ok_or_else(|| tf("cmdline.unknown", &[name]))?;
+
+    if spec.arg == ArgKind::None && !arg.is_empty() {
+        return Err(tf("cmdline.no_arg", &[spec.name]));
+    }
+    let required = |arg: &str| {
+        if arg.is_empty() {
+            Err(tf("cmdline.missing_arg", &[spec.name]))
+        } else {
+            Ok(arg.to_string())
+        }
+    };
+
+    Ok(match spec.name {
+        "run" => EditorCommand::Run,
+        "explain" => EditorCommand::Explain,
+        "preview" => EditorCommand::Preview,
+        "check" => EditorCommand::Validate,
+        "format" => EditorCommand::Format,
+        "clear" => EditorCommand::Clear,
+        "w" => EditorCommand::Write,
+        "q" => EditorCommand::Quit,
+        "tabnew" => EditorCommand::TabNew,
+        "tabnext" => EditorCommand::TabNext,
+        "tabprev" => EditorCommand::TabPrev,
+        "connect" => EditorCommand::Connect(required(arg)?),
+        "table" => EditorCommand::Table(required(arg)?),
+        "export" => EditorCommand::Export(match arg.to_ascii_lowercase().as_str() {
+            "" => None,
+            "csv" => Some(ExportFormat::Csv),
+            "json" => Some(ExportFormat::Json),
+            "sql" => Some(ExportFormat::Sql),
+            _ => return Err(tf("cmdline.unknown_format", &[arg])),
+        }),
+        _ => EditorCommand::Help,
+    })
+}
+
+/// 按模糊匹配得分排序候选项，得分相同时保持原有顺序
+fn rank<'a>(pattern: &str, candidates: impl Iterator<Item = &'a str>) -> Vec<&'a str> {
+    let mut scored: Vec<(i32, usize, &str)> = candidates
+        .enumerate()
+        .filter_map(|(idx, c)| fuzzy_score(pattern, c).map(|score| (score, idx, c)))
+        .collect();
+    scored.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+    scored.into_iter().map(|(_, _, c)| c).collect()
+}
+
+/// 补全命令行输入，返回补全后的完整输入
+///
+/// 没有空格时补全命令名（带参数的命令补全后追加空格），否则补全参数。
+pub fn complete_editor_command(input: &str, connections: &[String], tables: &[String]) -> Vec<String> {
+    let input = input.trim_start().trim_start_matches(':');
+    let Some((name, arg)) = input.split_once(char::is_whitespace) else {
+        return rank(input, COMMANDS.iter().map(|c| c.name))
+            .into_iter()
+            .map(|name| match find_command(name) {
+                Some(spec) if spec.arg != ArgKind::None => format!("{} ", name),
+                _ => name.to_string(),
+            })
+            .collect();
+    };
+
+    let Some(spec) = find_command(name) else {
+        return Vec::new();
+    };
+    let arg = arg.trim();
+    let candidates: Vec<&str> = match spec.arg {
+        ArgKind::None => return Vec::new(),
+        ArgKind::Connection => rank(arg, connections.iter().map(String::as_str)),
+        ArgKind::Table => rank(arg, tables.iter().map(String::as_str)),
+        ArgKind::ExportFormat => rank(arg, EXPORT_FORMATS.iter().copied()),
+    };
+    candidates.into_iter().map(|c| format!("{} {}", name, c)).collect()
+}
+
+// ============================================================================
+// 状态
+// ============================================================================
+
+/// 命令行状态
+#[derive(Default)]
+pub struct CommandLineState {
+    /// 输入内容（不含开头的 `:`）
+    pub input: String,
+    /// 当前补全候选项
+    completions: Vec<String>,
+    /// 当前选中的补全候选项
+    completion_index: Option<usize>,
+    /// 输入框需要获取焦点并把光标移到末尾
+    move_cursor_to_end: bool,
+}
+
+impl CommandLineState {
+    /// 创建新的状态
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 打开命令行（清空输入）
+    pub fn open(&mut self) {
+        self.input.clear();
+        self.reset_completion();
+        self.move_cursor_to_end = true;
+    }
+
+    /// 补全：第一次计算候选项并选中第一个，之后在候选项间循环
+    pub fn complete(&mut self, connections: &[String], tables: &[String], reverse: bool) {
+        if self.completions.is_empty() {
+            self.completions = complete_editor_command(&self.input, connections, tables);
+            self.completion_index = None;
+        }
+        if self.completions.is_empty() {
+            return;
+        }
+        let len = self.completions.len();
+        let idx = match (self.completion_index, reverse) {
+            (None, false) => 0,
+            (None, true) => len - 1,
+            (Some(i), false) => (i + 1) % len,
+            (Some(i), true) => (i + len - 1) % len,
+        };
+        self.completion_index = Some(idx);
+        self.input = self.completions[idx].clone();
+        self.move_cursor_to_end = true;
+        // 唯一候选项直接接受，便于继续补全参数
+        if len == 1 {
+            self.reset_completion();
+        }
+    }
+
+    fn reset_completion(&mut self) {
+        self.completions.clear();
+        self.completion_index = None;
+    }
+}
+
+// ============================================================================
+// UI
+// ============================================================================
+
+/// 命令行输入结果
+pub enum CommandLineResult {
+    /// 执行命令
+    Submit(Result<EditorCommand, String>),
+    /// 取消（Esc 或清空后退格）
+    Cancel,
+}
+
+/// 命令行组件
+pub struct CommandLine;
+
+impl CommandLine {
+    /// 显示命令行（占据编辑器状态栏的位置）
+    pub fn show(
+        ui: &mut egui::Ui,
+        state: &mut CommandLineState,
+        connections: &[String],
+        tables: &[String],
+    ) -> Option<CommandLineResult> {
+        // 在文本框处理之前截获补全和确认键
+        // Shift+Tab 需要先于 Tab 截获（Tab 的匹配会忽略 Shift）
+        let (shift_tab, tab, enter, escape, backspace) = ui.input_mut(|i| {
+            (
+                i.consume_key(Modifiers::SHIFT, Key::Tab),
+                i.consume_key(Modifiers::NONE, Key::Tab),
+                i.consume_key(Modifiers::NONE, Key::Enter),
+                i.consume_key(Modifiers::NONE, Key::Escape),
+                state.input.is_empty() && i.key_pressed(Key::Backspace),
+            )
+        });
+        if escape || backspace {
+            return Some(CommandLineResult::Cancel);
+        }
+        if enter {
+            return Some(CommandLineResult::Submit(parse_editor_command(&state.input)));
+        }
+        if tab || shift_tab {
+            state.complete(connections, tables, shift_tab);
+        }
+
+        egui::Frame::NONE
+            .fill(ui.style().visuals.faint_bg_color)
+            .inner_margin(egui::Margin::symmetric(8, 2))
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.spacing_mut().item_spacing.x = 4.0;
+                    ui.label(RichText::new(":").monospace().strong());
+
+                    let output = TextEdit::singleline(&mut state.input)
+                        .font(egui::TextStyle::Monospace)
+                        .frame(false)
+                        .hint_text(t("cmdline.placeholder"))
+                        .desired_width(ui.available_width() * 0.5)
+                        .show(ui);
+                    if output.response.changed() {
+                        state.reset_completion();
+                    }
+                    if state.move_cursor_to_end || !output.response.has_focus() {
+                        let mut text_state = output.state;
+                        let end = CCursor::new(state.input.chars().count());
+                        text_state.cursor.set_char_range(Some(CCursorRange::one(end)));
+                        text_state.store(ui.ctx(), output.response.id);
+                        output.response.request_focus();
+                        state.move_cursor_to_end = false;
+                    }
+
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        ui.label(RichText::new(t("cmdline.hint")).small().color(Color32::GRAY));
+                        Self::show_completions(ui, state);
+                    });
+                });
+            });
+
+        None
+    }
+
+    /// 显示补全候选项或当前命令的说明
+    fn show_completions(ui: &mut egui::Ui, state: &CommandLineState) {
+        if state.completions.len() > 1 {
+            // 从右向左布局，倒序添加使候选项按原顺序显示
+            for (idx, item) in state.completions.iter().enumerate().take(8).rev() {
+                let (name, arg) = split_command(item);
+                let text = if arg.is_empty() { name } else { arg };
+                let text = RichText::new(text).small().monospace();
+                if Some(idx) == state.completion_index {
+                    ui.label(text.strong().color(ui.visuals().selection.stroke.color));
+                } else {
+                    ui.label(text.color(Color32::GRAY));
+                }
+            }
+        } else if let Some(spec) = find_command(split_command(&state.input).0) {
+            ui.label(RichText::new(t(spec.description)).small().color(Color32::GRAY));
+        }
+    }
+}
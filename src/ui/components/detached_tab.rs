@@ -0,0 +1,157 @@
+//! 独立窗口中的查询 Tab
+//!
+//! 把查询 Tab 弹出到单独的系统窗口（egui 多视口），只读显示 SQL 和结果，
+//! 主窗口中重新执行该 Tab 的查询后窗口中的结果随之更新。
+
+use super::query_tabs::QueryTab;
+use crate::core::HighlightColors;
+use crate::ui::styles::{GRAY, MUTED};
+use eframe::egui;
+use egui::{RichText, ViewportBuilder, ViewportClass, ViewportId};
+
+/// 单元格显示的最大字符数
+const CELL_MAX_CHARS: usize = 120;
+
+/// 独立窗口上的操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DetachedTabAction {
+    /// 无操作
+    None,
+    /// 合并回主窗口（关闭独立窗口）
+    Reattach,
+    /// 在主窗口中切换到该 Tab
+    Focus,
+}
+
+/// 独立窗口中的查询 Tab
+pub struct DetachedTabWindow;
+
+impl DetachedTabWindow {
+    /// 独立窗口的视口 ID
+    pub fn viewport_id(tab: &QueryTab) -> ViewportId {
+        ViewportId::from_hash_of(("detached_query_tab", &tab.id))
+    }
+
+    /// 显示 Tab 的独立窗口
+    ///
+    /// 后端不支持多视口时退化为主窗口中的浮动窗口。
+    pub fn show(ctx: &egui::Context, tab: &QueryTab, highlight_colors: &HighlightColors) -> DetachedTabAction {
+        let mut action = DetachedTabAction::None;
+        let builder = ViewportBuilder::default()
+            .with_title(format!("{} - Gridix", tab.title))
+            .with_inner_size([900.0, 600.0])
+            .with_min_inner_size([400.0, 240.0]);
+
+        ctx.show_viewport_immediate(Self::viewport_id(tab), builder, |ctx, class| {
+            if class == ViewportClass::Embedded {
+                let mut open = true;
+                egui::Window::new(format!("⧉ {}", tab.title))
+                    .id(egui::Id::new(("detached_query_tab", &tab.id)))
+                    .open(&mut open)
+                    .default_size([700.0, 420.0])
+                    .show(ctx, |ui| Self::contents(ui, tab, highlight_colors, &mut action));
+                if !open {
+                    action = DetachedTabAction::Reattach;
+                }
+                return;
+            }
+
+            egui::CentralPanel::default().show(ctx, |ui| Self::contents(ui, tab, highlight_colors, &mut action));
+            if ctx.input(|i| i.viewport().close_requested()) {
+                action = DetachedTabAction::Reattach;
+            }
+        });
+        action
+    }
+
+    /// 窗口内容：状态栏、SQL 和结果表格
+    fn contents(ui: &mut egui::Ui, tab: &QueryTab, highlight_colors: &HighlightColors, action: &mut DetachedTabAction) {
+        ui.horizontal(|ui| {
+            ui.label(RichText::new(&tab.title).strong().color(highlight_colors.keyword));
+            if tab.executing {
+                ui.spinner();
+            }
+            if let Some(result) = &tab.result {
+                ui.label(RichText::new(format!("{} 行 × {} 列", result.rows.len(), result.columns.len())).small().color(GRAY));
+            }
+            if let Some(ms) = tab.query_time_ms {
+                ui.label(RichText::new(format!("{} ms", ms)).small().color(GRAY));
+            }
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui.button("⤓ 合并回主窗口").clicked() {
+                    *action = DetachedTabAction::Reattach;
+                }
+                if ui.button("在主窗口中查看").on_hover_text("在主窗口中切换到此标签页").clicked() {
+                    *action = DetachedTabAction::Focus;
+                }
+            });
+        });
+
+        if !tab.sql.trim().is_empty() {
+            egui::CollapsingHeader::new(RichText::new("SQL").small())
+                .id_salt(("detached_sql", &tab.id))
+                .show(ui, |ui| {
+                    ui.label(RichText::new(tab.sql.trim()).monospace().small());
+                });
+        }
+        ui.separator();
+
+        match &tab.result {
+            Some(result) if !result.columns.is_empty() => Self::result_table(ui, tab, result),
+            _ => {
+                let text = tab.last_message.as_deref().unwrap_or("还没有结果，在主窗口中执行此标签页的查询");
+                ui.label(RichText::new(text).color(MUTED));
+            }
+        }
+    }
+
+    /// 只读的结果表格（按行虚拟滚动）
+    fn result_table(ui: &mut egui::Ui, tab: &QueryTab, result: &crate::database::QueryResult) {
+        use egui_extras::{Column, TableBuilder};
+
+        let row_height = ui.text_style_height(&egui::TextStyle::Body) + 6.0;
+        TableBuilder::new(ui)
+            .id_salt(("detached_result", &tab.id))
+            .striped(true)
+            .resizable(true)
+            .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+            .column(Column::auto().at_least(36.0))
+            .columns(Column::initial(140.0).at_least(40.0).clip(true), result.columns.len())
+            .header(row_height + 2.0, |mut header| {
+                header.col(|ui| {
+                    ui.label(RichText::new("#").strong().color(GRAY));
+                });
+                for name in &result.columns {
+                    header.col(|ui| {
+                        ui.label(RichText::new(name).strong());
+                    });
+                }
+            })
+            .body(|body| {
+                body.rows(row_height, result.rows.len(), |mut row| {
+                    let idx = row.index();
+                    let Some(data) = result.rows.get(idx) else {
+                        return;
+                    };
+                    row.col(|ui| {
+                        ui.label(RichText::new((idx + 1).to_string()).color(GRAY));
+                    });
+                    for col in 0..result.columns.len() {
+                        let cell = data.get(col).map_or("", String::as_str);
+                        row.col(|ui| {
+                            if cell == "NULL" {
+                                ui.label(RichText::new("NULL").italics().color(MUTED));
+                            } else {
+                                let text: String = if cell.chars().count() > CELL_MAX_CHARS {
+                                    cell.chars().take(CELL_MAX_CHARS).chain(std::iter::once('…')).collect()
+                                } else {
+                                    cell.to_string()
+                                };
+                                ui.add(egui::Label::new(text).truncate()).on_hover_text(cell);
+                            }
+                        });
+                    }
+                });
+            });
+    }
+}
@@ -0,0 +1,235 @@
+//! ER 图表设计
+//!
+//! 在画布上新建表、添加列并拖出外键连线，最后为当前数据库方言生成
+//! CREATE TABLE 与外键约束语句。设计中的表以 `TableDefinition` 保存在 `ERTable::design` 中。
+
+use std::collections::HashSet;
+
+use super::render::calculate_table_size;
+use super::state::{ERColumn, ERDiagramState, ERTable, RelationType, Relationship};
+use crate::ui::{supports_alter_foreign_key, supports_foreign_keys, ForeignKeyDefinition, TableDefinition};
+use egui::Pos2;
+
+/// 新表与已有表之间的间距
+const DESIGN_GAP: f32 = 60.0;
+
+impl ERTable {
+    /// 由设计中的表定义创建 ER 表
+    pub fn from_design(table: TableDefinition) -> Self {
+        let mut er_table = ERTable::new(table.name.clone());
+        er_table.columns = design_columns(&table);
+        er_table.design = Some(table);
+        calculate_table_size(&mut er_table);
+        er_table
+    }
+
+    /// 是否为画布上设计、尚未创建的表
+    pub fn is_design(&self) -> bool {
+        self.design.is_some()
+    }
+}
+
+/// 将表定义的列转换为 ER 图中的列
+fn design_columns(table: &TableDefinition) -> Vec<ERColumn> {
+    table
+        .columns
+        .iter()
+        .map(|c| ERColumn {
+            name: c.name.clone(),
+            data_type: c.data_type.to_sql(&table.db_type),
+            is_primary_key: c.primary_key,
+            is_foreign_key: table.foreign_keys.iter().any(|fk| fk.column == c.name),
+            nullable: c.nullable && !c.primary_key,
+            default_value: (!c.default_value.is_empty()).then(|| c.default_value.clone()),
+        })
+        .collect()
+}
+
+impl ERDiagramState {
+    /// 设计中的表
+    pub fn design_tables(&self) -> impl Iterator<Item = (&ERTable, &TableDefinition)> {
+        self.tables.iter().filter_map(|t| t.design.as_ref().map(|d| (t, d)))
+    }
+
+    /// 是否有设计中的表
+    pub fn has_design(&self) -> bool {
+        self.tables.iter().any(ERTable::is_design)
+    }
+
+    /// 设计表的定义（按表名查找）
+    pub fn design_definition(&self, name: &str) -> Option<&TableDefinition> {
+        self.tables.iter().find(|t| t.name == name).and_then(|t| t.design.as_ref())
+    }
+
+    /// 新建或更新设计表；`original_name` 为编辑前的表名
+    pub fn apply_design(&mut self, original_name: Option<&str>, mut table: TableDefinition) {
+        // 删除已不存在的外键列
+        table.foreign_keys.retain(|fk| table.columns.iter().any(|c| c.name == fk.column));
+
+        let existing = original_name.and_then(|name| self.tables.iter().position(|t| t.name == name && t.is_design()));
+        if let Some(idx) = existing {
+            let old_name = self.tables[idx].name.clone();
+            if old_name != table.name {
+                // 表改名后更新其他设计表对它的引用
+                for other in self.tables.iter_mut().filter_map(|t| t.design.as_mut()) {
+                    for fk in other.foreign_keys.iter_mut().filter(|fk| fk.ref_table == old_name) {
+                        fk.ref_table = table.name.clone();
+                    }
+                }
+                for other in &mut self.tables {
+                    if let Some(design) = &other.design {
+                        other.columns = design_columns(design);
+                    }
+                }
+            }
+            let position = self.tables[idx].position;
+            let mut er_table = ERTable::from_design(table);
+            er_table.position = position;
+            self.tables[idx] = er_table;
+        } else {
+            let mut er_table = ERTable::from_design(table);
+            er_table.position = self.free_position();
+            self.tables.push(er_table);
+        }
+    }
+
+    /// 删除设计表，同时删除其他设计表指向它的外键
+    pub fn remove_design(&mut self, name: &str) {
+        self.tables.retain(|t| !(t.name == name && t.is_design()));
+        for table in &mut self.tables {
+            if let Some(design) = &mut table.design {
+                design.foreign_keys.retain(|fk| fk.ref_table != name);
+                table.columns = design_columns(design);
+            }
+        }
+        self.selected_table = None;
+    }
+
+    /// 在设计表的列上添加外键（同一列已有外键时替换）
+    pub fn add_design_link(&mut self, from_table: &str, from_column: &str, to_table: &str, to_column: &str) -> bool {
+        let Some(table) = self.tables.iter_mut().find(|t| t.name == from_table) else {
+            return false;
+        };
+        let Some(design) = &mut table.design else {
+            return false;
+        };
+        if !design.columns.iter().any(|c| c.name == from_column) {
+            return false;
+        }
+        design.foreign_keys.retain(|fk| fk.column != from_column);
+        design.foreign_keys.push(ForeignKeyDefinition {
+            column: from_column.to_string(),
+            ref_table: to_table.to_string(),
+            ref_column: to_column.to_string(),
+        });
+        table.columns = design_columns(design);
+        true
+    }
+
+    /// 删除设计表上的所有外键
+    pub fn clear_design_links(&mut self, name: &str) {
+        if let Some(table) = self.tables.iter_mut().find(|t| t.name == name)
+            && let Some(design) = &mut table.design
+        {
+            design.foreign_keys.clear();
+            table.columns = design_columns(design);
+        }
+    }
+
+    /// 设计表上的外键连线
+    pub fn design_relationships(&self) -> Vec<Relationship> {
+        self.design_tables()
+            .flat_map(|(table, design)| {
+                design.foreign_keys.iter().map(|fk| Relationship {
+                    from_table: table.name.clone(),
+                    from_column: fk.column.clone(),
+                    to_table: fk.ref_table.clone(),
+                    to_column: fk.ref_column.clone(),
+                    relation_type: RelationType::OneToMany,
+                })
+            })
+            .collect()
+    }
+
+    /// 已有关系与设计表外键连线
+    pub fn all_relationships(&self) -> Vec<Relationship> {
+        let mut relationships = self.relationships.clone();
+        relationships.extend(self.design_relationships());
+        relationships
+    }
+
+    /// 为设计中的表生成 DDL
+    ///
+    /// 支持 ALTER TABLE 添加外键的方言先建表再添加约束；SQLite/DuckDB 在建表语句中声明外键，
+    /// 并按依赖顺序建表；ClickHouse 不生成外键。
+    pub fn design_ddl(&self) -> Result<String, String> {
+        let designs: Vec<&TableDefinition> = self.design_tables().map(|(_, d)| d).collect();
+        if designs.is_empty() {
+            return Err("没有设计中的表".to_string());
+        }
+
+        let mut names = HashSet::new();
+        for design in &designs {
+            design.validate().map_err(|e| format!("表 {}: {}", design.name, e))?;
+            if !names.insert(design.name.to_lowercase()) {
+                return Err(format!("表名 '{}' 重复", design.name));
+            }
+            for fk in &design.foreign_keys {
+                let exists = self.tables.iter().any(|t| t.name == fk.ref_table && t.columns.iter().any(|c| c.name == fk.ref_column));
+                if !exists {
+                    return Err(format!("表 {}: 外键引用的 {}.{} 不存在", design.name, fk.ref_table, fk.ref_column));
+                }
+            }
+        }
+
+        let db_type = designs[0].db_type;
+        let mut statements = Vec::new();
+        if supports_alter_foreign_key(&db_type) {
+            for design in &designs {
+                let table = TableDefinition {
+                    foreign_keys: Vec::new(),
+                    ..(*design).clone()
+                };
+                statements.push(table.to_create_sql());
+            }
+            for design in &designs {
+                statements.extend(design.foreign_keys.iter().filter_map(|fk| fk.to_alter_sql(&design.name, &db_type)));
+            }
+        } else {
+            if !supports_foreign_keys(&db_type) && designs.iter().any(|d| !d.foreign_keys.is_empty()) {
+                statements.push(format!("-- {} 不支持外键约束，已忽略外键", db_type.display_name()));
+            }
+            statements.extend(dependency_order(&designs).into_iter().map(TableDefinition::to_create_sql));
+        }
+
+        Ok(statements.join("\n\n"))
+    }
+
+    /// 新表的位置：已有表的右侧
+    fn free_position(&self) -> Pos2 {
+        let right = self.tables.iter().map(|t| t.position.x + t.size.x).fold(f32::MIN, f32::max);
+        let top = self.tables.iter().map(|t| t.position.y).fold(f32::MAX, f32::min);
+        if self.tables.is_empty() {
+            Pos2::new(DESIGN_GAP, DESIGN_GAP)
+        } else {
+            Pos2::new(right + DESIGN_GAP, top)
+        }
+    }
+}
+
+/// 按外键依赖排序：被引用的设计表在前；存在循环引用时保持原顺序
+fn dependency_order<'a>(designs: &[&'a TableDefinition]) -> Vec<&'a TableDefinition> {
+    let mut ordered: Vec<&TableDefinition> = Vec::with_capacity(designs.len());
+    let mut remaining: Vec<&TableDefinition> = designs.to_vec();
+    while !remaining.is_empty() {
+        let ready = remaining.iter().position(|d| {
+            d.foreign_keys.iter().all(|fk| {
+                fk.ref_table == d.name
+                    || ordered.iter().any(|o| o.name == fk.ref_table)
+                    || !remaining.iter().any(|r| r.name == fk.ref_table)
+            })
+        });
+        ordered.push(remaining.remove(ready.unwrap_or(0)));
+    }
+    ordered
+}
@@ -0,0 +1,321 @@
+//! ER 图导出
+//!
+//! 将当前 ER 图导出为 SVG 矢量图，或生成 Mermaid / PlantUML 文本定义，便于嵌入文档。
+//! PNG 通过截取画布实现，见应用层的截图处理。
+
+use std::fmt::Write;
+
+use super::render::calculate_table_size;
+use super::state::{ERTable, RelationType, Relationship};
+
+/// 导出格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ERExportFormat {
+    /// PNG 图片（截取画布）
+    Png,
+    /// SVG 矢量图
+    Svg,
+    /// Mermaid erDiagram
+    Mermaid,
+    /// PlantUML
+    PlantUml,
+}
+
+impl ERExportFormat {
+    /// 所有格式
+    pub const ALL: [ERExportFormat; 4] =
+        [ERExportFormat::Png, ERExportFormat::Svg, ERExportFormat::Mermaid, ERExportFormat::PlantUml];
+
+    /// 显示名称
+    pub fn label(&self) -> &'static str {
+        match self {
+            ERExportFormat::Png => "PNG 图片",
+            ERExportFormat::Svg => "SVG 矢量图",
+            ERExportFormat::Mermaid => "Mermaid",
+            ERExportFormat::PlantUml => "PlantUML",
+        }
+    }
+
+    /// 文件扩展名
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ERExportFormat::Png => "png",
+            ERExportFormat::Svg => "svg",
+            ERExportFormat::Mermaid => "mmd",
+            ERExportFormat::PlantUml => "puml",
+        }
+    }
+}
+
+/// 将名称转换为 Mermaid/PlantUML 可用的标识符
+fn identifier(name: &str) -> String {
+    let id: String = name
+        .chars()
+        .map(|c| if c.is_alphanumeric() || c == '_' { c } else { '_' })
+        .collect();
+    if id.is_empty() { "_".to_string() } else { id }
+}
+
+/// Mermaid 属性类型：只允许字母、数字和少量符号
+fn mermaid_type(data_type: &str) -> String {
+    let ty: String = data_type
+        .trim()
+        .chars()
+        .map(|c| if c.is_alphanumeric() || "_-()[]".contains(c) { c } else { '_' })
+        .collect();
+    if ty.is_empty() { "unknown".to_string() } else { ty }
+}
+
+/// 生成 Mermaid erDiagram 定义
+pub fn to_mermaid(tables: &[ERTable], relationships: &[Relationship]) -> String {
+    let mut out = String::from("erDiagram\n");
+    for table in tables {
+        let _ = writeln!(out, "    {} {{", identifier(&table.name));
+        for col in &table.columns {
+            let keys: Vec<&str> = [(col.is_primary_key, "PK"), (col.is_foreign_key, "FK")]
+                .into_iter()
+                .filter_map(|(on, key)| on.then_some(key))
+                .collect();
+            let _ = write!(out, "        {} {}", mermaid_type(&col.data_type), identifier(&col.name));
+            if !keys.is_empty() {
+                let _ = write!(out, " {}", keys.join(", "));
+            }
+            out.push('\n');
+        }
+        out.push_str("    }\n");
+    }
+    for rel in relationships {
+        // 被引用的表在左侧
+        let cardinality = match rel.relation_type {
+            RelationType::OneToOne => "||--||",
+            RelationType::OneToMany => "||--o{",
+            RelationType::ManyToMany => "}o--o{",
+        };
+        let _ = writeln!(
+            out,
+            "    {} {} {} : \"{}\"",
+            identifier(&rel.to_table),
+            cardinality,
+            identifier(&rel.from_table),
+            rel.from_column.replace('"', "'")
+        );
+    }
+    out
+}
+
+/// 生成 PlantUML 实体关系图定义（IE 表示法，`*` 表示 NOT NULL）
+pub fn to_plantuml(tables: &[ERTable], relationships: &[Relationship]) -> String {
+    let mut out = String::from("@startuml\nhide circle\nskinparam linetype ortho\n\n");
+    for table in tables {
+        let _ = writeln!(out, "entity \"{}\" as {} {{", table.name.replace('"', "'"), identifier(&table.name));
+        let (keys, others): (Vec<_>, Vec<_>) = table.columns.iter().partition(|c| c.is_primary_key);
+        let line = |out: &mut String, col: &super::state::ERColumn| {
+            let mut stereotypes = Vec::new();
+            if col.is_primary_key {
+                stereotypes.push("<<PK>>");
+            }
+            if col.is_foreign_key {
+                stereotypes.push("<<FK>>");
+            }
+            let _ = writeln!(
+                out,
+                "  {}{} : {}{}",
+                if col.nullable { "" } else { "* " },
+                col.name,
+                col.data_type,
+                if stereotypes.is_empty() { String::new() } else { format!(" {}", stereotypes.join(" ")) }
+            );
+        };
+        for col in &keys {
+            line(&mut out, col);
+        }
+        if !keys.is_empty() {
+            out.push_str("  --\n");
+        }
+        for col in &others {
+            line(&mut out, col);
+        }
+        out.push_str("}\n\n");
+    }
+    for rel in relationships {
+        let cardinality = match rel.relation_type {
+            RelationType::OneToOne => "||--||",
+            RelationType::OneToMany => "||--o{",
+            RelationType::ManyToMany => "}o--o{",
+        };
+        let _ = writeln!(
+            out,
+            "{} {} {} : {}",
+            identifier(&rel.to_table),
+            cardinality,
+            identifier(&rel.from_table),
+            rel.from_column
+        );
+    }
+    out.push_str("@enduml\n");
+    out
+}
+
+/// 转义 XML 文本
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+const SVG_MARGIN: f32 = 40.0;
+const SVG_HEADER_HEIGHT: f32 = 36.0;
+const SVG_ROW_HEIGHT: f32 = 24.0;
+const SVG_PADDING: f32 = 12.0;
+
+/// 生成 SVG 矢量图（使用画布上的布局，浅色配色）
+pub fn to_svg(tables: &[ERTable], relationships: &[Relationship]) -> String {
+    let mut tables = tables.to_vec();
+    for table in &mut tables {
+        calculate_table_size(table);
+    }
+
+    let min_x = tables.iter().map(|t| t.position.x).fold(f32::MAX, f32::min);
+    let min_y = tables.iter().map(|t| t.position.y).fold(f32::MAX, f32::min);
+    let max_x = tables.iter().map(|t| t.position.x + t.size.x).fold(f32::MIN, f32::max);
+    let max_y = tables.iter().map(|t| t.position.y + t.size.y).fold(f32::MIN, f32::max);
+    let (origin_x, origin_y, width, height) = if tables.is_empty() {
+        (0.0, 0.0, SVG_MARGIN * 2.0, SVG_MARGIN * 2.0)
+    } else {
+        (min_x - SVG_MARGIN, min_y - SVG_MARGIN, max_x - min_x + SVG_MARGIN * 2.0, max_y - min_y + SVG_MARGIN * 2.0)
+    };
+    let x = |v: f32| v - origin_x;
+    let y = |v: f32| v - origin_y;
+
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w:.0}" height="{h:.0}" viewBox="0 0 {w:.0} {h:.0}" font-family="sans-serif">"#,
+        w = width,
+        h = height
+    );
+    out.push_str(r##"<rect width="100%" height="100%" fill="#fafafc"/>"##);
+    out.push('\n');
+
+    // 关系线在表格下方
+    for rel in relationships {
+        let (Some(from), Some(to)) = (
+            tables.iter().find(|t| t.name == rel.from_table),
+            tables.iter().find(|t| t.name == rel.to_table),
+        ) else {
+            continue;
+        };
+        let row_y = |table: &ERTable, column: &str| {
+            let idx = table.columns.iter().position(|c| c.name == column).unwrap_or(0);
+            table.position.y + SVG_HEADER_HEIGHT + idx as f32 * SVG_ROW_HEIGHT + SVG_ROW_HEIGHT / 2.0
+        };
+        let to_right = to.center().x > from.center().x;
+        let (fx, tx, dir) = if to_right {
+            (from.position.x + from.size.x, to.position.x, 1.0)
+        } else {
+            (from.position.x, to.position.x + to.size.x, -1.0)
+        };
+        let (fy, ty) = (row_y(from, &rel.from_column), row_y(to, &rel.to_column));
+        let _ = writeln!(
+            out,
+            r##"<path d="M {:.1} {:.1} C {:.1} {:.1}, {:.1} {:.1}, {:.1} {:.1}" fill="none" stroke="#828caa" stroke-width="2"/>"##,
+            x(fx),
+            y(fy),
+            x(fx + 50.0 * dir),
+            y(fy),
+            x(tx - 50.0 * dir),
+            y(ty),
+            x(tx),
+            y(ty)
+        );
+        // 箭头
+        let _ = writeln!(
+            out,
+            r##"<path d="M {:.1} {:.1} L {:.1} {:.1} L {:.1} {:.1}" fill="none" stroke="#828caa" stroke-width="2"/>"##,
+            x(tx - 8.0 * dir),
+            y(ty - 4.0),
+            x(tx),
+            y(ty),
+            x(tx - 8.0 * dir),
+            y(ty + 4.0)
+        );
+        let label = match rel.relation_type {
+            RelationType::OneToOne => "1:1",
+            RelationType::OneToMany => "1:N",
+            RelationType::ManyToMany => "N:M",
+        };
+        let _ = writeln!(
+            out,
+            r##"<text x="{:.1}" y="{:.1}" font-size="10" fill="#5f6368" text-anchor="middle">{}</text>"##,
+            x((fx + tx) / 2.0),
+            y((fy + ty) / 2.0 - 10.0),
+            label
+        );
+    }
+
+    for table in &tables {
+        let (tx, ty) = (x(table.position.x), y(table.position.y));
+        let (w, h) = (table.size.x, table.size.y);
+        let _ = writeln!(out, r#"<g>"#);
+        let _ = writeln!(
+            out,
+            r##"<rect x="{tx:.1}" y="{ty:.1}" width="{w:.1}" height="{h:.1}" rx="8" fill="#ffffff" stroke="#dadce4"/>"##
+        );
+        let _ = writeln!(
+            out,
+            r##"<path d="M {tx:.1} {hy:.1} L {rx:.1} {hy:.1}" stroke="#dadce4"/>"##,
+            hy = ty + SVG_HEADER_HEIGHT,
+            rx = tx + w
+        );
+        let _ = writeln!(
+            out,
+            r##"<text x="{:.1}" y="{:.1}" font-size="13" font-weight="bold" fill="#202124" text-anchor="middle">{}</text>"##,
+            tx + w / 2.0,
+            ty + SVG_HEADER_HEIGHT / 2.0 + 4.5,
+            xml_escape(&table.name)
+        );
+        for (i, col) in table.columns.iter().enumerate() {
+            let center_y = ty + SVG_HEADER_HEIGHT + i as f32 * SVG_ROW_HEIGHT + SVG_ROW_HEIGHT / 2.0;
+            let mut text_x = tx + SVG_PADDING;
+            if col.is_primary_key {
+                let _ = writeln!(
+                    out,
+                    r##"<circle cx="{:.1}" cy="{:.1}" r="3" fill="#fbbc04"/>"##,
+                    text_x + 4.0,
+                    center_y
+                );
+                text_x += 14.0;
+            }
+            if col.is_foreign_key {
+                let _ = writeln!(
+                    out,
+                    r##"<circle cx="{:.1}" cy="{:.1}" r="3" fill="none" stroke="#1a73e8" stroke-width="1.5"/>"##,
+                    text_x + 4.0,
+                    center_y
+                );
+                text_x += 14.0;
+            }
+            let name_color = if col.nullable { "#5f6368" } else { "#202124" };
+            let _ = writeln!(
+                out,
+                r#"<text x="{:.1}" y="{:.1}" font-size="11" fill="{}">{}</text>"#,
+                text_x,
+                center_y + 4.0,
+                name_color,
+                xml_escape(&col.name)
+            );
+            let _ = writeln!(
+                out,
+                r##"<text x="{:.1}" y="{:.1}" font-size="10" fill="#808691" text-anchor="end">{}</text>"##,
+                tx + w - SVG_PADDING,
+                center_y + 3.5,
+                xml_escape(&col.data_type)
+            );
+        }
+        out.push_str("</g>\n");
+    }
+
+    out.push_str("</svg>\n");
+    out
+}
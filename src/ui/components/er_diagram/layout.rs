@@ -151,7 +151,6 @@ pub fn force_directed_layout(
 /// 层次布局（适合有明确层次关系的表）
 /// 
 /// 根据外键关系确定层次，被引用的表在上层
-#[allow(dead_code)]
 pub fn hierarchical_layout(
     tables: &mut [ERTable],
     relationships: &[Relationship],
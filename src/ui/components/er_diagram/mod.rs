@@ -4,11 +4,16 @@
 //! - 显示表结构（列名、类型、主键、外键）
 //! - 显示表之间的关系（外键连接）
 //! - 支持拖动、缩放、自动布局
+//! - 在画布上设计新表并生成建表语句
+//! - 导出为 PNG/SVG 图片或 Mermaid/PlantUML 文本
 
+mod design;
+mod export;
 mod layout;
 mod render;
 mod state;
 
-pub use layout::{force_directed_layout, grid_layout};
+pub use export::{to_mermaid, to_plantuml, to_svg, ERExportFormat};
+pub use layout::{force_directed_layout, grid_layout, hierarchical_layout};
 pub use render::{calculate_table_size, ERDiagramResponse};
 pub use state::{ERColumn, ERDiagramState, ERTable, Relationship, RelationType};
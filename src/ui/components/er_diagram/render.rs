@@ -1,5 +1,6 @@
 //! ER 图渲染
 
+use super::export::ERExportFormat;
 use super::state::{ERDiagramState, ERTable, RelationType};
 use crate::core::ThemePreset;
 use egui::{self, Color32, FontId, Pos2, Rect, RichText, CornerRadius, Sense, Stroke, Vec2};
@@ -13,6 +14,16 @@ pub struct ERDiagramResponse {
     pub layout_requested: bool,
     /// 是否需要适应视图
     pub fit_view_requested: bool,
+    /// 请求导出的格式
+    pub export_requested: Option<ERExportFormat>,
+    /// 本帧为截图而完整绘制的画布区域（PNG 导出）
+    pub capture_rect: Option<Rect>,
+    /// 是否请求在画布上新建表
+    pub new_table_requested: bool,
+    /// 请求编辑的设计表
+    pub edit_table_requested: Option<String>,
+    /// 是否请求为设计中的表生成 DDL
+    pub generate_ddl_requested: bool,
 }
 
 /// 渲染颜色配置
@@ -144,6 +155,42 @@ impl ERDiagramState {
                 self.reset_view();
             }
 
+            ui.add_space(8.0);
+
+            // 表设计
+            if ui.add(
+                egui::Button::new(RichText::new("➕").size(14.0).color(Color32::LIGHT_GRAY))
+                    .frame(false)
+                    .min_size(Vec2::new(26.0, 26.0)),
+            ).on_hover_text("设计新表 [N]\n按住 Shift 从列拖到另一张表可添加外键").clicked() {
+                response.new_table_requested = true;
+            }
+
+            if ui.add_enabled(
+                self.has_design(),
+                egui::Button::new(RichText::new("📝").size(14.0).color(Color32::LIGHT_GRAY))
+                    .frame(false)
+                    .min_size(Vec2::new(26.0, 26.0)),
+            ).on_hover_text("为设计中的表生成建表语句").clicked() {
+                response.generate_ddl_requested = true;
+            }
+
+            ui.add_space(8.0);
+
+            // 导出菜单
+            ui.add_enabled_ui(!self.tables.is_empty(), |ui| {
+                ui.menu_button(RichText::new("💾").size(14.0).color(Color32::LIGHT_GRAY), |ui| {
+                    for format in ERExportFormat::ALL {
+                        if ui.button(format.label()).clicked() {
+                            response.export_requested = Some(format);
+                            ui.close();
+                        }
+                    }
+                })
+                .response
+                .on_hover_text("导出 ER 图");
+            });
+
             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                 ui.label(
                     RichText::new(format!("{} 张表", self.tables.len()))
@@ -172,6 +219,18 @@ impl ERDiagramState {
         );
         let canvas_rect = canvas_response.rect;
 
+        // 截图前让所有表完整显示在画布内
+        if self.capture_pending {
+            self.capture_pending = false;
+            if !self.tables.is_empty() {
+                for table in &mut self.tables {
+                    Self::calculate_table_size(table);
+                }
+                self.fit_to_view(canvas_rect.size());
+                response.capture_rect = Some(canvas_rect);
+            }
+        }
+
         // 绘制背景
         painter.rect_filled(canvas_rect, CornerRadius::ZERO, colors.background);
 
@@ -209,10 +268,28 @@ impl ERDiagramState {
             for table in &self.tables {
                 Self::draw_table_static(&painter, table, canvas_rect, &colors, self.pan_offset, self.zoom);
             }
+
+            // 正在拖出的外键连线
+            if let Some((table_idx, col_idx, pointer)) = self.linking
+                && let Some(table) = self.tables.get(table_idx)
+            {
+                let start = Pos2::new(
+                    canvas_rect.left() + (table.position.x + table.size.x + self.pan_offset.x) * self.zoom,
+                    canvas_rect.top() + (table.position.y + self.pan_offset.y) * self.zoom
+                        + (36.0 + col_idx as f32 * 24.0 + 12.0) * self.zoom,
+                );
+                painter.add(egui::Shape::dashed_line(
+                    &[start, pointer],
+                    Stroke::new(2.0, colors.table_selected_border),
+                    6.0,
+                    4.0,
+                ));
+            }
         }
 
         // 处理交互
-        self.handle_interaction(ui, &canvas_response, canvas_rect);
+        self.handle_interaction(ui, &canvas_response, canvas_rect, &mut response);
+        self.show_context_menu(&canvas_response, &mut response);
 
         // 键盘快捷键
         if canvas_response.has_focus() || canvas_response.hovered() {
@@ -226,6 +303,9 @@ impl ERDiagramState {
                 if i.key_pressed(egui::Key::F) {
                     response.fit_view_requested = true;
                 }
+                if i.key_pressed(egui::Key::N) {
+                    response.new_table_requested = true;
+                }
                 if i.key_pressed(egui::Key::Plus) || i.key_pressed(egui::Key::Equals) {
                     self.zoom_by(1.2);
                 }
@@ -367,6 +447,17 @@ impl ERDiagramState {
             egui::StrokeKind::Inside,
         );
 
+        // 设计中的表使用虚线外框
+        if table.is_design() {
+            let outline = table_rect.expand(3.0 * zoom);
+            painter.add(egui::Shape::dashed_line(
+                &[outline.left_top(), outline.right_top(), outline.right_bottom(), outline.left_bottom(), outline.left_top()],
+                Stroke::new(1.5, colors.table_selected_border),
+                6.0 * zoom,
+                4.0 * zoom,
+            ));
+        }
+
         // 绘制表头背景
         let header_rect = Rect::from_min_size(
             screen_pos,
@@ -613,7 +704,7 @@ impl ERDiagramState {
 
     /// 绘制关系线
     fn draw_relationships(&self, painter: &egui::Painter, canvas_rect: Rect, colors: &RenderColors) {
-        for rel in &self.relationships {
+        for rel in &self.all_relationships() {
             let from_table = self.tables.iter().find(|t| t.name == rel.from_table);
             let to_table = self.tables.iter().find(|t| t.name == rel.to_table);
 
@@ -709,13 +800,131 @@ impl ERDiagramState {
         }
     }
 
+    /// 指针下的表索引
+    fn table_at(&self, pos: Pos2, canvas_rect: Rect) -> Option<usize> {
+        self.tables.iter().position(|table| {
+            let screen_pos = Pos2::new(
+                canvas_rect.left() + (table.position.x + self.pan_offset.x) * self.zoom,
+                canvas_rect.top() + (table.position.y + self.pan_offset.y) * self.zoom,
+            );
+            Rect::from_min_size(screen_pos, table.size * self.zoom).contains(pos)
+        })
+    }
+
+    /// 指针下的列索引（表头返回 None）
+    fn column_at(&self, table_idx: usize, pos: Pos2, canvas_rect: Rect) -> Option<usize> {
+        let table = self.tables.get(table_idx)?;
+        let top = canvas_rect.top() + (table.position.y + self.pan_offset.y + 36.0) * self.zoom;
+        if pos.y < top {
+            return None;
+        }
+        let idx = ((pos.y - top) / (24.0 * self.zoom)) as usize;
+        (idx < table.columns.len()).then_some(idx)
+    }
+
+    /// 结束外键连线：落在另一张表的列上（或其主键）时添加外键
+    fn finish_linking(&mut self, pos: Option<Pos2>, canvas_rect: Rect) {
+        let Some((from_idx, from_col, _)) = self.linking.take() else {
+            return;
+        };
+        let Some(pos) = pos else {
+            return;
+        };
+        let Some(to_idx) = self.table_at(pos, canvas_rect).filter(|&i| i != from_idx) else {
+            return;
+        };
+        let to = &self.tables[to_idx];
+        let to_col = self
+            .column_at(to_idx, pos, canvas_rect)
+            .or_else(|| to.columns.iter().position(|c| c.is_primary_key))
+            .or_else(|| (!to.columns.is_empty()).then_some(0));
+        let Some(to_col) = to_col else {
+            return;
+        };
+        let from = &self.tables[from_idx];
+        let (from_table, from_column) = (from.name.clone(), from.columns[from_col].name.clone());
+        let (to_table, to_column) = (to.name.clone(), to.columns[to_col].name.clone());
+        self.add_design_link(&from_table, &from_column, &to_table, &to_column);
+    }
+
+    /// 右键菜单
+    fn show_context_menu(&mut self, canvas_response: &egui::Response, actions: &mut ERDiagramResponse) {
+        canvas_response.context_menu(|ui| {
+            let table = self.context_table.and_then(|i| self.tables.get(i));
+            if let Some(table) = table.filter(|t| t.is_design()) {
+                let name = table.name.clone();
+                let has_links = table.design.as_ref().is_some_and(|d| !d.foreign_keys.is_empty());
+                ui.label(RichText::new(&name).strong());
+                ui.separator();
+                if ui.button("✏ 编辑表...").clicked() {
+                    actions.edit_table_requested = Some(name.clone());
+                    ui.close();
+                }
+                if ui.add_enabled(has_links, egui::Button::new("✂ 清除外键")).clicked() {
+                    self.clear_design_links(&name);
+                    ui.close();
+                }
+                if ui.button("🗑 删除表").clicked() {
+                    self.remove_design(&name);
+                    ui.close();
+                }
+                ui.separator();
+            }
+            if ui.button("➕ 设计新表...").clicked() {
+                actions.new_table_requested = true;
+                ui.close();
+            }
+            if ui.add_enabled(self.has_design(), egui::Button::new("📝 生成建表语句")).clicked() {
+                actions.generate_ddl_requested = true;
+                ui.close();
+            }
+        });
+    }
+
     /// 处理交互
     fn handle_interaction(
         &mut self,
         ui: &egui::Ui,
         response: &egui::Response,
         canvas_rect: Rect,
+        actions: &mut ERDiagramResponse,
     ) {
+        // 右键记录对应的表
+        if response.secondary_clicked() {
+            self.context_table = response.interact_pointer_pos().and_then(|pos| self.table_at(pos, canvas_rect));
+        }
+
+        // 双击编辑设计中的表
+        if response.double_clicked()
+            && let Some(pos) = response.interact_pointer_pos()
+            && let Some(idx) = self.table_at(pos, canvas_rect)
+            && self.tables[idx].is_design()
+        {
+            actions.edit_table_requested = Some(self.tables[idx].name.clone());
+        }
+
+        // Shift + 拖动：从设计表的列拖出外键
+        if response.drag_started()
+            && ui.input(|i| i.modifiers.shift)
+            && let Some(pos) = response.interact_pointer_pos()
+            && let Some(idx) = self.table_at(pos, canvas_rect)
+            && self.tables[idx].is_design()
+            && let Some(col) = self.column_at(idx, pos, canvas_rect)
+        {
+            self.linking = Some((idx, col, pos));
+        }
+        if self.linking.is_some() {
+            if let Some(pos) = response.interact_pointer_pos()
+                && let Some(linking) = &mut self.linking
+            {
+                linking.2 = pos;
+            }
+            if response.drag_stopped() {
+                self.finish_linking(response.interact_pointer_pos(), canvas_rect);
+            }
+            return;
+        }
+
         // 滚轮缩放
         let scroll_delta = ui.input(|i| i.raw_scroll_delta);
         if response.hovered() && scroll_delta.y != 0.0 {
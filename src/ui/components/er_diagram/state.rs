@@ -2,6 +2,7 @@
 
 #![allow(dead_code)] // 公开 API
 
+use crate::ui::TableDefinition;
 use egui::{Pos2, Vec2};
 
 /// 关系类型
@@ -45,6 +46,8 @@ pub struct ERTable {
     pub size: Vec2,
     /// 是否被选中
     pub selected: bool,
+    /// 在画布上设计、尚未创建的表定义
+    pub design: Option<TableDefinition>,
 }
 
 impl ERTable {
@@ -56,6 +59,7 @@ impl ERTable {
             position: Pos2::ZERO,
             size: Vec2::ZERO,
             selected: false,
+            design: None,
         }
     }
 
@@ -108,6 +112,12 @@ pub struct ERDiagramState {
     pub needs_layout: bool,
     /// 是否显示 ER 图面板
     pub show: bool,
+    /// 下一帧是否为 PNG 导出截取画布
+    pub capture_pending: bool,
+    /// 正在拖出的外键连线（源表索引，源列索引，当前指针位置）
+    pub linking: Option<(usize, usize, Pos2)>,
+    /// 右键菜单对应的表索引
+    pub context_table: Option<usize>,
 }
 
 impl ERDiagramState {
@@ -120,12 +130,14 @@ impl ERDiagramState {
         }
     }
 
-    /// 清空数据
+    /// 清空数据（保留设计中的表）
     pub fn clear(&mut self) {
-        self.tables.clear();
+        self.tables.retain(|t| t.design.is_some());
         self.relationships.clear();
         self.selected_table = None;
         self.dragging_table = None;
+        self.linking = None;
+        self.context_table = None;
         self.needs_layout = true;
     }
 
@@ -184,6 +196,11 @@ impl ERDiagramState {
         self.zoom = 1.0;
     }
 
+    /// 请求在下一帧截取画布（用于导出 PNG）
+    pub fn request_capture(&mut self) {
+        self.capture_pending = true;
+    }
+
     /// 适应视图（将所有表居中显示）
     pub fn fit_to_view(&mut self, available_size: Vec2) {
         if self.tables.is_empty() {
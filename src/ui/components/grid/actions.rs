@@ -1,10 +1,12 @@
 //! 表格操作和 SQL 生成
 
 use super::state::DataGridState;
-use crate::database::QueryResult;
+use crate::core::{blob_literal, decode_blob, insert_row_sql, Upsert, UpsertSyntax};
+use crate::database::{DatabaseType, QueryResult};
 
 /// 焦点转移方向
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::enum_variant_names)]
 pub enum FocusTransfer {
     /// 转移到侧边栏
     ToSidebar,
@@ -17,8 +19,8 @@ pub enum FocusTransfer {
 /// 表格操作返回值
 #[derive(Default)]
 pub struct DataGridActions {
-    /// 需要执行的 SQL 语句列表
-    pub sql_to_execute: Vec<String>,
+    /// 保存修改生成的 SQL 语句（由调用方显示检查窗口后执行）
+    pub sql_to_review: Vec<String>,
     /// 状态消息
     pub message: Option<String>,
     /// 请求刷新表格数据
@@ -37,6 +39,28 @@ pub struct DataGridActions {
     pub scroll_to_bottom: bool,
     /// 请求切换到指定的查询Tab (1-indexed)
     pub switch_to_tab: Option<usize>,
+    /// 请求读取分页结果的指定页（从 0 开始）
+    pub page_request: Option<usize>,
+    /// 请求将筛选条件转换为 WHERE 子句在服务端重新查询
+    pub apply_filters_server_side: bool,
+    /// 请求批量修改筛选条件匹配的行
+    pub bulk_edit: bool,
+    /// 请求生成打印视图
+    pub print_view: bool,
+    /// 请求打开透视表窗口
+    pub pivot_view: bool,
+    /// 打印视图和透视表使用的行（筛选和排序后显示的原始行索引）
+    pub display_rows: Vec<usize>,
+    /// 列的数字格式已修改，需要按表保存
+    pub column_formats_changed: bool,
+    /// 手动调整的列宽已修改，需要按表保存
+    pub column_widths_changed: bool,
+    /// 列的显示顺序已修改，需要按表保存
+    pub column_order_changed: bool,
+    /// 标记或取消标记的敏感列 (列名, 是否敏感)，需要按连接保存
+    pub masked_column: Option<(String, bool)>,
+    /// 请求收藏或取消收藏的行（原始行索引），按主键保存到当前表的书签
+    pub bookmark_row: Option<usize>,
 }
 
 /// SQL 危险保留字（可能被用于注入攻击）
@@ -97,6 +121,20 @@ pub fn quote_identifier(name: &str, use_backticks: bool) -> Result<String, Strin
     }
 }
 
+/// 引用可能带 schema 前缀的表名（`schema.table` -> `"schema"."table"`）
+///
+/// 用于 SQLite 附加数据库中的表，每一段分别验证并加引号
+pub fn quote_qualified_identifier(name: &str, use_backticks: bool) -> Result<String, String> {
+    match name.split_once('.') {
+        Some((schema, table)) => Ok(format!(
+            "{}.{}",
+            quote_identifier(schema, use_backticks)?,
+            quote_identifier(table, use_backticks)?
+        )),
+        None => quote_identifier(name, use_backticks),
+    }
+}
+
 /// 转义 SQL 字符串值
 ///
 /// 处理单引号转义，防止 SQL 注入
@@ -108,7 +146,15 @@ pub fn escape_value(value: &str) -> String {
     format!("'{}'", value.replace('\'', "''"))
 }
 
-/// 生成保存修改的 SQL（带确认）
+/// 单元格的新值：二进制值按数据库类型生成十六进制字面量，其他值作为字符串
+fn cell_literal(value: &str, db_type: Option<DatabaseType>) -> String {
+    match (decode_blob(value), db_type) {
+        (Some(bytes), Some(db_type)) => blob_literal(&bytes, db_type),
+        _ => escape_value(value),
+    }
+}
+
+/// 生成保存修改的 SQL，交给调用方检查后执行
 pub fn generate_save_sql(
     result: &QueryResult,
     state: &mut DataGridState,
@@ -137,7 +183,6 @@ pub fn generate_save_sql(
     }
 
     let mut sql_statements = Vec::new();
-    let has_deletes = !state.rows_to_delete.is_empty();
 
     // 获取主键列索引
     // 优先使用已设置的主键，其次尝试查找 "id" 列
@@ -175,7 +220,7 @@ pub fn generate_save_sql(
                     let safe_value = if new_value.is_empty() || new_value.eq_ignore_ascii_case("null") {
                         "NULL".to_string()
                     } else {
-                        escape_value(new_value)
+                        cell_literal(new_value, state.db_type)
                     };
                     let safe_pk_value = escape_value(pk_value);
 
@@ -200,27 +245,25 @@ pub fn generate_save_sql(
             }
     }
 
-    // 生成 INSERT 语句
+    // 生成 INSERT 语句（选择 upsert 时主键已存在的行改为更新）
+    let upsert = state
+        .db_type
+        .and_then(UpsertSyntax::for_db)
+        .filter(|_| state.upsert_new_rows)
+        .map(|syntax| Upsert { syntax, key_columns: vec![pk_col.clone()] });
     for new_row in &state.new_rows {
         if new_row.iter().any(|v| !v.is_empty()) {
-            let cols = safe_columns.join(", ");
             let vals: Vec<String> = new_row
                 .iter()
                 .map(|v| {
                     if v.is_empty() || v.eq_ignore_ascii_case("null") {
                         "NULL".to_string()
                     } else {
-                        escape_value(v)
+                        cell_literal(v, state.db_type)
                     }
                 })
                 .collect();
-            let sql = format!(
-                "INSERT INTO {} ({}) VALUES ({});",
-                safe_table_name,
-                cols,
-                vals.join(", ")
-            );
-            sql_statements.push(sql);
+            sql_statements.push(insert_row_sql(&safe_table_name, &safe_columns, &vals.join(", "), upsert.as_ref()));
         }
     }
 
@@ -229,38 +272,7 @@ pub fn generate_save_sql(
         return;
     }
 
-    // 如果包含删除操作，需要确认
-    if has_deletes {
-        state.pending_sql = sql_statements;
-        state.show_save_confirm = true;
-        actions.message = Some(format!(
-            "包含 {} 条删除操作，请确认",
-            state.rows_to_delete.len()
-        ));
-    } else {
-        // 没有删除操作，直接执行
-        actions.sql_to_execute = sql_statements;
-        actions.message = Some(format!(
-            "将执行 {} 条 SQL 语句",
-            actions.sql_to_execute.len()
-        ));
-        state.clear_edits();
-    }
-}
-
-/// 确认执行待确认的 SQL
-pub fn confirm_pending_sql(state: &mut DataGridState, actions: &mut DataGridActions) {
-    if !state.pending_sql.is_empty() {
-        actions.sql_to_execute = std::mem::take(&mut state.pending_sql);
-        actions.message = Some(format!("执行 {} 条 SQL 语句", actions.sql_to_execute.len()));
-        state.clear_edits();
-    }
-    state.show_save_confirm = false;
-}
-
-/// 取消待确认的 SQL
-pub fn cancel_pending_sql(state: &mut DataGridState) {
-    state.pending_sql.clear();
-    state.show_save_confirm = false;
+    // 修改在确认执行后才清除，取消检查时仍保留
+    actions.sql_to_review = sql_statements;
 }
 
@@ -0,0 +1,128 @@
+//! 聚合统计
+//!
+//! 表格底部状态栏显示的 COUNT/SUM/AVG/MIN/MAX（类似电子表格）。
+//! 单元格目前都是文本：所有非 NULL 值都能解析为数字时按数值统计，
+//! 否则只统计个数，并按文本比较最小/最大值（ISO 格式的日期时间同样适用）。
+//! 列头菜单的“列统计”在此基础上再统计 NULL 和不同值的个数。
+
+use std::collections::HashSet;
+
+/// 一组单元格的聚合结果
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Aggregate {
+    /// 单元格总数
+    pub cells: usize,
+    /// 非 NULL 的单元格数
+    pub count: usize,
+    /// 数值统计（存在非数字值时为 `None`）
+    pub numeric: Option<NumericStats>,
+    /// 最小值（按数值或文本比较）
+    pub min: Option<String>,
+    /// 最大值（按数值或文本比较）
+    pub max: Option<String>,
+}
+
+/// 数值统计
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NumericStats {
+    /// 总和
+    pub sum: f64,
+    /// 平均值
+    pub avg: f64,
+}
+
+impl Aggregate {
+    /// 统计一组单元格，`NULL` 和空字符串不计入个数
+    pub fn compute<'a>(values: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut cells = 0;
+        let mut texts: Vec<&str> = Vec::new();
+        for value in values {
+            cells += 1;
+            if value != "NULL" && !value.is_empty() {
+                texts.push(value);
+            }
+        }
+
+        let numbers: Option<Vec<(f64, &str)>> = texts
+            .iter()
+            .map(|v| parse_number(v).map(|n| (n, *v)))
+            .collect();
+        let (numeric, min, max) = match numbers {
+            Some(numbers) if !numbers.is_empty() => {
+                let sum: f64 = numbers.iter().map(|(n, _)| n).sum();
+                let min = numbers.iter().min_by(|a, b| a.0.total_cmp(&b.0)).map(|(_, v)| v.to_string());
+                let max = numbers.iter().max_by(|a, b| a.0.total_cmp(&b.0)).map(|(_, v)| v.to_string());
+                let stats = NumericStats {
+                    sum,
+                    avg: sum / numbers.len() as f64,
+                };
+                (Some(stats), min, max)
+            }
+            _ => (
+                None,
+                texts.iter().min().map(|v| v.to_string()),
+                texts.iter().max().map(|v| v.to_string()),
+            ),
+        };
+
+        Self {
+            cells,
+            count: texts.len(),
+            numeric,
+            min,
+            max,
+        }
+    }
+}
+
+/// 整列的统计（列头菜单“列统计”）
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ColumnStats {
+    /// 列名
+    pub column: String,
+    /// 聚合结果
+    pub aggregate: Aggregate,
+    /// NULL 和空字符串的个数
+    pub nulls: usize,
+    /// 不同的非 NULL 值个数
+    pub distinct: usize,
+}
+
+impl ColumnStats {
+    /// 统计一列的值
+    pub fn compute<'a>(column: impl Into<String>, values: impl IntoIterator<Item = &'a str>) -> Self {
+        let values: Vec<&str> = values.into_iter().collect();
+        let aggregate = Aggregate::compute(values.iter().copied());
+        let distinct = values
+            .iter()
+            .filter(|v| **v != "NULL" && !v.is_empty())
+            .collect::<HashSet<_>>()
+            .len();
+        Self {
+            column: column.into(),
+            nulls: aggregate.cells - aggregate.count,
+            distinct,
+            aggregate,
+        }
+    }
+}
+
+/// 解析数字（`inf`、`NaN` 等不视为数字）
+fn parse_number(value: &str) -> Option<f64> {
+    let value = value.trim();
+    if value.is_empty()
+        || !value.chars().all(|c| c.is_ascii_digit() || matches!(c, '.' | '-' | '+' | 'e' | 'E'))
+    {
+        return None;
+    }
+    value.parse::<f64>().ok().filter(|n| n.is_finite())
+}
+
+/// 格式化统计值：整数不带小数，其他保留至多 4 位小数并去掉末尾的 0
+pub fn format_number(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < 1e15 {
+        return format!("{}", value as i64);
+    }
+    let text = format!("{:.4}", value);
+    text.trim_end_matches('0').trim_end_matches('.').to_string()
+}
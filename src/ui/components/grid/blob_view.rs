@@ -0,0 +1,67 @@
+//! 二进制单元格查看器
+//!
+//! 二进制单元格右键可打开查看窗口：十六进制/ASCII 对照显示（过大时只显示开头部分），
+//! 可将内容保存到文件，或从文件导入内容作为单元格的新值（保存修改后写入数据库）。
+
+use crate::core::constants::grid::{BLOB_HOVER_BYTES, BLOB_IMPORT_MAX_SIZE, BLOB_VIEW_MAX_BYTES};
+use crate::core::{decode_blob, encode_blob, hex_dump};
+use std::path::Path;
+
+/// 正在查看的二进制单元格
+#[derive(Debug, Clone)]
+pub struct BlobViewer {
+    /// 窗口标题（列名和行号）
+    pub title: String,
+    /// 单元格位置（行, 列）
+    pub cell: (usize, usize),
+    /// 二进制内容
+    pub bytes: Vec<u8>,
+    /// 十六进制/ASCII 对照文本（最多 `BLOB_VIEW_MAX_BYTES` 字节）
+    pub dump: String,
+}
+
+impl BlobViewer {
+    /// 解析单元格内容，不是二进制值时返回 `None`
+    pub fn open(title: String, cell: (usize, usize), value: &str) -> Option<Self> {
+        let bytes = decode_blob(value)?;
+        let dump = hex_dump(&bytes[..bytes.len().min(BLOB_VIEW_MAX_BYTES)]);
+        Some(Self { title, cell, bytes, dump })
+    }
+
+    /// 内容是否超出显示范围
+    pub fn is_truncated(&self) -> bool {
+        self.bytes.len() > BLOB_VIEW_MAX_BYTES
+    }
+
+    /// 保存内容到文件
+    pub fn save_to(&self, path: &Path) -> Result<(), String> {
+        std::fs::write(path, &self.bytes).map_err(|e| format!("保存文件失败: {}", e))
+    }
+
+    /// 读取文件内容替换当前内容，返回编码后的单元格文本
+    pub fn import_from(&mut self, path: &Path) -> Result<String, String> {
+        let size = std::fs::metadata(path).map_err(|e| format!("读取文件失败: {}", e))?.len();
+        if size > BLOB_IMPORT_MAX_SIZE {
+            return Err(format!(
+                "文件过大（{} 字节），最多导入 {} MB",
+                size,
+                BLOB_IMPORT_MAX_SIZE / 1024 / 1024
+            ));
+        }
+        let bytes = std::fs::read(path).map_err(|e| format!("读取文件失败: {}", e))?;
+        let encoded = encode_blob(&bytes);
+        self.dump = hex_dump(&bytes[..bytes.len().min(BLOB_VIEW_MAX_BYTES)]);
+        self.bytes = bytes;
+        Ok(encoded)
+    }
+}
+
+/// 悬停时显示的开头部分
+pub fn hover_preview(value: &str) -> Option<String> {
+    let bytes = decode_blob(value).filter(|b| !b.is_empty())?;
+    let mut dump = hex_dump(&bytes[..bytes.len().min(BLOB_HOVER_BYTES)]);
+    if bytes.len() > BLOB_HOVER_BYTES {
+        dump.push_str(&format!("\n… 共 {} 字节，右键「查看二进制」", bytes.len()));
+    }
+    Some(dump)
+}
@@ -0,0 +1,155 @@
+//! 重复行检测
+//!
+//! 按全部列或选定的列比较结果集中的行，值完全相同的行归为一组（至少两行）。
+//! 表格按组交替着色显示，并可删除每组多余的行：组内主键值互不相同时按主键删除第一行以外的行，
+//! 否则在 PostgreSQL/MySQL 上按比较列的值限制删除的行数。
+
+use super::actions::{escape_identifier, escape_value};
+use crate::database::{DatabaseType, QueryResult};
+use std::collections::{HashMap, HashSet};
+
+/// 删除多余重复行的方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DedupDelete {
+    /// 按主键删除每组第一行以外的行
+    PrimaryKey(usize),
+    /// 按比较列的值匹配，每组只删除多余的行数（PostgreSQL 通过 ctid，MySQL 使用 LIMIT）
+    Limited(DatabaseType),
+}
+
+/// 重复行分组结果
+#[derive(Debug, Clone, Default)]
+pub struct DuplicateGroups {
+    /// 参与比较的列索引
+    pub columns: Vec<usize>,
+    /// 重复行分组（原始行索引，按首次出现的顺序）
+    pub groups: Vec<Vec<usize>>,
+    /// 行索引 -> 分组序号
+    row_group: HashMap<usize, usize>,
+    /// 检测时的行数（结果变化后分组失效）
+    row_count: usize,
+}
+
+impl DuplicateGroups {
+    /// 按指定列查找重复行，列为空时比较全部列
+    pub fn find(result: &QueryResult, columns: &[usize]) -> Self {
+        let columns: Vec<usize> = if columns.is_empty() {
+            (0..result.columns.len()).collect()
+        } else {
+            columns.to_vec()
+        };
+
+        let mut by_key: HashMap<Vec<&str>, usize> = HashMap::new();
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+        for (idx, row) in result.rows.iter().enumerate() {
+            let key: Vec<&str> = columns.iter().map(|&c| row.get(c).map_or("", String::as_str)).collect();
+            match by_key.get(&key) {
+                Some(&group) => groups[group].push(idx),
+                None => {
+                    by_key.insert(key, groups.len());
+                    groups.push(vec![idx]);
+                }
+            }
+        }
+        groups.retain(|g| g.len() > 1);
+
+        let row_group = groups
+            .iter()
+            .enumerate()
+            .flat_map(|(group, rows)| rows.iter().map(move |&row| (row, group)))
+            .collect();
+        Self {
+            columns,
+            groups,
+            row_group,
+            row_count: result.rows.len(),
+        }
+    }
+
+    /// 行所在的分组序号
+    pub fn group_of(&self, row: usize) -> Option<usize> {
+        self.row_group.get(&row).copied()
+    }
+
+    /// 多余的重复行数（每组第一行以外的行）
+    pub fn redundant_rows(&self) -> usize {
+        self.groups.iter().map(|g| g.len() - 1).sum()
+    }
+
+    /// 保留每组第一行时需要删除的行
+    pub fn rows_to_delete(&self) -> Vec<usize> {
+        self.groups.iter().flat_map(|g| g.iter().skip(1).copied()).collect()
+    }
+
+    /// 选择删除多余行的方式，无法保证保留每组一行时返回 `None`
+    ///
+    /// 主键参与了比较或组内有相同、为空的主键值时，按主键删除会连同保留的行一起删掉。
+    pub fn delete_method(
+        &self,
+        result: &QueryResult,
+        primary_key: Option<usize>,
+        db_type: Option<DatabaseType>,
+    ) -> Option<DedupDelete> {
+        if let Some(pk) = primary_key
+            && self.keys_unique(result, pk)
+        {
+            return Some(DedupDelete::PrimaryKey(pk));
+        }
+        match db_type {
+            Some(db_type @ (DatabaseType::PostgreSQL | DatabaseType::MySQL)) => Some(DedupDelete::Limited(db_type)),
+            _ => None,
+        }
+    }
+
+    /// 每组内的主键值都不为空且互不相同
+    fn keys_unique(&self, result: &QueryResult, pk: usize) -> bool {
+        self.groups.iter().all(|group| {
+            let mut seen = HashSet::new();
+            group.iter().all(|&row| match result.rows.get(row).and_then(|r| r.get(pk)) {
+                Some(value) if value != "NULL" => seen.insert(value.as_str()),
+                _ => false,
+            })
+        })
+    }
+
+    /// 按比较列的值生成删除语句，每组只删除多余的行数
+    pub fn limited_delete_sql(&self, result: &QueryResult, table: &str, db_type: DatabaseType) -> Result<Vec<String>, String> {
+        let table = escape_identifier(table)?;
+        let columns = self
+            .columns
+            .iter()
+            .map(|&c| escape_identifier(result.columns.get(c).map_or("", String::as_str)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut statements = Vec::new();
+        for group in &self.groups {
+            let Some(row) = result.rows.get(group[0]) else {
+                continue;
+            };
+            let condition = self
+                .columns
+                .iter()
+                .zip(&columns)
+                .map(|(&c, name)| match row.get(c).map(String::as_str) {
+                    None | Some("NULL") => format!("{} IS NULL", name),
+                    Some(value) => format!("{} = {}", name, escape_value(value)),
+                })
+                .collect::<Vec<_>>()
+                .join(" AND ");
+            let extra = group.len() - 1;
+            statements.push(match db_type {
+                DatabaseType::PostgreSQL => format!(
+                    "DELETE FROM {} WHERE ctid IN (SELECT ctid FROM {} WHERE {} LIMIT {});",
+                    table, table, condition, extra
+                ),
+                _ => format!("DELETE FROM {} WHERE {} LIMIT {};", table, condition, extra),
+            });
+        }
+        Ok(statements)
+    }
+
+    /// 结果集已变化，分组不再适用
+    pub fn is_stale(&self, result: &QueryResult) -> bool {
+        self.row_count != result.rows.len() || self.columns.iter().any(|&c| c >= result.columns.len())
+    }
+}
@@ -0,0 +1,208 @@
+//! 不同值面板
+//!
+//! 列头菜单打开，在表格右侧列出一列的每个不同值及其出现次数（统计整个结果集），
+//! 勾选值后为该列生成一个“在列表中”的筛选条件（区分大小写），取消全部勾选时删除该条件。
+//! 列表以逗号分隔并去掉首尾空格，空值以及包含逗号或首尾空格的值不能勾选。
+
+use super::filter::{ColumnFilter, FilterOperator};
+use super::state::DataGridState;
+use crate::database::QueryResult;
+use crate::ui::styles::{GRAY, MUTED};
+use egui::{self, RichText};
+use std::collections::{HashMap, HashSet};
+
+/// 一列的不同值和出现次数
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValueFacet {
+    /// 列名
+    pub column: String,
+    /// 不同值和出现次数（按次数从多到少）
+    pub values: Vec<(String, usize)>,
+    /// 统计时的行数
+    row_count: usize,
+    /// 搜索值
+    search: String,
+}
+
+impl ValueFacet {
+    /// 统计一列的不同值
+    pub fn compute(result: &QueryResult, col_idx: usize) -> Self {
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        for row in &result.rows {
+            *counts.entry(row.get(col_idx).map_or("", String::as_str)).or_default() += 1;
+        }
+        let mut values: Vec<(String, usize)> = counts.into_iter().map(|(v, n)| (v.to_string(), n)).collect();
+        values.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        Self {
+            column: result.columns.get(col_idx).cloned().unwrap_or_default(),
+            values,
+            row_count: result.rows.len(),
+            search: String::new(),
+        }
+    }
+
+    /// 结果集已变化，需要重新统计
+    pub fn is_stale(&self, result: &QueryResult) -> bool {
+        self.row_count != result.rows.len() || !result.columns.contains(&self.column)
+    }
+}
+
+/// 值能否写入筛选条件的列表
+pub fn is_facet_selectable(value: &str) -> bool {
+    !value.is_empty() && !value.contains(',') && value.trim() == value
+}
+
+/// 列的“在列表中”筛选条件
+fn facet_filter<'a>(filters: &'a mut [ColumnFilter], column: &str) -> Option<&'a mut ColumnFilter> {
+    filters
+        .iter_mut()
+        .find(|f| f.column == column && f.operator == FilterOperator::In)
+}
+
+/// 列的“在列表中”筛选条件中勾选的值（没有该条件时为空）
+pub fn facet_selection(filters: &[ColumnFilter], column: &str) -> HashSet<String> {
+    filters
+        .iter()
+        .find(|f| f.column == column && f.operator == FilterOperator::In)
+        .map(|f| f.value.split(',').map(str::trim).filter(|v| !v.is_empty()).map(str::to_string).collect())
+        .unwrap_or_default()
+}
+
+/// 勾选或取消勾选一个值，返回筛选条件是否变化
+pub fn set_facet_value(filters: &mut Vec<ColumnFilter>, column: &str, value: &str, selected: bool) -> bool {
+    if !is_facet_selectable(value) {
+        return false;
+    }
+    let mut values: Vec<String> = match facet_filter(filters, column) {
+        Some(filter) => filter.value.split(',').map(str::trim).filter(|v| !v.is_empty()).map(str::to_string).collect(),
+        None => Vec::new(),
+    };
+    let present = values.iter().any(|v| v == value);
+    if present == selected {
+        return false;
+    }
+    if selected {
+        values.push(value.to_string());
+    } else {
+        values.retain(|v| v != value);
+    }
+    set_facet_values(filters, column, &values);
+    true
+}
+
+/// 用勾选的值替换列的筛选条件，没有值时删除该条件
+fn set_facet_values(filters: &mut Vec<ColumnFilter>, column: &str, values: &[String]) {
+    if values.is_empty() {
+        filters.retain(|f| !(f.column == column && f.operator == FilterOperator::In));
+        return;
+    }
+    let value = values.join(", ");
+    match facet_filter(filters, column) {
+        Some(filter) => {
+            filter.value = value;
+            filter.enabled = true;
+            filter.case_sensitive = true;
+        }
+        None => filters.push(
+            ColumnFilter::new(column.to_string())
+                .with_operator(FilterOperator::In)
+                .with_value(value)
+                .with_case_sensitive(true),
+        ),
+    }
+}
+
+/// 在表格右侧显示不同值面板，返回筛选条件是否变化
+pub fn show_facet_panel(ui: &mut egui::Ui, state: &mut DataGridState) -> bool {
+    let Some(facet) = &mut state.value_facet else {
+        return false;
+    };
+
+    let mut changed = false;
+    let mut close = false;
+    egui::SidePanel::right(ui.id().with("value_facet_panel"))
+        .resizable(true)
+        .default_width(220.0)
+        .width_range(160.0..=420.0)
+        .show_inside(ui, |ui| {
+            let selected = facet_selection(&state.filters, &facet.column);
+            ui.horizontal(|ui| {
+                ui.label(RichText::new(format!("不同值 - {}", facet.column)).strong());
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    if ui.small_button("✖").on_hover_text("关闭").clicked() {
+                        close = true;
+                    }
+                });
+            });
+            ui.label(
+                RichText::new(format!("{} 个不同值，已选 {} 个", facet.values.len(), selected.len()))
+                    .small()
+                    .color(GRAY),
+            );
+            let search_response =
+                ui.add(egui::TextEdit::singleline(&mut facet.search).hint_text("搜索值").desired_width(f32::INFINITY));
+            // 输入搜索值时表格不响应快捷键
+            if search_response.has_focus() {
+                state.focused = false;
+            }
+
+            let search = facet.search.to_lowercase();
+            let shown: Vec<&(String, usize)> = facet
+                .values
+                .iter()
+                .filter(|(v, _)| search.is_empty() || v.to_lowercase().contains(&search))
+                .collect();
+
+            ui.horizontal(|ui| {
+                if ui.small_button("全选").on_hover_text("勾选列出的所有值").clicked() {
+                    let mut values: Vec<String> = facet_selection(&state.filters, &facet.column).into_iter().collect();
+                    for (value, _) in shown.iter().filter(|(v, _)| is_facet_selectable(v)) {
+                        if !values.contains(value) {
+                            values.push(value.clone());
+                        }
+                    }
+                    set_facet_values(&mut state.filters, &facet.column, &values);
+                    changed = true;
+                }
+                if ui.add_enabled(!selected.is_empty(), egui::Button::new("清除").small()).clicked() {
+                    set_facet_values(&mut state.filters, &facet.column, &[]);
+                    changed = true;
+                }
+            });
+            ui.separator();
+
+            if shown.is_empty() {
+                ui.label(RichText::new("没有匹配的值").color(MUTED));
+                return;
+            }
+            let row_height = ui.spacing().interact_size.y;
+            egui::ScrollArea::vertical()
+                .auto_shrink([false, false])
+                .show_rows(ui, row_height, shown.len(), |ui, range| {
+                    for (value, count) in &shown[range] {
+                        ui.horizontal(|ui| {
+                            let mut checked = selected.contains(value.as_str());
+                            let text = if value.is_empty() { "(空)" } else { value.as_str() };
+                            let selectable = is_facet_selectable(value);
+                            let response = ui.add_enabled(selectable, egui::Checkbox::new(&mut checked, text));
+                            let response = if selectable {
+                                response
+                            } else {
+                                response.on_disabled_hover_text("空值以及包含逗号或首尾空格的值不能加入列表筛选")
+                            };
+                            if response.changed() {
+                                changed |= set_facet_value(&mut state.filters, &facet.column, value, checked);
+                            }
+                            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                ui.label(RichText::new(count.to_string()).small().monospace().color(GRAY));
+                            });
+                        });
+                    }
+                });
+        });
+
+    if close {
+        state.value_facet = None;
+    }
+    changed
+}
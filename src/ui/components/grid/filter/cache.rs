@@ -1,32 +1,93 @@
 //! 筛选缓存
 //!
-//! 提供筛选结果的缓存机制，避免重复计算。
-//! 对于大数据集（超过 PARALLEL_FILTER_THRESHOLD 行），使用并行处理。
+//! 提供筛选和排序结果的缓存机制，避免重复计算。
+//! 缓存按结果集（代数和行数）、搜索条件、筛选条件的哈希值和排序列失效。
+//! 替换结果集时调用 [`FilterCache::result_replaced`] 递增代数；不比较行数组地址，
+//! 旧结果释放后新结果可能重用同一地址。
+//! 对于大数据集（超过 PARALLEL_FILTER_THRESHOLD 行），使用并行处理；
+//! 超过 BACKGROUND_FILTER_THRESHOLD 行时在后台线程计算，条件再次变化时取消未完成的计算。
 
 use super::condition::ColumnFilter;
 use super::logic::FilterLogic;
 use super::operators::check_filter_match;
+use super::search::{SearchMatcher, SearchOptions};
+use super::super::sort::{sort_indices, ColumnSort};
 use crate::core::constants;
 use crate::database::QueryResult;
 use rayon::prelude::*;
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver};
+use std::sync::Arc;
+
+/// 计算筛选结果时的输入（任一项变化时重新计算）
+#[derive(Debug, Clone, PartialEq)]
+struct FilterKey {
+    search_text: String,
+    search_column: Option<String>,
+    search_options: SearchOptions,
+    filter_hash: u64,
+    sort: Option<ColumnSort>,
+    /// 结果集的行数
+    row_count: usize,
+    /// 结果集的代数（区分行数相同的不同结果）
+    generation: u64,
+}
+
+impl FilterKey {
+    fn new(
+        result: &QueryResult,
+        generation: u64,
+        search_text: &str,
+        search_column: &Option<String>,
+        search_options: &SearchOptions,
+        filters: &[ColumnFilter],
+        sort: Option<&ColumnSort>,
+    ) -> Self {
+        Self {
+            search_text: search_text.to_string(),
+            search_column: search_column.clone(),
+            search_options: search_options.clone(),
+            filter_hash: compute_filter_hash(filters),
+            sort: sort.cloned(),
+            row_count: result.rows.len(),
+            generation,
+        }
+    }
+
+    /// 是否针对同一个结果集
+    fn same_result(&self, other: &Self) -> bool {
+        self.row_count == other.row_count && self.generation == other.generation
+    }
+}
+
+/// 后台进行中的筛选计算（丢弃时取消）
+struct FilterJob {
+    key: FilterKey,
+    cancel: Arc<AtomicBool>,
+    receiver: Receiver<Vec<usize>>,
+}
+
+impl Drop for FilterJob {
+    fn drop(&mut self) {
+        self.cancel.store(true, Ordering::Relaxed);
+    }
+}
 
 /// 筛选缓存
 #[derive(Default)]
 pub struct FilterCache {
     /// 缓存是否有效
     pub valid: bool,
-    /// 上次搜索文本
-    pub last_search_text: String,
-    /// 上次搜索列
-    pub last_search_column: Option<String>,
-    /// 上次筛选条件的哈希值
-    pub last_filter_hash: u64,
-    /// 上次行数
-    pub last_row_count: usize,
-    /// 缓存的筛选后行索引
+    /// 缓存对应的输入
+    key: Option<FilterKey>,
+    /// 缓存的筛选（及排序）后行索引
     pub filtered_indices: Vec<usize>,
+    /// 后台进行中的计算
+    job: Option<FilterJob>,
+    /// 当前结果集的代数
+    generation: u64,
 }
 
 #[allow(dead_code)] // 公开 API，供外部使用
@@ -41,6 +102,18 @@ impl FilterCache {
         self.valid = false;
     }
 
+    /// 结果集已被替换：递增代数，取消针对旧结果的后台计算
+    pub fn result_replaced(&mut self) {
+        self.generation = self.generation.wrapping_add(1);
+        self.valid = false;
+        self.job = None;
+    }
+
+    /// 当前结果集的代数
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
     /// 获取缓存的过滤后行数（如果缓存有效）
     pub fn get_filtered_count(&self) -> Option<usize> {
         if self.valid {
@@ -54,6 +127,31 @@ impl FilterCache {
     pub fn is_valid(&self) -> bool {
         self.valid
     }
+
+    /// 是否正在后台计算
+    pub fn is_computing(&self) -> bool {
+        self.job.is_some()
+    }
+
+    /// 缓存对当前输入是否有效
+    fn matches(&self, key: &FilterKey) -> bool {
+        self.valid && self.key.as_ref() == Some(key)
+    }
+
+    /// 保存计算结果
+    fn store(&mut self, key: FilterKey, indices: Vec<usize>) {
+        self.key = Some(key);
+        self.filtered_indices = indices;
+        self.valid = true;
+    }
+
+    /// 按缓存的索引取出行
+    fn rows<'a>(&self, result: &'a QueryResult) -> Vec<(usize, &'a Vec<String>)> {
+        self.filtered_indices
+            .iter()
+            .filter_map(|&idx| result.rows.get(idx).map(|row| (idx, row)))
+            .collect()
+    }
 }
 
 /// 计算筛选条件的哈希值
@@ -71,70 +169,127 @@ fn compute_filter_hash(filters: &[ColumnFilter]) -> u64 {
     hasher.finish()
 }
 
-/// 带缓存的过滤行数据
+/// 带缓存的过滤行数据（正则表达式无效时忽略搜索条件）
+#[allow(dead_code)] // 公开 API，测试和性能基准使用
 pub fn filter_rows_cached<'a>(
     result: &'a QueryResult,
     search_text: &str,
     search_column: &Option<String>,
+    search_options: &SearchOptions,
     filters: &[ColumnFilter],
     cache: &mut FilterCache,
 ) -> Vec<(usize, &'a Vec<String>)> {
-    let filter_hash = compute_filter_hash(filters);
-    
-    // 检查缓存是否有效
-    let cache_valid = cache.valid
-        && cache.last_search_text == search_text
-        && cache.last_search_column == *search_column
-        && cache.last_filter_hash == filter_hash
-        && cache.last_row_count == result.rows.len();
-    
-    if cache_valid {
-        // 使用缓存的索引构建结果
-        return cache
-            .filtered_indices
-            .iter()
-            .filter_map(|&idx| result.rows.get(idx).map(|row| (idx, row)))
-            .collect();
-    }
-    
-    // 重新计算筛选结果
-    let filtered = filter_rows_internal(result, search_text, search_column, filters);
-    
-    // 更新缓存
-    cache.filtered_indices = filtered.iter().map(|(idx, _)| *idx).collect();
-    cache.last_search_text = search_text.to_string();
-    cache.last_search_column = search_column.clone();
-    cache.last_filter_hash = filter_hash;
-    cache.last_row_count = result.rows.len();
-    cache.valid = true;
-    
-    filtered
+    let key = FilterKey::new(result, cache.generation, search_text, search_column, search_options, filters, None);
+    if !cache.matches(&key) {
+        let indices = compute_indices(result, &key, filters, &AtomicBool::new(false)).unwrap_or_default();
+        cache.store(key, indices);
+    }
+    cache.rows(result)
 }
 
+/// 带缓存的筛选和排序，返回可显示的行及是否仍在后台计算
+///
+/// 行数达到 `BACKGROUND_FILTER_THRESHOLD` 时在后台线程计算，期间继续显示上次的结果
+/// （结果集已变化时按原始顺序显示全部行），计算完成后请求重绘；条件再次变化时取消未完成的计算
+pub fn filter_rows_background<'a>(
+    ctx: &egui::Context,
+    result: &'a Arc<QueryResult>,
+    search_text: &str,
+    search_column: &Option<String>,
+    search_options: &SearchOptions,
+    filters: &[ColumnFilter],
+    sort: Option<&ColumnSort>,
+    cache: &mut FilterCache,
+) -> (Vec<(usize, &'a Vec<String>)>, bool) {
+    let key = FilterKey::new(result, cache.generation, search_text, search_column, search_options, filters, sort);
 
+    // 收取已完成的后台计算
+    if let Some(job) = &cache.job
+        && let Ok(indices) = job.receiver.try_recv()
+        && let Some(job) = cache.job.take()
+    {
+        cache.store(job.key.clone(), indices);
+    }
+
+    if cache.matches(&key) {
+        cache.job = None;
+        return (cache.rows(result), false);
+    }
+
+    if result.rows.len() < constants::database::BACKGROUND_FILTER_THRESHOLD {
+        cache.job = None;
+        let indices = compute_indices(result, &key, filters, &AtomicBool::new(false)).unwrap_or_default();
+        cache.store(key, indices);
+        return (cache.rows(result), false);
+    }
+
+    if cache.job.as_ref().is_none_or(|job| job.key != key) {
+        // 替换旧的计算时将其取消
+        cache.job = Some(spawn_filter_job(ctx.clone(), Arc::clone(result), key.clone(), filters.to_vec()));
+    }
+
+    let rows = match &cache.key {
+        Some(cached) if cache.valid && cached.same_result(&key) => cache.rows(result),
+        _ => result.rows.iter().enumerate().collect(),
+    };
+    (rows, true)
+}
+
+/// 在 rayon 线程池中计算筛选和排序，完成后发送行索引并请求重绘
+fn spawn_filter_job(
+    ctx: egui::Context,
+    result: Arc<QueryResult>,
+    key: FilterKey,
+    filters: Vec<ColumnFilter>,
+) -> FilterJob {
+    let cancel = Arc::new(AtomicBool::new(false));
+    let (sender, receiver) = mpsc::channel();
+    let job_key = key.clone();
+    let job_cancel = Arc::clone(&cancel);
+    rayon::spawn(move || {
+        if let Some(indices) = compute_indices(&result, &job_key, &filters, &job_cancel)
+            && sender.send(indices).is_ok()
+        {
+            ctx.request_repaint();
+        }
+    });
+    FilterJob { key, cancel, receiver }
+}
+
+/// 计算筛选（及排序）后的行索引，取消时返回 `None`
+fn compute_indices(
+    result: &QueryResult,
+    key: &FilterKey,
+    filters: &[ColumnFilter],
+    cancel: &AtomicBool,
+) -> Option<Vec<usize>> {
+    let matcher = SearchMatcher::new(&key.search_text, &key.search_options).ok().flatten();
+    let mut indices = filter_rows_internal(result, matcher.as_ref(), &key.search_column, filters, cancel);
+    if cancel.load(Ordering::Relaxed) {
+        return None;
+    }
+    if let Some(sort) = &key.sort {
+        sort_indices(result, &mut indices, sort);
+        if cancel.load(Ordering::Relaxed) {
+            return None;
+        }
+    }
+    Some(indices)
+}
 
 /// 检查单行是否匹配筛选条件
 fn row_matches_filter(
     row: &[String],
-    search_text: &str,
-    search_lower: &str,
+    matcher: Option<&SearchMatcher>,
     search_col_idx: Option<usize>,
     active_filters: &[&ColumnFilter],
     filter_col_indices: &[Option<usize>],
 ) -> bool {
     // 搜索条件
-    let search_match = if search_text.is_empty() {
-        true
-    } else {
-        match search_col_idx {
-            Some(idx) => row
-                .get(idx)
-                .map(|cell| cell.to_lowercase().contains(search_lower))
-                .unwrap_or(false),
-            None => row
-                .iter()
-                .any(|cell| cell.to_lowercase().contains(search_lower)),
-        }
+    let search_match = match (matcher, search_col_idx) {
+        (None, _) => true,
+        (Some(matcher), Some(idx)) => row.get(idx).is_some_and(|cell| matcher.is_match(cell)),
+        (Some(matcher), None) => row.iter().any(|cell| matcher.is_match(cell)),
     };
 
     if !search_match {
@@ -181,16 +336,15 @@ fn row_matches_filter(
     current_result
 }
 
-/// 过滤行数据（内部实现）
-/// 对于大数据集使用并行处理
-fn filter_rows_internal<'a>(
-    result: &'a QueryResult,
-    search_text: &str,
+/// 过滤行数据（内部实现），返回匹配行的索引
+/// 对于大数据集使用并行处理，取消后不再检查剩余的行
+fn filter_rows_internal(
+    result: &QueryResult,
+    matcher: Option<&SearchMatcher>,
     search_column: &Option<String>,
     filters: &[ColumnFilter],
-) -> Vec<(usize, &'a Vec<String>)> {
-    let search_lower = search_text.to_lowercase();
-
+    cancel: &AtomicBool,
+) -> Vec<usize> {
     // 只使用启用的筛选条件
     let active_filters: Vec<&ColumnFilter> = filters.iter().filter(|f| f.enabled).collect();
 
@@ -205,42 +359,27 @@ fn filter_rows_internal<'a>(
         .map(|f| result.columns.iter().position(|c| c == &f.column))
         .collect();
 
+    let matches = |row: &Vec<String>| {
+        !cancel.load(Ordering::Relaxed)
+            && row_matches_filter(row, matcher, search_col_idx, &active_filters, &filter_col_indices)
+    };
+
     // 对于大数据集使用并行处理
     if result.rows.len() >= constants::database::PARALLEL_FILTER_THRESHOLD {
         result
             .rows
             .par_iter()
             .enumerate()
-            .filter(|(_, row)| {
-                row_matches_filter(
-                    row,
-                    search_text,
-                    &search_lower,
-                    search_col_idx,
-                    &active_filters,
-                    &filter_col_indices,
-                )
-            })
-            .collect::<Vec<_>>()
-            .into_iter()
-            .map(|(idx, row)| (idx, row))
+            .filter(|(_, row)| matches(row))
+            .map(|(idx, _)| idx)
             .collect()
     } else {
         result
             .rows
             .iter()
             .enumerate()
-            .filter(|(_, row)| {
-                row_matches_filter(
-                    row,
-                    search_text,
-                    &search_lower,
-                    search_col_idx,
-                    &active_filters,
-                    &filter_col_indices,
-                )
-            })
+            .filter(|(_, row)| matches(row))
+            .map(|(idx, _)| idx)
             .collect()
     }
 }
-
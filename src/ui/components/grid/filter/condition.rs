@@ -4,6 +4,7 @@
 
 use super::logic::FilterLogic;
 use super::operators::FilterOperator;
+use crate::core::SavedFilter;
 
 /// 列筛选条件
 #[derive(Clone)]
@@ -94,5 +95,42 @@ impl ColumnFilter {
         
         true
     }
-}
 
+    /// 条件的文字描述（如 `age 介于 18 和 30`），用于报表等只读展示
+    pub fn describe(&self) -> String {
+        let case = if self.case_sensitive && self.operator.supports_case_sensitivity() { "（区分大小写）" } else { "" };
+        if self.operator.needs_second_value() {
+            format!("{} {} {} 和 {}{}", self.column, self.operator.display_name(), self.value, self.value2, case)
+        } else if self.operator.needs_value() {
+            format!("{} {} {}{}", self.column, self.operator.display_name(), self.value, case)
+        } else {
+            format!("{} {}", self.column, self.operator.display_name())
+        }
+    }
+
+    /// 转换为可保存的筛选条件
+    pub fn to_saved(&self) -> SavedFilter {
+        SavedFilter {
+            column: self.column.clone(),
+            operator: self.operator.id().to_string(),
+            value: self.value.clone(),
+            value2: self.value2.clone(),
+            enabled: self.enabled,
+            case_sensitive: self.case_sensitive,
+            or_next: self.logic == FilterLogic::Or,
+        }
+    }
+
+    /// 从保存的筛选条件恢复（未知的操作符按默认操作符处理）
+    pub fn from_saved(saved: &SavedFilter) -> Self {
+        Self {
+            column: saved.column.clone(),
+            operator: FilterOperator::from_id(&saved.operator).unwrap_or_default(),
+            value: saved.value.clone(),
+            value2: saved.value2.clone(),
+            enabled: saved.enabled,
+            case_sensitive: saved.case_sensitive,
+            logic: if saved.or_next { FilterLogic::Or } else { FilterLogic::And },
+        }
+    }
+}
@@ -6,11 +6,16 @@ mod cache;
 mod condition;
 mod logic;
 mod operators;
+mod search;
+mod sql;
 mod ui;
 
 // 重新导出公共接口
-pub use cache::{filter_rows_cached, FilterCache};
+pub use cache::{filter_rows_background, filter_rows_cached, FilterCache};
 pub use condition::ColumnFilter;
 pub use logic::FilterLogic;
 pub use operators::{check_filter_match, FilterOperator};
+pub use search::{SearchMatcher, SearchOptions};
+pub use sql::filters_to_where;
+pub(crate) use sql::string_literal;
 pub use ui::show_filter_bar;
@@ -34,6 +34,9 @@ pub enum FilterOperator {
     
     // 正则
     Regex,
+
+    // JSON 路径（如 `$.user.id = 5`）
+    JsonPath,
 }
 
 impl FilterOperator {
@@ -59,6 +62,7 @@ impl FilterOperator {
             Self::IsEmpty => "为空字符串",
             Self::IsNotEmpty => "非空字符串",
             Self::Regex => "正则匹配",
+            Self::JsonPath => "JSON 路径",
         }
     }
 
@@ -84,9 +88,41 @@ impl FilterOperator {
             Self::IsEmpty => "''",
             Self::IsNotEmpty => "!''",
             Self::Regex => "/.*/",
+            Self::JsonPath => "$.",
+        }
+    }
+
+    /// 稳定的标识符（保存筛选预设时使用）
+    pub fn id(&self) -> &'static str {
+        match self {
+            Self::Contains => "contains",
+            Self::NotContains => "not_contains",
+            Self::Equals => "equals",
+            Self::NotEquals => "not_equals",
+            Self::StartsWith => "starts_with",
+            Self::EndsWith => "ends_with",
+            Self::GreaterThan => "gt",
+            Self::GreaterOrEqual => "ge",
+            Self::LessThan => "lt",
+            Self::LessOrEqual => "le",
+            Self::Between => "between",
+            Self::NotBetween => "not_between",
+            Self::In => "in",
+            Self::NotIn => "not_in",
+            Self::IsNull => "is_null",
+            Self::IsNotNull => "is_not_null",
+            Self::IsEmpty => "is_empty",
+            Self::IsNotEmpty => "is_not_empty",
+            Self::Regex => "regex",
+            Self::JsonPath => "json_path",
         }
     }
 
+    /// 根据标识符查找操作符
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::all().into_iter().find(|op| op.id() == id)
+    }
+
     /// 是否需要输入值
     pub fn needs_value(&self) -> bool {
         !matches!(
@@ -135,7 +171,6 @@ impl FilterOperator {
     }
 
     /// 获取所有操作符
-    #[allow(dead_code)] // 公开 API，供外部使用
     pub fn all() -> Vec<FilterOperator> {
         let mut ops = Vec::new();
         ops.extend_from_slice(Self::text_operators());
@@ -143,6 +178,7 @@ impl FilterOperator {
         ops.extend_from_slice(Self::set_operators());
         ops.extend_from_slice(Self::null_operators());
         ops.push(Self::Regex);
+        ops.push(Self::JsonPath);
         ops
     }
 
@@ -235,6 +271,9 @@ pub fn check_filter_match(
                 }
             }
         }
+
+        FilterOperator::JsonPath => crate::core::JsonPathCondition::parse(value)
+            .is_ok_and(|condition| condition.matches(cell)),
     }
 }
 
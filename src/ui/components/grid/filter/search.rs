@@ -0,0 +1,54 @@
+//! 结果搜索匹配
+//!
+//! 搜索栏的关键字按选项编译为正则表达式（普通文本会先转义），
+//! 同时用于筛选行、高亮单元格中的匹配片段和 n/N 跳转。
+
+use regex::{Regex, RegexBuilder};
+use std::ops::Range;
+
+/// 搜索选项
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SearchOptions {
+    /// 按正则表达式匹配
+    pub regex: bool,
+    /// 全词匹配
+    pub whole_word: bool,
+    /// 区分大小写
+    pub case_sensitive: bool,
+}
+
+/// 编译后的搜索条件
+#[derive(Clone, Debug)]
+pub struct SearchMatcher {
+    regex: Regex,
+}
+
+impl SearchMatcher {
+    /// 编译搜索条件，关键字为空时返回 `None`，正则表达式无效时返回错误
+    pub fn new(text: &str, options: &SearchOptions) -> Result<Option<Self>, String> {
+        if text.is_empty() {
+            return Ok(None);
+        }
+        let pattern = if options.regex { text.to_string() } else { regex::escape(text) };
+        let pattern = if options.whole_word { format!(r"\b(?:{})\b", pattern) } else { pattern };
+        RegexBuilder::new(&pattern)
+            .case_insensitive(!options.case_sensitive)
+            .build()
+            .map(|regex| Some(Self { regex }))
+            .map_err(|e| e.to_string())
+    }
+
+    /// 文本是否匹配
+    pub fn is_match(&self, text: &str) -> bool {
+        self.regex.is_match(text)
+    }
+
+    /// 文本中所有匹配片段的字节范围（忽略空匹配）
+    pub fn find_ranges(&self, text: &str) -> Vec<Range<usize>> {
+        self.regex
+            .find_iter(text)
+            .filter(|m| !m.is_empty())
+            .map(|m| m.range())
+            .collect()
+    }
+}
@@ -0,0 +1,336 @@
+//! 筛选条件转换为 SQL
+//!
+//! 将启用的筛选条件按当前数据库方言转换为 WHERE 子句，用于在服务端重新查询
+//! （客户端筛选只作用于已读取的行）。条件按从左到右的顺序组合 AND/OR，
+//! 与客户端筛选的求值顺序一致；取反类条件包含 NULL 值，与客户端把 NULL
+//! 当作文本比较的结果一致。
+
+use super::condition::ColumnFilter;
+use super::logic::FilterLogic;
+use super::operators::FilterOperator;
+use crate::core::{json_scalar_text, JsonCompare, JsonPath, JsonPathCondition, JsonPathSegment};
+use crate::database::DatabaseType;
+use crate::ui::components::grid::quote_identifier;
+
+/// LIKE 的转义字符（ClickHouse 不支持 ESCAPE 子句，使用反斜杠）
+const LIKE_ESCAPE: char = '!';
+
+/// 文本匹配位置
+#[derive(Clone, Copy)]
+enum LikeKind {
+    Contains,
+    StartsWith,
+    EndsWith,
+}
+
+/// 将启用且有效的筛选条件转换为 WHERE 子句（不含 `WHERE`），没有可用条件时返回 `None`
+pub fn filters_to_where(filters: &[ColumnFilter], db_type: DatabaseType) -> Result<Option<String>, String> {
+    let mut clause: Option<String> = None;
+    let mut pending_logic = FilterLogic::And;
+    for filter in filters.iter().filter(|f| f.enabled && f.is_valid()) {
+        let condition = filter_condition(filter, db_type)?;
+        clause = Some(match clause {
+            None => condition,
+            Some(prev) => {
+                let op = match pending_logic {
+                    FilterLogic::And => "AND",
+                    FilterLogic::Or => "OR",
+                };
+                format!("({}) {} ({})", prev, op, condition)
+            }
+        });
+        pending_logic = filter.logic;
+    }
+    Ok(clause)
+}
+
+/// 单个筛选条件
+fn filter_condition(filter: &ColumnFilter, db_type: DatabaseType) -> Result<String, String> {
+    let col = quote_identifier(&filter.column, db_type == DatabaseType::MySQL)?;
+    let value = filter.value.as_str();
+    let sensitive = filter.case_sensitive;
+    let text = text_expr(&col, db_type);
+
+    Ok(match filter.operator {
+        FilterOperator::Contains => like(&col, value, LikeKind::Contains, sensitive, db_type),
+        FilterOperator::StartsWith => like(&col, value, LikeKind::StartsWith, sensitive, db_type),
+        FilterOperator::EndsWith => like(&col, value, LikeKind::EndsWith, sensitive, db_type),
+        FilterOperator::NotContains => {
+            or_null(&col, &format!("NOT ({})", like(&col, value, LikeKind::Contains, sensitive, db_type)))
+        }
+        FilterOperator::Equals => equals(&col, value, sensitive, db_type),
+        FilterOperator::NotEquals => or_null(&col, &format!("NOT ({})", equals(&col, value, sensitive, db_type))),
+        FilterOperator::GreaterThan => format!("{} > {}", col, literal(value, db_type)),
+        FilterOperator::GreaterOrEqual => format!("{} >= {}", col, literal(value, db_type)),
+        FilterOperator::LessThan => format!("{} < {}", col, literal(value, db_type)),
+        FilterOperator::LessOrEqual => format!("{} <= {}", col, literal(value, db_type)),
+        FilterOperator::Between => between(&col, filter, db_type),
+        FilterOperator::NotBetween => or_null(&col, &format!("NOT ({})", between(&col, filter, db_type))),
+        FilterOperator::In => in_list(&col, value, sensitive, db_type),
+        FilterOperator::NotIn => or_null(&col, &format!("NOT ({})", in_list(&col, value, sensitive, db_type))),
+        FilterOperator::IsNull => format!("{} IS NULL", col),
+        FilterOperator::IsNotNull => format!("{} IS NOT NULL", col),
+        FilterOperator::IsEmpty => format!("({} IS NULL OR {} = '')", col, text),
+        // Oracle 把空字符串当作 NULL，`<> ''` 永远不成立
+        FilterOperator::IsNotEmpty if db_type == DatabaseType::Oracle => format!("{} IS NOT NULL", col),
+        FilterOperator::IsNotEmpty => format!("({} IS NOT NULL AND {} <> '')", col, text),
+        FilterOperator::Regex => regex(&text, value, db_type)?,
+        FilterOperator::JsonPath => json_path(&col, value, db_type)?,
+    })
+}
+
+/// 列转换为文本的表达式
+fn text_expr(col: &str, db_type: DatabaseType) -> String {
+    match db_type {
+        DatabaseType::MySQL => format!("CAST({} AS CHAR)", col),
+        DatabaseType::DuckDB => format!("CAST({} AS VARCHAR)", col),
+        DatabaseType::Oracle => format!("TO_CHAR({})", col),
+        DatabaseType::ClickHouse => format!("toString({})", col),
+        DatabaseType::Odbc => format!("CAST({} AS VARCHAR(4000))", col),
+        _ => format!("CAST({} AS TEXT)", col),
+    }
+}
+
+/// 区分大小写比较时使用的列表达式（MySQL 的默认排序规则不区分大小写）
+fn sensitive_expr(col: &str, db_type: DatabaseType) -> String {
+    match db_type {
+        DatabaseType::MySQL => format!("CAST({} AS BINARY)", col),
+        _ => text_expr(col, db_type),
+    }
+}
+
+/// 字符串字面量（MySQL 和 ClickHouse 的反斜杠也是转义符）
+pub(crate) fn string_literal(value: &str, db_type: DatabaseType) -> String {
+    let value = match db_type {
+        DatabaseType::MySQL | DatabaseType::ClickHouse => value.replace('\\', "\\\\"),
+        _ => value.to_string(),
+    };
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+/// 是否为数字，数字按文本比较时不需要转换大小写（`inf`、`NaN` 等不视为数字）
+fn is_number(value: &str) -> bool {
+    let value = value.trim();
+    !value.is_empty()
+        && value.chars().all(|c| c.is_ascii_digit() || matches!(c, '.' | '-' | '+' | 'e' | 'E'))
+        && value.parse::<f64>().is_ok_and(f64::is_finite)
+}
+
+/// 比较用的字面量，数字去掉首尾空白
+///
+/// 数字也作为字符串字面量：未指定类型的字面量会转换为列的类型，文本列不会因和数字比较而出错
+fn literal(value: &str, db_type: DatabaseType) -> String {
+    let value = if is_number(value) { value.trim() } else { value };
+    string_literal(value, db_type)
+}
+
+/// 取反条件同时匹配 NULL
+fn or_null(col: &str, condition: &str) -> String {
+    format!("({} IS NULL OR {})", col, condition)
+}
+
+/// 文本包含、开头、结尾匹配
+fn like(col: &str, value: &str, kind: LikeKind, case_sensitive: bool, db_type: DatabaseType) -> String {
+    // SQLite 的 LIKE 不区分大小写，区分时使用 GLOB
+    if case_sensitive && db_type == DatabaseType::SQLite {
+        let escaped: String = value
+            .chars()
+            .map(|c| match c {
+                '*' | '?' | '[' => format!("[{}]", c),
+                _ => c.to_string(),
+            })
+            .collect();
+        let pattern = match kind {
+            LikeKind::Contains => format!("*{}*", escaped),
+            LikeKind::StartsWith => format!("{}*", escaped),
+            LikeKind::EndsWith => format!("*{}", escaped),
+        };
+        return format!("{} GLOB {}", text_expr(col, db_type), string_literal(&pattern, db_type));
+    }
+
+    let (expr, value) = if case_sensitive {
+        (sensitive_expr(col, db_type), value.to_string())
+    } else {
+        (format!("LOWER({})", text_expr(col, db_type)), value.to_lowercase())
+    };
+    let escape = if db_type == DatabaseType::ClickHouse { '\\' } else { LIKE_ESCAPE };
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if c == '%' || c == '_' || c == escape {
+            escaped.push(escape);
+        }
+        escaped.push(c);
+    }
+    let pattern = match kind {
+        LikeKind::Contains => format!("%{}%", escaped),
+        LikeKind::StartsWith => format!("{}%", escaped),
+        LikeKind::EndsWith => format!("%{}", escaped),
+    };
+    let escape_clause = if db_type == DatabaseType::ClickHouse {
+        String::new()
+    } else {
+        format!(" ESCAPE '{}'", LIKE_ESCAPE)
+    };
+    format!("{} LIKE {}{}", expr, string_literal(&pattern, db_type), escape_clause)
+}
+
+/// 等于：与客户端筛选一致按文本比较（保留前导零），文本按大小写设置比较
+fn equals(col: &str, value: &str, case_sensitive: bool, db_type: DatabaseType) -> String {
+    if is_number(value) {
+        format!("{} = {}", text_expr(col, db_type), literal(value, db_type))
+    } else if case_sensitive {
+        format!("{} = {}", sensitive_expr(col, db_type), string_literal(value, db_type))
+    } else {
+        format!("LOWER({}) = {}", text_expr(col, db_type), string_literal(&value.to_lowercase(), db_type))
+    }
+}
+
+/// 介于两个值之间（包含边界）
+fn between(col: &str, filter: &ColumnFilter, db_type: DatabaseType) -> String {
+    format!(
+        "{} BETWEEN {} AND {}",
+        col,
+        literal(&filter.value, db_type),
+        literal(&filter.value2, db_type)
+    )
+}
+
+/// 在逗号分隔的列表中
+fn in_list(col: &str, value: &str, case_sensitive: bool, db_type: DatabaseType) -> String {
+    let values: Vec<&str> = value.split(',').map(str::trim).collect();
+    if values.iter().all(|v| is_number(v)) {
+        let items: Vec<String> = values.iter().map(|v| literal(v, db_type)).collect();
+        return format!("{} IN ({})", text_expr(col, db_type), items.join(", "));
+    }
+    let (expr, items): (String, Vec<String>) = if case_sensitive {
+        (sensitive_expr(col, db_type), values.iter().map(|v| string_literal(v, db_type)).collect())
+    } else {
+        (
+            format!("LOWER({})", text_expr(col, db_type)),
+            values.iter().map(|v| string_literal(&v.to_lowercase(), db_type)).collect(),
+        )
+    };
+    format!("{} IN ({})", expr, items.join(", "))
+}
+
+/// 正则匹配（与客户端一致，区分大小写）
+fn regex(text: &str, pattern: &str, db_type: DatabaseType) -> Result<String, String> {
+    let pattern_literal = string_literal(pattern, db_type);
+    match db_type {
+        DatabaseType::PostgreSQL => Ok(format!("{} ~ {}", text, pattern_literal)),
+        DatabaseType::MySQL => Ok(format!("REGEXP_LIKE({}, {}, 'c')", text, pattern_literal)),
+        DatabaseType::DuckDB => Ok(format!("regexp_matches({}, {})", text, pattern_literal)),
+        DatabaseType::Oracle => Ok(format!("REGEXP_LIKE({}, {}, 'c')", text, pattern_literal)),
+        DatabaseType::ClickHouse => Ok(format!("match({}, {})", text, pattern_literal)),
+        _ => Err(format!("{} 不支持在服务端按正则筛选", db_type.display_name())),
+    }
+}
+
+/// 路径取值的各种表达式
+struct JsonPathExprs {
+    /// 路径存在
+    exists: String,
+    /// 取值为文本（JSON null 为 SQL NULL）
+    text: String,
+    /// 取值为数字（不是数字时为 NULL）
+    number: String,
+    /// 取值是 JSON null
+    is_null: String,
+}
+
+/// JSON 路径条件，按各数据库的 JSON 函数取值后比较
+fn json_path(col: &str, value: &str, db_type: DatabaseType) -> Result<String, String> {
+    let condition = JsonPathCondition::parse(value)?;
+    let exprs = json_path_exprs(col, &condition.path, db_type)?;
+    let op = condition.compare.sql_operator();
+    let (left, right) = match &condition.value {
+        serde_json::Value::Null => {
+            return match condition.compare {
+                JsonCompare::Exists => Ok(exprs.exists),
+                JsonCompare::Eq => Ok(exprs.is_null),
+                JsonCompare::Ne => Ok(format!("NOT ({})", exprs.is_null)),
+                _ => Err("null 只能用 = 或 != 比较".to_string()),
+            };
+        }
+        serde_json::Value::Number(n) => (exprs.number, n.to_string()),
+        // SQLite 的 json_extract 把布尔值返回为 1/0
+        serde_json::Value::Bool(b) if db_type == DatabaseType::SQLite => (exprs.text, (*b as u8).to_string()),
+        other => (exprs.text, string_literal(&json_scalar_text(other), db_type)),
+    };
+    Ok(match condition.compare {
+        JsonCompare::Exists => exprs.exists,
+        JsonCompare::Ne => or_null(&left, &format!("{} {} {}", left, op, right)),
+        _ => format!("{} {} {}", left, op, right),
+    })
+}
+
+/// 各数据库提取 JSON 路径值的表达式
+fn json_path_exprs(col: &str, path: &JsonPath, db_type: DatabaseType) -> Result<JsonPathExprs, String> {
+    let sql_path = string_literal(&path.to_sql_path(), db_type);
+    Ok(match db_type {
+        DatabaseType::PostgreSQL => {
+            let elements: Vec<String> = path
+                .segments
+                .iter()
+                .map(|segment| match segment {
+                    JsonPathSegment::Key(key) => format!("\"{}\"", key.replace('\\', "\\\\").replace('"', "\\\"")),
+                    JsonPathSegment::Index(idx) => idx.to_string(),
+                })
+                .collect();
+            let pg_path = string_literal(&format!("{{{}}}", elements.join(",")), db_type);
+            let json = format!("CAST({} AS JSONB)", col);
+            let node = format!("({} #> {})", json, pg_path);
+            JsonPathExprs {
+                exists: format!("{} IS NOT NULL", node),
+                text: format!("({} #>> {})", json, pg_path),
+                number: format!(
+                    "CASE WHEN jsonb_typeof({}) = 'number' THEN CAST({} #>> {} AS NUMERIC) END",
+                    node, json, pg_path
+                ),
+                is_null: format!("jsonb_typeof({}) = 'null'", node),
+            }
+        }
+        DatabaseType::MySQL => {
+            let extract = format!("JSON_EXTRACT({}, {})", col, sql_path);
+            JsonPathExprs {
+                exists: format!("JSON_CONTAINS_PATH({}, 'one', {})", col, sql_path),
+                text: format!("JSON_UNQUOTE({})", extract),
+                number: format!(
+                    "CASE WHEN JSON_TYPE({}) IN ('INTEGER', 'UNSIGNED INTEGER', 'DOUBLE', 'DECIMAL') THEN {} END",
+                    extract, extract
+                ),
+                is_null: format!("JSON_TYPE({}) = 'NULL'", extract),
+            }
+        }
+        DatabaseType::SQLite => {
+            let kind = format!("json_type({}, {})", col, sql_path);
+            let extract = format!("json_extract({}, {})", col, sql_path);
+            JsonPathExprs {
+                exists: format!("{} IS NOT NULL", kind),
+                number: format!("CASE WHEN {} IN ('integer', 'real') THEN {} END", kind, extract),
+                text: extract,
+                is_null: format!("{} = 'null'", kind),
+            }
+        }
+        DatabaseType::DuckDB => {
+            let extract = format!("json_extract_string({}, {})", col, sql_path);
+            JsonPathExprs {
+                exists: format!("json_exists({}, {})", col, sql_path),
+                number: format!("TRY_CAST({} AS DOUBLE)", extract),
+                text: extract,
+                is_null: format!("json_type({}, {}) = 'NULL'", col, sql_path),
+            }
+        }
+        DatabaseType::Oracle => {
+            let text = format!("JSON_VALUE({}, {})", col, sql_path);
+            let exists = format!("JSON_EXISTS({}, {})", col, sql_path);
+            JsonPathExprs {
+                is_null: format!("({} AND {} IS NULL)", exists, text),
+                number: format!("JSON_VALUE({}, {} RETURNING NUMBER NULL ON ERROR)", col, sql_path),
+                exists,
+                text,
+            }
+        }
+        _ => return Err(format!("{} 不支持在服务端按 JSON 路径筛选", db_type.display_name())),
+    })
+}
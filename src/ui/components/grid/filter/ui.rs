@@ -4,6 +4,7 @@
 
 use super::condition::ColumnFilter;
 use crate::database::QueryResult;
+use crate::ui::components::grid::DataGridActions;
 use crate::ui::styles::GRAY;
 use egui::{self, Color32, RichText};
 
@@ -15,11 +16,14 @@ pub struct FilterBarState {
 
 /// 显示筛选状态栏（简洁版，只显示筛选数量）
 /// 
-/// 返回是否有修改（用于使缓存失效）
+/// 返回是否有修改（用于使缓存失效）。`server_side` 为 true 时（结果来自单表）
+/// 显示“服务端筛选”（将筛选条件转换为 WHERE 子句重新查询）和“批量修改”
 pub fn show_filter_bar(
     ui: &mut egui::Ui,
     _result: &QueryResult,
     filters: &mut Vec<ColumnFilter>,
+    actions: &mut DataGridActions,
+    server_side: bool,
 ) -> bool {
     if filters.is_empty() {
         return false;
@@ -46,6 +50,30 @@ pub fn show_filter_bar(
         {
             filters.clear();
         }
+
+        // 服务端筛选
+        if server_side
+            && enabled_count > 0
+            && ui
+                .add(egui::Label::new(RichText::new("服务端筛选").size(11.0).color(GRAY)).sense(egui::Sense::click()))
+                .on_hover_text("将筛选条件转换为 WHERE 子句，在数据库中重新查询整张表")
+                .on_hover_cursor(egui::CursorIcon::PointingHand)
+                .clicked()
+        {
+            actions.apply_filters_server_side = true;
+        }
+
+        // 批量修改
+        if server_side
+            && enabled_count > 0
+            && ui
+                .add(egui::Label::new(RichText::new("批量修改").size(11.0).color(GRAY)).sense(egui::Sense::click()))
+                .on_hover_text("将筛选条件匹配的所有行的某一列设置为新值或表达式")
+                .on_hover_cursor(egui::CursorIcon::PointingHand)
+                .clicked()
+        {
+            actions.bulk_edit = true;
+        }
     });
     
     filters.len() != initial_count
@@ -0,0 +1,123 @@
+//! 几何图形单元格预览
+//!
+//! PostGIS、MySQL 等的空间值（WKT/WKB）在表格中显示为 WKT 摘要，
+//! 悬停时显示缩略图，右键可打开查看窗口：按比例绘制点、线、多边形，并列出坐标。
+
+use crate::core::{parse_geometry, Coord, Geometry, SpatialValue};
+use crate::ui::styles::GRAY;
+use egui::{self, Color32, Pos2, Sense, Shape, Stroke, Vec2};
+
+/// 图形四周留白
+const CANVAS_MARGIN: f32 = 8.0;
+
+const COLOR_CANVAS_BG: Color32 = Color32::from_rgb(30, 34, 40);
+const COLOR_SHAPE_STROKE: Color32 = Color32::from_rgb(90, 180, 230);
+const COLOR_POINT: Color32 = Color32::from_rgb(240, 130, 80);
+
+/// 正在查看的几何值
+#[derive(Debug, Clone)]
+pub struct GeometryViewer {
+    /// 窗口标题（列名和行号）
+    pub title: String,
+    /// 解析后的值
+    pub value: SpatialValue,
+    /// 坐标列表
+    pub pretty: String,
+}
+
+impl GeometryViewer {
+    /// 解析单元格内容，不是几何值时返回 `None`
+    pub fn open(title: String, cell: &str) -> Option<Self> {
+        let value = parse_geometry(cell)?;
+        let pretty = value.pretty();
+        Some(Self { title, value, pretty })
+    }
+}
+
+/// 二进制形式（WKB 十六进制或 `\x...`）的几何值在表格中显示的 WKT 摘要
+pub fn cell_summary(cell: &str) -> Option<String> {
+    if !matches!(cell.as_bytes().first(), Some(b'0' | b'\\')) {
+        return None;
+    }
+    parse_geometry(cell).map(|v| v.geometry.to_wkt())
+}
+
+/// 在指定大小的区域内按比例绘制图形（保持纵横比，Y 轴向上）
+pub fn draw_geometry(ui: &mut egui::Ui, geometry: &Geometry, size: Vec2) -> egui::Response {
+    let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+    let painter = ui.painter_at(rect);
+    painter.rect_filled(rect, 4.0, COLOR_CANVAS_BG);
+
+    let Some(bounds) = geometry.bounds() else {
+        painter.text(rect.center(), egui::Align2::CENTER_CENTER, "EMPTY", egui::FontId::monospace(11.0), GRAY);
+        return response;
+    };
+    let inner = rect.shrink(CANVAS_MARGIN);
+    let width = (bounds.max.x - bounds.min.x) as f32;
+    let height = (bounds.max.y - bounds.min.y) as f32;
+    let scale = match (width > 0.0, height > 0.0) {
+        (true, true) => (inner.width() / width).min(inner.height() / height),
+        (true, false) => inner.width() / width,
+        (false, true) => inner.height() / height,
+        (false, false) => 1.0,
+    };
+    let center = Coord {
+        x: (bounds.min.x + bounds.max.x) / 2.0,
+        y: (bounds.min.y + bounds.max.y) / 2.0,
+    };
+    let to_screen = |c: &Coord| -> Pos2 {
+        inner.center() + Vec2::new((c.x - center.x) as f32 * scale, -((c.y - center.y) as f32) * scale)
+    };
+
+    let mut shapes = Vec::new();
+    collect_shapes(geometry, &to_screen, &mut shapes);
+    painter.extend(shapes);
+    response
+}
+
+fn collect_shapes(geometry: &Geometry, to_screen: &impl Fn(&Coord) -> Pos2, shapes: &mut Vec<Shape>) {
+    let stroke = Stroke::new(1.5, COLOR_SHAPE_STROKE);
+    let line = |coords: &[Coord]| Shape::line(coords.iter().map(to_screen).collect(), stroke);
+    let ring = |coords: &[Coord]| Shape::closed_line(coords.iter().map(to_screen).collect(), stroke);
+    let point = |c: &Coord| Shape::circle_filled(to_screen(c), 3.0, COLOR_POINT);
+    match geometry {
+        Geometry::Point(p) => shapes.extend(p.iter().map(point)),
+        Geometry::MultiPoint(points) => shapes.extend(points.iter().map(point)),
+        Geometry::LineString(coords) => shapes.push(line(coords)),
+        Geometry::MultiLineString(parts) => shapes.extend(parts.iter().map(|p| line(p))),
+        Geometry::Polygon(rings) => shapes.extend(rings.iter().map(|r| ring(r))),
+        Geometry::MultiPolygon(polygons) => shapes.extend(polygons.iter().flatten().map(|r| ring(r))),
+        Geometry::Collection(items) => {
+            for item in items {
+                collect_shapes(item, to_screen, shapes);
+            }
+        }
+    }
+}
+
+/// 悬停预览：缩略图和类型
+pub fn show_hover(ui: &mut egui::Ui, value: &SpatialValue) {
+    draw_geometry(ui, &value.geometry, Vec2::new(180.0, 120.0));
+    let points = value.geometry.coords().len();
+    let srid = value.srid.map(|s| format!("，SRID {}", s)).unwrap_or_default();
+    ui.label(egui::RichText::new(format!("{}（{} 个点{}）", value.geometry.type_name(), points, srid)).small().color(GRAY));
+}
+
+/// 查看窗口内容：左侧图形，右侧坐标列表
+pub fn show_viewer(ui: &mut egui::Ui, viewer: &GeometryViewer) -> Option<String> {
+    let mut copied = None;
+    ui.horizontal(|ui| {
+        if ui.button("📋 复制 WKT").clicked() {
+            copied = Some(viewer.value.to_ewkt());
+        }
+    });
+    ui.separator();
+    ui.horizontal_top(|ui| {
+        let side = ui.available_height().clamp(160.0, 360.0);
+        draw_geometry(ui, &viewer.value.geometry, Vec2::splat(side));
+        egui::ScrollArea::both().id_salt("geometry_coords").auto_shrink([false, false]).show(ui, |ui| {
+            ui.add(egui::Label::new(egui::RichText::new(&viewer.pretty).monospace()).extend());
+        });
+    });
+    copied
+}
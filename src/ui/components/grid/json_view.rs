@@ -0,0 +1,128 @@
+//! JSON 单元格查看器
+//!
+//! 自动识别 JSON 列（列头显示 `{}` 标记），单元格右键可打开查看窗口：
+//! 可展开的树形视图或格式化文本，并能复制格式化后的内容。
+
+use crate::core::{detect_json_columns, json_scalar_text};
+use crate::database::QueryResult;
+use crate::ui::styles::GRAY;
+use egui::{self, Color32, RichText};
+use serde_json::Value;
+
+/// 悬停预览最多显示的行数
+const HOVER_PREVIEW_LINES: usize = 30;
+
+// 树形视图的键和值颜色
+const COLOR_JSON_KEY: Color32 = Color32::from_rgb(130, 170, 220);
+const COLOR_JSON_STRING: Color32 = Color32::from_rgb(150, 200, 120);
+const COLOR_JSON_NUMBER: Color32 = Color32::from_rgb(220, 170, 100);
+const COLOR_JSON_LITERAL: Color32 = Color32::from_rgb(190, 140, 220);
+
+/// 识别出的 JSON 列（行数或列数变化后重新检测）
+#[derive(Debug, Clone, Default)]
+pub struct JsonColumns {
+    columns: Vec<bool>,
+    row_count: usize,
+}
+
+impl JsonColumns {
+    /// 检测结果集中的 JSON 列
+    pub fn detect(result: &QueryResult) -> Self {
+        Self {
+            columns: detect_json_columns(result),
+            row_count: result.rows.len(),
+        }
+    }
+
+    /// 列是否为 JSON 列
+    pub fn contains(&self, col: usize) -> bool {
+        self.columns.get(col).copied().unwrap_or(false)
+    }
+
+    /// 结果集已变化，需要重新检测
+    pub fn is_stale(&self, result: &QueryResult) -> bool {
+        self.row_count != result.rows.len() || self.columns.len() != result.columns.len()
+    }
+}
+
+/// 正在查看的 JSON 值
+#[derive(Debug, Clone)]
+pub struct JsonViewer {
+    /// 窗口标题（列名和行号）
+    pub title: String,
+    /// 解析后的值
+    pub value: Value,
+    /// 格式化后的文本
+    pub pretty: String,
+    /// 显示格式化文本而不是树形视图
+    pub show_text: bool,
+}
+
+impl JsonViewer {
+    /// 解析单元格内容，不是 JSON 时返回 `None`
+    pub fn open(title: String, cell: &str) -> Option<Self> {
+        let value: Value = serde_json::from_str(cell.trim()).ok()?;
+        let pretty = serde_json::to_string_pretty(&value).ok()?;
+        Some(Self {
+            title,
+            value,
+            pretty,
+            show_text: false,
+        })
+    }
+}
+
+/// 悬停时显示的格式化 JSON（过长时截断）
+pub fn hover_preview(pretty: &str) -> String {
+    let lines: Vec<&str> = pretty.lines().collect();
+    if lines.len() <= HOVER_PREVIEW_LINES {
+        return pretty.to_string();
+    }
+    format!(
+        "{}\n… 还有 {} 行，右键「查看 JSON」",
+        lines[..HOVER_PREVIEW_LINES].join("\n"),
+        lines.len() - HOVER_PREVIEW_LINES
+    )
+}
+
+/// 树形显示 JSON 值，对象和数组可展开（第一层默认展开）
+pub fn show_json_tree(ui: &mut egui::Ui, key: &str, value: &Value, depth: usize) {
+    match value {
+        Value::Object(map) => {
+            egui::CollapsingHeader::new(RichText::new(format!("{}  {{{}}}", key, map.len())).monospace().color(COLOR_JSON_KEY))
+                .id_salt(key)
+                .default_open(depth == 0)
+                .show(ui, |ui| {
+                    for (child_key, child) in map {
+                        show_json_tree(ui, child_key, child, depth + 1);
+                    }
+                });
+        }
+        Value::Array(items) => {
+            egui::CollapsingHeader::new(RichText::new(format!("{}  [{}]", key, items.len())).monospace().color(COLOR_JSON_KEY))
+                .id_salt(key)
+                .default_open(depth == 0)
+                .show(ui, |ui| {
+                    for (idx, child) in items.iter().enumerate() {
+                        show_json_tree(ui, &format!("[{}]", idx), child, depth + 1);
+                    }
+                });
+        }
+        scalar => {
+            ui.horizontal(|ui| {
+                ui.label(RichText::new(key).monospace().color(COLOR_JSON_KEY));
+                ui.label(RichText::new(":").color(GRAY));
+                let color = match scalar {
+                    Value::String(_) => COLOR_JSON_STRING,
+                    Value::Number(_) => COLOR_JSON_NUMBER,
+                    _ => COLOR_JSON_LITERAL,
+                };
+                let text = match scalar {
+                    Value::String(s) => format!("\"{}\"", s),
+                    other => json_scalar_text(other),
+                };
+                ui.add(egui::Label::new(RichText::new(text).monospace().color(color)).wrap());
+            });
+        }
+    }
+}
@@ -19,15 +19,27 @@
 //! - `y`: 复制当前单元格
 //! - `yy`: 复制整行
 //! - `p`: 粘贴
+//! - `P`: 把复制的行粘贴为新增行
 //! - `u/U`: 撤销
 //! - `/`: 添加筛选
 //! - `f`: 为当前列添加筛选
+//! - `n/N`: 跳转到下一个/上一个搜索匹配
 //! - `o/O`: 添加新行
+//! - `m`: 置顶/取消置顶当前行（最多 3 行）
+//! - `<` / `>`: 把当前列左移/右移（只影响显示顺序，按表保存）
 //! - `:w`: 保存修改
 //! - `q`: 放弃修改
 //! - `Ctrl+R`: 刷新表格数据
 //! - `Space+d`: 标记删除行
 //! - `Ctrl+S`: 保存修改
+//! - `Q` / `@`: 录制/回放键盘宏（由应用层处理，`3@` 回放 3 次）
+//!
+//! ## Select 模式键位
+//! - `hjkl/w/b`: 扩展选择
+//! - `d/c/y`: 清空/清空并编辑/复制选中内容
+//! - `f`: 向下填充（每列第一行的值复制到其余行）
+//! - `F`: 序列填充（数字和日期按前两行的差值递增）
+//! - `x`: 选择整行
 //!
 //! ## 视图模式 (z 前缀)
 //! - `zz`: 将当前行滚动到屏幕中央
@@ -43,17 +55,20 @@
 #![allow(clippy::too_many_arguments)]
 
 use super::actions::DataGridActions;
-use super::filter::ColumnFilter;
+use super::filter::{ColumnFilter, SearchMatcher};
 use super::mode::GridMode;
 use super::state::DataGridState;
 use crate::database::QueryResult;
 use egui::{self, Key};
+use std::collections::HashMap;
 
 pub fn handle_keyboard(
     ui: &mut egui::Ui,
     state: &mut DataGridState,
     result: &QueryResult,
     filtered_rows: &[(usize, &Vec<String>)],
+    matcher: Option<&SearchMatcher>,
+    search_col_idx: Option<usize>,
     actions: &mut DataGridActions,
 ) {
     // 如果表格未聚焦或处于编辑模式，不处理表格快捷键
@@ -87,7 +102,8 @@ pub fn handle_keyboard(
                 9 => Key::Num9,
                 _ => continue,
             };
-            if i.key_pressed(key) && !i.modifiers.ctrl {
+            // Shift+数字是符号（如 $、%、@），不作为计数
+            if i.key_pressed(key) && !i.modifiers.ctrl && !i.modifiers.shift {
                 // 0 只有在已有计数时才追加（避免单独按 0 误触发）
                 if digit == 0 && state.count.is_none() {
                     continue;
@@ -133,6 +149,12 @@ pub fn handle_keyboard(
                     max_col,
                     half_page,
                 );
+                // n/N 跳转到下一个/上一个搜索匹配
+                if i.key_pressed(Key::N) && !i.modifiers.ctrl && state.command_buffer.is_empty()
+                    && let Some(matcher) = matcher
+                {
+                    jump_to_match(state, filtered_rows, matcher, search_col_idx, !i.modifiers.shift, actions);
+                }
             }
             GridMode::Select => {
                 handle_select_mode(i, state, filtered_rows, actions, max_row, max_col);
@@ -186,6 +208,26 @@ fn handle_normal_mode(
         }
     }
 
+    // < / > 调整当前列的显示顺序
+    if state.command_buffer.is_empty() {
+        let delta = i.events.iter().find_map(|e| match e {
+            egui::Event::Text(t) if t == "<" => Some(-1),
+            egui::Event::Text(t) if t == ">" => Some(1),
+            _ => None,
+        });
+        if let Some(delta) = delta {
+            let col = state.cursor.1;
+            if state.move_column(&result.columns, col, delta) {
+                actions.column_order_changed = true;
+                state.scroll_to_col = Some(col);
+                // 列移动后重新计算横向滚动，让当前列保持可见
+                state.last_cursor_col = usize::MAX;
+                let name = result.columns.get(col).map_or("", String::as_str);
+                actions.message = Some(format!("已{}移列 {}", if delta < 0 { "左" } else { "右" }, name));
+            }
+        }
+    }
+
     // w 移动：向右移动一列（Helix 风格，类似于下一个单词）
     if i.key_pressed(Key::W) && !i.modifiers.ctrl && state.command_buffer.is_empty() {
         if state.cursor.1 >= max_col.saturating_sub(1) {
@@ -354,11 +396,11 @@ fn handle_normal_mode(
     }
 
     // q 放弃修改（兼容旧快捷键，不需要冒号前缀）
-    if i.key_pressed(Key::Q) && !i.modifiers.ctrl && state.command_buffer.is_empty() {
-        if state.has_changes() {
-            state.clear_edits();
-            actions.message = Some("已放弃所有修改 (q)".to_string());
-        }
+    if i.key_pressed(Key::Q) && !i.modifiers.ctrl && !i.modifiers.shift && state.command_buffer.is_empty()
+        && state.has_changes()
+    {
+        state.clear_edits();
+        actions.message = Some("已放弃所有修改 (q)".to_string());
     }
 
     // === 模式切换 ===
@@ -396,12 +438,12 @@ fn handle_normal_mode(
         actions.message = Some("选择全部 (%)".to_string());
     }
     // ;: 折叠选择到单个光标
-    if i.key_pressed(Key::Semicolon) && !i.modifiers.shift && state.command_buffer.is_empty() {
-        if state.mode == GridMode::Select {
-            state.mode = GridMode::Normal;
-            state.select_anchor = None;
-            actions.message = Some("折叠选择 (;)".to_string());
-        }
+    if i.key_pressed(Key::Semicolon) && !i.modifiers.shift && state.command_buffer.is_empty()
+        && state.mode == GridMode::Select
+    {
+        state.mode = GridMode::Normal;
+        state.select_anchor = None;
+        actions.message = Some("折叠选择 (;)".to_string());
     }
 
     // === 操作 ===
@@ -433,11 +475,22 @@ fn handle_normal_mode(
         }
         state.command_buffer.clear();
     }
-    if i.key_pressed(Key::P) && state.command_buffer.is_empty()
+    if i.key_pressed(Key::P) && !i.modifiers.ctrl && !i.modifiers.shift && state.command_buffer.is_empty()
         && let Some(text) = &state.clipboard {
             state.modified_cells.insert(state.cursor, text.clone());
             actions.message = Some("已粘贴 (p)".to_string());
         }
+    // P: 把复制的行粘贴为新增行（主键已存在时可在状态栏切换为 upsert）
+    if i.key_pressed(Key::P) && i.modifiers.shift && !i.modifiers.ctrl && state.command_buffer.is_empty()
+        && let Some(text) = state.clipboard.clone() {
+            let first_new_row = result.rows.len() + state.new_rows.len();
+            let count = state.paste_rows(&text, result.columns.len());
+            if count > 0 {
+                state.cursor = (first_new_row, 0);
+                state.scroll_to_row = Some(first_new_row);
+                actions.message = Some(format!("已粘贴 {} 行为新增行 (P)", count));
+            }
+        }
     if i.key_pressed(Key::U)
         && !i.modifiers.shift
         && !i.modifiers.ctrl
@@ -496,6 +549,19 @@ fn handle_normal_mode(
         actions.message = Some("已在开头添加新行 (O)".to_string());
     }
 
+    // === 置顶 ===
+    // m: 置顶/取消置顶当前行，便于与其他行对比（新增行不能置顶）
+    if i.key_pressed(Key::M) && !i.modifiers.ctrl && state.command_buffer.is_empty()
+        && state.cursor.0 < result.rows.len()
+    {
+        let row = state.cursor.0;
+        actions.message = Some(match state.toggle_pinned_row(row) {
+            Some(true) => format!("已置顶第 {} 行 (m)", row + 1),
+            Some(false) => format!("已取消置顶第 {} 行 (m)", row + 1),
+            None => format!("最多置顶 {} 行", super::state::MAX_PINNED_ROWS),
+        });
+    }
+
     // === 刷新 ===
     // Ctrl+R 刷新表格数据
     if i.modifiers.ctrl && i.key_pressed(Key::R) {
@@ -515,6 +581,46 @@ fn handle_normal_mode(
     }
 }
 
+/// 从光标处按行优先顺序查找下一个（或上一个）匹配的单元格，到达末尾时回绕
+fn jump_to_match(
+    state: &mut DataGridState,
+    filtered_rows: &[(usize, &Vec<String>)],
+    matcher: &SearchMatcher,
+    search_col_idx: Option<usize>,
+    forward: bool,
+    actions: &mut DataGridActions,
+) {
+    let cols = filtered_rows.first().map_or(0, |(_, row)| row.len());
+    let total = filtered_rows.len() * cols;
+    if total == 0 {
+        return;
+    }
+    let display_row = filtered_rows
+        .iter()
+        .position(|(idx, _)| *idx == state.cursor.0)
+        .unwrap_or(0);
+    let start = display_row * cols + state.cursor.1.min(cols - 1);
+
+    for step in 1..=total {
+        let pos = if forward { (start + step) % total } else { (start + total - step) % total };
+        let (display_idx, col) = (pos / cols, pos % cols);
+        if search_col_idx.is_some_and(|c| c != col) {
+            continue;
+        }
+        let (row_idx, row) = filtered_rows[display_idx];
+        if row.get(col).is_some_and(|cell| matcher.is_match(cell)) {
+            state.cursor = (row_idx, col);
+            state.scroll_to_row = Some(display_idx);
+            let wrapped = if forward { pos <= start } else { pos >= start };
+            if wrapped {
+                actions.message = Some(if forward { "已从开头继续搜索" } else { "已从末尾继续搜索" }.to_string());
+            }
+            return;
+        }
+    }
+    actions.message = Some("没有匹配的单元格".to_string());
+}
+
 fn handle_select_mode(
     i: &egui::InputState,
     state: &mut DataGridState,
@@ -560,6 +666,25 @@ fn handle_select_mode(
         state.select_anchor = None;
     }
 
+    // f 向下填充，F 按数字/日期序列填充
+    if i.key_pressed(Key::F) && !i.modifiers.ctrl {
+        // 选择范围使用原始行索引，被筛选掉的行不填充
+        let rows: HashMap<usize, &Vec<String>> = filtered_rows.iter().map(|(idx, row)| (*idx, *row)).collect();
+        let cell = |r: usize, c: usize| rows.get(&r).and_then(|row| row.get(c).cloned());
+        let count = if i.modifiers.shift {
+            state.fill_series(cell)
+        } else {
+            state.fill_down(cell)
+        };
+        actions.message = Some(if i.modifiers.shift {
+            format!("已按序列填充 {} 个单元格 (F)", count)
+        } else {
+            format!("已向下填充 {} 个单元格 (f)", count)
+        });
+        state.mode = GridMode::Normal;
+        state.select_anchor = None;
+    }
+
     // c 清空选中并进入插入
     if i.key_pressed(Key::C) {
         if let Some(((min_r, min_c), (max_r, max_c))) = state.get_selection() {
@@ -8,30 +8,56 @@
 //! - `filter`: 筛选条件（拆分为多个子模块）
 //! - `keyboard`: 键盘输入处理
 //! - `render`: 单元格渲染
+//! - `sort`: 客户端排序
 //! - `actions`: 操作和 SQL 生成
+//! - `aggregate`: 底部状态栏的聚合统计
+//! - `duplicates`: 重复行检测
+//! - `facet`: 不同值面板
+//! - `json_view`: JSON 列识别和单元格查看器
+//! - `blob_view`: 二进制单元格查看器
+//! - `geometry_view`: 几何图形单元格预览
 
 #![allow(clippy::too_many_arguments)]
 
 mod actions;
+mod aggregate;
+mod blob_view;
+mod duplicates;
+mod facet;
 pub mod filter;
+mod geometry_view;
+mod json_view;
 mod keyboard;
 mod mode;
 mod render;
+mod sort;
 mod state;
 
-pub use actions::{escape_identifier, escape_value, quote_identifier, DataGridActions, FocusTransfer};
+pub use actions::{
+    escape_identifier, escape_value, generate_save_sql, quote_identifier, quote_qualified_identifier,
+    DataGridActions, FocusTransfer,
+};
+pub use aggregate::{format_number, Aggregate, ColumnStats, NumericStats};
+pub use blob_view::BlobViewer;
+pub use duplicates::{DedupDelete, DuplicateGroups};
+pub use facet::{facet_selection, is_facet_selectable, set_facet_value, ValueFacet};
 pub use filter::{
-    check_filter_match, filter_rows_cached,
-    ColumnFilter, FilterCache, FilterLogic, FilterOperator,
+    check_filter_match, filter_rows_background, filter_rows_cached, filters_to_where,
+    ColumnFilter, FilterCache, FilterLogic, FilterOperator, SearchMatcher, SearchOptions,
 };
+pub use geometry_view::GeometryViewer;
+pub use json_view::{JsonColumns, JsonViewer};
 pub use mode::GridMode;
-pub use state::DataGridState;
+pub use sort::{sort_indices, ColumnSort};
+pub use state::{DataGridState, ResultPage};
 
-use crate::core::constants;
+use crate::core::{constants, detect_timestamp_columns, grid_text_style, UpsertSyntax};
 use crate::database::QueryResult;
 use crate::ui::styles::GRAY;
+use render::HeaderAction;
 use egui::{self, RichText, Vec2};
 use egui_extras::{Column, TableBuilder};
+use std::sync::Arc;
 
 // 使用集中管理的常量
 use constants::grid::{HEADER_HEIGHT, MAX_COL_WIDTH, MIN_COL_WIDTH, ROW_HEIGHT};
@@ -45,6 +71,11 @@ pub(crate) const COLOR_CELL_SELECTED: Color32 = Color32::from_rgb(60, 100, 180);
 pub(crate) const COLOR_CELL_EDITING: Color32 = Color32::from_rgb(80, 120, 200);
 pub(crate) const COLOR_CELL_MODIFIED: Color32 = Color32::from_rgb(100, 150, 80);
 pub(crate) const COLOR_VISUAL_SELECT: Color32 = Color32::from_rgb(120, 80, 160);
+/// 重复行分组交替使用的背景色
+pub(crate) const COLOR_DUPLICATE_GROUPS: [Color32; 2] = [
+    Color32::from_rgba_premultiplied(90, 70, 20, 90),
+    Color32::from_rgba_premultiplied(20, 70, 90, 90),
+];
 
 // ============================================================================
 // 数据表格组件
@@ -56,9 +87,10 @@ impl DataGrid {
     /// 显示可编辑的数据表格（Helix 风格）
     pub fn show_editable(
         ui: &mut egui::Ui,
-        result: &QueryResult,
+        result: &Arc<QueryResult>,
         search_text: &str,
         search_column: &Option<String>,
+        search_options: &SearchOptions,
         selected_row: &mut Option<usize>,
         selected_cell: &mut Option<(usize, usize)>,
         state: &mut DataGridState,
@@ -71,6 +103,23 @@ impl DataGrid {
             return (actions, (0, 0));
         }
 
+        // 结果变化后重复行分组失效
+        if state.duplicates.as_ref().is_some_and(|d| d.is_stale(result)) {
+            state.duplicates = None;
+        }
+        if state.json_columns.is_stale(result) {
+            state.json_columns = JsonColumns::detect(result);
+            state.timestamp_columns = detect_timestamp_columns(result);
+        }
+        // 结果变化后重新统计不同值（列已不存在时关闭面板）
+        if let Some(facet) = state.value_facet.as_ref().filter(|f| f.is_stale(result)) {
+            state.value_facet = result
+                .columns
+                .iter()
+                .position(|c| *c == facet.column)
+                .map(|col_idx| ValueFacet::compute(result, col_idx));
+        }
+
         // 显示模式状态栏和操作按钮
         Self::show_mode_bar(ui, state, result, table_name, &mut actions);
 
@@ -79,32 +128,68 @@ impl DataGrid {
         // 显示跳转对话框
         Self::show_goto_dialog(ui.ctx(), state, result.rows.len());
 
-        // 显示保存确认对话框
-        Self::show_save_confirm_dialog(ui.ctx(), state, &mut actions);
+        // 显示重复行检测窗口
+        Self::show_duplicates_dialog(ui.ctx(), state, result, table_name, &mut actions);
+
+        // 显示 JSON 查看窗口
+        Self::show_json_viewer(ui.ctx(), state, &mut actions);
+
+        // 显示二进制查看窗口
+        Self::show_blob_viewer(ui.ctx(), state, table_name, &mut actions);
+
+        // 显示几何图形查看窗口
+        Self::show_geometry_viewer(ui.ctx(), state, &mut actions);
+
+        // 显示列统计窗口
+        Self::show_column_stats(ui.ctx(), state);
+
+        // 查看窗口打开时表格不响应键盘
+        if state.json_viewer.is_some() || state.blob_viewer.is_some() || state.geometry_viewer.is_some() {
+            state.focused = false;
+        }
 
         // 显示筛选状态栏（简洁版）
-        let filter_changed = filter::show_filter_bar(ui, result, &mut state.filters);
+        let filter_changed = filter::show_filter_bar(ui, result, &mut state.filters, &mut actions, table_name.is_some());
         if filter_changed {
             state.filter_cache.invalidate();
         }
 
         ui.add_space(4.0);
 
-        // 过滤行（使用缓存）
-        let filtered_rows = filter::filter_rows_cached(
+        // 筛选和排序行（使用缓存，大结果集在后台计算）
+        let (filtered_rows, computing) = filter::filter_rows_background(
+            ui.ctx(),
             result,
             search_text,
             search_column,
+            search_options,
             &state.filters,
+            state.sort.as_ref(),
             &mut state.filter_cache,
         );
+        // 打印视图和透视表使用当前显示的行（筛选和排序后）
+        if actions.print_view || actions.pivot_view {
+            actions.display_rows = filtered_rows.iter().map(|(idx, _)| *idx).collect();
+        }
         // 总显示行数 = 筛选后的行 + 新增行
         let new_rows_count = state.new_rows.len();
         let filtered_count = filtered_rows.len() + new_rows_count;
         let total_count = result.rows.len() + new_rows_count;
+        // 置顶的行不受筛选影响（结果集变小后超出范围的行取消置顶）
+        state.pinned_rows.retain(|&r| r < result.rows.len());
+        let pinned_rows: Vec<(usize, &Vec<String>)> =
+            state.pinned_rows.iter().map(|&r| (r, &result.rows[r])).collect();
+
+        // 搜索匹配（用于高亮和 n/N 跳转，正则表达式无效时忽略）
+        let matcher = SearchMatcher::new(search_text, search_options).ok().flatten();
+        let search_col_idx = search_column
+            .as_ref()
+            .and_then(|name| result.columns.iter().position(|c| c == name));
 
         // 处理键盘输入
-        keyboard::handle_keyboard(ui, state, result, &filtered_rows, &mut actions);
+        let previous_col = state.cursor.1;
+        keyboard::handle_keyboard(ui, state, result, &filtered_rows, matcher.as_ref(), search_col_idx, &mut actions);
+        state.snap_cursor_to_visible(&result.columns, previous_col);
 
         // 处理新增行的编辑
         if let Some((virtual_idx, col_idx, new_value)) = state.pending_new_row_edit.take() {
@@ -126,31 +211,62 @@ impl DataGrid {
             state.pending_save = false;
         }
 
+        // 处理行号右键菜单中的收藏请求
+        actions.bookmark_row = state.pending_bookmark.take();
+
         // 同步选择状态
         *selected_row = Some(state.cursor.0);
         *selected_cell = Some(state.cursor);
 
-        // 获取每列的最佳宽度（使用缓存优化）
-        let col_widths = Self::get_column_widths(result, &filtered_rows, &mut state.column_width_cache);
-
-        // 收集需要添加筛选的列
-        let mut columns_to_filter: Vec<String> = Vec::new();
+        // 每列的宽度：手动调整过的列使用保存的宽度，其余按内容自动计算（使用缓存优化）
+        let col_widths: Vec<f32> = Self::get_column_widths(result, state.filter_cache.generation(), &filtered_rows, &mut state.column_width_cache)
+            .into_iter()
+            .zip(&result.columns)
+            .map(|(auto, name)| state.column_widths.get(name).copied().unwrap_or(auto))
+            .collect();
+        // 显示的列：冻结的列在左侧单独的表格中，其余列可以横向滚动
+        let visible = state.visible_columns(&result.columns);
+        let frozen = state.frozen_columns.min(visible.len().saturating_sub(1));
+        let (frozen_cols, scroll_cols) = visible.split_at(frozen);
+        // 列宽或显示的列变化（新的结果、自动调整列宽、隐藏/冻结列）时让表格重新使用这些宽度
+        let layout = (visible.clone(), frozen);
+        let reset_widths = col_widths != state.applied_widths || layout != state.applied_layout;
+        state.applied_widths = col_widths.clone();
+        state.applied_layout = layout;
+        // 实际渲染的列宽，用于发现拖动调整过的列
+        let mut rendered_widths = vec![None; col_widths.len()];
+
+        // 收集列头菜单中的操作
+        let mut header_actions: Vec<HeaderAction> = Vec::new();
 
         // 获取需要滚动到的行（表格内部处理垂直滚动）
         let scroll_to_row = state.scroll_to_row.take();
         let _ = state.scroll_to_col.take();
         
-        // 获取可用宽度
-        let available_width = ui.available_width();
+        // 不同值面板在表格右侧，勾选值后修改筛选条件
+        if facet::show_facet_panel(ui, state) {
+            state.filter_cache.invalidate();
+        }
+
+        // 获取可用宽度（冻结的列不参与横向滚动）
+        let frozen_width: f32 = if frozen_cols.is_empty() {
+            0.0
+        } else {
+            ROW_NUM_WIDTH + frozen_cols.iter().map(|&c| col_widths[c]).sum::<f32>()
+        };
+        let available_width = ui.available_width() - frozen_width;
+        // 横向滚动区域左侧的行号列宽度
+        let lead_width = if frozen_cols.is_empty() { ROW_NUM_WIDTH } else { 0.0 };
         
-        // 计算目标列的位置信息
+        // 计算目标列在横向滚动区域中的位置信息
         let current_col = state.cursor.1;
-        let mut col_left = ROW_NUM_WIDTH;
-        for i in 0..current_col {
-            if let Some(&w) = col_widths.get(i) {
-                col_left += w;
-            }
-        }
+        let cursor_pos = scroll_cols.iter().position(|&c| c == current_col);
+        let col_left = lead_width
+            + scroll_cols
+                .iter()
+                .take(cursor_pos.unwrap_or(0))
+                .map(|&c| col_widths[c])
+                .sum::<f32>();
         let col_width = col_widths.get(current_col).copied().unwrap_or(MIN_COL_WIDTH);
         let col_right = col_left + col_width;
         
@@ -158,12 +274,12 @@ impl DataGrid {
         let col_changed = current_col != state.last_cursor_col;
         state.last_cursor_col = current_col;
         
-        // 计算水平滚动偏移
+        // 计算水平滚动偏移（光标在冻结列上时不需要滚动）
         let mut target_h_offset = state.h_scroll_offset;
-        if col_changed {
+        if col_changed && cursor_pos.is_some() {
             // 向左移动时：确保列的左边缘可见
-            if col_left < state.h_scroll_offset + ROW_NUM_WIDTH {
-                target_h_offset = (col_left - ROW_NUM_WIDTH).max(0.0);
+            if col_left < state.h_scroll_offset + lead_width {
+                target_h_offset = (col_left - lead_width).max(0.0);
             }
             // 向右移动时：确保列的右边缘完全可见（预留100像素边距）
             else if col_right > state.h_scroll_offset + available_width - 100.0 {
@@ -171,157 +287,229 @@ impl DataGrid {
             }
         }
 
+        // 为底部状态栏预留高度
+        let footer_height = if state.show_footer {
+            ui.spacing().interact_size.y + ui.spacing().item_spacing.y * 2.0
+        } else {
+            0.0
+        };
+        let table_height = (ui.available_height() - footer_height).max(HEADER_HEIGHT + ROW_HEIGHT);
+
         // 创建表格
+        state.rendered_rows = None;
         let table_response = egui::Frame::NONE.show(ui, |ui| {
-            let scroll_output = egui::ScrollArea::horizontal()
-                .auto_shrink([false, false])
-                .scroll_offset(egui::vec2(target_h_offset, 0.0))
-                .show(ui, |ui| {
-                    // 构建表格，保留内部垂直滚动
-                    let mut table_builder = TableBuilder::new(ui)
-                        .striped(true)
-                        .resizable(true)
-                        .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
-                        .column(Column::exact(ROW_NUM_WIDTH));
-
-                    // 为每列设置基于内容的初始宽度
-                    for &width in &col_widths {
-                        table_builder = table_builder.column(
-                            Column::initial(width)
-                                .at_least(MIN_COL_WIDTH)
-                                .clip(true),
-                        );
-                    }
-
-                    // 使用表格内部的垂直滚动
-                    if let Some(target_row) = scroll_to_row {
-                        table_builder = table_builder.scroll_to_row(target_row, Some(egui::Align::Center));
-                    }
-
+            // 表格使用独立的字体设置，行高随字号增大
+            ui.style_mut().override_text_style = Some(grid_text_style());
+            let row_height = ROW_HEIGHT.max(ui.text_style_height(&grid_text_style()) + 8.0);
+            let table = GridTable {
+                result,
+                filtered_rows: &filtered_rows,
+                pinned_rows: &pinned_rows,
+                matcher: matcher.as_ref(),
+                search_col_idx,
+                col_widths: &col_widths,
+                visible_count: visible.len(),
+                frozen,
+                reset_widths,
+                scroll_to_row,
+                row_height,
+            };
 
-                    table_builder
-                        .header(HEADER_HEIGHT, |mut header| {
-                            // 行号列头
-                            header.col(|ui| {
-                                ui.label(RichText::new("#").strong().color(GRAY));
-                            });
-                            // 数据列头
-                            for (col_idx, col_name) in result.columns.iter().enumerate() {
-                                header.col(|ui| {
-                                    render::render_column_header(
-                                        ui,
-                                        col_name,
-                                        col_idx,
-                                        state,
-                                        &mut columns_to_filter,
-                                    );
-                                });
-                            }
-                        })
-                        .body(|body| {
-                            let filtered_rows_len = filtered_rows.len();
-                            body.rows(ROW_HEIGHT, filtered_count, |mut row| {
-                                let display_idx = row.index();
-                                
-                                // 判断是显示已有数据还是新增行
-                                if display_idx < filtered_rows_len {
-                                    // 显示已有数据行
-                                    if let Some((original_idx, row_data)) =
-                                        filtered_rows.get(display_idx)
-                                    {
-                                        let is_cursor_row = state.cursor.0 == *original_idx;
-                                        let is_row_deleted =
-                                            state.rows_to_delete.contains(original_idx);
-
-                                        row.set_selected(is_cursor_row || is_row_deleted);
-
-                                        // 行号列
-                                        row.col(|ui| {
-                                            render::render_row_number(
-                                                ui,
-                                                *original_idx,
-                                                is_cursor_row,
-                                                is_row_deleted,
-                                                state,
-                                            );
-                                        });
-
-                                        // 数据列
-                                        for (col_idx, cell) in row_data.iter().enumerate() {
-                                            row.col(|ui| {
-                                                render::render_editable_cell(
-                                                    ui,
-                                                    cell,
-                                                    *original_idx,
-                                                    col_idx,
-                                                    is_cursor_row,
-                                                    is_row_deleted,
-                                                    state,
-                                                );
-                                            });
-                                        }
-                                    }
-                                } else {
-                                    // 显示新增行（pending rows）
-                                    let new_row_idx = display_idx - filtered_rows_len;
-                                    // 新增行的虚拟原始索引 = 结果行数 + 新增行索引
-                                    let virtual_idx = result.rows.len() + new_row_idx;
-                                    let is_cursor_row = state.cursor.0 == virtual_idx;
-
-                                    // 新增行使用特殊高亮
-                                    row.set_selected(is_cursor_row);
-
-                                    // 行号列 - 显示 "+" 标记表示新增行
-                                    row.col(|ui| {
-                                        let text = RichText::new(format!("{}+", virtual_idx + 1))
-                                            .monospace()
-                                            .color(Color32::from_rgb(100, 200, 100));
-                                        ui.label(text);
-                                    });
-
-                                    // 数据列 - 显示新增行的内容
-                                    // 先克隆数据避免借用冲突
-                                    let new_row_data: Vec<String> = state
-                                        .new_rows
-                                        .get(new_row_idx)
-                                        .cloned()
-                                        .unwrap_or_default();
-                                    for (col_idx, cell) in new_row_data.iter().enumerate() {
-                                        row.col(|ui| {
-                                            render::render_new_row_cell(
-                                                ui,
-                                                cell,
-                                                virtual_idx,
-                                                col_idx,
-                                                is_cursor_row,
-                                                state,
-                                            );
-                                        });
-                                    }
-                                }
-                            });
-                        });
+            ui.horizontal_top(|ui| {
+                // 冻结的列：与右侧表格同步垂直滚动，不显示自己的滚动条
+                let frozen_offset = (!frozen_cols.is_empty()).then(|| {
+                    ui.allocate_ui(egui::vec2(frozen_width, table_height), |ui| {
+                        table.show(ui, state, frozen_cols, TablePart::Frozen, &mut rendered_widths, &mut header_actions)
+                    })
+                    .inner
                 });
-            // 更新保存的水平滚动偏移量
-            state.h_scroll_offset = scroll_output.state.offset.x;
+
+                let part = if frozen_cols.is_empty() { TablePart::Whole } else { TablePart::Scrolled };
+                let scroll_output = egui::ScrollArea::horizontal()
+                    .auto_shrink([false, false])
+                    .max_height(table_height)
+                    .scroll_offset(egui::vec2(target_h_offset, 0.0))
+                    .show(ui, |ui| {
+                        table.show(ui, state, scroll_cols, part, &mut rendered_widths, &mut header_actions)
+                    });
+                // 更新保存的水平滚动偏移量
+                state.h_scroll_offset = scroll_output.state.offset.x;
+                // 两个表格中被滚动的一个决定新的垂直偏移
+                if let Some(frozen_offset) = frozen_offset {
+                    state.v_scroll_offset = if (frozen_offset - state.v_scroll_offset).abs() > 0.5 {
+                        frozen_offset
+                    } else {
+                        scroll_output.inner
+                    };
+                }
+            });
         });
 
-        // 处理列筛选点击
-        for col_name in columns_to_filter {
-            if !state.filters.iter().any(|f| f.column == col_name) {
-                state.filters.push(ColumnFilter::new(col_name));
+        // 拖动列边界后记录手动调整的列宽
+        if !reset_widths {
+            for (col_idx, rendered) in rendered_widths.into_iter().enumerate() {
+                if let (Some(width), Some(applied)) = (rendered, state.applied_widths.get_mut(col_idx))
+                    && (width - *applied).abs() > 1.0
+                {
+                    *applied = width;
+                    state.column_widths.insert(result.columns[col_idx].clone(), width);
+                    actions.column_widths_changed = true;
+                }
             }
         }
 
+        // 处理列头菜单中的操作
+        for action in header_actions {
+            match action {
+                HeaderAction::Filter(col_name) => {
+                    if !state.filters.iter().any(|f| f.column == col_name) {
+                        state.filters.push(ColumnFilter::new(col_name));
+                    }
+                }
+                HeaderAction::Format(col_name, format) => {
+                    match format {
+                        Some(format) => state.column_formats.insert(col_name, format),
+                        None => state.column_formats.remove(&col_name),
+                    };
+                    actions.column_formats_changed = true;
+                }
+                HeaderAction::Mask(col_name, masked) => {
+                    if masked {
+                        state.masked_columns.insert(col_name.to_lowercase());
+                    } else {
+                        state.masked_columns.remove(&col_name.to_lowercase());
+                    }
+                    actions.masked_column = Some((col_name, masked));
+                }
+                HeaderAction::AutoFit(col_name) => {
+                    actions.column_widths_changed |= state.column_widths.remove(&col_name).is_some();
+                }
+                HeaderAction::AutoFitAll => {
+                    actions.column_widths_changed |= !state.column_widths.is_empty();
+                    state.column_widths.clear();
+                }
+                HeaderAction::Sort(sort) => state.sort = sort,
+                HeaderAction::Hide(col_name) => {
+                    if state.hide_column(&result.columns, &col_name) {
+                        let previous = state.cursor.1;
+                        state.snap_cursor_to_visible(&result.columns, previous);
+                    }
+                }
+                HeaderAction::ShowAllColumns => state.hidden_columns.clear(),
+                HeaderAction::Freeze(count) => state.frozen_columns = count,
+                HeaderAction::MoveColumn { from, to } => {
+                    actions.column_order_changed |= state.move_column_to(&result.columns, from, to);
+                }
+                HeaderAction::ResetColumnOrder => {
+                    actions.column_order_changed |= !state.column_order.is_empty();
+                    state.column_order.clear();
+                }
+                HeaderAction::Stats(col_idx) => {
+                    let name = result.columns.get(col_idx).cloned().unwrap_or_default();
+                    let values = filtered_rows.iter().filter_map(|(idx, row)| {
+                        state.modified_cells.get(&(*idx, col_idx)).or_else(|| row.get(col_idx)).map(String::as_str)
+                    });
+                    state.column_stats = Some(ColumnStats::compute(name, values));
+                }
+                HeaderAction::Facet(col_idx) => {
+                    state.value_facet = Some(ValueFacet::compute(result, col_idx));
+                }
+            }
+        }
+
+        // 后台筛选或排序时在表格右上角显示加载指示
+        if computing {
+            let rect = table_response.response.rect;
+            let size = 14.0;
+            let spinner_rect = egui::Rect::from_min_size(
+                egui::pos2(rect.right() - size - 6.0, rect.top() + (HEADER_HEIGHT - size) / 2.0),
+                egui::vec2(size, size),
+            );
+            egui::Spinner::new().size(size).paint_at(ui, spinner_rect);
+        }
+
         // 点击表格区域聚焦
         if table_response.response.clicked() {
             state.focused = true;
             actions.request_focus = true;
         }
 
+        if state.show_footer {
+            Self::show_footer(ui, result, &filtered_rows, state, filtered_count, total_count);
+        }
+
         (actions, (filtered_count, total_count))
     }
 
+    /// 底部状态栏：行数，以及选择范围（没有选择时为光标所在列）的聚合统计
+    fn show_footer(
+        ui: &mut egui::Ui,
+        result: &QueryResult,
+        filtered_rows: &[(usize, &Vec<String>)],
+        state: &DataGridState,
+        filtered_count: usize,
+        total_count: usize,
+    ) {
+        // 选择范围内的行和列（已修改的单元格使用修改后的值）
+        let (row_range, cols, scope) = match state.get_selection() {
+            Some(((min_r, min_c), (max_r, max_c))) => (
+                Some(min_r..=max_r),
+                min_c..=max_c,
+                format!("选择 {}x{}", max_r - min_r + 1, max_c - min_c + 1),
+            ),
+            None => {
+                let col = state.cursor.1;
+                (None, col..=col, result.columns.get(col).cloned().unwrap_or_default())
+            }
+        };
+        let rows = filtered_rows
+            .iter()
+            .filter(|(idx, _)| row_range.as_ref().is_none_or(|r| r.contains(idx)));
+        let aggregate = Aggregate::compute(rows.flat_map(|(idx, row)| {
+            cols.clone().filter_map(move |col| {
+                state
+                    .modified_cells
+                    .get(&(*idx, col))
+                    .or_else(|| row.get(col))
+                    .map(String::as_str)
+            })
+        }));
+
+        ui.horizontal(|ui| {
+            let rows_text = if filtered_count == total_count {
+                format!("{} 行", total_count)
+            } else {
+                format!("{} / {} 行", filtered_count, total_count)
+            };
+            ui.label(RichText::new(rows_text).small().color(GRAY));
+
+            if aggregate.cells == 0 {
+                return;
+            }
+            ui.separator();
+            ui.label(RichText::new(scope).small().color(COLOR_VISUAL_SELECT));
+
+            let mut stats = vec![format!("计数 {}", aggregate.count)];
+            if let Some(numeric) = aggregate.numeric {
+                stats.push(format!("求和 {}", format_number(numeric.sum)));
+                stats.push(format!("平均 {}", format_number(numeric.avg)));
+            }
+            if let (Some(min), Some(max)) = (&aggregate.min, &aggregate.max) {
+                let shorten = |v: &str| -> String {
+                    if v.chars().count() > CELL_TRUNCATE_LEN {
+                        format!("{}…", v.chars().take(CELL_TRUNCATE_LEN).collect::<String>())
+                    } else {
+                        v.to_string()
+                    }
+                };
+                stats.push(format!("最小 {}", shorten(min)));
+                stats.push(format!("最大 {}", shorten(max)));
+            }
+            ui.label(RichText::new(stats.join("  ·  ")).small().monospace())
+                .on_hover_text("非 NULL 值均为数字时按数值统计，否则按文本比较最小/最大值");
+        });
+    }
+
     /// 显示模式状态栏和操作按钮
     fn show_mode_bar(
         ui: &mut egui::Ui,
@@ -368,8 +556,34 @@ impl DataGrid {
                 );
             }
 
-            // 截断警告
-            if result.truncated {
+            // 分页读取：显示页码和翻页按钮（truncated 表示还有下一页）
+            if let Some(page) = &state.page {
+                ui.separator();
+                if ui
+                    .add_enabled(page.index > 0, egui::Button::new("◀").small())
+                    .on_hover_text("上一页")
+                    .clicked()
+                {
+                    actions.page_request = Some(page.index - 1);
+                }
+                let first_row = page.offset() + 1;
+                ui.label(
+                    RichText::new(format!("第 {} 页", page.index + 1)).small().color(GRAY),
+                )
+                .on_hover_text(format!(
+                    "第 {} - {} 行，按页从数据库读取",
+                    first_row,
+                    page.offset() + result.rows.len()
+                ));
+                if ui
+                    .add_enabled(result.truncated, egui::Button::new("▶").small())
+                    .on_hover_text("下一页")
+                    .clicked()
+                {
+                    actions.page_request = Some(page.index + 1);
+                }
+            } else if result.truncated {
+                // 截断警告
                 ui.separator();
                 let truncated_msg = if let Some(original) = result.original_row_count {
                     format!("! 已截断 (原{}行)", original)
@@ -401,6 +615,62 @@ impl DataGrid {
                 actions.open_filter_panel = true;
             }
 
+            // 查重 - 可点击文字，打开重复行检测窗口
+            let dup_text = match &state.duplicates {
+                Some(d) => format!("重复({}组)", d.groups.len()),
+                None => "查重".to_string(),
+            };
+            if ui
+                .add(egui::Label::new(RichText::new(dup_text).size(12.0).color(Color32::from_rgb(130, 160, 200))).sense(egui::Sense::click()))
+                .on_hover_text("查找重复行")
+                .on_hover_cursor(egui::CursorIcon::PointingHand)
+                .clicked()
+            {
+                Self::open_duplicates_dialog(state, result);
+            }
+
+            // 打印 - 可点击文字，把当前显示的行和列保存为分页的 HTML 报表
+            if ui
+                .add(egui::Label::new(RichText::new("打印").size(12.0).color(Color32::from_rgb(130, 160, 200))).sense(egui::Sense::click()))
+                .on_hover_text("生成打印视图（HTML，包含筛选条件和 SQL，可在浏览器中打印或存为 PDF）")
+                .on_hover_cursor(egui::CursorIcon::PointingHand)
+                .clicked()
+            {
+                actions.print_view = true;
+            }
+
+            // 透视 - 可点击文字，对当前显示的行做分组交叉汇总
+            if ui
+                .add(egui::Label::new(RichText::new("透视").size(12.0).color(Color32::from_rgb(130, 160, 200))).sense(egui::Sense::click()))
+                .on_hover_text("按行分组列和列分组列汇总当前显示的行（计数、求和、平均值）")
+                .on_hover_cursor(egui::CursorIcon::PointingHand)
+                .clicked()
+            {
+                actions.pivot_view = true;
+            }
+
+            // 隐藏的列 - 点击显示所有列
+            if !state.hidden_columns.is_empty()
+                && ui
+                    .add(egui::Label::new(RichText::new(format!("隐藏 {} 列", state.hidden_columns.len())).size(12.0).color(Color32::from_rgb(130, 160, 200))).sense(egui::Sense::click()))
+                    .on_hover_text("显示所有列")
+                    .on_hover_cursor(egui::CursorIcon::PointingHand)
+                    .clicked()
+            {
+                state.hidden_columns.clear();
+            }
+
+            // 置顶的行 - 点击取消所有置顶
+            if !state.pinned_rows.is_empty()
+                && ui
+                    .add(egui::Label::new(RichText::new(format!("📌 置顶 {} 行", state.pinned_rows.len())).size(12.0).color(Color32::from_rgb(130, 160, 200))).sense(egui::Sense::click()))
+                    .on_hover_text("取消所有置顶 [m 切换当前行]")
+                    .on_hover_cursor(egui::CursorIcon::PointingHand)
+                    .clicked()
+            {
+                state.pinned_rows.clear();
+            }
+
             // 操作按钮
             if table_name.is_some() {
                 ui.add_space(16.0);
@@ -470,16 +740,31 @@ impl DataGrid {
                             .small()
                             .color(COLOR_CELL_MODIFIED),
                     );
+
+                    // 新增行的插入方式（数据库支持 upsert 时可切换）
+                    if !state.new_rows.is_empty()
+                        && state.db_type.and_then(UpsertSyntax::for_db).is_some()
+                    {
+                        let label = if state.upsert_new_rows { "UPSERT" } else { "INSERT" };
+                        if ui
+                            .add(egui::Label::new(RichText::new(label).small().color(Color32::from_rgb(130, 160, 200))).sense(egui::Sense::click()))
+                            .on_hover_text("切换新增行的保存方式：INSERT 或 upsert（主键已存在时更新该行）")
+                            .on_hover_cursor(egui::CursorIcon::PointingHand)
+                            .clicked()
+                        {
+                            state.upsert_new_rows = !state.upsert_new_rows;
+                        }
+                    }
                 }
             }
 
             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                 let help = match state.mode {
                     GridMode::Normal => {
-                        "hjkl:移动 i:编辑 v:选择 d:删除 y:复制 p:粘贴 gg:顶部 G:底部"
+                        "hjkl:移动 i:编辑 v:选择 d:删除 y:复制 p:粘贴 P:粘贴为新行 gg:顶部 G:底部"
                     }
                     GridMode::Insert => "Esc:退出 Enter:确认",
-                    GridMode::Select => "hjkl:扩展 d:删除 y:复制 Esc:取消",
+                    GridMode::Select => "hjkl:扩展 d:删除 y:复制 f:向下填充 F:序列填充 Esc:取消",
                 };
                 ui.label(RichText::new(help).small().color(GRAY));
             });
@@ -500,9 +785,12 @@ impl DataGrid {
         width
     }
 
-    /// 计算数据的哈希值（用于缓存验证）
-    fn calculate_data_hash(
+    /// 计算数据标识（用于缓存验证）
+    ///
+    /// 只哈希结果集代数、列名、行数和采样行的位置，不读取单元格内容。
+    fn calculate_data_key(
         result: &QueryResult,
+        generation: u64,
         filtered_rows: &[(usize, &Vec<String>)],
         sample_count: usize,
     ) -> u64 {
@@ -510,46 +798,32 @@ impl DataGrid {
         use std::hash::{Hash, Hasher};
 
         let mut hasher = DefaultHasher::new();
-
-        // 哈希列名
-        for col in &result.columns {
-            col.hash(&mut hasher);
-        }
-
-        // 哈希采样数据的前几个字符（避免大字符串影响性能）
-        for (idx, row_data) in filtered_rows.iter().take(sample_count) {
+        generation.hash(&mut hasher);
+        result.columns.hash(&mut hasher);
+        result.rows.len().hash(&mut hasher);
+        filtered_rows.len().hash(&mut hasher);
+        for (idx, _) in filtered_rows.iter().take(sample_count) {
             idx.hash(&mut hasher);
-            for cell in row_data.iter() {
-                // 只哈希前 50 个字符
-                let sample: String = cell.chars().take(50).collect();
-                sample.hash(&mut hasher);
-            }
         }
-
         hasher.finish()
     }
 
-    /// 获取列宽（优先使用缓存）
+    /// 获取自动列宽（数据未变化时使用缓存）
     fn get_column_widths(
         result: &QueryResult,
+        generation: u64,
         filtered_rows: &[(usize, &Vec<String>)],
         cache: &mut state::ColumnWidthCache,
     ) -> Vec<f32> {
-        let column_count = result.columns.len();
         let sample_count = filtered_rows.len().min(100);
-        let data_hash = Self::calculate_data_hash(result, filtered_rows, sample_count);
+        let data_key = Self::calculate_data_key(result, generation, filtered_rows, sample_count);
 
-        // 检查缓存是否有效
-        if cache.is_valid(column_count, sample_count, data_hash) {
+        if cache.is_valid(data_key) {
             return cache.widths.clone();
         }
 
-        // 计算新的列宽
         let widths = Self::calculate_column_widths_internal(result, filtered_rows, sample_count);
-
-        // 更新缓存
-        cache.update(widths.clone(), column_count, sample_count, data_hash);
-
+        cache.update(widths.clone(), data_key);
         widths
     }
 
@@ -593,6 +867,316 @@ impl DataGrid {
         });
     }
 
+    /// 打开重复行检测窗口，默认比较主键以外的所有列
+    fn open_duplicates_dialog(state: &mut DataGridState, result: &QueryResult) {
+        if state.duplicate_columns.len() != result.columns.len() {
+            state.duplicate_columns = (0..result.columns.len())
+                .map(|i| state.primary_key_column != Some(i) || result.columns.len() == 1)
+                .collect();
+        }
+        state.show_duplicates_dialog = true;
+        state.focused = false;
+    }
+
+    /// 重复行检测窗口：选择比较的列、查找并着色、保留每组第一行并删除其余行
+    fn show_duplicates_dialog(
+        ctx: &egui::Context,
+        state: &mut DataGridState,
+        result: &QueryResult,
+        table_name: Option<&str>,
+        actions: &mut DataGridActions,
+    ) {
+        if !state.show_duplicates_dialog {
+            return;
+        }
+
+        let mut open = true;
+        egui::Window::new("查找重复行")
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(true)
+            .default_width(320.0)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("比较的列:");
+                    if ui.small_button("全选").clicked() {
+                        state.duplicate_columns.iter_mut().for_each(|c| *c = true);
+                    }
+                    if ui.small_button("清空").clicked() {
+                        state.duplicate_columns.iter_mut().for_each(|c| *c = false);
+                    }
+                });
+                egui::ScrollArea::vertical().max_height(240.0).show(ui, |ui| {
+                    for (checked, name) in state.duplicate_columns.iter_mut().zip(&result.columns) {
+                        ui.checkbox(checked, name);
+                    }
+                });
+
+                ui.separator();
+                if let Some(d) = &state.duplicates {
+                    ui.label(
+                        RichText::new(format!("{} 组重复，{} 行多余", d.groups.len(), d.redundant_rows()))
+                            .color(GRAY),
+                    );
+                }
+
+                ui.horizontal(|ui| {
+                    let columns: Vec<usize> = state
+                        .duplicate_columns
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, checked)| **checked)
+                        .map(|(i, _)| i)
+                        .collect();
+                    if ui.add_enabled(!columns.is_empty(), egui::Button::new("🔍 查找")).clicked() {
+                        let found = DuplicateGroups::find(result, &columns);
+                        actions.message = Some(if found.groups.is_empty() {
+                            "没有重复行".to_string()
+                        } else {
+                            format!(
+                                "找到 {} 组重复行，共 {} 行，其中 {} 行多余",
+                                found.groups.len(),
+                                found.groups.iter().map(Vec::len).sum::<usize>(),
+                                found.redundant_rows()
+                            )
+                        });
+                        state.duplicates = Some(found).filter(|d| !d.groups.is_empty());
+                    }
+
+                    let has_duplicates = state.duplicates.is_some();
+                    if ui.add_enabled(has_duplicates, egui::Button::new("清除高亮")).clicked() {
+                        state.duplicates = None;
+                    }
+                    let method = state
+                        .duplicates
+                        .as_ref()
+                        .and_then(|d| d.delete_method(result, state.primary_key_column, state.db_type));
+                    let hint = match method {
+                        Some(DedupDelete::PrimaryKey(_)) => "标记每组第一行以外的行待删除，并生成按主键删除的 DELETE 语句",
+                        Some(DedupDelete::Limited(_)) => {
+                            "组内的行无法按主键区分，按比较列的值删除每组多余的行数（PostgreSQL 使用 ctid，MySQL 使用 LIMIT），保留的不一定是第一行"
+                        }
+                        None => "需要组内互不相同的主键值，或使用 PostgreSQL/MySQL 连接",
+                    };
+                    if ui
+                        .add_enabled(
+                            method.is_some() && table_name.is_some(),
+                            egui::Button::new("🗑 保留首行，删除其余"),
+                        )
+                        .on_hover_text(hint)
+                        .on_disabled_hover_text(hint)
+                        .clicked()
+                        && let (Some(d), Some(table), Some(method)) = (state.duplicates.take(), table_name, method)
+                    {
+                        state.show_duplicates_dialog = false;
+                        match method {
+                            DedupDelete::PrimaryKey(_) => {
+                                for row in d.rows_to_delete() {
+                                    if !state.rows_to_delete.contains(&row) {
+                                        state.rows_to_delete.push(row);
+                                    }
+                                }
+                                actions::generate_save_sql(result, state, table, actions);
+                            }
+                            DedupDelete::Limited(db_type) => match d.limited_delete_sql(result, table, db_type) {
+                                Ok(statements) => actions.sql_to_review = statements,
+                                Err(e) => actions.message = Some(format!("无法生成删除语句: {}", e)),
+                            },
+                        }
+                    }
+                });
+            });
+
+        if !open || ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
+            state.show_duplicates_dialog = false;
+            state.focused = true;
+        }
+    }
+
+    /// 列统计窗口（统计筛选后的行，已修改的单元格使用修改后的值）
+    fn show_column_stats(ctx: &egui::Context, state: &mut DataGridState) {
+        let Some(stats) = &state.column_stats else {
+            return;
+        };
+
+        let mut open = true;
+        egui::Window::new(format!("列统计 - {}", stats.column))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                let aggregate = &stats.aggregate;
+                egui::Grid::new("column_stats_grid")
+                    .num_columns(2)
+                    .spacing([24.0, 4.0])
+                    .show(ui, |ui| {
+                        let mut row = |label: &str, value: String| {
+                            ui.label(RichText::new(label).color(GRAY));
+                            ui.label(RichText::new(value).monospace());
+                            ui.end_row();
+                        };
+                        row("行数", aggregate.cells.to_string());
+                        row("非 NULL", aggregate.count.to_string());
+                        row("NULL/空值", stats.nulls.to_string());
+                        row("不同值", stats.distinct.to_string());
+                        if let Some(numeric) = aggregate.numeric {
+                            row("求和", format_number(numeric.sum));
+                            row("平均", format_number(numeric.avg));
+                        }
+                        if let (Some(min), Some(max)) = (&aggregate.min, &aggregate.max) {
+                            row("最小", min.chars().take(CELL_TRUNCATE_LEN).collect());
+                            row("最大", max.chars().take(CELL_TRUNCATE_LEN).collect());
+                        }
+                    });
+                ui.label(
+                    RichText::new("统计当前筛选后的行；非 NULL 值均为数字时按数值统计")
+                        .small()
+                        .color(GRAY),
+                );
+            });
+
+        if !open || ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
+            state.column_stats = None;
+        }
+    }
+
+    /// JSON 查看窗口：树形视图或格式化文本
+    fn show_json_viewer(ctx: &egui::Context, state: &mut DataGridState, actions: &mut DataGridActions) {
+        let Some(viewer) = &mut state.json_viewer else {
+            return;
+        };
+
+        let mut open = true;
+        egui::Window::new(format!("JSON - {}", viewer.title))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(true)
+            .default_size([420.0, 360.0])
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.selectable_value(&mut viewer.show_text, false, "树形");
+                    ui.selectable_value(&mut viewer.show_text, true, "文本");
+                    ui.separator();
+                    if ui.button("📋 复制").on_hover_text("复制格式化后的 JSON").clicked() {
+                        ui.ctx().copy_text(viewer.pretty.clone());
+                        actions.message = Some("已复制格式化的 JSON".to_string());
+                    }
+                });
+                ui.separator();
+                egui::ScrollArea::both().auto_shrink([false, false]).show(ui, |ui| {
+                    if viewer.show_text {
+                        ui.add(egui::Label::new(RichText::new(&viewer.pretty).monospace()).extend());
+                    } else {
+                        json_view::show_json_tree(ui, "$", &viewer.value, 0);
+                    }
+                });
+            });
+
+        if !open || ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
+            state.json_viewer = None;
+            state.focused = true;
+        }
+    }
+
+    /// 二进制查看窗口：十六进制/ASCII 对照、保存到文件、从文件导入
+    fn show_blob_viewer(
+        ctx: &egui::Context,
+        state: &mut DataGridState,
+        table_name: Option<&str>,
+        actions: &mut DataGridActions,
+    ) {
+        let Some(viewer) = &mut state.blob_viewer else {
+            return;
+        };
+
+        let mut open = true;
+        let mut imported = None;
+        egui::Window::new(format!("二进制 - {}", viewer.title))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(true)
+            .default_size([560.0, 360.0])
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(RichText::new(format!("{} 字节", viewer.bytes.len())).color(GRAY));
+                    ui.separator();
+                    if ui.button("💾 保存到文件…").clicked()
+                        && let Some(path) = rfd::FileDialog::new().save_file()
+                    {
+                        actions.message = Some(match viewer.save_to(&path) {
+                            Ok(()) => format!("已保存 {} 字节到 {}", viewer.bytes.len(), path.display()),
+                            Err(e) => e,
+                        });
+                    }
+                    if ui
+                        .add_enabled(table_name.is_some(), egui::Button::new("📂 从文件导入…"))
+                        .on_hover_text("用文件内容替换单元格，保存修改后写入数据库")
+                        .clicked()
+                        && let Some(path) = rfd::FileDialog::new().pick_file()
+                    {
+                        match viewer.import_from(&path) {
+                            Ok(encoded) => imported = Some((viewer.cell, encoded)),
+                            Err(e) => actions.message = Some(e),
+                        }
+                    }
+                });
+                if viewer.is_truncated() {
+                    ui.label(
+                        RichText::new(format!(
+                            "只显示前 {} 字节，完整内容请保存到文件",
+                            constants::grid::BLOB_VIEW_MAX_BYTES
+                        ))
+                        .small()
+                        .color(GRAY),
+                    );
+                }
+                ui.separator();
+                egui::ScrollArea::both().auto_shrink([false, false]).show(ui, |ui| {
+                    ui.add(egui::Label::new(RichText::new(&viewer.dump).monospace()).extend());
+                });
+            });
+
+        if let Some((cell, encoded)) = imported {
+            let len = viewer.bytes.len();
+            state.modified_cells.insert(cell, encoded);
+            actions.message = Some(format!("已导入 {} 字节，保存修改后写入数据库", len));
+        }
+        if !open || ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
+            state.blob_viewer = None;
+            state.focused = true;
+        }
+    }
+
+    /// 几何图形查看窗口：按比例绘制图形并列出坐标
+    fn show_geometry_viewer(ctx: &egui::Context, state: &mut DataGridState, actions: &mut DataGridActions) {
+        let Some(viewer) = &state.geometry_viewer else {
+            return;
+        };
+
+        let mut open = true;
+        egui::Window::new(format!("几何图形 - {}", viewer.title))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(true)
+            .default_size([620.0, 380.0])
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                if let Some(wkt) = geometry_view::show_viewer(ui, viewer) {
+                    ui.ctx().copy_text(wkt);
+                    actions.message = Some("已复制 WKT".to_string());
+                }
+            });
+
+        if !open || ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
+            state.geometry_viewer = None;
+            state.focused = true;
+        }
+    }
+
     /// 显示跳转对话框 (Ctrl+G)
     fn show_goto_dialog(ctx: &egui::Context, state: &mut DataGridState, max_row: usize) {
         if !state.show_goto_dialog {
@@ -655,89 +1239,272 @@ impl DataGrid {
                 });
             });
     }
+}
 
-    /// 显示保存确认对话框（危险操作确认）
-    fn show_save_confirm_dialog(
-        ctx: &egui::Context,
+/// 表格的哪一部分（有冻结列时分为左右两个表格）
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum TablePart {
+    /// 没有冻结列，一个表格显示所有列
+    Whole,
+    /// 左侧冻结的列（含行号）
+    Frozen,
+    /// 右侧可以横向滚动的列
+    Scrolled,
+}
+
+/// 渲染表格所需的共享数据
+struct GridTable<'a> {
+    result: &'a QueryResult,
+    filtered_rows: &'a [(usize, &'a Vec<String>)],
+    /// 置顶的行（原始索引和数据）
+    pinned_rows: &'a [(usize, &'a Vec<String>)],
+    matcher: Option<&'a SearchMatcher>,
+    search_col_idx: Option<usize>,
+    /// 所有列的宽度（按列索引）
+    col_widths: &'a [f32],
+    /// 可见列的数量
+    visible_count: usize,
+    /// 冻结的可见列数
+    frozen: usize,
+    reset_widths: bool,
+    scroll_to_row: Option<usize>,
+    row_height: f32,
+}
+
+impl GridTable<'_> {
+    /// 渲染 `cols` 中的列，返回表格的垂直滚动偏移
+    ///
+    /// 有置顶行时列头和置顶行在上方单独的表格中，下方表格只显示数据行。
+    fn show(
+        &self,
+        ui: &mut egui::Ui,
         state: &mut DataGridState,
-        actions: &mut DataGridActions,
-    ) {
-        if !state.show_save_confirm {
-            return;
+        cols: &[usize],
+        part: TablePart,
+        rendered_widths: &mut [Option<f32>],
+        header_actions: &mut Vec<HeaderAction>,
+    ) -> f32 {
+        let with_row_numbers = part != TablePart::Scrolled;
+
+        if !self.pinned_rows.is_empty() {
+            let salt = if part == TablePart::Frozen { "grid_pinned_frozen" } else { "grid_pinned_rows" };
+            let pinned_table = self.builder(ui, cols, part, true).id_salt(salt).vscroll(false);
+            // 置顶区域只显示值，编辑在下方表格中进行（避免同一单元格出现两个输入框）
+            let editing_cell = state.editing_cell.take();
+            pinned_table
+                .header(HEADER_HEIGHT, |header| {
+                    self.show_header(header, state, cols, part, rendered_widths, header_actions);
+                })
+                .body(|body| {
+                    body.rows(self.row_height, self.pinned_rows.len(), |row| {
+                        let (original_idx, row_data) = self.pinned_rows[row.index()];
+                        self.show_data_row(row, state, cols, with_row_numbers, original_idx, row_data);
+                    });
+                });
+            state.editing_cell = editing_cell;
+            ui.separator();
         }
 
-        let delete_count = state.rows_to_delete.len();
-        let total_count = state.pending_sql.len();
+        // 构建表格，保留内部垂直滚动（有置顶行时列宽在上方表格中调整）
+        let mut table_builder = self.builder(ui, cols, part, self.pinned_rows.is_empty());
+        if part == TablePart::Frozen {
+            table_builder = table_builder
+                .id_salt("grid_frozen_columns")
+                .scroll_bar_visibility(egui::scroll_area::ScrollBarVisibility::AlwaysHidden);
+        }
+        // 有冻结列时两个表格使用相同的垂直偏移
+        if part != TablePart::Whole {
+            table_builder = table_builder
+                .vertical_scroll_offset(state.v_scroll_offset)
+                .animate_scrolling(false);
+        }
 
-        egui::Window::new("确认保存")
-            .collapsible(false)
-            .resizable(false)
-            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
-            .show(ctx, |ui| {
-                ui.vertical(|ui| {
-                    ui.label(RichText::new("此操作包含危险操作，请确认：").strong());
-                    ui.add_space(8.0);
+        // 使用表格内部的垂直滚动
+        if let Some(target_row) = self.scroll_to_row {
+            table_builder = table_builder.scroll_to_row(target_row, Some(egui::Align::Center));
+        }
 
-                    // 显示操作统计
-                    ui.horizontal(|ui| {
-                        ui.label(format!("将删除 {} 行数据", delete_count));
-                    });
-                    ui.horizontal(|ui| {
-                        ui.label(format!("共 {} 条 SQL 语句", total_count));
-                    });
+        let add_body = |body: egui_extras::TableBody<'_>, state: &mut DataGridState| {
+            let filtered_rows = self.filtered_rows;
+            let filtered_count = filtered_rows.len() + state.new_rows.len();
+            body.rows(self.row_height, filtered_count, |row| {
+                let display_idx = row.index();
 
-                    ui.add_space(8.0);
-
-                    // 显示预览的 SQL（最多显示5条）
-                    ui.collapsing("查看 SQL 预览", |ui| {
-                        egui::ScrollArea::vertical()
-                            .max_height(150.0)
-                            .show(ui, |ui| {
-                                for (i, sql) in state.pending_sql.iter().enumerate() {
-                                    let is_delete = sql.starts_with("DELETE");
-                                    let color = if is_delete {
-                                        Color32::from_rgb(200, 80, 80)
-                                    } else {
-                                        GRAY
-                                    };
-                                    ui.label(
-                                        RichText::new(format!("{}. {}", i + 1, sql))
-                                            .small()
-                                            .color(color),
-                                    );
-                                }
-                            });
-                    });
+                // 判断是显示已有数据还是新增行
+                if let Some((original_idx, row_data)) = filtered_rows.get(display_idx) {
+                    state.note_rendered_row(*original_idx);
+                    self.show_data_row(row, state, cols, with_row_numbers, *original_idx, row_data);
+                } else {
+                    self.show_new_row(row, state, cols, with_row_numbers, display_idx - filtered_rows.len());
+                }
+            });
+        };
+        let output = if self.pinned_rows.is_empty() {
+            table_builder
+                .header(HEADER_HEIGHT, |header| {
+                    self.show_header(header, state, cols, part, rendered_widths, header_actions);
+                })
+                .body(|body| add_body(body, state))
+        } else {
+            table_builder.body(|body| add_body(body, state))
+        };
+        output.state.offset.y
+    }
 
-                    ui.add_space(12.0);
+    /// 创建表格并设置列
+    fn builder<'b>(
+        &self,
+        ui: &'b mut egui::Ui,
+        cols: &[usize],
+        part: TablePart,
+        resizable: bool,
+    ) -> TableBuilder<'b> {
+        let mut table_builder = TableBuilder::new(ui)
+            .striped(true)
+            .resizable(resizable)
+            .cell_layout(egui::Layout::left_to_right(egui::Align::Center));
+        if part != TablePart::Scrolled {
+            table_builder = table_builder.column(Column::exact(ROW_NUM_WIDTH));
+        }
 
-                    ui.horizontal(|ui| {
-                        // 确认按钮（红色警告文字）
-                        if ui
-                            .add(
-                                egui::Button::new(RichText::new("⚠ 确认执行").size(13.0).color(Color32::from_rgb(255, 100, 100)))
-                                    .frame(false)
-                                    .min_size(Vec2::new(0.0, 24.0)),
-                            )
-                            .on_hover_text("确认执行 SQL 操作 [Enter]")
-                            .clicked()
-                        {
-                            actions::confirm_pending_sql(state, actions);
-                        }
+        // 为每列设置基于内容的初始宽度
+        for &col_idx in cols {
+            table_builder = table_builder.column(
+                Column::initial(self.col_widths[col_idx])
+                    .at_least(MIN_COL_WIDTH)
+                    .clip(true),
+            );
+        }
 
-                        ui.add_space(16.0);
+        if self.reset_widths {
+            table_builder.reset();
+        }
+        table_builder
+    }
 
-                        if ui.add(
-                            egui::Button::new(RichText::new("✕ 取消").size(13.0).color(Color32::LIGHT_GRAY))
-                                .frame(false)
-                                .min_size(Vec2::new(0.0, 24.0)),
-                        ).on_hover_text("取消 [Esc]").clicked()
-                            || ui.input(|i| i.key_pressed(egui::Key::Escape))
-                        {
-                            actions::cancel_pending_sql(state);
-                        }
-                    });
-                });
+    /// 渲染列头
+    fn show_header(
+        &self,
+        mut header: egui_extras::TableRow<'_, '_>,
+        state: &mut DataGridState,
+        cols: &[usize],
+        part: TablePart,
+        rendered_widths: &mut [Option<f32>],
+        header_actions: &mut Vec<HeaderAction>,
+    ) {
+        // 可见列中排在 cols 之前的列数（冻结列在左侧）
+        let first_pos = if part == TablePart::Scrolled { self.frozen } else { 0 };
+        // 行号列头
+        if part != TablePart::Scrolled {
+            header.col(|ui| {
+                ui.label(RichText::new("#").strong().color(GRAY));
+            });
+        }
+        // 数据列头
+        for (pos, &col_idx) in cols.iter().enumerate() {
+            header.col(|ui| {
+                rendered_widths[col_idx] = Some(ui.max_rect().width());
+                render::render_column_header(
+                    ui,
+                    &self.result.columns[col_idx],
+                    col_idx,
+                    first_pos + pos,
+                    self.visible_count,
+                    part == TablePart::Frozen,
+                    state,
+                    header_actions,
+                );
             });
+        }
+    }
+
+    /// 渲染已有数据行
+    fn show_data_row(
+        &self,
+        mut row: egui_extras::TableRow<'_, '_>,
+        state: &mut DataGridState,
+        cols: &[usize],
+        with_row_numbers: bool,
+        original_idx: usize,
+        row_data: &[String],
+    ) {
+        let is_cursor_row = state.cursor.0 == original_idx;
+        let is_row_deleted = state.rows_to_delete.contains(&original_idx);
+
+        row.set_selected(is_cursor_row || is_row_deleted);
+
+        // 行号列
+        if with_row_numbers {
+            let is_bookmarked = !state.bookmarked_rows.is_empty()
+                && state
+                    .primary_key_column
+                    .and_then(|pk| row_data.get(pk))
+                    .is_some_and(|key| state.bookmarked_rows.contains(key));
+            row.col(|ui| {
+                render::render_row_number(ui, original_idx, is_cursor_row, is_row_deleted, is_bookmarked, state);
+            });
+        }
+
+        // 数据列
+        for &col_idx in cols {
+            let cell = row_data.get(col_idx).map_or("", String::as_str);
+            let highlight = self
+                .matcher
+                .filter(|_| self.search_col_idx.is_none_or(|c| c == col_idx));
+            row.col(|ui| {
+                render::render_editable_cell(
+                    ui,
+                    &self.result.columns[col_idx],
+                    cell,
+                    original_idx,
+                    col_idx,
+                    is_cursor_row,
+                    is_row_deleted,
+                    highlight,
+                    state,
+                );
+            });
+        }
+    }
+
+    /// 渲染新增行（pending rows）
+    fn show_new_row(
+        &self,
+        mut row: egui_extras::TableRow<'_, '_>,
+        state: &mut DataGridState,
+        cols: &[usize],
+        with_row_numbers: bool,
+        new_row_idx: usize,
+    ) {
+        // 新增行的虚拟原始索引 = 结果行数 + 新增行索引
+        let virtual_idx = self.result.rows.len() + new_row_idx;
+        let is_cursor_row = state.cursor.0 == virtual_idx;
+
+        // 新增行使用特殊高亮
+        row.set_selected(is_cursor_row);
+
+        // 行号列 - 显示 "+" 标记表示新增行
+        if with_row_numbers {
+            row.col(|ui| {
+                let text = RichText::new(format!("{}+", virtual_idx + 1))
+                    .monospace()
+                    .color(Color32::from_rgb(100, 200, 100));
+                ui.label(text);
+            });
+        }
+
+        // 数据列 - 显示新增行的内容
+        // 先克隆数据避免借用冲突
+        let new_row_data: Vec<String> = state
+            .new_rows
+            .get(new_row_idx)
+            .cloned()
+            .unwrap_or_default();
+        for &col_idx in cols {
+            let cell = new_row_data.get(col_idx).map_or("", String::as_str);
+            row.col(|ui| {
+                render::render_new_row_cell(ui, cell, virtual_idx, col_idx, is_cursor_row, state);
+            });
+        }
     }
 }
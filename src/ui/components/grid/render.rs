@@ -2,11 +2,20 @@
 
 #![allow(clippy::too_many_arguments)]
 
+use super::blob_view::{self, BlobViewer};
+use super::filter::SearchMatcher;
+use super::geometry_view::{self, GeometryViewer};
+use super::json_view::{self, JsonViewer};
 use super::mode::GridMode;
+use super::sort::ColumnSort;
 use super::state::DataGridState;
 use super::{
     CELL_TRUNCATE_LEN, COLOR_CELL_EDITING, COLOR_CELL_MODIFIED, COLOR_CELL_SELECTED,
-    COLOR_VISUAL_SELECT,
+    COLOR_DUPLICATE_GROUPS, COLOR_VISUAL_SELECT,
+};
+use crate::core::{
+    blob_label, blob_len, grid_text_style, is_blob, is_number, looks_like_json, mask_value, parse_geometry, pretty_json, t,
+    NumberFormat, NumberSeparators,
 };
 use crate::ui::styles::GRAY;
 use egui::{self, Color32, Key, RichText, Sense, TextEdit, Vec2};
@@ -14,14 +23,54 @@ use egui::{self, Color32, Key, RichText, Sense, TextEdit, Vec2};
 
 // NULL 值颜色
 const COLOR_NULL: Color32 = Color32::from_rgb(120, 120, 140);
+// 几何值摘要颜色
+const COLOR_GEOMETRY: Color32 = Color32::from_rgb(110, 170, 200);
+// 搜索匹配片段的背景色
+const COLOR_SEARCH_MATCH: Color32 = Color32::from_rgb(150, 120, 30);
+
+/// 列头上的操作（渲染完表格后统一处理）
+pub enum HeaderAction {
+    /// 为列添加筛选条件
+    Filter(String),
+    /// 修改列的数字格式（`None` 表示恢复全局设置）
+    Format(String, Option<NumberFormat>),
+    /// 按内容自动调整列宽
+    AutoFit(String),
+    /// 自动调整所有列宽
+    AutoFitAll,
+    /// 修改排序（`None` 表示取消排序）
+    Sort(Option<ColumnSort>),
+    /// 隐藏列
+    Hide(String),
+    /// 显示所有隐藏的列
+    ShowAllColumns,
+    /// 冻结前 N 个可见列（0 表示取消冻结）
+    Freeze(usize),
+    /// 查看列统计
+    Stats(usize),
+    /// 打开列的不同值面板
+    Facet(usize),
+    /// 把列移动到另一列的位置（拖动列头）
+    MoveColumn { from: usize, to: usize },
+    /// 恢复列的原始顺序
+    ResetColumnOrder,
+    /// 标记或取消标记敏感列
+    Mask(String, bool),
+}
+
+/// 拖动列头时携带的列索引
+struct DraggedColumn(usize);
 
-/// 渲染列头
+/// 渲染列头，`visible_pos` 为列在可见列中的位置
 pub fn render_column_header(
     ui: &mut egui::Ui,
     col_name: &str,
     col_idx: usize,
+    visible_pos: usize,
+    visible_count: usize,
+    is_frozen: bool,
     state: &DataGridState,
-    columns_to_filter: &mut Vec<String>,
+    header_actions: &mut Vec<HeaderAction>,
 ) {
     ui.horizontal(|ui| {
         let is_cursor_col = state.cursor.1 == col_idx;
@@ -38,7 +87,118 @@ pub fn render_column_header(
             // 使用默认文字颜色（由主题控制），不单独设置颜色
             RichText::new(col_name).strong()
         };
-        ui.label(text);
+        let response = ui.add(egui::Label::new(text).sense(Sense::click_and_drag()));
+        // 拖动列名到另一列的列头上调整列顺序
+        response.dnd_set_drag_payload(DraggedColumn(col_idx));
+
+        // 右键排序、筛选、统计、隐藏/冻结列、调整列宽、设置本列的数字格式（按表保存）、标记敏感列（按连接保存）
+        let custom = state.column_formats.get(col_name).copied();
+        let masked = state.is_masked(col_name);
+        let sort = state.sort.as_ref().filter(|s| s.column == col_name);
+        response.context_menu(|ui| {
+            if ui.button("▲ 升序排序").clicked() {
+                header_actions.push(HeaderAction::Sort(Some(ColumnSort::ascending(col_name))));
+                ui.close();
+            }
+            if ui.button("▼ 降序排序").clicked() {
+                header_actions.push(HeaderAction::Sort(Some(ColumnSort::descending(col_name))));
+                ui.close();
+            }
+            if ui.add_enabled(sort.is_some(), egui::Button::new("✖ 取消排序")).clicked() {
+                header_actions.push(HeaderAction::Sort(None));
+                ui.close();
+            }
+            ui.separator();
+            if ui.button("🔍 筛选此列").clicked() {
+                header_actions.push(HeaderAction::Filter(col_name.to_string()));
+                ui.close();
+            }
+            if ui.button("📊 列统计").clicked() {
+                header_actions.push(HeaderAction::Stats(col_idx));
+                ui.close();
+            }
+            if ui.button("☑ 不同值筛选").on_hover_text("在右侧列出每个值和出现次数，勾选值进行筛选").clicked() {
+                header_actions.push(HeaderAction::Facet(col_idx));
+                ui.close();
+            }
+            if ui.button("📋 复制列名").clicked() {
+                ui.ctx().copy_text(col_name.to_string());
+                ui.close();
+            }
+            ui.separator();
+            if ui.add_enabled(visible_count > 1, egui::Button::new("🚫 隐藏此列")).clicked() {
+                header_actions.push(HeaderAction::Hide(col_name.to_string()));
+                ui.close();
+            }
+            if ui
+                .add_enabled(!state.hidden_columns.is_empty(), egui::Button::new("👁 显示所有列"))
+                .clicked()
+            {
+                header_actions.push(HeaderAction::ShowAllColumns);
+                ui.close();
+            }
+            if ui.button("📌 冻结到此列").clicked() {
+                header_actions.push(HeaderAction::Freeze(visible_pos + 1));
+                ui.close();
+            }
+            if ui.add_enabled(state.frozen_columns > 0, egui::Button::new("✖ 取消冻结")).clicked() {
+                header_actions.push(HeaderAction::Freeze(0));
+                ui.close();
+            }
+            if ui
+                .add_enabled(!state.column_order.is_empty(), egui::Button::new("↺ 恢复列顺序"))
+                .on_hover_text("拖动列名或按 < / > 调整列顺序")
+                .clicked()
+            {
+                header_actions.push(HeaderAction::ResetColumnOrder);
+                ui.close();
+            }
+            ui.separator();
+            if ui.button("↔ 自动调整列宽").clicked() {
+                header_actions.push(HeaderAction::AutoFit(col_name.to_string()));
+                ui.close();
+            }
+            if ui.button("⇔ 自动调整所有列宽").clicked() {
+                header_actions.push(HeaderAction::AutoFitAll);
+                ui.close();
+            }
+            ui.separator();
+            if let Some(change) = number_format_menu(ui, custom, state.value_format.number) {
+                header_actions.push(HeaderAction::Format(col_name.to_string(), change));
+            }
+            let mask_label = if masked { t("masking.unmark_column") } else { t("masking.mark_column") };
+            if ui
+                .button(mask_label)
+                .on_hover_text(t("masking.mark_column_hint"))
+                .clicked()
+            {
+                header_actions.push(HeaderAction::Mask(col_name.to_string(), !masked));
+                ui.close();
+            }
+        });
+
+        if masked {
+            ui.label(RichText::new("🔒").size(10.0).color(GRAY)).on_hover_text(t("masking.column_badge"));
+        }
+
+        if is_frozen {
+            ui.label(RichText::new("📌").size(10.0).color(GRAY)).on_hover_text("冻结列：横向滚动时保持可见");
+        }
+
+        if let Some(sort) = sort {
+            ui.label(RichText::new(sort.indicator()).size(10.0).color(state.mode.color()))
+                .on_hover_text(if sort.descending { "降序排序" } else { "升序排序" });
+        }
+
+        if state.value_format.converts_timestamps() && state.timestamp_columns.get(col_idx).is_some_and(|&t| t) {
+            ui.label(RichText::new("🕒").size(10.0).color(GRAY))
+                .on_hover_text(state.value_format.timestamp_hint());
+        }
+
+        if state.json_columns.contains(col_idx) {
+            ui.label(RichText::new("{}").size(10.0).color(GRAY))
+                .on_hover_text("JSON 列：悬停单元格预览，右键查看");
+        }
 
         // 筛选按钮 - 无边框图标
         let filter_icon = if has_filter { "▼" } else { "·" };
@@ -52,9 +212,64 @@ pub fn render_column_header(
                 .frame(false)
                 .min_size(Vec2::new(16.0, 16.0)),
         ).on_hover_text(format!("筛选 {} 列", col_name)).clicked() {
-            columns_to_filter.push(col_name.to_string());
+            header_actions.push(HeaderAction::Filter(col_name.to_string()));
+        }
+    });
+
+    // 整个列头都可以放下拖动的列，悬停时在列头左侧显示插入位置
+    let drop_zone = ui.interact(ui.max_rect(), ui.id().with(("column_drop", col_idx)), Sense::hover());
+    if let Some(dragged) = drop_zone.dnd_hover_payload::<DraggedColumn>()
+        && dragged.0 != col_idx
+    {
+        let rect = drop_zone.rect;
+        ui.painter().vline(rect.left(), rect.y_range(), egui::Stroke::new(2.0, state.mode.color()));
+    }
+    if let Some(dragged) = drop_zone.dnd_release_payload::<DraggedColumn>()
+        && dragged.0 != col_idx
+    {
+        header_actions.push(HeaderAction::MoveColumn { from: dragged.0, to: col_idx });
+    }
+}
+
+/// 列头右键菜单中的数字格式设置，返回修改后的格式（`Some(None)` 表示恢复全局设置）
+fn number_format_menu(
+    ui: &mut egui::Ui,
+    custom: Option<NumberFormat>,
+    global: NumberFormat,
+) -> Option<Option<NumberFormat>> {
+    let mut format = custom.unwrap_or(global);
+    let before = format;
+    ui.label(RichText::new("数字格式").strong());
+    for &separators in NumberSeparators::all() {
+        ui.radio_value(&mut format.separators, separators, separators.example());
+    }
+    ui.separator();
+    ui.horizontal(|ui| {
+        ui.label("小数位:");
+        ui.radio_value(&mut format.decimals, None, "原样");
+        for decimals in 0..=4 {
+            ui.radio_value(&mut format.decimals, Some(decimals), decimals.to_string());
         }
     });
+    ui.checkbox(&mut format.scientific, "科学计数法");
+    ui.checkbox(&mut format.align_right, "数字右对齐");
+    if custom.is_some() {
+        ui.separator();
+        if ui.button("↺ 恢复全局设置").clicked() {
+            ui.close();
+            return Some(None);
+        }
+    }
+    (format != before).then_some(Some(format))
+}
+
+/// 重复行所在分组的背景色（不是重复行时透明）
+fn duplicate_color(state: &DataGridState, row_idx: usize) -> Color32 {
+    state
+        .duplicates
+        .as_ref()
+        .and_then(|d| d.group_of(row_idx))
+        .map_or(Color32::TRANSPARENT, |group| COLOR_DUPLICATE_GROUPS[group % COLOR_DUPLICATE_GROUPS.len()])
 }
 
 /// 渲染行号单元格
@@ -63,29 +278,38 @@ pub fn render_row_number(
     row_idx: usize,
     is_cursor_row: bool,
     is_deleted: bool,
+    is_bookmarked: bool,
     state: &mut DataGridState,
 ) {
     // 只在删除状态时设置背景色，普通行由表格的 set_selected 和 striped 效果处理
     let bg = if is_deleted {
         Color32::from_rgb(150, 50, 50)
     } else {
-        Color32::TRANSPARENT
+        duplicate_color(state, row_idx)
     };
 
     egui::Frame::NONE
         .fill(bg)
         .inner_margin(4.0)
         .show(ui, |ui| {
+            let is_pinned = state.pinned_rows.contains(&row_idx);
+            // 置顶和收藏的行在行号前显示标记
+            let number = format!(
+                "{}{}{}",
+                if is_pinned { "📌" } else { "" },
+                if is_bookmarked { "🔖" } else { "" },
+                row_idx + 1
+            );
             let text = if is_deleted {
                 RichText::new(format!("✕{}", row_idx + 1))
                     .color(Color32::WHITE)
                     .small()
             } else if is_cursor_row {
-                RichText::new(format!("{}", row_idx + 1))
+                RichText::new(number)
                     .color(state.mode.color())
                     .small()
             } else {
-                RichText::new(format!("{}", row_idx + 1))
+                RichText::new(number)
                     .color(GRAY)
                     .small()
             };
@@ -107,6 +331,21 @@ pub fn render_row_number(
                     ).on_hover_text(tooltip).clicked()
                 };
                 
+                let pin_text = if is_pinned { "取消置顶" } else { "置顶" };
+                if menu_btn(ui, "📌", pin_text, "置顶到表格上方，滚动时保持可见 (m)", Color32::LIGHT_GRAY) {
+                    state.toggle_pinned_row(row_idx);
+                    ui.close();
+                }
+
+                // 收藏按主键保存，需要已知主键列
+                if state.primary_key_column.is_some() {
+                    let bookmark_text = if is_bookmarked { "取消收藏" } else { "收藏此行" };
+                    if menu_btn(ui, "🔖", bookmark_text, "按主键收藏，可在行书签列表中跳转", Color32::LIGHT_GRAY) {
+                        state.pending_bookmark = Some(row_idx);
+                        ui.close();
+                    }
+                }
+
                 if is_deleted {
                     if menu_btn(ui, "↩", "取消删除", "取消删除 (u)", Color32::LIGHT_GRAY) {
                         state.rows_to_delete.retain(|&x| x != row_idx);
@@ -125,11 +364,13 @@ pub fn render_row_number(
 /// 渲染可编辑的数据单元格
 pub fn render_editable_cell(
     ui: &mut egui::Ui,
+    column: &str,
     cell: &str,
     row_idx: usize,
     col_idx: usize,
     _is_cursor_row: bool, // 行级别高亮由 set_selected 处理
     is_row_deleted: bool,
+    highlight: Option<&SearchMatcher>,
     state: &mut DataGridState,
 ) {
     let is_cursor = state.cursor == (row_idx, col_idx);
@@ -155,7 +396,7 @@ pub fn render_editable_cell(
     } else if is_cursor {
         COLOR_CELL_SELECTED
     } else {
-        Color32::TRANSPARENT
+        duplicate_color(state, row_idx)
     };
 
     egui::Frame::NONE
@@ -168,12 +409,14 @@ pub fn render_editable_cell(
                 render_display_cell(
                     ui,
                     state,
+                    column,
                     cell,
                     &display_value,
                     row_idx,
                     col_idx,
                     is_cursor,
                     is_row_deleted,
+                    highlight,
                 );
             }
         });
@@ -188,7 +431,7 @@ fn render_editing_cell(
     let response = ui.add(
         TextEdit::singleline(&mut state.edit_text)
             .desired_width(ui.available_width() - 8.0)
-            .font(egui::TextStyle::Monospace),
+            .font(grid_text_style()),
     );
 
     let should_exit = ui.input(|i| i.key_pressed(Key::Escape) || i.key_pressed(Key::Enter));
@@ -209,15 +452,40 @@ fn render_editing_cell(
 fn render_display_cell(
     ui: &mut egui::Ui,
     state: &mut DataGridState,
+    column: &str,
     cell: &str,
     display_value: &str,
     row_idx: usize,
     col_idx: usize,
     is_cursor: bool,
     is_row_deleted: bool,
+    highlight: Option<&SearchMatcher>,
 ) {
-    let cell_text = format_cell_text(display_value, is_cursor);
-    let response = ui.add(egui::Label::new(cell_text).sense(Sense::click()));
+    // 时间戳列按显示设置转换时区，编辑和复制仍使用原始值；敏感列只显示脱敏后的值
+    let masked = state.is_masked(column);
+    let number_format = state.column_formats.get(column).copied().unwrap_or(state.value_format.number);
+    let converted = if masked {
+        Some(mask_value(display_value))
+    } else {
+        state
+            .timestamp_columns
+            .get(col_idx)
+            .is_some_and(|&is_timestamp| is_timestamp)
+            .then(|| state.value_format.format_timestamp(display_value))
+            .flatten()
+            .or_else(|| number_format.format(display_value))
+    };
+    let shown = converted.as_deref().unwrap_or(display_value);
+    let label = match highlight_cell_text(ui, shown, is_cursor, highlight) {
+        Some(job) => egui::Label::new(job),
+        None => egui::Label::new(format_cell_text(shown, is_cursor)),
+    }
+    .sense(Sense::click());
+    let response = if number_format.align_right && is_number(display_value) {
+        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| ui.add(label)).inner
+    } else {
+        ui.add(label)
+    };
 
     if response.clicked() {
         state.cursor = (row_idx, col_idx);
@@ -252,7 +520,7 @@ fn render_display_cell(
         }
         if menu_btn(ui, "📋", "复制", "复制内容 (y)") {
             state.clipboard = Some(display_value.to_string());
-            ui.ctx().copy_text(display_value.to_string());
+            ui.ctx().copy_text(if masked { mask_value(display_value) } else { display_value.to_string() });
             ui.close();
         }
         if menu_btn(ui, "📥", "粘贴", "粘贴内容 (p)") {
@@ -263,22 +531,95 @@ fn render_display_cell(
             }
             ui.close();
         }
-        if state.modified_cells.contains_key(&(row_idx, col_idx)) && menu_btn(ui, "↩", "还原", "还原修改 (u)")
+        // 敏感列的原值同样脱敏显示
+        let original = if masked { mask_value(cell) } else { shorten_hover_value(cell) };
+        if state.modified_cells.contains_key(&(row_idx, col_idx))
+            && menu_btn(ui, "↩", "还原", &format!("还原为原值: {} (u)", original))
         {
             state.modified_cells.remove(&(row_idx, col_idx));
             ui.close();
         }
+        // 敏感列不提供会显示原始值的查看器
+        if masked {
+            return;
+        }
+        if let Some(viewer) = GeometryViewer::open(format!("{} #{}", column, row_idx + 1), display_value) {
+            if menu_btn(ui, "🌐", "查看几何图形", "绘制图形并列出坐标") {
+                state.geometry_viewer = Some(viewer);
+                state.focused = false;
+                ui.close();
+            }
+        } else if is_blob(display_value) && menu_btn(ui, "🔢", "查看二进制", "十六进制查看，保存到文件或从文件导入") {
+            state.blob_viewer = BlobViewer::open(format!("{} #{}", column, row_idx + 1), (row_idx, col_idx), display_value);
+            state.focused = false;
+            ui.close();
+        }
+        if looks_like_json(display_value) && menu_btn(ui, "{}", "查看 JSON", "格式化查看 JSON 内容") {
+            state.json_viewer = JsonViewer::open(format!("{} #{}", column, row_idx + 1), display_value);
+            state.focused = false;
+            ui.close();
+        }
     });
 
-    if show_hover {
+    // 敏感列不显示原始值的悬停预览
+    if masked {
+        return;
+    }
+
+    // 已修改的单元格悬停时显示原值，其余单元格由插件渲染器优先提供悬停预览，
+    // 其次是几何图形、二进制内容和格式化的 JSON
+    let plugins = std::sync::Arc::clone(&state.plugins);
+    if state.modified_cells.contains_key(&(row_idx, col_idx)) {
+        response.on_hover_ui(|ui| show_modified_hover(ui, cell, display_value));
+    } else if let Some(renderer) = plugins.renderer_for(column, display_value) {
+        response.on_hover_ui(|ui| renderer.show(ui, column, display_value));
+    } else if let Some(value) = response.hovered().then(|| parse_geometry(display_value)).flatten() {
+        response.on_hover_ui(|ui| geometry_view::show_hover(ui, &value));
+    } else if let Some(dump) = response.hovered().then(|| blob_view::hover_preview(display_value)).flatten() {
+        response.on_hover_ui(|ui| {
+            ui.label(RichText::new(dump).monospace());
+        });
+    } else if let Some(pretty) = response.hovered().then(|| pretty_json(display_value)).flatten() {
+        response.on_hover_ui(|ui| {
+            ui.label(RichText::new(json_view::hover_preview(&pretty)).monospace());
+        });
+    } else if show_hover {
         response.on_hover_text(display_value);
     }
 }
 
+/// 已修改单元格的悬停提示：原值和修改后的值
+fn show_modified_hover(ui: &mut egui::Ui, original: &str, modified: &str) {
+    egui::Grid::new("modified_cell_hover").num_columns(2).show(ui, |ui| {
+        ui.label(RichText::new("原值").color(GRAY));
+        ui.label(RichText::new(shorten_hover_value(original)).monospace());
+        ui.end_row();
+        ui.label(RichText::new("新值").color(COLOR_CELL_MODIFIED));
+        ui.label(RichText::new(shorten_hover_value(modified)).monospace());
+        ui.end_row();
+    });
+    ui.label(RichText::new("右键菜单还原，或在单元格上按 u").small().color(GRAY));
+}
+
+/// 截断悬停提示中过长的值
+fn shorten_hover_value(value: &str) -> String {
+    const MAX_HOVER_CHARS: usize = 200;
+    if value.chars().count() > MAX_HOVER_CHARS {
+        format!("{}…", value.chars().take(MAX_HOVER_CHARS).collect::<String>())
+    } else {
+        value.to_string()
+    }
+}
+
 fn format_cell_text(cell: &str, is_cursor: bool) -> RichText {
     let text = if cell == "NULL" {
         // NULL 值使用斜体、特殊颜色和背景标记
         RichText::new("∅ NULL").italics().color(COLOR_NULL)
+    } else if let Some(wkt) = geometry_view::cell_summary(cell) {
+        let wkt = if wkt.len() > CELL_TRUNCATE_LEN { format!("{}...", &wkt[..CELL_TRUNCATE_LEN - 3]) } else { wkt };
+        RichText::new(wkt).color(COLOR_GEOMETRY)
+    } else if let Some(len) = blob_len(cell) {
+        RichText::new(blob_label(len)).italics().color(COLOR_NULL)
     } else if cell.len() > CELL_TRUNCATE_LEN {
         RichText::new(format!("{}...", &cell[..CELL_TRUNCATE_LEN - 3]))
     } else {
@@ -292,6 +633,52 @@ fn format_cell_text(cell: &str, is_cursor: bool) -> RichText {
     }
 }
 
+/// 高亮单元格中的搜索匹配片段（与 `format_cell_text` 相同的截断），没有匹配时返回 `None`
+fn highlight_cell_text(
+    ui: &egui::Ui,
+    cell: &str,
+    is_cursor: bool,
+    highlight: Option<&SearchMatcher>,
+) -> Option<egui::text::LayoutJob> {
+    let matcher = highlight?;
+    if cell == "NULL" || is_blob(cell) || geometry_view::cell_summary(cell).is_some() {
+        return None;
+    }
+    let (text, suffix) = if cell.len() > CELL_TRUNCATE_LEN {
+        (cell.get(..CELL_TRUNCATE_LEN - 3)?, "...")
+    } else {
+        (cell, "")
+    };
+    let ranges = matcher.find_ranges(text);
+    if ranges.is_empty() {
+        return None;
+    }
+
+    let mut job = egui::text::LayoutJob::default();
+    let mut append = |segment: &str, matched: bool| {
+        let mut rich = RichText::new(segment);
+        if matched {
+            rich = rich.background_color(COLOR_SEARCH_MATCH).color(Color32::WHITE);
+        }
+        if is_cursor {
+            rich = rich.underline();
+        }
+        rich.append_to(&mut job, ui.style(), egui::FontSelection::Default, egui::Align::Center);
+    };
+    let mut pos = 0;
+    for range in ranges {
+        if range.start > pos {
+            append(&text[pos..range.start], false);
+        }
+        append(&text[range.clone()], true);
+        pos = range.end;
+    }
+    if pos < text.len() || !suffix.is_empty() {
+        append(&format!("{}{}", &text[pos..], suffix), false);
+    }
+    Some(job)
+}
+
 // 新增行的背景色 - 浅绿色表示待保存
 const COLOR_NEW_ROW: Color32 = Color32::from_rgba_premultiplied(48, 96, 48, 60);
 
@@ -337,7 +724,7 @@ fn render_new_row_editing_cell(
     let response = ui.add(
         TextEdit::singleline(&mut state.edit_text)
             .desired_width(ui.available_width() - 8.0)
-            .font(egui::TextStyle::Monospace),
+            .font(grid_text_style()),
     );
 
     let should_exit = ui.input(|i| i.key_pressed(Key::Escape) || i.key_pressed(Key::Enter));
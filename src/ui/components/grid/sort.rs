@@ -0,0 +1,105 @@
+//! 结果集的客户端排序
+//!
+//! 排序只重排筛选后的行索引，不修改结果集本身；数字按数值比较，NULL 排在最后。
+
+use crate::core::constants;
+use crate::database::QueryResult;
+use rayon::prelude::*;
+use std::cmp::Ordering;
+
+/// 按列排序
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnSort {
+    /// 排序列名
+    pub column: String,
+    /// 是否降序
+    pub descending: bool,
+}
+
+impl ColumnSort {
+    /// 升序排序
+    pub fn ascending(column: impl Into<String>) -> Self {
+        Self {
+            column: column.into(),
+            descending: false,
+        }
+    }
+
+    /// 降序排序
+    pub fn descending(column: impl Into<String>) -> Self {
+        Self {
+            column: column.into(),
+            descending: true,
+        }
+    }
+
+    /// 列头显示的排序标记
+    pub fn indicator(&self) -> &'static str {
+        if self.descending { "▼" } else { "▲" }
+    }
+}
+
+/// 单元格的排序键
+#[derive(Debug, PartialEq)]
+enum SortKey<'a> {
+    Number(f64),
+    Text(&'a str),
+    Null,
+}
+
+impl<'a> SortKey<'a> {
+    fn new(cell: &'a str) -> Self {
+        if cell == "NULL" {
+            Self::Null
+        } else if let Ok(n) = cell.trim().parse::<f64>()
+            && n.is_finite()
+        {
+            Self::Number(n)
+        } else {
+            Self::Text(cell)
+        }
+    }
+
+    /// 数字排在文本之前，NULL 排在最后
+    fn rank(&self) -> u8 {
+        match self {
+            Self::Number(_) => 0,
+            Self::Text(_) => 1,
+            Self::Null => 2,
+        }
+    }
+
+    fn compare(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Number(a), Self::Number(b)) => a.total_cmp(b),
+            (Self::Text(a), Self::Text(b)) => a.cmp(b),
+            _ => self.rank().cmp(&other.rank()),
+        }
+    }
+}
+
+/// 按排序列重排行索引（稳定排序，降序时 NULL 仍排在最后）；排序列不存在时保持原顺序
+pub fn sort_indices(result: &QueryResult, indices: &mut Vec<usize>, sort: &ColumnSort) {
+    let Some(col) = result.columns.iter().position(|c| c == &sort.column) else {
+        return;
+    };
+
+    // 每行只解析一次排序键
+    let mut keyed: Vec<(usize, SortKey)> = indices
+        .iter()
+        .map(|&idx| {
+            let cell = result.rows.get(idx).and_then(|row| row.get(col)).map_or("NULL", String::as_str);
+            (idx, SortKey::new(cell))
+        })
+        .collect();
+    let compare = |(_, a): &(usize, SortKey), (_, b): &(usize, SortKey)| {
+        let order = a.compare(b);
+        if sort.descending && a.rank() == b.rank() { order.reverse() } else { order }
+    };
+    if keyed.len() >= constants::database::PARALLEL_FILTER_THRESHOLD {
+        keyed.par_sort_by(compare);
+    } else {
+        keyed.sort_by(compare);
+    }
+    *indices = keyed.into_iter().map(|(idx, _)| idx).collect();
+}
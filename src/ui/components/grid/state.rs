@@ -1,50 +1,95 @@
 //! 表格编辑状态
 
+use super::aggregate::ColumnStats;
+use super::blob_view::BlobViewer;
+use super::duplicates::DuplicateGroups;
+use super::facet::ValueFacet;
+use super::geometry_view::GeometryViewer;
 use super::filter::{ColumnFilter, FilterCache};
+use super::sort::ColumnSort;
+use super::json_view::{JsonColumns, JsonViewer};
 use super::mode::GridMode;
-use std::collections::HashMap;
+use crate::core::{NumberFormat, PluginRegistry, ValueFormatter};
+use crate::database::DatabaseType;
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+use std::sync::Arc;
 
-/// 列宽缓存
+/// 最多置顶的行数
+pub const MAX_PINNED_ROWS: usize = 3;
+
+/// 自动列宽缓存
+///
+/// 只在结果集、列或筛选后的行数变化时重新采样计算，避免每帧遍历单元格。
 #[derive(Default, Clone)]
 pub struct ColumnWidthCache {
     /// 缓存的列宽
     pub widths: Vec<f32>,
-    /// 数据哈希值（用于判断是否需要重新计算）
-    pub data_hash: u64,
-    /// 列数（用于快速判断）
-    pub column_count: usize,
-    /// 采样行数（用于快速判断）
-    pub sample_row_count: usize,
+    /// 计算列宽时的数据标识（结果集、列名和行数）
+    pub data_key: u64,
 }
 
 impl ColumnWidthCache {
     /// 检查缓存是否有效
-    pub fn is_valid(&self, column_count: usize, sample_row_count: usize, data_hash: u64) -> bool {
-        self.column_count == column_count
-            && self.sample_row_count == sample_row_count
-            && self.data_hash == data_hash
+    pub fn is_valid(&self, data_key: u64) -> bool {
+        !self.widths.is_empty() && self.data_key == data_key
     }
 
     /// 更新缓存
-    pub fn update(&mut self, widths: Vec<f32>, column_count: usize, sample_row_count: usize, data_hash: u64) {
+    pub fn update(&mut self, widths: Vec<f32>, data_key: u64) {
         self.widths = widths;
-        self.column_count = column_count;
-        self.sample_row_count = sample_row_count;
-        self.data_hash = data_hash;
+        self.data_key = data_key;
     }
 
     /// 清除缓存
     pub fn clear(&mut self) {
         self.widths.clear();
-        self.data_hash = 0;
-        self.column_count = 0;
-        self.sample_row_count = 0;
+        self.data_key = 0;
+    }
+}
+
+/// 分页读取的结果页
+///
+/// 大结果集按页从数据库流式读取，表格只持有当前页的数据
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResultPage {
+    /// 原始查询语句
+    pub sql: String,
+    /// 页码（从 0 开始）
+    pub index: usize,
+    /// 每页行数
+    pub size: usize,
+}
+
+impl ResultPage {
+    /// 第一页
+    pub fn first(sql: impl Into<String>, size: usize) -> Self {
+        Self {
+            sql: sql.into(),
+            index: 0,
+            size,
+        }
+    }
+
+    /// 当前页第一行在整个结果集中的偏移
+    pub fn offset(&self) -> usize {
+        self.index * self.size
+    }
+
+    /// 指定页码的页
+    pub fn with_index(&self, index: usize) -> Self {
+        Self {
+            index,
+            ..self.clone()
+        }
     }
 }
 
 /// 表格编辑状态
 #[derive(Default)]
 pub struct DataGridState {
+    /// 当前结果的分页信息（流式分页读取时有值）
+    pub page: Option<ResultPage>,
     /// 当前模式
     pub mode: GridMode,
     /// 当前光标位置 (row, col)
@@ -63,6 +108,8 @@ pub struct DataGridState {
     pub rows_to_delete: Vec<usize>,
     /// 新增的行数据
     pub new_rows: Vec<Vec<String>>,
+    /// 新增行保存为 upsert（主键已存在时更新）而不是普通 INSERT
+    pub upsert_new_rows: bool,
     /// 筛选条件列表
     pub filters: Vec<ColumnFilter>,
     /// 剪贴板内容
@@ -87,12 +134,10 @@ pub struct DataGridState {
     pub goto_input: String,
     /// 待保存标记 (Ctrl+S 触发)
     pub pending_save: bool,
-    /// 显示保存确认对话框
-    pub show_save_confirm: bool,
-    /// 待确认的 SQL 语句
-    pub pending_sql: Vec<String>,
     /// 筛选结果缓存
     pub filter_cache: FilterCache,
+    /// 客户端排序（按列头菜单选择）
+    pub sort: Option<ColumnSort>,
     /// 主键列索引（None 表示未知，编辑功能将被禁用）
     pub primary_key_column: Option<usize>,
     /// 正则表达式错误信息（用于向用户显示正则匹配失败原因）
@@ -100,14 +145,69 @@ pub struct DataGridState {
     pub regex_error: Option<String>,
     /// 待处理的新增行编辑 (虚拟行索引, 列索引, 新值)
     pub pending_new_row_edit: Option<(usize, usize, String)>,
-    /// 列宽缓存
+    /// 自动列宽缓存
     pub column_width_cache: ColumnWidthCache,
+    /// 当前表中手动调整过的列宽（按列名，按表保存）
+    pub column_widths: HashMap<String, f32>,
+    /// 上一帧交给表格的列宽（变化时重置表格记住的列宽）
+    pub applied_widths: Vec<f32>,
+    /// 上一帧显示的列和冻结列数（变化时同样重置列宽）
+    pub applied_layout: (Vec<usize>, usize),
+    /// 隐藏的列（按列名，切换表时重置）
+    pub hidden_columns: HashSet<String>,
+    /// 列的显示顺序（按列名，按表保存；为空时按结果中的顺序）
+    pub column_order: Vec<String>,
+    /// 冻结在左侧的可见列数（横向滚动时保持可见）
+    pub frozen_columns: usize,
+    /// 有冻结列时左右两个表格共享的垂直滚动偏移
+    pub v_scroll_offset: f32,
+    /// 上一帧显示的数据行（原始行索引范围，表格未显示时为 `None`）
+    pub rendered_rows: Option<Range<usize>>,
+    /// 置顶在表格上方的行（原始行索引，滚动时保持可见）
+    pub pinned_rows: Vec<usize>,
+    /// 当前表中收藏的行的主键值（行号前显示标记）
+    pub bookmarked_rows: HashSet<String>,
+    /// 行号右键菜单中请求收藏或取消收藏的行
+    pub pending_bookmark: Option<usize>,
+    /// 列统计窗口显示的统计
+    pub column_stats: Option<ColumnStats>,
+    /// 右侧不同值面板显示的列
+    pub value_facet: Option<ValueFacet>,
+    /// 插件注册表（单元格渲染器）
+    pub plugins: Arc<PluginRegistry>,
+    /// 是否显示底部的行数和聚合统计状态栏
+    pub show_footer: bool,
+    /// 重复行检测结果（用于分组着色）
+    pub duplicates: Option<DuplicateGroups>,
+    /// 显示重复行检测窗口
+    pub show_duplicates_dialog: bool,
+    /// 重复行检测中参与比较的列
+    pub duplicate_columns: Vec<bool>,
+    /// 识别出的 JSON 列（列头显示标记）
+    pub json_columns: JsonColumns,
+    /// 识别出的时间戳列（与 JSON 列一起检测）
+    pub timestamp_columns: Vec<bool>,
+    /// 单元格显示格式（时间戳时区转换、全局数字格式）
+    pub value_format: ValueFormatter,
+    /// 当前表各列单独设置的数字格式
+    pub column_formats: HashMap<String, NumberFormat>,
+    /// 当前连接标记为敏感的列（小写列名），显示脱敏后的值
+    pub masked_columns: HashSet<String>,
+    /// 正在查看的 JSON 单元格
+    pub json_viewer: Option<JsonViewer>,
+    /// 正在查看的二进制单元格
+    pub blob_viewer: Option<BlobViewer>,
+    /// 正在查看的几何图形单元格
+    pub geometry_viewer: Option<GeometryViewer>,
+    /// 当前连接的数据库类型（生成二进制字面量时使用）
+    pub db_type: Option<DatabaseType>,
 }
 
 impl DataGridState {
     pub fn new() -> Self {
         Self {
             focused: true,
+            show_footer: true,
             ..Default::default()
         }
     }
@@ -119,16 +219,255 @@ impl DataGridState {
         self.modified_cells.clear();
         self.rows_to_delete.clear();
         self.new_rows.clear();
+        self.upsert_new_rows = false;
         // 数据变化后清除列宽缓存
         self.column_width_cache.clear();
     }
 
+    /// 结果集已被替换，基于旧结果的筛选和列宽缓存失效
+    pub fn result_replaced(&mut self) {
+        self.filter_cache.result_replaced();
+        self.column_width_cache.clear();
+    }
+
+    /// 记录本帧显示的数据行
+    pub(super) fn note_rendered_row(&mut self, row: usize) {
+        self.rendered_rows = Some(match self.rendered_rows.take() {
+            Some(rows) => rows.start.min(row)..rows.end.max(row + 1),
+            None => row..row + 1,
+        });
+    }
+
+    /// 是否按原始顺序浏览结果（没有筛选、排序、置顶行、统计面板和未保存的修改），
+    /// 此时表格只读取显示位置、光标和选择范围内的行
+    pub fn is_plain_browsing(&self) -> bool {
+        self.sort.is_none()
+            && !self.filters.iter().any(|f| f.enabled)
+            && self.pinned_rows.is_empty()
+            && self.column_stats.is_none()
+            && self.value_facet.is_none()
+            && self.duplicates.is_none()
+            && !self.has_changes()
+    }
+
+    /// 表格正在使用的行（显示的行、光标和选择范围，按原始顺序浏览时即原始行索引）
+    pub fn rows_in_use(&self) -> Range<usize> {
+        let mut rows = self.cursor.0..self.cursor.0 + 1;
+        if let Some(rendered) = &self.rendered_rows {
+            rows = rows.start.min(rendered.start)..rows.end.max(rendered.end);
+        }
+        if let Some(((min_row, _), (max_row, _))) = self.get_selection() {
+            rows = rows.start.min(min_row)..rows.end.max(max_row + 1);
+        }
+        rows
+    }
+
     pub fn has_changes(&self) -> bool {
         !self.modified_cells.is_empty()
             || !self.rows_to_delete.is_empty()
             || !self.new_rows.is_empty()
     }
 
+    /// 把制表符分隔的文本（每行一条记录）粘贴为新增行，列数不足时补空，返回粘贴的行数
+    pub fn paste_rows(&mut self, text: &str, column_count: usize) -> usize {
+        let mut count = 0;
+        for line in text.lines().filter(|l| !l.trim().is_empty()) {
+            let mut row: Vec<String> = line.split('\t').take(column_count).map(str::to_string).collect();
+            row.resize(column_count, String::new());
+            self.new_rows.push(row);
+            count += 1;
+        }
+        count
+    }
+
+    /// 所有列按显示顺序排列的索引（包括隐藏的列）
+    ///
+    /// `column_order` 中的列排在前面，其余的列（如结果中新增的列）按原来的顺序排在后面。
+    pub fn ordered_columns(&self, columns: &[String]) -> Vec<usize> {
+        let mut order: Vec<usize> = Vec::with_capacity(columns.len());
+        for name in &self.column_order {
+            if let Some(idx) = columns.iter().position(|c| c == name)
+                && !order.contains(&idx)
+            {
+                order.push(idx);
+            }
+        }
+        let rest: Vec<usize> = (0..columns.len()).filter(|i| !order.contains(i)).collect();
+        order.extend(rest);
+        order
+    }
+
+    /// 显示的列索引（按显示顺序，排除隐藏的列）
+    pub fn visible_columns(&self, columns: &[String]) -> Vec<usize> {
+        self.ordered_columns(columns)
+            .into_iter()
+            .filter(|&i| !self.hidden_columns.contains(&columns[i]))
+            .collect()
+    }
+
+    /// 把列移动到另一列的位置（拖动列头），返回顺序是否变化
+    pub fn move_column_to(&mut self, columns: &[String], from: usize, to: usize) -> bool {
+        let mut order = self.ordered_columns(columns);
+        let (Some(from_pos), Some(to_pos)) =
+            (order.iter().position(|&c| c == from), order.iter().position(|&c| c == to))
+        else {
+            return false;
+        };
+        if from_pos == to_pos {
+            return false;
+        }
+        let col = order.remove(from_pos);
+        order.insert(to_pos, col);
+        // 恢复为原来的顺序时不再保存
+        self.column_order = if order.iter().enumerate().all(|(pos, &c)| pos == c) {
+            Vec::new()
+        } else {
+            order.into_iter().map(|c| columns[c].clone()).collect()
+        };
+        true
+    }
+
+    /// 把列在显示的列中左移（`delta < 0`）或右移，返回顺序是否变化
+    pub fn move_column(&mut self, columns: &[String], col: usize, delta: isize) -> bool {
+        let visible = self.visible_columns(columns);
+        let target = visible
+            .iter()
+            .position(|&c| c == col)
+            .and_then(|pos| pos.checked_add_signed(delta))
+            .and_then(|pos| visible.get(pos).copied());
+        target.is_some_and(|target| self.move_column_to(columns, col, target))
+    }
+
+    /// 列是否标记为敏感
+    pub fn is_masked(&self, column: &str) -> bool {
+        !self.masked_columns.is_empty() && self.masked_columns.contains(&column.to_lowercase())
+    }
+
+    /// 隐藏列（至少保留一列可见），返回是否隐藏
+    pub fn hide_column(&mut self, columns: &[String], name: &str) -> bool {
+        if self.visible_columns(columns).len() <= 1 || !columns.iter().any(|c| c == name) {
+            return false;
+        }
+        self.hidden_columns.insert(name.to_string())
+    }
+
+    /// 让键盘移动后的光标按列的显示顺序移动，并跳过隐藏的列（优先沿移动方向）
+    ///
+    /// 键盘按列索引移动光标；调整过列顺序时把移动的距离换算为显示顺序中的位置，
+    /// 跳到第一列/最后一列（如 `gh`/`gl`）对应显示顺序的首尾。
+    pub fn snap_cursor_to_visible(&mut self, columns: &[String], previous_col: usize) {
+        let order = self.ordered_columns(columns);
+        let position = |col: usize| order.iter().position(|&c| c == col);
+        let col = self.cursor.1;
+        if !self.column_order.is_empty()
+            && col != previous_col
+            && let Some(from) = position(previous_col)
+        {
+            let delta = col as isize - previous_col as isize;
+            let last = order.len() - 1;
+            let target = if delta.abs() > 1 && col == 0 {
+                0
+            } else if delta.abs() > 1 && col == last {
+                last
+            } else {
+                from.saturating_add_signed(delta).min(last)
+            };
+            self.cursor.1 = order[target];
+        }
+
+        let col = self.cursor.1;
+        if columns.get(col).is_none_or(|name| !self.hidden_columns.contains(name)) {
+            return;
+        }
+        let (Some(pos), previous_pos) = (position(col), position(previous_col)) else {
+            return;
+        };
+        let is_visible = |c: &usize| !self.hidden_columns.contains(&columns[*c]);
+        let forward = order[pos + 1..].iter().copied().find(is_visible);
+        let backward = order[..pos].iter().rev().copied().find(is_visible);
+        let target = if previous_pos.is_none_or(|p| pos >= p) {
+            forward.or(backward)
+        } else {
+            backward.or(forward)
+        };
+        if let Some(target) = target {
+            self.cursor.1 = target;
+        }
+    }
+
+    /// 切换行的置顶状态，返回是否置顶；已置顶 `MAX_PINNED_ROWS` 行时返回 `None`
+    pub fn toggle_pinned_row(&mut self, row: usize) -> Option<bool> {
+        if let Some(pos) = self.pinned_rows.iter().position(|&r| r == row) {
+            self.pinned_rows.remove(pos);
+            return Some(false);
+        }
+        if self.pinned_rows.len() >= MAX_PINNED_ROWS {
+            return None;
+        }
+        self.pinned_rows.push(row);
+        Some(true)
+    }
+
+    /// 向下填充：把选择范围内每列第一行的值复制到该列其余的单元格，返回修改的单元格数
+    ///
+    /// `cell` 按原始行索引返回单元格的值（不显示的行返回 `None`，这些行不修改）。
+    pub fn fill_down(&mut self, cell: impl Fn(usize, usize) -> Option<String>) -> usize {
+        let Some(((min_r, min_c), (max_r, max_c))) = self.get_selection() else {
+            return 0;
+        };
+        let mut count = 0;
+        for c in min_c..=max_c {
+            let Some(first) = self.cell_value(min_r, c, &cell) else {
+                continue;
+            };
+            for r in min_r + 1..=max_r {
+                if cell(r, c).is_some() {
+                    self.modified_cells.insert((r, c), first.clone());
+                    count += 1;
+                }
+            }
+        }
+        count
+    }
+
+    /// 序列填充：数字和日期列按前两行的差值（只选中一行有值时步长为 1）递增填充，
+    /// 其他列与向下填充相同，返回修改的单元格数
+    pub fn fill_series(&mut self, cell: impl Fn(usize, usize) -> Option<String>) -> usize {
+        let Some(((min_r, min_c), (max_r, max_c))) = self.get_selection() else {
+            return 0;
+        };
+        let mut count = 0;
+        for c in min_c..=max_c {
+            let Some(first) = self.cell_value(min_r, c, &cell) else {
+                continue;
+            };
+            let second = if max_r > min_r { self.cell_value(min_r + 1, c, &cell) } else { None };
+            let series = FillSeries::detect(&first, second.as_deref());
+            // 有两行起始值时从第三行开始填充
+            let start = match &series {
+                Some(series) if series.from_two_values => min_r + 2,
+                _ => min_r + 1,
+            };
+            for r in start..=max_r {
+                if cell(r, c).is_none() {
+                    continue;
+                }
+                let value = match &series {
+                    Some(series) => series.nth(r - min_r),
+                    None => first.clone(),
+                };
+                self.modified_cells.insert((r, c), value);
+                count += 1;
+            }
+        }
+        count
+    }
+
+    /// 单元格当前的值（已修改的单元格使用修改后的值）
+    fn cell_value(&self, row: usize, col: usize, cell: &impl Fn(usize, usize) -> Option<String>) -> Option<String> {
+        self.modified_cells.get(&(row, col)).cloned().or_else(|| cell(row, col))
+    }
+
     /// 获取选择范围
     pub fn get_selection(&self) -> Option<((usize, usize), (usize, usize))> {
         self.select_anchor.map(|anchor| {
@@ -197,3 +536,87 @@ impl DataGridState {
         self.scroll_to_row = Some(self.cursor.0);
     }
 }
+
+/// 序列填充的起始值和步长
+struct FillSeries {
+    start: SeriesValue,
+    /// 整数步长，日期为天数，日期时间为秒数
+    step: i64,
+    /// 浮点数的步长
+    float_step: f64,
+    /// 浮点数保留的小数位数
+    decimals: usize,
+    /// 步长是否由前两个值计算
+    from_two_values: bool,
+}
+
+enum SeriesValue {
+    Int(i64),
+    Float(f64),
+    Date(chrono::NaiveDate),
+    DateTime(chrono::NaiveDateTime),
+}
+
+impl FillSeries {
+    const DATE_FORMAT: &str = "%Y-%m-%d";
+    const DATETIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+    /// 识别起始值的类型和步长，不是数字或日期时返回 `None`
+    fn detect(first: &str, second: Option<&str>) -> Option<Self> {
+        let (first, second) = (first.trim(), second.map(str::trim));
+        let series = |start, step, float_step, decimals, from_two_values| FillSeries {
+            start,
+            step,
+            float_step,
+            decimals,
+            from_two_values,
+        };
+        if let Ok(a) = first.parse::<i64>() {
+            return Some(match second.and_then(|s| s.parse::<i64>().ok()) {
+                Some(b) => series(SeriesValue::Int(a), b - a, 0.0, 0, true),
+                None => series(SeriesValue::Int(a), 1, 0.0, 0, false),
+            });
+        }
+        if let Ok(a) = first.parse::<f64>()
+            && a.is_finite()
+        {
+            let decimals = |v: &str| v.split_once('.').map_or(0, |(_, d)| d.len());
+            return Some(match second.filter(|s| s.parse::<f64>().is_ok_and(f64::is_finite)) {
+                Some(s) => {
+                    let b = s.parse::<f64>().ok()?;
+                    series(SeriesValue::Float(a), 0, b - a, decimals(first).max(decimals(s)), true)
+                }
+                None => series(SeriesValue::Float(a), 0, 1.0, decimals(first), false),
+            });
+        }
+        if let Ok(a) = chrono::NaiveDate::parse_from_str(first, Self::DATE_FORMAT) {
+            let b = second.and_then(|s| chrono::NaiveDate::parse_from_str(s, Self::DATE_FORMAT).ok());
+            return Some(match b {
+                Some(b) => series(SeriesValue::Date(a), (b - a).num_days(), 0.0, 0, true),
+                None => series(SeriesValue::Date(a), 1, 0.0, 0, false),
+            });
+        }
+        if let Ok(a) = chrono::NaiveDateTime::parse_from_str(first, Self::DATETIME_FORMAT) {
+            let b = second.and_then(|s| chrono::NaiveDateTime::parse_from_str(s, Self::DATETIME_FORMAT).ok());
+            return Some(match b {
+                Some(b) => series(SeriesValue::DateTime(a), (b - a).num_seconds(), 0.0, 0, true),
+                // 只有一个日期时间时按天递增
+                None => series(SeriesValue::DateTime(a), 86_400, 0.0, 0, false),
+            });
+        }
+        None
+    }
+
+    /// 序列中第 `n` 个值（起始值为第 0 个）
+    fn nth(&self, n: usize) -> String {
+        let n = n as i64;
+        match self.start {
+            SeriesValue::Int(a) => a.saturating_add(self.step.saturating_mul(n)).to_string(),
+            SeriesValue::Float(a) => format!("{:.*}", self.decimals, a + self.float_step * n as f64),
+            SeriesValue::Date(a) => (a + chrono::Duration::days(self.step * n)).format(Self::DATE_FORMAT).to_string(),
+            SeriesValue::DateTime(a) => {
+                (a + chrono::Duration::seconds(self.step * n)).format(Self::DATETIME_FORMAT).to_string()
+            }
+        }
+    }
+}
@@ -0,0 +1,218 @@
+//! 按键提示栏
+//!
+//! 窗口底部的一行提示（类似 Helix），显示当前焦点区域可用的按键。
+//! 表格中输入了命令前缀（如 `g`）时改为显示可以继续输入的按键；
+//! 全局快捷键从 [`KeyBindings`] 读取，显示用户自定义后的按键，未绑定的操作不显示。
+
+use crate::core::{Action, KeyBindings};
+use crate::ui::styles::{GRAY, MUTED};
+use crate::ui::{EditorMode, FocusArea, GridMode};
+use eframe::egui;
+use egui::{Color32, RichText};
+
+/// 提示栏中按键的颜色
+const KEY_COLOR: Color32 = Color32::from_rgb(255, 200, 100);
+
+/// 一条按键提示
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyHint {
+    /// 按键（如 `gg`、`Ctrl+S`）
+    pub keys: String,
+    /// 说明
+    pub desc: String,
+}
+
+impl KeyHint {
+    fn new(keys: impl Into<String>, desc: impl Into<String>) -> Self {
+        Self { keys: keys.into(), desc: desc.into() }
+    }
+}
+
+/// 决定提示内容的界面状态
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HintContext<'a> {
+    /// 焦点区域
+    pub area: FocusArea,
+    /// 表格模式
+    pub grid_mode: GridMode,
+    /// 表格中已输入的命令前缀
+    pub grid_pending: &'a str,
+    /// SQL 编辑器模式
+    pub editor_mode: EditorMode,
+}
+
+/// 表格 Normal 模式
+const GRID_NORMAL: &[(&str, &str)] = &[
+    ("hjkl", "移动"),
+    ("i", "编辑"),
+    ("v", "选择"),
+    ("o", "新增行"),
+    ("dd", "删除行"),
+    ("yy", "复制行"),
+    ("p", "粘贴"),
+    ("u", "撤销"),
+    ("g", "跳转…"),
+    ("z", "视图…"),
+    (":", "命令…"),
+    ("Q / @", "录制/回放宏"),
+];
+
+/// 表格 Insert 模式
+const GRID_INSERT: &[(&str, &str)] = &[("Enter", "确认"), ("Esc", "退出编辑")];
+
+/// 表格 Select 模式
+const GRID_SELECT: &[(&str, &str)] = &[
+    ("hjkl", "扩展选择"),
+    ("x", "选择整行"),
+    ("y", "复制"),
+    ("d", "删除"),
+    ("f", "向下填充"),
+    ("F", "序列填充"),
+    (";", "折叠选择"),
+    ("Esc", "退出选择"),
+];
+
+/// SQL 编辑器 Normal 模式
+const EDITOR_NORMAL: &[(&str, &str)] = &[
+    ("i / a / o", "编辑"),
+    (":", "命令行"),
+    ("Shift+k/j", "历史命令"),
+    ("Shift+d", "清空"),
+    ("Ctrl+Enter / F5", "执行"),
+    ("F6", "执行计划"),
+    ("F7", "检查 SQL"),
+    ("Q / @", "录制/回放宏"),
+];
+
+/// SQL 编辑器 Insert 模式
+const EDITOR_INSERT: &[(&str, &str)] = &[
+    ("Esc", "Normal 模式"),
+    ("Tab", "补全"),
+    ("Ctrl+/", "注释"),
+    ("Ctrl+Alt+↑/↓", "多光标"),
+    ("Ctrl+Enter", "执行"),
+];
+
+/// SQL 编辑器命令行
+const EDITOR_COMMAND: &[(&str, &str)] = &[("Tab", "补全命令"), ("Enter", "执行命令"), ("Esc", "取消")];
+
+/// 侧边栏
+const SIDEBAR: &[(&str, &str)] = &[
+    ("j/k", "移动"),
+    ("Enter / l", "展开/打开"),
+    ("h", "返回"),
+    ("d", "删除"),
+    ("r", "重命名"),
+    ("e", "编辑"),
+];
+
+/// 查询 Tab 栏
+const QUERY_TABS: &[(&str, &str)] = &[
+    ("h/l", "切换"),
+    ("j / Enter", "进入表格"),
+    ("k", "工具栏"),
+    ("d", "关闭"),
+    ("r", "重命名"),
+    ("p", "固定"),
+];
+
+/// 工具栏
+const TOOLBAR: &[(&str, &str)] = &[("h/l", "移动"), ("Enter", "选择"), ("j / Esc", "Tab 栏")];
+
+/// 每个区域显示的全局快捷键（按键从快捷键设置中读取）
+fn area_actions(area: FocusArea) -> &'static [Action] {
+    match area {
+        FocusArea::DataGrid => &[Action::Save, Action::AddFilter, Action::Export, Action::Refresh],
+        FocusArea::SqlEditor => &[Action::ToggleEditor, Action::OpenFile, Action::Save],
+        FocusArea::Sidebar => &[Action::NewConnection, Action::ToggleSidebar],
+        FocusArea::QueryTabs => &[Action::NewTab, Action::CloseTab, Action::ReopenTab, Action::RenameTab, Action::PinTab],
+        FocusArea::Toolbar => &[Action::ShowKeyBindings, Action::ShowPreferences],
+        FocusArea::Dialog => &[],
+    }
+}
+
+/// 始终显示的全局快捷键
+const GLOBAL_ACTIONS: &[Action] = &[Action::ShowCommandPalette, Action::ShowHelp];
+
+/// 按键提示栏
+pub struct HintBar;
+
+impl HintBar {
+    /// 表格命令前缀之后可以继续输入的按键（完整的按键序列）
+    pub fn pending_hints(prefix: &str) -> &'static [(&'static str, &'static str)] {
+        match prefix {
+            "g" => &[("gg", "首行"), ("ge", "末行"), ("gh", "行首"), ("gl", "行尾")],
+            "z" => &[("zz", "当前行居中"), ("zt", "滚动到顶部"), ("zb", "滚动到底部")],
+            " " => &[("Space d", "标记删除当前行")],
+            ":" => &[(":w", "保存修改"), (":q", "放弃修改")],
+            "d" => &[("dd", "标记删除当前行")],
+            "y" => &[("yy", "复制整行")],
+            _ => &[],
+        }
+    }
+
+    /// 当前状态下的提示（表格有命令前缀时只显示前缀的后续按键）
+    pub fn hints(context: &HintContext, keybindings: &KeyBindings) -> Vec<KeyHint> {
+        let static_hints: &[(&str, &str)] = match context.area {
+            FocusArea::DataGrid => {
+                let pending = Self::pending_hints(context.grid_pending);
+                if !pending.is_empty() {
+                    return pending.iter().map(|&(k, d)| KeyHint::new(k, d)).collect();
+                }
+                match context.grid_mode {
+                    GridMode::Normal => GRID_NORMAL,
+                    GridMode::Insert => GRID_INSERT,
+                    GridMode::Select => GRID_SELECT,
+                }
+            }
+            FocusArea::SqlEditor => match context.editor_mode {
+                EditorMode::Normal => EDITOR_NORMAL,
+                EditorMode::Insert => EDITOR_INSERT,
+                EditorMode::Command => EDITOR_COMMAND,
+            },
+            FocusArea::Sidebar => SIDEBAR,
+            FocusArea::QueryTabs => QUERY_TABS,
+            FocusArea::Toolbar => TOOLBAR,
+            FocusArea::Dialog => &[],
+        };
+
+        let mut hints: Vec<KeyHint> = static_hints.iter().map(|&(k, d)| KeyHint::new(k, d)).collect();
+        for &action in area_actions(context.area).iter().chain(GLOBAL_ACTIONS) {
+            let keys = keybindings.display(action);
+            if !keys.is_empty() {
+                hints.push(KeyHint::new(keys, action.description()));
+            }
+        }
+        hints
+    }
+
+    /// 区域名称（表格和编辑器附带当前模式）
+    fn area_label(context: &HintContext) -> String {
+        match context.area {
+            FocusArea::DataGrid => format!("表格 {}", context.grid_mode.display_name()),
+            FocusArea::SqlEditor => format!("编辑器 {}", context.editor_mode.label()),
+            FocusArea::Sidebar => "侧边栏".to_string(),
+            FocusArea::QueryTabs => "Tab 栏".to_string(),
+            FocusArea::Toolbar => "工具栏".to_string(),
+            FocusArea::Dialog => "对话框".to_string(),
+        }
+    }
+
+    /// 显示提示栏
+    pub fn show(ui: &mut egui::Ui, context: &HintContext, keybindings: &KeyBindings) {
+        ui.horizontal(|ui| {
+            ui.spacing_mut().item_spacing.x = 4.0;
+            ui.label(RichText::new(Self::area_label(context)).small().strong().color(GRAY));
+            if !Self::pending_hints(context.grid_pending).is_empty() && context.area == FocusArea::DataGrid {
+                let prefix = if context.grid_pending == " " { "Space" } else { context.grid_pending };
+                ui.label(RichText::new(format!("{}:", prefix)).small().monospace().color(Color32::YELLOW));
+            }
+            ui.separator();
+            for hint in Self::hints(context, keybindings) {
+                ui.label(RichText::new(&hint.keys).small().monospace().color(KEY_COLOR));
+                ui.label(RichText::new(&hint.desc).small().color(MUTED));
+                ui.add_space(6.0);
+            }
+        });
+    }
+}
@@ -3,38 +3,64 @@
 //! 包含所有可重用的 UI 组件
 
 pub mod er_diagram;
+mod column_picker;
+mod command_line;
+mod detached_tab;
 mod grid;
+mod hint_bar;
 mod notifications;
 mod progress_indicator;
 mod query_tabs;
+mod search_bar;
 mod sql_editor;
 mod toolbar;
+mod tour_overlay;
 mod welcome;
 
 // 工具栏
 pub use toolbar::{Toolbar, ToolbarActions, ToolbarFocusTransfer};
 
 // SQL 编辑器
-pub use sql_editor::{EditorMode, SqlEditor, SqlEditorActions};
+pub use sql_editor::{EditorMode, SqlEditor, SqlEditorActions, SqlErrorMarker};
+
+// SQL 编辑器命令行
+#[allow(unused_imports)] // 公开 API
+pub use command_line::{
+    complete_editor_command, parse_editor_command, CommandLine, CommandLineResult, CommandLineState, EditorCommand,
+};
+
+// 列名补全输入框和结果搜索栏
+pub use column_picker::ColumnPicker;
+pub use search_bar::SearchBar;
 
 // 数据表格（Helix 风格）
 pub use grid::{
-    check_filter_match, escape_identifier, escape_value,
-    filter_rows_cached, quote_identifier, ColumnFilter, DataGrid,
-    DataGridState, FilterCache, FilterLogic, FilterOperator, FocusTransfer,
+    check_filter_match, escape_identifier, escape_value, facet_selection, filters_to_where, generate_save_sql, is_facet_selectable, set_facet_value,
+    filter_rows_background, filter_rows_cached, sort_indices, format_number, quote_identifier, quote_qualified_identifier, Aggregate, BlobViewer, ColumnStats, ColumnFilter, ColumnSort, DataGrid, GeometryViewer,
+    DataGridActions, DataGridState, DedupDelete, DuplicateGroups, FilterCache, FilterLogic, FilterOperator, FocusTransfer, GridMode, JsonColumns, JsonViewer, ResultPage, NumericStats, SearchMatcher, SearchOptions, ValueFacet,
 };
+pub(crate) use grid::filter::string_literal;
 
 // 欢迎页面
 pub use welcome::Welcome;
 
+// 底部按键提示栏
+pub use hint_bar::{HintBar, HintContext, KeyHint};
+
+// 新手引导
+pub use tour_overlay::{TourAction, TourOverlay};
+
 // 多 Tab 查询窗口
-pub use query_tabs::{QueryTab, QueryTabBar, QueryTabManager, TabBarActions, TabBarFocusTransfer};
+pub use query_tabs::{QueryTab, QueryTabBar, ResultSnapshot, QueryTabManager, TabBarActions, TabBarFocusTransfer};
+
+// 独立窗口中的查询 Tab
+pub use detached_tab::{DetachedTabAction, DetachedTabWindow};
 
 // ER 关系图
 #[allow(unused_imports)] // 公开 API
 pub use er_diagram::{
-    ERColumn, ERDiagramResponse, ERDiagramState, ERTable, Relationship, RelationType,
-    calculate_table_size, force_directed_layout, grid_layout,
+    ERColumn, ERDiagramResponse, ERDiagramState, ERExportFormat, ERTable, Relationship, RelationType,
+    calculate_table_size, force_directed_layout, grid_layout, hierarchical_layout, to_mermaid, to_plantuml, to_svg,
 };
 
 // 通知组件
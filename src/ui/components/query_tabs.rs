@@ -2,15 +2,30 @@
 //!
 //! 支持多个独立的 SQL 查询 Tab，每个 Tab 有自己的 SQL 编辑器和结果显示区域。
 
-use crate::core::HighlightColors;
+use crate::core::{HighlightColors, SqlFile, TabState};
 use crate::database::QueryResult;
+use super::grid::ResultPage;
+use chrono::{DateTime, Local};
 use egui::{self, Color32, RichText, Ui, Vec2};
+use std::path::Path;
+use std::sync::Arc;
 use uuid::Uuid;
 
 // ============================================================================
 // 查询 Tab 状态
 // ============================================================================
 
+/// 固定的结果快照，用于与之后再次执行的结果对比
+#[derive(Clone)]
+pub struct ResultSnapshot {
+    /// 产生该结果的 SQL
+    pub sql: String,
+    /// 结果
+    pub result: Arc<QueryResult>,
+    /// 固定时间
+    pub taken_at: DateTime<Local>,
+}
+
 /// 单个查询 Tab 的状态
 #[allow(dead_code)] // id 预留用于持久化和 Tab 标识
 #[derive(Clone)]
@@ -21,10 +36,12 @@ pub struct QueryTab {
     pub title: String,
     /// SQL 内容
     pub sql: String,
-    /// 查询结果
-    pub result: Option<QueryResult>,
+    /// 查询结果（与当前显示的结果共享，切换 Tab 时不复制数据）
+    pub result: Option<Arc<QueryResult>>,
     /// 是否正在执行
     pub executing: bool,
+    /// 是否在排队等待执行
+    pub queued: bool,
     /// 最后一条消息
     pub last_message: Option<String>,
     /// 查询耗时 (毫秒)
@@ -33,6 +50,20 @@ pub struct QueryTab {
     pub modified: bool,
     /// 关联的表名 (如果有)
     pub table_name: Option<String>,
+    /// 分页读取的当前页（如果有）
+    pub page: Option<ResultPage>,
+    /// 是否已固定（"关闭其他/关闭右侧"时保留，退出时保存到会话）
+    pub pinned: bool,
+    /// 是否为定时查询的结果 Tab（同时固定，不保存到会话）
+    pub scheduled: bool,
+    /// 标题是否由用户重命名（不再根据 SQL 自动更新）
+    pub renamed: bool,
+    /// 是否在独立窗口中显示
+    pub detached: bool,
+    /// 固定的结果快照
+    pub snapshot: Option<ResultSnapshot>,
+    /// 关联的 .sql 文件（如果有）
+    pub file: Option<SqlFile>,
 }
 
 impl QueryTab {
@@ -44,13 +75,57 @@ impl QueryTab {
             sql: String::new(),
             result: None,
             executing: false,
+            queued: false,
             last_message: None,
             query_time_ms: None,
             modified: false,
             table_name: None,
+            page: None,
+            pinned: false,
+            scheduled: false,
+            renamed: false,
+            detached: false,
+            snapshot: None,
+            file: None,
         }
     }
 
+    /// 从磁盘文件创建 Tab
+    pub fn from_file(file: SqlFile, content: String) -> Self {
+        let mut tab = Self::new();
+        tab.title = file.file_name();
+        tab.sql = content;
+        tab.file = Some(file);
+        tab
+    }
+
+    /// 从保存的状态（会话文件、回收站）创建 Tab
+    pub fn from_state(state: TabState) -> Self {
+        let mut tab = Self::new();
+        tab.title = state.title;
+        tab.sql = state.sql;
+        tab.table_name = state.associated_table;
+        tab.pinned = state.pinned;
+        tab.renamed = state.renamed;
+        tab
+    }
+
+    /// 需要保存的状态（不含查询结果）
+    pub fn to_state(&self) -> TabState {
+        TabState {
+            title: self.title.clone(),
+            sql: self.sql.clone(),
+            associated_table: self.table_name.clone(),
+            pinned: self.pinned,
+            renamed: self.renamed,
+        }
+    }
+
+    /// 关联文件的内容是否有未保存的修改
+    pub fn is_dirty(&self) -> bool {
+        self.file.as_ref().is_some_and(|f| f.is_dirty(&self.sql))
+    }
+
     /// 从 SQL 内容创建 Tab
     #[allow(dead_code)] // 公开 API，供外部使用
     pub fn from_sql(sql: &str) -> Self {
@@ -106,9 +181,9 @@ impl QueryTab {
         }
     }
 
-    /// 更新标题
+    /// 更新标题（重命名过的 Tab 保留用户的标题）
     pub fn update_title(&mut self) {
-        if self.table_name.is_none() {
+        if self.table_name.is_none() && !self.renamed {
             self.title = Self::extract_title(&self.sql);
         }
     }
@@ -138,6 +213,21 @@ pub struct QueryTabManager {
     pub active_index: usize,
     /// 最大 Tab 数量
     pub max_tabs: usize,
+    /// 正在重命名的 Tab
+    pub renaming: Option<TabRename>,
+    /// 已关闭、还未放入回收站的 Tab
+    closed: Vec<QueryTab>,
+}
+
+/// 正在重命名的 Tab（在 Tab 栏中显示输入框）
+#[derive(Debug, Clone)]
+pub struct TabRename {
+    /// Tab 索引
+    pub index: usize,
+    /// 编辑中的标题
+    pub text: String,
+    /// 是否已让输入框获得焦点
+    focused: bool,
 }
 
 impl Default for QueryTabManager {
@@ -153,6 +243,8 @@ impl QueryTabManager {
             tabs: Vec::new(),
             active_index: 0,
             max_tabs: 20,
+            renaming: None,
+            closed: Vec::new(),
         };
         // 创建初始 Tab
         manager.new_tab();
@@ -218,6 +310,52 @@ impl QueryTabManager {
         self.active_index
     }
 
+    /// 创建定时查询固定的结果 Tab 并激活，返回 Tab ID（已达最大数量时返回 None）
+    pub fn new_pinned_tab(&mut self, title: &str, sql: &str) -> Option<String> {
+        if self.tabs.len() >= self.max_tabs {
+            return None;
+        }
+
+        let mut tab = QueryTab::from_sql(sql);
+        tab.title = title.to_string();
+        tab.modified = false;
+        tab.pinned = true;
+        tab.scheduled = true;
+        let id = tab.id.clone();
+        self.tabs.push(tab);
+        self.active_index = self.tabs.len() - 1;
+        Some(id)
+    }
+
+    /// 在新 Tab 中打开文件并激活；文件已打开时激活已有的 Tab
+    ///
+    /// 已达最大数量时返回 None。
+    pub fn open_file_tab(&mut self, file: SqlFile, content: String) -> Option<usize> {
+        if let Some(idx) = self.position_of_file(file.path()) {
+            self.active_index = idx;
+            return Some(idx);
+        }
+        if self.tabs.len() >= self.max_tabs {
+            return None;
+        }
+
+        self.tabs.push(QueryTab::from_file(file, content));
+        self.active_index = self.tabs.len() - 1;
+        Some(self.active_index)
+    }
+
+    /// 按文件路径查找 Tab 索引
+    pub fn position_of_file(&self, path: &Path) -> Option<usize> {
+        self.tabs
+            .iter()
+            .position(|t| t.file.as_ref().is_some_and(|f| f.path() == path))
+    }
+
+    /// 按 ID 查找 Tab 索引
+    pub fn position(&self, id: &str) -> Option<usize> {
+        self.tabs.iter().position(|t| t.id == id)
+    }
+
     /// 为表创建新 Tab（如果已存在则激活）
     #[allow(dead_code)] // 公开 API，供外部使用
     pub fn new_tab_for_table(&mut self, table_name: &str, sql: &str) -> usize {
@@ -250,7 +388,9 @@ impl QueryTabManager {
         }
 
         if index < self.tabs.len() {
-            self.tabs.remove(index);
+            let tab = self.tabs.remove(index);
+            self.closed.push(tab);
+            self.renaming = None;
             
             // 调整活动索引
             if self.active_index >= self.tabs.len() {
@@ -266,21 +406,74 @@ impl QueryTabManager {
         self.close_tab(self.active_index);
     }
 
-    /// 关闭其他所有 Tab
+    /// 关闭其他所有 Tab（保留固定的 Tab）
     pub fn close_other_tabs(&mut self) {
-        if let Some(active_tab) = self.tabs.get(self.active_index).cloned() {
-            self.tabs = vec![active_tab];
-            self.active_index = 0;
-        }
+        let active = self.active_index.min(self.tabs.len().saturating_sub(1));
+        // 活动 Tab 左侧保留的固定 Tab 数即为活动 Tab 的新位置
+        let new_active = self.tabs[..active].iter().filter(|tab| tab.pinned).count();
+        self.close_tabs_where(|idx, tab| idx != active && !tab.pinned);
+        self.active_index = new_active;
+        self.renaming = None;
     }
 
-    /// 关闭右侧所有 Tab
+    /// 关闭右侧所有 Tab（保留固定的 Tab）
     pub fn close_tabs_to_right(&mut self) {
-        if self.active_index < self.tabs.len() - 1 {
-            self.tabs.truncate(self.active_index + 1);
+        let active = self.active_index;
+        self.close_tabs_where(|idx, tab| idx > active && !tab.pinned);
+        self.renaming = None;
+    }
+
+    /// 关闭满足条件的 Tab（参数为 Tab 索引和 Tab）
+    fn close_tabs_where(&mut self, close: impl Fn(usize, &QueryTab) -> bool) {
+        for (idx, tab) in std::mem::take(&mut self.tabs).into_iter().enumerate() {
+            if close(idx, &tab) {
+                self.closed.push(tab);
+            } else {
+                self.tabs.push(tab);
+            }
+        }
+    }
+
+    /// 取出已关闭的 Tab
+    pub fn take_closed(&mut self) -> Vec<QueryTab> {
+        std::mem::take(&mut self.closed)
+    }
+
+    /// 开始重命名 Tab
+    pub fn start_rename(&mut self, index: usize) {
+        if let Some(tab) = self.tabs.get(index) {
+            self.renaming = Some(TabRename { index, text: tab.title.clone(), focused: false });
         }
     }
 
+    /// 重命名 Tab；标题为空时恢复根据 SQL 自动生成的标题
+    pub fn rename_tab(&mut self, index: usize, title: &str) {
+        if let Some(tab) = self.tabs.get_mut(index) {
+            let title = title.trim();
+            if title.is_empty() {
+                tab.renamed = false;
+                tab.update_title();
+            } else {
+                tab.title = title.to_string();
+                tab.renamed = true;
+            }
+        }
+    }
+
+    /// 切换 Tab 的固定状态，返回是否固定
+    pub fn toggle_pin(&mut self, index: usize) -> Option<bool> {
+        let tab = self.tabs.get_mut(index)?;
+        tab.pinned = !tab.pinned;
+        Some(tab.pinned)
+    }
+
+    /// 切换 Tab 是否在独立窗口中显示，返回是否已分离
+    pub fn toggle_detach(&mut self, index: usize) -> Option<bool> {
+        let tab = self.tabs.get_mut(index)?;
+        tab.detached = !tab.detached;
+        Some(tab.detached)
+    }
+
     /// 获取当前活动的 Tab
     pub fn get_active(&self) -> Option<&QueryTab> {
         self.tabs.get(self.active_index)
@@ -367,6 +560,16 @@ pub struct TabBarActions {
     pub close_others: bool,
     /// 关闭右侧
     pub close_right: bool,
+    /// 取消排队中的查询的 Tab 索引
+    pub cancel_queued: Option<usize>,
+    /// 开始重命名指定 Tab
+    pub start_rename: Option<usize>,
+    /// 重命名完成 (Tab 索引, 新标题)
+    pub rename: Option<(usize, String)>,
+    /// 切换指定 Tab 的固定状态
+    pub toggle_pin: Option<usize>,
+    /// 在独立窗口中打开或合并回主窗口
+    pub toggle_detach: Option<usize>,
     /// 焦点转移
     pub focus_transfer: Option<TabBarFocusTransfer>,
 }
@@ -375,11 +578,12 @@ pub struct TabBarActions {
 pub struct QueryTabBar;
 
 impl QueryTabBar {
-    /// 显示 Tab 栏
+    /// 显示 Tab 栏（双击标题重命名）
     pub fn show(
         ui: &mut Ui,
         tabs: &[QueryTab],
         active_index: usize,
+        renaming: &mut Option<TabRename>,
         highlight_colors: &HighlightColors,
     ) -> TabBarActions {
         let mut actions = TabBarActions::default();
@@ -411,6 +615,20 @@ impl QueryTabBar {
                         // 状态图标
                         if tab.executing {
                             ui.spinner();
+                        } else if tab.queued {
+                            ui.label(RichText::new("⏸").color(highlight_colors.number).small())
+                                .on_hover_text("排队等待执行");
+                        } else if tab.scheduled {
+                            ui.label(RichText::new("📌").small()).on_hover_text("定时查询结果");
+                        } else if tab.pinned {
+                            ui.label(RichText::new("📌").small()).on_hover_text("已固定：关闭其他/右侧标签时保留");
+                        } else if tab.detached {
+                            ui.label(RichText::new("⧉").small()).on_hover_text("已在独立窗口中打开");
+                        } else if tab.snapshot.is_some() {
+                            ui.label(RichText::new("📸").small()).on_hover_text("已固定结果快照");
+                        } else if tab.is_dirty() {
+                            ui.label(RichText::new("●").color(highlight_colors.number).small())
+                                .on_hover_text("文件有未保存的修改");
                         } else if tab.modified {
                             ui.label(RichText::new("*").color(highlight_colors.number).small());
                         }
@@ -422,6 +640,26 @@ impl QueryTabBar {
                             highlight_colors.default
                         };
                         
+                        // 重命名中显示输入框：Enter 或失去焦点时确认，Esc 取消
+                        if let Some(rename) = renaming.as_mut().filter(|r| r.index == idx) {
+                            let response = ui.add(
+                                egui::TextEdit::singleline(&mut rename.text)
+                                    .desired_width(120.0)
+                                    .font(egui::TextStyle::Small),
+                            );
+                            if !rename.focused {
+                                response.request_focus();
+                                rename.focused = true;
+                            }
+                            if response.lost_focus() {
+                                if !ui.input(|i| i.key_pressed(egui::Key::Escape)) {
+                                    actions.rename = Some((idx, rename.text.clone()));
+                                }
+                                *renaming = None;
+                            }
+                            return;
+                        }
+
                         let title_response = ui.add(
                             egui::Label::new(
                                 RichText::new(&tab.title)
@@ -431,9 +669,17 @@ impl QueryTabBar {
                             .sense(egui::Sense::click()),
                         );
 
+                        let title_response = match &tab.file {
+                            Some(file) => title_response.on_hover_text(file.path().display().to_string()),
+                            None => title_response,
+                        };
+
                         if title_response.clicked() {
                             actions.switch_to = Some(idx);
                         }
+                        if title_response.double_clicked() {
+                            actions.start_rename = Some(idx);
+                        }
 
                         // 右键菜单
                         title_response.context_menu(|ui| {
@@ -446,22 +692,48 @@ impl QueryTabBar {
                                 ).on_hover_text(tooltip).clicked()
                             };
                             
+                            if tab.queued && menu_btn(ui, "⏹ 取消排队", "取消排队中的查询") {
+                                actions.cancel_queued = Some(idx);
+                                ui.close();
+                            }
+                            if menu_btn(ui, "✎ 重命名", "重命名此标签 (F2)") {
+                                actions.start_rename = Some(idx);
+                                ui.close();
+                            }
+                            let (pin_text, pin_tip) = if tab.pinned {
+                                ("📌 取消固定", "取消固定此标签")
+                            } else {
+                                ("📌 固定", "固定后关闭其他/右侧标签时保留，退出时保存")
+                            };
+                            if menu_btn(ui, pin_text, pin_tip) {
+                                actions.toggle_pin = Some(idx);
+                                ui.close();
+                            }
+                            let (detach_text, detach_tip) = if tab.detached {
+                                ("⤓ 合并回主窗口", "关闭此标签的独立窗口")
+                            } else {
+                                ("⧉ 在新窗口中打开", "在独立窗口中显示此标签的结果，可以拖到其他显示器")
+                            };
+                            if menu_btn(ui, detach_text, detach_tip) {
+                                actions.toggle_detach = Some(idx);
+                                ui.close();
+                            }
                             if menu_btn(ui, "✕ 关闭", "关闭此标签") {
                                 actions.close_tab = Some(idx);
                                 ui.close();
                             }
-                            if menu_btn(ui, "◎ 关闭其他", "关闭其他标签") {
+                            if menu_btn(ui, "◎ 关闭其他", "关闭其他未固定的标签") {
                                 actions.close_others = true;
                                 ui.close();
                             }
-                            if menu_btn(ui, "▷ 关闭右侧", "关闭右侧标签") {
+                            if menu_btn(ui, "▷ 关闭右侧", "关闭右侧未固定的标签") {
                                 actions.close_right = true;
                                 ui.close();
                             }
                         });
 
-                        // 关闭按钮 - 无边框图标
-                        if tabs.len() > 1 {
+                        // 关闭按钮 - 无边框图标（固定的标签只能从右键菜单关闭）
+                        if tabs.len() > 1 && !tab.pinned {
                             let close_response = ui.add(
                                 egui::Button::new(RichText::new("×").size(12.0).color(highlight_colors.comment))
                                     .frame(false)
@@ -490,6 +762,14 @@ impl QueryTabBar {
             ).on_hover_text("新建查询 (Ctrl+T)").clicked() {
                 actions.new_tab = true;
             }
+
+            // 排队中的查询数
+            let queued = tabs.iter().filter(|tab| tab.queued).count();
+            if queued > 0 {
+                ui.add_space(8.0);
+                ui.label(RichText::new(format!("⏳ {} 个排队", queued)).small().color(highlight_colors.number))
+                    .on_hover_text("同一连接上执行中的查询达到上限，这些查询会依次自动开始");
+            }
         });
 
         actions
@@ -501,6 +781,8 @@ impl QueryTabBar {
     /// - j: 向下进入数据表格
     /// - k: 向上进入工具栏
     /// - d: 删除当前Tab
+    /// - r: 重命名当前Tab
+    /// - p: 固定/取消固定当前Tab
     /// - Enter: 确认选择当前Tab（进入数据表格）
     pub fn handle_keyboard(
         ui: &mut Ui,
@@ -514,17 +796,17 @@ impl QueryTabBar {
         
         ui.input(|i| {
             // h/左箭头: 切换到左边的Tab
-            if i.key_pressed(egui::Key::H) || i.key_pressed(egui::Key::ArrowLeft) {
-                if active_index > 0 {
-                    actions.switch_to = Some(active_index - 1);
-                }
+            if (i.key_pressed(egui::Key::H) || i.key_pressed(egui::Key::ArrowLeft))
+                && active_index > 0
+            {
+                actions.switch_to = Some(active_index - 1);
             }
             
             // l/右箭头: 切换到右边的Tab
-            if i.key_pressed(egui::Key::L) || i.key_pressed(egui::Key::ArrowRight) {
-                if active_index < tab_count - 1 {
-                    actions.switch_to = Some(active_index + 1);
-                }
+            if (i.key_pressed(egui::Key::L) || i.key_pressed(egui::Key::ArrowRight))
+                && active_index < tab_count - 1
+            {
+                actions.switch_to = Some(active_index + 1);
             }
             
             // j/下箭头: 向下进入数据表格
@@ -546,6 +828,16 @@ impl QueryTabBar {
             if i.key_pressed(egui::Key::D) && tab_count > 1 {
                 actions.close_tab = Some(active_index);
             }
+
+            // r: 重命名当前Tab
+            if i.key_pressed(egui::Key::R) && !i.modifiers.ctrl {
+                actions.start_rename = Some(active_index);
+            }
+
+            // p: 固定/取消固定当前Tab
+            if i.key_pressed(egui::Key::P) && !i.modifiers.ctrl {
+                actions.toggle_pin = Some(active_index);
+            }
             
             // Escape: 返回数据表格
             if i.key_pressed(egui::Key::Escape) {
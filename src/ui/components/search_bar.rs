@@ -0,0 +1,82 @@
+//! 结果搜索栏
+//!
+//! 显示在结果表格上方，按关键字搜索所有列或指定的列（列名支持补全）。
+//! 可切换正则表达式、全词匹配和区分大小写，匹配片段在表格中高亮。
+
+use super::grid::{SearchMatcher, SearchOptions};
+use super::ColumnPicker;
+use crate::core::{t, tf};
+use crate::ui::styles::{DANGER, GRAY};
+use egui::{self, RichText, TextEdit};
+use std::collections::HashMap;
+
+/// 结果搜索栏
+pub struct SearchBar;
+
+impl SearchBar {
+    /// 显示搜索栏，返回是否正在输入（输入时表格不响应键盘）
+    pub fn show(
+        ui: &mut egui::Ui,
+        search_text: &mut String,
+        search_column: &mut Option<String>,
+        options: &mut SearchOptions,
+        columns: &[String],
+        types: Option<&HashMap<String, String>>,
+    ) -> bool {
+        let picker_id = ui.make_persistent_id("search_column");
+        // 切换结果后原来的列可能已不存在
+        if search_column.as_ref().is_some_and(|c| !columns.contains(c)) {
+            *search_column = None;
+        }
+
+        ui.horizontal(|ui| {
+            ui.spacing_mut().item_spacing.x = 4.0;
+            ui.label(RichText::new(t("search.label")).small().color(GRAY));
+            let error = SearchMatcher::new(search_text, options).err();
+            let mut edit = TextEdit::singleline(search_text)
+                .hint_text(t("search.placeholder"))
+                .font(egui::TextStyle::Small)
+                .desired_width(180.0);
+            if error.is_some() {
+                edit = edit.text_color(DANGER);
+            }
+            let input = ui.add(edit);
+            let input = match &error {
+                Some(e) => input.on_hover_text(tf("search.invalid_regex", &[e])),
+                None if !search_text.is_empty() => input.on_hover_text(t("search.navigate_hint")),
+                None => input,
+            };
+
+            Self::option_toggle(ui, &mut options.regex, ".*", "search.regex");
+            Self::option_toggle(ui, &mut options.whole_word, "W", "search.whole_word");
+            Self::option_toggle(ui, &mut options.case_sensitive, "Aa", "search.case_sensitive");
+
+            ui.label(RichText::new(t("search.in_column")).small().color(GRAY));
+            let mut column = search_column.clone().unwrap_or_default();
+            if ColumnPicker::show(ui, picker_id, &mut column, columns, types, 120.0, Some(t("search.all_columns"))) {
+                *search_column = (!column.is_empty()).then_some(column);
+            }
+
+            if (!search_text.is_empty() || search_column.is_some())
+                && ui
+                    .add(egui::Button::new(RichText::new("×").small().color(GRAY)).frame(false))
+                    .on_hover_text(t("search.clear"))
+                    .clicked()
+            {
+                search_text.clear();
+                *search_column = None;
+            }
+
+            input.has_focus() || ColumnPicker::is_editing(ui.ctx(), picker_id)
+        })
+        .inner
+    }
+
+    /// 搜索选项开关
+    fn option_toggle(ui: &mut egui::Ui, value: &mut bool, label: &str, tooltip: &str) {
+        let text = RichText::new(label).small().monospace();
+        if ui.selectable_label(*value, text).on_hover_text(t(tooltip)).clicked() {
+            *value = !*value;
+        }
+    }
+}
@@ -3,24 +3,47 @@
 //! 特性：
 //! - Normal 模式：hjkl 移动，w/b 词跳转，Helix 风格导航
 //! - Insert 模式：双击进入，正常输入
+//! - Command 模式：Normal 模式下按 `:` 打开命令行
 //! - Ctrl+Enter 执行 SQL
 //! - 语法高亮 + 自动补全
+//! - 当前行高亮、括号匹配、Ctrl+/ 切换注释
+//! - Ctrl+Alt+↑/↓ 多光标列编辑
+//! - 查询出错时用红色波浪线标出出错位置
 
 #![allow(clippy::too_many_arguments)]
 
-use crate::core::{highlight_sql, AutoComplete, CompletionKind, HighlightColors};
-use crate::ui::styles::GRAY;
-use egui::{self, Align, Color32, Key, Layout, PopupCloseBehavior, RichText, ScrollArea, TextEdit, Vec2};
+use crate::core::{
+    editor_text_style, find_matching_bracket, highlight_sql, toggle_line_comment, AutoComplete, CompletionKind,
+    write_preview, HighlightColors, MultiCursor,
+};
+use crate::ui::styles::{DANGER, GRAY};
+use super::command_line::{CommandLine, CommandLineResult, CommandLineState, EditorCommand};
+use egui::text::{CCursor, CCursorRange};
+use std::ops::Range;
+use egui::{self, Align, Color32, Key, Layout, Modifiers, PopupCloseBehavior, RichText, ScrollArea, TextEdit, Vec2};
 
 /// 行号区域宽度
 const LINE_NUMBER_WIDTH: f32 = 45.0;
 
+/// 查询出错的位置
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SqlErrorMarker {
+    /// 出错时编辑器中的文本（文本修改后不再显示标记）
+    pub text: String,
+    /// 出错位置的字节范围
+    pub span: Range<usize>,
+    /// 错误信息（悬停时显示）
+    pub message: String,
+}
+
 /// 编辑器模式
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum EditorMode {
     #[default]
     Normal,
     Insert,
+    /// `:` 命令行
+    Command,
 }
 
 impl EditorMode {
@@ -28,6 +51,7 @@ impl EditorMode {
         match self {
             EditorMode::Normal => "NOR",
             EditorMode::Insert => "INS",
+            EditorMode::Command => "CMD",
         }
     }
     
@@ -35,6 +59,7 @@ impl EditorMode {
         match self {
             EditorMode::Normal => Color32::from_rgb(130, 170, 255),  // 蓝色
             EditorMode::Insert => Color32::from_rgb(180, 230, 140),  // 绿色
+            EditorMode::Command => Color32::from_rgb(240, 190, 110), // 橙色
         }
     }
 }
@@ -96,6 +121,16 @@ fn get_cursor_position(text: &str, cursor_pos: usize) -> (usize, usize) {
     (line, column)
 }
 
+/// 字符索引转换为字节偏移（egui 光标使用字符索引）
+fn char_to_byte(text: &str, char_index: usize) -> usize {
+    text.char_indices().nth(char_index).map(|(i, _)| i).unwrap_or(text.len())
+}
+
+/// 字节偏移转换为字符索引
+fn byte_to_char(text: &str, byte_index: usize) -> usize {
+    text[..byte_index.min(text.len())].chars().count()
+}
+
 /// 获取行的起始和结束位置
 fn get_line_bounds(text: &str, cursor_pos: usize) -> (usize, usize) {
     let pos = cursor_pos.min(text.len());
@@ -147,10 +182,18 @@ pub struct SqlEditorActions {
     pub format: bool,
     pub clear: bool,
     pub explain: bool,
+    /// 预览 UPDATE/DELETE 将修改的行
+    pub preview_write: bool,
+    /// 不执行而检查 SQL
+    pub validate: bool,
     pub focus_to_grid: bool,
     pub request_focus: bool,
     /// Escape 键已被编辑器消费（用于退出 Insert 模式）
     pub escape_consumed: bool,
+    /// 命令行中执行的命令（执行、格式化等编辑器操作已转换为上面的标志）
+    pub command: Option<EditorCommand>,
+    /// 命令行输入错误
+    pub command_error: Option<String>,
 }
 
 impl SqlEditor {
@@ -170,6 +213,11 @@ impl SqlEditor {
         request_focus: &mut bool,
         is_focused: bool,
         editor_mode: &mut EditorMode,
+        console_hint: Option<&str>,
+        command_line: &mut CommandLineState,
+        connections: &[String],
+        tables: &[String],
+        error_marker: Option<&SqlErrorMarker>,
     ) -> SqlEditorActions {
         let mut actions = SqlEditorActions::default();
 
@@ -181,16 +229,36 @@ impl SqlEditor {
         let editor_height = (available_height - status_bar_height - toolbar_height - 8.0).max(60.0);
 
         // ========== 工具栏 ==========
-        Self::show_toolbar(ui, sql_input, is_executing, &mut actions, toolbar_height, *editor_mode);
+        Self::show_toolbar(ui, sql_input, is_executing, &mut actions, toolbar_height, *editor_mode, console_hint);
         
         ui.add_space(2.0);
 
         // ========== 编辑器主体 ==========
         let line_count = count_lines(sql_input);
-        let line_height = 16.0;
+        let editor_font = editor_text_style().resolve(ui.style());
+        let line_height = ui.text_style_height(&editor_text_style());
         
         // 共享滚动状态 ID
         let scroll_id = ui.id().with("editor_scroll");
+        // 文本框和多光标状态 ID
+        let text_id = ui.id().with("sql_editor_text");
+        let multi_cursor_id = text_id.with("multi_cursor");
+
+        // 上一帧的光标（用于行号高亮和编辑前的快捷键处理）
+        let is_insert = *editor_mode == EditorMode::Insert;
+        let prev_range = if is_insert {
+            TextEdit::load_state(ui.ctx(), text_id).and_then(|s| s.cursor.char_range())
+        } else {
+            None
+        };
+        let current_line = prev_range.map(|r| {
+            let pos = char_to_byte(sql_input, r.primary.index);
+            get_cursor_position(sql_input, pos).0
+        });
+        let error_marker = error_marker.filter(|marker| marker.text == *sql_input);
+        let error_line = error_marker
+            .and_then(|marker| sql_input.get(..marker.span.start))
+            .map(|before| before.matches('\n').count() + 1);
         
         ui.horizontal(|ui| {
             ui.spacing_mut().item_spacing.x = 0.0;
@@ -214,15 +282,21 @@ impl SqlEditor {
                             ui.set_width(LINE_NUMBER_WIDTH);
                             let display_lines = line_count.max((editor_height / line_height) as usize);
                             for line_num in 1..=display_lines {
+                                let color = if error_line == Some(line_num) {
+                                    DANGER
+                                } else if current_line == Some(line_num) {
+                                    highlight_colors.keyword
+                                } else {
+                                    highlight_colors.comment
+                                };
                                 ui.horizontal(|ui| {
                                     ui.set_height(line_height);
                                     ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
                                         ui.add_space(8.0);
                                         ui.label(
                                             RichText::new(format!("{}", line_num))
-                                                .monospace()
-                                                .size(13.0)
-                                                .color(highlight_colors.comment),
+                                                .font(editor_font.clone())
+                                                .color(color),
                                         );
                                     });
                                 });
@@ -242,6 +316,9 @@ impl SqlEditor {
                     let colors = highlight_colors.clone();
                     let mut layouter = |ui: &egui::Ui, text: &dyn egui::TextBuffer, wrap_width: f32| {
                         let mut job = highlight_sql(text.as_str(), &colors);
+                        for section in &mut job.sections {
+                            section.format.font_id = editor_font.clone();
+                        }
                         job.wrap.max_width = wrap_width;
                         ui.ctx().fonts_mut(|f| f.layout_job(job))
                     };
@@ -252,9 +329,34 @@ impl SqlEditor {
                         .show(ui, |ui| {
                             // Insert 模式：可编辑；Normal 模式：只读显示
                             let is_insert_mode = *editor_mode == EditorMode::Insert;
-                            
+
+                            // 多光标和注释切换需要在文本框处理输入之前修改文本
+                            let mut multi_cursor = ui.ctx().data(|d| {
+                                d.get_temp::<MultiCursor>(multi_cursor_id).unwrap_or_default()
+                            });
+                            if is_insert_mode && is_focused {
+                                let new_cursor = Self::handle_editing_keys(
+                                    ui,
+                                    sql_input,
+                                    prev_range,
+                                    &mut multi_cursor,
+                                    &mut actions,
+                                );
+                                if let Some(range) = new_cursor {
+                                    let mut state = TextEdit::load_state(ui.ctx(), text_id).unwrap_or_default();
+                                    state.cursor.set_char_range(Some(range));
+                                    state.store(ui.ctx(), text_id);
+                                }
+                            } else {
+                                multi_cursor.clear();
+                            }
+
+                            // 当前行背景需要画在文字下方，先占位
+                            let background = ui.painter().add(egui::Shape::Noop);
+
                             let output = TextEdit::multiline(sql_input)
-                                .font(egui::TextStyle::Monospace)
+                                .id(text_id)
+                                .font(editor_text_style())
                                 .desired_width(editor_width - 16.0)
                                 .desired_rows(((editor_height / line_height) as usize).max(4))
                                 .hint_text(if is_insert_mode { 
@@ -270,12 +372,36 @@ impl SqlEditor {
                             
                             let response = &output.response;
 
+                            Self::paint_decorations(
+                                ui,
+                                &output,
+                                sql_input,
+                                &multi_cursor,
+                                background,
+                                highlight_colors,
+                            );
+                            if let Some(marker) = error_marker {
+                                Self::paint_error_marker(ui, &output, sql_input, marker);
+                            }
+                            ui.ctx().data_mut(|d| d.insert_temp(multi_cursor_id, multi_cursor));
+
                             // 双击进入 Insert 模式
                             if response.double_clicked() {
                                 *editor_mode = EditorMode::Insert;
                                 response.request_focus();
                             }
                             
+                            // : 打开命令行
+                            if *editor_mode == EditorMode::Normal && is_focused {
+                                let colon = ui.input(|i| {
+                                    i.events.iter().any(|e| matches!(e, egui::Event::Text(t) if t == ":"))
+                                });
+                                if colon {
+                                    *editor_mode = EditorMode::Command;
+                                    command_line.open();
+                                }
+                            }
+
                             // i 键也可进入 Insert 模式（在 Normal 模式下）
                             if *editor_mode == EditorMode::Normal && is_focused {
                                 let enter_insert = ui.input(|i| {
@@ -390,7 +516,35 @@ impl SqlEditor {
 
         ui.add_space(2.0);
 
-        // ========== 状态栏 ==========
+        // ========== 命令行 / 状态栏 ==========
+        if *editor_mode == EditorMode::Command {
+            match CommandLine::show(ui, command_line, connections, tables) {
+                Some(CommandLineResult::Submit(Ok(command))) => {
+                    // 编辑器自身的操作直接转换为对应的标志，其余交给调用方
+                    match command {
+                        EditorCommand::Run => actions.execute = true,
+                        EditorCommand::Explain => actions.explain = true,
+                        EditorCommand::Preview => actions.preview_write = true,
+                        EditorCommand::Validate => actions.validate = true,
+                        EditorCommand::Format => actions.format = true,
+                        EditorCommand::Clear => actions.clear = true,
+                        command => actions.command = Some(command),
+                    }
+                    *editor_mode = EditorMode::Normal;
+                }
+                Some(CommandLineResult::Submit(Err(e))) => {
+                    actions.command_error = Some(e);
+                    *editor_mode = EditorMode::Normal;
+                }
+                Some(CommandLineResult::Cancel) => {
+                    actions.escape_consumed = true;
+                    *editor_mode = EditorMode::Normal;
+                }
+                None => {}
+            }
+            return actions;
+        }
+
         Self::show_status_bar(
             ui,
             sql_input,
@@ -413,6 +567,7 @@ impl SqlEditor {
         actions: &mut SqlEditorActions,
         height: f32,
         mode: EditorMode,
+        console_hint: Option<&str>,
     ) {
         ui.horizontal(|ui| {
             ui.set_height(height);
@@ -440,10 +595,23 @@ impl SqlEditor {
                 actions.execute = true;
             }
             
-            if icon_btn(ui, "📊", !is_executing && !sql_input.trim().is_empty(), "分析 (F6)") {
+            // 非 SQL 的命令控制台（Redis、MongoDB）不显示分析按钮
+            if console_hint.is_none() && icon_btn(ui, "📊", !is_executing && !sql_input.trim().is_empty(), "分析 (F6)") {
                 actions.explain = true;
             }
             
+            if console_hint.is_none()
+                && icon_btn(ui, "✔", !is_executing && !sql_input.trim().is_empty(), "检查 SQL，不执行 (F7)")
+            {
+                actions.validate = true;
+            }
+            
+            if console_hint.is_none()
+                && icon_btn(ui, "👁", !is_executing && write_preview(sql_input).is_some(), "预览影响的行 (:preview)")
+            {
+                actions.preview_write = true;
+            }
+            
             if icon_btn(ui, "🗑", !sql_input.is_empty(), "清空") {
                 actions.clear = true;
             }
@@ -455,12 +623,18 @@ impl SqlEditor {
             ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
                 if mode == EditorMode::Normal {
                     ui.label(RichText::new("双击/i 编辑").small().color(GRAY));
+                    ui.label(RichText::new(": 命令").small().color(GRAY));
                     ui.label(RichText::new("hjkl 移动").small().color(GRAY));
                 } else {
                     ui.label(RichText::new("Esc 退出编辑").small().color(GRAY));
                     ui.label(RichText::new("Tab 补全").small().color(GRAY));
+                    ui.label(RichText::new("Ctrl+/ 注释").small().color(GRAY));
+                    ui.label(RichText::new("Ctrl+Alt+↑↓ 多光标").small().color(GRAY));
                 }
                 ui.label(RichText::new("Ctrl+Enter 执行").small().color(GRAY));
+                if let Some(hint) = console_hint {
+                    ui.label(RichText::new(hint).small().color(GRAY));
+                }
             });
         });
     }
@@ -532,6 +706,194 @@ impl SqlEditor {
             });
     }
 
+    /// Insert 模式下需要先于文本框处理的按键：注释切换和多光标编辑
+    ///
+    /// 返回需要写回文本框的新光标位置。
+    fn handle_editing_keys(
+        ui: &mut egui::Ui,
+        sql_input: &mut String,
+        prev_range: Option<CCursorRange>,
+        multi_cursor: &mut MultiCursor,
+        actions: &mut SqlEditorActions,
+    ) -> Option<CCursorRange> {
+        let (primary, secondary) = prev_range
+            .map(|r| (char_to_byte(sql_input, r.primary.index), char_to_byte(sql_input, r.secondary.index)))
+            .unwrap_or((sql_input.len(), sql_input.len()));
+
+        // Ctrl+/ 切换选中行的注释
+        if ui.input_mut(|i| i.consume_key(Modifiers::COMMAND, Key::Slash)) {
+            multi_cursor.clear();
+            let (start, end) = toggle_line_comment(sql_input, secondary, primary);
+            let (start, end) = (byte_to_char(sql_input, start), byte_to_char(sql_input, end));
+            return Some(CCursorRange::two(CCursor::new(start), CCursor::new(end)));
+        }
+
+        // Ctrl+Alt+↑/↓ 在上/下一行同一列添加光标
+        let ctrl_alt = Modifiers::COMMAND | Modifiers::ALT;
+        let add_below = ui.input_mut(|i| i.consume_key(ctrl_alt, Key::ArrowDown));
+        let add_above = ui.input_mut(|i| i.consume_key(ctrl_alt, Key::ArrowUp));
+        if add_below {
+            multi_cursor.add_below(sql_input, primary);
+        }
+        if add_above {
+            multi_cursor.add_above(sql_input, primary);
+        }
+
+        if !multi_cursor.is_active() {
+            multi_cursor.clear();
+            return None;
+        }
+
+        // 多光标编辑：拦截输入事件并应用到每个光标，其余事件交还文本框
+        let mut edited = add_below || add_above;
+        let mut exit = false;
+        ui.input_mut(|i| {
+            let events = std::mem::take(&mut i.events);
+            for event in events {
+                match &event {
+                    egui::Event::Text(text) | egui::Event::Paste(text) if !text.contains('\n') => {
+                        multi_cursor.insert(sql_input, text);
+                    }
+                    egui::Event::Key { key, pressed: true, modifiers, .. } if !modifiers.command => match key {
+                        Key::Backspace => multi_cursor.backspace(sql_input),
+                        Key::Delete => multi_cursor.delete(sql_input),
+                        Key::ArrowLeft => multi_cursor.move_left(sql_input),
+                        Key::ArrowRight => multi_cursor.move_right(sql_input),
+                        Key::Home => multi_cursor.move_line_edge(sql_input, false),
+                        Key::End => multi_cursor.move_line_edge(sql_input, true),
+                        Key::Escape => {
+                            exit = true;
+                            actions.escape_consumed = true;
+                        }
+                        Key::ArrowUp | Key::ArrowDown | Key::Enter | Key::Tab | Key::PageUp | Key::PageDown => {
+                            exit = true;
+                            i.events.push(event.clone());
+                            continue;
+                        }
+                        _ => {
+                            i.events.push(event.clone());
+                            continue;
+                        }
+                    },
+                    // 鼠标点击退出多光标，其余事件原样保留
+                    egui::Event::PointerButton { pressed: true, .. } => {
+                        exit = true;
+                        i.events.push(event);
+                        continue;
+                    }
+                    _ => {
+                        i.events.push(event);
+                        continue;
+                    }
+                }
+                edited = true;
+            }
+        });
+
+        let last = multi_cursor.cursors().last().copied();
+        if exit {
+            multi_cursor.clear();
+        }
+        if !edited {
+            return None;
+        }
+        // 文本框自身的光标放在最下方的光标处
+        last.map(|pos| CCursorRange::one(CCursor::new(byte_to_char(sql_input, pos))))
+    }
+
+    /// 绘制当前行高亮、匹配括号和额外的光标
+    fn paint_decorations(
+        ui: &egui::Ui,
+        output: &egui::text_edit::TextEditOutput,
+        sql_input: &str,
+        multi_cursor: &MultiCursor,
+        background: egui::layers::ShapeIdx,
+        highlight_colors: &HighlightColors,
+    ) {
+        let Some(range) = output.cursor_range else {
+            return;
+        };
+        let painter = ui.painter();
+        let galley = &output.galley;
+        let origin = output.galley_pos.to_vec2();
+        let full_width = output.response.rect.x_range();
+
+        // 当前行背景
+        let row = galley.pos_from_cursor(range.primary).translate(origin);
+        painter.set(
+            background,
+            egui::Shape::rect_filled(
+                egui::Rect::from_x_y_ranges(full_width, row.y_range()),
+                0.0,
+                highlight_colors.keyword.gamma_multiply(0.08),
+            ),
+        );
+
+        // 匹配括号
+        let cursor = char_to_byte(sql_input, range.primary.index);
+        if let Some((a, b)) = find_matching_bracket(sql_input, cursor) {
+            let stroke = egui::Stroke::new(1.0, highlight_colors.keyword);
+            for pos in [a, b] {
+                let index = byte_to_char(sql_input, pos);
+                let left = galley.pos_from_cursor(CCursor::new(index)).translate(origin);
+                let right = galley.pos_from_cursor(CCursor::new(index + 1)).translate(origin);
+                let rect = egui::Rect::from_min_max(left.min, egui::pos2(right.min.x, left.max.y));
+                painter.rect_stroke(rect, 2.0, stroke, egui::StrokeKind::Inside);
+            }
+        }
+
+        // 多光标：文本框只绘制自身光标，其余光标在此补绘
+        if multi_cursor.is_active() {
+            let stroke = egui::Stroke::new(2.0, ui.visuals().text_cursor.stroke.color);
+            for &pos in multi_cursor.cursors() {
+                let rect = galley
+                    .pos_from_cursor(CCursor::new(byte_to_char(sql_input, pos)))
+                    .translate(origin);
+                painter.line_segment([rect.center_top(), rect.center_bottom()], stroke);
+            }
+        }
+    }
+
+    /// 用红色波浪线标出查询出错的位置，悬停时显示错误信息
+    fn paint_error_marker(
+        ui: &egui::Ui,
+        output: &egui::text_edit::TextEditOutput,
+        sql_input: &str,
+        marker: &SqlErrorMarker,
+    ) {
+        // 本帧刚修改过文本
+        if marker.text != sql_input {
+            return;
+        }
+        let galley = &output.galley;
+        let origin = output.galley_pos.to_vec2();
+        let left = galley
+            .pos_from_cursor(CCursor::new(byte_to_char(sql_input, marker.span.start)))
+            .translate(origin);
+        let right = galley
+            .pos_from_cursor(CCursor::new(byte_to_char(sql_input, marker.span.end)))
+            .translate(origin);
+        // 跨行时只标到第一行末尾；位置在文本末尾时标出一个字符宽度
+        let right_x = if right.min.y > left.min.y { output.response.rect.right() } else { right.min.x };
+        let (x0, x1) = (left.min.x, right_x.max(left.min.x + 6.0));
+
+        let y = left.max.y - 1.0;
+        let mut points = Vec::new();
+        let mut x = x0;
+        while x < x1 {
+            let offset = if points.len() % 2 == 0 { 0.0 } else { -2.0 };
+            points.push(egui::pos2(x, y + offset));
+            x += 2.0;
+        }
+        points.push(egui::pos2(x1, y));
+        ui.painter().add(egui::Shape::line(points, egui::Stroke::new(1.5, DANGER)));
+
+        let rect = egui::Rect::from_x_y_ranges(x0..=x1, left.y_range());
+        if output.response.hover_pos().is_some_and(|pos| rect.contains(pos)) {
+            output.response.clone().on_hover_text_at_pointer(&marker.message);
+        }
+    }
+
     /// Normal 模式：Helix 风格导航
     fn handle_normal_mode(
         ui: &mut egui::Ui,
@@ -569,16 +931,21 @@ impl SqlEditor {
                 actions.explain = true;
             }
             
+            // F7 检查 SQL
+            if i.key_pressed(Key::F7) && !sql_input.trim().is_empty() {
+                actions.validate = true;
+            }
+            
             // Escape 切换焦点到 Grid
             if i.key_pressed(Key::Escape) {
                 actions.focus_to_grid = true;
             }
             
             // Shift+↑↓ 或 K/J 历史导航
-            let history_up = (i.modifiers.shift && i.key_pressed(Key::ArrowUp)) 
-                || (i.key_pressed(Key::K) && i.modifiers.shift);
-            let history_down = (i.modifiers.shift && i.key_pressed(Key::ArrowDown))
-                || (i.key_pressed(Key::J) && i.modifiers.shift);
+            let history_up = i.modifiers.shift
+                && (i.key_pressed(Key::ArrowUp) || i.key_pressed(Key::K));
+            let history_down = i.modifiers.shift
+                && (i.key_pressed(Key::ArrowDown) || i.key_pressed(Key::J));
             
             if history_up && !command_history.is_empty() {
                 let new_idx = match *history_index {
@@ -649,12 +1016,17 @@ impl SqlEditor {
                 actions.explain = true;
             }
             
+            // F7 检查 SQL
+            if i.key_pressed(Key::F7) && !sql_input.trim().is_empty() {
+                actions.validate = true;
+            }
+            
             // Ctrl+Space 或 Alt+L 触发补全
-            if (i.modifiers.ctrl && i.key_pressed(Key::Space)) || (i.modifiers.alt && i.key_pressed(Key::L)) {
-                if has_completions {
-                    *show_autocomplete = true;
-                    *selected_completion = 0;
-                }
+            if ((i.modifiers.ctrl && i.key_pressed(Key::Space)) || (i.modifiers.alt && i.key_pressed(Key::L)))
+                && has_completions
+            {
+                *show_autocomplete = true;
+                *selected_completion = 0;
             }
 
             // 补全菜单导航
@@ -669,11 +1041,11 @@ impl SqlEditor {
                         *selected_completion -= 1;
                     }
                 }
-                if i.key_pressed(Key::Tab) || i.key_pressed(Key::Enter) {
-                    if *selected_completion < completions.len() {
-                        apply_completion_at_cursor(sql_input, cursor_pos, &completions[*selected_completion].insert_text);
-                        *show_autocomplete = false;
-                    }
+                if (i.key_pressed(Key::Tab) || i.key_pressed(Key::Enter))
+                    && *selected_completion < completions.len()
+                {
+                    apply_completion_at_cursor(sql_input, cursor_pos, &completions[*selected_completion].insert_text);
+                    *show_autocomplete = false;
                 }
             }
             
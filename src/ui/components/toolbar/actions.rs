@@ -1,4 +1,4 @@
-use crate::core::ThemePreset;
+use crate::core::{Language, ThemePreset};
 
 /// 主题下拉框状态
 #[derive(Default, Clone)]
@@ -33,6 +33,10 @@ pub struct ToolbarActions {
     pub toggle_editor: bool,
     pub show_editor: bool,
     pub theme_changed: Option<ThemePreset>,
+    pub show_theme_editor: bool,
+    pub show_font_settings: bool,
+    pub show_preferences: bool,
+    pub language_changed: Option<Language>,
     pub toggle_dark_mode: bool,
     pub switch_connection: Option<String>,
     pub switch_database: Option<String>,
@@ -47,10 +51,55 @@ pub struct ToolbarActions {
     pub create_table: bool,
     pub create_database: bool,
     pub create_user: bool,
+    // 权限管理
+    pub manage_privileges: bool,
+    // 表大小概览
+    pub show_size_overview: bool,
+    // 会话监控
+    pub show_session_monitor: bool,
+    // 当前表操作
+    pub truncate_table: bool,
+    pub duplicate_table: bool,
+    // 测试数据生成
+    pub generate_test_data: bool,
+    // 定时查询
+    pub show_scheduler: bool,
+    // 结果快照对比
+    pub pin_snapshot: bool,
+    pub compare_snapshot: bool,
+    // 审计日志
+    pub show_audit_log: bool,
+    // SQL 文件
+    pub open_file: bool,
+    pub save_file: bool,
+    // 工作流
+    pub show_workflows: bool,
+    // 本地 API 服务
+    pub show_api_server: bool,
+    // 通知中心
+    pub show_notifications: bool,
+    // 运行日志
+    pub show_log_viewer: bool,
+    // 全库数据搜索
+    pub show_data_search: bool,
+    // 连接池诊断
+    pub show_pool_stats: bool,
+    // 回收站
+    pub show_trash: bool,
+    // 主密码
+    pub show_master_password: bool,
+    // 基准测试
+    pub show_benchmark: bool,
+    // 查询分析
+    pub show_profile: bool,
+    // 行书签
+    pub show_row_bookmarks: bool,
     // ER 图
     pub toggle_er_diagram: bool,
     // 关于对话框
     pub show_about: bool,
+    // 新手引导
+    pub start_tour: bool,
     // 快捷键设置
     pub show_keybindings: bool,
     // 焦点转移
@@ -1,5 +1,7 @@
 use egui::{Color32, CornerRadius, Id, Key, RichText, Vec2};
 
+use crate::core::t;
+
 use super::actions::{DropdownState, ToolbarActions};
 use super::utils::render_menu_item;
 
@@ -14,7 +16,7 @@ pub fn show_actions_dropdown(ui: &mut egui::Ui, has_result: bool, actions: &mut
         egui::Button::new(RichText::new("⚡").size(15.0).color(Color32::LIGHT_GRAY))
             .frame(false)
             .min_size(Vec2::new(24.0, 24.0)),
-    ).on_hover_text("操作菜单");
+    ).on_hover_text(t("toolbar.actions"));
     
     if response.clicked() {
         state.is_open = !state.is_open;
@@ -23,10 +25,32 @@ pub fn show_actions_dropdown(ui: &mut egui::Ui, has_result: bool, actions: &mut
     
     if state.is_open {
         let menu_items = [
-            ("导出", "Ctrl+E", has_result),
-            ("导入", "Ctrl+I", true),
-            ("ER图", "Ctrl+R", true),
-            ("历史", "Ctrl+H", true),
+            (t("toolbar.export"), "Ctrl+E", has_result),
+            (t("toolbar.import"), "Ctrl+I", true),
+            (t("toolbar.er_diagram"), "Ctrl+R", true),
+            (t("toolbar.history"), "Ctrl+H", true),
+            (t("toolbar.privileges"), "Ctrl+Shift+M", true),
+            (t("toolbar.size_overview"), "", true),
+            (t("toolbar.session_monitor"), "", true),
+            (t("toolbar.copy_table"), "", true),
+            (t("toolbar.truncate_table"), "", true),
+            (t("toolbar.generate_data"), "", true),
+            (t("toolbar.scheduled_queries"), "", true),
+            (t("toolbar.pin_snapshot"), "", has_result),
+            (t("toolbar.compare_snapshot"), "", has_result),
+            (t("toolbar.audit_log"), "", true),
+            (t("toolbar.open_file"), "Ctrl+O", true),
+            (t("toolbar.save_file"), "Ctrl+S", true),
+            (t("toolbar.workflows"), "", true),
+            (t("toolbar.api_server"), "", true),
+            (t("toolbar.log_viewer"), "", true),
+            (t("toolbar.data_search"), "Ctrl+Shift+G", true),
+            (t("toolbar.pool_stats"), "", true),
+            (t("toolbar.trash"), "", true),
+            (t("toolbar.master_password"), "", true),
+            (t("toolbar.benchmark"), "", true),
+            (t("toolbar.profile"), "", true),
+            (t("toolbar.row_bookmarks"), "", true),
         ];
         
         egui::Area::new(popup_id)
@@ -97,6 +121,28 @@ pub fn show_actions_dropdown(ui: &mut egui::Ui, has_result: bool, actions: &mut
                                     1 => actions.import = true,
                                     2 => actions.toggle_er_diagram = true,
                                     3 => actions.show_history = true,
+                                    4 => actions.manage_privileges = true,
+                                    5 => actions.show_size_overview = true,
+                                    6 => actions.show_session_monitor = true,
+                                    7 => actions.duplicate_table = true,
+                                    8 => actions.truncate_table = true,
+                                    9 => actions.generate_test_data = true,
+                                    10 => actions.show_scheduler = true,
+                                    11 => actions.pin_snapshot = true,
+                                    12 => actions.compare_snapshot = true,
+                                    13 => actions.show_audit_log = true,
+                                    14 => actions.open_file = true,
+                                    15 => actions.save_file = true,
+                                    16 => actions.show_workflows = true,
+                                    17 => actions.show_api_server = true,
+                                    18 => actions.show_log_viewer = true,
+                                    19 => actions.show_data_search = true,
+                                    20 => actions.show_pool_stats = true,
+                                    21 => actions.show_trash = true,
+                                    22 => actions.show_master_password = true,
+                                    23 => actions.show_benchmark = true,
+                                    24 => actions.show_profile = true,
+                                    25 => actions.show_row_bookmarks = true,
                                     _ => {}
                                 }
                                 state.is_open = false;
@@ -113,6 +159,28 @@ pub fn show_actions_dropdown(ui: &mut egui::Ui, has_result: bool, actions: &mut
                                     1 => actions.import = true,
                                     2 => actions.toggle_er_diagram = true,
                                     3 => actions.show_history = true,
+                                    4 => actions.manage_privileges = true,
+                                    5 => actions.show_size_overview = true,
+                                    6 => actions.show_session_monitor = true,
+                                    7 => actions.duplicate_table = true,
+                                    8 => actions.truncate_table = true,
+                                    9 => actions.generate_test_data = true,
+                                    10 => actions.show_scheduler = true,
+                                    11 => actions.pin_snapshot = true,
+                                    12 => actions.compare_snapshot = true,
+                                    13 => actions.show_audit_log = true,
+                                    14 => actions.open_file = true,
+                                    15 => actions.save_file = true,
+                                    16 => actions.show_workflows = true,
+                                    17 => actions.show_api_server = true,
+                                    18 => actions.show_log_viewer = true,
+                                    19 => actions.show_data_search = true,
+                                    20 => actions.show_pool_stats = true,
+                                    21 => actions.show_trash = true,
+                                    22 => actions.show_master_password = true,
+                                    23 => actions.show_benchmark = true,
+                                    24 => actions.show_profile = true,
+                                    25 => actions.show_row_bookmarks = true,
                                     _ => {}
                                 }
                             }
@@ -146,7 +214,7 @@ pub fn show_create_dropdown(ui: &mut egui::Ui, actions: &mut ToolbarActions) {
         egui::Button::new(RichText::new("+").size(15.0).color(Color32::LIGHT_GRAY))
             .frame(false)
             .min_size(Vec2::new(24.0, 24.0)),
-    ).on_hover_text("新建菜单");
+    ).on_hover_text(t("toolbar.create_menu"));
     
     if response.clicked() {
         state.is_open = !state.is_open;
@@ -155,9 +223,9 @@ pub fn show_create_dropdown(ui: &mut egui::Ui, actions: &mut ToolbarActions) {
     
     if state.is_open {
         let menu_items = [
-            ("新建表", "Ctrl+Shift+N"),
-            ("新建库", "Ctrl+Shift+D"),
-            ("新建用户", "Ctrl+Shift+U"),
+            (t("toolbar.new_table"), "Ctrl+Shift+N"),
+            (t("toolbar.new_database"), "Ctrl+Shift+D"),
+            (t("toolbar.new_user"), "Ctrl+Shift+U"),
         ];
         
         egui::Area::new(popup_id)
@@ -7,14 +7,14 @@ mod utils;
 
 pub use actions::{ToolbarActions, ToolbarFocusTransfer};
 
-use crate::core::{ProgressManager, ThemeManager};
+use crate::core::{language, t, tf, Language, ProgressManager, ThemeManager};
 use crate::ui::styles::{MARGIN_MD, MARGIN_SM};
-use egui::{Color32, Vec2};
+use egui::{Color32, RichText, Vec2};
 
 use super::ProgressIndicator;
 use dropdowns::{show_actions_dropdown, show_create_dropdown};
 use theme_combo::{helix_theme_combo_simple, DARK_THEMES, LIGHT_THEMES};
-use utils::{separator, icon_button, icon_button_with_focus, text_button};
+use utils::{separator, badge_icon_button, icon_button, icon_button_with_focus, text_button};
 
 pub struct Toolbar;
 
@@ -38,12 +38,13 @@ impl Toolbar {
         selected_table: Option<&str>,
         ui_scale: f32,
         progress: &ProgressManager,
+        unseen_errors: usize,
     ) -> Option<u64> {
         Self::show_with_focus(
             ui, theme_manager, has_result, show_sidebar, show_editor,
             is_dark_mode, actions, connections, active_connection,
             databases, selected_database, tables, selected_table,
-            ui_scale, progress, false, 0,
+            ui_scale, progress, unseen_errors, false, 0,
         )
     }
 
@@ -65,6 +66,7 @@ impl Toolbar {
         selected_table: Option<&str>,
         ui_scale: f32,
         progress: &ProgressManager,
+        unseen_errors: usize,
         is_focused: bool,
         selected_index: usize,
     ) -> Option<u64> {
@@ -128,7 +130,7 @@ impl Toolbar {
                             actions.show_about = true;
                         }
                         
-                        response.on_hover_text("关于我们");
+                        response.on_hover_text(t("toolbar.about"));
                         
                         ui.add_space(8.0);
                         separator(ui);
@@ -151,7 +153,7 @@ impl Toolbar {
                         if let Some(new_idx) = helix_theme_combo_simple(
                             ui,
                             "theme_selector",
-                            theme_manager.current,
+                            theme_manager.display_name(),
                             current_theme_idx,
                             themes,
                             200.0,
@@ -162,14 +164,47 @@ impl Toolbar {
                             }
                         actions.open_theme_selector = false;
 
+                        if icon_button(ui, "🎨", t("toolbar.edit_theme"), true) {
+                            actions.show_theme_editor = true;
+                        }
+                        if icon_button(ui, "🔤", t("toolbar.font_settings"), true) {
+                            actions.show_font_settings = true;
+                        }
+                        if icon_button(ui, "⚙", t("toolbar.preferences"), true) {
+                            actions.show_preferences = true;
+                        }
+                        // 通知中心，有未查看的错误时显示数量角标
+                        let tooltip = if unseen_errors > 0 {
+                            tf("toolbar.unseen_errors", &[&unseen_errors.to_string()])
+                        } else {
+                            t("toolbar.notifications").to_string()
+                        };
+                        if badge_icon_button(ui, "🔔", &tooltip, unseen_errors) {
+                            actions.show_notifications = true;
+                        }
+                        ui.menu_button(RichText::new("🌐").size(15.0).color(Color32::LIGHT_GRAY), |ui| {
+                            for lang in Language::ALL {
+                                if ui
+                                    .selectable_label(language() == lang, lang.native_name())
+                                    .on_hover_text(lang.code())
+                                    .clicked()
+                                {
+                                    actions.language_changed = Some(lang);
+                                    ui.close();
+                                }
+                            }
+                        })
+                        .response
+                        .on_hover_text(t("toolbar.language"));
+
                         ui.add_space(4.0);
 
                         // 日/夜模式切换按钮
                         let mode_icon = if is_dark_mode { "🌙" } else { "☀" };
                         let mode_tooltip = if is_dark_mode {
-                            "切换到日间模式 (Ctrl+D)"
+                            t("toolbar.to_light")
                         } else {
-                            "切换到夜间模式 (Ctrl+D)"
+                            t("toolbar.to_dark")
                         };
 
                         if icon_button(ui, mode_icon, mode_tooltip, true) {
@@ -204,13 +239,13 @@ impl Toolbar {
     ) {
         // 侧边栏切换 (索引 0)
         let sidebar_icon = if show_sidebar { "◀" } else { "▶" };
-        if icon_button_with_focus(ui, sidebar_icon, "侧边栏 (Ctrl+B)", true, is_focused && selected_index == 0) {
+        if icon_button_with_focus(ui, sidebar_icon, t("toolbar.sidebar"), true, is_focused && selected_index == 0) {
             actions.toggle_sidebar = true;
         }
 
         // 编辑器切换 (索引 1)
         let editor_icon = if show_editor { "▼" } else { "▲" };
-        if icon_button_with_focus(ui, editor_icon, "编辑器 (Ctrl+J)", true, is_focused && selected_index == 1) {
+        if icon_button_with_focus(ui, editor_icon, t("toolbar.editor"), true, is_focused && selected_index == 1) {
             actions.toggle_editor = true;
         }
     }
@@ -218,18 +253,18 @@ impl Toolbar {
     /// 显示缩放控制
     fn show_zoom_controls(ui: &mut egui::Ui, ui_scale: f32, actions: &mut ToolbarActions) {
         // 缩小按钮
-        if icon_button(ui, "−", "缩小 (Ctrl+-)", true) {
+        if icon_button(ui, "−", t("toolbar.zoom_out"), true) {
             actions.zoom_out = true;
         }
 
         // 缩放比例显示（可点击重置）
         let scale_text = format!("{}%", (ui_scale * 100.0).round() as i32);
-        if text_button(ui, &scale_text, "重置缩放 (Ctrl+0)", true) {
+        if text_button(ui, &scale_text, t("toolbar.zoom_reset"), true) {
             actions.zoom_reset = true;
         }
 
         // 放大按钮
-        if icon_button(ui, "+", "放大 (Ctrl++)", true) {
+        if icon_button(ui, "+", t("toolbar.zoom_in"), true) {
             actions.zoom_in = true;
         }
     }
@@ -243,7 +278,7 @@ impl Toolbar {
         selected_index: usize,
     ) {
         // 刷新 (索引 2)
-        if icon_button_with_focus(ui, "🔄", "刷新 (F5)", true, is_focused && selected_index == 2) {
+        if icon_button_with_focus(ui, "🔄", t("toolbar.refresh"), true, is_focused && selected_index == 2) {
             actions.refresh_tables = true;
         }
 
@@ -264,12 +299,12 @@ impl Toolbar {
         ui.add_space(4.0);
 
         // 快捷键设置 (索引 5)
-        if icon_button_with_focus(ui, "⌨", "快捷键设置", true, is_focused && selected_index == 5) {
+        if icon_button_with_focus(ui, "⌨", t("toolbar.keybindings"), true, is_focused && selected_index == 5) {
             actions.show_keybindings = true;
         }
 
         // 帮助 (索引 6)
-        if icon_button_with_focus(ui, "?", "帮助 (F1)", true, is_focused && selected_index == 6) {
+        if icon_button_with_focus(ui, "?", t("toolbar.help"), true, is_focused && selected_index == 6) {
             actions.show_help = true;
         }
     }
@@ -289,17 +324,17 @@ impl Toolbar {
         
         ui.input(|i| {
             // h/左箭头: 向左移动
-            if i.key_pressed(egui::Key::H) || i.key_pressed(egui::Key::ArrowLeft) {
-                if *toolbar_index > 0 {
-                    *toolbar_index -= 1;
-                }
+            if (i.key_pressed(egui::Key::H) || i.key_pressed(egui::Key::ArrowLeft))
+                && *toolbar_index > 0
+            {
+                *toolbar_index -= 1;
             }
             
             // l/右箭头: 向右移动
-            if i.key_pressed(egui::Key::L) || i.key_pressed(egui::Key::ArrowRight) {
-                if *toolbar_index < TOOLBAR_ITEMS - 1 {
-                    *toolbar_index += 1;
-                }
+            if (i.key_pressed(egui::Key::L) || i.key_pressed(egui::Key::ArrowRight))
+                && *toolbar_index < TOOLBAR_ITEMS - 1
+            {
+                *toolbar_index += 1;
             }
             
             // j/下箭头: 向下进入Tab栏
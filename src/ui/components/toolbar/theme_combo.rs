@@ -1,4 +1,4 @@
-use crate::core::ThemePreset;
+use crate::core::{t, ThemePreset};
 use crate::ui::styles::MUTED;
 use egui::{Color32, CornerRadius, Id, Key, RichText, Vec2};
 
@@ -36,7 +36,7 @@ pub const LIGHT_THEMES: &[ThemePreset] = &[
 pub fn helix_theme_combo_simple(
     ui: &mut egui::Ui,
     id_source: &str,
-    current_theme: ThemePreset,
+    display_text: &str,
     selected_index: usize,
     themes: &[ThemePreset],
     width: f32,
@@ -62,12 +62,11 @@ pub fn helix_theme_combo_simple(
     }
 
     // 按钮 - 无边框图标样式
-    let display_text = current_theme.display_name();
     let response = ui.add(
         egui::Button::new(RichText::new(display_text).size(13.0).color(Color32::LIGHT_GRAY))
             .frame(false)
             .min_size(Vec2::new(0.0, 24.0))
-    ).on_hover_text("选择主题 (Ctrl+Shift+T)");
+    ).on_hover_text(t("toolbar.select_theme"));
 
     if response.clicked() {
         state.is_open = !state.is_open;
@@ -178,7 +177,7 @@ pub fn helix_theme_combo_simple(
                         ui.horizontal(|ui| {
                             ui.add_space(8.0);
                             ui.label(
-                                RichText::new("j/k 选择  Enter 确认  Esc 取消")
+                                RichText::new(t("toolbar.theme_hint"))
                                     .small()
                                     .color(MUTED),
                             );
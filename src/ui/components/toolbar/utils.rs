@@ -1,3 +1,4 @@
+use crate::core::t;
 use crate::ui::styles::MUTED;
 use egui::{Color32, RichText, Vec2};
 
@@ -39,6 +40,31 @@ pub fn icon_button_with_focus(
         .clicked()
 }
 
+/// 带数量角标的图标按钮，`count` 为 0 时不显示角标
+pub fn badge_icon_button(ui: &mut egui::Ui, icon: &str, tooltip: &str, count: usize) -> bool {
+    let button = egui::Button::new(RichText::new(icon).size(15.0).color(Color32::LIGHT_GRAY))
+        .min_size(Vec2::new(24.0, 24.0))
+        .frame(false);
+    let response = ui.add(button).on_hover_text(tooltip);
+
+    if count > 0 {
+        let text = if count > 99 { "99+".to_string() } else { count.to_string() };
+        let center = response.rect.right_top() + Vec2::new(-4.0, 5.0);
+        let radius = if text.len() > 1 { 7.0 } else { 6.0 };
+        let painter = ui.painter();
+        painter.circle_filled(center, radius, Color32::from_rgb(220, 60, 60));
+        painter.text(
+            center,
+            egui::Align2::CENTER_CENTER,
+            text,
+            egui::FontId::proportional(9.0),
+            Color32::WHITE,
+        );
+    }
+
+    response.clicked()
+}
+
 /// 纯文字按钮（无边框）
 pub fn text_button(ui: &mut egui::Ui, text: &str, tooltip: &str, enabled: bool) -> bool {
     let color = if enabled { Color32::LIGHT_GRAY } else { Color32::from_gray(60) };
@@ -151,7 +177,7 @@ pub fn render_combo_item(
                 // 浅色主题标识
                 if is_light_theme {
                     ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                        ui.label(RichText::new("日").small().color(Color32::from_rgb(255, 200, 100)));
+                        ui.label(RichText::new(t("common.day_short")).small().color(Color32::from_rgb(255, 200, 100)));
                     });
                 }
             });
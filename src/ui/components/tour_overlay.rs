@@ -0,0 +1,159 @@
+//! 新手引导覆盖层
+//!
+//! 变暗整个窗口并高亮当前步骤的区域，在区域旁显示说明卡片。
+//! 引导期间主界面不响应鼠标点击。
+
+use crate::core::{Tour, TOUR_STEPS};
+use crate::ui::styles::GRAY;
+use eframe::egui;
+use egui::{Align2, Color32, Id, Key, Order, Pos2, Rect, RichText, Sense, Stroke, StrokeKind, Vec2};
+
+/// 说明卡片的宽度
+const CALLOUT_WIDTH: f32 = 360.0;
+/// 高亮区域与说明卡片之间的间距
+const CALLOUT_GAP: f32 = 12.0;
+/// 高亮区域的外边距
+const HIGHLIGHT_PADDING: f32 = 4.0;
+
+/// 引导中的操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TourAction {
+    /// 无操作
+    None,
+    /// 下一步（最后一步时为完成）
+    Next,
+    /// 上一步
+    Prev,
+    /// 退出引导
+    Skip,
+}
+
+/// 新手引导覆盖层
+pub struct TourOverlay;
+
+impl TourOverlay {
+    /// 显示当前步骤，`target` 为高亮区域的位置（区域未显示时为 `None`，卡片显示在窗口中央）
+    ///
+    /// Enter/→ 下一步，← 上一步，Esc 退出。
+    pub fn show(ctx: &egui::Context, tour: &Tour, target: Option<Rect>) -> TourAction {
+        let Some(step) = tour.current() else {
+            return TourAction::None;
+        };
+        let mut action = ctx.input(|i| {
+            if i.key_pressed(Key::Escape) {
+                TourAction::Skip
+            } else if i.key_pressed(Key::Enter) || i.key_pressed(Key::ArrowRight) {
+                TourAction::Next
+            } else if i.key_pressed(Key::ArrowLeft) {
+                TourAction::Prev
+            } else {
+                TourAction::None
+            }
+        });
+
+        let screen = ctx.content_rect();
+        let highlight = target.map(|r| r.expand(HIGHLIGHT_PADDING).intersect(screen));
+
+        // 变暗并拦截主界面的点击，高亮区域保持原样
+        egui::Area::new(Id::new("tour_overlay"))
+            .order(Order::Foreground)
+            .fixed_pos(screen.min)
+            .show(ctx, |ui| {
+                ui.allocate_rect(screen, Sense::click());
+                let dim = Color32::from_black_alpha(150);
+                let painter = ui.painter();
+                match highlight {
+                    Some(rect) => {
+                        for part in surrounding_rects(screen, rect) {
+                            painter.rect_filled(part, 0.0, dim);
+                        }
+                        painter.rect_stroke(
+                            rect,
+                            4.0,
+                            Stroke::new(2.0, Color32::from_rgb(130, 180, 255)),
+                            StrokeKind::Outside,
+                        );
+                    }
+                    None => {
+                        painter.rect_filled(screen, 0.0, dim);
+                    }
+                }
+            });
+
+        let (pos, pivot) = callout_position(screen, highlight);
+        egui::Area::new(Id::new("tour_callout"))
+            .order(Order::Tooltip)
+            .fixed_pos(pos)
+            .pivot(pivot)
+            .constrain(true)
+            .show(ctx, |ui| {
+                egui::Frame::popup(ui.style()).inner_margin(12.0).show(ui, |ui| {
+                    ui.set_width(CALLOUT_WIDTH);
+                    ui.label(
+                        RichText::new(format!("{} / {}", tour.step_index() + 1, TOUR_STEPS.len()))
+                            .small()
+                            .color(GRAY),
+                    );
+                    ui.label(RichText::new(step.title).size(16.0).strong());
+                    ui.add_space(4.0);
+                    ui.label(step.body);
+
+                    if !step.keys.is_empty() {
+                        ui.add_space(6.0);
+                        egui::Grid::new("tour_keys").num_columns(2).spacing([12.0, 2.0]).show(ui, |ui| {
+                            for (key, desc) in step.keys {
+                                ui.label(RichText::new(*key).monospace().color(Color32::from_rgb(255, 200, 100)));
+                                ui.label(*desc);
+                                ui.end_row();
+                            }
+                        });
+                    }
+
+                    ui.add_space(8.0);
+                    ui.horizontal(|ui| {
+                        if ui.small_button("跳过引导").clicked() {
+                            action = TourAction::Skip;
+                        }
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            let next_text = if tour.is_last() { "完成" } else { "下一步 →" };
+                            if ui.button(next_text).clicked() {
+                                action = TourAction::Next;
+                            }
+                            if tour.step_index() > 0 && ui.button("← 上一步").clicked() {
+                                action = TourAction::Prev;
+                            }
+                        });
+                    });
+                });
+            });
+
+        action
+    }
+}
+
+/// 高亮区域四周需要变暗的矩形（上、下、左、右）
+fn surrounding_rects(screen: Rect, hole: Rect) -> [Rect; 4] {
+    [
+        Rect::from_min_max(screen.min, Pos2::new(screen.max.x, hole.min.y)),
+        Rect::from_min_max(Pos2::new(screen.min.x, hole.max.y), screen.max),
+        Rect::from_min_max(Pos2::new(screen.min.x, hole.min.y), Pos2::new(hole.min.x, hole.max.y)),
+        Rect::from_min_max(Pos2::new(hole.max.x, hole.min.y), Pos2::new(screen.max.x, hole.max.y)),
+    ]
+}
+
+/// 说明卡片的位置：窄区域（侧边栏）放在右侧，其余放在下方或上方，都放不下时放在区域内部
+fn callout_position(screen: Rect, highlight: Option<Rect>) -> (Pos2, Align2) {
+    let Some(rect) = highlight else {
+        return (screen.center(), Align2::CENTER_CENTER);
+    };
+    let needed = Vec2::new(CALLOUT_WIDTH + CALLOUT_GAP * 2.0, 220.0);
+    if rect.width() < screen.width() * 0.4 && screen.max.x - rect.max.x >= needed.x {
+        (Pos2::new(rect.max.x + CALLOUT_GAP, rect.min.y + CALLOUT_GAP), Align2::LEFT_TOP)
+    } else if screen.max.y - rect.max.y >= needed.y {
+        (Pos2::new(rect.min.x + CALLOUT_GAP, rect.max.y + CALLOUT_GAP), Align2::LEFT_TOP)
+    } else if rect.min.y - screen.min.y >= needed.y {
+        (Pos2::new(rect.min.x + CALLOUT_GAP, rect.min.y - CALLOUT_GAP), Align2::LEFT_BOTTOM)
+    } else {
+        (rect.center(), Align2::CENTER_CENTER)
+    }
+}
@@ -2,6 +2,7 @@
 //! 支持 Helix 风格的键盘导航
 
 use super::keyboard;
+use crate::core::t;
 use egui::{self, Color32, RichText, Vec2};
 
 pub struct AboutDialog;
@@ -24,7 +25,7 @@ impl AboutDialog {
             }
         });
 
-        egui::Window::new("关于")
+        egui::Window::new(t("about.title"))
             .collapsible(false)
             .resizable(false)
             .fixed_size(Vec2::new(420.0, 340.0))
@@ -40,7 +41,7 @@ impl AboutDialog {
 
                     // 主标题
                     ui.label(
-                        RichText::new("不是吧哥们")
+                        RichText::new(t("about.tagline"))
                             .size(24.0)
                             .strong()
                             .color(Color32::from_rgb(255, 193, 7))
@@ -50,7 +51,7 @@ impl AboutDialog {
 
                     // 副标题
                     ui.label(
-                        RichText::new("真当我们 Navicat 了？")
+                        RichText::new(t("about.not_navicat"))
                             .size(18.0)
                             .color(Color32::from_rgb(100, 149, 237))
                     );
@@ -59,7 +60,7 @@ impl AboutDialog {
 
                     // 说明文字
                     ui.label(
-                        RichText::new("我们可是开源项目嘿嘿，不收费哈！")
+                        RichText::new(t("about.open_source"))
                             .size(16.0)
                     );
 
@@ -73,7 +74,7 @@ impl AboutDialog {
                     ui.horizontal(|ui| {
                         ui.label(RichText::new("👤").size(14.0));
                         ui.label(
-                            RichText::new("作者: MCB-SMART-BOY")
+                            RichText::new(t("about.author"))
                                 .size(14.0)
                                 .strong()
                         );
@@ -96,7 +97,7 @@ impl AboutDialog {
                     ui.horizontal(|ui| {
                         ui.label(RichText::new("🌟").size(14.0));
                         ui.label(
-                            RichText::new("欢迎 Star & 贡献代码")
+                            RichText::new(t("about.star"))
                                 .size(14.0)
                                 .color(Color32::GRAY)
                         );
@@ -107,7 +108,7 @@ impl AboutDialog {
 
                     // 快捷键提示
                     ui.label(
-                        RichText::new("[Esc/q/Enter 关闭]")
+                        RichText::new(t("about.close_hint"))
                             .small()
                             .color(Color32::GRAY)
                     );
@@ -115,7 +116,7 @@ impl AboutDialog {
                     ui.add_space(6.0);
 
                     // 关闭按钮
-                    if ui.button(RichText::new("知道啦~ [Enter]").size(14.0)).clicked() {
+                    if ui.button(RichText::new(t("about.ok")).size(14.0)).clicked() {
                         *show = false;
                     }
 
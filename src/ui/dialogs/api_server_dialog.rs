@@ -0,0 +1,329 @@
+//! 本地 API 服务面板
+//!
+//! 编辑通过本地 HTTP 接口共享的只读查询，设置端口和访问令牌，并启动或停止服务。
+
+use super::keyboard;
+use crate::core::{generate_api_token, t, tf, ApiServerConfig, SavedQuery};
+use egui::{self, Color32, RichText, TextEdit};
+
+// ============================================================================
+// 面板结果
+// ============================================================================
+
+/// API 服务面板的结果
+pub enum ApiServerResult {
+    /// 无操作
+    None,
+    /// 保存设置
+    Save(ApiServerConfig),
+    /// 保存设置并启动服务
+    Start(ApiServerConfig),
+    /// 停止服务
+    Stop,
+}
+
+// ============================================================================
+// 面板状态
+// ============================================================================
+
+/// API 服务面板状态
+#[derive(Default)]
+pub struct ApiServerState {
+    /// 是否显示面板
+    pub show: bool,
+    /// 正在编辑的设置（保存前不影响配置）
+    pub config: ApiServerConfig,
+    /// 可选的连接名
+    pub connections: Vec<String>,
+    /// 服务正在监听的端口
+    pub running_port: Option<u16>,
+    /// 当前编辑器中的 SQL（用于新建查询）
+    pub editor_sql: String,
+    /// 当前活动连接（用于新建查询）
+    pub active_connection: Option<String>,
+    /// 有未保存的修改
+    pub dirty: bool,
+    /// 错误信息
+    pub error: Option<String>,
+}
+
+impl ApiServerState {
+    /// 创建新的面板状态
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 打开面板，编辑配置的副本
+    pub fn open(
+        &mut self,
+        config: ApiServerConfig,
+        connections: Vec<String>,
+        editor_sql: String,
+        active_connection: Option<String>,
+    ) {
+        self.show = true;
+        self.config = config;
+        self.connections = connections;
+        self.editor_sql = editor_sql;
+        self.active_connection = active_connection;
+        self.dirty = false;
+        self.error = None;
+    }
+
+    /// 关闭面板
+    pub fn close(&mut self) {
+        self.show = false;
+        self.error = None;
+    }
+
+    /// 用编辑器中的 SQL 和活动连接新建查询
+    pub fn add_query(&mut self) {
+        let name = (1..)
+            .map(|n| format!("query{}", n))
+            .find(|name| self.config.find(name).is_none())
+            .unwrap_or_default();
+        let connection = self
+            .active_connection
+            .clone()
+            .or_else(|| self.connections.first().cloned())
+            .unwrap_or_default();
+        self.config.queries.push(SavedQuery {
+            name,
+            connection,
+            sql: self.editor_sql.trim().to_string(),
+        });
+        self.dirty = true;
+    }
+
+    /// 删除查询
+    pub fn remove_query(&mut self, idx: usize) {
+        if idx < self.config.queries.len() {
+            self.config.queries.remove(idx);
+            self.dirty = true;
+        }
+    }
+
+    /// 查询的访问地址
+    pub fn query_url(&self, query: &SavedQuery) -> String {
+        format!("http://127.0.0.1:{}/queries/{}", self.config.port, query.name)
+    }
+
+    /// 校验并返回设置，或记录错误
+    fn confirm(&mut self) -> Option<ApiServerConfig> {
+        for query in &mut self.config.queries {
+            query.name = query.name.trim().to_string();
+        }
+        match self.config.validate() {
+            Ok(()) => {
+                self.error = None;
+                self.dirty = false;
+                Some(self.config.clone())
+            }
+            Err(e) => {
+                self.error = Some(e);
+                None
+            }
+        }
+    }
+}
+
+// ============================================================================
+// 面板 UI
+// ============================================================================
+
+/// API 服务面板
+pub struct ApiServerDialog;
+
+impl ApiServerDialog {
+    /// 显示面板
+    pub fn show(ctx: &egui::Context, state: &mut ApiServerState) -> ApiServerResult {
+        if !state.show {
+            return ApiServerResult::None;
+        }
+
+        if !keyboard::has_text_focus(ctx) && keyboard::handle_close_keys(ctx) {
+            state.close();
+            return ApiServerResult::None;
+        }
+
+        let mut result = ApiServerResult::None;
+        let mut open = true;
+        egui::Window::new(t("api.title"))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(true)
+            .default_width(640.0)
+            .default_height(460.0)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                Self::show_settings(ui, state);
+                ui.separator();
+                Self::show_queries(ui, state);
+
+                if let Some(err) = &state.error {
+                    ui.add_space(4.0);
+                    ui.label(RichText::new(err).color(Color32::from_rgb(255, 100, 100)));
+                }
+
+                ui.separator();
+                ui.horizontal(|ui| {
+                    let save_text = if state.dirty { t("common.save_icon_changed") } else { t("common.save_icon") };
+                    if ui.button(save_text).clicked()
+                        && let Some(config) = state.confirm()
+                    {
+                        result = ApiServerResult::Save(config);
+                    }
+                    match state.running_port {
+                        Some(port) => {
+                            if ui.button(t("api.stop")).clicked() {
+                                result = ApiServerResult::Stop;
+                            }
+                            ui.label(
+                                RichText::new(tf("api.running", &[&port.to_string()]))
+                                    .color(Color32::from_rgb(100, 200, 120)),
+                            );
+                        }
+                        None => {
+                            if ui
+                                .button(t("api.start"))
+                                .on_hover_text(t("api.start_hint"))
+                                .clicked()
+                                && let Some(config) = state.confirm()
+                            {
+                                result = ApiServerResult::Start(config);
+                            }
+                            ui.label(RichText::new(t("api.stopped")).color(Color32::GRAY));
+                        }
+                    }
+                });
+            });
+
+        if !open {
+            state.close();
+        }
+
+        result
+    }
+
+    /// 端口、令牌和自动启动
+    fn show_settings(ui: &mut egui::Ui, state: &mut ApiServerState) {
+        let mut changed = false;
+        ui.horizontal(|ui| {
+            ui.label(t("api.port"));
+            changed |= ui
+                .add(egui::DragValue::new(&mut state.config.port).range(1024..=65535))
+                .changed();
+            ui.label(t("api.token"));
+            changed |= ui
+                .add(
+                    TextEdit::singleline(&mut state.config.token)
+                        .password(true)
+                        .desired_width(160.0)
+                        .hint_text(t("api.token_hint")),
+                )
+                .on_hover_text(t("api.token_header_hint"))
+                .changed();
+            if ui.small_button("🔄").on_hover_text(t("api.new_token")).clicked() {
+                match generate_api_token() {
+                    Ok(token) => {
+                        state.config.token = token;
+                        changed = true;
+                    }
+                    Err(e) => state.error = Some(e),
+                }
+            }
+            if ui
+                .add_enabled(!state.config.token.is_empty(), egui::Button::new("📋").small())
+                .on_hover_text(t("api.copy_token"))
+                .clicked()
+            {
+                ui.ctx().copy_text(state.config.token.clone());
+            }
+            changed |= ui.checkbox(&mut state.config.auto_start, t("api.auto_start")).changed();
+        });
+        ui.label(
+            RichText::new(t("api.scope_hint"))
+                .small()
+                .color(Color32::GRAY),
+        );
+        if changed {
+            state.dirty = true;
+        }
+    }
+
+    /// 共享的查询列表
+    fn show_queries(ui: &mut egui::Ui, state: &mut ApiServerState) {
+        ui.horizontal(|ui| {
+            ui.label(RichText::new(t("api.shared_queries")).strong());
+            if ui
+                .small_button("➕")
+                .on_hover_text(t("api.add_query_hint"))
+                .clicked()
+            {
+                state.add_query();
+            }
+        });
+
+        let connections = state.connections.clone();
+        let mut changed = false;
+        let mut remove = None;
+        let mut copy_url = None;
+        egui::ScrollArea::vertical()
+            .id_salt("api_queries")
+            .max_height(300.0)
+            .show(ui, |ui| {
+                if state.config.queries.is_empty() {
+                    ui.label(RichText::new(t("api.no_queries")).color(Color32::GRAY));
+                }
+                for (i, query) in state.config.queries.iter_mut().enumerate() {
+                    egui::Frame::group(ui.style()).show(ui, |ui| {
+                        ui.horizontal(|ui| {
+                            ui.label(t("common.name"));
+                            changed |= ui
+                                .add(TextEdit::singleline(&mut query.name).desired_width(140.0))
+                                .changed();
+                            ui.label(t("common.connection"));
+                            egui::ComboBox::from_id_salt(("api_query_connection", i))
+                                .selected_text(if query.connection.is_empty() {
+                                    t("common.select_connection")
+                                } else {
+                                    query.connection.as_str()
+                                })
+                                .show_ui(ui, |ui| {
+                                    for name in &connections {
+                                        changed |= ui.selectable_value(&mut query.connection, name.clone(), name).changed();
+                                    }
+                                });
+                            if ui.small_button("📋").on_hover_text(t("api.copy_url")).clicked() {
+                                copy_url = Some(i);
+                            }
+                            if ui.small_button("🗑").on_hover_text(t("common.delete")).clicked() {
+                                remove = Some(i);
+                            }
+                        });
+                        changed |= ui
+                            .add(
+                                TextEdit::multiline(&mut query.sql)
+                                    .code_editor()
+                                    .desired_rows(2)
+                                    .desired_width(f32::INFINITY)
+                                    .hint_text("SELECT ..."),
+                            )
+                            .changed();
+                    });
+                }
+            });
+
+        if changed {
+            state.dirty = true;
+        }
+        if let Some(i) = copy_url
+            && let Some(query) = state.config.queries.get(i)
+        {
+            ui.ctx().copy_text(state.query_url(query));
+        }
+        if let Some(i) = remove {
+            state.remove_query(i);
+        }
+    }
+}
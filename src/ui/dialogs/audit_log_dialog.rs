@@ -0,0 +1,247 @@
+//! 审计日志窗口
+//!
+//! 浏览审计日志中记录的写操作，按连接、来源、状态和语句内容筛选，
+//! 并可将筛选结果导出为 CSV。
+
+use super::keyboard;
+use crate::core::{audit_report, AuditEntry, AuditSource};
+use crate::database::QueryResult;
+use egui::{self, Color32, RichText};
+
+// ============================================================================
+// 窗口结果
+// ============================================================================
+
+/// 审计日志窗口的结果
+pub enum AuditLogResult {
+    /// 无操作
+    None,
+    /// 重新读取日志
+    Refresh,
+    /// 导出筛选后的记录
+    Export(QueryResult),
+}
+
+// ============================================================================
+// 窗口状态
+// ============================================================================
+
+/// 审计日志窗口状态
+#[derive(Default)]
+pub struct AuditLogState {
+    /// 是否显示窗口
+    pub show: bool,
+    /// 日志文件路径
+    pub path: String,
+    /// 所有记录（最新的在前）
+    pub entries: Vec<AuditEntry>,
+    /// 搜索文本（匹配语句和错误信息）
+    pub search: String,
+    /// 只显示该连接的记录
+    pub connection: Option<String>,
+    /// 只显示该来源的记录
+    pub source: Option<AuditSource>,
+    /// 只显示失败的记录
+    pub failures_only: bool,
+}
+
+impl AuditLogState {
+    /// 创建新的窗口状态
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 打开窗口；`entries` 按写入顺序排列
+    pub fn open(&mut self, mut entries: Vec<AuditEntry>, path: String) {
+        entries.reverse();
+        self.entries = entries;
+        self.path = path;
+        if self.connection.as_ref().is_some_and(|c| !self.entries.iter().any(|e| &e.connection == c)) {
+            self.connection = None;
+        }
+        self.show = true;
+    }
+
+    /// 关闭窗口
+    pub fn close(&mut self) {
+        self.show = false;
+        self.entries.clear();
+    }
+
+    /// 日志中出现过的连接名（按名称排序）
+    pub fn connections(&self) -> Vec<String> {
+        let mut connections: Vec<String> = self.entries.iter().map(|e| e.connection.clone()).collect();
+        connections.sort();
+        connections.dedup();
+        connections
+    }
+
+    /// 符合筛选条件的记录
+    pub fn filtered(&self) -> Vec<&AuditEntry> {
+        let search = self.search.trim().to_lowercase();
+        self.entries
+            .iter()
+            .filter(|e| self.connection.as_ref().is_none_or(|c| &e.connection == c))
+            .filter(|e| self.source.is_none_or(|s| e.source == s))
+            .filter(|e| !self.failures_only || !e.success)
+            .filter(|e| {
+                search.is_empty()
+                    || e.sql.to_lowercase().contains(&search)
+                    || e.error.as_ref().is_some_and(|err| err.to_lowercase().contains(&search))
+            })
+            .collect()
+    }
+}
+
+// ============================================================================
+// 窗口 UI
+// ============================================================================
+
+/// 审计日志窗口
+pub struct AuditLogDialog;
+
+impl AuditLogDialog {
+    /// 显示窗口
+    pub fn show(ctx: &egui::Context, state: &mut AuditLogState) -> AuditLogResult {
+        if !state.show {
+            return AuditLogResult::None;
+        }
+
+        if !keyboard::has_text_focus(ctx) && keyboard::handle_close_keys(ctx) {
+            state.close();
+            return AuditLogResult::None;
+        }
+
+        let mut result = AuditLogResult::None;
+
+        let mut open = true;
+        egui::Window::new("📜 审计日志")
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(true)
+            .default_size([820.0, 480.0])
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(RichText::new(&state.path).small().color(Color32::GRAY))
+                        .on_hover_text("日志只追加不修改，每行一条 JSON 记录");
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui.button("🔄 刷新").clicked() {
+                            result = AuditLogResult::Refresh;
+                        }
+                    });
+                });
+
+                // 筛选
+                ui.horizontal(|ui| {
+                    ui.add(egui::TextEdit::singleline(&mut state.search).hint_text("🔍 搜索语句").desired_width(200.0));
+
+                    let connection_text = state.connection.clone().unwrap_or_else(|| "全部连接".to_string());
+                    egui::ComboBox::from_id_salt("audit_connection_filter")
+                        .selected_text(connection_text)
+                        .width(130.0)
+                        .show_ui(ui, |ui| {
+                            ui.selectable_value(&mut state.connection, None, "全部连接");
+                            for connection in state.connections() {
+                                let text = connection.clone();
+                                ui.selectable_value(&mut state.connection, Some(connection), text);
+                            }
+                        });
+
+                    egui::ComboBox::from_id_salt("audit_source_filter")
+                        .selected_text(state.source.map_or("全部来源", |s| s.label()))
+                        .width(100.0)
+                        .show_ui(ui, |ui| {
+                            ui.selectable_value(&mut state.source, None, "全部来源");
+                            for source in AuditSource::ALL {
+                                ui.selectable_value(&mut state.source, Some(source), source.label());
+                            }
+                        });
+
+                    ui.checkbox(&mut state.failures_only, "仅失败");
+                });
+
+                let entries = state.filtered();
+                ui.horizontal(|ui| {
+                    ui.label(format!("{} / {} 条记录", entries.len(), state.entries.len()));
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui.add_enabled(!entries.is_empty(), egui::Button::new("💾 导出 CSV...")).clicked() {
+                            result = AuditLogResult::Export(audit_report(&entries));
+                        }
+                    });
+                });
+
+                ui.separator();
+
+                if entries.is_empty() {
+                    ui.vertical_centered(|ui| {
+                        ui.add_space(20.0);
+                        ui.label(RichText::new("没有审计记录").italics().color(Color32::GRAY));
+                    });
+                    return;
+                }
+
+                Self::show_entries(ui, &entries);
+            });
+
+        if !open {
+            state.close();
+        }
+
+        result
+    }
+
+    /// 记录表格
+    fn show_entries(ui: &mut egui::Ui, entries: &[&AuditEntry]) {
+        use egui_extras::{Column, TableBuilder};
+
+        TableBuilder::new(ui)
+            .striped(true)
+            .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+            .column(Column::exact(140.0))
+            .column(Column::auto().at_least(80.0).clip(true))
+            .column(Column::auto().at_least(70.0))
+            .column(Column::exact(40.0))
+            .column(Column::auto().at_least(50.0))
+            .column(Column::remainder().clip(true))
+            .header(20.0, |mut header| {
+                for title in ["时间", "连接", "来源", "状态", "影响行数", "SQL"] {
+                    header.col(|ui| {
+                        ui.label(RichText::new(title).strong().small());
+                    });
+                }
+            })
+            .body(|body| {
+                body.rows(18.0, entries.len(), |mut row| {
+                    let entry = entries[row.index()];
+                    row.col(|ui| {
+                        ui.label(RichText::new(entry.timestamp.format("%Y-%m-%d %H:%M:%S").to_string()).small());
+                    });
+                    row.col(|ui| {
+                        ui.label(RichText::new(&entry.connection).small())
+                            .on_hover_text(&entry.database_type);
+                    });
+                    row.col(|ui| {
+                        ui.label(RichText::new(entry.source.label()).small());
+                    });
+                    row.col(|ui| {
+                        if entry.success {
+                            ui.label(RichText::new("成功").small().color(Color32::from_rgb(100, 200, 120)));
+                        } else {
+                            ui.label(RichText::new("失败").small().color(Color32::from_rgb(230, 100, 100)))
+                                .on_hover_text(entry.error.as_deref().unwrap_or_default());
+                        }
+                    });
+                    row.col(|ui| {
+                        if let Some(rows) = entry.affected_rows {
+                            ui.label(RichText::new(rows.to_string()).small());
+                        }
+                    });
+                    row.col(|ui| {
+                        let first_line = entry.sql.lines().next().unwrap_or_default();
+                        ui.label(RichText::new(first_line).monospace().small()).on_hover_text(&entry.sql);
+                    });
+                });
+            });
+    }
+}
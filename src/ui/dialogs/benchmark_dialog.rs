@@ -0,0 +1,282 @@
+//! 查询基准测试窗口
+//!
+//! 设置执行次数和预热次数后重复执行编辑器中的查询（由调用方在后台执行），
+//! 显示最小、平均、P95 耗时和每秒行数，并用柱状图画出每次的耗时。
+
+use super::keyboard;
+use crate::core::constants::database::{BENCHMARK_RUNS_RANGE, BENCHMARK_WARMUP_RANGE};
+use crate::core::{t, tf, BenchmarkSample, BenchmarkStats};
+use egui::{self, Color32, RichText};
+
+/// 耗时图的高度
+const CHART_HEIGHT: f32 = 140.0;
+
+/// 基准测试窗口的结果
+pub enum BenchmarkResult {
+    /// 无操作
+    None,
+    /// 开始执行
+    Start,
+    /// 停止执行（点击停止或关闭窗口）
+    Stop,
+}
+
+/// 基准测试窗口状态
+pub struct BenchmarkState {
+    /// 是否显示窗口
+    pub show: bool,
+    /// 要执行的 SQL
+    pub sql: String,
+    /// 计时的执行次数
+    pub runs: usize,
+    /// 计时前的预热次数
+    pub warmup: usize,
+    /// 是否正在执行
+    pub running: bool,
+    /// 当前执行的编号（用于丢弃已停止执行的迟到结果）
+    pub run_id: u64,
+    /// 已完成的预热次数
+    pub warmup_done: usize,
+    /// 每次计时执行的结果
+    pub samples: Vec<BenchmarkSample>,
+    /// 执行出错时的错误信息
+    pub error: Option<String>,
+}
+
+impl Default for BenchmarkState {
+    fn default() -> Self {
+        Self {
+            show: false,
+            sql: String::new(),
+            runs: 10,
+            warmup: 1,
+            running: false,
+            run_id: 0,
+            warmup_done: 0,
+            samples: Vec::new(),
+            error: None,
+        }
+    }
+}
+
+impl BenchmarkState {
+    /// 创建新的窗口状态
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 打开窗口，SQL 变化时清空上次的结果
+    pub fn open(&mut self, sql: String) {
+        self.show = true;
+        if self.sql != sql {
+            self.sql = sql;
+            self.samples.clear();
+            self.warmup_done = 0;
+            self.error = None;
+        }
+    }
+
+    /// 关闭窗口（正在进行的执行由调用方停止）
+    pub fn close(&mut self) {
+        self.show = false;
+        self.running = false;
+    }
+
+    /// 开始新的执行，返回执行编号
+    pub fn start(&mut self) -> u64 {
+        self.run_id += 1;
+        self.running = true;
+        self.warmup_done = 0;
+        self.samples.clear();
+        self.error = None;
+        self.run_id
+    }
+
+    /// 记录一次执行的结果，忽略已停止执行的结果
+    pub fn add_sample(&mut self, run_id: u64, warmup: bool, sample: Result<BenchmarkSample, String>) {
+        if run_id != self.run_id {
+            return;
+        }
+        match sample {
+            Ok(_) if warmup => self.warmup_done += 1,
+            Ok(sample) => self.samples.push(sample),
+            Err(e) => {
+                self.error = Some(e);
+                self.running = false;
+            }
+        }
+    }
+
+    /// 执行结束（全部完成、出错或已停止）
+    pub fn finish(&mut self, run_id: u64) {
+        if run_id == self.run_id {
+            self.running = false;
+        }
+    }
+}
+
+/// 查询基准测试窗口
+pub struct BenchmarkDialog;
+
+impl BenchmarkDialog {
+    /// 显示窗口
+    pub fn show(ctx: &egui::Context, state: &mut BenchmarkState) -> BenchmarkResult {
+        if !state.show {
+            return BenchmarkResult::None;
+        }
+
+        let mut result = BenchmarkResult::None;
+
+        if !keyboard::has_text_focus(ctx) && keyboard::handle_close_keys(ctx) {
+            let running = state.running;
+            state.close();
+            return if running { BenchmarkResult::Stop } else { BenchmarkResult::None };
+        }
+
+        let mut open = true;
+        egui::Window::new(t("benchmark.title"))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(true)
+            .default_size([560.0, 420.0])
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                egui::ScrollArea::vertical()
+                    .id_salt("benchmark_sql_scroll")
+                    .max_height(80.0)
+                    .show(ui, |ui| {
+                        ui.label(RichText::new(&state.sql).monospace().color(Color32::GRAY));
+                    });
+                ui.separator();
+
+                ui.horizontal(|ui| {
+                    ui.add_enabled_ui(!state.running, |ui| {
+                        ui.label(t("benchmark.runs"));
+                        ui.add(egui::DragValue::new(&mut state.runs).range(BENCHMARK_RUNS_RANGE));
+                        ui.label(t("benchmark.warmup"));
+                        ui.add(egui::DragValue::new(&mut state.warmup).range(BENCHMARK_WARMUP_RANGE))
+                            .on_hover_text(t("benchmark.warmup_hint"));
+                    });
+
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if state.running {
+                            if ui.button(t("benchmark.stop")).clicked() {
+                                result = BenchmarkResult::Stop;
+                            }
+                            ui.spinner();
+                            ui.label(if state.warmup_done < state.warmup {
+                                tf("benchmark.warming_up", &[&state.warmup_done.to_string(), &state.warmup.to_string()])
+                            } else {
+                                format!("{}/{}", state.samples.len(), state.runs)
+                            });
+                        } else if ui.button(t("benchmark.start")).clicked() {
+                            result = BenchmarkResult::Start;
+                        }
+                    });
+                });
+
+                if let Some(error) = &state.error {
+                    ui.label(RichText::new(tf("benchmark.error", &[error])).color(Color32::from_rgb(255, 100, 100)));
+                }
+
+                if let Some(stats) = BenchmarkStats::from_samples(&state.samples) {
+                    ui.separator();
+                    Self::show_stats(ui, &stats);
+                    ui.add_space(8.0);
+                    Self::show_chart(ui, &state.samples, &stats);
+                } else if !state.running && state.error.is_none() {
+                    ui.add_space(8.0);
+                    ui.label(
+                        RichText::new(t("benchmark.intro"))
+                            .color(Color32::GRAY),
+                    );
+                }
+            });
+
+        if !open {
+            let running = state.running;
+            state.close();
+            if running {
+                result = BenchmarkResult::Stop;
+            }
+        }
+
+        result
+    }
+
+    /// 统计数据
+    fn show_stats(ui: &mut egui::Ui, stats: &BenchmarkStats) {
+        egui::Grid::new("benchmark_stats").num_columns(6).spacing([16.0, 4.0]).show(ui, |ui| {
+            for label in [t("benchmark.count"), t("benchmark.min"), t("benchmark.avg"), "P95", t("benchmark.max"), t("benchmark.rows_per_sec")] {
+                ui.label(RichText::new(label).color(Color32::GRAY));
+            }
+            ui.end_row();
+            ui.label(stats.runs.to_string());
+            for ms in [stats.min_ms, stats.avg_ms, stats.p95_ms, stats.max_ms] {
+                ui.label(RichText::new(format_ms(ms)).strong());
+            }
+            ui.label(stats.rows_per_sec.map_or_else(|| "-".to_string(), |r| format!("{:.0}", r)));
+            ui.end_row();
+        });
+    }
+
+    /// 每次执行耗时的柱状图（虚线为平均值和 P95）
+    fn show_chart(ui: &mut egui::Ui, samples: &[BenchmarkSample], stats: &BenchmarkStats) {
+        let width = ui.available_width();
+        let (rect, response) = ui.allocate_exact_size(egui::vec2(width, CHART_HEIGHT), egui::Sense::hover());
+        let painter = ui.painter_at(rect);
+        let visuals = ui.visuals();
+        painter.rect_filled(rect, 4.0, visuals.extreme_bg_color);
+
+        let max = stats.max_ms.max(f64::EPSILON);
+        let y_of = |ms: f64| rect.bottom() - (ms / max) as f32 * (rect.height() - 12.0);
+        let slot = rect.width() / samples.len() as f32;
+        let bar_width = (slot * 0.8).max(1.0);
+        let hovered = response
+            .hover_pos()
+            .map(|pos| (((pos.x - rect.left()) / slot) as usize).min(samples.len() - 1));
+
+        for (i, sample) in samples.iter().enumerate() {
+            let x = rect.left() + slot * i as f32 + (slot - bar_width) / 2.0;
+            let bar = egui::Rect::from_min_max(egui::pos2(x, y_of(sample.elapsed_ms)), egui::pos2(x + bar_width, rect.bottom()));
+            let color = if hovered == Some(i) { visuals.selection.bg_fill } else { visuals.widgets.inactive.bg_fill };
+            painter.rect_filled(bar, 1.0, color);
+        }
+
+        let line = |ms: f64, color: Color32, label: &str| {
+            let y = y_of(ms);
+            painter.extend(egui::Shape::dashed_line(
+                &[egui::pos2(rect.left(), y), egui::pos2(rect.right(), y)],
+                egui::Stroke::new(1.0, color),
+                6.0,
+                4.0,
+            ));
+            painter.text(
+                egui::pos2(rect.right() - 4.0, y - 2.0),
+                egui::Align2::RIGHT_BOTTOM,
+                format!("{} {}", label, format_ms(ms)),
+                egui::FontId::proportional(11.0),
+                color,
+            );
+        };
+        line(stats.avg_ms, Color32::from_rgb(100, 180, 255), t("benchmark.avg"));
+        line(stats.p95_ms, Color32::from_rgb(255, 170, 60), "P95");
+
+        if let Some(i) = hovered {
+            let sample = &samples[i];
+            response.on_hover_text(tf(
+                "benchmark.sample",
+                &[&(i + 1).to_string(), &format_ms(sample.elapsed_ms), &sample.rows.to_string()],
+            ));
+        }
+    }
+}
+
+/// 格式化毫秒耗时
+fn format_ms(ms: f64) -> String {
+    if ms >= 1000.0 {
+        format!("{:.2} s", ms / 1000.0)
+    } else {
+        format!("{:.1} ms", ms)
+    }
+}
@@ -0,0 +1,240 @@
+//! 批量修改窗口
+//!
+//! 把当前筛选条件匹配的行的某一列设置为新值或表达式：生成 `UPDATE ... SET ... WHERE <筛选条件>`，
+//! 先在影响行预览窗口中确认将被修改的行，再执行。
+
+use super::keyboard;
+use crate::core::t;
+use crate::database::DatabaseType;
+use crate::ui::components::{quote_identifier, string_literal};
+use egui::{self, Color32, RichText, TextEdit};
+
+// ============================================================================
+// 窗口结果
+// ============================================================================
+
+/// 批量修改窗口的结果
+pub enum BulkEditResult {
+    /// 无操作
+    None,
+    /// 预览生成的 UPDATE 语句
+    Preview(String),
+}
+
+/// 新值的类型
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BulkValueKind {
+    /// 文本值（按字符串字面量转义，输入 `NULL` 时也是文本）
+    #[default]
+    Literal,
+    /// SQL 表达式（原样使用，如 `price * 1.1`）
+    Expression,
+}
+
+// ============================================================================
+// 窗口状态
+// ============================================================================
+
+/// 批量修改窗口状态
+#[derive(Default)]
+pub struct BulkEditState {
+    /// 是否显示窗口
+    pub show: bool,
+    /// 已加引号的表名
+    pub quoted_table: String,
+    /// 可选的列
+    pub columns: Vec<String>,
+    /// 要修改的列
+    pub column: String,
+    /// 新值或表达式
+    pub value: String,
+    /// 新值的类型
+    pub value_kind: BulkValueKind,
+    /// 设为 NULL（忽略输入的值）
+    pub set_null: bool,
+    /// 筛选条件生成的 WHERE 子句（不含 `WHERE`）
+    pub where_clause: String,
+    /// 数据库类型
+    pub db_type: DatabaseType,
+}
+
+impl BulkEditState {
+    /// 创建新的窗口状态
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 打开窗口，默认选中第一列
+    pub fn open(&mut self, quoted_table: String, columns: Vec<String>, where_clause: String, db_type: DatabaseType) {
+        self.show = true;
+        self.quoted_table = quoted_table;
+        self.column = columns.first().cloned().unwrap_or_default();
+        self.columns = columns;
+        self.value.clear();
+        self.value_kind = BulkValueKind::Literal;
+        self.set_null = false;
+        self.where_clause = where_clause;
+        self.db_type = db_type;
+    }
+
+    /// 关闭窗口
+    pub fn close(&mut self) {
+        self.show = false;
+        self.columns.clear();
+        self.value.clear();
+    }
+
+    /// 生成 UPDATE 语句
+    pub fn update_sql(&self) -> Result<String, String> {
+        let column = quote_identifier(&self.column, self.db_type == DatabaseType::MySQL)?;
+        let value = match self.value_kind {
+            _ if self.set_null => "NULL".to_string(),
+            BulkValueKind::Literal => string_literal(&self.value, self.db_type),
+            BulkValueKind::Expression => {
+                let expr = self.value.trim().trim_end_matches(';').trim_end();
+                if expr.is_empty() {
+                    return Err(t("bulk_edit.expression_required").to_string());
+                }
+                if expr.contains(';') {
+                    return Err(t("bulk_edit.single_statement").to_string());
+                }
+                expr.to_string()
+            }
+        };
+        if self.where_clause.trim().is_empty() {
+            return Err(t("bulk_edit.no_filters").to_string());
+        }
+        Ok(format!(
+            "UPDATE {} SET {} = {} WHERE {}",
+            self.quoted_table, column, value, self.where_clause
+        ))
+    }
+}
+
+// ============================================================================
+// 窗口 UI
+// ============================================================================
+
+/// 批量修改窗口
+pub struct BulkEditDialog;
+
+impl BulkEditDialog {
+    /// 显示窗口
+    pub fn show(ctx: &egui::Context, state: &mut BulkEditState) -> BulkEditResult {
+        if !state.show {
+            return BulkEditResult::None;
+        }
+
+        let mut result = BulkEditResult::None;
+        let mut should_close = false;
+        let sql = state.update_sql();
+
+        // 键盘快捷键处理
+        if !keyboard::has_text_focus(ctx) {
+            match keyboard::handle_dialog_keys(ctx) {
+                keyboard::DialogAction::Cancel => {
+                    state.close();
+                    return BulkEditResult::None;
+                }
+                keyboard::DialogAction::Confirm => {
+                    if let Ok(sql) = &sql {
+                        result = BulkEditResult::Preview(sql.clone());
+                        should_close = true;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let mut open = true;
+        egui::Window::new(t("bulk_edit.title"))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(true)
+            .default_width(520.0)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.label(
+                    RichText::new(t("bulk_edit.intro"))
+                        .small()
+                        .color(Color32::GRAY),
+                );
+                ui.add_space(4.0);
+
+                egui::Grid::new("bulk_edit_grid")
+                    .num_columns(2)
+                    .spacing([12.0, 6.0])
+                    .show(ui, |ui| {
+                        ui.label(t("bulk_edit.column"));
+                        egui::ComboBox::from_id_salt("bulk_edit_column")
+                            .selected_text(&state.column)
+                            .width(240.0)
+                            .show_ui(ui, |ui| {
+                                for column in &state.columns {
+                                    ui.selectable_value(&mut state.column, column.clone(), column);
+                                }
+                            });
+                        ui.end_row();
+
+                        ui.label(t("bulk_edit.value"));
+                        ui.horizontal(|ui| {
+                            ui.add_enabled_ui(!state.set_null, |ui| {
+                                ui.radio_value(&mut state.value_kind, BulkValueKind::Literal, t("bulk_edit.literal"));
+                                ui.radio_value(&mut state.value_kind, BulkValueKind::Expression, t("bulk_edit.expression"));
+                            });
+                            ui.checkbox(&mut state.set_null, t("bulk_edit.set_null"));
+                        });
+                        ui.end_row();
+
+                        ui.label("");
+                        let hint = match state.value_kind {
+                            BulkValueKind::Expression => t("bulk_edit.expression_hint"),
+                            _ => t("bulk_edit.value_hint"),
+                        };
+                        ui.add_enabled(
+                            !state.set_null,
+                            TextEdit::singleline(&mut state.value)
+                                .hint_text(hint)
+                                .code_editor()
+                                .desired_width(240.0),
+                        );
+                        ui.end_row();
+                    });
+
+                ui.add_space(4.0);
+                ui.separator();
+                match &sql {
+                    Ok(sql) => {
+                        ui.add(
+                            TextEdit::multiline(&mut sql.as_str())
+                                .code_editor()
+                                .desired_width(f32::INFINITY)
+                                .desired_rows(2),
+                        );
+                    }
+                    Err(e) => {
+                        ui.label(RichText::new(e).color(Color32::from_rgb(255, 100, 100)));
+                    }
+                }
+
+                ui.add_space(8.0);
+                ui.horizontal(|ui| {
+                    if ui.add_enabled(sql.is_ok(), egui::Button::new(t("bulk_edit.preview"))).clicked()
+                        && let Ok(sql) = &sql
+                    {
+                        result = BulkEditResult::Preview(sql.clone());
+                        should_close = true;
+                    }
+                    if ui.button(t("common.cancel_esc")).clicked() {
+                        should_close = true;
+                    }
+                });
+            });
+
+        if !open || should_close {
+            state.close();
+        }
+
+        result
+    }
+}
@@ -0,0 +1,190 @@
+//! 修改加密密钥对话框
+//!
+//! 用于修改 SQLCipher 加密数据库的密钥（PRAGMA rekey）。
+//! 支持 Helix 风格的键盘导航。
+
+use super::keyboard::{self, DialogAction};
+use egui::{self, Color32, RichText, TextEdit};
+
+// ============================================================================
+// 对话框结果
+// ============================================================================
+
+/// 修改密钥对话框的结果
+pub enum ChangeKeyDialogResult {
+    /// 无操作
+    None,
+    /// 用户确认修改 (连接名, 新密钥)
+    Change(String, String),
+    /// 用户取消
+    Cancelled,
+}
+
+// ============================================================================
+// 对话框状态
+// ============================================================================
+
+/// 修改密钥对话框状态
+#[derive(Default)]
+pub struct ChangeKeyDialogState {
+    /// 是否显示对话框
+    pub show: bool,
+    /// 目标连接名
+    pub connection_name: String,
+    /// 新密钥
+    pub new_key: String,
+    /// 确认新密钥
+    pub confirm_key: String,
+    /// 错误信息
+    pub error: Option<String>,
+}
+
+impl ChangeKeyDialogState {
+    /// 创建新的对话框状态
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 打开对话框
+    pub fn open(&mut self, connection_name: impl Into<String>) {
+        self.reset();
+        self.connection_name = connection_name.into();
+        self.show = true;
+    }
+
+    /// 关闭对话框
+    pub fn close(&mut self) {
+        self.show = false;
+        self.reset();
+    }
+
+    /// 重置状态
+    fn reset(&mut self) {
+        self.connection_name.clear();
+        self.new_key.clear();
+        self.confirm_key.clear();
+        self.error = None;
+    }
+
+    /// 校验输入，返回新密钥
+    pub fn validate(&self) -> Result<String, String> {
+        if self.new_key.is_empty() {
+            return Err("新密钥不能为空".to_string());
+        }
+        if self.new_key != self.confirm_key {
+            return Err("两次输入的密钥不一致".to_string());
+        }
+        Ok(self.new_key.clone())
+    }
+}
+
+// ============================================================================
+// 对话框 UI
+// ============================================================================
+
+/// 修改加密密钥对话框
+pub struct ChangeKeyDialog;
+
+impl ChangeKeyDialog {
+    /// 显示对话框
+    pub fn show(ctx: &egui::Context, state: &mut ChangeKeyDialogState) -> ChangeKeyDialogResult {
+        if !state.show {
+            return ChangeKeyDialogResult::None;
+        }
+
+        let mut result = ChangeKeyDialogResult::None;
+        let mut should_close = false;
+
+        // 键盘快捷键处理
+        if !keyboard::has_text_focus(ctx) {
+            if keyboard::handle_close_keys(ctx) {
+                state.close();
+                return ChangeKeyDialogResult::Cancelled;
+            }
+
+            if let DialogAction::Confirm = keyboard::handle_dialog_keys(ctx) {
+                match state.validate() {
+                    Ok(key) => {
+                        result = ChangeKeyDialogResult::Change(state.connection_name.clone(), key);
+                        should_close = true;
+                    }
+                    Err(e) => state.error = Some(e),
+                }
+            }
+        }
+
+        egui::Window::new("🔑 修改加密密钥")
+            .collapsible(false)
+            .resizable(false)
+            .min_width(360.0)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.label(
+                    RichText::new(format!("连接: {}", state.connection_name))
+                        .color(Color32::from_rgb(150, 150, 160)),
+                );
+                ui.add_space(8.0);
+
+                egui::Grid::new("change_key_form")
+                    .num_columns(2)
+                    .spacing([12.0, 8.0])
+                    .show(ui, |ui| {
+                        ui.label("新密钥:");
+                        ui.add(
+                            TextEdit::singleline(&mut state.new_key)
+                                .password(true)
+                                .desired_width(220.0),
+                        );
+                        ui.end_row();
+
+                        ui.label("确认密钥:");
+                        ui.add(
+                            TextEdit::singleline(&mut state.confirm_key)
+                                .password(true)
+                                .desired_width(220.0),
+                        );
+                        ui.end_row();
+                    });
+
+                ui.add_space(4.0);
+                ui.label(
+                    RichText::new("提示: 修改后请妥善保管新密钥，丢失将无法打开数据库")
+                        .small()
+                        .color(Color32::from_rgb(120, 120, 120)),
+                );
+
+                if let Some(err) = &state.error {
+                    ui.add_space(4.0);
+                    ui.label(RichText::new(err).color(Color32::from_rgb(255, 100, 100)));
+                }
+
+                ui.add_space(8.0);
+
+                ui.horizontal(|ui| {
+                    if ui.button("修改 [Enter]").clicked() {
+                        match state.validate() {
+                            Ok(key) => {
+                                result = ChangeKeyDialogResult::Change(
+                                    state.connection_name.clone(),
+                                    key,
+                                );
+                                should_close = true;
+                            }
+                            Err(e) => state.error = Some(e),
+                        }
+                    }
+
+                    if ui.button("取消 [Esc]").clicked() {
+                        result = ChangeKeyDialogResult::Cancelled;
+                        should_close = true;
+                    }
+                });
+            });
+
+        if should_close {
+            state.close();
+        }
+
+        result
+    }
+}
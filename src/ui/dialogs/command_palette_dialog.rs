@@ -0,0 +1,261 @@
+//! 命令面板
+//!
+//! 按名称模糊搜索所有操作（快捷键操作、工具栏功能、连接、表、主题、收藏的查询），
+//! 执行时复用菜单和快捷键的处理逻辑。
+
+use crate::core::{fuzzy_score, t, Action, RecentTarget, ThemePreset};
+use crate::ui::ToolbarActions;
+use egui::{self, Color32, Key, Modifiers, RichText};
+
+/// 最多显示的匹配结果数
+const MAX_RESULTS: usize = 200;
+
+// ============================================================================
+// 命令
+// ============================================================================
+
+/// 命令面板中可执行的命令
+#[derive(Clone)]
+pub enum PaletteCommand {
+    /// 快捷键系统中的操作
+    Action(Action),
+    /// 工具栏功能（设置对应的工具栏操作标志）
+    Toolbar(fn(&mut ToolbarActions)),
+    /// 切换到连接
+    Connect(String),
+    /// 切换数据库
+    SwitchDatabase(String),
+    /// 打开表
+    OpenTable(String),
+    /// 切换主题
+    SetTheme(ThemePreset),
+    /// 执行 SQL（收藏的查询）
+    RunSql(String),
+    /// 打开最近使用的表或数据库（必要时先切换连接和数据库）
+    OpenRecent(RecentTarget),
+}
+
+/// 命令面板中的一项
+#[derive(Clone)]
+pub struct PaletteItem {
+    /// 分组名称（显示在名称前）
+    pub group: &'static str,
+    /// 显示名称
+    pub label: String,
+    /// 右侧提示（快捷键等）
+    pub hint: String,
+    /// 额外的搜索关键字（如英文名称）
+    pub keywords: String,
+    /// 执行的命令
+    pub command: PaletteCommand,
+}
+
+impl PaletteItem {
+    /// 创建新的命令项
+    pub fn new(group: &'static str, label: impl Into<String>, command: PaletteCommand) -> Self {
+        Self {
+            group,
+            label: label.into(),
+            hint: String::new(),
+            keywords: String::new(),
+            command,
+        }
+    }
+
+    /// 设置右侧提示
+    pub fn with_hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = hint.into();
+        self
+    }
+
+    /// 设置额外的搜索关键字
+    pub fn with_keywords(mut self, keywords: impl Into<String>) -> Self {
+        self.keywords = keywords.into();
+        self
+    }
+
+    /// 与搜索内容的匹配得分
+    fn score(&self, query: &str) -> Option<i32> {
+        let label = fuzzy_score(query, &self.label);
+        let group = fuzzy_score(query, &format!("{} {}", self.group, self.label)).map(|s| s - 2);
+        let keywords = fuzzy_score(query, &self.keywords).map(|s| s - 1);
+        [label, group, keywords].into_iter().flatten().max()
+    }
+}
+
+// ============================================================================
+// 状态
+// ============================================================================
+
+/// 命令面板状态
+#[derive(Default)]
+pub struct CommandPaletteState {
+    /// 是否显示
+    pub show: bool,
+    /// 搜索内容
+    pub query: String,
+    /// 当前选中的结果序号
+    pub selected: usize,
+    /// 所有命令
+    items: Vec<PaletteItem>,
+    /// 搜索框提示文字（为空时使用命令面板的默认提示）
+    placeholder: Option<&'static str>,
+    /// 选中项变化后需要滚动到可见位置
+    scroll_to_selected: bool,
+}
+
+impl CommandPaletteState {
+    /// 创建新的状态
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 打开命令面板
+    pub fn open(&mut self, items: Vec<PaletteItem>) {
+        self.items = items;
+        self.placeholder = None;
+        self.query.clear();
+        self.selected = 0;
+        self.show = true;
+    }
+
+    /// 以自定义的搜索框提示打开（如最近使用的表快速切换器）
+    pub fn open_with_placeholder(&mut self, items: Vec<PaletteItem>, placeholder: &'static str) {
+        self.open(items);
+        self.placeholder = Some(placeholder);
+    }
+
+    /// 关闭命令面板
+    pub fn close(&mut self) {
+        self.show = false;
+        self.items.clear();
+    }
+
+    /// 按匹配得分排序的结果（命令序号），得分相同时保持原有顺序
+    pub fn matches(&self) -> Vec<usize> {
+        let mut scored: Vec<(i32, usize)> = self
+            .items
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, item)| item.score(&self.query).map(|score| (score, idx)))
+            .collect();
+        scored.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+        scored.into_iter().take(MAX_RESULTS).map(|(_, idx)| idx).collect()
+    }
+
+    /// 命令项
+    pub fn item(&self, idx: usize) -> Option<&PaletteItem> {
+        self.items.get(idx)
+    }
+}
+
+// ============================================================================
+// UI
+// ============================================================================
+
+/// 命令面板
+pub struct CommandPalette;
+
+impl CommandPalette {
+    /// 显示命令面板，返回选中执行的命令
+    pub fn show(ctx: &egui::Context, state: &mut CommandPaletteState) -> Option<PaletteCommand> {
+        if !state.show {
+            return None;
+        }
+
+        let matches = state.matches();
+        if state.selected >= matches.len() {
+            state.selected = matches.len().saturating_sub(1);
+        }
+
+        // 在文本框处理之前截获导航键
+        let (up, down, enter, escape) = ctx.input_mut(|i| {
+            (
+                i.consume_key(Modifiers::NONE, Key::ArrowUp),
+                i.consume_key(Modifiers::NONE, Key::ArrowDown),
+                i.consume_key(Modifiers::NONE, Key::Enter),
+                i.consume_key(Modifiers::NONE, Key::Escape),
+            )
+        });
+        if escape {
+            state.close();
+            return None;
+        }
+        if down && !matches.is_empty() {
+            state.selected = (state.selected + 1) % matches.len();
+            state.scroll_to_selected = true;
+        }
+        if up && !matches.is_empty() {
+            state.selected = (state.selected + matches.len() - 1) % matches.len();
+            state.scroll_to_selected = true;
+        }
+
+        let mut chosen = enter.then(|| matches.get(state.selected).copied()).flatten();
+        let mut open = true;
+
+        egui::Window::new("command_palette")
+            .id(egui::Id::new("command_palette"))
+            .title_bar(false)
+            .collapsible(false)
+            .resizable(false)
+            .fixed_size([520.0, 0.0])
+            .anchor(egui::Align2::CENTER_TOP, [0.0, 80.0])
+            .open(&mut open)
+            .show(ctx, |ui| {
+                let input = ui.add(
+                    egui::TextEdit::singleline(&mut state.query)
+                        .hint_text(state.placeholder.unwrap_or_else(|| t("palette.placeholder")))
+                        .desired_width(f32::INFINITY),
+                );
+                if !input.has_focus() {
+                    input.request_focus();
+                }
+                if input.changed() {
+                    state.selected = 0;
+                }
+
+                ui.separator();
+
+                if matches.is_empty() {
+                    ui.label(RichText::new(t("palette.no_match")).color(Color32::GRAY));
+                }
+
+                egui::ScrollArea::vertical().max_height(360.0).auto_shrink([false, true]).show(ui, |ui| {
+                    for (row, &idx) in matches.iter().enumerate() {
+                        let item = &state.items[idx];
+                        let selected = row == state.selected;
+                        let response = ui
+                            .horizontal(|ui| {
+                                let label = ui.selectable_label(
+                                    selected,
+                                    format!("{}: {}", item.group, item.label),
+                                );
+                                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                    if !item.hint.is_empty() {
+                                        ui.label(RichText::new(&item.hint).monospace().weak());
+                                    }
+                                });
+                                label
+                            })
+                            .inner;
+                        if response.clicked() {
+                            chosen = Some(idx);
+                        }
+                        if selected && state.scroll_to_selected {
+                            response.scroll_to_me(None);
+                            state.scroll_to_selected = false;
+                        }
+                    }
+                });
+
+                ui.separator();
+                ui.label(RichText::new(t("palette.hint")).small().color(Color32::GRAY));
+            });
+
+        let command = chosen.and_then(|idx| state.item(idx)).map(|item| item.command.clone());
+        if !open || command.is_some() {
+            state.close();
+        }
+        command
+    }
+}
@@ -5,6 +5,7 @@
 //! - `Esc` / `n` - 取消操作
 
 use super::keyboard;
+use crate::core::t;
 use crate::ui::styles::{DANGER, GRAY, SPACING_MD, SPACING_LG};
 use egui::{self, Color32, RichText, CornerRadius};
 
@@ -72,7 +73,7 @@ impl ConfirmDialog {
                 // 快捷键提示
                 ui.horizontal(|ui| {
                     ui.add_space(SPACING_MD);
-                    ui.label(RichText::new("按 y 确认，n 取消").small().color(GRAY));
+                    ui.label(RichText::new(t("common.confirm_hint")).small().color(GRAY));
                 });
 
                 ui.add_space(SPACING_MD);
@@ -1,6 +1,14 @@
 //! 数据库连接对话框
 
 use super::keyboard::{self, DialogAction};
+use crate::core::{t, tf};
+use crate::core::constants::database::pool::{
+    MYSQL_IDLE_TIMEOUT_SECS, MYSQL_POOL_MAX_CONNECTIONS, MYSQL_POOL_MIN_CONNECTIONS, POOL_CONNECTIONS_RANGE,
+    POOL_IDLE_TIMEOUT_RANGE_SECS,
+};
+use crate::core::constants::database::{
+    CONNECTION_TIMEOUT_RANGE_SECS, CONNECTION_TIMEOUT_SECS, QUERY_TIMEOUT_RANGE_SECS, QUERY_TIMEOUT_SECS,
+};
 use crate::database::{ConnectionConfig, DatabaseType, MySqlSslMode, PostgresSslMode, SshAuthMethod};
 use crate::ui::styles::{DANGER, GRAY, MUTED, SUCCESS, SPACING_SM, SPACING_MD, SPACING_LG};
 use egui::{self, Color32, Key, Modifiers, RichText, CornerRadius, TextEdit};
@@ -26,28 +34,33 @@ impl ValidationResult {
     }
 }
 
+/// 数值超出范围时的错误信息
+fn range_error<T: std::fmt::Display>(key: &str, range: &std::ops::RangeInclusive<T>) -> String {
+    tf(key, &[&range.start().to_string(), &range.end().to_string()])
+}
+
 /// 验证连接配置
 fn validate_config(config: &ConnectionConfig) -> ValidationResult {
     let mut result = ValidationResult::new();
 
     // 验证连接名称
     if config.name.is_empty() {
-        result.add_error("连接名称不能为空");
+        result.add_error(t("conn.name_required"));
     } else if config.name.len() > 64 {
-        result.add_error("连接名称不能超过 64 个字符");
+        result.add_error(t("conn.name_too_long"));
     }
 
     match config.db_type {
         DatabaseType::SQLite => {
             // SQLite 验证
             if config.database.is_empty() {
-                result.add_error("数据库文件路径不能为空");
+                result.add_error(t("conn.path_required"));
             } else {
                 let path = Path::new(&config.database);
                 // 检查父目录是否存在
                 if let Some(parent) = path.parent()
                     && !parent.as_os_str().is_empty() && !parent.exists() {
-                        result.add_error(format!("目录不存在: {}", parent.display()));
+                        result.add_error(tf("conn.dir_missing", &[&parent.display().to_string()]));
                     }
                 // 检查文件扩展名
                 if let Some(ext) = path.extension() {
@@ -58,29 +71,97 @@ fn validate_config(config: &ConnectionConfig) -> ValidationResult {
                 }
             }
         }
-        DatabaseType::PostgreSQL | DatabaseType::MySQL => {
+        DatabaseType::DuckDB => {
+            // DuckDB 路径为空时使用内存数据库
+            if !config.is_duckdb_in_memory()
+                && let Some(parent) = Path::new(&config.database).parent()
+                && !parent.as_os_str().is_empty()
+                && !parent.exists()
+            {
+                result.add_error(tf("conn.dir_missing", &[&parent.display().to_string()]));
+            }
+        }
+        DatabaseType::Oracle => {
+            // 主机为空时服务名需为 TNS 别名或完整描述符
+            if config.host.is_empty() && config.oracle_service_name.trim().is_empty() {
+                result.add_error(t("conn.host_or_service_required"));
+            } else if config.host.contains(' ') {
+                result.add_error(t("conn.host_has_spaces"));
+            }
+            if config.username.is_empty() {
+                result.add_error(t("conn.oracle_username_required"));
+            }
+        }
+        DatabaseType::Odbc => {
+            if config.database.trim().is_empty() {
+                result.add_error(t("conn.dsn_required"));
+            }
+        }
+        // 主机填写完整 URI（如 mongodb+srv://）时由驱动解析
+        DatabaseType::MongoDB if config.host.trim().starts_with("mongodb") => {}
+        DatabaseType::PostgreSQL
+        | DatabaseType::MySQL
+        | DatabaseType::ClickHouse
+        | DatabaseType::Redis
+        | DatabaseType::MongoDB => {
             // 主机验证
             if config.host.is_empty() {
-                result.add_error("主机地址不能为空");
+                result.add_error(t("conn.host_required"));
             } else if config.host.contains(' ') {
-                result.add_error("主机地址不能包含空格");
+                result.add_error(t("conn.host_has_spaces"));
             } else if config.host.len() > 255 {
-                result.add_error("主机地址过长");
+                result.add_error(t("conn.host_too_long"));
             }
 
             // 端口验证（u16 类型范围已确保 0-65535）
             if config.port == 0 {
-                result.add_error("端口号不能为 0");
+                result.add_error(t("conn.port_zero"));
             }
             // 注: 小于 1024 的端口是系统保留端口，但某些数据库可能使用
 
             // 用户名验证（可选但推荐）
             if config.username.len() > 128 {
-                result.add_error("用户名过长");
+                result.add_error(t("conn.username_too_long"));
+            }
+
+            // 客户端证书与私钥需成对出现
+            if config.db_type == DatabaseType::PostgreSQL
+                && config.ssl_client_cert.is_empty() != config.ssl_client_key.is_empty()
+            {
+                result.add_error(t("conn.client_cert_pair"));
             }
         }
     }
 
+    // 单独配置的超时需在允许范围内
+    if config.connect_timeout.is_some_and(|v| !CONNECTION_TIMEOUT_RANGE_SECS.contains(&v)) {
+        result.add_error(range_error("conn.connect_timeout_range", &CONNECTION_TIMEOUT_RANGE_SECS));
+    }
+    if config.query_timeout.is_some_and(|v| !QUERY_TIMEOUT_RANGE_SECS.contains(&v)) {
+        result.add_error(range_error("conn.query_timeout_range", &QUERY_TIMEOUT_RANGE_SECS));
+    }
+
+    // 连接池配置
+    let pool = &config.pool;
+    if [pool.min_connections, pool.max_connections]
+        .iter()
+        .flatten()
+        .any(|v| !POOL_CONNECTIONS_RANGE.contains(v))
+    {
+        result.add_error(range_error("conn.pool_size_range", &POOL_CONNECTIONS_RANGE));
+    }
+    if let (Some(min), Some(max)) = (pool.min_connections, pool.max_connections)
+        && min > max
+    {
+        result.add_error(t("conn.pool_min_exceeds_max"));
+    }
+    if pool.idle_timeout_secs.is_some_and(|v| !POOL_IDLE_TIMEOUT_RANGE_SECS.contains(&v)) {
+        result.add_error(range_error("conn.idle_timeout_range", &POOL_IDLE_TIMEOUT_RANGE_SECS));
+    }
+    if pool.acquire_timeout_secs.is_some_and(|v| !CONNECTION_TIMEOUT_RANGE_SECS.contains(&v)) {
+        result.add_error(range_error("conn.acquire_timeout_range", &CONNECTION_TIMEOUT_RANGE_SECS));
+    }
+
     result
 }
 
@@ -116,13 +197,13 @@ impl ConnectionDialog {
             // 数据库类型快捷键
             let db_types = DatabaseType::all();
             ctx.input(|i| {
-                // 数字键 1/2/3 选择数据库类型
-                for (idx, key) in [Key::Num1, Key::Num2, Key::Num3].iter().enumerate() {
+                // 数字键按顺序选择已启用的数据库类型
+                for (idx, key) in [Key::Num1, Key::Num2, Key::Num3, Key::Num4, Key::Num5, Key::Num6, Key::Num7, Key::Num8, Key::Num9].iter().enumerate() {
                     if i.key_pressed(*key) && i.modifiers.is_none()
                         && let Some(db_type) = db_types.get(idx) {
                             config.db_type = *db_type;
                             config.port = db_type.default_port();
-                            if config.host.is_empty() && !matches!(db_type, DatabaseType::SQLite) {
+                            if config.host.is_empty() && db_type.requires_network() {
                                 config.host = "localhost".to_string();
                             }
                         }
@@ -135,7 +216,7 @@ impl ConnectionDialog {
                         let new_type = db_types[current_idx - 1];
                         config.db_type = new_type;
                         config.port = new_type.default_port();
-                        if config.host.is_empty() && !matches!(new_type, DatabaseType::SQLite) {
+                        if config.host.is_empty() && new_type.requires_network() {
                             config.host = "localhost".to_string();
                         }
                     }
@@ -146,27 +227,24 @@ impl ConnectionDialog {
                         let new_type = db_types[current_idx + 1];
                         config.db_type = new_type;
                         config.port = new_type.default_port();
-                        if config.host.is_empty() && !matches!(new_type, DatabaseType::SQLite) {
+                        if config.host.is_empty() && new_type.requires_network() {
                             config.host = "localhost".to_string();
                         }
                     }
                 }
 
-                // Ctrl+O 打开文件（仅 SQLite）
-                if matches!(config.db_type, DatabaseType::SQLite)
+                // Ctrl+O 打开文件（仅 SQLite/DuckDB）
+                if config.db_type.is_file_based()
                     && i.key_pressed(Key::O)
                     && i.modifiers == Modifiers::CTRL
-                    && let Some(path) = rfd::FileDialog::new()
-                        .add_filter("SQLite 数据库", &["db", "sqlite", "sqlite3"])
-                        .add_filter("所有文件", &["*"])
-                        .pick_file()
+                    && let Some(path) = Self::pick_database_file(config.db_type)
                     {
                         config.database = path.display().to_string();
                     }
             });
         }
 
-        egui::Window::new("🔗 新建数据库连接")
+        egui::Window::new(t("conn.title"))
             .open(&mut is_open)
             .resizable(false)
             .collapsible(false)
@@ -194,15 +272,31 @@ impl ConnectionDialog {
                         Self::show_postgres_ssl_config(ui, config);
                         ui.add_space(SPACING_LG);
                     }
-                    DatabaseType::SQLite => {}
+                    DatabaseType::SQLite
+                    | DatabaseType::DuckDB
+                    | DatabaseType::Oracle
+                    | DatabaseType::Odbc
+                    | DatabaseType::ClickHouse
+                    | DatabaseType::Redis
+                    | DatabaseType::MongoDB => {}
                 }
 
-                // SSH 隧道配置（仅对非 SQLite 显示）
-                if !matches!(config.db_type, DatabaseType::SQLite) {
+                // SSH 隧道配置（仅对网络数据库显示）
+                if config.db_type.requires_network() {
                     Self::show_ssh_tunnel_config(ui, config);
                     ui.add_space(SPACING_LG);
                 }
 
+                // 超时配置
+                Self::show_timeout_config(ui, config);
+                ui.add_space(SPACING_LG);
+
+                // 连接池配置（MySQL/PostgreSQL）
+                if matches!(config.db_type, DatabaseType::MySQL | DatabaseType::PostgreSQL) {
+                    Self::show_pool_config(ui, config);
+                    ui.add_space(SPACING_LG);
+                }
+
                 // 连接字符串预览
                 Self::show_connection_preview(ui, config);
 
@@ -227,7 +321,7 @@ impl ConnectionDialog {
         // 快捷键提示
         ui.horizontal(|ui| {
             ui.add_space(SPACING_SM);
-            ui.label(RichText::new("数据库类型 [1/2/3 或 h/l 切换]").small().color(MUTED));
+            ui.label(RichText::new(t("conn.db_type_hint")).small().color(MUTED));
         });
         ui.add_space(4.0);
 
@@ -236,12 +330,19 @@ impl ConnectionDialog {
             
             for (idx, db_type) in DatabaseType::all().iter().enumerate() {
                 let is_selected = config.db_type == *db_type;
-                let (icon, name, color, key) = match db_type {
-                    DatabaseType::SQLite => ("🗃️", "SQLite", Color32::from_rgb(80, 160, 220), "1"),
-                    DatabaseType::PostgreSQL => ("🐘", "PostgreSQL", Color32::from_rgb(80, 130, 180), "2"),
-                    DatabaseType::MySQL => ("🐬", "MySQL", Color32::from_rgb(240, 150, 80), "3"),
+                let (icon, name, color) = match db_type {
+                    DatabaseType::SQLite => ("🗃️", "SQLite", Color32::from_rgb(80, 160, 220)),
+                    DatabaseType::PostgreSQL => ("🐘", "PostgreSQL", Color32::from_rgb(80, 130, 180)),
+                    DatabaseType::MySQL => ("🐬", "MySQL", Color32::from_rgb(240, 150, 80)),
+                    DatabaseType::DuckDB => ("🦆", "DuckDB", Color32::from_rgb(230, 200, 60)),
+                    DatabaseType::Oracle => ("🏛", "Oracle", Color32::from_rgb(200, 70, 60)),
+                    DatabaseType::Odbc => ("🔌", "ODBC", Color32::from_rgb(140, 140, 160)),
+                    DatabaseType::Redis => ("🔑", "Redis", Color32::from_rgb(220, 60, 50)),
+                    DatabaseType::MongoDB => ("🍃", "MongoDB", Color32::from_rgb(80, 170, 90)),
+                    DatabaseType::ClickHouse => ("📊", "ClickHouse", Color32::from_rgb(250, 205, 50)),
                 };
-                let _ = idx; // 用于后续扩展
+                // 快捷键按已启用类型的顺序编号
+                let key = (idx + 1).to_string();
 
                 let fill = if is_selected {
                     Color32::from_rgba_unmultiplied(color.r(), color.g(), color.b(), 40)
@@ -274,7 +375,7 @@ impl ConnectionDialog {
                 if response.clicked() {
                     config.db_type = *db_type;
                     config.port = db_type.default_port();
-                    if config.host.is_empty() && !matches!(db_type, DatabaseType::SQLite) {
+                    if config.host.is_empty() && db_type.requires_network() {
                         config.host = "localhost".to_string();
                     }
                 }
@@ -296,18 +397,29 @@ impl ConnectionDialog {
                     .spacing([16.0, 10.0])
                     .show(ui, |ui| {
                         // 连接名称
-                        ui.label(RichText::new("连接名称").color(GRAY));
+                        ui.label(RichText::new(t("conn.name")).color(GRAY));
                         ui.add(
                             TextEdit::singleline(&mut config.name)
-                                .hint_text("我的数据库")
+                                .hint_text(t("conn.name_hint"))
                                 .char_limit(64)
                                 .desired_width(280.0)
                         );
                         ui.end_row();
 
-                        if !matches!(config.db_type, DatabaseType::SQLite) {
+                        // ODBC 数据源（DSN 名称或完整连接字符串）
+                        if config.db_type == DatabaseType::Odbc {
+                            ui.label(RichText::new(t("conn.data_source")).color(GRAY));
+                            ui.add(
+                                TextEdit::singleline(&mut config.database)
+                                    .hint_text(t("conn.data_source_hint"))
+                                    .desired_width(280.0)
+                            );
+                            ui.end_row();
+                        }
+
+                        if config.db_type.requires_network() {
                             // 主机地址
-                            ui.label(RichText::new("主机地址").color(GRAY));
+                            ui.label(RichText::new(t("conn.host")).color(GRAY));
                             ui.add(
                                 TextEdit::singleline(&mut config.host)
                                     .hint_text("localhost")
@@ -317,7 +429,7 @@ impl ConnectionDialog {
                             ui.end_row();
 
                             // 端口
-                            ui.label(RichText::new("端口").color(GRAY));
+                            ui.label(RichText::new(t("conn.port")).color(GRAY));
                             let mut port_string = config.port.to_string();
                             ui.add(
                                 TextEdit::singleline(&mut port_string)
@@ -328,19 +440,27 @@ impl ConnectionDialog {
                                 config.port = port;
                             }
                             ui.end_row();
+                        }
 
+                        // ODBC 的用户名/密码可选，未写入连接字符串时自动追加
+                        if !config.db_type.is_file_based() {
                             // 用户名
-                            ui.label(RichText::new("用户名").color(GRAY));
+                            ui.label(RichText::new(t("conn.username")).color(GRAY));
                             ui.add(
                                 TextEdit::singleline(&mut config.username)
-                                    .hint_text("root")
+                                    .hint_text(match config.db_type {
+                                        DatabaseType::Redis => t("conn.optional_acl_user"),
+                                        DatabaseType::MongoDB => t("conn.optional"),
+                                        DatabaseType::ClickHouse => "default",
+                                        _ => "root",
+                                    })
                                     .char_limit(128)
                                     .desired_width(280.0)
                             );
                             ui.end_row();
 
                             // 密码
-                            ui.label(RichText::new("密码").color(GRAY));
+                            ui.label(RichText::new(t("conn.password")).color(GRAY));
                             ui.add(
                                 TextEdit::singleline(&mut config.password)
                                     .password(true)
@@ -350,31 +470,58 @@ impl ConnectionDialog {
                             ui.end_row();
                         }
 
-                        // SQLite 文件路径（必填）
-                        if matches!(config.db_type, DatabaseType::SQLite) {
-                            ui.label(RichText::new("文件路径").color(GRAY));
+                        // Oracle 服务名（EZConnect），也可填写 TNS 别名或完整描述符
+                        if config.db_type == DatabaseType::Oracle {
+                            ui.label(RichText::new(t("conn.service_name")).color(GRAY));
+                            ui.add(
+                                TextEdit::singleline(&mut config.oracle_service_name)
+                                    .hint_text(t("conn.service_name_hint"))
+                                    .desired_width(280.0)
+                            );
+                            ui.end_row();
+                        }
+
+                        // 文件路径（SQLite 必填，DuckDB 留空为内存数据库）
+                        if config.db_type.is_file_based() {
+                            ui.label(RichText::new(t("conn.file_path")).color(GRAY));
 
+                            let hint = if config.db_type == DatabaseType::DuckDB {
+                                t("conn.in_memory_hint")
+                            } else {
+                                "/path/to/database.db"
+                            };
                             ui.horizontal(|ui| {
                                 ui.add(
                                     TextEdit::singleline(&mut config.database)
-                                        .hint_text("/path/to/database.db")
+                                        .hint_text(hint)
                                         .desired_width(200.0)
                                 );
 
                                 if ui.add(
-                                    egui::Button::new("浏览 [Ctrl+O]")
+                                    egui::Button::new(t("conn.browse_file"))
                                         .corner_radius(CornerRadius::same(4))
                                 ).clicked()
-                                    && let Some(path) = rfd::FileDialog::new()
-                                        .add_filter("SQLite 数据库", &["db", "sqlite", "sqlite3"])
-                                        .add_filter("所有文件", &["*"])
-                                        .pick_file()
+                                    && let Some(path) = Self::pick_database_file(config.db_type)
                                     {
                                         config.database = path.display().to_string();
                                     }
                             });
                             ui.end_row();
                         }
+
+                        if matches!(config.db_type, DatabaseType::SQLite) {
+
+                            // SQLCipher 加密密钥（可选）
+                            ui.label(RichText::new(t("conn.encryption_key")).color(GRAY));
+                            ui.add(
+                                TextEdit::singleline(&mut config.sqlite_key)
+                                    .password(true)
+                                    .hint_text(t("conn.encryption_key_hint"))
+                                    .char_limit(256)
+                                    .desired_width(280.0)
+                            );
+                            ui.end_row();
+                        }
                     });
             });
 
@@ -384,17 +531,32 @@ impl ConnectionDialog {
             ui.add_space(SPACING_MD);
             ui.add_space(4.0);
             let tip = match config.db_type {
-                DatabaseType::SQLite => "输入 SQLite 数据库文件路径，文件不存在时将自动创建",
-                DatabaseType::PostgreSQL => "默认端口 5432，连接后可选择数据库",
-                DatabaseType::MySQL => "默认端口 3306，连接后可选择数据库",
+                DatabaseType::SQLite => t("conn.tip_sqlite"),
+                DatabaseType::PostgreSQL => t("conn.tip_postgres"),
+                DatabaseType::MySQL => t("conn.tip_mysql"),
+                DatabaseType::DuckDB => t("conn.tip_duckdb"),
+                DatabaseType::Oracle => t("conn.tip_oracle"),
+                DatabaseType::Odbc => t("conn.tip_odbc"),
+                DatabaseType::Redis => t("conn.tip_redis"),
+                DatabaseType::MongoDB => t("conn.tip_mongodb"),
+                DatabaseType::ClickHouse => t("conn.tip_clickhouse"),
             };
             ui.label(RichText::new(tip).small().color(MUTED));
         });
     }
 
+    /// 选择本地数据库文件
+    fn pick_database_file(db_type: DatabaseType) -> Option<std::path::PathBuf> {
+        let dialog = match db_type {
+            DatabaseType::DuckDB => rfd::FileDialog::new().add_filter(t("conn.duckdb_files"), &["duckdb", "db"]),
+            _ => rfd::FileDialog::new().add_filter(t("conn.sqlite_files"), &["db", "sqlite", "sqlite3"]),
+        };
+        dialog.add_filter(t("conn.all_files"), &["*"]).pick_file()
+    }
+
     /// MySQL SSL 配置
     fn show_mysql_ssl_config(ui: &mut egui::Ui, config: &mut ConnectionConfig) {
-        ui.collapsing("🔐 SSL/TLS 加密", |ui| {
+        ui.collapsing(t("conn.ssl"), |ui| {
             ui.add_space(SPACING_SM);
 
             egui::Frame::NONE
@@ -407,7 +569,7 @@ impl ConnectionDialog {
                         .spacing([16.0, 8.0])
                         .show(ui, |ui| {
                             // SSL 模式选择
-                            ui.label(RichText::new("SSL 模式").color(GRAY));
+                            ui.label(RichText::new(t("conn.ssl_mode")).color(GRAY));
                             egui::ComboBox::new("ssl_mode_combo", "")
                                 .selected_text(config.mysql_ssl_mode.display_name())
                                 .show_ui(ui, |ui| {
@@ -431,17 +593,17 @@ impl ConnectionDialog {
                                 config.mysql_ssl_mode,
                                 MySqlSslMode::VerifyCa | MySqlSslMode::VerifyIdentity
                             ) {
-                                ui.label(RichText::new("CA 证书").color(GRAY));
+                                ui.label(RichText::new(t("conn.ca_cert")).color(GRAY));
                                 ui.horizontal(|ui| {
                                     ui.add(
                                         TextEdit::singleline(&mut config.ssl_ca_cert)
                                             .hint_text("/path/to/ca-cert.pem")
                                             .desired_width(160.0),
                                     );
-                                    if ui.button("浏览").clicked()
+                                    if ui.button(t("conn.browse")).clicked()
                                         && let Some(path) = rfd::FileDialog::new()
-                                            .add_filter("证书文件", &["pem", "crt", "cer"])
-                                            .add_filter("所有文件", &["*"])
+                                            .add_filter(t("conn.cert_files"), &["pem", "crt", "cer"])
+                                            .add_filter(t("conn.all_files"), &["*"])
                                             .pick_file()
                                         {
                                             config.ssl_ca_cert = path.display().to_string();
@@ -455,11 +617,11 @@ impl ConnectionDialog {
 
                     // SSL 模式说明
                     let tip = match config.mysql_ssl_mode {
-                        MySqlSslMode::Disabled => "不使用加密，数据以明文传输",
-                        MySqlSslMode::Preferred => "优先使用 SSL，如果服务器不支持则回退到明文",
-                        MySqlSslMode::Required => "必须使用 SSL 加密，不验证服务器证书",
-                        MySqlSslMode::VerifyCa => "验证服务器 CA 证书，不检查主机名",
-                        MySqlSslMode::VerifyIdentity => "完整验证：检查 CA 证书和服务器主机名",
+                        MySqlSslMode::Disabled => t("conn.ssl_tip_disabled"),
+                        MySqlSslMode::Preferred => t("conn.ssl_tip_preferred"),
+                        MySqlSslMode::Required => t("conn.ssl_tip_required"),
+                        MySqlSslMode::VerifyCa => t("conn.ssl_tip_verify_ca"),
+                        MySqlSslMode::VerifyIdentity => t("conn.ssl_tip_verify_full"),
                     };
                     ui.label(RichText::new(tip).small().color(MUTED));
                 });
@@ -468,7 +630,7 @@ impl ConnectionDialog {
 
     /// PostgreSQL SSL 配置
     fn show_postgres_ssl_config(ui: &mut egui::Ui, config: &mut ConnectionConfig) {
-        ui.collapsing("🔐 SSL/TLS 加密", |ui| {
+        ui.collapsing(t("conn.ssl"), |ui| {
             ui.add_space(SPACING_SM);
 
             egui::Frame::NONE
@@ -481,7 +643,7 @@ impl ConnectionDialog {
                         .spacing([16.0, 8.0])
                         .show(ui, |ui| {
                             // SSL 模式选择
-                            ui.label(RichText::new("SSL 模式").color(GRAY));
+                            ui.label(RichText::new(t("conn.ssl_mode")).color(GRAY));
                             egui::ComboBox::new("pg_ssl_mode_combo", "")
                                 .selected_text(config.postgres_ssl_mode.display_name())
                                 .show_ui(ui, |ui| {
@@ -505,17 +667,17 @@ impl ConnectionDialog {
                                 config.postgres_ssl_mode,
                                 PostgresSslMode::VerifyCa | PostgresSslMode::VerifyFull
                             ) {
-                                ui.label(RichText::new("CA 证书").color(GRAY));
+                                ui.label(RichText::new(t("conn.ca_cert")).color(GRAY));
                                 ui.horizontal(|ui| {
                                     ui.add(
                                         TextEdit::singleline(&mut config.ssl_ca_cert)
                                             .hint_text("/path/to/ca-cert.pem")
                                             .desired_width(160.0),
                                     );
-                                    if ui.button("浏览").clicked()
+                                    if ui.button(t("conn.browse")).clicked()
                                         && let Some(path) = rfd::FileDialog::new()
-                                            .add_filter("证书文件", &["pem", "crt", "cer"])
-                                            .add_filter("所有文件", &["*"])
+                                            .add_filter(t("conn.cert_files"), &["pem", "crt", "cer"])
+                                            .add_filter(t("conn.all_files"), &["*"])
                                             .pick_file()
                                         {
                                             config.ssl_ca_cert = path.display().to_string();
@@ -523,17 +685,56 @@ impl ConnectionDialog {
                                 });
                                 ui.end_row();
                             }
+
+                            // 客户端证书/私钥（启用 SSL 时可选，用于双向认证）
+                            if config.postgres_ssl_mode != PostgresSslMode::Disable {
+                                ui.label(RichText::new(t("conn.client_cert")).color(GRAY));
+                                ui.horizontal(|ui| {
+                                    ui.add(
+                                        TextEdit::singleline(&mut config.ssl_client_cert)
+                                            .hint_text("/path/to/client-cert.pem")
+                                            .desired_width(160.0),
+                                    );
+                                    if ui.button(t("conn.browse")).clicked()
+                                        && let Some(path) = rfd::FileDialog::new()
+                                            .add_filter(t("conn.cert_files"), &["pem", "crt", "cer"])
+                                            .add_filter(t("conn.all_files"), &["*"])
+                                            .pick_file()
+                                        {
+                                            config.ssl_client_cert = path.display().to_string();
+                                        }
+                                });
+                                ui.end_row();
+
+                                ui.label(RichText::new(t("conn.client_key")).color(GRAY));
+                                ui.horizontal(|ui| {
+                                    ui.add(
+                                        TextEdit::singleline(&mut config.ssl_client_key)
+                                            .hint_text("/path/to/client-key.pem")
+                                            .desired_width(160.0),
+                                    );
+                                    if ui.button(t("conn.browse")).clicked()
+                                        && let Some(path) = rfd::FileDialog::new()
+                                            .add_filter(t("conn.key_files"), &["pem", "key"])
+                                            .add_filter(t("conn.all_files"), &["*"])
+                                            .pick_file()
+                                        {
+                                            config.ssl_client_key = path.display().to_string();
+                                        }
+                                });
+                                ui.end_row();
+                            }
                         });
 
                     ui.add_space(SPACING_SM);
 
                     // SSL 模式说明
                     let tip = match config.postgres_ssl_mode {
-                        PostgresSslMode::Disable => "不使用加密，数据以明文传输",
-                        PostgresSslMode::Prefer => "优先使用 SSL，如果服务器不支持则回退到明文",
-                        PostgresSslMode::Require => "必须使用 SSL 加密，不验证服务器证书",
-                        PostgresSslMode::VerifyCa => "验证服务器 CA 证书，不检查主机名",
-                        PostgresSslMode::VerifyFull => "完整验证：检查 CA 证书和服务器主机名",
+                        PostgresSslMode::Disable => t("conn.ssl_tip_disabled"),
+                        PostgresSslMode::Prefer => t("conn.ssl_tip_preferred"),
+                        PostgresSslMode::Require => t("conn.ssl_tip_required"),
+                        PostgresSslMode::VerifyCa => t("conn.ssl_tip_verify_ca"),
+                        PostgresSslMode::VerifyFull => t("conn.ssl_tip_verify_full"),
                     };
                     ui.label(RichText::new(tip).small().color(MUTED));
                 });
@@ -541,8 +742,127 @@ impl ConnectionDialog {
     }
 
     /// SSH 隧道配置
+    /// 超时配置（未勾选时使用首选项中的默认值）
+    fn show_timeout_config(ui: &mut egui::Ui, config: &mut ConnectionConfig) {
+        ui.collapsing(t("conn.timeouts"), |ui| {
+            ui.add_space(SPACING_SM);
+
+            egui::Grid::new("connection_timeout_form")
+                .num_columns(2)
+                .spacing([16.0, 8.0])
+                .show(ui, |ui| {
+                    if config.db_type.requires_network() {
+                        Self::optional_row(
+                            ui,
+                            t("conn.connect_timeout"),
+                            &mut config.connect_timeout,
+                            CONNECTION_TIMEOUT_SECS,
+                            CONNECTION_TIMEOUT_RANGE_SECS,
+                            t("conn.seconds_suffix"),
+                        );
+                    }
+                    Self::optional_row(
+                        ui,
+                        t("conn.query_timeout"),
+                        &mut config.query_timeout,
+                        QUERY_TIMEOUT_SECS,
+                        QUERY_TIMEOUT_RANGE_SECS,
+                        t("conn.seconds_suffix"),
+                    );
+                });
+
+            ui.add_space(SPACING_SM);
+            ui.label(
+                RichText::new(t("conn.timeouts_tip"))
+                    .small()
+                    .color(MUTED),
+            );
+        });
+    }
+
+    /// 连接池配置（未勾选时使用内置默认值）
+    fn show_pool_config(ui: &mut egui::Ui, config: &mut ConnectionConfig) {
+        ui.collapsing(t("conn.pool"), |ui| {
+            ui.add_space(SPACING_SM);
+
+            let fallback_acquire = config.connect_timeout_secs();
+            let pool = &mut config.pool;
+            egui::Grid::new("connection_pool_form")
+                .num_columns(2)
+                .spacing([16.0, 8.0])
+                .show(ui, |ui| {
+                    if config.db_type == DatabaseType::MySQL {
+                        Self::optional_row(
+                            ui,
+                            t("conn.pool_min"),
+                            &mut pool.min_connections,
+                            MYSQL_POOL_MIN_CONNECTIONS,
+                            POOL_CONNECTIONS_RANGE,
+                            "",
+                        );
+                        Self::optional_row(
+                            ui,
+                            t("conn.pool_max"),
+                            &mut pool.max_connections,
+                            MYSQL_POOL_MAX_CONNECTIONS,
+                            POOL_CONNECTIONS_RANGE,
+                            "",
+                        );
+                        Self::optional_row(
+                            ui,
+                            t("conn.pool_idle_timeout"),
+                            &mut pool.idle_timeout_secs,
+                            MYSQL_IDLE_TIMEOUT_SECS,
+                            POOL_IDLE_TIMEOUT_RANGE_SECS,
+                            t("conn.seconds_suffix"),
+                        );
+                    }
+                    Self::optional_row(
+                        ui,
+                        t("conn.pool_acquire_timeout"),
+                        &mut pool.acquire_timeout_secs,
+                        fallback_acquire,
+                        CONNECTION_TIMEOUT_RANGE_SECS,
+                        t("conn.seconds_suffix"),
+                    );
+                });
+
+            ui.add_space(SPACING_SM);
+            let tip = if config.db_type == DatabaseType::MySQL {
+                t("conn.pool_tip_mysql")
+            } else {
+                t("conn.pool_tip_postgres")
+            };
+            ui.label(RichText::new(tip).small().color(MUTED));
+        });
+    }
+
+    /// 一行可选设置：勾选后可单独指定数值
+    fn optional_row<T: egui::emath::Numeric>(
+        ui: &mut egui::Ui,
+        label: &str,
+        value: &mut Option<T>,
+        fallback: T,
+        range: std::ops::RangeInclusive<T>,
+        suffix: &str,
+    ) {
+        ui.label(RichText::new(label).color(GRAY));
+        ui.horizontal(|ui| {
+            let mut custom = value.is_some();
+            if ui.checkbox(&mut custom, t("conn.override")).changed() {
+                *value = custom.then_some(fallback);
+            }
+            if let Some(number) = value {
+                ui.add(egui::DragValue::new(number).range(range).suffix(suffix));
+            } else {
+                ui.label(RichText::new(t("conn.default")).color(MUTED));
+            }
+        });
+        ui.end_row();
+    }
+
     fn show_ssh_tunnel_config(ui: &mut egui::Ui, config: &mut ConnectionConfig) {
-        ui.collapsing("🔒 SSH 隧道（可选）", |ui| {
+        ui.collapsing(t("conn.ssh"), |ui| {
             ui.add_space(SPACING_SM);
 
             egui::Frame::NONE
@@ -553,7 +873,7 @@ impl ConnectionDialog {
                     // 启用 SSH 隧道
                     ui.horizontal(|ui| {
                         ui.checkbox(&mut config.ssh_config.enabled, "");
-                        ui.label(RichText::new("启用 SSH 隧道").color(GRAY));
+                        ui.label(RichText::new(t("conn.ssh_enable")).color(GRAY));
                     });
 
                     if config.ssh_config.enabled {
@@ -564,16 +884,16 @@ impl ConnectionDialog {
                             .spacing([16.0, 8.0])
                             .show(ui, |ui| {
                                 // SSH 主机
-                                ui.label(RichText::new("SSH 主机").color(GRAY));
+                                ui.label(RichText::new(t("conn.ssh_host")).color(GRAY));
                                 ui.add(
                                     TextEdit::singleline(&mut config.ssh_config.ssh_host)
-                                        .hint_text("跳板机地址")
+                                        .hint_text(t("conn.ssh_host_hint"))
                                         .desired_width(200.0),
                                 );
                                 ui.end_row();
 
                                 // SSH 端口
-                                ui.label(RichText::new("SSH 端口").color(GRAY));
+                                ui.label(RichText::new(t("conn.ssh_port")).color(GRAY));
                                 let mut port_str = config.ssh_config.ssh_port.to_string();
                                 if ui.add(
                                     TextEdit::singleline(&mut port_str)
@@ -585,16 +905,16 @@ impl ConnectionDialog {
                                 ui.end_row();
 
                                 // SSH 用户名
-                                ui.label(RichText::new("SSH 用户名").color(GRAY));
+                                ui.label(RichText::new(t("conn.ssh_username")).color(GRAY));
                                 ui.add(
                                     TextEdit::singleline(&mut config.ssh_config.ssh_username)
-                                        .hint_text("用户名")
+                                        .hint_text(t("conn.username"))
                                         .desired_width(200.0),
                                 );
                                 ui.end_row();
 
                                 // 认证方式
-                                ui.label(RichText::new("认证方式").color(GRAY));
+                                ui.label(RichText::new(t("conn.ssh_auth")).color(GRAY));
                                 ui.horizontal(|ui| {
                                     ui.selectable_value(
                                         &mut config.ssh_config.auth_method,
@@ -612,7 +932,7 @@ impl ConnectionDialog {
                                 // 密码或私钥
                                 match config.ssh_config.auth_method {
                                     SshAuthMethod::Password => {
-                                        ui.label(RichText::new("SSH 密码").color(GRAY));
+                                        ui.label(RichText::new(t("conn.ssh_password")).color(GRAY));
                                         ui.add(
                                             TextEdit::singleline(&mut config.ssh_config.ssh_password)
                                                 .password(true)
@@ -621,16 +941,16 @@ impl ConnectionDialog {
                                         ui.end_row();
                                     }
                                     SshAuthMethod::PrivateKey => {
-                                        ui.label(RichText::new("私钥路径").color(GRAY));
+                                        ui.label(RichText::new(t("conn.ssh_key_path")).color(GRAY));
                                         ui.horizontal(|ui| {
                                             ui.add(
                                                 TextEdit::singleline(&mut config.ssh_config.private_key_path)
                                                     .hint_text("~/.ssh/id_rsa")
                                                     .desired_width(160.0),
                                             );
-                                            if ui.button("浏览").clicked()
+                                            if ui.button(t("conn.browse")).clicked()
                                                 && let Some(path) = rfd::FileDialog::new()
-                                                    .add_filter("私钥文件", &["pem", "key", "*"])
+                                                    .add_filter(t("conn.key_files"), &["pem", "key", "*"])
                                                     .pick_file()
                                                 {
                                                     config.ssh_config.private_key_path = path.display().to_string();
@@ -638,11 +958,11 @@ impl ConnectionDialog {
                                         });
                                         ui.end_row();
 
-                                        ui.label(RichText::new("私钥密码").color(GRAY));
+                                        ui.label(RichText::new(t("conn.ssh_key_passphrase")).color(GRAY));
                                         ui.add(
                                             TextEdit::singleline(&mut config.ssh_config.private_key_passphrase)
                                                 .password(true)
-                                                .hint_text("（可选）")
+                                                .hint_text(t("conn.optional_paren"))
                                                 .desired_width(200.0),
                                         );
                                         ui.end_row();
@@ -650,20 +970,20 @@ impl ConnectionDialog {
                                 }
 
                                 // 远程数据库地址（从 SSH 服务器视角）
-                                ui.label(RichText::new("远程主机").color(GRAY));
+                                ui.label(RichText::new(t("conn.remote_host")).color(GRAY));
                                 ui.add(
                                     TextEdit::singleline(&mut config.ssh_config.remote_host)
-                                        .hint_text("数据库主机（如 127.0.0.1）")
+                                        .hint_text(t("conn.remote_host_hint"))
                                         .desired_width(200.0),
                                 );
                                 ui.end_row();
 
                                 // 远程端口
-                                ui.label(RichText::new("远程端口").color(GRAY));
+                                ui.label(RichText::new(t("conn.remote_port")).color(GRAY));
                                 let mut remote_port_str = config.ssh_config.remote_port.to_string();
                                 if ui.add(
                                     TextEdit::singleline(&mut remote_port_str)
-                                        .hint_text("数据库端口")
+                                        .hint_text(t("conn.remote_port_hint"))
                                         .desired_width(80.0),
                                 ).changed()
                                     && let Ok(port) = remote_port_str.parse::<u16>() {
@@ -674,7 +994,7 @@ impl ConnectionDialog {
 
                         ui.add_space(SPACING_SM);
                         ui.label(
-                            RichText::new("提示：启用 SSH 隧道后，连接将通过跳板机转发到远程数据库")
+                            RichText::new(t("conn.ssh_tip"))
                                 .small()
                                 .color(MUTED),
                         );
@@ -685,7 +1005,7 @@ impl ConnectionDialog {
 
     /// 连接字符串预览
     fn show_connection_preview(ui: &mut egui::Ui, config: &ConnectionConfig) {
-        ui.collapsing("🔍 连接字符串预览", |ui| {
+        ui.collapsing(t("conn.preview"), |ui| {
             ui.add_space(SPACING_SM);
             
             egui::Frame::NONE
@@ -717,14 +1037,14 @@ impl ConnectionDialog {
         // 快捷键提示
         ui.horizontal(|ui| {
             ui.add_space(SPACING_SM);
-            ui.label(RichText::new("快捷键: Esc/q 关闭 | Enter 保存").small().color(MUTED));
+            ui.label(RichText::new(t("conn.shortcuts")).small().color(MUTED));
         });
         ui.add_space(SPACING_SM);
 
         ui.horizontal(|ui| {
             // 取消按钮
             if ui.add(
-                egui::Button::new("取消 [Esc]")
+                egui::Button::new(t("common.cancel_esc"))
                     .corner_radius(CornerRadius::same(6))
             ).clicked() {
                 *should_close = true;
@@ -733,7 +1053,7 @@ impl ConnectionDialog {
             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                 // 保存按钮
                 let save_btn = egui::Button::new(
-                    RichText::new("保存并连接 [Enter]")
+                    RichText::new(t("conn.save_and_connect"))
                         .color(if validation.is_valid { Color32::WHITE } else { GRAY })
                 )
                 .fill(if validation.is_valid { SUCCESS } else { Color32::from_rgb(80, 80, 90) })
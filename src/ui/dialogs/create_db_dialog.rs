@@ -4,7 +4,9 @@
 //! 支持 Helix 风格的键盘导航。
 
 use super::keyboard::{self, DialogAction};
+use crate::core::t;
 use crate::database::DatabaseType;
+use crate::ui::quote_identifier;
 use egui::{self, Color32, RichText, TextEdit};
 
 // ============================================================================
@@ -75,6 +77,12 @@ impl CreateDbDialogState {
             DatabaseType::SQLite => {
                 // SQLite 使用文件路径
             }
+            DatabaseType::DuckDB | DatabaseType::ClickHouse => {
+                // DuckDB 创建 schema，ClickHouse 使用默认的 Atomic 引擎，无额外选项
+            }
+            DatabaseType::Oracle | DatabaseType::Odbc | DatabaseType::Redis | DatabaseType::MongoDB => {
+                // Oracle 的 schema 即用户；ODBC 数据源由 DSN 决定；Redis 库号固定；MongoDB 写入时自动建库
+            }
         }
     }
 
@@ -100,23 +108,29 @@ impl CreateDbDialogState {
     pub fn generate_sql(&self) -> Result<String, String> {
         // 验证数据库名
         if self.db_name.is_empty() {
-            return Err("数据库名称不能为空".to_string());
+            return Err(t("create_db.name_required").to_string());
         }
 
         // 验证数据库名格式
         if !self.db_name.chars().all(|c| c.is_alphanumeric() || c == '_') {
-            return Err("数据库名只能包含字母、数字和下划线".to_string());
+            return Err(t("create_db.name_invalid").to_string());
         }
 
         match self.db_type {
             DatabaseType::MySQL => self.generate_mysql_sql(),
             DatabaseType::PostgreSQL => self.generate_postgres_sql(),
             DatabaseType::SQLite => self.generate_sqlite_sql(),
+            DatabaseType::DuckDB => Ok(format!("CREATE SCHEMA {};", quote_identifier(&self.db_name, false)?)),
+            DatabaseType::ClickHouse => Ok(format!("CREATE DATABASE {};", quote_identifier(&self.db_name, true)?)),
+            DatabaseType::Oracle => Err(t("create_db.oracle_unsupported").to_string()),
+            DatabaseType::Odbc => Err(t("create_db.odbc_unsupported").to_string()),
+            DatabaseType::Redis => Err(t("create_db.redis_unsupported").to_string()),
+            DatabaseType::MongoDB => Err(t("create_db.mongodb_unsupported").to_string()),
         }
     }
 
     fn generate_mysql_sql(&self) -> Result<String, String> {
-        let mut sql = format!("CREATE DATABASE `{}`", self.db_name);
+        let mut sql = format!("CREATE DATABASE {}", quote_identifier(&self.db_name, true)?);
         
         if !self.charset.is_empty() {
             sql.push_str(&format!(" CHARACTER SET {}", self.charset));
@@ -131,7 +145,7 @@ impl CreateDbDialogState {
     }
 
     fn generate_postgres_sql(&self) -> Result<String, String> {
-        let mut sql = format!("CREATE DATABASE \"{}\"", self.db_name);
+        let mut sql = format!("CREATE DATABASE {}", quote_identifier(&self.db_name, false)?);
         
         if !self.encoding.is_empty() {
             sql.push_str(&format!(" ENCODING '{}'", self.encoding));
@@ -142,7 +156,7 @@ impl CreateDbDialogState {
         }
         
         if !self.owner.is_empty() {
-            sql.push_str(&format!(" OWNER \"{}\"", self.owner));
+            sql.push_str(&format!(" OWNER {}", quote_identifier(&self.owner, false)?));
         }
         
         sql.push(';');
@@ -153,7 +167,7 @@ impl CreateDbDialogState {
         // SQLite 不需要 CREATE DATABASE 语句
         // 只需要连接到新文件即可创建
         if self.sqlite_path.is_empty() && self.db_name.is_empty() {
-            return Err("请指定数据库文件路径或名称".to_string());
+            return Err(t("create_db.sqlite_path_required").to_string());
         }
         
         // 返回文件路径作为特殊标记
@@ -210,9 +224,13 @@ impl CreateDbDialog {
         }
 
         let title = match state.db_type {
-            DatabaseType::MySQL => "新建 MySQL 数据库",
-            DatabaseType::PostgreSQL => "新建 PostgreSQL 数据库",
-            DatabaseType::SQLite => "新建 SQLite 数据库",
+            DatabaseType::MySQL => t("create_db.title_mysql"),
+            DatabaseType::PostgreSQL => t("create_db.title_postgres"),
+            DatabaseType::SQLite => t("create_db.title_sqlite"),
+            DatabaseType::DuckDB => t("create_db.title_duckdb"),
+            DatabaseType::Oracle => t("create_db.title_oracle"),
+            DatabaseType::ClickHouse => t("create_db.title_clickhouse"),
+            DatabaseType::Odbc | DatabaseType::Redis | DatabaseType::MongoDB => t("create_db.title"),
         };
 
         egui::Window::new(title)
@@ -224,11 +242,11 @@ impl CreateDbDialog {
                 ui.vertical(|ui| {
                     // 数据库名称
                     ui.horizontal(|ui| {
-                        ui.label("数据库名:");
+                        ui.label(t("create_db.name"));
                         ui.add(
                             TextEdit::singleline(&mut state.db_name)
                                 .desired_width(200.0)
-                                .hint_text("输入数据库名称"),
+                                .hint_text(t("create_db.name_hint")),
                         );
                     });
 
@@ -245,6 +263,12 @@ impl CreateDbDialog {
                         DatabaseType::SQLite => {
                             Self::show_sqlite_options(ui, state);
                         }
+                        DatabaseType::DuckDB
+                        | DatabaseType::Oracle
+                        | DatabaseType::ClickHouse
+                        | DatabaseType::Odbc
+                        | DatabaseType::Redis
+                        | DatabaseType::MongoDB => {}
                     }
 
                     ui.add_space(8.0);
@@ -252,7 +276,7 @@ impl CreateDbDialog {
 
                     // 预览 SQL
                     if !matches!(state.db_type, DatabaseType::SQLite) {
-                        ui.collapsing("预览 SQL", |ui| {
+                        ui.collapsing(t("common.preview_sql"), |ui| {
                             let sql = state.generate_sql().unwrap_or_default();
                             ui.add(
                                 TextEdit::multiline(&mut sql.as_str())
@@ -274,7 +298,7 @@ impl CreateDbDialog {
                     // 快捷键提示
                     ui.horizontal(|ui| {
                         ui.label(
-                            RichText::new("快捷键: Esc/q 关闭 | Enter 创建")
+                            RichText::new(t("common.create_shortcuts"))
                                 .small()
                                 .color(Color32::from_rgb(120, 120, 120)),
                         );
@@ -284,7 +308,7 @@ impl CreateDbDialog {
 
                     // 按钮
                     ui.horizontal(|ui| {
-                        if ui.button("创建 [Enter]").clicked() {
+                        if ui.button(t("common.create_enter")).clicked() {
                             match state.generate_sql() {
                                 Ok(sql) => {
                                     result = CreateDbDialogResult::Create(sql);
@@ -296,7 +320,7 @@ impl CreateDbDialog {
                             }
                         }
 
-                        if ui.button("取消 [Esc]").clicked() {
+                        if ui.button(t("common.cancel_esc")).clicked() {
                             result = CreateDbDialogResult::Cancelled;
                             should_close = true;
                         }
@@ -313,11 +337,11 @@ impl CreateDbDialog {
 
     fn show_mysql_options(ui: &mut egui::Ui, state: &mut CreateDbDialogState) {
         ui.group(|ui| {
-            ui.label(RichText::new("MySQL 选项").strong());
+            ui.label(RichText::new(t("create_db.mysql_options")).strong());
             ui.add_space(4.0);
 
             ui.horizontal(|ui| {
-                ui.label("字符集:");
+                ui.label(t("create_db.charset"));
                 egui::ComboBox::from_id_salt("charset")
                     .selected_text(&state.charset)
                     .width(150.0)
@@ -330,7 +354,7 @@ impl CreateDbDialog {
             });
 
             ui.horizontal(|ui| {
-                ui.label("排序规则:");
+                ui.label(t("create_db.collation"));
                 egui::ComboBox::from_id_salt("collation")
                     .selected_text(&state.collation)
                     .width(200.0)
@@ -362,11 +386,11 @@ impl CreateDbDialog {
 
     fn show_postgres_options(ui: &mut egui::Ui, state: &mut CreateDbDialogState) {
         ui.group(|ui| {
-            ui.label(RichText::new("PostgreSQL 选项").strong());
+            ui.label(RichText::new(t("create_db.postgres_options")).strong());
             ui.add_space(4.0);
 
             ui.horizontal(|ui| {
-                ui.label("编码:");
+                ui.label(t("create_db.encoding"));
                 egui::ComboBox::from_id_salt("encoding")
                     .selected_text(&state.encoding)
                     .width(100.0)
@@ -378,7 +402,7 @@ impl CreateDbDialog {
             });
 
             ui.horizontal(|ui| {
-                ui.label("模板:");
+                ui.label(t("create_db.template"));
                 egui::ComboBox::from_id_salt("template")
                     .selected_text(&state.template)
                     .width(120.0)
@@ -389,11 +413,11 @@ impl CreateDbDialog {
             });
 
             ui.horizontal(|ui| {
-                ui.label("所有者:");
+                ui.label(t("create_db.owner"));
                 ui.add(
                     TextEdit::singleline(&mut state.owner)
                         .desired_width(150.0)
-                        .hint_text("可选，留空使用当前用户"),
+                        .hint_text(t("create_db.owner_hint")),
                 );
             });
         });
@@ -401,21 +425,21 @@ impl CreateDbDialog {
 
     fn show_sqlite_options(ui: &mut egui::Ui, state: &mut CreateDbDialogState) {
         ui.group(|ui| {
-            ui.label(RichText::new("SQLite 选项").strong());
+            ui.label(RichText::new(t("create_db.sqlite_options")).strong());
             ui.add_space(4.0);
 
             ui.horizontal(|ui| {
-                ui.label("文件路径:");
+                ui.label(t("create_db.file_path"));
                 ui.add(
                     TextEdit::singleline(&mut state.sqlite_path)
                         .desired_width(250.0)
-                        .hint_text("输入完整路径，或留空使用数据库名.db"),
+                        .hint_text(t("create_db.file_path_hint")),
                 );
             });
 
             ui.add_space(4.0);
             ui.label(
-                RichText::new("提示: SQLite 数据库将在指定路径创建新文件")
+                RichText::new(t("create_db.sqlite_hint"))
                     .small()
                     .color(Color32::from_rgb(120, 120, 120)),
             );
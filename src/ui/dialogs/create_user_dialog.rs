@@ -1,11 +1,12 @@
 //! 新建用户对话框
 //!
-//! 提供创建新数据库用户的 UI，支持 MySQL 和 PostgreSQL。
+//! 提供创建新数据库用户的 UI，支持 MySQL、PostgreSQL 和 Oracle。
 //! SQLite 不支持用户管理。
 //! 支持 Helix 风格的键盘导航。
 
 use super::keyboard::{self, DialogAction};
-use crate::database::DatabaseType;
+use crate::core::{t, tf};
+use crate::database::{DatabaseType, DriverCapabilities};
 use egui::{self, Color32, RichText, TextEdit};
 
 // ============================================================================
@@ -108,30 +109,52 @@ impl CreateUserDialogState {
         // 初始化权限列表
         self.privileges = match db_type {
             DatabaseType::MySQL => vec![
-                Privilege::new("SELECT", "查询数据"),
-                Privilege::new("INSERT", "插入数据"),
-                Privilege::new("UPDATE", "更新数据"),
-                Privilege::new("DELETE", "删除数据"),
-                Privilege::new("CREATE", "创建表/数据库"),
-                Privilege::new("DROP", "删除表/数据库"),
-                Privilege::new("ALTER", "修改表结构"),
-                Privilege::new("INDEX", "创建/删除索引"),
-                Privilege::new("REFERENCES", "创建外键"),
-                Privilege::new("CREATE VIEW", "创建视图"),
+                Privilege::new("SELECT", t("user.priv_select")),
+                Privilege::new("INSERT", t("user.priv_insert")),
+                Privilege::new("UPDATE", t("user.priv_update")),
+                Privilege::new("DELETE", t("user.priv_delete")),
+                Privilege::new("CREATE", t("user.priv_create")),
+                Privilege::new("DROP", t("user.priv_drop")),
+                Privilege::new("ALTER", t("user.priv_alter")),
+                Privilege::new("INDEX", t("user.priv_index")),
+                Privilege::new("REFERENCES", t("user.priv_references")),
+                Privilege::new("CREATE VIEW", t("user.priv_create_view")),
             ],
             DatabaseType::PostgreSQL => vec![
-                Privilege::new("SELECT", "查询数据"),
-                Privilege::new("INSERT", "插入数据"),
-                Privilege::new("UPDATE", "更新数据"),
-                Privilege::new("DELETE", "删除数据"),
-                Privilege::new("TRUNCATE", "清空表"),
-                Privilege::new("REFERENCES", "创建外键"),
-                Privilege::new("TRIGGER", "创建触发器"),
-                Privilege::new("CREATE", "创建对象"),
-                Privilege::new("CONNECT", "连接数据库"),
-                Privilege::new("TEMPORARY", "创建临时表"),
+                Privilege::new("SELECT", t("user.priv_select")),
+                Privilege::new("INSERT", t("user.priv_insert")),
+                Privilege::new("UPDATE", t("user.priv_update")),
+                Privilege::new("DELETE", t("user.priv_delete")),
+                Privilege::new("TRUNCATE", t("user.priv_truncate")),
+                Privilege::new("REFERENCES", t("user.priv_references")),
+                Privilege::new("TRIGGER", t("user.priv_trigger")),
+                Privilege::new("CREATE", t("user.priv_create_objects")),
+                Privilege::new("CONNECT", t("user.priv_connect")),
+                Privilege::new("TEMPORARY", t("user.priv_temporary")),
             ],
-            DatabaseType::SQLite => vec![], // SQLite 不支持用户管理
+            // Oracle 授予系统权限（schema 即用户，无需选择数据库）
+            DatabaseType::Oracle => vec![
+                Privilege::new("CREATE SESSION", t("user.priv_create_session")),
+                Privilege::new("CREATE TABLE", t("user.priv_create_table")),
+                Privilege::new("CREATE VIEW", t("user.priv_create_view")),
+                Privilege::new("CREATE SEQUENCE", t("user.priv_create_sequence")),
+                Privilege::new("CREATE PROCEDURE", t("user.priv_create_procedure")),
+                Privilege::new("CREATE TRIGGER", t("user.priv_trigger")),
+                Privilege::new("UNLIMITED TABLESPACE", t("user.priv_unlimited_tablespace")),
+            ],
+            // ClickHouse 按库授予 SQL 权限
+            DatabaseType::ClickHouse => vec![
+                Privilege::new("SELECT", t("user.priv_select")),
+                Privilege::new("INSERT", t("user.priv_insert")),
+                Privilege::new("ALTER", t("user.priv_alter_data")),
+                Privilege::new("CREATE", t("user.priv_create_table_view")),
+                Privilege::new("DROP", t("user.priv_drop_table_view")),
+                Privilege::new("TRUNCATE", t("user.priv_truncate")),
+                Privilege::new("OPTIMIZE", t("user.priv_optimize")),
+                Privilege::new("SHOW", t("user.priv_show")),
+            ],
+            // SQLite/DuckDB/ODBC 不支持用户管理
+            DatabaseType::SQLite | DatabaseType::DuckDB | DatabaseType::Odbc | DatabaseType::Redis | DatabaseType::MongoDB => vec![],
         };
     }
 
@@ -156,23 +179,23 @@ impl CreateUserDialogState {
     /// 验证输入
     fn validate(&self) -> Result<(), String> {
         if self.username.is_empty() {
-            return Err("用户名不能为空".to_string());
+            return Err(t("user.name_required").to_string());
         }
 
         if !self.username.chars().all(|c| c.is_alphanumeric() || c == '_') {
-            return Err("用户名只能包含字母、数字和下划线".to_string());
+            return Err(t("user.name_invalid").to_string());
         }
 
         if self.password.is_empty() {
-            return Err("密码不能为空".to_string());
+            return Err(t("user.password_required").to_string());
         }
 
         if self.password != self.confirm_password {
-            return Err("两次输入的密码不一致".to_string());
+            return Err(t("user.password_mismatch").to_string());
         }
 
         if self.password.len() < 4 {
-            return Err("密码长度至少为 4 位".to_string());
+            return Err(t("user.password_too_short").to_string());
         }
 
         Ok(())
@@ -185,7 +208,11 @@ impl CreateUserDialogState {
         match self.db_type {
             DatabaseType::MySQL => self.generate_mysql_sql(),
             DatabaseType::PostgreSQL => self.generate_postgres_sql(),
-            DatabaseType::SQLite => Err("SQLite 不支持用户管理".to_string()),
+            DatabaseType::Oracle => self.generate_oracle_sql(),
+            DatabaseType::ClickHouse => self.generate_clickhouse_sql(),
+            DatabaseType::SQLite | DatabaseType::DuckDB | DatabaseType::Odbc | DatabaseType::Redis | DatabaseType::MongoDB => {
+                Err(tf("user.unsupported", &[self.db_type.display_name()]))
+            }
         }
     }
 
@@ -210,7 +237,7 @@ impl CreateUserDialogState {
                     .map(|p| p.name)
                     .collect();
                 if selected.is_empty() {
-                    return Err("请至少选择一个权限".to_string());
+                    return Err(t("user.privileges_required").to_string());
                 }
                 selected.join(", ")
             };
@@ -251,7 +278,7 @@ impl CreateUserDialogState {
                     .map(|p| p.name)
                     .collect();
                 if selected.is_empty() {
-                    return Err("请至少选择一个权限".to_string());
+                    return Err(t("user.privileges_required").to_string());
                 }
                 
                 // PostgreSQL 权限授予比较复杂，这里简化处理
@@ -266,6 +293,66 @@ impl CreateUserDialogState {
 
         Ok(statements)
     }
+
+    fn generate_oracle_sql(&self) -> Result<Vec<String>, String> {
+        // Oracle 的带引号密码中不能包含双引号
+        if self.password.contains('"') {
+            return Err(t("user.oracle_password_quote").to_string());
+        }
+
+        let username = self.username.to_uppercase();
+        let mut statements = vec![format!(
+            "CREATE USER \"{}\" IDENTIFIED BY \"{}\";",
+            username, self.password
+        )];
+
+        let privileges = if self.grant_all {
+            "CONNECT, RESOURCE".to_string()
+        } else {
+            let selected: Vec<&str> = self.privileges
+                .iter()
+                .filter(|p| p.selected)
+                .map(|p| p.name)
+                .collect();
+            if selected.is_empty() {
+                return Err(t("user.privileges_required").to_string());
+            }
+            selected.join(", ")
+        };
+        statements.push(format!("GRANT {} TO \"{}\";", privileges, username));
+
+        Ok(statements)
+    }
+
+    fn generate_clickhouse_sql(&self) -> Result<Vec<String>, String> {
+        let escaped_password = self.password.replace('\\', "\\\\").replace('\'', "\\'");
+        let mut statements = vec![format!(
+            "CREATE USER `{}` IDENTIFIED WITH sha256_password BY '{}';",
+            self.username, escaped_password
+        )];
+
+        if !self.grant_database.is_empty() {
+            let privileges = if self.grant_all {
+                "ALL".to_string()
+            } else {
+                let selected: Vec<&str> = self.privileges
+                    .iter()
+                    .filter(|p| p.selected)
+                    .map(|p| p.name)
+                    .collect();
+                if selected.is_empty() {
+                    return Err(t("user.privileges_required").to_string());
+                }
+                selected.join(", ")
+            };
+            statements.push(format!(
+                "GRANT {} ON `{}`.* TO `{}`;",
+                privileges, self.grant_database, self.username
+            ));
+        }
+
+        Ok(statements)
+    }
 }
 
 // ============================================================================
@@ -285,8 +372,8 @@ impl CreateUserDialog {
             return CreateUserDialogResult::None;
         }
 
-        // SQLite 不支持
-        if matches!(state.db_type, DatabaseType::SQLite) {
+        // SQLite/DuckDB/ODBC 不支持
+        if !DriverCapabilities::for_db_type(state.db_type).user_management {
             state.close();
             return CreateUserDialogResult::None;
         }
@@ -317,9 +404,11 @@ impl CreateUserDialog {
         }
 
         let title = match state.db_type {
-            DatabaseType::MySQL => "新建 MySQL 用户",
-            DatabaseType::PostgreSQL => "新建 PostgreSQL 用户",
-            DatabaseType::SQLite => "新建用户", // 不会显示
+            DatabaseType::MySQL => t("user.title_mysql"),
+            DatabaseType::PostgreSQL => t("user.title_postgres"),
+            DatabaseType::Oracle => t("user.title_oracle"),
+            DatabaseType::ClickHouse => t("user.title_clickhouse"),
+            DatabaseType::SQLite | DatabaseType::DuckDB | DatabaseType::Odbc | DatabaseType::Redis | DatabaseType::MongoDB => t("user.title"), // 不会显示
         };
 
         egui::Window::new(title)
@@ -331,42 +420,42 @@ impl CreateUserDialog {
                 ui.vertical(|ui| {
                     // 基本信息
                     ui.group(|ui| {
-                        ui.label(RichText::new("基本信息").strong());
+                        ui.label(RichText::new(t("user.basic_info")).strong());
                         ui.add_space(4.0);
 
                         ui.horizontal(|ui| {
-                            ui.label("用户名:");
+                            ui.label(t("user.username"));
                             ui.add(
                                 TextEdit::singleline(&mut state.username)
                                     .desired_width(200.0)
-                                    .hint_text("输入用户名"),
+                                    .hint_text(t("user.username_hint")),
                             );
                         });
 
                         ui.horizontal(|ui| {
-                            ui.label("密  码:");
+                            ui.label(t("user.password"));
                             ui.add(
                                 TextEdit::singleline(&mut state.password)
                                     .password(true)
                                     .desired_width(200.0)
-                                    .hint_text("输入密码"),
+                                    .hint_text(t("user.password_hint")),
                             );
                         });
 
                         ui.horizontal(|ui| {
-                            ui.label("确  认:");
+                            ui.label(t("user.confirm"));
                             ui.add(
                                 TextEdit::singleline(&mut state.confirm_password)
                                     .password(true)
                                     .desired_width(200.0)
-                                    .hint_text("再次输入密码"),
+                                    .hint_text(t("user.confirm_hint")),
                             );
                         });
 
                         // MySQL 特有：主机
                         if matches!(state.db_type, DatabaseType::MySQL) {
                             ui.horizontal(|ui| {
-                                ui.label("主  机:");
+                                ui.label(t("user.host"));
                                 egui::ComboBox::from_id_salt("host")
                                     .selected_text(&state.host)
                                     .width(150.0)
@@ -379,7 +468,7 @@ impl CreateUserDialog {
                                         ui.selectable_value(
                                             &mut state.host,
                                             "%".to_string(),
-                                            "% (所有主机)",
+                                            t("user.any_host"),
                                         );
                                         ui.selectable_value(
                                             &mut state.host,
@@ -395,57 +484,72 @@ impl CreateUserDialog {
 
                     // 权限设置
                     ui.group(|ui| {
-                        ui.label(RichText::new("权限设置").strong());
+                        ui.label(RichText::new(t("user.privileges")).strong());
                         ui.add_space(4.0);
 
-                        ui.horizontal(|ui| {
-                            ui.label("授权数据库:");
-                            egui::ComboBox::from_id_salt("grant_db")
-                                .selected_text(if state.grant_database.is_empty() {
-                                    "选择数据库（可选）"
-                                } else {
-                                    &state.grant_database
-                                })
-                                .width(200.0)
-                                .show_ui(ui, |ui| {
-                                    ui.selectable_value(
-                                        &mut state.grant_database,
-                                        String::new(),
-                                        "不授权",
-                                    );
-                                    for db in &state.available_databases {
+                        // Oracle 直接授予系统权限/角色
+                        if matches!(state.db_type, DatabaseType::Oracle) {
+                            ui.checkbox(&mut state.grant_all, t("user.grant_oracle_roles"));
+
+                            if !state.grant_all {
+                                ui.add_space(4.0);
+                                ui.horizontal_wrapped(|ui| {
+                                    for priv_item in &mut state.privileges {
+                                        ui.checkbox(&mut priv_item.selected, priv_item.name)
+                                            .on_hover_text(priv_item.description);
+                                    }
+                                });
+                            }
+                        } else {
+                            ui.horizontal(|ui| {
+                                ui.label(t("user.grant_database"));
+                                egui::ComboBox::from_id_salt("grant_db")
+                                    .selected_text(if state.grant_database.is_empty() {
+                                        t("user.grant_database_hint")
+                                    } else {
+                                        &state.grant_database
+                                    })
+                                    .width(200.0)
+                                    .show_ui(ui, |ui| {
                                         ui.selectable_value(
                                             &mut state.grant_database,
-                                            db.clone(),
-                                            db,
+                                            String::new(),
+                                            t("user.no_grant"),
                                         );
-                                    }
-                                });
-                        });
+                                        for db in &state.available_databases {
+                                            ui.selectable_value(
+                                                &mut state.grant_database,
+                                                db.clone(),
+                                                db,
+                                            );
+                                        }
+                                    });
+                            });
 
-                        if !state.grant_database.is_empty() {
-                            ui.add_space(4.0);
+                            if !state.grant_database.is_empty() {
+                                ui.add_space(4.0);
                             
-                            ui.checkbox(&mut state.grant_all, "授予所有权限 (ALL PRIVILEGES)");
+                                ui.checkbox(&mut state.grant_all, t("user.grant_all"));
+
+                                if !state.grant_all {
+                                    ui.add_space(4.0);
+                                    ui.label(
+                                        RichText::new(t("user.select_privileges"))
+                                            .small()
+                                            .color(Color32::from_rgb(150, 150, 150)),
+                                    );
 
-                            if !state.grant_all {
-                                ui.add_space(4.0);
-                                ui.label(
-                                    RichText::new("选择权限:")
-                                        .small()
-                                        .color(Color32::from_rgb(150, 150, 150)),
-                                );
-
-                                egui::ScrollArea::vertical()
-                                    .max_height(150.0)
-                                    .show(ui, |ui| {
-                                        ui.horizontal_wrapped(|ui| {
-                                            for priv_item in &mut state.privileges {
-                                                ui.checkbox(&mut priv_item.selected, priv_item.name)
-                                                    .on_hover_text(priv_item.description);
-                                            }
+                                    egui::ScrollArea::vertical()
+                                        .max_height(150.0)
+                                        .show(ui, |ui| {
+                                            ui.horizontal_wrapped(|ui| {
+                                                for priv_item in &mut state.privileges {
+                                                    ui.checkbox(&mut priv_item.selected, priv_item.name)
+                                                        .on_hover_text(priv_item.description);
+                                                }
+                                            });
                                         });
-                                    });
+                                }
                             }
                         }
                     });
@@ -454,7 +558,7 @@ impl CreateUserDialog {
                     ui.separator();
 
                     // 预览 SQL
-                    ui.collapsing("预览 SQL", |ui| {
+                    ui.collapsing(t("common.preview_sql"), |ui| {
                         let sql = state.generate_sql()
                             .map(|stmts| stmts.join("\n"))
                             .unwrap_or_default();
@@ -477,7 +581,7 @@ impl CreateUserDialog {
                     // 快捷键提示
                     ui.horizontal(|ui| {
                         ui.label(
-                            RichText::new("快捷键: Esc/q 关闭 | Enter 创建")
+                            RichText::new(t("common.create_shortcuts"))
                                 .small()
                                 .color(Color32::from_rgb(120, 120, 120)),
                         );
@@ -487,7 +591,7 @@ impl CreateUserDialog {
 
                     // 按钮
                     ui.horizontal(|ui| {
-                        if ui.button("创建 [Enter]").clicked() {
+                        if ui.button(t("common.create_enter")).clicked() {
                             match state.generate_sql() {
                                 Ok(statements) => {
                                     result = CreateUserDialogResult::Create(statements);
@@ -499,7 +603,7 @@ impl CreateUserDialog {
                             }
                         }
 
-                        if ui.button("取消 [Esc]").clicked() {
+                        if ui.button(t("common.cancel_esc")).clicked() {
                             result = CreateUserDialogResult::Cancelled;
                             should_close = true;
                         }
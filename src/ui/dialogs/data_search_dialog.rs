@@ -0,0 +1,398 @@
+//! 全库数据搜索窗口
+//!
+//! 输入关键字并选择要搜索的表，在各表的文本列中查找包含关键字的行。
+//! 查询由调用方异步并发执行，结果按表分组逐个显示，点击命中的行在表格中打开该表的搜索查询。
+
+use super::keyboard;
+use crate::core::constants::database::DATA_SEARCH_ROW_LIMIT;
+use crate::core::{t, tf};
+use crate::database::QueryResult;
+use egui::{self, Color32, RichText};
+
+/// 命中行中单元格值的最大显示长度
+const MAX_VALUE_CHARS: usize = 80;
+
+// ============================================================================
+// 窗口结果
+// ============================================================================
+
+/// 全库数据搜索窗口的结果
+pub enum DataSearchResult {
+    /// 无操作
+    None,
+    /// 开始搜索
+    Search,
+    /// 停止正在进行的搜索（点击停止或关闭窗口）
+    Cancel,
+    /// 在表格中打开某张表的搜索结果
+    Open {
+        /// 表名
+        table: String,
+        /// 该表的搜索 SQL
+        sql: String,
+    },
+}
+
+// ============================================================================
+// 窗口状态
+// ============================================================================
+
+/// 可搜索的表
+#[derive(Debug, Clone)]
+pub struct DataSearchTable {
+    /// 表名
+    pub name: String,
+    /// 是否参与搜索
+    pub selected: bool,
+}
+
+/// 一张表的搜索结果
+#[derive(Debug, Clone)]
+pub enum DataSearchOutcome {
+    /// 表中没有可搜索的文本列
+    NoTextColumns,
+    /// 已执行搜索查询 (SQL, 查询结果)
+    Searched(String, Result<QueryResult, String>),
+    /// 读取列信息失败
+    Failed(String),
+}
+
+/// 有命中或出错的表
+#[derive(Debug, Clone)]
+pub struct DataSearchHit {
+    /// 表名
+    pub table: String,
+    /// 搜索 SQL
+    pub sql: String,
+    /// 命中的行（或错误信息）
+    pub result: Result<QueryResult, String>,
+}
+
+/// 全库数据搜索窗口状态
+#[derive(Default)]
+pub struct DataSearchState {
+    /// 是否显示窗口
+    pub show: bool,
+    /// 搜索关键字
+    pub term: String,
+    /// 只搜索列名包含该文本的列（为空时搜索所有文本列）
+    pub column_filter: String,
+    /// 是否区分大小写
+    pub case_sensitive: bool,
+    /// 当前数据库的表
+    pub tables: Vec<DataSearchTable>,
+    /// 表列表的筛选文本
+    pub table_filter: String,
+    /// 是否正在搜索
+    pub searching: bool,
+    /// 当前搜索的编号（用于丢弃已取消搜索的迟到结果）
+    pub search_id: u64,
+    /// 本次搜索的关键字
+    pub searched_term: String,
+    /// 本次搜索的表数量
+    pub total: usize,
+    /// 已完成的表数量
+    pub completed: usize,
+    /// 没有文本列而跳过的表数量
+    pub skipped: usize,
+    /// 有命中或出错的表（按表名排序）
+    pub hits: Vec<DataSearchHit>,
+}
+
+impl DataSearchState {
+    /// 创建新的窗口状态
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 打开窗口，表列表变化时重新全选（保留上次的关键字和结果）
+    pub fn open(&mut self, tables: Vec<String>) {
+        self.show = true;
+        let unchanged = self.tables.len() == tables.len()
+            && self.tables.iter().zip(&tables).all(|(t, name)| &t.name == name);
+        if !unchanged {
+            self.tables = tables
+                .into_iter()
+                .map(|name| DataSearchTable { name, selected: true })
+                .collect();
+            self.hits.clear();
+            self.total = 0;
+            self.completed = 0;
+            self.skipped = 0;
+        }
+    }
+
+    /// 关闭窗口（正在进行的搜索由调用方取消）
+    pub fn close(&mut self) {
+        self.show = false;
+        self.searching = false;
+    }
+
+    /// 选中的表名
+    pub fn selected_tables(&self) -> Vec<String> {
+        self.tables.iter().filter(|t| t.selected).map(|t| t.name.clone()).collect()
+    }
+
+    /// 开始新的搜索，返回搜索编号
+    pub fn start(&mut self, table_count: usize) -> u64 {
+        self.search_id += 1;
+        self.searching = true;
+        self.searched_term = self.term.clone();
+        self.total = table_count;
+        self.completed = 0;
+        self.skipped = 0;
+        self.hits.clear();
+        self.search_id
+    }
+
+    /// 记录一张表的搜索结果，忽略已取消搜索的结果
+    pub fn add_result(&mut self, search_id: u64, table: String, outcome: DataSearchOutcome) {
+        if search_id != self.search_id {
+            return;
+        }
+        self.completed += 1;
+        let (sql, result) = match outcome {
+            DataSearchOutcome::NoTextColumns => {
+                self.skipped += 1;
+                return;
+            }
+            DataSearchOutcome::Searched(_, Ok(ref r)) if r.rows.is_empty() => return,
+            DataSearchOutcome::Searched(sql, result) => (sql, result),
+            DataSearchOutcome::Failed(e) => (String::new(), Err(e)),
+        };
+        let idx = self.hits.partition_point(|h| h.table < table);
+        self.hits.insert(idx, DataSearchHit { table, sql, result });
+    }
+
+    /// 搜索结束（全部完成或已取消）
+    pub fn finish(&mut self, search_id: u64) {
+        if search_id == self.search_id {
+            self.searching = false;
+        }
+    }
+
+    /// 命中的总行数
+    pub fn hit_rows(&self) -> usize {
+        self.hits.iter().filter_map(|h| h.result.as_ref().ok()).map(|r| r.rows.len()).sum()
+    }
+}
+
+/// 单元格值是否包含关键字
+fn cell_matches(value: &str, term: &str, case_sensitive: bool) -> bool {
+    if case_sensitive {
+        value.contains(term)
+    } else {
+        value.to_lowercase().contains(&term.to_lowercase())
+    }
+}
+
+/// 截断过长的单元格值
+fn truncate_value(value: &str) -> String {
+    if value.chars().count() > MAX_VALUE_CHARS {
+        let head: String = value.chars().take(MAX_VALUE_CHARS).collect();
+        format!("{}…", head)
+    } else {
+        value.to_string()
+    }
+}
+
+// ============================================================================
+// 窗口 UI
+// ============================================================================
+
+/// 全库数据搜索窗口
+pub struct DataSearchDialog;
+
+impl DataSearchDialog {
+    /// 显示窗口
+    pub fn show(ctx: &egui::Context, state: &mut DataSearchState) -> DataSearchResult {
+        if !state.show {
+            return DataSearchResult::None;
+        }
+
+        let mut result = DataSearchResult::None;
+
+        if !keyboard::has_text_focus(ctx) && keyboard::handle_close_keys(ctx) {
+            let searching = state.searching;
+            state.close();
+            return if searching { DataSearchResult::Cancel } else { DataSearchResult::None };
+        }
+
+        let mut open = true;
+        egui::Window::new(t("data_search.title"))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(true)
+            .default_size([760.0, 520.0])
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(t("data_search.keyword"));
+                    let response = ui.add(
+                        egui::TextEdit::singleline(&mut state.term)
+                            .hint_text(t("data_search.keyword_hint"))
+                            .desired_width(220.0),
+                    );
+                    let enter = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                    ui.label(t("data_search.column_filter"));
+                    ui.add(
+                        egui::TextEdit::singleline(&mut state.column_filter)
+                            .hint_text(t("data_search.column_filter_hint"))
+                            .desired_width(120.0),
+                    );
+                    ui.checkbox(&mut state.case_sensitive, t("data_search.case_sensitive"));
+
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if state.searching {
+                            if ui.button(t("benchmark.stop")).clicked() {
+                                result = DataSearchResult::Cancel;
+                            }
+                            ui.spinner();
+                        } else {
+                            let can_search =
+                                !state.term.is_empty() && state.tables.iter().any(|t| t.selected);
+                            if ui.add_enabled(can_search, egui::Button::new(t("data_search.search"))).clicked()
+                                || (enter && can_search)
+                            {
+                                result = DataSearchResult::Search;
+                            }
+                        }
+                    });
+                });
+
+                ui.separator();
+
+                egui::SidePanel::left("data_search_tables")
+                    .resizable(true)
+                    .default_width(200.0)
+                    .show_inside(ui, |ui| Self::show_tables(ui, state));
+
+                egui::CentralPanel::default().show_inside(ui, |ui| {
+                    if let Some(open) = Self::show_hits(ui, state) {
+                        result = open;
+                    }
+                });
+            });
+
+        if !open {
+            let searching = state.searching;
+            state.close();
+            if searching {
+                result = DataSearchResult::Cancel;
+            }
+        }
+
+        result
+    }
+
+    /// 表选择列表
+    fn show_tables(ui: &mut egui::Ui, state: &mut DataSearchState) {
+        ui.horizontal(|ui| {
+            let selected = state.tables.iter().filter(|t| t.selected).count();
+            ui.label(tf("data_search.tables", &[&selected.to_string(), &state.tables.len().to_string()]));
+            if ui.small_button(t("data_search.select_all")).clicked() {
+                state.tables.iter_mut().for_each(|t| t.selected = true);
+            }
+            if ui.small_button(t("data_search.clear")).clicked() {
+                state.tables.iter_mut().for_each(|t| t.selected = false);
+            }
+        });
+        ui.add(
+            egui::TextEdit::singleline(&mut state.table_filter)
+                .hint_text(t("data_search.filter_tables"))
+                .desired_width(f32::INFINITY),
+        );
+        let filter = state.table_filter.to_lowercase();
+        egui::ScrollArea::vertical()
+            .id_salt("data_search_tables_scroll")
+            .show(ui, |ui| {
+                for table in state
+                    .tables
+                    .iter_mut()
+                    .filter(|t| filter.is_empty() || t.name.to_lowercase().contains(&filter))
+                {
+                    ui.checkbox(&mut table.selected, &table.name);
+                }
+            });
+    }
+
+    /// 按表分组的命中结果，点击行时返回打开结果
+    fn show_hits(ui: &mut egui::Ui, state: &DataSearchState) -> Option<DataSearchResult> {
+        if state.total > 0 {
+            let mut summary = tf(
+                "data_search.summary",
+                &[
+                    &state.completed.to_string(),
+                    &state.total.to_string(),
+                    &state.hits.iter().filter(|h| h.result.is_ok()).count().to_string(),
+                    &state.hit_rows().to_string(),
+                ],
+            );
+            if state.skipped > 0 {
+                summary.push_str(&tf("data_search.skipped", &[&state.skipped.to_string()]));
+            }
+            ui.label(RichText::new(summary).color(Color32::GRAY));
+            ui.separator();
+        }
+
+        if state.hits.is_empty() {
+            if !state.searching && state.total > 0 {
+                ui.label(RichText::new(t("data_search.no_hits")).color(Color32::GRAY));
+            }
+            return None;
+        }
+
+        let mut open = None;
+        egui::ScrollArea::vertical()
+            .id_salt("data_search_hits_scroll")
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                for hit in &state.hits {
+                    let header = match &hit.result {
+                        Ok(r) if r.truncated || r.rows.len() >= DATA_SEARCH_ROW_LIMIT => {
+                            tf("data_search.hit_rows_truncated", &[&hit.table, &r.rows.len().to_string()])
+                        }
+                        Ok(r) => tf("data_search.hit_rows", &[&hit.table, &r.rows.len().to_string()]),
+                        Err(_) => tf("data_search.hit_error", &[&hit.table]),
+                    };
+                    egui::CollapsingHeader::new(header)
+                        .id_salt(("data_search_hit", &hit.table))
+                        .default_open(true)
+                        .show(ui, |ui| match &hit.result {
+                            Err(e) => {
+                                ui.label(RichText::new(e).color(Color32::from_rgb(255, 100, 100)));
+                            }
+                            Ok(r) => {
+                                if ui.small_button(t("data_search.open")).clicked() {
+                                    open = Some(DataSearchResult::Open {
+                                        table: hit.table.clone(),
+                                        sql: hit.sql.clone(),
+                                    });
+                                }
+                                for row in &r.rows {
+                                    let cells: Vec<String> = r
+                                        .columns
+                                        .iter()
+                                        .zip(row)
+                                        .filter(|(_, v)| cell_matches(v, &state.searched_term, state.case_sensitive))
+                                        .map(|(c, v)| format!("{}: {}", c, truncate_value(v)))
+                                        .collect();
+                                    let text = if cells.is_empty() { t("data_search.matched").to_string() } else { cells.join("  ·  ") };
+                                    if ui
+                                        .add(egui::Label::new(RichText::new(text).monospace()).sense(egui::Sense::click()))
+                                        .on_hover_text(t("data_search.open_hint"))
+                                        .clicked()
+                                    {
+                                        open = Some(DataSearchResult::Open {
+                                            table: hit.table.clone(),
+                                            sql: hit.sql.clone(),
+                                        });
+                                    }
+                                }
+                            }
+                        });
+                }
+            });
+        open
+    }
+}
@@ -0,0 +1,460 @@
+//! 测试数据生成对话框
+//!
+//! 选择表后为每一列配置生成器（序列、随机数、姓名、邮箱、日期范围、正则模式、候选列表），
+//! 预览生成结果并指定行数，由调用方按批次插入。支持 Helix 风格的键盘导航。
+
+use super::keyboard::{self, DialogAction};
+use crate::core::{generate_rows, GenValue, Rng, ValueGenerator};
+use crate::database::{ColumnInfo, DatabaseType};
+use egui::{self, Color32, RichText, TextEdit};
+
+/// 每条 INSERT 语句包含的行数
+pub const DATAGEN_BATCH_SIZE: usize = 500;
+/// 单次最多生成的行数
+pub const DATAGEN_MAX_ROWS: usize = 1_000_000;
+/// 预览行数
+const PREVIEW_ROWS: usize = 5;
+/// 预览使用的固定种子（配置不变时预览保持稳定）
+const PREVIEW_SEED: u64 = 42;
+
+// ============================================================================
+// SQL 生成
+// ============================================================================
+
+/// 生成多行 INSERT 语句（Oracle 使用 INSERT ALL），`schema` 为调用方确认过的 schema
+pub fn insert_rows_sql(
+    db_type: DatabaseType,
+    schema: Option<&str>,
+    table: &str,
+    columns: &[String],
+    rows: &[Vec<GenValue>],
+) -> String {
+    let table = db_type.quote_table(schema, table);
+    let columns = columns.iter().map(|c| db_type.quote_identifier(c)).collect::<Vec<_>>().join(", ");
+    let backslash_escapes = db_type == DatabaseType::MySQL;
+    let values = rows.iter().map(|row| {
+        let values: Vec<String> = row.iter().map(|v| v.to_sql(backslash_escapes)).collect();
+        format!("({})", values.join(", "))
+    });
+
+    if db_type == DatabaseType::Oracle {
+        let intos: Vec<String> = values.map(|v| format!("  INTO {} ({}) VALUES {}", table, columns, v)).collect();
+        format!("INSERT ALL\n{}\nSELECT 1 FROM DUAL", intos.join("\n"))
+    } else {
+        format!("INSERT INTO {} ({}) VALUES\n{};", table, columns, values.collect::<Vec<_>>().join(",\n"))
+    }
+}
+
+// ============================================================================
+// 对话框结果
+// ============================================================================
+
+/// 数据生成计划
+#[derive(Debug, Clone)]
+pub struct DataGenPlan {
+    /// 目标表
+    pub table: String,
+    /// 要插入的列
+    pub columns: Vec<String>,
+    /// 与列一一对应的生成器
+    pub generators: Vec<ValueGenerator>,
+    /// 生成行数
+    pub row_count: usize,
+}
+
+/// 数据生成对话框的结果
+pub enum DataGenResult {
+    /// 无操作
+    None,
+    /// 需要加载表的列信息
+    LoadColumns(String),
+    /// 用户确认生成
+    Generate(DataGenPlan),
+    /// 用户取消
+    Cancelled,
+}
+
+// ============================================================================
+// 对话框状态
+// ============================================================================
+
+/// 列及其生成器
+#[derive(Debug, Clone)]
+pub struct DataGenColumn {
+    pub info: ColumnInfo,
+    pub generator: ValueGenerator,
+}
+
+/// 数据生成对话框状态
+pub struct DataGenState {
+    /// 是否显示对话框
+    pub show: bool,
+    /// 当前数据库类型
+    pub db_type: DatabaseType,
+    /// 可选的表
+    pub tables: Vec<String>,
+    /// 目标表
+    pub table: String,
+    /// 是否正在加载列信息
+    pub loading: bool,
+    /// 列配置
+    pub columns: Vec<DataGenColumn>,
+    /// 生成行数
+    pub row_count: usize,
+    /// 错误信息
+    pub error: Option<String>,
+}
+
+impl Default for DataGenState {
+    fn default() -> Self {
+        Self {
+            show: false,
+            db_type: DatabaseType::default(),
+            tables: Vec::new(),
+            table: String::new(),
+            loading: false,
+            columns: Vec::new(),
+            row_count: 100,
+            error: None,
+        }
+    }
+}
+
+impl DataGenState {
+    /// 创建新的对话框状态
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 打开对话框；指定表时进入加载状态（列信息由调用方异步加载）
+    pub fn open(&mut self, db_type: DatabaseType, tables: Vec<String>, table: Option<String>) {
+        self.show = true;
+        self.db_type = db_type;
+        self.tables = tables;
+        self.columns.clear();
+        self.error = None;
+        match table {
+            Some(table) => self.select_table(table),
+            None => {
+                self.table.clear();
+                self.loading = false;
+            }
+        }
+    }
+
+    /// 关闭对话框
+    pub fn close(&mut self) {
+        self.show = false;
+        self.loading = false;
+        self.columns.clear();
+        self.error = None;
+    }
+
+    /// 切换目标表并进入加载状态
+    pub fn select_table(&mut self, table: String) {
+        self.table = table;
+        self.columns.clear();
+        self.error = None;
+        self.loading = true;
+    }
+
+    /// 设置列信息并按列名和类型推荐生成器（忽略已切换到其他表之后返回的旧结果）
+    pub fn set_columns(&mut self, table: &str, result: Result<Vec<ColumnInfo>, String>) {
+        if !self.show || table != self.table {
+            return;
+        }
+        self.loading = false;
+        match result {
+            Ok(columns) => {
+                self.columns = columns
+                    .into_iter()
+                    .map(|info| {
+                        let generator = ValueGenerator::suggest(
+                            &info.name,
+                            &info.data_type,
+                            info.is_primary_key,
+                            info.default_value.as_deref(),
+                        );
+                        DataGenColumn { info, generator }
+                    })
+                    .collect();
+                self.error = None;
+            }
+            Err(e) => {
+                self.columns.clear();
+                self.error = Some(e);
+            }
+        }
+    }
+
+    /// 校验配置并生成计划
+    pub fn plan(&self) -> Result<DataGenPlan, String> {
+        if self.table.is_empty() {
+            return Err("请选择表".to_string());
+        }
+        if self.row_count == 0 || self.row_count > DATAGEN_MAX_ROWS {
+            return Err(format!("行数应在 1 到 {} 之间", DATAGEN_MAX_ROWS));
+        }
+        let mut columns = Vec::new();
+        let mut generators = Vec::new();
+        for column in &self.columns {
+            if column.generator == ValueGenerator::Skip {
+                continue;
+            }
+            column
+                .generator
+                .compile()
+                .map_err(|e| format!("列 {}: {}", column.info.name, e))?;
+            columns.push(column.info.name.clone());
+            generators.push(column.generator.clone());
+        }
+        if columns.is_empty() {
+            return Err("至少需要为一列配置生成器".to_string());
+        }
+        Ok(DataGenPlan {
+            table: self.table.clone(),
+            columns,
+            generators,
+            row_count: self.row_count,
+        })
+    }
+
+    /// 生成预览行
+    pub fn preview(&self) -> Result<(Vec<String>, Vec<Vec<GenValue>>), String> {
+        let plan = self.plan()?;
+        let mut compiled = plan
+            .generators
+            .iter()
+            .map(|g| g.compile())
+            .collect::<Result<Vec<_>, _>>()?;
+        let rows = generate_rows(&mut compiled, &mut Rng::new(PREVIEW_SEED), PREVIEW_ROWS.min(plan.row_count));
+        Ok((plan.columns, rows))
+    }
+}
+
+// ============================================================================
+// 对话框 UI
+// ============================================================================
+
+/// 数据生成对话框
+pub struct DataGenDialog;
+
+impl DataGenDialog {
+    /// 显示对话框
+    pub fn show(ctx: &egui::Context, state: &mut DataGenState) -> DataGenResult {
+        if !state.show {
+            return DataGenResult::None;
+        }
+
+        let mut result = DataGenResult::None;
+
+        // 键盘快捷键处理
+        if !keyboard::has_text_focus(ctx) {
+            if keyboard::handle_close_keys(ctx) {
+                state.close();
+                return DataGenResult::Cancelled;
+            }
+
+            if let DialogAction::Confirm = keyboard::handle_dialog_keys(ctx) {
+                result = Self::confirm(state);
+            }
+        }
+
+        let mut open = true;
+        egui::Window::new("🎲 生成测试数据")
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(true)
+            .default_size([720.0, 480.0])
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("表:");
+                    let mut selected = None;
+                    egui::ComboBox::from_id_salt("datagen_table")
+                        .selected_text(if state.table.is_empty() { "选择表" } else { state.table.as_str() })
+                        .width(220.0)
+                        .show_ui(ui, |ui| {
+                            for table in &state.tables {
+                                if ui.selectable_label(*table == state.table, table).clicked() {
+                                    selected = Some(table.clone());
+                                }
+                            }
+                        });
+                    if let Some(table) = selected
+                        && table != state.table
+                    {
+                        state.select_table(table.clone());
+                        result = DataGenResult::LoadColumns(table);
+                    }
+
+                    ui.separator();
+                    ui.label("行数:");
+                    ui.add(egui::DragValue::new(&mut state.row_count).range(1..=DATAGEN_MAX_ROWS).speed(10));
+
+                    if state.loading {
+                        ui.spinner();
+                    }
+                });
+
+                ui.separator();
+
+                if let Some(err) = &state.error {
+                    ui.label(RichText::new(err).color(Color32::from_rgb(255, 100, 100)));
+                    ui.add_space(4.0);
+                }
+
+                egui::ScrollArea::vertical()
+                    .id_salt("datagen_columns")
+                    .max_height(260.0)
+                    .show(ui, |ui| {
+                        egui::Grid::new("datagen_columns_grid")
+                            .num_columns(4)
+                            .striped(true)
+                            .spacing([12.0, 6.0])
+                            .show(ui, |ui| {
+                                ui.label(RichText::new("列").strong());
+                                ui.label(RichText::new("类型").strong());
+                                ui.label(RichText::new("生成器").strong());
+                                ui.label(RichText::new("参数").strong());
+                                ui.end_row();
+
+                                for (i, column) in state.columns.iter_mut().enumerate() {
+                                    let name = if column.info.is_primary_key {
+                                        format!("🔑 {}", column.info.name)
+                                    } else {
+                                        column.info.name.clone()
+                                    };
+                                    ui.label(name);
+                                    ui.label(RichText::new(&column.info.data_type).color(Color32::GRAY));
+                                    egui::ComboBox::from_id_salt(("datagen_kind", i))
+                                        .selected_text(column.generator.display_name())
+                                        .width(120.0)
+                                        .show_ui(ui, |ui| {
+                                            for kind in ValueGenerator::kinds() {
+                                                let same = std::mem::discriminant(&kind)
+                                                    == std::mem::discriminant(&column.generator);
+                                                if ui.selectable_label(same, kind.display_name()).clicked() && !same {
+                                                    column.generator = kind;
+                                                }
+                                            }
+                                        });
+                                    ui.horizontal(|ui| Self::show_params(ui, &mut column.generator));
+                                    ui.end_row();
+                                }
+                            });
+                    });
+
+                // 预览
+                if !state.columns.is_empty() {
+                    ui.separator();
+                    ui.label(RichText::new("预览").strong());
+                    match state.preview() {
+                        Ok((columns, rows)) => {
+                            egui::ScrollArea::horizontal().id_salt("datagen_preview").show(ui, |ui| {
+                                egui::Grid::new("datagen_preview_grid")
+                                    .num_columns(columns.len())
+                                    .striped(true)
+                                    .show(ui, |ui| {
+                                        for column in &columns {
+                                            ui.label(RichText::new(column).small().strong());
+                                        }
+                                        ui.end_row();
+                                        for row in &rows {
+                                            for value in row {
+                                                let text = RichText::new(value.display()).small().monospace();
+                                                ui.label(if *value == GenValue::Null {
+                                                    text.color(Color32::GRAY)
+                                                } else {
+                                                    text
+                                                });
+                                            }
+                                            ui.end_row();
+                                        }
+                                    });
+                            });
+                        }
+                        Err(e) => {
+                            ui.label(RichText::new(e).small().color(Color32::from_rgb(230, 180, 80)));
+                        }
+                    }
+                }
+
+                ui.add_space(8.0);
+                ui.horizontal(|ui| {
+                    let ready = !state.loading && !state.columns.is_empty();
+                    if ui
+                        .add_enabled(ready, egui::Button::new(format!("🎲 生成 {} 行 [Enter]", state.row_count)))
+                        .clicked()
+                    {
+                        result = Self::confirm(state);
+                    }
+                    if ui.button("取消 [Esc]").clicked() {
+                        state.close();
+                        result = DataGenResult::Cancelled;
+                    }
+                });
+            });
+
+        if !open {
+            state.close();
+            result = DataGenResult::Cancelled;
+        }
+
+        result
+    }
+
+    /// 确认生成：校验通过时关闭对话框并返回计划
+    fn confirm(state: &mut DataGenState) -> DataGenResult {
+        if state.loading || state.columns.is_empty() {
+            return DataGenResult::None;
+        }
+        match state.plan() {
+            Ok(plan) => {
+                state.close();
+                DataGenResult::Generate(plan)
+            }
+            Err(e) => {
+                state.error = Some(e);
+                DataGenResult::None
+            }
+        }
+    }
+
+    /// 生成器参数编辑
+    fn show_params(ui: &mut egui::Ui, generator: &mut ValueGenerator) {
+        match generator {
+            ValueGenerator::Sequence { start, step } => {
+                ui.label("起始");
+                ui.add(egui::DragValue::new(start));
+                ui.label("步长");
+                ui.add(egui::DragValue::new(step));
+            }
+            ValueGenerator::Int { min, max } => {
+                ui.add(egui::DragValue::new(min));
+                ui.label("~");
+                ui.add(egui::DragValue::new(max));
+            }
+            ValueGenerator::Float { min, max, decimals } => {
+                ui.add(egui::DragValue::new(min).speed(0.1));
+                ui.label("~");
+                ui.add(egui::DragValue::new(max).speed(0.1));
+                ui.label("小数位");
+                ui.add(egui::DragValue::new(decimals).range(0..=10));
+            }
+            ValueGenerator::Date { from, to, with_time } => {
+                ui.add(TextEdit::singleline(from).desired_width(80.0));
+                ui.label("~");
+                ui.add(TextEdit::singleline(to).desired_width(80.0));
+                ui.checkbox(with_time, "含时间");
+            }
+            ValueGenerator::Pattern(pattern) => {
+                ui.add(TextEdit::singleline(pattern).desired_width(180.0).hint_text("[A-Z]{3}-\\d{4}"));
+            }
+            ValueGenerator::List(list) => {
+                ui.add(TextEdit::singleline(list).desired_width(180.0).hint_text("逗号分隔"));
+            }
+            ValueGenerator::Skip | ValueGenerator::Null | ValueGenerator::Name | ValueGenerator::Email => {}
+        }
+    }
+}
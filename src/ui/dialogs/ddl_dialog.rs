@@ -4,6 +4,7 @@
 //! 支持 Helix 风格的键盘导航。
 
 use super::keyboard::{self, DialogAction, ListNavigation};
+use crate::core::{t, tf};
 use crate::database::DatabaseType;
 use egui::{self, Color32, Key, RichText, TextEdit};
 
@@ -83,6 +84,11 @@ impl ColumnType {
             DatabaseType::SQLite => self.to_sqlite_sql(),
             DatabaseType::MySQL => self.to_mysql_sql(),
             DatabaseType::PostgreSQL => self.to_postgres_sql(),
+            DatabaseType::DuckDB => self.to_duckdb_sql(),
+            DatabaseType::Oracle => self.to_oracle_sql(),
+            DatabaseType::ClickHouse => self.to_clickhouse_sql(),
+            // Redis/MongoDB 不会打开建表对话框，按 ANSI 处理以保持完整
+            DatabaseType::Odbc | DatabaseType::Redis | DatabaseType::MongoDB => self.to_ansi_sql(),
         }
     }
 
@@ -151,6 +157,99 @@ impl ColumnType {
         }
     }
 
+    fn to_duckdb_sql(&self) -> String {
+        match self {
+            Self::Integer => "INTEGER".to_string(),
+            Self::BigInt => "BIGINT".to_string(),
+            Self::SmallInt => "SMALLINT".to_string(),
+            Self::TinyInt => "TINYINT".to_string(),
+            Self::Float => "FLOAT".to_string(),
+            Self::Double => "DOUBLE".to_string(),
+            Self::Decimal { precision, scale } => format!("DECIMAL({},{})", precision, scale),
+            Self::Varchar(_) | Self::Char(_) | Self::Text => "VARCHAR".to_string(),
+            Self::Date => "DATE".to_string(),
+            Self::Time => "TIME".to_string(),
+            Self::DateTime => "TIMESTAMP".to_string(),
+            Self::Timestamp => "TIMESTAMPTZ".to_string(),
+            Self::Blob | Self::Binary(_) => "BLOB".to_string(),
+            Self::Boolean => "BOOLEAN".to_string(),
+            Self::Json => "JSON".to_string(),
+            Self::Uuid => "UUID".to_string(),
+            Self::Custom(s) => s.clone(),
+        }
+    }
+
+    fn to_oracle_sql(&self) -> String {
+        match self {
+            Self::Integer => "NUMBER(10)".to_string(),
+            Self::BigInt => "NUMBER(19)".to_string(),
+            Self::SmallInt => "NUMBER(5)".to_string(),
+            Self::TinyInt => "NUMBER(3)".to_string(),
+            Self::Float => "BINARY_FLOAT".to_string(),
+            Self::Double => "BINARY_DOUBLE".to_string(),
+            Self::Decimal { precision, scale } => format!("NUMBER({},{})", precision, scale),
+            Self::Varchar(len) => format!("VARCHAR2({})", len),
+            Self::Char(len) => format!("CHAR({})", len),
+            Self::Text | Self::Json => "CLOB".to_string(),
+            Self::Date => "DATE".to_string(),
+            // Oracle 没有独立的 TIME 类型
+            Self::Time | Self::DateTime => "TIMESTAMP".to_string(),
+            Self::Timestamp => "TIMESTAMP WITH TIME ZONE".to_string(),
+            Self::Blob => "BLOB".to_string(),
+            Self::Binary(len) => format!("RAW({})", len),
+            Self::Boolean => "NUMBER(1)".to_string(),
+            Self::Uuid => "RAW(16)".to_string(),
+            Self::Custom(s) => s.clone(),
+        }
+    }
+
+    fn to_clickhouse_sql(&self) -> String {
+        match self {
+            Self::Integer => "Int32".to_string(),
+            Self::BigInt => "Int64".to_string(),
+            Self::SmallInt => "Int16".to_string(),
+            Self::TinyInt => "Int8".to_string(),
+            Self::Float => "Float32".to_string(),
+            Self::Double => "Float64".to_string(),
+            Self::Decimal { precision, scale } => format!("Decimal({},{})", precision, scale),
+            // 字符串不限长度，定长类型使用 FixedString
+            Self::Varchar(_) | Self::Text | Self::Blob | Self::Json => "String".to_string(),
+            Self::Char(len) | Self::Binary(len) => format!("FixedString({})", len),
+            Self::Date => "Date".to_string(),
+            // 旧版本没有独立的 TIME 类型，按字符串保存
+            Self::Time => "String".to_string(),
+            Self::DateTime => "DateTime".to_string(),
+            Self::Timestamp => "DateTime64(3)".to_string(),
+            Self::Boolean => "Bool".to_string(),
+            Self::Uuid => "UUID".to_string(),
+            Self::Custom(s) => s.clone(),
+        }
+    }
+
+    /// SQL 标准类型（用于 ODBC 等方言未知的数据源）
+    fn to_ansi_sql(&self) -> String {
+        match self {
+            Self::Integer => "INTEGER".to_string(),
+            Self::BigInt => "BIGINT".to_string(),
+            Self::SmallInt | Self::TinyInt => "SMALLINT".to_string(),
+            Self::Float => "REAL".to_string(),
+            Self::Double => "DOUBLE PRECISION".to_string(),
+            Self::Decimal { precision, scale } => format!("DECIMAL({},{})", precision, scale),
+            Self::Varchar(len) => format!("VARCHAR({})", len),
+            Self::Char(len) => format!("CHAR({})", len),
+            Self::Text | Self::Json => "CLOB".to_string(),
+            Self::Date => "DATE".to_string(),
+            Self::Time => "TIME".to_string(),
+            Self::DateTime => "TIMESTAMP".to_string(),
+            Self::Timestamp => "TIMESTAMP WITH TIME ZONE".to_string(),
+            Self::Blob => "BLOB".to_string(),
+            Self::Binary(len) => format!("VARBINARY({})", len),
+            Self::Boolean => "BOOLEAN".to_string(),
+            Self::Uuid => "CHAR(36)".to_string(),
+            Self::Custom(s) => s.clone(),
+        }
+    }
+
     /// 常用类型列表
     pub fn common_types() -> Vec<Self> {
         vec![
@@ -213,11 +312,25 @@ impl Default for ColumnDefinition {
 impl ColumnDefinition {
     /// 生成列的 SQL 定义
     pub fn to_sql(&self, db_type: &DatabaseType) -> String {
+        if matches!(db_type, DatabaseType::ClickHouse) {
+            return self.to_clickhouse_sql();
+        }
+
         let mut parts = vec![
-            quote_identifier(&self.name, db_type),
+            db_type.quote_identifier(&self.name),
             self.data_type.to_sql(db_type),
         ];
 
+        // Oracle/标准 SQL 要求 DEFAULT/IDENTITY 紧跟在类型之后、约束之前
+        let is_standard_identity = matches!(db_type, DatabaseType::Oracle | DatabaseType::Odbc);
+        if is_standard_identity {
+            if self.auto_increment {
+                parts.push("GENERATED BY DEFAULT AS IDENTITY".to_string());
+            } else if !self.default_value.is_empty() {
+                parts.push(format!("DEFAULT {}", self.default_value));
+            }
+        }
+
         if self.primary_key {
             parts.push("PRIMARY KEY".to_string());
         }
@@ -234,6 +347,13 @@ impl ColumnDefinition {
                 DatabaseType::PostgreSQL => {
                     // PostgreSQL 使用 SERIAL 类型，这里假设已经设置了正确的类型
                 }
+                DatabaseType::DuckDB => {
+                    // DuckDB 没有自增关键字，需要使用 SEQUENCE + DEFAULT nextval()
+                }
+                DatabaseType::Oracle | DatabaseType::Odbc => {
+                    // 已在类型后添加 IDENTITY 子句
+                }
+                DatabaseType::ClickHouse | DatabaseType::Redis | DatabaseType::MongoDB => {}
             }
         }
 
@@ -245,7 +365,7 @@ impl ColumnDefinition {
             parts.push("UNIQUE".to_string());
         }
 
-        if !self.default_value.is_empty() {
+        if !self.default_value.is_empty() && !is_standard_identity {
             parts.push(format!("DEFAULT {}", self.default_value));
         }
 
@@ -255,12 +375,81 @@ impl ColumnDefinition {
 
         parts.join(" ")
     }
+
+    /// ClickHouse 列定义：可空列使用 Nullable 类型，主键写在表的 ORDER BY 中，没有自增和唯一约束
+    fn to_clickhouse_sql(&self) -> String {
+        let data_type = self.data_type.to_sql(&DatabaseType::ClickHouse);
+        let mut parts = vec![DatabaseType::ClickHouse.quote_identifier(&self.name)];
+        if self.nullable && !self.primary_key {
+            parts.push(format!("Nullable({})", data_type));
+        } else {
+            parts.push(data_type);
+        }
+        if !self.default_value.is_empty() {
+            parts.push(format!("DEFAULT {}", self.default_value));
+        }
+        if !self.comment.is_empty() {
+            parts.push(format!("COMMENT '{}'", self.comment.replace('\'', "''")));
+        }
+        parts.join(" ")
+    }
 }
 
 // ============================================================================
 // 表定义
 // ============================================================================
 
+/// 外键定义
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ForeignKeyDefinition {
+    /// 本表的列
+    pub column: String,
+    /// 引用的表
+    pub ref_table: String,
+    /// 引用的列
+    pub ref_column: String,
+}
+
+impl ForeignKeyDefinition {
+    /// 约束名：`fk_<表名>_<列名>`
+    pub fn constraint_name(&self, table: &str) -> String {
+        format!("fk_{}_{}", table, self.column)
+    }
+
+    /// 生成建表语句中的表级约束
+    pub fn to_sql(&self, db_type: &DatabaseType) -> String {
+        format!(
+            "FOREIGN KEY ({}) REFERENCES {} ({})",
+            db_type.quote_identifier(&self.column),
+            db_type.quote_identifier(&self.ref_table),
+            db_type.quote_identifier(&self.ref_column)
+        )
+    }
+
+    /// 生成为已有表添加约束的 ALTER TABLE 语句；SQLite/DuckDB 不支持时返回 None
+    pub fn to_alter_sql(&self, table: &str, db_type: &DatabaseType) -> Option<String> {
+        if !supports_alter_foreign_key(db_type) {
+            return None;
+        }
+        Some(format!(
+            "ALTER TABLE {} ADD CONSTRAINT {} {};",
+            db_type.quote_identifier(table),
+            db_type.quote_identifier(&self.constraint_name(table)),
+            self.to_sql(db_type)
+        ))
+    }
+}
+
+/// 是否支持外键约束
+pub fn supports_foreign_keys(db_type: &DatabaseType) -> bool {
+    !matches!(db_type, DatabaseType::ClickHouse | DatabaseType::Redis | DatabaseType::MongoDB)
+}
+
+/// 是否支持用 ALTER TABLE 添加外键（SQLite/DuckDB 只能在建表时声明）
+pub fn supports_alter_foreign_key(db_type: &DatabaseType) -> bool {
+    supports_foreign_keys(db_type) && !matches!(db_type, DatabaseType::SQLite | DatabaseType::DuckDB)
+}
+
 /// 表定义
 #[derive(Debug, Clone, Default)]
 pub struct TableDefinition {
@@ -270,6 +459,8 @@ pub struct TableDefinition {
     pub columns: Vec<ColumnDefinition>,
     /// 表注释
     pub comment: String,
+    /// 外键约束（ClickHouse 会忽略）
+    pub foreign_keys: Vec<ForeignKeyDefinition>,
     /// 数据库类型
     pub db_type: DatabaseType,
 }
@@ -289,12 +480,15 @@ impl TableDefinition {
             return String::new();
         }
 
-        let table_name = quote_identifier(&self.name, &self.db_type);
-        let columns: Vec<String> = self
+        let table_name = self.db_type.quote_identifier(&self.name);
+        let mut columns: Vec<String> = self
             .columns
             .iter()
             .map(|c| format!("    {}", c.to_sql(&self.db_type)))
             .collect();
+        if supports_foreign_keys(&self.db_type) {
+            columns.extend(self.foreign_keys.iter().map(|fk| format!("    {}", fk.to_sql(&self.db_type))));
+        }
 
         let mut sql = format!(
             "CREATE TABLE {} (\n{}\n)",
@@ -302,6 +496,25 @@ impl TableDefinition {
             columns.join(",\n")
         );
 
+        // ClickHouse 表需要指定引擎，主键列作为排序键
+        if matches!(self.db_type, DatabaseType::ClickHouse) {
+            let keys: Vec<String> = self
+                .columns
+                .iter()
+                .filter(|c| c.primary_key)
+                .map(|c| self.db_type.quote_identifier(&c.name))
+                .collect();
+            let order_by = match keys.len() {
+                0 => "tuple()".to_string(),
+                1 => keys[0].clone(),
+                _ => format!("({})", keys.join(", ")),
+            };
+            sql.push_str(&format!("\nENGINE = MergeTree\nORDER BY {}", order_by));
+            if !self.comment.is_empty() {
+                sql.push_str(&format!("\nCOMMENT '{}'", self.comment.replace('\'', "''")));
+            }
+        }
+
         // MySQL 表注释
         if !self.comment.is_empty() && matches!(self.db_type, DatabaseType::MySQL) {
             sql.push_str(&format!(" COMMENT='{}'", self.comment.replace('\'', "''")));
@@ -309,8 +522,8 @@ impl TableDefinition {
 
         sql.push(';');
 
-        // PostgreSQL 表注释需要单独的语句
-        if !self.comment.is_empty() && matches!(self.db_type, DatabaseType::PostgreSQL) {
+        // PostgreSQL/Oracle 表注释需要单独的语句
+        if !self.comment.is_empty() && matches!(self.db_type, DatabaseType::PostgreSQL | DatabaseType::Oracle) {
             sql.push_str(&format!(
                 "\nCOMMENT ON TABLE {} IS '{}';",
                 table_name,
@@ -324,28 +537,34 @@ impl TableDefinition {
     /// 验证表定义
     pub fn validate(&self) -> Result<(), String> {
         if self.name.is_empty() {
-            return Err("表名不能为空".to_string());
+            return Err(t("ddl.table_name_required").to_string());
         }
 
         if self.columns.is_empty() {
-            return Err("至少需要一个列".to_string());
+            return Err(t("ddl.columns_required").to_string());
         }
 
         // 检查列名是否有重复
         let mut names = std::collections::HashSet::new();
         for col in &self.columns {
             if col.name.is_empty() {
-                return Err("列名不能为空".to_string());
+                return Err(t("ddl.column_name_required").to_string());
             }
             if !names.insert(col.name.to_lowercase()) {
-                return Err(format!("列名 '{}' 重复", col.name));
+                return Err(tf("ddl.duplicate_column", &[&col.name]));
             }
         }
 
         // 检查主键数量
         let pk_count = self.columns.iter().filter(|c| c.primary_key).count();
         if pk_count > 1 {
-            return Err("只能有一个主键列".to_string());
+            return Err(t("ddl.single_primary_key").to_string());
+        }
+
+        for fk in &self.foreign_keys {
+            if !self.columns.iter().any(|c| c.name == fk.column) {
+                return Err(tf("ddl.foreign_key_column_missing", &[&fk.column]));
+            }
         }
 
         Ok(())
@@ -372,6 +591,10 @@ pub struct DdlDialogState {
     pub generated_sql: String,
     /// 当前选中的列索引（用于键盘导航）
     pub selected_column: usize,
+    /// 是否在 ER 图中设计表（确认后放到画布上而不是执行）
+    pub design_mode: bool,
+    /// 正在编辑的设计表原名（新建时为空）
+    pub original_name: Option<String>,
 }
 
 #[allow(dead_code)] // 公开 API，供外部使用
@@ -399,12 +622,48 @@ impl DdlDialogState {
         self.selected_column = 0;
     }
 
+    /// 在 ER 图中设计新表
+    pub fn open_design_table(&mut self, db_type: DatabaseType) {
+        self.open_create_table(db_type);
+        self.design_mode = true;
+        self.original_name = None;
+    }
+
+    /// 编辑 ER 图中的设计表
+    pub fn open_edit_design(&mut self, table: TableDefinition) {
+        self.original_name = Some(table.name.clone());
+        self.table = table;
+        self.design_mode = true;
+        self.show = true;
+        self.error = None;
+        self.generated_sql.clear();
+        self.selected_column = 0;
+    }
+
     /// 关闭对话框
     pub fn close(&mut self) {
         self.show = false;
         self.table = TableDefinition::default();
         self.error = None;
         self.generated_sql.clear();
+        self.design_mode = false;
+        self.original_name = None;
+    }
+
+    /// 确认对话框：校验表定义并生成结果
+    fn confirm(&mut self) -> Option<DdlDialogResult> {
+        if let Err(e) = self.table.validate() {
+            self.error = Some(e);
+            return None;
+        }
+        Some(if self.design_mode {
+            DdlDialogResult::Design {
+                original_name: self.original_name.clone(),
+                table: self.table.clone(),
+            }
+        } else {
+            DdlDialogResult::Create(self.table.to_create_sql())
+        })
     }
 }
 
@@ -412,6 +671,17 @@ impl DdlDialogState {
 // DDL 对话框 UI
 // ============================================================================
 
+/// DDL 对话框的结果
+pub enum DdlDialogResult {
+    /// 执行建表语句
+    Create(String),
+    /// 将设计的表放到 ER 图上（编辑时带原表名）
+    Design {
+        original_name: Option<String>,
+        table: TableDefinition,
+    },
+}
+
 /// DDL 对话框
 pub struct DdlDialog;
 
@@ -420,12 +690,12 @@ impl DdlDialog {
     pub fn show_create_table(
         ctx: &egui::Context,
         state: &mut DdlDialogState,
-    ) -> Option<String> {
+    ) -> Option<DdlDialogResult> {
         if !state.show {
             return None;
         }
 
-        let mut result: Option<String> = None;
+        let mut result: Option<DdlDialogResult> = None;
         let mut should_close = false;
 
         // 键盘快捷键处理（仅在没有文本框焦点时）
@@ -437,31 +707,21 @@ impl DdlDialog {
             }
 
             // Enter 创建表
-            if let DialogAction::Confirm = keyboard::handle_dialog_keys(ctx) {
-                match state.table.validate() {
-                    Ok(()) => {
-                        result = Some(state.table.to_create_sql());
-                        state.close();
-                        return result;
-                    }
-                    Err(e) => {
-                        state.error = Some(e);
-                    }
-                }
+            if let DialogAction::Confirm = keyboard::handle_dialog_keys(ctx)
+                && let Some(confirmed) = state.confirm()
+            {
+                state.close();
+                return Some(confirmed);
             }
 
             // 列导航
             let col_count = state.table.columns.len();
             match keyboard::handle_list_navigation(ctx) {
-                ListNavigation::Up => {
-                    if state.selected_column > 0 {
-                        state.selected_column -= 1;
-                    }
+                ListNavigation::Up if state.selected_column > 0 => {
+                    state.selected_column -= 1;
                 }
-                ListNavigation::Down => {
-                    if state.selected_column < col_count.saturating_sub(1) {
-                        state.selected_column += 1;
-                    }
+                ListNavigation::Down if state.selected_column < col_count.saturating_sub(1) => {
+                    state.selected_column += 1;
                 }
                 ListNavigation::Start => {
                     state.selected_column = 0;
@@ -469,13 +729,11 @@ impl DdlDialog {
                 ListNavigation::End => {
                     state.selected_column = col_count.saturating_sub(1);
                 }
-                ListNavigation::Delete => {
+                ListNavigation::Delete if col_count > 1 => {
                     // dd 删除当前列
-                    if col_count > 1 {
-                        state.table.columns.remove(state.selected_column);
-                        if state.selected_column >= state.table.columns.len() {
-                            state.selected_column = state.table.columns.len().saturating_sub(1);
-                        }
+                    state.table.columns.remove(state.selected_column);
+                    if state.selected_column >= state.table.columns.len() {
+                        state.selected_column = state.table.columns.len().saturating_sub(1);
                     }
                 }
                 ListNavigation::AddBelow => {
@@ -510,7 +768,8 @@ impl DdlDialog {
             });
         }
 
-        egui::Window::new("创建表")
+        let title = if state.design_mode { t("ddl.design_title") } else { t("ddl.create_title") };
+        egui::Window::new(title)
             .collapsible(false)
             .resizable(true)
             .min_width(600.0)
@@ -520,20 +779,20 @@ impl DdlDialog {
                 ui.vertical(|ui| {
                     // 表名输入
                     ui.horizontal(|ui| {
-                        ui.label("表名:");
+                        ui.label(t("ddl.table_name"));
                         ui.add(
                             TextEdit::singleline(&mut state.table.name)
                                 .desired_width(200.0)
-                                .hint_text("输入表名"),
+                                .hint_text(t("ddl.table_name_hint")),
                         );
 
                         ui.add_space(20.0);
 
-                        ui.label("注释:");
+                        ui.label(t("ddl.comment"));
                         ui.add(
                             TextEdit::singleline(&mut state.table.comment)
                                 .desired_width(200.0)
-                                .hint_text("可选"),
+                                .hint_text(t("ddl.optional")),
                         );
                     });
 
@@ -542,15 +801,15 @@ impl DdlDialog {
 
                     // 列定义区域
                     ui.horizontal(|ui| {
-                        ui.label(RichText::new("列定义").strong());
+                        ui.label(RichText::new(t("ddl.columns")).strong());
                         ui.label(
-                            RichText::new("[j/k 移动 | o/O 添加 | dd 删除 | Space 切换主键]")
+                            RichText::new(t("ddl.columns_hint"))
                                 .small()
                                 .color(Color32::from_rgb(120, 120, 120)),
                         );
                         
                         ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                            if ui.button("+ 添加列 [o]").clicked() {
+                            if ui.button(t("ddl.add_column")).clicked() {
                                 state.table.columns.push(ColumnDefinition::default());
                             }
                         });
@@ -560,9 +819,9 @@ impl DdlDialog {
 
                     // 列表头
                     ui.horizontal(|ui| {
-                        ui.label(RichText::new("列名").small().strong());
+                        ui.label(RichText::new(t("ddl.column_name")).small().strong());
                         ui.add_space(80.0);
-                        ui.label(RichText::new("类型").small().strong());
+                        ui.label(RichText::new(t("ddl.column_type")).small().strong());
                         ui.add_space(80.0);
                         ui.label(RichText::new("PK").small().strong());
                         ui.add_space(10.0);
@@ -572,7 +831,7 @@ impl DdlDialog {
                         ui.add_space(10.0);
                         ui.label(RichText::new("UQ").small().strong());
                         ui.add_space(10.0);
-                        ui.label(RichText::new("默认值").small().strong());
+                        ui.label(RichText::new(t("ddl.default_value")).small().strong());
                     });
 
                     ui.add_space(4.0);
@@ -601,7 +860,7 @@ impl DdlDialog {
                                     ui.add(
                                         TextEdit::singleline(&mut col.name)
                                             .desired_width(100.0)
-                                            .hint_text("列名"),
+                                            .hint_text(t("ddl.column_name")),
                                     );
 
                                     // 类型选择
@@ -618,7 +877,7 @@ impl DdlDialog {
                                     // 主键
                                     let mut pk = col.primary_key;
                                     if ui.checkbox(&mut pk, "")
-                                        .on_hover_text("主键")
+                                        .on_hover_text(t("ddl.primary_key"))
                                         .changed()
                                     {
                                         col.primary_key = pk;
@@ -630,12 +889,12 @@ impl DdlDialog {
 
                                     // 自增
                                     ui.checkbox(&mut col.auto_increment, "")
-                                        .on_hover_text("自增");
+                                        .on_hover_text(t("ddl.auto_increment"));
 
                                     // 非空
                                     let mut not_null = !col.nullable;
                                     if ui.checkbox(&mut not_null, "")
-                                        .on_hover_text("非空")
+                                        .on_hover_text(t("ddl.not_null"))
                                         .changed()
                                     {
                                         col.nullable = !not_null;
@@ -643,19 +902,19 @@ impl DdlDialog {
 
                                     // 唯一
                                     ui.checkbox(&mut col.unique, "")
-                                        .on_hover_text("唯一");
+                                        .on_hover_text(t("ddl.unique"));
 
                                     // 默认值
                                     ui.add(
                                         TextEdit::singleline(&mut col.default_value)
                                             .desired_width(80.0)
-                                            .hint_text("默认值"),
+                                            .hint_text(t("ddl.default_value")),
                                     );
 
                                     // 删除按钮
                                     if col_count > 1
                                         && ui.small_button("×")
-                                            .on_hover_text("删除列 [dd]")
+                                            .on_hover_text(t("ddl.delete_column"))
                                             .clicked()
                                         {
                                             col_to_remove = Some(idx);
@@ -686,7 +945,7 @@ impl DdlDialog {
                     ui.separator();
 
                     // 预览 SQL
-                    ui.collapsing("预览 SQL", |ui| {
+                    ui.collapsing(t("ddl.preview_sql"), |ui| {
                         let sql = state.table.to_create_sql();
                         ui.add(
                             TextEdit::multiline(&mut sql.as_str())
@@ -707,7 +966,11 @@ impl DdlDialog {
                     // 快捷键提示
                     ui.horizontal(|ui| {
                         ui.label(
-                            RichText::new("快捷键: Esc/q 关闭 | Enter 创建")
+                            RichText::new(if state.design_mode {
+                                t("ddl.design_shortcuts")
+                            } else {
+                                t("ddl.create_shortcuts")
+                            })
                                 .small()
                                 .color(Color32::from_rgb(120, 120, 120)),
                         );
@@ -717,19 +980,13 @@ impl DdlDialog {
 
                     // 按钮
                     ui.horizontal(|ui| {
-                        if ui.button("创建表 [Enter]").clicked() {
-                            match state.table.validate() {
-                                Ok(()) => {
-                                    result = Some(state.table.to_create_sql());
-                                    should_close = true;
-                                }
-                                Err(e) => {
-                                    state.error = Some(e);
-                                }
-                            }
+                        let confirm_text = if state.design_mode { t("ddl.add_to_diagram") } else { t("ddl.create_button") };
+                        if ui.button(confirm_text).clicked() {
+                            result = state.confirm();
+                            should_close = result.is_some();
                         }
 
-                        if ui.button("取消 [Esc]").clicked() {
+                        if ui.button(t("common.cancel_esc")).clicked() {
                             should_close = true;
                         }
                     });
@@ -744,20 +1001,6 @@ impl DdlDialog {
     }
 }
 
-// ============================================================================
-// 工具函数
-// ============================================================================
-
-/// 引用标识符
-fn quote_identifier(name: &str, db_type: &DatabaseType) -> String {
-    match db_type {
-        DatabaseType::MySQL => format!("`{}`", name.replace('`', "``")),
-        DatabaseType::PostgreSQL | DatabaseType::SQLite => {
-            format!("\"{}\"", name.replace('"', "\"\""))
-        }
-    }
-}
-
 // ============================================================================
 // 测试
 // ============================================================================
@@ -0,0 +1,350 @@
+//! 对象依赖关系窗口
+//!
+//! 以小型关系图显示选中的表或视图依赖的对象（上方）和依赖它的对象（下方），
+//! 节点位置使用 ER 图的层次布局计算。点击表或视图节点切换到该对象的依赖关系。
+
+use super::keyboard;
+use crate::core::{DependencyGraph, DependencyLink, DependencyObjectKind};
+use crate::database::{ForeignKeyInfo, TriggerInfo, ViewInfo};
+use crate::ui::{hierarchical_layout, ERTable, RelationType, Relationship};
+use egui::{self, Color32, Rect, RichText, Sense, Stroke, Vec2};
+
+/// 节点尺寸（布局坐标）
+const NODE_SIZE: Vec2 = Vec2::new(180.0, 48.0);
+
+/// 依赖关系窗口的结果
+pub enum DependencyResult {
+    /// 无操作
+    None,
+    /// 重新读取外键、视图和触发器
+    Refresh,
+}
+
+/// 依赖关系窗口读取的元数据
+#[derive(Debug, Clone, Default)]
+pub struct DependencyData {
+    /// 当前数据库的表
+    pub tables: Vec<String>,
+    pub foreign_keys: Vec<ForeignKeyInfo>,
+    pub views: Vec<ViewInfo>,
+    pub triggers: Vec<TriggerInfo>,
+}
+
+/// 图中的一个节点
+struct DependencyNode {
+    name: String,
+    kind: DependencyObjectKind,
+    /// 与中心对象的关系说明（中心对象为空）
+    detail: String,
+    /// 布局后的矩形（布局坐标）
+    rect: Rect,
+}
+
+/// 依赖关系窗口状态
+#[derive(Default)]
+pub struct DependencyState {
+    /// 是否显示窗口
+    pub show: bool,
+    /// 是否正在读取元数据
+    pub loading: bool,
+    /// 读取失败时的错误信息
+    pub error: Option<String>,
+    /// 已读取的元数据
+    data: DependencyData,
+    /// 当前显示的依赖关系
+    graph: Option<DependencyGraph>,
+    /// 之前查看过的对象（用于返回）
+    history: Vec<String>,
+    /// 布局后的节点
+    nodes: Vec<DependencyNode>,
+    /// 节点之间的连线（依赖方索引, 被依赖方索引）
+    edges: Vec<(usize, usize)>,
+}
+
+impl DependencyState {
+    /// 创建新的窗口状态
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 打开窗口并开始读取（元数据由调用方异步读取）
+    pub fn open(&mut self, object: String) {
+        self.show = true;
+        self.loading = true;
+        self.error = None;
+        self.history.clear();
+        self.graph = Some(DependencyGraph::build(&object, &[], &[], &[], &[]));
+        self.nodes.clear();
+        self.edges.clear();
+    }
+
+    /// 当前查看的对象
+    pub fn object(&self) -> Option<&str> {
+        self.graph.as_ref().map(|g| g.object.as_str())
+    }
+
+    /// 元数据读取完成
+    pub fn set_data(&mut self, result: Result<DependencyData, String>) {
+        self.loading = false;
+        match result {
+            Ok(data) => {
+                self.data = data;
+                self.error = None;
+                if let Some(object) = self.object().map(str::to_string) {
+                    self.focus(&object);
+                }
+            }
+            Err(e) => self.error = Some(e),
+        }
+    }
+
+    /// 关闭窗口
+    pub fn close(&mut self) {
+        self.show = false;
+        self.loading = false;
+    }
+
+    /// 切换到指定对象的依赖关系并重新布局
+    fn focus(&mut self, object: &str) {
+        let graph = DependencyGraph::build(
+            object,
+            &self.data.tables,
+            &self.data.foreign_keys,
+            &self.data.views,
+            &self.data.triggers,
+        );
+
+        let mut nodes = vec![DependencyNode {
+            name: graph.object.clone(),
+            kind: graph.kind,
+            detail: String::new(),
+            rect: Rect::NOTHING,
+        }];
+        let mut edges = Vec::new();
+        let mut add = |link: &DependencyLink, depends_on: bool| {
+            nodes.push(DependencyNode {
+                name: link.name.clone(),
+                kind: link.kind,
+                detail: link.detail.clone(),
+                rect: Rect::NOTHING,
+            });
+            let idx = nodes.len() - 1;
+            edges.push(if depends_on { (0, idx) } else { (idx, 0) });
+        };
+        graph.depends_on.iter().for_each(|link| add(link, true));
+        graph.dependents.iter().for_each(|link| add(link, false));
+
+        // 借用 ER 图的层次布局：被依赖的对象在上层
+        let key = |i: usize| i.to_string();
+        let mut tables: Vec<ERTable> = (0..nodes.len()).map(|i| ERTable::new(key(i))).collect();
+        let relationships: Vec<Relationship> = edges
+            .iter()
+            .map(|&(from, to)| Relationship {
+                from_table: key(from),
+                from_column: String::new(),
+                to_table: key(to),
+                to_column: String::new(),
+                relation_type: RelationType::OneToMany,
+            })
+            .collect();
+        hierarchical_layout(&mut tables, &relationships, Vec2::new(24.0, 40.0));
+        for (node, table) in nodes.iter_mut().zip(&tables) {
+            node.rect = Rect::from_min_size(table.position, NODE_SIZE);
+        }
+
+        self.graph = Some(graph);
+        self.nodes = nodes;
+        self.edges = edges;
+    }
+}
+
+/// 对象依赖关系窗口
+pub struct DependencyDialog;
+
+impl DependencyDialog {
+    /// 显示窗口
+    pub fn show(ctx: &egui::Context, state: &mut DependencyState) -> DependencyResult {
+        if !state.show {
+            return DependencyResult::None;
+        }
+
+        let mut result = DependencyResult::None;
+
+        if !keyboard::has_text_focus(ctx) {
+            if keyboard::handle_close_keys(ctx) {
+                state.close();
+                return DependencyResult::None;
+            }
+            if !state.loading && ctx.input(|i| i.key_pressed(egui::Key::R) || i.key_pressed(egui::Key::F5)) {
+                result = DependencyResult::Refresh;
+            }
+        }
+
+        let title = format!("依赖关系 - {}", state.object().unwrap_or_default());
+        let mut open = true;
+        egui::Window::new(title)
+            .id(egui::Id::new("dependency_dialog"))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(true)
+            .default_size([680.0, 460.0])
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    if ui.add_enabled(!state.history.is_empty(), egui::Button::new("← 返回")).clicked()
+                        && let Some(previous) = state.history.pop()
+                    {
+                        state.focus(&previous);
+                    }
+                    if let Some(graph) = &state.graph
+                        && !state.loading
+                    {
+                        ui.label(
+                            RichText::new(format!(
+                                "{} · 依赖 {} 个对象 · 被 {} 个对象依赖",
+                                graph.kind.display_name(),
+                                graph.depends_on.len(),
+                                graph.dependents.len()
+                            ))
+                            .color(Color32::GRAY),
+                        );
+                    }
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if state.loading {
+                            ui.spinner();
+                        } else if ui.button("🔄 刷新 [r]").clicked() {
+                            result = DependencyResult::Refresh;
+                        }
+                    });
+                });
+                ui.separator();
+
+                if let Some(error) = &state.error {
+                    ui.label(RichText::new(format!("读取失败: {}", error)).color(Color32::from_rgb(255, 100, 100)));
+                    return;
+                }
+                if state.loading {
+                    return;
+                }
+                if state.graph.as_ref().is_some_and(DependencyGraph::is_empty) {
+                    ui.label(RichText::new("没有找到依赖该对象或被该对象依赖的表、视图和触发器").color(Color32::GRAY));
+                    return;
+                }
+                if let Some(target) = Self::show_graph(ui, state) {
+                    if let Some(current) = state.object().map(str::to_string) {
+                        state.history.push(current);
+                    }
+                    state.focus(&target);
+                }
+            });
+
+        if !open {
+            state.close();
+        }
+        result
+    }
+
+    /// 绘制关系图，返回被点击的表或视图
+    fn show_graph(ui: &mut egui::Ui, state: &DependencyState) -> Option<String> {
+        let (canvas, _) = ui.allocate_exact_size(ui.available_size(), Sense::hover());
+        let bounds = state.nodes.iter().fold(Rect::NOTHING, |acc, n| acc.union(n.rect));
+        if !bounds.is_positive() {
+            return None;
+        }
+
+        // 缩放到画布内并居中
+        let margin = 12.0;
+        let scale = ((canvas.width() - margin * 2.0) / bounds.width())
+            .min((canvas.height() - margin * 2.0) / bounds.height())
+            .clamp(0.3, 1.0);
+        let offset = canvas.center() - bounds.center() * scale;
+        let to_screen = |rect: Rect| Rect::from_min_max(
+            (rect.min.to_vec2() * scale + offset).to_pos2(),
+            (rect.max.to_vec2() * scale + offset).to_pos2(),
+        );
+
+        let painter = ui.painter_at(canvas);
+        let visuals = ui.visuals().clone();
+        let edge_stroke = Stroke::new(1.5, visuals.weak_text_color());
+
+        // 连线从依赖方指向被依赖方
+        for &(from, to) in &state.edges {
+            let (from, to) = (to_screen(state.nodes[from].rect), to_screen(state.nodes[to].rect));
+            let (start, end) = if from.center().y >= to.center().y {
+                (from.center_top(), to.center_bottom())
+            } else {
+                (from.center_bottom(), to.center_top())
+            };
+            painter.arrow(start, end - start, edge_stroke);
+        }
+
+        let mut clicked = None;
+        for (idx, node) in state.nodes.iter().enumerate() {
+            let rect = to_screen(node.rect);
+            let response = ui.interact(rect, ui.id().with(("dependency_node", idx)), Sense::click());
+            let color = kind_color(node.kind);
+            let fill = if response.hovered() { color.gamma_multiply(0.35) } else { color.gamma_multiply(0.18) };
+            let stroke_width = if idx == 0 { 2.5 } else { 1.0 };
+            painter.rect(rect, 6.0 * scale, fill, Stroke::new(stroke_width, color), egui::StrokeKind::Inside);
+
+            let font = |size: f32| egui::FontId::proportional(size * scale);
+            painter.text(
+                rect.center() - Vec2::new(0.0, 7.0 * scale),
+                egui::Align2::CENTER_CENTER,
+                truncate_name(&node.name),
+                font(14.0),
+                visuals.strong_text_color(),
+            );
+            painter.text(
+                rect.center() + Vec2::new(0.0, 11.0 * scale),
+                egui::Align2::CENTER_CENTER,
+                node.kind.display_name(),
+                font(11.0),
+                color,
+            );
+
+            let navigable = idx > 0 && node.kind != DependencyObjectKind::Trigger;
+            let response = if node.detail.is_empty() {
+                response.on_hover_text(&node.name)
+            } else {
+                response.on_hover_text(format!("{}\n{}", node.name, node.detail))
+            };
+            if navigable && response.clicked() {
+                clicked = Some(node.name.clone());
+            }
+            if navigable {
+                response.on_hover_cursor(egui::CursorIcon::PointingHand);
+            }
+        }
+
+        if state.nodes.len() > 1 {
+            painter.text(
+                canvas.left_bottom() + Vec2::new(4.0, -4.0),
+                egui::Align2::LEFT_BOTTOM,
+                "上方为它依赖的对象，下方为依赖它的对象；点击表或视图查看其依赖关系",
+                egui::FontId::proportional(11.0),
+                Color32::GRAY,
+            );
+        }
+        clicked
+    }
+}
+
+/// 节点颜色
+fn kind_color(kind: DependencyObjectKind) -> Color32 {
+    match kind {
+        DependencyObjectKind::Table => Color32::from_rgb(90, 150, 230),
+        DependencyObjectKind::View => Color32::from_rgb(82, 196, 106),
+        DependencyObjectKind::Trigger => Color32::from_rgb(230, 160, 60),
+    }
+}
+
+/// 过长的对象名截断显示
+fn truncate_name(name: &str) -> String {
+    const MAX_CHARS: usize = 22;
+    if name.chars().count() > MAX_CHARS {
+        format!("{}…", name.chars().take(MAX_CHARS - 1).collect::<String>())
+    } else {
+        name.to_string()
+    }
+}
@@ -3,17 +3,18 @@
 //! 支持的快捷键：
 //! - `Esc` - 关闭对话框
 //! - `Enter` - 导出（当配置有效时）
-//! - `1/2/3` - 快速选择格式 (CSV/SQL/JSON)
+//! - `1/2/3` - 快速选择格式 (CSV/SQL/JSON)，`4`-`9` 选择插件格式
 //! - `h/l` - 切换格式
 //! - `j/k` - 在列选择中导航
 //! - `Space` - 切换当前列的选中状态
 //! - `a` - 全选/取消全选列
 
 use super::keyboard;
-use crate::core::ExportFormat;
+use crate::core::{mask_value, t, tf, ExportFormat, PluginRegistry};
 use crate::database::QueryResult;
 use crate::ui::styles::{DANGER, GRAY, MUTED, SUCCESS, SPACING_SM, SPACING_MD};
 use egui::{self, Color32, Key, RichText, CornerRadius, ScrollArea, TextEdit};
+use std::borrow::Cow;
 
 /// 导出配置
 #[derive(Clone)]
@@ -38,6 +39,16 @@ pub struct ExportConfig {
     pub sql_batch_size: usize,
     /// JSON: 是否美化输出
     pub json_pretty: bool,
+    /// JSON: 保留嵌套结构（`a.b` 列和 JSON 单元格还原为嵌套对象）
+    pub json_nested: bool,
+    /// 插件导出格式 id（设置时代替 `format`）
+    pub plugin_exporter: Option<String>,
+    /// 导出列的顺序（表格中的列显示顺序，为空时按结果中的顺序）
+    pub column_order: Vec<usize>,
+    /// 标记为敏感的列索引，默认导出脱敏值
+    pub masked_columns: Vec<usize>,
+    /// 导出敏感列的原始值（导出前需要确认）
+    pub unmask: bool,
     /// 键盘导航: 当前选中的列索引
     #[doc(hidden)]
     pub nav_column_index: usize,
@@ -56,6 +67,11 @@ impl Default for ExportConfig {
             sql_use_transaction: true,
             sql_batch_size: 100,
             json_pretty: true,
+            json_nested: false,
+            plugin_exporter: None,
+            column_order: Vec::new(),
+            masked_columns: Vec::new(),
+            unmask: false,
             nav_column_index: 0,
         }
     }
@@ -69,14 +85,14 @@ impl ExportConfig {
         }
     }
 
-    /// 获取选中的列索引
+    /// 获取选中的列索引（按 `column_order` 排列）
     pub fn get_selected_column_indices(&self) -> Vec<usize> {
-        self.selected_columns
-            .iter()
-            .enumerate()
-            .filter(|&(_, selected)| *selected)
-            .map(|(i, _)| i)
-            .collect()
+        let is_selected = |i: &usize| self.selected_columns.get(*i).is_some_and(|s| *s);
+        if self.column_order.len() == self.selected_columns.len() {
+            self.column_order.iter().copied().filter(is_selected).collect()
+        } else {
+            (0..self.selected_columns.len()).filter(is_selected).collect()
+        }
     }
 
     /// 是否全选
@@ -88,17 +104,55 @@ impl ExportConfig {
     pub fn selected_column_count(&self) -> usize {
         self.selected_columns.iter().filter(|&&s| s).count()
     }
+
+    /// 导出的单元格值（敏感列未选择导出原始值时脱敏）
+    pub fn export_value<'a>(&self, column: usize, value: &'a str) -> Cow<'a, str> {
+        if !self.unmask && self.masked_columns.contains(&column) {
+            Cow::Owned(mask_value(value))
+        } else {
+            Cow::Borrowed(value)
+        }
+    }
+
+    /// 将以原始值导出的已选敏感列
+    pub fn unmasked_columns(&self) -> impl Iterator<Item = usize> + '_ {
+        self.masked_columns
+            .iter()
+            .copied()
+            .filter(|&i| self.unmask && self.selected_columns.get(i).is_some_and(|s| *s))
+    }
+
+    /// 是否会导出敏感列的原始值
+    pub fn exports_unmasked(&self) -> bool {
+        self.unmasked_columns().next().is_some()
+    }
+
+    /// 选择内置格式
+    pub fn set_format(&mut self, format: ExportFormat) {
+        self.format = format;
+        self.plugin_exporter = None;
+    }
+
+    /// 当前格式的显示名称和文件扩展名
+    pub fn format_label(&self, plugins: &PluginRegistry) -> (String, String) {
+        match self.plugin_exporter.as_deref().and_then(|id| plugins.exporter(id)) {
+            Some(exporter) => (exporter.name().to_string(), exporter.extension().to_string()),
+            None => (self.format.display_name().to_string(), self.format.extension().to_string()),
+        }
+    }
 }
 
 pub struct ExportDialog;
 
 impl ExportDialog {
+    #[allow(clippy::too_many_arguments)]
     pub fn show(
         ctx: &egui::Context,
         show: &mut bool,
         config: &mut ExportConfig,
         table_name: &str,
         data: Option<&QueryResult>,
+        plugins: &PluginRegistry,
         on_export: &mut Option<ExportConfig>,
         status_message: &Option<Result<String, String>>,
     ) {
@@ -133,29 +187,40 @@ impl ExportDialog {
             ctx.input(|i| {
                 // 数字键快速选择格式: 1=CSV, 2=SQL, 3=JSON
                 if i.key_pressed(Key::Num1) {
-                    config.format = ExportFormat::Csv;
+                    config.set_format(ExportFormat::Csv);
                 }
                 if i.key_pressed(Key::Num2) {
-                    config.format = ExportFormat::Sql;
+                    config.set_format(ExportFormat::Sql);
                 }
                 if i.key_pressed(Key::Num3) {
-                    config.format = ExportFormat::Json;
+                    config.set_format(ExportFormat::Json);
+                }
+                // 4-9 选择插件格式
+                for (key, exporter) in [Key::Num4, Key::Num5, Key::Num6, Key::Num7, Key::Num8, Key::Num9]
+                    .into_iter()
+                    .zip(plugins.exporters())
+                {
+                    if i.key_pressed(key) {
+                        config.plugin_exporter = Some(exporter.id().to_string());
+                    }
                 }
 
                 // h/l 切换格式
                 if i.key_pressed(Key::H) || i.key_pressed(Key::ArrowLeft) {
-                    config.format = match config.format {
+                    let format = match config.format {
                         ExportFormat::Csv => ExportFormat::Json,
                         ExportFormat::Sql => ExportFormat::Csv,
                         ExportFormat::Json => ExportFormat::Sql,
                     };
+                    config.set_format(format);
                 }
                 if i.key_pressed(Key::L) || i.key_pressed(Key::ArrowRight) {
-                    config.format = match config.format {
+                    let format = match config.format {
                         ExportFormat::Csv => ExportFormat::Sql,
                         ExportFormat::Sql => ExportFormat::Json,
                         ExportFormat::Json => ExportFormat::Csv,
                     };
+                    config.set_format(format);
                 }
 
                 // j/k 列选择导航
@@ -190,7 +255,7 @@ impl ExportDialog {
             });
         }
 
-        egui::Window::new("📤 导出数据")
+        egui::Window::new(t("export.title"))
             .collapsible(false)
             .resizable(false)
             .min_width(320.0)
@@ -207,7 +272,7 @@ impl ExportDialog {
                 ui.add_space(SPACING_SM);
 
                 // 格式选择（紧凑版）
-                Self::show_format_selector(ui, config);
+                Self::show_format_selector(ui, config, plugins);
 
                 ui.add_space(SPACING_MD);
 
@@ -227,14 +292,16 @@ impl ExportDialog {
 
                         ui.add_space(SPACING_SM);
 
-                        // 格式特定选项（折叠）
-                        Self::show_format_options(ui, config);
+                        // 格式特定选项（折叠），插件格式没有选项
+                        if config.plugin_exporter.is_none() {
+                            Self::show_format_options(ui, config);
+                        }
 
                         ui.add_space(SPACING_SM);
 
                         // 导出预览（折叠）
                         if let Some(result) = data {
-                            Self::show_preview(ui, config, result);
+                            Self::show_preview(ui, config, result, plugins);
                         }
                     });
 
@@ -250,7 +317,7 @@ impl ExportDialog {
                 ui.add_space(SPACING_SM);
 
                 // 底部按钮
-                Self::show_buttons(ui, show, config, on_export, row_count);
+                Self::show_buttons(ui, show, config, plugins, on_export, row_count);
 
                 ui.add_space(SPACING_SM);
             });
@@ -266,7 +333,7 @@ impl ExportDialog {
     ) {
         ui.horizontal(|ui| {
             // 表名
-            ui.label(RichText::new("表:").small().color(GRAY));
+            ui.label(RichText::new(t("export.table")).small().color(GRAY));
             ui.label(RichText::new(table_name).strong());
 
             ui.separator();
@@ -279,47 +346,60 @@ impl ExportDialog {
                 row_count.saturating_sub(config.start_row)
             };
 
-            ui.label(RichText::new(format!(
-                "导出: {}列 × {}行",
-                selected_cols, export_rows
+            ui.label(RichText::new(tf(
+                "export.summary",
+                &[&selected_cols.to_string(), &export_rows.to_string()],
             )).small().color(MUTED));
             
-            ui.label(RichText::new(format!("(共{}×{})", col_count, row_count))
+            ui.label(RichText::new(tf("export.total", &[&col_count.to_string(), &row_count.to_string()]))
                 .small()
                 .color(MUTED));
         });
     }
 
     /// 格式选择器（紧凑版）
-    fn show_format_selector(ui: &mut egui::Ui, config: &mut ExportConfig) {
+    fn show_format_selector(ui: &mut egui::Ui, config: &mut ExportConfig, plugins: &PluginRegistry) {
         ui.horizontal(|ui| {
-            ui.label(RichText::new("格式:").color(GRAY));
+            ui.label(RichText::new(t("export.format")).color(GRAY));
             
             for (idx, (fmt, icon, name)) in [
                 (ExportFormat::Csv, "📊", "CSV"),
                 (ExportFormat::Sql, "📝", "SQL"),
                 (ExportFormat::Json, "🔧", "JSON"),
             ].iter().enumerate() {
-                let is_selected = config.format == *fmt;
+                let is_selected = config.plugin_exporter.is_none() && config.format == *fmt;
                 let text = format!("{} {} [{}]", icon, name, idx + 1);
                 
                 if ui.selectable_label(is_selected, RichText::new(&text).strong()).clicked() {
-                    config.format = *fmt;
+                    config.set_format(*fmt);
+                }
+            }
+
+            // 插件格式
+            for (idx, exporter) in plugins.exporters().iter().enumerate() {
+                let is_selected = config.plugin_exporter.as_deref() == Some(exporter.id());
+                let text = if idx < 6 {
+                    format!("🧩 {} [{}]", exporter.name(), idx + 4)
+                } else {
+                    format!("🧩 {}", exporter.name())
+                };
+                if ui.selectable_label(is_selected, RichText::new(&text).strong()).clicked() {
+                    config.plugin_exporter = Some(exporter.id().to_string());
                 }
             }
             
             ui.separator();
-            ui.label(RichText::new("h/l 切换").small().color(GRAY));
+            ui.label(RichText::new(t("export.switch_hint")).small().color(GRAY));
         });
     }
 
     /// 导出范围
     fn show_row_range(ui: &mut egui::Ui, config: &mut ExportConfig, total_rows: usize) {
         ui.horizontal(|ui| {
-            ui.label(RichText::new("行数:").color(GRAY));
+            ui.label(RichText::new(t("export.rows")).color(GRAY));
             
             // 快捷按钮
-            for (label, limit) in [("全部", 0), ("100", 100), ("1000", 1000)] {
+            for (label, limit) in [(t("export.all_rows"), 0), ("100", 100), ("1000", 1000)] {
                 if ui.selectable_label(
                     config.row_limit == limit && config.start_row == 0,
                     label
@@ -332,7 +412,7 @@ impl ExportDialog {
             ui.separator();
             
             // 自定义行数
-            ui.label(RichText::new("自定义:").small().color(GRAY));
+            ui.label(RichText::new(t("export.custom")).small().color(GRAY));
             let mut limit_str = if config.row_limit == 0 {
                 String::new()
             } else {
@@ -341,7 +421,7 @@ impl ExportDialog {
             if ui.add(
                 TextEdit::singleline(&mut limit_str)
                     .desired_width(50.0)
-                    .hint_text("全部")
+                    .hint_text(t("export.all_rows"))
             ).changed() {
                 config.row_limit = limit_str.parse().unwrap_or(0);
             }
@@ -352,10 +432,9 @@ impl ExportDialog {
 
     /// 列选择器（折叠面板）
     fn show_column_selector(ui: &mut egui::Ui, config: &mut ExportConfig, columns: &[String]) {
-        let header = format!(
-            "选择列 ({}/{}) [j/k Space a]",
-            config.selected_column_count(),
-            columns.len()
+        let header = tf(
+            "export.columns_header",
+            &[&config.selected_column_count().to_string(), &columns.len().to_string()],
         );
         
         egui::CollapsingHeader::new(header)
@@ -363,7 +442,7 @@ impl ExportDialog {
             .show(ui, |ui| {
                 ui.horizontal(|ui| {
                     let all_selected = config.all_columns_selected();
-                    if ui.button(if all_selected { "取消全选 [a]" } else { "全选 [a]" }).clicked() {
+                    if ui.button(if all_selected { t("export.deselect_all") } else { t("export.select_all") }).clicked() {
                         let new_state = !all_selected;
                         for s in &mut config.selected_columns {
                             *s = new_state;
@@ -371,7 +450,7 @@ impl ExportDialog {
                     }
                     
                     ui.separator();
-                    ui.label(RichText::new("j/k 导航, Space 切换").small().color(GRAY));
+                    ui.label(RichText::new(t("export.column_nav_hint")).small().color(GRAY));
                 });
                 
                 ui.add_space(4.0);
@@ -413,21 +492,31 @@ impl ExportDialog {
                                                     if ui.checkbox(&mut config.selected_columns[i], &display_name).clicked() {
                                                         config.nav_column_index = i;
                                                     }
+                                                    if config.masked_columns.contains(&i) {
+                                                        ui.label(RichText::new("🔒").small().color(GRAY))
+                                                            .on_hover_text(t("export.sensitive_column"));
+                                                    }
                                                 });
                                             });
                                     }
                                 }
                             });
                     });
+
+                if !config.masked_columns.is_empty() {
+                    ui.add_space(4.0);
+                    ui.checkbox(&mut config.unmask, t("export.unmask"))
+                        .on_hover_text(t("export.unmask_hint"));
+                }
             });
     }
 
     /// 格式特定选项（折叠面板）
     fn show_format_options(ui: &mut egui::Ui, config: &mut ExportConfig) {
         let header = match config.format {
-            ExportFormat::Csv => "CSV 选项",
-            ExportFormat::Sql => "SQL 选项",
-            ExportFormat::Json => "JSON 选项",
+            ExportFormat::Csv => t("export.csv_options"),
+            ExportFormat::Sql => t("export.sql_options"),
+            ExportFormat::Json => t("export.json_options"),
         };
         
         egui::CollapsingHeader::new(header)
@@ -444,7 +533,7 @@ impl ExportDialog {
     /// CSV 选项
     fn show_csv_options(ui: &mut egui::Ui, config: &mut ExportConfig) {
         ui.horizontal(|ui| {
-            ui.label(RichText::new("分隔符:").small().color(GRAY));
+            ui.label(RichText::new(t("export.delimiter")).small().color(GRAY));
             for (label, delim) in [(",", ','), (";", ';'), ("Tab", '\t'), ("|", '|')] {
                 if ui.selectable_label(config.csv_delimiter == delim, label).clicked() {
                     config.csv_delimiter = delim;
@@ -453,19 +542,19 @@ impl ExportDialog {
         });
 
         ui.horizontal(|ui| {
-            ui.checkbox(&mut config.csv_include_header, "包含表头");
+            ui.checkbox(&mut config.csv_include_header, t("export.include_header"));
         });
     }
 
     /// SQL 选项
     fn show_sql_options(ui: &mut egui::Ui, config: &mut ExportConfig) {
         ui.horizontal(|ui| {
-            ui.checkbox(&mut config.sql_use_transaction, "事务包装");
+            ui.checkbox(&mut config.sql_use_transaction, t("export.use_transaction"));
             
             ui.separator();
             
-            ui.label(RichText::new("批量:").small().color(GRAY));
-            for (label, size) in [("单行", 0), ("100", 100), ("500", 500)] {
+            ui.label(RichText::new(t("export.batch")).small().color(GRAY));
+            for (label, size) in [(t("export.batch_single"), 0), ("100", 100), ("500", 500)] {
                 if ui.selectable_label(config.sql_batch_size == size, label).clicked() {
                     config.sql_batch_size = size;
                 }
@@ -476,21 +565,28 @@ impl ExportDialog {
     /// JSON 选项
     fn show_json_options(ui: &mut egui::Ui, config: &mut ExportConfig) {
         ui.horizontal(|ui| {
-            ui.checkbox(&mut config.json_pretty, "美化输出");
+            ui.checkbox(&mut config.json_pretty, t("export.pretty"));
             if config.json_pretty {
-                ui.label(RichText::new("(带缩进)").small().color(MUTED));
+                ui.label(RichText::new(t("export.pretty_hint")).small().color(MUTED));
             } else {
-                ui.label(RichText::new("(紧凑)").small().color(MUTED));
+                ui.label(RichText::new(t("export.compact_hint")).small().color(MUTED));
             }
         });
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut config.json_nested, t("export.nested"));
+            ui.label(RichText::new(t("export.nested_hint")).small().color(MUTED));
+        });
     }
 
     /// 导出预览（折叠面板）
-    fn show_preview(ui: &mut egui::Ui, config: &ExportConfig, data: &QueryResult) {
-        egui::CollapsingHeader::new("预览")
+    fn show_preview(ui: &mut egui::Ui, config: &ExportConfig, data: &QueryResult, plugins: &PluginRegistry) {
+        egui::CollapsingHeader::new(t("export.preview"))
             .default_open(false)
             .show(ui, |ui| {
-                let preview_text = Self::generate_preview(config, data);
+                let preview_text = match config.plugin_exporter.as_deref().and_then(|id| plugins.exporter(id)) {
+                    Some(exporter) => Self::generate_plugin_preview(exporter, config, data),
+                    None => Self::generate_preview(config, data),
+                };
                 
                 egui::Frame::NONE
                     .fill(Color32::from_rgba_unmultiplied(40, 40, 50, 60))
@@ -511,11 +607,44 @@ impl ExportDialog {
             });
     }
 
+    /// 用插件导出前几行生成预览
+    fn generate_plugin_preview(
+        exporter: &dyn crate::core::ExporterPlugin,
+        config: &ExportConfig,
+        data: &QueryResult,
+    ) -> String {
+        let selected_indices = config.get_selected_column_indices();
+        if selected_indices.is_empty() {
+            return t("export.no_columns").to_string();
+        }
+        let sample = QueryResult {
+            columns: selected_indices.iter().filter_map(|&i| data.columns.get(i).cloned()).collect(),
+            rows: data
+                .rows
+                .iter()
+                .skip(config.start_row)
+                .take(3)
+                .map(|row| {
+                    selected_indices
+                        .iter()
+                        .filter_map(|&i| row.get(i).map(|v| config.export_value(i, v).into_owned()))
+                        .collect()
+                })
+                .collect(),
+            ..Default::default()
+        };
+        let mut out = Vec::new();
+        match exporter.export(&sample, &mut out) {
+            Ok(()) => String::from_utf8_lossy(&out).into_owned(),
+            Err(e) => tf("export.preview_failed", &[&e.to_string()]),
+        }
+    }
+
     /// 生成预览文本
     fn generate_preview(config: &ExportConfig, data: &QueryResult) -> String {
         let selected_indices = config.get_selected_column_indices();
         if selected_indices.is_empty() {
-            return "（未选择任何列）".to_string();
+            return t("export.no_columns").to_string();
         }
 
         let preview_rows = 3.min(data.rows.len());
@@ -531,14 +660,14 @@ impl ExportDialog {
                     lines.push(selected_cols.iter().map(|s| s.as_str()).collect::<Vec<_>>().join(&config.csv_delimiter.to_string()));
                 }
                 for row in data.rows.iter().skip(config.start_row).take(preview_rows) {
-                    let values: Vec<&str> = selected_indices
+                    let values: Vec<Cow<str>> = selected_indices
                         .iter()
-                        .filter_map(|&i| row.get(i).map(|s| s.as_str()))
+                        .filter_map(|&i| row.get(i).map(|s| config.export_value(i, s)))
                         .collect();
                     lines.push(values.join(&config.csv_delimiter.to_string()));
                 }
                 if data.rows.len() > preview_rows {
-                    lines.push(format!("... (+{} 行)", data.rows.len() - preview_rows));
+                    lines.push(tf("export.more_rows", &[&(data.rows.len() - preview_rows).to_string()]));
                 }
                 lines.join("\n")
             }
@@ -548,13 +677,13 @@ impl ExportDialog {
                 for row in data.rows.iter().skip(config.start_row).take(preview_rows.min(2)) {
                     let values: Vec<String> = selected_indices
                         .iter()
-                        .filter_map(|&i| row.get(i))
+                        .filter_map(|&i| row.get(i).map(|v| config.export_value(i, v)))
                         .map(|v| if v == "NULL" { "NULL".to_string() } else { format!("'{}'", v) })
                         .collect();
                     lines.push(format!("INSERT INTO `t` ({}) VALUES ({});", cols_str, values.join(", ")));
                 }
                 if data.rows.len() > 2 {
-                    lines.push(format!("... (+{} 条)", data.rows.len() - 2));
+                    lines.push(tf("export.more_items", &[&(data.rows.len() - 2).to_string()]));
                 }
                 lines.join("\n")
             }
@@ -565,7 +694,7 @@ impl ExportDialog {
                         .iter()
                         .zip(selected_cols.iter())
                         .filter_map(|(&i, col)| {
-                            row.get(i).map(|v| {
+                            row.get(i).map(|v| config.export_value(i, v)).map(|v| {
                                 if v == "NULL" {
                                     format!("\"{}\": null", col)
                                 } else {
@@ -577,7 +706,7 @@ impl ExportDialog {
                     items.push(format!("{{ {} }}", obj.join(", ")));
                 }
                 if data.rows.len() > 2 {
-                    items.push(format!("... (+{} 条)", data.rows.len() - 2));
+                    items.push(tf("export.more_items", &[&(data.rows.len() - 2).to_string()]));
                 }
                 format!("[{}]", items.join(", "))
             }
@@ -608,18 +737,19 @@ impl ExportDialog {
         ui: &mut egui::Ui,
         show: &mut bool,
         config: &ExportConfig,
+        plugins: &PluginRegistry,
         on_export: &mut Option<ExportConfig>,
         row_count: usize,
     ) {
         let can_export = config.selected_column_count() > 0 && row_count > 0;
 
         ui.horizontal(|ui| {
-            if ui.button("取消 [Esc]").clicked() {
+            if ui.button(t("common.cancel_esc")).clicked() {
                 *show = false;
             }
 
             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                let btn_text = format!("导出 {} [Enter]", config.format.display_name());
+                let btn_text = tf("export.button", &[&config.format_label(plugins).0]);
                 let export_btn = egui::Button::new(
                     RichText::new(&btn_text)
                         .color(if can_export { Color32::WHITE } else { GRAY })
@@ -631,7 +761,7 @@ impl ExportDialog {
                 }
 
                 if !can_export {
-                    ui.label(RichText::new("请选择列").small().color(DANGER));
+                    ui.label(RichText::new(t("export.select_columns")).small().color(DANGER));
                 }
             });
         });
@@ -0,0 +1,110 @@
+//! 文件外部修改提示
+//!
+//! 查询 Tab 关联的 .sql 文件在外部被修改或删除时，让用户选择重新加载磁盘内容
+//! 还是保留编辑器中的内容。
+//!
+//! 支持的快捷键：
+//! - `r` - 重新加载
+//! - `Esc` / `k` - 保留编辑器内容
+
+use crate::core::{t, tf, ExternalChange};
+use crate::ui::styles::{GRAY, SPACING_LG, SPACING_MD};
+use egui::{self, Key, RichText};
+
+/// 外部修改提示的结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileChangeResult {
+    /// 无操作
+    None,
+    /// 用磁盘内容替换编辑器内容
+    Reload,
+    /// 保留编辑器内容
+    Keep,
+}
+
+/// 外部修改提示状态
+#[derive(Default)]
+pub struct FileChangeState {
+    /// 是否显示
+    pub show: bool,
+    /// 发生变化的 Tab ID
+    pub tab_id: String,
+    /// 文件路径（显示用）
+    pub path: String,
+    /// 变化内容
+    pub change: Option<ExternalChange>,
+}
+
+impl FileChangeState {
+    /// 创建新的状态
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 打开提示
+    pub fn open(&mut self, tab_id: impl Into<String>, path: impl Into<String>, change: ExternalChange) {
+        self.tab_id = tab_id.into();
+        self.path = path.into();
+        self.change = Some(change);
+        self.show = true;
+    }
+
+    /// 关闭提示，返回 Tab ID 和变化内容
+    pub fn take(&mut self) -> Option<(String, ExternalChange)> {
+        self.show = false;
+        self.change.take().map(|c| (std::mem::take(&mut self.tab_id), c))
+    }
+}
+
+/// 外部修改提示
+pub struct FileChangeDialog;
+
+impl FileChangeDialog {
+    /// 显示提示
+    pub fn show(ctx: &egui::Context, state: &mut FileChangeState) -> FileChangeResult {
+        let Some(change) = state.change.as_ref().filter(|_| state.show) else {
+            return FileChangeResult::None;
+        };
+        let removed = *change == ExternalChange::Removed;
+
+        let keys = ctx.input(|i| {
+            if i.key_pressed(Key::R) && !removed {
+                FileChangeResult::Reload
+            } else if i.key_pressed(Key::Escape) || i.key_pressed(Key::K) {
+                FileChangeResult::Keep
+            } else {
+                FileChangeResult::None
+            }
+        });
+        if keys != FileChangeResult::None {
+            return keys;
+        }
+
+        let mut result = FileChangeResult::None;
+        egui::Window::new(t("file.changed_title"))
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .min_width(360.0)
+            .show(ctx, |ui| {
+                ui.add_space(SPACING_MD);
+                let message = if removed { "file.removed_message" } else { "file.changed_message" };
+                ui.label(RichText::new(tf(message, &[&state.path])).size(14.0));
+                ui.add_space(SPACING_LG);
+                ui.label(RichText::new(t("file.changed_hint")).small().color(GRAY));
+                ui.add_space(SPACING_MD);
+
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    if ui.add_enabled(!removed, egui::Button::new(t("file.reload"))).clicked() {
+                        result = FileChangeResult::Reload;
+                    }
+                    ui.add_space(SPACING_MD);
+                    if ui.button(t("file.keep")).clicked() {
+                        result = FileChangeResult::Keep;
+                    }
+                });
+                ui.add_space(SPACING_MD);
+            });
+        result
+    }
+}
@@ -0,0 +1,194 @@
+//! 字体设置窗口
+//!
+//! 分别为界面、SQL 编辑器和数据表格选择字体（系统字体或内置字体）和字号。
+
+use super::keyboard;
+use crate::core::{constants, t, FontChoice, FontSettings, SystemFont};
+use egui::{self, Color32, RichText};
+
+// ============================================================================
+// 窗口结果
+// ============================================================================
+
+/// 字体设置窗口的结果
+pub enum FontSettingsResult {
+    /// 无操作
+    None,
+    /// 应用并保存设置
+    Apply(FontSettings),
+}
+
+// ============================================================================
+// 窗口状态
+// ============================================================================
+
+/// 字体设置窗口状态
+#[derive(Default)]
+pub struct FontSettingsState {
+    /// 是否显示窗口
+    pub show: bool,
+    /// 正在编辑的设置
+    pub settings: FontSettings,
+    /// 可选的系统字体
+    pub fonts: Vec<SystemFont>,
+    /// 字体名称过滤
+    pub filter: String,
+    /// 编辑器字体只列出等宽字体
+    pub monospace_only: bool,
+}
+
+impl FontSettingsState {
+    /// 创建新的窗口状态
+    pub fn new() -> Self {
+        Self {
+            monospace_only: true,
+            ..Default::default()
+        }
+    }
+
+    /// 打开窗口
+    pub fn open(&mut self, settings: FontSettings, fonts: Vec<SystemFont>) {
+        self.settings = settings;
+        self.fonts = fonts;
+        self.filter.clear();
+        self.show = true;
+    }
+
+    /// 关闭窗口
+    pub fn close(&mut self) {
+        self.show = false;
+        self.fonts.clear();
+    }
+
+    /// 按过滤条件筛选字体
+    pub fn filtered_fonts(&self, monospace_only: bool) -> Vec<&SystemFont> {
+        let filter = self.filter.trim().to_lowercase();
+        self.fonts
+            .iter()
+            .filter(|f| !monospace_only || f.looks_monospace())
+            .filter(|f| filter.is_empty() || f.name.to_lowercase().contains(&filter))
+            .collect()
+    }
+}
+
+// ============================================================================
+// 窗口 UI
+// ============================================================================
+
+/// 字体设置窗口
+pub struct FontSettingsDialog;
+
+impl FontSettingsDialog {
+    /// 显示窗口
+    pub fn show(ctx: &egui::Context, state: &mut FontSettingsState) -> FontSettingsResult {
+        if !state.show {
+            return FontSettingsResult::None;
+        }
+
+        if !keyboard::has_text_focus(ctx) && keyboard::handle_close_keys(ctx) {
+            state.close();
+            return FontSettingsResult::None;
+        }
+
+        let mut result = FontSettingsResult::None;
+        let mut close = false;
+
+        let mut open = true;
+        egui::Window::new(t("fonts.title"))
+            .id(egui::Id::new("font_settings_dialog"))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .default_width(440.0)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(t("common.filter"));
+                    ui.add(
+                        egui::TextEdit::singleline(&mut state.filter)
+                            .hint_text(t("fonts.filter_hint"))
+                            .desired_width(160.0),
+                    );
+                    ui.checkbox(&mut state.monospace_only, t("fonts.monospace_only"));
+                });
+                if state.fonts.is_empty() {
+                    ui.label(RichText::new(t("fonts.none_found")).small().color(Color32::GRAY));
+                }
+
+                ui.separator();
+
+                let all_fonts: Vec<SystemFont> = state.filtered_fonts(false).into_iter().cloned().collect();
+                let mono_fonts: Vec<SystemFont> =
+                    state.filtered_fonts(state.monospace_only).into_iter().cloned().collect();
+
+                egui::Grid::new("font_settings_grid").num_columns(3).spacing([12.0, 8.0]).show(ui, |ui| {
+                    let settings = &mut state.settings;
+                    Self::font_row(ui, "ui", t("fonts.ui"), t("fonts.builtin_ui"), &mut settings.ui, &all_fonts);
+                    Self::font_row(ui, "editor", t("fonts.editor"), t("fonts.builtin_editor"), &mut settings.editor, &mono_fonts);
+                    Self::font_row(ui, "grid", t("fonts.grid"), t("fonts.builtin_grid"), &mut settings.grid, &all_fonts);
+                });
+
+                ui.add_space(4.0);
+                ui.label(
+                    RichText::new(t("fonts.fallback_note"))
+                        .small()
+                        .color(Color32::GRAY),
+                );
+
+                ui.separator();
+                ui.horizontal(|ui| {
+                    if ui.button(t("common.ok")).clicked() {
+                        result = FontSettingsResult::Apply(state.settings.clone());
+                        close = true;
+                    }
+                    if ui.button(t("common.apply")).clicked() {
+                        result = FontSettingsResult::Apply(state.settings.clone());
+                    }
+                    if ui.button(t("common.reset_defaults")).clicked() {
+                        state.settings = FontSettings::default();
+                    }
+                    if ui.button(t("common.cancel_esc")).clicked() {
+                        close = true;
+                    }
+                });
+            });
+
+        if !open || close {
+            state.close();
+        }
+
+        result
+    }
+
+    /// 一行字体选择：字体族下拉框 + 字号
+    fn font_row(
+        ui: &mut egui::Ui,
+        id: &str,
+        label: &str,
+        builtin: &str,
+        choice: &mut FontChoice,
+        fonts: &[SystemFont],
+    ) {
+        ui.label(label);
+        let selected = choice.family.clone().unwrap_or_else(|| builtin.to_string());
+        egui::ComboBox::from_id_salt(("font_family", id))
+            .selected_text(selected)
+            .width(220.0)
+            .height(320.0)
+            .show_ui(ui, |ui| {
+                ui.selectable_value(&mut choice.family, None, builtin);
+                for font in fonts {
+                    let value = Some(font.name.clone());
+                    ui.selectable_value(&mut choice.family, value, &font.name)
+                        .on_hover_text(font.path.display().to_string());
+                }
+            });
+        ui.add(
+            egui::DragValue::new(&mut choice.size)
+                .range(constants::ui::FONT_SIZE_MIN..=constants::ui::FONT_SIZE_MAX)
+                .speed(0.5)
+                .suffix(" pt"),
+        );
+        ui.end_row();
+    }
+}
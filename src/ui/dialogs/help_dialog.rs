@@ -2,15 +2,16 @@
 //!
 //! 提供人性化的快速上手指南和功能说明
 
+use crate::core::t;
 use egui::{self, Color32, Key, RichText, ScrollArea, Vec2};
 
 pub struct HelpDialog;
 
 impl HelpDialog {
-    /// 显示帮助对话框
-    pub fn show_with_scroll(ctx: &egui::Context, open: &mut bool, _scroll_offset: &mut f32) {
+    /// 显示帮助对话框，返回是否要开始新手引导
+    pub fn show_with_scroll(ctx: &egui::Context, open: &mut bool, _scroll_offset: &mut f32) -> bool {
         if !*open {
-            return;
+            return false;
         }
 
         // 处理键盘关闭
@@ -20,10 +21,12 @@ impl HelpDialog {
 
         if should_close {
             *open = false;
-            return;
+            return false;
         }
 
-        egui::Window::new("Gridix 使用指南")
+        let mut start_tour = false;
+
+        egui::Window::new(t("help.title"))
             .open(open)
             .resizable(true)
             .default_width(680.0)
@@ -35,8 +38,13 @@ impl HelpDialog {
                 // 顶部操作提示
                 ui.horizontal(|ui| {
                     ui.spacing_mut().item_spacing = Vec2::new(12.0, 0.0);
-                    Self::hint(ui, "j/k", "滚动");
-                    Self::hint(ui, "q/Esc", "关闭");
+                    Self::hint(ui, "j/k", t("help.scroll"));
+                    Self::hint(ui, "q/Esc", t("help.close"));
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui.button(t("help.tour")).on_hover_text(t("help.tour_hint")).clicked() {
+                            start_tour = true;
+                        }
+                    });
                 });
                 ui.add_space(8.0);
                 ui.separator();
@@ -72,6 +80,7 @@ impl HelpDialog {
                         ui.add_space(20.0);
                     });
             });
+        start_tour
     }
 
     fn hint(ui: &mut egui::Ui, key: &str, desc: &str) {
@@ -89,44 +98,45 @@ impl HelpDialog {
         // =====================================================================
         // 欢迎
         // =====================================================================
-        ui.label(RichText::new("欢迎使用 Gridix!").size(20.0).strong().color(accent));
+        ui.label(RichText::new(t("help.welcome")).size(20.0).strong().color(accent));
         ui.add_space(6.0);
-        ui.label(RichText::new(
-            "Gridix 是一款采用 Helix/Vim 风格键位的数据库管理工具。\n\
-             无需频繁使用鼠标，双手始终保持在键盘上，高效管理你的数据。"
-        ).color(text));
+        ui.label(RichText::new(t("help.intro")).color(text));
 
         ui.add_space(20.0);
 
         // =====================================================================
         // 快速上手
         // =====================================================================
-        Self::section(ui, "快速上手", accent);
+        Self::section(ui, t("help.quick_start"), accent);
         
-        ui.label(RichText::new("1. 连接数据库").color(highlight));
-        ui.label(RichText::new("   按 Ctrl+N 创建新连接，支持 MySQL、PostgreSQL、SQLite").color(text));
+        ui.label(RichText::new(t("help.step_connect")).color(highlight));
+        ui.label(RichText::new(t("help.step_connect_desc")).color(text));
         ui.add_space(4.0);
         
-        ui.label(RichText::new("2. 浏览数据").color(highlight));
-        ui.label(RichText::new("   在左侧边栏选择表，点击或按 Enter 查看数据").color(text));
+        ui.label(RichText::new(t("help.step_browse")).color(highlight));
+        ui.label(RichText::new(t("help.step_browse_desc")).color(text));
         ui.add_space(4.0);
         
-        ui.label(RichText::new("3. 编辑数据").color(highlight));
-        ui.label(RichText::new("   在表格中按 i 进入编辑模式，修改后按 Ctrl+S 保存").color(text));
+        ui.label(RichText::new(t("help.step_edit")).color(highlight));
+        ui.label(RichText::new(t("help.step_edit_desc")).color(text));
         ui.add_space(4.0);
         
-        ui.label(RichText::new("4. 执行 SQL").color(highlight));
-        ui.label(RichText::new("   按 Ctrl+J 打开编辑器，输入 SQL 后按 Ctrl+Enter 执行").color(text));
+        ui.label(RichText::new(t("help.step_sql")).color(highlight));
+        ui.label(RichText::new(t("help.step_sql_desc")).color(text));
+        ui.add_space(4.0);
+
+        ui.label(RichText::new(t("help.step_drop")).color(highlight));
+        ui.label(RichText::new(t("help.step_drop_desc")).color(text));
 
         ui.add_space(20.0);
 
         // =====================================================================
         // 界面导航
         // =====================================================================
-        Self::section(ui, "界面导航 (hjkl 全局通用)", accent);
+        Self::section(ui, t("help.navigation"), accent);
         
         ui.label(RichText::new(
-            "Gridix 的各个区域之间可以用 hjkl 无缝切换："
+            t("help.navigation_desc")
         ).color(muted).italics());
         ui.add_space(8.0);
 
@@ -135,10 +145,10 @@ impl HelpDialog {
         ui.add_space(12.0);
         
         Self::keys(ui, &[
-            ("h", "向左移动 / 返回上级"),
-            ("j", "向下移动 / 进入下级区域"),
-            ("k", "向上移动 / 进入上级区域"),
-            ("l", "向右移动 / 展开 / 确认"),
+            ("h", t("help.nav_h")),
+            ("j", t("help.nav_j")),
+            ("k", t("help.nav_k")),
+            ("l", t("help.nav_l")),
         ], key_color, text);
 
         ui.add_space(20.0);
@@ -146,36 +156,47 @@ impl HelpDialog {
         // =====================================================================
         // 常用快捷键
         // =====================================================================
-        Self::section(ui, "常用快捷键", accent);
+        Self::section(ui, t("help.common_keys"), accent);
 
-        Self::subsection(ui, "窗口控制", highlight);
+        Self::subsection(ui, t("help.window"), highlight);
         Self::keys(ui, &[
-            ("Ctrl+B", "切换侧边栏"),
-            ("Ctrl+J", "切换 SQL 编辑器"),
-            ("Ctrl+R", "切换 ER 关系图"),
-            ("Ctrl+T", "新建查询标签页"),
-            ("Ctrl+W", "关闭当前标签页"),
-            ("F1", "打开此帮助"),
+            ("Ctrl+B", t("help.toggle_sidebar")),
+            ("Ctrl+J", t("help.toggle_editor")),
+            ("Ctrl+R", t("help.toggle_er")),
+            ("Ctrl+T", t("help.new_tab")),
+            ("Ctrl+W", t("help.close_tab")),
+            ("Ctrl+Shift+T", t("help.reopen_tab")),
+            ("F2", t("help.rename_tab")),
+            ("F1", t("help.open_help")),
         ], key_color, text);
 
         ui.add_space(8.0);
 
-        Self::subsection(ui, "数据操作", highlight);
+        Self::subsection(ui, t("help.data"), highlight);
         Self::keys(ui, &[
-            ("F5", "刷新数据"),
-            ("Ctrl+S", "保存修改"),
-            ("Ctrl+E", "导出数据"),
-            ("Ctrl+I", "导入数据"),
-            ("/", "添加筛选条件"),
+            ("F5", t("help.refresh")),
+            ("Ctrl+S", t("help.save")),
+            ("Ctrl+O", t("help.open_sql")),
+            ("Ctrl+Shift+S", t("help.save_sql_as")),
+            ("Ctrl+E", t("help.export")),
+            ("Ctrl+I", t("help.import")),
+            ("Ctrl+Shift+M", t("help.grants")),
+            ("/", t("help.add_filter")),
+            ("n / N", t("help.next_match")),
         ], key_color, text);
 
         ui.add_space(8.0);
 
-        Self::subsection(ui, "外观设置", highlight);
+        Self::subsection(ui, t("help.appearance"), highlight);
         Self::keys(ui, &[
-            ("Ctrl+D", "切换日间/夜间模式"),
-            ("Ctrl++/-", "放大/缩小界面"),
-            ("Ctrl+0", "重置缩放"),
+            ("Ctrl+D", t("help.toggle_theme")),
+            ("Ctrl++/-", t("help.zoom")),
+            ("Ctrl+0", t("help.reset_zoom")),
+            ("Ctrl+,", t("help.preferences")),
+            ("Ctrl+Shift+P", t("help.command_palette")),
+            ("Ctrl+P", t("help.quick_switch")),
+            ("Ctrl+Shift+G", t("help.data_search")),
+            ("Alt+K", t("help.keybindings")),
         ], key_color, text);
 
         ui.add_space(20.0);
@@ -183,52 +204,59 @@ impl HelpDialog {
         // =====================================================================
         // 表格编辑 (Helix 风格)
         // =====================================================================
-        Self::section(ui, "表格编辑 (Helix 风格)", accent);
+        Self::section(ui, t("help.grid"), accent);
 
         ui.label(RichText::new(
-            "表格采用模态编辑，类似 Helix/Vim 编辑器，分为三种模式："
+            t("help.grid_desc")
         ).color(muted).italics());
         ui.add_space(8.0);
 
-        Self::subsection(ui, "Normal 模式 - 浏览和导航", highlight);
+        Self::subsection(ui, t("help.normal_mode"), highlight);
         Self::keys(ui, &[
-            ("hjkl / 方向键", "移动光标"),
-            ("gg", "跳到第一行"),
-            ("G", "跳到最后一行"),
-            ("Ctrl+u/d", "上/下翻半页"),
-            ("5j", "向下移动5行 (数字前缀)"),
+            (t("help.arrow_keys"), t("help.move_cursor")),
+            ("gg", t("help.first_row")),
+            ("G", t("help.last_row")),
+            ("Ctrl+u/d", t("help.half_page")),
+            ("5j", t("help.count_prefix")),
+            ("Q", t("help.record_macro")),
+            ("@ / 3@", t("help.replay_macro")),
         ], key_color, text);
 
         ui.add_space(8.0);
 
-        Self::subsection(ui, "Insert 模式 - 编辑内容", highlight);
+        Self::subsection(ui, t("help.insert_mode"), highlight);
         Self::keys(ui, &[
-            ("i", "进入编辑模式"),
-            ("a", "追加模式"),
-            ("c", "清空单元格并编辑"),
-            ("Esc / Enter", "退出编辑"),
+            ("i", t("help.enter_insert")),
+            ("a", t("help.append")),
+            ("c", t("help.change")),
+            ("Esc / Enter", t("help.exit_insert")),
         ], key_color, text);
 
         ui.add_space(8.0);
 
-        Self::subsection(ui, "Select 模式 - 批量操作", highlight);
+        Self::subsection(ui, t("help.select_mode"), highlight);
         Self::keys(ui, &[
-            ("v", "进入选择模式"),
-            ("x", "选择整行"),
-            ("y", "复制选中内容"),
-            ("d", "删除选中内容"),
-            ("Esc", "退出选择"),
+            ("v", t("help.enter_select")),
+            ("x", t("help.select_row")),
+            ("y", t("help.copy_selection")),
+            ("d", t("help.delete_selection")),
+            ("f", t("help.fill_down")),
+            ("F", t("help.fill_series")),
+            ("Esc", t("help.exit_select")),
         ], key_color, text);
 
         ui.add_space(8.0);
 
-        Self::subsection(ui, "行操作", highlight);
+        Self::subsection(ui, t("help.rows"), highlight);
         Self::keys(ui, &[
-            ("o / O", "在下方/上方插入新行"),
-            ("dd", "标记删除当前行"),
-            ("yy", "复制整行"),
-            ("p", "粘贴"),
-            ("u", "撤销修改"),
+            ("o / O", t("help.insert_row")),
+            ("dd", t("help.delete_row")),
+            ("yy", t("help.copy_row")),
+            ("p", t("help.paste")),
+            ("P", t("help.paste_rows")),
+            ("u", t("help.undo")),
+            ("m", t("help.pin_row")),
+            ("< / >", t("help.move_column")),
         ], key_color, text);
 
         ui.add_space(20.0);
@@ -236,20 +264,38 @@ impl HelpDialog {
         // =====================================================================
         // SQL 编辑器
         // =====================================================================
-        Self::section(ui, "SQL 编辑器", accent);
+        Self::section(ui, t("help.editor"), accent);
 
         ui.label(RichText::new(
-            "编辑器同样支持 Normal/Insert 双模式，左下角显示当前模式。"
+            t("help.editor_desc")
         ).color(muted).italics());
         ui.add_space(8.0);
 
         Self::keys(ui, &[
-            ("i / 双击", "进入编辑模式"),
-            ("Esc", "退出编辑模式"),
-            ("Ctrl+Enter / F5", "执行 SQL"),
-            ("F6", "分析执行计划 (EXPLAIN)"),
-            ("Tab", "选择自动补全"),
-            ("Shift+k/j", "浏览历史命令"),
+            (t("help.double_click"), t("help.enter_insert")),
+            ("Esc", t("help.exit_editor_insert")),
+            ("Ctrl+Enter / F5", t("help.run_sql")),
+            ("F6", t("help.explain")),
+            ("F7 / :check", t("help.check_sql")),
+            ("Tab", t("help.accept_completion")),
+            ("Shift+k/j", t("help.history")),
+            ("Ctrl+/", t("help.toggle_comment")),
+            ("Ctrl+Alt+↑/↓", t("help.add_cursor")),
+            (t("help.multi_cursor_esc"), t("help.exit_multi_cursor")),
+        ], key_color, text);
+
+        ui.add_space(8.0);
+
+        Self::subsection(ui, t("help.command_line"), highlight);
+        Self::keys(ui, &[
+            (":run / :r", t("help.run_sql")),
+            (":format / :explain / :clear", t("help.format_explain_clear")),
+            (":w / :q", t("help.write_quit")),
+            (":tabnew / :tabn / :tabp", t("help.tab_commands")),
+            (t("help.connect_command"), t("help.switch_connection")),
+            (t("help.table_command"), t("help.open_table")),
+            (":export [csv|json|sql]", t("help.export_result")),
+            ("Tab / Shift+Tab", t("help.complete_command")),
         ], key_color, text);
 
         ui.add_space(20.0);
@@ -257,19 +303,21 @@ impl HelpDialog {
         // =====================================================================
         // 侧边栏
         // =====================================================================
-        Self::section(ui, "侧边栏导航", accent);
+        Self::section(ui, t("help.sidebar"), accent);
 
         ui.label(RichText::new(
-            "侧边栏分为多个面板：连接、数据库、表、筛选、触发器、存储过程。"
+            t("help.sidebar_desc")
         ).color(muted).italics());
         ui.add_space(8.0);
 
         Self::keys(ui, &[
-            ("j/k", "上下移动选择"),
-            ("Enter / l", "展开 / 连接 / 查询表"),
-            ("h", "折叠 / 返回上级面板"),
-            ("d", "删除选中项"),
-            ("Ctrl+1~6", "快速切换到对应面板"),
+            ("j/k", t("help.sidebar_move")),
+            ("Enter / l", t("help.sidebar_enter")),
+            ("h", t("help.sidebar_back")),
+            ("d", t("help.sidebar_delete")),
+            ("r", t("help.sidebar_rename")),
+            ("e", t("help.sidebar_edit")),
+            ("Ctrl+1~7", t("help.sidebar_panels")),
         ], key_color, text);
 
         ui.add_space(20.0);
@@ -277,19 +325,19 @@ impl HelpDialog {
         // =====================================================================
         // 筛选功能
         // =====================================================================
-        Self::section(ui, "筛选功能", accent);
+        Self::section(ui, t("help.filters"), accent);
 
         ui.label(RichText::new(
-            "在表格中按 / 或 f 快速添加筛选条件。支持以下操作符："
+            t("help.filters_desc")
         ).color(muted).italics());
         ui.add_space(8.0);
 
         Self::keys(ui, &[
-            ("~ 包含", "模糊匹配，如 ~john"),
-            ("= 等于", "精确匹配，如 =admin"),
-            ("!= 不等于", "排除匹配"),
-            ("> / <", "数值比较"),
-            ("为空 / 不为空", "NULL 判断"),
+            (t("help.op_contains"), t("help.op_contains_desc")),
+            (t("help.op_equals"), t("help.op_equals_desc")),
+            (t("help.op_not_equals"), t("help.op_not_equals_desc")),
+            ("> / <", t("help.op_compare")),
+            (t("help.op_null"), t("help.op_null_desc")),
         ], key_color, text);
 
         ui.add_space(20.0);
@@ -297,12 +345,12 @@ impl HelpDialog {
         // =====================================================================
         // 支持的数据库
         // =====================================================================
-        Self::section(ui, "支持的数据库", accent);
+        Self::section(ui, t("help.databases"), accent);
 
         Self::keys(ui, &[
-            ("MySQL", "默认端口 3306，支持 SSH 隧道"),
-            ("PostgreSQL", "默认端口 5432，支持 SSH 隧道"),
-            ("SQLite", "本地文件数据库，无需网络"),
+            ("MySQL", t("help.db_mysql")),
+            ("PostgreSQL", t("help.db_postgres")),
+            ("SQLite", t("help.db_sqlite")),
         ], key_color, text);
 
         ui.add_space(20.0);
@@ -319,10 +367,10 @@ impl HelpDialog {
         });
         ui.add_space(4.0);
         ui.label(RichText::new(
-            "一款采用 Helix 风格键位的现代数据库管理工具"
+            t("help.about")
         ).small().color(muted));
         ui.label(RichText::new(
-            "使用 Rust + egui 构建 | 开源免费"
+            t("help.built_with")
         ).small().color(muted));
         
         ui.add_space(8.0);
@@ -370,21 +418,21 @@ impl HelpDialog {
             ui.vertical(|ui| {
                 // 简化的布局示意
                 ui.label(RichText::new("    ┌─────────────────────────────────────────┐").monospace().color(box_color));
-                ui.label(RichText::new("    │              [工具栏]                   │").monospace().color(text_color));
+                ui.label(RichText::new(t("help.diagram_toolbar")).monospace().color(text_color));
                 ui.label(RichText::new("    └─────────────────────────────────────────┘").monospace().color(box_color));
                 ui.label(RichText::new("                    ↑k  ↓j").monospace().color(arrow_color));
                 ui.label(RichText::new("    ┌─────────────────────────────────────────┐").monospace().color(box_color));
-                ui.label(RichText::new("    │            [查询标签栏]                 │").monospace().color(text_color));
+                ui.label(RichText::new(t("help.diagram_tabs")).monospace().color(text_color));
                 ui.label(RichText::new("    └─────────────────────────────────────────┘").monospace().color(box_color));
                 ui.label(RichText::new("                    ↑k  ↓j").monospace().color(arrow_color));
                 ui.label(RichText::new("┌────────┐    ┌────────────────────────────────┐").monospace().color(box_color));
                 ui.label(RichText::new("│        │ ←h │                                │").monospace().color(arrow_color));
-                ui.label(RichText::new("│[侧边栏]│ l→ │          [数据表格]            │").monospace().color(text_color));
+                ui.label(RichText::new(t("help.diagram_sidebar_grid")).monospace().color(text_color));
                 ui.label(RichText::new("│        │    │                                │").monospace().color(box_color));
                 ui.label(RichText::new("└────────┘    └────────────────────────────────┘").monospace().color(box_color));
                 ui.label(RichText::new("                    ↑k  ↓j").monospace().color(arrow_color));
                 ui.label(RichText::new("              ┌────────────────────────────────┐").monospace().color(box_color));
-                ui.label(RichText::new("              │        [SQL 编辑器]            │").monospace().color(text_color));
+                ui.label(RichText::new(t("help.diagram_editor")).monospace().color(text_color));
                 ui.label(RichText::new("              └────────────────────────────────┘").monospace().color(box_color));
             });
         });
@@ -1,6 +1,7 @@
 //! 导入文件解析器
 
 use super::import_types::{ImportPreview, SqlImportConfig};
+use crate::core::t;
 
 /// 解析 SQL 文件，处理注释并分割语句
 pub fn parse_sql_file(content: &str, config: &SqlImportConfig) -> ImportPreview {
@@ -114,14 +115,14 @@ pub fn parse_sql_file(content: &str, config: &SqlImportConfig) -> ImportPreview
     let final_stmt = current_statement.trim().to_string();
     if !final_stmt.is_empty() {
         statements.push(final_stmt);
-        warnings.push("最后一条语句没有分号".to_string());
+        warnings.push(t("import.missing_semicolon").to_string());
     }
 
     // 过滤空语句
     statements.retain(|s| !s.trim().is_empty());
 
     ImportPreview {
-        columns: vec!["SQL 语句".to_string()],
+        columns: vec![t("import.sql_statement_column").to_string()],
         preview_rows: statements.iter().take(10).map(|s| vec![s.clone()]).collect(),
         total_rows: statements.len(),
         statement_count: statements.len(),
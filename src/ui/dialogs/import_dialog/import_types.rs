@@ -1,5 +1,6 @@
 //! 导入相关类型定义
 
+use crate::core::{t, Upsert, UpsertSyntax};
 use std::path::PathBuf;
 
 /// 导入格式
@@ -145,6 +146,12 @@ pub struct ImportState {
     pub csv_config: CsvImportConfig,
     /// JSON 配置
     pub json_config: JsonImportConfig,
+    /// CSV/JSON 导入时主键已存在则更新（生成 upsert 代替 INSERT）
+    pub upsert: bool,
+    /// upsert 的冲突键列（逗号分隔，PostgreSQL/DuckDB 需要）
+    pub upsert_keys: String,
+    /// 当前数据库的 upsert 语法（不支持时为 `None`）
+    pub upsert_syntax: Option<UpsertSyntax>,
     /// 预览数据
     pub preview: Option<ImportPreview>,
     /// 是否正在加载
@@ -183,6 +190,25 @@ impl ImportState {
     pub fn clear(&mut self) {
         *self = Self::default();
     }
+
+    /// 生成导入语句使用的 upsert 配置（未选择 upsert 时为 `None`）
+    pub fn upsert_config(&self) -> Result<Option<Upsert>, String> {
+        if !self.upsert {
+            return Ok(None);
+        }
+        let syntax = self.upsert_syntax.ok_or(t("import.upsert_unsupported"))?;
+        let key_columns: Vec<String> = self
+            .upsert_keys
+            .split(',')
+            .map(str::trim)
+            .filter(|k| !k.is_empty())
+            .map(str::to_string)
+            .collect();
+        if syntax.needs_key() && key_columns.is_empty() {
+            return Err(t("import.conflict_columns_required").to_string());
+        }
+        Ok(Some(Upsert { syntax, key_columns }))
+    }
 }
 
 /// 导入操作结果
@@ -14,6 +14,7 @@ pub use import_parser::parse_sql_file;
 pub use import_types::*;
 
 use super::keyboard;
+use crate::core::{t, tf, UpsertSyntax};
 use crate::ui::styles::{DANGER, GRAY, MUTED, SPACING_SM};
 use egui::{self, Color32, Key, RichText, CornerRadius, ScrollArea, TextEdit, Vec2};
 
@@ -100,7 +101,7 @@ impl ImportDialog {
             });
         }
 
-        egui::Window::new("📥 导入数据")
+        egui::Window::new(t("import.title"))
             .collapsible(false)
             .resizable(false)
             .fixed_size(Vec2::new(600.0, 500.0))
@@ -144,13 +145,13 @@ impl ImportDialog {
                     } else if state.loading {
                         ui.horizontal(|ui| {
                             ui.spinner();
-                            ui.label("正在加载...");
+                            ui.label(t("import.loading"));
                         });
                     } else if let Some(ref err) = state.error {
                         ui.label(RichText::new(format!("❌ {}", err)).color(DANGER));
                     } else {
                         ui.horizontal(|ui| {
-                            if ui.button("🔍 加载预览").clicked() {
+                            if ui.button(t("import.load_preview")).clicked() {
                                 action = ImportAction::RefreshPreview;
                             }
                         });
@@ -178,14 +179,14 @@ impl ImportDialog {
         let mut action = ImportAction::None;
 
         ui.horizontal(|ui| {
-            ui.label(RichText::new("文件:").color(GRAY));
+            ui.label(RichText::new(t("import.file")).color(GRAY));
 
             // 显示当前文件路径
             let path_text = state
                 .file_path
                 .as_ref()
                 .map(|p| p.display().to_string())
-                .unwrap_or_else(|| "未选择文件".to_string());
+                .unwrap_or_else(|| t("import.no_file").to_string());
 
             let path_display = if path_text.len() > 60 {
                 format!("...{}", &path_text[path_text.len() - 57..])
@@ -199,7 +200,7 @@ impl ImportDialog {
                     .interactive(false),
             );
 
-            if ui.button("📂 浏览...").clicked() {
+            if ui.button(t("import.browse")).clicked() {
                 action = ImportAction::SelectFile;
             }
         });
@@ -219,14 +220,14 @@ impl ImportDialog {
                     } else {
                         format!("{:.1} MB", size as f64 / 1024.0 / 1024.0)
                     };
-                    ui.label(RichText::new(format!("大小: {}", size_str)).small().color(MUTED));
+                    ui.label(RichText::new(tf("import.file_size", &[&size_str])).small().color(MUTED));
                 }
 
                 ui.separator();
 
                 // 格式图标
                 ui.label(
-                    RichText::new(format!("{} {} 格式", state.format.icon(), state.format.name()))
+                    RichText::new(tf("import.format_badge", &[state.format.icon(), state.format.name()]))
                         .small()
                         .color(MUTED),
                 );
@@ -240,7 +241,7 @@ impl ImportDialog {
     fn show_format_mode_selector(ui: &mut egui::Ui, state: &mut ImportState) {
         ui.horizontal(|ui| {
             // 格式选择
-            ui.label(RichText::new("格式:").color(GRAY));
+            ui.label(RichText::new(t("import.format")).color(GRAY));
             for (idx, fmt) in [ImportFormat::Sql, ImportFormat::Csv, ImportFormat::Json]
                 .iter()
                 .enumerate()
@@ -260,19 +261,19 @@ impl ImportDialog {
         // 模式选择（仅 SQL 格式显示）
         if state.format == ImportFormat::Sql {
             ui.horizontal(|ui| {
-                ui.label(RichText::new("模式:").color(GRAY));
+                ui.label(RichText::new(t("import.mode")).color(GRAY));
 
                 if ui
-                    .selectable_label(state.mode == ImportMode::Execute, "🚀 直接执行")
-                    .on_hover_text("逐条执行 SQL 语句")
+                    .selectable_label(state.mode == ImportMode::Execute, t("import.mode_execute"))
+                    .on_hover_text(t("import.mode_execute_hint"))
                     .clicked()
                 {
                     state.mode = ImportMode::Execute;
                 }
 
                 if ui
-                    .selectable_label(state.mode == ImportMode::CopyToEditor, "📋 复制到编辑器")
-                    .on_hover_text("将 SQL 复制到编辑器中")
+                    .selectable_label(state.mode == ImportMode::CopyToEditor, t("import.mode_copy"))
+                    .on_hover_text(t("import.mode_copy_hint"))
                     .clicked()
                 {
                     state.mode = ImportMode::CopyToEditor;
@@ -283,24 +284,24 @@ impl ImportDialog {
 
     /// SQL 选项
     fn show_sql_options(ui: &mut egui::Ui, state: &mut ImportState) {
-        egui::CollapsingHeader::new("SQL 导入选项")
+        egui::CollapsingHeader::new(t("import.sql_options"))
             .default_open(true)
             .show(ui, |ui| {
                 ui.horizontal(|ui| {
-                    ui.checkbox(&mut state.sql_config.strip_comments, "移除注释");
-                    ui.label(RichText::new("(-- 和 /* */)").small().color(MUTED));
+                    ui.checkbox(&mut state.sql_config.strip_comments, t("import.strip_comments"));
+                    ui.label(RichText::new(t("import.comment_styles")).small().color(MUTED));
                 });
 
-                ui.checkbox(&mut state.sql_config.strip_empty_lines, "移除空行");
+                ui.checkbox(&mut state.sql_config.strip_empty_lines, t("import.strip_empty_lines"));
 
                 if state.mode == ImportMode::Execute {
                     ui.add_space(SPACING_SM);
                     ui.horizontal(|ui| {
-                        ui.checkbox(&mut state.sql_config.stop_on_error, "遇到错误时停止");
+                        ui.checkbox(&mut state.sql_config.stop_on_error, t("import.stop_on_error"));
                     });
                     ui.horizontal(|ui| {
-                        ui.checkbox(&mut state.sql_config.use_transaction, "使用事务");
-                        ui.label(RichText::new("(全部成功或全部回滚)").small().color(MUTED));
+                        ui.checkbox(&mut state.sql_config.use_transaction, t("import.use_transaction"));
+                        ui.label(RichText::new(t("import.transaction_hint")).small().color(MUTED));
                     });
                 }
             });
@@ -308,16 +309,16 @@ impl ImportDialog {
 
     /// CSV 选项
     fn show_csv_options(ui: &mut egui::Ui, state: &mut ImportState, _is_mysql: bool) {
-        egui::CollapsingHeader::new("CSV 导入选项")
+        egui::CollapsingHeader::new(t("import.csv_options"))
             .default_open(true)
             .show(ui, |ui| {
                 // 表名
                 ui.horizontal(|ui| {
-                    ui.label(RichText::new("目标表:").color(GRAY));
+                    ui.label(RichText::new(t("import.target_table")).color(GRAY));
                     ui.add(
                         TextEdit::singleline(&mut state.csv_config.table_name)
                             .desired_width(150.0)
-                            .hint_text("表名"),
+                            .hint_text(t("import.table_name")),
                     );
                 });
 
@@ -325,7 +326,7 @@ impl ImportDialog {
 
                 // 分隔符
                 ui.horizontal(|ui| {
-                    ui.label(RichText::new("分隔符:").color(GRAY));
+                    ui.label(RichText::new(t("import.delimiter")).color(GRAY));
                     for (label, delim) in [(",", ','), (";", ';'), ("Tab", '\t'), ("|", '|')] {
                         if ui
                             .selectable_label(state.csv_config.delimiter == delim, label)
@@ -340,11 +341,11 @@ impl ImportDialog {
                 ui.add_space(SPACING_SM);
 
                 ui.horizontal(|ui| {
-                    ui.checkbox(&mut state.csv_config.has_header, "首行为表头");
+                    ui.checkbox(&mut state.csv_config.has_header, t("import.has_header"));
 
                     ui.separator();
 
-                    ui.label(RichText::new("跳过行:").color(GRAY));
+                    ui.label(RichText::new(t("import.skip_rows")).color(GRAY));
                     let mut skip_str = state.csv_config.skip_rows.to_string();
                     if ui
                         .add(TextEdit::singleline(&mut skip_str).desired_width(40.0))
@@ -354,21 +355,23 @@ impl ImportDialog {
                         state.preview = None;
                     }
                 });
+
+                Self::show_upsert_options(ui, state);
             });
     }
 
     /// JSON 选项
     fn show_json_options(ui: &mut egui::Ui, state: &mut ImportState, _is_mysql: bool) {
-        egui::CollapsingHeader::new("JSON 导入选项")
+        egui::CollapsingHeader::new(t("import.json_options"))
             .default_open(true)
             .show(ui, |ui| {
                 // 表名
                 ui.horizontal(|ui| {
-                    ui.label(RichText::new("目标表:").color(GRAY));
+                    ui.label(RichText::new(t("import.target_table")).color(GRAY));
                     ui.add(
                         TextEdit::singleline(&mut state.json_config.table_name)
                             .desired_width(150.0)
-                            .hint_text("表名"),
+                            .hint_text(t("import.table_name")),
                     );
                 });
 
@@ -376,28 +379,57 @@ impl ImportDialog {
 
                 // JSON 路径
                 ui.horizontal(|ui| {
-                    ui.label(RichText::new("数据路径:").color(GRAY));
+                    ui.label(RichText::new(t("import.json_path")).color(GRAY));
                     ui.add(
                         TextEdit::singleline(&mut state.json_config.json_path)
                             .desired_width(200.0)
-                            .hint_text("例如: data.items (留空表示根数组)"),
+                            .hint_text(t("import.json_path_hint")),
                     );
                 });
 
                 ui.add_space(SPACING_SM);
 
-                ui.checkbox(&mut state.json_config.flatten_nested, "展平嵌套对象");
+                ui.checkbox(&mut state.json_config.flatten_nested, t("import.flatten_nested"));
+
+                Self::show_upsert_options(ui, state);
             });
     }
 
+    /// 主键已存在时的处理方式（INSERT 或 upsert）
+    fn show_upsert_options(ui: &mut egui::Ui, state: &mut ImportState) {
+        ui.add_space(SPACING_SM);
+        let Some(syntax) = state.upsert_syntax else {
+            state.upsert = false;
+            return;
+        };
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut state.upsert, t("import.upsert"));
+            let hint = match syntax {
+                UpsertSyntax::OnConflict => "ON CONFLICT DO UPDATE",
+                UpsertSyntax::OnDuplicateKey => "ON DUPLICATE KEY UPDATE",
+                UpsertSyntax::InsertOrReplace => "INSERT OR REPLACE",
+            };
+            ui.label(RichText::new(hint).small().color(MUTED));
+        });
+        if state.upsert && syntax.needs_key() {
+            ui.horizontal(|ui| {
+                ui.label(RichText::new(t("import.conflict_columns")).color(GRAY));
+                ui.add(
+                    TextEdit::singleline(&mut state.upsert_keys)
+                        .desired_width(200.0)
+                        .hint_text(t("import.conflict_columns_hint")),
+                );
+            });
+        }
+    }
+
     /// 预览区域
     fn show_preview(ui: &mut egui::Ui, state: &ImportState, preview: &ImportPreview) {
         let header = match state.format {
-            ImportFormat::Sql => format!("预览 ({} 条 SQL 语句)", preview.statement_count),
-            _ => format!(
-                "预览 ({} 列 × {} 行)",
-                preview.columns.len(),
-                preview.total_rows
+            ImportFormat::Sql => tf("import.preview_statements", &[&preview.statement_count.to_string()]),
+            _ => tf(
+                "import.preview_table",
+                &[&preview.columns.len().to_string(), &preview.total_rows.to_string()],
             ),
         };
 
@@ -453,7 +485,7 @@ impl ImportDialog {
 
         if preview.statement_count > 10 {
             ui.label(
-                RichText::new(format!("... 还有 {} 条语句", preview.statement_count - 10))
+                RichText::new(tf("import.more_statements", &[&(preview.statement_count - 10).to_string()]))
                     .small()
                     .color(MUTED),
             );
@@ -465,7 +497,7 @@ impl ImportDialog {
         use egui_extras::{Column, TableBuilder};
 
         if preview.columns.is_empty() {
-            ui.label(RichText::new("无数据").color(MUTED));
+            ui.label(RichText::new(t("import.no_data")).color(MUTED));
             return;
         }
 
@@ -503,9 +535,9 @@ impl ImportDialog {
         if preview.total_rows > preview.preview_rows.len() {
             ui.add_space(SPACING_SM);
             ui.label(
-                RichText::new(format!(
-                    "... 还有 {} 行数据",
-                    preview.total_rows - preview.preview_rows.len()
+                RichText::new(tf(
+                    "import.more_rows",
+                    &[&(preview.total_rows - preview.preview_rows.len()).to_string()],
                 ))
                 .small()
                 .color(MUTED),
@@ -522,13 +554,13 @@ impl ImportDialog {
             let has_preview = state.preview.is_some();
 
             // 刷新预览按钮
-            if has_file && ui.button("🔄 刷新预览 [Ctrl+R]").clicked() {
+            if has_file && ui.button(t("import.refresh_preview")).clicked() {
                 action = ImportAction::RefreshPreview;
             }
 
             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                 // 取消按钮
-                if ui.button("取消 [Esc]").clicked() {
+                if ui.button(t("common.cancel_esc")).clicked() {
                     *show = false;
                     action = ImportAction::Close;
                 }
@@ -538,8 +570,8 @@ impl ImportDialog {
 
                 ui.add_enabled_ui(can_import, |ui| {
                     let btn_text = match state.mode {
-                        ImportMode::Execute => "🚀 执行导入 [Enter]",
-                        ImportMode::CopyToEditor => "📋 复制到编辑器 [Enter]",
+                        ImportMode::Execute => t("import.execute"),
+                        ImportMode::CopyToEditor => t("import.copy_to_editor"),
                     };
 
                     if ui.button(RichText::new(btn_text).strong()).clicked() {
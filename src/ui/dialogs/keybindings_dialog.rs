@@ -2,7 +2,7 @@
 //!
 //! 允许用户自定义快捷键绑定。
 
-use crate::core::{Action, KeyBinding, KeyBindings, KeyCode, KeyModifiers};
+use crate::core::{t, tf, Action, KeyBinding, KeyBindings, KeyCode, KeyModifiers};
 use eframe::egui::{self, Key, RichText};
 
 /// 快捷键编辑对话框状态
@@ -28,6 +28,8 @@ pub struct KeyBindingsDialogState {
     has_changes: bool,
     /// 冲突提示
     conflict_message: Option<String>,
+    /// 与其他操作冲突、等待确认替换的绑定：(操作, 新快捷键, 冲突的操作)
+    pending_conflict: Option<(Action, KeyBinding, Action)>,
 }
 
 impl KeyBindingsDialogState {
@@ -43,12 +45,19 @@ impl KeyBindingsDialogState {
         self.current_category = None;
         self.has_changes = false;
         self.conflict_message = None;
+        self.pending_conflict = None;
     }
 
     /// 关闭对话框
     pub fn close(&mut self) {
         self.show = false;
         self.recording = false;
+        self.pending_conflict = None;
+    }
+
+    /// 是否正在录制快捷键（录制期间应用不响应快捷键）
+    pub fn is_recording(&self) -> bool {
+        self.show && self.recording
     }
 
     /// 重置为默认快捷键
@@ -56,6 +65,7 @@ impl KeyBindingsDialogState {
         self.bindings = KeyBindings::default();
         self.has_changes = true;
         self.conflict_message = None;
+        self.pending_conflict = None;
     }
 
     /// 获取编辑后的快捷键绑定
@@ -64,16 +74,32 @@ impl KeyBindingsDialogState {
         &self.bindings
     }
 
-    /// 检查快捷键冲突
-    fn check_conflict(&self, action: Action, binding: &KeyBinding) -> Option<Action> {
-        for a in Action::all() {
-            if *a != action
-                && let Some(existing) = self.bindings.get(*a)
-                    && existing == binding {
-                        return Some(*a);
-                    }
+    /// 应用录制的快捷键：无效时给出提示，与其他操作冲突时等待确认替换
+    fn apply_recorded(&mut self, action: Action, binding: KeyBinding) {
+        self.pending_conflict = None;
+        if !binding.is_valid_shortcut() {
+            self.conflict_message = Some(tf("keys.missing_modifier", &[&binding.display()]));
+        } else if let Some(conflict_action) = self.bindings.conflict_with(action, &binding) {
+            self.conflict_message = Some(tf(
+                "keys.in_use",
+                &[&binding.display(), conflict_action.description()],
+            ));
+            self.pending_conflict = Some((action, binding, conflict_action));
+        } else {
+            self.bindings.set(action, binding);
+            self.has_changes = true;
+            self.conflict_message = None;
+        }
+    }
+
+    /// 确认替换：把快捷键从冲突的操作移到当前操作
+    fn replace_conflict(&mut self) {
+        if let Some((action, binding, conflict_action)) = self.pending_conflict.take() {
+            self.bindings.remove(conflict_action);
+            self.bindings.set(action, binding);
+            self.has_changes = true;
+            self.conflict_message = None;
         }
-        None
     }
 }
 
@@ -92,7 +118,7 @@ impl KeyBindingsDialog {
         let mut result = None;
         let mut should_close = false;
 
-        egui::Window::new("快捷键设置")
+        egui::Window::new(t("keys.title"))
             .collapsible(false)
             .resizable(true)
             .default_width(600.0)
@@ -102,24 +128,24 @@ impl KeyBindingsDialog {
                 // 顶部工具栏
                 ui.horizontal(|ui| {
                     // 搜索框
-                    ui.label("搜索:");
+                    ui.label(t("keys.search"));
                     ui.add(
                         egui::TextEdit::singleline(&mut state.filter)
                             .desired_width(150.0)
-                            .hint_text("输入操作名称...")
+                            .hint_text(t("keys.search_hint"))
                     );
 
                     ui.separator();
 
                     // 分类筛选
-                    ui.label("分类:");
+                    ui.label(t("keys.category"));
                     egui::ComboBox::from_id_salt("category_filter")
-                        .selected_text(state.current_category.unwrap_or("全部"))
+                        .selected_text(state.current_category.unwrap_or(t("keys.all")))
                         .show_ui(ui, |ui| {
-                            if ui.selectable_label(state.current_category.is_none(), "全部").clicked() {
+                            if ui.selectable_label(state.current_category.is_none(), t("keys.all")).clicked() {
                                 state.current_category = None;
                             }
-                            for category in &["全局", "创建", "Tab", "编辑", "缩放"] {
+                            for category in Action::categories() {
                                 if ui.selectable_label(
                                     state.current_category == Some(*category),
                                     *category
@@ -130,7 +156,7 @@ impl KeyBindingsDialog {
                         });
 
                     ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                        if ui.button("重置为默认").clicked() {
+                        if ui.button(t("keys.reset")).clicked() {
                             state.reset_to_defaults();
                         }
                     });
@@ -139,14 +165,35 @@ impl KeyBindingsDialog {
                 ui.add_space(8.0);
 
                 // 冲突提示
-                if let Some(msg) = &state.conflict_message {
+                if let Some(msg) = state.conflict_message.clone() {
                     ui.horizontal(|ui| {
                         ui.label(RichText::new("⚠").color(egui::Color32::YELLOW));
                         ui.label(RichText::new(msg).color(egui::Color32::YELLOW));
+                        if state.pending_conflict.is_some() {
+                            if ui.button(t("keys.replace")).clicked() {
+                                state.replace_conflict();
+                            }
+                            if ui.button(t("keys.keep")).clicked() {
+                                state.pending_conflict = None;
+                                state.conflict_message = None;
+                            }
+                        }
                     });
                     ui.add_space(4.0);
                 }
 
+                // 已有的冲突（如手动编辑配置文件导致）
+                let conflicts = state.bindings.find_conflicts();
+                for (a, b, binding) in &conflicts {
+                    ui.label(
+                        RichText::new(tf(
+                            "keys.conflict",
+                            &[a.description(), b.description(), &binding.display()],
+                        ))
+                        .color(egui::Color32::from_rgb(255, 100, 100)),
+                    );
+                }
+
                 // 快捷键列表
                 egui::ScrollArea::vertical()
                     .max_height(350.0)
@@ -157,9 +204,9 @@ impl KeyBindingsDialog {
                             .striped(true)
                             .show(ui, |ui| {
                                 // 表头
-                                ui.label(RichText::new("操作").strong());
-                                ui.label(RichText::new("快捷键").strong());
-                                ui.label(RichText::new("分类").strong());
+                                ui.label(RichText::new(t("keys.action")).strong());
+                                ui.label(RichText::new(t("keys.shortcut")).strong());
+                                ui.label(RichText::new(t("keys.category_header")).strong());
                                 ui.end_row();
 
                                 let filter_lower = state.filter.to_lowercase();
@@ -190,22 +237,22 @@ impl KeyBindingsDialog {
                                     // 快捷键显示/编辑
                                     let binding_text = state.bindings.get(*action)
                                         .map(|b| b.display())
-                                        .unwrap_or_else(|| "未设置".to_string());
+                                        .unwrap_or_else(|| t("keys.unset").to_string());
 
                                     let is_recording = state.recording && state.selected_action == Some(*action);
 
                                     if is_recording {
                                         // 显示录制中状态
-                                        let recording_text = if state.recorded_key.is_some() {
+                                        let recording_text = if let Some(key) = state.recorded_key {
                                             let binding = KeyBinding::new(
-                                                state.recorded_key.unwrap(),
+                                                key,
                                                 state.recorded_modifiers,
                                             );
                                             binding.display()
                                         } else if state.recorded_modifiers != KeyModifiers::NONE {
                                             format!("{}+...", state.recorded_modifiers)
                                         } else {
-                                            "按下快捷键...".to_string()
+                                            t("keys.press_keys").to_string()
                                         };
 
                                         ui.label(
@@ -244,27 +291,18 @@ impl KeyBindingsDialog {
                             if let egui::Event::Key { key, pressed: true, .. } = event {
                                 // 尝试转换为 KeyCode（修饰键会返回 None）
                                 if let Some(key_code) = KeyCode::from_egui_key(*key) {
+                                    // ESC 用于取消录制
+                                    if key_code == KeyCode::Escape {
+                                        continue;
+                                    }
                                     state.recorded_key = Some(key_code);
 
-                                    // 创建新绑定
+                                    // 创建新绑定并检查冲突
                                     let new_binding = KeyBinding::new(
                                         key_code,
                                         state.recorded_modifiers,
                                     );
-
-                                    // 检查冲突
-                                    if let Some(conflict_action) = state.check_conflict(action, &new_binding) {
-                                        state.conflict_message = Some(format!(
-                                            "快捷键 {} 已被 \"{}\" 使用",
-                                            new_binding.display(),
-                                            conflict_action.description()
-                                        ));
-                                    } else {
-                                        // 应用新绑定
-                                        state.bindings.set(action, new_binding);
-                                        state.has_changes = true;
-                                        state.conflict_message = None;
-                                    }
+                                    state.apply_recorded(action, new_binding);
 
                                     state.recording = false;
                                 }
@@ -285,18 +323,21 @@ impl KeyBindingsDialog {
                 ui.horizontal(|ui| {
                     // 清除选中操作的快捷键
                     if let Some(action) = state.selected_action
-                        && ui.button("清除快捷键").clicked() {
+                        && ui.button(t("keys.clear")).clicked() {
                             state.bindings.remove(action);
                             state.has_changes = true;
                         }
 
                     ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                        if ui.button("取消").clicked() {
+                        if ui.button(t("common.cancel")).clicked() {
                             should_close = true;
                         }
 
-                        let save_text = if state.has_changes { "保存 *" } else { "保存" };
-                        if ui.button(save_text).clicked() {
+                        let save_text = if state.has_changes { t("keys.save_changed") } else { t("keys.save") };
+                        let save = ui
+                            .add_enabled(conflicts.is_empty(), egui::Button::new(save_text))
+                            .on_disabled_hover_text(t("keys.resolve_conflicts"));
+                        if save.clicked() {
                             result = Some(state.bindings.clone());
                             should_close = true;
                         }
@@ -306,7 +347,7 @@ impl KeyBindingsDialog {
                 // 帮助提示
                 ui.add_space(4.0);
                 ui.label(
-                    RichText::new("提示: 选中操作后点击快捷键按钮开始录制，按 ESC 取消")
+                    RichText::new(t("keys.hint"))
                         .small()
                         .weak()
                 );
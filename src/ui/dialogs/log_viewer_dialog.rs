@@ -0,0 +1,224 @@
+//! 日志查看器
+//!
+//! 浏览应用运行日志，按级别和文本筛选；跟随模式下自动读取新追加的记录并滚动到底部。
+//! 筛选结果可复制，便于附加到问题反馈中。
+
+use std::time::{Duration, Instant};
+
+use super::keyboard;
+use crate::core::{LogEntry, LogLevel, LogTail};
+use egui::{self, Color32, RichText, TextEdit};
+
+/// 窗口中最多保留的记录数
+pub const MAX_LOG_VIEWER_ENTRIES: usize = 5000;
+
+/// 跟随模式下读取新记录的间隔
+const FOLLOW_INTERVAL: Duration = Duration::from_secs(1);
+
+// ============================================================================
+// 窗口状态
+// ============================================================================
+
+/// 日志查看器状态
+pub struct LogViewerState {
+    /// 是否显示窗口
+    pub show: bool,
+    /// 日志文件
+    tail: Option<LogTail>,
+    /// 已读取的记录（按写入顺序）
+    pub entries: Vec<LogEntry>,
+    /// 只显示不低于该级别的记录
+    pub min_level: LogLevel,
+    /// 搜索文本（匹配整行）
+    pub search: String,
+    /// 跟随模式：自动读取新记录并滚动到底部
+    pub follow: bool,
+    /// 上次读取时间
+    last_poll: Option<Instant>,
+    /// 读取错误
+    pub error: Option<String>,
+}
+
+impl Default for LogViewerState {
+    fn default() -> Self {
+        Self {
+            show: false,
+            tail: None,
+            entries: Vec::new(),
+            min_level: LogLevel::Info,
+            search: String::new(),
+            follow: true,
+            last_poll: None,
+            error: None,
+        }
+    }
+}
+
+impl LogViewerState {
+    /// 创建新的窗口状态
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 打开窗口并读取日志文件
+    pub fn open(&mut self, tail: LogTail) {
+        self.show = true;
+        self.tail = Some(tail);
+        self.reload();
+    }
+
+    /// 关闭窗口
+    pub fn close(&mut self) {
+        self.show = false;
+        self.entries.clear();
+    }
+
+    /// 日志文件路径
+    pub fn path(&self) -> Option<String> {
+        self.tail.as_ref().map(|t| t.path().display().to_string())
+    }
+
+    /// 从头重新读取
+    pub fn reload(&mut self) {
+        self.entries.clear();
+        if let Some(tail) = &self.tail {
+            self.tail = Some(LogTail::new(tail.path()));
+        }
+        self.poll();
+    }
+
+    /// 读取新追加的记录，超出上限时丢弃最早的记录
+    pub fn poll(&mut self) {
+        self.last_poll = Some(Instant::now());
+        let Some(tail) = &mut self.tail else {
+            return;
+        };
+        match tail.poll() {
+            Ok(entries) => {
+                self.error = None;
+                self.entries.extend(entries);
+                if self.entries.len() > MAX_LOG_VIEWER_ENTRIES {
+                    let excess = self.entries.len() - MAX_LOG_VIEWER_ENTRIES;
+                    self.entries.drain(..excess);
+                }
+            }
+            Err(e) => self.error = Some(format!("读取日志失败: {}", e)),
+        }
+    }
+
+    /// 符合筛选条件的记录
+    pub fn filtered(&self) -> Vec<&LogEntry> {
+        let search = self.search.trim().to_lowercase();
+        self.entries
+            .iter()
+            .filter(|e| e.level >= self.min_level)
+            .filter(|e| search.is_empty() || e.to_line().to_lowercase().contains(&search))
+            .collect()
+    }
+
+    /// 筛选结果的文本，每行一条
+    pub fn filtered_text(&self) -> String {
+        self.filtered().iter().map(|e| e.to_line()).collect::<Vec<_>>().join("\n")
+    }
+}
+
+// ============================================================================
+// 窗口 UI
+// ============================================================================
+
+/// 日志查看器窗口
+pub struct LogViewerDialog;
+
+impl LogViewerDialog {
+    /// 显示窗口
+    pub fn show(ctx: &egui::Context, state: &mut LogViewerState) {
+        if !state.show {
+            return;
+        }
+
+        if !keyboard::has_text_focus(ctx) && keyboard::handle_close_keys(ctx) {
+            state.close();
+            return;
+        }
+
+        if state.follow {
+            if state.last_poll.is_none_or(|t| t.elapsed() >= FOLLOW_INTERVAL) {
+                state.poll();
+            }
+            ctx.request_repaint_after(FOLLOW_INTERVAL);
+        }
+
+        let mut open = true;
+        egui::Window::new("🪵 运行日志")
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(true)
+            .default_size([860.0, 500.0])
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                if let Some(path) = state.path() {
+                    ui.horizontal(|ui| {
+                        ui.label(RichText::new(&path).small().color(Color32::GRAY))
+                            .on_hover_text("每行一条 JSON 记录，超过大小上限时轮转为 .1、.2 ……");
+                        if ui.small_button("📋").on_hover_text("复制路径").clicked() {
+                            ui.ctx().copy_text(path);
+                        }
+                    });
+                }
+
+                // 筛选
+                ui.horizontal(|ui| {
+                    egui::ComboBox::from_id_salt("log_level_filter")
+                        .selected_text(format!("≥ {}", state.min_level.label()))
+                        .width(90.0)
+                        .show_ui(ui, |ui| {
+                            for level in LogLevel::ALL {
+                                ui.selectable_value(
+                                    &mut state.min_level,
+                                    level,
+                                    RichText::new(level.label()).color(level.color()),
+                                );
+                            }
+                        });
+                    ui.add(TextEdit::singleline(&mut state.search).hint_text("🔍 搜索").desired_width(220.0));
+                    ui.checkbox(&mut state.follow, "跟随")
+                        .on_hover_text("自动读取新日志并滚动到底部");
+                    if ui.button("🔄 重新读取").clicked() {
+                        state.reload();
+                    }
+                    if ui
+                        .button("📋 复制")
+                        .on_hover_text("复制筛选后的日志，便于附加到问题反馈")
+                        .clicked()
+                    {
+                        ui.ctx().copy_text(state.filtered_text());
+                    }
+                });
+
+                if let Some(err) = &state.error {
+                    ui.label(RichText::new(err).color(Color32::from_rgb(255, 100, 100)));
+                }
+                ui.separator();
+
+                let entries = state.filtered();
+                ui.label(
+                    RichText::new(format!("{} / {} 条", entries.len(), state.entries.len()))
+                        .small()
+                        .color(Color32::GRAY),
+                );
+                let row_height = ui.text_style_height(&egui::TextStyle::Monospace);
+                egui::ScrollArea::both()
+                    .auto_shrink([false, false])
+                    .stick_to_bottom(state.follow)
+                    .show_rows(ui, row_height, entries.len(), |ui, range| {
+                        for entry in &entries[range] {
+                            ui.label(RichText::new(entry.to_line()).monospace().color(entry.level.color()));
+                        }
+                    });
+            });
+
+        if !open {
+            state.close();
+        }
+    }
+}
@@ -0,0 +1,230 @@
+//! 主密码对话框
+//!
+//! 启动时解锁主密码加密的连接配置，以及设置、修改或取消主密码。
+
+use super::keyboard::{self, DialogAction};
+use egui::{self, Color32, RichText, TextEdit};
+
+// ============================================================================
+// 对话框结果
+// ============================================================================
+
+/// 主密码对话框的结果
+pub enum MasterPasswordResult {
+    /// 无操作
+    None,
+    /// 用输入的主密码解锁（成功后由调用方关闭对话框）
+    Unlock(String),
+    /// 设置新的主密码
+    Set(String),
+    /// 取消主密码
+    Remove,
+    /// 用户关闭对话框
+    Cancelled,
+}
+
+/// 对话框的用途
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MasterPasswordMode {
+    /// 解锁加密的连接配置
+    #[default]
+    Unlock,
+    /// 设置或修改主密码
+    Set {
+        /// 是否已设置主密码
+        has_password: bool,
+    },
+}
+
+// ============================================================================
+// 对话框状态
+// ============================================================================
+
+/// 主密码对话框状态
+#[derive(Default)]
+pub struct MasterPasswordState {
+    /// 是否显示对话框
+    pub show: bool,
+    /// 对话框用途
+    pub mode: MasterPasswordMode,
+    /// 主密码
+    pub password: String,
+    /// 确认主密码
+    pub confirm: String,
+    /// 错误信息
+    pub error: Option<String>,
+    /// 是否已让输入框获得焦点
+    focused: bool,
+}
+
+impl MasterPasswordState {
+    /// 创建新的对话框状态
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 打开解锁对话框
+    pub fn open_unlock(&mut self) {
+        self.open(MasterPasswordMode::Unlock);
+    }
+
+    /// 打开设置主密码对话框
+    pub fn open_settings(&mut self, has_password: bool) {
+        self.open(MasterPasswordMode::Set { has_password });
+    }
+
+    fn open(&mut self, mode: MasterPasswordMode) {
+        *self = Self { show: true, mode, ..Self::default() };
+    }
+
+    /// 关闭对话框
+    pub fn close(&mut self) {
+        *self = Self::default();
+    }
+
+    /// 操作失败，显示错误并清空输入
+    pub fn fail(&mut self, error: impl Into<String>) {
+        self.error = Some(error.into());
+        self.password.clear();
+        self.confirm.clear();
+        self.focused = false;
+    }
+
+    /// 校验输入，返回主密码
+    pub fn validate(&self) -> Result<String, String> {
+        if self.password.is_empty() {
+            return Err("主密码不能为空".to_string());
+        }
+        if matches!(self.mode, MasterPasswordMode::Set { .. }) && self.password != self.confirm {
+            return Err("两次输入的主密码不一致".to_string());
+        }
+        Ok(self.password.clone())
+    }
+
+    /// 提交输入
+    fn submit(&mut self) -> MasterPasswordResult {
+        match self.validate() {
+            Ok(password) => match self.mode {
+                MasterPasswordMode::Unlock => MasterPasswordResult::Unlock(password),
+                MasterPasswordMode::Set { .. } => MasterPasswordResult::Set(password),
+            },
+            Err(e) => {
+                self.error = Some(e);
+                MasterPasswordResult::None
+            }
+        }
+    }
+}
+
+// ============================================================================
+// 对话框 UI
+// ============================================================================
+
+/// 主密码对话框
+pub struct MasterPasswordDialog;
+
+impl MasterPasswordDialog {
+    /// 显示对话框
+    pub fn show(ctx: &egui::Context, state: &mut MasterPasswordState) -> MasterPasswordResult {
+        if !state.show {
+            return MasterPasswordResult::None;
+        }
+
+        let mut result = MasterPasswordResult::None;
+
+        if !keyboard::has_text_focus(ctx) {
+            if keyboard::handle_close_keys(ctx) {
+                state.close();
+                return MasterPasswordResult::Cancelled;
+            }
+            if let DialogAction::Confirm = keyboard::handle_dialog_keys(ctx) {
+                result = state.submit();
+            }
+        }
+
+        let is_unlock = state.mode == MasterPasswordMode::Unlock;
+        let title = if is_unlock { "🔐 解锁连接" } else { "🔐 主密码" };
+        egui::Window::new(title)
+            .collapsible(false)
+            .resizable(false)
+            .min_width(380.0)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                let hint = if is_unlock {
+                    "连接配置已使用主密码加密，请输入主密码解锁"
+                } else {
+                    "主密码用于加密配置文件中的所有连接，启动时需要输入"
+                };
+                ui.label(RichText::new(hint).color(Color32::from_rgb(150, 150, 160)));
+                ui.add_space(8.0);
+
+                let mut enter_pressed = false;
+                egui::Grid::new("master_password_form")
+                    .num_columns(2)
+                    .spacing([12.0, 8.0])
+                    .show(ui, |ui| {
+                        ui.label(if is_unlock { "主密码:" } else { "新主密码:" });
+                        let response = ui.add(
+                            TextEdit::singleline(&mut state.password)
+                                .password(true)
+                                .desired_width(220.0),
+                        );
+                        if !state.focused {
+                            response.request_focus();
+                            state.focused = true;
+                        }
+                        enter_pressed |= response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                        ui.end_row();
+
+                        if !is_unlock {
+                            ui.label("确认主密码:");
+                            let response = ui.add(
+                                TextEdit::singleline(&mut state.confirm)
+                                    .password(true)
+                                    .desired_width(220.0),
+                            );
+                            enter_pressed |= response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                            ui.end_row();
+                        }
+                    });
+                if enter_pressed {
+                    result = state.submit();
+                }
+
+                ui.add_space(4.0);
+                ui.label(
+                    RichText::new("提示: 忘记主密码将无法恢复已保存的连接")
+                        .small()
+                        .color(Color32::from_rgb(120, 120, 120)),
+                );
+
+                if let Some(err) = &state.error {
+                    ui.add_space(4.0);
+                    ui.label(RichText::new(err).color(Color32::from_rgb(255, 100, 100)));
+                }
+
+                ui.add_space(8.0);
+                ui.horizontal(|ui| {
+                    let confirm = if is_unlock { "解锁 [Enter]" } else { "保存 [Enter]" };
+                    if ui.button(confirm).clicked() {
+                        result = state.submit();
+                    }
+                    if let MasterPasswordMode::Set { has_password: true } = state.mode
+                        && ui.button("取消主密码").clicked()
+                    {
+                        result = MasterPasswordResult::Remove;
+                    }
+                    let cancel = if is_unlock { "跳过 [Esc]" } else { "取消 [Esc]" };
+                    if ui.button(cancel).clicked() {
+                        result = MasterPasswordResult::Cancelled;
+                    }
+                });
+            });
+
+        match result {
+            MasterPasswordResult::Set(_) | MasterPasswordResult::Remove | MasterPasswordResult::Cancelled => state.close(),
+            MasterPasswordResult::None | MasterPasswordResult::Unlock(_) => {}
+        }
+        result
+    }
+}
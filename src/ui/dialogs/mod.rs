@@ -10,28 +10,115 @@
 //! - `1-9` - 数字键快速选择
 
 mod about_dialog;
+mod api_server_dialog;
+mod audit_log_dialog;
+mod benchmark_dialog;
+mod bulk_edit_dialog;
+mod change_key_dialog;
+mod command_palette_dialog;
 mod common;
 mod confirm_dialog;
 mod connection_dialog;
 mod create_db_dialog;
 mod create_user_dialog;
+mod data_search_dialog;
+mod datagen_dialog;
 mod ddl_dialog;
+mod dependency_dialog;
 mod dialog_trait;
 mod export_dialog;
+mod file_change_dialog;
+mod font_settings_dialog;
 mod help_dialog;
 mod import_dialog;
 mod keybindings_dialog;
+mod log_viewer_dialog;
+mod master_password_dialog;
+mod notification_center_dialog;
+mod object_ddl_dialog;
+mod pivot_dialog;
+mod pool_stats_dialog;
+mod preferences_dialog;
+mod privilege_dialog;
+mod profile_dialog;
+mod result_diff_dialog;
+mod row_bookmark_dialog;
+mod scheduler_dialog;
+mod sequence_dialog;
+mod session_monitor_dialog;
+mod size_overview_dialog;
+mod sql_diff_dialog;
+mod sql_review_dialog;
+mod table_action_dialog;
+mod table_ddl_dialog;
+mod theme_editor_dialog;
+mod trash_dialog;
+mod workflow_dialog;
+mod write_preview_dialog;
 pub mod keyboard;
 
 pub use about_dialog::AboutDialog;
+pub use api_server_dialog::{ApiServerDialog, ApiServerResult, ApiServerState};
+pub use audit_log_dialog::{AuditLogDialog, AuditLogResult, AuditLogState};
+pub use benchmark_dialog::{BenchmarkDialog, BenchmarkResult, BenchmarkState};
+pub use bulk_edit_dialog::{BulkEditDialog, BulkEditResult, BulkEditState, BulkValueKind};
+pub use change_key_dialog::{ChangeKeyDialog, ChangeKeyDialogResult, ChangeKeyDialogState};
+pub use command_palette_dialog::{CommandPalette, CommandPaletteState, PaletteCommand, PaletteItem};
 pub use confirm_dialog::ConfirmDialog;
 pub use connection_dialog::ConnectionDialog;
 pub use create_db_dialog::{CreateDbDialog, CreateDbDialogResult, CreateDbDialogState};
 pub use create_user_dialog::{CreateUserDialog, CreateUserDialogResult, CreateUserDialogState};
-pub use ddl_dialog::{ColumnDefinition, ColumnType, DdlDialog, DdlDialogState, TableDefinition};
+pub use data_search_dialog::{
+    DataSearchDialog, DataSearchHit, DataSearchOutcome, DataSearchResult, DataSearchState, DataSearchTable,
+};
+pub use datagen_dialog::{
+    insert_rows_sql, DataGenColumn, DataGenDialog, DataGenPlan, DataGenResult, DataGenState, DATAGEN_BATCH_SIZE,
+};
+pub use ddl_dialog::{
+    supports_alter_foreign_key, supports_foreign_keys, ColumnDefinition, ColumnType, DdlDialog, DdlDialogResult,
+    DdlDialogState, ForeignKeyDefinition, TableDefinition,
+};
+pub use dependency_dialog::{DependencyData, DependencyDialog, DependencyResult, DependencyState};
 pub use export_dialog::{ExportConfig, ExportDialog};
+pub use file_change_dialog::{FileChangeDialog, FileChangeResult, FileChangeState};
+pub use font_settings_dialog::{FontSettingsDialog, FontSettingsResult, FontSettingsState};
 pub use help_dialog::HelpDialog;
 pub use keybindings_dialog::{KeyBindingsDialog, KeyBindingsDialogState};
+pub use log_viewer_dialog::{LogViewerDialog, LogViewerState};
+pub use master_password_dialog::{MasterPasswordDialog, MasterPasswordMode, MasterPasswordResult, MasterPasswordState};
+pub use notification_center_dialog::{NotificationCenterDialog, NotificationCenterResult, NotificationCenterState};
+pub use pivot_dialog::{PivotDialog, PivotResult, PivotState};
+pub use pool_stats_dialog::{PoolStatsDialog, PoolStatsResult, PoolStatsState};
+pub use preferences_dialog::{PreferencesDialog, PreferencesResult, PreferencesState, PreferencesTab};
+pub use privilege_dialog::{
+    database_privileges, table_privileges, PrivilegeDialog, PrivilegeDialogResult, PrivilegeDialogState,
+};
+pub use profile_dialog::{ProfileDialog, ProfileResult, ProfileState};
+pub use size_overview_dialog::{
+    format_bytes, SizeOverviewDialog, SizeOverviewResult, SizeOverviewState, SizeSortColumn,
+};
+pub use result_diff_dialog::{diff_color, ResultDiffDialog, ResultDiffResult, ResultDiffState};
+pub use row_bookmark_dialog::{RowBookmarkDialog, RowBookmarkResult, RowBookmarkState};
+pub use scheduler_dialog::{SchedulerDialog, SchedulerResult, SchedulerState};
+pub use sequence_dialog::{SequenceDialog, SequenceDialogResult, SequenceDialogState};
+pub use sql_diff_dialog::{SqlDiffDialog, SqlDiffResult, SqlDiffState};
+pub use sql_review_dialog::{SqlReviewDialog, SqlReviewResult, SqlReviewState, StatementCounts};
+pub use table_action_dialog::{
+    drop_table_sql, duplicate_table_sql, rename_table_sql, truncate_table_sql, TableAction, TableActionDialog, TableActionResult, TableActionState,
+};
+pub use table_ddl_dialog::{TableDdlDialog, TableDdlResult, TableDdlState};
+pub use theme_editor_dialog::{ThemeEditorDialog, ThemeEditorResult, ThemeEditorState};
+pub use trash_dialog::{TrashDialog, TrashDialogResult, TrashDialogState};
+pub use workflow_dialog::{WorkflowDialog, WorkflowResult, WorkflowState};
+pub use write_preview_dialog::{
+    WritePreviewDialog, WritePreviewResult, WritePreviewState, WRITE_PREVIEW_ROW_LIMIT,
+};
+pub use session_monitor_dialog::{
+    duration_color, format_duration, SessionMonitorDialog, SessionMonitorResult, SessionMonitorState,
+};
+pub use object_ddl_dialog::{
+    drop_object_sql, DbObjectKind, ObjectDdlDialog, ObjectDdlDialogResult, ObjectDdlDialogState,
+};
 pub use import_dialog::{
     parse_sql_file, ImportAction, ImportDialog, ImportFormat, ImportPreview, ImportState,
 };
@@ -0,0 +1,157 @@
+//! 通知中心
+//!
+//! 列出已经消失的历史通知，可按级别和文本筛选，并复制消息内容。
+
+use super::keyboard;
+use crate::core::{NotificationLevel, NotificationManager, NotificationRecord};
+use egui::{self, Color32, RichText, TextEdit};
+
+/// 通知中心的结果
+pub enum NotificationCenterResult {
+    /// 无操作
+    None,
+    /// 清空历史记录
+    Clear,
+}
+
+/// 通知中心状态
+pub struct NotificationCenterState {
+    /// 是否显示窗口
+    pub show: bool,
+    /// 显示的级别，与 `NotificationLevel::ALL` 一一对应
+    pub levels: [bool; 4],
+    /// 文本筛选
+    pub search: String,
+}
+
+impl Default for NotificationCenterState {
+    fn default() -> Self {
+        Self {
+            show: false,
+            levels: [true; 4],
+            search: String::new(),
+        }
+    }
+}
+
+impl NotificationCenterState {
+    /// 创建新的状态
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 打开窗口
+    pub fn open(&mut self) {
+        self.show = true;
+    }
+
+    /// 关闭窗口
+    pub fn close(&mut self) {
+        self.show = false;
+    }
+
+    /// 是否显示该级别
+    pub fn shows_level(&self, level: NotificationLevel) -> bool {
+        NotificationLevel::ALL
+            .iter()
+            .position(|l| *l == level)
+            .is_some_and(|i| self.levels[i])
+    }
+
+    /// 记录是否符合筛选条件
+    pub fn matches(&self, record: &NotificationRecord) -> bool {
+        let search = self.search.trim().to_lowercase();
+        self.shows_level(record.level) && (search.is_empty() || record.message.to_lowercase().contains(&search))
+    }
+}
+
+/// 通知中心窗口
+pub struct NotificationCenterDialog;
+
+impl NotificationCenterDialog {
+    /// 显示窗口
+    pub fn show(
+        ctx: &egui::Context,
+        state: &mut NotificationCenterState,
+        notifications: &NotificationManager,
+    ) -> NotificationCenterResult {
+        if !state.show {
+            return NotificationCenterResult::None;
+        }
+
+        if !keyboard::has_text_focus(ctx) && keyboard::handle_close_keys(ctx) {
+            state.close();
+            return NotificationCenterResult::None;
+        }
+
+        let mut result = NotificationCenterResult::None;
+        let mut open = true;
+        egui::Window::new("🔔 通知中心")
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(true)
+            .default_width(560.0)
+            .default_height(420.0)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    for (i, level) in NotificationLevel::ALL.iter().enumerate() {
+                        let count = notifications.history().filter(|r| r.level == *level).count();
+                        let text = RichText::new(format!("{} ({})", level.label(), count)).color(level.color());
+                        ui.toggle_value(&mut state.levels[i], text);
+                    }
+                    ui.separator();
+                    ui.add(TextEdit::singleline(&mut state.search).desired_width(140.0).hint_text("搜索..."));
+                    if ui
+                        .add_enabled(notifications.history_len() > 0, egui::Button::new("🗑 清空"))
+                        .clicked()
+                    {
+                        result = NotificationCenterResult::Clear;
+                    }
+                });
+                ui.separator();
+
+                let records: Vec<&NotificationRecord> =
+                    notifications.history().filter(|r| state.matches(r)).collect();
+                if records.is_empty() {
+                    ui.label(RichText::new("暂无通知").color(Color32::GRAY));
+                    return;
+                }
+                egui::ScrollArea::vertical()
+                    .auto_shrink([false, false])
+                    .show(ui, |ui| {
+                        for record in records {
+                            Self::show_record(ui, record);
+                        }
+                    });
+            });
+
+        if !open {
+            state.close();
+        }
+
+        result
+    }
+
+    /// 单条记录
+    fn show_record(ui: &mut egui::Ui, record: &NotificationRecord) {
+        ui.horizontal_top(|ui| {
+            ui.label(
+                RichText::new(record.timestamp.format("%H:%M:%S").to_string())
+                    .monospace()
+                    .small()
+                    .color(Color32::GRAY),
+            )
+            .on_hover_text(record.timestamp.format("%Y-%m-%d %H:%M:%S").to_string());
+            ui.label(RichText::new(format!("[{}]", record.level.icon())).color(record.level.color()));
+            if ui
+                .small_button("📋")
+                .on_hover_text("复制消息")
+                .clicked()
+            {
+                ui.ctx().copy_text(record.message.clone());
+            }
+            ui.add(egui::Label::new(&record.message).wrap());
+        });
+    }
+}
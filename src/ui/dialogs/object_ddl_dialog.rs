@@ -0,0 +1,457 @@
+//! 触发器/存储过程/函数编辑对话框
+//!
+//! 新建时按数据库方言填入 CREATE 模板，编辑时载入现有定义。
+//! 不支持 `CREATE OR REPLACE` 的对象（MySQL、SQLite 等）保存时先删除原对象再重新创建，重建失败时恢复原定义。
+//!
+//! 快捷键：
+//! - `Ctrl+Enter` - 执行
+//! - `Esc` - 取消
+
+use crate::core::{highlight_sql, HighlightColors};
+use crate::database::{DatabaseType, RoutineInfo, RoutineType, TriggerInfo};
+use crate::ui::quote_identifier;
+use egui::{self, Color32, Key, RichText, TextEdit};
+
+// ============================================================================
+// 对象类型
+// ============================================================================
+
+/// 可编辑的数据库对象类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DbObjectKind {
+    Trigger,
+    Function,
+    Procedure,
+}
+
+impl DbObjectKind {
+    /// 显示名称
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            DbObjectKind::Trigger => "触发器",
+            DbObjectKind::Function => "函数",
+            DbObjectKind::Procedure => "存储过程",
+        }
+    }
+
+    /// DROP 语句中的对象关键字
+    fn sql_keyword(&self) -> &'static str {
+        match self {
+            DbObjectKind::Trigger => "TRIGGER",
+            DbObjectKind::Function => "FUNCTION",
+            DbObjectKind::Procedure => "PROCEDURE",
+        }
+    }
+
+    /// 存储过程/函数对应的对象类型
+    pub fn from_routine(routine_type: &RoutineType) -> Self {
+        match routine_type {
+            RoutineType::Function => DbObjectKind::Function,
+            RoutineType::Procedure => DbObjectKind::Procedure,
+        }
+    }
+
+    /// 生成该数据库的 CREATE 模板，不支持的组合返回 `None`
+    pub fn template(&self, db_type: DatabaseType) -> Option<&'static str> {
+        match (self, db_type) {
+            (DbObjectKind::Trigger, DatabaseType::SQLite) => Some(
+                "CREATE TRIGGER trigger_name\n\
+                 AFTER UPDATE ON table_name\n\
+                 FOR EACH ROW\n\
+                 BEGIN\n\
+                 \x20   UPDATE table_name SET updated_at = CURRENT_TIMESTAMP WHERE id = NEW.id;\n\
+                 END;",
+            ),
+            (DbObjectKind::Trigger, DatabaseType::PostgreSQL) => Some(
+                "-- 触发器函数需返回 trigger 类型，可先通过「新建函数」创建\n\
+                 CREATE TRIGGER trigger_name\n\
+                 BEFORE INSERT OR UPDATE ON table_name\n\
+                 FOR EACH ROW\n\
+                 EXECUTE FUNCTION trigger_function();",
+            ),
+            (DbObjectKind::Trigger, DatabaseType::MySQL) => Some(
+                "CREATE TRIGGER trigger_name\n\
+                 BEFORE INSERT ON table_name\n\
+                 FOR EACH ROW\n\
+                 BEGIN\n\
+                 \x20   SET NEW.created_at = NOW();\n\
+                 END",
+            ),
+            (DbObjectKind::Trigger, DatabaseType::Oracle) => Some(
+                "CREATE OR REPLACE TRIGGER trigger_name\n\
+                 BEFORE INSERT ON table_name\n\
+                 FOR EACH ROW\n\
+                 BEGIN\n\
+                 \x20   :NEW.created_at := SYSDATE;\n\
+                 END;",
+            ),
+            (DbObjectKind::Function, DatabaseType::PostgreSQL) => Some(
+                "CREATE OR REPLACE FUNCTION function_name(a integer, b integer)\n\
+                 RETURNS integer\n\
+                 LANGUAGE plpgsql\n\
+                 AS $$\n\
+                 BEGIN\n\
+                 \x20   RETURN a + b;\n\
+                 END;\n\
+                 $$;",
+            ),
+            (DbObjectKind::Procedure, DatabaseType::PostgreSQL) => Some(
+                "CREATE OR REPLACE PROCEDURE procedure_name(p_id integer)\n\
+                 LANGUAGE plpgsql\n\
+                 AS $$\n\
+                 BEGIN\n\
+                 \x20   DELETE FROM table_name WHERE id = p_id;\n\
+                 END;\n\
+                 $$;",
+            ),
+            (DbObjectKind::Function, DatabaseType::MySQL) => Some(
+                "CREATE FUNCTION function_name(a INT, b INT)\n\
+                 RETURNS INT\n\
+                 DETERMINISTIC\n\
+                 BEGIN\n\
+                 \x20   RETURN a + b;\n\
+                 END",
+            ),
+            (DbObjectKind::Procedure, DatabaseType::MySQL) => Some(
+                "CREATE PROCEDURE procedure_name(IN p_id INT)\n\
+                 BEGIN\n\
+                 \x20   SELECT * FROM table_name WHERE id = p_id;\n\
+                 END",
+            ),
+            _ => None,
+        }
+    }
+}
+
+/// 生成删除对象的 SQL
+///
+/// PostgreSQL 触发器需要指定所在表；函数/过程带参数签名以区分重载，
+/// 参数含默认值时签名不能直接用于 DROP，此时只按名称删除
+pub fn drop_object_sql(
+    db_type: DatabaseType,
+    kind: DbObjectKind,
+    name: &str,
+    table: &str,
+    parameters: &str,
+) -> Result<String, String> {
+    let use_backticks = db_type == DatabaseType::MySQL;
+    let quoted = quote_identifier(name, use_backticks)?;
+    let keyword = kind.sql_keyword();
+    match (db_type, kind) {
+        (DatabaseType::PostgreSQL, DbObjectKind::Trigger) => Ok(format!(
+            "DROP TRIGGER {} ON {};",
+            quoted,
+            quote_identifier(table, false)?
+        )),
+        (DatabaseType::PostgreSQL, _) if !parameters.to_uppercase().contains(" DEFAULT ") => {
+            Ok(format!("DROP {} {}({});", keyword, quoted, parameters))
+        }
+        (DatabaseType::SQLite | DatabaseType::PostgreSQL | DatabaseType::MySQL, _)
+        | (DatabaseType::Oracle, DbObjectKind::Trigger) => Ok(format!("DROP {} {};", keyword, quoted)),
+        _ => Err(format!("{} 不支持删除{}", db_type.display_name(), kind.display_name())),
+    }
+}
+
+// ============================================================================
+// 对话框结果
+// ============================================================================
+
+/// 对象编辑对话框的结果
+pub enum ObjectDdlDialogResult {
+    /// 无操作
+    None,
+    /// 用户确认执行（按顺序执行的 SQL 语句，以及重建失败时恢复原对象的语句）
+    Execute(Vec<String>, Option<String>),
+    /// 用户取消
+    Cancelled,
+}
+
+// ============================================================================
+// 对话框状态
+// ============================================================================
+
+/// 对象编辑对话框状态
+pub struct ObjectDdlDialogState {
+    /// 是否显示对话框
+    pub show: bool,
+    /// 当前数据库类型
+    pub db_type: DatabaseType,
+    /// 对象类型
+    pub kind: DbObjectKind,
+    /// 编辑时原对象的删除语句（新建时为 `None`）
+    pub replace_drop: Option<String>,
+    /// 原对象名称（编辑时显示在标题中）
+    pub original_name: String,
+    /// 编辑器中的 CREATE 语句
+    pub sql: String,
+    /// 编辑时载入的原定义，先删除再重建失败时用于恢复原对象
+    pub original_sql: String,
+    /// 错误信息
+    pub error: Option<String>,
+}
+
+impl Default for ObjectDdlDialogState {
+    fn default() -> Self {
+        Self {
+            show: false,
+            db_type: DatabaseType::default(),
+            kind: DbObjectKind::Trigger,
+            replace_drop: None,
+            original_name: String::new(),
+            sql: String::new(),
+            original_sql: String::new(),
+            error: None,
+        }
+    }
+}
+
+impl ObjectDdlDialogState {
+    /// 创建新的对话框状态
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 打开新建对话框，填入方言模板
+    pub fn open_create(&mut self, db_type: DatabaseType, kind: DbObjectKind) -> Result<(), String> {
+        let template = kind
+            .template(db_type)
+            .ok_or_else(|| format!("{} 不支持新建{}", db_type.display_name(), kind.display_name()))?;
+        self.reset();
+        self.db_type = db_type;
+        self.kind = kind;
+        self.sql = template.to_string();
+        self.show = true;
+        Ok(())
+    }
+
+    /// 打开触发器编辑对话框
+    pub fn open_edit_trigger(&mut self, db_type: DatabaseType, trigger: &TriggerInfo) -> Result<(), String> {
+        // Oracle 目录中的描述不含触发器主体，无法还原完整定义
+        if db_type == DatabaseType::Oracle {
+            return Err("Oracle 触发器定义不含主体，请在编辑器中使用 CREATE OR REPLACE TRIGGER 修改".to_string());
+        }
+        let drop = drop_object_sql(db_type, DbObjectKind::Trigger, &trigger.name, &trigger.table_name, "")?;
+        self.open_edit(db_type, DbObjectKind::Trigger, &trigger.name, drop, trigger.definition.clone());
+        Ok(())
+    }
+
+    /// 打开存储过程/函数编辑对话框
+    ///
+    /// MySQL 目录只保存过程体，按名称、参数和返回类型还原 CREATE 语句
+    pub fn open_edit_routine(&mut self, db_type: DatabaseType, routine: &RoutineInfo) -> Result<(), String> {
+        let kind = DbObjectKind::from_routine(&routine.routine_type);
+        let drop = drop_object_sql(db_type, kind, &routine.name, "", &routine.parameters)?;
+        let sql = match db_type {
+            DatabaseType::MySQL => {
+                if routine.definition == "(定义不可见)" {
+                    return Err("没有查看该对象定义的权限".to_string());
+                }
+                let returns = match (&kind, &routine.return_type) {
+                    (DbObjectKind::Function, Some(ret)) => format!("\nRETURNS {}", ret),
+                    _ => String::new(),
+                };
+                format!(
+                    "CREATE {} {}({}){}\n{}",
+                    kind.sql_keyword(),
+                    quote_identifier(&routine.name, true)?,
+                    routine.parameters,
+                    returns,
+                    routine.definition
+                )
+            }
+            _ => routine.definition.clone(),
+        };
+        self.open_edit(db_type, kind, &routine.name, drop, sql);
+        Ok(())
+    }
+
+    fn open_edit(&mut self, db_type: DatabaseType, kind: DbObjectKind, name: &str, drop: String, sql: String) {
+        self.reset();
+        self.db_type = db_type;
+        self.kind = kind;
+        self.original_name = name.to_string();
+        self.replace_drop = Some(drop);
+        self.original_sql = sql.clone();
+        self.sql = sql;
+        self.show = true;
+    }
+
+    /// 关闭对话框
+    pub fn close(&mut self) {
+        self.show = false;
+        self.reset();
+    }
+
+    /// 重置状态
+    fn reset(&mut self) {
+        self.replace_drop = None;
+        self.original_name.clear();
+        self.sql.clear();
+        self.original_sql.clear();
+        self.error = None;
+    }
+
+    /// 是否为编辑已有对象
+    pub fn is_edit(&self) -> bool {
+        self.replace_drop.is_some()
+    }
+
+    /// 编辑时语句是否自带 `OR REPLACE`，无需先删除原对象
+    fn replaces_in_place(&self) -> bool {
+        let words: Vec<String> = self
+            .sql
+            .lines()
+            .filter(|line| !line.trim_start().starts_with("--"))
+            .flat_map(str::split_whitespace)
+            .take(3)
+            .map(str::to_uppercase)
+            .collect();
+        words == ["CREATE", "OR", "REPLACE"]
+    }
+
+    /// 生成按顺序执行的语句
+    pub fn statements(&self) -> Result<Vec<String>, String> {
+        let sql = self.sql.trim();
+        if sql.is_empty() {
+            return Err("SQL 不能为空".to_string());
+        }
+        match &self.replace_drop {
+            Some(drop) if !self.replaces_in_place() => Ok(vec![drop.clone(), sql.to_string()]),
+            _ => Ok(vec![sql.to_string()]),
+        }
+    }
+
+    /// 先删除再重建时恢复原对象的语句（原定义），直接替换或新建时为 `None`
+    pub fn restore_sql(&self) -> Option<String> {
+        self.replace_drop
+            .as_ref()
+            .filter(|_| !self.replaces_in_place())
+            .map(|_| self.original_sql.trim().to_string())
+    }
+}
+
+// ============================================================================
+// 对话框 UI
+// ============================================================================
+
+/// 触发器/存储过程/函数编辑对话框
+pub struct ObjectDdlDialog;
+
+impl ObjectDdlDialog {
+    /// 显示对话框
+    pub fn show(
+        ctx: &egui::Context,
+        state: &mut ObjectDdlDialogState,
+        highlight_colors: &HighlightColors,
+    ) -> ObjectDdlDialogResult {
+        if !state.show {
+            return ObjectDdlDialogResult::None;
+        }
+
+        let mut result = ObjectDdlDialogResult::None;
+        let mut should_close = false;
+        let mut submit = false;
+
+        // 编辑器内 Enter 用于换行，Ctrl+Enter 执行
+        ctx.input(|i| {
+            if i.key_pressed(Key::Escape) {
+                should_close = true;
+                result = ObjectDdlDialogResult::Cancelled;
+            }
+            if i.key_pressed(Key::Enter) && i.modifiers.ctrl {
+                submit = true;
+            }
+        });
+
+        let title = if state.is_edit() {
+            format!("编辑{}: {}", state.kind.display_name(), state.original_name)
+        } else {
+            format!("新建{}", state.kind.display_name())
+        };
+
+        egui::Window::new(title)
+            .collapsible(false)
+            .resizable(true)
+            .default_size([640.0, 420.0])
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.label(
+                    RichText::new(state.db_type.display_name())
+                        .color(Color32::from_rgb(150, 150, 160)),
+                );
+                ui.add_space(4.0);
+
+                let colors = highlight_colors.clone();
+                let mut layouter = |ui: &egui::Ui, text: &dyn egui::TextBuffer, wrap_width: f32| {
+                    let mut job = highlight_sql(text.as_str(), &colors);
+                    job.wrap.max_width = wrap_width;
+                    ui.ctx().fonts_mut(|f| f.layout_job(job))
+                };
+
+                egui::ScrollArea::vertical()
+                    .id_salt("object_ddl_editor")
+                    .max_height(320.0)
+                    .show(ui, |ui| {
+                        ui.add(
+                            TextEdit::multiline(&mut state.sql)
+                                .font(egui::TextStyle::Monospace)
+                                .code_editor()
+                                .desired_width(f32::INFINITY)
+                                .desired_rows(16)
+                                .layouter(&mut layouter),
+                        );
+                    });
+
+                ui.add_space(4.0);
+                if state.is_edit() && !state.replaces_in_place() {
+                    ui.label(
+                        RichText::new("提示: 执行时将先删除原对象再按上述定义重新创建")
+                            .small()
+                            .color(Color32::from_rgb(230, 180, 80)),
+                    );
+                }
+
+                if let Some(err) = &state.error {
+                    ui.add_space(4.0);
+                    ui.label(RichText::new(err).color(Color32::from_rgb(255, 100, 100)));
+                }
+
+                ui.add_space(8.0);
+
+                ui.horizontal(|ui| {
+                    if ui.button("执行 [Ctrl+Enter]").clicked() {
+                        submit = true;
+                    }
+
+                    if !state.is_edit()
+                        && let Some(template) = state.kind.template(state.db_type)
+                        && ui.button("重置模板").clicked()
+                    {
+                        state.sql = template.to_string();
+                        state.error = None;
+                    }
+
+                    if ui.button("取消 [Esc]").clicked() {
+                        result = ObjectDdlDialogResult::Cancelled;
+                        should_close = true;
+                    }
+                });
+            });
+
+        if submit && !should_close {
+            match state.statements() {
+                Ok(statements) => {
+                    result = ObjectDdlDialogResult::Execute(statements, state.restore_sql());
+                    should_close = true;
+                }
+                Err(e) => state.error = Some(e),
+            }
+        }
+
+        if should_close {
+            state.close();
+        }
+
+        result
+    }
+}
@@ -0,0 +1,364 @@
+//! 透视表窗口
+//!
+//! 对打开窗口时表格中显示的行（筛选和排序后）做交叉汇总。选择行分组列（可多级）、
+//! 列分组列和汇总方式后立即重新计算；有下级分组的行可以展开和折叠，结果可以导出为 CSV。
+
+use super::keyboard;
+use crate::core::{PivotAggregate, PivotCell, PivotConfig, PivotGroup, PivotTable};
+use crate::database::QueryResult;
+use crate::ui::styles::{DANGER, GRAY, MUTED};
+use egui::{self, RichText};
+use std::collections::HashSet;
+
+/// 每级分组的缩进
+const INDENT: f32 = 16.0;
+
+/// 透视表窗口的结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PivotResult {
+    /// 无操作
+    None,
+    /// 导出透视结果
+    Export,
+}
+
+/// 透视表窗口状态
+#[derive(Default)]
+pub struct PivotState {
+    /// 是否显示窗口
+    pub show: bool,
+    /// 打开窗口时显示的行
+    source: QueryResult,
+    /// 当前的透视设置
+    config: PivotConfig,
+    /// 计算结果
+    table: Option<Result<PivotTable, String>>,
+    /// 展开的分组（从第一级开始的分组值）
+    expanded: HashSet<Vec<String>>,
+}
+
+impl PivotState {
+    /// 创建新的状态
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 打开窗口，`rows` 为表格中显示的原始行索引；列相同时保留上次的设置
+    pub fn open(&mut self, result: &QueryResult, rows: &[usize]) {
+        if self.source.columns != result.columns {
+            self.config = PivotConfig {
+                row_columns: vec![0],
+                ..Default::default()
+            };
+            self.expanded.clear();
+        }
+        self.source = QueryResult {
+            columns: result.columns.clone(),
+            rows: rows.iter().filter_map(|&i| result.rows.get(i).cloned()).collect(),
+            ..Default::default()
+        };
+        self.show = true;
+        self.recompute();
+    }
+
+    /// 计算结果（用于导出）
+    pub fn table(&self) -> Option<&PivotTable> {
+        self.table.as_ref().and_then(|t| t.as_ref().ok())
+    }
+
+    /// 关闭窗口并释放复制的行
+    pub fn close(&mut self) {
+        self.show = false;
+        self.source.rows.clear();
+        self.table = None;
+    }
+
+    fn recompute(&mut self) {
+        self.table = Some(PivotTable::compute(&self.source, &self.config));
+    }
+
+    /// 展开或折叠所有分组
+    fn set_all_expanded(&mut self, expanded: bool) {
+        self.expanded.clear();
+        if !expanded {
+            return;
+        }
+        let mut paths = Vec::new();
+        if let Some(table) = self.table() {
+            collect_paths(&table.groups, &mut Vec::new(), &mut paths);
+        }
+        self.expanded.extend(paths);
+    }
+}
+
+/// 有下级分组的分组路径
+fn collect_paths(groups: &[PivotGroup], path: &mut Vec<String>, paths: &mut Vec<Vec<String>>) {
+    for group in groups.iter().filter(|g| !g.children.is_empty()) {
+        path.push(group.key.clone());
+        paths.push(path.clone());
+        collect_paths(&group.children, path, paths);
+        path.pop();
+    }
+}
+
+/// 表格中显示的一行
+struct VisibleGroup<'a> {
+    group: &'a PivotGroup,
+    depth: usize,
+    path: Vec<String>,
+}
+
+/// 按展开状态列出显示的分组
+fn visible_groups<'a>(
+    groups: &'a [PivotGroup],
+    expanded: &HashSet<Vec<String>>,
+    path: &mut Vec<String>,
+    out: &mut Vec<VisibleGroup<'a>>,
+) {
+    for group in groups {
+        path.push(group.key.clone());
+        out.push(VisibleGroup { group, depth: path.len() - 1, path: path.clone() });
+        if expanded.contains(path) {
+            visible_groups(&group.children, expanded, path, out);
+        }
+        path.pop();
+    }
+}
+
+/// 透视表窗口
+pub struct PivotDialog;
+
+impl PivotDialog {
+    /// 显示窗口
+    pub fn show(ctx: &egui::Context, state: &mut PivotState) -> PivotResult {
+        if !state.show {
+            return PivotResult::None;
+        }
+
+        if !keyboard::has_text_focus(ctx) && keyboard::handle_close_keys(ctx) {
+            state.close();
+            return PivotResult::None;
+        }
+
+        let mut result = PivotResult::None;
+        let mut open = true;
+        egui::Window::new("▦ 透视表")
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(true)
+            .default_width(720.0)
+            .default_height(460.0)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                let previous = state.config.clone();
+                Self::show_config(ui, &state.source.columns, &mut state.config);
+                if state.config != previous {
+                    if state.config.row_columns != previous.row_columns {
+                        state.expanded.clear();
+                    }
+                    state.recompute();
+                }
+
+                ui.horizontal(|ui| {
+                    ui.label(
+                        RichText::new(format!("汇总表格中显示的 {} 行", state.source.rows.len()))
+                            .small()
+                            .color(GRAY),
+                    );
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        let ready = state.table().is_some();
+                        if ui.add_enabled(ready, egui::Button::new("💾 导出 CSV")).clicked() {
+                            result = PivotResult::Export;
+                        }
+                        let nested = state.config.row_columns.len() > 1;
+                        if ui.add_enabled(ready && nested, egui::Button::new("全部折叠")).clicked() {
+                            state.set_all_expanded(false);
+                        }
+                        if ui.add_enabled(ready && nested, egui::Button::new("全部展开")).clicked() {
+                            state.set_all_expanded(true);
+                        }
+                    });
+                });
+                ui.separator();
+
+                match &state.table {
+                    Some(Err(e)) => {
+                        ui.label(RichText::new(e).color(DANGER));
+                    }
+                    Some(Ok(table)) if table.groups.is_empty() => {
+                        ui.label(RichText::new("没有可汇总的行").color(MUTED));
+                    }
+                    Some(Ok(table)) => {
+                        let column_name = state.config.column_column.and_then(|i| state.source.columns.get(i));
+                        if let Some(toggled) = Self::show_table(ui, table, &state.expanded, column_name)
+                            && !state.expanded.remove(&toggled)
+                        {
+                            state.expanded.insert(toggled);
+                        }
+                    }
+                    None => {}
+                }
+            });
+
+        if !open {
+            state.close();
+        }
+
+        result
+    }
+
+    /// 透视设置
+    fn show_config(ui: &mut egui::Ui, columns: &[String], config: &mut PivotConfig) {
+        let name = |idx: Option<usize>| idx.and_then(|i| columns.get(i)).map_or("(无)", String::as_str);
+
+        ui.horizontal_wrapped(|ui| {
+            ui.label("行分组:");
+            let mut remove = None;
+            for (pos, &col) in config.row_columns.iter().enumerate() {
+                if ui
+                    .small_button(format!("{} ✖", name(Some(col))))
+                    .on_hover_text("移除该分组")
+                    .clicked()
+                {
+                    remove = Some(pos);
+                }
+            }
+            if let Some(pos) = remove {
+                config.row_columns.remove(pos);
+            }
+            ui.menu_button("+ 添加", |ui| {
+                egui::ScrollArea::vertical().max_height(300.0).show(ui, |ui| {
+                    for (idx, column) in columns.iter().enumerate() {
+                        if !config.row_columns.contains(&idx) && ui.button(column).clicked() {
+                            config.row_columns.push(idx);
+                            ui.close();
+                        }
+                    }
+                });
+            });
+        });
+
+        ui.horizontal(|ui| {
+            ui.label("列分组:");
+            egui::ComboBox::from_id_salt("pivot_column")
+                .selected_text(name(config.column_column))
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut config.column_column, None, "(无)");
+                    for (idx, column) in columns.iter().enumerate() {
+                        ui.selectable_value(&mut config.column_column, Some(idx), column);
+                    }
+                });
+
+            ui.add_space(12.0);
+            ui.label("汇总:");
+            egui::ComboBox::from_id_salt("pivot_aggregate")
+                .selected_text(config.aggregate.display_name())
+                .show_ui(ui, |ui| {
+                    for aggregate in PivotAggregate::ALL {
+                        ui.selectable_value(&mut config.aggregate, aggregate, aggregate.display_name());
+                    }
+                });
+
+            if config.aggregate.needs_value() {
+                egui::ComboBox::from_id_salt("pivot_value")
+                    .selected_text(name(config.value_column))
+                    .show_ui(ui, |ui| {
+                        for (idx, column) in columns.iter().enumerate() {
+                            ui.selectable_value(&mut config.value_column, Some(idx), column);
+                        }
+                    });
+            }
+        });
+    }
+
+    /// 透视结果表格，返回点击展开或折叠的分组
+    fn show_table(
+        ui: &mut egui::Ui,
+        table: &PivotTable,
+        expanded: &HashSet<Vec<String>>,
+        column_name: Option<&String>,
+    ) -> Option<Vec<String>> {
+        use egui_extras::{Column, TableBuilder};
+
+        let mut rows = Vec::new();
+        visible_groups(&table.groups, expanded, &mut Vec::new(), &mut rows);
+        let mut toggled = None;
+
+        if let Some(column_name) = column_name {
+            ui.label(RichText::new(format!("列: {}", column_name)).small().color(GRAY));
+        }
+        let value_text = |ui: &mut egui::Ui, cell: &PivotCell, strong: bool| {
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                let text = RichText::new(table.format(cell)).monospace();
+                ui.label(if strong { text.strong() } else { text });
+            });
+        };
+
+        egui::ScrollArea::horizontal().id_salt("pivot_scroll").show(ui, |ui| {
+            TableBuilder::new(ui)
+                .striped(true)
+                .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+                .column(Column::auto().at_least(160.0).clip(true).resizable(true))
+                .columns(Column::auto().at_least(60.0).clip(true), table.column_keys.len())
+                .column(Column::auto().at_least(60.0))
+                .header(20.0, |mut header| {
+                    header.col(|ui| {
+                        ui.label(RichText::new(table.row_headers.join(" / ")).strong().small());
+                    });
+                    for key in &table.column_keys {
+                        header.col(|ui| {
+                            ui.label(RichText::new(display_key(key)).strong().small());
+                        });
+                    }
+                    header.col(|ui| {
+                        ui.label(RichText::new("合计").strong().small());
+                    });
+                })
+                .body(|body| {
+                    body.rows(18.0, rows.len() + 1, |mut table_row| {
+                        let index = table_row.index();
+                        let Some(row) = rows.get(index) else {
+                            // 最后一行为总计
+                            table_row.col(|ui| {
+                                ui.label(RichText::new("总计").strong());
+                            });
+                            for cell in table.totals.iter().take(table.column_keys.len()) {
+                                table_row.col(|ui| value_text(ui, cell, true));
+                            }
+                            table_row.col(|ui| value_text(ui, &table.grand_total, true));
+                            return;
+                        };
+
+                        // 有下级分组的行为小计，加粗显示
+                        let has_children = !row.group.children.is_empty();
+                        table_row.col(|ui| {
+                            ui.add_space(row.depth as f32 * INDENT);
+                            if has_children {
+                                let icon = if expanded.contains(&row.path) { "▼" } else { "▶" };
+                                let label = format!("{} {} ({})", icon, display_key(&row.group.key), row.group.total.count);
+                                if ui
+                                    .add(egui::Label::new(RichText::new(label).strong()).sense(egui::Sense::click()))
+                                    .on_hover_cursor(egui::CursorIcon::PointingHand)
+                                    .clicked()
+                                {
+                                    toggled = Some(row.path.clone());
+                                }
+                            } else {
+                                ui.label(display_key(&row.group.key));
+                            }
+                        });
+                        for cell in row.group.cells.iter().take(table.column_keys.len()) {
+                            table_row.col(|ui| value_text(ui, cell, has_children));
+                        }
+                        table_row.col(|ui| value_text(ui, &row.group.total, true));
+                    });
+                });
+        });
+        toggled
+    }
+}
+
+/// 空的分组值显示为 (空)
+fn display_key(key: &str) -> &str {
+    if key.is_empty() { "(空)" } else { key }
+}
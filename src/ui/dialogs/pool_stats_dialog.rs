@@ -0,0 +1,167 @@
+//! 连接池诊断窗口
+//!
+//! 按连接池键显示已建立、正在使用和空闲的连接数，以及等待获取连接的请求，
+//! 打开期间每秒刷新，用于排查连接池配置过小或连接泄漏。
+
+use std::time::{Duration, Instant};
+
+use super::keyboard;
+use crate::database::PoolStats;
+use egui::{self, Color32, RichText};
+
+/// 刷新间隔
+pub const POOL_STATS_REFRESH: Duration = Duration::from_secs(1);
+
+const WARN_COLOR: Color32 = Color32::from_rgb(230, 180, 60);
+
+// ============================================================================
+// 窗口结果
+// ============================================================================
+
+/// 连接池诊断窗口的结果
+pub enum PoolStatsResult {
+    /// 无操作
+    None,
+    /// 断开所有空闲的连接池
+    CloseIdle,
+}
+
+// ============================================================================
+// 窗口状态
+// ============================================================================
+
+/// 连接池诊断窗口状态
+#[derive(Default)]
+pub struct PoolStatsState {
+    /// 是否显示窗口
+    pub show: bool,
+    /// 各连接池的使用情况
+    pub stats: Vec<PoolStats>,
+    /// 上次刷新的时间
+    last_refresh: Option<Instant>,
+}
+
+impl PoolStatsState {
+    /// 创建新的窗口状态
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 打开窗口
+    pub fn open(&mut self, stats: Vec<PoolStats>) {
+        self.show = true;
+        self.set_stats(stats);
+    }
+
+    /// 关闭窗口
+    pub fn close(&mut self) {
+        self.show = false;
+        self.stats.clear();
+        self.last_refresh = None;
+    }
+
+    /// 是否需要刷新（窗口打开且距上次刷新超过间隔）
+    pub fn needs_refresh(&self) -> bool {
+        self.show && self.last_refresh.is_none_or(|t| t.elapsed() >= POOL_STATS_REFRESH)
+    }
+
+    /// 设置刷新结果
+    pub fn set_stats(&mut self, stats: Vec<PoolStats>) {
+        self.stats = stats;
+        self.last_refresh = Some(Instant::now());
+    }
+
+    /// 下一帧重新读取
+    pub fn invalidate(&mut self) {
+        self.last_refresh = None;
+    }
+}
+
+// ============================================================================
+// 窗口 UI
+// ============================================================================
+
+/// 连接池诊断窗口
+pub struct PoolStatsDialog;
+
+impl PoolStatsDialog {
+    /// 显示窗口
+    pub fn show(ctx: &egui::Context, state: &mut PoolStatsState) -> PoolStatsResult {
+        if !state.show {
+            return PoolStatsResult::None;
+        }
+
+        if keyboard::handle_close_keys(ctx) {
+            state.close();
+            return PoolStatsResult::None;
+        }
+        ctx.request_repaint_after(POOL_STATS_REFRESH);
+
+        let mut result = PoolStatsResult::None;
+        let mut open = true;
+        egui::Window::new("🔌 连接池")
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(true)
+            .default_size([640.0, 280.0])
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                if state.stats.is_empty() {
+                    ui.label(RichText::new("当前没有 MySQL/PostgreSQL 连接池").color(Color32::GRAY));
+                    return;
+                }
+
+                ui.horizontal(|ui| {
+                    if ui
+                        .button("⏏ 断开所有空闲连接池")
+                        .on_hover_text("断开当前没有查询在使用的连接池，下次查询时自动重新连接")
+                        .clicked()
+                    {
+                        result = PoolStatsResult::CloseIdle;
+                    }
+                });
+                ui.separator();
+
+                egui::ScrollArea::vertical().auto_shrink([false, true]).show(ui, |ui| {
+                    egui::Grid::new("pool_stats_grid")
+                        .num_columns(7)
+                        .striped(true)
+                        .spacing([16.0, 6.0])
+                        .show(ui, |ui| {
+                            for header in ["连接池", "已建立", "使用中", "空闲", "等待", "上限", "上次使用"] {
+                                ui.label(RichText::new(header).strong());
+                            }
+                            ui.end_row();
+
+                            for stats in &state.stats {
+                                ui.label(RichText::new(&stats.key).monospace())
+                                    .on_hover_text(stats.db_type.display_name());
+                                ui.label(stats.connections.to_string());
+                                // 全部连接都在使用时以警告色显示
+                                let saturated = stats.active() >= stats.max_connections;
+                                let active = RichText::new(stats.active().to_string());
+                                ui.label(if saturated { active.color(WARN_COLOR) } else { active });
+                                ui.label(stats.idle.to_string());
+                                let waiting = RichText::new(stats.waiting.to_string());
+                                ui.label(if stats.waiting > 0 { waiting.color(WARN_COLOR) } else { waiting });
+                                ui.label(stats.max_connections.to_string());
+                                ui.label(format!("{} 秒前", stats.idle_secs));
+                                ui.end_row();
+                            }
+                        });
+                });
+
+                ui.add_space(4.0);
+                ui.label(
+                    RichText::new("PostgreSQL 每个数据库使用一个长连接；MySQL 连接池大小可在连接设置中调整")
+                        .small()
+                        .color(Color32::GRAY),
+                );
+            });
+
+        if !open {
+            state.close();
+        }
+        result
+    }
+}
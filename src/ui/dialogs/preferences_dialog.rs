@@ -0,0 +1,476 @@
+//! 首选项窗口
+//!
+//! 分为常规 / 编辑器 / 表格 / 数据库 / 快捷键五个标签页，编辑 `AppConfig` 中的可配置项。
+//! 修改会立即预览，点击确定后保存，取消时恢复打开窗口前的设置。
+
+use super::keyboard;
+use crate::core::{constants, t, KeyBindings, Language, NumberSeparators, Preferences, TimestampDisplay};
+use egui::{self, Color32, RichText};
+
+// ============================================================================
+// 窗口结果
+// ============================================================================
+
+/// 首选项窗口的结果
+pub enum PreferencesResult {
+    /// 无操作
+    None,
+    /// 预览修改（立即生效，不写入配置文件）
+    Preview(Preferences),
+    /// 保存设置
+    Save(Preferences),
+    /// 取消，恢复打开窗口前的设置
+    Cancel(Preferences),
+    /// 打开字体设置窗口
+    OpenFontSettings,
+    /// 打开快捷键编辑窗口
+    OpenKeyBindings,
+}
+
+// ============================================================================
+// 窗口状态
+// ============================================================================
+
+/// 首选项标签页
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PreferencesTab {
+    #[default]
+    General,
+    Editor,
+    Grid,
+    Database,
+    Shortcuts,
+}
+
+impl PreferencesTab {
+    /// 所有标签页
+    pub const ALL: [PreferencesTab; 5] = [
+        PreferencesTab::General,
+        PreferencesTab::Editor,
+        PreferencesTab::Grid,
+        PreferencesTab::Database,
+        PreferencesTab::Shortcuts,
+    ];
+
+    /// 标签页名称
+    pub fn label(&self) -> &'static str {
+        match self {
+            PreferencesTab::General => t("prefs.tab_general"),
+            PreferencesTab::Editor => t("prefs.tab_editor"),
+            PreferencesTab::Grid => t("prefs.tab_grid"),
+            PreferencesTab::Database => t("prefs.tab_database"),
+            PreferencesTab::Shortcuts => t("prefs.tab_shortcuts"),
+        }
+    }
+}
+
+/// 首选项窗口状态
+#[derive(Default)]
+pub struct PreferencesState {
+    /// 是否显示窗口
+    pub show: bool,
+    /// 当前标签页
+    pub tab: PreferencesTab,
+    /// 正在编辑的设置
+    pub prefs: Preferences,
+    /// 打开窗口时的设置（取消时恢复）
+    pub original: Preferences,
+    /// 校验错误
+    pub errors: Vec<String>,
+}
+
+impl PreferencesState {
+    /// 创建新的窗口状态
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 打开窗口
+    pub fn open(&mut self, prefs: Preferences) {
+        self.original = prefs.clone();
+        self.prefs = prefs;
+        self.errors.clear();
+        self.show = true;
+    }
+
+    /// 关闭窗口
+    pub fn close(&mut self) {
+        self.show = false;
+        self.errors.clear();
+    }
+
+    /// 是否有未保存的修改
+    pub fn is_modified(&self) -> bool {
+        self.prefs != self.original
+    }
+}
+
+// ============================================================================
+// 窗口 UI
+// ============================================================================
+
+/// 首选项窗口
+pub struct PreferencesDialog;
+
+impl PreferencesDialog {
+    /// 显示窗口
+    pub fn show(ctx: &egui::Context, state: &mut PreferencesState, keybindings: &KeyBindings) -> PreferencesResult {
+        if !state.show {
+            return PreferencesResult::None;
+        }
+
+        if !keyboard::has_text_focus(ctx) && keyboard::handle_close_keys(ctx) {
+            state.close();
+            return PreferencesResult::Cancel(state.original.clone());
+        }
+
+        let mut result = PreferencesResult::None;
+        let mut changed = false;
+
+        let mut open = true;
+        egui::Window::new(t("prefs.title"))
+            .id(egui::Id::new("preferences_dialog"))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .default_width(520.0)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    for tab in PreferencesTab::ALL {
+                        ui.selectable_value(&mut state.tab, tab, tab.label());
+                    }
+                });
+                ui.separator();
+
+                egui::ScrollArea::vertical().max_height(360.0).auto_shrink([false, true]).show(ui, |ui| {
+                    let prefs = &mut state.prefs;
+                    match state.tab {
+                        PreferencesTab::General => changed |= Self::general_tab(ui, prefs),
+                        PreferencesTab::Editor => {
+                            changed |= Self::editor_tab(ui, prefs);
+                            ui.add_space(8.0);
+                            if ui.button(t("prefs.open_fonts")).clicked() {
+                                result = PreferencesResult::OpenFontSettings;
+                            }
+                        }
+                        PreferencesTab::Grid => changed |= Self::grid_tab(ui, prefs),
+                        PreferencesTab::Database => changed |= Self::database_tab(ui, prefs),
+                        PreferencesTab::Shortcuts => {
+                            if ui.button(t("prefs.edit_shortcuts")).clicked() {
+                                result = PreferencesResult::OpenKeyBindings;
+                            }
+                            ui.add_space(8.0);
+                            Self::shortcuts_tab(ui, keybindings);
+                        }
+                    }
+                });
+
+                for error in &state.errors {
+                    ui.label(RichText::new(error).color(Color32::from_rgb(255, 100, 100)));
+                }
+
+                ui.separator();
+                ui.horizontal(|ui| {
+                    if ui.button(t("common.ok")).clicked() {
+                        match state.prefs.validate() {
+                            Ok(()) => result = PreferencesResult::Save(state.prefs.clone()),
+                            Err(errors) => state.errors = errors,
+                        }
+                    }
+                    if ui.button(t("common.reset_defaults")).clicked() {
+                        state.prefs = Preferences::default();
+                        changed = true;
+                    }
+                    if ui.button(t("common.cancel_esc")).clicked() {
+                        result = PreferencesResult::Cancel(state.original.clone());
+                    }
+                    if state.is_modified() {
+                        ui.label(RichText::new(t("prefs.modified")).small().color(Color32::GRAY));
+                    }
+                });
+            });
+
+        if changed && matches!(result, PreferencesResult::None) {
+            state.errors.clear();
+            if state.prefs.validate().is_ok() {
+                result = PreferencesResult::Preview(state.prefs.clone());
+            }
+        }
+
+        if !open {
+            result = PreferencesResult::Cancel(state.original.clone());
+        }
+        if matches!(result, PreferencesResult::Save(_) | PreferencesResult::Cancel(_)) {
+            state.close();
+        }
+
+        result
+    }
+
+    /// 常规：语言、缩放、确认提示
+    fn general_tab(ui: &mut egui::Ui, prefs: &mut Preferences) -> bool {
+        let mut changed = false;
+        egui::Grid::new("prefs_general").num_columns(2).spacing([16.0, 8.0]).show(ui, |ui| {
+            ui.label(t("prefs.language"));
+            egui::ComboBox::from_id_salt("prefs_language")
+                .selected_text(prefs.language.native_name())
+                .show_ui(ui, |ui| {
+                    for lang in Language::ALL {
+                        changed |= ui.selectable_value(&mut prefs.language, lang, lang.native_name()).changed();
+                    }
+                })
+                .response
+                .on_hover_text(t("prefs.language_coverage"));
+            ui.end_row();
+
+            ui.label(t("prefs.ui_scale"));
+            changed |= ui
+                .add(
+                    egui::Slider::new(&mut prefs.ui_scale, constants::ui::UI_SCALE_MIN..=constants::ui::UI_SCALE_MAX)
+                        .step_by(0.05)
+                        .custom_formatter(|v, _| format!("{:.0}%", v * 100.0)),
+                )
+                .changed();
+            ui.end_row();
+        });
+        changed |= ui
+            .checkbox(&mut prefs.show_key_hints, t("prefs.show_key_hints"))
+            .on_hover_text(t("prefs.show_key_hints_hint"))
+            .changed();
+
+        ui.add_space(8.0);
+        ui.label(RichText::new(t("prefs.confirmations")).strong());
+        changed |= ui.checkbox(&mut prefs.confirm_delete_connection, t("prefs.confirm_delete_connection")).changed();
+        changed |= ui.checkbox(&mut prefs.confirm_drop_object, t("prefs.confirm_drop_object")).changed();
+        changed |= ui.checkbox(&mut prefs.confirm_grid_delete, t("prefs.confirm_grid_delete")).changed();
+        changed
+    }
+
+    /// 编辑器：历史记录
+    fn editor_tab(ui: &mut egui::Ui, prefs: &mut Preferences) -> bool {
+        let mut changed = false;
+        egui::Grid::new("prefs_editor").num_columns(2).spacing([16.0, 8.0]).show(ui, |ui| {
+            ui.label(t("prefs.history_limit"));
+            changed |= ui
+                .add(egui::DragValue::new(&mut prefs.history_limit).range(constants::history::QUERY_HISTORY_LIMIT_RANGE))
+                .changed();
+            ui.end_row();
+
+            ui.label(t("prefs.command_history_limit"));
+            changed |= ui
+                .add(
+                    egui::DragValue::new(&mut prefs.command_history_limit)
+                        .range(constants::history::COMMAND_HISTORY_LIMIT_RANGE),
+                )
+                .changed();
+            ui.end_row();
+
+            ui.label(t("prefs.trash_retention_days"));
+            changed |= ui
+                .add(
+                    egui::DragValue::new(&mut prefs.trash_retention_days)
+                        .range(constants::history::TRASH_RETENTION_RANGE_DAYS),
+                )
+                .changed();
+            ui.end_row();
+        });
+        changed
+    }
+
+    /// 表格：预览行数、结果集上限
+    fn grid_tab(ui: &mut egui::Ui, prefs: &mut Preferences) -> bool {
+        let mut changed = false;
+        egui::Grid::new("prefs_grid").num_columns(2).spacing([16.0, 8.0]).show(ui, |ui| {
+            ui.label(t("prefs.default_query_limit"));
+            changed |= ui
+                .add(
+                    egui::DragValue::new(&mut prefs.default_query_limit)
+                        .range(constants::database::QUERY_LIMIT_RANGE)
+                        .speed(10.0),
+                )
+                .changed();
+            ui.end_row();
+
+            ui.label(t("prefs.max_result_rows"));
+            changed |= ui
+                .add(
+                    egui::DragValue::new(&mut prefs.max_result_rows)
+                        .range(constants::database::RESULT_ROWS_RANGE)
+                        .speed(1000.0),
+                )
+                .on_hover_text(t("prefs.max_result_rows_hint"))
+                .changed();
+            ui.end_row();
+
+            ui.label(t("prefs.memory_warning_mb"));
+            changed |= ui
+                .add(
+                    egui::DragValue::new(&mut prefs.memory_warning_mb)
+                        .range(constants::database::MEMORY_WARNING_RANGE_MB)
+                        .speed(64.0)
+                        .suffix(" MB"),
+                )
+                .on_hover_text(t("prefs.memory_warning_mb_hint"))
+                .changed();
+            ui.end_row();
+
+            ui.label(t("prefs.timestamp_display")).on_hover_text(t("prefs.timestamp_display_hint"));
+            egui::ComboBox::from_id_salt("prefs_timestamp_display")
+                .selected_text(t(prefs.timestamp_display.i18n_key()))
+                .show_ui(ui, |ui| {
+                    for &mode in TimestampDisplay::all() {
+                        changed |= ui.selectable_value(&mut prefs.timestamp_display, mode, t(mode.i18n_key())).changed();
+                    }
+                });
+            ui.end_row();
+
+            ui.label(t("prefs.number_separators")).on_hover_text(t("prefs.number_format_hint"));
+            egui::ComboBox::from_id_salt("prefs_number_separators")
+                .selected_text(prefs.number_format.separators.example())
+                .show_ui(ui, |ui| {
+                    for &separators in NumberSeparators::all() {
+                        changed |= ui
+                            .selectable_value(&mut prefs.number_format.separators, separators, separators.example())
+                            .changed();
+                    }
+                });
+            ui.end_row();
+
+            ui.label(t("prefs.number_decimals"));
+            let decimals_text = |d: Option<u8>| d.map_or_else(|| t("prefs.number_decimals_keep").to_string(), |d| d.to_string());
+            egui::ComboBox::from_id_salt("prefs_number_decimals")
+                .selected_text(decimals_text(prefs.number_format.decimals))
+                .show_ui(ui, |ui| {
+                    for decimals in std::iter::once(None).chain((0..=6).map(Some)) {
+                        changed |= ui
+                            .selectable_value(&mut prefs.number_format.decimals, decimals, decimals_text(decimals))
+                            .changed();
+                    }
+                });
+            ui.end_row();
+        });
+        changed |= ui.checkbox(&mut prefs.number_format.scientific, t("prefs.number_scientific")).changed();
+        changed |= ui.checkbox(&mut prefs.number_format.align_right, t("prefs.number_align_right")).changed();
+        ui.add_space(8.0);
+        changed |= ui
+            .checkbox(&mut prefs.show_grid_footer, t("prefs.show_grid_footer"))
+            .on_hover_text(t("prefs.show_grid_footer_hint"))
+            .changed();
+        changed |= ui
+            .checkbox(&mut prefs.spill_large_results, t("prefs.spill_large_results"))
+            .on_hover_text(t("prefs.spill_large_results_hint"))
+            .changed();
+        ui.add_space(8.0);
+        ui.horizontal(|ui| {
+            ui.label(t("prefs.image_preview_dir"));
+            changed |= ui
+                .add(
+                    egui::TextEdit::singleline(&mut prefs.image_preview_dir)
+                        .hint_text(t("prefs.image_preview_dir_empty"))
+                        .desired_width(240.0),
+                )
+                .on_hover_text(t("prefs.image_preview_dir_hint"))
+                .changed();
+        });
+        changed
+    }
+
+    /// 数据库：默认超时和元数据刷新
+    fn database_tab(ui: &mut egui::Ui, prefs: &mut Preferences) -> bool {
+        let mut changed = false;
+        egui::Grid::new("prefs_database").num_columns(2).spacing([16.0, 8.0]).show(ui, |ui| {
+            ui.label(t("prefs.connect_timeout"));
+            changed |= ui
+                .add(
+                    egui::DragValue::new(&mut prefs.connect_timeout_secs)
+                        .range(constants::database::CONNECTION_TIMEOUT_RANGE_SECS)
+                        .suffix(" s"),
+                )
+                .changed();
+            ui.end_row();
+
+            ui.label(t("prefs.query_timeout"));
+            changed |= ui
+                .add(
+                    egui::DragValue::new(&mut prefs.query_timeout_secs)
+                        .range(constants::database::QUERY_TIMEOUT_RANGE_SECS)
+                        .suffix(" s"),
+                )
+                .changed();
+            ui.end_row();
+
+            ui.label(t("prefs.metadata_refresh"));
+            changed |= ui
+                .add(
+                    egui::DragValue::new(&mut prefs.metadata_refresh_secs)
+                        .range(constants::database::METADATA_REFRESH_RANGE_SECS)
+                        .suffix(" s"),
+                )
+                .on_hover_text(t("prefs.metadata_refresh_hint"))
+                .changed();
+            ui.end_row();
+
+            ui.label(t("prefs.keep_alive"));
+            changed |= ui
+                .add(
+                    egui::DragValue::new(&mut prefs.keep_alive_secs)
+                        .range(constants::database::KEEP_ALIVE_RANGE_SECS)
+                        .suffix(" s"),
+                )
+                .on_hover_text(t("prefs.keep_alive_hint"))
+                .changed();
+            ui.end_row();
+
+            ui.label(t("prefs.pool_idle_ttl"));
+            changed |= ui
+                .add(
+                    egui::DragValue::new(&mut prefs.pool_idle_ttl_secs)
+                        .range(constants::database::POOL_IDLE_TTL_RANGE_SECS)
+                        .suffix(" s"),
+                )
+                .on_hover_text(t("prefs.pool_idle_ttl_hint"))
+                .changed();
+            ui.end_row();
+
+            ui.label(t("prefs.max_parallel_queries"));
+            changed |= ui
+                .add(
+                    egui::DragValue::new(&mut prefs.max_parallel_queries)
+                        .range(constants::database::PARALLEL_QUERIES_RANGE),
+                )
+                .on_hover_text(t("prefs.max_parallel_queries_hint"))
+                .changed();
+            ui.end_row();
+
+            ui.label(t("prefs.desktop_notify"));
+            changed |= ui
+                .add(
+                    egui::DragValue::new(&mut prefs.desktop_notify_secs)
+                        .range(constants::database::DESKTOP_NOTIFY_RANGE_SECS)
+                        .suffix(" s"),
+                )
+                .on_hover_text(t("prefs.desktop_notify_hint"))
+                .changed();
+            ui.end_row();
+        });
+        ui.add_space(4.0);
+        ui.label(RichText::new(t("prefs.timeout_hint")).small().color(Color32::GRAY));
+        changed
+    }
+
+    /// 快捷键：当前绑定一览
+    fn shortcuts_tab(ui: &mut egui::Ui, keybindings: &KeyBindings) {
+        for (category, bindings) in keybindings.bindings_by_category() {
+            ui.label(RichText::new(category).strong());
+            egui::Grid::new(("prefs_shortcuts", category)).num_columns(2).spacing([16.0, 4.0]).show(ui, |ui| {
+                for (action, binding) in bindings {
+                    ui.label(action.description());
+                    ui.label(RichText::new(binding.display()).monospace());
+                    ui.end_row();
+                }
+            });
+            ui.add_space(6.0);
+        }
+    }
+}
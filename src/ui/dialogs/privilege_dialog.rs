@@ -0,0 +1,389 @@
+//! 权限管理对话框
+//!
+//! 列出用户/角色，按数据库和表显示已授予的权限，通过复选框矩阵修改权限，
+//! 生成 GRANT/REVOKE 语句并在执行前预览。支持 MySQL 和 PostgreSQL。
+//! 支持 Helix 风格的键盘导航。
+
+use std::collections::HashSet;
+
+use super::keyboard::{self, DialogAction};
+use crate::database::{DatabaseType, GrantInfo};
+use egui::{self, Color32, RichText, TextEdit};
+
+// ============================================================================
+// 对话框结果
+// ============================================================================
+
+/// 权限管理对话框的结果
+pub enum PrivilegeDialogResult {
+    /// 无操作
+    None,
+    /// 需要加载指定用户的权限
+    LoadGrants(String),
+    /// 用户确认修改（GRANT/REVOKE 语句列表）
+    Apply(Vec<String>),
+    /// 用户取消
+    Cancelled,
+}
+
+// ============================================================================
+// 权限定义
+// ============================================================================
+
+/// 数据库级可授予的权限
+pub fn database_privileges(db_type: DatabaseType) -> &'static [&'static str] {
+    match db_type {
+        DatabaseType::MySQL => &[
+            "SELECT", "INSERT", "UPDATE", "DELETE", "CREATE", "DROP", "ALTER", "INDEX", "REFERENCES", "TRIGGER",
+        ],
+        DatabaseType::PostgreSQL => &["CONNECT", "CREATE", "TEMPORARY"],
+        _ => &[],
+    }
+}
+
+/// 表级可授予的权限
+pub fn table_privileges(db_type: DatabaseType) -> &'static [&'static str] {
+    match db_type {
+        DatabaseType::MySQL => &[
+            "SELECT", "INSERT", "UPDATE", "DELETE", "CREATE", "DROP", "ALTER", "INDEX", "REFERENCES", "TRIGGER",
+        ],
+        DatabaseType::PostgreSQL => &["SELECT", "INSERT", "UPDATE", "DELETE", "TRUNCATE", "REFERENCES", "TRIGGER"],
+        _ => &[],
+    }
+}
+
+// ============================================================================
+// 对话框状态
+// ============================================================================
+
+/// 权限管理对话框状态
+#[derive(Default)]
+pub struct PrivilegeDialogState {
+    /// 是否显示对话框
+    pub show: bool,
+    /// 当前数据库类型
+    pub db_type: DatabaseType,
+    /// 当前数据库
+    pub database: String,
+    /// 当前数据库的表
+    pub tables: Vec<String>,
+    /// 用户/角色列表（MySQL 为 `'user'@'host'`）
+    pub users: Vec<String>,
+    /// 是否正在加载用户列表
+    pub loading_users: bool,
+    /// 选中的用户
+    pub selected_user: Option<String>,
+    /// 是否正在加载权限
+    pub loading_grants: bool,
+    /// 加载时的权限（表名, 权限），数据库级权限的表名为空
+    original: HashSet<(String, String)>,
+    /// 编辑后的权限
+    granted: HashSet<(String, String)>,
+    /// 错误信息
+    pub error: Option<String>,
+}
+
+impl PrivilegeDialogState {
+    /// 创建新的对话框状态
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 打开对话框（用户列表由调用方异步加载）
+    pub fn open(&mut self, db_type: DatabaseType, database: String, tables: Vec<String>) {
+        *self = Self {
+            show: true,
+            db_type,
+            database,
+            tables,
+            loading_users: true,
+            ..Self::default()
+        };
+    }
+
+    /// 关闭对话框
+    pub fn close(&mut self) {
+        *self = Self::default();
+    }
+
+    /// 设置用户列表
+    pub fn set_users(&mut self, users: Vec<String>) {
+        self.users = users;
+        self.loading_users = false;
+    }
+
+    /// 选中用户并清空已加载的权限，等待调用方加载
+    pub fn select_user(&mut self, user: String) {
+        self.selected_user = Some(user);
+        self.original.clear();
+        self.granted.clear();
+        self.error = None;
+        self.loading_grants = true;
+    }
+
+    /// 设置用户的权限（用户已切换时忽略过期结果）
+    pub fn set_grants(&mut self, user: &str, grants: Vec<GrantInfo>) {
+        if self.selected_user.as_deref() != Some(user) {
+            return;
+        }
+        self.original = grants.into_iter().map(|g| (g.table_name, g.privilege)).collect();
+        self.granted = self.original.clone();
+        self.loading_grants = false;
+    }
+
+    /// 权限是否已勾选（`table` 为空表示数据库级）
+    pub fn is_granted(&self, table: &str, privilege: &str) -> bool {
+        self.granted.contains(&(table.to_string(), privilege.to_string()))
+    }
+
+    /// 勾选或取消权限
+    pub fn set_granted(&mut self, table: &str, privilege: &str, granted: bool) {
+        let key = (table.to_string(), privilege.to_string());
+        if granted {
+            self.granted.insert(key);
+        } else {
+            self.granted.remove(&key);
+        }
+    }
+
+    /// 是否有未应用的修改
+    pub fn has_changes(&self) -> bool {
+        self.original != self.granted
+    }
+
+    /// 根据勾选变化生成 GRANT/REVOKE 语句（先数据库级，再按表顺序）
+    pub fn statements(&self) -> Result<Vec<String>, String> {
+        let user = self.selected_user.as_deref().ok_or("未选择用户")?;
+        let grantee = match self.db_type {
+            DatabaseType::MySQL => user.to_string(),
+            DatabaseType::PostgreSQL => format!("\"{}\"", user.replace('"', "\"\"")),
+            _ => return Err(format!("{} 暂不支持权限管理", self.db_type.display_name())),
+        };
+
+        let objects = std::iter::once(("", database_privileges(self.db_type)))
+            .chain(self.tables.iter().map(|t| (t.as_str(), table_privileges(self.db_type))));
+
+        let mut statements = Vec::new();
+        for (table, privileges) in objects {
+            let changed = |want: bool| -> Vec<&str> {
+                privileges
+                    .iter()
+                    .copied()
+                    .filter(|p| {
+                        let key = (table.to_string(), p.to_string());
+                        self.granted.contains(&key) == want && self.original.contains(&key) != want
+                    })
+                    .collect()
+            };
+            let target = self.target(table);
+            let to_grant = changed(true);
+            if !to_grant.is_empty() {
+                statements.push(format!("GRANT {} ON {} TO {};", to_grant.join(", "), target, grantee));
+            }
+            let to_revoke = changed(false);
+            if !to_revoke.is_empty() {
+                statements.push(format!("REVOKE {} ON {} FROM {};", to_revoke.join(", "), target, grantee));
+            }
+        }
+        Ok(statements)
+    }
+
+    /// GRANT/REVOKE 的授权对象
+    fn target(&self, table: &str) -> String {
+        match (self.db_type, table) {
+            (DatabaseType::PostgreSQL, "") => format!("DATABASE \"{}\"", self.database.replace('"', "\"\"")),
+            (DatabaseType::PostgreSQL, table) => format!("TABLE \"public\".\"{}\"", table.replace('"', "\"\"")),
+            (_, "") => format!("`{}`.*", self.database.replace('`', "``")),
+            (_, table) => format!("`{}`.`{}`", self.database.replace('`', "``"), table.replace('`', "``")),
+        }
+    }
+}
+
+// ============================================================================
+// 对话框 UI
+// ============================================================================
+
+/// 权限管理对话框
+pub struct PrivilegeDialog;
+
+impl PrivilegeDialog {
+    /// 显示对话框
+    pub fn show(ctx: &egui::Context, state: &mut PrivilegeDialogState) -> PrivilegeDialogResult {
+        if !state.show {
+            return PrivilegeDialogResult::None;
+        }
+
+        let mut result = PrivilegeDialogResult::None;
+        let mut should_close = false;
+
+        // 键盘快捷键处理
+        if !keyboard::has_text_focus(ctx) {
+            if keyboard::handle_close_keys(ctx) {
+                state.close();
+                return PrivilegeDialogResult::Cancelled;
+            }
+
+            if let DialogAction::Confirm = keyboard::handle_dialog_keys(ctx)
+                && state.has_changes()
+            {
+                match state.statements() {
+                    Ok(statements) => result = PrivilegeDialogResult::Apply(statements),
+                    Err(e) => state.error = Some(e),
+                }
+            }
+        }
+
+        let mut open = true;
+        egui::Window::new(format!("权限管理 - {}", state.database))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(true)
+            .default_size([760.0, 480.0])
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.horizontal_top(|ui| {
+                    // 用户列表
+                    ui.vertical(|ui| {
+                        ui.set_width(180.0);
+                        ui.horizontal(|ui| {
+                            ui.label(RichText::new("用户/角色").strong());
+                            if state.loading_users {
+                                ui.spinner();
+                            }
+                        });
+                        ui.separator();
+                        egui::ScrollArea::vertical()
+                            .id_salt("privilege_users")
+                            .max_height(360.0)
+                            .show(ui, |ui| {
+                                let mut clicked = None;
+                                for user in &state.users {
+                                    let selected = state.selected_user.as_ref() == Some(user);
+                                    if ui.selectable_label(selected, user).clicked() && !selected {
+                                        clicked = Some(user.clone());
+                                    }
+                                }
+                                if let Some(user) = clicked {
+                                    state.select_user(user.clone());
+                                    result = PrivilegeDialogResult::LoadGrants(user);
+                                }
+                            });
+                    });
+
+                    ui.separator();
+
+                    // 权限矩阵
+                    ui.vertical(|ui| {
+                        let Some(user) = state.selected_user.clone() else {
+                            ui.label(RichText::new("选择左侧用户查看权限").color(Color32::GRAY));
+                            return;
+                        };
+                        ui.horizontal(|ui| {
+                            ui.label(RichText::new(&user).strong());
+                            if state.loading_grants {
+                                ui.spinner();
+                            } else if ui.small_button("刷新").clicked() {
+                                state.select_user(user.clone());
+                                result = PrivilegeDialogResult::LoadGrants(user.clone());
+                            }
+                        });
+                        ui.separator();
+                        if state.loading_grants {
+                            return;
+                        }
+
+                        Self::show_matrix(ui, state);
+
+                        ui.add_space(8.0);
+
+                        // 预览 SQL
+                        ui.collapsing("预览 SQL", |ui| {
+                            let sql = state.statements().map(|stmts| stmts.join("\n")).unwrap_or_default();
+                            ui.add(
+                                TextEdit::multiline(&mut sql.as_str())
+                                    .code_editor()
+                                    .desired_width(f32::INFINITY)
+                                    .desired_rows(4),
+                            );
+                        });
+                    });
+                });
+
+                if let Some(err) = &state.error {
+                    ui.add_space(4.0);
+                    ui.label(RichText::new(err).color(Color32::from_rgb(255, 100, 100)));
+                }
+
+                ui.add_space(8.0);
+
+                ui.horizontal(|ui| {
+                    if ui
+                        .add_enabled(state.has_changes(), egui::Button::new("应用 [Enter]"))
+                        .clicked()
+                    {
+                        match state.statements() {
+                            Ok(statements) => result = PrivilegeDialogResult::Apply(statements),
+                            Err(e) => state.error = Some(e),
+                        }
+                    }
+
+                    if ui.button("关闭 [Esc]").clicked() {
+                        result = PrivilegeDialogResult::Cancelled;
+                        should_close = true;
+                    }
+                });
+            });
+
+        if should_close || !open {
+            state.close();
+        }
+
+        result
+    }
+
+    /// 复选框矩阵：第一行为数据库级权限，之后每行一张表
+    fn show_matrix(ui: &mut egui::Ui, state: &mut PrivilegeDialogState) {
+        let db_privileges = database_privileges(state.db_type);
+        let tbl_privileges = table_privileges(state.db_type);
+
+        ui.label(RichText::new("数据库级权限").small().color(Color32::GRAY));
+        ui.horizontal_wrapped(|ui| {
+            for privilege in db_privileges {
+                let mut granted = state.is_granted("", privilege);
+                if ui.checkbox(&mut granted, *privilege).changed() {
+                    state.set_granted("", privilege, granted);
+                }
+            }
+        });
+
+        ui.add_space(6.0);
+        ui.label(RichText::new("表级权限").small().color(Color32::GRAY));
+
+        egui::ScrollArea::both()
+            .id_salt("privilege_matrix")
+            .max_height(260.0)
+            .show(ui, |ui| {
+                egui::Grid::new("privilege_grid")
+                    .striped(true)
+                    .spacing([10.0, 4.0])
+                    .show(ui, |ui| {
+                        ui.label("");
+                        for privilege in tbl_privileges {
+                            ui.label(RichText::new(*privilege).small().strong());
+                        }
+                        ui.end_row();
+
+                        for table in state.tables.clone() {
+                            ui.label(&table);
+                            for privilege in tbl_privileges {
+                                let mut granted = state.is_granted(&table, privilege);
+                                if ui.checkbox(&mut granted, "").changed() {
+                                    state.set_granted(&table, privilege, granted);
+                                }
+                            }
+                            ui.end_row();
+                        }
+                    });
+            });
+    }
+}
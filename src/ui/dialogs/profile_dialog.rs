@@ -0,0 +1,176 @@
+//! 查询分析面板
+//!
+//! 显示最近执行的查询的运行时统计（I/O、缓冲区、临时文件、检查的行等），
+//! 与查询执行时的耗时放在一起比较。统计由调用方在后台读取；
+//! 勾选“执行后自动分析”时，面板打开期间每次查询成功后都会重新分析。
+
+use super::keyboard;
+use crate::database::QueryProfile;
+use egui::{self, Color32, RichText};
+
+/// 查询分析面板的结果
+pub enum ProfileResult {
+    /// 无操作
+    None,
+    /// 重新分析
+    Refresh,
+}
+
+/// 查询分析面板状态
+#[derive(Default)]
+pub struct ProfileState {
+    /// 是否显示面板
+    pub show: bool,
+    /// 执行查询后自动分析
+    pub auto: bool,
+    /// 分析的 SQL
+    pub sql: String,
+    /// 查询执行时的耗时（毫秒）
+    pub query_elapsed_ms: Option<u64>,
+    /// 是否正在分析
+    pub loading: bool,
+    /// 当前分析的编号（用于丢弃过期的结果）
+    pub request_id: u64,
+    /// 分析结果
+    pub profile: Option<Result<QueryProfile, String>>,
+}
+
+impl ProfileState {
+    /// 创建新的面板状态
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 开始分析，返回分析编号
+    pub fn start(&mut self, sql: String, query_elapsed_ms: Option<u64>) -> u64 {
+        self.show = true;
+        self.sql = sql;
+        self.query_elapsed_ms = query_elapsed_ms;
+        self.loading = true;
+        self.profile = None;
+        self.request_id += 1;
+        self.request_id
+    }
+
+    /// 无法分析（显示原因）
+    pub fn fail(&mut self, sql: String, query_elapsed_ms: Option<u64>, error: String) {
+        self.start(sql, query_elapsed_ms);
+        self.loading = false;
+        self.profile = Some(Err(error));
+    }
+
+    /// 记录分析结果，忽略过期的结果
+    pub fn set_result(&mut self, request_id: u64, profile: Result<QueryProfile, String>) {
+        if request_id == self.request_id {
+            self.loading = false;
+            self.profile = Some(profile);
+        }
+    }
+}
+
+/// 查询分析面板
+pub struct ProfileDialog;
+
+impl ProfileDialog {
+    /// 显示面板
+    pub fn show(ctx: &egui::Context, state: &mut ProfileState) -> ProfileResult {
+        if !state.show {
+            return ProfileResult::None;
+        }
+
+        let mut result = ProfileResult::None;
+
+        if !keyboard::has_text_focus(ctx) && keyboard::handle_close_keys(ctx) {
+            state.show = false;
+            return result;
+        }
+
+        let mut open = true;
+        egui::Window::new("📊 查询分析")
+            .open(&mut open)
+            .collapsible(true)
+            .resizable(true)
+            .default_size([480.0, 420.0])
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.checkbox(&mut state.auto, "执行后自动分析")
+                        .on_hover_text("面板打开时，每次查询成功后再执行一次以读取统计（只分析只读查询）");
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if state.loading {
+                            ui.spinner();
+                        } else if ui
+                            .add_enabled(!state.sql.is_empty(), egui::Button::new("🔄 重新分析"))
+                            .clicked()
+                        {
+                            result = ProfileResult::Refresh;
+                        }
+                    });
+                });
+                ui.separator();
+
+                if state.sql.is_empty() {
+                    ui.label(RichText::new("还没有执行过查询").color(Color32::GRAY));
+                    return;
+                }
+                egui::ScrollArea::vertical()
+                    .id_salt("profile_sql_scroll")
+                    .max_height(60.0)
+                    .show(ui, |ui| {
+                        ui.label(RichText::new(&state.sql).monospace().color(Color32::GRAY));
+                    });
+                ui.add_space(4.0);
+
+                match &state.profile {
+                    None => {
+                        ui.label(RichText::new("正在分析...").color(Color32::GRAY));
+                    }
+                    Some(Err(e)) => {
+                        ui.label(RichText::new(e).color(Color32::from_rgb(255, 100, 100)));
+                    }
+                    Some(Ok(profile)) => Self::show_profile(ui, profile, state.query_elapsed_ms),
+                }
+            });
+
+        if !open {
+            state.show = false;
+        }
+        result
+    }
+
+    /// 统计表和执行计划
+    fn show_profile(ui: &mut egui::Ui, profile: &QueryProfile, query_elapsed_ms: Option<u64>) {
+        egui::Grid::new("profile_stats").num_columns(2).striped(true).spacing([24.0, 4.0]).show(ui, |ui| {
+            if let Some(ms) = query_elapsed_ms {
+                ui.label("查询耗时");
+                ui.label(RichText::new(format!("{} ms", ms)).strong());
+                ui.end_row();
+            }
+            ui.label("分析时耗时");
+            ui.label(format!("{} ms", profile.elapsed_ms));
+            ui.end_row();
+            ui.label("返回的行");
+            ui.label(profile.rows.to_string());
+            ui.end_row();
+            for (name, value) in &profile.stats {
+                ui.label(name);
+                ui.label(RichText::new(value).monospace());
+                ui.end_row();
+            }
+        });
+
+        if let Some(details) = &profile.details {
+            ui.add_space(8.0);
+            egui::CollapsingHeader::new("执行计划")
+                .id_salt("profile_details")
+                .default_open(true)
+                .show(ui, |ui| {
+                    egui::ScrollArea::both()
+                        .id_salt("profile_details_scroll")
+                        .max_height(200.0)
+                        .show(ui, |ui| {
+                            ui.label(RichText::new(details).monospace().size(12.0));
+                        });
+                });
+        }
+    }
+}
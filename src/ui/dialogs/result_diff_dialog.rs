@@ -0,0 +1,280 @@
+//! 结果对比窗口
+//!
+//! 将固定的结果快照与当前结果对比，按键列或整行匹配，
+//! 用颜色区分新增、删除和修改的行，并可导出对比报告。
+
+use super::keyboard;
+use crate::core::{diff_results, DiffKind, ResultDiff};
+use crate::database::QueryResult;
+use crate::ui::ResultSnapshot;
+use egui::{self, Color32, RichText};
+use std::sync::Arc;
+
+const ADDED_COLOR: Color32 = Color32::from_rgb(100, 200, 120);
+const REMOVED_COLOR: Color32 = Color32::from_rgb(230, 100, 100);
+const CHANGED_COLOR: Color32 = Color32::from_rgb(230, 180, 80);
+
+/// 变化类型对应的颜色
+pub fn diff_color(kind: DiffKind) -> Color32 {
+    match kind {
+        DiffKind::Added => ADDED_COLOR,
+        DiffKind::Removed => REMOVED_COLOR,
+        DiffKind::Changed => CHANGED_COLOR,
+        DiffKind::Unchanged => Color32::GRAY,
+    }
+}
+
+// ============================================================================
+// 窗口结果
+// ============================================================================
+
+/// 结果对比窗口的结果
+pub enum ResultDiffResult {
+    /// 无操作
+    None,
+    /// 导出对比报告
+    Export(QueryResult),
+}
+
+// ============================================================================
+// 窗口状态
+// ============================================================================
+
+/// 结果对比窗口状态
+#[derive(Default)]
+pub struct ResultDiffState {
+    /// 是否显示窗口
+    pub show: bool,
+    /// 快照
+    pub snapshot: Option<ResultSnapshot>,
+    /// 当前结果
+    pub current: Arc<QueryResult>,
+    /// 当前结果的 SQL 与快照不同
+    pub sql_changed: bool,
+    /// 用于匹配行的键列（为空时按整行匹配）
+    pub key_columns: Vec<String>,
+    /// 是否显示未变化的行
+    pub show_unchanged: bool,
+    /// 对比结果
+    pub diff: Option<Result<ResultDiff, String>>,
+}
+
+impl ResultDiffState {
+    /// 创建新的窗口状态
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 打开窗口并对比；`key_column` 存在于结果中时默认按其匹配
+    pub fn open(&mut self, snapshot: ResultSnapshot, current: Arc<QueryResult>, sql: &str, key_column: Option<&str>) {
+        self.sql_changed = snapshot.sql.trim() != sql.trim();
+        self.key_columns = key_column
+            .filter(|k| current.columns.iter().any(|c| c == k) && snapshot.result.columns.iter().any(|c| c == k))
+            .map(|k| vec![k.to_string()])
+            .unwrap_or_default();
+        self.snapshot = Some(snapshot);
+        self.current = current;
+        self.show = true;
+        self.recompute();
+    }
+
+    /// 关闭窗口
+    pub fn close(&mut self) {
+        self.show = false;
+        self.snapshot = None;
+        self.current = Arc::default();
+        self.diff = None;
+    }
+
+    /// 切换某列是否作为键列
+    pub fn toggle_key(&mut self, column: &str) {
+        match self.key_columns.iter().position(|k| k == column) {
+            Some(i) => {
+                self.key_columns.remove(i);
+            }
+            None => self.key_columns.push(column.to_string()),
+        }
+        self.recompute();
+    }
+
+    /// 重新计算对比结果
+    pub fn recompute(&mut self) {
+        self.diff = self
+            .snapshot
+            .as_ref()
+            .map(|snapshot| diff_results(&snapshot.result, &self.current, &self.key_columns));
+    }
+}
+
+// ============================================================================
+// 窗口 UI
+// ============================================================================
+
+/// 结果对比窗口
+pub struct ResultDiffDialog;
+
+impl ResultDiffDialog {
+    /// 显示窗口
+    pub fn show(ctx: &egui::Context, state: &mut ResultDiffState) -> ResultDiffResult {
+        if !state.show {
+            return ResultDiffResult::None;
+        }
+
+        if !keyboard::has_text_focus(ctx) && keyboard::handle_close_keys(ctx) {
+            state.close();
+            return ResultDiffResult::None;
+        }
+
+        let mut result = ResultDiffResult::None;
+        let mut toggled_key = None;
+
+        let mut open = true;
+        egui::Window::new("🔀 结果对比")
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(true)
+            .default_size([760.0, 480.0])
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                let Some(snapshot) = &state.snapshot else {
+                    return;
+                };
+
+                ui.horizontal(|ui| {
+                    ui.label(format!(
+                        "快照 {}（{} 行） → 当前（{} 行）",
+                        snapshot.taken_at.format("%H:%M:%S"),
+                        snapshot.result.rows.len(),
+                        state.current.rows.len()
+                    ));
+                    if state.sql_changed {
+                        ui.label(RichText::new("⚠ 查询语句与快照不同").small().color(CHANGED_COLOR));
+                    }
+                });
+
+                // 匹配方式
+                ui.horizontal_wrapped(|ui| {
+                    ui.label("匹配键:");
+                    if state.key_columns.is_empty() {
+                        ui.label(RichText::new("整行").color(Color32::GRAY));
+                    }
+                    for column in &state.current.columns {
+                        let selected = state.key_columns.contains(column);
+                        if ui.selectable_label(selected, column).clicked() {
+                            toggled_key = Some(column.clone());
+                        }
+                    }
+                });
+
+                ui.separator();
+
+                let diff = match &state.diff {
+                    Some(Ok(diff)) => diff,
+                    Some(Err(e)) => {
+                        ui.label(RichText::new(e).color(REMOVED_COLOR));
+                        return;
+                    }
+                    None => return,
+                };
+
+                ui.horizontal(|ui| {
+                    for kind in [DiffKind::Added, DiffKind::Removed, DiffKind::Changed, DiffKind::Unchanged] {
+                        ui.label(
+                            RichText::new(format!("{} {}", kind.label(), diff.count(kind))).color(diff_color(kind)),
+                        );
+                    }
+                    ui.separator();
+                    ui.checkbox(&mut state.show_unchanged, "显示未变化的行");
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui.add_enabled(!diff.is_identical(), egui::Button::new("💾 导出报告...")).clicked() {
+                            result = ResultDiffResult::Export(diff.to_report());
+                        }
+                    });
+                });
+
+                if diff.is_identical() {
+                    ui.add_space(8.0);
+                    ui.label(RichText::new("两次结果完全相同").color(ADDED_COLOR));
+                    if !state.show_unchanged {
+                        return;
+                    }
+                }
+
+                ui.add_space(4.0);
+                Self::show_rows(ui, diff, state.show_unchanged);
+            });
+
+        if let Some(column) = toggled_key {
+            state.toggle_key(&column);
+        }
+
+        if !open {
+            state.close();
+        }
+
+        result
+    }
+
+    /// 对比结果表格：修改的单元格显示为「旧值 → 新值」
+    fn show_rows(ui: &mut egui::Ui, diff: &ResultDiff, show_unchanged: bool) {
+        use egui_extras::{Column, TableBuilder};
+
+        let rows: Vec<_> = diff
+            .rows
+            .iter()
+            .filter(|r| show_unchanged || r.kind != DiffKind::Unchanged)
+            .collect();
+
+        egui::ScrollArea::horizontal().id_salt("result_diff_scroll").show(ui, |ui| {
+            TableBuilder::new(ui)
+                .striped(true)
+                .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+                .column(Column::exact(24.0))
+                .columns(Column::auto().at_least(60.0).clip(true), diff.columns.len())
+                .header(20.0, |mut header| {
+                    header.col(|_| {});
+                    for column in &diff.columns {
+                        header.col(|ui| {
+                            ui.label(RichText::new(column).strong().small());
+                        });
+                    }
+                })
+                .body(|body| {
+                    body.rows(18.0, rows.len(), |mut table_row| {
+                        let row = rows[table_row.index()];
+                        let color = diff_color(row.kind);
+                        let marker = match row.kind {
+                            DiffKind::Added => "+",
+                            DiffKind::Removed => "-",
+                            DiffKind::Changed => "~",
+                            DiffKind::Unchanged => "",
+                        };
+                        table_row.col(|ui| {
+                            ui.label(RichText::new(marker).monospace().color(color));
+                        });
+                        for (i, value) in row.values.iter().enumerate() {
+                            table_row.col(|ui| {
+                                let old = row
+                                    .old_values
+                                    .as_ref()
+                                    .filter(|_| row.changed_columns.contains(&i))
+                                    .and_then(|old| old.get(i));
+                                match old {
+                                    Some(old) => {
+                                        ui.label(RichText::new(format!("{} → {}", old, value)).small().color(color))
+                                            .on_hover_text(format!("修改前: {}\n修改后: {}", old, value));
+                                    }
+                                    None if row.kind == DiffKind::Changed => {
+                                        ui.label(RichText::new(value).small());
+                                    }
+                                    None => {
+                                        ui.label(RichText::new(value).small().color(color));
+                                    }
+                                }
+                            });
+                        }
+                    });
+                });
+        });
+    }
+}
@@ -0,0 +1,147 @@
+//! 行书签列表窗口
+//!
+//! 按表列出当前数据库中收藏的行（当前表在最前），点击后按主键重新查询该表并定位到该行。
+
+use super::keyboard;
+use crate::core::{RowBookmark, RowBookmarks};
+use crate::ui::styles::{DANGER, GRAY, MUTED};
+use egui::{self, RichText};
+
+/// 说明的最大字符数
+const LABEL_MAX_CHARS: usize = 48;
+
+/// 行书签列表窗口的结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RowBookmarkResult {
+    /// 无操作
+    None,
+    /// 跳转到收藏的行 (表名, 书签)
+    Jump(String, RowBookmark),
+    /// 删除书签 (表名, 书签)
+    Remove(String, RowBookmark),
+}
+
+/// 行书签列表窗口状态
+#[derive(Default)]
+pub struct RowBookmarkState {
+    /// 是否显示窗口
+    pub show: bool,
+}
+
+impl RowBookmarkState {
+    /// 创建新的状态
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 打开窗口
+    pub fn open(&mut self) {
+        self.show = true;
+    }
+
+    /// 关闭窗口
+    pub fn close(&mut self) {
+        self.show = false;
+    }
+}
+
+/// 行书签列表窗口
+pub struct RowBookmarkDialog;
+
+impl RowBookmarkDialog {
+    /// 显示窗口，`target` 为当前的 (连接, 数据库)
+    pub fn show(
+        ctx: &egui::Context,
+        state: &mut RowBookmarkState,
+        bookmarks: &RowBookmarks,
+        target: Option<(&str, Option<&str>)>,
+        current_table: Option<&str>,
+    ) -> RowBookmarkResult {
+        if !state.show {
+            return RowBookmarkResult::None;
+        }
+
+        if !keyboard::has_text_focus(ctx) && keyboard::handle_close_keys(ctx) {
+            state.close();
+            return RowBookmarkResult::None;
+        }
+
+        let mut tables = target
+            .map(|(connection, database)| bookmarks.for_database(connection, database))
+            .unwrap_or_default();
+        // 当前表排在最前
+        tables.sort_by_key(|(table, _)| Some(*table) != current_table);
+
+        let mut result = RowBookmarkResult::None;
+        let mut open = true;
+        egui::Window::new("🔖 行书签")
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(true)
+            .default_width(520.0)
+            .default_height(360.0)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.label(
+                    RichText::new("在表格行号上右键收藏行；点击书签按主键重新查询该表")
+                        .small()
+                        .color(GRAY),
+                );
+                ui.separator();
+
+                if target.is_none() {
+                    ui.label(RichText::new("请先连接数据库").color(MUTED));
+                    return;
+                }
+                if tables.is_empty() {
+                    ui.label(RichText::new("当前数据库中没有收藏的行").color(MUTED));
+                    return;
+                }
+                egui::ScrollArea::vertical()
+                    .auto_shrink([false, false])
+                    .show(ui, |ui| {
+                        for (table, rows) in &tables {
+                            ui.label(RichText::new(format!("📋 {} ({})", table, rows.len())).strong());
+                            for bookmark in rows.iter() {
+                                if let Some(action) = Self::show_bookmark(ui, table, bookmark) {
+                                    result = action;
+                                }
+                            }
+                            ui.add_space(6.0);
+                        }
+                    });
+            });
+
+        if !open {
+            state.close();
+        }
+
+        result
+    }
+
+    /// 单个书签
+    fn show_bookmark(ui: &mut egui::Ui, table: &str, bookmark: &RowBookmark) -> Option<RowBookmarkResult> {
+        let mut action = None;
+        ui.horizontal(|ui| {
+            ui.add_space(12.0);
+            let key = format!("{} = {}", bookmark.key_column, bookmark.key_value);
+            if ui
+                .link(RichText::new(key).monospace())
+                .on_hover_text("按主键重新查询该表")
+                .clicked()
+            {
+                action = Some(RowBookmarkResult::Jump(table.to_string(), bookmark.clone()));
+            }
+            if !bookmark.label.is_empty() {
+                let label: String = bookmark.label.chars().take(LABEL_MAX_CHARS).collect();
+                ui.label(RichText::new(label).small().color(GRAY));
+            }
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui.small_button(RichText::new("✖").color(DANGER)).on_hover_text("删除书签").clicked() {
+                    action = Some(RowBookmarkResult::Remove(table.to_string(), bookmark.clone()));
+                }
+            });
+        });
+        action
+    }
+}
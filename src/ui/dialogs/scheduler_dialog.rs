@@ -0,0 +1,351 @@
+//! 定时查询对话框
+//!
+//! 将当前编辑器中的查询设置为按间隔或 cron 表达式重复执行，结果显示在固定的标签页中，
+//! 可选设置行数或列值阈值提醒；同时列出已有的定时查询，支持暂停、立即执行和删除。
+
+use super::keyboard::{self, DialogAction};
+use crate::core::{t, tf, QueryScheduler, Schedule, ScheduledQuery, Threshold, ThresholdOp, ThresholdTarget};
+use egui::{self, Color32, RichText, TextEdit};
+
+// ============================================================================
+// 对话框结果
+// ============================================================================
+
+/// 定时查询对话框的结果
+pub enum SchedulerResult {
+    /// 无操作
+    None,
+    /// 新建定时查询
+    Create(Box<ScheduledQuery>),
+    /// 启用或暂停
+    SetEnabled(u64, bool),
+    /// 立即执行
+    RunNow(u64),
+    /// 删除
+    Remove(u64),
+    /// 切换到结果标签页
+    ShowTab(String),
+}
+
+// ============================================================================
+// 对话框状态
+// ============================================================================
+
+/// 定时查询对话框状态
+pub struct SchedulerState {
+    /// 是否显示对话框
+    pub show: bool,
+    /// 执行查询的连接名（为空时只能管理已有任务）
+    pub connection: String,
+    /// 查询语句
+    pub sql: String,
+    /// 任务名称
+    pub name: String,
+    /// 执行计划（间隔或 cron 表达式）
+    pub schedule: String,
+    /// 是否启用阈值提醒
+    pub threshold_enabled: bool,
+    /// 阈值检查的列（为空时检查行数）
+    pub threshold_column: String,
+    /// 阈值比较运算
+    pub threshold_op: ThresholdOp,
+    /// 阈值
+    pub threshold_value: f64,
+    /// 错误信息
+    pub error: Option<String>,
+}
+
+impl Default for SchedulerState {
+    fn default() -> Self {
+        Self {
+            show: false,
+            connection: String::new(),
+            sql: String::new(),
+            name: String::new(),
+            schedule: "30s".to_string(),
+            threshold_enabled: false,
+            threshold_column: String::new(),
+            threshold_op: ThresholdOp::Gt,
+            threshold_value: 0.0,
+            error: None,
+        }
+    }
+}
+
+impl SchedulerState {
+    /// 创建新的对话框状态
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 打开对话框，以当前查询和连接作为新任务的默认值
+    pub fn open(&mut self, connection: Option<String>, sql: &str) {
+        self.show = true;
+        self.connection = connection.unwrap_or_default();
+        self.sql = sql.trim().to_string();
+        self.name = if self.sql.is_empty() { String::new() } else { t("scheduler.default_name").to_string() };
+        self.error = None;
+    }
+
+    /// 关闭对话框
+    pub fn close(&mut self) {
+        self.show = false;
+        self.error = None;
+    }
+
+    /// 是否可以新建任务
+    pub fn can_create(&self) -> bool {
+        !self.connection.is_empty() && !self.sql.is_empty()
+    }
+
+    /// 校验输入并生成任务
+    pub fn build(&self) -> Result<ScheduledQuery, String> {
+        if self.connection.is_empty() {
+            return Err(t("common.need_connection").to_string());
+        }
+        if self.sql.is_empty() {
+            return Err(t("scheduler.need_query").to_string());
+        }
+        let name = self.name.trim();
+        if name.is_empty() {
+            return Err(t("common.name_required").to_string());
+        }
+        let schedule = Schedule::parse(&self.schedule)?;
+        let mut job = ScheduledQuery::new(name, self.connection.clone(), self.sql.clone(), schedule);
+        if self.threshold_enabled {
+            let column = self.threshold_column.trim();
+            job.threshold = Some(Threshold {
+                target: if column.is_empty() {
+                    ThresholdTarget::RowCount
+                } else {
+                    ThresholdTarget::Column(column.to_string())
+                },
+                op: self.threshold_op,
+                value: self.threshold_value,
+            });
+        }
+        Ok(job)
+    }
+
+    /// 确认新建：返回任务，或记录错误
+    fn confirm(&mut self) -> Option<ScheduledQuery> {
+        match self.build() {
+            Ok(job) => {
+                self.error = None;
+                Some(job)
+            }
+            Err(e) => {
+                self.error = Some(e);
+                None
+            }
+        }
+    }
+}
+
+// ============================================================================
+// 对话框 UI
+// ============================================================================
+
+/// 定时查询对话框
+pub struct SchedulerDialog;
+
+impl SchedulerDialog {
+    /// 显示对话框
+    pub fn show(ctx: &egui::Context, state: &mut SchedulerState, scheduler: &QueryScheduler) -> SchedulerResult {
+        if !state.show {
+            return SchedulerResult::None;
+        }
+
+        let mut result = SchedulerResult::None;
+
+        // 键盘快捷键处理
+        if !keyboard::has_text_focus(ctx) {
+            if keyboard::handle_close_keys(ctx) {
+                state.close();
+                return SchedulerResult::None;
+            }
+
+            if let DialogAction::Confirm = keyboard::handle_dialog_keys(ctx)
+                && state.can_create()
+                && let Some(job) = state.confirm()
+            {
+                result = SchedulerResult::Create(Box::new(job));
+            }
+        }
+
+        let mut open = true;
+        egui::Window::new(t("scheduler.title"))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(true)
+            .default_width(560.0)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                if state.can_create() {
+                    Self::show_create_form(ui, state, &mut result);
+                } else {
+                    ui.label(
+                        RichText::new(t("scheduler.intro"))
+                            .color(Color32::GRAY),
+                    );
+                }
+
+                ui.separator();
+                Self::show_jobs(ui, scheduler, &mut result);
+            });
+
+        if !open {
+            state.close();
+        }
+
+        result
+    }
+
+    /// 新建任务表单
+    fn show_create_form(ui: &mut egui::Ui, state: &mut SchedulerState, result: &mut SchedulerResult) {
+        egui::Grid::new("scheduler_form")
+            .num_columns(2)
+            .spacing([12.0, 6.0])
+            .show(ui, |ui| {
+                ui.label(t("common.connection"));
+                ui.label(RichText::new(&state.connection).strong());
+                ui.end_row();
+
+                ui.label(t("scheduler.query"));
+                let preview: String = state.sql.lines().next().unwrap_or_default().chars().take(60).collect();
+                ui.label(RichText::new(preview).monospace().small()).on_hover_text(&state.sql);
+                ui.end_row();
+
+                ui.label(t("common.name"));
+                ui.add(TextEdit::singleline(&mut state.name).desired_width(220.0));
+                ui.end_row();
+
+                ui.label(t("scheduler.schedule"));
+                ui.horizontal(|ui| {
+                    ui.add(TextEdit::singleline(&mut state.schedule).desired_width(140.0).hint_text("30s"));
+                    let hint = match Schedule::parse(&state.schedule) {
+                        Ok(schedule) => RichText::new(schedule.describe()).small().color(Color32::GRAY),
+                        Err(e) => RichText::new(e).small().color(Color32::from_rgb(230, 180, 80)),
+                    };
+                    ui.label(hint);
+                })
+                .response
+                .on_hover_text(t("scheduler.schedule_hint"));
+                ui.end_row();
+
+                ui.label(t("scheduler.threshold"));
+                ui.horizontal(|ui| {
+                    ui.checkbox(&mut state.threshold_enabled, "");
+                    ui.add_enabled_ui(state.threshold_enabled, |ui| {
+                        ui.add(
+                            TextEdit::singleline(&mut state.threshold_column)
+                                .desired_width(100.0)
+                                .hint_text(t("scheduler.rows")),
+                        )
+                        .on_hover_text(t("scheduler.threshold_hint"));
+                        egui::ComboBox::from_id_salt("scheduler_threshold_op")
+                            .selected_text(state.threshold_op.symbol())
+                            .width(48.0)
+                            .show_ui(ui, |ui| {
+                                for op in ThresholdOp::ALL {
+                                    ui.selectable_value(&mut state.threshold_op, op, op.symbol());
+                                }
+                            });
+                        ui.add(egui::DragValue::new(&mut state.threshold_value).speed(1.0));
+                    });
+                });
+                ui.end_row();
+            });
+
+        if let Some(err) = &state.error {
+            ui.add_space(4.0);
+            ui.label(RichText::new(err).color(Color32::from_rgb(255, 100, 100)));
+        }
+
+        ui.add_space(4.0);
+        if ui.button(t("scheduler.start")).clicked()
+            && let Some(job) = state.confirm()
+        {
+            *result = SchedulerResult::Create(Box::new(job));
+        }
+    }
+
+    /// 已有任务列表
+    fn show_jobs(ui: &mut egui::Ui, scheduler: &QueryScheduler, result: &mut SchedulerResult) {
+        if scheduler.is_empty() {
+            ui.label(RichText::new(t("scheduler.empty")).color(Color32::GRAY));
+            return;
+        }
+
+        egui::ScrollArea::vertical()
+            .id_salt("scheduler_jobs")
+            .max_height(240.0)
+            .show(ui, |ui| {
+                egui::Grid::new("scheduler_jobs_grid")
+                    .num_columns(5)
+                    .striped(true)
+                    .spacing([12.0, 6.0])
+                    .show(ui, |ui| {
+                        for header in [t("scheduler.name_header"), t("scheduler.schedule_header"), t("scheduler.threshold_header"), t("scheduler.status_header"), ""] {
+                            ui.label(RichText::new(header).strong());
+                        }
+                        ui.end_row();
+
+                        for job in scheduler.jobs() {
+                            ui.label(&job.name).on_hover_text(format!("{}\n\n{}", job.connection, job.sql));
+                            ui.label(job.schedule.describe());
+                            match &job.threshold {
+                                Some(threshold) => {
+                                    let text = RichText::new(threshold.describe());
+                                    ui.label(if job.alerting {
+                                        text.color(Color32::from_rgb(230, 180, 80))
+                                    } else {
+                                        text
+                                    });
+                                }
+                                None => {
+                                    ui.label("-");
+                                }
+                            }
+
+                            let status = if job.running {
+                                RichText::new(t("common.running"))
+                            } else if let Some(err) = &job.last_error {
+                                RichText::new(tf("scheduler.error", &[err])).color(Color32::from_rgb(255, 100, 100))
+                            } else if !job.enabled {
+                                RichText::new(t("scheduler.paused")).color(Color32::GRAY)
+                            } else {
+                                match job.next_run {
+                                    Some(next) => RichText::new(tf("scheduler.next_run", &[&next.format("%H:%M:%S").to_string()])),
+                                    None => RichText::new(t("scheduler.no_next_run")).color(Color32::GRAY),
+                                }
+                            };
+                            ui.label(status.small()).on_hover_text(tf("scheduler.run_count", &[&job.run_count.to_string()]));
+
+                            ui.horizontal(|ui| {
+                                let (icon, tooltip) = if job.enabled { ("⏸", t("scheduler.pause")) } else { ("▶", t("scheduler.resume")) };
+                                if ui.small_button(icon).on_hover_text(tooltip).clicked() {
+                                    *result = SchedulerResult::SetEnabled(job.id, !job.enabled);
+                                }
+                                if ui
+                                    .add_enabled(job.enabled && !job.running, egui::Button::new("⟳").small())
+                                    .on_hover_text(t("scheduler.run_now"))
+                                    .clicked()
+                                {
+                                    *result = SchedulerResult::RunNow(job.id);
+                                }
+                                if let Some(tab_id) = &job.tab_id
+                                    && ui.small_button("📌").on_hover_text(t("scheduler.view_result")).clicked()
+                                {
+                                    *result = SchedulerResult::ShowTab(tab_id.clone());
+                                }
+                                if ui.small_button("🗑").on_hover_text(t("common.delete")).clicked() {
+                                    *result = SchedulerResult::Remove(job.id);
+                                }
+                            });
+                            ui.end_row();
+                        }
+                    });
+            });
+    }
+}
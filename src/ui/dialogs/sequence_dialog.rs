@@ -0,0 +1,204 @@
+//! 设置序列下一个值对话框
+//!
+//! 重置 PostgreSQL 序列或 MySQL/SQLite 的自增计数器，执行前预览将要执行的 SQL。
+//! 支持 Helix 风格的键盘导航。
+
+use super::keyboard::{self, DialogAction};
+use crate::database::{DatabaseType, SequenceInfo};
+use egui::{self, Color32, RichText, TextEdit};
+
+// ============================================================================
+// 对话框结果
+// ============================================================================
+
+/// 设置序列对话框的结果
+pub enum SequenceDialogResult {
+    /// 无操作
+    None,
+    /// 用户确认修改（SQL 语句）
+    Restart(String),
+    /// 用户取消
+    Cancelled,
+}
+
+// ============================================================================
+// 对话框状态
+// ============================================================================
+
+/// 设置序列对话框状态
+#[derive(Default)]
+pub struct SequenceDialogState {
+    /// 是否显示对话框
+    pub show: bool,
+    /// 当前数据库类型
+    pub db_type: DatabaseType,
+    /// 目标序列
+    pub sequence: Option<SequenceInfo>,
+    /// 输入的下一个值
+    pub next_value: String,
+    /// 错误信息
+    pub error: Option<String>,
+}
+
+impl SequenceDialogState {
+    /// 创建新的对话框状态
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 打开对话框，默认填入当前的下一个值
+    pub fn open(&mut self, db_type: DatabaseType, sequence: SequenceInfo) {
+        self.next_value = sequence.next_value.map(|v| v.to_string()).unwrap_or_else(|| "1".to_string());
+        self.db_type = db_type;
+        self.sequence = Some(sequence);
+        self.error = None;
+        self.show = true;
+    }
+
+    /// 关闭对话框
+    pub fn close(&mut self) {
+        self.show = false;
+        self.sequence = None;
+        self.next_value.clear();
+        self.error = None;
+    }
+
+    /// 校验输入并生成 SQL
+    pub fn generate_sql(&self) -> Result<String, String> {
+        let sequence = self.sequence.as_ref().ok_or("未选择序列")?;
+        let value: i64 = self
+            .next_value
+            .trim()
+            .parse()
+            .map_err(|_| "下一个值必须是整数".to_string())?;
+        // SQLite 的 seq 为已使用的最大值，下一个值至少为 1
+        if self.db_type != DatabaseType::PostgreSQL && value < 1 {
+            return Err("自增计数器的下一个值必须大于 0".to_string());
+        }
+        sequence.restart_sql(self.db_type, value)
+    }
+}
+
+// ============================================================================
+// 对话框 UI
+// ============================================================================
+
+/// 设置序列下一个值对话框
+pub struct SequenceDialog;
+
+impl SequenceDialog {
+    /// 显示对话框
+    pub fn show(ctx: &egui::Context, state: &mut SequenceDialogState) -> SequenceDialogResult {
+        if !state.show {
+            return SequenceDialogResult::None;
+        }
+        let Some(sequence) = state.sequence.clone() else {
+            state.close();
+            return SequenceDialogResult::None;
+        };
+
+        let mut result = SequenceDialogResult::None;
+        let mut should_close = false;
+
+        // 键盘快捷键处理
+        if !keyboard::has_text_focus(ctx) {
+            if keyboard::handle_close_keys(ctx) {
+                state.close();
+                return SequenceDialogResult::Cancelled;
+            }
+
+            if let DialogAction::Confirm = keyboard::handle_dialog_keys(ctx) {
+                match state.generate_sql() {
+                    Ok(sql) => {
+                        result = SequenceDialogResult::Restart(sql);
+                        should_close = true;
+                    }
+                    Err(e) => state.error = Some(e),
+                }
+            }
+        }
+
+        egui::Window::new("设置序列下一个值")
+            .collapsible(false)
+            .resizable(false)
+            .min_width(380.0)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                egui::Grid::new("sequence_info")
+                    .num_columns(2)
+                    .spacing([12.0, 6.0])
+                    .show(ui, |ui| {
+                        ui.label("序列:");
+                        ui.label(RichText::new(&sequence.name).strong());
+                        ui.end_row();
+
+                        if let (Some(table), Some(column)) = (&sequence.table_name, &sequence.column_name) {
+                            ui.label("所属列:");
+                            ui.label(format!("{}.{}", table, column));
+                            ui.end_row();
+                        }
+
+                        ui.label("当前值:");
+                        ui.label(
+                            sequence
+                                .last_value
+                                .map(|v| v.to_string())
+                                .unwrap_or_else(|| "尚未使用".to_string()),
+                        );
+                        ui.end_row();
+
+                        ui.label("下一个值:");
+                        ui.add(TextEdit::singleline(&mut state.next_value).desired_width(160.0));
+                        ui.end_row();
+                    });
+
+                ui.add_space(8.0);
+
+                // 预览 SQL
+                let sql = state.generate_sql().unwrap_or_default();
+                ui.add(
+                    TextEdit::multiline(&mut sql.as_str())
+                        .code_editor()
+                        .desired_width(f32::INFINITY)
+                        .desired_rows(2),
+                );
+
+                ui.add_space(4.0);
+                ui.label(
+                    RichText::new("提示: 下一个值小于已有数据时可能产生主键冲突")
+                        .small()
+                        .color(Color32::from_rgb(230, 180, 80)),
+                );
+
+                if let Some(err) = &state.error {
+                    ui.add_space(4.0);
+                    ui.label(RichText::new(err).color(Color32::from_rgb(255, 100, 100)));
+                }
+
+                ui.add_space(8.0);
+
+                ui.horizontal(|ui| {
+                    if ui.button("执行 [Enter]").clicked() {
+                        match state.generate_sql() {
+                            Ok(sql) => {
+                                result = SequenceDialogResult::Restart(sql);
+                                should_close = true;
+                            }
+                            Err(e) => state.error = Some(e),
+                        }
+                    }
+
+                    if ui.button("取消 [Esc]").clicked() {
+                        result = SequenceDialogResult::Cancelled;
+                        should_close = true;
+                    }
+                });
+            });
+
+        if should_close {
+            state.close();
+        }
+
+        result
+    }
+}
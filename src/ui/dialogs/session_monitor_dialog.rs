@@ -0,0 +1,394 @@
+//! 会话监控窗口
+//!
+//! 显示服务器上的会话及正在执行的查询（PostgreSQL `pg_stat_activity`、
+//! MySQL `PROCESSLIST`），支持定时自动刷新、按执行时长高亮以及取消查询/终止会话。
+
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+
+use super::keyboard;
+use crate::core::{t, tf};
+use crate::database::{DatabaseType, SessionInfo};
+use egui::{self, Color32, RichText};
+
+/// 自动刷新间隔选项（秒，0 表示关闭）
+pub const REFRESH_INTERVALS: [u64; 4] = [0, 2, 5, 10];
+/// 执行时长达到此秒数时以警告色显示
+pub const WARN_SECS: i64 = 5;
+/// 执行时长达到此秒数时以危险色显示
+pub const DANGER_SECS: i64 = 30;
+
+const WARN_COLOR: Color32 = Color32::from_rgb(230, 180, 60);
+const DANGER_COLOR: Color32 = Color32::from_rgb(255, 100, 100);
+
+// ============================================================================
+// 窗口结果
+// ============================================================================
+
+/// 会话监控窗口的结果
+pub enum SessionMonitorResult {
+    /// 无操作
+    None,
+    /// 重新加载
+    Refresh,
+    /// 执行终止会话的 SQL
+    Kill(String),
+}
+
+// ============================================================================
+// 窗口状态
+// ============================================================================
+
+/// 会话监控窗口状态
+pub struct SessionMonitorState {
+    /// 是否显示窗口
+    pub show: bool,
+    /// 标题（连接名）
+    pub title: String,
+    /// 数据库类型
+    pub db_type: DatabaseType,
+    /// 是否正在加载
+    pub loading: bool,
+    /// 会话列表
+    pub sessions: Vec<SessionInfo>,
+    /// 是否显示空闲会话
+    pub show_idle: bool,
+    /// 自动刷新间隔（秒，0 表示关闭）
+    pub refresh_secs: u64,
+    /// 上次加载完成的时间
+    pub last_refresh: Option<Instant>,
+    /// 等待确认的终止操作（会话 ID，是否仅取消查询）
+    pub pending_kill: Option<(i64, bool)>,
+    /// 错误信息
+    pub error: Option<String>,
+}
+
+impl Default for SessionMonitorState {
+    fn default() -> Self {
+        Self {
+            show: false,
+            title: String::new(),
+            db_type: DatabaseType::default(),
+            loading: false,
+            sessions: Vec::new(),
+            show_idle: false,
+            refresh_secs: 5,
+            last_refresh: None,
+            pending_kill: None,
+            error: None,
+        }
+    }
+}
+
+impl SessionMonitorState {
+    /// 创建新的窗口状态
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 打开窗口并进入加载状态（数据由调用方异步加载）
+    pub fn open(&mut self, db_type: DatabaseType, title: String) {
+        self.show = true;
+        self.db_type = db_type;
+        self.title = title;
+        self.sessions.clear();
+        self.pending_kill = None;
+        self.error = None;
+        self.last_refresh = None;
+        self.loading = true;
+    }
+
+    /// 关闭窗口
+    pub fn close(&mut self) {
+        self.show = false;
+        self.loading = false;
+        self.sessions.clear();
+        self.pending_kill = None;
+        self.error = None;
+    }
+
+    /// 设置加载结果
+    pub fn set_result(&mut self, result: Result<Vec<SessionInfo>, String>) {
+        self.loading = false;
+        self.last_refresh = Some(Instant::now());
+        match result {
+            Ok(sessions) => {
+                // 已结束的会话不再需要确认
+                if let Some((id, _)) = self.pending_kill
+                    && !sessions.iter().any(|s| s.id == id)
+                {
+                    self.pending_kill = None;
+                }
+                self.sessions = sessions;
+                self.error = None;
+            }
+            Err(e) => {
+                self.sessions.clear();
+                self.error = Some(e);
+            }
+        }
+    }
+
+    /// 距离下次自动刷新的时间，`None` 表示不自动刷新
+    pub fn next_refresh_in(&self) -> Option<Duration> {
+        if self.refresh_secs == 0 || self.loading {
+            return None;
+        }
+        let interval = Duration::from_secs(self.refresh_secs);
+        let elapsed = self.last_refresh.map_or(interval, |t| t.elapsed());
+        Some(interval.saturating_sub(elapsed))
+    }
+
+    /// 阻塞其他会话的会话 ID
+    pub fn blocking_ids(&self) -> HashSet<i64> {
+        self.sessions.iter().flat_map(|s| s.blocked_by.iter().copied()).collect()
+    }
+
+    /// 当前应显示的会话
+    pub fn visible_sessions(&self) -> impl Iterator<Item = &SessionInfo> {
+        let blocking = self.blocking_ids();
+        self.sessions
+            .iter()
+            .filter(move |s| self.show_idle || s.is_active() || blocking.contains(&s.id))
+    }
+}
+
+/// 按执行时长选择高亮颜色（仅活跃会话）
+pub fn duration_color(session: &SessionInfo) -> Option<Color32> {
+    if !session.is_active() {
+        return None;
+    }
+    match session.duration_secs? {
+        secs if secs >= DANGER_SECS => Some(DANGER_COLOR),
+        secs if secs >= WARN_SECS => Some(WARN_COLOR),
+        _ => None,
+    }
+}
+
+/// 格式化持续时间（如 `45s`、`3m 05s`、`2h 10m`）
+pub fn format_duration(secs: i64) -> String {
+    let secs = secs.max(0);
+    match secs {
+        s if s < 60 => format!("{}s", s),
+        s if s < 3600 => format!("{}m {:02}s", s / 60, s % 60),
+        s => format!("{}h {:02}m", s / 3600, s % 3600 / 60),
+    }
+}
+
+// ============================================================================
+// 窗口 UI
+// ============================================================================
+
+/// 会话监控窗口
+pub struct SessionMonitorDialog;
+
+impl SessionMonitorDialog {
+    /// 显示窗口
+    pub fn show(ctx: &egui::Context, state: &mut SessionMonitorState) -> SessionMonitorResult {
+        if !state.show {
+            return SessionMonitorResult::None;
+        }
+
+        let mut result = SessionMonitorResult::None;
+
+        // 键盘快捷键处理
+        if !keyboard::has_text_focus(ctx) {
+            if state.pending_kill.is_some() && ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
+                state.pending_kill = None;
+            } else if keyboard::handle_close_keys(ctx) {
+                state.close();
+                return SessionMonitorResult::None;
+            }
+            // r / F5 刷新
+            if !state.loading && ctx.input(|i| i.key_pressed(egui::Key::R) || i.key_pressed(egui::Key::F5)) {
+                result = SessionMonitorResult::Refresh;
+            }
+        }
+
+        // 自动刷新
+        if let Some(remaining) = state.next_refresh_in() {
+            if remaining.is_zero() {
+                result = SessionMonitorResult::Refresh;
+            } else {
+                ctx.request_repaint_after(remaining);
+            }
+        }
+
+        let mut open = true;
+        egui::Window::new(tf("sessions.title", &[&state.title]))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(true)
+            .default_size([860.0, 480.0])
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                Self::show_toolbar(ui, state, &mut result);
+                ui.separator();
+
+                if let Some((id, query_only)) = state.pending_kill {
+                    Self::show_kill_confirm(ui, state, id, query_only, &mut result);
+                    ui.separator();
+                }
+
+                if let Some(err) = &state.error {
+                    ui.label(RichText::new(err).color(DANGER_COLOR));
+                    return;
+                }
+
+                Self::show_grid(ui, state);
+
+                ui.add_space(4.0);
+                ui.label(
+                    RichText::new(tf("sessions.legend", &[&WARN_SECS.to_string(), &DANGER_SECS.to_string()]))
+                    .small()
+                    .color(Color32::GRAY),
+                );
+            });
+
+        if !open {
+            state.close();
+        }
+
+        result
+    }
+
+    /// 顶部：统计、显示空闲开关、刷新间隔和刷新按钮
+    fn show_toolbar(ui: &mut egui::Ui, state: &mut SessionMonitorState, result: &mut SessionMonitorResult) {
+        ui.horizontal(|ui| {
+            let active = state.sessions.iter().filter(|s| s.is_active()).count();
+            let blocked = state.sessions.iter().filter(|s| !s.blocked_by.is_empty()).count();
+            let mut summary = tf("sessions.summary", &[&state.sessions.len().to_string(), &active.to_string()]);
+            if blocked > 0 {
+                summary.push_str(&tf("sessions.blocked", &[&blocked.to_string()]));
+            }
+            ui.label(summary);
+
+            ui.separator();
+            ui.checkbox(&mut state.show_idle, t("sessions.show_idle"));
+
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if state.loading {
+                    ui.spinner();
+                } else if ui.button(t("sessions.refresh")).clicked() {
+                    *result = SessionMonitorResult::Refresh;
+                }
+
+                let label = |secs: u64| if secs == 0 { t("sessions.off").to_string() } else { format!("{}s", secs) };
+                egui::ComboBox::from_id_salt("session_monitor_interval")
+                    .selected_text(label(state.refresh_secs))
+                    .width(60.0)
+                    .show_ui(ui, |ui| {
+                        for secs in REFRESH_INTERVALS {
+                            ui.selectable_value(&mut state.refresh_secs, secs, label(secs));
+                        }
+                    });
+                ui.label(t("sessions.auto_refresh"));
+            });
+        });
+    }
+
+    /// 终止操作的确认栏
+    fn show_kill_confirm(
+        ui: &mut egui::Ui,
+        state: &mut SessionMonitorState,
+        id: i64,
+        query_only: bool,
+        result: &mut SessionMonitorResult,
+    ) {
+        let prompt = if query_only {
+            tf("sessions.confirm_cancel", &[&id.to_string()])
+        } else {
+            tf("sessions.confirm_kill", &[&id.to_string()])
+        };
+        ui.horizontal(|ui| {
+            ui.label(RichText::new(prompt).color(WARN_COLOR));
+            if ui.button(t("sessions.confirm")).clicked() {
+                let sql = state
+                    .sessions
+                    .iter()
+                    .find(|s| s.id == id)
+                    .map(|s| s.kill_sql(state.db_type, query_only));
+                match sql {
+                    Some(Ok(sql)) => *result = SessionMonitorResult::Kill(sql),
+                    Some(Err(e)) => state.error = Some(e),
+                    None => {}
+                }
+                state.pending_kill = None;
+            }
+            if ui.button(t("common.cancel")).clicked() {
+                state.pending_kill = None;
+            }
+        });
+    }
+
+    /// 会话表格
+    fn show_grid(ui: &mut egui::Ui, state: &mut SessionMonitorState) {
+        let blocking = state.blocking_ids();
+        let sessions: Vec<SessionInfo> = state.visible_sessions().cloned().collect();
+        if sessions.is_empty() {
+            if !state.loading {
+                ui.label(RichText::new(t("sessions.empty")).color(Color32::GRAY));
+            }
+            return;
+        }
+
+        egui::ScrollArea::both()
+            .id_salt("session_monitor_scroll")
+            .max_height(ui.available_height() - 24.0)
+            .show(ui, |ui| {
+                egui::Grid::new("session_monitor_grid")
+                    .striped(true)
+                    .spacing([12.0, 4.0])
+                    .show(ui, |ui| {
+                        for header in ["ID", t("sessions.user"), t("sessions.database"), t("sessions.client"), t("sessions.state"), t("sessions.duration"), t("sessions.wait"), t("sessions.query"), ""] {
+                            ui.label(RichText::new(header).strong());
+                        }
+                        ui.end_row();
+
+                        for session in &sessions {
+                            let mut id_text = session.id.to_string();
+                            if !session.blocked_by.is_empty() {
+                                id_text.push_str(" 🔒");
+                            }
+                            if blocking.contains(&session.id) {
+                                id_text.push_str(" ⛔");
+                            }
+                            ui.label(id_text);
+                            ui.label(&session.user);
+                            ui.label(session.database.as_deref().unwrap_or("-"));
+                            ui.label(session.client.as_deref().unwrap_or("-"));
+                            ui.label(&session.state);
+
+                            let duration = session.duration_secs.map(format_duration).unwrap_or_else(|| "-".into());
+                            match duration_color(session) {
+                                Some(color) => ui.label(RichText::new(duration).color(color).strong()),
+                                None => ui.label(duration),
+                            };
+
+                            let mut wait = session.wait.clone().unwrap_or_default();
+                            if !session.blocked_by.is_empty() {
+                                let ids: Vec<String> = session.blocked_by.iter().map(i64::to_string).collect();
+                                wait = tf("sessions.blocked_by", &[&ids.join(", "), &wait]);
+                            }
+                            ui.label(wait.trim());
+
+                            let query: String = session.query.split_whitespace().collect::<Vec<_>>().join(" ");
+                            let short: String = query.chars().take(80).collect();
+                            ui.label(RichText::new(short).monospace()).on_hover_text(&session.query);
+
+                            ui.horizontal(|ui| {
+                                if session.is_active()
+                                    && ui.small_button("⏹").on_hover_text(t("sessions.cancel_query")).clicked()
+                                {
+                                    state.pending_kill = Some((session.id, true));
+                                }
+                                if ui.small_button("✖").on_hover_text(t("sessions.kill")).clicked() {
+                                    state.pending_kill = Some((session.id, false));
+                                }
+                            });
+                            ui.end_row();
+                        }
+                    });
+            });
+    }
+}
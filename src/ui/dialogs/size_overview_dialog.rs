@@ -0,0 +1,335 @@
+//! 大小概览窗口
+//!
+//! 按表显示行数、数据大小和索引大小，支持按列排序，并以条形图对比各表占用。
+//! 数据来自系统目录，行数可能为估算值。
+
+use std::cmp::Ordering;
+
+use super::keyboard;
+use crate::database::TableSizeInfo;
+use egui::{self, Color32, RichText, Sense, Vec2};
+
+/// 数据部分条形颜色
+const DATA_COLOR: Color32 = Color32::from_rgb(90, 150, 230);
+/// 索引部分条形颜色
+const INDEX_COLOR: Color32 = Color32::from_rgb(230, 160, 80);
+
+// ============================================================================
+// 窗口结果
+// ============================================================================
+
+/// 大小概览窗口的结果
+pub enum SizeOverviewResult {
+    /// 无操作
+    None,
+    /// 重新加载
+    Refresh,
+}
+
+// ============================================================================
+// 窗口状态
+// ============================================================================
+
+/// 排序列
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SizeSortColumn {
+    /// 表名
+    Name,
+    /// 行数
+    Rows,
+    /// 数据大小
+    Data,
+    /// 索引大小
+    Index,
+    /// 总大小
+    #[default]
+    Total,
+}
+
+/// 大小概览窗口状态
+#[derive(Default)]
+pub struct SizeOverviewState {
+    /// 是否显示窗口
+    pub show: bool,
+    /// 标题（数据库名或连接名）
+    pub title: String,
+    /// 是否正在加载
+    pub loading: bool,
+    /// 各表大小（已按当前排序列排序）
+    pub tables: Vec<TableSizeInfo>,
+    /// 排序列
+    pub sort: SizeSortColumn,
+    /// 是否降序
+    pub descending: bool,
+    /// 错误信息
+    pub error: Option<String>,
+}
+
+impl SizeOverviewState {
+    /// 创建新的窗口状态
+    pub fn new() -> Self {
+        Self {
+            descending: true,
+            ..Self::default()
+        }
+    }
+
+    /// 打开窗口并进入加载状态（数据由调用方异步加载）
+    pub fn open(&mut self, title: String) {
+        self.show = true;
+        self.title = title;
+        self.tables.clear();
+        self.error = None;
+        self.loading = true;
+    }
+
+    /// 关闭窗口
+    pub fn close(&mut self) {
+        self.show = false;
+        self.loading = false;
+        self.tables.clear();
+        self.error = None;
+    }
+
+    /// 设置加载结果
+    pub fn set_result(&mut self, result: Result<Vec<TableSizeInfo>, String>) {
+        self.loading = false;
+        match result {
+            Ok(tables) => {
+                self.tables = tables;
+                self.error = None;
+                self.apply_sort();
+            }
+            Err(e) => {
+                self.tables.clear();
+                self.error = Some(e);
+            }
+        }
+    }
+
+    /// 按指定列排序：同一列切换升降序，新列的数值默认降序、表名默认升序
+    pub fn sort_by(&mut self, column: SizeSortColumn) {
+        if self.sort == column {
+            self.descending = !self.descending;
+        } else {
+            self.sort = column;
+            self.descending = column != SizeSortColumn::Name;
+        }
+        self.apply_sort();
+    }
+
+    /// 按当前排序列排序（缺失的值始终排在最后）
+    fn apply_sort(&mut self) {
+        let column = self.sort;
+        let descending = self.descending;
+        self.tables.sort_by(|a, b| {
+            if column == SizeSortColumn::Name {
+                let ord = a.name.cmp(&b.name);
+                return if descending { ord.reverse() } else { ord };
+            }
+            let key = |t: &TableSizeInfo| match column {
+                SizeSortColumn::Rows => t.row_count,
+                SizeSortColumn::Data => t.data_bytes,
+                SizeSortColumn::Index => t.index_bytes,
+                _ => t.total_bytes(),
+            };
+            match (key(a), key(b)) {
+                (Some(x), Some(y)) => {
+                    let ord = x.cmp(&y);
+                    if descending { ord.reverse() } else { ord }
+                }
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => a.name.cmp(&b.name),
+            }
+        });
+    }
+}
+
+/// 格式化字节数（B/KB/MB/GB/TB，1024 进制）
+pub fn format_bytes(bytes: i64) -> String {
+    const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value.abs() >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+// ============================================================================
+// 窗口 UI
+// ============================================================================
+
+/// 大小概览窗口
+pub struct SizeOverviewDialog;
+
+impl SizeOverviewDialog {
+    /// 显示窗口
+    pub fn show(ctx: &egui::Context, state: &mut SizeOverviewState) -> SizeOverviewResult {
+        if !state.show {
+            return SizeOverviewResult::None;
+        }
+
+        let mut result = SizeOverviewResult::None;
+
+        // 键盘快捷键处理
+        if !keyboard::has_text_focus(ctx) {
+            if keyboard::handle_close_keys(ctx) {
+                state.close();
+                return SizeOverviewResult::None;
+            }
+            // r / F5 刷新
+            if !state.loading && ctx.input(|i| i.key_pressed(egui::Key::R) || i.key_pressed(egui::Key::F5)) {
+                result = SizeOverviewResult::Refresh;
+            }
+        }
+
+        let mut open = true;
+        egui::Window::new(format!("大小概览 - {}", state.title))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(true)
+            .default_size([720.0, 480.0])
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    let total = |f: fn(&TableSizeInfo) -> Option<i64>| -> Option<i64> {
+                        state.tables.iter().filter_map(f).reduce(|a, b| a + b)
+                    };
+                    let mut summary = format!("{} 张表", state.tables.len());
+                    if let Some(bytes) = total(TableSizeInfo::total_bytes) {
+                        summary.push_str(&format!(" · 总计 {}", format_bytes(bytes)));
+                    }
+                    if let Some(bytes) = total(|t| t.data_bytes) {
+                        summary.push_str(&format!(" · 数据 {}", format_bytes(bytes)));
+                    }
+                    if let Some(bytes) = total(|t| t.index_bytes) {
+                        summary.push_str(&format!(" · 索引 {}", format_bytes(bytes)));
+                    }
+                    ui.label(summary);
+
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if state.loading {
+                            ui.spinner();
+                        } else if ui.button("🔄 刷新 [r]").clicked() {
+                            result = SizeOverviewResult::Refresh;
+                        }
+                    });
+                });
+
+                ui.separator();
+
+                if let Some(err) = &state.error {
+                    ui.label(RichText::new(err).color(Color32::from_rgb(255, 100, 100)));
+                    return;
+                }
+                if state.tables.is_empty() {
+                    if !state.loading {
+                        ui.label(RichText::new("暂无数据表").color(Color32::GRAY));
+                    }
+                    return;
+                }
+
+                Self::show_grid(ui, state);
+
+                ui.add_space(4.0);
+                ui.horizontal(|ui| {
+                    ui.label(RichText::new("■ 数据").small().color(DATA_COLOR));
+                    ui.label(RichText::new("■ 索引").small().color(INDEX_COLOR));
+                    ui.label(
+                        RichText::new("行数来自统计信息，可能为估算值；点击列标题排序")
+                            .small()
+                            .color(Color32::GRAY),
+                    );
+                });
+            });
+
+        if !open {
+            state.close();
+        }
+
+        result
+    }
+
+    /// 表格：表名、行数、数据、索引、总计及占用条形图
+    fn show_grid(ui: &mut egui::Ui, state: &mut SizeOverviewState) {
+        let max_total = state.tables.iter().filter_map(TableSizeInfo::total_bytes).max().unwrap_or(0);
+        let max_rows = state.tables.iter().filter_map(|t| t.row_count).max().unwrap_or(0);
+        let fmt_bytes = |v: Option<i64>| v.map(format_bytes).unwrap_or_else(|| "-".to_string());
+
+        egui::ScrollArea::vertical()
+            .id_salt("size_overview_scroll")
+            .max_height(ui.available_height() - 24.0)
+            .show(ui, |ui| {
+                egui::Grid::new("size_overview_grid")
+                    .striped(true)
+                    .spacing([16.0, 4.0])
+                    .show(ui, |ui| {
+                        let headers = [
+                            ("表", SizeSortColumn::Name),
+                            ("行数", SizeSortColumn::Rows),
+                            ("数据", SizeSortColumn::Data),
+                            ("索引", SizeSortColumn::Index),
+                            ("总计", SizeSortColumn::Total),
+                        ];
+                        for (label, column) in headers {
+                            let text = if state.sort == column {
+                                format!("{} {}", label, if state.descending { "▼" } else { "▲" })
+                            } else {
+                                label.to_string()
+                            };
+                            if ui
+                                .add(egui::Label::new(RichText::new(text).strong()).sense(Sense::click()))
+                                .clicked()
+                            {
+                                state.sort_by(column);
+                            }
+                        }
+                        ui.label("");
+                        ui.end_row();
+
+                        for table in &state.tables {
+                            ui.label(&table.name);
+                            ui.label(table.row_count.map(|n| n.to_string()).unwrap_or_else(|| "-".to_string()));
+                            ui.label(fmt_bytes(table.data_bytes));
+                            ui.label(fmt_bytes(table.index_bytes));
+                            ui.label(fmt_bytes(table.total_bytes()));
+                            Self::size_bar(ui, table, max_total, max_rows);
+                            ui.end_row();
+                        }
+                    });
+            });
+    }
+
+    /// 占用条形图：有大小信息时按数据/索引分段，否则按行数
+    fn size_bar(ui: &mut egui::Ui, table: &TableSizeInfo, max_total: i64, max_rows: i64) {
+        let (rect, _) = ui.allocate_exact_size(Vec2::new(160.0, 10.0), Sense::hover());
+        let painter = ui.painter();
+        painter.rect_filled(rect, 2.0, ui.visuals().faint_bg_color);
+
+        let ratio = |value: i64, max: i64| if max > 0 { value as f32 / max as f32 } else { 0.0 };
+        let segments = match table.total_bytes() {
+            Some(_) => vec![
+                (ratio(table.data_bytes.unwrap_or(0), max_total), DATA_COLOR),
+                (ratio(table.index_bytes.unwrap_or(0), max_total), INDEX_COLOR),
+            ],
+            None => vec![(ratio(table.row_count.unwrap_or(0), max_rows), DATA_COLOR)],
+        };
+
+        let mut left = rect.left();
+        for (fraction, color) in segments {
+            let width = rect.width() * fraction.clamp(0.0, 1.0);
+            if width > 0.0 {
+                let segment = egui::Rect::from_min_size(egui::pos2(left, rect.top()), Vec2::new(width, rect.height()));
+                painter.rect_filled(segment, 2.0, color);
+                left += width;
+            }
+        }
+    }
+}
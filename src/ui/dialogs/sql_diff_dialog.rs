@@ -0,0 +1,184 @@
+//! SQL 对比窗口
+//!
+//! 将历史中的语句与当前编辑器中的语句逐行并排对比，可用历史语句替换编辑器内容。
+
+use super::keyboard;
+use super::result_diff_dialog::diff_color;
+use crate::core::{side_by_side_diff, DiffKind, LineChange, SideBySideLine};
+use egui::{self, Color32, RichText};
+
+// ============================================================================
+// 窗口结果
+// ============================================================================
+
+/// SQL 对比窗口的结果
+pub enum SqlDiffResult {
+    /// 无操作
+    None,
+    /// 用历史语句替换编辑器内容
+    UseOld(String),
+}
+
+// ============================================================================
+// 窗口状态
+// ============================================================================
+
+/// SQL 对比窗口状态
+#[derive(Default)]
+pub struct SqlDiffState {
+    /// 是否显示窗口
+    pub show: bool,
+    /// 左侧（历史语句）标题
+    pub old_title: String,
+    /// 历史语句
+    pub old: String,
+    /// 当前编辑器中的语句
+    pub new: String,
+    /// 并排对比的行
+    pub lines: Vec<SideBySideLine>,
+}
+
+impl SqlDiffState {
+    /// 创建新的窗口状态
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 打开窗口并对比
+    pub fn open(&mut self, old_title: impl Into<String>, old: &str, new: &str) {
+        self.old_title = old_title.into();
+        self.old = old.to_string();
+        self.new = new.to_string();
+        self.lines = side_by_side_diff(old, new);
+        self.show = true;
+    }
+
+    /// 关闭窗口
+    pub fn close(&mut self) {
+        self.show = false;
+        self.lines.clear();
+    }
+
+    /// 两段语句是否完全相同
+    pub fn is_identical(&self) -> bool {
+        self.lines.iter().all(|l| l.change == LineChange::Same)
+    }
+}
+
+// ============================================================================
+// 窗口 UI
+// ============================================================================
+
+/// SQL 对比窗口
+pub struct SqlDiffDialog;
+
+impl SqlDiffDialog {
+    /// 显示窗口
+    pub fn show(ctx: &egui::Context, state: &mut SqlDiffState) -> SqlDiffResult {
+        if !state.show {
+            return SqlDiffResult::None;
+        }
+
+        if !keyboard::has_text_focus(ctx) && keyboard::handle_close_keys(ctx) {
+            state.close();
+            return SqlDiffResult::None;
+        }
+
+        let mut result = SqlDiffResult::None;
+
+        let mut open = true;
+        egui::Window::new("🔀 SQL 对比")
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(true)
+            .default_size([760.0, 420.0])
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    if state.is_identical() {
+                        ui.label(RichText::new("两段语句完全相同").color(diff_color(DiffKind::Added)));
+                    } else {
+                        let count = |change: LineChange| state.lines.iter().filter(|l| l.change == change).count();
+                        for (change, kind) in [
+                            (LineChange::Added, DiffKind::Added),
+                            (LineChange::Removed, DiffKind::Removed),
+                            (LineChange::Changed, DiffKind::Changed),
+                        ] {
+                            ui.label(
+                                RichText::new(format!("{} {} 行", kind.label(), count(change))).color(diff_color(kind)),
+                            );
+                        }
+                    }
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui
+                            .add_enabled(!state.is_identical(), egui::Button::new("↩ 使用历史语句"))
+                            .on_hover_text("用左侧的历史语句替换编辑器内容")
+                            .clicked()
+                        {
+                            result = SqlDiffResult::UseOld(state.old.clone());
+                        }
+                    });
+                });
+
+                ui.separator();
+                Self::show_lines(ui, state);
+            });
+
+        if matches!(result, SqlDiffResult::UseOld(_)) || !open {
+            state.close();
+        }
+
+        result
+    }
+
+    /// 并排显示的对比行
+    fn show_lines(ui: &mut egui::Ui, state: &SqlDiffState) {
+        use egui_extras::{Column, TableBuilder};
+
+        let half = ((ui.available_width() - 80.0) / 2.0).max(120.0);
+        TableBuilder::new(ui)
+            .striped(false)
+            .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+            .column(Column::exact(32.0))
+            .column(Column::initial(half).at_least(120.0).clip(true))
+            .column(Column::exact(32.0))
+            .column(Column::remainder().at_least(120.0).clip(true))
+            .header(20.0, |mut header| {
+                header.col(|_| {});
+                header.col(|ui| {
+                    ui.label(RichText::new(&state.old_title).strong().small());
+                });
+                header.col(|_| {});
+                header.col(|ui| {
+                    ui.label(RichText::new("当前编辑器").strong().small());
+                });
+            })
+            .body(|body| {
+                body.rows(18.0, state.lines.len(), |mut row| {
+                    let line = &state.lines[row.index()];
+                    let (left_color, right_color) = match line.change {
+                        LineChange::Same => (None, None),
+                        LineChange::Changed => (Some(diff_color(DiffKind::Changed)), Some(diff_color(DiffKind::Changed))),
+                        LineChange::Removed => (Some(diff_color(DiffKind::Removed)), None),
+                        LineChange::Added => (None, Some(diff_color(DiffKind::Added))),
+                    };
+                    for (side, color) in [(&line.left, left_color), (&line.right, right_color)] {
+                        row.col(|ui| {
+                            if let Some((number, _)) = side {
+                                ui.label(RichText::new(number.to_string()).monospace().small().color(Color32::GRAY));
+                            }
+                        });
+                        row.col(|ui| {
+                            if let Some((_, text)) = side {
+                                let text = RichText::new(text).monospace();
+                                ui.label(match color {
+                                    Some(color) => text.color(color),
+                                    None => text,
+                                });
+                            }
+                        });
+                    }
+                });
+            });
+    }
+}
@@ -0,0 +1,243 @@
+//! 表格保存 SQL 检查窗口
+//!
+//! 保存表格修改前显示生成的 UPDATE/DELETE/INSERT 语句，可以修改语句、选择是否在事务中执行，
+//! 或只复制语句而不执行。
+
+use super::keyboard;
+use crate::database::split_statements;
+use egui::{self, Color32, RichText, TextEdit};
+
+// ============================================================================
+// 窗口结果
+// ============================================================================
+
+/// SQL 检查窗口的结果
+pub enum SqlReviewResult {
+    /// 无操作
+    None,
+    /// 执行语句
+    Execute {
+        statements: Vec<String>,
+        /// 是否在一个事务中执行
+        transaction: bool,
+    },
+    /// 已复制语句（不执行）
+    Copied,
+    /// 取消
+    Cancelled,
+}
+
+// ============================================================================
+// 窗口状态
+// ============================================================================
+
+/// 各类语句的数量
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StatementCounts {
+    pub updates: usize,
+    pub deletes: usize,
+    pub inserts: usize,
+    pub others: usize,
+}
+
+impl StatementCounts {
+    /// 统计语句类型
+    pub fn of(statements: &[String]) -> Self {
+        let mut counts = Self::default();
+        for sql in statements {
+            let verb = sql
+                .split_whitespace()
+                .next()
+                .unwrap_or_default()
+                .to_ascii_lowercase();
+            match verb.as_str() {
+                "update" => counts.updates += 1,
+                "delete" => counts.deletes += 1,
+                "insert" => counts.inserts += 1,
+                _ => counts.others += 1,
+            }
+        }
+        counts
+    }
+
+    /// 语句总数
+    pub fn total(&self) -> usize {
+        self.updates + self.deletes + self.inserts + self.others
+    }
+}
+
+/// SQL 检查窗口状态
+#[derive(Default)]
+pub struct SqlReviewState {
+    /// 是否显示窗口
+    pub show: bool,
+    /// 表名
+    pub table: String,
+    /// 可编辑的 SQL（每行一条语句）
+    pub sql: String,
+    /// 是否在一个事务中执行
+    pub use_transaction: bool,
+    /// 当前数据库是否支持事务执行
+    pub transaction_supported: bool,
+}
+
+impl SqlReviewState {
+    /// 创建新的窗口状态
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 打开窗口，支持事务的数据库默认在事务中执行
+    pub fn open(&mut self, table: String, statements: &[String], transaction_supported: bool) {
+        self.show = true;
+        self.table = table;
+        self.sql = statements
+            .iter()
+            .map(|sql| format!("{};", sql.trim().trim_end_matches(';')))
+            .collect::<Vec<_>>()
+            .join("\n");
+        self.transaction_supported = transaction_supported;
+        self.use_transaction = transaction_supported;
+    }
+
+    /// 关闭窗口
+    pub fn close(&mut self) {
+        self.show = false;
+        self.sql.clear();
+    }
+
+    /// 编辑后的语句
+    pub fn statements(&self) -> Vec<String> {
+        split_statements(&self.sql)
+    }
+
+    /// 是否在事务中执行（数据库不支持时始终为 false）
+    pub fn transaction(&self) -> bool {
+        self.use_transaction && self.transaction_supported
+    }
+
+    /// 复制用的脚本：在事务中执行时用 BEGIN/COMMIT 包裹
+    pub fn script(&self) -> String {
+        let body = self
+            .statements()
+            .iter()
+            .map(|sql| format!("{};", sql))
+            .collect::<Vec<_>>()
+            .join("\n");
+        if self.transaction() {
+            format!("BEGIN;\n{}\nCOMMIT;", body)
+        } else {
+            body
+        }
+    }
+}
+
+// ============================================================================
+// 窗口 UI
+// ============================================================================
+
+/// 表格保存 SQL 检查窗口
+pub struct SqlReviewDialog;
+
+impl SqlReviewDialog {
+    /// 显示窗口
+    pub fn show(ctx: &egui::Context, state: &mut SqlReviewState) -> SqlReviewResult {
+        if !state.show {
+            return SqlReviewResult::None;
+        }
+
+        let mut result = SqlReviewResult::None;
+        let statements = state.statements();
+        let counts = StatementCounts::of(&statements);
+
+        // 键盘快捷键：Esc 取消，Ctrl+Enter 执行（编辑 SQL 时也有效）
+        if ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
+            state.close();
+            return SqlReviewResult::Cancelled;
+        }
+        if !statements.is_empty() && ctx.input(|i| i.modifiers.command && i.key_pressed(egui::Key::Enter)) {
+            result = SqlReviewResult::Execute {
+                statements: statements.clone(),
+                transaction: state.transaction(),
+            };
+        }
+        // y 复制
+        if !keyboard::has_text_focus(ctx) && ctx.input(|i| i.key_pressed(egui::Key::Y)) {
+            ctx.copy_text(state.script());
+            result = SqlReviewResult::Copied;
+        }
+
+        let mut open = true;
+        egui::Window::new(format!("检查 SQL - {}", state.table))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(true)
+            .default_size([640.0, 400.0])
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                let mut summary = format!("共 {} 条语句", counts.total());
+                for (count, label) in [(counts.updates, "更新"), (counts.deletes, "删除"), (counts.inserts, "插入")] {
+                    if count > 0 {
+                        summary.push_str(&format!(" · {} {}", label, count));
+                    }
+                }
+                ui.label(summary);
+                if counts.deletes > 0 {
+                    ui.label(
+                        RichText::new(format!("警告: 包含 {} 条 DELETE 语句，删除的行无法恢复", counts.deletes))
+                            .small()
+                            .color(Color32::from_rgb(230, 180, 80)),
+                    );
+                }
+                ui.add_space(4.0);
+
+                egui::ScrollArea::vertical()
+                    .id_salt("sql_review_scroll")
+                    .max_height(ui.available_height() - 64.0)
+                    .show(ui, |ui| {
+                        ui.add(
+                            TextEdit::multiline(&mut state.sql)
+                                .code_editor()
+                                .desired_width(f32::INFINITY)
+                                .desired_rows(8),
+                        );
+                    });
+
+                ui.add_space(4.0);
+                ui.add_enabled(
+                    state.transaction_supported,
+                    egui::Checkbox::new(&mut state.use_transaction, "在事务中执行（任一语句失败时全部回滚）"),
+                )
+                .on_disabled_hover_text("当前数据库不支持事务执行，语句将依次执行");
+
+                ui.add_space(8.0);
+                ui.horizontal(|ui| {
+                    if ui
+                        .add_enabled(!statements.is_empty(), egui::Button::new("执行 [Ctrl+Enter]"))
+                        .clicked()
+                    {
+                        result = SqlReviewResult::Execute {
+                            statements: statements.clone(),
+                            transaction: state.transaction(),
+                        };
+                    }
+                    if ui.add_enabled(!statements.is_empty(), egui::Button::new("📋 复制 [y]")).clicked() {
+                        ctx.copy_text(state.script());
+                        result = SqlReviewResult::Copied;
+                    }
+                    if ui.button("取消 [Esc]").clicked() {
+                        result = SqlReviewResult::Cancelled;
+                    }
+                });
+            });
+
+        if !open {
+            result = SqlReviewResult::Cancelled;
+        }
+        if !matches!(result, SqlReviewResult::None) {
+            state.close();
+        }
+
+        result
+    }
+}
@@ -0,0 +1,418 @@
+//! 表操作对话框
+//!
+//! 重命名、删除、清空和复制表，执行前预览按数据库方言生成的 SQL；
+//! 删除和清空表需要输入表名确认。支持 Helix 风格的键盘导航。
+
+use super::keyboard::{self, DialogAction};
+use crate::database::DatabaseType;
+use egui::{self, Color32, RichText, TextEdit};
+
+// ============================================================================
+// SQL 生成
+// ============================================================================
+
+/// 生成重命名表的 SQL
+///
+/// `schema` 为调用方确认过的 schema（如 SQLite 附加数据库的别名），新表名留在同一 schema 内
+pub fn rename_table_sql(db_type: DatabaseType, schema: Option<&str>, table: &str, new_name: &str) -> Result<String, String> {
+    let new_name = new_name.trim();
+    if new_name.is_empty() {
+        return Err("新表名不能为空".to_string());
+    }
+    if new_name == table {
+        return Err("新表名与原表名相同".to_string());
+    }
+    let old = db_type.quote_table(schema, table);
+    match db_type {
+        DatabaseType::MySQL | DatabaseType::ClickHouse => {
+            Ok(format!("RENAME TABLE {} TO {};", old, db_type.quote_table(schema, new_name)))
+        }
+        DatabaseType::Redis | DatabaseType::MongoDB => {
+            Err(format!("{} 不支持重命名表", db_type.display_name()))
+        }
+        _ => Ok(format!("ALTER TABLE {} RENAME TO {};", old, db_type.quote_identifier(new_name))),
+    }
+}
+
+/// 生成删除表的 SQL
+pub fn drop_table_sql(db_type: DatabaseType, schema: Option<&str>, table: &str) -> Result<String, String> {
+    match db_type {
+        DatabaseType::Redis | DatabaseType::MongoDB => {
+            Err(format!("{} 不支持删除表", db_type.display_name()))
+        }
+        _ => Ok(format!("DROP TABLE {};", db_type.quote_table(schema, table))),
+    }
+}
+
+/// 生成清空表的 SQL（SQLite 没有 TRUNCATE，使用 DELETE）
+pub fn truncate_table_sql(db_type: DatabaseType, schema: Option<&str>, table: &str) -> Result<String, String> {
+    let quoted = db_type.quote_table(schema, table);
+    match db_type {
+        DatabaseType::SQLite => Ok(format!("DELETE FROM {};", quoted)),
+        DatabaseType::Redis | DatabaseType::MongoDB => {
+            Err(format!("{} 不支持清空表", db_type.display_name()))
+        }
+        _ => Ok(format!("TRUNCATE TABLE {};", quoted)),
+    }
+}
+
+/// 生成复制表的 SQL，`with_data` 为 false 时只复制结构
+///
+/// MySQL/PostgreSQL/ClickHouse 复制完整表结构（含索引等）后再插入数据；
+/// 其他数据库使用 CREATE TABLE ... AS SELECT，不保留约束和索引；新表与原表位于同一 schema
+pub fn duplicate_table_sql(
+    db_type: DatabaseType,
+    schema: Option<&str>,
+    table: &str,
+    new_name: &str,
+    with_data: bool,
+) -> Result<Vec<String>, String> {
+    let new_name = new_name.trim();
+    if new_name.is_empty() {
+        return Err("新表名不能为空".to_string());
+    }
+    if new_name == table {
+        return Err("新表名与原表名相同".to_string());
+    }
+    let source = db_type.quote_table(schema, table);
+    let target = db_type.quote_table(schema, new_name);
+
+    let insert = |overriding: &str| format!("INSERT INTO {}{} SELECT * FROM {};", target, overriding, source);
+    let statements = match db_type {
+        DatabaseType::MySQL => {
+            let mut statements = vec![format!("CREATE TABLE {} LIKE {};", target, source)];
+            if with_data {
+                statements.push(insert(""));
+            }
+            statements
+        }
+        DatabaseType::PostgreSQL => {
+            let mut statements = vec![format!("CREATE TABLE {} (LIKE {} INCLUDING ALL);", target, source)];
+            if with_data {
+                // 允许写入 GENERATED ALWAYS 标识列
+                statements.push(insert(" OVERRIDING SYSTEM VALUE"));
+            }
+            statements
+        }
+        DatabaseType::ClickHouse => {
+            let mut statements = vec![format!("CREATE TABLE {} AS {};", target, source)];
+            if with_data {
+                statements.push(insert(""));
+            }
+            statements
+        }
+        DatabaseType::Redis | DatabaseType::MongoDB => {
+            return Err(format!("{} 不支持复制表", db_type.display_name()));
+        }
+        _ => {
+            let filter = if with_data { "" } else { " WHERE 1 = 0" };
+            vec![format!("CREATE TABLE {} AS SELECT * FROM {}{};", target, source, filter)]
+        }
+    };
+    Ok(statements)
+}
+
+// ============================================================================
+// 对话框结果
+// ============================================================================
+
+/// 表操作类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableAction {
+    /// 重命名
+    Rename,
+    /// 删除
+    Drop,
+    /// 清空数据
+    Truncate,
+    /// 复制为新表
+    Duplicate,
+}
+
+impl TableAction {
+    /// 操作名称
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            TableAction::Rename => "重命名表",
+            TableAction::Drop => "删除表",
+            TableAction::Truncate => "清空表",
+            TableAction::Duplicate => "复制表",
+        }
+    }
+
+    /// 是否需要输入表名确认
+    pub fn requires_confirmation(&self) -> bool {
+        matches!(self, TableAction::Drop | TableAction::Truncate)
+    }
+}
+
+/// 表操作对话框的结果
+pub enum TableActionResult {
+    /// 无操作
+    None,
+    /// 用户确认执行（操作、表名、SQL 语句）
+    Execute(TableAction, String, Vec<String>),
+    /// 用户取消
+    Cancelled,
+}
+
+// ============================================================================
+// 对话框状态
+// ============================================================================
+
+/// 表操作对话框状态
+pub struct TableActionState {
+    /// 是否显示对话框
+    pub show: bool,
+    /// 操作类型
+    pub action: TableAction,
+    /// 当前数据库类型
+    pub db_type: DatabaseType,
+    /// 目标表（完整名称，附加数据库的表带 schema 前缀）
+    pub table: String,
+    /// 目标表所在的 schema（仅当表名前缀是已知 schema 时才有值）
+    pub schema: Option<String>,
+    /// 输入内容（重命名/复制时为新表名，删除/清空时为确认输入的表名）
+    pub input: String,
+    /// 复制表时是否包含数据
+    pub with_data: bool,
+    /// 错误信息
+    pub error: Option<String>,
+    /// 下一帧聚焦输入框
+    focus_input: bool,
+}
+
+impl Default for TableActionState {
+    fn default() -> Self {
+        Self {
+            show: false,
+            action: TableAction::Rename,
+            db_type: DatabaseType::default(),
+            table: String::new(),
+            schema: None,
+            input: String::new(),
+            with_data: true,
+            error: None,
+            focus_input: false,
+        }
+    }
+}
+
+impl TableActionState {
+    /// 创建新的对话框状态
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 打开对话框；重命名时预填原表名，复制时预填 `<表名>_copy`
+    ///
+    /// `schema` 由调用方按连接判断（只有已知 schema 的前缀才拆分），表名本身带点时为 None
+    pub fn open(&mut self, action: TableAction, db_type: DatabaseType, schema: Option<String>, table: String) {
+        self.schema = schema;
+        self.table = table;
+        let name = self.name();
+        self.input = match action {
+            TableAction::Rename => name.to_string(),
+            TableAction::Duplicate => format!("{}_copy", name),
+            TableAction::Drop | TableAction::Truncate => String::new(),
+        };
+        self.action = action;
+        self.db_type = db_type;
+        self.error = None;
+        self.focus_input = true;
+        self.show = true;
+    }
+
+    /// 关闭对话框
+    pub fn close(&mut self) {
+        self.show = false;
+        self.table.clear();
+        self.schema = None;
+        self.input.clear();
+        self.error = None;
+    }
+
+    /// 去掉 schema 前缀后的表名
+    fn name(&self) -> &str {
+        self.schema
+            .as_deref()
+            .and_then(|schema| self.table.strip_prefix(schema)?.strip_prefix('.'))
+            .unwrap_or(&self.table)
+    }
+
+    /// 校验输入并生成 SQL
+    pub fn generate_sql(&self) -> Result<Vec<String>, String> {
+        let (db_type, schema, table) = (self.db_type, self.schema.as_deref(), self.name());
+        match self.action {
+            TableAction::Rename => rename_table_sql(db_type, schema, table, &self.input).map(|sql| vec![sql]),
+            TableAction::Drop => drop_table_sql(db_type, schema, table).map(|sql| vec![sql]),
+            TableAction::Truncate => truncate_table_sql(db_type, schema, table).map(|sql| vec![sql]),
+            TableAction::Duplicate => duplicate_table_sql(db_type, schema, table, &self.input, self.with_data),
+        }
+    }
+
+    /// 是否已满足执行条件（删除/清空需输入与表名完全一致的确认文本）
+    pub fn is_confirmed(&self) -> bool {
+        !self.action.requires_confirmation() || self.input.trim() == self.table
+    }
+
+    /// 确认执行：返回要执行的 SQL，或记录错误
+    fn confirm(&mut self) -> Option<Vec<String>> {
+        if !self.is_confirmed() {
+            self.error = Some("输入的表名不匹配".to_string());
+            return None;
+        }
+        match self.generate_sql() {
+            Ok(statements) => Some(statements),
+            Err(e) => {
+                self.error = Some(e);
+                None
+            }
+        }
+    }
+}
+
+// ============================================================================
+// 对话框 UI
+// ============================================================================
+
+/// 表操作对话框
+pub struct TableActionDialog;
+
+impl TableActionDialog {
+    /// 显示对话框
+    pub fn show(ctx: &egui::Context, state: &mut TableActionState) -> TableActionResult {
+        if !state.show {
+            return TableActionResult::None;
+        }
+
+        let mut result = TableActionResult::None;
+        let mut should_close = false;
+
+        // 键盘快捷键处理
+        if !keyboard::has_text_focus(ctx) {
+            if keyboard::handle_close_keys(ctx) {
+                state.close();
+                return TableActionResult::Cancelled;
+            }
+
+            if let DialogAction::Confirm = keyboard::handle_dialog_keys(ctx)
+                && let Some(statements) = state.confirm()
+            {
+                result = TableActionResult::Execute(state.action, state.table.clone(), statements);
+                should_close = true;
+            }
+        }
+
+        let needs_confirm = state.action.requires_confirmation();
+
+        egui::Window::new(state.action.display_name())
+            .collapsible(false)
+            .resizable(false)
+            .min_width(400.0)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                egui::Grid::new("table_action_info")
+                    .num_columns(2)
+                    .spacing([12.0, 6.0])
+                    .show(ui, |ui| {
+                        ui.label("表:");
+                        ui.label(RichText::new(&state.table).strong());
+                        ui.end_row();
+
+                        ui.label(if needs_confirm { "确认表名:" } else { "新表名:" });
+                        let response = ui.add(
+                            TextEdit::singleline(&mut state.input)
+                                .desired_width(220.0)
+                                .hint_text(if needs_confirm { state.table.as_str() } else { "" }),
+                        );
+                        if state.focus_input {
+                            response.request_focus();
+                            state.focus_input = false;
+                        }
+                        if response.changed() {
+                            state.error = None;
+                        }
+                        // 输入框内按 Enter 直接确认
+                        if response.lost_focus()
+                            && ui.input(|i| i.key_pressed(egui::Key::Enter))
+                            && let Some(statements) = state.confirm()
+                        {
+                            result = TableActionResult::Execute(state.action, state.table.clone(), statements);
+                            should_close = true;
+                        }
+                        ui.end_row();
+
+                        if state.action == TableAction::Duplicate {
+                            ui.label("复制内容:");
+                            ui.horizontal(|ui| {
+                                ui.radio_value(&mut state.with_data, false, "仅结构");
+                                ui.radio_value(&mut state.with_data, true, "结构和数据");
+                            });
+                            ui.end_row();
+                        }
+                    });
+
+                ui.add_space(8.0);
+
+                // 预览 SQL
+                let sql = state.generate_sql().map(|s| s.join("\n")).unwrap_or_default();
+                ui.add(
+                    TextEdit::multiline(&mut sql.as_str())
+                        .code_editor()
+                        .desired_width(f32::INFINITY)
+                        .desired_rows(2),
+                );
+
+                let warning = match state.action {
+                    TableAction::Drop => Some("警告: 删除后表中的数据将无法恢复，请输入表名确认"),
+                    TableAction::Truncate => Some("警告: 清空后表中的数据将无法恢复，请输入表名确认"),
+                    TableAction::Duplicate
+                        if !matches!(
+                            state.db_type,
+                            DatabaseType::MySQL | DatabaseType::PostgreSQL | DatabaseType::ClickHouse
+                        ) =>
+                    {
+                        Some("提示: 新表不包含原表的主键、约束和索引")
+                    }
+                    _ => None,
+                };
+                if let Some(warning) = warning {
+                    ui.add_space(4.0);
+                    ui.label(RichText::new(warning).small().color(Color32::from_rgb(230, 180, 80)));
+                }
+
+                if let Some(err) = &state.error {
+                    ui.add_space(4.0);
+                    ui.label(RichText::new(err).color(Color32::from_rgb(255, 100, 100)));
+                }
+
+                ui.add_space(8.0);
+
+                ui.horizontal(|ui| {
+                    let label = match state.action {
+                        TableAction::Drop => "删除 [Enter]",
+                        TableAction::Truncate => "清空 [Enter]",
+                        _ => "执行 [Enter]",
+                    };
+                    if ui.add_enabled(state.is_confirmed(), egui::Button::new(label)).clicked()
+                        && let Some(statements) = state.confirm()
+                    {
+                        result = TableActionResult::Execute(state.action, state.table.clone(), statements);
+                        should_close = true;
+                    }
+
+                    if ui.button("取消 [Esc]").clicked() {
+                        result = TableActionResult::Cancelled;
+                        should_close = true;
+                    }
+                });
+            });
+
+        if should_close {
+            state.close();
+        }
+
+        result
+    }
+}
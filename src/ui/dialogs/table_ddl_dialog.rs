@@ -0,0 +1,171 @@
+//! 建表语句查看窗口
+//!
+//! 只读显示表的 CREATE TABLE 语句（带语法高亮），支持复制、导出为 .sql 文件，
+//! 或在新的查询标签页中打开以便修改。
+
+use super::keyboard;
+use crate::core::{highlight_sql, HighlightColors};
+use egui::{self, Color32, RichText};
+
+// ============================================================================
+// 窗口结果
+// ============================================================================
+
+/// 建表语句窗口的结果
+pub enum TableDdlResult {
+    /// 无操作
+    None,
+    /// 导出为文件（表名，语句）
+    Export(String, String),
+    /// 在新标签页中打开
+    OpenInTab(String),
+}
+
+// ============================================================================
+// 窗口状态
+// ============================================================================
+
+/// 建表语句窗口状态
+#[derive(Default)]
+pub struct TableDdlState {
+    /// 是否显示窗口
+    pub show: bool,
+    /// 表名
+    pub table: String,
+    /// 是否正在加载
+    pub loading: bool,
+    /// 建表语句
+    pub ddl: String,
+    /// 错误信息
+    pub error: Option<String>,
+}
+
+impl TableDdlState {
+    /// 创建新的窗口状态
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 打开窗口并进入加载状态（数据由调用方异步加载）
+    pub fn open(&mut self, table: String) {
+        self.show = true;
+        self.table = table;
+        self.ddl.clear();
+        self.error = None;
+        self.loading = true;
+    }
+
+    /// 关闭窗口
+    pub fn close(&mut self) {
+        self.show = false;
+        self.loading = false;
+        self.ddl.clear();
+        self.error = None;
+    }
+
+    /// 设置加载结果（忽略已切换到其他表之后返回的旧结果）
+    pub fn set_result(&mut self, table: &str, result: Result<String, String>) {
+        if !self.show || table != self.table {
+            return;
+        }
+        self.loading = false;
+        match result {
+            Ok(ddl) => {
+                self.ddl = ddl;
+                self.error = None;
+            }
+            Err(e) => {
+                self.ddl.clear();
+                self.error = Some(e);
+            }
+        }
+    }
+}
+
+// ============================================================================
+// 窗口 UI
+// ============================================================================
+
+/// 建表语句窗口
+pub struct TableDdlDialog;
+
+impl TableDdlDialog {
+    /// 显示窗口
+    pub fn show(ctx: &egui::Context, state: &mut TableDdlState, colors: &HighlightColors) -> TableDdlResult {
+        if !state.show {
+            return TableDdlResult::None;
+        }
+
+        let mut result = TableDdlResult::None;
+        let ready = !state.loading && state.error.is_none() && !state.ddl.is_empty();
+
+        // 键盘快捷键处理
+        if !keyboard::has_text_focus(ctx) {
+            if keyboard::handle_close_keys(ctx) {
+                state.close();
+                return TableDdlResult::None;
+            }
+            // y 复制
+            if ready && ctx.input(|i| i.key_pressed(egui::Key::Y)) {
+                ctx.copy_text(state.ddl.clone());
+            }
+        }
+
+        let mut open = true;
+        egui::Window::new(format!("建表语句 - {}", state.table))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(true)
+            .default_size([640.0, 420.0])
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.add_enabled_ui(ready, |ui| {
+                        if ui.button("📋 复制 [y]").clicked() {
+                            ui.ctx().copy_text(state.ddl.clone());
+                        }
+                        if ui.button("💾 导出...").clicked() {
+                            result = TableDdlResult::Export(state.table.clone(), state.ddl.clone());
+                        }
+                        if ui.button("📝 在新标签页中打开").clicked() {
+                            result = TableDdlResult::OpenInTab(state.ddl.clone());
+                        }
+                    });
+                    if state.loading {
+                        ui.spinner();
+                    }
+                });
+
+                ui.separator();
+
+                if let Some(err) = &state.error {
+                    ui.label(RichText::new(err).color(Color32::from_rgb(255, 100, 100)));
+                    return;
+                }
+
+                let mut layouter = |ui: &egui::Ui, text: &dyn egui::TextBuffer, wrap_width: f32| {
+                    let mut job = highlight_sql(text.as_str(), colors);
+                    job.wrap.max_width = wrap_width;
+                    ui.ctx().fonts_mut(|f| f.layout_job(job))
+                };
+                egui::ScrollArea::both()
+                    .id_salt("table_ddl_scroll")
+                    .auto_shrink([false, false])
+                    .show(ui, |ui| {
+                        // 不可变文本：可选中复制但不能编辑
+                        ui.add(
+                            egui::TextEdit::multiline(&mut state.ddl.as_str())
+                                .code_editor()
+                                .desired_width(f32::INFINITY)
+                                .layouter(&mut layouter),
+                        );
+                    });
+            });
+
+        if !open {
+            state.close();
+        }
+
+        result
+    }
+}
@@ -0,0 +1,302 @@
+//! 主题编辑器
+//!
+//! 在预设主题的基础上调整界面颜色和语法高亮颜色，保存为命名的自定义主题，
+//! 并可导入/导出 TOML 或 JSON 主题文件。修改会实时预览。
+
+use super::keyboard;
+use crate::core::{t, tf, CustomTheme, ThemePreset};
+use egui::{self, Color32, RichText};
+
+// ============================================================================
+// 窗口结果
+// ============================================================================
+
+/// 主题编辑器的结果
+pub enum ThemeEditorResult {
+    /// 无操作
+    None,
+    /// 预览修改后的主题
+    Preview(CustomTheme),
+    /// 保存主题（编辑已保存主题时带原名称）
+    Save {
+        original_name: Option<String>,
+        theme: CustomTheme,
+    },
+    /// 编辑已保存的主题
+    Load(String),
+    /// 删除已保存的主题
+    Delete(String),
+    /// 从文件导入主题
+    Import,
+    /// 导出主题到文件
+    Export(CustomTheme),
+    /// 关闭窗口（未保存的预览需要恢复）
+    Close,
+}
+
+// ============================================================================
+// 窗口状态
+// ============================================================================
+
+/// 主题编辑器状态
+#[derive(Default)]
+pub struct ThemeEditorState {
+    /// 是否显示窗口
+    pub show: bool,
+    /// 正在编辑的主题
+    pub theme: Option<CustomTheme>,
+    /// 编辑已保存主题时的原名称
+    pub original_name: Option<String>,
+    /// 已保存的自定义主题名称
+    pub saved_names: Vec<String>,
+    /// 错误信息
+    pub error: Option<String>,
+}
+
+impl ThemeEditorState {
+    /// 创建新的窗口状态
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 打开编辑器
+    pub fn open(&mut self, theme: CustomTheme, original_name: Option<String>, saved_names: Vec<String>) {
+        self.theme = Some(theme);
+        self.original_name = original_name;
+        self.saved_names = saved_names;
+        self.error = None;
+        self.show = true;
+    }
+
+    /// 关闭编辑器
+    pub fn close(&mut self) {
+        self.show = false;
+        self.theme = None;
+        self.original_name = None;
+        self.error = None;
+    }
+
+    /// 校验主题名称
+    pub fn validate(&self) -> Result<(), String> {
+        let Some(theme) = &self.theme else {
+            return Err(t("theme_editor.no_theme").to_string());
+        };
+        let name = theme.name.trim();
+        if name.is_empty() {
+            return Err(t("theme_editor.name_required").to_string());
+        }
+        let renamed = self.original_name.as_deref() != Some(name);
+        if renamed && self.saved_names.iter().any(|n| n == name) {
+            return Err(tf("theme_editor.name_exists", &[name]));
+        }
+        Ok(())
+    }
+}
+
+// ============================================================================
+// 窗口 UI
+// ============================================================================
+
+/// 主题编辑器窗口
+pub struct ThemeEditorDialog;
+
+impl ThemeEditorDialog {
+    /// 显示窗口
+    pub fn show(ctx: &egui::Context, state: &mut ThemeEditorState) -> ThemeEditorResult {
+        if !state.show {
+            return ThemeEditorResult::None;
+        }
+
+        if !keyboard::has_text_focus(ctx) && keyboard::handle_close_keys(ctx) {
+            state.close();
+            return ThemeEditorResult::Close;
+        }
+
+        let mut result = ThemeEditorResult::None;
+        let mut changed = false;
+
+        let mut open = true;
+        egui::Window::new(t("theme_editor.title"))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(true)
+            .default_size([460.0, 560.0])
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                // 已保存的主题
+                ui.horizontal(|ui| {
+                    let current = state.original_name.clone().unwrap_or_else(|| t("theme_editor.unsaved").to_string());
+                    egui::ComboBox::from_id_salt("theme_editor_saved")
+                        .selected_text(current)
+                        .width(160.0)
+                        .show_ui(ui, |ui| {
+                            for name in &state.saved_names {
+                                let selected = state.original_name.as_ref() == Some(name);
+                                if ui.selectable_label(selected, name).clicked() && !selected {
+                                    result = ThemeEditorResult::Load(name.clone());
+                                }
+                            }
+                            if state.saved_names.is_empty() {
+                                ui.label(RichText::new(t("theme_editor.no_custom")).italics().color(Color32::GRAY));
+                            }
+                        });
+
+                    if ui.button(t("theme_editor.import")).clicked() {
+                        result = ThemeEditorResult::Import;
+                    }
+                    if let Some(theme) = &state.theme
+                        && ui.button(t("theme_editor.export")).on_hover_text(t("theme_editor.export_hint")).clicked()
+                    {
+                        result = ThemeEditorResult::Export(theme.clone());
+                    }
+                    if let Some(name) = &state.original_name
+                        && ui.button(t("theme_editor.delete")).clicked()
+                    {
+                        result = ThemeEditorResult::Delete(name.clone());
+                    }
+                });
+
+                ui.separator();
+
+                let Some(theme) = &mut state.theme else {
+                    return;
+                };
+
+                egui::Grid::new("theme_editor_meta").num_columns(2).spacing([12.0, 6.0]).show(ui, |ui| {
+                    ui.label(t("common.name"));
+                    ui.add(egui::TextEdit::singleline(&mut theme.name).desired_width(200.0));
+                    ui.end_row();
+
+                    ui.label(t("theme_editor.based_on"));
+                    let mut base = theme.base;
+                    egui::ComboBox::from_id_salt("theme_editor_base")
+                        .selected_text(base.display_name())
+                        .width(200.0)
+                        .show_ui(ui, |ui| {
+                            for preset in ThemePreset::ALL {
+                                ui.selectable_value(&mut base, preset, preset.display_name());
+                            }
+                        })
+                        .response
+                        .on_hover_text(t("theme_editor.based_on_hint"));
+                    if base != theme.base {
+                        *theme = CustomTheme {
+                            name: std::mem::take(&mut theme.name),
+                            ..CustomTheme::from_preset("", base)
+                        };
+                        changed = true;
+                    }
+                    ui.end_row();
+
+                    ui.label(t("theme_editor.dark"));
+                    changed |= ui.checkbox(&mut theme.dark, t("theme_editor.dark_style")).changed();
+                    ui.end_row();
+                });
+
+                ui.separator();
+
+                egui::ScrollArea::vertical().auto_shrink([false, true]).max_height(360.0).show(ui, |ui| {
+                    let colors = &mut theme.colors;
+                    changed |= Self::color_section(ui, t("theme_editor.background"), &mut [
+                        (t("theme_editor.bg_primary"), &mut colors.bg_primary),
+                        (t("theme_editor.bg_secondary"), &mut colors.bg_secondary),
+                        (t("theme_editor.bg_tertiary"), &mut colors.bg_tertiary),
+                    ]);
+                    changed |= Self::color_section(ui, t("theme_editor.text"), &mut [
+                        (t("theme_editor.fg_primary"), &mut colors.fg_primary),
+                        (t("theme_editor.fg_secondary"), &mut colors.fg_secondary),
+                        (t("theme_editor.fg_muted"), &mut colors.fg_muted),
+                    ]);
+                    changed |= Self::color_section(ui, t("theme_editor.accent"), &mut [
+                        (t("theme_editor.accent"), &mut colors.accent),
+                        (t("theme_editor.accent_hover"), &mut colors.accent_hover),
+                    ]);
+                    changed |= Self::color_section(ui, t("theme_editor.status"), &mut [
+                        (t("theme_editor.success"), &mut colors.success),
+                        (t("theme_editor.warning"), &mut colors.warning),
+                        (t("theme_editor.error"), &mut colors.error),
+                        (t("theme_editor.info"), &mut colors.info),
+                    ]);
+                    changed |= Self::color_section(ui, t("theme_editor.border"), &mut [
+                        (t("theme_editor.border"), &mut colors.border),
+                        (t("theme_editor.border_hover"), &mut colors.border_hover),
+                    ]);
+                    changed |= Self::color_section(ui, t("theme_editor.selection_section"), &mut [
+                        (t("theme_editor.selection"), &mut colors.selection),
+                        (t("theme_editor.highlight"), &mut colors.highlight),
+                    ]);
+                    let syntax = &mut theme.syntax;
+                    changed |= Self::color_section(ui, t("theme_editor.syntax"), &mut [
+                        (t("theme_editor.keyword"), &mut syntax.keyword),
+                        (t("theme_editor.function"), &mut syntax.function),
+                        (t("theme_editor.string"), &mut syntax.string),
+                        (t("theme_editor.number"), &mut syntax.number),
+                        (t("theme_editor.operator"), &mut syntax.operator),
+                        (t("theme_editor.comment"), &mut syntax.comment),
+                        (t("theme_editor.identifier"), &mut syntax.identifier),
+                        (t("theme_editor.punctuation"), &mut syntax.punctuation),
+                    ]);
+                });
+
+                if let Some(err) = &state.error {
+                    ui.label(RichText::new(err).color(Color32::from_rgb(255, 100, 100)));
+                }
+
+                ui.separator();
+                ui.horizontal(|ui| {
+                    if ui.button(t("theme_editor.save")).clicked() {
+                        match state.validate() {
+                            Ok(()) => {
+                                if let Some(theme) = &mut state.theme {
+                                    theme.name = theme.name.trim().to_string();
+                                    result = ThemeEditorResult::Save {
+                                        original_name: state.original_name.clone(),
+                                        theme: theme.clone(),
+                                    };
+                                }
+                            }
+                            Err(e) => state.error = Some(e),
+                        }
+                    }
+                    if ui.button(t("theme_editor.close")).on_hover_text(t("theme_editor.close_hint")).clicked() {
+                        result = ThemeEditorResult::Close;
+                    }
+                });
+            });
+
+        if changed
+            && matches!(result, ThemeEditorResult::None)
+            && let Some(theme) = &state.theme
+        {
+            result = ThemeEditorResult::Preview(theme.clone());
+        }
+
+        if !open {
+            result = ThemeEditorResult::Close;
+        }
+        if matches!(result, ThemeEditorResult::Close) {
+            state.close();
+        }
+
+        result
+    }
+
+    /// 一组颜色编辑行，返回是否有修改
+    fn color_section(ui: &mut egui::Ui, title: &str, rows: &mut [(&str, &mut Color32)]) -> bool {
+        let mut changed = false;
+        ui.label(RichText::new(title).strong());
+        egui::Grid::new(("theme_editor_section", title)).num_columns(3).spacing([12.0, 4.0]).show(ui, |ui| {
+            for (label, color) in rows.iter_mut() {
+                ui.add_space(8.0);
+                ui.label(*label);
+                ui.horizontal(|ui| {
+                    changed |= ui.color_edit_button_srgba(color).changed();
+                    ui.label(RichText::new(color.to_hex()).monospace().small().color(Color32::GRAY));
+                });
+                ui.end_row();
+            }
+        });
+        ui.add_space(6.0);
+        changed
+    }
+}
@@ -0,0 +1,151 @@
+//! 回收站窗口
+//!
+//! 列出删除的连接和关闭的查询 Tab（最近删除的在最前），可以恢复或永久删除。
+
+use super::keyboard;
+use crate::core::{Trash, TrashEntry, TrashItem};
+use crate::ui::styles::{DANGER, GRAY, MUTED};
+use chrono::Local;
+use egui::{self, RichText};
+
+/// SQL 预览的最大字符数
+const PREVIEW_MAX_CHARS: usize = 60;
+
+/// 回收站窗口的结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrashDialogResult {
+    /// 无操作
+    None,
+    /// 恢复记录
+    Restore(usize),
+    /// 永久删除记录
+    Delete(usize),
+    /// 清空回收站
+    Empty,
+}
+
+/// 回收站窗口状态
+#[derive(Default)]
+pub struct TrashDialogState {
+    /// 是否显示窗口
+    pub show: bool,
+}
+
+impl TrashDialogState {
+    /// 创建新的状态
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 打开窗口
+    pub fn open(&mut self) {
+        self.show = true;
+    }
+
+    /// 关闭窗口
+    pub fn close(&mut self) {
+        self.show = false;
+    }
+}
+
+/// 回收站窗口
+pub struct TrashDialog;
+
+impl TrashDialog {
+    /// 显示窗口
+    pub fn show(
+        ctx: &egui::Context,
+        state: &mut TrashDialogState,
+        trash: &Trash,
+        retention_days: u32,
+    ) -> TrashDialogResult {
+        if !state.show {
+            return TrashDialogResult::None;
+        }
+
+        if !keyboard::has_text_focus(ctx) && keyboard::handle_close_keys(ctx) {
+            state.close();
+            return TrashDialogResult::None;
+        }
+
+        let mut result = TrashDialogResult::None;
+        let mut open = true;
+        egui::Window::new("♻ 回收站")
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(true)
+            .default_width(560.0)
+            .default_height(380.0)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(
+                        RichText::new(format!("删除的连接和关闭的标签页保留 {} 天", retention_days))
+                            .small()
+                            .color(GRAY),
+                    );
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui.add_enabled(!trash.is_empty(), egui::Button::new("🗑 清空")).clicked() {
+                            result = TrashDialogResult::Empty;
+                        }
+                    });
+                });
+                ui.separator();
+
+                if trash.is_empty() {
+                    ui.label(RichText::new("回收站是空的").color(MUTED));
+                    return;
+                }
+                egui::ScrollArea::vertical()
+                    .auto_shrink([false, false])
+                    .show(ui, |ui| {
+                        for (index, entry) in trash.entries().iter().enumerate().rev() {
+                            if let Some(action) = Self::show_entry(ui, index, entry, retention_days) {
+                                result = action;
+                            }
+                        }
+                    });
+            });
+
+        if !open {
+            state.close();
+        }
+
+        result
+    }
+
+    /// 单条记录
+    fn show_entry(ui: &mut egui::Ui, index: usize, entry: &TrashEntry, retention_days: u32) -> Option<TrashDialogResult> {
+        let mut action = None;
+        let (icon, detail) = match &entry.item {
+            TrashItem::Connection { config, .. } => ("🔌", config.db_type.display_name().to_string()),
+            TrashItem::Tab(tab) => {
+                let first_line = tab.sql.trim().lines().next().unwrap_or_default();
+                let preview: String = first_line.chars().take(PREVIEW_MAX_CHARS).collect();
+                ("📄", preview)
+            }
+        };
+        let days_left = (entry.deleted_at + chrono::Duration::days(i64::from(retention_days)) - Local::now()).num_days();
+
+        ui.horizontal(|ui| {
+            ui.label(icon);
+            ui.label(RichText::new(entry.item.label()).strong());
+            ui.label(RichText::new(detail).small().monospace().color(GRAY));
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui.small_button(RichText::new("✖").color(DANGER)).on_hover_text("永久删除").clicked() {
+                    action = Some(TrashDialogResult::Delete(index));
+                }
+                if ui.small_button("↩ 恢复").clicked() {
+                    action = Some(TrashDialogResult::Restore(index));
+                }
+                ui.label(
+                    RichText::new(format!("{} 天后清除", days_left.max(0)))
+                        .small()
+                        .color(MUTED),
+                )
+                .on_hover_text(format!("删除于 {}", entry.deleted_at.format("%Y-%m-%d %H:%M")));
+            });
+        });
+        action
+    }
+}
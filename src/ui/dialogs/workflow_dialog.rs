@@ -0,0 +1,480 @@
+//! 工作流面板
+//!
+//! 编辑保存在配置中的工作流（查询 → 导出 → 查询 ... 的步骤序列）并在后台运行，
+//! 显示每个步骤的执行状态。
+
+use super::keyboard;
+use crate::core::{t, tf, ExportFormat, PluginRegistry, StepStatus, Workflow, WorkflowRun, WorkflowStep};
+use egui::{self, Color32, RichText, TextEdit};
+
+// ============================================================================
+// 面板结果
+// ============================================================================
+
+/// 工作流面板的结果
+pub enum WorkflowResult {
+    /// 无操作
+    None,
+    /// 保存全部工作流
+    Save(Vec<Workflow>),
+    /// 运行工作流
+    Run(Workflow),
+}
+
+// ============================================================================
+// 面板状态
+// ============================================================================
+
+/// 工作流面板状态
+#[derive(Default)]
+pub struct WorkflowState {
+    /// 是否显示面板
+    pub show: bool,
+    /// 正在编辑的工作流（保存前不影响配置）
+    pub workflows: Vec<Workflow>,
+    /// 当前选中的工作流
+    pub selected: usize,
+    /// 可选的连接名
+    pub connections: Vec<String>,
+    /// 最近一次运行的进度
+    pub run: Option<WorkflowRun>,
+    /// 有未保存的修改
+    pub dirty: bool,
+    /// 错误信息
+    pub error: Option<String>,
+}
+
+impl WorkflowState {
+    /// 创建新的面板状态
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 打开面板，编辑配置中工作流的副本
+    pub fn open(&mut self, workflows: Vec<Workflow>, connections: Vec<String>) {
+        self.show = true;
+        self.workflows = workflows;
+        self.connections = connections;
+        self.selected = self.selected.min(self.workflows.len().saturating_sub(1));
+        self.dirty = false;
+        self.error = None;
+    }
+
+    /// 关闭面板
+    pub fn close(&mut self) {
+        self.show = false;
+        self.error = None;
+    }
+
+    /// 当前选中的工作流
+    pub fn selected_workflow(&self) -> Option<&Workflow> {
+        self.workflows.get(self.selected)
+    }
+
+    /// 当前是否有工作流在运行
+    pub fn is_running(&self) -> bool {
+        self.run.as_ref().is_some_and(|run| !run.is_finished())
+    }
+
+    /// 新建工作流并选中
+    pub fn add_workflow(&mut self) {
+        let name = (1..)
+            .map(|n| tf("workflow.default_name", &[&n.to_string()]))
+            .find(|name| !self.workflows.iter().any(|w| &w.name == name))
+            .unwrap_or_default();
+        self.workflows.push(Workflow::new(name));
+        self.selected = self.workflows.len() - 1;
+        self.dirty = true;
+    }
+
+    /// 删除选中的工作流
+    pub fn remove_selected(&mut self) {
+        if self.selected < self.workflows.len() {
+            self.workflows.remove(self.selected);
+            self.selected = self.selected.min(self.workflows.len().saturating_sub(1));
+            self.dirty = true;
+        }
+    }
+
+    /// 向选中的工作流末尾添加步骤
+    pub fn add_step(&mut self, step: WorkflowStep) {
+        if let Some(workflow) = self.workflows.get_mut(self.selected) {
+            workflow.steps.push(step);
+            self.dirty = true;
+        }
+    }
+
+    /// 上移（`up`）或下移步骤
+    pub fn move_step(&mut self, idx: usize, up: bool) {
+        let Some(workflow) = self.workflows.get_mut(self.selected) else {
+            return;
+        };
+        let target = if up { idx.checked_sub(1) } else { Some(idx + 1) };
+        if let Some(target) = target.filter(|&t| t < workflow.steps.len()) {
+            workflow.steps.swap(idx, target);
+            self.dirty = true;
+        }
+    }
+
+    /// 删除步骤
+    pub fn remove_step(&mut self, idx: usize) {
+        if let Some(workflow) = self.workflows.get_mut(self.selected)
+            && idx < workflow.steps.len()
+        {
+            workflow.steps.remove(idx);
+            self.dirty = true;
+        }
+    }
+
+    /// 校验工作流名称（不能为空、不能重复）
+    pub fn validate_names(&self) -> Result<(), String> {
+        for (i, workflow) in self.workflows.iter().enumerate() {
+            let name = workflow.name.trim();
+            if name.is_empty() {
+                return Err(t("workflow.name_required").to_string());
+            }
+            if self.workflows[..i].iter().any(|w| w.name.trim() == name) {
+                return Err(tf("workflow.duplicate_name", &[name]));
+            }
+        }
+        Ok(())
+    }
+
+    /// 确认保存：返回全部工作流，或记录错误
+    fn confirm_save(&mut self) -> Option<Vec<Workflow>> {
+        match self.validate_names() {
+            Ok(()) => {
+                self.error = None;
+                self.dirty = false;
+                Some(self.workflows.clone())
+            }
+            Err(e) => {
+                self.error = Some(e);
+                None
+            }
+        }
+    }
+
+    /// 确认运行：返回选中的工作流，或记录错误
+    fn confirm_run(&mut self) -> Option<Workflow> {
+        let workflow = self.selected_workflow()?.clone();
+        match workflow.validate() {
+            Ok(()) => {
+                self.error = None;
+                Some(workflow)
+            }
+            Err(e) => {
+                self.error = Some(e);
+                None
+            }
+        }
+    }
+}
+
+// ============================================================================
+// 面板 UI
+// ============================================================================
+
+/// 工作流面板
+pub struct WorkflowDialog;
+
+impl WorkflowDialog {
+    /// 显示面板
+    pub fn show(ctx: &egui::Context, state: &mut WorkflowState, plugins: &PluginRegistry) -> WorkflowResult {
+        if !state.show {
+            return WorkflowResult::None;
+        }
+
+        if !keyboard::has_text_focus(ctx) && keyboard::handle_close_keys(ctx) {
+            state.close();
+            return WorkflowResult::None;
+        }
+
+        let mut result = WorkflowResult::None;
+        let mut open = true;
+        egui::Window::new(t("workflow.title"))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(true)
+            .default_width(760.0)
+            .default_height(480.0)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.horizontal_top(|ui| {
+                    ui.vertical(|ui| {
+                        ui.set_width(180.0);
+                        Self::show_list(ui, state);
+                    });
+                    ui.separator();
+                    ui.vertical(|ui| {
+                        Self::show_editor(ui, state, plugins);
+                    });
+                });
+
+                if let Some(err) = &state.error {
+                    ui.add_space(4.0);
+                    ui.label(RichText::new(err).color(Color32::from_rgb(255, 100, 100)));
+                }
+
+                ui.separator();
+                ui.horizontal(|ui| {
+                    let save_text = if state.dirty { t("common.save_icon_changed") } else { t("common.save_icon") };
+                    if ui.button(save_text).clicked()
+                        && let Some(workflows) = state.confirm_save()
+                    {
+                        result = WorkflowResult::Save(workflows);
+                    }
+                    let can_run = state.selected_workflow().is_some() && !state.is_running();
+                    if ui
+                        .add_enabled(can_run, egui::Button::new(t("workflow.run")))
+                        .on_hover_text(t("workflow.run_hint"))
+                        .clicked()
+                        && let Some(workflow) = state.confirm_run()
+                    {
+                        result = WorkflowResult::Run(workflow);
+                    }
+                    ui.label(
+                        RichText::new(t("workflow.cli_hint"))
+                            .small()
+                            .color(Color32::GRAY),
+                    );
+                });
+
+                if let Some(run) = &state.run {
+                    ui.separator();
+                    Self::show_run(ui, run);
+                }
+            });
+
+        if !open {
+            state.close();
+        }
+
+        result
+    }
+
+    /// 工作流列表
+    fn show_list(ui: &mut egui::Ui, state: &mut WorkflowState) {
+        ui.horizontal(|ui| {
+            ui.label(RichText::new(t("workflow.list")).strong());
+            if ui.small_button("➕").on_hover_text(t("workflow.new")).clicked() {
+                state.add_workflow();
+            }
+            if ui
+                .add_enabled(state.selected_workflow().is_some(), egui::Button::new("🗑").small())
+                .on_hover_text(t("workflow.delete"))
+                .clicked()
+            {
+                state.remove_selected();
+            }
+        });
+        ui.add_space(4.0);
+
+        if state.workflows.is_empty() {
+            ui.label(RichText::new(t("workflow.empty")).color(Color32::GRAY));
+            return;
+        }
+        egui::ScrollArea::vertical()
+            .id_salt("workflow_list")
+            .max_height(320.0)
+            .show(ui, |ui| {
+                for (i, workflow) in state.workflows.iter().enumerate() {
+                    let text = format!("{} ({})", workflow.name, workflow.steps.len());
+                    if ui.selectable_label(i == state.selected, text).clicked() {
+                        state.selected = i;
+                    }
+                }
+            });
+    }
+
+    /// 选中工作流的步骤编辑
+    fn show_editor(ui: &mut egui::Ui, state: &mut WorkflowState, plugins: &PluginRegistry) {
+        let connections = state.connections.clone();
+        let selected = state.selected;
+        let Some(workflow) = state.workflows.get_mut(selected) else {
+            ui.label(RichText::new(t("workflow.start_hint")).color(Color32::GRAY));
+            return;
+        };
+
+        let mut changed = false;
+        let mut step_action: Option<(usize, StepAction)> = None;
+
+        ui.horizontal(|ui| {
+            ui.label(t("common.name"));
+            changed |= ui.add(TextEdit::singleline(&mut workflow.name).desired_width(200.0)).changed();
+            changed |= ui
+                .checkbox(&mut workflow.continue_on_error, t("workflow.continue_on_error"))
+                .on_hover_text(t("workflow.continue_on_error_hint"))
+                .changed();
+        });
+        ui.add_space(4.0);
+
+        egui::ScrollArea::vertical()
+            .id_salt("workflow_steps")
+            .max_height(300.0)
+            .show(ui, |ui| {
+                let count = workflow.steps.len();
+                for (i, step) in workflow.steps.iter_mut().enumerate() {
+                    egui::Frame::group(ui.style()).show(ui, |ui| {
+                        ui.horizontal(|ui| {
+                            ui.label(RichText::new(format!("{}.", i + 1)).strong());
+                            if ui.add_enabled(i > 0, egui::Button::new("⬆").small()).clicked() {
+                                step_action = Some((i, StepAction::Up));
+                            }
+                            if ui.add_enabled(i + 1 < count, egui::Button::new("⬇").small()).clicked() {
+                                step_action = Some((i, StepAction::Down));
+                            }
+                            if ui.small_button("🗑").on_hover_text(t("workflow.delete_step")).clicked() {
+                                step_action = Some((i, StepAction::Remove));
+                            }
+                        });
+                        changed |= Self::show_step(ui, i, step, &connections, plugins);
+                    });
+                }
+                if count == 0 {
+                    ui.label(RichText::new(t("workflow.no_steps")).color(Color32::GRAY));
+                }
+            });
+
+        if changed {
+            state.dirty = true;
+        }
+        if let Some((idx, action)) = step_action {
+            match action {
+                StepAction::Up => state.move_step(idx, true),
+                StepAction::Down => state.move_step(idx, false),
+                StepAction::Remove => state.remove_step(idx),
+            }
+        }
+
+        ui.horizontal(|ui| {
+            if ui.button(t("workflow.add_query")).clicked() {
+                let connection = connections.first().cloned().unwrap_or_default();
+                state.add_step(WorkflowStep::query(connection, ""));
+            }
+            if ui.button(t("workflow.add_export")).clicked() {
+                state.add_step(WorkflowStep::export(ExportFormat::Csv, "export_{date}.csv"));
+            }
+        });
+    }
+
+    /// 单个步骤的编辑控件，返回是否有修改
+    fn show_step(
+        ui: &mut egui::Ui,
+        idx: usize,
+        step: &mut WorkflowStep,
+        connections: &[String],
+        plugins: &PluginRegistry,
+    ) -> bool {
+        let mut changed = false;
+        match step {
+            WorkflowStep::Query { connection, sql } => {
+                ui.horizontal(|ui| {
+                    ui.label(t("workflow.query_connection"));
+                    egui::ComboBox::from_id_salt(("workflow_step_connection", idx))
+                        .selected_text(if connection.is_empty() { t("common.select_connection") } else { connection.as_str() })
+                        .show_ui(ui, |ui| {
+                            for name in connections {
+                                changed |= ui.selectable_value(connection, name.clone(), name).changed();
+                            }
+                        });
+                });
+                changed |= ui
+                    .add(
+                        TextEdit::multiline(sql)
+                            .code_editor()
+                            .desired_rows(3)
+                            .desired_width(f32::INFINITY)
+                            .hint_text("SELECT ..."),
+                    )
+                    .changed();
+            }
+            WorkflowStep::Export { format, path, table, plugin } => {
+                // 插件格式按 id 查找，未注册时仍显示 id，运行时报错
+                let exporter = plugin.as_deref().and_then(|id| plugins.exporter(id));
+                let (format_name, extension) = match (exporter, plugin.as_deref()) {
+                    (Some(exporter), _) => (exporter.name().to_string(), exporter.extension().to_string()),
+                    (None, Some(id)) => (id.to_string(), String::new()),
+                    (None, None) => (format.display_name().to_string(), format.extension().to_string()),
+                };
+                ui.horizontal(|ui| {
+                    ui.label(t("workflow.export_previous"));
+                    egui::ComboBox::from_id_salt(("workflow_step_format", idx))
+                        .selected_text(&format_name)
+                        .width(90.0)
+                        .show_ui(ui, |ui| {
+                            for f in [ExportFormat::Csv, ExportFormat::Json, ExportFormat::Sql] {
+                                if ui.selectable_label(plugin.is_none() && *format == f, f.display_name()).clicked() {
+                                    *format = f;
+                                    *plugin = None;
+                                    changed = true;
+                                }
+                            }
+                            for exporter in plugins.exporters() {
+                                if ui
+                                    .selectable_label(plugin.as_deref() == Some(exporter.id()), exporter.name())
+                                    .clicked()
+                                {
+                                    *plugin = Some(exporter.id().to_string());
+                                    changed = true;
+                                }
+                            }
+                        });
+                    changed |= ui
+                        .add(TextEdit::singleline(path).desired_width(260.0).hint_text(t("workflow.path")))
+                        .on_hover_text(t("workflow.path_hint"))
+                        .changed();
+                    if ui.small_button("📂").on_hover_text(t("workflow.choose_path")).clicked()
+                        && let Some(picked) = rfd::FileDialog::new()
+                            .add_filter(&format_name, &[extension.as_str()])
+                            .save_file()
+                    {
+                        *path = picked.display().to_string();
+                        changed = true;
+                    }
+                    if plugin.is_none() && *format == ExportFormat::Sql {
+                        changed |= ui
+                            .add(TextEdit::singleline(table).desired_width(100.0).hint_text(t("workflow.table")))
+                            .changed();
+                    }
+                });
+            }
+        }
+        changed
+    }
+
+    /// 最近一次运行的步骤状态
+    fn show_run(ui: &mut egui::Ui, run: &WorkflowRun) {
+        ui.label(
+            RichText::new(tf(
+                "workflow.run_progress",
+                &[&run.workflow.name, &run.finished_count().to_string(), &run.statuses.len().to_string()],
+            ))
+            .strong(),
+        );
+        for (i, (step, status)) in run.workflow.steps.iter().zip(&run.statuses).enumerate() {
+            let (icon, detail, color) = match status {
+                StepStatus::Pending => ("○", String::new(), Color32::GRAY),
+                StepStatus::Running => ("⏳", t("common.running").to_string(), Color32::from_rgb(100, 160, 230)),
+                StepStatus::Done(msg) => ("✔", msg.clone(), Color32::from_rgb(100, 200, 120)),
+                StepStatus::Failed(e) => ("✖", e.clone(), Color32::from_rgb(255, 100, 100)),
+                StepStatus::Skipped => ("↷", t("workflow.skipped").to_string(), Color32::GRAY),
+            };
+            ui.horizontal(|ui| {
+                ui.label(RichText::new(icon).color(color));
+                let desc: String = step.describe().chars().take(60).collect();
+                ui.label(RichText::new(format!("{}. {}", i + 1, desc)).small());
+                if !detail.is_empty() {
+                    ui.label(RichText::new(detail).small().color(color));
+                }
+            });
+        }
+    }
+}
+
+/// 步骤上的按钮操作
+enum StepAction {
+    Up,
+    Down,
+    Remove,
+}
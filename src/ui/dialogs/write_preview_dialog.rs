@@ -0,0 +1,251 @@
+//! 影响行预览窗口
+//!
+//! 执行 UPDATE/DELETE 前先运行条件相同的 SELECT，显示将被修改的行及行数，
+//! 确认后再执行原语句。
+
+use super::keyboard;
+use crate::core::{WriteKind, WritePreview};
+use crate::database::QueryResult;
+use egui::{self, Color32, RichText, TextEdit};
+
+/// 窗口中最多显示的行数（行数统计仍按全部结果）
+pub const WRITE_PREVIEW_ROW_LIMIT: usize = 200;
+
+/// 单元格最多显示的字符数
+const CELL_DISPLAY_CHARS: usize = 60;
+
+// ============================================================================
+// 窗口结果
+// ============================================================================
+
+/// 影响行预览窗口的结果
+pub enum WritePreviewResult {
+    /// 无操作
+    None,
+    /// 确认执行原语句
+    Execute(String),
+}
+
+// ============================================================================
+// 窗口状态
+// ============================================================================
+
+/// 影响行预览窗口状态
+#[derive(Default)]
+pub struct WritePreviewState {
+    /// 是否显示窗口
+    pub show: bool,
+    /// 原 UPDATE/DELETE 语句
+    pub sql: String,
+    /// 改写得到的预览查询
+    pub preview: Option<WritePreview>,
+    /// 是否正在加载
+    pub loading: bool,
+    /// 预览查询的结果
+    pub result: Option<QueryResult>,
+    /// 错误信息
+    pub error: Option<String>,
+}
+
+impl WritePreviewState {
+    /// 创建新的窗口状态
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 打开窗口并进入加载状态（预览查询由调用方异步执行）
+    pub fn open(&mut self, sql: String, preview: WritePreview) {
+        self.show = true;
+        self.sql = sql;
+        self.preview = Some(preview);
+        self.result = None;
+        self.error = None;
+        self.loading = true;
+    }
+
+    /// 关闭窗口
+    pub fn close(&mut self) {
+        self.show = false;
+        self.loading = false;
+        self.preview = None;
+        self.result = None;
+        self.error = None;
+    }
+
+    /// 设置预览查询的结果（忽略已切换到其他语句之后返回的旧结果）
+    pub fn set_result(&mut self, select_sql: &str, result: Result<QueryResult, String>) {
+        if !self.show || self.preview.as_ref().is_none_or(|p| p.select_sql != select_sql) {
+            return;
+        }
+        self.loading = false;
+        match result {
+            Ok(res) => {
+                self.result = Some(res);
+                self.error = None;
+            }
+            Err(e) => {
+                self.result = None;
+                self.error = Some(e);
+            }
+        }
+    }
+
+    /// 将被修改的行数（预览完成前为 `None`）
+    pub fn affected_rows(&self) -> Option<usize> {
+        self.result.as_ref().map(|res| res.original_row_count.unwrap_or(res.rows.len()))
+    }
+
+    /// 预览成功后才允许执行
+    pub fn can_execute(&self) -> bool {
+        !self.loading && self.error.is_none() && self.result.is_some()
+    }
+}
+
+// ============================================================================
+// 窗口 UI
+// ============================================================================
+
+/// 影响行预览窗口
+pub struct WritePreviewDialog;
+
+impl WritePreviewDialog {
+    /// 显示窗口
+    pub fn show(ctx: &egui::Context, state: &mut WritePreviewState) -> WritePreviewResult {
+        if !state.show {
+            return WritePreviewResult::None;
+        }
+        let Some(preview) = state.preview.clone() else {
+            state.close();
+            return WritePreviewResult::None;
+        };
+
+        let mut result = WritePreviewResult::None;
+        let mut should_close = false;
+
+        // 键盘快捷键处理
+        if !keyboard::has_text_focus(ctx) {
+            match keyboard::handle_dialog_keys(ctx) {
+                keyboard::DialogAction::Cancel => {
+                    state.close();
+                    return WritePreviewResult::None;
+                }
+                keyboard::DialogAction::Confirm if state.can_execute() => {
+                    result = WritePreviewResult::Execute(state.sql.clone());
+                    should_close = true;
+                }
+                _ => {}
+            }
+        }
+
+        let verb = match preview.kind {
+            WriteKind::Update => "更新",
+            WriteKind::Delete => "删除",
+        };
+
+        let mut open = true;
+        egui::Window::new("影响行预览")
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(true)
+            .default_size([720.0, 460.0])
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    if state.loading {
+                        ui.spinner();
+                        ui.label("正在查询将被修改的行...");
+                    } else if let Some(count) = state.affected_rows() {
+                        ui.label(RichText::new(format!("将{} {} 行", verb, count)).strong());
+                    }
+                });
+
+                if !preview.has_where {
+                    ui.label(
+                        RichText::new(format!("警告: 语句没有 WHERE 条件，将{}表中的所有行", verb))
+                            .small()
+                            .color(Color32::from_rgb(230, 180, 80)),
+                    );
+                }
+
+                ui.add_space(4.0);
+                ui.collapsing("预览查询", |ui| {
+                    ui.add(
+                        TextEdit::multiline(&mut preview.select_sql.as_str())
+                            .code_editor()
+                            .desired_width(f32::INFINITY)
+                            .desired_rows(2),
+                    );
+                });
+                ui.separator();
+
+                if let Some(err) = &state.error {
+                    ui.label(RichText::new(err).color(Color32::from_rgb(255, 100, 100)));
+                } else if let Some(res) = &state.result {
+                    Self::show_rows(ui, res);
+                }
+
+                ui.add_space(8.0);
+                ui.horizontal(|ui| {
+                    if ui
+                        .add_enabled(state.can_execute(), egui::Button::new(format!("确认{} [Enter]", verb)))
+                        .clicked()
+                    {
+                        result = WritePreviewResult::Execute(state.sql.clone());
+                        should_close = true;
+                    }
+                    if ui.button("取消 [Esc]").clicked() {
+                        should_close = true;
+                    }
+                });
+            });
+
+        if !open || should_close {
+            state.close();
+        }
+
+        result
+    }
+
+    /// 将被修改的行（最多显示 `WRITE_PREVIEW_ROW_LIMIT` 行）
+    fn show_rows(ui: &mut egui::Ui, res: &QueryResult) {
+        if res.rows.is_empty() {
+            ui.label(RichText::new("没有符合条件的行").color(Color32::GRAY));
+            return;
+        }
+
+        egui::ScrollArea::both()
+            .id_salt("write_preview_scroll")
+            .max_height(ui.available_height() - 48.0)
+            .show(ui, |ui| {
+                egui::Grid::new("write_preview_grid")
+                    .striped(true)
+                    .spacing([16.0, 4.0])
+                    .show(ui, |ui| {
+                        for column in &res.columns {
+                            ui.label(RichText::new(column).strong());
+                        }
+                        ui.end_row();
+
+                        for row in res.rows.iter().take(WRITE_PREVIEW_ROW_LIMIT) {
+                            for cell in row {
+                                let text = if cell.chars().count() > CELL_DISPLAY_CHARS {
+                                    format!("{}…", cell.chars().take(CELL_DISPLAY_CHARS).collect::<String>())
+                                } else {
+                                    cell.clone()
+                                };
+                                ui.label(RichText::new(text).monospace());
+                            }
+                            ui.end_row();
+                        }
+                    });
+            });
+
+        if res.rows.len() > WRITE_PREVIEW_ROW_LIMIT {
+            ui.label(
+                RichText::new(format!("仅显示前 {} 行", WRITE_PREVIEW_ROW_LIMIT))
+                    .small()
+                    .color(Color32::GRAY),
+            );
+        }
+    }
+}
@@ -9,16 +9,26 @@ pub mod styles;
 #[allow(unused_imports)] // 公开 API，供外部使用
 pub use components::{
     // 数据表格相关
-    check_filter_match, escape_identifier, escape_value,
-    filter_rows_cached, quote_identifier, ColumnFilter, DataGrid,
-    DataGridState, FilterCache, FilterLogic, FilterOperator, FocusTransfer,
+    check_filter_match, escape_identifier, escape_value, facet_selection, filters_to_where, generate_save_sql, is_facet_selectable, set_facet_value,
+    filter_rows_background, filter_rows_cached, sort_indices, format_number, quote_identifier, quote_qualified_identifier, Aggregate, BlobViewer, ColumnStats, ColumnFilter, ColumnSort, DataGrid, GeometryViewer,
+    DataGridActions, DataGridState, DedupDelete, DuplicateGroups, FilterCache, FilterLogic, FilterOperator, FocusTransfer, GridMode, JsonColumns, JsonViewer, ResultPage, NumericStats, SearchMatcher, SearchOptions, ValueFacet,
+    // 列名补全和结果搜索
+    ColumnPicker, SearchBar,
     // 其他组件
-    EditorMode, SqlEditor, SqlEditorActions, Toolbar, ToolbarActions, ToolbarFocusTransfer, Welcome,
+    EditorMode, SqlEditor, SqlEditorActions, SqlErrorMarker, Toolbar, ToolbarActions, ToolbarFocusTransfer, Welcome,
+    // 按键提示栏
+    HintBar, HintContext, KeyHint,
+    // SQL 编辑器命令行
+    complete_editor_command, parse_editor_command, CommandLine, CommandLineResult, CommandLineState, EditorCommand,
     // 多 Tab 查询
-    QueryTab, QueryTabBar, QueryTabManager, TabBarActions, TabBarFocusTransfer,
+    QueryTab, QueryTabBar, QueryTabManager, ResultSnapshot, TabBarActions, TabBarFocusTransfer,
+    DetachedTabAction, DetachedTabWindow,
+    // 新手引导
+    TourAction, TourOverlay,
     // ER 关系图
     er_diagram::{ERColumn, ERDiagramState, ERTable, Relationship, RelationType, ERDiagramResponse,
-                 calculate_table_size, force_directed_layout, grid_layout},
+                 ERExportFormat, calculate_table_size, force_directed_layout, grid_layout, hierarchical_layout,
+                 to_mermaid, to_plantuml, to_svg},
     // 通知组件
     NotificationToast,
     // 进度指示器
@@ -27,18 +37,88 @@ pub use components::{
 #[allow(unused_imports)] // 公开 API，供外部使用
 pub use dialogs::{
     // DDL 对话框
-    ColumnDefinition, ColumnType, DdlDialog, DdlDialogState, TableDefinition,
+    ColumnDefinition, ColumnType, DdlDialog, DdlDialogResult, DdlDialogState, ForeignKeyDefinition, TableDefinition,
+    supports_alter_foreign_key, supports_foreign_keys,
     // 新建数据库/用户对话框
     CreateDbDialog, CreateDbDialogResult, CreateDbDialogState,
     CreateUserDialog, CreateUserDialogResult, CreateUserDialogState,
+    // 修改加密密钥对话框
+    ChangeKeyDialog, ChangeKeyDialogResult, ChangeKeyDialogState,
     // 其他对话框
     AboutDialog, ConfirmDialog, ConnectionDialog, ExportConfig, ExportDialog, HelpDialog,
     // 导入对话框
     parse_sql_file, ImportAction, ImportDialog, ImportFormat, ImportPreview, ImportState,
     // 快捷键设置对话框
     KeyBindingsDialog, KeyBindingsDialogState,
+    // 触发器/存储过程/函数编辑对话框
+    drop_object_sql, DbObjectKind, ObjectDdlDialog, ObjectDdlDialogResult, ObjectDdlDialogState,
+    // 设置序列下一个值对话框
+    SequenceDialog, SequenceDialogResult, SequenceDialogState,
+    // 权限管理对话框
+    database_privileges, table_privileges, PrivilegeDialog, PrivilegeDialogResult, PrivilegeDialogState,
+    // 全库数据搜索窗口
+    DataSearchDialog, DataSearchHit, DataSearchOutcome, DataSearchResult, DataSearchState, DataSearchTable,
+    // 大小概览窗口
+    format_bytes, SizeOverviewDialog, SizeOverviewResult, SizeOverviewState, SizeSortColumn,
+    // 会话监控窗口
+    duration_color, format_duration, SessionMonitorDialog, SessionMonitorResult, SessionMonitorState,
+    // 建表语句窗口
+    TableDdlDialog, TableDdlResult, TableDdlState,
+    // 表操作对话框
+    drop_table_sql, duplicate_table_sql, rename_table_sql, truncate_table_sql, TableAction, TableActionDialog, TableActionResult, TableActionState,
+    // 测试数据生成对话框
+    insert_rows_sql, DataGenColumn, DataGenDialog, DataGenPlan, DataGenResult, DataGenState, DATAGEN_BATCH_SIZE,
+    // 定时查询对话框
+    SchedulerDialog, SchedulerResult, SchedulerState,
+    // 结果对比窗口
+    diff_color, ResultDiffDialog, ResultDiffResult, ResultDiffState,
+    // SQL 对比窗口
+    SqlDiffDialog, SqlDiffResult, SqlDiffState,
+    // 表格保存 SQL 检查窗口
+    SqlReviewDialog, SqlReviewResult, SqlReviewState, StatementCounts,
+    // 审计日志窗口
+    AuditLogDialog, AuditLogResult, AuditLogState,
+    // 主题编辑器
+    ThemeEditorDialog, ThemeEditorResult, ThemeEditorState,
+    // 字体设置
+    FontSettingsDialog, FontSettingsResult, FontSettingsState,
+    // 首选项
+    PreferencesDialog, PreferencesResult, PreferencesState, PreferencesTab,
+    // 命令面板
+    CommandPalette, CommandPaletteState, PaletteCommand, PaletteItem,
+    // 文件外部修改提示
+    FileChangeDialog, FileChangeResult, FileChangeState,
+    // 工作流面板
+    WorkflowDialog, WorkflowResult, WorkflowState,
+    // API 服务面板
+    ApiServerDialog, ApiServerResult, ApiServerState,
+    // 通知中心
+    NotificationCenterDialog, NotificationCenterResult, NotificationCenterState,
+    // 日志查看器
+    LogViewerDialog, LogViewerState,
+    // 连接池诊断
+    PoolStatsDialog, PoolStatsResult, PoolStatsState,
+    // 回收站
+    TrashDialog, TrashDialogResult, TrashDialogState,
+    // 主密码
+    MasterPasswordDialog, MasterPasswordMode, MasterPasswordResult, MasterPasswordState,
+    // 基准测试
+    BenchmarkDialog, BenchmarkResult, BenchmarkState,
+    // 查询分析
+    ProfileDialog, ProfileResult, ProfileState,
+    // 依赖关系
+    DependencyData, DependencyDialog, DependencyResult, DependencyState,
+    // 行书签
+    RowBookmarkDialog, RowBookmarkResult, RowBookmarkState,
+    // 透视表
+    PivotDialog, PivotResult, PivotState,
+    // 影响行预览
+    WritePreviewDialog, WritePreviewResult, WritePreviewState, WRITE_PREVIEW_ROW_LIMIT,
+    // 批量修改
+    BulkEditDialog, BulkEditResult, BulkEditState, BulkValueKind,
 };
-pub use panels::{HistoryPanel, HistoryPanelState, Sidebar, SidebarActions, SidebarFocusTransfer, SidebarPanelState};
+#[allow(unused_imports)] // 公开 API，供外部使用
+pub use panels::{filter_indices, HistoryPanel, HistoryPanelAction, HistoryPanelState, MaintenanceTarget, Sidebar, SidebarActions, SidebarFocusTransfer, SidebarPanelState};
 
 /// 全局焦点区域
 /// 
@@ -63,8 +143,8 @@ pub enum FocusArea {
 
 /// 侧边栏焦点子区域
 /// 
-/// 用于 Ctrl+1/2/3/4/5/6 快捷键切换侧边栏不同区域的焦点
-/// 顺序：连接 -> 数据库 -> 表 -> 筛选 -> 触发器 -> 存储过程
+/// 用于 Ctrl+1~7 快捷键切换侧边栏不同区域的焦点
+/// 顺序：连接 -> 数据库 -> 表 -> 筛选 -> 触发器 -> 存储过程 -> 序列
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum SidebarSection {
     /// 1. 连接列表
@@ -80,4 +160,6 @@ pub enum SidebarSection {
     Triggers,
     /// 6. 存储过程/函数列表
     Routines,
+    /// 7. 序列/自增计数器列表
+    Sequences,
 }
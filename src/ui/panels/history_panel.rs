@@ -1,11 +1,41 @@
-use crate::core::QueryHistory;
+use crate::core::{constants, HistoryFilter, QueryHistory};
 use crate::ui::dialogs::keyboard;
 use crate::ui::styles::{DANGER, GRAY, SUCCESS};
-use egui::{self, Key, RichText};
+use egui::{self, Color32, Key, RichText};
+
+const FAVORITE_COLOR: Color32 = Color32::from_rgb(230, 180, 80);
+
+/// 历史面板的操作
+#[derive(Debug, Clone, PartialEq)]
+pub enum HistoryPanelAction {
+    /// 使用选中的 SQL
+    Use(String),
+    /// 立即重新执行
+    Run(String),
+    /// 在新标签页中打开
+    OpenInNewTab(String),
+    /// 与当前编辑器中的语句对比（历史记录索引）
+    DiffWithEditor(usize),
+    /// 切换收藏状态（历史记录索引）
+    ToggleFavorite(usize),
+    /// 删除记录（历史记录索引）
+    Remove(usize),
+    /// 清空历史（保留收藏）
+    Clear,
+    /// 修改保留条数
+    SetLimit(usize),
+}
 
 #[derive(Default)]
 pub struct HistoryPanelState {
+    /// 当前选中项在筛选结果中的位置
     pub selected_index: usize,
+    /// 筛选条件
+    pub filter: HistoryFilter,
+    /// 正在编辑的保留条数
+    limit_edit: Option<usize>,
+    /// 下一帧聚焦搜索框
+    focus_search: bool,
 }
 
 pub struct HistoryPanel;
@@ -15,29 +45,38 @@ impl HistoryPanel {
         ctx: &egui::Context,
         show: &mut bool,
         history: &QueryHistory,
-        selected_sql: &mut Option<String>,
-        clear_history: &mut bool,
         state: &mut HistoryPanelState,
-    ) {
+    ) -> Option<HistoryPanelAction> {
         if !*show {
-            return;
+            return None;
+        }
+
+        let mut action = None;
+        let visible = history.search(&state.filter);
+        if !visible.is_empty() {
+            state.selected_index = state.selected_index.min(visible.len() - 1);
         }
 
         // Helix 键盘导航
         if !keyboard::has_text_focus(ctx) {
-            let len = history.len();
-            
+            let len = visible.len();
+
             // Esc/q 关闭
             if keyboard::handle_close_keys(ctx) {
                 *show = false;
-                return;
+                return None;
             }
-            
+
             // Ctrl+Delete 清空历史
             if ctx.input(|i| i.modifiers.ctrl && i.key_pressed(Key::Delete)) {
-                *clear_history = true;
+                action = Some(HistoryPanelAction::Clear);
             }
-            
+
+            // / 搜索
+            if ctx.input(|i| i.key_pressed(Key::Slash)) {
+                state.focus_search = true;
+            }
+
             if len > 0 {
                 // j/k 或 ↑/↓ 导航
                 if ctx.input(|i| i.key_pressed(Key::J) || i.key_pressed(Key::ArrowDown)) {
@@ -46,7 +85,7 @@ impl HistoryPanel {
                 if ctx.input(|i| i.key_pressed(Key::K) || i.key_pressed(Key::ArrowUp)) {
                     state.selected_index = state.selected_index.saturating_sub(1);
                 }
-                
+
                 // g/G 跳转到首/尾
                 if ctx.input(|i| i.key_pressed(Key::G) && !i.modifiers.shift) {
                     state.selected_index = 0;
@@ -54,7 +93,7 @@ impl HistoryPanel {
                 if ctx.input(|i| i.key_pressed(Key::G) && i.modifiers.shift) {
                     state.selected_index = len - 1;
                 }
-                
+
                 // Ctrl+u/d 翻页
                 if ctx.input(|i| i.modifiers.ctrl && i.key_pressed(Key::U)) {
                     state.selected_index = state.selected_index.saturating_sub(10);
@@ -62,37 +101,115 @@ impl HistoryPanel {
                 if ctx.input(|i| i.modifiers.ctrl && i.key_pressed(Key::D)) {
                     state.selected_index = (state.selected_index + 10).min(len - 1);
                 }
-                
+
+                let selected = visible[state.selected_index];
+                let selected_sql = || history.items()[selected].sql.clone();
+
+                // r 重新执行，t 在新标签页打开，y 复制，c 与编辑器对比
+                if ctx.input(|i| i.key_pressed(Key::R)) {
+                    *show = false;
+                    return Some(HistoryPanelAction::Run(selected_sql()));
+                }
+                if ctx.input(|i| i.key_pressed(Key::T)) {
+                    *show = false;
+                    return Some(HistoryPanelAction::OpenInNewTab(selected_sql()));
+                }
+                if ctx.input(|i| i.key_pressed(Key::Y)) {
+                    ctx.copy_text(selected_sql());
+                }
+                if ctx.input(|i| i.key_pressed(Key::C)) {
+                    action = Some(HistoryPanelAction::DiffWithEditor(selected));
+                }
+
+                // f 收藏/取消收藏
+                if ctx.input(|i| i.key_pressed(Key::F)) {
+                    action = Some(HistoryPanelAction::ToggleFavorite(selected));
+                }
+
+                // Delete 删除当前项
+                if ctx.input(|i| !i.modifiers.ctrl && i.key_pressed(Key::Delete)) {
+                    action = Some(HistoryPanelAction::Remove(selected));
+                }
+
                 // Enter/l 选择当前项
-                if ctx.input(|i| i.key_pressed(Key::Enter) || i.key_pressed(Key::L))
-                    && let Some(item) = history.items().get(state.selected_index) {
-                        *selected_sql = Some(item.sql.clone());
-                        *show = false;
-                        return;
-                    }
+                if ctx.input(|i| i.key_pressed(Key::Enter) || i.key_pressed(Key::L)) {
+                    *show = false;
+                    return Some(HistoryPanelAction::Use(selected_sql()));
+                }
             }
         }
 
-        egui::Window::new("查询历史 [j/k 导航, Enter 选择, Esc 关闭]")
+        egui::Window::new("查询历史 [j/k 导航, / 搜索, f 收藏, r 执行, t 新标签, c 对比, Enter 选择, Esc 关闭]")
             .collapsible(true)
             .resizable(true)
-            .default_size([500.0, 400.0])
+            .default_size([560.0, 440.0])
             .show(ctx, |ui| {
                 ui.horizontal(|ui| {
-                    ui.label(format!("{} 条记录", history.len()));
+                    if state.filter.is_empty() {
+                        ui.label(format!("{} 条记录", history.len()));
+                    } else {
+                        ui.label(format!("{} / {} 条记录", visible.len(), history.len()));
+                    }
                     ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                         if ui.button("关闭 [Esc]").clicked() {
                             *show = false;
                         }
                         if ui
                             .add_enabled(!history.is_empty(), egui::Button::new("清空 [Ctrl+Del]"))
+                            .on_hover_text("收藏的记录会保留")
                             .clicked()
                         {
-                            *clear_history = true;
+                            action = Some(HistoryPanelAction::Clear);
                         }
+
+                        // 保留条数：拖动结束或输入完成后生效
+                        let mut limit = state.limit_edit.unwrap_or(history.max_size());
+                        let response = ui
+                            .add(egui::DragValue::new(&mut limit).range(constants::history::QUERY_HISTORY_LIMIT_RANGE))
+                            .on_hover_text("保留的历史条数（不含收藏）");
+                        if response.changed() {
+                            state.limit_edit = Some(limit);
+                        }
+                        if (response.drag_stopped() || response.lost_focus())
+                            && let Some(limit) = state.limit_edit.take()
+                            && limit != history.max_size()
+                        {
+                            action = Some(HistoryPanelAction::SetLimit(limit));
+                        }
+                        ui.label("保留:");
                     });
                 });
 
+                // 搜索与筛选
+                ui.horizontal(|ui| {
+                    let search = ui.add(
+                        egui::TextEdit::singleline(&mut state.filter.text)
+                            .hint_text("🔍 搜索 SQL [/]")
+                            .desired_width(220.0),
+                    );
+                    if state.focus_search {
+                        search.request_focus();
+                        state.focus_search = false;
+                    }
+                    if search.changed() {
+                        state.selected_index = 0;
+                    }
+
+                    let connection_text = state.filter.connection.clone().unwrap_or_else(|| "全部连接".to_string());
+                    egui::ComboBox::from_id_salt("history_connection_filter")
+                        .selected_text(connection_text)
+                        .width(140.0)
+                        .show_ui(ui, |ui| {
+                            ui.selectable_value(&mut state.filter.connection, None, "全部连接");
+                            for connection in history.connections() {
+                                let text = connection.clone();
+                                ui.selectable_value(&mut state.filter.connection, Some(connection), text);
+                            }
+                        });
+
+                    ui.toggle_value(&mut state.filter.favorites_only, "★ 收藏");
+                });
+
                 ui.separator();
 
                 if history.is_empty() {
@@ -102,16 +219,24 @@ impl HistoryPanel {
                     });
                     return;
                 }
+                if visible.is_empty() {
+                    ui.vertical_centered(|ui| {
+                        ui.add_space(20.0);
+                        ui.label(RichText::new("没有符合条件的记录").italics().color(GRAY));
+                    });
+                    return;
+                }
 
                 egui::ScrollArea::vertical().show(ui, |ui| {
-                    for (idx, item) in history.items().iter().enumerate() {
-                        let is_selected = idx == state.selected_index;
+                    for (pos, &idx) in visible.iter().enumerate() {
+                        let item = &history.items()[idx];
+                        let is_selected = pos == state.selected_index;
                         let bg_color = if is_selected {
                             ui.visuals().selection.bg_fill
                         } else {
                             ui.visuals().extreme_bg_color
                         };
-                        
+
                         let frame = egui::Frame::NONE
                             .inner_margin(8.0)
                             .corner_radius(4.0)
@@ -119,6 +244,16 @@ impl HistoryPanel {
 
                         let response = frame.show(ui, |ui| {
                             ui.horizontal(|ui| {
+                                // 收藏
+                                let (star, tooltip) = if item.favorite {
+                                    (RichText::new("★").color(FAVORITE_COLOR), "取消收藏 [f]")
+                                } else {
+                                    (RichText::new("☆").color(GRAY), "收藏 [f]")
+                                };
+                                if ui.add(egui::Button::new(star).frame(false)).on_hover_text(tooltip).clicked() {
+                                    action = Some(HistoryPanelAction::ToggleFavorite(idx));
+                                }
+
                                 // 状态图标 - 使用图标+文字双重指示，对色盲友好
                                 if item.success {
                                     ui.colored_label(SUCCESS, "[OK] 成功");
@@ -128,28 +263,63 @@ impl HistoryPanel {
 
                                 ui.separator();
 
-                                // 数据库类型
+                                // 连接与数据库类型
+                                if let Some(connection) = &item.connection {
+                                    ui.label(RichText::new(connection).small().strong());
+                                }
                                 ui.label(RichText::new(&item.database_type).small());
 
                                 ui.separator();
 
                                 // 时间戳
                                 ui.label(
-                                    RichText::new(item.timestamp.format("%H:%M:%S").to_string())
+                                    RichText::new(item.timestamp.format("%m-%d %H:%M:%S").to_string())
                                         .small()
                                         .color(GRAY),
                                 );
 
-                                // 影响行数
-                                if let Some(rows) = item.rows_affected {
+                                // 返回/影响行数与耗时
+                                if let Some(rows) = item.row_count {
+                                    ui.separator();
+                                    ui.label(RichText::new(format!("返回 {} 行", rows)).small());
+                                } else if let Some(rows) = item.rows_affected {
                                     ui.separator();
-                                    ui.label(RichText::new(format!("{} 行", rows)).small());
+                                    ui.label(RichText::new(format!("影响 {} 行", rows)).small());
                                 }
+                                if let Some(ms) = item.elapsed_ms {
+                                    ui.label(RichText::new(format!("{}ms", ms)).small().color(GRAY));
+                                }
+
+                                // 连续执行次数
+                                if item.run_count > 1 {
+                                    ui.label(RichText::new(format!("×{}", item.run_count)).small().color(GRAY))
+                                        .on_hover_text(format!("连续执行 {} 次", item.run_count));
+                                }
+
+                                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                    if ui.small_button("🗑").on_hover_text("删除 [Delete]").clicked() {
+                                        action = Some(HistoryPanelAction::Remove(idx));
+                                    }
+                                    if ui.small_button("🔀").on_hover_text("与编辑器对比 [c]").clicked() {
+                                        action = Some(HistoryPanelAction::DiffWithEditor(idx));
+                                    }
+                                    if ui.small_button("📋").on_hover_text("复制 [y]").clicked() {
+                                        ui.ctx().copy_text(item.sql.clone());
+                                    }
+                                    if ui.small_button("➕").on_hover_text("在新标签页打开 [t]").clicked() {
+                                        action = Some(HistoryPanelAction::OpenInNewTab(item.sql.clone()));
+                                        *show = false;
+                                    }
+                                    if ui.small_button("▶").on_hover_text("重新执行 [r]").clicked() {
+                                        action = Some(HistoryPanelAction::Run(item.sql.clone()));
+                                        *show = false;
+                                    }
+                                });
                             });
 
                             // SQL 预览
-                            let sql_preview = if item.sql.len() > 100 {
-                                format!("{}...", &item.sql[..100])
+                            let sql_preview = if item.sql.chars().count() > 100 {
+                                format!("{}...", item.sql.chars().take(100).collect::<String>())
                             } else {
                                 item.sql.clone()
                             };
@@ -163,7 +333,7 @@ impl HistoryPanel {
                             );
 
                             if response.clicked() {
-                                *selected_sql = Some(item.sql.clone());
+                                action = Some(HistoryPanelAction::Use(item.sql.clone()));
                                 *show = false;
                             }
 
@@ -173,23 +343,25 @@ impl HistoryPanel {
 
                             response.on_hover_text("点击使用此查询");
                         });
-                        
+
                         // 点击整个条目也可以选择
                         if response.response.clicked() {
-                            state.selected_index = idx;
+                            state.selected_index = pos;
                         }
-                        
+
                         // 双击执行
                         if response.response.double_clicked() {
-                            *selected_sql = Some(item.sql.clone());
+                            action = Some(HistoryPanelAction::Use(item.sql.clone()));
                             *show = false;
                         }
 
-                        if idx < history.len() - 1 {
+                        if pos < visible.len() - 1 {
                             ui.add_space(4.0);
                         }
                     }
                 });
             });
+
+        action
     }
 }
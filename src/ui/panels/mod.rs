@@ -3,5 +3,5 @@
 mod history_panel;
 mod sidebar;
 
-pub use history_panel::{HistoryPanel, HistoryPanelState};
-pub use sidebar::{Sidebar, SidebarActions, SidebarFocusTransfer, SidebarPanelState};
+pub use history_panel::{HistoryPanel, HistoryPanelAction, HistoryPanelState};
+pub use sidebar::{filter_indices, MaintenanceTarget, Sidebar, SidebarActions, SidebarFocusTransfer, SidebarPanelState};
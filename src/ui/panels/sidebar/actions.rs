@@ -1,6 +1,7 @@
 //! 侧边栏操作和事件定义
 
-use crate::ui::SidebarSection;
+use crate::database::{MaintenanceOp, RoutineInfo, SequenceInfo, TriggerInfo};
+use crate::ui::{DbObjectKind, SidebarSection};
 
 /// 焦点转移方向（从侧边栏转出）
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -9,6 +10,15 @@ pub enum SidebarFocusTransfer {
     ToDataGrid,
 }
 
+/// 维护操作的对象
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MaintenanceTarget {
+    /// 整个数据库（SQLite 为 schema 名）
+    Database(String),
+    /// 单张表
+    Table(String),
+}
+
 /// 侧边栏操作
 #[derive(Default)]
 pub struct SidebarActions {
@@ -18,20 +28,62 @@ pub struct SidebarActions {
     pub select_database: Option<String>,
     pub show_table_schema: Option<String>,
     pub query_table: Option<String>,
+    /// 查看表的建表语句
+    pub show_table_ddl: Option<String>,
+    /// 查看表的依赖关系
+    pub show_dependencies: Option<String>,
+    /// 删除表（打开确认对话框）
+    pub drop_table: Option<String>,
+    /// 清空表（打开确认对话框）
+    pub truncate_table: Option<String>,
+    /// 复制表（打开复制对话框）
+    pub duplicate_table: Option<String>,
+    /// 为表生成测试数据（打开数据生成对话框）
+    pub generate_test_data: Option<String>,
     /// 在 SQL 编辑器中显示触发器定义
     pub show_trigger_definition: Option<String>,
     /// 在 SQL 编辑器中显示存储过程/函数定义
     pub show_routine_definition: Option<String>,
+    /// 新建触发器/存储过程/函数
+    pub create_object: Option<DbObjectKind>,
+    /// 编辑触发器
+    pub edit_trigger: Option<TriggerInfo>,
+    /// 删除触发器（需确认）
+    pub drop_trigger: Option<TriggerInfo>,
+    /// 编辑存储过程/函数
+    pub edit_routine: Option<RoutineInfo>,
+    /// 删除存储过程/函数（需确认）
+    pub drop_routine: Option<RoutineInfo>,
+    /// 修改序列/自增计数器的下一个值（打开设置对话框）
+    pub restart_sequence: Option<SequenceInfo>,
+    /// 打开当前连接的表大小概览
+    pub show_size_overview: bool,
+    /// 打开当前连接的会话监控
+    pub show_session_monitor: bool,
+    /// 执行表/数据库维护操作
+    pub maintenance: Option<(MaintenanceOp, MaintenanceTarget)>,
     /// 焦点转移请求（转出侧边栏）
     pub focus_transfer: Option<SidebarFocusTransfer>,
     /// Section 切换请求（侧边栏内部层级导航）
     pub section_change: Option<SidebarSection>,
     /// 编辑连接配置（打开连接对话框）
     pub edit_connection: Option<String>,
+    /// 修改 SQLite 加密密钥（连接名）
+    pub change_sqlite_key: Option<String>,
+    /// 新建草稿本（内存 SQLite）连接
+    pub new_scratchpad: bool,
+    /// 将草稿本保存为文件（连接名）
+    pub save_scratchpad: Option<String>,
+    /// 为 SQLite 连接附加数据库文件（连接名）
+    pub attach_sqlite_database: Option<String>,
+    /// 分离 SQLite 附加数据库（连接名, 别名）
+    pub detach_sqlite_database: Option<(String, String)>,
     /// 重命名项目（连接/表等）
     pub rename_item: Option<(SidebarSection, String)>,
     /// 刷新当前列表
     pub refresh: bool,
+    /// 加载下一批表（表列表分批读取时）
+    pub load_more_tables: bool,
     /// 筛选条件已更改
     pub filter_changed: bool,
     /// 添加新的筛选条件
@@ -44,6 +96,10 @@ pub struct SidebarActions {
     pub focus_filter_input: Option<usize>,
     /// 切换筛选条件的列 (索引, true=下一个/false=上一个)
     pub cycle_filter_column: Option<(usize, bool)>,
+    /// 将当前筛选条件保存为当前表的预设（预设名称）
+    pub save_filter_preset: Option<String>,
+    /// 删除当前表的筛选预设（预设名称）
+    pub delete_filter_preset: Option<String>,
 }
 
 #[allow(dead_code)] // 公开 API，供外部使用
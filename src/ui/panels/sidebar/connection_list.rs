@@ -1,21 +1,39 @@
 //! 连接列表渲染
 
-use crate::database::ConnectionManager;
+use crate::core::{t, tf, HealthStatus};
+use crate::database::{ConnectionManager, DatabaseType, MaintenanceOp};
 use crate::ui::styles::{DANGER, GRAY, MUTED, SUCCESS, MARGIN_MD, MARGIN_SM, SPACING_SM, SPACING_MD, SPACING_LG};
 use crate::ui::SidebarSection;
-use super::{SidebarActions, SidebarPanelState, SidebarSelectionState, DatabaseList, TableList};
+use super::quick_filter::quick_filter;
+use super::table_list::maintenance_menu;
+use super::{MaintenanceTarget, SidebarActions, SidebarPanelState, DatabaseList, TableList};
 use egui::{self, Color32, RichText, CornerRadius, Vec2};
 
 /// 连接项数据（用于避免借用冲突）
 pub(crate) struct ConnectionItemData {
     pub is_active: bool,
     pub is_connected: bool,
+    /// 是否为 SQLite 连接
+    pub is_sqlite: bool,
+    /// 是否为 SQLCipher 加密的 SQLite 连接
+    pub is_encrypted: bool,
+    /// 是否为草稿本（内存 SQLite）
+    pub is_scratchpad: bool,
+    /// 是否支持会话监控（MySQL/PostgreSQL）
+    pub supports_sessions: bool,
     pub db_type: String,
     pub host: String,
     pub databases: Vec<String>,
     pub selected_database: Option<String>,
+    /// 表列表（渲染期间从连接中移出，避免每帧复制很长的列表）
     pub tables: Vec<String>,
+    /// 是否还有未加载的表
+    pub has_more_tables: bool,
     pub error: Option<String>,
+    /// 保活检查状态（仅已连接时）
+    pub health: Option<HealthStatus>,
+    /// 保活检查的悬停提示
+    pub health_hint: String,
 }
 
 /// 连接列表
@@ -37,7 +55,7 @@ impl ConnectionList {
     ) {
         // 上部标题栏
         ui.horizontal(|ui| {
-            Self::show_header(ui, show_connection_dialog, is_focused, focused_section);
+            Self::show_header(ui, show_connection_dialog, actions, is_focused, focused_section);
         });
 
         // 连接列表区域 - 使用固定宽度防止内容扩展面板
@@ -58,8 +76,23 @@ impl ConnectionList {
                 } else {
                     // 快捷键提示（在第一个连接上方）
                     Self::show_shortcuts_hint(ui);
-                    
-                    for (idx, name) in connection_names.iter().enumerate() {
+                    quick_filter(
+                        ui,
+                        &mut panel_state.quick_filters,
+                        SidebarSection::Connections,
+                        0.0,
+                        t("sidebar.filter_connections"),
+                        false,
+                    );
+
+                    let visible = panel_state
+                        .quick_filters
+                        .indices(SidebarSection::Connections, &connection_names);
+                    if visible.is_empty() {
+                        ui.label(RichText::new(t("sidebar.no_matching_connections")).italics().small().color(MUTED));
+                    }
+                    for (idx, &name_idx) in visible.iter().enumerate() {
+                        let name = &connection_names[name_idx];
                         // 判断是否为键盘导航选中项
                         let is_nav_selected = is_focused 
                             && focused_section == SidebarSection::Connections 
@@ -73,7 +106,7 @@ impl ConnectionList {
                             is_focused,
                             focused_section,
                             is_nav_selected,
-                            &panel_state.selection,
+                            panel_state,
                         );
                     }
                 }
@@ -83,7 +116,13 @@ impl ConnectionList {
     }
 
     /// 显示标题栏
-    fn show_header(ui: &mut egui::Ui, show_connection_dialog: &mut bool, is_focused: bool, focused_section: SidebarSection) {
+    fn show_header(
+        ui: &mut egui::Ui,
+        show_connection_dialog: &mut bool,
+        actions: &mut SidebarActions,
+        is_focused: bool,
+        focused_section: SidebarSection,
+    ) {
         // 使用与工具栏完全相同的 Frame 包裹
         egui::Frame::NONE
             .inner_margin(egui::Margin::symmetric(MARGIN_MD, MARGIN_SM))
@@ -92,17 +131,18 @@ impl ConnectionList {
                     ui.spacing_mut().item_spacing = egui::Vec2::new(6.0, 0.0);
 
                     // 标题
-                    ui.label(RichText::new("🔗 连接").strong());
+                    ui.label(RichText::new(t("sidebar.connections_title")).strong());
                     
                     // 显示当前焦点区域提示
-                    if is_focused && !matches!(focused_section, SidebarSection::Triggers | SidebarSection::Routines | SidebarSection::Filters) {
+                    if is_focused && !matches!(focused_section, SidebarSection::Triggers | SidebarSection::Routines | SidebarSection::Sequences | SidebarSection::Filters) {
                         let section_text = match focused_section {
-                            SidebarSection::Connections => "连接",
-                            SidebarSection::Databases => "数据库",
-                            SidebarSection::Tables => "表",
-                            SidebarSection::Triggers => "触发器",
-                            SidebarSection::Routines => "存储过程",
-                            SidebarSection::Filters => "筛选",
+                            SidebarSection::Connections => t("sidebar.section_connections"),
+                            SidebarSection::Databases => t("sidebar.section_databases"),
+                            SidebarSection::Tables => t("sidebar.section_tables"),
+                            SidebarSection::Triggers => t("sidebar.section_triggers"),
+                            SidebarSection::Routines => t("sidebar.section_routines"),
+                            SidebarSection::Sequences => t("sidebar.section_sequences"),
+                            SidebarSection::Filters => t("sidebar.section_filters"),
                         };
                         ui.label(RichText::new(format!("→ {}", section_text)).small().color(SUCCESS));
                     }
@@ -114,9 +154,18 @@ impl ConnectionList {
                             egui::Button::new(RichText::new("+").size(15.0).color(Color32::LIGHT_GRAY))
                                 .frame(false)
                                 .min_size(Vec2::new(24.0, 24.0)),
-                        ).on_hover_text("新建连接 (Ctrl+N)").clicked() {
+                        ).on_hover_text(t("sidebar.new_connection_hint")).clicked() {
                             *show_connection_dialog = true;
                         }
+
+                        // 草稿本 - 一键创建内存数据库
+                        if ui.add(
+                            egui::Button::new(RichText::new("🧪").size(13.0).color(Color32::LIGHT_GRAY))
+                                .frame(false)
+                                .min_size(Vec2::new(24.0, 24.0)),
+                        ).on_hover_text(t("sidebar.new_scratchpad_hint")).clicked() {
+                            actions.new_scratchpad = true;
+                        }
                     });
                 });
             });
@@ -136,7 +185,7 @@ impl ConnectionList {
             ui.add_space(SPACING_LG);
 
             ui.label(
-                RichText::new("暂无连接")
+                RichText::new(t("sidebar.no_connections"))
                     .size(16.0)
                     .color(GRAY),
             );
@@ -144,7 +193,7 @@ impl ConnectionList {
             ui.add_space(SPACING_SM);
 
             ui.label(
-                RichText::new("创建一个数据库连接开始使用")
+                RichText::new(t("sidebar.no_connections_hint"))
                     .small()
                     .color(MUTED),
             );
@@ -152,10 +201,10 @@ impl ConnectionList {
             ui.add_space(SPACING_LG);
 
             if ui.add(
-                egui::Button::new(RichText::new("+ 新建连接").size(14.0).color(Color32::LIGHT_GRAY))
+                egui::Button::new(RichText::new(t("sidebar.new_connection")).size(14.0).color(Color32::LIGHT_GRAY))
                     .frame(false)
                     .min_size(Vec2::new(0.0, 24.0)),
-            ).on_hover_text("新建连接 (Ctrl+N)").clicked() {
+            ).on_hover_text(t("sidebar.new_connection_hint")).clicked() {
                 *show_connection_dialog = true;
             }
         });
@@ -169,13 +218,16 @@ impl ConnectionList {
                 ui.horizontal_wrapped(|ui| {
                     ui.spacing_mut().item_spacing = egui::Vec2::new(4.0, 0.0);
                     ui.label(RichText::new("j/k").small().color(GRAY));
-                    ui.label(RichText::new("导航").small().color(MUTED));
+                    ui.label(RichText::new(t("sidebar.key_navigate")).small().color(MUTED));
                     ui.label(RichText::new("·").small().color(MUTED));
                     ui.label(RichText::new("Enter").small().color(GRAY));
-                    ui.label(RichText::new("选择").small().color(MUTED));
+                    ui.label(RichText::new(t("sidebar.key_select")).small().color(MUTED));
                     ui.label(RichText::new("·").small().color(MUTED));
                     ui.label(RichText::new("g/G").small().color(GRAY));
-                    ui.label(RichText::new("首/尾").small().color(MUTED));
+                    ui.label(RichText::new(t("sidebar.key_first_last")).small().color(MUTED));
+                    ui.label(RichText::new("·").small().color(MUTED));
+                    ui.label(RichText::new("/").small().color(GRAY));
+                    ui.label(RichText::new(t("sidebar.key_filter")).small().color(MUTED));
                 });
             });
     }
@@ -191,22 +243,35 @@ impl ConnectionList {
         is_focused: bool,
         focused_section: SidebarSection,
         is_nav_selected: bool,
-        selection: &SidebarSelectionState,
+        panel_state: &mut SidebarPanelState,
     ) {
         // 先提取需要的数据，避免借用冲突
+        let is_active = connection_manager.active.as_deref() == Some(name);
         let conn_data = {
-            let Some(conn) = connection_manager.connections.get(name) else {
+            let Some(conn) = connection_manager.connections.get_mut(name) else {
                 return;
             };
             ConnectionItemData {
-                is_active: connection_manager.active.as_deref() == Some(name),
+                is_active,
                 is_connected: conn.connected,
-                db_type: conn.config.db_type.display_name().to_string(),
+                is_sqlite: conn.config.db_type == DatabaseType::SQLite,
+                is_scratchpad: conn.config.is_scratchpad(),
+                supports_sessions: matches!(conn.config.db_type, DatabaseType::MySQL | DatabaseType::PostgreSQL),
+                is_encrypted: conn.config.db_type == DatabaseType::SQLite
+                    && !conn.config.sqlite_key.is_empty(),
+                db_type: if conn.config.is_scratchpad() {
+                    t("sidebar.scratchpad").to_string()
+                } else {
+                    conn.config.db_type.display_name().to_string()
+                },
                 host: conn.config.host.clone(),
                 databases: conn.databases.clone(),
                 selected_database: conn.selected_database.clone(),
-                tables: conn.tables.clone(),
+                tables: std::mem::take(&mut conn.tables),
+                has_more_tables: conn.has_more_tables,
                 error: conn.error.clone(),
+                health: conn.connected.then(|| conn.keep_alive.status()),
+                health_hint: conn.keep_alive.describe(),
             }
         };
 
@@ -216,9 +281,22 @@ impl ConnectionList {
             .inner_margin(egui::Margin::symmetric(MARGIN_SM, 2))
             .show(ui, |ui| {
                 // 连接头部
-                let header_response = egui::collapsing_header::CollapsingHeader::new(
-                    Self::connection_header_text(name, conn_data.is_active, conn_data.is_connected, is_nav_selected),
-                )
+                let mut header = egui::text::LayoutJob::default();
+                Self::connection_header_text(name, conn_data.is_active, conn_data.is_connected, is_nav_selected)
+                    .append_to(&mut header, ui.style(), egui::FontSelection::Default, egui::Align::Center);
+                if let Some(health) = conn_data.health {
+                    RichText::new(" ●")
+                        .color(health_color(health))
+                        .append_to(&mut header, ui.style(), egui::FontSelection::Default, egui::Align::Center);
+                }
+                let queued = panel_state.queued_queries.get(name).copied().unwrap_or(0);
+                if queued > 0 {
+                    RichText::new(tf("sidebar.queued_queries", &[&queued.to_string()]))
+                        .small()
+                        .color(MUTED)
+                        .append_to(&mut header, ui.style(), egui::FontSelection::Default, egui::Align::Center);
+                }
+                let header_response = egui::collapsing_header::CollapsingHeader::new(header)
                 .default_open(conn_data.is_active)
                 .show(ui, |ui| {
                     ui.add_space(SPACING_SM);
@@ -233,6 +311,7 @@ impl ConnectionList {
                         ui,
                         name,
                         conn_data.is_active,
+                        conn_data.health == Some(HealthStatus::Down),
                         selected_table,
                         actions,
                     );
@@ -247,12 +326,13 @@ impl ConnectionList {
                             &conn_data.databases,
                             conn_data.selected_database.as_deref(),
                             &conn_data.tables,
+                            conn_data.has_more_tables,
                             connection_manager,
                             selected_table,
                             actions,
                             is_focused,
                             focused_section,
-                            selection,
+                            panel_state,
                         );
                     } else if conn_data.is_connected {
                         // SQLite 模式：直接显示表列表
@@ -260,12 +340,13 @@ impl ConnectionList {
                             ui,
                             name,
                             &conn_data.tables,
+                            conn_data.has_more_tables,
                             connection_manager,
                             selected_table,
                             actions,
                             is_focused,
                             focused_section,
-                            selection,
+                            panel_state,
                         );
                     }
 
@@ -276,21 +357,70 @@ impl ConnectionList {
                     }
                 });
 
+                let mut hints = Vec::new();
+                if conn_data.health.is_some() {
+                    hints.push(conn_data.health_hint.clone());
+                }
+                if queued > 0 {
+                    hints.push(tf("sidebar.queued_queries_hint", &[&queued.to_string()]));
+                }
+                if !hints.is_empty() {
+                    header_response.header_response.clone().on_hover_text(hints.join("\n"));
+                }
+
                 // 右键菜单
                 let is_active_for_menu = conn_data.is_active;
+                let is_encrypted = conn_data.is_encrypted;
+                let is_sqlite = conn_data.is_sqlite;
+                let is_scratchpad = conn_data.is_scratchpad;
+                let is_connected_for_menu = conn_data.is_connected;
+                let has_databases = !conn_data.databases.is_empty();
                 header_response.header_response.context_menu(|ui| {
                     if is_active_for_menu {
-                        if ui.button("断开连接").clicked() {
+                        if ui.button(t("sidebar.disconnect")).clicked() {
                             actions.disconnect = Some(name.to_string());
                             ui.close();
                         }
-                    } else if ui.button("🔗 连接").clicked() {
+                    } else if ui.button(t("sidebar.connect_icon")).clicked() {
                         actions.connect = Some(name.to_string());
                         ui.close();
                     }
+                    if is_scratchpad && ui.button(t("sidebar.save_scratchpad")).clicked() {
+                        actions.save_scratchpad = Some(name.to_string());
+                        ui.close();
+                    }
+                    if is_active_for_menu && is_connected_for_menu && ui.button(t("sidebar.size_overview")).clicked() {
+                        actions.show_size_overview = true;
+                        ui.close();
+                    }
+                    if conn_data.supports_sessions
+                        && is_active_for_menu
+                        && is_connected_for_menu
+                        && ui.button(t("sidebar.session_monitor")).clicked()
+                    {
+                        actions.show_session_monitor = true;
+                        ui.close();
+                    }
+                    // 未附加数据库时 SQLite 直接显示表，整库维护放在连接菜单中
+                    if is_sqlite && is_active_for_menu && is_connected_for_menu && !has_databases {
+                        maintenance_menu(
+                            ui,
+                            MaintenanceOp::available(DatabaseType::SQLite, false),
+                            MaintenanceTarget::Database("main".to_string()),
+                            actions,
+                        );
+                    }
+                    if is_sqlite && ui.button(t("sidebar.attach_database")).clicked() {
+                        actions.attach_sqlite_database = Some(name.to_string());
+                        ui.close();
+                    }
+                    if is_encrypted && ui.button(t("sidebar.change_key")).clicked() {
+                        actions.change_sqlite_key = Some(name.to_string());
+                        ui.close();
+                    }
                     ui.separator();
                     if ui
-                        .button(RichText::new("🗑 删除").color(DANGER))
+                        .button(RichText::new(t("sidebar.delete_icon")).color(DANGER))
                         .clicked()
                     {
                         actions.delete = Some(name.to_string());
@@ -298,6 +428,11 @@ impl ConnectionList {
                     }
                 });
             });
+
+        // 放回表列表
+        if let Some(conn) = connection_manager.connections.get_mut(name) {
+            conn.tables = conn_data.tables;
+        }
     }
 
     /// 连接头部文本
@@ -345,6 +480,7 @@ impl ConnectionList {
         ui: &mut egui::Ui,
         name: &str,
         is_active: bool,
+        is_down: bool,
         selected_table: &mut Option<String>,
         actions: &mut SidebarActions,
     ) {
@@ -360,16 +496,21 @@ impl ConnectionList {
                 ).on_hover_text(tooltip).clicked()
             };
 
+            // 保活检查判定连接已断开时提供重新连接
+            if is_down && icon_btn(ui, "🔄", t("sidebar.reconnect"), Color32::LIGHT_GRAY) {
+                actions.connect = Some(name.to_string());
+            }
+
             if is_active {
-                if icon_btn(ui, "⏏", "断开连接", Color32::LIGHT_GRAY) {
+                if icon_btn(ui, "⏏", t("sidebar.disconnect"), Color32::LIGHT_GRAY) {
                     actions.disconnect = Some(name.to_string());
                     *selected_table = None;
                 }
-            } else if icon_btn(ui, "🔗", "连接", Color32::LIGHT_GRAY) {
+            } else if icon_btn(ui, "🔗", t("sidebar.connect"), Color32::LIGHT_GRAY) {
                 actions.connect = Some(name.to_string());
             }
 
-            if icon_btn(ui, "🗑", "删除连接", DANGER) {
+            if icon_btn(ui, "🗑", t("sidebar.delete_connection"), DANGER) {
                 actions.delete = Some(name.to_string());
             }
         });
@@ -394,6 +535,16 @@ impl ConnectionList {
     }
 }
 
+/// 保活检查状态对应的圆点颜色
+fn health_color(health: HealthStatus) -> Color32 {
+    match health {
+        HealthStatus::Unknown => GRAY,
+        HealthStatus::Healthy => SUCCESS,
+        HealthStatus::Degraded => Color32::from_rgb(230, 180, 60),
+        HealthStatus::Down => DANGER,
+    }
+}
+
 /// 截断错误信息
 fn truncate_error(error: &str) -> String {
     if error.len() > 50 {
@@ -1,16 +1,18 @@
 //! 数据库列表渲染
 
-use crate::database::ConnectionManager;
+use crate::database::{ConnectionManager, DatabaseType, MaintenanceOp};
 use crate::ui::styles::{MUTED, SPACING_LG};
 use crate::ui::SidebarSection;
-use super::{SidebarActions, SidebarSelectionState, TableList};
+use super::quick_filter::quick_filter;
+use super::table_list::maintenance_menu;
+use super::{MaintenanceTarget, SidebarActions, SidebarPanelState, TableList};
 use egui::{self, Color32, RichText, CornerRadius};
 
 /// 数据库列表
 pub struct DatabaseList;
 
 impl DatabaseList {
-    /// 显示数据库列表（MySQL/PostgreSQL）
+    /// 显示数据库列表（MySQL/PostgreSQL/Redis/MongoDB）
     #[allow(clippy::too_many_arguments)]
     pub fn show(
         ui: &mut egui::Ui,
@@ -18,22 +20,63 @@ impl DatabaseList {
         databases: &[String],
         selected_database: Option<&str>,
         tables: &[String],
+        has_more_tables: bool,
         connection_manager: &mut ConnectionManager,
         selected_table: &mut Option<String>,
         actions: &mut SidebarActions,
         is_focused: bool,
         focused_section: SidebarSection,
-        selection: &SidebarSelectionState,
+        panel_state: &mut SidebarPanelState,
     ) {
         // 数据库区域是否高亮
         let highlight_databases = is_focused && focused_section == SidebarSection::Databases;
         // 表区域是否高亮
         let highlight_tables = is_focused && focused_section == SidebarSection::Tables;
+        // SQLite 的"数据库"是 main 和附加的 schema
+        let is_sqlite = connection_manager
+            .connections
+            .get(conn_name)
+            .is_some_and(|c| c.config.db_type == DatabaseType::SQLite);
+        // Redis 的"表"是键
+        let is_redis = connection_manager
+            .connections
+            .get(conn_name)
+            .is_some_and(|c| c.config.db_type == DatabaseType::Redis);
+        // MongoDB 的"表"是集合
+        let is_mongodb = connection_manager
+            .connections
+            .get(conn_name)
+            .is_some_and(|c| c.config.db_type == DatabaseType::MongoDB);
+        let maintenance_ops = connection_manager
+            .connections
+            .get(conn_name)
+            .map_or(&[][..], |c| MaintenanceOp::available(c.config.db_type, false));
+        let item_unit = if is_redis {
+            "键"
+        } else if is_mongodb {
+            "集合"
+        } else {
+            "表"
+        };
         
+        quick_filter(
+            ui,
+            &mut panel_state.quick_filters,
+            SidebarSection::Databases,
+            0.0,
+            "筛选数据库",
+            false,
+        );
+        let visible = panel_state.quick_filters.indices(SidebarSection::Databases, databases);
+        if visible.is_empty() {
+            ui.label(RichText::new("没有匹配的数据库").italics().small().color(MUTED));
+        }
+
         // 数据库列表
-        for (idx, database) in databases.iter().enumerate() {
+        for (idx, &db_idx) in visible.iter().enumerate() {
+            let database = &databases[db_idx];
             let is_selected = selected_database == Some(database.as_str());
-            let is_nav_selected = highlight_databases && idx == selection.databases;
+            let is_nav_selected = highlight_databases && idx == panel_state.selection.databases;
 
             // 数据库项 - 整行可点击
             let db_bg = if is_nav_selected {
@@ -63,7 +106,8 @@ impl DatabaseList {
                         // 表数量提示（选中时显示）
                         if is_selected {
                             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                                ui.label(RichText::new(format!("{} 表", tables.len())).small().color(MUTED));
+                                let more = if has_more_tables { "+" } else { "" };
+                                ui.label(RichText::new(format!("{}{} {}", tables.len(), more, item_unit)).small().color(MUTED));
                             });
                         }
                     });
@@ -76,12 +120,38 @@ impl DatabaseList {
                 connection_manager.active = Some(conn_name.to_string());
                 actions.select_database = Some(database.clone());
             }
+
+            // 右键菜单 - 维护操作（作用于当前连接，SQLite 各 schema 共用连接），分离附加的 SQLite 数据库
+            let can_maintain = !maintenance_ops.is_empty() && (is_sqlite || is_selected);
+            let can_detach = is_sqlite && database != "main";
+            if can_maintain || can_detach {
+                db_response.context_menu(|ui| {
+                    if can_maintain {
+                        maintenance_menu(ui, maintenance_ops, MaintenanceTarget::Database(database.clone()), actions);
+                    }
+                    if can_detach && ui.button("⏏ 分离数据库").clicked() {
+                        actions.detach_sqlite_database =
+                            Some((conn_name.to_string(), database.clone()));
+                        ui.close();
+                    }
+                });
+            }
             
             // 如果是选中项且有焦点，滚动到可见
             if is_nav_selected {
                 db_response.scroll_to_me(Some(egui::Align::Center));
             }
 
+            // Redis：键匹配模式，回车后重新扫描当前库
+            if is_selected && is_redis {
+                Self::show_key_pattern(ui, conn_name, database, connection_manager, actions);
+            }
+
+            // MongoDB：结果中嵌套文档的显示方式
+            if is_selected && is_mongodb {
+                Self::show_flatten_option(ui, conn_name, connection_manager);
+            }
+
             // 如果此数据库被选中，显示其下的表列表
             if is_selected && !tables.is_empty() {
                 ui.add_space(SPACING_LG / 2.0);
@@ -89,13 +159,56 @@ impl DatabaseList {
                     ui,
                     conn_name,
                     tables,
+                    has_more_tables,
                     connection_manager,
                     selected_table,
                     actions,
                     highlight_tables,
-                    selection.tables,
+                    panel_state,
                 );
             }
         }
     }
+
+    /// 显示 Redis 键匹配模式输入框
+    fn show_key_pattern(
+        ui: &mut egui::Ui,
+        conn_name: &str,
+        database: &str,
+        connection_manager: &mut ConnectionManager,
+        actions: &mut SidebarActions,
+    ) {
+        let Some(conn) = connection_manager.connections.get_mut(conn_name) else {
+            return;
+        };
+        ui.horizontal(|ui| {
+            ui.add_space(SPACING_LG);
+            ui.label(RichText::new("🔍").small().color(MUTED));
+            let response = ui.add(
+                egui::TextEdit::singleline(&mut conn.config.redis_key_pattern)
+                    .hint_text("*")
+                    .desired_width(ui.available_width() - SPACING_LG)
+                    .font(egui::TextStyle::Small),
+            )
+            .on_hover_text("键匹配模式（如 user:*），回车刷新");
+            if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                actions.select_database = Some(database.to_string());
+            }
+        });
+    }
+
+    /// 显示 MongoDB 嵌套文档展开选项
+    fn show_flatten_option(ui: &mut egui::Ui, conn_name: &str, connection_manager: &mut ConnectionManager) {
+        let Some(conn) = connection_manager.connections.get_mut(conn_name) else {
+            return;
+        };
+        ui.horizontal(|ui| {
+            ui.add_space(SPACING_LG);
+            ui.checkbox(
+                &mut conn.config.mongodb_flatten,
+                RichText::new("展开嵌套字段").small().color(MUTED),
+            )
+            .on_hover_text("将嵌套文档拆分为 a.b 形式的列，下次查询生效");
+        });
+    }
 }
@@ -2,7 +2,10 @@
 //!
 //! 显示在左侧栏的筛选条件管理面板
 
-use crate::ui::{ColumnFilter, FilterLogic, FilterOperator, SidebarSection};
+use super::SidebarActions;
+use crate::core::FilterPreset;
+use crate::ui::{ColumnFilter, ColumnPicker, FilterLogic, FilterOperator, SidebarSection};
+use std::collections::HashMap;
 use crate::ui::styles::{GRAY, MUTED, SUCCESS};
 use egui::{self, Color32, RichText, Vec2, TextEdit, CornerRadius};
 
@@ -12,13 +15,18 @@ pub struct FilterPanel;
 impl FilterPanel {
     /// 显示筛选面板
     ///
-    /// 返回是否有修改（用于使缓存失效）
+    /// 返回是否有修改（用于使缓存失效）。`presets` 为当前表的筛选预设
+    /// （没有选中表时为 `None`），保存和删除预设通过 `actions` 交给应用处理
+    #[allow(clippy::too_many_arguments)]
     pub fn show(
         ui: &mut egui::Ui,
         is_focused: bool,
         focused_section: SidebarSection,
         filters: &mut Vec<ColumnFilter>,
         columns: &[String],
+        column_types: Option<&HashMap<String, String>>,
+        presets: Option<&[FilterPreset]>,
+        actions: &mut SidebarActions,
         height: f32,
     ) -> bool {
         let mut changed = false;
@@ -44,8 +52,8 @@ impl FilterPanel {
                 ui.spacing_mut().item_spacing.x = 2.0;
                 
                 // 添加按钮
-                if !columns.is_empty() {
-                    if ui
+                if !columns.is_empty()
+                    && ui
                         .add(
                             egui::Button::new(RichText::new("+").size(13.0).color(Color32::from_rgb(100, 180, 100)))
                                 .frame(false)
@@ -57,11 +65,10 @@ impl FilterPanel {
                         filters.push(ColumnFilter::new(columns.first().cloned().unwrap_or_default()));
                         changed = true;
                     }
-                }
                 
                 // 清空按钮
-                if !filters.is_empty() {
-                    if ui
+                if !filters.is_empty()
+                    && ui
                         .add(
                             egui::Button::new(RichText::new("×").size(13.0).color(Color32::from_rgb(160, 100, 100)))
                                 .frame(false)
@@ -73,6 +80,12 @@ impl FilterPanel {
                         filters.clear();
                         changed = true;
                     }
+
+                // 预设菜单
+                if let Some(presets) = presets
+                    && Self::show_presets_menu(ui, filters, presets, actions)
+                {
+                    changed = true;
                 }
             });
         });
@@ -136,17 +149,11 @@ impl FilterPanel {
                                         }
 
                                         ui.add_enabled_ui(filter.enabled, |ui| {
-                                            // 列选择
-                                            egui::ComboBox::new(format!("col_{}", idx), "")
-                                                .selected_text(RichText::new(truncate_str(&filter.column, 6)).size(10.0))
-                                                .width(55.0)
-                                                .show_ui(ui, |ui| {
-                                                    for c in columns {
-                                                        if ui.selectable_value(&mut filter.column, c.clone(), c).changed() {
-                                                            changed = true;
-                                                        }
-                                                    }
-                                                });
+                                            // 列选择（输入时模糊补全列名）
+                                            let picker_id = ui.id().with("column");
+                                            if ColumnPicker::show(ui, picker_id, &mut filter.column, columns, column_types, 55.0, None) {
+                                                changed = true;
+                                            }
 
                                             // 操作符
                                             egui::ComboBox::new(format!("op_{}", idx), "")
@@ -173,6 +180,11 @@ impl FilterPanel {
                                                             changed = true;
                                                         }
                                                     }
+                                                    ui.separator();
+                                                    ui.label(RichText::new("JSON").size(10.0).color(GRAY));
+                                                    if ui.selectable_value(&mut filter.operator, FilterOperator::JsonPath, FilterOperator::JsonPath.display_name()).changed() {
+                                                        changed = true;
+                                                    }
                                                 });
                                         });
 
@@ -204,7 +216,7 @@ impl FilterPanel {
                                                         TextEdit::singleline(&mut filter.value)
                                                             .desired_width(ui.available_width() - 24.0)
                                                             .font(egui::TextStyle::Small)
-                                                            .hint_text("值..."),
+                                                            .hint_text(if filter.operator == FilterOperator::JsonPath { "$.user.id = 5" } else { "值..." }),
                                                     )
                                                     .changed()
                                                 {
@@ -271,13 +283,73 @@ impl FilterPanel {
 
         changed
     }
-}
 
-/// 截断字符串
-fn truncate_str(s: &str, max_len: usize) -> String {
-    if s.chars().count() > max_len {
-        format!("{}…", s.chars().take(max_len).collect::<String>())
-    } else {
-        s.to_string()
+    /// 预设菜单：应用、删除已保存的预设，或将当前筛选保存为预设
+    ///
+    /// 返回是否应用了预设
+    fn show_presets_menu(
+        ui: &mut egui::Ui,
+        filters: &mut Vec<ColumnFilter>,
+        presets: &[FilterPreset],
+        actions: &mut SidebarActions,
+    ) -> bool {
+        let name_id = ui.id().with("filter_preset_name");
+        let mut applied = false;
+        let title = if presets.is_empty() {
+            "预设".to_string()
+        } else {
+            format!("预设 ({})", presets.len())
+        };
+
+        ui.menu_button(RichText::new(title).size(11.0).color(GRAY), |ui| {
+            ui.set_min_width(180.0);
+            if presets.is_empty() {
+                ui.label(RichText::new("当前表没有预设").size(11.0).color(MUTED));
+            }
+            for preset in presets {
+                ui.horizontal(|ui| {
+                    let label = format!("{} ({})", preset.name, preset.filters.len());
+                    if ui.button(RichText::new(label).size(11.0)).on_hover_text("应用此预设").clicked() {
+                        *filters = preset.filters.iter().map(ColumnFilter::from_saved).collect();
+                        applied = true;
+                        ui.close();
+                    }
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui
+                            .add(egui::Button::new(RichText::new("×").size(11.0).color(GRAY)).frame(false))
+                            .on_hover_text("删除预设")
+                            .clicked()
+                        {
+                            actions.delete_filter_preset = Some(preset.name.clone());
+                        }
+                    });
+                });
+            }
+
+            ui.separator();
+            let mut name = ui.ctx().data_mut(|d| d.get_temp::<String>(name_id).unwrap_or_default());
+            ui.horizontal(|ui| {
+                let input = ui.add(
+                    TextEdit::singleline(&mut name)
+                        .hint_text("预设名称")
+                        .font(egui::TextStyle::Small)
+                        .desired_width(110.0),
+                );
+                let can_save = !filters.is_empty() && !name.trim().is_empty();
+                let submit = input.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                let exists = presets.iter().any(|p| p.name == name.trim());
+                let button = ui
+                    .add_enabled(can_save, egui::Button::new(RichText::new("保存").size(11.0)))
+                    .on_hover_text(if exists { "覆盖同名预设" } else { "将当前筛选保存为预设" });
+                if can_save && (button.clicked() || submit) {
+                    actions.save_filter_preset = Some(name.trim().to_string());
+                    name.clear();
+                    ui.close();
+                }
+            });
+            ui.ctx().data_mut(|d| d.insert_temp(name_id, name));
+        });
+
+        applied
     }
 }
@@ -1,14 +1,19 @@
 //! 侧边栏组件 - 连接管理和表列表
 //!
-//! 侧边栏分为三个独立面板：
+//! 侧边栏分为以下独立面板：
 //! - 连接/数据库/表列表
+//! - 筛选条件
 //! - 触发器列表
 //! - 存储过程/函数列表
+//! - 序列/自增计数器列表
+//! - 插件注册的面板（追加在末尾）
 //!
 //! 每个面板可以：
 //! - 独立显示/隐藏（通过顶部工具栏按钮）
 //! - 独立折叠/展开（通过面板标题栏的折叠按钮）
 //! - 通过拖动分割条调整大小
+//!
+//! 连接、数据库、表、触发器和存储过程列表获得焦点时按 "/" 快速筛选。
 
 mod state;
 mod actions;
@@ -17,10 +22,12 @@ mod database_list;
 mod table_list;
 mod trigger_panel;
 mod routine_panel;
+mod sequence_panel;
 mod filter_panel;
+mod quick_filter;
 
-pub use state::{SidebarPanelState, SidebarSelectionState};
-pub use actions::{SidebarActions, SidebarFocusTransfer};
+pub use state::{filter_indices, SidebarPanelState, SidebarQuickFilters};
+pub use actions::{MaintenanceTarget, SidebarActions, SidebarFocusTransfer};
 pub use filter_panel::FilterPanel;
 
 use connection_list::ConnectionList;
@@ -28,10 +35,13 @@ use database_list::DatabaseList;
 use table_list::TableList;
 use trigger_panel::TriggerPanel;
 use routine_panel::RoutinePanel;
+use sequence_panel::SequencePanel;
 
+use crate::core::{FilterPreset, PanelContext, PluginRegistry};
 use crate::database::ConnectionManager;
 use crate::ui::SidebarSection;
 use egui::{self, Color32, CornerRadius, Vec2};
+use std::collections::HashMap;
 
 /// 分割条高度
 const DIVIDER_HEIGHT: f32 = 6.0;
@@ -54,6 +64,9 @@ impl Sidebar {
         width: f32,
         filters: &mut Vec<ColumnFilter>,
         columns: &[String],
+        column_types: Option<&HashMap<String, String>>,
+        filter_presets: Option<&[FilterPreset]>,
+        plugins: &PluginRegistry,
     ) -> (SidebarActions, bool) {
         let mut filter_changed = false;
         let mut actions = SidebarActions::default();
@@ -62,12 +75,22 @@ impl Sidebar {
         // ====== 面板可见性控制工具栏 ======
         Self::show_visibility_toolbar(ui, panel_state);
 
+        // "/"：筛选当前区域
+        if is_focused
+            && SidebarQuickFilters::supports(focused_section)
+            && !ctx.wants_keyboard_input()
+            && ctx.input(|i| i.key_pressed(egui::Key::Slash))
+        {
+            panel_state.quick_filters.focus_request = Some(focused_section);
+        }
+
         // 处理键盘导航
         let (item_count, selected_index) = Self::get_section_info(focused_section, connection_manager, panel_state, filters);
         if item_count > 0 && *selected_index >= item_count {
             *selected_index = item_count.saturating_sub(1);
         }
-        if is_focused && item_count > 0 {
+        // 输入框（筛选值、Redis 键模式）获得焦点时不拦截按键
+        if is_focused && item_count > 0 && !ctx.wants_keyboard_input() {
             Self::handle_keyboard_navigation(
                 &ctx,
                 focused_section,
@@ -102,8 +125,12 @@ impl Sidebar {
                 heights.connections,
             );
 
-            // 分割条：连接 <-> 筛选/触发器/存储过程
-            if panel_state.show_filters || panel_state.show_triggers || panel_state.show_routines {
+            // 分割条：连接 <-> 筛选/触发器/存储过程/序列
+            if panel_state.show_filters
+                || panel_state.show_triggers
+                || panel_state.show_routines
+                || panel_state.show_sequences
+            {
                 Self::show_divider(ui, panel_state, 0, width);
             }
         }
@@ -116,13 +143,16 @@ impl Sidebar {
                 focused_section,
                 filters,
                 columns,
+                column_types,
+                filter_presets,
+                &mut actions,
                 heights.filters,
             ) {
                 filter_changed = true;
             }
 
-            // 分割条：筛选 <-> 触发器/存储过程
-            if panel_state.show_triggers || panel_state.show_routines {
+            // 分割条：筛选 <-> 触发器/存储过程/序列
+            if panel_state.show_triggers || panel_state.show_routines || panel_state.show_sequences {
                 Self::show_divider(ui, panel_state, 1, width);
             }
         }
@@ -135,10 +165,11 @@ impl Sidebar {
                 focused_section,
                 panel_state,
                 heights.triggers,
+                &mut actions,
             );
 
-            // 分割条：触发器 <-> 存储过程
-            if panel_state.show_routines {
+            // 分割条：触发器 <-> 存储过程/序列
+            if panel_state.show_routines || panel_state.show_sequences {
                 Self::show_divider(ui, panel_state, 2, width);
             }
         }
@@ -151,11 +182,50 @@ impl Sidebar {
                 focused_section,
                 panel_state,
                 heights.routines,
+                &mut actions,
             );
+
+            // 分割条：存储过程 <-> 序列
+            if panel_state.show_sequences {
+                Self::show_divider(ui, panel_state, 3, width);
+            }
+        }
+
+        // ====== 序列面板 ======
+        if panel_state.show_sequences {
+            SequencePanel::show(
+                ui,
+                is_focused,
+                focused_section,
+                panel_state,
+                heights.sequences,
+                &mut actions,
+            );
+        }
+
+        // ====== 插件面板 ======
+        if !plugins.panels().is_empty() {
+            let active = connection_manager.get_active();
+            let panel_ctx = PanelContext {
+                connection: connection_manager.active.as_deref(),
+                database: active.and_then(|c| c.selected_database.as_deref()),
+                table: selected_table.as_deref(),
+            };
+            for panel in plugins.panels() {
+                egui::CollapsingHeader::new(panel.title())
+                    .id_salt(("plugin_panel", panel.id()))
+                    .default_open(false)
+                    .show(ui, |ui| panel.show(ui, &panel_ctx));
+            }
         }
 
         // 如果没有任何面板显示
-        if !panel_state.show_connections && !panel_state.show_triggers && !panel_state.show_routines && !panel_state.show_filters {
+        if !panel_state.show_connections
+            && !panel_state.show_triggers
+            && !panel_state.show_routines
+            && !panel_state.show_sequences
+            && !panel_state.show_filters
+        {
             ui.vertical_centered(|ui| {
                 ui.add_space(20.0);
                 ui.label(egui::RichText::new("点击上方按钮显示面板").color(Color32::GRAY));
@@ -172,27 +242,33 @@ impl Sidebar {
         panel_state: &'a mut SidebarPanelState,
         filters: &[crate::ui::ColumnFilter],
     ) -> (usize, &'a mut usize) {
+        // 支持快速筛选的区域按筛选后的列表计数
+        let visible_count = Self::visible_indices(focused_section, connection_manager, panel_state).map(|v| v.len());
         match focused_section {
             SidebarSection::Connections => (
-                connection_manager.connections.len(),
+                visible_count.unwrap_or(0),
                 &mut panel_state.selection.connections,
             ),
             SidebarSection::Databases => (
-                connection_manager.get_active().map(|c| c.databases.len()).unwrap_or(0),
+                visible_count.unwrap_or(0),
                 &mut panel_state.selection.databases,
             ),
             SidebarSection::Tables => (
-                connection_manager.get_active().map(|c| c.tables.len()).unwrap_or(0),
+                visible_count.unwrap_or(0),
                 &mut panel_state.selection.tables,
             ),
             SidebarSection::Triggers => (
-                panel_state.triggers.len(),
+                visible_count.unwrap_or(0),
                 &mut panel_state.selection.triggers,
             ),
             SidebarSection::Routines => (
-                panel_state.routines.len(),
+                visible_count.unwrap_or(0),
                 &mut panel_state.selection.routines,
             ),
+            SidebarSection::Sequences => (
+                panel_state.sequences.len(),
+                &mut panel_state.selection.sequences,
+            ),
             SidebarSection::Filters => (
                 filters.len(),
                 &mut panel_state.selection.filters,
@@ -200,8 +276,26 @@ impl Sidebar {
         }
     }
 
+    /// 当前区域按快速筛选过滤后的项在原列表中的索引（不支持筛选的区域返回 `None`）
+    fn visible_indices(
+        section: SidebarSection,
+        connection_manager: &ConnectionManager,
+        panel_state: &SidebarPanelState,
+    ) -> Option<Vec<usize>> {
+        let quick_filters = &panel_state.quick_filters;
+        let active = connection_manager.get_active();
+        Some(match section {
+            SidebarSection::Connections => quick_filters.indices(section, connection_manager.connections.keys()),
+            SidebarSection::Databases => quick_filters.indices(section, active.map_or(&[][..], |c| &c.databases)),
+            SidebarSection::Tables => quick_filters.indices(section, active.map_or(&[][..], |c| &c.tables)),
+            SidebarSection::Triggers => quick_filters.indices(section, panel_state.triggers.iter().map(|t| &t.name)),
+            SidebarSection::Routines => quick_filters.indices(section, panel_state.routines.iter().map(|r| &r.name)),
+            SidebarSection::Filters | SidebarSection::Sequences => return None,
+        })
+    }
+
     /// 计算各面板高度
-    /// 面板顺序：连接(0) -> 筛选(1) -> 触发器(2) -> 存储过程(3)
+    /// 面板顺序：连接(0) -> 筛选(1) -> 触发器(2) -> 存储过程(3) -> 序列(4)
     fn calculate_panel_heights(panel_state: &SidebarPanelState, available_height: f32) -> PanelHeights {
         // 统计可见面板
         let visible_panels: Vec<(usize, f32)> = [
@@ -209,6 +303,7 @@ impl Sidebar {
             (1, panel_state.filters_ratio, panel_state.show_filters),
             (2, panel_state.triggers_ratio, panel_state.show_triggers),
             (3, panel_state.routines_ratio, panel_state.show_routines),
+            (4, panel_state.sequences_ratio, panel_state.show_sequences),
         ]
         .iter()
         .filter(|(_, _, visible)| *visible)
@@ -218,7 +313,7 @@ impl Sidebar {
         let visible_count = visible_panels.len();
         
         if visible_count == 0 {
-            return PanelHeights { connections: 0.0, filters: 0.0, triggers: 0.0, routines: 0.0 };
+            return PanelHeights { connections: 0.0, filters: 0.0, triggers: 0.0, routines: 0.0, sequences: 0.0 };
         }
 
         // 计算分割条占用的空间
@@ -233,7 +328,7 @@ impl Sidebar {
         let total_ratio = if total_ratio > 0.0 { total_ratio } else { 1.0 };
         
         // 按比例分配高度
-        let mut heights = PanelHeights { connections: 0.0, filters: 0.0, triggers: 0.0, routines: 0.0 };
+        let mut heights = PanelHeights { connections: 0.0, filters: 0.0, triggers: 0.0, routines: 0.0, sequences: 0.0 };
         
         for (idx, ratio) in &visible_panels {
             let height = (expandable_height * ratio / total_ratio).max(60.0);
@@ -242,6 +337,7 @@ impl Sidebar {
                 1 => heights.filters = height,
                 2 => heights.triggers = height,
                 3 => heights.routines = height,
+                4 => heights.sequences = height,
                 _ => {}
             }
         }
@@ -298,7 +394,7 @@ impl Sidebar {
     }
 
     /// 调整面板比例
-    /// 分割条顺序：0=连接↔筛选, 1=筛选↔触发器, 2=触发器↔存储过程
+    /// 分割条顺序：0=连接↔筛选, 1=筛选↔触发器, 2=触发器↔存储过程, 3=存储过程↔序列
     fn adjust_panel_ratios(panel_state: &mut SidebarPanelState, divider_index: usize, delta: f32) {
         let delta_ratio = delta / 500.0; // 转换为比例变化
         
@@ -330,6 +426,15 @@ impl Sidebar {
                     panel_state.routines_ratio = (panel_state.routines_ratio - delta_ratio).clamp(0.1, 0.8);
                 }
             }
+            3 => {
+                // 存储过程 <-> 序列
+                if panel_state.show_routines {
+                    panel_state.routines_ratio = (panel_state.routines_ratio + delta_ratio).clamp(0.1, 0.8);
+                }
+                if panel_state.show_sequences {
+                    panel_state.sequences_ratio = (panel_state.sequences_ratio - delta_ratio).clamp(0.1, 0.8);
+                }
+            }
             _ => {}
         }
     }
@@ -346,12 +451,19 @@ impl Sidebar {
         filters: &mut Vec<crate::ui::ColumnFilter>,
         actions: &mut SidebarActions,
     ) {
+        // 导航索引是筛选后列表中的位置，取项时换算为原列表的索引
+        let visible = Self::visible_indices(focused_section, connection_manager, panel_state);
+        let source = |index: usize| match &visible {
+            Some(visible) => visible.get(index).copied(),
+            None => Some(index),
+        };
         let selected_index = match focused_section {
             SidebarSection::Connections => &mut panel_state.selection.connections,
             SidebarSection::Databases => &mut panel_state.selection.databases,
             SidebarSection::Tables => &mut panel_state.selection.tables,
             SidebarSection::Triggers => &mut panel_state.selection.triggers,
             SidebarSection::Routines => &mut panel_state.selection.routines,
+            SidebarSection::Sequences => &mut panel_state.selection.sequences,
             SidebarSection::Filters => &mut panel_state.selection.filters,
         };
 
@@ -381,11 +493,11 @@ impl Sidebar {
             }
             // gs：查看表结构（需要在 Tables section）
             if i.key_pressed(egui::Key::S) && panel_state.command_buffer == "g" {
-                if let SidebarSection::Tables = focused_section {
-                    if let Some(conn) = connection_manager.get_active()
-                        && let Some(table) = conn.tables.get(*selected_index) {
-                            actions.show_table_schema = Some(table.clone());
-                        }
+                if let SidebarSection::Tables = focused_section
+                    && let Some(conn) = connection_manager.get_active()
+                    && let Some(table) = source(*selected_index).and_then(|i| conn.tables.get(i))
+                {
+                    actions.show_table_schema = Some(table.clone());
                 }
                 panel_state.command_buffer.clear();
             }
@@ -399,33 +511,36 @@ impl Sidebar {
                 match focused_section {
                     SidebarSection::Connections => {
                         let names: Vec<_> = connection_manager.connections.keys().cloned().collect();
-                        if let Some(name) = names.get(*selected_index) {
+                        if let Some(name) = source(*selected_index).and_then(|i| names.get(i)) {
                             actions.connect = Some(name.clone());
                         }
                     }
                     SidebarSection::Databases => {
                         if let Some(conn) = connection_manager.get_active()
-                            && let Some(db) = conn.databases.get(*selected_index) {
+                            && let Some(db) = source(*selected_index).and_then(|i| conn.databases.get(i)) {
                                 actions.select_database = Some(db.clone());
                             }
                     }
                     SidebarSection::Tables => {
                         if let Some(conn) = connection_manager.get_active()
-                            && let Some(table) = conn.tables.get(*selected_index) {
+                            && let Some(table) = source(*selected_index).and_then(|i| conn.tables.get(i)) {
                                 actions.query_table = Some(table.clone());
                                 *selected_table = Some(table.clone());
                             }
                     }
                     SidebarSection::Triggers => {
-                        if let Some(trigger) = panel_state.triggers.get(*selected_index) {
+                        if let Some(trigger) = source(*selected_index).and_then(|i| panel_state.triggers.get(i)) {
                             actions.show_trigger_definition = Some(trigger.definition.clone());
                         }
                     }
                     SidebarSection::Routines => {
-                        if let Some(routine) = panel_state.routines.get(*selected_index) {
+                        if let Some(routine) = source(*selected_index).and_then(|i| panel_state.routines.get(i)) {
                             actions.show_routine_definition = Some(routine.definition.clone());
                         }
                     }
+                    SidebarSection::Sequences => {
+                        actions.restart_sequence = panel_state.sequences.get(*selected_index).cloned();
+                    }
                     SidebarSection::Filters => {
                         // Enter 切换筛选条件的启用状态
                         if let Some(filter) = filters.get_mut(*selected_index) {
@@ -437,17 +552,18 @@ impl Sidebar {
             }
             
             // Space：在 Filters section 切换启用状态
-            if i.key_pressed(egui::Key::Space) && focused_section == SidebarSection::Filters {
-                if let Some(filter) = filters.get_mut(*selected_index) {
-                    filter.enabled = !filter.enabled;
-                    actions.filter_changed = true;
-                }
+            if i.key_pressed(egui::Key::Space) && focused_section == SidebarSection::Filters
+                && let Some(filter) = filters.get_mut(*selected_index)
+            {
+                filter.enabled = !filter.enabled;
+                actions.filter_changed = true;
             }
 
             // h 或左箭头：向上层级导航
-            // 顺序：存储过程 <- 触发器 <- 筛选 <- 表 <- 数据库 <- 连接
+            // 顺序：序列 <- 存储过程 <- 触发器 <- 筛选 <- 表 <- 数据库 <- 连接
             if i.key_pressed(egui::Key::H) || i.key_pressed(egui::Key::ArrowLeft) {
                 let new_section = match focused_section {
+                    SidebarSection::Sequences => Some(SidebarSection::Routines),
                     SidebarSection::Routines => Some(SidebarSection::Triggers),
                     SidebarSection::Triggers => Some(SidebarSection::Filters),
                     SidebarSection::Filters => Some(SidebarSection::Tables),
@@ -467,7 +583,7 @@ impl Sidebar {
             }
 
             // l 或右箭头：向下层级导航
-            // 顺序：连接 -> 数据库 -> 表 -> 筛选 -> 触发器 -> 存储过程
+            // 顺序：连接 -> 数据库 -> 表 -> 筛选 -> 触发器 -> 存储过程 -> 序列
             if i.key_pressed(egui::Key::L) || i.key_pressed(egui::Key::ArrowRight) {
                 let conn = connection_manager.get_active();
                 let has_databases = conn.map(|c| !c.databases.is_empty()).unwrap_or(false);
@@ -475,6 +591,8 @@ impl Sidebar {
                 let has_filters = !filters.is_empty();
                 let has_triggers = !panel_state.triggers.is_empty();
                 let has_routines = !panel_state.routines.is_empty();
+                // 序列面板默认隐藏，仅在显示时进入
+                let has_sequences = panel_state.show_sequences && !panel_state.sequences.is_empty();
 
                 let new_section = match focused_section {
                     SidebarSection::Connections => {
@@ -496,6 +614,8 @@ impl Sidebar {
                             Some(SidebarSection::Triggers)
                         } else if has_routines {
                             Some(SidebarSection::Routines)
+                        } else if has_sequences {
+                            Some(SidebarSection::Sequences)
                         } else {
                             None
                         }
@@ -505,14 +625,25 @@ impl Sidebar {
                             Some(SidebarSection::Triggers) 
                         } else if has_routines {
                             Some(SidebarSection::Routines)
+                        } else if has_sequences {
+                            Some(SidebarSection::Sequences)
                         } else {
                             None
                         }
                     }
                     SidebarSection::Triggers => {
-                        if has_routines { Some(SidebarSection::Routines) } else { None }
+                        if has_routines {
+                            Some(SidebarSection::Routines)
+                        } else if has_sequences {
+                            Some(SidebarSection::Sequences)
+                        } else {
+                            None
+                        }
+                    }
+                    SidebarSection::Routines => {
+                        if has_sequences { Some(SidebarSection::Sequences) } else { None }
                     }
-                    SidebarSection::Routines => None,
+                    SidebarSection::Sequences => None,
                 };
 
                 if let Some(section) = new_section {
@@ -522,68 +653,84 @@ impl Sidebar {
                 }
             }
 
-            // d：删除选中项（连接/表/筛选条件）
+            // d：删除选中项（连接/表/触发器/存储过程/筛选条件）
             if i.key_pressed(egui::Key::D) && !i.modifiers.ctrl && !i.modifiers.shift {
                 match focused_section {
                     SidebarSection::Connections => {
                         let names: Vec<_> = connection_manager.connections.keys().cloned().collect();
-                        if let Some(name) = names.get(*selected_index) {
+                        if let Some(name) = source(*selected_index).and_then(|i| names.get(i)) {
                             actions.delete = Some(name.clone());
                         }
                     }
                     SidebarSection::Tables => {
-                        // 表删除需要确认对话框，设置删除请求
+                        // 表删除需要输入表名确认
                         if let Some(conn) = connection_manager.get_active()
-                            && let Some(table) = conn.tables.get(*selected_index) {
-                                actions.delete = Some(format!("table:{}", table));
+                            && let Some(table) = source(*selected_index).and_then(|i| conn.tables.get(i)) {
+                                actions.drop_table = Some(table.clone());
                             }
                     }
-                    SidebarSection::Filters => {
+                    SidebarSection::Triggers => {
+                        actions.drop_trigger = source(*selected_index).and_then(|i| panel_state.triggers.get(i)).cloned();
+                    }
+                    SidebarSection::Routines => {
+                        actions.drop_routine = source(*selected_index).and_then(|i| panel_state.routines.get(i)).cloned();
+                    }
+                    SidebarSection::Filters if *selected_index < filters.len() => {
                         // 删除选中的筛选条件
-                        if *selected_index < filters.len() {
-                            filters.remove(*selected_index);
-                            // 调整选中索引
-                            if *selected_index >= filters.len() && !filters.is_empty() {
-                                *selected_index = filters.len() - 1;
-                            }
-                            actions.filter_changed = true;
+                        filters.remove(*selected_index);
+                        // 调整选中索引
+                        if *selected_index >= filters.len() && !filters.is_empty() {
+                            *selected_index = filters.len() - 1;
                         }
+                        actions.filter_changed = true;
                     }
                     _ => {} // 其他 section 暂不支持删除
                 }
             }
             
             // x：在 Filters section 也支持删除（Helix 风格）
-            if i.key_pressed(egui::Key::X) && focused_section == SidebarSection::Filters {
-                if *selected_index < filters.len() {
-                    filters.remove(*selected_index);
-                    if *selected_index >= filters.len() && !filters.is_empty() {
-                        *selected_index = filters.len() - 1;
-                    }
-                    actions.filter_changed = true;
+            if i.key_pressed(egui::Key::X) && focused_section == SidebarSection::Filters
+                && *selected_index < filters.len()
+            {
+                filters.remove(*selected_index);
+                if *selected_index >= filters.len() && !filters.is_empty() {
+                    *selected_index = filters.len() - 1;
                 }
+                actions.filter_changed = true;
             }
 
-            // e：编辑选中的连接配置
+            // e：编辑选中的连接配置/触发器/存储过程
             if i.key_pressed(egui::Key::E) && !i.modifiers.ctrl {
-                if let SidebarSection::Connections = focused_section {
-                    let names: Vec<_> = connection_manager.connections.keys().cloned().collect();
-                    if let Some(name) = names.get(*selected_index) {
-                        actions.edit_connection = Some(name.clone());
+                match focused_section {
+                    SidebarSection::Connections => {
+                        let names: Vec<_> = connection_manager.connections.keys().cloned().collect();
+                        if let Some(name) = source(*selected_index).and_then(|i| names.get(i)) {
+                            actions.edit_connection = Some(name.clone());
+                        }
+                    }
+                    SidebarSection::Triggers => {
+                        actions.edit_trigger = source(*selected_index).and_then(|i| panel_state.triggers.get(i)).cloned();
+                    }
+                    SidebarSection::Routines => {
+                        actions.edit_routine = source(*selected_index).and_then(|i| panel_state.routines.get(i)).cloned();
+                    }
+                    SidebarSection::Sequences => {
+                        actions.restart_sequence = panel_state.sequences.get(*selected_index).cloned();
                     }
+                    _ => {}
                 }
             }
 
             // r：重命名选中项
-            if i.key_pressed(egui::Key::R) && !i.modifiers.ctrl {
+            if i.key_pressed(egui::Key::R) && !i.modifiers.ctrl && !i.modifiers.shift {
                 let item_name = match focused_section {
                     SidebarSection::Connections => {
                         let names: Vec<_> = connection_manager.connections.keys().cloned().collect();
-                        names.get(*selected_index).cloned()
+                        source(*selected_index).and_then(|i| names.get(i)).cloned()
                     }
                     SidebarSection::Tables => {
                         connection_manager.get_active()
-                            .and_then(|c| c.tables.get(*selected_index).cloned())
+                            .and_then(|c| source(*selected_index).and_then(|i| c.tables.get(i)).cloned())
                     }
                     _ => None,
                 };
@@ -623,58 +770,50 @@ impl Sidebar {
                 }
                 
                 // w：切换筛选对象（列）到下一个（Helix: w = word forward）
-                if i.key_pressed(egui::Key::W) && !i.modifiers.ctrl {
-                    if *selected_index < filters.len() {
+                if i.key_pressed(egui::Key::W) && !i.modifiers.ctrl
+                    && *selected_index < filters.len() {
                         actions.cycle_filter_column = Some((*selected_index, true));
                     }
-                }
                 
                 // b：切换筛选对象（列）到上一个（Helix: b = word backward）
-                if i.key_pressed(egui::Key::B) && !i.modifiers.ctrl {
-                    if *selected_index < filters.len() {
+                if i.key_pressed(egui::Key::B) && !i.modifiers.ctrl
+                    && *selected_index < filters.len() {
                         actions.cycle_filter_column = Some((*selected_index, false));
                     }
-                }
                 
                 // n：切换筛选规则（操作符）到下一个（Helix: n = next search）
-                if i.key_pressed(egui::Key::N) && !i.modifiers.ctrl && !i.modifiers.shift {
-                    if let Some(filter) = filters.get_mut(*selected_index) {
+                if i.key_pressed(egui::Key::N) && !i.modifiers.ctrl && !i.modifiers.shift
+                    && let Some(filter) = filters.get_mut(*selected_index) {
                         filter.operator = next_operator(&filter.operator);
                         actions.filter_changed = true;
                     }
-                }
                 
                 // N (Shift+n)：切换筛选规则（操作符）到上一个
-                if i.key_pressed(egui::Key::N) && i.modifiers.shift {
-                    if let Some(filter) = filters.get_mut(*selected_index) {
+                if i.key_pressed(egui::Key::N) && i.modifiers.shift
+                    && let Some(filter) = filters.get_mut(*selected_index) {
                         filter.operator = prev_operator(&filter.operator);
                         actions.filter_changed = true;
                     }
-                }
                 
                 // t：切换当前筛选条件的 AND/OR 逻辑
-                if i.key_pressed(egui::Key::T) {
-                    if *selected_index < filters.len() {
+                if i.key_pressed(egui::Key::T)
+                    && *selected_index < filters.len() {
                         actions.toggle_filter_logic = Some(*selected_index);
                     }
-                }
                 
                 // i：编辑筛选值（Helix: i = insert mode）
-                if i.key_pressed(egui::Key::I) {
-                    if *selected_index < filters.len() {
+                if i.key_pressed(egui::Key::I)
+                    && *selected_index < filters.len() {
                         actions.focus_filter_input = Some(*selected_index);
                     }
-                }
                 
                 // s：切换大小写敏感（Helix: s = select）
-                if i.key_pressed(egui::Key::S) && panel_state.command_buffer.is_empty() {
-                    if let Some(filter) = filters.get_mut(*selected_index) {
-                        if filter.operator.supports_case_sensitivity() {
+                if i.key_pressed(egui::Key::S) && panel_state.command_buffer.is_empty()
+                    && let Some(filter) = filters.get_mut(*selected_index)
+                        && filter.operator.supports_case_sensitivity() {
                             filter.case_sensitive = !filter.case_sensitive;
                             actions.filter_changed = true;
                         }
-                    }
-                }
             }
         });
 
@@ -722,6 +861,11 @@ impl Sidebar {
             if icon_toggle(ui, "📦", panel_state.show_routines, "存储过程面板 (Ctrl+6)") {
                 panel_state.show_routines = !panel_state.show_routines;
             }
+
+            // 7. 序列面板
+            if icon_toggle(ui, "#", panel_state.show_sequences, "序列面板 (Ctrl+7)") {
+                panel_state.show_sequences = !panel_state.show_sequences;
+            }
         });
 
         ui.separator();
@@ -733,6 +877,7 @@ struct PanelHeights {
     connections: f32,
     triggers: f32,
     routines: f32,
+    sequences: f32,
     filters: f32,
 }
 
@@ -758,7 +903,8 @@ fn next_operator(current: &crate::ui::FilterOperator) -> crate::ui::FilterOperat
         IsNotNull => IsEmpty,
         IsEmpty => IsNotEmpty,
         IsNotEmpty => Regex,
-        Regex => Contains,
+        Regex => JsonPath,
+        JsonPath => Contains,
     }
 }
 
@@ -766,7 +912,7 @@ fn next_operator(current: &crate::ui::FilterOperator) -> crate::ui::FilterOperat
 fn prev_operator(current: &crate::ui::FilterOperator) -> crate::ui::FilterOperator {
     use crate::ui::FilterOperator::*;
     match current {
-        Contains => Regex,
+        Contains => JsonPath,
         NotContains => Contains,
         Equals => NotContains,
         NotEquals => Equals,
@@ -785,5 +931,6 @@ fn prev_operator(current: &crate::ui::FilterOperator) -> crate::ui::FilterOperat
         IsEmpty => IsNotNull,
         IsNotEmpty => IsEmpty,
         Regex => IsNotEmpty,
+        JsonPath => Regex,
     }
 }
@@ -0,0 +1,71 @@
+//! 侧边栏快速筛选输入框
+
+use crate::ui::styles::{MUTED, SPACING_LG};
+use crate::ui::SidebarSection;
+use super::SidebarQuickFilters;
+use egui::{self, RichText};
+
+/// 显示区域的快速筛选框
+///
+/// `always` 为 false 时，只在有筛选文本、正在输入或刚按下 "/" 时显示。
+/// 按 Esc 清空筛选，Enter 结束输入并回到键盘导航。
+pub(super) fn quick_filter(
+    ui: &mut egui::Ui,
+    filters: &mut SidebarQuickFilters,
+    section: SidebarSection,
+    indent: f32,
+    hint: &str,
+    always: bool,
+) {
+    let id = egui::Id::new(("sidebar_quick_filter", format!("{:?}", section)));
+    let focus_requested = filters.focus_request == Some(section);
+    let has_focus = ui.memory(|m| m.has_focus(id));
+    let fuzzy = filters.fuzzy;
+    let Some(text) = filters.get_mut(section) else {
+        return;
+    };
+    if !always && text.is_empty() && !focus_requested && !has_focus {
+        return;
+    }
+
+    let mut toggle_fuzzy = false;
+    ui.horizontal(|ui| {
+        ui.add_space(indent);
+        let response = ui
+            .add(
+                egui::TextEdit::singleline(text)
+                    .id(id)
+                    .hint_text(format!("🔍 {}", hint))
+                    .desired_width(ui.available_width() - SPACING_LG - 20.0)
+                    .font(egui::TextStyle::Small),
+            )
+            .on_hover_text("按 / 开始筛选，Enter 回到列表，Esc 清空");
+        if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Escape)) {
+            text.clear();
+        }
+        // 在输入框处理完本帧的按键后再获取焦点，避免 "/" 被输入
+        if focus_requested {
+            response.request_focus();
+        }
+
+        let color = if fuzzy { egui::Color32::from_rgb(130, 180, 255) } else { MUTED };
+        if ui
+            .add(egui::Button::new(RichText::new("≈").small().color(color)).frame(false))
+            .on_hover_text(if fuzzy {
+                "模糊匹配：按顺序包含所有字符（点击切换为子串匹配）"
+            } else {
+                "子串匹配（点击切换为模糊匹配）"
+            })
+            .clicked()
+        {
+            toggle_fuzzy = true;
+        }
+    });
+
+    if focus_requested {
+        filters.focus_request = None;
+    }
+    if toggle_fuzzy {
+        filters.fuzzy = !filters.fuzzy;
+    }
+}
@@ -2,8 +2,9 @@
 
 use crate::database::{RoutineInfo, RoutineType};
 use crate::ui::styles::{GRAY, MUTED, SUCCESS, SPACING_LG, SPACING_SM, MARGIN_SM};
-use crate::ui::SidebarSection;
-use super::SidebarPanelState;
+use crate::ui::{DbObjectKind, SidebarSection};
+use super::quick_filter::quick_filter;
+use super::{SidebarActions, SidebarPanelState};
 use egui::{self, Color32, RichText, CornerRadius, Vec2};
 
 /// 存储过程/函数面板
@@ -17,6 +18,7 @@ impl RoutinePanel {
         focused_section: SidebarSection,
         panel_state: &mut SidebarPanelState,
         height: f32,
+        actions: &mut SidebarActions,
     ) {
         // 标题栏
         ui.horizontal(|ui| {
@@ -43,9 +45,25 @@ impl RoutinePanel {
             if panel_state.loading_routines {
                 ui.spinner();
             }
+
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                ui.menu_button(RichText::new("+").size(13.0).color(Color32::from_rgb(100, 180, 100)), |ui| {
+                    if ui.button("新建函数").clicked() {
+                        actions.create_object = Some(DbObjectKind::Function);
+                        ui.close();
+                    }
+                    if ui.button("新建存储过程").clicked() {
+                        actions.create_object = Some(DbObjectKind::Procedure);
+                        ui.close();
+                    }
+                })
+                .response
+                .on_hover_text("新建存储过程/函数");
+            });
         });
         
         ui.separator();
+        quick_filter(ui, &mut panel_state.quick_filters, SidebarSection::Routines, 0.0, "筛选过程/函数", false);
         
         // 存储过程/函数列表
         let scroll_width = ui.available_width();
@@ -66,10 +84,17 @@ impl RoutinePanel {
                         ui.label(RichText::new("SQLite 不支持存储过程").small().color(GRAY));
                     });
                 } else {
-                    for (idx, routine) in panel_state.routines.iter().enumerate() {
+                    let visible = panel_state
+                        .quick_filters
+                        .indices(SidebarSection::Routines, panel_state.routines.iter().map(|r| &r.name));
+                    if visible.is_empty() {
+                        ui.label(RichText::new("没有匹配的存储过程/函数").italics().small().color(MUTED));
+                    }
+                    for (idx, &routine_idx) in visible.iter().enumerate() {
+                        let routine = &panel_state.routines[routine_idx];
                         let is_nav_selected = highlight_routines && idx == selected_idx;
                         
-                        let response = Self::show_routine_item(ui, routine, is_nav_selected);
+                        let response = Self::show_routine_item(ui, routine, is_nav_selected, actions);
                         
                         // 如果是选中项且有焦点，滚动到可见
                         if is_nav_selected && highlight_routines {
@@ -81,7 +106,12 @@ impl RoutinePanel {
     }
     
     /// 显示单个存储过程/函数项，返回 Response 用于滚动控制
-    fn show_routine_item(ui: &mut egui::Ui, routine: &RoutineInfo, is_nav_selected: bool) -> egui::Response {
+    fn show_routine_item(
+        ui: &mut egui::Ui,
+        routine: &RoutineInfo,
+        is_nav_selected: bool,
+        actions: &mut SidebarActions,
+    ) -> egui::Response {
         let bg_color = if is_nav_selected {
             Color32::from_rgba_unmultiplied(100, 150, 255, 35)
         } else {
@@ -174,6 +204,22 @@ impl RoutinePanel {
                 ui.ctx().copy_text(routine.definition.clone());
                 ui.close();
             }
+            if ui.add(
+                egui::Button::new(RichText::new("✏ 编辑").size(13.0).color(Color32::LIGHT_GRAY))
+                    .frame(false)
+                    .min_size(Vec2::new(0.0, 24.0)),
+            ).on_hover_text(format!("编辑{}定义 [e]", routine.routine_type)).clicked() {
+                actions.edit_routine = Some(routine.clone());
+                ui.close();
+            }
+            if ui.add(
+                egui::Button::new(RichText::new("🗑 删除").size(13.0).color(Color32::from_rgb(235, 87, 87)))
+                    .frame(false)
+                    .min_size(Vec2::new(0.0, 24.0)),
+            ).on_hover_text(format!("删除{} [d]", routine.routine_type)).clicked() {
+                actions.drop_routine = Some(routine.clone());
+                ui.close();
+            }
         });
         
         // 悬停显示完整参数
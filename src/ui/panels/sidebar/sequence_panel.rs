@@ -0,0 +1,149 @@
+//! 序列/自增计数器面板渲染
+
+use crate::database::SequenceInfo;
+use crate::ui::styles::{GRAY, MUTED, SUCCESS, SPACING_LG, SPACING_SM, MARGIN_SM};
+use crate::ui::SidebarSection;
+use super::{SidebarActions, SidebarPanelState};
+use egui::{self, Color32, RichText, CornerRadius, Vec2};
+
+/// 序列面板
+pub struct SequencePanel;
+
+impl SequencePanel {
+    /// 显示序列面板（PostgreSQL 序列，MySQL/SQLite 自增计数器）
+    pub fn show(
+        ui: &mut egui::Ui,
+        is_focused: bool,
+        focused_section: SidebarSection,
+        panel_state: &mut SidebarPanelState,
+        height: f32,
+        actions: &mut SidebarActions,
+    ) {
+        // 标题栏
+        ui.horizontal(|ui| {
+            let sequence_count = panel_state.sequences.len();
+            let title = if sequence_count > 0 {
+                format!("序列 ({})", sequence_count)
+            } else {
+                "序列".to_string()
+            };
+
+            ui.label(RichText::new(title).strong());
+
+            // 显示当前焦点区域提示
+            if is_focused && focused_section == SidebarSection::Sequences {
+                ui.label(RichText::new("*").small().color(SUCCESS));
+            }
+
+            // 加载指示器
+            if panel_state.loading_sequences {
+                ui.spinner();
+            }
+        });
+
+        ui.separator();
+
+        let scroll_width = ui.available_width();
+        let highlight_sequences = is_focused && focused_section == SidebarSection::Sequences;
+        let selected_idx = panel_state.selection.sequences;
+
+        egui::ScrollArea::vertical()
+            .id_salt("sequence_scroll")
+            .max_height(height - 30.0)
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                ui.set_max_width(scroll_width);
+                if panel_state.sequences.is_empty() {
+                    ui.vertical_centered(|ui| {
+                        ui.add_space(SPACING_LG);
+                        ui.label(RichText::new("暂无序列").small().color(MUTED));
+                        ui.add_space(SPACING_SM);
+                        ui.label(RichText::new("支持 PostgreSQL 序列和 MySQL/SQLite 自增列").small().color(GRAY));
+                    });
+                } else {
+                    for (idx, sequence) in panel_state.sequences.iter().enumerate() {
+                        let is_nav_selected = highlight_sequences && idx == selected_idx;
+
+                        let response = Self::show_sequence_item(ui, sequence, is_nav_selected, actions);
+
+                        // 如果是选中项且有焦点，滚动到可见
+                        if is_nav_selected && highlight_sequences {
+                            response.scroll_to_me(Some(egui::Align::Center));
+                        }
+                    }
+                }
+            });
+    }
+
+    /// 显示单个序列项，返回 Response 用于滚动控制
+    fn show_sequence_item(
+        ui: &mut egui::Ui,
+        sequence: &SequenceInfo,
+        is_nav_selected: bool,
+        actions: &mut SidebarActions,
+    ) -> egui::Response {
+        let bg_color = if is_nav_selected {
+            Color32::from_rgba_unmultiplied(100, 150, 255, 35)
+        } else {
+            Color32::TRANSPARENT
+        };
+
+        let response = egui::Frame::NONE
+            .fill(bg_color)
+            .corner_radius(CornerRadius::same(4))
+            .inner_margin(egui::Margin::symmetric(MARGIN_SM, 4))
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    let icon = if is_nav_selected { ">" } else { "#" };
+                    let text_color = if is_nav_selected {
+                        Color32::from_rgb(100, 180, 255)
+                    } else {
+                        Color32::from_rgb(180, 180, 190)
+                    };
+
+                    ui.label(RichText::new(icon).color(text_color));
+
+                    ui.vertical(|ui| {
+                        // 序列名称，绑定到列时显示所属列
+                        ui.label(RichText::new(&sequence.name).color(text_color));
+                        if let (Some(table), Some(column)) = (&sequence.table_name, &sequence.column_name) {
+                            ui.label(RichText::new(format!("{}.{}", table, column)).small().color(MUTED));
+                        }
+
+                        let current = sequence
+                            .last_value
+                            .map(|v| v.to_string())
+                            .unwrap_or_else(|| "-".to_string());
+                        let next = sequence
+                            .next_value
+                            .map(|v| v.to_string())
+                            .unwrap_or_else(|| "-".to_string());
+                        ui.label(RichText::new(format!("当前 {} · 下一个 {}", current, next)).small().color(MUTED));
+                    });
+                });
+            })
+            .response
+            .interact(egui::Sense::click());
+
+        response.context_menu(|ui| {
+            if ui.add(
+                egui::Button::new(RichText::new("↻ 设置下一个值").size(13.0).color(Color32::LIGHT_GRAY))
+                    .frame(false)
+                    .min_size(Vec2::new(0.0, 24.0)),
+            ).on_hover_text("重置序列/自增计数器 [e]").clicked() {
+                actions.restart_sequence = Some(sequence.clone());
+                ui.close();
+            }
+            if ui.add(
+                egui::Button::new(RichText::new("📋 复制名称").size(13.0).color(Color32::LIGHT_GRAY))
+                    .frame(false)
+                    .min_size(Vec2::new(0.0, 24.0)),
+            ).clicked() {
+                ui.ctx().copy_text(sequence.name.clone());
+                ui.close();
+            }
+        });
+
+        response
+    }
+}
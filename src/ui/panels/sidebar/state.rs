@@ -1,6 +1,10 @@
 //! 侧边栏状态定义
 
-use crate::database::{RoutineInfo, TriggerInfo};
+use std::collections::HashMap;
+
+use crate::core::fuzzy_score;
+use crate::database::{RoutineInfo, SequenceInfo, TriggerInfo};
+use crate::ui::SidebarSection;
 
 /// 侧边栏各区域的选中索引
 #[derive(Debug, Clone, Default)]
@@ -15,6 +19,8 @@ pub struct SidebarSelectionState {
     pub triggers: usize,
     /// 存储过程/函数列表选中索引
     pub routines: usize,
+    /// 序列列表选中索引
+    pub sequences: usize,
     /// 筛选条件选中索引
     pub filters: usize,
 }
@@ -26,6 +32,7 @@ impl SidebarSelectionState {
         self.tables = 0;
         self.triggers = 0;
         self.routines = 0;
+        self.sequences = 0;
     }
     
     /// 重置表相关的选中索引（切换数据库时调用）
@@ -33,6 +40,7 @@ impl SidebarSelectionState {
         self.tables = 0;
         self.triggers = 0;
         self.routines = 0;
+        self.sequences = 0;
     }
 }
 
@@ -69,13 +77,33 @@ pub struct SidebarPanelState {
     /// 是否正在加载存储过程
     pub loading_routines: bool,
     
+    // ===== 序列面板 =====
+    /// 序列/自增计数器面板是否显示
+    pub show_sequences: bool,
+    /// 序列面板高度比例
+    pub sequences_ratio: f32,
+    /// 序列/自增计数器列表
+    pub sequences: Vec<SequenceInfo>,
+    /// 是否正在加载序列
+    pub loading_sequences: bool,
+    
     // ===== 筛选面板 =====
     /// 筛选面板是否显示
     pub show_filters: bool,
     /// 筛选面板高度比例
     pub filters_ratio: f32,
     
+    // ===== 表列表 =====
+    /// 是否正在加载下一批表
+    pub loading_tables: bool,
+    
+    // ===== 查询队列 =====
+    /// 各连接排队中的查询数（由应用每帧更新，只包含有排队查询的连接）
+    pub queued_queries: HashMap<String, usize>,
+
     // ===== 其他状态 =====
+    /// 各区域的快速筛选
+    pub quick_filters: SidebarQuickFilters,
     /// 各区域的选中状态
     pub selection: SidebarSelectionState,
     /// 当前正在拖动的分割条索引 (0=连接/触发器, 1=触发器/存储过程)
@@ -105,10 +133,21 @@ impl Default for SidebarPanelState {
             routine_selected_index: 0,
             loading_routines: false,
             
+            // 序列面板 - 默认隐藏（Ctrl+7 打开），占 20%
+            show_sequences: false,
+            sequences_ratio: 0.2,
+            sequences: Vec::new(),
+            loading_sequences: false,
+            
             // 筛选面板 - 默认显示，占 20%
             show_filters: true,
             filters_ratio: 0.2,
             
+            loading_tables: false,
+
+            queued_queries: HashMap::new(),
+            
+            quick_filters: SidebarQuickFilters::default(),
             selection: SidebarSelectionState::default(),
             dragging_divider: None,
             command_buffer: String::new(),
@@ -146,4 +185,91 @@ impl SidebarPanelState {
         self.selection.routines = 0;
         self.loading_routines = false;
     }
+    
+    /// 设置序列列表（刷新后尽量保持选中位置）
+    pub fn set_sequences(&mut self, sequences: Vec<SequenceInfo>) {
+        self.selection.sequences = self.selection.sequences.min(sequences.len().saturating_sub(1));
+        self.sequences = sequences;
+        self.loading_sequences = false;
+    }
+}
+
+/// 侧边栏各区域的快速筛选（区域获得焦点时按 "/" 输入）
+///
+/// 键盘导航的索引基于筛选后的列表。
+#[derive(Debug, Clone, Default)]
+pub struct SidebarQuickFilters {
+    pub connections: String,
+    pub databases: String,
+    pub tables: String,
+    pub triggers: String,
+    pub routines: String,
+    /// 模糊匹配（按顺序包含筛选中的所有字符），否则为子串匹配
+    pub fuzzy: bool,
+    /// 需要获得焦点的筛选框
+    pub focus_request: Option<SidebarSection>,
+}
+
+impl SidebarQuickFilters {
+    /// 区域是否支持快速筛选
+    pub fn supports(section: SidebarSection) -> bool {
+        matches!(
+            section,
+            SidebarSection::Connections
+                | SidebarSection::Databases
+                | SidebarSection::Tables
+                | SidebarSection::Triggers
+                | SidebarSection::Routines
+        )
+    }
+
+    /// 区域的筛选文本（不支持筛选的区域为空）
+    pub fn get(&self, section: SidebarSection) -> &str {
+        match section {
+            SidebarSection::Connections => &self.connections,
+            SidebarSection::Databases => &self.databases,
+            SidebarSection::Tables => &self.tables,
+            SidebarSection::Triggers => &self.triggers,
+            SidebarSection::Routines => &self.routines,
+            SidebarSection::Filters | SidebarSection::Sequences => "",
+        }
+    }
+
+    /// 区域的筛选文本（可修改）
+    pub fn get_mut(&mut self, section: SidebarSection) -> Option<&mut String> {
+        match section {
+            SidebarSection::Connections => Some(&mut self.connections),
+            SidebarSection::Databases => Some(&mut self.databases),
+            SidebarSection::Tables => Some(&mut self.tables),
+            SidebarSection::Triggers => Some(&mut self.triggers),
+            SidebarSection::Routines => Some(&mut self.routines),
+            SidebarSection::Filters | SidebarSection::Sequences => None,
+        }
+    }
+
+    /// 区域中符合筛选的项在原列表中的索引
+    pub fn indices<S: AsRef<str>>(&self, section: SidebarSection, items: impl IntoIterator<Item = S>) -> Vec<usize> {
+        filter_indices(items, self.get(section), self.fuzzy)
+    }
+}
+
+/// 符合筛选的项在列表中的索引（筛选为空时返回全部）
+///
+/// 子串匹配不区分大小写；模糊匹配要求按顺序包含筛选中的所有字符。
+pub fn filter_indices<S: AsRef<str>>(items: impl IntoIterator<Item = S>, filter: &str, fuzzy: bool) -> Vec<usize> {
+    let filter = filter.trim().to_lowercase();
+    items
+        .into_iter()
+        .enumerate()
+        .filter(|(_, item)| {
+            let item = item.as_ref();
+            filter.is_empty()
+                || if fuzzy {
+                    fuzzy_score(&filter, item).is_some()
+                } else {
+                    item.to_lowercase().contains(&filter)
+                }
+        })
+        .map(|(i, _)| i)
+        .collect()
 }
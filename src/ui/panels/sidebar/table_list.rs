@@ -1,11 +1,75 @@
 //! 表列表渲染
 
-use crate::database::ConnectionManager;
+use std::ops::Range;
+
+use crate::core::{t, tf};
+use crate::database::{ConnectionManager, MaintenanceOp};
 use crate::ui::styles::{GRAY, MUTED, SPACING_SM, SPACING_LG};
 use crate::ui::SidebarSection;
-use super::{SidebarActions, SidebarSelectionState};
+use super::quick_filter::quick_filter;
+use super::{MaintenanceTarget, SidebarActions, SidebarPanelState};
 use egui::{self, Color32, RichText, CornerRadius};
 
+/// 维护操作子菜单（数据库不支持时不显示）
+pub(super) fn maintenance_menu(
+    ui: &mut egui::Ui,
+    ops: &[MaintenanceOp],
+    target: MaintenanceTarget,
+    actions: &mut SidebarActions,
+) {
+    if ops.is_empty() {
+        return;
+    }
+    ui.menu_button(t("sidebar.maintenance"), |ui| {
+        for op in ops {
+            if ui.button(format!("{} ({})", op.display_name(), op.description())).clicked() {
+                actions.maintenance = Some((*op, target.clone()));
+                ui.close();
+            }
+        }
+    });
+}
+
+/// 表项的高度（文字高度加上下内边距，固定高度便于只渲染可见的行）
+fn row_height(ui: &egui::Ui) -> f32 {
+    ui.text_style_height(&egui::TextStyle::Body) + 10.0
+}
+
+/// 在滚动区域中可见的行范围（每行占 `stride` 高度，从当前光标处开始）
+fn visible_rows(ui: &egui::Ui, stride: f32, count: usize) -> Range<usize> {
+    let top = ui.cursor().top();
+    let clip = ui.clip_rect();
+    let first = ((clip.top() - top) / stride).floor().max(0.0) as usize;
+    let last = ((clip.bottom() - top) / stride).ceil().max(0.0) as usize + 1;
+    first.min(count)..last.min(count)
+}
+
+/// 列表末尾的"加载更多"按钮（表是分批读取的且还有未读取的表时显示）
+fn load_more(
+    ui: &mut egui::Ui,
+    loaded: usize,
+    has_more: bool,
+    loading: bool,
+    indent: f32,
+    actions: &mut SidebarActions,
+) {
+    if !has_more {
+        return;
+    }
+    ui.horizontal(|ui| {
+        ui.add_space(indent);
+        if loading {
+            ui.spinner();
+            ui.label(RichText::new(t("sidebar.loading")).small().color(MUTED));
+        } else if ui
+            .small_button(tf("sidebar.load_more", &[&loaded.to_string()]))
+            .clicked()
+        {
+            actions.load_more_tables = true;
+        }
+    });
+}
+
 /// 表列表
 pub struct TableList;
 
@@ -16,41 +80,58 @@ impl TableList {
         ui: &mut egui::Ui,
         conn_name: &str,
         tables: &[String],
+        has_more: bool,
         connection_manager: &mut ConnectionManager,
         selected_table: &mut Option<String>,
         actions: &mut SidebarActions,
         is_focused: bool,
         focused_section: SidebarSection,
-        selection: &SidebarSelectionState,
+        panel_state: &mut SidebarPanelState,
     ) {
         let highlight_tables = is_focused && focused_section == SidebarSection::Tables;
+        let maintenance_ops = connection_manager
+            .connections
+            .get(conn_name)
+            .map_or(&[][..], |c| MaintenanceOp::available(c.config.db_type, true));
         if tables.is_empty() {
             ui.horizontal(|ui| {
                 ui.add_space(SPACING_LG);
-                ui.label(RichText::new("暂无数据表").italics().small().color(MUTED));
+                ui.label(RichText::new(t("sidebar.no_tables")).italics().small().color(MUTED));
             });
             return;
         }
 
+        let indices = panel_state.quick_filters.indices(SidebarSection::Tables, tables);
+        let more = if has_more { "+" } else { "" };
+
         // 表列表标题
         ui.horizontal(|ui| {
             ui.add_space(SPACING_LG);
-            ui.label(
-                RichText::new(format!("数据表 ({})", tables.len()))
-                    .small()
-                    .strong()
-                    .color(GRAY),
-            );
+            let title = if indices.len() == tables.len() {
+                tf("sidebar.tables_title", &[&tables.len().to_string(), more])
+            } else {
+                tf("sidebar.tables_title_filtered", &[&indices.len().to_string(), &tables.len().to_string(), more])
+            };
+            ui.label(RichText::new(title).small().strong().color(GRAY));
         });
 
         ui.add_space(SPACING_SM);
+        let hint = if has_more { t("sidebar.filter_loaded_tables") } else { t("sidebar.filter_tables") };
+        quick_filter(ui, &mut panel_state.quick_filters, SidebarSection::Tables, SPACING_LG, hint, true);
 
-        // 表列表
-        for (idx, table) in tables.iter().enumerate() {
+        // 表列表（只渲染可见的行）
+        let row_height = row_height(ui);
+        let stride = row_height + ui.spacing().item_spacing.y;
+        let list_top = ui.cursor().top();
+        let range = visible_rows(ui, stride, indices.len());
+        ui.add_space(range.start as f32 * stride);
+        for (idx, &table_idx) in indices.iter().enumerate().take(range.end).skip(range.start) {
+            let table = &tables[table_idx];
             let is_selected = selected_table.as_deref() == Some(table);
-            let is_nav_selected = highlight_tables && idx == selection.tables;
+            let is_nav_selected = highlight_tables && idx == panel_state.selection.tables;
 
             let row_response = ui.horizontal(|ui| {
+                ui.set_min_height(row_height);
                 ui.add_space(SPACING_LG + 4.0);
 
                 // 表项
@@ -87,14 +168,44 @@ impl TableList {
 
                 // 右键菜单
                 response.context_menu(|ui| {
-                    if ui.button("📊 查询前 100 行").clicked() {
+                    if ui.button(t("sidebar.query_top_icon")).clicked() {
                         actions.query_table = Some(table.clone());
                         ui.close();
                     }
-                    if ui.button("🔍 查看表结构").clicked() {
+                    if ui.button(t("sidebar.structure_icon")).clicked() {
                         actions.show_table_schema = Some(table.clone());
                         ui.close();
                     }
+                    if ui.button(t("sidebar.ddl_icon")).clicked() {
+                        actions.show_table_ddl = Some(table.clone());
+                        ui.close();
+                    }
+                    if ui.button(t("sidebar.dependencies_icon")).clicked() {
+                        actions.show_dependencies = Some(table.clone());
+                        ui.close();
+                    }
+                    maintenance_menu(ui, maintenance_ops, MaintenanceTarget::Table(table.clone()), actions);
+                    ui.separator();
+                    if ui.button(t("sidebar.rename_icon")).clicked() {
+                        actions.rename_item = Some((SidebarSection::Tables, table.clone()));
+                        ui.close();
+                    }
+                    if ui.button(t("sidebar.duplicate_icon")).clicked() {
+                        actions.duplicate_table = Some(table.clone());
+                        ui.close();
+                    }
+                    if ui.button(t("sidebar.generate_data_icon")).clicked() {
+                        actions.generate_test_data = Some(table.clone());
+                        ui.close();
+                    }
+                    if ui.button(t("sidebar.truncate_icon")).clicked() {
+                        actions.truncate_table = Some(table.clone());
+                        ui.close();
+                    }
+                    if ui.button(t("sidebar.drop_icon")).clicked() {
+                        actions.drop_table = Some(table.clone());
+                        ui.close();
+                    }
                 });
             }).response;
             
@@ -103,6 +214,35 @@ impl TableList {
                 row_response.scroll_to_me(Some(egui::Align::Center));
             }
         }
+        ui.add_space((indices.len() - range.end) as f32 * stride);
+        if highlight_tables {
+            Self::scroll_to_offscreen(ui, list_top, stride, &range, panel_state.selection.tables, indices.len());
+        }
+
+        if indices.is_empty() {
+            ui.horizontal(|ui| {
+                ui.add_space(SPACING_LG + 4.0);
+                ui.label(RichText::new(t("sidebar.no_matching_tables")).italics().small().color(MUTED));
+            });
+        }
+        load_more(ui, tables.len(), has_more, panel_state.loading_tables, SPACING_LG + 4.0, actions);
+    }
+
+    /// 键盘选中的行不在渲染范围内时，滚动到该行（渲染后再由行自身保持居中）
+    fn scroll_to_offscreen(
+        ui: &egui::Ui,
+        list_top: f32,
+        stride: f32,
+        range: &Range<usize>,
+        nav_index: usize,
+        count: usize,
+    ) {
+        if nav_index >= count || range.contains(&nav_index) {
+            return;
+        }
+        let top = list_top + nav_index as f32 * stride;
+        let rect = egui::Rect::from_x_y_ranges(ui.max_rect().x_range(), top..=top + stride);
+        ui.scroll_to_rect(rect, Some(egui::Align::Center));
     }
 
     /// 显示嵌套的表列表（在数据库下方）
@@ -111,19 +251,37 @@ impl TableList {
         ui: &mut egui::Ui,
         conn_name: &str,
         tables: &[String],
+        has_more: bool,
         connection_manager: &mut ConnectionManager,
         selected_table: &mut Option<String>,
         actions: &mut SidebarActions,
         highlight_tables: bool,
-        nav_index: usize,
+        panel_state: &mut SidebarPanelState,
     ) {
-        // 表列表
-        for (idx, table) in tables.iter().enumerate() {
+        let maintenance_ops = connection_manager
+            .connections
+            .get(conn_name)
+            .map_or(&[][..], |c| MaintenanceOp::available(c.config.db_type, true));
+        let indices = panel_state.quick_filters.indices(SidebarSection::Tables, tables);
+        let nav_index = panel_state.selection.tables;
+
+        let hint = if has_more { t("sidebar.filter_loaded_tables") } else { t("sidebar.filter_tables") };
+        quick_filter(ui, &mut panel_state.quick_filters, SidebarSection::Tables, SPACING_LG, hint, true);
+
+        // 表列表（只渲染可见的行）
+        let row_height = row_height(ui);
+        let stride = row_height + ui.spacing().item_spacing.y;
+        let list_top = ui.cursor().top();
+        let range = visible_rows(ui, stride, indices.len());
+        ui.add_space(range.start as f32 * stride);
+        for (idx, &table_idx) in indices.iter().enumerate().take(range.end).skip(range.start) {
+            let table = &tables[table_idx];
             let is_nav_selected = highlight_tables && idx == nav_index;
             let is_selected = selected_table.as_deref() == Some(table);
 
             // 表项 - 带缩进
             let row_response = ui.horizontal(|ui| {
+                ui.set_min_height(row_height);
                 ui.add_space(SPACING_LG);
 
                 let table_bg = if is_nav_selected {
@@ -160,14 +318,44 @@ impl TableList {
 
                 // 右键菜单
                 response.context_menu(|ui| {
-                    if ui.button("查询前 100 行").clicked() {
+                    if ui.button(t("sidebar.query_top")).clicked() {
                         actions.query_table = Some(table.clone());
                         ui.close();
                     }
-                    if ui.button("查看表结构").clicked() {
+                    if ui.button(t("sidebar.structure")).clicked() {
                         actions.show_table_schema = Some(table.clone());
                         ui.close();
                     }
+                    if ui.button(t("sidebar.ddl")).clicked() {
+                        actions.show_table_ddl = Some(table.clone());
+                        ui.close();
+                    }
+                    if ui.button(t("sidebar.dependencies")).clicked() {
+                        actions.show_dependencies = Some(table.clone());
+                        ui.close();
+                    }
+                    maintenance_menu(ui, maintenance_ops, MaintenanceTarget::Table(table.clone()), actions);
+                    ui.separator();
+                    if ui.button(t("sidebar.rename")).clicked() {
+                        actions.rename_item = Some((SidebarSection::Tables, table.clone()));
+                        ui.close();
+                    }
+                    if ui.button(t("sidebar.duplicate")).clicked() {
+                        actions.duplicate_table = Some(table.clone());
+                        ui.close();
+                    }
+                    if ui.button(t("sidebar.generate_data")).clicked() {
+                        actions.generate_test_data = Some(table.clone());
+                        ui.close();
+                    }
+                    if ui.button(t("sidebar.truncate")).clicked() {
+                        actions.truncate_table = Some(table.clone());
+                        ui.close();
+                    }
+                    if ui.button(t("sidebar.drop")).clicked() {
+                        actions.drop_table = Some(table.clone());
+                        ui.close();
+                    }
                 });
             }).response;
             
@@ -176,5 +364,17 @@ impl TableList {
                 row_response.scroll_to_me(Some(egui::Align::Center));
             }
         }
+        ui.add_space((indices.len() - range.end) as f32 * stride);
+        if highlight_tables {
+            Self::scroll_to_offscreen(ui, list_top, stride, &range, nav_index, indices.len());
+        }
+
+        if indices.is_empty() {
+            ui.horizontal(|ui| {
+                ui.add_space(SPACING_LG);
+                ui.label(RichText::new(t("sidebar.no_matching_tables")).italics().small().color(MUTED));
+            });
+        }
+        load_more(ui, tables.len(), has_more, panel_state.loading_tables, SPACING_LG, actions);
     }
 }
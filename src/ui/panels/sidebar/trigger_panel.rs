@@ -2,8 +2,9 @@
 
 use crate::database::TriggerInfo;
 use crate::ui::styles::{GRAY, MUTED, SUCCESS, SPACING_LG, SPACING_SM, MARGIN_SM};
-use crate::ui::SidebarSection;
-use super::SidebarPanelState;
+use crate::ui::{DbObjectKind, SidebarSection};
+use super::quick_filter::quick_filter;
+use super::{SidebarActions, SidebarPanelState};
 use egui::{self, Color32, RichText, CornerRadius, Vec2};
 
 /// 触发器面板
@@ -17,6 +18,7 @@ impl TriggerPanel {
         focused_section: SidebarSection,
         panel_state: &mut SidebarPanelState,
         height: f32,
+        actions: &mut SidebarActions,
     ) {
         // 标题栏
         ui.horizontal(|ui| {
@@ -38,9 +40,24 @@ impl TriggerPanel {
             if panel_state.loading_triggers {
                 ui.spinner();
             }
+
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui
+                    .add(
+                        egui::Button::new(RichText::new("+").size(13.0).color(Color32::from_rgb(100, 180, 100)))
+                            .frame(false)
+                            .min_size(Vec2::new(18.0, 18.0)),
+                    )
+                    .on_hover_text("新建触发器")
+                    .clicked()
+                {
+                    actions.create_object = Some(DbObjectKind::Trigger);
+                }
+            });
         });
         
         ui.separator();
+        quick_filter(ui, &mut panel_state.quick_filters, SidebarSection::Triggers, 0.0, "筛选触发器", false);
         
         // 触发器列表 - 使用固定宽度防止内容扩展面板
         let scroll_width = ui.available_width();
@@ -61,10 +78,17 @@ impl TriggerPanel {
                         ui.label(RichText::new("选择数据库后自动加载").small().color(GRAY));
                     });
                 } else {
-                    for (idx, trigger) in panel_state.triggers.iter().enumerate() {
+                    let visible = panel_state
+                        .quick_filters
+                        .indices(SidebarSection::Triggers, panel_state.triggers.iter().map(|t| &t.name));
+                    if visible.is_empty() {
+                        ui.label(RichText::new("没有匹配的触发器").italics().small().color(MUTED));
+                    }
+                    for (idx, &trigger_idx) in visible.iter().enumerate() {
+                        let trigger = &panel_state.triggers[trigger_idx];
                         let is_nav_selected = highlight_triggers && idx == selected_idx;
                         
-                        let response = Self::show_trigger_item(ui, trigger, is_nav_selected);
+                        let response = Self::show_trigger_item(ui, trigger, is_nav_selected, actions);
                         
                         // 如果是选中项且有焦点，滚动到可见
                         if is_nav_selected && highlight_triggers {
@@ -76,7 +100,12 @@ impl TriggerPanel {
     }
     
     /// 显示单个触发器项，返回 Response 用于滚动控制
-    fn show_trigger_item(ui: &mut egui::Ui, trigger: &TriggerInfo, is_nav_selected: bool) -> egui::Response {
+    fn show_trigger_item(
+        ui: &mut egui::Ui,
+        trigger: &TriggerInfo,
+        is_nav_selected: bool,
+        actions: &mut SidebarActions,
+    ) -> egui::Response {
         let bg_color = if is_nav_selected {
             Color32::from_rgba_unmultiplied(100, 150, 255, 35)  // 降低透明度
         } else {
@@ -138,6 +167,22 @@ impl TriggerPanel {
                 ui.ctx().copy_text(trigger.definition.clone());
                 ui.close();
             }
+            if ui.add(
+                egui::Button::new(RichText::new("✏ 编辑").size(13.0).color(Color32::LIGHT_GRAY))
+                    .frame(false)
+                    .min_size(Vec2::new(0.0, 24.0)),
+            ).on_hover_text("编辑触发器定义 [e]").clicked() {
+                actions.edit_trigger = Some(trigger.clone());
+                ui.close();
+            }
+            if ui.add(
+                egui::Button::new(RichText::new("🗑 删除").size(13.0).color(Color32::from_rgb(235, 87, 87)))
+                    .frame(false)
+                    .min_size(Vec2::new(0.0, 24.0)),
+            ).on_hover_text("删除触发器 [d]").clicked() {
+                actions.drop_trigger = Some(trigger.clone());
+                ui.close();
+            }
         });
         
         response
@@ -16,3 +16,25 @@ fn test_table_completion() {
     let completions = ac.get_completions("SELECT * FROM us", 16);
     assert!(completions.iter().any(|c| c.label == "users"));
 }
+
+#[test]
+fn test_clickhouse_dialect_completion() {
+    use gridix::database::DatabaseType;
+
+    let mut ac = AutoComplete::new();
+    assert!(!ac.get_completions("PREW", 4).iter().any(|c| c.label == "PREWHERE"));
+
+    ac.set_dialect(DatabaseType::ClickHouse);
+    let completions = ac.get_completions("SELECT * FROM t PREW", 20);
+    assert!(completions.iter().any(|c| c.label == "PREWHERE"));
+
+    // 驼峰函数名按原样插入
+    let completions = ac.get_completions("SELECT touin", 12);
+    let item = completions.iter().find(|c| c.label == "toUInt64()").expect("toUInt64");
+    assert_eq!(item.insert_text, "toUInt64(");
+    assert_eq!(item.detail.as_deref(), Some("ClickHouse 函数"));
+
+    // 切换到其他数据库后不再提示方言项
+    ac.set_dialect(DatabaseType::PostgreSQL);
+    assert!(!ac.get_completions("SELECT touin", 12).iter().any(|c| c.label == "toUInt64()"));
+}
@@ -1,14 +1,52 @@
 //! Core 模块测试
 
 use gridix::core::{
-    NotificationManager,
+    NotificationManager, NotificationLevel, NOTIFICATION_HISTORY_LIMIT,
     ProgressManager,
     KeyBinding, KeyBindings, KeyCode, Action,
     SessionState, TabState,
     AutoComplete,
     format_sql,
     SqlHighlighter, HighlightColors,
+    generate_rows, parse_pattern, GenValue, Rng, ValueGenerator,
+    diff_results, DiffKind,
+    QueryScheduler, Schedule, ScheduledQuery, Threshold, ThresholdOp, ThresholdTarget,
+    HistoryFilter, QueryHistory, QueryHistoryItem,
+    side_by_side_diff, LineChange,
+    find_matching_bracket, toggle_line_comment, MultiCursor,
+    audit_report, is_read_only_statement, is_write_statement, AuditLog, AuditRecorder, AuditSource,
+    CustomTheme, ThemeManager, ThemePreset,
+    AppConfig, ConfigLocation, ConfigReload, Preferences, split_config_args,
+    catalog_keys, language, set_language, t, tf, translate, Language,
+    build_font_definitions, scan_font_dirs, FontSettings, SystemFont, EDITOR_FONT, GRID_FONT,
+    fuzzy_rank, fuzzy_score,
+    ExternalChange, SqlFile,
+    DroppedFileKind,
+    parse_cli_args, write_csv, CliCommand, ExportFormat,
+    expand_export_path, StepStatus, Workflow, WorkflowRun, WorkflowStep,
+    ExporterPlugin, ImagePathRenderer, PluginRegistry,
+    http_response, percent_decode, ApiFormat, ApiRequest, ApiRoute, ApiServerConfig, SavedQuery,
+    rotated_log_path, LogEntry, LogLevel, LogTail, RotatingFileWriter,
+    ddl_tables, is_ddl_statement, pick_column_tables, remap_selection, CatalogDiff, MetadataRefresh,
+    HealthStatus, KeepAlive, KEEP_ALIVE_DOWN_AFTER,
+    QueryQueue,
+    write_preview, WriteKind,
+    import_csv_to_sql, insert_row_sql, CsvImportConfig, Upsert, UpsertSyntax,
+    PrintReport, KeyMacro, Tour, TourTarget, TOUR_STEPS,
+    Trash, TrashItem,
+    analyze_sql_error, suggest_for_unknown, suggest_name, SqlErrorInfo, UnknownObject,
+    RecentObjects, RecentTarget,
+    FilterPreset, FilterPresets, SavedFilter,
+    data_search_sql, is_text_type, searchable_columns,
+    blob_label, blob_len, blob_literal, decode_blob, encode_blob, hex_dump, is_blob,
+    parse_geometry, Coord, Geometry,
+    ColumnOrders, ColumnWidths,
+    detect_timestamp_columns, format_offset, is_number, parse_timestamp, ColumnFormats, NumberFormat, NumberSeparators,
+    TimestampDisplay, ValueFormatter,
+    detect_json_columns, looks_like_json, pretty_json, JsonCompare, JsonPath, JsonPathCondition, JsonPathSegment,
 };
+use gridix::database::{ColumnInfo, DatabaseType, QueryResult};
+use chrono::NaiveDate;
 use std::sync::atomic::Ordering;
 
 // ============================================================================
@@ -47,6 +85,38 @@ fn test_max_notifications() {
     assert_eq!(count, 3);
 }
 
+#[test]
+fn test_notification_history() {
+    let mut manager = NotificationManager::new().with_max_notifications(2);
+
+    manager.info("1");
+    manager.error("2");
+    let id = manager.warning("3");
+    manager.error("4");
+    manager.dismiss(id);
+
+    // 超出显示数量或被关闭的通知仍保留在历史中，新记录在前
+    assert_eq!(manager.iter().count(), 1);
+    let messages: Vec<&str> = manager.history().map(|r| r.message.as_str()).collect();
+    assert_eq!(messages, vec!["4", "3", "2", "1"]);
+    assert_eq!(manager.history().nth(1).unwrap().level, NotificationLevel::Warning);
+
+    assert_eq!(manager.unseen_errors(), 2);
+    manager.mark_seen();
+    assert_eq!(manager.unseen_errors(), 0);
+    manager.error("5");
+    assert_eq!(manager.unseen_errors(), 1);
+
+    manager.clear_history();
+    assert_eq!(manager.history_len(), 0);
+    assert_eq!(manager.unseen_errors(), 0);
+
+    for i in 0..NOTIFICATION_HISTORY_LIMIT + 10 {
+        manager.info(i.to_string());
+    }
+    assert_eq!(manager.history_len(), NOTIFICATION_HISTORY_LIMIT);
+}
+
 // ============================================================================
 // Progress 测试
 // ============================================================================
@@ -136,6 +206,76 @@ fn test_find_conflicts() {
     assert!(!conflicts.is_empty());
 }
 
+#[test]
+fn test_fuzzy_score() {
+    assert_eq!(fuzzy_score("", "anything"), Some(0));
+    assert!(fuzzy_score("ntb", "new table").is_some());
+    assert!(fuzzy_score("NEW", "new table").is_some());
+    assert!(fuzzy_score("tbn", "new table").is_none());
+    assert!(fuzzy_score("导出", "导出数据").is_some());
+
+    // 单词开头、连续匹配得分更高
+    assert!(fuzzy_score("nt", "new table") > fuzzy_score("nt", "connect"));
+    assert!(fuzzy_score("tab", "table") > fuzzy_score("tab", "t_a_b"));
+    assert!(fuzzy_score("users", "users") > fuzzy_score("users", "users_archive"));
+}
+
+#[test]
+fn test_fuzzy_rank() {
+    let columns = ["created_at", "user_id", "id", "updated_at"];
+    assert_eq!(fuzzy_rank("", &columns), vec![0, 1, 2, 3]);
+    assert_eq!(fuzzy_rank("id", &columns), vec![2, 1]);
+    assert_eq!(fuzzy_rank("at", &columns).len(), 2);
+    assert!(fuzzy_rank("zz", &columns).is_empty());
+}
+
+#[test]
+fn test_key_binding_parse_plus_and_validity() {
+    let binding = KeyBinding::parse("Ctrl++").unwrap();
+    assert_eq!(binding, KeyBinding::ctrl(KeyCode::Plus));
+    assert_eq!(KeyBinding::parse(&binding.display()), Some(binding));
+
+    assert!(KeyBinding::ctrl(KeyCode::A).is_valid_shortcut());
+    assert!(KeyBinding::key_only(KeyCode::F5).is_valid_shortcut());
+    assert!(!KeyBinding::key_only(KeyCode::A).is_valid_shortcut());
+    assert!(!KeyBinding::parse("Shift+A").unwrap().is_valid_shortcut());
+}
+
+#[test]
+fn test_default_bindings_have_no_conflicts() {
+    let bindings = KeyBindings::default();
+    assert!(bindings.find_conflicts().is_empty());
+    assert_eq!(
+        bindings.conflict_with(Action::NewTab, &KeyBinding::ctrl(KeyCode::N)),
+        Some(Action::NewConnection)
+    );
+    for action in Action::all() {
+        assert_eq!(Action::from_id(action.id()), Some(*action));
+        assert!(Action::categories().contains(&action.category()));
+    }
+}
+
+#[test]
+fn test_keybindings_persist_in_config() {
+    let mut config = AppConfig::default();
+    config.keybindings.set(Action::Save, KeyBinding::ctrl_shift(KeyCode::S));
+    config.keybindings.remove(Action::GotoLine);
+
+    let toml_str = toml::to_string(&config).unwrap();
+    assert!(toml_str.contains("save = \"Ctrl+Shift+S\""));
+    let parsed: AppConfig = toml::from_str(&toml_str).unwrap();
+    assert_eq!(parsed.keybindings, config.keybindings);
+
+    // 未出现的操作使用默认值，未知操作和无效快捷键被忽略
+    let parsed: AppConfig = toml::from_str(
+        "connections = []\n[keybindings]\nsave = \"Alt+S\"\nbogus = \"Ctrl+Q\"\nrefresh = \"Ctrl+???\"\n",
+    )
+    .unwrap();
+    assert_eq!(parsed.keybindings.display(Action::Save), "Alt+S");
+    assert_eq!(parsed.keybindings.display(Action::Refresh), "F5");
+    assert_eq!(parsed.keybindings.display(Action::NewConnection), "Ctrl+N");
+}
+
 // ============================================================================
 // Session 测试
 // ============================================================================
@@ -150,6 +290,10 @@ fn test_tab_state() {
     let tab = TabState::with_table("Users", "SELECT * FROM users", "users");
     assert!(tab.associated_table.is_some());
     assert_eq!(tab.associated_table.unwrap(), "users");
+
+    // 旧的会话文件没有固定和重命名字段
+    let tab: TabState = toml::from_str("title = \"Q\"\nsql = \"SELECT 1\"").unwrap();
+    assert!(!tab.pinned && !tab.renamed);
 }
 
 #[test]
@@ -225,6 +369,31 @@ fn test_table_completion() {
     assert!(completions.iter().any(|c| c.label == "users"));
 }
 
+#[test]
+fn test_column_types_from_catalog() {
+    let column = |name: &str, data_type: &str| ColumnInfo {
+        name: name.to_string(),
+        data_type: data_type.to_string(),
+        is_primary_key: false,
+        is_nullable: true,
+        default_value: None,
+    };
+    let mut ac = AutoComplete::new();
+    ac.set_columns("logs".to_string(), vec!["message".to_string()]);
+    assert!(ac.column_types("logs").is_none());
+
+    ac.set_column_infos("users".to_string(), vec![column("id", "integer"), column("email", "varchar(255)")]);
+    assert!(ac.has_columns("users"));
+    let types = ac.column_types("users").unwrap();
+    assert_eq!(types.get("email").map(String::as_str), Some("varchar(255)"));
+
+    // 表被删除后一并丢弃
+    ac.retain_columns(&["logs".to_string()]);
+    assert!(ac.column_types("users").is_none());
+    ac.clear();
+    assert!(!ac.has_columns("logs"));
+}
+
 // ============================================================================
 // Formatter 测试
 // ============================================================================
@@ -335,3 +504,2878 @@ fn test_highlight_with_numbers() {
     assert!(job.text.contains("123"));
     assert!(job.text.contains("45.67"));
 }
+
+// ============================================================================
+// 测试数据生成测试
+// ============================================================================
+
+#[test]
+fn test_datagen_sequence_and_ranges() {
+    let mut rng = Rng::new(7);
+    let mut generators = vec![
+        ValueGenerator::Sequence { start: 10, step: 5 }.compile().unwrap(),
+        ValueGenerator::Int { min: 1, max: 3 }.compile().unwrap(),
+        ValueGenerator::Float { min: 0.0, max: 1.0, decimals: 2 }.compile().unwrap(),
+        ValueGenerator::Null.compile().unwrap(),
+    ];
+    let rows = generate_rows(&mut generators, &mut rng, 50);
+    assert_eq!(rows.len(), 50);
+    assert_eq!(rows[0][0], GenValue::Number("10".to_string()));
+    assert_eq!(rows[2][0], GenValue::Number("20".to_string()));
+    for row in &rows {
+        let n: i64 = row[1].display().parse().unwrap();
+        assert!((1..=3).contains(&n));
+        let f = row[2].display();
+        assert_eq!(f.split('.').nth(1).map(str::len), Some(2));
+        assert_eq!(row[3], GenValue::Null);
+    }
+
+    // 相同种子结果可复现
+    let again = generate_rows(
+        &mut [ValueGenerator::Int { min: 1, max: 3 }.compile().unwrap()],
+        &mut Rng::new(7),
+        5,
+    );
+    let first = generate_rows(
+        &mut [ValueGenerator::Int { min: 1, max: 3 }.compile().unwrap()],
+        &mut Rng::new(7),
+        5,
+    );
+    assert_eq!(again, first);
+}
+
+#[test]
+fn test_datagen_dates_and_lists() {
+    let mut rng = Rng::new(1);
+    let mut date = ValueGenerator::Date {
+        from: "2024-02-27".to_string(),
+        to: "2024-03-01".to_string(),
+        with_time: false,
+    }
+    .compile()
+    .unwrap();
+    for _ in 0..20 {
+        let value = date.generate(&mut rng);
+        assert!(["2024-02-27", "2024-02-28", "2024-02-29", "2024-03-01"].contains(&value.display()));
+    }
+
+    let mut list = ValueGenerator::List("red, green ,1".to_string()).compile().unwrap();
+    for _ in 0..20 {
+        match list.generate(&mut rng) {
+            GenValue::Text(s) => assert!(s == "red" || s == "green"),
+            GenValue::Number(s) => assert_eq!(s, "1"),
+            GenValue::Null => panic!("unexpected NULL"),
+        }
+    }
+
+    assert!(ValueGenerator::Date { from: "2024-03-01".to_string(), to: "2024-01-01".to_string(), with_time: true }
+        .compile()
+        .is_err());
+    assert!(ValueGenerator::Date { from: "bad".to_string(), to: "2024-01-01".to_string(), with_time: false }
+        .compile()
+        .is_err());
+    assert!(ValueGenerator::List(" , ".to_string()).compile().is_err());
+    assert!(ValueGenerator::Skip.compile().is_err());
+}
+
+#[test]
+fn test_datagen_pattern() {
+    let regex = regex::Regex::new(r"^[A-Z]{3}-\d{4}(x|yz)?$").unwrap();
+    let mut pattern = ValueGenerator::Pattern(r"[A-Z]{3}-\d{4}(x|yz)?".to_string()).compile().unwrap();
+    let mut rng = Rng::new(99);
+    for _ in 0..50 {
+        let value = pattern.generate(&mut rng);
+        assert!(regex.is_match(value.display()), "{}", value.display());
+    }
+
+    assert!(parse_pattern("[a-z]{2,4}\\w+").is_ok());
+    assert!(parse_pattern("[a-z").is_err());
+    assert!(parse_pattern("(ab").is_err());
+    assert!(parse_pattern("a{3").is_err());
+    assert!(parse_pattern("a{4,2}").is_err());
+    assert!(parse_pattern("*a").is_err());
+    assert!(parse_pattern("[^a]").is_err());
+}
+
+#[test]
+fn test_datagen_suggest() {
+    assert_eq!(ValueGenerator::suggest("id", "integer", true, None), ValueGenerator::Skip);
+    assert_eq!(ValueGenerator::suggest("id", "bigint", true, Some("nextval('t_id_seq')")), ValueGenerator::Skip);
+    assert_eq!(ValueGenerator::suggest("id", "bigint", true, None), ValueGenerator::Sequence { start: 1, step: 1 });
+    assert_eq!(ValueGenerator::suggest("user_email", "varchar(255)", false, None), ValueGenerator::Email);
+    assert_eq!(ValueGenerator::suggest("full_name", "text", false, None), ValueGenerator::Name);
+    assert!(matches!(ValueGenerator::suggest("created_at", "timestamp", false, None), ValueGenerator::Date { with_time: true, .. }));
+    assert!(matches!(ValueGenerator::suggest("price", "decimal(10,2)", false, None), ValueGenerator::Float { .. }));
+    assert_eq!(GenValue::Text("it's a\\b".to_string()).to_sql(false), "'it''s a\\b'");
+    assert_eq!(GenValue::Text("a\\b".to_string()).to_sql(true), "'a\\\\b'");
+}
+
+// ============================================================================
+// 定时查询测试
+// ============================================================================
+
+fn at(day: u32, hour: u32, minute: u32, second: u32) -> chrono::NaiveDateTime {
+    // 2024-05-06 为周一
+    NaiveDate::from_ymd_opt(2024, 5, day).unwrap().and_hms_opt(hour, minute, second).unwrap()
+}
+
+#[test]
+fn test_schedule_parse() {
+    assert_eq!(Schedule::parse("30s"), Ok(Schedule::Interval(30)));
+    assert_eq!(Schedule::parse("5m"), Ok(Schedule::Interval(300)));
+    assert_eq!(Schedule::parse(" 2h "), Ok(Schedule::Interval(7200)));
+    assert_eq!(Schedule::parse("45"), Ok(Schedule::Interval(45)));
+    assert!(Schedule::parse("1s").is_err());
+    assert!(Schedule::parse("10x").is_err());
+    assert!(Schedule::parse("").is_err());
+    assert!(Schedule::parse("* * *").is_err());
+    assert!(Schedule::parse("60 * * * *").is_err());
+    assert!(Schedule::parse("*/0 * * * *").is_err());
+    assert!(Schedule::parse("5-1 * * * *").is_err());
+    assert_eq!(Schedule::parse("300s").unwrap().describe(), "每 5 分钟");
+    assert_eq!(Schedule::parse("*/10  9-18 * * 1-5").unwrap().describe(), "cron: */10 9-18 * * 1-5");
+}
+
+#[test]
+fn test_schedule_next_after() {
+    let interval = Schedule::parse("30s").unwrap();
+    assert_eq!(interval.next_after(at(6, 10, 0, 0)), Some(at(6, 10, 0, 30)));
+
+    // 工作日 9-18 点每 10 分钟
+    let cron = Schedule::parse("*/10 9-18 * * 1-5").unwrap();
+    assert_eq!(cron.next_after(at(6, 9, 5, 30)), Some(at(6, 9, 10, 0)));
+    assert_eq!(cron.next_after(at(6, 9, 10, 0)), Some(at(6, 9, 20, 0)));
+    assert_eq!(cron.next_after(at(6, 18, 55, 0)), Some(at(7, 9, 0, 0)));
+    // 周五晚上之后跳到下周一
+    assert_eq!(cron.next_after(at(10, 19, 0, 0)), Some(at(13, 9, 0, 0)));
+
+    // 周日可以写作 7
+    let sunday = Schedule::parse("0 8 * * 7").unwrap();
+    assert_eq!(sunday.next_after(at(6, 0, 0, 0)), Some(at(12, 8, 0, 0)));
+
+    // 日和周同时限制时满足其一即可
+    let either = Schedule::parse("0 0 15 * 1").unwrap();
+    assert_eq!(either.next_after(at(7, 0, 0, 0)), Some(at(13, 0, 0, 0)));
+    assert_eq!(either.next_after(at(13, 0, 0, 0)), Some(at(15, 0, 0, 0)));
+
+    // 不存在的日期
+    assert_eq!(Schedule::parse("0 0 31 2 *").unwrap().next_after(at(6, 0, 0, 0)), None);
+}
+
+#[test]
+fn test_threshold() {
+    let result = QueryResult {
+        columns: vec!["name".to_string(), "Waiting".to_string()],
+        rows: vec![vec!["a".to_string(), " 12 ".to_string()], vec!["b".to_string(), "3".to_string()]],
+        ..Default::default()
+    };
+    let rows = Threshold { target: ThresholdTarget::RowCount, op: ThresholdOp::Ge, value: 2.0 };
+    assert_eq!(rows.observe(&result), Some(2.0));
+    assert!(rows.is_crossed(&result));
+    assert_eq!(rows.describe(), "行数 >= 2");
+
+    let column = Threshold { target: ThresholdTarget::Column("waiting".to_string()), op: ThresholdOp::Gt, value: 12.0 };
+    assert_eq!(column.observe(&result), Some(12.0));
+    assert!(!column.is_crossed(&result));
+
+    let text = Threshold { target: ThresholdTarget::Column("name".to_string()), op: ThresholdOp::Ne, value: 0.0 };
+    assert_eq!(text.observe(&result), None);
+    assert!(!text.is_crossed(&result));
+}
+
+#[test]
+fn test_query_scheduler() {
+    let mut scheduler = QueryScheduler::new();
+    let mut job = ScheduledQuery::new("waits", "local", "SELECT 1", Schedule::Interval(30));
+    job.threshold = Some(Threshold { target: ThresholdTarget::RowCount, op: ThresholdOp::Gt, value: 1.0 });
+    let id = scheduler.add(job, at(6, 10, 0, 0));
+
+    // 间隔任务立即执行一次
+    let due = scheduler.take_due(at(6, 10, 0, 0));
+    assert_eq!(due.len(), 1);
+    assert_eq!(due[0].id, id);
+    // 执行中不会重复取出
+    assert!(scheduler.take_due(at(6, 10, 0, 1)).is_empty());
+    assert_eq!(scheduler.time_until_next(at(6, 10, 0, 1)), None);
+
+    let rows = |n: usize| QueryResult {
+        columns: vec!["x".to_string()],
+        rows: vec![vec!["1".to_string()]; n],
+        ..Default::default()
+    };
+
+    // 未越过阈值
+    assert_eq!(scheduler.finish(id, at(6, 10, 0, 1), Ok(&rows(1))), None);
+    assert_eq!(scheduler.get(id).unwrap().next_run, Some(at(6, 10, 0, 31)));
+    assert_eq!(scheduler.time_until_next(at(6, 10, 0, 21)), Some(std::time::Duration::from_secs(10)));
+    assert!(scheduler.take_due(at(6, 10, 0, 30)).is_empty());
+
+    // 越过阈值时提醒一次
+    assert_eq!(scheduler.take_due(at(6, 10, 0, 31)).len(), 1);
+    let alert = scheduler.finish(id, at(6, 10, 0, 32), Ok(&rows(3)));
+    assert!(alert.unwrap().contains("waits"));
+    scheduler.take_due(at(6, 10, 1, 2));
+    assert_eq!(scheduler.finish(id, at(6, 10, 1, 2), Ok(&rows(5))), None);
+
+    // 错误不影响提醒状态
+    scheduler.take_due(at(6, 10, 1, 32));
+    assert_eq!(scheduler.finish(id, at(6, 10, 1, 32), Err("boom")), None);
+    assert_eq!(scheduler.get(id).unwrap().last_error.as_deref(), Some("boom"));
+
+    // 恢复后再次越过时重新提醒
+    scheduler.take_due(at(6, 10, 2, 2));
+    assert_eq!(scheduler.finish(id, at(6, 10, 2, 2), Ok(&rows(0))), None);
+    scheduler.take_due(at(6, 10, 2, 32));
+    assert!(scheduler.finish(id, at(6, 10, 2, 32), Ok(&rows(2))).is_some());
+    assert_eq!(scheduler.get(id).unwrap().run_count, 6);
+    assert!(scheduler.get(id).unwrap().last_error.is_none());
+
+    // 暂停后不再执行，立即执行对暂停的任务无效
+    scheduler.set_enabled(id, false, at(6, 10, 3, 0));
+    scheduler.run_now(id, at(6, 10, 3, 0));
+    assert!(scheduler.take_due(at(6, 11, 0, 0)).is_empty());
+    scheduler.set_enabled(id, true, at(6, 11, 0, 0));
+    scheduler.run_now(id, at(6, 11, 0, 0));
+    assert_eq!(scheduler.take_due(at(6, 11, 0, 0)).len(), 1);
+
+    assert!(scheduler.remove(id).is_some());
+    assert!(scheduler.is_empty());
+}
+
+// ============================================================================
+// 结果对比测试
+// ============================================================================
+
+fn result_of(columns: &[&str], rows: &[&[&str]]) -> QueryResult {
+    QueryResult {
+        columns: columns.iter().map(|c| c.to_string()).collect(),
+        rows: rows.iter().map(|r| r.iter().map(|v| v.to_string()).collect()).collect(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_diff_results_by_key() {
+    let old = result_of(&["id", "name", "qty"], &[&["1", "apple", "3"], &["2", "pear", "5"], &["3", "fig", "1"]]);
+    // 列顺序不同也能对比
+    let new = result_of(&["qty", "id", "name"], &[&["3", "1", "apple"], &["7", "2", "pear"], &["2", "4", "kiwi"]]);
+
+    let diff = diff_results(&old, &new, &["id".to_string()]).unwrap();
+    assert_eq!(diff.columns, vec!["qty", "id", "name"]);
+    assert_eq!(diff.count(DiffKind::Unchanged), 1);
+    assert_eq!(diff.count(DiffKind::Changed), 1);
+    assert_eq!(diff.count(DiffKind::Added), 1);
+    assert_eq!(diff.count(DiffKind::Removed), 1);
+    assert!(!diff.is_identical());
+
+    let changed = &diff.rows[1];
+    assert_eq!(changed.kind, DiffKind::Changed);
+    assert_eq!(changed.changed_columns, vec![0]);
+    assert_eq!(changed.old_values.as_ref().unwrap()[0], "5");
+    // 删除的行排在最后，值按当前列顺序排列
+    assert_eq!(diff.rows[3].kind, DiffKind::Removed);
+    assert_eq!(diff.rows[3].values, vec!["1", "3", "fig"]);
+
+    let report = diff.to_report();
+    assert_eq!(report.columns, vec!["变化", "qty", "id", "name"]);
+    let labels: Vec<&str> = report.rows.iter().map(|r| r[0].as_str()).collect();
+    assert_eq!(labels, vec!["修改前", "修改后", "新增", "删除"]);
+    assert_eq!(report.rows[0], vec!["修改前", "5", "2", "pear"]);
+}
+
+#[test]
+fn test_diff_results_full_row() {
+    let old = result_of(&["a", "b"], &[&["1", "x"], &["1", "x"], &["2", "y"]]);
+    let new = result_of(&["a", "b"], &[&["1", "x"], &["2", "z"], &["2", "y"]]);
+
+    // 整行匹配：修改表现为删除 + 新增，重复行按次数匹配
+    let diff = diff_results(&old, &new, &[]).unwrap();
+    assert_eq!(diff.count(DiffKind::Unchanged), 2);
+    assert_eq!(diff.count(DiffKind::Added), 1);
+    assert_eq!(diff.count(DiffKind::Removed), 1);
+    assert_eq!(diff.count(DiffKind::Changed), 0);
+    assert_eq!(diff.rows.last().unwrap().values, vec!["1", "x"]);
+
+    assert!(diff_results(&old, &old, &[]).unwrap().is_identical());
+    assert!(diff_results(&old, &old, &[]).unwrap().to_report().rows.is_empty());
+}
+
+#[test]
+fn test_diff_results_errors() {
+    let old = result_of(&["id", "v"], &[&["1", "a"], &["1", "b"]]);
+    let new = result_of(&["id", "v"], &[&["1", "a"]]);
+    // 键重复
+    assert!(diff_results(&old, &new, &["id".to_string()]).is_err());
+    assert!(diff_results(&new, &old, &["id".to_string()]).is_err());
+    // 键列不存在
+    assert!(diff_results(&new, &new, &["missing".to_string()]).is_err());
+    // 列不同
+    let other = result_of(&["id", "w"], &[&["1", "a"]]);
+    assert!(diff_results(&new, &other, &[]).is_err());
+    let wider = result_of(&["id", "v", "w"], &[&["1", "a", "c"]]);
+    assert!(diff_results(&new, &wider, &[]).is_err());
+}
+
+// ============================================================================
+// 查询历史测试
+// ============================================================================
+
+fn add_history(history: &mut QueryHistory, sql: &str, connection: &str) {
+    history.add(QueryHistoryItem::new(sql.to_string(), "SQLite".to_string(), Some(connection.to_string()), true));
+}
+
+#[test]
+fn test_query_history_dedupe_and_favorites() {
+    let mut history = QueryHistory::new(3);
+    add_history(&mut history, "SELECT 1", "a");
+    history.add(QueryHistoryItem {
+        elapsed_ms: Some(12),
+        row_count: Some(1),
+        ..QueryHistoryItem::new("SELECT 1 ".to_string(), "SQLite".to_string(), Some("a".to_string()), true)
+    });
+    assert_eq!(history.len(), 1);
+    assert_eq!(history.items()[0].run_count, 2);
+    assert_eq!(history.items()[0].elapsed_ms, Some(12));
+    assert_eq!(history.items()[0].row_count, Some(1));
+
+    // 不同连接的相同语句不合并
+    add_history(&mut history, "SELECT 1", "b");
+    assert_eq!(history.len(), 2);
+
+    // 收藏的记录不会被淘汰
+    history.toggle_favorite(1);
+    for i in 0..5 {
+        add_history(&mut history, &format!("SELECT {}", i + 10), "a");
+    }
+    assert_eq!(history.len(), 4);
+    assert!(history.items().last().unwrap().favorite);
+    assert_eq!(history.items().last().unwrap().sql, "SELECT 1");
+
+    // 清空时保留收藏
+    history.clear();
+    assert_eq!(history.len(), 1);
+
+    // 取消收藏后按上限淘汰
+    history.set_max_size(0);
+    assert_eq!(history.len(), 1);
+    history.toggle_favorite(0);
+    assert!(history.is_empty());
+}
+
+#[test]
+fn test_query_history_search() {
+    let mut history = QueryHistory::new(100);
+    add_history(&mut history, "SELECT * FROM users WHERE id = 1", "prod");
+    add_history(&mut history, "select name from Orders", "dev");
+    add_history(&mut history, "DELETE FROM users", "dev");
+    history.toggle_favorite(2);
+
+    let search = |text: &str, connection: Option<&str>, favorites_only: bool| {
+        history.search(&HistoryFilter {
+            text: text.to_string(),
+            connection: connection.map(str::to_string),
+            favorites_only,
+        })
+    };
+    assert_eq!(search("", None, false), vec![0, 1, 2]);
+    assert_eq!(search("USERS", None, false), vec![0, 2]);
+    assert_eq!(search("users select", None, false), vec![2]);
+    assert_eq!(search("", Some("dev"), false), vec![0, 1]);
+    assert_eq!(search("users", Some("dev"), false), vec![0]);
+    assert_eq!(search("", None, true), vec![2]);
+    assert!(search("missing", None, false).is_empty());
+    assert_eq!(history.connections(), vec!["dev", "prod"]);
+}
+
+#[test]
+fn test_query_history_persistence() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("history.toml");
+
+    let mut history = QueryHistory::new(100);
+    add_history(&mut history, "SELECT 'a\"b'", "a");
+    add_history(&mut history, "SELECT 2", "b");
+    history.toggle_favorite(1);
+    history.save_to(&path).unwrap();
+
+    // 加载时使用新的上限，收藏不计入
+    let loaded = QueryHistory::load_from(&path, 0);
+    assert_eq!(loaded.len(), 1);
+    assert_eq!(loaded.max_size(), 0);
+    let item = &loaded.items()[0];
+    assert_eq!(item.sql, "SELECT 'a\"b'");
+    assert_eq!(item.connection.as_deref(), Some("a"));
+    assert!(item.favorite);
+
+    // 旧格式（无连接、收藏字段）也能加载
+    std::fs::write(
+        &path,
+        "[[items]]\nsql = \"SELECT 1\"\ntimestamp = \"2024-01-01T00:00:00+08:00\"\ndatabase_type = \"SQLite\"\nsuccess = true\n",
+    )
+    .unwrap();
+    let legacy = QueryHistory::load_from(&path, 10);
+    assert_eq!(legacy.len(), 1);
+    assert_eq!(legacy.items()[0].run_count, 1);
+    assert!(legacy.items()[0].connection.is_none());
+
+    // 文件不存在时为空
+    assert!(QueryHistory::load_from(&dir.path().join("missing.toml"), 10).is_empty());
+}
+
+#[test]
+fn test_side_by_side_diff() {
+    let old = "SELECT id\nFROM users\nWHERE id = 1\nORDER BY id";
+    let new = "SELECT id, name\nFROM users\nORDER BY id\nLIMIT 10";
+    let lines = side_by_side_diff(old, new);
+    let changes: Vec<LineChange> = lines.iter().map(|l| l.change).collect();
+    assert_eq!(
+        changes,
+        vec![LineChange::Changed, LineChange::Same, LineChange::Removed, LineChange::Same, LineChange::Added]
+    );
+    assert_eq!(lines[0].left, Some((1, "SELECT id".to_string())));
+    assert_eq!(lines[0].right, Some((1, "SELECT id, name".to_string())));
+    assert_eq!(lines[2].right, None);
+    assert_eq!(lines[4].right, Some((4, "LIMIT 10".to_string())));
+
+    assert!(side_by_side_diff(old, old).iter().all(|l| l.change == LineChange::Same));
+    assert_eq!(side_by_side_diff("", "a\nb").len(), 2);
+}
+
+// ============================================================================
+// 编辑器文本操作测试
+// ============================================================================
+
+#[test]
+fn test_find_matching_bracket() {
+    let sql = "SELECT COUNT(*) FROM t WHERE id IN (1, (2))";
+    // 光标在 '(' 之前
+    assert_eq!(find_matching_bracket(sql, 12), Some((12, 14)));
+    // 光标在 ')' 之后
+    assert_eq!(find_matching_bracket(sql, 15), Some((14, 12)));
+    // 嵌套括号
+    let open = sql.find("(1").unwrap();
+    assert_eq!(find_matching_bracket(sql, open), Some((open, sql.len() - 1)));
+    // 引号中的括号不参与匹配
+    assert_eq!(find_matching_bracket("f(')', x)", 1), Some((1, 8)));
+    assert_eq!(find_matching_bracket("SELECT 1", 3), None);
+    assert_eq!(find_matching_bracket("(a", 0), None);
+}
+
+#[test]
+fn test_toggle_line_comment() {
+    let mut sql = String::from("SELECT *\n  FROM t\n\nWHERE id = 1");
+    let end = sql.len();
+    let (start, new_end) = toggle_line_comment(&mut sql, 0, end);
+    assert_eq!(sql, "-- SELECT *\n--   FROM t\n\n-- WHERE id = 1");
+    assert_eq!((start, new_end), (0, sql.len()));
+
+    // 全部已注释时取消注释
+    let end = sql.len();
+    toggle_line_comment(&mut sql, 0, end);
+    assert_eq!(sql, "SELECT *\n  FROM t\n\nWHERE id = 1");
+
+    // 无选区时只切换光标所在行
+    let cursor = sql.find("FROM").unwrap();
+    let (pos, _) = toggle_line_comment(&mut sql, cursor, cursor);
+    assert_eq!(sql, "SELECT *\n  -- FROM t\n\nWHERE id = 1");
+    assert!(sql[pos..].starts_with("-- FROM"));
+}
+
+#[test]
+fn test_multi_cursor_column_edit() {
+    let mut sql = String::from("(1, 'a'),\n(2, 'b'),\n(3)");
+    let mut cursors = MultiCursor::default();
+    assert!(!cursors.is_active());
+
+    // 在第一行第 1 列之后放置光标并向下扩展
+    assert!(cursors.add_below(&sql, 1));
+    assert!(cursors.add_below(&sql, 1));
+    assert!(!cursors.add_below(&sql, 1));
+    assert!(cursors.is_active());
+    assert_eq!(cursors.cursors().len(), 3);
+
+    cursors.insert(&mut sql, "1");
+    assert_eq!(sql, "(11, 'a'),\n(12, 'b'),\n(13)");
+    cursors.backspace(&mut sql);
+    assert_eq!(sql, "(1, 'a'),\n(2, 'b'),\n(3)");
+    cursors.insert(&mut sql, "x");
+    assert_eq!(sql, "(x1, 'a'),\n(x2, 'b'),\n(x3)");
+
+    // 行尾不会删除换行
+    cursors.move_line_edge(&sql, true);
+    cursors.delete(&mut sql);
+    assert_eq!(sql, "(x1, 'a'),\n(x2, 'b'),\n(x3)");
+
+    // 从最后一行向上添加光标，保持相同的列
+    let mut cursors = MultiCursor::default();
+    let last = sql.rfind(')').unwrap();
+    assert!(cursors.add_above(&sql, last));
+    assert!(cursors.add_above(&sql, last));
+    assert!(!cursors.add_above(&sql, last));
+    cursors.insert(&mut sql, "!");
+    assert_eq!(sql, "(x1!, 'a'),\n(x2!, 'b'),\n(x3!)");
+
+    cursors.clear();
+    assert!(cursors.cursors().is_empty());
+}
+
+// ============================================================================
+// 审计日志测试
+// ============================================================================
+
+#[test]
+fn test_is_write_statement() {
+    for sql in [
+        "INSERT INTO t VALUES (1)",
+        "  update t set a = 1",
+        "-- 注释\nDELETE FROM t",
+        "/* 批量 */ CREATE TABLE t (id INT)",
+        "drop table t",
+        "GRANT SELECT ON t TO u",
+        "WITH x AS (SELECT 1) DELETE FROM t WHERE id IN (SELECT * FROM x)",
+        "CALL purge()",
+        "EXEC sp_cleanup",
+        "EXECUTE stmt",
+        "DO $$ BEGIN DELETE FROM t; END $$",
+        "COPY t FROM '/tmp/t.csv'",
+        "LOAD DATA INFILE 'a.csv' INTO TABLE t",
+        "LOCK TABLES t WRITE",
+        "SET GLOBAL max_connections = 10",
+    ] {
+        assert!(is_write_statement(sql), "{}", sql);
+    }
+    for sql in ["SELECT * FROM t", "WITH x AS (SELECT 1) SELECT * FROM x", "EXPLAIN DELETE FROM t", "-- DELETE", ""] {
+        assert!(!is_write_statement(sql), "{}", sql);
+    }
+}
+
+#[test]
+fn test_is_read_only_statement() {
+    for sql in [
+        "SELECT * FROM t",
+        "-- 注释\n(SELECT 1) UNION (SELECT 2)",
+        "WITH x AS (SELECT 1) SELECT * FROM x",
+        "SHOW TABLES",
+        "DESCRIBE t",
+        "EXPLAIN DELETE FROM t",
+        "EXPLAIN ANALYZE SELECT * FROM t",
+        "VALUES (1), (2)",
+    ] {
+        assert!(is_read_only_statement(sql), "{}", sql);
+    }
+    for sql in [
+        "CALL purge()",
+        "EXEC sp_cleanup",
+        "PRAGMA journal_mode = DELETE",
+        "SELECT * INTO backup FROM t",
+        "WITH x AS (DELETE FROM t RETURNING *) SELECT * FROM x",
+        "EXPLAIN ANALYZE DELETE FROM t",
+        "DELETE FROM t",
+        "",
+    ] {
+        assert!(!is_read_only_statement(sql), "{}", sql);
+    }
+}
+
+#[test]
+fn test_audit_log_append_and_read() {
+    let dir = tempfile::tempdir().unwrap();
+    let log = AuditLog::new(dir.path().join("nested").join("audit.log"));
+    assert!(log.read().unwrap().is_empty());
+
+    let recorder = AuditRecorder::new(Some(log.clone()), "prod".to_string(), "MySQL".to_string(), AuditSource::GridSave);
+    recorder.record("SELECT 1", Ok(0));
+    recorder.record("UPDATE t SET a = 1\nWHERE id = 2", Ok(1));
+    recorder.record("DELETE FROM t", Err("权限不足"));
+    AuditRecorder::new(Some(log.clone()), "dev".to_string(), "SQLite".to_string(), AuditSource::Editor)
+        .record("DROP TABLE t", Ok(0));
+    // 未配置日志时不记录
+    AuditRecorder::new(None, "dev".to_string(), "SQLite".to_string(), AuditSource::Editor).record("DROP TABLE t", Ok(0));
+
+    // 无法解析的行被跳过
+    let mut file = std::fs::OpenOptions::new().append(true).open(log.path()).unwrap();
+    std::io::Write::write_all(&mut file, b"not json\n").unwrap();
+
+    let entries = log.read().unwrap();
+    assert_eq!(entries.len(), 3);
+    assert_eq!(entries[0].sql, "UPDATE t SET a = 1\nWHERE id = 2");
+    assert_eq!(entries[0].source, AuditSource::GridSave);
+    assert_eq!(entries[0].affected_rows, Some(1));
+    assert!(!entries[1].success);
+    assert_eq!(entries[1].error.as_deref(), Some("权限不足"));
+    assert_eq!(entries[2].connection, "dev");
+    assert_eq!(entries[2].source, AuditSource::Editor);
+
+    let refs: Vec<_> = entries.iter().collect();
+    let report = audit_report(&refs);
+    assert_eq!(report.columns.len(), 8);
+    assert_eq!(report.rows.len(), 3);
+    assert_eq!(report.rows[1][4], "失败");
+    assert_eq!(report.rows[0][5], "1");
+}
+
+#[test]
+fn test_audit_record_admin() {
+    let dir = tempfile::tempdir().unwrap();
+    let log = AuditLog::new(dir.path().join("audit.log"));
+
+    // 终止会话和表维护不是普通写语句，也要记录
+    let recorder = AuditRecorder::new(Some(log.clone()), "prod".to_string(), "PostgreSQL".to_string(), AuditSource::Session);
+    recorder.record("SELECT pg_terminate_backend(42);", Ok(1));
+    recorder.record_admin("SELECT pg_terminate_backend(42);", Ok(1));
+    AuditRecorder::new(Some(log.clone()), "prod".to_string(), "PostgreSQL".to_string(), AuditSource::Maintenance)
+        .record_admin("VACUUM;", Err("权限不足"));
+
+    let entries = log.read().unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].source, AuditSource::Session);
+    assert_eq!(entries[1].source, AuditSource::Maintenance);
+    assert!(!entries[1].success);
+}
+
+#[test]
+fn test_custom_theme_roundtrip() {
+    let mut theme = CustomTheme::from_preset("My Nord", ThemePreset::Nord);
+    theme.colors.accent = egui::Color32::from_rgb(0x12, 0x34, 0x56);
+    theme.syntax.keyword = egui::Color32::from_rgb(0xab, 0xcd, 0xef);
+    assert!(theme.dark);
+
+    let toml = theme.to_toml().unwrap();
+    assert!(toml.contains("accent = \"#123456ff\""));
+    assert_eq!(CustomTheme::parse(&toml).unwrap(), theme);
+
+    let json = theme.to_json().unwrap();
+    assert_eq!(CustomTheme::parse(&json).unwrap(), theme);
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("theme.json");
+    theme.export_to(&path).unwrap();
+    assert!(std::fs::read_to_string(&path).unwrap().trim_start().starts_with('{'));
+    assert_eq!(CustomTheme::import_from(&path).unwrap(), theme);
+
+    assert!(CustomTheme::parse(&toml.replace("#123456ff", "not-a-color")).is_err());
+    assert!(CustomTheme::parse(&toml.replace("My Nord", " ")).is_err());
+}
+
+#[test]
+fn test_theme_manager_custom_theme() {
+    let mut theme = CustomTheme::from_preset("Paper", ThemePreset::GithubLight);
+    theme.syntax.keyword = egui::Color32::from_rgb(200, 0, 0);
+
+    let mut manager = ThemeManager::new(ThemePreset::TokyoNight);
+    manager.set_custom(theme.clone());
+    assert_eq!(manager.display_name(), "Paper");
+    assert!(!manager.is_dark());
+    assert_eq!(manager.current, ThemePreset::GithubLight);
+
+    let colors = HighlightColors::from_manager(&manager);
+    assert!(colors.custom);
+    assert_eq!(colors.keyword, egui::Color32::from_rgb(200, 0, 0));
+
+    manager.set_theme(ThemePreset::Dracula);
+    assert!(manager.custom.is_none());
+    assert!(!HighlightColors::from_manager(&manager).custom);
+    assert_eq!(ThemePreset::ALL.len(), 19);
+}
+
+// ============================================================================
+// 字体设置测试
+// ============================================================================
+
+#[test]
+fn test_font_settings_scan_and_definitions() {
+    let dir = tempfile::tempdir().unwrap();
+    let nested = dir.path().join("truetype");
+    std::fs::create_dir_all(&nested).unwrap();
+    std::fs::write(nested.join("FiraCode-Regular.ttf"), b"not a real font").unwrap();
+    std::fs::write(dir.path().join("Arial.TTF"), b"").unwrap();
+    std::fs::write(dir.path().join("readme.txt"), b"").unwrap();
+
+    let fonts = scan_font_dirs(&[dir.path().to_path_buf(), dir.path().join("missing")]);
+    let names: Vec<&str> = fonts.iter().map(|f| f.name.as_str()).collect();
+    assert_eq!(names, vec!["Arial", "FiraCode-Regular"]);
+    assert!(fonts[1].looks_monospace());
+    assert!(!fonts[0].looks_monospace());
+
+    // 默认设置：编辑器以内置等宽字体开头，表格使用界面字体族
+    let defaults = build_font_definitions(&FontSettings::default(), &[]);
+    let editor = &defaults.families[&egui::FontFamily::Name(EDITOR_FONT.into())];
+    assert_eq!(editor.first().map(String::as_str), Some("Hack"));
+    assert!(editor.contains(&"noto_sans_sc".to_string()));
+    assert_eq!(
+        defaults.families[&egui::FontFamily::Name(GRID_FONT.into())],
+        defaults.families[&egui::FontFamily::Proportional]
+    );
+
+    // 选中的系统字体排在最前，缺失的字体回退到内置字体
+    let mut settings = FontSettings::default();
+    settings.editor.family = Some("FiraCode-Regular".to_string());
+    settings.grid.family = Some("NoSuchFont".to_string());
+    let defs = build_font_definitions(&settings, &fonts);
+    let editor = &defs.families[&egui::FontFamily::Name(EDITOR_FONT.into())];
+    assert_eq!(editor.first().map(String::as_str), Some("system:FiraCode-Regular"));
+    assert!(defs.font_data.contains_key("system:FiraCode-Regular"));
+    assert_eq!(defs.families[&egui::FontFamily::Name(GRID_FONT.into())][0], "noto_sans_sc");
+
+    let missing = SystemFont { name: "Gone".to_string(), path: dir.path().join("gone.ttf") };
+    settings.ui.family = Some("Gone".to_string());
+    let defs = build_font_definitions(&settings, &[missing]);
+    assert_eq!(defs.families[&egui::FontFamily::Proportional][0], "noto_sans_sc");
+}
+
+#[test]
+fn test_font_settings_config_defaults() {
+    let settings: FontSettings = toml::from_str("[editor]\nfamily = \"JetBrains Mono\"\nsize = 15.0\n").unwrap();
+    assert_eq!(settings.editor.family.as_deref(), Some("JetBrains Mono"));
+    assert_eq!(settings.editor.size, 15.0);
+    assert_eq!(settings.ui, FontSettings::default().ui);
+    assert!(settings.grid.family.is_none());
+
+    let mut huge = settings.editor.clone();
+    huge.size = 400.0;
+    assert_eq!(huge.clamped_size(), 32.0);
+    huge.size = f32::NAN;
+    assert!(huge.clamped_size().is_finite());
+}
+
+// ============================================================================
+// 国际化测试
+// ============================================================================
+
+#[test]
+fn test_i18n_catalog_complete() {
+    let keys: Vec<&str> = catalog_keys().collect();
+    let unique: std::collections::HashSet<&str> = keys.iter().copied().collect();
+    assert_eq!(keys.len(), unique.len(), "目录中有重复的键名");
+
+    for key in keys {
+        for lang in Language::ALL {
+            let text = translate(lang, key);
+            assert!(!text.is_empty() && text != key, "{} 缺少 {} 翻译", key, lang.code());
+            // 占位符数量必须一致
+            assert_eq!(
+                text.matches("{}").count(),
+                translate(Language::ZhCn, key).matches("{}").count(),
+                "{} 的 {} 翻译占位符数量不一致",
+                key,
+                lang.code()
+            );
+        }
+    }
+}
+
+#[test]
+fn test_i18n_lookup_and_format() {
+    assert_eq!(translate(Language::ZhCn, "toolbar.export"), "导出");
+    assert_eq!(translate(Language::EnUs, "toolbar.export"), "Export");
+    assert_eq!(translate(Language::EnUs, "no.such.key"), "no.such.key");
+
+    assert_eq!(Language::from_code("en"), Some(Language::EnUs));
+    assert_eq!(Language::from_code("zh_cn"), Some(Language::ZhCn));
+    assert_eq!(Language::from_code("fr-FR"), None);
+    assert_eq!(toml::to_string(&std::collections::BTreeMap::from([("lang", Language::EnUs)])).unwrap().trim(), "lang = \"en-US\"");
+
+    let previous = language();
+    set_language(Language::EnUs);
+    assert_eq!(t("common.ok"), "OK");
+    assert_eq!(tf("theme.saved", &["Nord"]), "Theme Nord saved");
+    assert_eq!(tf("theme.saved", &[]), "Theme {} saved");
+    set_language(Language::ZhCn);
+    assert_eq!(tf("notify.switched_to", &["main"]), "切换到: main");
+    let unmask = tf("masking.unmask_message", &["salary, ssn"]);
+    assert!(unmask.contains("salary, ssn") && unmask.lines().count() == 2);
+    set_language(previous);
+}
+
+// ============================================================================
+// 首选项测试
+// ============================================================================
+
+#[test]
+fn test_preferences_roundtrip_and_validate() {
+    let mut config = AppConfig::default();
+    let mut prefs = config.preferences();
+    assert_eq!(prefs, Preferences::default());
+    assert!(prefs.validate().is_ok());
+
+    prefs.language = Language::EnUs;
+    prefs.query_timeout_secs = 3600;
+    prefs.max_result_rows = 2000;
+    prefs.confirm_delete_connection = false;
+    config.apply_preferences(&prefs);
+    assert_eq!(config.query_timeout_secs, 3600);
+    assert_eq!(config.language, Language::EnUs);
+    assert!(!config.confirm_delete_connection);
+    assert_eq!(config.preferences(), prefs);
+
+    // 图片预览目录为空时不预览
+    assert!(config.image_preview_dir.is_none());
+    prefs.image_preview_dir = " /data/images ".to_string();
+    config.apply_preferences(&prefs);
+    assert_eq!(config.image_preview_dir.as_deref(), Some(std::path::Path::new("/data/images")));
+    prefs.image_preview_dir.clear();
+    config.apply_preferences(&prefs);
+    assert!(config.image_preview_dir.is_none());
+
+    prefs.max_result_rows = 10;
+    prefs.connect_timeout_secs = 0;
+    let errors = prefs.validate().unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert!(errors.iter().any(|e| e.contains("连接超时")));
+}
+
+#[test]
+fn test_config_defaults_and_sanitize() {
+    // 旧配置文件没有新字段时使用默认值
+    let mut config: AppConfig = toml::from_str("connections = []\nmax_result_rows = 1\nquery_timeout_secs = 99999999\n").unwrap();
+    assert!(config.confirm_grid_delete);
+    assert!(config.show_grid_footer);
+    assert_eq!(config.timestamp_display, TimestampDisplay::AsStored);
+    assert!(config.number_format.is_plain());
+    assert_eq!(config.default_query_limit, 100);
+    assert_eq!(config.connect_timeout_secs, 30);
+
+    config.sanitize();
+    assert_eq!(config.max_result_rows, 100);
+    assert_eq!(config.query_timeout_secs, 86400);
+    assert!(config.preferences().validate().is_ok());
+}
+
+// ============================================================================
+// SQL 文件测试
+// ============================================================================
+
+#[test]
+fn test_sql_file_save_and_external_change() {
+    use std::time::{Duration, SystemTime};
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("report.sql");
+    std::fs::write(&path, "SELECT 1;").unwrap();
+
+    let (mut file, content) = SqlFile::open(&path).unwrap();
+    assert_eq!(content, "SELECT 1;");
+    assert_eq!(file.file_name(), "report.sql");
+    assert!(!file.is_dirty(&content));
+    assert!(file.is_dirty("SELECT 2;"));
+    assert_eq!(file.check_external_change(), None);
+
+    file.save("SELECT 2;").unwrap();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "SELECT 2;");
+    assert!(!file.is_dirty("SELECT 2;"));
+
+    // 外部修改（显式推进修改时间，避免文件系统时间精度影响）
+    std::fs::write(&path, "SELECT 3;").unwrap();
+    let later = SystemTime::now() + Duration::from_secs(5);
+    std::fs::File::options().write(true).open(&path).unwrap().set_modified(later).unwrap();
+    let change = file.check_external_change().unwrap();
+    assert_eq!(change, ExternalChange::Modified("SELECT 3;".to_string()));
+    // 同一变化只报告一次
+    assert_eq!(file.check_external_change(), None);
+
+    // 保留编辑器内容后，编辑器内容视为未保存
+    file.keep_local(&change);
+    assert!(file.is_dirty("SELECT 2;"));
+    file.reloaded("SELECT 3;");
+    assert!(!file.is_dirty("SELECT 3;"));
+
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(file.check_external_change(), Some(ExternalChange::Removed));
+}
+
+// ============================================================================
+// 拖放文件测试
+// ============================================================================
+
+#[test]
+fn test_dropped_file_kind() {
+    use std::path::Path;
+
+    assert_eq!(DroppedFileKind::from_path(Path::new("/tmp/app.db")), DroppedFileKind::SqliteDatabase);
+    assert_eq!(DroppedFileKind::from_path(Path::new("data.SQLite3")), DroppedFileKind::SqliteDatabase);
+    assert_eq!(DroppedFileKind::from_path(Path::new("report.sql")), DroppedFileKind::SqlScript);
+    assert_eq!(DroppedFileKind::from_path(Path::new("users.CSV")), DroppedFileKind::ImportData);
+    assert_eq!(DroppedFileKind::from_path(Path::new("rows.json")), DroppedFileKind::ImportData);
+    assert_eq!(DroppedFileKind::from_path(Path::new("notes.txt")), DroppedFileKind::Unsupported);
+    assert_eq!(DroppedFileKind::from_path(Path::new("Makefile")), DroppedFileKind::Unsupported);
+}
+
+// ============================================================================
+// 命令行参数测试
+// ============================================================================
+
+fn cli(args: &[&str]) -> Result<CliCommand, String> {
+    parse_cli_args(args.iter().map(|s| s.to_string()))
+}
+
+#[test]
+fn test_parse_cli_args() {
+    assert_eq!(cli(&[]), Ok(CliCommand::Gui));
+    assert_eq!(cli(&["--help"]), Ok(CliCommand::Help));
+    assert_eq!(cli(&["-V"]), Ok(CliCommand::Version));
+
+    let Ok(CliCommand::Run(args)) = cli(&["--connection", "prod", "-q", "SELECT 1", "--output=out.json"]) else {
+        panic!("应解析为无界面执行");
+    };
+    assert_eq!(args.connection, "prod");
+    assert_eq!(args.query, "SELECT 1");
+    // 未指定格式时按输出文件扩展名推断
+    assert_eq!(args.format, ExportFormat::Json);
+    assert_eq!(args.table, "exported");
+
+    let Ok(CliCommand::Run(args)) = cli(&["-c", "dev", "-q", "SELECT 1", "-f", "SQL", "-o", "out.txt", "-t", "users"]) else {
+        panic!("应解析为无界面执行");
+    };
+    assert_eq!(args.format, ExportFormat::Sql);
+    assert_eq!(args.table, "users");
+
+    let Ok(CliCommand::Run(args)) = cli(&["-c", "dev", "-q", "SELECT 1"]) else {
+        panic!("应解析为无界面执行");
+    };
+    assert_eq!(args.format, ExportFormat::Csv);
+    assert_eq!(args.output, None);
+
+    assert!(cli(&["-q", "SELECT 1"]).unwrap_err().contains("--connection"));
+    assert!(cli(&["-c", "dev"]).unwrap_err().contains("--query"));
+    assert!(cli(&["-c", "dev", "-q", "SELECT 1", "-f", "xml"]).unwrap_err().contains("xml"));
+
+    // 插件格式：显式指定或按扩展名推断
+    let Ok(CliCommand::Run(args)) = cli(&["-c", "dev", "-q", "SELECT 1", "-f", "Markdown"]) else {
+        panic!("应解析为无界面执行");
+    };
+    assert_eq!(args.plugin_format.as_deref(), Some("markdown"));
+    let Ok(CliCommand::Run(args)) = cli(&["-c", "dev", "-q", "SELECT 1", "-o", "out.md"]) else {
+        panic!("应解析为无界面执行");
+    };
+    assert_eq!(args.plugin_format.as_deref(), Some("markdown"));
+    assert!(cli(&["-c"]).unwrap_err().contains("缺少值"));
+    assert!(cli(&["--bogus"]).unwrap_err().contains("--bogus"));
+
+    assert_eq!(cli(&["--workflow", "nightly"]), Ok(CliCommand::Workflow("nightly".to_string())));
+    assert!(cli(&["-w", "nightly", "-c", "dev"]).is_err());
+}
+
+#[test]
+fn test_headless_query_records_audit() {
+    use gridix::app::run_query_headless;
+    use gridix::database::{ConnectionConfig, DatabaseType};
+
+    let dir = tempfile::tempdir().unwrap();
+    let log = AuditLog::new(dir.path().join("audit.log"));
+    let mut config = ConnectionConfig::new("cli", DatabaseType::SQLite);
+    config.database = dir.path().join("cli.db").to_string_lossy().into_owned();
+    let run = |sql: &str| {
+        let Ok(CliCommand::Run(mut args)) = cli(&["-c", "cli", "-q", sql]) else {
+            panic!("应解析为查询命令");
+        };
+        args.output = Some(dir.path().join("out.csv"));
+        run_query_headless(&config, &args, Some(log.clone()))
+    };
+
+    run("CREATE TABLE t (id INTEGER)").unwrap();
+    run("INSERT INTO t VALUES (1), (2)").unwrap();
+    assert_eq!(run("SELECT * FROM t"), Ok(2));
+    assert!(run("DELETE FROM missing").is_err());
+
+    // 只记录写操作，失败的语句也记录
+    let entries = log.read().unwrap();
+    assert_eq!(entries.len(), 3);
+    assert!(entries.iter().all(|e| e.source == AuditSource::Cli && e.connection == "cli"));
+    assert_eq!(entries[1].sql, "INSERT INTO t VALUES (1), (2)");
+    assert_eq!(entries[1].affected_rows, Some(2));
+    assert!(!entries[2].success);
+}
+
+#[test]
+fn test_write_csv_to_writer() {
+    let result = QueryResult {
+        columns: vec!["id".to_string(), "name".to_string()],
+        rows: vec![vec!["1".to_string(), "a,b".to_string()]],
+        ..Default::default()
+    };
+    let mut out = Vec::new();
+    write_csv(&result, &mut out).unwrap();
+    assert_eq!(String::from_utf8(out).unwrap(), "id,name\n1,\"a,b\"\n");
+}
+
+// ============================================================================
+// 工作流测试
+// ============================================================================
+
+fn sample_workflow() -> Workflow {
+    let mut workflow = Workflow::new("nightly");
+    workflow.steps = vec![
+        WorkflowStep::query("prod", "SELECT * FROM orders"),
+        WorkflowStep::export(ExportFormat::Csv, "orders_{date}.csv"),
+        WorkflowStep::query("prod", "DELETE FROM staging"),
+    ];
+    workflow
+}
+
+#[test]
+fn test_workflow_validate() {
+    assert!(sample_workflow().validate().is_ok());
+    assert!(Workflow::new("empty").validate().unwrap_err().contains("没有步骤"));
+
+    let mut workflow = sample_workflow();
+    workflow.steps.swap(0, 1);
+    assert!(workflow.validate().unwrap_err().contains("第 1 步"));
+
+    let mut workflow = sample_workflow();
+    workflow.steps[2] = WorkflowStep::query("", "SELECT 1");
+    assert!(workflow.validate().unwrap_err().contains("第 3 步"));
+}
+
+#[test]
+fn test_workflow_run_failure_handling() {
+    // 默认失败后跳过剩余步骤
+    let mut run = WorkflowRun::new(sample_workflow());
+    assert_eq!(run.start_next(), Some(0));
+    assert_eq!(run.statuses[0], StepStatus::Running);
+    assert!(!run.is_finished());
+    run.finish_step(0, Err("连接失败".to_string()));
+    assert_eq!(run.statuses[1], StepStatus::Skipped);
+    assert_eq!(run.statuses[2], StepStatus::Skipped);
+    assert_eq!(run.start_next(), None);
+    assert!(run.is_finished());
+    assert_eq!(run.failed_count(), 1);
+    assert_eq!(run.finished_count(), 3);
+
+    // 失败后继续执行
+    let mut workflow = sample_workflow();
+    workflow.continue_on_error = true;
+    let mut run = WorkflowRun::new(workflow);
+    let idx = run.start_next().unwrap();
+    run.finish_step(idx, Ok("3 行".to_string()));
+    let idx = run.start_next().unwrap();
+    run.finish_step(idx, Err("磁盘已满".to_string()));
+    assert_eq!(run.start_next(), Some(2));
+    assert_eq!(run.finished_count(), 2);
+}
+
+#[test]
+fn test_workflow_config_roundtrip() {
+    let mut config = AppConfig::default();
+    config.workflows.push(sample_workflow());
+    let text = toml::to_string(&config).unwrap();
+    assert!(text.contains("type = \"export\""));
+    assert!(text.contains("format = \"csv\""));
+    assert!(!text.contains("plugin"));
+    let parsed: AppConfig = toml::from_str(&text).unwrap();
+    assert_eq!(parsed.workflows, config.workflows);
+
+    // 导出插件格式按 id 保存
+    config.workflows[0].steps[1] = WorkflowStep::export_plugin("markdown", "orders.md");
+    assert_eq!(config.workflows[0].steps[1].describe(), "导出 markdown → orders.md");
+    let text = toml::to_string(&config).unwrap();
+    assert!(text.contains("plugin = \"markdown\""));
+    let parsed: AppConfig = toml::from_str(&text).unwrap();
+    assert_eq!(parsed.workflows, config.workflows);
+
+    let now = chrono::Local::now();
+    let expanded = expand_export_path("out_{date}_{time}.csv", now);
+    assert_eq!(expanded, format!("out_{}_{}.csv", now.format("%Y%m%d"), now.format("%H%M%S")));
+}
+
+// ==================== 插件注册表测试 ====================
+
+struct UpperCsvExporter;
+
+impl ExporterPlugin for UpperCsvExporter {
+    fn id(&self) -> &str {
+        "upper"
+    }
+
+    fn name(&self) -> &str {
+        "Upper"
+    }
+
+    fn extension(&self) -> &str {
+        "txt"
+    }
+
+    fn export(&self, result: &QueryResult, out: &mut dyn std::io::Write) -> Result<(), String> {
+        let mut buf = Vec::new();
+        write_csv(result, &mut buf)?;
+        out.write_all(String::from_utf8_lossy(&buf).to_uppercase().as_bytes())
+            .map_err(|e| e.to_string())
+    }
+}
+
+#[test]
+fn test_plugin_registry_exporters() {
+    let result = QueryResult {
+        columns: vec!["id".to_string(), "name".to_string()],
+        rows: vec![vec!["1".to_string(), "a|b".to_string()]],
+        ..Default::default()
+    };
+
+    let mut registry = PluginRegistry::new();
+    let markdown = registry.exporter("MARKDOWN").expect("内置 Markdown 导出");
+    assert_eq!(markdown.extension(), "md");
+    let mut out = Vec::new();
+    markdown.export(&result, &mut out).unwrap();
+    assert_eq!(String::from_utf8(out).unwrap(), "| id | name |\n| --- | --- |\n| 1 | a\\|b |\n");
+
+    registry.register_exporter(UpperCsvExporter);
+    registry.register_exporter(UpperCsvExporter);
+    assert_eq!(registry.exporters().len(), 2);
+    let mut out = Vec::new();
+    registry.exporter("upper").unwrap().export(&result, &mut out).unwrap();
+    assert!(String::from_utf8(out).unwrap().starts_with("ID,NAME"));
+
+    assert!(PluginRegistry::empty().exporter("markdown").is_none());
+}
+
+#[test]
+fn test_plugin_registry_renderers() {
+    // 图片预览会读取本地文件，默认不注册
+    let mut registry = PluginRegistry::new();
+    assert!(registry.renderer_for("avatar", "/tmp/a.PNG").is_none());
+
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path().join("images");
+    std::fs::create_dir_all(root.join("users")).unwrap();
+    std::fs::write(root.join("users").join("a.png"), b"png").unwrap();
+    std::fs::write(dir.path().join("secret.png"), b"png").unwrap();
+
+    registry.set_image_preview_dir(Some(&root));
+    assert_eq!(registry.renderer_for("avatar", "users/a.PNG").map(|r| r.id()), Some("image_path"));
+    assert!(registry.renderer_for("avatar", "https://example.com/a.png").is_none());
+    assert!(registry.renderer_for("name", "alice").is_none());
+    assert!(registry.panels().is_empty());
+
+    // 清空预览目录后不再预览
+    registry.set_image_preview_dir(None);
+    assert!(registry.renderer_for("avatar", "users/a.PNG").is_none());
+
+    // 只读取预览目录中的文件
+    let renderer = ImagePathRenderer::new(&root);
+    assert!(renderer.resolve(" users/a.png ").is_some());
+    assert!(renderer.resolve(&root.join("users").join("a.png").display().to_string()).is_some());
+    assert!(renderer.resolve("../secret.png").is_none());
+    assert!(renderer.resolve(&dir.path().join("secret.png").display().to_string()).is_none());
+    assert!(renderer.resolve("users/missing.png").is_none());
+}
+
+// ==================== 本地 API 服务测试 ====================
+
+fn api_request(head: &str) -> ApiRequest {
+    ApiRequest::parse(head).unwrap()
+}
+
+#[test]
+fn test_api_request_routing() {
+    assert_eq!(api_request("GET / HTTP/1.1\r\n\r\n").route(), ApiRoute::List);
+    assert_eq!(api_request("GET /queries/ HTTP/1.1\r\n\r\n").route(), ApiRoute::List);
+    assert_eq!(api_request("POST /queries HTTP/1.1\r\n\r\n").route(), ApiRoute::MethodNotAllowed);
+    assert_eq!(api_request("GET /other HTTP/1.1\r\n\r\n").route(), ApiRoute::NotFound);
+
+    let query = |name: &str, format| ApiRoute::Query { name: name.to_string(), format };
+    assert_eq!(api_request("GET /queries/daily HTTP/1.1\r\n\r\n").route(), query("daily", ApiFormat::Json));
+    assert_eq!(api_request("GET /queries/daily.csv HTTP/1.1\r\n\r\n").route(), query("daily", ApiFormat::Csv));
+    assert_eq!(
+        api_request("GET /queries/daily?format=CSV HTTP/1.1\r\n\r\n").route(),
+        query("daily", ApiFormat::Csv)
+    );
+    assert_eq!(
+        api_request("GET /queries/%E6%97%A5%E6%8A%A5.json HTTP/1.1\r\n\r\n").route(),
+        query("日报", ApiFormat::Json)
+    );
+
+    assert_eq!(percent_decode("a+b%2Fc%zz"), "a b/c%zz");
+    assert!(ApiRequest::parse("garbage").is_err());
+}
+
+#[test]
+fn test_api_request_token() {
+    let bearer = api_request("GET /queries HTTP/1.1\r\nHost: localhost\r\nAuthorization: Bearer s3cret\r\n\r\n");
+    assert_eq!(bearer.bearer.as_deref(), Some("s3cret"));
+    assert!(bearer.authorized("s3cret"));
+    assert!(!bearer.authorized("other"));
+
+    assert!(!bearer.authorized("s3cre"));
+    assert!(!bearer.authorized("s3cret0"));
+
+    // 只接受请求头中的令牌，查询参数中的令牌会留在日志和浏览历史中
+    let param = api_request("GET /queries?token=s3cret HTTP/1.1\r\n\r\n");
+    assert!(!param.authorized("s3cret"));
+
+    // 未设置令牌时拒绝所有请求
+    let anonymous = api_request("GET /queries HTTP/1.1\r\n\r\n");
+    assert!(!anonymous.authorized(""));
+    assert!(!anonymous.authorized("s3cret"));
+
+    // 只接受本机 Host，防止 DNS 重绑定
+    assert!(bearer.allowed_host());
+    assert!(api_request("GET / HTTP/1.1\r\nhost: 127.0.0.1:7878\r\n\r\n").allowed_host());
+    assert!(!api_request("GET / HTTP/1.1\r\nHost: evil.example:7878\r\n\r\n").allowed_host());
+    assert!(!api_request("GET / HTTP/1.1\r\nHost: localhost.evil.example\r\n\r\n").allowed_host());
+    assert!(!anonymous.allowed_host());
+
+    let mut config = ApiServerConfig::default();
+    assert!(config.ensure_token().unwrap());
+    assert!(config.token.len() >= 32);
+    let token = config.token.clone();
+    assert!(!config.ensure_token().unwrap());
+    assert_eq!(config.token, token);
+}
+
+#[test]
+fn test_api_saved_query_validation() {
+    let query = |name: &str, sql: &str| SavedQuery {
+        name: name.to_string(),
+        connection: "prod".to_string(),
+        sql: sql.to_string(),
+    };
+    let mut config = ApiServerConfig {
+        queries: vec![query("daily", "SELECT * FROM orders")],
+        ..Default::default()
+    };
+    assert!(config.validate().is_ok());
+    assert!(config.find("daily").is_some());
+
+    config.queries.push(query("purge", "DELETE FROM orders"));
+    assert!(config.validate().unwrap_err().contains("只读"));
+    // 只读语句后面不能跟其他语句
+    // 不在只读白名单中的语句（调用存储过程等）同样拒绝
+    config.queries[1] = query("purge", "CALL purge()");
+    assert!(config.validate().unwrap_err().contains("只读"));
+    config.queries[1] = query("purge", "SELECT 1; DELETE FROM orders");
+    assert!(config.validate().unwrap_err().contains("一条语句"));
+    config.queries[1] = query("trailing", "SELECT 1;");
+    assert!(config.validate().is_ok());
+    config.queries[1] = query("a/b", "SELECT 1");
+    assert!(config.validate().unwrap_err().contains("不能包含"));
+    config.queries[1] = query(" daily ", "SELECT 1");
+    assert!(config.validate().unwrap_err().contains("重复"));
+
+    // 配置往返
+    let mut app_config = AppConfig::default();
+    app_config.api_server.queries.push(query("daily", "SELECT 1"));
+    let text = toml::to_string(&app_config).unwrap();
+    let parsed: AppConfig = toml::from_str(&text).unwrap();
+    assert_eq!(parsed.api_server, app_config.api_server);
+
+    let response = String::from_utf8(http_response(404, "text/plain", b"nope")).unwrap();
+    assert!(response.starts_with("HTTP/1.1 404 Not Found\r\n"));
+    assert!(response.contains("Content-Length: 4\r\n"));
+    assert!(response.ends_with("\r\n\r\nnope"));
+}
+
+// ============================================================================
+// 应用日志测试
+// ============================================================================
+
+#[test]
+fn test_log_entry_parse() {
+    let line = r#"{"timestamp":"2026-01-02T03:04:05.000000Z","level":"WARN","fields":{"message":"连接失败","host":"db1","retries":3},"target":"gridix::database"}"#;
+    let entry = LogEntry::parse(line).unwrap();
+    assert_eq!(entry.level, LogLevel::Warn);
+    assert_eq!(entry.target, "gridix::database");
+    assert_eq!(entry.message, "连接失败");
+    assert!(entry.fields.contains(&("host".to_string(), "db1".to_string())));
+    assert!(entry.fields.contains(&("retries".to_string(), "3".to_string())));
+    assert!(entry.to_line().contains("WARN  gridix::database: 连接失败"));
+    assert!(entry.to_line().contains("host=db1"));
+
+    // 非 JSON 的行按纯文本保留，空行忽略
+    let plain = LogEntry::parse("thread 'main' panicked").unwrap();
+    assert_eq!(plain.level, LogLevel::Info);
+    assert_eq!(plain.message, "thread 'main' panicked");
+    assert!(LogEntry::parse("   ").is_none());
+
+    assert_eq!(LogLevel::parse("warning"), Some(LogLevel::Warn));
+    assert!(LogLevel::Error > LogLevel::Info);
+}
+
+#[test]
+fn test_rotating_log_writer() {
+    use std::io::Write;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("logs").join("gridix.log");
+    let mut writer = RotatingFileWriter::new(&path, 16, 2).unwrap();
+
+    for line in ["first line\n", "second line\n", "third line\n", "fourth line\n"] {
+        writer.write_all(line.as_bytes()).unwrap();
+    }
+    writer.flush().unwrap();
+
+    // 每条都超过上限，只保留当前文件和两个旧文件
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "fourth line\n");
+    assert_eq!(std::fs::read_to_string(rotated_log_path(&path, 1)).unwrap(), "third line\n");
+    assert_eq!(std::fs::read_to_string(rotated_log_path(&path, 2)).unwrap(), "second line\n");
+    assert!(!rotated_log_path(&path, 3).exists());
+}
+
+#[test]
+fn test_log_tail_incremental() {
+    use std::io::Write;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("gridix.log");
+    let mut tail = LogTail::new(&path);
+    assert!(tail.poll().unwrap().is_empty());
+
+    let mut file = std::fs::OpenOptions::new().create(true).append(true).open(&path).unwrap();
+    file.write_all(b"one\ntwo\nthr").unwrap();
+    let messages: Vec<String> = tail.poll().unwrap().into_iter().map(|e| e.message).collect();
+    assert_eq!(messages, vec!["one", "two"]);
+
+    // 不完整的行等到换行后再返回
+    file.write_all(b"ee\n").unwrap();
+    let messages: Vec<String> = tail.poll().unwrap().into_iter().map(|e| e.message).collect();
+    assert_eq!(messages, vec!["three"]);
+    assert!(tail.poll().unwrap().is_empty());
+
+    // 文件被轮转（变小）后从头读取
+    std::fs::write(&path, "new\n").unwrap();
+    let messages: Vec<String> = tail.poll().unwrap().into_iter().map(|e| e.message).collect();
+    assert_eq!(messages, vec!["new"]);
+}
+
+// ============================================================================
+// 后台元数据刷新测试
+// ============================================================================
+
+fn names(list: &[&str]) -> Vec<String> {
+    list.iter().map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn test_catalog_diff_and_selection() {
+    let old = names(&["a", "b", "c"]);
+    let new = names(&["a", "c", "d"]);
+    let diff = CatalogDiff::between(&old, &new);
+    assert_eq!(diff.added, names(&["d"]));
+    assert_eq!(diff.removed, names(&["b"]));
+    assert!(CatalogDiff::between(&old, &old).is_empty());
+
+    // 选中项按名称保持
+    assert_eq!(remap_selection(&old, &new, 2), 1);
+    // 已删除时保持原索引并限制在范围内
+    assert_eq!(remap_selection(&old, &new, 1), 1);
+    assert_eq!(remap_selection(&old, &names(&["a"]), 2), 0);
+    assert_eq!(remap_selection(&old, &[], 1), 0);
+}
+
+#[test]
+fn test_ddl_tables() {
+    assert_eq!(
+        ddl_tables("CREATE TABLE IF NOT EXISTS \"s\".\"users\" (id INT)"),
+        Some(names(&["users"]))
+    );
+    assert_eq!(ddl_tables("-- 删除\nDROP TABLE a, b CASCADE;"), Some(names(&["a", "b"])));
+    assert_eq!(ddl_tables("alter table x rename to y"), Some(names(&["x", "y"])));
+    assert_eq!(ddl_tables("ALTER TABLE x RENAME COLUMN a TO b"), Some(names(&["x"])));
+    assert_eq!(ddl_tables("RENAME TABLE `a` TO `b`"), Some(names(&["a", "b"])));
+    assert_eq!(ddl_tables("CREATE OR REPLACE VIEW v AS SELECT 1"), Some(names(&["v"])));
+    assert_eq!(ddl_tables("SELECT * FROM t"), None);
+    assert_eq!(ddl_tables("CREATE INDEX idx ON t(a)"), None);
+
+    // 与审计、预处理语句缓存共用同一套注释和关键字判断
+    assert!(is_ddl_statement("/* 迁移 */ CREATE INDEX idx ON t(a)"));
+    assert!(is_ddl_statement("-- 注释\nrename table a to b"));
+    assert!(!is_ddl_statement("-- DROP TABLE t\nSELECT 1"));
+    assert!(!is_ddl_statement("SELECT * FROM created"));
+}
+
+#[test]
+fn test_metadata_refresh_schedule() {
+    use std::time::{Duration, Instant};
+
+    let now = Instant::now();
+    let mut refresh = MetadataRefresh::new();
+    // 未连接时不计时
+    assert!(!refresh.is_due(60, now));
+
+    refresh.reset(now);
+    assert!(!refresh.is_due(60, now));
+    assert!(refresh.is_due(60, now + Duration::from_secs(60)));
+    assert!(!refresh.is_due(0, now + Duration::from_secs(3600)));
+    assert_eq!(refresh.time_until_next(60, now + Duration::from_secs(20)), Some(Duration::from_secs(40)));
+
+    // DDL 请求立即到期，刷新中不再重复开始
+    refresh.request(names(&["b", "a"]));
+    assert!(refresh.is_due(0, now));
+    let (generation, stale) = refresh.start();
+    assert_eq!(stale, names(&["a", "b"]));
+    assert!(!refresh.is_due(60, now + Duration::from_secs(120)));
+    assert!(refresh.finish(generation, now));
+    assert!(!refresh.is_due(60, now));
+
+    // 切换连接后，之前发出的结果被丢弃
+    refresh.request(Vec::new());
+    let (old_generation, _) = refresh.start();
+    refresh.reset(now);
+    assert!(!refresh.finish(old_generation, now));
+}
+
+#[test]
+fn test_pick_column_tables() {
+    let tables = names(&["a", "b", "c", "d"]);
+    let cached = ["a", "b"];
+    let picked = pick_column_tables(&tables, &names(&["b", "gone"]), |t| cached.contains(&t), 10);
+    assert_eq!(picked, names(&["b", "c", "d"]));
+    let picked = pick_column_tables(&tables, &[], |t| cached.contains(&t), 1);
+    assert_eq!(picked, names(&["c"]));
+}
+
+#[test]
+fn test_metadata_refresh_preference() {
+    let mut config = AppConfig::default();
+    assert_eq!(config.metadata_refresh_secs, 300);
+    let mut prefs = config.preferences();
+    prefs.metadata_refresh_secs = 0;
+    assert!(prefs.validate().is_ok());
+    prefs.metadata_refresh_secs = 100_000;
+    assert!(prefs.validate().is_err());
+    config.apply_preferences(&prefs);
+    config.sanitize();
+    assert_eq!(config.metadata_refresh_secs, 86400);
+}
+
+// ============================================================================
+// 最近使用的表和数据库测试
+// ============================================================================
+
+#[test]
+fn test_recent_objects_rank_by_frequency_and_recency() {
+    let now = chrono::Local::now();
+    let mut recent = RecentObjects::default();
+    // 一周前频繁使用的表
+    for _ in 0..3 {
+        recent.record("prod", Some("shop"), Some("orders"), now - chrono::Duration::days(6));
+    }
+    // 刚刚用过一次的表和数据库
+    recent.record("prod", Some("shop"), Some("users"), now);
+    recent.record("local", None, Some("notes"), now - chrono::Duration::minutes(5));
+    recent.record("prod", Some("crm"), None, now - chrono::Duration::days(60));
+
+    let ranked = recent.ranked_entries(now);
+    let labels: Vec<String> = ranked.iter().map(|e| e.label()).collect();
+    assert_eq!(labels, vec!["shop.users", "notes", "shop.orders", "crm"]);
+    assert_eq!(ranked[2].count, 3);
+    assert_eq!(
+        ranked[1].target(),
+        RecentTarget { connection: "local".into(), database: None, table: Some("notes".into()) }
+    );
+
+    // 再次使用会增加次数并更新时间
+    recent.record("prod", Some("shop"), Some("orders"), now);
+    assert_eq!(recent.ranked_entries(now)[0].label(), "shop.orders");
+
+    recent.remove(&RecentTarget { connection: "prod".into(), database: Some("shop".into()), table: Some("users".into()) });
+    recent.remove_connection("local");
+    let labels: Vec<String> = recent.ranked_entries(now).iter().map(|e| e.label()).collect();
+    assert_eq!(labels, vec!["shop.orders", "crm"]);
+
+    // 随配置保存和恢复
+    let config = AppConfig { recent_objects: recent, ..Default::default() };
+    let restored: AppConfig = toml::from_str(&toml::to_string(&config).unwrap()).unwrap();
+    assert_eq!(restored.recent_objects.ranked_entries(now).len(), 2);
+}
+
+#[test]
+fn test_recent_objects_evict_lowest_score() {
+    let now = chrono::Local::now();
+    let mut recent = RecentObjects::default();
+    recent.record("prod", Some("shop"), Some("orders"), now);
+    recent.record("prod", Some("shop"), Some("orders"), now);
+    for i in 0..gridix::core::constants::history::MAX_RECENT_OBJECTS {
+        recent.record("prod", Some("shop"), Some(&format!("t{}", i)), now - chrono::Duration::hours(i as i64));
+    }
+    let ranked = recent.ranked_entries(now);
+    assert_eq!(ranked.len(), gridix::core::constants::history::MAX_RECENT_OBJECTS);
+    assert_eq!(ranked[0].label(), "shop.orders");
+    // 最早使用的表被淘汰
+    assert!(ranked.iter().all(|e| e.label() != format!("shop.t{}", gridix::core::constants::history::MAX_RECENT_OBJECTS - 1)));
+}
+
+// ============================================================================
+// 筛选预设测试
+// ============================================================================
+
+fn saved_filter(column: &str, value: &str) -> SavedFilter {
+    SavedFilter {
+        column: column.to_string(),
+        operator: "equals".to_string(),
+        value: value.to_string(),
+        value2: String::new(),
+        enabled: true,
+        case_sensitive: false,
+        or_next: false,
+    }
+}
+
+#[test]
+fn test_filter_presets_per_table() {
+    let mut presets = FilterPresets::default();
+    let active = FilterPreset { name: "active".to_string(), filters: vec![saved_filter("status", "active")] };
+    presets.save("prod", Some("shop"), "users", active.clone());
+    presets.save("prod", Some("shop"), "orders", active.clone());
+
+    // 同名预设覆盖，其他表和数据库互不影响
+    let replaced = FilterPreset { name: "active".to_string(), filters: vec![saved_filter("status", "on")] };
+    presets.save("prod", Some("shop"), "users", replaced.clone());
+    assert_eq!(presets.for_table("prod", Some("shop"), "users"), &[replaced]);
+    assert_eq!(presets.for_table("prod", Some("shop"), "orders"), std::slice::from_ref(&active));
+    assert!(presets.for_table("prod", Some("other"), "users").is_empty());
+
+    presets.remove("prod", Some("shop"), "users", "active");
+    assert!(presets.for_table("prod", Some("shop"), "users").is_empty());
+    presets.remove_table("prod", Some("shop"), "orders");
+    assert!(presets.for_table("prod", Some("shop"), "orders").is_empty());
+
+    presets.save("dev", None, "users", active);
+    presets.remove_connection("dev");
+    assert!(presets.for_table("dev", None, "users").is_empty());
+}
+
+#[test]
+fn test_filter_presets_config_roundtrip() {
+    let mut config = AppConfig::default();
+    config.filter_presets.save(
+        "prod",
+        None,
+        "users",
+        FilterPreset { name: "recent".to_string(), filters: vec![saved_filter("id", "1")] },
+    );
+    let parsed: AppConfig = toml::from_str(&toml::to_string(&config).unwrap()).unwrap();
+    assert_eq!(parsed.filter_presets.for_table("prod", None, "users")[0].filters[0].value, "1");
+
+    // 会话中保存上次的筛选条件
+    let mut session = SessionState::new();
+    session.record_filters(vec![saved_filter("name", "bob")]);
+    let parsed: SessionState = toml::from_str(&toml::to_string(&session).unwrap()).unwrap();
+    assert_eq!(parsed.active_filters, vec![saved_filter("name", "bob")]);
+}
+
+// ============================================================================
+// 全库数据搜索测试
+// ============================================================================
+
+#[test]
+fn test_data_search_columns() {
+    assert!(is_text_type("VARCHAR(255)"));
+    assert!(is_text_type("text"));
+    assert!(is_text_type("Nullable(String)"));
+    assert!(!is_text_type("integer"));
+    assert!(!is_text_type("timestamp"));
+
+    let column = |name: &str, data_type: &str| ColumnInfo {
+        name: name.to_string(),
+        data_type: data_type.to_string(),
+        is_primary_key: false,
+        is_nullable: true,
+        default_value: None,
+    };
+    let columns = vec![column("id", "integer"), column("email", "varchar(255)"), column("note", "text")];
+    assert_eq!(searchable_columns(&columns, ""), vec!["email", "note"]);
+    assert_eq!(searchable_columns(&columns, "MAIL"), vec!["email"]);
+    assert!(searchable_columns(&columns, "id").is_empty());
+}
+
+#[test]
+fn test_data_search_sql() {
+    let cols = vec!["\"email\"".to_string(), "\"note\"".to_string()];
+    let sql = data_search_sql(DatabaseType::PostgreSQL, "\"users\"", &cols, "50%_O'k", false, 50).unwrap();
+    assert_eq!(
+        sql,
+        "SELECT * FROM \"users\" WHERE LOWER(\"email\") LIKE '%50!%!_o''k%' ESCAPE '!' \
+         OR LOWER(\"note\") LIKE '%50!%!_o''k%' ESCAPE '!' LIMIT 50;"
+    );
+
+    let cols = vec!["`email`".to_string()];
+    let sql = data_search_sql(DatabaseType::MySQL, "`users`", &cols, "a\\b", true, 10).unwrap();
+    assert!(sql.contains("BINARY `email` LIKE '%a\\\\b%' ESCAPE '!'"), "{}", sql);
+
+    assert!(data_search_sql(DatabaseType::SQLite, "\"users\"", &[], "x", false, 10).is_none());
+    assert!(data_search_sql(DatabaseType::SQLite, "\"users\"", &cols, "", false, 10).is_none());
+}
+
+// ============================================================================
+// JSON 路径测试
+// ============================================================================
+
+#[test]
+fn test_json_detection() {
+    assert!(looks_like_json(" {\"a\": 1} "));
+    assert!(looks_like_json("[1, 2]"));
+    assert!(!looks_like_json("{not json}"));
+    assert!(!looks_like_json("42"));
+    assert_eq!(pretty_json("{\"a\":[1]}").unwrap(), "{\n  \"a\": [\n    1\n  ]\n}");
+    assert!(pretty_json("text").is_none());
+
+    let result = QueryResult {
+        columns: vec!["id".to_string(), "meta".to_string(), "mixed".to_string()],
+        rows: vec![
+            vec!["1".to_string(), "{\"a\":1}".to_string(), "[]".to_string()],
+            vec!["2".to_string(), "NULL".to_string(), "plain".to_string()],
+        ],
+        affected_rows: 0,
+        truncated: false,
+        original_row_count: None,
+    };
+    assert_eq!(detect_json_columns(&result), vec![false, true, false]);
+}
+
+#[test]
+fn test_json_path_condition() {
+    let path = JsonPath::parse("$.user[\"first name\"].tags[2]").unwrap();
+    assert_eq!(
+        path.segments,
+        vec![
+            JsonPathSegment::Key("user".to_string()),
+            JsonPathSegment::Key("first name".to_string()),
+            JsonPathSegment::Key("tags".to_string()),
+            JsonPathSegment::Index(2),
+        ]
+    );
+    assert_eq!(path.to_sql_path(), "$.\"user\".\"first name\".\"tags\"[2]");
+    assert!(JsonPath::parse("user.id").is_err());
+    assert!(JsonPath::parse("$.tags[x]").is_err());
+
+    let cell = r#"{"user": {"id": 5, "name": "Ann", "a=b": true}, "tags": ["x"], "gone": null}"#;
+    let check = |text: &str| JsonPathCondition::parse(text).unwrap().matches(cell);
+    assert!(check("$.user.id = 5"));
+    assert!(check("$.user.id = 5.0"));
+    assert!(check("$.user.id >= 3"));
+    assert!(!check("$.user.id < 5"));
+    assert!(check("$.user.name = Ann"));
+    assert!(check("$.user.name = \"Ann\""));
+    assert!(check("$.user[\"a=b\"] = true"));
+    assert!(check("$.tags[0] != y"));
+    assert!(check("$.missing != 1"));
+    assert!(!check("$.missing = 1"));
+    assert!(check("$.gone"));
+    assert!(!check("$.tags[1]"));
+    assert!(!JsonPathCondition::parse("$.user.id = 5").unwrap().matches("not json"));
+
+    let condition = JsonPathCondition::parse("$.user.id <> 5").unwrap();
+    assert_eq!(condition.compare, JsonCompare::Ne);
+    assert!(JsonPathCondition::parse("$.user.id =").is_err());
+}
+
+// ============================================================================
+// 二进制单元格测试
+// ============================================================================
+
+#[test]
+fn test_blob_encoding() {
+    let bytes = b"Hi\x00\xff";
+    let cell = encode_blob(bytes);
+    assert_eq!(cell, "\\x486900ff");
+    assert!(is_blob(&cell));
+    assert_eq!(blob_len(&cell), Some(4));
+    assert_eq!(blob_label(4), "<BLOB 4 bytes>");
+    assert_eq!(decode_blob(&cell).unwrap(), bytes);
+    assert!(!is_blob("\\x123"));
+    assert!(!is_blob("\\xzz"));
+    assert!(!is_blob("hello"));
+
+    assert_eq!(hex_dump(bytes), "00000000  48 69 00 ff                                      |Hi..|");
+    let dump = hex_dump(&[b'a'; 20]);
+    assert_eq!(dump.lines().count(), 2);
+    assert!(dump.lines().nth(1).unwrap().starts_with("00000010  61 61 61 61"));
+
+    assert_eq!(blob_literal(bytes, DatabaseType::PostgreSQL), "'\\x486900ff'::bytea");
+    assert_eq!(blob_literal(bytes, DatabaseType::SQLite), "X'486900ff'");
+    assert_eq!(blob_literal(bytes, DatabaseType::Oracle), "HEXTORAW('486900ff')");
+}
+
+// ============================================================================
+// 几何值测试
+// ============================================================================
+
+#[test]
+fn test_geometry_wkt() {
+    let value = parse_geometry("SRID=4326;POINT Z (116.397128 39.916527 50)").unwrap();
+    assert_eq!(value.srid, Some(4326));
+    assert_eq!(value.geometry, Geometry::Point(Some(Coord { x: 116.397128, y: 39.916527 })));
+    assert_eq!(value.to_ewkt(), "SRID=4326;POINT (116.397128 39.916527)");
+
+    let polygon = parse_geometry("polygon((0 0, 4 0, 4 3, 0 0), (1 1, 2 1, 1 2, 1 1))").unwrap();
+    assert_eq!(polygon.srid, None);
+    assert_eq!(
+        polygon.geometry.to_wkt(),
+        "POLYGON ((0 0, 4 0, 4 3, 0 0), (1 1, 2 1, 1 2, 1 1))"
+    );
+    let bounds = polygon.geometry.bounds().unwrap();
+    assert_eq!((bounds.min, bounds.max), (Coord { x: 0.0, y: 0.0 }, Coord { x: 4.0, y: 3.0 }));
+    let pretty = polygon.pretty();
+    assert!(pretty.starts_with("POLYGON\n范围: 0 0 - 4 3\n外环（4 个点）"), "{}", pretty);
+    assert!(pretty.contains("内环 1（4 个点）"));
+
+    assert_eq!(
+        parse_geometry("MULTIPOINT (1 2, (3 4))").unwrap().geometry,
+        Geometry::MultiPoint(vec![Coord { x: 1.0, y: 2.0 }, Coord { x: 3.0, y: 4.0 }])
+    );
+    assert_eq!(
+        parse_geometry("GEOMETRYCOLLECTION (POINT EMPTY, LINESTRING (0 0, 1 1))").unwrap().geometry.to_wkt(),
+        "GEOMETRYCOLLECTION (POINT EMPTY, LINESTRING (0 0, 1 1))"
+    );
+    assert!(parse_geometry("Point of sale").is_none());
+    assert!(parse_geometry("POINT (1 2) extra").is_none());
+    assert!(parse_geometry("hello").is_none());
+}
+
+#[test]
+fn test_geometry_wkb() {
+    // PostGIS 的 EWKB 十六进制输出：SRID=4326;POINT(1 2)
+    let value = parse_geometry("0101000020E6100000000000000000F03F0000000000000040").unwrap();
+    assert_eq!(value.srid, Some(4326));
+    assert_eq!(value.geometry, Geometry::Point(Some(Coord { x: 1.0, y: 2.0 })));
+
+    // 大端序的 LINESTRING(0 0, 1 1)
+    let value = parse_geometry(
+        "000000000200000002000000000000000000000000000000003FF00000000000003FF0000000000000",
+    )
+    .unwrap();
+    assert_eq!(value.geometry.to_wkt(), "LINESTRING (0 0, 1 1)");
+
+    // MySQL 的内部格式：4 字节 SRID + WKB
+    let mut bytes = 4326u32.to_le_bytes().to_vec();
+    bytes.extend([1, 1, 0, 0, 0]);
+    bytes.extend(3.5f64.to_le_bytes());
+    bytes.extend((-1.0f64).to_le_bytes());
+    let value = parse_geometry(&encode_blob(&bytes)).unwrap();
+    assert_eq!(value.to_ewkt(), "SRID=4326;POINT (3.5 -1)");
+
+    assert!(parse_geometry("0101000020E6100000000000000000F03F").is_none());
+    assert!(parse_geometry("123456789012345678").is_none());
+}
+
+// ============================================================================
+// 单元格显示格式
+// ============================================================================
+
+#[test]
+fn test_parse_timestamp() {
+    let (naive, offset) = parse_timestamp("2024-03-01 08:30:00").unwrap();
+    assert_eq!(naive.to_string(), "2024-03-01 08:30:00");
+    assert!(offset.is_none());
+
+    let (_, offset) = parse_timestamp("2024-03-01T08:30:00.250+08:00").unwrap();
+    assert_eq!(offset.unwrap().local_minus_utc(), 8 * 3600);
+    let (_, offset) = parse_timestamp("2024-03-01 08:30:00-05").unwrap();
+    assert_eq!(offset.unwrap().local_minus_utc(), -5 * 3600);
+    let (_, offset) = parse_timestamp("2024-03-01T08:30:00Z").unwrap();
+    assert_eq!(offset.unwrap().local_minus_utc(), 0);
+
+    assert!(parse_timestamp("2024-03-01").is_none());
+    assert!(parse_timestamp("not a timestamp at all").is_none());
+    assert!(parse_timestamp("2024-13-01 08:30:00").is_none());
+    assert!(parse_timestamp("2024-03-01中08:30:00").is_none());
+}
+
+#[test]
+fn test_timestamp_display_conversion() {
+    let mut formatter = ValueFormatter::default();
+    assert!(!formatter.converts_timestamps());
+    assert_eq!(formatter.format_timestamp("2024-03-01 08:30:00+08:00"), None);
+
+    // 带偏移量的值直接换算
+    formatter.timestamp_display = TimestampDisplay::Utc;
+    assert_eq!(
+        formatter.format_timestamp("2024-03-01 08:30:00+08:00").as_deref(),
+        Some("2024-03-01 00:30:00")
+    );
+    assert_eq!(
+        formatter.format_timestamp("2024-03-01 01:00:00.5+08").as_deref(),
+        Some("2024-02-29 17:00:00.500")
+    );
+    // 不带偏移量的值需要服务器时区
+    assert_eq!(formatter.format_timestamp("2024-03-01 08:30:00"), None);
+    formatter.server_offset = Some(-5 * 3600);
+    assert_eq!(formatter.format_timestamp("2024-03-01 20:30:00").as_deref(), Some("2024-03-02 01:30:00"));
+
+    formatter.timestamp_display = TimestampDisplay::Server;
+    assert_eq!(formatter.format_timestamp("2024-03-01 20:30:00+00:00").as_deref(), Some("2024-03-01 15:30:00"));
+    assert_eq!(formatter.format_timestamp("hello"), None);
+    assert_eq!(formatter.timestamp_hint(), "时间显示为服务器时区（UTC-05:00）");
+
+    formatter.server_offset = None;
+    assert!(formatter.timestamp_hint().contains("服务器时区未知"));
+    assert_eq!(format_offset(5 * 3600 + 30 * 60), "UTC+05:30");
+}
+
+#[test]
+fn test_detect_timestamp_columns() {
+    let result = QueryResult {
+        columns: vec!["id".into(), "created_at".into(), "note".into()],
+        rows: vec![
+            vec!["1".into(), "2024-03-01 08:30:00".into(), "2024-03-01 08:30:00".into()],
+            vec!["2".into(), "NULL".into(), "later".into()],
+        ],
+        ..Default::default()
+    };
+    assert_eq!(detect_timestamp_columns(&result), vec![false, true, false]);
+}
+
+#[test]
+fn test_number_format() {
+    let plain = NumberFormat::default();
+    assert_eq!(plain.format("1234567.5"), None);
+
+    let comma = NumberFormat { separators: NumberSeparators::Comma, ..Default::default() };
+    assert_eq!(comma.format("1234567.891").as_deref(), Some("1,234,567.891"));
+    assert_eq!(comma.format("-123456").as_deref(), Some("-123,456"));
+    assert_eq!(comma.format("999").as_deref(), Some("999"));
+    // 大整数不经过浮点数
+    assert_eq!(comma.format("12345678901234567890").as_deref(), Some("12,345,678,901,234,567,890"));
+    // 不是数字或像编号的值保持原样
+    assert_eq!(comma.format("007"), None);
+    assert_eq!(comma.format("abc"), None);
+    assert_eq!(comma.format("NaN"), None);
+    assert_eq!(comma.format("1-2"), None);
+
+    let period = NumberFormat { separators: NumberSeparators::Period, decimals: Some(2), ..Default::default() };
+    assert_eq!(period.format("1234567.891").as_deref(), Some("1.234.567,89"));
+    let space = NumberFormat { separators: NumberSeparators::Space, decimals: Some(0), ..Default::default() };
+    assert_eq!(space.format("0.6").as_deref(), Some("1"));
+    assert_eq!(space.format("-9876.4").as_deref(), Some("-9 876"));
+
+    let scientific = NumberFormat { scientific: true, decimals: Some(2), ..Default::default() };
+    assert_eq!(scientific.format("123456").as_deref(), Some("1.23e5"));
+    assert_eq!(scientific.format("0.00042").as_deref(), Some("4.20e-4"));
+
+    assert!(is_number(" 42 "));
+    assert!(is_number("-1.5e3"));
+    assert!(!is_number("0x1F"));
+    assert!(!is_number("2024-03-01"));
+}
+
+#[test]
+fn test_column_formats_per_table() {
+    let mut formats = ColumnFormats::default();
+    let comma = NumberFormat { separators: NumberSeparators::Comma, align_right: true, ..Default::default() };
+    let columns = std::collections::HashMap::from([("price".to_string(), comma)]);
+    formats.set_table("local", Some("shop"), "orders", &columns);
+    formats.set_table("local", None, "orders", &columns);
+
+    assert_eq!(formats.for_table("local", Some("shop"), "orders").get("price"), Some(&comma));
+    assert!(formats.for_table("local", Some("shop"), "items").is_empty());
+
+    // 随配置保存和读取
+    let text = toml::to_string(&formats).unwrap();
+    let loaded: ColumnFormats = toml::from_str(&text).unwrap();
+    assert_eq!(loaded.for_table("local", Some("shop"), "orders"), columns);
+
+    formats.set_table("local", Some("shop"), "orders", &std::collections::HashMap::new());
+    assert!(formats.for_table("local", Some("shop"), "orders").is_empty());
+    formats.remove_connection("local");
+    assert!(formats.for_table("local", None, "orders").is_empty());
+}
+
+#[test]
+fn test_column_widths_per_table() {
+    let mut widths = ColumnWidths::default();
+    let columns = std::collections::HashMap::from([("name".to_string(), 180.4), ("note".to_string(), 320.6)]);
+    widths.set_table("local", Some("shop"), "orders", &columns);
+
+    // 保存为整数像素
+    let saved = widths.for_table("local", Some("shop"), "orders");
+    assert_eq!(saved.get("name"), Some(&180.0));
+    assert_eq!(saved.get("note"), Some(&321.0));
+    assert!(widths.for_table("local", Some("other"), "orders").is_empty());
+
+    let config = AppConfig { column_widths: widths, ..Default::default() };
+    let loaded: AppConfig = toml::from_str(&toml::to_string(&config).unwrap()).unwrap();
+    assert_eq!(loaded.column_widths.for_table("local", Some("shop"), "orders"), saved);
+
+    let mut widths = loaded.column_widths;
+    widths.remove_table("local", Some("shop"), "orders");
+    assert!(widths.for_table("local", Some("shop"), "orders").is_empty());
+}
+
+#[test]
+fn test_column_orders_per_table() {
+    let mut orders = ColumnOrders::default();
+    let columns = vec!["name".to_string(), "id".to_string()];
+    orders.set_table("local", Some("shop"), "orders", &columns);
+    assert_eq!(orders.for_table("local", Some("shop"), "orders"), columns);
+    assert!(orders.for_table("local", None, "orders").is_empty());
+
+    let config = AppConfig { column_orders: orders, ..Default::default() };
+    let loaded: AppConfig = toml::from_str(&toml::to_string(&config).unwrap()).unwrap();
+    assert_eq!(loaded.column_orders.for_table("local", Some("shop"), "orders"), columns);
+
+    // 恢复原始顺序后删除记录
+    let mut orders = loaded.column_orders;
+    orders.set_table("local", Some("shop"), "orders", &[]);
+    assert!(orders.for_table("local", Some("shop"), "orders").is_empty());
+}
+
+#[test]
+fn test_keep_alive_status() {
+    use std::time::{Duration, Instant};
+
+    let mut keep_alive = KeepAlive::default();
+    let start = Instant::now();
+    // 第一次调用只开始计时
+    assert!(!keep_alive.is_due(60, start));
+    assert!(!keep_alive.is_due(0, start + Duration::from_secs(120)));
+    assert_eq!(keep_alive.time_until_next(60, start + Duration::from_secs(20)), Some(Duration::from_secs(40)));
+    let due = start + Duration::from_secs(60);
+    assert!(keep_alive.is_due(60, due));
+
+    // 检查期间不会重复开始
+    keep_alive.start(due);
+    assert!(!keep_alive.is_due(60, due + Duration::from_secs(120)));
+    assert_eq!(keep_alive.status(), HealthStatus::Unknown);
+
+    assert!(keep_alive.record(Ok(5)));
+    assert_eq!(keep_alive.status(), HealthStatus::Healthy);
+    assert!(keep_alive.record(Ok(5_000)));
+    assert_eq!(keep_alive.status(), HealthStatus::Degraded);
+
+    // 连续失败达到阈值后判定为断开
+    keep_alive.record(Ok(5));
+    for _ in 1..KEEP_ALIVE_DOWN_AFTER {
+        keep_alive.record(Err("timeout".to_string()));
+        assert_eq!(keep_alive.status(), HealthStatus::Degraded);
+    }
+    assert!(keep_alive.record(Err("connection reset".to_string())));
+    assert_eq!(keep_alive.status(), HealthStatus::Down);
+    assert_eq!(keep_alive.last_error(), Some("connection reset"));
+    assert!(keep_alive.describe().contains("connection reset"));
+
+    assert!(keep_alive.record(Ok(3)));
+    assert_eq!(keep_alive.status(), HealthStatus::Healthy);
+    assert_eq!(keep_alive.last_error(), None);
+}
+
+#[test]
+fn test_query_queue() {
+    let mut queue = QueryQueue::new(2);
+    assert!(queue.is_idle());
+
+    // 未达上限时立即执行
+    assert_eq!(queue.submit("pg", "t1", 1), Some(1));
+    assert_eq!(queue.submit("pg", "t2", 2), Some(2));
+    // 其他连接不受影响
+    assert_eq!(queue.submit("mysql", "t3", 3), Some(3));
+    // 达到上限后排队
+    assert_eq!(queue.submit("pg", "t4", 4), None);
+    assert_eq!(queue.submit("pg", "t5", 5), None);
+    assert_eq!(queue.queued("pg"), 2);
+    assert_eq!(queue.queued("mysql"), 0);
+    assert_eq!(queue.running("pg"), 2);
+    assert_eq!(queue.position("t5"), Some(2));
+    assert_eq!(queue.position("t1"), None);
+    assert_eq!(queue.connection_of("t3"), Some("mysql"));
+
+    // 同一 Tab 多次提交时依次排队，不丢弃之前的查询
+    assert_eq!(queue.submit("pg", "t4", 40), None);
+    assert_eq!(queue.queued("pg"), 3);
+    assert_eq!(queue.position("t4"), Some(1));
+
+    // 完成后按提交顺序开始
+    assert_eq!(queue.finish("t1"), vec![("pg".to_string(), "t4".to_string(), 4)]);
+    assert!(queue.is_running("t4"));
+    assert!(queue.finish("t3").is_empty());
+
+    // 同一 Tab 的上一个查询完成后才开始下一个
+    assert_eq!(queue.finish("t4"), vec![("pg".to_string(), "t5".to_string(), 5)]);
+    assert_eq!(queue.submit("pg", "t2", 20), None);
+    assert_eq!(queue.finish("t5"), vec![("pg".to_string(), "t4".to_string(), 40)]);
+    assert_eq!(queue.finish("t2"), vec![("pg".to_string(), "t2".to_string(), 20)]);
+
+    // 取消排队
+    assert_eq!(queue.submit("pg", "t6", 6), None);
+    assert!(queue.cancel("t6"));
+    assert!(!queue.cancel("t6"));
+
+    // 关闭的 Tab 的排队查询被丢弃
+    assert_eq!(queue.submit("pg", "t7", 7), None);
+    queue.retain_tabs(|id| id != "t7");
+    assert_eq!(queue.queued("pg"), 0);
+    assert!(queue.finish("t4").is_empty());
+    assert!(queue.finish("t2").is_empty());
+    assert!(queue.is_idle());
+}
+
+#[test]
+fn test_query_queue_limit() {
+    let mut queue = QueryQueue::new(0);
+    // 上限至少为 1
+    assert_eq!(queue.submit("pg", "t1", 1), Some(1));
+    assert_eq!(queue.submit("pg", "t2", 2), None);
+    assert_eq!(queue.submit("pg", "t3", 3), None);
+
+    // 提高上限后立即开始排队中的查询
+    assert_eq!(queue.set_limit(2), vec![("pg".to_string(), "t2".to_string(), 2)]);
+    assert_eq!(queue.running("pg"), 2);
+    assert_eq!(queue.position("t3"), Some(1));
+
+    // 降低上限不影响执行中的查询
+    assert!(queue.set_limit(1).is_empty());
+    assert!(queue.finish("t1").is_empty());
+    assert_eq!(queue.finish("t2"), vec![("pg".to_string(), "t3".to_string(), 3)]);
+}
+
+#[test]
+fn test_write_preview() {
+    let preview = write_preview("UPDATE users SET name = 'a; b' WHERE id = 1;").unwrap();
+    assert_eq!(preview.kind, WriteKind::Update);
+    assert_eq!(preview.select_sql, "SELECT * FROM users WHERE id = 1");
+    assert!(preview.has_where);
+
+    // 子查询中的关键字不作为语句边界
+    let preview = write_preview("delete from orders where user_id in (select id from users where age > 3) limit 10").unwrap();
+    assert_eq!(preview.kind, WriteKind::Delete);
+    assert_eq!(
+        preview.select_sql,
+        "SELECT * FROM orders where user_id in (select id from users where age > 3) limit 10"
+    );
+
+    // 没有 WHERE 条件时影响整张表
+    let preview = write_preview("-- 清空\nDELETE FROM logs").unwrap();
+    assert_eq!(preview.select_sql, "SELECT * FROM logs");
+    assert!(!preview.has_where);
+
+    // PostgreSQL: UPDATE ... FROM、DELETE ... USING 和 RETURNING
+    let preview = write_preview("UPDATE users u SET total = o.sum FROM totals o WHERE o.id = u.id RETURNING u.id").unwrap();
+    assert_eq!(preview.select_sql, "SELECT u.* FROM users u, totals o WHERE o.id = u.id");
+    let preview = write_preview("DELETE FROM users AS u USING banned b WHERE b.id = u.id").unwrap();
+    assert_eq!(preview.select_sql, "SELECT u.* FROM users AS u, banned b WHERE b.id = u.id");
+
+    // 赋值和条件中的 FROM 不是 UPDATE ... FROM 子句
+    let preview = write_preview("UPDATE t SET changed = a IS DISTINCT FROM b WHERE id = 1").unwrap();
+    assert_eq!(preview.select_sql, "SELECT * FROM t WHERE id = 1");
+    let preview = write_preview("UPDATE t SET y = EXTRACT(YEAR FROM d) WHERE id = 1").unwrap();
+    assert_eq!(preview.select_sql, "SELECT * FROM t WHERE id = 1");
+    let preview = write_preview("UPDATE t SET a = 1 WHERE b IS NOT DISTINCT FROM c").unwrap();
+    assert_eq!(preview.select_sql, "SELECT * FROM t WHERE b IS NOT DISTINCT FROM c");
+
+    // MySQL: 修饰词、多表 UPDATE 和多表 DELETE
+    let preview = write_preview("UPDATE LOW_PRIORITY items i JOIN stock s ON s.id = i.id SET i.qty = s.qty").unwrap();
+    assert_eq!(preview.select_sql, "SELECT * FROM items i JOIN stock s ON s.id = i.id");
+    let preview = write_preview("DELETE QUICK t1 FROM t1 JOIN t2 ON t1.id = t2.id WHERE t2.x = 1").unwrap();
+    assert_eq!(preview.select_sql, "SELECT t1.* FROM t1 JOIN t2 ON t1.id = t2.id WHERE t2.x = 1");
+
+    // 非写语句和多条语句不改写
+    assert!(write_preview("SELECT * FROM users").is_none());
+    assert!(write_preview("INSERT INTO users VALUES (1)").is_none());
+    assert!(write_preview("DELETE FROM a;DELETE FROM b").is_none());
+    assert!(write_preview("UPDATE users").is_none());
+}
+
+#[test]
+fn test_upsert() {
+    let columns = vec!["\"id\"".to_string(), "\"name\"".to_string()];
+    let upsert = |db_type, keys: &[&str]| Upsert {
+        syntax: UpsertSyntax::for_db(db_type).unwrap(),
+        key_columns: keys.iter().map(|k| k.to_string()).collect(),
+    };
+
+    // 普通 INSERT
+    assert_eq!(
+        insert_row_sql("\"users\"", &columns, "1, 'a'", None),
+        "INSERT INTO \"users\" (\"id\", \"name\") VALUES (1, 'a');"
+    );
+
+    // PostgreSQL：冲突时更新非键列，没有可更新的列时忽略
+    let pg = upsert(DatabaseType::PostgreSQL, &["\"id\""]);
+    assert!(pg.syntax.needs_key());
+    assert_eq!(
+        pg.statement("\"users\"", &columns, "1, 'a'"),
+        "INSERT INTO \"users\" (\"id\", \"name\") VALUES (1, 'a') ON CONFLICT (\"id\") DO UPDATE SET \"name\" = EXCLUDED.\"name\";"
+    );
+    assert!(pg.statement("\"users\"", &columns[..1], "1").ends_with("ON CONFLICT DO NOTHING;"));
+
+    // MySQL
+    let columns_mysql = vec!["`id`".to_string(), "`name`".to_string()];
+    let mysql = upsert(DatabaseType::MySQL, &["`id`"]);
+    assert!(!mysql.syntax.needs_key());
+    assert_eq!(
+        mysql.statement("`users`", &columns_mysql, "1, 'a'"),
+        "INSERT INTO `users` (`id`, `name`) VALUES (1, 'a') ON DUPLICATE KEY UPDATE `name` = VALUES(`name`);"
+    );
+    assert!(mysql.statement("`users`", &columns_mysql[..1], "1").starts_with("INSERT IGNORE INTO"));
+
+    // SQLite
+    let sqlite = upsert(DatabaseType::SQLite, &[]);
+    assert!(sqlite.statement("\"users\"", &columns, "1, 'a'").starts_with("INSERT OR REPLACE INTO \"users\""));
+
+    // 不支持的数据库
+    assert!(UpsertSyntax::for_db(DatabaseType::Redis).is_none());
+
+    // CSV 导入生成 upsert（冲突键列按导入语句的引号方式引用）
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("users.csv");
+    std::fs::write(&path, "id,name\n1,alice\n2,bob\n").unwrap();
+    let config = CsvImportConfig {
+        table_name: "users".to_string(),
+        upsert: Some(Upsert { syntax: UpsertSyntax::OnConflict, key_columns: vec!["id".to_string()] }),
+        ..Default::default()
+    };
+    let result = import_csv_to_sql(&path, &config, false).unwrap();
+    assert_eq!(result.sql_statements.len(), 2);
+    assert!(result.sql_statements[0].ends_with("ON CONFLICT (\"id\") DO UPDATE SET \"name\" = EXCLUDED.\"name\";"));
+    let plain = import_csv_to_sql(&path, &CsvImportConfig { upsert: None, ..config }, false).unwrap();
+    assert!(plain.sql_statements[1].starts_with("INSERT INTO \"users\""));
+    assert!(!plain.sql_statements[1].contains("ON CONFLICT"));
+}
+
+#[test]
+fn test_print_report() {
+    let columns = vec!["id".to_string(), "name".to_string()];
+    let rows: Vec<Vec<String>> = (1..=5)
+        .map(|i| vec![i.to_string(), if i == 3 { "NULL".to_string() } else { format!("<user {}>", i) }])
+        .collect();
+    let row_refs: Vec<&[String]> = rows.iter().map(Vec::as_slice).collect();
+
+    let mut report = PrintReport::new("users & roles", "2024-01-02 03:04:05");
+    report.sql = Some("SELECT * FROM users WHERE name <> ''".to_string());
+    report.filters = vec!["name 包含 a".to_string()];
+    report.rows_per_page = 2;
+    assert_eq!(report.page_count(5), 3);
+    assert_eq!(report.page_count(0), 1);
+
+    let html = report.to_html(&columns, &row_refs);
+    assert!(html.contains("<title>users &amp; roles</title>"));
+    assert!(html.contains("2024-01-02 03:04:05 · 5 行"));
+    assert!(html.contains("筛选条件：name 包含 a"));
+    assert!(html.contains("WHERE name &lt;&gt; ''"));
+    // 单元格转义，NULL 单独标记
+    assert!(html.contains("<td>&lt;user 1&gt;</td>"));
+    assert!(html.contains("<td class=\"null\">NULL</td>"));
+    // 每页重复列头，行号连续
+    assert_eq!(html.matches("<section class=\"page\">").count(), 3);
+    assert_eq!(html.matches("<thead>").count(), 3);
+    assert!(html.contains("<td class=\"row-number\">5</td>"));
+    assert!(html.contains("第 3 / 3 页"));
+
+    report.rows_per_page = 0;
+    assert_eq!(report.page_count(5), 1);
+    assert_eq!(report.to_html(&columns, &row_refs).matches("<thead>").count(), 1);
+}
+
+#[test]
+fn test_key_macro() {
+    let key = |key: egui::Key, pressed: bool| egui::Event::Key {
+        key,
+        physical_key: None,
+        pressed,
+        repeat: false,
+        modifiers: egui::Modifiers::NONE,
+    };
+    let mut key_macro = KeyMacro::new();
+    assert!(!key_macro.replay(1));
+
+    // 未录制时忽略事件
+    key_macro.record_frame(&[key(egui::Key::J, true)]);
+    assert!(key_macro.start());
+    assert!(key_macro.is_recording());
+    key_macro.record_frame(&[key(egui::Key::J, true), key(egui::Key::J, false)]);
+    key_macro.record_frame(&[egui::Event::PointerGone]);
+    key_macro.record_frame(&[key(egui::Key::I, true), egui::Event::Text("x".to_string())]);
+    // 最后一帧是停止录制的按键，不计入宏
+    key_macro.record_frame(&[key(egui::Key::Q, true)]);
+    assert_eq!(key_macro.stop(), 2);
+    assert!(!key_macro.is_recording());
+
+    // 回放两次，每帧注入一帧录制的事件
+    assert!(key_macro.replay(2));
+    assert!(key_macro.is_replaying());
+    assert!(!key_macro.start());
+    assert!(!key_macro.replay(1));
+    let frames: Vec<Vec<egui::Event>> = std::iter::from_fn(|| key_macro.next_frame()).collect();
+    assert_eq!(frames.len(), 4);
+    assert_eq!(frames[1].len(), 2);
+    assert!(matches!(&frames[3][1], egui::Event::Text(t) if t == "x"));
+    assert!(!key_macro.is_replaying());
+
+    // 没有录制到按键时保留之前的宏
+    assert!(key_macro.start());
+    key_macro.record_frame(&[key(egui::Key::Q, true)]);
+    assert_eq!(key_macro.stop(), 0);
+    assert!(key_macro.replay(1));
+}
+
+#[test]
+fn test_tour() {
+    // 每个区域都有对应的步骤，首尾两步显示在窗口中央
+    for target in [TourTarget::Sidebar, TourTarget::Toolbar, TourTarget::Editor, TourTarget::Grid] {
+        assert!(TOUR_STEPS.iter().any(|s| s.target == Some(target)));
+    }
+    assert_eq!(TOUR_STEPS[0].target, None);
+    assert_eq!(TOUR_STEPS[TOUR_STEPS.len() - 1].target, None);
+
+    let mut tour = Tour::new();
+    assert!(tour.current().is_none());
+    assert!(!tour.advance());
+    tour.start();
+    assert_eq!(tour.step_index(), 0);
+    tour.prev();
+    assert_eq!(tour.step_index(), 0);
+    assert!(!tour.advance());
+    assert_eq!(tour.current().unwrap().target, Some(TourTarget::Sidebar));
+    while !tour.is_last() {
+        assert!(!tour.advance());
+    }
+    // 最后一步的"下一步"结束引导
+    assert!(tour.advance());
+    assert!(!tour.is_active());
+
+    // 重新打开时从第一步开始
+    tour.start();
+    assert_eq!(tour.step_index(), 0);
+    tour.finish();
+    assert!(tour.current().is_none());
+
+    // 旧配置没有该字段时视为未完成
+    let parsed: AppConfig = toml::from_str("connections = []\n").unwrap();
+    assert!(!parsed.tour_completed);
+}
+
+#[test]
+fn test_sql_error_analysis() {
+    // PostgreSQL：驱动附加的字符位置（从 1 开始）
+    let sql = "SELECT id,\n  emial FROM users";
+    let info = analyze_sql_error("查询错误: db error: ERROR: column \"emial\" does not exist (位置: 14)", sql);
+    assert_eq!(info.span, Some(13..18));
+    assert_eq!(info.line, Some(2));
+    assert_eq!(info.unknown, Some(UnknownObject::Column("emial".to_string())));
+
+    // MySQL：near '...' at line N
+    let sql = "SELECT *\nFORM users";
+    let info = analyze_sql_error(
+        "You have an error in your SQL syntax; check the manual near 'FORM users' at line 2",
+        sql,
+    );
+    assert_eq!(info.span, Some(9..13));
+    assert_eq!(info.line, Some(2));
+
+    // MySQL：语句在末尾不完整
+    let info = analyze_sql_error("syntax error near '' at line 1", "SELECT * FROM ");
+    assert_eq!(info.span, Some(13..13));
+
+    // SQLite：没有位置时按名称查找，带表名前缀的列名只取列名
+    let info = analyze_sql_error("no such column: u.nmae", "SELECT u.nmae FROM users u");
+    assert_eq!(info.unknown, Some(UnknownObject::Column("nmae".to_string())));
+    assert_eq!(info.span, Some(9..13));
+    let info = analyze_sql_error("Table 'shop.ordrs' doesn't exist", "select * from ordrs");
+    assert_eq!(info.unknown, Some(UnknownObject::Table("ordrs".to_string())));
+    assert_eq!(info.span, Some(14..19));
+
+    // 无法识别的错误
+    assert_eq!(analyze_sql_error("permission denied", "SELECT 1"), SqlErrorInfo::default());
+
+    // 名称建议：忽略大小写，差异过大时不建议
+    assert_eq!(suggest_name("emial", ["id", "email", "name"]), Some("email"));
+    assert_eq!(suggest_name("NAME", ["name"]), Some("name"));
+    assert_eq!(suggest_name("xyz", ["email", "name"]), None);
+
+    // 列名优先在语句引用的表中查找
+    let mut autocomplete = AutoComplete::new();
+    autocomplete.set_tables(vec!["users".to_string(), "orders".to_string()]);
+    autocomplete.set_columns("users".to_string(), vec!["id".to_string(), "email".to_string()]);
+    autocomplete.set_columns("orders".to_string(), vec!["id".to_string(), "total".to_string(), "emails".to_string()]);
+    let column = UnknownObject::Column("emial".to_string());
+    assert_eq!(suggest_for_unknown(&column, "SELECT emial FROM users", &autocomplete).as_deref(), Some("email"));
+    assert_eq!(suggest_for_unknown(&column, "SELECT emial FROM orders", &autocomplete).as_deref(), None);
+    let table = UnknownObject::Table("user".to_string());
+    assert_eq!(suggest_for_unknown(&table, "SELECT * FROM user", &autocomplete).as_deref(), Some("users"));
+}
+
+// ============================================================================
+// 回收站测试
+// ============================================================================
+
+#[test]
+fn test_trash() {
+    use chrono::{Duration, Local};
+    use gridix::database::ConnectionConfig;
+
+    let now = Local::now();
+    let mut trash = Trash::default();
+    assert!(trash.is_empty());
+    assert!(trash.pop_tab().is_none());
+
+    let config = ConnectionConfig { name: "prod".into(), ..Default::default() };
+    trash.push_connection(config, vec!["SELECT 1".into()], now - Duration::days(40));
+    trash.push_tab(TabState::new("查询 1", "SELECT 1"), now - Duration::days(2));
+    trash.push_tab(TabState::new("查询 2", "SELECT 2"), now);
+    assert_eq!(trash.entries().len(), 3);
+    assert_eq!(trash.entries()[0].item.label(), "prod");
+
+    // 最近关闭的 Tab 先取出，连接不受影响
+    assert_eq!(trash.pop_tab().unwrap().title, "查询 2");
+    assert_eq!(trash.entries().len(), 2);
+
+    // 超过保留天数的记录被清除
+    let expired = trash.purge(now, 30);
+    assert_eq!(expired.len(), 1);
+    assert!(matches!(&expired[0].item, TrashItem::Connection { history, .. } if history == &["SELECT 1"]));
+    assert_eq!(trash.entries().len(), 1);
+
+    let entry = trash.take(0).unwrap();
+    assert!(entry.item.is_tab());
+    assert!(trash.take(0).is_none());
+
+    // 关闭的 Tab 只保留最近的若干个
+    for i in 0..25 {
+        trash.push_tab(TabState::new(format!("查询 {}", i), "SELECT 1"), now);
+    }
+    assert_eq!(trash.entries().len(), 20);
+    assert_eq!(trash.entries()[0].item.label(), "查询 5");
+    assert_eq!(trash.clear().len(), 20);
+    assert!(trash.is_empty());
+
+    // 回收站随配置保存
+    let mut config = AppConfig::default();
+    config.trash.push_tab(TabState::with_table("users", "SELECT * FROM users", "users"), now);
+    config
+        .trash
+        .push_connection(ConnectionConfig { name: "dev".into(), ..Default::default() }, Vec::new(), now);
+    let parsed: AppConfig = toml::from_str(&toml::to_string(&config).unwrap()).unwrap();
+    let labels: Vec<&str> = parsed.trash.entries().iter().map(|e| e.item.label()).collect();
+    assert_eq!(labels, ["users", "dev"]);
+}
+
+#[test]
+fn test_new_action_default_does_not_steal_configured_key() {
+    // 旧版本配置中主题选择器使用 Ctrl+Shift+T，新增的"重新打开 Tab"不再绑定该快捷键
+    let parsed: AppConfig =
+        toml::from_str("connections = []\n[keybindings]\nshow_theme_selector = \"Ctrl+Shift+T\"\n").unwrap();
+    assert_eq!(parsed.keybindings.display(Action::ShowThemeSelector), "Ctrl+Shift+T");
+    assert!(parsed.keybindings.get(Action::ReopenTab).is_none());
+    assert!(parsed.keybindings.find_conflicts().is_empty());
+
+    let defaults = KeyBindings::default();
+    assert_eq!(defaults.display(Action::ReopenTab), "Ctrl+Shift+T");
+}
+
+#[test]
+fn test_split_config_args() {
+    let split = |args: &[&str]| split_config_args(args.iter().map(|s| s.to_string()));
+
+    let (location, rest) = split(&["--profile", "work", "-c", "prod", "-q", "SELECT 1"]).unwrap();
+    assert_eq!(location, ConfigLocation::Profile("work".into()));
+    assert_eq!(rest, ["-c", "prod", "-q", "SELECT 1"]);
+    assert!(location.path().unwrap().ends_with("profiles/work.toml"));
+
+    let (location, rest) = split(&["--config=/tmp/gridix.toml"]).unwrap();
+    assert_eq!(location, ConfigLocation::File("/tmp/gridix.toml".into()));
+    assert!(rest.is_empty());
+    assert_eq!(split(&[]).unwrap().0, ConfigLocation::Default);
+
+    assert!(split(&["--profile"]).is_err());
+    assert!(split(&["--profile", "../etc"]).is_err());
+    assert!(split(&["--profile", "a", "--config", "b.toml"]).is_err());
+}
+
+#[test]
+fn test_config_reload() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("config.toml");
+    let mut config = AppConfig::default();
+    std::fs::write(&path, toml::to_string(&config).unwrap()).unwrap();
+
+    // 内容未变化时没有需要重新加载的设置
+    let on_disk = AppConfig::read(&path).unwrap();
+    assert!(config.apply_reloaded(&on_disk).is_empty());
+
+    // 外部修改了夜间主题和快捷键
+    let mut edited = AppConfig::read(&path).unwrap();
+    edited.dark_theme = ThemePreset::Dracula;
+    edited.keybindings.set(Action::Save, KeyBinding::ctrl_shift(KeyCode::S));
+    edited.default_query_limit = 5000;
+    std::fs::write(&path, toml::to_string(&edited).unwrap()).unwrap();
+    let reload = config.apply_reloaded(&AppConfig::read(&path).unwrap());
+    assert_eq!(reload, ConfigReload { theme: true, keybindings: true });
+    assert_eq!(config.theme_preset, ThemePreset::Dracula);
+    assert_eq!(config.keybindings.display(Action::Save), "Ctrl+Shift+S");
+    // 其他设置需要重启或在首选项中修改
+    assert_ne!(config.default_query_limit, 5000);
+
+    std::fs::write(&path, "connections = [").unwrap();
+    assert!(AppConfig::read(&path).is_err());
+}
+
+#[test]
+fn test_master_password_vault() {
+    use gridix::database::ConnectionConfig;
+
+    let mut config = AppConfig::default();
+    config.connections.push(ConnectionConfig { name: "prod".into(), host: "db.internal".into(), ..Default::default() });
+    assert!(!config.has_master_password());
+    config.set_master_password("correct horse").unwrap();
+
+    // 连接列表加密保存，配置文件中看不到连接信息
+    let saved = config.to_toml().unwrap();
+    assert!(!saved.contains("db.internal"));
+    let mut loaded: AppConfig = toml::from_str(&saved).unwrap();
+    assert!(loaded.is_locked());
+    assert!(loaded.connections.is_empty());
+
+    // 未解锁时保存不会丢失加密的连接
+    let mut relocked: AppConfig = toml::from_str(&loaded.to_toml().unwrap()).unwrap();
+    assert!(relocked.unlock("wrong").is_err());
+    assert!(relocked.is_locked());
+    assert!(relocked.set_master_password("other").is_err());
+
+    let unlocked = loaded.unlock("correct horse").unwrap();
+    assert_eq!(unlocked.len(), 1);
+    assert_eq!(loaded.connections[0].host, "db.internal");
+    assert!(!loaded.is_locked());
+    assert_eq!(relocked.unlock("correct horse").unwrap().len(), 1);
+
+    // 取消主密码后恢复明文保存
+    loaded.remove_master_password().unwrap();
+    let saved = loaded.to_toml().unwrap();
+    assert!(saved.contains("db.internal"));
+    assert!(!toml::from_str::<AppConfig>(&saved).unwrap().has_master_password());
+}
+
+#[test]
+fn test_master_password_seals_trash() {
+    use gridix::database::ConnectionConfig;
+
+    let mut config = AppConfig::default();
+    let deleted = ConnectionConfig {
+        name: "old".into(),
+        host: "legacy.internal".into(),
+        username: "admin".into(),
+        password: "s3cret-pass".into(),
+        ..Default::default()
+    };
+    let now = chrono::Local::now();
+    config.trash.push_connection(deleted, vec!["SELECT 1".into()], now - chrono::Duration::minutes(1));
+    config.trash.push_tab(TabState::new("草稿", "SELECT 2"), now);
+    config.set_master_password("correct horse").unwrap();
+
+    // 回收站中删除的连接和连接列表一起加密，关闭的 Tab 仍明文保存
+    let saved = config.to_toml().unwrap();
+    assert!(!saved.contains("legacy.internal"));
+    assert!(!saved.contains("s3cret-pass"));
+    assert!(saved.contains("草稿"));
+    let mut loaded: AppConfig = toml::from_str(&saved).unwrap();
+    assert_eq!(loaded.trash.entries().len(), 1);
+
+    loaded.unlock("correct horse").unwrap();
+    assert_eq!(loaded.trash.entries().len(), 2);
+    let TrashItem::Connection { config: restored, history } = &loaded.trash.entries()[0].item else {
+        panic!("删除的连接应排在关闭的 Tab 之前");
+    };
+    assert_eq!(restored.password, "s3cret-pass");
+    assert_eq!(history, &vec!["SELECT 1".to_string()]);
+}
+
+#[test]
+fn test_recovery_state() {
+    use gridix::core::{RecoveredEdits, RecoveryState};
+
+    let dir = std::env::temp_dir().join(format!("gridix_recovery_{}", std::process::id()));
+    let path = dir.join("config.recovery.toml");
+    let mut state = RecoveryState {
+        saved_at: chrono::Local::now(),
+        tabs: vec![TabState::new("查询 1", "  ")],
+        grid_edits: None,
+    };
+    assert!(state.is_empty());
+
+    state.tabs.push(TabState::new("查询 2", "SELECT 1"));
+    state.grid_edits = Some(RecoveredEdits {
+        connection: "local".into(),
+        table: "users".into(),
+        statements: vec!["UPDATE users SET name = 'a' WHERE id = 1;".into()],
+    });
+    assert!(!state.is_empty());
+    let script = state.grid_edits.as_ref().unwrap().script();
+    assert!(script.contains("users") && script.contains("UPDATE users SET name = 'a' WHERE id = 1;"));
+
+    state.save(&path, None).unwrap();
+    assert!(!RecoveryState::is_encrypted(&path));
+    let loaded = RecoveryState::load(&path, None).unwrap();
+    assert!(loaded.same_content(&state));
+    assert_eq!(loaded.tabs[1].sql, "SELECT 1");
+
+    let mut changed = loaded.clone();
+    changed.tabs[1].sql.push_str(" + 1");
+    assert!(!changed.same_content(&state));
+
+    RecoveryState::remove(&path);
+    assert!(RecoveryState::load(&path, None).is_none());
+    // 文件不存在时删除不报错
+    RecoveryState::remove(&path);
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_recovery_state_encrypted() {
+    use gridix::core::{MasterKey, RecoveredEdits, RecoveryState};
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("config.recovery.toml");
+    let state = RecoveryState {
+        saved_at: chrono::Local::now(),
+        tabs: vec![TabState::new("查询 1", "SELECT * FROM salaries")],
+        grid_edits: Some(RecoveredEdits {
+            connection: "hr".into(),
+            table: "salaries".into(),
+            statements: vec!["UPDATE salaries SET amount = 9000 WHERE id = 1;".into()],
+        }),
+    };
+
+    // 设置了主密码时加密保存，文件中没有明文
+    let key = MasterKey::create("correct horse").unwrap();
+    state.save(&path, Some(&key)).unwrap();
+    assert!(RecoveryState::is_encrypted(&path));
+    let content = std::fs::read_to_string(&path).unwrap();
+    assert!(!content.contains("salaries"));
+
+    // 未解锁或使用其他密钥时无法读取
+    assert!(RecoveryState::load(&path, None).is_none());
+    let other = MasterKey::create("correct horse").unwrap();
+    assert!(RecoveryState::load(&path, Some(&other)).is_none());
+    let loaded = RecoveryState::load(&path, Some(&key)).unwrap();
+    assert!(loaded.same_content(&state));
+}
+
+#[test]
+fn test_result_spill_store() {
+    use gridix::core::SpillStore;
+    use gridix::database::QueryResult;
+
+    let result = QueryResult {
+        columns: vec!["id".into(), "name".into()],
+        rows: vec![vec!["1".into(), "张三".into()], vec!["2".into(), String::new()]],
+        affected_rows: 0,
+        truncated: true,
+        original_row_count: Some(10),
+    };
+    let empty = QueryResult::default();
+    assert!(result.estimated_size() > empty.estimated_size());
+
+    let parent = tempfile::tempdir().unwrap();
+    let mut store = SpillStore::new(parent.path().to_path_buf());
+    assert!(store.dir().is_none());
+    let spilled = store.spill("tab/1", &result).unwrap();
+    assert_eq!(spilled.rows, 2);
+    let path = spilled.path.clone();
+    // 缓存文件在随机命名的私有目录中
+    let dir = store.dir().unwrap().to_path_buf();
+    assert!(path.starts_with(&dir) && dir.starts_with(parent.path()) && dir != parent.path());
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        assert_eq!(std::fs::metadata(&dir).unwrap().permissions().mode() & 0o777, 0o700);
+        assert_eq!(std::fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+    }
+    assert!(store.contains("tab/1"));
+
+    let restored = store.restore("tab/1").unwrap().unwrap();
+    assert_eq!(restored.columns, result.columns);
+    assert_eq!(restored.rows, result.rows);
+    assert!(restored.truncated);
+    assert_eq!(restored.original_row_count, Some(10));
+    assert!(!store.contains("tab/1"));
+    assert!(store.restore("tab/1").is_none());
+
+    store.spill("a", &empty).unwrap();
+    store.spill("b", &empty).unwrap();
+    store.retain(|key| key == "b");
+    assert!(!store.contains("a") && store.contains("b"));
+    assert_eq!(store.restore("b").unwrap().unwrap().original_row_count, None);
+
+    store.clear();
+    assert!(!dir.exists());
+}
+
+#[test]
+fn test_result_spill_pages() {
+    use gridix::core::{SpillStore, SPILL_PAGE_ROWS};
+    use gridix::database::QueryResult;
+    use std::sync::Arc;
+
+    let rows: Vec<Vec<String>> = (0..SPILL_PAGE_ROWS * 3 + 10).map(|i| vec![i.to_string(), format!("row {}", i)]).collect();
+    let mut result = Arc::new(QueryResult {
+        columns: vec!["id".into(), "name".into()],
+        rows: rows.clone(),
+        ..Default::default()
+    });
+    let parent = tempfile::tempdir().unwrap();
+    let mut store = SpillStore::new(parent.path().to_path_buf());
+
+    // 保留第二页附近，先写入最远的页，释放足够的内存后停止
+    let keep = SPILL_PAGE_ROWS..SPILL_PAGE_ROWS + 10;
+    let freed = store.spill_pages(&mut result, keep.clone(), 1).unwrap();
+    assert!(freed > 0);
+    assert_eq!(store.spilled_pages(&result), 1);
+    assert!(result.rows[SPILL_PAGE_ROWS * 3].is_empty());
+    assert_eq!(result.rows.len(), rows.len());
+    store.spill_pages(&mut result, keep, usize::MAX).unwrap();
+    assert_eq!(store.spilled_pages(&result), 3);
+    assert!(result.rows[0].is_empty());
+    assert_eq!(result.rows[SPILL_PAGE_ROWS], rows[SPILL_PAGE_ROWS]);
+
+    // 结果被其他地方引用时不修改
+    let shared = Arc::clone(&result);
+    assert_eq!(store.load_pages(&mut result, 0..usize::MAX).unwrap(), 0);
+    drop(shared);
+
+    // 滚动到附近时只读回重叠的页
+    assert_eq!(store.load_pages(&mut result, 5..6).unwrap(), 1);
+    assert_eq!(result.rows[..SPILL_PAGE_ROWS], rows[..SPILL_PAGE_ROWS]);
+    assert_eq!(store.load_pages(&mut result, 0..usize::MAX).unwrap(), 2);
+    assert_eq!(result.rows, rows);
+    assert_eq!(store.spilled_pages(&result), 0);
+
+    // 结果释放后删除其缓存文件
+    store.spill_pages(&mut result, 0..0, usize::MAX).unwrap();
+    assert_eq!(store.spilled_pages(&result), 4);
+    let dir = store.dir().unwrap().to_path_buf();
+    drop(result);
+    store.prune_pages();
+    assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 0);
+}
+
+#[test]
+fn test_benchmark_stats() {
+    use gridix::core::{BenchmarkSample, BenchmarkStats};
+
+    assert!(BenchmarkStats::from_samples(&[]).is_none());
+
+    // 1..=20 ms，每次 10 行
+    let samples: Vec<BenchmarkSample> = (1..=20)
+        .rev()
+        .map(|ms| BenchmarkSample { elapsed_ms: ms as f64, rows: 10 })
+        .collect();
+    let stats = BenchmarkStats::from_samples(&samples).unwrap();
+    assert_eq!(stats.runs, 20);
+    assert_eq!(stats.min_ms, 1.0);
+    assert_eq!(stats.max_ms, 20.0);
+    assert_eq!(stats.avg_ms, 10.5);
+    assert_eq!(stats.p95_ms, 19.0);
+    // 200 行 / 0.21 秒
+    assert!((stats.rows_per_sec.unwrap() - 200.0 / 0.21).abs() < 1e-6);
+
+    let single = BenchmarkStats::from_samples(&[BenchmarkSample { elapsed_ms: 0.0, rows: 5 }]).unwrap();
+    assert_eq!(single.p95_ms, 0.0);
+    assert_eq!(single.rows_per_sec, None);
+}
+
+// ============================================================================
+// 桌面通知测试
+// ============================================================================
+
+#[test]
+fn test_query_notification_text() {
+    use gridix::core::query_notification_text;
+
+    let (title, body) = query_notification_text(Some("报表"), "SELECT *\n  FROM orders", Ok(42), 12_500);
+    assert_eq!(title, "Gridix: 查询完成（报表）");
+    assert_eq!(body, "42 行，用时 12.5 s\nSELECT * FROM orders");
+
+    let (title, body) = query_notification_text(None, "SELECT 1", Err("timeout\ndetails"), 3000);
+    assert_eq!(title, "Gridix: 查询失败");
+    assert_eq!(body, "用时 3.0 s：timeout\nSELECT 1");
+
+    let long_sql = format!("SELECT {}", "x".repeat(200));
+    let (_, body) = query_notification_text(None, &long_sql, Ok(0), 0);
+    let preview = body.lines().nth(1).unwrap();
+    assert!(preview.ends_with('…'));
+    assert_eq!(preview.chars().count(), 81);
+
+    let prefs = gridix::core::AppConfig::default().preferences();
+    assert!(prefs.validate().is_ok());
+}
+
+// ============================================================================
+// 依赖关系测试
+// ============================================================================
+
+#[test]
+fn test_dependency_graph() {
+    use gridix::core::{sql_identifiers, DependencyGraph, DependencyObjectKind};
+    use gridix::database::{ForeignKeyInfo, TriggerInfo, ViewInfo};
+
+    let ids = sql_identifiers("SELECT \"Orders\".id, 'users' FROM shop.`Orders` -- items\n/* users */ JOIN [line_items] l");
+    assert!(ids.contains("orders") && ids.contains("shop") && ids.contains("line_items"));
+    assert!(!ids.contains("users") && !ids.contains("items"));
+
+    let fk = |from: &str, from_column: &str, to: &str| ForeignKeyInfo {
+        from_table: from.to_string(),
+        from_column: from_column.to_string(),
+        to_table: to.to_string(),
+        to_column: "id".to_string(),
+    };
+    let tables: Vec<String> = ["customers", "orders", "order_items"].iter().map(|t| t.to_string()).collect();
+    let foreign_keys = vec![
+        fk("orders", "customer_id", "customers"),
+        fk("order_items", "order_id", "orders"),
+        fk("order_items", "parent_order_id", "orders"),
+    ];
+    let views = vec![
+        ViewInfo { name: "order_totals".to_string(), definition: "SELECT o.id, SUM(i.qty) FROM Orders o JOIN order_items i".to_string() },
+        ViewInfo { name: "big_orders".to_string(), definition: "SELECT * FROM order_totals WHERE 'orders' <> ''".to_string() },
+    ];
+    let triggers = vec![TriggerInfo {
+        name: "orders_audit".to_string(),
+        table_name: "orders".to_string(),
+        event: "UPDATE".to_string(),
+        timing: "AFTER".to_string(),
+        definition: "CREATE TRIGGER orders_audit AFTER UPDATE ON orders BEGIN SELECT 1; END".to_string(),
+    }];
+
+    let graph = DependencyGraph::build("orders", &tables, &foreign_keys, &views, &triggers);
+    assert_eq!(graph.kind, DependencyObjectKind::Table);
+    assert_eq!(graph.depends_on.len(), 1);
+    assert_eq!(graph.depends_on[0].name, "customers");
+    let dependents: Vec<(&str, DependencyObjectKind)> =
+        graph.dependents.iter().map(|l| (l.name.as_str(), l.kind)).collect();
+    assert_eq!(
+        dependents,
+        vec![
+            ("order_items", DependencyObjectKind::Table),
+            ("order_totals", DependencyObjectKind::View),
+            ("orders_audit", DependencyObjectKind::Trigger),
+        ]
+    );
+    // 多列外键合并为一条
+    assert_eq!(graph.dependents[0].detail, "外键 order_id → id, 外键 parent_order_id → id");
+    assert_eq!(graph.dependents[2].detail, "AFTER UPDATE");
+
+    // 视图依赖引用的表和视图
+    let graph = DependencyGraph::build("big_orders", &tables, &foreign_keys, &views, &triggers);
+    assert_eq!(graph.kind, DependencyObjectKind::View);
+    assert_eq!(graph.depends_on.len(), 1);
+    assert_eq!(graph.depends_on[0].name, "order_totals");
+    assert!(graph.dependents.is_empty());
+
+    assert!(DependencyGraph::build("customers_archive", &tables, &foreign_keys, &views, &triggers).is_empty());
+}
+
+// ============================================================================
+// 敏感列脱敏测试
+// ============================================================================
+
+#[test]
+fn test_mask_value_and_rules() {
+    use gridix::core::{mask_value, MaskingRules};
+
+    assert_eq!(mask_value("john.doe@example.com"), "jo***@***.com");
+    assert_eq!(mask_value("a@b.io"), "***@***.io");
+    assert_eq!(mask_value("123-45-6789"), "***-**-6789");
+    assert_eq!(mask_value("13812345678"), "*******5678");
+    assert_eq!(mask_value("Bob"), "***");
+    assert_eq!(mask_value("NULL"), "NULL");
+    assert_eq!(mask_value(""), "");
+
+    let mut rules = MaskingRules::default();
+    rules.set_masked("prod", "Email", true);
+    rules.set_masked("prod", "ssn", true);
+    assert!(rules.columns("prod").contains("email"));
+    assert!(rules.columns("dev").is_empty());
+    assert_eq!(rules.columns("prod").len(), 2);
+
+    let toml = toml::to_string(&rules).unwrap();
+    let restored: MaskingRules = toml::from_str(&toml).unwrap();
+    assert!(restored.columns("prod").contains("ssn"));
+
+    rules.set_masked("prod", "email", false);
+    rules.set_masked("prod", "ssn", false);
+    assert!(rules.columns("prod").is_empty());
+    rules.set_masked("dev", "phone", true);
+    rules.remove_connection("dev");
+    assert!(rules.columns("dev").is_empty());
+}
+
+#[test]
+fn test_row_bookmarks() {
+    use gridix::core::{RowBookmark, RowBookmarks};
+
+    let bookmark = |key: &str| RowBookmark {
+        key_column: "id".to_string(),
+        key_value: key.to_string(),
+        label: format!("user {}", key),
+    };
+
+    let mut bookmarks = RowBookmarks::default();
+    assert!(bookmarks.toggle("prod", Some("shop"), "users", bookmark("1")));
+    assert!(bookmarks.toggle("prod", Some("shop"), "users", bookmark("7")));
+    assert!(bookmarks.toggle("prod", Some("shop"), "orders", bookmark("42")));
+    assert!(bookmarks.toggle("prod", None, "users", bookmark("1")));
+
+    let users = bookmarks.for_table("prod", Some("shop"), "users");
+    assert_eq!(users.len(), 2);
+    assert!(users[1].same_row("id", "7"));
+    assert!(bookmarks.for_table("prod", Some("other"), "users").is_empty());
+
+    let tables: Vec<&str> = bookmarks.for_database("prod", Some("shop")).iter().map(|(t, _)| *t).collect();
+    assert_eq!(tables, vec!["orders", "users"]);
+
+    let toml = toml::to_string(&bookmarks).unwrap();
+    let restored: RowBookmarks = toml::from_str(&toml).unwrap();
+    assert_eq!(restored.for_table("prod", Some("shop"), "orders")[0].label, "user 42");
+
+    // 再次收藏同一行时取消收藏
+    assert!(!bookmarks.toggle("prod", Some("shop"), "users", bookmark("1")));
+    assert_eq!(bookmarks.for_table("prod", Some("shop"), "users").len(), 1);
+
+    bookmarks.remove("prod", Some("shop"), "users", "id", "7");
+    assert!(bookmarks.for_table("prod", Some("shop"), "users").is_empty());
+    bookmarks.remove_table("prod", Some("shop"), "orders");
+    assert!(bookmarks.for_database("prod", Some("shop")).is_empty());
+    bookmarks.remove_connection("prod");
+    assert!(bookmarks.for_table("prod", None, "users").is_empty());
+}
+
+#[test]
+fn test_pivot_table() {
+    use gridix::core::{PivotAggregate, PivotConfig, PivotTable};
+    use gridix::database::QueryResult;
+
+    let row = |values: &[&str]| values.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+    let result = QueryResult {
+        columns: vec!["region".into(), "city".into(), "year".into(), "amount".into()],
+        rows: vec![
+            row(&["east", "a", "2023", "10"]),
+            row(&["east", "a", "2024", "20"]),
+            row(&["east", "b", "2024", "5"]),
+            row(&["west", "c", "2023", "NULL"]),
+            row(&["west", "c", "2023", "7.5"]),
+        ],
+        ..Default::default()
+    };
+
+    let mut config = PivotConfig { row_columns: vec![0], column_column: Some(2), ..Default::default() };
+    let table = PivotTable::compute(&result, &config).unwrap();
+    assert_eq!(table.column_keys, vec!["2023", "2024"]);
+    assert_eq!(table.groups.len(), 2);
+    assert_eq!(table.groups[0].key, "east");
+    assert_eq!(table.format(&table.groups[0].cells[1]), "2");
+    assert_eq!(table.format(&table.groups[1].total), "2");
+    assert_eq!(table.format(&table.grand_total), "5");
+
+    config.row_columns = vec![0, 1];
+    config.aggregate = PivotAggregate::Sum;
+    assert!(PivotTable::compute(&result, &config).is_err());
+    config.value_column = Some(3);
+    let table = PivotTable::compute(&result, &config).unwrap();
+    assert_eq!(table.groups[0].children.len(), 2);
+    assert_eq!(table.format(&table.groups[0].total), "35");
+    assert_eq!(table.format(&table.groups[1].cells[1]), "");
+    assert_eq!(table.format(&table.grand_total), "42.5");
+
+    config.aggregate = PivotAggregate::Avg;
+    config.column_column = None;
+    let table = PivotTable::compute(&result, &config).unwrap();
+    assert_eq!(table.format(&table.groups[1].total), "7.5");
+
+    // 导出：每个分组一行（上级分组为小计），最后一行为总计
+    let report = table.to_query_result();
+    assert_eq!(report.columns, vec!["region", "city", "合计"]);
+    assert_eq!(report.rows.len(), 6);
+    assert_eq!(report.rows[0], vec!["east", "", "11.6667"]);
+    assert_eq!(report.rows[1], vec!["east", "a", "15"]);
+    assert_eq!(report.rows[5], vec!["总计", "", "10.625"]);
+
+    config.row_columns.clear();
+    assert!(PivotTable::compute(&result, &config).is_err());
+}
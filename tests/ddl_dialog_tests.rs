@@ -27,6 +27,25 @@ fn test_column_definition() {
     assert!(sql.contains("AUTO_INCREMENT"));
 }
 
+#[test]
+fn test_oracle_column_definition() {
+    assert_eq!(ColumnType::Varchar(100).to_sql(&DatabaseType::Oracle), "VARCHAR2(100)");
+    assert_eq!(ColumnType::Boolean.to_sql(&DatabaseType::Oracle), "NUMBER(1)");
+
+    let col = ColumnDefinition {
+        name: "id".to_string(),
+        data_type: ColumnType::BigInt,
+        primary_key: true,
+        auto_increment: true,
+        nullable: false,
+        ..Default::default()
+    };
+    assert_eq!(
+        col.to_sql(&DatabaseType::Oracle),
+        "\"id\" NUMBER(19) GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY"
+    );
+}
+
 #[test]
 fn test_table_definition() {
     let mut table = TableDefinition::new(DatabaseType::MySQL);
@@ -3,7 +3,7 @@
 //! 测试表定义、列类型转换等
 
 use gridix::database::DatabaseType;
-use gridix::ui::{ColumnDefinition, ColumnType, TableDefinition};
+use gridix::ui::{ColumnDefinition, ColumnType, ERDiagramState, ERTable, ForeignKeyDefinition, TableDefinition};
 
 #[test]
 fn test_column_type_sql() {
@@ -55,6 +55,31 @@ fn test_table_definition() {
     assert!(sql.contains("`name`"));
 }
 
+#[test]
+fn test_clickhouse_table_definition() {
+    let mut table = TableDefinition::new(DatabaseType::ClickHouse);
+    table.name = "events".to_string();
+    table.columns.push(ColumnDefinition {
+        name: "id".to_string(),
+        data_type: ColumnType::BigInt,
+        primary_key: true,
+        nullable: false,
+        ..Default::default()
+    });
+    table.columns.push(ColumnDefinition {
+        name: "payload".to_string(),
+        data_type: ColumnType::Text,
+        ..Default::default()
+    });
+
+    // 可空列使用 Nullable 类型，主键作为 MergeTree 排序键
+    let sql = table.to_create_sql();
+    assert!(sql.contains("`id` Int64"));
+    assert!(sql.contains("`payload` Nullable(String)"));
+    assert!(!sql.contains("PRIMARY KEY"));
+    assert!(sql.contains("ENGINE = MergeTree\nORDER BY `id`"));
+}
+
 #[test]
 fn test_table_validation() {
     let table = TableDefinition::default();
@@ -68,3 +93,173 @@ fn test_table_validation() {
     });
     assert!(table.validate().is_ok());
 }
+
+#[test]
+fn test_object_drop_sql() {
+    use gridix::ui::{drop_object_sql, DbObjectKind};
+
+    assert_eq!(
+        drop_object_sql(DatabaseType::PostgreSQL, DbObjectKind::Trigger, "audit", "users", "").unwrap(),
+        "DROP TRIGGER \"audit\" ON \"users\";"
+    );
+    assert_eq!(
+        drop_object_sql(DatabaseType::MySQL, DbObjectKind::Procedure, "cleanup", "", "IN p_id INT").unwrap(),
+        "DROP PROCEDURE `cleanup`;"
+    );
+    // PostgreSQL 函数带签名以区分重载，含默认值时只按名称删除
+    assert_eq!(
+        drop_object_sql(DatabaseType::PostgreSQL, DbObjectKind::Function, "add", "", "a integer, b integer").unwrap(),
+        "DROP FUNCTION \"add\"(a integer, b integer);"
+    );
+    assert_eq!(
+        drop_object_sql(DatabaseType::PostgreSQL, DbObjectKind::Function, "add", "", "a integer DEFAULT 1").unwrap(),
+        "DROP FUNCTION \"add\";"
+    );
+    assert!(drop_object_sql(DatabaseType::SQLite, DbObjectKind::Trigger, "t; DROP TABLE x", "", "").is_err());
+    assert!(drop_object_sql(DatabaseType::ClickHouse, DbObjectKind::Function, "f", "", "").is_err());
+}
+
+#[test]
+fn test_object_ddl_statements() {
+    use gridix::database::{RoutineInfo, RoutineType, TriggerInfo};
+    use gridix::ui::{DbObjectKind, ObjectDdlDialogState};
+
+    let mut state = ObjectDdlDialogState::new();
+    state.open_create(DatabaseType::MySQL, DbObjectKind::Trigger).unwrap();
+    assert!(state.sql.starts_with("CREATE TRIGGER"));
+    assert_eq!(state.statements().unwrap(), vec![state.sql.trim().to_string()]);
+    assert!(state.open_create(DatabaseType::SQLite, DbObjectKind::Function).is_err());
+
+    // 编辑 SQLite 触发器：先删除再重建
+    let trigger = TriggerInfo {
+        name: "touch".to_string(),
+        table_name: "users".to_string(),
+        event: "UPDATE".to_string(),
+        timing: "AFTER".to_string(),
+        definition: "CREATE TRIGGER touch AFTER UPDATE ON users BEGIN SELECT 1; END".to_string(),
+    };
+    state.open_edit_trigger(DatabaseType::SQLite, &trigger).unwrap();
+    assert_eq!(
+        state.statements().unwrap(),
+        vec!["DROP TRIGGER \"touch\";".to_string(), trigger.definition.clone()]
+    );
+    // 重建失败时用载入的原定义恢复，而不是编辑后的语句
+    state.sql = "CREATE TRIGGER touch AFTER UPDATE ON users BEGIN SELEC 1; END".to_string();
+    assert_eq!(state.restore_sql(), Some(trigger.definition.clone()));
+
+    // PostgreSQL 函数定义自带 OR REPLACE，直接替换
+    let routine = RoutineInfo {
+        name: "add".to_string(),
+        routine_type: RoutineType::Function,
+        parameters: "a integer, b integer".to_string(),
+        return_type: Some("integer".to_string()),
+        definition: "CREATE OR REPLACE FUNCTION public.add(a integer, b integer)\n RETURNS integer\n LANGUAGE sql\nAS $function$ SELECT a + b $function$\n".to_string(),
+    };
+    state.open_edit_routine(DatabaseType::PostgreSQL, &routine).unwrap();
+    assert_eq!(state.statements().unwrap().len(), 1);
+    assert_eq!(state.restore_sql(), None);
+
+    // MySQL 目录只有过程体，按签名还原 CREATE 语句
+    let routine = RoutineInfo {
+        name: "add".to_string(),
+        routine_type: RoutineType::Function,
+        parameters: "a int, b int".to_string(),
+        return_type: Some("int".to_string()),
+        definition: "RETURN a + b".to_string(),
+    };
+    state.open_edit_routine(DatabaseType::MySQL, &routine).unwrap();
+    assert_eq!(
+        state.statements().unwrap(),
+        vec![
+            "DROP FUNCTION `add`;".to_string(),
+            "CREATE FUNCTION `add`(a int, b int)\nRETURNS int\nRETURN a + b".to_string(),
+        ]
+    );
+}
+
+fn design_table(db_type: DatabaseType, name: &str, columns: &[&str]) -> TableDefinition {
+    let mut table = TableDefinition::new(db_type);
+    table.name = name.to_string();
+    for (i, column) in columns.iter().enumerate() {
+        table.columns.push(ColumnDefinition {
+            name: column.to_string(),
+            data_type: ColumnType::Integer,
+            primary_key: i == 0,
+            nullable: i != 0,
+            ..Default::default()
+        });
+    }
+    table
+}
+
+#[test]
+fn test_foreign_key_sql() {
+    let mut table = design_table(DatabaseType::SQLite, "orders", &["id", "user_id"]);
+    table.foreign_keys.push(ForeignKeyDefinition {
+        column: "user_id".to_string(),
+        ref_table: "users".to_string(),
+        ref_column: "id".to_string(),
+    });
+    assert!(table.validate().is_ok());
+    assert!(table.to_create_sql().contains("    FOREIGN KEY (\"user_id\") REFERENCES \"users\" (\"id\")\n)"));
+    assert_eq!(table.foreign_keys[0].to_alter_sql("orders", &DatabaseType::SQLite), None);
+    assert_eq!(
+        table.foreign_keys[0].to_alter_sql("orders", &DatabaseType::MySQL).as_deref(),
+        Some("ALTER TABLE `orders` ADD CONSTRAINT `fk_orders_user_id` FOREIGN KEY (`user_id`) REFERENCES `users` (`id`);")
+    );
+
+    table.foreign_keys[0].column = "missing".to_string();
+    assert!(table.validate().is_err());
+}
+
+#[test]
+fn test_er_design_ddl() {
+    let mut state = ERDiagramState::new();
+    let mut users = ERTable::new("users".to_string());
+    users.columns = ERTable::from_design(design_table(DatabaseType::SQLite, "users", &["id"])).columns;
+    state.tables.push(users);
+    assert!(state.design_ddl().is_err());
+
+    // 先设计引用方，再设计被引用方，SQLite 需要按依赖顺序建表
+    state.apply_design(None, design_table(DatabaseType::SQLite, "items", &["id", "order_id"]));
+    state.apply_design(None, design_table(DatabaseType::SQLite, "orders", &["id", "user_id"]));
+    assert!(state.add_design_link("items", "order_id", "orders", "id"));
+    assert!(state.add_design_link("orders", "user_id", "users", "id"));
+    assert!(!state.add_design_link("users", "id", "orders", "id"));
+    assert_eq!(state.design_relationships().len(), 2);
+    assert!(state.tables.iter().find(|t| t.name == "items").unwrap().columns[1].is_foreign_key);
+
+    let sql = state.design_ddl().unwrap();
+    let orders = sql.find("CREATE TABLE \"orders\"").unwrap();
+    let items = sql.find("CREATE TABLE \"items\"").unwrap();
+    assert!(orders < items);
+    assert!(!sql.contains("ALTER TABLE"));
+
+    // 改名后引用随之更新
+    let mut renamed = state.design_definition("orders").unwrap().clone();
+    renamed.name = "purchases".to_string();
+    state.apply_design(Some("orders"), renamed);
+    assert!(state.design_ddl().unwrap().contains("REFERENCES \"purchases\""));
+
+    // 删除被引用的表时去掉外键
+    state.remove_design("purchases");
+    assert!(state.design_relationships().is_empty());
+    assert!(!state.design_ddl().unwrap().contains("FOREIGN KEY"));
+}
+
+#[test]
+fn test_er_design_ddl_alter_dialect() {
+    let mut state = ERDiagramState::new();
+    state.apply_design(None, design_table(DatabaseType::PostgreSQL, "a", &["id", "b_id"]));
+    state.apply_design(None, design_table(DatabaseType::PostgreSQL, "b", &["id", "a_id"]));
+    assert!(state.add_design_link("a", "b_id", "b", "id"));
+    assert!(state.add_design_link("b", "a_id", "a", "id"));
+
+    // 循环引用：先建表，再添加约束
+    let sql = state.design_ddl().unwrap();
+    let last_create = sql.rfind("CREATE TABLE").unwrap();
+    let first_alter = sql.find("ALTER TABLE").unwrap();
+    assert!(last_create < first_alter);
+    assert_eq!(sql.matches("ADD CONSTRAINT").count(), 2);
+    assert!(!sql.contains("    FOREIGN KEY"));
+}
@@ -1,6 +1,7 @@
 //! 导出模块测试
 
 use gridix::core::{parse_csv_line, sql_value_from_string, json_value_to_sql};
+use gridix::ui::{to_mermaid, to_plantuml, to_svg, ERColumn, ERTable, RelationType, Relationship};
 
 #[test]
 fn test_parse_csv_line_simple() {
@@ -32,3 +33,68 @@ fn test_json_value_to_sql() {
     assert_eq!(json_value_to_sql(&serde_json::json!("test")), "'test'");
     assert_eq!(json_value_to_sql(&serde_json::json!(true)), "1");
 }
+
+fn er_column(name: &str, data_type: &str, is_primary_key: bool, is_foreign_key: bool) -> ERColumn {
+    ERColumn {
+        name: name.to_string(),
+        data_type: data_type.to_string(),
+        is_primary_key,
+        is_foreign_key,
+        nullable: !is_primary_key,
+        default_value: None,
+    }
+}
+
+fn er_sample() -> (Vec<ERTable>, Vec<Relationship>) {
+    let mut users = ERTable::new("users".to_string());
+    users.columns = vec![er_column("id", "INTEGER", true, false), er_column("name", "VARCHAR(50)", false, false)];
+    let mut orders = ERTable::new("order items".to_string());
+    orders.position = egui::pos2(300.0, 40.0);
+    orders.columns = vec![er_column("id", "INTEGER", true, false), er_column("user_id", "INTEGER", false, true)];
+    let relationships = vec![Relationship {
+        from_table: "order items".to_string(),
+        from_column: "user_id".to_string(),
+        to_table: "users".to_string(),
+        to_column: "id".to_string(),
+        relation_type: RelationType::OneToMany,
+    }];
+    (vec![users, orders], relationships)
+}
+
+#[test]
+fn test_er_to_mermaid() {
+    let (tables, relationships) = er_sample();
+    let mermaid = to_mermaid(&tables, &relationships);
+    assert!(mermaid.starts_with("erDiagram\n"));
+    assert!(mermaid.contains("    users {\n        INTEGER id PK\n        VARCHAR(50) name\n    }"));
+    assert!(mermaid.contains("INTEGER user_id FK"));
+    assert!(mermaid.contains("users ||--o{ order_items : \"user_id\""));
+}
+
+#[test]
+fn test_er_to_plantuml() {
+    let (tables, relationships) = er_sample();
+    let plantuml = to_plantuml(&tables, &relationships);
+    assert!(plantuml.starts_with("@startuml\n"));
+    assert!(plantuml.trim_end().ends_with("@enduml"));
+    assert!(plantuml.contains("entity \"order items\" as order_items {"));
+    assert!(plantuml.contains("  * id : INTEGER <<PK>>\n  --\n  user_id : INTEGER <<FK>>"));
+    assert!(plantuml.contains("users ||--o{ order_items : user_id"));
+}
+
+#[test]
+fn test_er_to_svg() {
+    let (mut tables, mut relationships) = er_sample();
+    tables[0].name = "a<b>&c".to_string();
+    relationships[0].to_table = "a<b>&c".to_string();
+    let svg = to_svg(&tables, &relationships);
+    assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+    assert!(svg.trim_end().ends_with("</svg>"));
+    assert!(svg.contains("a&lt;b&gt;&amp;c"));
+    assert_eq!(svg.matches("<g>").count(), 2);
+    // 关系线与箭头
+    assert!(svg.contains(" C "));
+
+    let empty = to_svg(&[], &[]);
+    assert!(empty.contains("width=\"80\""));
+}
@@ -1,6 +1,7 @@
 //! SQL 格式化测试
 
-use gridix::core::format_sql;
+use gridix::core::{format_sql, format_sql_for};
+use gridix::database::DatabaseType;
 
 #[test]
 fn test_simple_select() {
@@ -17,3 +18,21 @@ fn test_multicolumn_select() {
     let formatted = format_sql(sql);
     assert!(formatted.contains("SELECT"));
 }
+
+#[test]
+fn test_oracle_dialect_keywords() {
+    let sql = "select * from emp start with mgr is null connect by prior id = mgr fetch first 10 rows only";
+    let formatted = format_sql_for(sql, &DatabaseType::Oracle);
+    assert!(formatted.lines().any(|l| l.starts_with("START WITH")));
+    assert!(formatted.lines().any(|l| l.starts_with("CONNECT BY")));
+    assert!(formatted.lines().any(|l| l.starts_with("FETCH FIRST")));
+}
+
+#[test]
+fn test_clickhouse_dialect_keywords() {
+    let sql = "select * from hits array join tags prewhere id > 10 where x = 1 settings max_threads = 4";
+    let formatted = format_sql_for(sql, &DatabaseType::ClickHouse);
+    assert!(formatted.lines().any(|l| l.starts_with("ARRAY JOIN")));
+    assert!(formatted.lines().any(|l| l.starts_with("PREWHERE")));
+    assert!(formatted.lines().any(|l| l.starts_with("SETTINGS")));
+}
@@ -2,7 +2,11 @@
 //!
 //! 测试 SQL 标识符转义、值转义、列宽缓存等功能
 
-use gridix::ui::{escape_identifier, escape_value, quote_identifier, DataGridState};
+use gridix::database::QueryResult;
+use gridix::ui::{
+    escape_identifier, escape_value, facet_selection, is_facet_selectable, quote_identifier, quote_qualified_identifier,
+    set_facet_value, ColumnFilter, ColumnStats, DataGridState, FilterOperator, ValueFacet,
+};
 
 // ============================================================================
 // 标识符转义测试
@@ -63,6 +67,16 @@ mod identifier_escape {
         );
     }
 
+    #[test]
+    fn test_quote_qualified_identifier() {
+        assert_eq!(
+            quote_qualified_identifier("archive.users", false).unwrap(),
+            "\"archive\".\"users\""
+        );
+        assert_eq!(quote_qualified_identifier("users", false).unwrap(), "\"users\"");
+        assert!(quote_qualified_identifier("archive.users;--", false).is_err());
+    }
+
     #[test]
     fn test_escape_value() {
         assert_eq!(escape_value("hello"), "'hello'");
@@ -212,4 +226,184 @@ mod grid_state {
         assert_eq!(state.cursor, (5, 0));
         assert!(state.count.is_none()); // Count should be cleared after use
     }
+
+    #[test]
+    fn test_paste_rows() {
+        let mut state = DataGridState::new();
+        let count = state.paste_rows("1\talice\textra\n\n2", 2);
+        assert_eq!(count, 2);
+        assert_eq!(state.new_rows, vec![
+            vec!["1".to_string(), "alice".to_string()],
+            vec!["2".to_string(), String::new()],
+        ]);
+        assert!(state.has_changes());
+
+        state.upsert_new_rows = true;
+        state.clear_edits();
+        assert!(state.new_rows.is_empty());
+        assert!(!state.upsert_new_rows);
+    }
+
+    #[test]
+    fn test_hidden_columns() {
+        let columns: Vec<String> = ["id", "name", "email", "age"].iter().map(|c| c.to_string()).collect();
+        let mut state = DataGridState::new();
+        assert_eq!(state.visible_columns(&columns), vec![0, 1, 2, 3]);
+
+        assert!(state.hide_column(&columns, "name"));
+        assert!(state.hide_column(&columns, "email"));
+        assert!(!state.hide_column(&columns, "missing"));
+        assert_eq!(state.visible_columns(&columns), vec![0, 3]);
+
+        // 向右移动到隐藏列时跳到右侧的可见列，向左时跳到左侧
+        state.cursor = (0, 1);
+        state.snap_cursor_to_visible(&columns, 0);
+        assert_eq!(state.cursor.1, 3);
+        state.cursor = (0, 2);
+        state.snap_cursor_to_visible(&columns, 3);
+        assert_eq!(state.cursor.1, 0);
+
+        // 至少保留一列可见
+        assert!(state.hide_column(&columns, "id"));
+        assert!(!state.hide_column(&columns, "age"));
+        assert_eq!(state.visible_columns(&columns), vec![3]);
+    }
+
+    #[test]
+    fn test_pinned_rows() {
+        let mut state = DataGridState::new();
+        assert_eq!(state.toggle_pinned_row(5), Some(true));
+        assert_eq!(state.toggle_pinned_row(2), Some(true));
+        assert_eq!(state.toggle_pinned_row(9), Some(true));
+        // 最多置顶 3 行
+        assert_eq!(state.toggle_pinned_row(7), None);
+        assert_eq!(state.pinned_rows, vec![5, 2, 9]);
+
+        // 再次切换取消置顶，空出位置
+        assert_eq!(state.toggle_pinned_row(2), Some(false));
+        assert_eq!(state.toggle_pinned_row(7), Some(true));
+        assert_eq!(state.pinned_rows, vec![5, 9, 7]);
+    }
+
+    #[test]
+    fn test_column_order() {
+        let columns: Vec<String> = ["id", "name", "email", "age"].iter().map(|c| c.to_string()).collect();
+        let mut state = DataGridState::new();
+        assert_eq!(state.visible_columns(&columns), vec![0, 1, 2, 3]);
+
+        // 拖动 age 到 name 的位置
+        assert!(state.move_column_to(&columns, 3, 1));
+        assert_eq!(state.visible_columns(&columns), vec![0, 3, 1, 2]);
+        assert_eq!(state.column_order, vec!["id", "age", "name", "email"]);
+
+        // 右移时跳过隐藏的列
+        state.hidden_columns.insert("name".to_string());
+        assert!(state.move_column(&columns, 3, 1));
+        assert_eq!(state.ordered_columns(&columns), vec![0, 1, 2, 3]);
+        // 回到原始顺序时不再保存
+        assert!(state.column_order.is_empty());
+        assert!(!state.move_column(&columns, 3, 1));
+
+        // 光标按显示顺序移动：l 从 id 移到显示在右侧的 age
+        state.hidden_columns.clear();
+        state.move_column_to(&columns, 3, 1);
+        state.cursor = (0, 1);
+        state.snap_cursor_to_visible(&columns, 0);
+        assert_eq!(state.cursor.1, 3);
+    }
+
+    #[test]
+    fn test_fill_down_and_series() {
+        let rows = [
+            vec!["1".to_string(), "2024-01-30".to_string(), "a".to_string()],
+            vec!["3".to_string(), "2024-02-01".to_string(), "b".to_string()],
+            vec!["".to_string(), "".to_string(), "".to_string()],
+            vec!["".to_string(), "".to_string(), "".to_string()],
+        ];
+        let cell = |r: usize, c: usize| rows.get(r).and_then(|row| row.get(c).cloned());
+
+        // 向下填充：第一行的值复制到其余行
+        let mut state = DataGridState::new();
+        state.select_anchor = Some((0, 0));
+        state.cursor = (3, 2);
+        assert_eq!(state.fill_down(cell), 9);
+        assert_eq!(state.modified_cells.get(&(3, 0)).map(String::as_str), Some("1"));
+        assert_eq!(state.modified_cells.get(&(2, 2)).map(String::as_str), Some("a"));
+
+        // 序列填充：按前两行的差值递增，文本列复制第一行
+        let mut state = DataGridState::new();
+        state.select_anchor = Some((0, 0));
+        state.cursor = (3, 2);
+        assert_eq!(state.fill_series(cell), 7);
+        assert_eq!(state.modified_cells.get(&(2, 0)).map(String::as_str), Some("5"));
+        assert_eq!(state.modified_cells.get(&(3, 0)).map(String::as_str), Some("7"));
+        assert_eq!(state.modified_cells.get(&(3, 1)).map(String::as_str), Some("2024-02-05"));
+        assert_eq!(state.modified_cells.get(&(1, 2)).map(String::as_str), Some("a"));
+
+        // 只有一个起始值时步长为 1，使用已修改的值作为起点
+        let mut state = DataGridState::new();
+        state.modified_cells.insert((1, 0), "1.5".to_string());
+        state.select_anchor = Some((1, 0));
+        state.cursor = (3, 0);
+        assert_eq!(state.fill_series(cell), 2);
+        assert_eq!(state.modified_cells.get(&(3, 0)).map(String::as_str), Some("3.5"));
+    }
+
+    #[test]
+    fn test_column_stats() {
+        let stats = ColumnStats::compute("score", ["10", "20", "NULL", "", "20"]);
+        assert_eq!(stats.column, "score");
+        assert_eq!(stats.aggregate.cells, 5);
+        assert_eq!(stats.aggregate.count, 3);
+        assert_eq!(stats.nulls, 2);
+        assert_eq!(stats.distinct, 2);
+        assert_eq!(stats.aggregate.numeric.unwrap().sum, 50.0);
+        assert_eq!(stats.aggregate.min.as_deref(), Some("10"));
+
+        let stats = ColumnStats::compute("name", ["b", "a"]);
+        assert!(stats.aggregate.numeric.is_none());
+        assert_eq!(stats.aggregate.max.as_deref(), Some("b"));
+    }
+
+    #[test]
+    fn test_value_facet() {
+        let result = QueryResult {
+            columns: vec!["id".to_string(), "city".to_string()],
+            rows: [("1", "Paris"), ("2", "Rome"), ("3", "Paris"), ("4", ""), ("5", "Oslo, NO")]
+                .iter()
+                .map(|(id, city)| vec![id.to_string(), city.to_string()])
+                .collect(),
+            ..Default::default()
+        };
+        let facet = ValueFacet::compute(&result, 1);
+        assert_eq!(facet.column, "city");
+        // 按次数从多到少，次数相同时按值排序
+        assert_eq!(facet.values[0], ("Paris".to_string(), 2));
+        assert_eq!(facet.values[1], (String::new(), 1));
+        assert_eq!(facet.values.len(), 4);
+        assert!(!facet.is_stale(&result));
+
+        assert!(is_facet_selectable("Paris"));
+        assert!(!is_facet_selectable("Oslo, NO"));
+        assert!(!is_facet_selectable(" Paris"));
+        assert!(!is_facet_selectable(""));
+
+        let mut filters: Vec<ColumnFilter> = Vec::new();
+        assert!(set_facet_value(&mut filters, "city", "Paris", true));
+        assert!(set_facet_value(&mut filters, "city", "Rome", true));
+        assert!(!set_facet_value(&mut filters, "city", "Rome", true));
+        assert!(!set_facet_value(&mut filters, "city", "Oslo, NO", true));
+        assert_eq!(filters.len(), 1);
+        assert_eq!(filters[0].operator, FilterOperator::In);
+        assert_eq!(filters[0].value, "Paris, Rome");
+        assert!(filters[0].case_sensitive);
+        assert_eq!(facet_selection(&filters, "city").len(), 2);
+        assert!(facet_selection(&filters, "id").is_empty());
+
+        // 取消最后一个值时删除筛选条件
+        assert!(set_facet_value(&mut filters, "city", "Paris", false));
+        assert_eq!(filters[0].value, "Rome");
+        assert!(set_facet_value(&mut filters, "city", "Rome", false));
+        assert!(filters.is_empty());
+    }
 }
@@ -1,9 +1,26 @@
 //! UI 对话框测试
 
+use std::sync::Arc;
+
 use gridix::ui::dialogs::{
     DialogResult, DialogSize, DialogButtons, DialogState,
     SimpleDialogState, DataDialogState,
     DialogStyle, FooterResult,
+    PrivilegeDialogState, SizeOverviewState, SizeSortColumn, format_bytes,
+    SessionMonitorState, duration_color, format_duration, TableDdlState,
+    TableAction, TableActionState, drop_table_sql, rename_table_sql,
+    duplicate_table_sql, truncate_table_sql, SchedulerState, ResultDiffState,
+    AuditLogState, ThemeEditorState, PreferencesState, PreferencesTab,
+    CommandPaletteState, PaletteCommand, PaletteItem,
+    FileChangeState, WorkflowState, ApiServerState, NotificationCenterState, LogViewerState, PoolStatsState,
+    DataSearchOutcome, DataSearchState, WritePreviewState, SqlReviewState, StatementCounts,
+    BulkEditState, BulkValueKind, CreateDbDialogState,
+};
+use gridix::core::{write_preview, Action, ApiServerConfig, AuditEntry, LogEntry, LogLevel, NotificationLevel, NotificationManager, ExternalChange, SqlFile, Workflow, WorkflowStep, ExportFormat, AuditSource, CustomTheme, KeyBinding, KeyBindings, KeyCode, Preferences, Schedule, ThemePreset, ThresholdOp, ThresholdTarget};
+use gridix::database::{DatabaseType, GrantInfo, QueryResult, SessionInfo, TableSizeInfo};
+use gridix::ui::{
+    complete_editor_command, filter_indices, parse_editor_command, CommandLineState, EditorCommand, DetachedTabWindow, EditorMode, FocusArea, GridMode, HintBar,
+    HintContext, QueryTabManager, ResultSnapshot,
 };
 
 // ============================================================================
@@ -120,3 +137,1602 @@ fn test_footer_result() {
     assert!(cancelled.has_action());
     assert!(cancelled.cancelled);
 }
+
+// ============================================================================
+// 权限管理对话框测试
+// ============================================================================
+
+fn grant(table: &str, privilege: &str) -> GrantInfo {
+    GrantInfo {
+        table_name: table.to_string(),
+        privilege: privilege.to_string(),
+    }
+}
+
+#[test]
+fn test_privilege_statements_mysql() {
+    let mut state = PrivilegeDialogState::new();
+    state.open(DatabaseType::MySQL, "shop".to_string(), vec!["orders".to_string(), "users".to_string()]);
+    state.select_user("'app'@'%'".to_string());
+    state.set_grants("'app'@'%'", vec![grant("", "SELECT"), grant("orders", "INSERT")]);
+    assert!(!state.has_changes());
+    assert!(state.statements().unwrap().is_empty());
+
+    state.set_granted("", "INSERT", true);
+    state.set_granted("orders", "INSERT", false);
+    state.set_granted("users", "SELECT", true);
+    state.set_granted("users", "UPDATE", true);
+    assert!(state.has_changes());
+    assert_eq!(
+        state.statements().unwrap(),
+        vec![
+            "GRANT INSERT ON `shop`.* TO 'app'@'%';",
+            "REVOKE INSERT ON `shop`.`orders` FROM 'app'@'%';",
+            "GRANT SELECT, UPDATE ON `shop`.`users` TO 'app'@'%';",
+        ]
+    );
+}
+
+#[test]
+fn test_privilege_statements_postgres() {
+    let mut state = PrivilegeDialogState::new();
+    state.open(DatabaseType::PostgreSQL, "shop".to_string(), vec!["orders".to_string()]);
+    state.select_user("reader".to_string());
+    state.set_grants("reader", vec![grant("", "CONNECT"), grant("orders", "SELECT")]);
+
+    state.set_granted("", "CONNECT", false);
+    state.set_granted("orders", "TRUNCATE", true);
+    assert_eq!(
+        state.statements().unwrap(),
+        vec![
+            "REVOKE CONNECT ON DATABASE \"shop\" FROM \"reader\";",
+            "GRANT TRUNCATE ON TABLE \"public\".\"orders\" TO \"reader\";",
+        ]
+    );
+}
+
+#[test]
+fn test_privilege_stale_grants_ignored() {
+    let mut state = PrivilegeDialogState::new();
+    state.open(DatabaseType::PostgreSQL, "shop".to_string(), Vec::new());
+    state.select_user("a".to_string());
+    state.select_user("b".to_string());
+    // 切换用户后，先前用户的结果不应覆盖
+    state.set_grants("a", vec![grant("", "CONNECT")]);
+    assert!(state.loading_grants);
+    assert!(!state.is_granted("", "CONNECT"));
+
+    state.set_grants("b", Vec::new());
+    assert!(!state.loading_grants);
+}
+
+// ============================================================================
+// 大小概览测试
+// ============================================================================
+
+fn table_size(name: &str, rows: Option<i64>, data: Option<i64>, index: Option<i64>) -> TableSizeInfo {
+    TableSizeInfo {
+        name: name.to_string(),
+        row_count: rows,
+        data_bytes: data,
+        index_bytes: index,
+    }
+}
+
+#[test]
+fn test_size_overview_sort() {
+    let mut state = SizeOverviewState::new();
+    state.open("shop".to_string());
+    assert!(state.loading);
+    state.set_result(Ok(vec![
+        table_size("a", Some(10), Some(100), Some(50)),
+        table_size("b", None, None, None),
+        table_size("c", Some(5), Some(400), Some(0)),
+    ]));
+    assert!(!state.loading);
+
+    // 默认按总大小降序，缺失值排最后
+    let names = |s: &SizeOverviewState| s.tables.iter().map(|t| t.name.clone()).collect::<Vec<_>>();
+    assert_eq!(names(&state), vec!["c", "a", "b"]);
+
+    // 切换到行数列默认降序，再次点击切换为升序
+    state.sort_by(SizeSortColumn::Rows);
+    assert_eq!(names(&state), vec!["a", "c", "b"]);
+    state.sort_by(SizeSortColumn::Rows);
+    assert_eq!(names(&state), vec!["c", "a", "b"]);
+
+    // 表名默认升序
+    state.sort_by(SizeSortColumn::Name);
+    assert_eq!(names(&state), vec!["a", "b", "c"]);
+
+    state.set_result(Err("失败".to_string()));
+    assert!(state.tables.is_empty());
+    assert_eq!(state.error.as_deref(), Some("失败"));
+}
+
+#[test]
+fn test_format_bytes() {
+    assert_eq!(format_bytes(512), "512 B");
+    assert_eq!(format_bytes(1536), "1.5 KB");
+    assert_eq!(format_bytes(10 * 1024 * 1024), "10.0 MB");
+    assert_eq!(format_bytes(3 * 1024 * 1024 * 1024), "3.0 GB");
+}
+
+// ============================================================================
+// 会话监控测试
+// ============================================================================
+
+fn session(id: i64, state: &str, duration_secs: i64, blocked_by: Vec<i64>) -> SessionInfo {
+    SessionInfo {
+        id,
+        user: "app".to_string(),
+        state: state.to_string(),
+        duration_secs: Some(duration_secs),
+        blocked_by,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_session_monitor_visible_sessions() {
+    let mut state = SessionMonitorState::new();
+    state.open(DatabaseType::PostgreSQL, "pg".to_string());
+    assert!(state.loading);
+
+    state.set_result(Ok(vec![
+        session(1, "idle in transaction", 120, vec![]),
+        session(2, "active", 40, vec![1]),
+        session(3, "idle", 600, vec![]),
+    ]));
+    assert!(!state.loading);
+
+    // 空闲会话默认隐藏
+    let visible: Vec<i64> = state.visible_sessions().map(|s| s.id).collect();
+    assert_eq!(visible, vec![1, 2]);
+    assert!(state.blocking_ids().contains(&1));
+
+    state.show_idle = true;
+    assert_eq!(state.visible_sessions().count(), 3);
+}
+
+#[test]
+fn test_session_monitor_pending_kill_cleared() {
+    let mut state = SessionMonitorState::new();
+    state.open(DatabaseType::MySQL, "mysql".to_string());
+    state.set_result(Ok(vec![session(7, "Query", 3, vec![])]));
+
+    state.pending_kill = Some((7, false));
+    state.set_result(Ok(vec![session(7, "Query", 5, vec![])]));
+    assert_eq!(state.pending_kill, Some((7, false)));
+
+    // 会话已结束时不再等待确认
+    state.set_result(Ok(vec![]));
+    assert!(state.pending_kill.is_none());
+}
+
+#[test]
+fn test_session_monitor_auto_refresh() {
+    let mut state = SessionMonitorState::new();
+    state.open(DatabaseType::PostgreSQL, "pg".to_string());
+    assert!(state.next_refresh_in().is_none(), "加载中不应自动刷新");
+
+    state.set_result(Ok(vec![]));
+    let remaining = state.next_refresh_in().unwrap();
+    assert!(remaining > std::time::Duration::ZERO);
+    assert!(remaining <= std::time::Duration::from_secs(state.refresh_secs));
+
+    state.refresh_secs = 0;
+    assert!(state.next_refresh_in().is_none());
+}
+
+#[test]
+fn test_session_duration_highlight() {
+    assert!(duration_color(&session(1, "active", 1, vec![])).is_none());
+    let warn = duration_color(&session(1, "active", 10, vec![])).unwrap();
+    let danger = duration_color(&session(1, "active", 60, vec![])).unwrap();
+    assert_ne!(warn, danger);
+    // 空闲会话不按时长高亮
+    assert!(duration_color(&session(1, "idle", 600, vec![])).is_none());
+}
+
+#[test]
+fn test_format_duration() {
+    assert_eq!(format_duration(45), "45s");
+    assert_eq!(format_duration(185), "3m 05s");
+    assert_eq!(format_duration(7800), "2h 10m");
+    assert_eq!(format_duration(-3), "0s");
+}
+
+// ============================================================================
+// 建表语句窗口测试
+// ============================================================================
+
+#[test]
+fn test_table_ddl_stale_result_ignored() {
+    let mut state = TableDdlState::new();
+    state.open("users".to_string());
+    state.open("orders".to_string());
+
+    // 先前请求的结果晚到时不覆盖当前表
+    state.set_result("users", Ok("CREATE TABLE users ();".to_string()));
+    assert!(state.loading);
+    assert!(state.ddl.is_empty());
+
+    state.set_result("orders", Ok("CREATE TABLE orders ();".to_string()));
+    assert!(!state.loading);
+    assert_eq!(state.ddl, "CREATE TABLE orders ();");
+
+    state.close();
+    state.set_result("orders", Err("late".to_string()));
+    assert!(state.error.is_none());
+}
+
+// ============================================================================
+// 重命名/删除表测试
+// ============================================================================
+
+#[test]
+fn test_rename_table_sql() {
+    assert_eq!(
+        rename_table_sql(DatabaseType::MySQL, None, "users", "members").unwrap(),
+        "RENAME TABLE `users` TO `members`;"
+    );
+    assert_eq!(
+        rename_table_sql(DatabaseType::PostgreSQL, None, "users", "members").unwrap(),
+        "ALTER TABLE \"users\" RENAME TO \"members\";"
+    );
+    // SQLite 附加数据库的表在原 schema 内重命名
+    assert_eq!(
+        rename_table_sql(DatabaseType::SQLite, Some("archive"), "orders", "orders_2023").unwrap(),
+        "ALTER TABLE \"archive\".\"orders\" RENAME TO \"orders_2023\";"
+    );
+    assert!(rename_table_sql(DatabaseType::SQLite, Some("archive"), "orders", "orders").is_err());
+    assert!(rename_table_sql(DatabaseType::MySQL, None, "users", "  ").is_err());
+    assert!(rename_table_sql(DatabaseType::Redis, None, "users", "members").is_err());
+}
+
+#[test]
+fn test_drop_table_sql() {
+    assert_eq!(drop_table_sql(DatabaseType::MySQL, None, "a`b").unwrap(), "DROP TABLE `a``b`;");
+    assert_eq!(drop_table_sql(DatabaseType::PostgreSQL, None, "users").unwrap(), "DROP TABLE \"users\";");
+    assert!(drop_table_sql(DatabaseType::MongoDB, None, "users").is_err());
+}
+
+#[test]
+fn test_table_action_dotted_table_name() {
+    // 前缀不是已附加数据库时，带点的表名整体引用
+    let mut state = TableActionState::new();
+    state.open(TableAction::Drop, DatabaseType::SQLite, None, "a.b".to_string());
+    state.input = "a.b".to_string();
+    assert_eq!(state.generate_sql().unwrap(), vec!["DROP TABLE \"a.b\";".to_string()]);
+
+    state.open(TableAction::Rename, DatabaseType::SQLite, None, "a.b".to_string());
+    assert_eq!(state.input, "a.b");
+    state.input = "c".to_string();
+    assert_eq!(state.generate_sql().unwrap(), vec!["ALTER TABLE \"a.b\" RENAME TO \"c\";".to_string()]);
+    assert_eq!(truncate_table_sql(DatabaseType::SQLite, None, "a.b").unwrap(), "DELETE FROM \"a.b\";");
+}
+
+#[test]
+fn test_table_action_drop_requires_name() {
+    let mut state = TableActionState::new();
+    state.open(TableAction::Drop, DatabaseType::SQLite, None, "users".to_string());
+    assert!(state.input.is_empty());
+    assert!(!state.is_confirmed());
+
+    state.input = "user".to_string();
+    assert!(!state.is_confirmed());
+    state.input = "users".to_string();
+    assert!(state.is_confirmed());
+    assert_eq!(state.generate_sql().unwrap(), vec!["DROP TABLE \"users\";".to_string()]);
+}
+
+#[test]
+fn test_table_action_rename_prefills_name() {
+    let mut state = TableActionState::new();
+    state.open(TableAction::Rename, DatabaseType::SQLite, Some("archive".to_string()), "archive.orders".to_string());
+    assert_eq!(state.input, "orders");
+    assert!(state.generate_sql().is_err(), "未修改名称时不应生成 SQL");
+
+    state.input = "orders_old".to_string();
+    assert_eq!(
+        state.generate_sql().unwrap(),
+        vec!["ALTER TABLE \"archive\".\"orders\" RENAME TO \"orders_old\";".to_string()]
+    );
+}
+
+// ============================================================================
+// 清空/复制表测试
+// ============================================================================
+
+#[test]
+fn test_truncate_table_sql() {
+    assert_eq!(truncate_table_sql(DatabaseType::SQLite, None, "users").unwrap(), "DELETE FROM \"users\";");
+    assert_eq!(truncate_table_sql(DatabaseType::MySQL, None, "users").unwrap(), "TRUNCATE TABLE `users`;");
+    assert_eq!(
+        truncate_table_sql(DatabaseType::PostgreSQL, None, "users").unwrap(),
+        "TRUNCATE TABLE \"users\";"
+    );
+    assert!(truncate_table_sql(DatabaseType::Redis, None, "users").is_err());
+}
+
+#[test]
+fn test_duplicate_table_sql() {
+    assert_eq!(
+        duplicate_table_sql(DatabaseType::MySQL, None, "users", "users_copy", true).unwrap(),
+        vec![
+            "CREATE TABLE `users_copy` LIKE `users`;".to_string(),
+            "INSERT INTO `users_copy` SELECT * FROM `users`;".to_string(),
+        ]
+    );
+    assert_eq!(
+        duplicate_table_sql(DatabaseType::PostgreSQL, None, "users", "users_copy", false).unwrap(),
+        vec!["CREATE TABLE \"users_copy\" (LIKE \"users\" INCLUDING ALL);".to_string()]
+    );
+    assert_eq!(
+        duplicate_table_sql(DatabaseType::PostgreSQL, None, "users", "users_copy", true).unwrap()[1],
+        "INSERT INTO \"users_copy\" OVERRIDING SYSTEM VALUE SELECT * FROM \"users\";"
+    );
+    // SQLite 附加数据库的表复制到同一 schema
+    assert_eq!(
+        duplicate_table_sql(DatabaseType::SQLite, Some("archive"), "orders", "orders_copy", false).unwrap(),
+        vec!["CREATE TABLE \"archive\".\"orders_copy\" AS SELECT * FROM \"archive\".\"orders\" WHERE 1 = 0;".to_string()]
+    );
+    // 带点的表名不是 schema 前缀时，新表名同样整体引用
+    assert_eq!(
+        duplicate_table_sql(DatabaseType::SQLite, None, "a.b", "a.b_copy", false).unwrap(),
+        vec!["CREATE TABLE \"a.b_copy\" AS SELECT * FROM \"a.b\" WHERE 1 = 0;".to_string()]
+    );
+    assert!(duplicate_table_sql(DatabaseType::SQLite, None, "users", "users", true).is_err());
+    assert!(duplicate_table_sql(DatabaseType::MySQL, None, "users", "", true).is_err());
+}
+
+#[test]
+fn test_table_action_truncate_and_duplicate() {
+    let mut state = TableActionState::new();
+    state.open(TableAction::Truncate, DatabaseType::MySQL, None, "logs".to_string());
+    assert!(!state.is_confirmed());
+    state.input = "logs".to_string();
+    assert!(state.is_confirmed());
+
+    state.open(TableAction::Duplicate, DatabaseType::MySQL, None, "logs".to_string());
+    assert_eq!(state.input, "logs_copy");
+    assert!(state.is_confirmed());
+    state.with_data = false;
+    assert_eq!(state.generate_sql().unwrap(), vec!["CREATE TABLE `logs_copy` LIKE `logs`;".to_string()]);
+
+    // 附加数据库的表复制到同一 schema
+    state.open(TableAction::Duplicate, DatabaseType::SQLite, Some("archive".to_string()), "archive.orders".to_string());
+    assert_eq!(state.input, "orders_copy");
+    assert_eq!(
+        state.generate_sql().unwrap(),
+        vec!["CREATE TABLE \"archive\".\"orders_copy\" AS SELECT * FROM \"archive\".\"orders\" WHERE 1 = 0;".to_string()]
+    );
+}
+
+// ============================================================================
+// 定时查询对话框测试
+// ============================================================================
+
+#[test]
+fn test_scheduler_state_build() {
+    let mut state = SchedulerState::new();
+    state.open(None, "SELECT 1");
+    assert!(!state.can_create());
+    assert!(state.build().is_err());
+
+    state.open(Some("local".to_string()), "  SELECT count(*) AS n FROM jobs  ");
+    assert!(state.can_create());
+    assert_eq!(state.sql, "SELECT count(*) AS n FROM jobs");
+
+    let job = state.build().unwrap();
+    assert_eq!(job.connection, "local");
+    assert_eq!(job.schedule, Schedule::Interval(30));
+    assert!(job.threshold.is_none());
+
+    state.schedule = "0 * * * *".to_string();
+    state.threshold_enabled = true;
+    state.threshold_column = " n ".to_string();
+    state.threshold_op = ThresholdOp::Ge;
+    state.threshold_value = 10.0;
+    let job = state.build().unwrap();
+    let threshold = job.threshold.unwrap();
+    assert_eq!(threshold.target, ThresholdTarget::Column("n".to_string()));
+    assert_eq!(threshold.op, ThresholdOp::Ge);
+
+    state.threshold_column.clear();
+    assert_eq!(state.build().unwrap().threshold.unwrap().target, ThresholdTarget::RowCount);
+
+    state.schedule = "every minute".to_string();
+    assert!(state.build().is_err());
+    state.schedule = "1m".to_string();
+    state.name = "  ".to_string();
+    assert!(state.build().is_err());
+}
+
+#[test]
+fn test_pinned_result_tab() {
+    let mut tabs = QueryTabManager::new();
+    let id = tabs.new_pinned_tab("⏱ waits", "SELECT 1").unwrap();
+    let idx = tabs.position(&id).unwrap();
+    assert_eq!(tabs.active_index, idx);
+    assert!(tabs.tabs[idx].pinned);
+    assert!(!tabs.tabs[idx].modified);
+    assert_eq!(tabs.tabs[idx].title, "⏱ waits");
+
+    tabs.close_tab(idx);
+    assert_eq!(tabs.position(&id), None);
+
+    tabs.max_tabs = tabs.tabs.len();
+    assert!(tabs.new_pinned_tab("full", "SELECT 1").is_none());
+}
+
+#[test]
+fn test_tab_pin_and_rename() {
+    let mut tabs = QueryTabManager::new();
+    tabs.new_tab();
+    tabs.new_tab();
+    tabs.new_tab();
+    // 固定第 1 个和第 4 个，从第 2 个关闭右侧：只关闭第 3 个
+    assert_eq!(tabs.toggle_pin(0), Some(true));
+    assert_eq!(tabs.toggle_pin(3), Some(true));
+    let pinned_id = tabs.tabs[3].id.clone();
+    tabs.set_active(1);
+    tabs.close_tabs_to_right();
+    assert_eq!(tabs.len(), 3);
+    assert_eq!(tabs.position(&pinned_id), Some(2));
+
+    // 关闭其他时保留固定的标签页，活动标签页位置随之调整
+    tabs.new_tab();
+    let active_id = tabs.tabs[3].id.clone();
+    tabs.close_other_tabs();
+    assert_eq!(tabs.len(), 3);
+    assert_eq!(tabs.tabs[tabs.active_index].id, active_id);
+    assert!(tabs.tabs.iter().filter(|t| t.id != active_id).all(|t| t.pinned));
+
+    // 重命名后不再根据 SQL 更新标题，清空名称时恢复自动标题
+    let idx = tabs.active_index;
+    tabs.rename_tab(idx, "  月报  ");
+    tabs.tabs[idx].sql = "SELECT * FROM orders".to_string();
+    tabs.tabs[idx].update_title();
+    assert_eq!(tabs.tabs[idx].title, "月报");
+    tabs.rename_tab(idx, "");
+    assert_eq!(tabs.tabs[idx].title, "查询 orders");
+}
+
+#[test]
+fn test_tab_detach() {
+    let mut tabs = QueryTabManager::new();
+    tabs.new_tab();
+    assert_eq!(tabs.toggle_detach(1), Some(true));
+    assert!(tabs.tabs[1].detached && !tabs.tabs[0].detached);
+    // 每个标签页的独立窗口使用不同的视口
+    assert_ne!(
+        DetachedTabWindow::viewport_id(&tabs.tabs[0]),
+        DetachedTabWindow::viewport_id(&tabs.tabs[1])
+    );
+    assert_eq!(tabs.toggle_detach(1), Some(false));
+    assert_eq!(tabs.toggle_detach(5), None);
+}
+
+#[test]
+fn test_file_tab_reuse_and_dirty() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("q.sql");
+    std::fs::write(&path, "SELECT 1;").unwrap();
+
+    let mut tabs = QueryTabManager::new();
+    let (file, content) = SqlFile::open(&path).unwrap();
+    let idx = tabs.open_file_tab(file, content).unwrap();
+    assert_eq!(tabs.tabs[idx].title, "q.sql");
+    assert!(!tabs.tabs[idx].is_dirty());
+    tabs.tabs[idx].sql.push_str(" -- edit");
+    assert!(tabs.tabs[idx].is_dirty());
+
+    // 再次打开同一文件时切换到已有标签页，保留编辑内容
+    tabs.new_tab();
+    let (file, content) = SqlFile::open(&path).unwrap();
+    assert_eq!(tabs.open_file_tab(file, content), Some(idx));
+    assert_eq!(tabs.active_index, idx);
+    assert!(tabs.tabs[idx].is_dirty());
+    assert_eq!(tabs.position_of_file(&path), Some(idx));
+
+    let mut state = FileChangeState::new();
+    state.open(tabs.tabs[idx].id.clone(), "q.sql", ExternalChange::Removed);
+    assert!(state.show);
+    assert_eq!(state.take(), Some((tabs.tabs[idx].id.clone(), ExternalChange::Removed)));
+    assert!(!state.show);
+}
+
+// ============================================================================
+// 结果对比窗口测试
+// ============================================================================
+
+#[test]
+fn test_result_diff_state_keys() {
+    let result = |qty: &str| QueryResult {
+        columns: vec!["id".to_string(), "qty".to_string()],
+        rows: vec![vec!["1".to_string(), qty.to_string()]],
+        ..Default::default()
+    };
+    let snapshot = ResultSnapshot {
+        sql: "SELECT * FROM t".to_string(),
+        result: Arc::new(result("1")),
+        taken_at: chrono::Local::now(),
+    };
+
+    let mut state = ResultDiffState::new();
+    state.open(snapshot.clone(), Arc::new(result("2")), "SELECT * FROM t ", Some("id"));
+    assert!(state.show);
+    assert!(!state.sql_changed);
+    assert_eq!(state.key_columns, vec!["id"]);
+    assert_eq!(state.diff.as_ref().unwrap().as_ref().unwrap().count(gridix::core::DiffKind::Changed), 1);
+
+    // 取消键列后按整行匹配
+    state.toggle_key("id");
+    assert!(state.key_columns.is_empty());
+    let diff = state.diff.as_ref().unwrap().as_ref().unwrap();
+    assert_eq!(diff.count(gridix::core::DiffKind::Added), 1);
+    assert_eq!(diff.count(gridix::core::DiffKind::Removed), 1);
+
+    // 不存在的主键列被忽略
+    state.open(snapshot, Arc::new(result("1")), "SELECT id FROM t", Some("missing"));
+    assert!(state.sql_changed);
+    assert!(state.key_columns.is_empty());
+    assert!(state.diff.as_ref().unwrap().as_ref().unwrap().is_identical());
+
+    state.close();
+    assert!(!state.show);
+    assert!(state.diff.is_none());
+}
+
+#[test]
+fn test_audit_log_state_filter() {
+    let entry = |connection: &str, source: AuditSource, sql: &str, error: Option<&str>| AuditEntry {
+        timestamp: chrono::Local::now(),
+        connection: connection.to_string(),
+        database_type: "SQLite".to_string(),
+        source,
+        sql: sql.to_string(),
+        success: error.is_none(),
+        affected_rows: if error.is_none() { Some(1) } else { None },
+        error: error.map(str::to_string),
+    };
+    let entries = vec![
+        entry("a", AuditSource::Editor, "INSERT INTO t VALUES (1)", None),
+        entry("b", AuditSource::GridSave, "UPDATE t SET v = 2", None),
+        entry("a", AuditSource::GridSave, "DELETE FROM t", Some("locked")),
+    ];
+
+    let mut state = AuditLogState::new();
+    state.open(entries, "audit.log".to_string());
+    assert!(state.show);
+    // 最新的在前
+    assert_eq!(state.entries[0].sql, "DELETE FROM t");
+    assert_eq!(state.filtered().len(), 3);
+    assert_eq!(state.connections(), vec!["a", "b"]);
+
+    state.connection = Some("a".to_string());
+    assert_eq!(state.filtered().len(), 2);
+    state.source = Some(AuditSource::GridSave);
+    assert_eq!(state.filtered().len(), 1);
+    state.connection = None;
+    state.source = None;
+    state.failures_only = true;
+    assert_eq!(state.filtered().len(), 1);
+    state.failures_only = false;
+    state.search = "LOCKED".to_string();
+    assert_eq!(state.filtered()[0].sql, "DELETE FROM t");
+    state.search = "update".to_string();
+    assert_eq!(state.filtered()[0].connection, "b");
+}
+
+#[test]
+fn test_theme_editor_state_validate() {
+    let mut state = ThemeEditorState::new();
+    assert!(state.validate().is_err());
+
+    let names = vec!["Mine".to_string(), "Other".to_string()];
+    state.open(CustomTheme::from_preset("Mine", ThemePreset::Nord), Some("Mine".to_string()), names.clone());
+    assert!(state.show);
+    assert!(state.validate().is_ok());
+
+    // 改名为已存在的主题
+    state.theme.as_mut().unwrap().name = "Other".to_string();
+    assert!(state.validate().is_err());
+
+    // 新主题不能与已保存的重名，也不能为空
+    state.open(CustomTheme::from_preset("Mine", ThemePreset::Nord), None, names);
+    assert!(state.validate().is_err());
+    state.theme.as_mut().unwrap().name = "  ".to_string();
+    assert!(state.validate().is_err());
+    state.theme.as_mut().unwrap().name = "New".to_string();
+    assert!(state.validate().is_ok());
+
+    state.close();
+    assert!(!state.show);
+    assert!(state.theme.is_none());
+}
+
+#[test]
+fn test_preferences_state_tracks_modifications() {
+    let mut state = PreferencesState::new();
+    assert!(!state.show);
+    assert_eq!(state.tab, PreferencesTab::General);
+
+    state.open(Preferences::default());
+    assert!(state.show);
+    assert!(!state.is_modified());
+
+    state.prefs.default_query_limit = 500;
+    assert!(state.is_modified());
+    assert_eq!(state.original.default_query_limit, 100);
+    assert_eq!(PreferencesTab::ALL.len(), 5);
+
+    state.close();
+    assert!(!state.show);
+}
+
+#[test]
+fn test_command_palette_ranks_matches() {
+    let mut state = CommandPaletteState::new();
+    state.open(vec![
+        PaletteItem::new("命令", "导出数据", PaletteCommand::Action(Action::Export)).with_keywords("export"),
+        PaletteItem::new("打开表", "orders_archive", PaletteCommand::OpenTable("orders_archive".into())),
+        PaletteItem::new("打开表", "orders", PaletteCommand::OpenTable("orders".into())),
+        PaletteItem::new("连接", "prod", PaletteCommand::Connect("prod".into())).with_hint("MySQL"),
+    ]);
+    assert!(state.show);
+    assert_eq!(state.matches(), vec![0, 1, 2, 3]);
+
+    // 按英文关键字搜索中文名称的操作
+    state.query = "exp".into();
+    assert_eq!(state.matches(), vec![0]);
+
+    // 更短、更紧凑的匹配排在前面
+    state.query = "ord".into();
+    let matches = state.matches();
+    assert_eq!(matches.len(), 2);
+    assert_eq!(state.item(matches[0]).unwrap().label, "orders");
+
+    state.query = "zzz".into();
+    assert!(state.matches().is_empty());
+
+    state.close();
+    assert!(!state.show);
+}
+
+#[test]
+fn test_editor_command_line() {
+    assert_eq!(parse_editor_command("run"), Ok(EditorCommand::Run));
+    assert_eq!(parse_editor_command(":r"), Ok(EditorCommand::Run));
+    assert_eq!(parse_editor_command("preview"), Ok(EditorCommand::Preview));
+    assert_eq!(parse_editor_command("w"), Ok(EditorCommand::Write));
+    assert_eq!(parse_editor_command("e  users "), Ok(EditorCommand::Table("users".into())));
+    assert_eq!(parse_editor_command("connect prod"), Ok(EditorCommand::Connect("prod".into())));
+    assert_eq!(parse_editor_command("export CSV"), Ok(EditorCommand::Export(Some(ExportFormat::Csv))));
+    assert_eq!(parse_editor_command("export"), Ok(EditorCommand::Export(None)));
+    assert!(parse_editor_command("").is_err());
+    assert!(parse_editor_command("bogus").is_err());
+    assert!(parse_editor_command("connect").is_err());
+    assert!(parse_editor_command("run now").is_err());
+    assert!(parse_editor_command("export xml").is_err());
+
+    let connections = vec!["local".to_string(), "prod".to_string()];
+    let tables = vec!["orders_archive".to_string(), "orders".to_string(), "users".to_string()];
+
+    // 命令名补全，带参数的命令追加空格
+    assert_eq!(complete_editor_command("con", &connections, &tables), vec!["connect "]);
+    assert_eq!(complete_editor_command("tabn", &connections, &tables)[0], "tabnew");
+    // 参数补全
+    assert_eq!(complete_editor_command("connect p", &connections, &tables), vec!["connect prod"]);
+    assert_eq!(complete_editor_command("e ord", &connections, &tables), vec!["e orders", "e orders_archive"]);
+    assert_eq!(complete_editor_command("export j", &connections, &tables), vec!["export json"]);
+    assert!(complete_editor_command("run x", &connections, &tables).is_empty());
+
+    // Tab 在候选项间循环
+    let mut state = CommandLineState::new();
+    state.open();
+    state.input = "table ord".into();
+    state.complete(&connections, &tables, false);
+    assert_eq!(state.input, "table orders");
+    state.complete(&connections, &tables, false);
+    assert_eq!(state.input, "table orders_archive");
+    state.complete(&connections, &tables, true);
+    assert_eq!(state.input, "table orders");
+}
+
+// ============================================================================
+// 工作流面板测试
+// ============================================================================
+
+#[test]
+fn test_workflow_state_editing() {
+    let mut state = WorkflowState::new();
+    state.open(vec![Workflow::new("工作流 1")], vec!["prod".to_string()]);
+    assert!(state.show);
+    assert!(!state.dirty);
+
+    state.add_workflow();
+    assert_eq!(state.selected, 1);
+    assert_eq!(state.selected_workflow().unwrap().name, "工作流 2");
+    assert!(state.dirty);
+
+    state.add_step(WorkflowStep::query("prod", "SELECT 1"));
+    state.add_step(WorkflowStep::query("prod", "SELECT 2"));
+    state.move_step(1, true);
+    assert_eq!(state.selected_workflow().unwrap().steps[0], WorkflowStep::query("prod", "SELECT 2"));
+    state.move_step(0, true);
+    assert_eq!(state.selected_workflow().unwrap().steps[0], WorkflowStep::query("prod", "SELECT 2"));
+    state.remove_step(0);
+    assert_eq!(state.selected_workflow().unwrap().steps.len(), 1);
+
+    state.workflows[1].name = "工作流 1".to_string();
+    assert!(state.validate_names().unwrap_err().contains("重复"));
+    state.remove_selected();
+    assert_eq!(state.selected, 0);
+    assert!(state.validate_names().is_ok());
+    assert!(!state.is_running());
+}
+
+// ============================================================================
+// API 服务面板测试
+// ============================================================================
+
+#[test]
+fn test_api_server_state_queries() {
+    let mut state = ApiServerState::new();
+    state.open(
+        ApiServerConfig::default(),
+        vec!["dev".to_string(), "prod".to_string()],
+        "  SELECT * FROM orders  ".to_string(),
+        Some("prod".to_string()),
+    );
+    assert!(state.show);
+    assert!(!state.dirty);
+
+    state.add_query();
+    state.add_query();
+    assert!(state.dirty);
+    assert_eq!(state.config.queries[0].name, "query1");
+    assert_eq!(state.config.queries[1].name, "query2");
+    assert_eq!(state.config.queries[0].connection, "prod");
+    assert_eq!(state.config.queries[0].sql, "SELECT * FROM orders");
+    assert_eq!(
+        state.query_url(&state.config.queries[1]),
+        format!("http://127.0.0.1:{}/queries/query2", state.config.port)
+    );
+
+    state.remove_query(0);
+    assert_eq!(state.config.queries.len(), 1);
+    assert_eq!(state.config.queries[0].name, "query2");
+
+    // 没有活动连接时使用第一个连接
+    state.active_connection = None;
+    state.add_query();
+    assert_eq!(state.config.queries[1].name, "query1");
+    assert_eq!(state.config.queries[1].connection, "dev");
+}
+
+// ============================================================================
+// 通知中心测试
+// ============================================================================
+
+#[test]
+fn test_notification_center_filter() {
+    let mut manager = NotificationManager::new();
+    manager.info("已连接 dev");
+    manager.error("连接 prod 失败");
+    manager.warning("查询结果已截断");
+
+    let mut state = NotificationCenterState::new();
+    state.open();
+    assert!(state.show);
+    assert_eq!(manager.history().filter(|r| state.matches(r)).count(), 3);
+
+    // 只看错误
+    state.levels = [false, false, false, true];
+    assert!(state.shows_level(NotificationLevel::Error));
+    assert!(!state.shows_level(NotificationLevel::Info));
+    let matched: Vec<&str> = manager
+        .history()
+        .filter(|r| state.matches(r))
+        .map(|r| r.message.as_str())
+        .collect();
+    assert_eq!(matched, vec!["连接 prod 失败"]);
+
+    // 文本筛选不区分大小写，并与级别筛选同时生效
+    state.levels = [true; 4];
+    state.search = " PROD ".to_string();
+    assert_eq!(manager.history().filter(|r| state.matches(r)).count(), 1);
+    state.search = "连接".to_string();
+    assert_eq!(manager.history().filter(|r| state.matches(r)).count(), 2);
+
+    state.close();
+    assert!(!state.show);
+}
+
+// ============================================================================
+// 日志查看器测试
+// ============================================================================
+
+#[test]
+fn test_log_viewer_filter() {
+    let entry = |level: LogLevel, message: &str| LogEntry {
+        timestamp: String::new(),
+        level,
+        target: "gridix::app".to_string(),
+        message: message.to_string(),
+        fields: Vec::new(),
+    };
+    let mut state = LogViewerState::new();
+    state.entries = vec![
+        entry(LogLevel::Debug, "读取配置"),
+        entry(LogLevel::Info, "连接 dev 成功"),
+        entry(LogLevel::Error, "连接 prod 失败"),
+    ];
+
+    // 默认只显示 INFO 及以上
+    assert_eq!(state.filtered().len(), 2);
+    state.min_level = LogLevel::Trace;
+    assert_eq!(state.filtered().len(), 3);
+
+    // 搜索匹配整行（包括模块名）
+    state.search = "PROD".to_string();
+    assert_eq!(state.filtered().len(), 1);
+    state.search = "gridix::app".to_string();
+    assert_eq!(state.filtered().len(), 3);
+
+    state.search = "连接".to_string();
+    state.min_level = LogLevel::Error;
+    assert_eq!(state.filtered_text(), "ERROR gridix::app: 连接 prod 失败");
+}
+
+// ============================================================================
+// 侧边栏表列表测试
+// ============================================================================
+
+#[test]
+fn test_sidebar_table_filter() {
+    let tables: Vec<String> = ["orders", "Order_Items", "users", "user_roles"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    assert_eq!(filter_indices(&tables, "", false), vec![0, 1, 2, 3]);
+    assert_eq!(filter_indices(&tables, "  ", false), vec![0, 1, 2, 3]);
+    // 不区分大小写的子串匹配
+    assert_eq!(filter_indices(&tables, "ORDER", false), vec![0, 1]);
+    assert_eq!(filter_indices(&tables, "role", false), vec![3]);
+    assert!(filter_indices(&tables, "missing", false).is_empty());
+}
+
+#[test]
+fn test_connection_append_tables() {
+    use gridix::database::{Connection, ConnectionConfig};
+
+    let mut conn = Connection::new(ConnectionConfig::default());
+    conn.set_database("app".to_string(), vec!["a".to_string()]);
+    conn.has_more_tables = true;
+    conn.append_tables(vec!["b".to_string()], false);
+    assert_eq!(conn.tables, vec!["a", "b"]);
+    assert!(!conn.has_more_tables);
+
+    // 切换数据库后重新开始
+    conn.has_more_tables = true;
+    conn.set_database("other".to_string(), Vec::new());
+    assert!(!conn.has_more_tables);
+}
+
+#[test]
+fn test_sidebar_quick_filter_fuzzy() {
+    let names = ["prod-mysql", "local sqlite", "staging_pg"];
+    // 子串匹配需要连续出现
+    assert!(filter_indices(names, "pmy", false).is_empty());
+    // 模糊匹配只要求按顺序出现
+    assert_eq!(filter_indices(names, "pmy", true), vec![0]);
+    assert_eq!(filter_indices(names, "SQL", true), vec![0, 1]);
+    assert_eq!(filter_indices(names, "", true), vec![0, 1, 2]);
+}
+
+// ============================================================================
+// 服务端筛选测试
+// ============================================================================
+
+#[test]
+fn test_filters_to_where() {
+    use gridix::ui::{check_filter_match, filters_to_where, ColumnFilter, FilterLogic, FilterOperator};
+
+    let name = ColumnFilter::new("name".to_string())
+        .with_operator(FilterOperator::Contains)
+        .with_value("50%_O'k".to_string());
+    let mut age = ColumnFilter::new("age".to_string())
+        .with_operator(FilterOperator::GreaterThan)
+        .with_value("18".to_string());
+    age.logic = FilterLogic::Or;
+    let mut deleted = ColumnFilter::new("deleted".to_string()).with_operator(FilterOperator::IsNull);
+    let mut disabled = ColumnFilter::new("skip".to_string()).with_operator(FilterOperator::IsNull);
+    disabled.enabled = false;
+
+    let filters = vec![name, disabled, age, deleted.clone()];
+    assert_eq!(
+        filters_to_where(&filters, DatabaseType::PostgreSQL).unwrap().unwrap(),
+        "((LOWER(CAST(\"name\" AS TEXT)) LIKE '%50!%!_o''k%' ESCAPE '!') AND (\"age\" > '18')) OR (\"deleted\" IS NULL)"
+    );
+
+    // MySQL 使用反引号，区分大小写时按二进制比较
+    deleted.operator = FilterOperator::NotEquals;
+    deleted.value = "Yes".to_string();
+    deleted.case_sensitive = true;
+    assert_eq!(
+        filters_to_where(&[deleted], DatabaseType::MySQL).unwrap().unwrap(),
+        "(`deleted` IS NULL OR NOT (CAST(`deleted` AS BINARY) = 'Yes'))"
+    );
+
+    // 数字也作为字符串字面量，等于和列表按文本比较，保留前导零
+    let zip = ColumnFilter::new("zip".to_string())
+        .with_operator(FilterOperator::Equals)
+        .with_value(" 02134 ".to_string());
+    assert_eq!(
+        filters_to_where(&[zip], DatabaseType::PostgreSQL).unwrap().unwrap(),
+        "CAST(\"zip\" AS TEXT) = '02134'"
+    );
+    let ids = ColumnFilter::new("id".to_string())
+        .with_operator(FilterOperator::In)
+        .with_value("1, 02".to_string());
+    assert_eq!(
+        filters_to_where(&[ids], DatabaseType::MySQL).unwrap().unwrap(),
+        "CAST(`id` AS CHAR) IN ('1', '02')"
+    );
+
+    // Oracle 的空字符串就是 NULL
+    let note = ColumnFilter::new("note".to_string()).with_operator(FilterOperator::IsNotEmpty);
+    assert_eq!(filters_to_where(&[note], DatabaseType::Oracle).unwrap().unwrap(), "\"note\" IS NOT NULL");
+
+    // 日期等非数字值作为字符串
+    let between = ColumnFilter::new("created".to_string())
+        .with_operator(FilterOperator::Between)
+        .with_value("2024-01-01".to_string())
+        .with_value2("2024-12-31".to_string());
+    assert_eq!(
+        filters_to_where(&[between], DatabaseType::Oracle).unwrap().unwrap(),
+        "\"created\" BETWEEN '2024-01-01' AND '2024-12-31'"
+    );
+
+    // SQLite 不支持正则，没有可用条件时为空
+    let regex = ColumnFilter::new("name".to_string())
+        .with_operator(FilterOperator::Regex)
+        .with_value("^a".to_string());
+    assert!(filters_to_where(std::slice::from_ref(&regex), DatabaseType::SQLite).is_err());
+    assert_eq!(
+        filters_to_where(&[regex], DatabaseType::PostgreSQL).unwrap().unwrap(),
+        "CAST(\"name\" AS TEXT) ~ '^a'"
+    );
+    assert_eq!(filters_to_where(&[], DatabaseType::SQLite).unwrap(), None);
+
+    // JSON 路径条件按各数据库的 JSON 函数转换
+    let json = |value: &str| {
+        ColumnFilter::new("meta".to_string())
+            .with_operator(FilterOperator::JsonPath)
+            .with_value(value.to_string())
+    };
+    assert_eq!(
+        filters_to_where(&[json("$.user.id = 5")], DatabaseType::PostgreSQL).unwrap().unwrap(),
+        "CASE WHEN jsonb_typeof((CAST(\"meta\" AS JSONB) #> '{\"user\",\"id\"}')) = 'number' \
+         THEN CAST(CAST(\"meta\" AS JSONB) #>> '{\"user\",\"id\"}' AS NUMERIC) END = 5"
+    );
+    assert_eq!(
+        filters_to_where(&[json("$.tags[0] != 'a'")], DatabaseType::MySQL).unwrap().unwrap(),
+        "(JSON_UNQUOTE(JSON_EXTRACT(`meta`, '$.\"tags\"[0]')) IS NULL \
+         OR JSON_UNQUOTE(JSON_EXTRACT(`meta`, '$.\"tags\"[0]')) <> 'a')"
+    );
+    assert_eq!(
+        filters_to_where(&[json("$.active = true")], DatabaseType::SQLite).unwrap().unwrap(),
+        "json_extract(\"meta\", '$.\"active\"') = 1"
+    );
+    assert_eq!(
+        filters_to_where(&[json("$.deleted_at")], DatabaseType::DuckDB).unwrap().unwrap(),
+        "json_exists(\"meta\", '$.\"deleted_at\"')"
+    );
+    assert!(filters_to_where(&[json("$.a = 1")], DatabaseType::ClickHouse).is_err());
+    assert!(filters_to_where(&[json("user.id = 1")], DatabaseType::PostgreSQL).is_err());
+    assert!(check_filter_match("{\"user\": {\"id\": 5}}", &FilterOperator::JsonPath, "$.user.id = 5", "", false));
+    assert!(!check_filter_match("{\"user\": {\"id\": 6}}", &FilterOperator::JsonPath, "$.user.id = 5", "", false));
+
+    assert_eq!(
+        DatabaseType::Oracle.select_where_sql("\"t\"", "\"a\" = 1", 10),
+        "SELECT * FROM \"t\" WHERE \"a\" = 1 FETCH FIRST 10 ROWS ONLY;"
+    );
+}
+
+#[test]
+fn test_filter_saved_roundtrip() {
+    use gridix::ui::{ColumnFilter, FilterLogic, FilterOperator};
+
+    let filter = ColumnFilter::new("created".to_string())
+        .with_operator(FilterOperator::NotBetween)
+        .with_value("1".to_string())
+        .with_value2("9".to_string())
+        .with_case_sensitive(true)
+        .with_logic(FilterLogic::Or);
+    let saved = filter.to_saved();
+    assert_eq!(saved.operator, "not_between");
+    assert!(saved.or_next);
+
+    let restored = ColumnFilter::from_saved(&saved);
+    assert_eq!(restored.operator, FilterOperator::NotBetween);
+    assert_eq!(restored.logic, FilterLogic::Or);
+    assert_eq!((restored.value.as_str(), restored.value2.as_str()), ("1", "9"));
+    assert!(restored.case_sensitive);
+
+    // 每个操作符的标识符都能还原
+    for op in FilterOperator::all() {
+        assert_eq!(FilterOperator::from_id(op.id()), Some(op));
+    }
+}
+
+// ============================================================================
+// 结果搜索测试
+// ============================================================================
+
+#[test]
+fn test_search_matcher_options() {
+    use gridix::ui::{SearchMatcher, SearchOptions};
+
+    let plain = SearchOptions::default();
+    assert!(SearchMatcher::new("", &plain).unwrap().is_none());
+
+    // 默认忽略大小写，正则字符按普通文本匹配
+    let matcher = SearchMatcher::new("a.b", &plain).unwrap().unwrap();
+    assert!(matcher.is_match("XA.By"));
+    assert!(!matcher.is_match("axb"));
+    assert_eq!(matcher.find_ranges("a.b and A.B"), vec![0..3, 8..11]);
+
+    let case = SearchOptions { case_sensitive: true, ..Default::default() };
+    assert!(!SearchMatcher::new("Bob", &case).unwrap().unwrap().is_match("bob"));
+
+    let word = SearchOptions { whole_word: true, ..Default::default() };
+    let matcher = SearchMatcher::new("cat", &word).unwrap().unwrap();
+    assert!(matcher.is_match("a cat."));
+    assert!(!matcher.is_match("concatenate"));
+
+    let regex = SearchOptions { regex: true, ..Default::default() };
+    let matcher = SearchMatcher::new(r"^\d{3}$", &regex).unwrap().unwrap();
+    assert!(matcher.is_match("123"));
+    assert!(!matcher.is_match("1234"));
+    assert!(SearchMatcher::new("(", &regex).is_err());
+}
+
+#[test]
+fn test_filter_rows_with_search_options() {
+    use gridix::ui::{filter_rows_cached, FilterCache, SearchOptions};
+
+    let result = QueryResult {
+        columns: vec!["name".to_string(), "email".to_string()],
+        rows: vec![
+            vec!["Ann".to_string(), "ann@x.io".to_string()],
+            vec!["anna".to_string(), "anna@y.io".to_string()],
+            vec!["Bob".to_string(), "bob@x.io".to_string()],
+        ],
+        ..Default::default()
+    };
+    let mut cache = FilterCache::default();
+    let rows = |options: &SearchOptions, text: &str, column: Option<&str>, cache: &mut FilterCache| {
+        filter_rows_cached(&result, text, &column.map(str::to_string), options, &[], cache)
+            .into_iter()
+            .map(|(idx, _)| idx)
+            .collect::<Vec<_>>()
+    };
+
+    let mut options = SearchOptions::default();
+    assert_eq!(rows(&options, "ann", Some("name"), &mut cache), vec![0, 1]);
+    // 切换选项后缓存失效
+    options.whole_word = true;
+    assert_eq!(rows(&options, "ann", Some("name"), &mut cache), vec![0]);
+    options.case_sensitive = true;
+    assert!(rows(&options, "ann", Some("name"), &mut cache).is_empty());
+
+    let regex = SearchOptions { regex: true, ..Default::default() };
+    assert_eq!(rows(&regex, r"@x\.io$", None, &mut cache), vec![0, 2]);
+    // 无效的正则表达式不筛选
+    assert_eq!(rows(&regex, "[", None, &mut cache), vec![0, 1, 2]);
+}
+
+#[test]
+fn test_filter_cache_keyed_by_result() {
+    use gridix::ui::{filter_rows_cached, FilterCache, SearchOptions};
+
+    let result = |names: &[&str]| QueryResult {
+        columns: vec!["name".to_string()],
+        rows: names.iter().map(|n| vec![n.to_string()]).collect(),
+        ..Default::default()
+    };
+    let first = Arc::new(result(&["ann", "bob"]));
+    let second = Arc::new(result(&["bob", "ann"]));
+    let options = SearchOptions::default();
+    let mut cache = FilterCache::default();
+    let rows = |r: &QueryResult, cache: &mut FilterCache| {
+        filter_rows_cached(r, "ann", &None, &options, &[], cache)
+            .into_iter()
+            .map(|(idx, _)| idx)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(rows(&first, &mut cache), vec![0]);
+    // 共享的同一结果命中缓存
+    let shared = Arc::clone(&first);
+    assert_eq!(rows(&shared, &mut cache), vec![0]);
+    assert!(cache.is_valid());
+    // 替换为行数相同的新结果后不复用旧的筛选索引（不依赖行数组地址）
+    let generation = cache.generation();
+    cache.result_replaced();
+    assert!(!cache.is_valid());
+    assert_ne!(cache.generation(), generation);
+    assert_eq!(rows(&second, &mut cache), vec![1]);
+}
+
+#[test]
+fn test_sort_indices() {
+    use gridix::ui::{sort_indices, ColumnSort};
+
+    let result = QueryResult {
+        columns: vec!["v".to_string()],
+        rows: ["10", "NULL", "9", "b", "a", "-1.5"].iter().map(|v| vec![v.to_string()]).collect(),
+        ..Default::default()
+    };
+    let sorted = |sort: ColumnSort| {
+        let mut indices: Vec<usize> = (0..result.rows.len()).collect();
+        sort_indices(&result, &mut indices, &sort);
+        indices.iter().map(|&i| result.rows[i][0].as_str()).collect::<Vec<_>>()
+    };
+
+    // 数字按数值比较并排在文本之前，NULL 始终在最后
+    assert_eq!(sorted(ColumnSort::ascending("v")), ["-1.5", "9", "10", "a", "b", "NULL"]);
+    assert_eq!(sorted(ColumnSort::descending("v")), ["10", "9", "-1.5", "b", "a", "NULL"]);
+    // 排序列不存在时保持原顺序
+    assert_eq!(sorted(ColumnSort::ascending("missing"))[..2], ["10", "NULL"]);
+}
+
+#[test]
+fn test_filter_rows_background() {
+    use gridix::ui::{filter_rows_background, ColumnSort, FilterCache, SearchOptions};
+
+    let ctx = egui::Context::default();
+    let result = Arc::new(QueryResult {
+        columns: vec!["n".to_string()],
+        rows: (0..60_000).map(|i| vec![i.to_string()]).collect(),
+        ..Default::default()
+    });
+    let options = SearchOptions::default();
+    let sort = ColumnSort::descending("n");
+    let mut cache = FilterCache::default();
+
+    // 大结果集在后台计算，计算期间按原始顺序显示
+    let (rows, computing) = filter_rows_background(&ctx, &result, "5999", &None, &options, &[], Some(&sort), &mut cache);
+    assert!(computing);
+    assert_eq!(rows.len(), 60_000);
+
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(10);
+    let indices = loop {
+        let (rows, computing) = filter_rows_background(&ctx, &result, "5999", &None, &options, &[], Some(&sort), &mut cache);
+        if !computing {
+            break rows.into_iter().map(|(idx, _)| idx).collect::<Vec<_>>();
+        }
+        assert!(std::time::Instant::now() < deadline, "后台筛选超时");
+        std::thread::sleep(std::time::Duration::from_millis(5));
+    };
+    let expected: Vec<usize> = (0..60_000).rev().filter(|i| i.to_string().contains("5999")).collect();
+    assert_eq!(indices, expected);
+
+    // 条件变化时替换（取消）未完成的计算
+    let (_, computing) = filter_rows_background(&ctx, &result, "1", &None, &options, &[], None, &mut cache);
+    assert!(computing);
+    assert!(cache.is_computing());
+}
+
+// ============================================================================
+// 全库数据搜索窗口测试
+// ============================================================================
+
+#[test]
+fn test_data_search_state() {
+    let rows = |n: usize| QueryResult {
+        columns: vec!["name".to_string()],
+        rows: (0..n).map(|i| vec![format!("bob{}", i)]).collect(),
+        ..Default::default()
+    };
+    let mut state = DataSearchState::new();
+    state.open(vec!["users".to_string(), "orders".to_string(), "logs".to_string()]);
+    assert!(state.show);
+    state.tables[2].selected = false;
+    assert_eq!(state.selected_tables(), vec!["users", "orders"]);
+
+    state.term = "bob".to_string();
+    let first = state.start(2);
+    let second = state.start(2);
+    assert!(state.searching);
+
+    // 已取消搜索的结果被忽略
+    state.add_result(first, "users".to_string(), DataSearchOutcome::Searched("q".to_string(), Ok(rows(3))));
+    assert!(state.hits.is_empty());
+
+    state.add_result(second, "users".to_string(), DataSearchOutcome::Searched("q1".to_string(), Ok(rows(2))));
+    state.add_result(second, "orders".to_string(), DataSearchOutcome::Failed("boom".to_string()));
+    state.add_result(second, "logs".to_string(), DataSearchOutcome::Searched("q2".to_string(), Ok(rows(0))));
+    state.add_result(second, "audit".to_string(), DataSearchOutcome::NoTextColumns);
+    assert_eq!(state.completed, 4);
+    assert_eq!(state.skipped, 1);
+    // 无命中的表不显示，其余按表名排序
+    let tables: Vec<&str> = state.hits.iter().map(|h| h.table.as_str()).collect();
+    assert_eq!(tables, vec!["orders", "users"]);
+    assert_eq!(state.hit_rows(), 2);
+
+    state.finish(first);
+    assert!(state.searching);
+    state.finish(second);
+    assert!(!state.searching);
+
+    // 表列表不变时保留结果和选择
+    state.close();
+    state.open(vec!["users".to_string(), "orders".to_string(), "logs".to_string()]);
+    assert_eq!(state.hits.len(), 2);
+    assert!(!state.tables[2].selected);
+    state.open(vec!["users".to_string()]);
+    assert!(state.hits.is_empty());
+    assert!(state.tables[0].selected);
+}
+
+// ============================================================================
+// 表格聚合统计测试
+// ============================================================================
+
+#[test]
+fn test_grid_aggregate() {
+    use gridix::ui::{format_number, Aggregate};
+
+    let numbers = Aggregate::compute(["3", "NULL", "1.5", "-2", ""]);
+    assert_eq!(numbers.cells, 5);
+    assert_eq!(numbers.count, 3);
+    let stats = numbers.numeric.unwrap();
+    assert_eq!(format_number(stats.sum), "2.5");
+    assert_eq!(format_number(stats.avg), "0.8333");
+    assert_eq!(numbers.min.as_deref(), Some("-2"));
+    assert_eq!(numbers.max.as_deref(), Some("3"));
+
+    // 存在非数字值时按文本比较
+    let texts = Aggregate::compute(["2024-03-01", "2023-12-31", "NaN"]);
+    assert!(texts.numeric.is_none());
+    assert_eq!(texts.count, 3);
+    assert_eq!(texts.min.as_deref(), Some("2023-12-31"));
+    assert_eq!(texts.max.as_deref(), Some("NaN"));
+
+    let empty = Aggregate::compute(["NULL"]);
+    assert_eq!(empty.count, 0);
+    assert!(empty.numeric.is_none() && empty.min.is_none());
+
+    assert_eq!(format_number(10.0), "10");
+    assert_eq!(format_number(0.1 + 0.2), "0.3");
+}
+
+// ============================================================================
+// 重复行检测测试
+// ============================================================================
+
+#[test]
+fn test_duplicate_groups() {
+    use gridix::ui::DuplicateGroups;
+
+    let row = |id: &str, name: &str, city: &str| vec![id.to_string(), name.to_string(), city.to_string()];
+    let result = QueryResult {
+        columns: vec!["id".to_string(), "name".to_string(), "city".to_string()],
+        rows: vec![
+            row("1", "bob", "Paris"),
+            row("2", "amy", "Rome"),
+            row("3", "bob", "Paris"),
+            row("4", "amy", "Oslo"),
+            row("5", "bob", "Paris"),
+        ],
+        ..Default::default()
+    };
+
+    // 全部列：主键不同，没有重复
+    assert!(DuplicateGroups::find(&result, &[]).groups.is_empty());
+
+    let by_name_city = DuplicateGroups::find(&result, &[1, 2]);
+    assert_eq!(by_name_city.groups, vec![vec![0, 2, 4]]);
+    assert_eq!(by_name_city.redundant_rows(), 2);
+    assert_eq!(by_name_city.rows_to_delete(), vec![2, 4]);
+    assert_eq!(by_name_city.group_of(2), Some(0));
+    assert_eq!(by_name_city.group_of(1), None);
+
+    let by_name = DuplicateGroups::find(&result, &[1]);
+    assert_eq!(by_name.groups, vec![vec![0, 2, 4], vec![1, 3]]);
+    assert_eq!(by_name.group_of(3), Some(1));
+    assert!(!by_name.is_stale(&result));
+
+    let mut changed = result.clone();
+    changed.rows.pop();
+    assert!(by_name.is_stale(&changed));
+}
+
+#[test]
+fn test_duplicate_delete_method() {
+    use gridix::database::DatabaseType;
+    use gridix::ui::{DedupDelete, DuplicateGroups};
+
+    let row = |id: &str, name: &str| vec![id.to_string(), name.to_string()];
+    let result = QueryResult {
+        columns: vec!["id".to_string(), "name".to_string()],
+        rows: vec![row("1", "bob"), row("2", "o'neil"), row("3", "bob"), row("2", "o'neil"), row("4", "NULL"), row("5", "NULL")],
+        ..Default::default()
+    };
+    let by_name = DuplicateGroups::find(&result, &[1]);
+    let all = DuplicateGroups::find(&result, &[]);
+
+    // 组内主键互不相同时按主键删除
+    let pg = Some(DatabaseType::PostgreSQL);
+    let distinct_ids = QueryResult { rows: result.rows[..3].to_vec(), ..result.clone() };
+    assert_eq!(
+        DuplicateGroups::find(&distinct_ids, &[1]).delete_method(&distinct_ids, Some(0), None),
+        Some(DedupDelete::PrimaryKey(0))
+    );
+    // 组内主键相同（完全相同的行），按主键删除会连保留的行一起删掉
+    assert_eq!(by_name.delete_method(&result, Some(0), None), None);
+    assert_eq!(all.delete_method(&result, Some(0), Some(DatabaseType::SQLite)), None);
+    assert_eq!(by_name.delete_method(&result, None, pg), Some(DedupDelete::Limited(DatabaseType::PostgreSQL)));
+
+    let sql = by_name.limited_delete_sql(&result, "users", DatabaseType::MySQL).unwrap();
+    assert_eq!(
+        sql,
+        vec![
+            "DELETE FROM users WHERE name = 'bob' LIMIT 1;",
+            "DELETE FROM users WHERE name = 'o''neil' LIMIT 1;",
+            "DELETE FROM users WHERE name IS NULL LIMIT 1;",
+        ]
+    );
+    let sql = all.limited_delete_sql(&result, "users", DatabaseType::PostgreSQL).unwrap();
+    assert_eq!(
+        sql,
+        vec!["DELETE FROM users WHERE ctid IN (SELECT ctid FROM users WHERE id = '2' AND name = 'o''neil' LIMIT 1);"]
+    );
+    assert!(by_name.limited_delete_sql(&result, "bad;name", DatabaseType::MySQL).is_err());
+}
+
+// ============================================================================
+// 二进制查看器测试
+// ============================================================================
+
+#[test]
+fn test_blob_viewer_files() {
+    use gridix::ui::BlobViewer;
+
+    assert!(BlobViewer::open("data #1".to_string(), (0, 1), "text").is_none());
+    let mut viewer = BlobViewer::open("data #1".to_string(), (0, 1), "\\x4142").unwrap();
+    assert_eq!(viewer.bytes, b"AB");
+    assert!(viewer.dump.ends_with("|AB|"));
+    assert!(!viewer.is_truncated());
+
+    let dir = tempfile::tempdir().unwrap();
+    let out = dir.path().join("out.bin");
+    viewer.save_to(&out).unwrap();
+    assert_eq!(std::fs::read(&out).unwrap(), b"AB");
+
+    let input = dir.path().join("in.bin");
+    std::fs::write(&input, [0u8, 1, 2]).unwrap();
+    assert_eq!(viewer.import_from(&input).unwrap(), "\\x000102");
+    assert_eq!(viewer.bytes, vec![0, 1, 2]);
+    assert!(viewer.import_from(&dir.path().join("missing")).is_err());
+}
+
+#[test]
+fn test_pool_stats_state() {
+    use gridix::database::{DatabaseType, PoolStats};
+
+    let mut state = PoolStatsState::new();
+    assert!(!state.needs_refresh());
+
+    let stats = PoolStats {
+        key: "mysql:localhost:3306:root:shop".to_string(),
+        db_type: DatabaseType::MySQL,
+        connections: 5,
+        idle: 2,
+        waiting: 0,
+        max_connections: 10,
+        idle_secs: 0,
+    };
+    assert_eq!(stats.active(), 3);
+    state.open(vec![stats]);
+    assert!(state.show);
+    // 刚刷新过，间隔内不再刷新
+    assert!(!state.needs_refresh());
+
+    state.invalidate();
+    assert!(state.needs_refresh());
+
+    state.close();
+    assert!(state.stats.is_empty());
+    assert!(!state.needs_refresh());
+}
+
+#[test]
+fn test_write_preview_state() {
+    let mut state = WritePreviewState::new();
+    let sql = "DELETE FROM users WHERE id > 10".to_string();
+    state.open(sql.clone(), write_preview(&sql).unwrap());
+    assert!(state.loading);
+    assert!(!state.can_execute());
+
+    // 其他语句的旧结果被忽略
+    state.set_result("SELECT * FROM orders", Ok(QueryResult::default()));
+    assert!(state.loading);
+
+    let rows = QueryResult {
+        columns: vec!["id".into()],
+        rows: vec![vec!["11".into()], vec!["12".into()]],
+        ..QueryResult::default()
+    };
+    state.set_result("SELECT * FROM users WHERE id > 10", Ok(rows));
+    assert_eq!(state.affected_rows(), Some(2));
+    assert!(state.can_execute());
+
+    // 预览失败时不允许执行
+    state.set_result("SELECT * FROM users WHERE id > 10", Err("denied".into()));
+    assert!(!state.can_execute());
+    assert_eq!(state.affected_rows(), None);
+
+    state.close();
+    assert!(!state.show);
+    assert!(state.preview.is_none());
+}
+
+#[test]
+fn test_sql_review_state() {
+    let mut state = SqlReviewState::new();
+    let statements = vec![
+        "UPDATE users SET name = 'a' WHERE id = 1;".to_string(),
+        "DELETE FROM users WHERE id = 2;".to_string(),
+    ];
+    state.open("users".to_string(), &statements, true);
+    assert!(state.show);
+    assert!(state.transaction());
+    assert_eq!(state.sql, "UPDATE users SET name = 'a' WHERE id = 1;\nDELETE FROM users WHERE id = 2;");
+    assert_eq!(
+        StatementCounts::of(&state.statements()),
+        StatementCounts { updates: 1, deletes: 1, inserts: 0, others: 0 }
+    );
+    assert_eq!(
+        state.script(),
+        "BEGIN;\nUPDATE users SET name = 'a' WHERE id = 1;\nDELETE FROM users WHERE id = 2;\nCOMMIT;"
+    );
+
+    // 编辑后的语句
+    state.sql.push_str("\nINSERT INTO users (id) VALUES (3)");
+    assert_eq!(state.statements().len(), 3);
+    state.use_transaction = false;
+    assert!(!state.script().starts_with("BEGIN"));
+
+    // 不支持事务的数据库始终依次执行
+    state.open("users".to_string(), &statements, false);
+    state.use_transaction = true;
+    assert!(!state.transaction());
+
+    state.close();
+    assert!(!state.show);
+    assert!(state.sql.is_empty());
+}
+
+#[test]
+fn test_bulk_edit_state() {
+    let mut state = BulkEditState::new();
+    let columns = vec!["status".to_string(), "price".to_string()];
+    state.open("`orders`".to_string(), columns, "`status` = 'new'".to_string(), DatabaseType::MySQL);
+    assert!(state.show);
+    assert_eq!(state.column, "status");
+
+    // 文本值按字符串转义
+    state.value = "it's done".to_string();
+    assert_eq!(
+        state.update_sql().unwrap(),
+        "UPDATE `orders` SET `status` = 'it''s done' WHERE `status` = 'new'"
+    );
+    // MySQL 的反斜杠是转义符，结尾的反斜杠不能转义掉右引号
+    state.value = "C:\\temp\\".to_string();
+    assert_eq!(
+        state.update_sql().unwrap(),
+        "UPDATE `orders` SET `status` = 'C:\\\\temp\\\\' WHERE `status` = 'new'"
+    );
+
+    // 表达式原样使用，不允许多条语句
+    state.column = "price".to_string();
+    state.value_kind = BulkValueKind::Expression;
+    state.value = "price * 1.1;".to_string();
+    assert_eq!(
+        state.update_sql().unwrap(),
+        "UPDATE `orders` SET `price` = price * 1.1 WHERE `status` = 'new'"
+    );
+    state.value = "0; DROP TABLE orders".to_string();
+    assert!(state.update_sql().is_err());
+    state.value = "  ".to_string();
+    assert!(state.update_sql().is_err());
+
+    // 文本 NULL 仍是字符串，勾选设为 NULL 时忽略输入的值
+    state.value_kind = BulkValueKind::Literal;
+    state.value = "NULL".to_string();
+    assert!(state.update_sql().unwrap().contains("SET `price` = 'NULL'"));
+    state.set_null = true;
+    assert!(state.update_sql().unwrap().contains("SET `price` = NULL"));
+
+    // 生成的语句可以预览影响行
+    let preview = write_preview(&state.update_sql().unwrap()).unwrap();
+    assert_eq!(preview.select_sql, "SELECT * FROM `orders` WHERE `status` = 'new'");
+
+    // PostgreSQL 使用双引号，非法列名报错
+    state.open("\"orders\"".to_string(), vec!["status".to_string()], "TRUE".to_string(), DatabaseType::PostgreSQL);
+    state.value = "done".to_string();
+    assert!(state.update_sql().unwrap().starts_with("UPDATE \"orders\" SET \"status\""));
+    state.column = "bad;col".to_string();
+    assert!(state.update_sql().is_err());
+
+    // 没有筛选条件时不生成语句
+    state.open("t".to_string(), vec!["a".to_string()], String::new(), DatabaseType::SQLite);
+    assert!(state.update_sql().is_err());
+
+    state.close();
+    assert!(!state.show);
+}
+
+#[test]
+fn test_key_hint_bar() {
+    let mut keybindings = KeyBindings::default();
+    let mut context = HintContext {
+        area: FocusArea::DataGrid,
+        grid_mode: GridMode::Normal,
+        grid_pending: "",
+        editor_mode: EditorMode::Normal,
+    };
+    let hints = HintBar::hints(&context, &keybindings);
+    assert!(hints.iter().any(|h| h.keys == "hjkl"));
+    assert!(hints.iter().any(|h| h.keys == "Ctrl+S" && h.desc == Action::Save.description()));
+
+    // 全局快捷键按自定义后的按键显示
+    keybindings.set(Action::Save, KeyBinding::ctrl(KeyCode::W));
+    let hints = HintBar::hints(&context, &keybindings);
+    assert!(hints.iter().any(|h| h.keys == "Ctrl+W" && h.desc == Action::Save.description()));
+    assert!(!hints.iter().any(|h| h.keys == "Ctrl+S"));
+
+    // 输入命令前缀后只显示可以继续输入的按键
+    context.grid_pending = "g";
+    let keys: Vec<String> = HintBar::hints(&context, &keybindings).into_iter().map(|h| h.keys).collect();
+    assert_eq!(keys, ["gg", "ge", "gh", "gl"]);
+    assert!(HintBar::pending_hints("x").is_empty());
+
+    // 未绑定的操作不显示
+    context.area = FocusArea::QueryTabs;
+    let hints = HintBar::hints(&context, &keybindings);
+    assert!(hints.iter().any(|h| h.desc == Action::RenameTab.description()));
+    assert!(!hints.iter().any(|h| h.desc == Action::PinTab.description()));
+
+    context.area = FocusArea::SqlEditor;
+    context.editor_mode = EditorMode::Insert;
+    assert!(HintBar::hints(&context, &keybindings).iter().any(|h| h.keys == "Tab"));
+}
+
+// ============================================================================
+// 新建数据库对话框测试
+// ============================================================================
+
+#[test]
+fn test_create_db_sql_quotes_names() {
+    let mut state = CreateDbDialogState::new();
+    state.open(DatabaseType::DuckDB);
+    state.db_name = "analytics".to_string();
+    assert_eq!(state.generate_sql().unwrap(), "CREATE SCHEMA \"analytics\";");
+
+    state.open(DatabaseType::ClickHouse);
+    state.db_name = "events".to_string();
+    assert_eq!(state.generate_sql().unwrap(), "CREATE DATABASE `events`;");
+
+    // 名称中的引号不能拼进语句
+    state.open(DatabaseType::DuckDB);
+    state.db_name = "a\"; DROP SCHEMA main; --".to_string();
+    assert!(state.generate_sql().is_err());
+}